@@ -3,6 +3,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Range;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex, mpsc};
 use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
@@ -12,12 +13,13 @@ use codex_app_server_protocol::SkillMetadata;
 use gpui::{
     AnchoredPositionMode, Animation, AnimationExt as _, AnyWindowHandle, App, AppContext as _,
     Bounds, ClipboardItem, Context, Corner, Decorations, DragMoveEvent, Empty, Entity, EntityId,
-    EntityInputHandler, FocusHandle, InteractiveElement as _, KeyBinding, ListAlignment,
-    ListOffset, ListSizingBehavior, ListState, Menu, MenuItem, MouseButton, MouseDownEvent,
-    MouseMoveEvent, MouseUpEvent, OsAction, ParentElement as _, PathPromptOptions, Pixels, Point,
-    Render, ScrollHandle, ScrollWheelEvent, SharedString, StatefulInteractiveElement as _,
-    Styled as _, SystemMenuType, Task, TitlebarOptions, Window, WindowOptions, actions, anchored,
-    canvas, deferred, div, list, point, prelude::FluentBuilder as _, px,
+    EntityInputHandler, FocusHandle, InteractiveElement as _, KeyBinding, KeyDownEvent,
+    ListAlignment, ListOffset, ListSizingBehavior, ListState, Menu, MenuItem, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, OsAction, ParentElement as _, PathPromptOptions,
+    Pixels, Point, Render, ScrollHandle, ScrollWheelEvent, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Subscription, SystemMenuType, Task,
+    TitlebarOptions, Window, WindowHandle, WindowOptions, actions, anchored, canvas, deferred, div,
+    list, point, prelude::FluentBuilder as _, px,
 };
 use gpui_component::{
     ActiveTheme as _, Colorize as _, GlobalState, Root, RopeExt, StyledExt as _, Theme, ThemeMode,
@@ -36,25 +38,60 @@ mod hunk_picker;
 use hunk_assets::HunkAssets;
 pub(crate) use hunk_assets::HunkIconName;
 
+use hunk_domain::bidi::TextDirection;
+use hunk_domain::change_set_size::ChangeSetSizeWarning;
+use hunk_domain::comment_import::ImportedComment;
 use hunk_domain::config::{
-    AppConfig, ConfigStore, KeyboardShortcuts, TerminalConfig, TerminalShell, ThemePreference,
+    AppConfig, ConfigStore, DesktopNotificationsConfig, DiffRowDoubleClickAction,
+    FileMiddleClickAction, FontConfig, FormatterCommand, FormattingConfig, GestureConfig,
+    GitTimeoutConfig, KeyboardShortcuts, ReviewTimerConfig, TerminalConfig, TerminalShell,
+    ThemePreference,
 };
+use hunk_domain::crash::CrashReport;
 use hunk_domain::db::{
-    CommentLineSide, CommentRecord, CommentStatus, DatabaseStore, NewComment,
-    format_comment_clipboard_blob, next_status_for_unmatched_anchor, now_unix_ms,
+    CommentExportFormat, CommentExportScope, CommentLineSide, CommentRecord, CommentScopeCounts,
+    CommentScopeRevision, CommentStatus, DatabaseActor, DatabaseReply, DatabaseStore, NewComment,
+    UsageMetricKind, UsageMetricsSummary, format_comment_clipboard_blob,
+    is_comment_database_locked, next_status_for_unmatched_anchor, now_unix_ms,
 };
-use hunk_domain::diff::{DiffCell, DiffCellKind, DiffRowKind, SideBySideRow};
+use hunk_domain::diff::{DiffCell, DiffCellKind, DiffRowKind, HunkFolds, SideBySideRow};
+use hunk_domain::editor_recovery::{EditorRecoveryStore, RecoveredEditorBuffer};
+use hunk_domain::l10n::Language;
 use hunk_domain::markdown_preview::MarkdownPreviewBlock;
+use hunk_domain::mechanical_changes::{MechanicalChangeApprovals, MechanicalChangeGroup};
+use hunk_domain::snapshot_cache::SnapshotCacheStore;
 use hunk_domain::state::{
     AiCollaborationModeSelection, AiServiceTierSelection, AppState, AppStateStore,
     CachedChangedFileState, CachedLocalBranchState, CachedRecentCommitState,
-    CachedRecentCommitsState, CachedWorkflowState, ReviewCompareSelectionState,
+    CachedRecentCommitsState, CachedWorkflowState, MaintenanceRun, PanelDock,
+    ReviewCompareSelectionState,
 };
+use hunk_git::abandoned::AbandonedCommit;
+use hunk_git::cancel::CancellationToken;
+use hunk_git::codeowners::CodeOwners;
+use hunk_git::commit_message::CommitTrailer;
+use hunk_git::compare::CompareSnapshot;
+use hunk_git::encoding::TextEncoding;
+use hunk_git::error::GitError;
 use hunk_git::git::{ChangedFile, FileStatus, LineStats, LocalBranch, RepoSnapshotFingerprint};
 use hunk_git::history::{
-    DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, RecentCommitSummary, RecentCommitsFingerprint,
+    CommitChangedFile, CommitDetails, DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, FileAuthorship,
+    RecentCommitSummary, RecentCommitsFingerprint,
 };
+use hunk_git::hunk_apply::{HunkApplyMode, HunkSelector, HunkStageState};
+use hunk_git::init::RepoInitTemplate;
+use hunk_git::issues::RepoIssue;
+use hunk_git::operations::{OperationDiff, RepoOperation};
+use hunk_git::path_collisions::PathCollision;
+use hunk_git::recovery::GitRecoveryAction;
+use hunk_git::sparse_checkout::SparseCheckout;
+use hunk_git::stack::{BranchStack, StackAction, StackCommit, StackPlanEntry};
+use hunk_git::tickets::TicketLinker;
+use hunk_git::user_config::{GitConfigScope, GitSigningFormat, GitUserSettings};
 use hunk_git::worktree::WorkspaceTargetSummary;
+use hunk_server::co_review::{
+    CoReviewEvent, CoReviewMessage, CoReviewSession, Participant, Presence, SharedComment,
+};
 use hunk_terminal::{
     TerminalEvent, TerminalScreenSnapshot, TerminalScroll, TerminalSessionHandle,
     TerminalSpawnRequest, spawn_terminal_session,
@@ -89,14 +126,18 @@ use ai_runtime::spawn_ai_worker;
 use branch_picker::{
     BranchPickerDelegate, branch_picker_selected_index, build_branch_picker_delegate,
 };
+use ci_log::{CiLogReference, ci_log_references};
 use data::{
-    DiffRowSegmentCache, DiffStreamRowMeta, FileRowRange, RepoTreeNode, RepoTreeNodeKind,
-    RepoTreeRow, WorkspaceSwitchAction, WorkspaceViewMode,
+    ChangedFileSort, ChangedLinesOnlyMode, DiffRowSegmentCache, DiffStreamRowMeta, FileRowRange,
+    RepoTreeNode, RepoTreeNodeKind, RepoTreeRow, ReviewedDirectories, WorkspacePanel,
+    WorkspaceSwitchAction, WorkspaceViewMode,
 };
+use detached_diff_window::open_detached_diff_window;
 use hunk_picker::{
     HunkPickerAction, HunkPickerConfig, HunkPickerEvent, HunkPickerState,
     hunk_picker_action_for_keystroke, render_hunk_picker,
 };
+use l10n::{tr, tr_args};
 use project_picker::{
     ProjectPickerDelegate, build_project_picker_delegate, project_picker_selected_index,
 };
@@ -108,11 +149,15 @@ use refresh_policy::{
     should_reload_diff_after_snapshot, should_reload_empty_files_workspace_tree,
     should_reload_repo_tree_after_snapshot, should_request_startup_git_workspace_refresh,
     should_run_cold_start_reconcile, should_scroll_selected_after_reload,
+    single_file_watch_update_path,
 };
+use repo_content_search::{RepoContentSearchResults, search_repo_contents};
 use repo_file_search::RepoFileSearchProvider;
 use review_compare_picker::{
     ReviewComparePickerDelegate, ReviewCompareSourceOption, build_review_compare_picker_delegate,
 };
+use review_timer::{ReviewTimer, review_timer_label};
+use vim_mode::{VimCommand, VimMode, vim_command_for_key};
 use workspace_target_picker::{
     WorkspaceTargetPickerDelegate, build_workspace_target_picker_delegate,
     workspace_target_picker_selected_index,
@@ -122,7 +167,11 @@ use workspace_view::{SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE, SHORTCUT_CONTEXT_TRE
 const FPS_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
 const AI_PERF_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
 const AUTO_REFRESH_SCROLL_DEBOUNCE: Duration = Duration::from_millis(500);
+/// Digit width assumed for the diff gutters until the configured code font is measured.
 const DIFF_MONO_CHAR_WIDTH: f32 = 8.0;
+const DIFF_TEXT_ZOOM_MIN: f32 = 0.75;
+const DIFF_TEXT_ZOOM_MAX: f32 = 1.4;
+const DIFF_TEXT_ZOOM_PER_SCROLL_PX: f32 = 0.002;
 const DIFF_LINE_NUMBER_MIN_DIGITS: u32 = 3;
 const DIFF_LINE_NUMBER_EXTRA_PADDING: f32 = 6.0;
 const DIFF_MARKER_GUTTER_WIDTH: f32 = 10.0;
@@ -135,20 +184,33 @@ const DIFF_SPLIT_HANDLE_WIDTH: f32 = 1.0;
 const DIFF_SPLIT_HANDLE_HIT_WIDTH: f32 = 10.0;
 const FILE_EDITOR_MAX_BYTES: usize = 2_400_000;
 const FILE_EDITOR_TAB_LIMIT: usize = 8;
+/// How far back the file banner counts distinct contributors.
+const FILE_AUTHORSHIP_WINDOW_DAYS: i64 = 90;
 pub(crate) const FILES_WORKSPACE_RAIL_HEIGHT: f32 = 32.0;
 const ABOUT_HUNK_VERSION_LABEL: &str = concat!("Version ", env!("CARGO_PKG_VERSION"));
 const ABOUT_HUNK_DESCRIPTION_LINE_ONE: &str = "A fast diff viewer and Codex orchestrator.";
 const ABOUT_HUNK_DESCRIPTION_LINE_TWO: &str = "Hunk is built in GPUI and aims to be very fast.";
 const MARKDOWN_PREVIEW_DEBOUNCE: Duration = Duration::from_millis(200);
+const EDITOR_LINE_CHANGES_DEBOUNCE: Duration = Duration::from_millis(300);
+const REPO_CONTENT_SEARCH_DEBOUNCE: Duration = Duration::from_millis(250);
+const EDITOR_RECOVERY_INTERVAL: Duration = Duration::from_secs(2);
+const REVIEW_TIMER_TICK_INTERVAL: Duration = Duration::from_secs(20);
 const DIFF_SEGMENT_PREFETCH_RADIUS_ROWS: usize = 120;
 const DIFF_SEGMENT_PREFETCH_STEP_ROWS: usize = 24;
 const DIFF_SEGMENT_PREFETCH_BATCH_ROWS: usize = 96;
 const SIDEBAR_REPO_LIST_ESTIMATED_ROW_HEIGHT: f32 = 24.0;
 const COMMENT_CONTEXT_RADIUS_ROWS: usize = 2;
-const COMMENT_RETENTION_DAYS: i64 = 14;
 const COMMENT_PREVIEW_MAX_ITEMS: usize = 64;
 const COMMENT_RECONCILE_MISS_THRESHOLD: u8 = 2;
 const COMMENT_FUZZY_MATCH_MIN_SCORE: i32 = 6;
+const COMMENTS_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+const COMMENTS_LOAD_FAILED_MESSAGE: &str = "Failed to load comments from local database.";
+const COMMENT_PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+const CO_REVIEW_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const REMOTE_BRANCH_WATCH_INTERVAL: Duration = Duration::from_secs(120);
+const REMOTE_BRANCH_WATCH_TIMEOUT: Duration = Duration::from_secs(30);
+const CI_WATCH_INTERVAL: Duration = Duration::from_secs(30);
+const CI_WATCH_MAX_POLLS: usize = 120;
 const COMMENT_FUZZY_RENAME_MATCH_MIN_SCORE: i32 = 11;
 const AI_TIMELINE_DEFAULT_VISIBLE_TURNS: usize = 80;
 const AI_TIMELINE_TURN_PAGE_SIZE: usize = 80;
@@ -170,103 +232,45 @@ mod project_open;
 mod project_picker;
 mod refresh_policy;
 mod review_compare_picker;
+mod review_timer;
 mod workspace_target_picker;
 
 include!("app/types.rs");
+include!("app/panel_state.rs");
 
 mod ai_git_progress;
 mod ai_rollout_fallback;
 mod ai_runtime;
+mod ci_log;
 mod controller;
 mod data;
+mod data_line_stats;
+mod data_path_copy;
 mod data_segments;
+mod data_stream;
+mod desktop_notifications;
+mod detached_diff_window;
+mod diff_links;
+mod file_formatter;
+mod github_review;
 mod highlight;
+mod l10n;
 mod markdown_links;
 mod native_files_editor;
 mod notifications;
 mod render;
+mod repo_content_search;
 mod repo_file_search;
 mod review_workspace_session;
 mod terminal_cursor;
 mod theme;
+mod vim_mode;
 mod workspace_surface;
 mod workspace_view;
 
-actions!(
-    diff_viewer,
-    [
-        SelectNextLine,
-        SelectPreviousLine,
-        ExtendSelectionNextLine,
-        ExtendSelectionPreviousLine,
-        CopySelection,
-        SelectAllDiffRows,
-        NextHunk,
-        PreviousHunk,
-        NextFile,
-        PreviousFile,
-        ViewCurrentReviewFile,
-        ToggleSidebarTree,
-        SwitchToFilesView,
-        SwitchToReviewView,
-        SwitchToGitView,
-        SwitchToAiView,
-        AiToggleTerminalDrawer,
-        AiTerminalSendCtrlC,
-        AiTerminalSendCtrlA,
-        AiTerminalSendTab,
-        AiTerminalSendBackTab,
-        AiTerminalSendUp,
-        AiTerminalSendDown,
-        AiTerminalSendLeft,
-        AiTerminalSendRight,
-        AiTerminalSendHome,
-        AiTerminalSendEnd,
-        AiNewThread,
-        AiNewWorktreeThread,
-        AiQueuePrompt,
-        AiEditLastQueuedPrompt,
-        AiInterruptSelectedTurn,
-        OpenProject,
-        QuickOpenFile,
-        FilesEditorCopy,
-        FilesEditorCut,
-        FilesEditorPaste,
-        FilesEditorMoveUp,
-        FilesEditorMoveDown,
-        FilesEditorMoveLeft,
-        FilesEditorMoveRight,
-        FilesEditorMoveToBeginningOfLine,
-        FilesEditorMoveToEndOfLine,
-        FilesEditorMoveToBeginningOfDocument,
-        FilesEditorMoveToEndOfDocument,
-        FilesEditorMoveToPreviousWordStart,
-        FilesEditorMoveToNextWordEnd,
-        FilesEditorSelectUp,
-        FilesEditorSelectDown,
-        FilesEditorSelectLeft,
-        FilesEditorSelectRight,
-        FilesEditorSelectToBeginningOfLine,
-        FilesEditorSelectToEndOfLine,
-        FilesEditorSelectToBeginningOfDocument,
-        FilesEditorSelectToEndOfDocument,
-        FilesEditorSelectToPreviousWordStart,
-        FilesEditorSelectToNextWordEnd,
-        FilesEditorPageUp,
-        FilesEditorPageDown,
-        NextEditorTab,
-        PreviousEditorTab,
-        CloseEditorTab,
-        SaveCurrentFile,
-        AboutHunk,
-        OpenSettings,
-        QuitApp,
-        RepoTreeNewFile,
-        RepoTreeNewFolder,
-        RepoTreeRenameFile,
-        RepoTreeCancelInlineEdit,
-    ]
-);
+include!("app/actions.rs");
+include!("app/menus.rs");
+include!("app/launch.rs");
 
 fn preferred_ui_font_family() -> &'static str {
     if cfg!(target_os = "macos") {
@@ -288,660 +292,6 @@ fn preferred_mono_font_family() -> &'static str {
     }
 }
 
-fn build_application_menus() -> Vec<Menu> {
-    if cfg!(target_os = "macos") {
-        vec![
-            Menu {
-                name: "Hunk".into(),
-                items: vec![
-                    MenuItem::action("About Hunk", AboutHunk),
-                    MenuItem::separator(),
-                    MenuItem::os_submenu("Services", SystemMenuType::Services),
-                    MenuItem::separator(),
-                    MenuItem::action("Settings...", OpenSettings),
-                    MenuItem::separator(),
-                    MenuItem::action("Quit Hunk", QuitApp),
-                ],
-            },
-            Menu {
-                name: "File".into(),
-                items: vec![
-                    MenuItem::action("Open Project...", OpenProject),
-                    MenuItem::action("Quick Open...", QuickOpenFile),
-                    MenuItem::action("Save File", SaveCurrentFile),
-                    MenuItem::separator(),
-                    MenuItem::action("About Hunk", AboutHunk),
-                    MenuItem::action("Settings...", OpenSettings),
-                ],
-            },
-            Menu {
-                name: "Edit".into(),
-                items: vec![
-                    MenuItem::os_action("Copy", CopySelection, OsAction::Copy),
-                    MenuItem::os_action("Select All", SelectAllDiffRows, OsAction::SelectAll),
-                ],
-            },
-        ]
-    } else {
-        vec![
-            Menu {
-                name: "File".into(),
-                items: vec![
-                    MenuItem::action("Open Project...", OpenProject),
-                    MenuItem::action("Quick Open...", QuickOpenFile),
-                    MenuItem::action("Save File", SaveCurrentFile),
-                    MenuItem::separator(),
-                    MenuItem::action("About Hunk", AboutHunk),
-                    MenuItem::action("Settings...", OpenSettings),
-                    MenuItem::separator(),
-                    MenuItem::action("Quit Hunk", QuitApp),
-                ],
-            },
-            Menu {
-                name: "Edit".into(),
-                items: vec![
-                    MenuItem::action("Copy", CopySelection),
-                    MenuItem::action("Select All", SelectAllDiffRows),
-                ],
-            },
-        ]
-    }
-}
-
-fn install_application_menus(cx: &mut App) {
-    cx.set_menus(build_application_menus());
-    GlobalState::global_mut(cx).set_app_menus(
-        build_application_menus()
-            .into_iter()
-            .map(|menu| menu.owned())
-            .collect(),
-    );
-}
-
-fn load_keyboard_shortcuts() -> KeyboardShortcuts {
-    let store = match ConfigStore::new() {
-        Ok(store) => store,
-        Err(err) => {
-            error!("failed to initialize config path for keyboard shortcuts: {err:#}");
-            return KeyboardShortcuts::default();
-        }
-    };
-
-    match store.load_or_create_default() {
-        Ok(config) => config.keyboard_shortcuts,
-        Err(err) => {
-            error!(
-                "failed to load keyboard shortcuts from {}: {err:#}",
-                store.path().display()
-            );
-            KeyboardShortcuts::default()
-        }
-    }
-}
-
-fn bind_keyboard_shortcuts(cx: &mut App, shortcuts: &KeyboardShortcuts) {
-    let mut bindings = Vec::new();
-
-    bindings.extend(shortcuts.select_next_line.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            SelectNextLine,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.select_previous_line.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            SelectPreviousLine,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.extend_selection_next_line.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            ExtendSelectionNextLine,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(
-        shortcuts
-            .extend_selection_previous_line
-            .iter()
-            .map(|shortcut| {
-                KeyBinding::new(
-                    shortcut.as_str(),
-                    ExtendSelectionPreviousLine,
-                    Some(WorkspaceViewMode::Diff.shortcut_context()),
-                )
-            }),
-    );
-    bindings.extend(shortcuts.copy_selection.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            CopySelection,
-            Some(SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE),
-        )
-    }));
-    bindings.extend(shortcuts.select_all_diff_rows.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            SelectAllDiffRows,
-            Some(SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE),
-        )
-    }));
-    bindings.extend(shortcuts.next_hunk.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            NextHunk,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.previous_hunk.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            PreviousHunk,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.next_file.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            NextFile,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.previous_file.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            PreviousFile,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.view_current_review_file.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            ViewCurrentReviewFile,
-            Some(WorkspaceViewMode::Diff.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.toggle_sidebar_tree.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            ToggleSidebarTree,
-            Some(SHORTCUT_CONTEXT_TREE_WORKSPACE),
-        )
-    }));
-    bindings.extend(
-        shortcuts
-            .switch_to_files_view
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToFilesView, None)),
-    );
-    bindings.extend(
-        shortcuts
-            .switch_to_review_view
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToReviewView, None)),
-    );
-    bindings.extend(
-        shortcuts
-            .switch_to_git_view
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToGitView, None)),
-    );
-    bindings.extend(
-        shortcuts
-            .switch_to_ai_view
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToAiView, None)),
-    );
-    bindings.extend(shortcuts.toggle_ai_terminal_drawer.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            AiToggleTerminalDrawer,
-            Some(WorkspaceViewMode::Ai.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.toggle_ai_terminal_drawer.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            AiToggleTerminalDrawer,
-            Some(WorkspaceViewMode::Files.shortcut_context()),
-        )
-    }));
-    bindings.push(KeyBinding::new(
-        "ctrl-c",
-        AiTerminalSendCtrlC,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-a",
-        AiTerminalSendCtrlA,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "tab",
-        AiTerminalSendTab,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-tab",
-        AiTerminalSendBackTab,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new("up", AiTerminalSendUp, Some("AiTerminal")));
-    bindings.push(KeyBinding::new(
-        "down",
-        AiTerminalSendDown,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "left",
-        AiTerminalSendLeft,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "right",
-        AiTerminalSendRight,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "home",
-        AiTerminalSendHome,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "end",
-        AiTerminalSendEnd,
-        Some("AiTerminal"),
-    ));
-    bindings.push(KeyBinding::new(
-        "cmd-n",
-        AiNewThread,
-        Some(WorkspaceViewMode::Ai.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-n",
-        AiNewThread,
-        Some(WorkspaceViewMode::Ai.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new(
-        "cmd-shift-n",
-        AiNewWorktreeThread,
-        Some(WorkspaceViewMode::Ai.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-shift-n",
-        AiNewWorktreeThread,
-        Some(WorkspaceViewMode::Ai.shortcut_context()),
-    ));
-    bindings.extend(
-        shortcuts
-            .open_project
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), OpenProject, None)),
-    );
-    bindings.push(KeyBinding::new(
-        "cmd-p",
-        QuickOpenFile,
-        Some(WorkspaceViewMode::Files.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-p",
-        QuickOpenFile,
-        Some(WorkspaceViewMode::Files.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new(
-        "cmd-c",
-        FilesEditorCopy,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-c",
-        FilesEditorCopy,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "cmd-x",
-        FilesEditorCut,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-x",
-        FilesEditorCut,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "cmd-v",
-        FilesEditorPaste,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "ctrl-v",
-        FilesEditorPaste,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "up",
-        FilesEditorMoveUp,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "down",
-        FilesEditorMoveDown,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "left",
-        FilesEditorMoveLeft,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "right",
-        FilesEditorMoveRight,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-up",
-        FilesEditorSelectUp,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-down",
-        FilesEditorSelectDown,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-left",
-        FilesEditorSelectLeft,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-right",
-        FilesEditorSelectRight,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "home",
-        FilesEditorMoveToBeginningOfLine,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "end",
-        FilesEditorMoveToEndOfLine,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-home",
-        FilesEditorSelectToBeginningOfLine,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-end",
-        FilesEditorSelectToEndOfLine,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "pageup",
-        FilesEditorPageUp,
-        Some("FilesEditor"),
-    ));
-    bindings.push(KeyBinding::new(
-        "pagedown",
-        FilesEditorPageDown,
-        Some("FilesEditor"),
-    ));
-    if cfg!(target_os = "macos") {
-        bindings.push(KeyBinding::new(
-            "cmd-left",
-            FilesEditorMoveToBeginningOfLine,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-right",
-            FilesEditorMoveToEndOfLine,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-up",
-            FilesEditorMoveToBeginningOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-down",
-            FilesEditorMoveToEndOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-shift-left",
-            FilesEditorSelectToBeginningOfLine,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-shift-right",
-            FilesEditorSelectToEndOfLine,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-shift-up",
-            FilesEditorSelectToBeginningOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-shift-down",
-            FilesEditorSelectToEndOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-home",
-            FilesEditorMoveToBeginningOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "cmd-end",
-            FilesEditorMoveToEndOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "alt-left",
-            FilesEditorMoveToPreviousWordStart,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "alt-right",
-            FilesEditorMoveToNextWordEnd,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "alt-shift-left",
-            FilesEditorSelectToPreviousWordStart,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "alt-shift-right",
-            FilesEditorSelectToNextWordEnd,
-            Some("FilesEditor"),
-        ));
-    } else {
-        bindings.push(KeyBinding::new(
-            "ctrl-left",
-            FilesEditorMoveToPreviousWordStart,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-right",
-            FilesEditorMoveToNextWordEnd,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-shift-left",
-            FilesEditorSelectToPreviousWordStart,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-shift-right",
-            FilesEditorSelectToNextWordEnd,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-home",
-            FilesEditorMoveToBeginningOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-end",
-            FilesEditorMoveToEndOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-shift-home",
-            FilesEditorSelectToBeginningOfDocument,
-            Some("FilesEditor"),
-        ));
-        bindings.push(KeyBinding::new(
-            "ctrl-shift-end",
-            FilesEditorSelectToEndOfDocument,
-            Some("FilesEditor"),
-        ));
-    }
-    bindings.extend(shortcuts.save_current_file.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            SaveCurrentFile,
-            Some(WorkspaceViewMode::Files.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.next_editor_tab.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            NextEditorTab,
-            Some(WorkspaceViewMode::Files.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.previous_editor_tab.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            PreviousEditorTab,
-            Some(WorkspaceViewMode::Files.shortcut_context()),
-        )
-    }));
-    bindings.extend(shortcuts.close_editor_tab.iter().map(|shortcut| {
-        KeyBinding::new(
-            shortcut.as_str(),
-            CloseEditorTab,
-            Some(WorkspaceViewMode::Files.shortcut_context()),
-        )
-    }));
-    bindings.extend(
-        shortcuts
-            .open_settings
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), OpenSettings, None)),
-    );
-    bindings.extend(
-        shortcuts
-            .quit_app
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), QuitApp, None)),
-    );
-    bindings.extend(
-        shortcuts
-            .repo_tree_new_file
-            .iter()
-            .map(|shortcut| KeyBinding::new(shortcut.as_str(), RepoTreeNewFile, Some("RepoTree"))),
-    );
-    bindings.extend(
-        shortcuts.repo_tree_new_folder.iter().map(|shortcut| {
-            KeyBinding::new(shortcut.as_str(), RepoTreeNewFolder, Some("RepoTree"))
-        }),
-    );
-    bindings.extend(
-        shortcuts.repo_tree_rename_file.iter().map(|shortcut| {
-            KeyBinding::new(shortcut.as_str(), RepoTreeRenameFile, Some("RepoTree"))
-        }),
-    );
-    bindings.push(KeyBinding::new(
-        "escape",
-        RepoTreeCancelInlineEdit,
-        Some("RepoTree"),
-    ));
-    bindings.push(KeyBinding::new(
-        "escape",
-        RepoTreeCancelInlineEdit,
-        Some("RepoTreeInlineEdit"),
-    ));
-    bindings.push(KeyBinding::new(
-        "escape",
-        AiInterruptSelectedTurn,
-        Some(WorkspaceViewMode::Ai.shortcut_context()),
-    ));
-    bindings.push(KeyBinding::new("tab", AiQueuePrompt, Some("AiComposer")));
-    bindings.push(KeyBinding::new(
-        "ctrl-shift-up",
-        AiEditLastQueuedPrompt,
-        Some("AiComposer"),
-    ));
-    bindings.push(KeyBinding::new(
-        "shift-enter",
-        InputEnter { secondary: true },
-        Some("Input"),
-    ));
-
-    cx.bind_keys(bindings);
-}
-
-pub fn run() -> Result<()> {
-    let app = gpui_platform::application().with_assets(HunkAssets);
-    let keyboard_shortcuts = load_keyboard_shortcuts();
-    app.on_reopen(|cx: &mut App| {
-        if cx.windows().is_empty() {
-            open_main_window(cx);
-        }
-        cx.activate(true);
-    });
-
-    app.run(move |cx| {
-        gpui_component::init(cx);
-        theme::install_hunk_themes(cx);
-        // Keep a global quit hook alive so tracked Codex hosts are cleaned up even if a
-        // particular view/runtime teardown path is bypassed during shutdown.
-        std::mem::forget(cx.on_app_quit(|_| async move {
-            hunk_codex::host::begin_host_shutdown();
-            hunk_codex::host::cleanup_tracked_hosts_for_shutdown();
-        }));
-        cx.on_action(quit_app);
-        bind_keyboard_shortcuts(cx, &keyboard_shortcuts);
-        install_application_menus(cx);
-        cx.activate(true);
-        open_main_window(cx);
-    });
-
-    Ok(())
-}
-
-fn open_main_window(cx: &mut App) {
-    let window_options = WindowOptions {
-        app_id: Some("hunk_desktop".into()),
-        titlebar: Some(TitlebarOptions {
-            title: Some("Hunk".into()),
-            ..Default::default()
-        }),
-        ..Default::default()
-    };
-
-    if let Err(err) = cx.open_window(window_options, |window, cx| {
-        let view = cx.new(|cx| DiffViewer::new(window, cx));
-        view.update(cx, |this, cx| this.defer_root_focus(cx));
-        cx.new(|cx| Root::new(view, window, cx))
-    }) {
-        error!("failed to open window: {err:#}");
-    }
-}
-
-fn quit_app(_: &QuitApp, cx: &mut App) {
-    cx.quit();
-}
-
 include!("app/settings.rs");
 
 struct RepoTreeCacheState {
@@ -968,9 +318,13 @@ struct RepoTreeState {
     task: Task<()>,
     loading: bool,
     reload_pending: bool,
+    /// A snapshot changed the file list while the tree was hidden; reload once it is shown.
+    reload_deferred: bool,
     error: Option<String>,
     changed_only: bool,
+    changed_sort: ChangedFileSort,
     last_reload: Instant,
+    sparse_checkout: Option<SparseCheckout>,
 }
 
 impl RepoTreeState {
@@ -993,9 +347,12 @@ impl RepoTreeState {
             task: Task::ready(()),
             loading: false,
             reload_pending: false,
+            reload_deferred: false,
             error: None,
             changed_only: false,
+            changed_sort: ChangedFileSort::default(),
             last_reload: Instant::now(),
+            sparse_checkout: None,
         }
     }
 }
@@ -1013,6 +370,9 @@ struct WorkspaceProjectState {
     review_loaded_left_source_id: Option<String>,
     review_loaded_right_source_id: Option<String>,
     review_loaded_collapsed_files: BTreeSet<String>,
+    review_loaded_changed_lines_only: ChangedLinesOnlyMode,
+    review_loaded_hunk_folds: HunkFolds,
+    review_loaded_compare_snapshot: Option<Arc<CompareSnapshot>>,
     branch_name: String,
     branch_has_upstream: bool,
     branch_ahead_count: usize,
@@ -1027,6 +387,7 @@ struct WorkspaceProjectState {
     recent_commits: Vec<RecentCommitSummary>,
     recent_commits_error: Option<String>,
     collapsed_files: BTreeSet<String>,
+    stats_ignored_paths: BTreeSet<String>,
     selected_path: Option<String>,
     selected_status: Option<FileStatus>,
     file_line_stats: BTreeMap<String, LineStats>,
@@ -1034,6 +395,12 @@ struct WorkspaceProjectState {
     review_files: Vec<ChangedFile>,
     review_file_status_by_path: BTreeMap<String, FileStatus>,
     review_file_line_stats: BTreeMap<String, LineStats>,
+    review_file_encodings: BTreeMap<String, TextEncoding>,
+    review_extracted_text_paths: BTreeSet<String>,
+    review_mechanical_changes: ReviewMechanicalChanges,
+    review_hunk_stage_states: BTreeMap<String, BTreeMap<String, HunkStageState>>,
+    review_change_set_size: ReviewChangeSetSize,
+    review_reviewed_directories: ReviewedDirectories,
     review_overall_line_stats: LineStats,
     review_compare_loading: bool,
     review_compare_error: Option<String>,
@@ -1075,6 +442,9 @@ struct ReviewWorkspaceSurfaceState {
     diff_split_bounds: Option<Bounds<Pixels>>,
     diff_left_line_number_width: f32,
     diff_right_line_number_width: f32,
+    /// Measured digit advance of the code font at its configured size, before zoom.
+    diff_mono_char_width: f32,
+    diff_text_zoom: f32,
     last_surface_snapshot: Option<review_workspace_session::ReviewWorkspaceSurfaceSnapshot>,
     last_prefetched_visible_row_range: Option<std::ops::Range<usize>>,
     last_diff_scroll_offset: Option<gpui::Point<gpui::Pixels>>,
@@ -1293,10 +663,14 @@ impl ReviewWorkspaceSurfaceState {
             diff_split_bounds: None,
             diff_left_line_number_width: crate::app::data::line_number_column_width(
                 DIFF_LINE_NUMBER_MIN_DIGITS,
+                DIFF_MONO_CHAR_WIDTH,
             ),
             diff_right_line_number_width: crate::app::data::line_number_column_width(
                 DIFF_LINE_NUMBER_MIN_DIGITS,
+                DIFF_MONO_CHAR_WIDTH,
             ),
+            diff_mono_char_width: DIFF_MONO_CHAR_WIDTH,
+            diff_text_zoom: 1.0,
             last_surface_snapshot: None,
             last_prefetched_visible_row_range: None,
             last_diff_scroll_offset: None,
@@ -1332,21 +706,84 @@ impl ReviewWorkspaceSurfaceState {
 struct DiffViewer {
     config_store: Option<ConfigStore>,
     config: AppConfig,
+    ticket_linker: Option<TicketLinker>,
     settings_draft: Option<SettingsDraft>,
+    diagnostics_loading: bool,
+    git_recovery_prompt: Option<GitRecoveryPromptState>,
+    repo_clone_prompt: Option<RepoClonePromptState>,
+    repo_clone_task: Task<()>,
+    hunk_apply_prompt: Option<HunkApplyPromptState>,
+    hunk_apply_task: Task<()>,
+    commit_message_editor: Option<CommitMessageEditorState>,
+    commit_message_editor_task: Task<()>,
+    repo_issues_panel: Option<RepoIssuesPanelState>,
+    repo_issues_task: Task<()>,
+    ci_log_panel: Option<CiLogPanelState>,
+    repo_content_search_panel: Option<RepoContentSearchPanelState>,
+    operation_diff_panel: Option<OperationDiffPanelState>,
+    operation_diff_task: Task<()>,
+    operation_history_panel: Option<OperationHistoryPanelState>,
+    operation_history_task: Task<()>,
+    stack_editor_panel: Option<StackEditorPanelState>,
+    stack_editor_task: Task<()>,
+    previous_crash_report: Option<CrashReport>,
+    crash_report_viewer_open: bool,
+    usage_metrics_task: Task<()>,
+    maintenance_task: Task<()>,
+    git_config_save_task: Task<()>,
+    comment_prune_task: Task<()>,
+    sparse_checkout_task: Task<()>,
+    code_owners: Option<CodeOwners>,
+    code_owners_task: Task<()>,
+    head_branch_names: Vec<String>,
+    head_branches_task: Task<()>,
+    comment_rename_task: Task<()>,
+    launch_read_only: bool,
+    review_export_task: Task<()>,
     state_store: Option<AppStateStore>,
     state: AppState,
     database_store: Option<DatabaseStore>,
+    /// Background thread for comment loads, writes and pruning, so they stay off the UI thread.
+    database_actor: Option<DatabaseActor>,
+    snapshot_cache_store: Option<SnapshotCacheStore>,
+    snapshot_cache_task: Task<()>,
+    /// State id of the review snapshot last read from or written to the on-disk cache.
+    review_snapshot_cache_state_id: Option<String>,
+    editor_recovery_store: Option<EditorRecoveryStore>,
+    review_mechanical_changes_task: Task<()>,
+    review_hunk_stage_task: Task<()>,
     window_handle: AnyWindowHandle,
+    detached_diff_window: Option<WindowHandle<Root>>,
     comments_cache: Vec<CommentRecord>,
+    /// Comment totals of the current scope for the toolbar and the file tree.
+    comment_counts: CommentScopeCounts,
     comments_preview_open: bool,
     comments_show_non_open: bool,
+    comments_author_filter: Option<String>,
+    comment_import_task: Task<()>,
+    comment_export_task: Task<()>,
+    github_review_task: Task<()>,
+    comment_import_unplaced: Vec<ImportedComment>,
     comment_miss_streaks: BTreeMap<String, u8>,
     comment_row_matches: BTreeMap<String, usize>,
     comment_open_row_counts: Vec<usize>,
     hovered_comment_row: Option<usize>,
     active_comment_editor_row: Option<usize>,
+    /// A comment save is waiting on the database thread; further saves are ignored until it lands.
+    comment_save_in_flight: bool,
     comment_input_state: Entity<InputState>,
     comment_status_message: Option<String>,
+    comments_watch_revision: Option<CommentScopeRevision>,
+    comments_watch_task: Task<()>,
+    comments_load_task: Task<()>,
+    co_review: Option<CoReviewState>,
+    co_review_panel_open: bool,
+    co_review_joining: bool,
+    co_review_join_input_state: Entity<InputState>,
+    co_review_task: Task<()>,
+    remote_branch_watch_head: Option<RemoteBranchWatchHead>,
+    remote_branch_watch_task: Task<()>,
+    ci_watch_task: Task<()>,
     project_path: Option<PathBuf>,
     repo_root: Option<PathBuf>,
     workspace_targets: Vec<WorkspaceTargetSummary>,
@@ -1360,6 +797,15 @@ struct DiffViewer {
     review_loaded_left_source_id: Option<String>,
     review_loaded_right_source_id: Option<String>,
     review_loaded_collapsed_files: BTreeSet<String>,
+    review_loaded_changed_lines_only: ChangedLinesOnlyMode,
+    review_loaded_hunk_folds: HunkFolds,
+    review_loaded_compare_snapshot: Option<Arc<CompareSnapshot>>,
+    /// Keeps the file tree selection and the diff scroll position in step. Turned off while
+    /// comparing files manually.
+    review_tree_follow: bool,
+    /// File picked in the tree. It stays selected while any of its rows are on screen, even when
+    /// another file owns the top row.
+    review_tree_clicked_path: Option<String>,
     branch_name: String,
     branch_has_upstream: bool,
     branch_ahead_count: usize,
@@ -1525,11 +971,16 @@ struct DiffViewer {
     branch_picker_state: Entity<HunkPickerState<BranchPickerDelegate>>,
     branch_input_state: Entity<InputState>,
     branch_input_has_text: bool,
+    branch_name_policy_message: Option<String>,
     commit_input_state: Entity<InputState>,
+    /// Case collisions the user chose to commit anyway, keyed by `PathCollision::key`.
+    acknowledged_path_collisions: BTreeSet<String>,
     git_action_epoch: usize,
     git_action_task: Task<()>,
     git_action_loading: bool,
     git_action_label: Option<String>,
+    git_action_cancel: Option<CancellationToken>,
+    git_action_cancelling: bool,
     workspace_target_switch_loading: bool,
     git_status_message: Option<String>,
     git_workspace_refresh_epoch: usize,
@@ -1541,7 +992,32 @@ struct DiffViewer {
     last_commit_subject: Option<String>,
     recent_commits: Vec<RecentCommitSummary>,
     recent_commits_error: Option<String>,
+    abandoned_commits_visible: bool,
+    abandoned_commits: Vec<AbandonedCommit>,
+    abandoned_commits_loading: bool,
+    abandoned_commits_error: Option<String>,
+    abandoned_commit_restoring: Option<String>,
+    abandoned_commits_task: Task<()>,
+    recent_commit_files_commit_id: Option<String>,
+    recent_commit_files: Vec<CommitChangedFile>,
+    recent_commit_files_loading: bool,
+    recent_commit_files_error: Option<String>,
+    recent_commit_files_task: Task<()>,
+    commit_details_panel: Option<CommitDetailsPanelState>,
+    commit_details_task: Task<()>,
+    file_authorship: BTreeMap<String, FileAuthorship>,
+    file_authorship_head_commit_id: Option<String>,
+    file_authorship_loading_path: Option<String>,
+    file_authorship_task: Task<()>,
+    file_history_open: bool,
     collapsed_files: BTreeSet<String>,
+    stats_ignored_paths: BTreeSet<String>,
+    changed_lines_only: ChangedLinesOnlyMode,
+    /// Files laid out right to left in the diff, for documents written mostly in RTL scripts.
+    right_to_left_files: BTreeSet<String>,
+    /// Shows the diff as one interleaved column, like `git diff`, instead of side by side.
+    diff_unified_view: bool,
+    hunk_folds: HunkFolds,
     selected_path: Option<String>,
     selected_status: Option<FileStatus>,
     file_line_stats: BTreeMap<String, LineStats>,
@@ -1549,6 +1025,13 @@ struct DiffViewer {
     review_files: Vec<ChangedFile>,
     review_file_status_by_path: BTreeMap<String, FileStatus>,
     review_file_line_stats: BTreeMap<String, LineStats>,
+    review_file_encodings: BTreeMap<String, TextEncoding>,
+    review_extracted_text_paths: BTreeSet<String>,
+    review_mechanical_changes: ReviewMechanicalChanges,
+    /// Whether each working-copy hunk is staged, by file path and hunk header.
+    review_hunk_stage_states: BTreeMap<String, BTreeMap<String, HunkStageState>>,
+    review_change_set_size: ReviewChangeSetSize,
+    review_reviewed_directories: ReviewedDirectories,
     review_overall_line_stats: LineStats,
     review_compare_loading: bool,
     review_compare_error: Option<String>,
@@ -1559,9 +1042,11 @@ struct DiffViewer {
     auto_refresh_unmodified_streak: u32,
     auto_refresh_task: Task<()>,
     repo_watch_task: Task<()>,
+    repo_watch_error: Option<String>,
     repo_watch_refresh_epoch: usize,
     repo_watch_pending_refresh: Option<SnapshotRefreshRequest>,
     repo_watch_pending_git_workspace_refresh: bool,
+    repo_watch_pending_metadata_change: bool,
     repo_watch_pending_recent_commits_refresh: bool,
     repo_watch_refresh_task: Task<()>,
     snapshot_epoch: usize,
@@ -1584,6 +1069,8 @@ struct DiffViewer {
     last_snapshot_fingerprint: Option<RepoSnapshotFingerprint>,
     open_project_task: Task<()>,
     patch_epoch: usize,
+    /// Stops the background patch load of the current `patch_epoch` once a newer one starts.
+    patch_cancel: CancellationToken,
     patch_task: Task<()>,
     patch_loading: bool,
     in_app_menu_bar: Option<Entity<AppMenuBar>>,
@@ -1591,6 +1078,7 @@ struct DiffViewer {
     repo_tree_focus_handle: FocusHandle,
     files_editor_focus_handle: FocusHandle,
     drag_selecting_rows: bool,
+    vim_mode: VimMode,
     scroll_selected_after_reload: bool,
     last_scroll_activity_at: Instant,
     segment_prefetch_epoch: usize,
@@ -1603,8 +1091,18 @@ struct DiffViewer {
     fps_task: Task<()>,
     ai_perf_metrics: RefCell<AiPerfMetrics>,
     repo_discovery_failed: bool,
+    repo_init_path: Option<PathBuf>,
+    repo_init_template: RepoInitTemplate,
+    repo_init_loading: bool,
     error_message: Option<String>,
-    sidebar_collapsed: bool,
+    tree_restore_dock: PanelDock,
+    /// Maximizes the active view's main pane, hiding the toolbar, footer, and docked panels.
+    focus_mode: bool,
+    /// Active time in Review view, shown in the footer when the review timer is enabled.
+    review_timer: ReviewTimer,
+    review_timer_task: Task<()>,
+    /// Whether the main window has focus, as last reported by its activation observer.
+    window_active: bool,
     repo_tree: RepoTreeState,
     repo_tree_inline_edit: Option<RepoTreeInlineEditState>,
     repo_tree_context_menu: Option<RepoTreeContextMenuState>,
@@ -1620,8 +1118,10 @@ struct DiffViewer {
     file_quick_open_visible: bool,
     file_quick_open_matches: Vec<String>,
     file_quick_open_selected_ix: usize,
+    last_offered_clipboard_text: Option<String>,
     editor_path: Option<String>,
     editor_loading: bool,
+    pending_editor_caret_line: Option<(String, usize)>,
     editor_error: Option<String>,
     editor_dirty: bool,
     editor_last_saved_text: Option<String>,
@@ -1635,12 +1135,21 @@ struct DiffViewer {
     editor_markdown_preview_loading: bool,
     editor_markdown_preview_revision: usize,
     editor_markdown_preview: bool,
+    editor_line_changes_task: Task<()>,
+    editor_line_changes_scheduled: Option<(hunk_text::BufferId, u64)>,
+    editor_recovery_task: Task<()>,
+    editor_recovery_scheduled: bool,
+    /// Repository the open editor buffers were loaded from, kept for flushing their unsaved
+    /// edits after the workspace has already moved on.
+    editor_recovery_root: Option<PathBuf>,
+    editor_recovery_prompt: Option<EditorRecoveryPrompt>,
     editor_search_visible: bool,
 }
 
 impl Drop for DiffViewer {
     fn drop(&mut self) {
         self.sync_active_file_editor_tab_state();
+        self.write_editor_recovery_blocking();
         for tab in &self.file_editor_tabs {
             tab.files_editor.borrow_mut().shutdown();
         }
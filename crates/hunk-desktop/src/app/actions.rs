@@ -0,0 +1,680 @@
+actions!(
+    diff_viewer,
+    [
+        SelectNextLine,
+        SelectPreviousLine,
+        ExtendSelectionNextLine,
+        ExtendSelectionPreviousLine,
+        CopySelection,
+        SelectAllDiffRows,
+        NextHunk,
+        PreviousHunk,
+        NextFile,
+        PreviousFile,
+        ViewCurrentReviewFile,
+        ToggleReviewPin,
+        NextReviewPin,
+        PreviousReviewPin,
+        ToggleSidebarTree,
+        ToggleFocusMode,
+        SwitchToFilesView,
+        SwitchToReviewView,
+        SwitchToGitView,
+        SwitchToAiView,
+        AiToggleTerminalDrawer,
+        AiTerminalSendCtrlC,
+        AiTerminalSendCtrlA,
+        AiTerminalSendTab,
+        AiTerminalSendBackTab,
+        AiTerminalSendUp,
+        AiTerminalSendDown,
+        AiTerminalSendLeft,
+        AiTerminalSendRight,
+        AiTerminalSendHome,
+        AiTerminalSendEnd,
+        AiNewThread,
+        AiNewWorktreeThread,
+        AiQueuePrompt,
+        AiEditLastQueuedPrompt,
+        AiInterruptSelectedTurn,
+        OpenProject,
+        OpenPathFromClipboard,
+        OpenCiLogPanel,
+        SearchRepository,
+        CloneRepository,
+        QuickOpenFile,
+        FilesEditorCopy,
+        FilesEditorCut,
+        FilesEditorPaste,
+        FilesEditorToggleLineComment,
+        FilesEditorFormat,
+        FilesEditorMoveUp,
+        FilesEditorMoveDown,
+        FilesEditorMoveLeft,
+        FilesEditorMoveRight,
+        FilesEditorMoveToBeginningOfLine,
+        FilesEditorMoveToEndOfLine,
+        FilesEditorMoveToBeginningOfDocument,
+        FilesEditorMoveToEndOfDocument,
+        FilesEditorMoveToPreviousWordStart,
+        FilesEditorMoveToNextWordEnd,
+        FilesEditorSelectUp,
+        FilesEditorSelectDown,
+        FilesEditorSelectLeft,
+        FilesEditorSelectRight,
+        FilesEditorSelectToBeginningOfLine,
+        FilesEditorSelectToEndOfLine,
+        FilesEditorSelectToBeginningOfDocument,
+        FilesEditorSelectToEndOfDocument,
+        FilesEditorSelectToPreviousWordStart,
+        FilesEditorSelectToNextWordEnd,
+        FilesEditorPageUp,
+        FilesEditorPageDown,
+        NextEditorTab,
+        PreviousEditorTab,
+        CloseEditorTab,
+        SaveCurrentFile,
+        AboutHunk,
+        OpenSettings,
+        OpenDiagnostics,
+        OpenRepositoryIssues,
+        OpenOperationDiff,
+        OpenOperationHistory,
+        OpenStackEditor,
+        ResetWorkspaceLayout,
+        OpenDetachedDiffWindow,
+        ExportReviewPdf,
+        ExportReviewPdfGrayscale,
+        QuitApp,
+        RepoTreeNewFile,
+        RepoTreeNewFolder,
+        RepoTreeRenameFile,
+        RepoTreeCancelInlineEdit,
+    ]
+);
+
+fn load_keyboard_shortcuts() -> KeyboardShortcuts {
+    let store = match ConfigStore::new() {
+        Ok(store) => store,
+        Err(err) => {
+            error!("failed to initialize config path for keyboard shortcuts: {err:#}");
+            return KeyboardShortcuts::default();
+        }
+    };
+
+    match store.load_or_create_default() {
+        Ok(config) => config.keyboard_shortcuts,
+        Err(err) => {
+            error!(
+                "failed to load keyboard shortcuts from {}: {err:#}",
+                store.path().display()
+            );
+            KeyboardShortcuts::default()
+        }
+    }
+}
+
+fn bind_keyboard_shortcuts(cx: &mut App, shortcuts: &KeyboardShortcuts) {
+    let mut bindings = Vec::new();
+
+    bindings.extend(shortcuts.select_next_line.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            SelectNextLine,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.select_previous_line.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            SelectPreviousLine,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.extend_selection_next_line.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            ExtendSelectionNextLine,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(
+        shortcuts
+            .extend_selection_previous_line
+            .iter()
+            .map(|shortcut| {
+                KeyBinding::new(
+                    shortcut.as_str(),
+                    ExtendSelectionPreviousLine,
+                    Some(WorkspaceViewMode::Diff.shortcut_context()),
+                )
+            }),
+    );
+    bindings.extend(shortcuts.copy_selection.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            CopySelection,
+            Some(SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE),
+        )
+    }));
+    bindings.extend(shortcuts.select_all_diff_rows.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            SelectAllDiffRows,
+            Some(SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE),
+        )
+    }));
+    bindings.extend(shortcuts.next_hunk.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            NextHunk,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.previous_hunk.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            PreviousHunk,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.next_file.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            NextFile,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.previous_file.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            PreviousFile,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.view_current_review_file.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            ViewCurrentReviewFile,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.toggle_review_pin.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            ToggleReviewPin,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.next_review_pin.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            NextReviewPin,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.previous_review_pin.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            PreviousReviewPin,
+            Some(WorkspaceViewMode::Diff.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.toggle_sidebar_tree.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            ToggleSidebarTree,
+            Some(SHORTCUT_CONTEXT_TREE_WORKSPACE),
+        )
+    }));
+    bindings.extend(
+        shortcuts
+            .toggle_focus_mode
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), ToggleFocusMode, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .switch_to_files_view
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToFilesView, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .switch_to_review_view
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToReviewView, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .switch_to_git_view
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToGitView, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .switch_to_ai_view
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SwitchToAiView, None)),
+    );
+    bindings.extend(shortcuts.toggle_ai_terminal_drawer.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            AiToggleTerminalDrawer,
+            Some(WorkspaceViewMode::Ai.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.toggle_ai_terminal_drawer.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            AiToggleTerminalDrawer,
+            Some(WorkspaceViewMode::Files.shortcut_context()),
+        )
+    }));
+    bindings.push(KeyBinding::new(
+        "ctrl-c",
+        AiTerminalSendCtrlC,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-a",
+        AiTerminalSendCtrlA,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "tab",
+        AiTerminalSendTab,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-tab",
+        AiTerminalSendBackTab,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new("up", AiTerminalSendUp, Some("AiTerminal")));
+    bindings.push(KeyBinding::new(
+        "down",
+        AiTerminalSendDown,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "left",
+        AiTerminalSendLeft,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "right",
+        AiTerminalSendRight,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "home",
+        AiTerminalSendHome,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "end",
+        AiTerminalSendEnd,
+        Some("AiTerminal"),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-n",
+        AiNewThread,
+        Some(WorkspaceViewMode::Ai.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-n",
+        AiNewThread,
+        Some(WorkspaceViewMode::Ai.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-shift-n",
+        AiNewWorktreeThread,
+        Some(WorkspaceViewMode::Ai.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-shift-n",
+        AiNewWorktreeThread,
+        Some(WorkspaceViewMode::Ai.shortcut_context()),
+    ));
+    bindings.extend(
+        shortcuts
+            .open_project
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), OpenProject, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .open_path_from_clipboard
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), OpenPathFromClipboard, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .search_repository
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), SearchRepository, None)),
+    );
+    bindings.push(KeyBinding::new(
+        "cmd-p",
+        QuickOpenFile,
+        Some(WorkspaceViewMode::Files.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-p",
+        QuickOpenFile,
+        Some(WorkspaceViewMode::Files.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-c",
+        FilesEditorCopy,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-c",
+        FilesEditorCopy,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-x",
+        FilesEditorCut,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-x",
+        FilesEditorCut,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-v",
+        FilesEditorPaste,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-v",
+        FilesEditorPaste,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "cmd-/",
+        FilesEditorToggleLineComment,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "ctrl-/",
+        FilesEditorToggleLineComment,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "alt-shift-f",
+        FilesEditorFormat,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "up",
+        FilesEditorMoveUp,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "down",
+        FilesEditorMoveDown,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "left",
+        FilesEditorMoveLeft,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "right",
+        FilesEditorMoveRight,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-up",
+        FilesEditorSelectUp,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-down",
+        FilesEditorSelectDown,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-left",
+        FilesEditorSelectLeft,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-right",
+        FilesEditorSelectRight,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "home",
+        FilesEditorMoveToBeginningOfLine,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "end",
+        FilesEditorMoveToEndOfLine,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-home",
+        FilesEditorSelectToBeginningOfLine,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-end",
+        FilesEditorSelectToEndOfLine,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "pageup",
+        FilesEditorPageUp,
+        Some("FilesEditor"),
+    ));
+    bindings.push(KeyBinding::new(
+        "pagedown",
+        FilesEditorPageDown,
+        Some("FilesEditor"),
+    ));
+    if cfg!(target_os = "macos") {
+        bindings.push(KeyBinding::new(
+            "cmd-left",
+            FilesEditorMoveToBeginningOfLine,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-right",
+            FilesEditorMoveToEndOfLine,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-up",
+            FilesEditorMoveToBeginningOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-down",
+            FilesEditorMoveToEndOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-shift-left",
+            FilesEditorSelectToBeginningOfLine,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-shift-right",
+            FilesEditorSelectToEndOfLine,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-shift-up",
+            FilesEditorSelectToBeginningOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-shift-down",
+            FilesEditorSelectToEndOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-home",
+            FilesEditorMoveToBeginningOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "cmd-end",
+            FilesEditorMoveToEndOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "alt-left",
+            FilesEditorMoveToPreviousWordStart,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "alt-right",
+            FilesEditorMoveToNextWordEnd,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "alt-shift-left",
+            FilesEditorSelectToPreviousWordStart,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "alt-shift-right",
+            FilesEditorSelectToNextWordEnd,
+            Some("FilesEditor"),
+        ));
+    } else {
+        bindings.push(KeyBinding::new(
+            "ctrl-left",
+            FilesEditorMoveToPreviousWordStart,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-right",
+            FilesEditorMoveToNextWordEnd,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-shift-left",
+            FilesEditorSelectToPreviousWordStart,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-shift-right",
+            FilesEditorSelectToNextWordEnd,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-home",
+            FilesEditorMoveToBeginningOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-end",
+            FilesEditorMoveToEndOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-shift-home",
+            FilesEditorSelectToBeginningOfDocument,
+            Some("FilesEditor"),
+        ));
+        bindings.push(KeyBinding::new(
+            "ctrl-shift-end",
+            FilesEditorSelectToEndOfDocument,
+            Some("FilesEditor"),
+        ));
+    }
+    bindings.extend(shortcuts.save_current_file.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            SaveCurrentFile,
+            Some(WorkspaceViewMode::Files.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.next_editor_tab.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            NextEditorTab,
+            Some(WorkspaceViewMode::Files.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.previous_editor_tab.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            PreviousEditorTab,
+            Some(WorkspaceViewMode::Files.shortcut_context()),
+        )
+    }));
+    bindings.extend(shortcuts.close_editor_tab.iter().map(|shortcut| {
+        KeyBinding::new(
+            shortcut.as_str(),
+            CloseEditorTab,
+            Some(WorkspaceViewMode::Files.shortcut_context()),
+        )
+    }));
+    bindings.extend(
+        shortcuts
+            .open_settings
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), OpenSettings, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .quit_app
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), QuitApp, None)),
+    );
+    bindings.extend(
+        shortcuts
+            .repo_tree_new_file
+            .iter()
+            .map(|shortcut| KeyBinding::new(shortcut.as_str(), RepoTreeNewFile, Some("RepoTree"))),
+    );
+    bindings.extend(
+        shortcuts.repo_tree_new_folder.iter().map(|shortcut| {
+            KeyBinding::new(shortcut.as_str(), RepoTreeNewFolder, Some("RepoTree"))
+        }),
+    );
+    bindings.extend(
+        shortcuts.repo_tree_rename_file.iter().map(|shortcut| {
+            KeyBinding::new(shortcut.as_str(), RepoTreeRenameFile, Some("RepoTree"))
+        }),
+    );
+    bindings.push(KeyBinding::new(
+        "escape",
+        RepoTreeCancelInlineEdit,
+        Some("RepoTree"),
+    ));
+    bindings.push(KeyBinding::new(
+        "escape",
+        RepoTreeCancelInlineEdit,
+        Some("RepoTreeInlineEdit"),
+    ));
+    bindings.push(KeyBinding::new(
+        "escape",
+        AiInterruptSelectedTurn,
+        Some(WorkspaceViewMode::Ai.shortcut_context()),
+    ));
+    bindings.push(KeyBinding::new("tab", AiQueuePrompt, Some("AiComposer")));
+    bindings.push(KeyBinding::new(
+        "ctrl-shift-up",
+        AiEditLastQueuedPrompt,
+        Some("AiComposer"),
+    ));
+    bindings.push(KeyBinding::new(
+        "shift-enter",
+        InputEnter { secondary: true },
+        Some("Input"),
+    ));
+
+    cx.bind_keys(bindings);
+}
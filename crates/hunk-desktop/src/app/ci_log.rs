@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use super::markdown_links::{MarkdownWorkspaceFileLink, resolve_clipboard_path_target};
+
+const CI_LOG_MAX_LINES: usize = 20_000;
+const CI_LOG_MAX_REFERENCES: usize = 200;
+
+/// A failing test or repository location mentioned by a pasted CI log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CiLogReference {
+    pub test_name: Option<String>,
+    pub location: Option<MarkdownWorkspaceFileLink>,
+}
+
+/// Collects failing tests and `path:line` locations of files in the workspace, in log order and
+/// without repeats. Lines naming neither are skipped.
+pub(crate) fn ci_log_references(text: &str, workspace_root: &Path) -> Vec<CiLogReference> {
+    let mut references = Vec::new();
+    for line in text.lines().take(CI_LOG_MAX_LINES) {
+        let line = strip_ansi_escapes(line);
+        let test_name = failing_test_name(line.as_str());
+        let location = resolve_clipboard_path_target(line.as_str(), workspace_root);
+        if test_name.is_none() && location.is_none() {
+            continue;
+        }
+
+        let reference = CiLogReference {
+            test_name,
+            location,
+        };
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+        if references.len() >= CI_LOG_MAX_REFERENCES {
+            break;
+        }
+    }
+    references
+}
+
+/// The failing test a line reports, for cargo, go, pytest and jest output.
+fn failing_test_name(line: &str) -> Option<String> {
+    let line = line.trim();
+    // cargo: `test parser::tests::rejects_empty ... FAILED`
+    if let Some(name) = line
+        .strip_prefix("test ")
+        .and_then(|rest| rest.strip_suffix(" ... FAILED"))
+    {
+        return Some(name.trim().to_string());
+    }
+    // cargo: `thread 'parser::tests::rejects_empty' panicked at src/parser.rs:12:5:`
+    if let Some(rest) = line.strip_prefix("thread '")
+        && let Some((name, _)) = rest.split_once("' panicked")
+        && name != "main"
+    {
+        return Some(name.to_string());
+    }
+    // go: `--- FAIL: TestParse (0.00s)`
+    if let Some(rest) = line.strip_prefix("--- FAIL: ") {
+        return rest.split_whitespace().next().map(str::to_string);
+    }
+    // pytest: `FAILED tests/test_parser.py::test_empty - AssertionError`
+    if let Some(rest) = line.strip_prefix("FAILED ") {
+        let node_id = rest.split(" - ").next().unwrap_or(rest).trim();
+        return node_id.split_once("::").map(|(_, name)| name.to_string());
+    }
+    // jest: `● Parser › rejects empty input`
+    if let Some(rest) = line.strip_prefix("● ") {
+        return Some(rest.trim().to_string()).filter(|name| !name.is_empty());
+    }
+    None
+}
+
+fn strip_ansi_escapes(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            stripped.push(ch);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            for ch in chars.by_ref() {
+                if ch.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ci_log_references, failing_test_name};
+
+    #[test]
+    fn failing_test_name_recognizes_common_runners() {
+        assert_eq!(
+            failing_test_name("test parser::tests::rejects_empty ... FAILED").as_deref(),
+            Some("parser::tests::rejects_empty")
+        );
+        assert_eq!(
+            failing_test_name("--- FAIL: TestParse (0.00s)").as_deref(),
+            Some("TestParse")
+        );
+        assert_eq!(
+            failing_test_name("FAILED tests/test_app.py::test_login - AssertionError").as_deref(),
+            Some("test_login")
+        );
+        assert_eq!(
+            failing_test_name("  ● Parser › rejects empty input").as_deref(),
+            Some("Parser › rejects empty input")
+        );
+        assert_eq!(failing_test_name("test parser::tests::ok ... ok"), None);
+    }
+
+    #[test]
+    fn ci_log_references_pair_tests_with_locations_and_skip_repeats() {
+        let workspace = tempfile::tempdir().expect("tempdir");
+        let file_path = workspace.path().join("src/parser.rs");
+        std::fs::create_dir_all(file_path.parent().expect("parent")).expect("create dir");
+        std::fs::write(&file_path, "\n").expect("write file");
+
+        let log = "\
+running 2 tests
+test parser::tests::ok ... ok
+test parser::tests::rejects_empty ... FAILED
+\u{1b}[31mthread 'parser::tests::rejects_empty' panicked at src/parser.rs:12:5:\u{1b}[0m
+thread 'parser::tests::rejects_empty' panicked at src/parser.rs:12:5:
+  --> src/missing.rs:4:1
+";
+        let references = ci_log_references(log, workspace.path());
+        assert_eq!(references.len(), 2);
+        assert_eq!(
+            references[0].test_name.as_deref(),
+            Some("parser::tests::rejects_empty")
+        );
+        assert_eq!(references[0].location, None);
+        let location = references[1].location.as_ref().expect("location");
+        assert_eq!(location.normalized_path, "src/parser.rs");
+        assert_eq!(location.line, Some(12));
+        assert_eq!(
+            references[1].test_name.as_deref(),
+            Some("parser::tests::rejects_empty")
+        );
+    }
+}
@@ -0,0 +1,101 @@
+impl DiffViewer {
+    /// Switches the Recent Commits panel between branch history and recently abandoned commits.
+    pub(super) fn toggle_abandoned_commits(&mut self, cx: &mut Context<Self>) {
+        if self.abandoned_commits_visible {
+            self.close_abandoned_commits();
+            cx.notify();
+            return;
+        }
+        self.abandoned_commits_visible = true;
+        self.load_abandoned_commits_list(cx);
+    }
+
+    fn close_abandoned_commits(&mut self) {
+        self.abandoned_commits_visible = false;
+        self.abandoned_commits.clear();
+        self.abandoned_commits_loading = false;
+        self.abandoned_commits_error = None;
+        self.abandoned_commit_restoring = None;
+        self.abandoned_commits_task = Task::ready(());
+    }
+
+    fn load_abandoned_commits_list(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.abandoned_commits_error = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+        self.abandoned_commits_loading = true;
+        self.abandoned_commits_error = None;
+        cx.notify();
+
+        self.abandoned_commits_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    load_abandoned_commits(repo_root.as_path(), DEFAULT_ABANDONED_COMMIT_LIMIT)
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    this.abandoned_commits_loading = false;
+                    match result {
+                        Ok(commits) => this.abandoned_commits = commits,
+                        Err(err) => {
+                            error!("loading abandoned commits failed: {err:#}");
+                            this.abandoned_commits.clear();
+                            this.abandoned_commits_error = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Brings an abandoned commit back by creating a `restored/` branch at it.
+    pub(super) fn restore_abandoned_commit_by_id(
+        &mut self,
+        commit_id: String,
+        cx: &mut Context<Self>,
+    ) {
+        if self.abandoned_commit_restoring.is_some()
+            || self.block_in_read_only("Restore commit", cx)
+        {
+            return;
+        }
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        self.abandoned_commit_restoring = Some(commit_id.clone());
+        cx.notify();
+
+        self.abandoned_commits_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { restore_abandoned_commit(repo_root.as_path(), &commit_id) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    this.abandoned_commit_restoring = None;
+                    match result {
+                        Ok(branch_name) => {
+                            let message = format!("Restored as branch {branch_name}");
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_git_workspace_refresh(false, cx);
+                            this.load_abandoned_commits_list(cx);
+                        }
+                        Err(err) => {
+                            error!("restoring abandoned commit failed: {err:#}");
+                            Self::push_error_notification(format!("Restore failed: {err}"), cx);
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -285,6 +285,9 @@ fn workspace_mad_max_mode_reads_per_workspace_flags() {
         ai_thread_session_overrides: Default::default(),
         git_workflow_cache_by_repo: Default::default(),
         git_recent_commits_cache_by_repo: Default::default(),
+        workspace_layout: Default::default(),
+        maintenance: Default::default(),
+        read_only_project_paths: Default::default(),
     };
     assert!(!workspace_mad_max_mode(&state, Some("/repo-a")));
     assert!(!workspace_mad_max_mode(&state, Some("/repo-b")));
@@ -331,6 +334,9 @@ fn workspace_include_hidden_models_reads_per_workspace_flags() {
         ai_thread_session_overrides: Default::default(),
         git_workflow_cache_by_repo: Default::default(),
         git_recent_commits_cache_by_repo: Default::default(),
+        workspace_layout: Default::default(),
+        maintenance: Default::default(),
+        read_only_project_paths: Default::default(),
     };
     assert!(workspace_include_hidden_models(&state, Some("/repo-a")));
     assert!(!workspace_include_hidden_models(&state, Some("/repo-b")));
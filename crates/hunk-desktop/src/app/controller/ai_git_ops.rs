@@ -78,6 +78,9 @@ impl DiffViewer {
     }
 
     pub(super) fn ai_publish_blocker(&self) -> Option<String> {
+        if self.read_only_active() {
+            return Some(READ_ONLY_REASON.to_string());
+        }
         ai_publish_blocker_reason(self.ai_current_thread_git_action_context("publishing"))
     }
 
@@ -152,6 +155,9 @@ impl DiffViewer {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.block_in_read_only("Delete worktree", cx) {
+            return;
+        }
         let context =
             match self.ai_current_managed_worktree_delete_context("deleting its worktree") {
                 Ok(context) => context,
@@ -517,6 +523,7 @@ impl DiffViewer {
         let branch_name = context.branch_name.clone();
         let repo_root = context.repo_root.clone();
         let epoch = self.begin_git_action("Commit and Push", cx);
+        let cancel = self.install_git_action_cancel(self.config.git_timeouts.push_secs);
         self.begin_ai_git_progress(
             epoch,
             AiGitProgressAction::CommitAndPush,
@@ -550,7 +557,11 @@ impl DiffViewer {
                         commit_message_text.as_str(),
                     ) {
                         Ok(created) => Some(created.subject),
-                        Err(err) if err.to_string().contains("no changes to commit") => None,
+                        Err(err)
+                            if GitError::classify(&err) == Some(GitError::NothingToCommit) =>
+                        {
+                            None
+                        }
                         Err(err) => return Err(err),
                     };
 
@@ -562,6 +573,7 @@ impl DiffViewer {
                     push_current_branch_with_publish_fallback(
                         repo_root.as_path(),
                         branch_name.as_str(),
+                        &cancel,
                     )?;
 
                     Ok((committed_subject, branch_name))
@@ -605,8 +617,9 @@ impl DiffViewer {
                         );
                         let summary = err.to_string();
                         this.git_status_message = Some(format!("Git error: {err:#}"));
-                        Self::push_error_notification(
+                        Self::push_git_error_notification(
                             format!("Commit and Push failed: {summary}"),
+                            &err,
                             cx,
                         );
                     }
@@ -659,6 +672,7 @@ impl DiffViewer {
         let branch_name = context.branch_name.clone();
         let start_mode = context.start_mode;
         let epoch = self.begin_git_action("Open PR", cx);
+        let cancel = self.install_git_action_cancel(self.config.git_timeouts.push_secs);
         let open_pr_branch_strategy = ai_open_pr_branch_strategy(repo_root.as_path(), &branch_name);
         let create_review_branch =
             open_pr_branch_strategy == AiOpenPrBranchStrategy::CreateReviewBranch;
@@ -745,7 +759,11 @@ impl DiffViewer {
                         commit_message_text.as_str(),
                     ) {
                         Ok(created) => Some(created.subject),
-                        Err(err) if err.to_string().contains("no changes to commit") => None,
+                        Err(err)
+                            if GitError::classify(&err) == Some(GitError::NothingToCommit) =>
+                        {
+                            None
+                        }
                         Err(err) => return Err(err),
                     };
 
@@ -760,6 +778,7 @@ impl DiffViewer {
                     push_current_branch_with_publish_fallback(
                         repo_root.as_path(),
                         review_branch_name.as_str(),
+                        &cancel,
                     )?;
 
                     send_ai_git_progress(
@@ -119,7 +119,9 @@ fn activate_new_ai_review_branch(
         ) {
             Ok(()) => return Ok(candidate_branch_name),
             Err(err) => {
-                if err.to_string().contains("already exists") && attempt < 20 {
+                if matches!(GitError::classify(&err), Some(GitError::BranchExists { .. }))
+                    && attempt < 20
+                {
                     continue;
                 }
                 return Err(err);
@@ -131,16 +133,19 @@ fn activate_new_ai_review_branch(
 fn push_current_branch_with_publish_fallback(
     repo_root: &std::path::Path,
     branch_name: &str,
+    cancel: &CancellationToken,
 ) -> anyhow::Result<()> {
-    match push_current_branch(repo_root, branch_name, true) {
+    match push_current_branch_with_cancel(repo_root, branch_name, true, cancel) {
         Ok(()) => Ok(()),
-        Err(err) if err.to_string().contains("publish this branch before pushing") => {
-            push_current_branch(repo_root, branch_name, false)
-        }
-        Err(err) if err.to_string().contains("already published") => {
-            push_current_branch(repo_root, branch_name, true)
-        }
-        Err(err) => Err(err),
+        Err(err) => match GitError::classify(&err) {
+            Some(GitError::PublishRequired) => {
+                push_current_branch_with_cancel(repo_root, branch_name, false, cancel)
+            }
+            Some(GitError::AlreadyPublished { .. }) => {
+                push_current_branch_with_cancel(repo_root, branch_name, true, cancel)
+            }
+            _ => Err(err),
+        },
     }
 }
 
@@ -0,0 +1,97 @@
+impl DiffViewer {
+    /// Checks the loaded comparison against the review budget. The focused part and dismissal
+    /// carry over while the comparison stays over budget.
+    fn refresh_review_change_set_size(&mut self) {
+        let warning = hunk_domain::change_set_size::assess_change_set(
+            self.review_files.iter().map(|file| {
+                let changed_lines = self
+                    .review_file_line_stats
+                    .get(file.path.as_str())
+                    .map_or(0, |stats| stats.changed());
+                (file.path.as_str(), changed_lines)
+            }),
+            &self.config.review_budget,
+        );
+
+        let size = &mut self.review_change_set_size;
+        if warning.is_none() {
+            size.dismissed = false;
+        }
+        let part_count = warning.as_ref().map_or(0, |warning| warning.parts.len());
+        if size
+            .focused_part
+            .is_some_and(|part_ix| part_ix >= part_count)
+        {
+            size.focused_part = None;
+            for path in std::mem::take(&mut size.collapsed_paths) {
+                self.collapsed_files.remove(path.as_str());
+            }
+        }
+        size.warning = warning;
+    }
+
+    /// Reviews one part of the proposed split by collapsing every file outside it and jumping
+    /// to its first file. Focusing the part again expands the files it collapsed.
+    pub(super) fn toggle_review_change_set_part_focus(
+        &mut self,
+        part_ix: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(part) = self
+            .review_change_set_size
+            .warning
+            .as_ref()
+            .and_then(|warning| warning.parts.get(part_ix))
+            .cloned()
+        else {
+            return;
+        };
+
+        let previous_part = self.review_change_set_size.focused_part.take();
+        for path in std::mem::take(&mut self.review_change_set_size.collapsed_paths) {
+            self.collapsed_files.remove(path.as_str());
+        }
+        if previous_part != Some(part_ix) {
+            let part_paths = part
+                .paths
+                .iter()
+                .map(String::as_str)
+                .collect::<BTreeSet<_>>();
+            let collapsed_paths = self
+                .review_files
+                .iter()
+                .filter(|file| {
+                    !part_paths.contains(file.path.as_str())
+                        && !self.collapsed_files.contains(file.path.as_str())
+                })
+                .map(|file| file.path.clone())
+                .collect::<BTreeSet<_>>();
+            for path in &part.paths {
+                self.collapsed_files.remove(path.as_str());
+            }
+            self.collapsed_files.extend(collapsed_paths.iter().cloned());
+            self.review_change_set_size.collapsed_paths = collapsed_paths;
+            self.review_change_set_size.focused_part = Some(part_ix);
+
+            if let Some(path) = part.paths.first() {
+                let status = self
+                    .review_files
+                    .iter()
+                    .find(|file| file.path == *path)
+                    .map(|file| file.status);
+                self.set_review_selected_file(Some(path.clone()), status);
+                self.scroll_selected_after_reload = true;
+            }
+        }
+
+        self.review_surface.last_diff_scroll_offset = None;
+        self.last_scroll_activity_at = Instant::now();
+        self.request_selected_diff_reload(cx);
+        cx.notify();
+    }
+
+    pub(super) fn dismiss_review_change_set_size_warning(&mut self, cx: &mut Context<Self>) {
+        self.review_change_set_size.dismissed = true;
+        cx.notify();
+    }
+}
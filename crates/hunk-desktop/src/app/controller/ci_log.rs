@@ -0,0 +1,150 @@
+impl DiffViewer {
+    pub(super) fn open_ci_log_panel_action(
+        &mut self,
+        _: &OpenCiLogPanel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.ci_log_panel.is_none() {
+            let log_input_state = cx.new(|cx| {
+                InputState::new(window, cx)
+                    .multi_line(true)
+                    .rows(6)
+                    .placeholder(tr("ci-log-placeholder"))
+            });
+            self.ci_log_panel = Some(CiLogPanelState {
+                log_input_state,
+                entries: Vec::new(),
+                scanned: false,
+            });
+        }
+        if let Some(panel) = self.ci_log_panel.as_ref() {
+            panel
+                .log_input_state
+                .update(cx, |state, cx| state.focus(window, cx));
+        }
+        cx.notify();
+    }
+
+    pub(super) fn close_ci_log_panel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.ci_log_panel.take().is_some() {
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    /// Re-reads the pasted log and marks which of its references fall inside the current diff.
+    pub(super) fn scan_ci_log(&mut self, cx: &mut Context<Self>) {
+        let Some(workspace_root) = self
+            .selected_git_workspace_root()
+            .or_else(|| self.repo_root.clone())
+        else {
+            return;
+        };
+        let Some(panel) = self.ci_log_panel.as_ref() else {
+            return;
+        };
+        let text = panel.log_input_state.read(cx).value().to_string();
+        let entries = ci_log_references(text.as_str(), workspace_root.as_path())
+            .into_iter()
+            .map(|reference| CiLogPanelEntry {
+                in_change: self.ci_log_reference_in_change(&reference),
+                reference,
+            })
+            .collect();
+
+        let Some(panel) = self.ci_log_panel.as_mut() else {
+            return;
+        };
+        panel.entries = entries;
+        panel.scanned = true;
+        cx.notify();
+    }
+
+    pub(super) fn open_ci_log_reference(
+        &mut self,
+        entry_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(reference) = self
+            .ci_log_panel
+            .as_ref()
+            .and_then(|panel| panel.entries.get(entry_ix))
+            .map(|entry| entry.reference.clone())
+        else {
+            return;
+        };
+
+        if let Some(row_ix) = self.review_row_for_ci_log_reference(&reference) {
+            self.focus_handle.focus(window, cx);
+            self.select_row_and_scroll(row_ix, false, cx);
+            return;
+        }
+        match (reference.location, reference.test_name) {
+            (Some(location), _) => {
+                self.open_clipboard_path(location.normalized_path, location.line, window, cx);
+            }
+            (None, Some(test_name)) => {
+                self.set_git_warning_message(
+                    tr_args("ci-log-test-not-in-diff", &[("test", test_name.as_str())]),
+                    Some(window),
+                    cx,
+                );
+            }
+            (None, None) => {}
+        }
+    }
+
+    /// The review row a reference points at: its line or file, or the line defining its test.
+    fn review_row_for_ci_log_reference(&self, reference: &CiLogReference) -> Option<usize> {
+        if let Some(location) = reference.location.as_ref() {
+            return self.review_row_for_path_line(location.normalized_path.as_str(), location.line);
+        }
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return None;
+        }
+        let name = reference
+            .test_name
+            .as_deref()?
+            .rsplit(['›', ':', '.', '/'])
+            .next()?
+            .trim();
+        if name.is_empty() {
+            return None;
+        }
+        let definitions = [
+            format!("fn {name}("),
+            format!("def {name}("),
+            format!("func {name}("),
+            format!("'{name}'"),
+            format!("\"{name}\""),
+        ];
+        let session = self.review_workspace_session.as_ref()?;
+        (0..session.row_count()).find(|row_ix| {
+            session.row(*row_ix).is_some_and(|row| {
+                row.kind == DiffRowKind::Code
+                    && row.right.kind != DiffCellKind::None
+                    && definitions
+                        .iter()
+                        .any(|definition| row.right.text.contains(definition.as_str()))
+            })
+        })
+    }
+
+    /// A reference is part of the change when it names a changed file without a line, or lands
+    /// on an added line.
+    fn ci_log_reference_in_change(&self, reference: &CiLogReference) -> bool {
+        let Some(row_ix) = self.review_row_for_ci_log_reference(reference) else {
+            return false;
+        };
+        let Some(row) = self
+            .review_workspace_session
+            .as_ref()
+            .and_then(|session| session.row(row_ix))
+        else {
+            return false;
+        };
+        row.kind != DiffRowKind::Code || row.right.kind == DiffCellKind::Added
+    }
+}
@@ -0,0 +1,113 @@
+impl DiffViewer {
+    fn clipboard_path_target(&self, cx: &mut Context<Self>) -> Option<MarkdownWorkspaceFileLink> {
+        let text = cx.read_from_clipboard().and_then(|item| item.text())?;
+        let workspace_root = self
+            .selected_git_workspace_root()
+            .or_else(|| self.repo_root.clone())?;
+        resolve_clipboard_path_target(text.as_str(), workspace_root.as_path())
+    }
+
+    pub(super) fn open_path_from_clipboard_action(
+        &mut self,
+        _: &OpenPathFromClipboard,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(link) = self.clipboard_path_target(cx) else {
+            self.set_git_warning_message(
+                tr("clipboard-path-none"),
+                Some(window),
+                cx,
+            );
+            return;
+        };
+        self.open_clipboard_path(link.normalized_path, link.line, window, cx);
+    }
+
+    /// Jumps to `path` in the review when the diff shows it, otherwise opens it in Files.
+    fn open_clipboard_path(
+        &mut self,
+        path: String,
+        line: Option<usize>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(row_ix) = self.review_row_for_path_line(path.as_str(), line) {
+            self.focus_handle.focus(window, cx);
+            self.select_row_and_scroll(row_ix, false, cx);
+            return;
+        }
+
+        let status = self
+            .status_for_path(path.as_str())
+            .unwrap_or(FileStatus::Unknown);
+        match line {
+            Some(line) => self.open_file_in_files_workspace_at_line(path, status, line, window, cx),
+            None => self.open_file_in_files_workspace(path, status, window, cx),
+        };
+    }
+
+    /// The review row showing new-side `line` of `path`, or the file's header without a line.
+    fn review_row_for_path_line(&self, path: &str, line: Option<usize>) -> Option<usize> {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return None;
+        }
+        let session = self.review_workspace_session.as_ref()?;
+        let range = session.file_range_for_path(path)?;
+        let Some(line) = line else {
+            return Some(range.start_row);
+        };
+        (range.start_row..range.end_row).find(|row_ix| {
+            session.row(*row_ix).is_some_and(|row| {
+                row.kind == DiffRowKind::Code
+                    && row
+                        .right
+                        .line
+                        .is_some_and(|row_line| row_line as usize == line)
+            })
+        })
+    }
+
+    /// Offers the clipboard's path once per copied text, when the window comes to the front.
+    fn offer_clipboard_path_on_activation(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.config.offer_clipboard_paths || !window.is_window_active() {
+            return;
+        }
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        if self.last_offered_clipboard_text.as_deref() == Some(text.as_str()) {
+            return;
+        }
+        self.last_offered_clipboard_text = Some(text);
+        let Some(link) = self.clipboard_path_target(cx) else {
+            return;
+        };
+
+        let label = match link.line {
+            Some(line) => format!("{}:{line}", link.normalized_path),
+            None => link.normalized_path.clone(),
+        };
+        let view = cx.entity().downgrade();
+        gpui_component::WindowExt::push_notification(
+            window,
+            crate::app::notifications::info_with_action(
+                tr_args("clipboard-path-found", &[("path", label.as_str())]),
+                tr("clipboard-path-open"),
+                move |window, cx| {
+                    if let Some(view) = view.upgrade() {
+                        view.update(cx, |this, cx| {
+                            this.open_clipboard_path(
+                                link.normalized_path.clone(),
+                                link.line,
+                                window,
+                                cx,
+                            );
+                        });
+                    }
+                },
+            ),
+            cx,
+        );
+    }
+}
@@ -0,0 +1,399 @@
+impl DiffViewer {
+    pub(super) fn toggle_co_review_panel(&mut self, cx: &mut Context<Self>) {
+        self.co_review_panel_open = !self.co_review_panel_open;
+        cx.notify();
+    }
+
+    pub(super) fn co_review_participant_count(&self) -> usize {
+        self.co_review
+            .as_ref()
+            .map_or(0, |state| state.peers.len().saturating_add(1))
+    }
+
+    pub(super) fn co_review_following_name(&self) -> Option<&str> {
+        let state = self.co_review.as_ref()?;
+        let following = state.following.as_deref()?;
+        state.peers.get(following).map(|peer| peer.name.as_str())
+    }
+
+    fn co_review_display_name(&self) -> String {
+        let configured = self.config.co_review.display_name.trim();
+        if !configured.is_empty() {
+            return configured.to_string();
+        }
+        std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .ok()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| "Reviewer".to_string())
+    }
+
+    pub(super) fn host_co_review_session(&mut self, cx: &mut Context<Self>) {
+        if self.co_review.is_some() || self.co_review_joining {
+            return;
+        }
+
+        let bind_addr = std::net::SocketAddr::from((
+            std::net::Ipv4Addr::UNSPECIFIED,
+            self.config.co_review.port,
+        ));
+        let participant = Participant::new(self.co_review_display_name());
+        match CoReviewSession::host(bind_addr, participant) {
+            Ok(session) => {
+                let share_target = session.local_addr().map(|addr| {
+                    hunk_server::co_review::format_join_target(
+                        hunk_server::co_review::lan_address(addr.port()),
+                        session.join_code(),
+                    )
+                });
+                self.install_co_review_session(session, share_target, cx);
+                Self::push_success_notification(
+                    "Co-review session started. Share the address with your reviewer.".to_string(),
+                    cx,
+                );
+            }
+            Err(err) => {
+                error!("failed to host co-review session: {err:#}");
+                Self::push_error_notification(format!("Could not start co-review: {err:#}"), cx);
+            }
+        }
+        cx.notify();
+    }
+
+    pub(super) fn join_co_review_session(&mut self, cx: &mut Context<Self>) {
+        if self.co_review.is_some() || self.co_review_joining {
+            return;
+        }
+
+        let input = self.co_review_join_input_state.read(cx).value().to_string();
+        let (address, join_code) = match hunk_server::co_review::parse_join_target(input.as_str()) {
+            Ok(target) => target,
+            Err(err) => {
+                Self::push_warning_notification(err.to_string(), None, cx);
+                return;
+            }
+        };
+
+        // Connecting can block for a few seconds, so it runs off the UI thread.
+        let participant = Participant::new(self.co_review_display_name());
+        self.co_review_joining = true;
+        cx.notify();
+        self.co_review_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    CoReviewSession::join(address.as_str(), join_code.as_str(), participant)
+                })
+                .await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    this.co_review_joining = false;
+                    match result {
+                        Ok(session) => {
+                            this.install_co_review_session(session, None, cx);
+                            Self::push_success_notification(
+                                "Joined co-review session.".to_string(),
+                                cx,
+                            );
+                        }
+                        Err(err) => {
+                            error!("failed to join co-review session: {err:#}");
+                            Self::push_error_notification(
+                                format!("Could not join co-review: {err:#}"),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn leave_co_review_session(&mut self, cx: &mut Context<Self>) {
+        if self.co_review.take().is_none() {
+            return;
+        }
+        self.co_review_task = Task::ready(());
+        cx.notify();
+    }
+
+    pub(super) fn toggle_co_review_follow(
+        &mut self,
+        participant_id: String,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(state) = self.co_review.as_mut() else {
+            return;
+        };
+        if state.following.as_deref() == Some(participant_id.as_str()) {
+            state.following = None;
+            cx.notify();
+            return;
+        }
+
+        let presence = state
+            .peers
+            .get(participant_id.as_str())
+            .map(|peer| peer.presence.clone());
+        state.following = Some(participant_id);
+        if let Some(presence) = presence {
+            self.follow_co_review_presence(&presence, cx);
+        }
+        cx.notify();
+    }
+
+    fn install_co_review_session(
+        &mut self,
+        session: CoReviewSession,
+        share_target: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        self.co_review = Some(CoReviewState {
+            session,
+            share_target,
+            peers: BTreeMap::new(),
+            following: None,
+            last_sent_presence: None,
+        });
+        self.co_review_task = cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(CO_REVIEW_POLL_INTERVAL)
+                    .await;
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+                let mut active = true;
+                this.update(cx, |this, cx| {
+                    active = this.poll_co_review(cx);
+                });
+                if !active {
+                    return;
+                }
+            }
+        });
+    }
+
+    fn poll_co_review(&mut self, cx: &mut Context<Self>) -> bool {
+        let Some(state) = self.co_review.as_ref() else {
+            return false;
+        };
+        let events = std::iter::from_fn(|| state.session.try_recv()).collect::<Vec<_>>();
+        let changed = !events.is_empty();
+        for event in events {
+            self.apply_co_review_event(event, cx);
+        }
+        self.publish_co_review_presence();
+        if changed {
+            cx.notify();
+        }
+        self.co_review.is_some()
+    }
+
+    fn apply_co_review_event(&mut self, event: CoReviewEvent, cx: &mut Context<Self>) {
+        let message = match event {
+            CoReviewEvent::Message(message) => message,
+            CoReviewEvent::Disconnected(reason) => {
+                self.co_review = None;
+                Self::push_warning_notification(reason, None, cx);
+                return;
+            }
+        };
+        let Some(state) = self.co_review.as_mut() else {
+            return;
+        };
+
+        match message {
+            CoReviewMessage::Hello { participant, .. } => {
+                if participant.id != state.session.participant().id {
+                    state.peers.insert(
+                        participant.id,
+                        CoReviewPeer {
+                            name: participant.name,
+                            presence: Presence::default(),
+                        },
+                    );
+                }
+            }
+            CoReviewMessage::Presence {
+                participant_id,
+                presence,
+            } => {
+                let is_followed = state.following.as_deref() == Some(participant_id.as_str());
+                if let Some(peer) = state.peers.get_mut(participant_id.as_str()) {
+                    peer.presence = presence.clone();
+                }
+                if is_followed {
+                    self.follow_co_review_presence(&presence, cx);
+                }
+            }
+            CoReviewMessage::CommentAdded {
+                participant_id,
+                comment,
+            } => {
+                let author = state
+                    .peers
+                    .get(participant_id.as_str())
+                    .map(|peer| peer.name.clone());
+                self.import_co_review_comment(comment, author, cx);
+            }
+            CoReviewMessage::Leave { participant_id } => {
+                state.peers.remove(participant_id.as_str());
+                if state.following.as_deref() == Some(participant_id.as_str()) {
+                    state.following = None;
+                }
+            }
+            CoReviewMessage::Rejected { .. } => {}
+        }
+    }
+
+    fn current_co_review_presence(&self) -> Presence {
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return Presence::default();
+        };
+        let Some(top_row) = self.current_review_surface_top_row() else {
+            return Presence::default();
+        };
+        let Some(range) = session.file_at_or_after_surface_row(top_row) else {
+            return Presence::default();
+        };
+        Presence {
+            path: Some(range.path.clone()),
+            row_offset: Some(top_row.saturating_sub(range.start_row)),
+        }
+    }
+
+    fn publish_co_review_presence(&mut self) {
+        let presence = self.current_co_review_presence();
+        let Some(state) = self.co_review.as_mut() else {
+            return;
+        };
+        if state.last_sent_presence.as_ref() == Some(&presence) {
+            return;
+        }
+        if let Err(err) = state.session.send_presence(presence.clone()) {
+            error!("failed to publish co-review presence: {err:#}");
+            return;
+        }
+        state.last_sent_presence = Some(presence);
+    }
+
+    fn follow_co_review_presence(&mut self, presence: &Presence, cx: &mut Context<Self>) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        let Some(path) = presence.path.as_deref() else {
+            return;
+        };
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return;
+        };
+        let Some(range) = session.file_range_for_path(path) else {
+            return;
+        };
+        let last_row = range.end_row.saturating_sub(1).max(range.start_row);
+        let target_row = range
+            .start_row
+            .saturating_add(presence.row_offset.unwrap_or(0))
+            .min(last_row);
+        let (path, status) = (range.path.clone(), range.status);
+        let top_offset_px = session.row_top_offset_px(target_row);
+
+        self.set_review_selected_file(Some(path), Some(status));
+        if let Some(top_offset_px) = top_offset_px {
+            self.review_surface
+                .diff_scroll_handle
+                .set_offset(point(px(0.), -px(top_offset_px as f32)));
+        }
+        self.review_surface.last_diff_scroll_offset = None;
+        self.last_scroll_activity_at = Instant::now();
+        cx.notify();
+    }
+
+    fn share_co_review_comment(&self, record: &CommentRecord) {
+        let Some(state) = self.co_review.as_ref() else {
+            return;
+        };
+        let comment = SharedComment {
+            origin_id: record.id.clone(),
+            file_path: record.file_path.clone(),
+            line_side: match record.line_side {
+                CommentLineSide::Left => "old",
+                CommentLineSide::Right => "new",
+                CommentLineSide::Meta => "meta",
+            }
+            .to_string(),
+            old_line: record.old_line,
+            new_line: record.new_line,
+            hunk_header: record.hunk_header.clone(),
+            line_text: record.line_text.clone(),
+            context_before: record.context_before.clone(),
+            context_after: record.context_after.clone(),
+            anchor_hash: record.anchor_hash.clone(),
+            text: record.comment_text.clone(),
+        };
+        if let Err(err) = state.session.share_comment(comment) {
+            error!("failed to share co-review comment: {err:#}");
+        }
+    }
+
+    fn import_co_review_comment(
+        &mut self,
+        comment: SharedComment,
+        author: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            return;
+        };
+        let origin_id = comment.origin_id;
+
+        let line_side = match comment.line_side.as_str() {
+            "old" => CommentLineSide::Left,
+            "new" => CommentLineSide::Right,
+            _ => CommentLineSide::Meta,
+        };
+        let input = NewComment {
+            repo_root,
+            branch_name: self.comment_scope_branch_name(),
+            created_head_commit: None,
+            file_path: comment.file_path,
+            line_side,
+            old_line: comment.old_line,
+            new_line: comment.new_line,
+            row_stable_id: None,
+            hunk_header: comment.hunk_header,
+            line_text: comment.line_text,
+            context_before: comment.context_before,
+            context_after: comment.context_after,
+            anchor_hash: comment.anchor_hash,
+            comment_text: comment.text,
+            author_name: author,
+            author_email: None,
+        };
+        let reply = actor.call(move |store| {
+            // Instances on the same machine share one database, so the comment may already exist.
+            if matches!(store.get_comment(origin_id.as_str()), Ok(Some(_))) {
+                return Ok(None);
+            }
+            store.create_comment(&input).map(Some)
+        });
+        Self::finish_comment_write(
+            reply,
+            |this, result, cx| match result {
+                Ok(created) => {
+                    this.reload_comments_cache(false, cx);
+                    if let Some(record) = created {
+                        this.notify_comment_mentions(std::slice::from_ref(&record), cx);
+                    }
+                }
+                Err(err) => error!("failed to store shared co-review comment: {err:#}"),
+            },
+            cx,
+        );
+    }
+}
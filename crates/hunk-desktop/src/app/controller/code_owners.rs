@@ -0,0 +1,45 @@
+impl DiffViewer {
+    /// Re-reads CODEOWNERS on every full refresh, since switching branches can change it.
+    fn request_code_owners_reload(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.repo_root.clone() else {
+            self.code_owners = None;
+            return;
+        };
+
+        self.code_owners_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let repo_root = repo_root.clone();
+                    async move { load_code_owners(repo_root.as_path()) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.repo_root.as_ref() != Some(&repo_root) {
+                        return;
+                    }
+                    let code_owners = result.unwrap_or_else(|err| {
+                        warn!("failed to load CODEOWNERS: {err:#}");
+                        None
+                    });
+                    if this.code_owners != code_owners {
+                        this.code_owners = code_owners;
+                        cx.notify();
+                    }
+                });
+            }
+        });
+    }
+
+    /// Short owner label for a changed file: the first owner, plus how many others share it.
+    pub(super) fn code_owners_label_for_path(&self, path: &str) -> Option<String> {
+        let owners = self.code_owners.as_ref()?.owners_for(path);
+        let (first, rest) = owners.split_first()?;
+        Some(match rest.len() {
+            0 => first.clone(),
+            others => format!("{first} +{others}"),
+        })
+    }
+}
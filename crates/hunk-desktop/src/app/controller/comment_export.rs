@@ -0,0 +1,147 @@
+impl DiffViewer {
+    /// Every comment of the current review scope, resolved and stale ones included.
+    fn comment_export_source(
+        &mut self,
+        cx: &mut Context<Self>,
+    ) -> Option<(DatabaseActor, CommentExportScope)> {
+        let Some(actor) = self.database_actor.clone() else {
+            Self::push_error_notification(tr("comment-import-database-unavailable"), cx);
+            return None;
+        };
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            Self::push_error_notification(tr("comment-import-no-repository"), cx);
+            return None;
+        };
+        if self.comments_cache.is_empty() {
+            Self::push_error_notification(tr("comment-export-empty"), cx);
+            return None;
+        }
+
+        let scope = CommentExportScope {
+            repo_root,
+            branch_name: self.comment_scope_branch_name(),
+            include_non_open: true,
+        };
+        Some((actor, scope))
+    }
+
+    /// Copies the review's comments as Markdown, ready to paste into a pull request description.
+    pub(super) fn copy_review_comments_markdown(&mut self, cx: &mut Context<Self>) {
+        let Some((actor, scope)) = self.comment_export_source(cx) else {
+            return;
+        };
+        let reply =
+            actor.call(move |store| store.export_comments(&scope, CommentExportFormat::Markdown));
+        cx.spawn(async move |this, cx| {
+            let result = reply.await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.update(cx, |_, cx| match result {
+                Ok(markdown) => {
+                    cx.write_to_clipboard(ClipboardItem::new_string(markdown));
+                    Self::push_success_notification(tr("comment-export-copied"), cx);
+                }
+                Err(err) => {
+                    error!("comment export failed: {err:#}");
+                    Self::push_error_notification(
+                        tr_args(
+                            "comment-export-failed",
+                            &[("error", format!("{err:#}").as_str())],
+                        ),
+                        cx,
+                    );
+                }
+            });
+        })
+        .detach();
+    }
+
+    /// Asks where to save, then writes the review's comments as Markdown or JSON.
+    pub(super) fn save_review_comments(
+        &mut self,
+        format: CommentExportFormat,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((actor, scope)) = self.comment_export_source(cx) else {
+            return;
+        };
+
+        let repo_root = self.selected_git_workspace_root();
+        let project_name = repo_root
+            .as_deref()
+            .and_then(|root| root.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "review".to_string());
+        let directory = repo_root
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let suggested_name = format!("{project_name}-comments.{}", format.file_extension());
+        let prompt = cx.prompt_for_new_path(directory.as_path(), Some(suggested_name.as_str()));
+
+        self.comment_export_task = cx.spawn(async move |this, cx| {
+            let target_path = match prompt.await {
+                Ok(Ok(Some(path))) => path,
+                Ok(Ok(None)) => return,
+                Ok(Err(err)) => {
+                    if let Some(this) = this.upgrade() {
+                        this.update(cx, |_, cx| {
+                            Self::push_error_notification(
+                                tr_args(
+                                    "comment-import-picker-failed",
+                                    &[("error", format!("{err:#}").as_str())],
+                                ),
+                                cx,
+                            );
+                        });
+                    }
+                    return;
+                }
+                Err(err) => {
+                    error!("comment export prompt channel closed: {err}");
+                    return;
+                }
+            };
+
+            // The export reads on the database thread; only the file write runs in the background.
+            let result = match actor
+                .call(move |store| store.export_comments(&scope, format))
+                .await
+            {
+                Ok(contents) => {
+                    let target_path = target_path.clone();
+                    cx.background_executor()
+                        .spawn(async move {
+                            std::fs::write(target_path.as_path(), contents).with_context(|| {
+                                format!("failed to write {}", target_path.display())
+                            })
+                        })
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |_, cx| match result {
+                    Ok(()) => Self::push_success_notification(
+                        tr_args(
+                            "comment-export-saved",
+                            &[("path", target_path.display().to_string().as_str())],
+                        ),
+                        cx,
+                    ),
+                    Err(err) => {
+                        error!("comment export failed: {err:#}");
+                        Self::push_error_notification(
+                            tr_args(
+                                "comment-export-failed",
+                                &[("error", format!("{err:#}").as_str())],
+                            ),
+                            cx,
+                        );
+                    }
+                });
+            }
+        });
+    }
+}
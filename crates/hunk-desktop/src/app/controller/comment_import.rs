@@ -0,0 +1,255 @@
+/// Row for an imported comment: the row showing its line on the requested side, preferring
+/// one anchored to that side.
+fn imported_comment_row(
+    entry: &ImportedComment,
+    row_anchor_index: &BTreeMap<usize, RowCommentAnchor>,
+    rows_by_path: &BTreeMap<String, Vec<usize>>,
+) -> Option<usize> {
+    let line = entry.line?;
+    let mut fallback = None;
+    for row_ix in rows_by_path.get(entry.file_path.as_str())? {
+        let Some(anchor) = row_anchor_index.get(row_ix) else {
+            continue;
+        };
+        let anchor_line = match entry.line_side {
+            CommentLineSide::Left => anchor.old_line,
+            _ => anchor.new_line,
+        };
+        if anchor_line != Some(line) {
+            continue;
+        }
+        if anchor.line_side == entry.line_side {
+            return Some(*row_ix);
+        }
+        fallback.get_or_insert(*row_ix);
+    }
+    fallback
+}
+
+impl DiffViewer {
+    /// Asks for a review file and creates comments for every entry that lands on a row of the
+    /// loaded diff. The rest are kept aside for manual placement.
+    pub(super) fn import_review_comments(&mut self, cx: &mut Context<Self>) {
+        if !self.review_comments_enabled() {
+            self.comment_status_message = Some(tr("comment-import-disabled"));
+            cx.notify();
+            return;
+        }
+
+        let prompt = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: Some(tr("comment-import-title").into()),
+        });
+
+        self.comment_import_task = cx.spawn(async move |this, cx| {
+            let selection = match prompt.await {
+                Ok(selection) => selection,
+                Err(err) => {
+                    error!("comment import prompt channel closed: {err}");
+                    return;
+                }
+            };
+            let path = match selection {
+                Ok(Some(paths)) => paths.into_iter().next(),
+                Ok(None) => None,
+                Err(err) => {
+                    if let Some(this) = this.upgrade() {
+                        this.update(cx, |this, cx| {
+                            this.comment_status_message = Some(tr_args(
+                                "comment-import-picker-failed",
+                                &[("error", format!("{err:#}").as_str())],
+                            ));
+                            cx.notify();
+                        });
+                    }
+                    return;
+                }
+            };
+            let Some(path) = path else {
+                return;
+            };
+
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let contents = std::fs::read_to_string(path.as_path())
+                        .with_context(|| format!("failed to read {}", path.display()))?;
+                    parse_comment_import(contents.as_str())
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(entries) => this.apply_imported_comments(entries, cx),
+                        Err(err) => {
+                            error!("failed to import review comments: {err:#}");
+                            Self::push_error_notification(
+                                tr_args(
+                                    "comment-import-failed",
+                                    &[("error", format!("{err:#}").as_str())],
+                                ),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    fn apply_imported_comments(&mut self, entries: Vec<ImportedComment>, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            self.comment_status_message = Some(tr("comment-import-database-unavailable"));
+            return;
+        };
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            self.comment_status_message = Some(tr("comment-import-no-repository"));
+            return;
+        };
+        if entries.is_empty() {
+            Self::push_warning_notification(tr("comment-import-empty"), None, cx);
+            return;
+        }
+
+        let branch_name = self.comment_scope_branch_name();
+        let (row_anchor_index, rows_by_path) = self.build_comment_row_anchor_index();
+        let mut placed = Vec::new();
+        let mut unplaced = Vec::new();
+        for entry in entries {
+            let Some(row_ix) = imported_comment_row(&entry, &row_anchor_index, &rows_by_path)
+            else {
+                unplaced.push(entry);
+                continue;
+            };
+            let Some(anchor) = row_anchor_index.get(&row_ix).cloned() else {
+                unplaced.push(entry);
+                continue;
+            };
+            let input = NewComment {
+                repo_root: repo_root.clone(),
+                branch_name: branch_name.clone(),
+                created_head_commit: None,
+                file_path: anchor.file_path,
+                line_side: anchor.line_side,
+                old_line: anchor.old_line,
+                new_line: anchor.new_line,
+                row_stable_id: self
+                    .active_diff_row_metadata(row_ix)
+                    .map(|row| row.stable_id),
+                hunk_header: anchor.hunk_header,
+                line_text: anchor.line_text,
+                context_before: anchor.context_before,
+                context_after: anchor.context_after,
+                anchor_hash: anchor.anchor_hash,
+                comment_text: entry.text.clone(),
+                author_name: entry.author.clone(),
+                author_email: None,
+            };
+            placed.push((entry, input));
+        }
+
+        // Kept to list as unplaced if the database thread is gone before it creates anything.
+        let placed_entries = placed
+            .iter()
+            .map(|(entry, _)| entry.clone())
+            .collect::<Vec<_>>();
+        let reply = actor.call(move |store| {
+            Ok(placed
+                .into_iter()
+                .map(|(entry, input)| match store.create_comment(&input) {
+                    Ok(record) => Ok(record),
+                    Err(err) => {
+                        error!("failed to create imported comment: {err:#}");
+                        Err(entry)
+                    }
+                })
+                .collect::<Vec<_>>())
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| {
+                let outcomes = result.unwrap_or_else(|err| {
+                    error!("failed to create imported comments: {err:#}");
+                    placed_entries.into_iter().map(Err).collect()
+                });
+                let mut created = 0usize;
+                for outcome in outcomes {
+                    match outcome {
+                        Ok(record) => {
+                            this.share_co_review_comment(&record);
+                            created += 1;
+                        }
+                        Err(entry) => unplaced.push(entry),
+                    }
+                }
+                this.finish_comment_import(created, unplaced, cx);
+            },
+            cx,
+        );
+    }
+
+    fn finish_comment_import(
+        &mut self,
+        created: usize,
+        unplaced: Vec<ImportedComment>,
+        cx: &mut Context<Self>,
+    ) {
+        self.comment_import_unplaced = unplaced;
+        self.reload_comments_cache(false, cx);
+        let unplaced_count = self.comment_import_unplaced.len();
+        let created_label = created.to_string();
+        let unplaced_label = unplaced_count.to_string();
+        let message = match (created, unplaced_count) {
+            (_, 0) => tr_args(
+                "comment-import-imported",
+                &[("created", created_label.as_str())],
+            ),
+            (0, _) => tr_args(
+                "comment-import-none-matched",
+                &[("unplaced", unplaced_label.as_str())],
+            ),
+            _ => tr_args(
+                "comment-import-partial",
+                &[
+                    ("created", created_label.as_str()),
+                    ("unplaced", unplaced_label.as_str()),
+                ],
+            ),
+        };
+        if unplaced_count == 0 {
+            Self::push_success_notification(message.clone(), cx);
+        } else {
+            self.comments_preview_open = true;
+            Self::push_warning_notification(message.clone(), None, cx);
+        }
+        self.comment_status_message = Some(message);
+    }
+
+    /// Copies the entries that could not be anchored as a `path:line: comment` list.
+    pub(super) fn copy_unplaced_comment_imports(&mut self, cx: &mut Context<Self>) {
+        if self.comment_import_unplaced.is_empty() {
+            return;
+        }
+        let list = self
+            .comment_import_unplaced
+            .iter()
+            .map(|entry| match entry.line {
+                Some(line) => format!("{}:{line}: {}", entry.file_path, entry.text),
+                None => format!("{}: {}", entry.file_path, entry.text),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        cx.write_to_clipboard(ClipboardItem::new_string(list));
+        self.comment_status_message = Some(tr("comment-import-copied"));
+        cx.notify();
+    }
+
+    pub(super) fn dismiss_unplaced_comment_imports(&mut self, cx: &mut Context<Self>) {
+        self.comment_import_unplaced.clear();
+        cx.notify();
+    }
+}
@@ -1,5 +1,18 @@
 pub(super) type RowCommentAnchor = crate::app::review_workspace_session::ReviewCommentAnchor;
 
+/// What happens once a comment reload lands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentsReloadFollowUp {
+    None,
+    /// Raise mention notifications for comments that were not cached before.
+    NotifyMentions,
+    /// Check open comments against the loaded diff, then look for comments left on the old
+    /// side of a rename when `migrate_renames` is set.
+    Reconcile {
+        migrate_renames: bool,
+    },
+}
+
 #[derive(Debug, Clone)]
 struct FuzzyCommentKey {
     line_side: CommentLineSide,
@@ -70,6 +83,16 @@ impl DiffViewer {
         }
     }
 
+    fn spawn_database_actor(store: DatabaseStore) -> Option<DatabaseActor> {
+        match DatabaseActor::spawn(store) {
+            Ok(actor) => Some(actor),
+            Err(err) => {
+                error!("failed to start the database thread: {err:#}");
+                None
+            }
+        }
+    }
+
     fn clear_comment_ui_state(&mut self) {
         self.hovered_comment_row = None;
         self.active_comment_editor_row = None;
@@ -127,29 +150,137 @@ impl DiffViewer {
         }
     }
 
+    /// Loads the comment scope's comments and counts on the calling thread. Only used when the
+    /// database thread could not be started.
     fn refresh_comments_cache_from_store(&mut self) {
+        // Anything an in-flight background load returns is older than this.
+        self.comments_load_task = Task::ready(());
+        let Some((store, repo_root, branch_name)) = self.comments_load_scope() else {
+            return;
+        };
+        let counts = store.count_comments(repo_root.as_str(), branch_name.as_str());
+        let records = store.list_comments(repo_root.as_str(), branch_name.as_str(), true);
+        self.apply_loaded_comments(repo_root.as_str(), branch_name.as_str(), counts, records);
+    }
+
+    /// Loads the comment scope on the database thread and applies it when it arrives, unless
+    /// the scope changed meanwhile. With `notify_mentions`, comments that were not cached before
+    /// raise mention notifications.
+    fn reload_comments_cache(&mut self, notify_mentions: bool, cx: &mut Context<Self>) {
+        let follow_up = if notify_mentions {
+            CommentsReloadFollowUp::NotifyMentions
+        } else {
+            CommentsReloadFollowUp::None
+        };
+        self.load_comments_cache(follow_up, cx);
+    }
+
+    /// Reloads the comment scope for a freshly loaded review diff, then reconciles comment
+    /// anchors against it and moves comments of renamed files.
+    fn reload_comments_for_loaded_diff(&mut self, cx: &mut Context<Self>) {
+        self.load_comments_cache(
+            CommentsReloadFollowUp::Reconcile {
+                migrate_renames: true,
+            },
+            cx,
+        );
+    }
+
+    fn load_comments_cache(&mut self, follow_up: CommentsReloadFollowUp, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            self.refresh_comments_cache_from_store();
+            return;
+        };
+        let Some((_, repo_root, branch_name)) = self.comments_load_scope() else {
+            self.comments_load_task = Task::ready(());
+            return;
+        };
+
+        let counts = actor.count_comments(repo_root.clone(), branch_name.clone());
+        let records = actor.list_comments(repo_root.clone(), branch_name.clone(), true);
+        self.comments_load_task = cx.spawn(async move |this, cx| {
+            let counts = counts.await;
+            let records = records.await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                if this.comment_scope_repo_root().as_deref() != Some(repo_root.as_str())
+                    || this.comment_scope_branch_name() != branch_name
+                {
+                    return;
+                }
+                let known_ids = (follow_up == CommentsReloadFollowUp::NotifyMentions).then(|| {
+                    this.comments_cache
+                        .iter()
+                        .map(|comment| comment.id.clone())
+                        .collect::<BTreeSet<_>>()
+                });
+                this.apply_loaded_comments(
+                    repo_root.as_str(),
+                    branch_name.as_str(),
+                    counts,
+                    records,
+                );
+                if let Some(known_ids) = known_ids {
+                    let new_comments = this
+                        .comments_cache
+                        .iter()
+                        .filter(|comment| !known_ids.contains(comment.id.as_str()))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    this.notify_comment_mentions(new_comments.as_slice(), cx);
+                }
+                if let CommentsReloadFollowUp::Reconcile { migrate_renames } = follow_up {
+                    this.reconcile_comments_with_loaded_diff(cx);
+                    if migrate_renames {
+                        this.request_comment_rename_migration(cx);
+                    }
+                }
+                cx.notify();
+            });
+        });
+    }
+
+    /// The store and scope comments load from. Clears the cache when there is none.
+    fn comments_load_scope(&mut self) -> Option<(DatabaseStore, String, String)> {
         if !self.review_comments_enabled() {
             self.comments_cache.clear();
+            self.comment_counts = CommentScopeCounts::default();
             self.comment_miss_streaks.clear();
             self.reset_comment_row_match_cache();
             self.clear_comment_ui_state();
             self.comment_status_message = None;
-            return;
+            return None;
         }
 
         let Some(store) = self.database_store.clone() else {
             self.comments_cache.clear();
+            self.comment_counts = CommentScopeCounts::default();
             self.reset_comment_row_match_cache();
-            return;
+            return None;
         };
         let Some(repo_root) = self.comment_scope_repo_root() else {
             self.comments_cache.clear();
+            self.comment_counts = CommentScopeCounts::default();
             self.reset_comment_row_match_cache();
-            return;
+            return None;
         };
-        let branch_name = self.comment_scope_branch_name();
+        Some((store, repo_root, self.comment_scope_branch_name()))
+    }
 
-        match store.list_comments(repo_root.as_str(), branch_name.as_str(), true) {
+    fn apply_loaded_comments(
+        &mut self,
+        repo_root: &str,
+        branch_name: &str,
+        counts: Result<CommentScopeCounts>,
+        records: Result<Vec<CommentRecord>>,
+    ) {
+        self.comment_counts = counts.unwrap_or_else(|err| {
+            error!("failed to count comments for repo '{repo_root}': {err:#}");
+            CommentScopeCounts::default()
+        });
+        match records {
             Ok(records) => {
                 self.comments_cache = records;
                 let open_ids = self
@@ -160,9 +291,20 @@ impl DiffViewer {
                     .collect::<BTreeSet<_>>();
                 self.comment_miss_streaks
                     .retain(|comment_id, _| open_ids.contains(comment_id));
+                if self.comments_author_filter.as_ref().is_some_and(|author| {
+                    !self
+                        .comments_cache
+                        .iter()
+                        .any(|comment| comment.author_label() == Some(author.as_str()))
+                }) {
+                    self.comments_author_filter = None;
+                }
                 self.auto_show_non_open_if_open_empty();
                 self.rebuild_comment_row_match_cache();
-                self.comment_status_message = None;
+                // Reloads land after the write that asked for them; keep that write's message.
+                if self.comment_status_message.as_deref() == Some(COMMENTS_LOAD_FAILED_MESSAGE) {
+                    self.comment_status_message = None;
+                }
             }
             Err(err) => {
                 error!(
@@ -171,21 +313,62 @@ impl DiffViewer {
                 );
                 self.comments_cache.clear();
                 self.reset_comment_row_match_cache();
-                self.comment_status_message =
-                    Some("Failed to load comments from local database.".to_string());
+                self.comment_status_message = Some(COMMENTS_LOAD_FAILED_MESSAGE.to_string());
             }
         }
     }
 
-    fn prune_expired_comments(&mut self) {
-        let Some(store) = self.database_store.clone() else {
+    /// Keeps comments attached to files after they are renamed. Each move is an old and a new
+    /// path. Once the moves land the scope reloads and is reconciled again.
+    fn migrate_comments_for_renamed_paths(
+        &mut self,
+        moves: Vec<(String, String)>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(actor) = self.database_actor.clone() else {
             return;
         };
-        let retention_ms = COMMENT_RETENTION_DAYS.saturating_mul(24 * 60 * 60 * 1000);
-        let cutoff = now_unix_ms().saturating_sub(retention_ms);
-        if let Err(err) = store.prune_non_open_comments(cutoff) {
-            error!("failed to prune old comments: {err:#}");
-        }
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            return;
+        };
+        let branch_name = self.comment_scope_branch_name();
+        let reply = actor.call(move |store| {
+            let mut moved = 0;
+            for (old_path, new_path) in moves {
+                match store.migrate_comment_paths(
+                    repo_root.as_str(),
+                    branch_name.as_str(),
+                    old_path.as_str(),
+                    new_path.as_str(),
+                ) {
+                    Ok(count) => moved += count,
+                    Err(err) => {
+                        error!("failed to move comments from {old_path} to {new_path}: {err:#}");
+                    }
+                }
+            }
+            Ok(moved)
+        });
+        Self::finish_comment_write(
+            reply,
+            |this, result, cx| {
+                let moved = result.unwrap_or(0);
+                if moved == 0 {
+                    return;
+                }
+                this.load_comments_cache(
+                    CommentsReloadFollowUp::Reconcile {
+                        migrate_renames: false,
+                    },
+                    cx,
+                );
+                this.comment_status_message = Some(match moved {
+                    1 => "Moved 1 comment to its renamed file.".to_string(),
+                    count => format!("Moved {count} comments to their renamed files."),
+                });
+            },
+            cx,
+        );
     }
 
     pub(super) fn comments_open_count(&self) -> usize {
@@ -215,6 +398,11 @@ impl DiffViewer {
             .filter(|comment| {
                 self.comments_show_non_open || comment.status == CommentStatus::Open
             })
+            .filter(|comment| {
+                self.comments_author_filter
+                    .as_deref()
+                    .is_none_or(|author| comment.author_label() == Some(author))
+            })
             .take(COMMENT_PREVIEW_MAX_ITEMS)
             .cloned()
             .collect::<Vec<_>>()
@@ -232,6 +420,40 @@ impl DiffViewer {
         cx.notify();
     }
 
+    /// Distinct comment authors in this scope, for the author filter.
+    pub(super) fn comment_authors(&self) -> Vec<String> {
+        self.comments_cache
+            .iter()
+            .filter_map(CommentRecord::author_label)
+            .map(ToOwned::to_owned)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    pub(super) fn set_comments_author_filter(
+        &mut self,
+        author: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        if self.comments_author_filter == author {
+            return;
+        }
+        self.comments_author_filter = author;
+        cx.notify();
+    }
+
+    /// The Git identity new comments are attributed to.
+    fn comment_author_identity(&self) -> hunk_git::user_config::UserIdentity {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return Default::default();
+        };
+        hunk_git::user_config::read_user_identity(repo_root.as_path()).unwrap_or_else(|err| {
+            warn!("failed to read Git identity for comment author: {err:#}");
+            Default::default()
+        })
+    }
+
     pub(super) fn toggle_comments_preview(&mut self, cx: &mut Context<Self>) {
         if !self.review_comments_enabled() {
             self.comment_status_message =
@@ -241,6 +463,9 @@ impl DiffViewer {
         }
         if !self.comments_preview_open {
             self.auto_show_non_open_if_open_empty();
+            if self.workspace_panel_dock(WorkspacePanel::Comments) == PanelDock::Hidden {
+                self.set_workspace_panel_dock(WorkspacePanel::Comments, PanelDock::Floating, cx);
+            }
         }
         self.comments_preview_open = !self.comments_preview_open;
         cx.notify();
@@ -308,85 +533,6 @@ impl DiffViewer {
         cx.notify();
     }
 
-    pub(super) fn save_active_comment(
-        &mut self,
-        window: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        if !self.review_comments_enabled() {
-            self.comment_status_message =
-                Some("Comments are disabled for custom compare pairs.".to_string());
-            self.active_comment_editor_row = None;
-            cx.notify();
-            return;
-        }
-        let Some(store) = self.database_store.clone() else {
-            self.comment_status_message =
-                Some("Comments database is unavailable on this machine.".to_string());
-            cx.notify();
-            return;
-        };
-        let Some(row_ix) = self.active_comment_editor_row else {
-            return;
-        };
-
-        let comment_text = self.comment_input_state.read(cx).value().trim().to_string();
-        if comment_text.is_empty() {
-            self.comment_status_message = Some("Comment text cannot be empty.".to_string());
-            cx.notify();
-            return;
-        }
-
-        let Some(anchor) = self.build_row_comment_anchor(row_ix) else {
-            self.comment_status_message =
-                Some("Could not resolve a stable anchor for this diff row.".to_string());
-            cx.notify();
-            return;
-        };
-        let Some(repo_root) = self.comment_scope_repo_root() else {
-            self.comment_status_message = Some("No repository is open.".to_string());
-            cx.notify();
-            return;
-        };
-
-        let input = NewComment {
-            repo_root,
-            branch_name: self.comment_scope_branch_name(),
-            created_head_commit: None,
-            file_path: anchor.file_path,
-            line_side: anchor.line_side,
-            old_line: anchor.old_line,
-            new_line: anchor.new_line,
-            row_stable_id: self
-                .active_diff_row_metadata(row_ix)
-                .map(|row| row.stable_id),
-            hunk_header: anchor.hunk_header,
-            line_text: anchor.line_text,
-            context_before: anchor.context_before,
-            context_after: anchor.context_after,
-            anchor_hash: anchor.anchor_hash,
-            comment_text,
-        };
-
-        match store.create_comment(&input) {
-            Ok(_) => {
-                self.active_comment_editor_row = None;
-                self.comments_preview_open = false;
-                let state = self.comment_input_state.clone();
-                state.update(cx, |input, cx| {
-                    input.set_value("", window, cx);
-                });
-                self.refresh_comments_cache_from_store();
-                self.comment_status_message = Some("Comment added.".to_string());
-            }
-            Err(err) => {
-                error!("failed to create diff comment: {err:#}");
-                self.comment_status_message = Some("Failed to save comment.".to_string());
-            }
-        }
-        cx.notify();
-    }
-
     pub(super) fn copy_comment_bundle_by_id(&mut self, id: String, cx: &mut Context<Self>) {
         let Some(comment) = self.comments_cache.iter().find(|comment| comment.id == id) else {
             return;
@@ -416,152 +562,6 @@ impl DiffViewer {
         cx.notify();
     }
 
-    pub(super) fn delete_comment_by_id(&mut self, id: String, cx: &mut Context<Self>) {
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-
-        match store.delete_comment(id.as_str()) {
-            Ok(_) => {
-                self.comment_miss_streaks.remove(id.as_str());
-                self.refresh_comments_cache_from_store();
-                self.comment_status_message = Some("Comment deleted.".to_string());
-            }
-            Err(err) => {
-                error!("failed to delete comment {id}: {err:#}");
-                self.comment_status_message = Some("Failed to delete comment.".to_string());
-            }
-        }
-        cx.notify();
-    }
-
-    pub(super) fn reopen_comment_by_id(&mut self, id: String, cx: &mut Context<Self>) {
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-
-        match store.mark_comment_status(id.as_str(), CommentStatus::Open, None, now_unix_ms()) {
-            Ok(updated) => {
-                if updated {
-                    self.comment_miss_streaks.remove(id.as_str());
-                    self.refresh_comments_cache_from_store();
-                    self.comment_status_message = Some("Comment reopened.".to_string());
-                }
-            }
-            Err(err) => {
-                error!("failed to reopen comment {id}: {err:#}");
-                self.comment_status_message = Some("Failed to reopen comment.".to_string());
-            }
-        }
-        cx.notify();
-    }
-
-    pub(super) fn resolve_all_stale_comments(&mut self, cx: &mut Context<Self>) {
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-        let stale_ids = self
-            .comments_cache
-            .iter()
-            .filter(|comment| comment.status == CommentStatus::Stale)
-            .map(|comment| comment.id.clone())
-            .collect::<Vec<_>>();
-        if stale_ids.is_empty() {
-            self.comment_status_message = Some("No stale comments to resolve.".to_string());
-            cx.notify();
-            return;
-        }
-
-        let now = now_unix_ms();
-        let resolved =
-            match store.mark_many_comment_status(&stale_ids, CommentStatus::Resolved, None, now) {
-                Ok(updated) => {
-                    for id in &stale_ids {
-                        self.comment_miss_streaks.remove(id.as_str());
-                    }
-                    updated
-                }
-                Err(err) => {
-                    error!("failed to resolve stale comments in batch: {err:#}");
-                    0
-                }
-            };
-
-        self.refresh_comments_cache_from_store();
-        self.comment_status_message = Some(format!("Resolved {resolved} stale comments."));
-        cx.notify();
-    }
-
-    pub(super) fn reopen_all_stale_comments(&mut self, cx: &mut Context<Self>) {
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-        let stale_ids = self
-            .comments_cache
-            .iter()
-            .filter(|comment| comment.status == CommentStatus::Stale)
-            .map(|comment| comment.id.clone())
-            .collect::<Vec<_>>();
-        if stale_ids.is_empty() {
-            self.comment_status_message = Some("No stale comments to reopen.".to_string());
-            cx.notify();
-            return;
-        }
-
-        let now = now_unix_ms();
-        let reopened =
-            match store.mark_many_comment_status(&stale_ids, CommentStatus::Open, None, now) {
-                Ok(updated) => {
-                    for id in &stale_ids {
-                        self.comment_miss_streaks.remove(id.as_str());
-                    }
-                    updated
-                }
-                Err(err) => {
-                    error!("failed to reopen stale comments in batch: {err:#}");
-                    0
-                }
-            };
-
-        self.refresh_comments_cache_from_store();
-        self.comment_status_message = Some(format!("Reopened {reopened} stale comments."));
-        cx.notify();
-    }
-
-    pub(super) fn delete_all_resolved_comments(&mut self, cx: &mut Context<Self>) {
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-        let resolved_ids = self
-            .comments_cache
-            .iter()
-            .filter(|comment| comment.status == CommentStatus::Resolved)
-            .map(|comment| comment.id.clone())
-            .collect::<Vec<_>>();
-        if resolved_ids.is_empty() {
-            self.comment_status_message = Some("No resolved comments to delete.".to_string());
-            cx.notify();
-            return;
-        }
-
-        let deleted = match store.delete_many_comments(&resolved_ids) {
-            Ok(updated) => {
-                for id in &resolved_ids {
-                    self.comment_miss_streaks.remove(id.as_str());
-                }
-                updated
-            }
-            Err(err) => {
-                error!("failed to delete resolved comments in batch: {err:#}");
-                0
-            }
-        };
-
-        self.refresh_comments_cache_from_store();
-        self.comment_status_message = Some(format!("Deleted {deleted} resolved comments."));
-        cx.notify();
-    }
-
     pub(super) fn jump_to_comment_by_id(&mut self, id: String, cx: &mut Context<Self>) {
         let Some(comment) = self
             .comments_cache
@@ -608,14 +608,22 @@ impl DiffViewer {
         cx.notify();
     }
 
-    pub(super) fn reconcile_comments_with_loaded_diff(&mut self) {
-        self.refresh_comments_cache_from_store();
-        let Some(store) = self.database_store.clone() else {
-            return;
-        };
-        if self.comments_cache.is_empty() {
+    fn review_file_hides_rows(&self, path: &str) -> bool {
+        self.review_loaded_changed_lines_only.applies_to(path)
+            || self
+                .review_loaded_hunk_folds
+                .folds_rows(path, &self.loaded_review_hunks(path))
+    }
+
+    /// Marks open comments seen, stale or resolved by whether the loaded diff still shows
+    /// their anchors. Runs on the cached comments; the writes go to the database thread.
+    fn reconcile_comments_with_loaded_diff(&mut self, cx: &mut Context<Self>) {
+        if !self.review_comments_enabled() || self.comments_cache.is_empty() {
             return;
         }
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
 
         let now = now_unix_ms();
         let changed_paths = self
@@ -623,10 +631,10 @@ impl DiffViewer {
             .iter()
             .map(|file| file.path.clone())
             .collect::<BTreeSet<_>>();
-        let mut should_reload = false;
         let mut seen_ids = Vec::new();
         let mut stale_ids = Vec::new();
         let mut resolved_ids = Vec::new();
+        let mut file_hides_rows = BTreeMap::<String, bool>::new();
 
         for comment in self
             .comments_cache
@@ -641,6 +649,14 @@ impl DiffViewer {
             }
 
             let file_is_changed = changed_paths.contains(comment.file_path.as_str());
+            // Hidden rows would read as missing anchors, so never mark those comments stale.
+            if file_is_changed
+                && *file_hides_rows
+                    .entry(comment.file_path.clone())
+                    .or_insert_with(|| self.review_file_hides_rows(comment.file_path.as_str()))
+            {
+                continue;
+            }
             if file_is_changed {
                 match self.file_anchor_reconcile_state(comment.file_path.as_str()) {
                     FileAnchorReconcileState::Ready | FileAnchorReconcileState::Unavailable => {}
@@ -669,30 +685,39 @@ impl DiffViewer {
             }
         }
 
-        if let Err(err) = store.touch_many_comment_seen(&seen_ids, now) {
-            error!("failed to batch update comment last_seen: {err:#}");
+        if seen_ids.is_empty() && stale_ids.is_empty() && resolved_ids.is_empty() {
+            return;
         }
-        match store.mark_many_comment_status(&stale_ids, CommentStatus::Stale, Some("anchor_not_found"), now)
-        {
-            Ok(updated) => {
-                should_reload |= updated > 0;
+        let reply = actor.call(move |store| {
+            if let Err(err) = store.touch_many_comment_seen(&seen_ids, now) {
+                error!("failed to batch update comment last_seen: {err:#}");
             }
-            Err(err) => {
-                error!("failed to batch update stale comment status: {err:#}");
+            let mut updated = 0;
+            match store.mark_many_comment_status(
+                &stale_ids,
+                CommentStatus::Stale,
+                Some("anchor_not_found"),
+                now,
+            ) {
+                Ok(count) => updated += count,
+                Err(err) => error!("failed to batch update stale comment status: {err:#}"),
             }
-        }
-        match store.mark_many_comment_status(&resolved_ids, CommentStatus::Resolved, None, now) {
-            Ok(updated) => {
-                should_reload |= updated > 0;
-            }
-            Err(err) => {
-                error!("failed to batch update resolved comment status: {err:#}");
+            match store.mark_many_comment_status(&resolved_ids, CommentStatus::Resolved, None, now)
+            {
+                Ok(count) => updated += count,
+                Err(err) => error!("failed to batch update resolved comment status: {err:#}"),
             }
-        }
-
-        if should_reload {
-            self.refresh_comments_cache_from_store();
-        }
+            Ok(updated)
+        });
+        Self::finish_comment_write(
+            reply,
+            |this, result, cx| {
+                if result.is_ok_and(|updated| updated > 0) {
+                    this.reload_comments_cache(false, cx);
+                }
+            },
+            cx,
+        );
     }
 
     pub(super) fn build_row_comment_anchor(&self, row_ix: usize) -> Option<RowCommentAnchor> {
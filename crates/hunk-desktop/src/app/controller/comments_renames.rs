@@ -0,0 +1,68 @@
+impl DiffViewer {
+    /// Moves comments on files the loaded diff shows as renamed over to the new path, so they
+    /// are checked against the renamed file instead of going stale on the old one.
+    fn request_comment_rename_migration(&mut self, cx: &mut Context<Self>) {
+        if !self.review_comments_enabled() {
+            return;
+        }
+        let renamed_paths = self
+            .active_diff_files()
+            .iter()
+            .filter(|file| file.status == FileStatus::Renamed)
+            .map(|file| file.path.clone())
+            .collect::<BTreeSet<_>>();
+        if renamed_paths.is_empty() {
+            return;
+        }
+        let has_orphaned_comments = self.comments_cache.iter().any(|comment| {
+            comment.status != CommentStatus::Resolved
+                && !self.active_diff_contains_path(comment.file_path.as_str())
+        });
+        if !has_orphaned_comments {
+            return;
+        }
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+
+        self.comment_rename_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let repo_root = repo_root.clone();
+                    async move { load_worktree_renames(repo_root.as_path()) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.selected_git_workspace_root().as_ref() != Some(&repo_root) {
+                        return;
+                    }
+                    let renames = match result {
+                        Ok(renames) => renames,
+                        Err(err) => {
+                            warn!("failed to detect renamed files for comments: {err:#}");
+                            return;
+                        }
+                    };
+
+                    let moves = renames
+                        .into_iter()
+                        .filter(|(new_path, old_path)| {
+                            renamed_paths.contains(new_path.as_str())
+                                && this
+                                    .comments_cache
+                                    .iter()
+                                    .any(|comment| &comment.file_path == old_path)
+                        })
+                        .map(|(new_path, old_path)| (old_path, new_path))
+                        .collect::<Vec<_>>();
+                    if !moves.is_empty() {
+                        this.migrate_comments_for_renamed_paths(moves, cx);
+                    }
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,58 @@
+impl DiffViewer {
+    // Comments can also be written by `hunk serve`, so poll a cheap scope revision and reload
+    // the cache when another process changes it.
+    fn start_comments_watch(&mut self, cx: &mut Context<Self>) {
+        self.comments_watch_task = cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(COMMENTS_WATCH_INTERVAL)
+                    .await;
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+
+                let mut scope = None;
+                this.update(cx, |this, _| {
+                    scope = this.comments_watch_scope();
+                });
+                let Some((actor, repo_root, branch_name)) = scope else {
+                    continue;
+                };
+
+                let revision = actor.comment_scope_revision(repo_root, branch_name).await;
+                this.update(cx, |this, cx| {
+                    this.apply_comments_watch_revision(revision, cx);
+                });
+            }
+        });
+    }
+
+    fn comments_watch_scope(&self) -> Option<(DatabaseActor, String, String)> {
+        if !self.review_comments_enabled() {
+            return None;
+        }
+        let actor = self.database_actor.clone()?;
+        let repo_root = self.comment_scope_repo_root()?;
+        Some((actor, repo_root, self.comment_scope_branch_name()))
+    }
+
+    fn apply_comments_watch_revision(
+        &mut self,
+        revision: Result<CommentScopeRevision>,
+        cx: &mut Context<Self>,
+    ) {
+        let revision = match revision {
+            Ok(revision) => revision,
+            Err(err) => {
+                error!("failed to poll comment scope revision: {err:#}");
+                return;
+            }
+        };
+        let previous = self.comments_watch_revision.replace(revision);
+        if previous.is_none_or(|previous| previous == revision) {
+            return;
+        }
+
+        self.reload_comments_cache(true, cx);
+    }
+}
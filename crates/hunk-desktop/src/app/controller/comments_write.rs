@@ -0,0 +1,331 @@
+/// The status line for a failed comment write. A write that timed out behind another instance
+/// says so instead of reporting the `fallback_key` message.
+fn comment_write_failure_message(err: &anyhow::Error, fallback_key: &str) -> String {
+    if is_comment_database_locked(err) {
+        tr("comment-database-locked")
+    } else {
+        tr(fallback_key)
+    }
+}
+
+impl DiffViewer {
+    /// Hands a comment write back to the UI thread once the database thread finished it. Writes
+    /// wait there for other instances holding the database, never on the UI thread.
+    fn finish_comment_write<T: 'static>(
+        reply: DatabaseReply<T>,
+        on_done: impl FnOnce(&mut Self, Result<T>, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        cx.spawn(async move |this, cx| {
+            let result = reply.await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                on_done(this, result, cx);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    pub(super) fn save_active_comment(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        if self.comment_save_in_flight {
+            return;
+        }
+        if !self.review_comments_enabled() {
+            self.comment_status_message =
+                Some("Comments are disabled for custom compare pairs.".to_string());
+            self.active_comment_editor_row = None;
+            cx.notify();
+            return;
+        }
+        let Some(actor) = self.database_actor.clone() else {
+            self.comment_status_message =
+                Some("Comments database is unavailable on this machine.".to_string());
+            cx.notify();
+            return;
+        };
+        let Some(row_ix) = self.active_comment_editor_row else {
+            return;
+        };
+
+        let comment_text = self.comment_input_state.read(cx).value().trim().to_string();
+        if comment_text.is_empty() {
+            self.comment_status_message = Some("Comment text cannot be empty.".to_string());
+            cx.notify();
+            return;
+        }
+
+        let Some(anchor) = self.build_row_comment_anchor(row_ix) else {
+            self.comment_status_message =
+                Some("Could not resolve a stable anchor for this diff row.".to_string());
+            cx.notify();
+            return;
+        };
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            self.comment_status_message = Some("No repository is open.".to_string());
+            cx.notify();
+            return;
+        };
+
+        let author = self.comment_author_identity();
+        let input = NewComment {
+            repo_root,
+            branch_name: self.comment_scope_branch_name(),
+            created_head_commit: None,
+            file_path: anchor.file_path,
+            line_side: anchor.line_side,
+            old_line: anchor.old_line,
+            new_line: anchor.new_line,
+            row_stable_id: self
+                .active_diff_row_metadata(row_ix)
+                .map(|row| row.stable_id),
+            hunk_header: anchor.hunk_header,
+            line_text: anchor.line_text,
+            context_before: anchor.context_before,
+            context_after: anchor.context_after,
+            anchor_hash: anchor.anchor_hash,
+            comment_text,
+            author_name: author.name.filter(|name| !name.trim().is_empty()),
+            author_email: author.email.filter(|email| !email.trim().is_empty()),
+        };
+
+        self.comment_save_in_flight = true;
+        let reply = actor.call(move |store| store.create_comment(&input));
+        let input_state = self.comment_input_state.clone();
+        let window_handle = self.window_handle;
+        cx.spawn(async move |this, cx| {
+            let result = reply.await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            // The editor may have moved to another row while the write waited; leave that draft.
+            let clear_editor = this.update(cx, |this, cx| {
+                this.comment_save_in_flight = false;
+                let clear_editor = match result {
+                    Ok(record) => {
+                        this.share_co_review_comment(&record);
+                        let editor_unchanged = this.active_comment_editor_row == Some(row_ix);
+                        if editor_unchanged {
+                            this.active_comment_editor_row = None;
+                        }
+                        this.comments_preview_open = false;
+                        this.reload_comments_cache(false, cx);
+                        this.comment_status_message = Some("Comment added.".to_string());
+                        this.record_usage_metric(UsageMetricKind::CommentWritten, None, cx);
+                        editor_unchanged
+                    }
+                    Err(err) => {
+                        error!("failed to create diff comment: {err:#}");
+                        this.comment_status_message =
+                            Some(comment_write_failure_message(&err, "comment-save-failed"));
+                        false
+                    }
+                };
+                cx.notify();
+                clear_editor
+            });
+            if clear_editor
+                && let Err(err) = cx.update_window(window_handle, |_, window, cx| {
+                    input_state.update(cx, |input, cx| input.set_value("", window, cx));
+                })
+            {
+                error!("failed to clear the comment editor: {err:#}");
+            }
+        })
+        .detach();
+        cx.notify();
+    }
+
+    pub(super) fn delete_comment_by_id(&mut self, id: String, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+
+        let reply = actor.call({
+            let id = id.clone();
+            move |store| store.delete_comment(id.as_str())
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| match result {
+                Ok(_) => {
+                    this.comment_miss_streaks.remove(id.as_str());
+                    this.reload_comments_cache(false, cx);
+                    this.comment_status_message = Some("Comment deleted.".to_string());
+                }
+                Err(err) => {
+                    error!("failed to delete comment {id}: {err:#}");
+                    this.comment_status_message =
+                        Some(comment_write_failure_message(&err, "comment-delete-failed"));
+                }
+            },
+            cx,
+        );
+    }
+
+    pub(super) fn reopen_comment_by_id(&mut self, id: String, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+
+        let reply = actor.call({
+            let id = id.clone();
+            move |store| {
+                store.mark_comment_status(id.as_str(), CommentStatus::Open, None, now_unix_ms())
+            }
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| match result {
+                Ok(updated) => {
+                    if updated {
+                        this.comment_miss_streaks.remove(id.as_str());
+                        this.reload_comments_cache(false, cx);
+                        this.comment_status_message = Some("Comment reopened.".to_string());
+                    }
+                }
+                Err(err) => {
+                    error!("failed to reopen comment {id}: {err:#}");
+                    this.comment_status_message =
+                        Some(comment_write_failure_message(&err, "comment-reopen-failed"));
+                }
+            },
+            cx,
+        );
+    }
+
+    pub(super) fn resolve_all_stale_comments(&mut self, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+        let stale_ids = self.comment_ids_with_status(CommentStatus::Stale);
+        if stale_ids.is_empty() {
+            self.comment_status_message = Some("No stale comments to resolve.".to_string());
+            cx.notify();
+            return;
+        }
+
+        let reply = actor.call({
+            let stale_ids = stale_ids.clone();
+            move |store| {
+                store.mark_many_comment_status(
+                    &stale_ids,
+                    CommentStatus::Resolved,
+                    None,
+                    now_unix_ms(),
+                )
+            }
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| {
+                let Some(resolved) =
+                    this.finish_batch_comment_write(result, &stale_ids, "resolve stale", cx)
+                else {
+                    return;
+                };
+                this.comment_status_message = Some(format!("Resolved {resolved} stale comments."));
+            },
+            cx,
+        );
+    }
+
+    pub(super) fn reopen_all_stale_comments(&mut self, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+        let stale_ids = self.comment_ids_with_status(CommentStatus::Stale);
+        if stale_ids.is_empty() {
+            self.comment_status_message = Some("No stale comments to reopen.".to_string());
+            cx.notify();
+            return;
+        }
+
+        let reply = actor.call({
+            let stale_ids = stale_ids.clone();
+            move |store| {
+                store.mark_many_comment_status(&stale_ids, CommentStatus::Open, None, now_unix_ms())
+            }
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| {
+                let Some(reopened) =
+                    this.finish_batch_comment_write(result, &stale_ids, "reopen stale", cx)
+                else {
+                    return;
+                };
+                this.comment_status_message = Some(format!("Reopened {reopened} stale comments."));
+            },
+            cx,
+        );
+    }
+
+    pub(super) fn delete_all_resolved_comments(&mut self, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+        let resolved_ids = self.comment_ids_with_status(CommentStatus::Resolved);
+        if resolved_ids.is_empty() {
+            self.comment_status_message = Some("No resolved comments to delete.".to_string());
+            cx.notify();
+            return;
+        }
+
+        let reply = actor.call({
+            let resolved_ids = resolved_ids.clone();
+            move |store| store.delete_many_comments(&resolved_ids)
+        });
+        Self::finish_comment_write(
+            reply,
+            move |this, result, cx| {
+                let Some(deleted) =
+                    this.finish_batch_comment_write(result, &resolved_ids, "delete resolved", cx)
+                else {
+                    return;
+                };
+                this.comment_status_message = Some(format!("Deleted {deleted} resolved comments."));
+            },
+            cx,
+        );
+    }
+
+    fn comment_ids_with_status(&self, status: CommentStatus) -> Vec<String> {
+        self.comments_cache
+            .iter()
+            .filter(|comment| comment.status == status)
+            .map(|comment| comment.id.clone())
+            .collect()
+    }
+
+    /// Shared tail of the batch actions: forgets the ids' miss streaks and reloads. Returns how
+    /// many rows changed, or `None` after reporting that another instance held the database.
+    fn finish_batch_comment_write(
+        &mut self,
+        result: Result<usize>,
+        ids: &[String],
+        action: &str,
+        cx: &mut Context<Self>,
+    ) -> Option<usize> {
+        let updated = match result {
+            Ok(updated) => {
+                for id in ids {
+                    self.comment_miss_streaks.remove(id.as_str());
+                }
+                updated
+            }
+            Err(err) => {
+                error!("failed to {action} comments in batch: {err:#}");
+                if is_comment_database_locked(&err) {
+                    self.comment_status_message = Some(tr("comment-database-locked"));
+                    return None;
+                }
+                0
+            }
+        };
+        self.reload_comments_cache(false, cx);
+        Some(updated)
+    }
+}
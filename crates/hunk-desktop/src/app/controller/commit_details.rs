@@ -0,0 +1,55 @@
+impl DiffViewer {
+    /// Opens the commit inspector and loads the commit's metadata and patches.
+    pub(super) fn open_commit_details(&mut self, commit_id: String, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+        self.commit_details_panel = Some(CommitDetailsPanelState {
+            commit_id: commit_id.clone(),
+            loading: true,
+            details: None,
+            error_message: None,
+        });
+        cx.notify();
+
+        self.commit_details_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let commit_id = commit_id.clone();
+                    async move { load_commit_details(repo_root.as_path(), &commit_id) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.commit_details_panel.as_mut() else {
+                        return;
+                    };
+                    if panel.commit_id != commit_id {
+                        return;
+                    }
+                    panel.loading = false;
+                    match result {
+                        Ok(details) => panel.details = Some(details),
+                        Err(err) => {
+                            error!("loading details of commit {commit_id} failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn close_commit_details(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.commit_details_panel.take().is_some() {
+            self.commit_details_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+}
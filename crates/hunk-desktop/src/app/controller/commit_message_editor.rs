@@ -0,0 +1,228 @@
+impl CommitMessageEditorState {
+    /// Reassembles the message from the editor fields. Reviewed-by accepts several reviewers
+    /// separated by commas, one trailer each.
+    pub(super) fn structured_message(&self, cx: &App) -> StructuredCommitMessage {
+        let mut message = StructuredCommitMessage {
+            subject: self.subject_input_state.read(cx).value().to_string(),
+            body: self.body_input_state.read(cx).value().to_string(),
+            trailers: self.trailers.clone(),
+        };
+        let reviewed_by = self.reviewed_by_input_state.read(cx).value().to_string();
+        message.set_trailer_values(REVIEWED_BY_TRAILER, reviewed_by.split(','));
+        let change_id = self.change_id_input_state.read(cx).value().to_string();
+        message.set_trailer_values(CHANGE_ID_TRAILER, [change_id.as_str()]);
+        message
+    }
+}
+
+impl DiffViewer {
+    pub(super) fn open_commit_message_editor(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        if self.commit_message_editor.is_some() {
+            return;
+        }
+
+        let subject_input_state = git_recovery_input("", "Summarize the change", window, cx);
+        let body_input_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line(true)
+                .rows(6)
+                .placeholder("Explain what changed and why")
+        });
+        let reviewed_by_input_state =
+            git_recovery_input("", "Name <email>, Name <email>", window, cx);
+        let change_id_input_state =
+            git_recovery_input("", "I followed by 40 hex characters", window, cx);
+        let _subscriptions = [
+            &subject_input_state,
+            &body_input_state,
+            &reviewed_by_input_state,
+            &change_id_input_state,
+        ]
+        .into_iter()
+        .map(|input_state| {
+            cx.subscribe(input_state, |_, _, event, cx| {
+                if matches!(event, InputEvent::Change) {
+                    cx.notify();
+                }
+            })
+        })
+        .collect();
+        self.commit_message_editor = Some(CommitMessageEditorState {
+            commit_id: None,
+            subject_input_state,
+            body_input_state,
+            reviewed_by_input_state,
+            change_id_input_state,
+            trailers: Vec::new(),
+            published_upstream: None,
+            saving: false,
+            error_message: None,
+            _subscriptions,
+        });
+        cx.notify();
+
+        self.commit_message_editor_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { load_head_commit_message(repo_root.as_path()) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.commit_message_editor.is_none() {
+                        return;
+                    }
+                    match result {
+                        Ok(loaded) => this.defer_fill_commit_message_editor(loaded, cx),
+                        Err(err) => {
+                            error!("loading the last commit message failed: {err:#}");
+                            if let Some(editor) = this.commit_message_editor.as_mut() {
+                                editor.error_message = Some(format!("{err:#}"));
+                            }
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Input values can only be set with a window, which the background load does not have.
+    fn defer_fill_commit_message_editor(&self, loaded: HeadCommitMessage, cx: &mut Context<Self>) {
+        let window_handle = self.window_handle;
+        let view = cx.entity();
+        cx.defer(move |cx| {
+            let result = cx.update_window(window_handle, |_, window, cx| {
+                view.update(cx, |this, cx| {
+                    this.fill_commit_message_editor(loaded, window, cx);
+                });
+            });
+            if let Err(err) = result {
+                error!("failed to fill commit message editor: {err:#}");
+            }
+        });
+    }
+
+    fn fill_commit_message_editor(
+        &mut self,
+        loaded: HeadCommitMessage,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(editor) = self.commit_message_editor.as_mut() else {
+            return;
+        };
+        let message = StructuredCommitMessage::parse(loaded.message.as_str());
+        let reviewed_by = message.trailer_values(REVIEWED_BY_TRAILER).join(", ");
+        let change_id = message.trailer_values(CHANGE_ID_TRAILER).join(" ");
+        let fields = [
+            (&editor.subject_input_state, message.subject.clone()),
+            (&editor.body_input_state, message.body.clone()),
+            (&editor.reviewed_by_input_state, reviewed_by),
+            (&editor.change_id_input_state, change_id),
+        ];
+        for (input_state, value) in fields {
+            input_state.update(cx, |state, cx| state.set_value(value, window, cx));
+        }
+        editor
+            .subject_input_state
+            .update(cx, |state, cx| state.focus(window, cx));
+        editor.commit_id = Some(loaded.commit_id);
+        editor.trailers = message.trailers;
+        editor.published_upstream = loaded.published_upstream;
+        cx.notify();
+    }
+
+    pub(super) fn close_commit_message_editor(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .commit_message_editor
+            .as_ref()
+            .is_some_and(|editor| !editor.saving)
+        {
+            self.commit_message_editor = None;
+            self.commit_message_editor_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn submit_commit_message_editor(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        let Some(editor) = self.commit_message_editor.as_ref() else {
+            return;
+        };
+        if editor.saving || editor.commit_id.is_none() {
+            return;
+        }
+        if self.block_in_read_only("Reword commit", cx) {
+            return;
+        }
+
+        let message = editor.structured_message(cx);
+        let validation_error = if let Some(upstream) = editor.published_upstream.as_ref() {
+            Some(format!(
+                "The last commit is already on '{upstream}'. Rewording it would rewrite pushed history."
+            ))
+        } else {
+            message
+                .validate()
+                .into_iter()
+                .find(|problem| problem.is_blocking())
+                .map(|problem| format!("Fix the message first: {problem}."))
+        };
+        let Some(editor) = self.commit_message_editor.as_mut() else {
+            return;
+        };
+        if let Some(message) = validation_error {
+            editor.error_message = Some(message);
+            cx.notify();
+            return;
+        }
+        editor.saving = true;
+        editor.error_message = None;
+        cx.notify();
+
+        let message = message.to_message();
+        self.commit_message_editor_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { reword_head_commit(repo_root.as_path(), message.as_str()) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(commit) => {
+                            this.commit_message_editor = None;
+                            let message = format!("Reworded last commit: {}", commit.subject);
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_git_workspace_refresh(true, cx);
+                        }
+                        Err(err) => {
+                            error!("rewording the last commit failed: {err:#}");
+                            if let Some(editor) = this.commit_message_editor.as_mut() {
+                                editor.saving = false;
+                                editor.error_message = Some(format!("{err:#}"));
+                            }
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -9,6 +9,36 @@ impl DiffViewer {
         cx.notify();
     }
 
+    pub(super) fn open_file_path_actions_menu(
+        &mut self,
+        path: String,
+        position: Point<gpui::Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        let line = self.cursor_line_for_path(path.as_str());
+        let exists_on_disk = self.repo_file_exists_on_disk(path.as_str());
+        let can_collapse_hunks = self
+            .review_hunk_collapse_keys(path.as_str())
+            .into_iter()
+            .any(|key| !self.hunk_folds.is_collapsed(path.as_str(), key));
+        let can_expand_hunks = self
+            .hunk_folds
+            .folds_rows(path.as_str(), &self.loaded_review_hunks(path.as_str()));
+        let can_convert_to_utf8 = self.review_file_can_convert_to_utf8(path.as_str());
+        self.open_workspace_text_context_menu(
+            WorkspaceTextContextMenuTarget::FilePath(FilePathContextMenuTarget {
+                path,
+                line,
+                exists_on_disk,
+                can_collapse_hunks,
+                can_expand_hunks,
+                can_convert_to_utf8,
+            }),
+            position,
+            cx,
+        );
+    }
+
     pub(super) fn close_workspace_text_context_menu(&mut self, cx: &mut Context<Self>) {
         if self.workspace_text_context_menu.take().is_some() {
             cx.notify();
@@ -60,6 +90,10 @@ impl DiffViewer {
                 };
                 cx.write_to_clipboard(ClipboardItem::new_string(selection_text));
             }
+            WorkspaceTextContextMenuTarget::FilePath(target) => {
+                let path = target.path.clone();
+                self.copy_file_path(path.as_str(), FilePathCopyFormat::RelativePath, cx);
+            }
         }
         self.close_workspace_text_context_menu(cx);
     }
@@ -111,7 +145,8 @@ impl DiffViewer {
                 }
             }
             WorkspaceTextContextMenuTarget::SelectableText(_)
-            | WorkspaceTextContextMenuTarget::DiffRows(_) => return,
+            | WorkspaceTextContextMenuTarget::DiffRows(_)
+            | WorkspaceTextContextMenuTarget::FilePath(_) => return,
         }
         self.close_workspace_text_context_menu(cx);
         cx.notify();
@@ -156,6 +191,7 @@ impl DiffViewer {
             WorkspaceTextContextMenuTarget::DiffRows(_) => {
                 self.select_all_rows(cx);
             }
+            WorkspaceTextContextMenuTarget::FilePath(_) => return,
         }
         self.close_workspace_text_context_menu(cx);
     }
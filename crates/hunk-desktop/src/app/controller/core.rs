@@ -1,5 +1,7 @@
 include!("core_bootstrap.rs");
 
+include!("core_workflow_cache.rs");
+
 include!("core_workspace_projects.rs");
 
 include!("core_workspace_targets.rs");
@@ -26,6 +26,16 @@ impl DiffViewer {
         }
     }
 
+    fn load_ticket_linker(config: &hunk_domain::config::TicketLinksConfig) -> Option<TicketLinker> {
+        match TicketLinker::new(config.patterns.as_slice(), config.url_template.as_str()) {
+            Ok(linker) => Some(linker),
+            Err(err) => {
+                error!("failed to load ticket link patterns: {err:#}");
+                None
+            }
+        }
+    }
+
     fn load_app_state() -> (Option<AppStateStore>, AppState) {
         let store = match AppStateStore::new() {
             Ok(store) => store,
@@ -53,6 +63,35 @@ impl DiffViewer {
         Theme::change(mode, Some(window), cx);
     }
 
+    /// Applies the configured fonts to both themes and re-measures the code font so the diff
+    /// gutters follow its glyph width.
+    fn apply_font_preferences(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        crate::app::theme::apply_hunk_fonts(&self.config.fonts, cx);
+        self.apply_theme_preference(window, cx);
+
+        let text_system = window.text_system();
+        let font_id = text_system.resolve_font(&gpui::font(cx.theme().mono_font_family.clone()));
+        match text_system.advance(font_id, px(self.config.fonts.mono_font_size), '0') {
+            Ok(advance) => self.review_surface.diff_mono_char_width = f32::from(advance.width),
+            Err(err) => warn!("failed to measure the diff code font: {err:#}"),
+        }
+        self.recompute_diff_layout();
+        cx.notify();
+    }
+
+    /// Review code text size with the diff text zoom applied.
+    pub(super) fn diff_code_font_size(&self) -> Pixels {
+        px(self.config.fonts.mono_font_size * self.review_surface.diff_text_zoom)
+    }
+
+    pub(super) fn code_font_features(&self) -> gpui::FontFeatures {
+        if self.config.fonts.mono_ligatures {
+            gpui::FontFeatures::default()
+        } else {
+            gpui::FontFeatures::disable_ligatures()
+        }
+    }
+
     fn persist_config(&self) {
         let Some(store) = &self.config_store else {
             return;
@@ -102,175 +141,6 @@ impl DiffViewer {
             .map(|path| path.to_string_lossy().to_string())
     }
 
-    fn workflow_cache_unix_time() -> i64 {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|duration| duration.as_secs() as i64)
-            .unwrap_or(0)
-    }
-
-    fn file_status_from_cache_tag(status_tag: &str) -> FileStatus {
-        match status_tag {
-            "A" => FileStatus::Added,
-            "M" => FileStatus::Modified,
-            "D" => FileStatus::Deleted,
-            "R" => FileStatus::Renamed,
-            "U" => FileStatus::Untracked,
-            "T" => FileStatus::TypeChange,
-            "!" => FileStatus::Conflicted,
-            _ => FileStatus::Unknown,
-        }
-    }
-
-    fn hydrate_workflow_cache_if_available(&mut self, cx: &mut Context<Self>) {
-        let Some(expected_root) = self
-            .project_path
-            .clone()
-            .or_else(|| self.state.active_project_path().cloned())
-        else {
-            return;
-        };
-        let cache_key = expected_root.to_string_lossy().to_string();
-        let Some(cache) = self.state.git_workflow_cache_by_repo.get(cache_key.as_str()).cloned()
-        else {
-            return;
-        };
-        let Some(root) = cache.root.clone() else {
-            return;
-        };
-        let cached_project_root =
-            hunk_git::worktree::primary_repo_root(root.as_path()).unwrap_or_else(|_| root.clone());
-        if cached_project_root != expected_root {
-            return;
-        }
-
-        let previous_ai_workspace_key = self
-            .ai_worker_workspace_key
-            .clone()
-            .or_else(|| self.ai_workspace_key());
-        self.sync_ai_visible_composer_prompt_to_draft(cx);
-        self.project_path = Some(cached_project_root);
-        self.repo_root = Some(root.clone());
-        self.active_workspace_target_id = self.persisted_workspace_target_id();
-        self.ai_handle_workspace_change(previous_ai_workspace_key, cx);
-        self.branch_name = if cache.branch_name.is_empty() {
-            "unknown".to_string()
-        } else {
-            cache.branch_name
-        };
-        self.branch_has_upstream = cache.branch_has_upstream;
-        self.branch_ahead_count = cache.branch_ahead_count;
-        self.branch_behind_count = cache.branch_behind_count;
-        self.branches = cache
-            .branches
-            .into_iter()
-            .map(|branch| LocalBranch {
-                name: branch.name,
-                is_current: branch.is_current,
-                tip_unix_time: branch.tip_unix_time,
-                attached_workspace_target_id: branch.attached_workspace_target_id,
-                attached_workspace_target_root: branch.attached_workspace_target_root,
-                attached_workspace_target_label: branch.attached_workspace_target_label,
-            })
-            .collect();
-        self.files = cache
-            .files
-            .into_iter()
-            .map(|file| ChangedFile {
-                path: file.path,
-                status: Self::file_status_from_cache_tag(file.status_tag.as_str()),
-                staged: file.staged,
-                unstaged: file.unstaged,
-                untracked: file.untracked,
-            })
-            .collect();
-        self.file_status_by_path = self
-            .files
-            .iter()
-            .map(|file| (file.path.clone(), file.status))
-            .collect();
-        self.last_commit_subject = cache.last_commit_subject;
-        self.selected_path = self
-            .selected_path
-            .clone()
-            .filter(|selected| self.files.iter().any(|file| &file.path == selected))
-            .or_else(|| self.files.first().map(|file| file.path.clone()));
-        self.selected_status = self
-            .selected_path
-            .as_deref()
-            .and_then(|selected| self.status_for_path(selected));
-        self.sync_ai_worktree_base_branch_from_repo();
-        self.sync_branch_picker_state(cx);
-        self.sync_ai_worktree_base_branch_picker_state(cx);
-        self.refresh_workspace_targets_from_git_state(cx);
-        self.sync_git_workspace_with_primary_state();
-        self.repo_discovery_failed = false;
-        self.error_message = None;
-        debug!(
-            "hydrated git workflow cache for {} (files={} branches={})",
-            root.display(),
-            self.files.len(),
-            self.branches.len(),
-        );
-        cx.notify();
-    }
-
-    fn persist_workflow_cache(&mut self) {
-        let Some(root) = self.repo_root.clone() else {
-            return;
-        };
-        let Some(cache_key) = self.current_workspace_project_key() else {
-            return;
-        };
-
-        let mut cache = CachedWorkflowState {
-            root: Some(root),
-            branch_name: self.branch_name.clone(),
-            branch_has_upstream: self.branch_has_upstream,
-            branch_ahead_count: self.branch_ahead_count,
-            branch_behind_count: self.branch_behind_count,
-            branches: self
-                .branches
-                .iter()
-                .map(|branch| CachedLocalBranchState {
-                    name: branch.name.clone(),
-                    is_current: branch.is_current,
-                    tip_unix_time: branch.tip_unix_time,
-                    attached_workspace_target_id: branch.attached_workspace_target_id.clone(),
-                    attached_workspace_target_root: branch.attached_workspace_target_root.clone(),
-                    attached_workspace_target_label: branch.attached_workspace_target_label.clone(),
-                })
-                .collect(),
-            files: self
-                .files
-                .iter()
-                .map(|file| CachedChangedFileState {
-                    path: file.path.clone(),
-                    status_tag: file.status.tag().to_string(),
-                    staged: file.staged,
-                    unstaged: file.unstaged,
-                    untracked: file.untracked,
-                })
-                .collect(),
-            last_commit_subject: self.last_commit_subject.clone(),
-            cached_unix_time: 0,
-        };
-
-        if let Some(previous) = self.state.git_workflow_cache_by_repo.get(cache_key.as_str()) {
-            let mut previous_without_time = previous.clone();
-            previous_without_time.cached_unix_time = 0;
-            if previous_without_time == cache {
-                return;
-            }
-        }
-
-        cache.cached_unix_time = Self::workflow_cache_unix_time();
-        self.state
-            .git_workflow_cache_by_repo
-            .insert(cache_key, cache);
-        self.persist_state();
-    }
-
     fn sync_theme_with_system_if_needed(&self, window: &mut Window, cx: &mut Context<Self>) {
         if self.config.theme != ThemePreference::System {
             return;
@@ -278,11 +148,19 @@ impl DiffViewer {
         self.apply_theme_preference(window, cx);
     }
 
-    pub(super) fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+    pub(super) fn new(launch: LaunchOptions, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let (config_store, config) = Self::load_app_config();
+        crate::app::l10n::set_language(config.language);
+        let mut hunk_folds = HunkFolds::default();
+        hunk_folds.set_large_hunk_threshold(config.large_hunk_fold_lines);
+        let ticket_linker = Self::load_ticket_linker(&config.ticket_links);
         let (state_store, mut state) = Self::load_app_state();
         let preferred_ai_session = hunk_domain::state::AiThreadSessionState::preferred_defaults();
         let database_store = Self::load_database_store();
+        let database_actor = database_store.clone().and_then(Self::spawn_database_actor);
+        let snapshot_cache_store = Self::load_snapshot_cache_store();
+        let editor_recovery_store = Self::load_editor_recovery_store();
+        let previous_crash_report = Self::load_previous_crash_report();
         state.normalize_workspace_state();
         let initial_project_path = state.active_project_path().cloned();
         let initial_ai_workspace_key = initial_project_path
@@ -298,6 +176,11 @@ impl DiffViewer {
             .and_then(|workspace| state.ai_workspace_include_hidden_models.get(workspace))
             .copied()
             .unwrap_or(true);
+        let tree_restore_dock = if state.workspace_layout.tree.is_docked() {
+            state.workspace_layout.tree
+        } else {
+            PanelDock::Left
+        };
         let branch_picker_state = cx.new(|cx| {
             HunkPickerState::new(
                 BranchPickerDelegate::default(),
@@ -367,6 +250,9 @@ impl DiffViewer {
                 .rows(3)
                 .placeholder("Add comment for this diff row")
         });
+        let co_review_join_input_state = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("192.168.1.20:47321#CODE")
+        });
         let ai_composer_file_completion_provider =
             Rc::new(AiComposerFileCompletionProvider::default());
         let ai_composer_input_state = cx.new(|cx| {
@@ -390,21 +276,80 @@ impl DiffViewer {
         let mut view = Self {
             config_store,
             config,
+            ticket_linker,
             settings_draft: None,
+            diagnostics_loading: false,
+            git_recovery_prompt: None,
+            repo_clone_prompt: None,
+            hunk_apply_prompt: None,
+            hunk_apply_task: Task::ready(()),
+            commit_message_editor: None,
+            commit_message_editor_task: Task::ready(()),
+            repo_clone_task: Task::ready(()),
+            repo_issues_panel: None,
+            repo_issues_task: Task::ready(()),
+            ci_log_panel: None,
+            repo_content_search_panel: None,
+            operation_diff_panel: None,
+            operation_diff_task: Task::ready(()),
+            operation_history_panel: None,
+            operation_history_task: Task::ready(()),
+            stack_editor_panel: None,
+            stack_editor_task: Task::ready(()),
+            previous_crash_report,
+            crash_report_viewer_open: false,
+            usage_metrics_task: Task::ready(()),
+            maintenance_task: Task::ready(()),
+            git_config_save_task: Task::ready(()),
+            comment_prune_task: Task::ready(()),
+            sparse_checkout_task: Task::ready(()),
+            code_owners: None,
+            code_owners_task: Task::ready(()),
+            head_branch_names: Vec::new(),
+            head_branches_task: Task::ready(()),
+            comment_rename_task: Task::ready(()),
+            launch_read_only: launch.read_only,
+            review_export_task: Task::ready(()),
             state_store,
             state,
             database_store,
+            database_actor,
+            snapshot_cache_store,
+            snapshot_cache_task: Task::ready(()),
+            review_snapshot_cache_state_id: None,
+            editor_recovery_store,
+            review_mechanical_changes_task: Task::ready(()),
+            review_hunk_stage_task: Task::ready(()),
             window_handle: window.window_handle(),
+            detached_diff_window: None,
             comments_cache: Vec::new(),
+            comment_counts: CommentScopeCounts::default(),
             comments_preview_open: false,
             comments_show_non_open: false,
+            comments_author_filter: None,
+            comment_import_task: Task::ready(()),
+            comment_export_task: Task::ready(()),
+            github_review_task: Task::ready(()),
+            comment_import_unplaced: Vec::new(),
             comment_miss_streaks: BTreeMap::new(),
             comment_row_matches: BTreeMap::new(),
             comment_open_row_counts: Vec::new(),
             hovered_comment_row: None,
             active_comment_editor_row: None,
+            comment_save_in_flight: false,
             comment_input_state,
             comment_status_message: None,
+            comments_watch_revision: None,
+            comments_watch_task: Task::ready(()),
+            comments_load_task: Task::ready(()),
+            co_review: None,
+            co_review_panel_open: false,
+            co_review_joining: false,
+            co_review_join_input_state,
+            co_review_task: Task::ready(()),
+            remote_branch_watch_head: None,
+            remote_branch_watch_task: Task::ready(()),
+            ci_watch_task: Task::ready(()),
             project_path: initial_project_path,
             repo_root: None,
             workspace_targets: Vec::new(),
@@ -418,6 +363,11 @@ impl DiffViewer {
             review_loaded_left_source_id: None,
             review_loaded_right_source_id: None,
             review_loaded_collapsed_files: BTreeSet::new(),
+            review_loaded_changed_lines_only: ChangedLinesOnlyMode::default(),
+            review_loaded_hunk_folds: HunkFolds::default(),
+            review_loaded_compare_snapshot: None,
+            review_tree_follow: true,
+            review_tree_clicked_path: None,
             branch_name: "unknown".to_string(),
             branch_has_upstream: false,
             branch_ahead_count: 0,
@@ -583,11 +533,15 @@ impl DiffViewer {
             branch_picker_state,
             branch_input_state,
             branch_input_has_text: false,
+            branch_name_policy_message: None,
             commit_input_state,
+            acknowledged_path_collisions: BTreeSet::new(),
             git_action_epoch: 0,
             git_action_task: Task::ready(()),
             git_action_loading: false,
             git_action_label: None,
+            git_action_cancel: None,
+            git_action_cancelling: false,
             workspace_target_switch_loading: false,
             git_status_message: None,
             git_workspace_refresh_epoch: 0,
@@ -599,7 +553,30 @@ impl DiffViewer {
             last_commit_subject: None,
             recent_commits: Vec::new(),
             recent_commits_error: None,
+            abandoned_commits_visible: false,
+            abandoned_commits: Vec::new(),
+            abandoned_commits_loading: false,
+            abandoned_commits_error: None,
+            abandoned_commit_restoring: None,
+            abandoned_commits_task: Task::ready(()),
+            recent_commit_files_commit_id: None,
+            recent_commit_files: Vec::new(),
+            recent_commit_files_loading: false,
+            recent_commit_files_error: None,
+            recent_commit_files_task: Task::ready(()),
+            commit_details_panel: None,
+            commit_details_task: Task::ready(()),
+            file_authorship: BTreeMap::new(),
+            file_authorship_head_commit_id: None,
+            file_authorship_loading_path: None,
+            file_authorship_task: Task::ready(()),
+            file_history_open: false,
             collapsed_files: BTreeSet::new(),
+            stats_ignored_paths: BTreeSet::new(),
+            changed_lines_only: ChangedLinesOnlyMode::default(),
+            right_to_left_files: BTreeSet::new(),
+            diff_unified_view: false,
+            hunk_folds,
             selected_path: None,
             selected_status: None,
             file_line_stats: BTreeMap::new(),
@@ -607,6 +584,12 @@ impl DiffViewer {
             review_files: Vec::new(),
             review_file_status_by_path: BTreeMap::new(),
             review_file_line_stats: BTreeMap::new(),
+            review_file_encodings: BTreeMap::new(),
+            review_extracted_text_paths: BTreeSet::new(),
+            review_mechanical_changes: ReviewMechanicalChanges::default(),
+            review_hunk_stage_states: BTreeMap::new(),
+            review_change_set_size: ReviewChangeSetSize::default(),
+            review_reviewed_directories: ReviewedDirectories::default(),
             review_overall_line_stats: LineStats::default(),
             review_compare_loading: false,
             review_compare_error: None,
@@ -617,9 +600,11 @@ impl DiffViewer {
             auto_refresh_unmodified_streak: 0,
             auto_refresh_task: Task::ready(()),
             repo_watch_task: Task::ready(()),
+            repo_watch_error: None,
             repo_watch_refresh_epoch: 0,
             repo_watch_pending_refresh: None,
             repo_watch_pending_git_workspace_refresh: false,
+            repo_watch_pending_metadata_change: false,
             repo_watch_pending_recent_commits_refresh: false,
             repo_watch_refresh_task: Task::ready(()),
             snapshot_epoch: 0,
@@ -642,6 +627,7 @@ impl DiffViewer {
             last_snapshot_fingerprint: None,
             open_project_task: Task::ready(()),
             patch_epoch: 0,
+            patch_cancel: CancellationToken::new(),
             patch_task: Task::ready(()),
             patch_loading: false,
             in_app_menu_bar,
@@ -649,6 +635,7 @@ impl DiffViewer {
             repo_tree_focus_handle: cx.focus_handle(),
             files_editor_focus_handle: cx.focus_handle(),
             drag_selecting_rows: false,
+            vim_mode: VimMode::Normal,
             scroll_selected_after_reload: true,
             last_scroll_activity_at: Instant::now(),
             segment_prefetch_epoch: 0,
@@ -661,8 +648,15 @@ impl DiffViewer {
             fps_task: Task::ready(()),
             ai_perf_metrics: RefCell::new(AiPerfMetrics::default()),
             repo_discovery_failed: false,
+            repo_init_path: None,
+            repo_init_template: RepoInitTemplate::default(),
+            repo_init_loading: false,
             error_message: None,
-            sidebar_collapsed: false,
+            tree_restore_dock,
+            focus_mode: false,
+            review_timer: ReviewTimer::default(),
+            review_timer_task: Task::ready(()),
+            window_active: false,
             repo_tree: RepoTreeState::new(),
             repo_tree_inline_edit: None,
             repo_tree_context_menu: None,
@@ -678,8 +672,10 @@ impl DiffViewer {
             file_quick_open_visible: false,
             file_quick_open_matches: Vec::new(),
             file_quick_open_selected_ix: 0,
+            last_offered_clipboard_text: None,
             editor_path: None,
             editor_loading: false,
+            pending_editor_caret_line: None,
             editor_error: None,
             editor_dirty: false,
             editor_last_saved_text: None,
@@ -693,14 +689,21 @@ impl DiffViewer {
             editor_markdown_preview_loading: false,
             editor_markdown_preview_revision: 0,
             editor_markdown_preview: false,
+            editor_line_changes_task: Task::ready(()),
+            editor_line_changes_scheduled: None,
+            editor_recovery_task: Task::ready(()),
+            editor_recovery_scheduled: false,
+            editor_recovery_root: None,
+            editor_recovery_prompt: None,
             editor_search_visible: false,
         };
 
         let branch_input_state = view.branch_input_state.clone();
         cx.subscribe(&branch_input_state, |this, _, event, cx| {
             if matches!(event, InputEvent::Change) {
-                this.branch_input_has_text =
-                    !this.branch_input_state.read(cx).value().trim().is_empty();
+                let value = this.branch_input_state.read(cx).value().to_string();
+                this.branch_input_has_text = !value.trim().is_empty();
+                this.branch_name_policy_message = this.branch_name_policy_message(value.as_str());
                 cx.notify();
             }
         })
@@ -888,13 +891,22 @@ impl DiffViewer {
         view.update_project_picker_state(window, cx);
         view.update_workspace_target_picker_state(window, cx);
         view.update_review_compare_picker_states(window, cx);
-        view.apply_theme_preference(window, cx);
+        view.apply_font_preferences(window, cx);
         cx.observe_window_appearance(window, |this, window, cx| {
             this.sync_theme_with_system_if_needed(window, cx);
         })
         .detach();
+        cx.observe_window_activation(window, |this, window, cx| {
+            this.window_active = window.is_window_active();
+            this.sync_review_timer(cx);
+            this.offer_clipboard_path_on_activation(window, cx);
+        })
+        .detach();
+        view.window_active = window.is_window_active();
+        view.sync_review_timer(cx);
 
         view.hydrate_workflow_cache_if_available(cx);
+        view.hydrate_review_snapshot_cache_if_available(cx);
         view.hydrate_recent_commits_cache_if_available(cx);
         view.restore_active_workspace_target_root_from_state(cx);
         view.request_snapshot_refresh(cx);
@@ -905,8 +917,10 @@ impl DiffViewer {
         view.start_repo_watch(cx);
         view.start_fps_monitor(cx);
         view.rebuild_ai_thread_sidebar_state();
-        view.prune_expired_comments();
-        view.refresh_comments_cache_from_store();
+        view.reload_comments_cache(false, cx);
+        view.start_comment_prune_schedule(cx);
+        view.start_comments_watch(cx);
+        view.start_remote_branch_watch(cx);
         view
     }
 
@@ -8,6 +8,15 @@ impl DiffViewer {
         self.open_project_picker(cx);
     }
 
+    pub(super) fn clone_repository_action(
+        &mut self,
+        _: &CloneRepository,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_repo_clone(window, cx);
+    }
+
     pub(super) fn confirm_remove_workspace_project_action(
         &mut self,
         project_path: std::path::PathBuf,
@@ -464,6 +473,7 @@ impl DiffViewer {
             branch_behind_count,
             branches,
             files,
+            path_collisions,
             last_commit_subject,
             ..
         } = snapshot;
@@ -476,6 +486,7 @@ impl DiffViewer {
         self.git_workspace.branch_behind_count = branch_behind_count;
         self.git_workspace.branches = branches;
         self.git_workspace.files = files;
+        self.git_workspace.path_collisions = path_collisions;
         self.git_workspace.file_status_by_path = self
             .git_workspace
             .files
@@ -590,6 +601,7 @@ impl DiffViewer {
                             );
                             this.last_git_workspace_fingerprint = Some(fingerprint);
                             this.apply_git_workspace_snapshot(root.clone(), workflow_snapshot, file_line_stats);
+                            this.request_head_branches_reload(cx);
                             if refresh_recent_commits {
                                 this.request_recent_commits_refresh(true, cx);
                             }
@@ -43,6 +43,21 @@ impl DiffViewer {
             })
             .unwrap_or_default();
 
+        let file_code_owners = self
+            .review_workspace_session
+            .as_ref()
+            .map(|session| {
+                session
+                    .file_ranges()
+                    .iter()
+                    .filter_map(|range| {
+                        let label = self.code_owners_label_for_path(range.path.as_str())?;
+                        Some((range.path.clone(), label))
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            })
+            .unwrap_or_default();
+
         let search_highlight_columns_by_row = self
             .review_workspace_session
             .as_ref()
@@ -57,9 +72,18 @@ impl DiffViewer {
             comment_affordance_rows,
             comment_open_counts_by_row,
             active_comment_editor_row,
+            pinned_rows: self.review_pinned_rows(),
             collapsed_paths: self.collapsed_files.clone(),
             view_file_enabled_paths,
+            file_encodings: self
+                .review_file_encodings
+                .iter()
+                .map(|(path, encoding)| (path.clone(), encoding.label()))
+                .collect(),
+            extracted_text_paths: self.review_extracted_text_paths.clone(),
+            file_code_owners,
             search_highlight_columns_by_row,
+            hunk_stage_states_by_row: self.review_hunk_stage_states_by_row(),
         }
     }
 
@@ -569,10 +593,12 @@ impl DiffViewer {
 
     fn start_repo_watch(&mut self, cx: &mut Context<Self>) {
         self.repo_watch_task = Task::ready(());
+        self.repo_watch_error = None;
         self.repo_watch_refresh_task = Task::ready(());
         self.repo_watch_refresh_epoch = 0;
         self.repo_watch_pending_refresh = None;
         self.repo_watch_pending_git_workspace_refresh = false;
+        self.repo_watch_pending_metadata_change = false;
         self.repo_watch_pending_recent_commits_refresh = false;
 
         let primary_root = self.repo_root.clone().or_else(|| self.project_path.clone());
@@ -597,6 +623,7 @@ impl DiffViewer {
             Ok(watcher) => watcher,
             Err(err) => {
                 error!("failed to start file watch for {}: {err}", watch_roots_for_cb);
+                self.repo_watch_error = Some(format!("failed to start file watch: {err}"));
                 return;
             }
         };
@@ -604,6 +631,10 @@ impl DiffViewer {
         for watch_root in &watch_roots {
             if let Err(err) = watcher.watch(watch_root, notify::RecursiveMode::Recursive) {
                 error!("failed to watch repository at {}: {err}", watch_root.display());
+                self.repo_watch_error = Some(format!(
+                    "failed to watch repository at {}: {err}",
+                    watch_root.display()
+                ));
                 return;
             }
         }
@@ -704,6 +735,7 @@ impl DiffViewer {
                         if recent_commits_changed || git_workspace_recent_commits_changed {
                             this.repo_watch_pending_recent_commits_refresh = true;
                         }
+                        this.repo_watch_pending_metadata_change |= metadata_changed;
                         if !dirty_paths.is_empty() {
                             this.queue_dirty_paths(dirty_paths);
                         }
@@ -748,8 +780,16 @@ impl DiffViewer {
                         std::mem::take(&mut this.repo_watch_pending_git_workspace_refresh);
                     let refresh_recent_commits =
                         std::mem::take(&mut this.repo_watch_pending_recent_commits_refresh);
+                    let metadata_changed =
+                        std::mem::take(&mut this.repo_watch_pending_metadata_change);
                     if let Some(request) = request {
-                        this.request_snapshot_refresh_internal(request, cx);
+                        // Edits confined to the selected review file only need that file's patch.
+                        let patched_in_place = !metadata_changed
+                            && !refresh_git_workspace
+                            && this.refresh_selected_review_file_in_place(cx);
+                        if !patched_in_place {
+                            this.request_snapshot_refresh_internal(request, cx);
+                        }
                     }
                     if refresh_git_workspace {
                         this.request_git_workspace_refresh(false, cx);
@@ -763,6 +803,8 @@ impl DiffViewer {
     }
 
     fn next_patch_epoch(&mut self) -> usize {
+        self.patch_cancel.cancel();
+        self.patch_cancel = CancellationToken::new();
         self.patch_epoch = self.patch_epoch.saturating_add(1);
         self.patch_epoch
     }
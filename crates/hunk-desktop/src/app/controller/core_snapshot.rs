@@ -26,12 +26,16 @@ impl DiffViewer {
         self.sync_review_compare_picker_states(cx);
         if !restored_warm_state {
             self.hydrate_workflow_cache_if_available(cx);
+            self.hydrate_review_snapshot_cache_if_available(cx);
         }
         self.restore_active_workspace_target_root_from_state(cx);
         self.files_handle_project_change(previous_files_terminal_project_key, cx);
         self.ai_handle_workspace_change(previous_ai_workspace_key, cx);
         self.git_status_message = None;
         self.repo_discovery_failed = false;
+        self.repo_init_path = None;
+        self.close_abandoned_commits();
+        self.close_recent_commit_files();
         self.error_message = None;
         if !restored_warm_state {
             self.reset_recent_commits_state();
@@ -81,6 +85,7 @@ impl DiffViewer {
         self.review_loaded_left_source_id = None;
         self.review_loaded_right_source_id = None;
         self.review_loaded_collapsed_files.clear();
+        self.clear_loaded_review_row_filters();
         self.sync_review_compare_picker_states(cx);
         self.ai_handle_workspace_change(previous_ai_workspace_key, cx);
         self.request_ai_composer_file_completion_reload(cx);
@@ -111,6 +116,7 @@ impl DiffViewer {
         self.selected_status = None;
         self.overall_line_stats = LineStats::default();
         self.comments_cache.clear();
+        self.comment_counts = CommentScopeCounts::default();
         self.comment_miss_streaks.clear();
         self.reset_comment_row_match_cache();
         self.clear_comment_ui_state();
@@ -435,6 +441,11 @@ impl DiffViewer {
                         cold_start,
                         this.line_stats_loading
                     );
+                    this.record_usage_metric(
+                        UsageMetricKind::SnapshotLoad,
+                        Some(elapsed.as_millis() as u64),
+                        cx,
+                    );
 
                     cx.notify();
                     this.maybe_run_pending_snapshot_refresh(cx);
@@ -556,7 +567,7 @@ impl DiffViewer {
                             this.git_status_message = Some(format!(
                                 "Selected folder is not a Git repository: {err:#}"
                             ));
-                            cx.notify();
+                            this.prompt_repo_init(selected_path, cx);
                         });
                     }
                     return;
@@ -581,11 +592,13 @@ impl DiffViewer {
         let root_is_selected_workspace = self.selected_git_workspace_root().as_ref() == Some(&root);
 
         if root_is_primary {
+            let path_collisions = snapshot.path_collisions.clone();
             self.last_snapshot_fingerprint = Some(fingerprint.clone());
             self.apply_primary_git_index_snapshot(snapshot);
             if root_is_selected_workspace {
                 self.last_git_workspace_fingerprint = Some(fingerprint);
                 self.sync_git_workspace_with_primary_state();
+                self.git_workspace.path_collisions = path_collisions;
             }
             return;
         }
@@ -633,6 +646,7 @@ impl DiffViewer {
         let WorkflowSnapshot {
             working_copy_commit_id,
             files,
+            path_collisions,
             last_commit_subject,
             ..
         } = snapshot;
@@ -640,6 +654,7 @@ impl DiffViewer {
         self.git_workspace.root = Some(root);
         self.git_workspace.working_copy_commit_id = Some(working_copy_commit_id);
         self.git_workspace.files = files;
+        self.git_workspace.path_collisions = path_collisions;
         self.git_workspace.file_status_by_path = self
             .git_workspace
             .files
@@ -678,6 +693,7 @@ impl DiffViewer {
             branch_behind_count,
             branches,
             files,
+            path_collisions,
             last_commit_subject,
         } = snapshot;
 
@@ -713,6 +729,9 @@ impl DiffViewer {
         self.last_commit_subject = last_commit_subject;
         self.sync_ai_worktree_base_branch_from_repo();
         self.sync_git_workspace_with_primary_state();
+        if self.selected_git_workspace_root().as_ref() == Some(&primary_root) {
+            self.git_workspace.path_collisions = path_collisions;
+        }
         self.sync_branch_picker_state(cx);
         self.sync_ai_worktree_base_branch_picker_state(cx);
         self.refresh_workspace_targets_from_git_state(cx);
@@ -763,6 +782,10 @@ impl DiffViewer {
                 .and_then(|selected| self.status_for_path(selected));
         }
 
+        if previous_working_copy_commit_id != self.working_copy_commit_id {
+            self.refresh_editor_line_changes_for_new_head(cx);
+        }
+
         if full_refresh {
             let selected_changed = self.selected_path != previous_selected_path
                 || self.selected_status != previous_selected_status;
@@ -774,15 +797,19 @@ impl DiffViewer {
                 file_list_changed,
             );
 
-            self.refresh_comments_cache_from_store();
+            self.reload_comments_cache(false, cx);
+            self.request_code_owners_reload(cx);
+            self.request_head_branches_reload(cx);
 
             let should_reload_repo_tree = should_reload_repo_tree_after_snapshot(
                 root_changed,
-                self.workspace_view_mode.supports_sidebar_tree(),
+                self.workspace_view_mode.supports_sidebar_tree() && self.sidebar_tree_visible(),
                 file_list_changed,
             );
             if should_reload_repo_tree {
                 self.request_repo_tree_reload(cx);
+            } else if root_changed || file_list_changed {
+                self.repo_tree.reload_deferred = true;
             }
 
             self.bootstrap_files_workspace_if_needed(cx);
@@ -849,10 +876,9 @@ impl DiffViewer {
     }
 
     fn is_missing_repository_error(err: &anyhow::Error) -> bool {
-        err.chain().any(|cause| {
-            let message = cause.to_string();
-            message.contains("failed to discover git repository")
-                || message.contains("could not find repository")
-        })
+        matches!(
+            GitError::classify(err),
+            Some(GitError::NotARepository { .. })
+        )
     }
 }
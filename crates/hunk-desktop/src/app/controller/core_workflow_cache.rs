@@ -0,0 +1,170 @@
+impl DiffViewer {
+    fn workflow_cache_unix_time() -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or(0)
+    }
+
+    fn file_status_from_cache_tag(status_tag: &str) -> FileStatus {
+        match status_tag {
+            "A" => FileStatus::Added,
+            "M" => FileStatus::Modified,
+            "D" => FileStatus::Deleted,
+            "R" => FileStatus::Renamed,
+            "U" => FileStatus::Untracked,
+            "T" => FileStatus::TypeChange,
+            "!" => FileStatus::Conflicted,
+            _ => FileStatus::Unknown,
+        }
+    }
+
+    fn hydrate_workflow_cache_if_available(&mut self, cx: &mut Context<Self>) {
+        let Some(expected_root) = self
+            .project_path
+            .clone()
+            .or_else(|| self.state.active_project_path().cloned())
+        else {
+            return;
+        };
+        let cache_key = expected_root.to_string_lossy().to_string();
+        let Some(cache) = self.state.git_workflow_cache_by_repo.get(cache_key.as_str()).cloned()
+        else {
+            return;
+        };
+        let Some(root) = cache.root.clone() else {
+            return;
+        };
+        let cached_project_root =
+            hunk_git::worktree::primary_repo_root(root.as_path()).unwrap_or_else(|_| root.clone());
+        if cached_project_root != expected_root {
+            return;
+        }
+
+        let previous_ai_workspace_key = self
+            .ai_worker_workspace_key
+            .clone()
+            .or_else(|| self.ai_workspace_key());
+        self.sync_ai_visible_composer_prompt_to_draft(cx);
+        self.project_path = Some(cached_project_root);
+        self.repo_root = Some(root.clone());
+        self.active_workspace_target_id = self.persisted_workspace_target_id();
+        self.ai_handle_workspace_change(previous_ai_workspace_key, cx);
+        self.branch_name = if cache.branch_name.is_empty() {
+            "unknown".to_string()
+        } else {
+            cache.branch_name
+        };
+        self.branch_has_upstream = cache.branch_has_upstream;
+        self.branch_ahead_count = cache.branch_ahead_count;
+        self.branch_behind_count = cache.branch_behind_count;
+        self.branches = cache
+            .branches
+            .into_iter()
+            .map(|branch| LocalBranch {
+                name: branch.name,
+                is_current: branch.is_current,
+                tip_unix_time: branch.tip_unix_time,
+                attached_workspace_target_id: branch.attached_workspace_target_id,
+                attached_workspace_target_root: branch.attached_workspace_target_root,
+                attached_workspace_target_label: branch.attached_workspace_target_label,
+            })
+            .collect();
+        self.files = cache
+            .files
+            .into_iter()
+            .map(|file| ChangedFile {
+                path: file.path,
+                status: Self::file_status_from_cache_tag(file.status_tag.as_str()),
+                staged: file.staged,
+                unstaged: file.unstaged,
+                untracked: file.untracked,
+            })
+            .collect();
+        self.file_status_by_path = self
+            .files
+            .iter()
+            .map(|file| (file.path.clone(), file.status))
+            .collect();
+        self.last_commit_subject = cache.last_commit_subject;
+        self.selected_path = self
+            .selected_path
+            .clone()
+            .filter(|selected| self.files.iter().any(|file| &file.path == selected))
+            .or_else(|| self.files.first().map(|file| file.path.clone()));
+        self.selected_status = self
+            .selected_path
+            .as_deref()
+            .and_then(|selected| self.status_for_path(selected));
+        self.sync_ai_worktree_base_branch_from_repo();
+        self.sync_branch_picker_state(cx);
+        self.sync_ai_worktree_base_branch_picker_state(cx);
+        self.refresh_workspace_targets_from_git_state(cx);
+        self.sync_git_workspace_with_primary_state();
+        self.repo_discovery_failed = false;
+        self.error_message = None;
+        debug!(
+            "hydrated git workflow cache for {} (files={} branches={})",
+            root.display(),
+            self.files.len(),
+            self.branches.len(),
+        );
+        cx.notify();
+    }
+
+    fn persist_workflow_cache(&mut self) {
+        let Some(root) = self.repo_root.clone() else {
+            return;
+        };
+        let Some(cache_key) = self.current_workspace_project_key() else {
+            return;
+        };
+
+        let mut cache = CachedWorkflowState {
+            root: Some(root),
+            branch_name: self.branch_name.clone(),
+            branch_has_upstream: self.branch_has_upstream,
+            branch_ahead_count: self.branch_ahead_count,
+            branch_behind_count: self.branch_behind_count,
+            branches: self
+                .branches
+                .iter()
+                .map(|branch| CachedLocalBranchState {
+                    name: branch.name.clone(),
+                    is_current: branch.is_current,
+                    tip_unix_time: branch.tip_unix_time,
+                    attached_workspace_target_id: branch.attached_workspace_target_id.clone(),
+                    attached_workspace_target_root: branch.attached_workspace_target_root.clone(),
+                    attached_workspace_target_label: branch.attached_workspace_target_label.clone(),
+                })
+                .collect(),
+            files: self
+                .files
+                .iter()
+                .map(|file| CachedChangedFileState {
+                    path: file.path.clone(),
+                    status_tag: file.status.tag().to_string(),
+                    staged: file.staged,
+                    unstaged: file.unstaged,
+                    untracked: file.untracked,
+                })
+                .collect(),
+            last_commit_subject: self.last_commit_subject.clone(),
+            cached_unix_time: 0,
+        };
+
+        if let Some(previous) = self.state.git_workflow_cache_by_repo.get(cache_key.as_str()) {
+            let mut previous_without_time = previous.clone();
+            previous_without_time.cached_unix_time = 0;
+            if previous_without_time == cache {
+                return;
+            }
+        }
+
+        cache.cached_unix_time = Self::workflow_cache_unix_time();
+        self.state
+            .git_workflow_cache_by_repo
+            .insert(cache_key, cache);
+        self.persist_state();
+    }
+}
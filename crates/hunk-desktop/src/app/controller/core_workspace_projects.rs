@@ -13,6 +13,9 @@ impl DiffViewer {
             review_loaded_left_source_id: None,
             review_loaded_right_source_id: None,
             review_loaded_collapsed_files: BTreeSet::new(),
+            review_loaded_changed_lines_only: ChangedLinesOnlyMode::default(),
+            review_loaded_hunk_folds: HunkFolds::default(),
+            review_loaded_compare_snapshot: None,
             branch_name: "unknown".to_string(),
             branch_has_upstream: false,
             branch_ahead_count: 0,
@@ -27,6 +30,7 @@ impl DiffViewer {
             recent_commits: Vec::new(),
             recent_commits_error: None,
             collapsed_files: BTreeSet::new(),
+            stats_ignored_paths: BTreeSet::new(),
             selected_path: None,
             selected_status: None,
             file_line_stats: BTreeMap::new(),
@@ -34,6 +38,12 @@ impl DiffViewer {
             review_files: Vec::new(),
             review_file_status_by_path: BTreeMap::new(),
             review_file_line_stats: BTreeMap::new(),
+            review_file_encodings: BTreeMap::new(),
+            review_extracted_text_paths: BTreeSet::new(),
+            review_mechanical_changes: ReviewMechanicalChanges::default(),
+            review_hunk_stage_states: BTreeMap::new(),
+            review_change_set_size: ReviewChangeSetSize::default(),
+            review_reviewed_directories: ReviewedDirectories::default(),
             review_overall_line_stats: LineStats::default(),
             review_compare_loading: false,
             review_compare_error: None,
@@ -117,6 +127,11 @@ impl DiffViewer {
             review_loaded_left_source_id: self.review_loaded_left_source_id.take(),
             review_loaded_right_source_id: self.review_loaded_right_source_id.take(),
             review_loaded_collapsed_files: std::mem::take(&mut self.review_loaded_collapsed_files),
+            review_loaded_changed_lines_only: std::mem::take(
+                &mut self.review_loaded_changed_lines_only,
+            ),
+            review_loaded_hunk_folds: std::mem::take(&mut self.review_loaded_hunk_folds),
+            review_loaded_compare_snapshot: self.review_loaded_compare_snapshot.take(),
             branch_name: std::mem::take(&mut self.branch_name),
             branch_has_upstream: self.branch_has_upstream,
             branch_ahead_count: self.branch_ahead_count,
@@ -131,6 +146,7 @@ impl DiffViewer {
             recent_commits: std::mem::take(&mut self.recent_commits),
             recent_commits_error: self.recent_commits_error.take(),
             collapsed_files: std::mem::take(&mut self.collapsed_files),
+            stats_ignored_paths: std::mem::take(&mut self.stats_ignored_paths),
             selected_path: self.selected_path.take(),
             selected_status: self.selected_status.take(),
             file_line_stats: std::mem::take(&mut self.file_line_stats),
@@ -141,6 +157,12 @@ impl DiffViewer {
             review_files: std::mem::take(&mut self.review_files),
             review_file_status_by_path: std::mem::take(&mut self.review_file_status_by_path),
             review_file_line_stats: std::mem::take(&mut self.review_file_line_stats),
+            review_file_encodings: std::mem::take(&mut self.review_file_encodings),
+            review_extracted_text_paths: std::mem::take(&mut self.review_extracted_text_paths),
+            review_mechanical_changes: std::mem::take(&mut self.review_mechanical_changes),
+            review_hunk_stage_states: std::mem::take(&mut self.review_hunk_stage_states),
+            review_change_set_size: std::mem::take(&mut self.review_change_set_size),
+            review_reviewed_directories: std::mem::take(&mut self.review_reviewed_directories),
             review_overall_line_stats: self.review_overall_line_stats,
             review_compare_loading: self.review_compare_loading,
             review_compare_error: self.review_compare_error.take(),
@@ -203,6 +225,9 @@ impl DiffViewer {
         self.review_loaded_left_source_id = state.review_loaded_left_source_id;
         self.review_loaded_right_source_id = state.review_loaded_right_source_id;
         self.review_loaded_collapsed_files = state.review_loaded_collapsed_files;
+        self.review_loaded_changed_lines_only = state.review_loaded_changed_lines_only;
+        self.review_loaded_hunk_folds = state.review_loaded_hunk_folds;
+        self.review_loaded_compare_snapshot = state.review_loaded_compare_snapshot;
         self.branch_name = state.branch_name;
         self.branch_has_upstream = state.branch_has_upstream;
         self.branch_ahead_count = state.branch_ahead_count;
@@ -217,6 +242,7 @@ impl DiffViewer {
         self.recent_commits = state.recent_commits;
         self.recent_commits_error = state.recent_commits_error;
         self.collapsed_files = state.collapsed_files;
+        self.stats_ignored_paths = state.stats_ignored_paths;
         self.selected_path = state.selected_path;
         self.selected_status = state.selected_status;
         self.file_line_stats = state.file_line_stats;
@@ -224,10 +250,19 @@ impl DiffViewer {
         self.review_files = state.review_files;
         self.review_file_status_by_path = state.review_file_status_by_path;
         self.review_file_line_stats = state.review_file_line_stats;
+        self.review_file_encodings = state.review_file_encodings;
+        self.review_extracted_text_paths = state.review_extracted_text_paths;
+        self.review_mechanical_changes = state.review_mechanical_changes;
+        self.review_hunk_stage_states = state.review_hunk_stage_states;
+        self.review_change_set_size = state.review_change_set_size;
+        self.review_reviewed_directories = state.review_reviewed_directories;
         self.review_overall_line_stats = state.review_overall_line_stats;
         self.review_compare_loading = state.review_compare_loading;
         self.review_compare_error = state.review_compare_error;
         self.review_workspace_session = state.review_workspace_session;
+        if let Some(session) = self.review_workspace_session.as_mut() {
+            session.set_unified_rows(self.diff_unified_view);
+        }
         self.review_loaded_snapshot_fingerprint = state.review_loaded_snapshot_fingerprint;
         self.overall_line_stats = state.overall_line_stats;
         self.last_git_workspace_fingerprint = state.last_git_workspace_fingerprint;
@@ -0,0 +1,63 @@
+impl DiffViewer {
+    fn load_previous_crash_report() -> Option<CrashReport> {
+        let store = match CrashReportStore::new() {
+            Ok(store) => store,
+            Err(err) => {
+                error!("failed to initialize crash report path: {err:#}");
+                return None;
+            }
+        };
+
+        match store.latest_unseen() {
+            Ok(report) => report.map(|(_, report)| report),
+            Err(err) => {
+                error!(
+                    "failed to load crash reports from {}: {err:#}",
+                    store.dir().display()
+                );
+                None
+            }
+        }
+    }
+
+    pub(super) fn open_crash_report_viewer(&mut self, cx: &mut Context<Self>) {
+        if self.previous_crash_report.is_some() {
+            self.crash_report_viewer_open = true;
+            cx.notify();
+        }
+    }
+
+    pub(super) fn close_crash_report_viewer(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.crash_report_viewer_open {
+            self.crash_report_viewer_open = false;
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    /// Hides the previous-session banner for good by marking the stored reports as seen.
+    pub(super) fn dismiss_previous_crash_report(&mut self, cx: &mut Context<Self>) {
+        self.previous_crash_report = None;
+        self.crash_report_viewer_open = false;
+        if let Err(err) = CrashReportStore::new().and_then(|store| store.mark_all_seen()) {
+            error!("failed to mark crash reports as seen: {err:#}");
+        }
+        cx.notify();
+    }
+
+    /// Copies the report only when the user asks, so nothing leaves the machine on its own.
+    pub(super) fn copy_previous_crash_report(&mut self, cx: &mut Context<Self>) {
+        let Some(report) = self.previous_crash_report.as_ref() else {
+            return;
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(report.to_issue_text()));
+        Self::push_success_notification(
+            "Crash report copied. Paste it into a new issue.".to_string(),
+            cx,
+        );
+    }
+}
@@ -0,0 +1,313 @@
+impl DiffViewer {
+    fn notify_desktop_event(
+        &mut self,
+        event: DesktopNotificationEvent,
+        title: String,
+        body: String,
+        target: DesktopNotificationTarget,
+        cx: &mut Context<Self>,
+    ) {
+        if !event.enabled(&self.config.desktop_notifications) {
+            return;
+        }
+
+        self.push_desktop_event_notification(format!("{title}: {body}"), target.clone(), cx);
+        // The in-app toast is enough while Hunk is in front.
+        if Self::any_window_active(cx) {
+            return;
+        }
+
+        cx.spawn(async move |this, cx| {
+            let clicked = cx
+                .background_executor()
+                .spawn(async move { show_os_notification(title.as_str(), body.as_str()) })
+                .await;
+            match clicked {
+                Ok(true) => {
+                    if let Some(this) = this.upgrade() {
+                        this.update(cx, |this, cx| {
+                            cx.activate(true);
+                            this.open_desktop_notification_target(target, cx);
+                        });
+                    }
+                }
+                Ok(false) => {}
+                Err(err) => warn!("failed to show desktop notification: {err:#}"),
+            }
+        })
+        .detach();
+    }
+
+    fn any_window_active(cx: &mut Context<Self>) -> bool {
+        cx.windows().into_iter().any(|window_handle| {
+            cx.update_window(window_handle, |_, window, _| window.is_window_active())
+                .unwrap_or(false)
+        })
+    }
+
+    fn push_desktop_event_notification(
+        &self,
+        message: String,
+        target: DesktopNotificationTarget,
+        cx: &mut Context<Self>,
+    ) {
+        let view = cx.entity().downgrade();
+        for window_handle in cx.windows() {
+            let view = view.clone();
+            let target = target.clone();
+            if let Err(err) = cx.update_window(window_handle, |_, window, cx| {
+                gpui_component::WindowExt::push_notification(
+                    window,
+                    crate::app::notifications::info_with_action(
+                        message.clone(),
+                        "Open",
+                        move |_, cx| {
+                            if let Some(view) = view.upgrade() {
+                                view.update(cx, |this, cx| {
+                                    this.open_desktop_notification_target(target.clone(), cx);
+                                });
+                            }
+                        },
+                    ),
+                    cx,
+                );
+            }) {
+                error!("failed to show desktop event notification: {err:#}");
+            }
+        }
+    }
+
+    fn open_desktop_notification_target(
+        &mut self,
+        target: DesktopNotificationTarget,
+        cx: &mut Context<Self>,
+    ) {
+        match target {
+            DesktopNotificationTarget::GitWorkspace => {
+                self.set_workspace_view_mode(WorkspaceViewMode::GitWorkspace, cx);
+            }
+            DesktopNotificationTarget::Comment(comment_id) => {
+                self.set_workspace_view_mode(WorkspaceViewMode::Diff, cx);
+                self.comments_preview_open = true;
+                self.jump_to_comment_by_id(comment_id, cx);
+            }
+            DesktopNotificationTarget::Url(url) => {
+                if let Err(err) = open_url_in_browser(url.as_str()) {
+                    Self::push_error_notification(format!("Failed to open {url}: {err:#}"), cx);
+                }
+            }
+        }
+    }
+
+    fn local_head_commit_id(&self) -> Option<String> {
+        self.last_git_workspace_fingerprint
+            .as_ref()
+            .or(self.last_snapshot_fingerprint.as_ref())
+            .and_then(|fingerprint| fingerprint.head_commit_id().map(str::to_string))
+    }
+
+    // Polls GitHub Actions through `gh` until every run for the pushed commit has finished.
+    fn start_ci_watch_after_push(&mut self, cx: &mut Context<Self>) {
+        if !DesktopNotificationEvent::CiFinished.enabled(&self.config.desktop_notifications) {
+            return;
+        }
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        let Some(commit_id) = self.local_head_commit_id() else {
+            return;
+        };
+        let branch_name = self.git_workspace.branch_name.clone();
+
+        self.ci_watch_task = cx.spawn(async move |this, cx| {
+            for _ in 0..CI_WATCH_MAX_POLLS {
+                cx.background_executor().timer(CI_WATCH_INTERVAL).await;
+                let repo_root = repo_root.clone();
+                let query_branch_name = branch_name.clone();
+                let commit_id = commit_id.clone();
+                let state = cx
+                    .background_executor()
+                    .spawn(async move {
+                        query_github_runs(
+                            repo_root.as_path(),
+                            query_branch_name.as_str(),
+                            commit_id.as_str(),
+                        )
+                    })
+                    .await;
+                let state = match state {
+                    Ok(CiRunsState::Pending) => continue,
+                    Ok(state) => state,
+                    Err(err) => {
+                        debug!("stopped watching CI for '{branch_name}': {err:#}");
+                        return;
+                    }
+                };
+
+                if let Some(this) = this.upgrade() {
+                    this.update(cx, |this, cx| {
+                        this.notify_ci_finished(branch_name.as_str(), state, cx);
+                    });
+                }
+                return;
+            }
+        });
+    }
+
+    fn notify_ci_finished(
+        &mut self,
+        branch_name: &str,
+        state: CiRunsState,
+        cx: &mut Context<Self>,
+    ) {
+        let (title, body, url) = match state {
+            CiRunsState::Pending => return,
+            CiRunsState::Passed { url } => (
+                "CI passed",
+                format!("All checks passed for {branch_name}."),
+                url,
+            ),
+            CiRunsState::Failed { url, failed_runs } => (
+                "CI failed",
+                format!("{} failed for {branch_name}.", failed_runs.join(", ")),
+                url,
+            ),
+        };
+        let target = url
+            .map(DesktopNotificationTarget::Url)
+            .unwrap_or(DesktopNotificationTarget::GitWorkspace);
+        self.notify_desktop_event(
+            DesktopNotificationEvent::CiFinished,
+            title.to_string(),
+            body,
+            target,
+            cx,
+        );
+    }
+
+    // Reads the upstream head with a ref listing only, so passive watching never fetches.
+    fn start_remote_branch_watch(&mut self, cx: &mut Context<Self>) {
+        self.remote_branch_watch_task = cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(REMOTE_BRANCH_WATCH_INTERVAL)
+                    .await;
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+
+                let mut scope = None;
+                this.update(cx, |this, _| {
+                    scope = this.remote_branch_watch_scope();
+                });
+                let Some((repo_root, branch_name)) = scope else {
+                    continue;
+                };
+
+                let head = cx
+                    .background_executor()
+                    .spawn({
+                        let repo_root = repo_root.clone();
+                        let branch_name = branch_name.clone();
+                        async move {
+                            remote_upstream_head_with_cancel(
+                                repo_root.as_path(),
+                                branch_name.as_str(),
+                                &CancellationToken::with_timeout(REMOTE_BRANCH_WATCH_TIMEOUT),
+                            )
+                        }
+                    })
+                    .await;
+                this.update(cx, |this, cx| {
+                    this.apply_remote_branch_head(repo_root, branch_name, head, cx);
+                });
+            }
+        });
+    }
+
+    fn remote_branch_watch_scope(&self) -> Option<(PathBuf, String)> {
+        if !DesktopNotificationEvent::RemoteBranchMoved.enabled(&self.config.desktop_notifications)
+            || !self.git_workspace.branch_has_upstream
+            || self.git_action_loading
+        {
+            return None;
+        }
+        let repo_root = self.selected_git_workspace_root()?;
+        Some((repo_root, self.git_workspace.branch_name.clone()))
+    }
+
+    fn apply_remote_branch_head(
+        &mut self,
+        root: PathBuf,
+        branch_name: String,
+        head: Result<Option<RemoteUpstreamHead>>,
+        cx: &mut Context<Self>,
+    ) {
+        let head = match head {
+            Ok(Some(head)) => head,
+            Ok(None) => {
+                self.remote_branch_watch_head = None;
+                return;
+            }
+            Err(err) => {
+                debug!("failed to read remote head for '{branch_name}': {err:#}");
+                return;
+            }
+        };
+
+        let previous = self
+            .remote_branch_watch_head
+            .replace(RemoteBranchWatchHead {
+                root: root.clone(),
+                branch_name: branch_name.clone(),
+                commit_id: head.commit_id.clone(),
+            });
+        let Some(previous) = previous else {
+            return;
+        };
+        if previous.root != root
+            || previous.branch_name != branch_name
+            || previous.commit_id == head.commit_id
+        {
+            return;
+        }
+        // Our own push moves the remote to the local head; only report other people's pushes.
+        if self.local_head_commit_id().as_deref() == Some(head.commit_id.as_str()) {
+            return;
+        }
+
+        let short_id = head.commit_id.chars().take(8).collect::<String>();
+        self.notify_desktop_event(
+            DesktopNotificationEvent::RemoteBranchMoved,
+            "Remote branch moved".to_string(),
+            format!(
+                "{}/{} now points to {short_id}.",
+                head.remote_name, head.remote_branch_name
+            ),
+            DesktopNotificationTarget::GitWorkspace,
+            cx,
+        );
+    }
+
+    fn notify_comment_mentions(&mut self, comments: &[CommentRecord], cx: &mut Context<Self>) {
+        if !DesktopNotificationEvent::CommentMention.enabled(&self.config.desktop_notifications) {
+            return;
+        }
+        let handle = self.co_review_display_name();
+        let mentions = comments
+            .iter()
+            .filter(|comment| text_mentions_handle(comment.comment_text.as_str(), handle.as_str()))
+            .map(|comment| (comment.id.clone(), comment.file_path.clone()))
+            .collect::<Vec<_>>();
+
+        for (comment_id, file_path) in mentions {
+            self.notify_desktop_event(
+                DesktopNotificationEvent::CommentMention,
+                "You were mentioned".to_string(),
+                format!("New comment on {file_path}."),
+                DesktopNotificationTarget::Comment(comment_id),
+                cx,
+            );
+        }
+    }
+}
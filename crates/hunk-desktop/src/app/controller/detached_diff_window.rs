@@ -0,0 +1,27 @@
+impl DiffViewer {
+    /// Pops the selected file's diff out into its own window, or brings the existing one to the
+    /// front. The detached window reads this viewer directly, so selection and refreshes carry
+    /// over without extra syncing.
+    pub(super) fn open_detached_diff_window_action(
+        &mut self,
+        _: &OpenDetachedDiffWindow,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(handle) = self.detached_diff_window
+            && handle
+                .update(cx, |_, window, _| window.activate_window())
+                .is_ok()
+        {
+            return;
+        }
+
+        match open_detached_diff_window(cx.entity(), cx) {
+            Ok(handle) => self.detached_diff_window = Some(handle),
+            Err(err) => {
+                error!("failed to open detached diff window: {err:#}");
+                Self::push_error_notification(format!("Failed to open diff window: {err:#}"), cx);
+            }
+        }
+    }
+}
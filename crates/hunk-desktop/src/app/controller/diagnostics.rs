@@ -0,0 +1,174 @@
+impl DiffViewer {
+    pub(super) fn open_diagnostics_action(
+        &mut self,
+        _: &OpenDiagnostics,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.diagnostics_loading {
+            return;
+        }
+
+        let repo_root = self.selected_git_workspace_root();
+        let database_store = self.database_store.clone();
+        let watcher_check = self.repo_watch_diagnostic(repo_root.as_deref());
+        self.diagnostics_loading = true;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let checks = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut checks = hunk_git::doctor::run_git_diagnostics(repo_root.as_deref());
+                    checks.push(watcher_check);
+                    checks.push(database_diagnostic(database_store.as_ref()));
+                    checks
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    this.diagnostics_loading = false;
+                    this.show_diagnostics_dialog(checks, cx);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
+    fn repo_watch_diagnostic(
+        &self,
+        repo_root: Option<&std::path::Path>,
+    ) -> hunk_git::doctor::DiagnosticCheck {
+        use hunk_git::doctor::DiagnosticCheck;
+
+        let Some(repo_root) = repo_root else {
+            return DiagnosticCheck::warning(
+                "File watcher",
+                "No repository is open",
+                "Open a project to start watching for changes.",
+            );
+        };
+        match self.repo_watch_error.as_ref() {
+            Some(err) => DiagnosticCheck::fail(
+                "File watcher",
+                err.clone(),
+                if cfg!(target_os = "linux") {
+                    "Raise fs.inotify.max_user_watches or close other watchers, then reopen the project."
+                } else {
+                    "Check file permissions for the repository, then reopen the project."
+                },
+            ),
+            None => {
+                DiagnosticCheck::pass("File watcher", format!("Watching {}", repo_root.display()))
+            }
+        }
+    }
+
+    fn show_diagnostics_dialog(
+        &self,
+        checks: Vec<hunk_git::doctor::DiagnosticCheck>,
+        cx: &mut Context<Self>,
+    ) {
+        let failures = checks
+            .iter()
+            .filter(|check| check.status == hunk_git::doctor::DiagnosticStatus::Fail)
+            .count();
+        let summary = if failures == 0 {
+            "All checks passed.".to_string()
+        } else {
+            format!("{failures} check(s) failed.")
+        };
+
+        if let Err(err) = cx.update_window(self.window_handle, move |_, window, cx| {
+            gpui_component::WindowExt::open_alert_dialog(window, cx, move |alert, _, cx| {
+                alert
+                    .width(px(560.0))
+                    .title("Diagnostics")
+                    .description(summary.clone())
+                    .button_props(
+                        gpui_component::dialog::DialogButtonProps::default().ok_text("Close"),
+                    )
+                    .child(v_flex().gap_2().children(checks.iter().map(|check| {
+                        let status_color = match check.status {
+                            hunk_git::doctor::DiagnosticStatus::Pass => cx.theme().success,
+                            hunk_git::doctor::DiagnosticStatus::Warning => cx.theme().warning,
+                            hunk_git::doctor::DiagnosticStatus::Fail => cx.theme().danger,
+                        };
+                        v_flex()
+                            .gap_0p5()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .font_semibold()
+                                            .text_color(status_color)
+                                            .child(check.status.label()),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(check.name.clone()),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .whitespace_normal()
+                                    .child(check.detail.clone()),
+                            )
+                            .when_some(check.remediation.clone(), |this, remediation| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().foreground)
+                                        .whitespace_normal()
+                                        .child(remediation),
+                                )
+                            })
+                    })))
+            });
+        }) {
+            error!("failed to show diagnostics dialog: {err:#}");
+        }
+    }
+}
+
+fn database_diagnostic(
+    database_store: Option<&DatabaseStore>,
+) -> hunk_git::doctor::DiagnosticCheck {
+    use hunk_git::doctor::DiagnosticCheck;
+
+    let Some(database_store) = database_store else {
+        return DiagnosticCheck::fail(
+            "Comments database",
+            "Database store is unavailable",
+            "Check that ~/.hunkdiff is writable, then restart Hunk.",
+        );
+    };
+    match database_store.check_integrity() {
+        Ok(problems) if problems.is_empty() => DiagnosticCheck::pass(
+            "Comments database",
+            format!("{} passed integrity check", database_store.path().display()),
+        ),
+        Ok(problems) => DiagnosticCheck::fail(
+            "Comments database",
+            problems.join("; "),
+            format!(
+                "Back up and remove {} to recreate it. Existing comments will be lost.",
+                database_store.path().display()
+            ),
+        ),
+        Err(err) => DiagnosticCheck::fail(
+            "Comments database",
+            format!("{err:#}"),
+            "Check that ~/.hunkdiff is writable, then restart Hunk.",
+        ),
+    }
+}
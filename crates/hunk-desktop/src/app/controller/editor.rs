@@ -123,6 +123,7 @@ impl DiffViewer {
         self.restore_file_editor_tab_state(tab_index);
         self.file_editor_tab_scroll_handle.scroll_to_item(tab_index);
         self.sync_editor_search_query(cx);
+        self.request_file_authorship(cx);
         if let Some(window) = window {
             self.files_editor_focus_handle.focus(window, cx);
         }
@@ -446,17 +447,18 @@ impl DiffViewer {
         self.sync_active_file_editor_tab_state();
         cx.notify();
 
+        let recovery_store = self.editor_recovery_store.clone();
         self.editor_task = cx.spawn(async move |this, cx| {
             let target_path = path.clone();
             let repo_root_for_load = repo_root.clone();
             let result = cx.background_executor().spawn(async move {
-                load_file_editor_document(
+                load_file_editor_document_with_recovery(
+                    recovery_store,
                     &repo_root_for_load,
                     target_path.as_str(),
-                    FILE_EDITOR_MAX_BYTES,
                 )
             });
-            let result = result.await;
+            let (result, recovered) = result.await;
 
             if let Some(this) = this.upgrade() {
                 this.update(cx, |this, cx| {
@@ -479,6 +481,7 @@ impl DiffViewer {
                                 &repo_root,
                                 text.as_str(),
                             );
+                            let opened = open_result.is_ok();
                             let should_schedule_preview = {
                                 let tab = &mut this.file_editor_tabs[tab_index];
                                 tab.loading = false;
@@ -497,10 +500,16 @@ impl DiffViewer {
                             if is_active {
                                 this.restore_file_editor_tab_state(tab_index);
                                 this.sync_editor_search_query(cx);
+                                this.apply_pending_editor_caret_line(path.as_str());
                                 this.focus_files_editor(cx);
                                 if should_schedule_preview {
                                     this.schedule_editor_markdown_preview_parse(cx);
                                 }
+                                this.schedule_editor_line_changes_refresh(cx);
+                            }
+                            if opened {
+                                this.editor_recovery_root = Some(repo_root.clone());
+                                this.offer_editor_recovery(tab_id, recovered, text.as_str(), cx);
                             }
                         }
                         Err(err) => {
@@ -542,6 +551,12 @@ impl DiffViewer {
         cx: &mut Context<Self>,
     ) -> bool {
         if !self.can_open_file_in_files_workspace(path.as_str(), status) {
+            if status != FileStatus::Deleted
+                && self.path_hidden_by_sparse_checkout(path.as_str())
+            {
+                self.confirm_widen_sparse_checkout(path, window, cx);
+                return false;
+            }
             let message = if status == FileStatus::Deleted {
                 format!("{path} was deleted in this review and can't be opened in Files view.")
             } else {
@@ -582,7 +597,22 @@ impl DiffViewer {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.editor_loading || self.editor_save_loading {
+        self.sync_editor_dirty_from_input(cx);
+        if self.config.formatting.format_on_save
+            && self.editor_dirty
+            && self.current_editor_formatter().is_some()
+        {
+            self.format_current_editor_file(true, cx);
+        } else {
+            self.write_current_editor_file(cx);
+        }
+    }
+
+    fn write_current_editor_file(&mut self, cx: &mut Context<Self>) {
+        if self.editor_loading
+            || self.editor_save_loading
+            || self.block_in_read_only("Save file", cx)
+        {
             return;
         }
 
@@ -617,6 +647,7 @@ impl DiffViewer {
         let saved_text = current_text;
         let path_for_write = path.clone();
         let status_path = path.clone();
+        let repo_root_for_recovery = repo_root.clone();
         let epoch = self.next_editor_save_epoch();
         self.cancel_editor_save_task();
         self.editor_save_loading = true;
@@ -658,6 +689,7 @@ impl DiffViewer {
                             if is_active {
                                 this.restore_file_editor_tab_state(tab_index);
                             }
+                            this.discard_editor_recovery(&repo_root_for_recovery, &status_path, cx);
                             this.git_status_message = Some(format!("Saved {}", status_path));
                             this.request_snapshot_refresh(cx);
                         }
@@ -715,6 +747,8 @@ impl DiffViewer {
             cx.notify();
         }
         self.schedule_editor_markdown_preview_parse(cx);
+        self.schedule_editor_line_changes_refresh(cx);
+        self.schedule_editor_recovery_snapshot(cx);
     }
 
     fn invalidate_editor_markdown_preview(&mut self) {
@@ -838,8 +872,9 @@ impl DiffViewer {
         true
     }
 
-    pub(super) fn clear_editor_state(&mut self, _cx: &mut Context<Self>) {
+    pub(super) fn clear_editor_state(&mut self, cx: &mut Context<Self>) {
         self.sync_active_file_editor_tab_state();
+        self.flush_editor_recovery(cx);
         for tab in &mut self.file_editor_tabs {
             tab.files_editor.borrow_mut().shutdown();
         }
@@ -904,6 +939,21 @@ impl DiffViewer {
         }
     }
 
+    pub(super) fn files_editor_toggle_line_comment_action(
+        &mut self,
+        _: &FilesEditorToggleLineComment,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.editor_markdown_preview || !self.files_editor_focus_handle.is_focused(window) {
+            return;
+        }
+        if self.files_editor.borrow_mut().toggle_line_comment_action() {
+            self.sync_editor_dirty_from_input(cx);
+            cx.notify();
+        }
+    }
+
     pub(super) fn files_editor_move_up_action(
         &mut self,
         _: &FilesEditorMoveUp,
@@ -0,0 +1,160 @@
+impl DiffViewer {
+    pub(super) fn files_editor_format_action(
+        &mut self,
+        _: &FilesEditorFormat,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.editor_markdown_preview || !self.files_editor_focus_handle.is_focused(window) {
+            return;
+        }
+        self.format_current_editor_file(false, cx);
+    }
+
+    pub(super) fn current_editor_formatter(&self) -> Option<FormatterCommand> {
+        let language = self.files_editor.borrow().language_scope_name()?;
+        self.config
+            .formatting
+            .formatter_for_language(language.as_str())
+            .cloned()
+    }
+
+    /// Runs the open file's formatter in the background and applies its output as one undoable
+    /// edit. With `save_after`, the file is written afterwards even if the formatter failed, so
+    /// a broken formatter never blocks saving.
+    pub(super) fn format_current_editor_file(&mut self, save_after: bool, cx: &mut Context<Self>) {
+        if self.editor_loading
+            || self.editor_save_loading
+            || self.block_in_read_only("Format file", cx)
+        {
+            return;
+        }
+
+        let Some(repo_root) = self.repo_root.clone() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+        let Some(path) = self.editor_path.clone() else {
+            self.git_status_message = Some(tr("editor-format-no-file"));
+            cx.notify();
+            return;
+        };
+        let Some(formatter) = self.current_editor_formatter() else {
+            self.git_status_message = Some(tr_args(
+                "editor-format-no-formatter",
+                &[("path", path.as_str())],
+            ));
+            cx.notify();
+            return;
+        };
+        let text = match self.current_editor_text() {
+            Ok(text) => text,
+            Err(err) => {
+                self.git_status_message = Some(tr_args(
+                    "editor-format-blocked",
+                    &[
+                        ("path", path.as_str()),
+                        ("error", format!("{err:#}").as_str()),
+                    ],
+                ));
+                cx.notify();
+                return;
+            }
+        };
+
+        self.files_editor.borrow_mut().set_format_error(None);
+        let epoch = self.next_editor_save_epoch();
+        self.cancel_editor_save_task();
+        self.editor_save_loading = true;
+        self.git_status_message = None;
+        self.sync_active_file_editor_tab_state();
+        cx.notify();
+        let Some(tab_id) = self.active_file_editor_tab_id else {
+            return;
+        };
+
+        self.editor_save_task = cx.spawn(async move |this, cx| {
+            let absolute_path = repo_root.join(path.as_str());
+            let input = text.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    run_formatter(
+                        &formatter,
+                        &repo_root,
+                        &absolute_path,
+                        input.as_str(),
+                        FORMATTER_TIMEOUT,
+                    )
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, move |this, cx| {
+                    let Some(tab_index) = this.file_editor_tab_index_for_id(tab_id) else {
+                        return;
+                    };
+                    if epoch != this.file_editor_tabs[tab_index].save_epoch {
+                        return;
+                    }
+
+                    let is_active = this.active_file_editor_tab_id == Some(tab_id);
+                    let tab_editor = this.file_editor_tabs[tab_index].files_editor.clone();
+                    this.file_editor_tabs[tab_index].save_loading = false;
+                    if is_active {
+                        this.editor_save_loading = false;
+                    }
+
+                    let mut formatted_changed = false;
+                    match result {
+                        // Edits typed while the formatter ran win over its output.
+                        Ok(formatted)
+                            if tab_editor.borrow().current_text().as_deref()
+                                == Some(text.as_str()) =>
+                        {
+                            formatted_changed = tab_editor
+                                .borrow_mut()
+                                .apply_formatted_text(formatted.as_str());
+                            this.git_status_message = Some(tr_args(
+                                if formatted_changed {
+                                    "editor-format-done"
+                                } else {
+                                    "editor-format-unchanged"
+                                },
+                                &[("path", path.as_str())],
+                            ));
+                        }
+                        Ok(_) => {
+                            this.git_status_message =
+                                Some(tr_args("editor-format-skipped", &[("path", path.as_str())]));
+                        }
+                        Err(err) => {
+                            tab_editor
+                                .borrow_mut()
+                                .set_format_error(Some((err.line.unwrap_or(0), err.message)));
+                            this.git_status_message =
+                                Some(tr_args("editor-format-failed", &[("path", path.as_str())]));
+                        }
+                    }
+
+                    if !is_active {
+                        if formatted_changed {
+                            this.file_editor_tabs[tab_index].dirty = true;
+                        }
+                        cx.notify();
+                        return;
+                    }
+                    this.sync_editor_dirty_from_input(cx);
+                    if save_after {
+                        // This task is finishing; detach it so starting the write does not
+                        // cancel it.
+                        std::mem::replace(&mut this.editor_save_task, Task::ready(())).detach();
+                        this.write_current_editor_file(cx);
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
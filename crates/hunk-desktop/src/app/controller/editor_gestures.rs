@@ -0,0 +1,35 @@
+impl DiffViewer {
+    /// Opens `path` in Files and puts the caret on one-based `line`, once the file has loaded.
+    pub(super) fn open_file_in_files_workspace_at_line(
+        &mut self,
+        path: String,
+        status: FileStatus,
+        line: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        self.pending_editor_caret_line = Some((path.clone(), line.saturating_sub(1)));
+        if !self.open_file_in_files_workspace(path.clone(), status, window, cx) {
+            self.pending_editor_caret_line = None;
+            return false;
+        }
+        if !self.editor_loading {
+            self.apply_pending_editor_caret_line(path.as_str());
+        }
+        true
+    }
+
+    /// Moves the caret to the line a double-click gesture asked for once `path` is open.
+    fn apply_pending_editor_caret_line(&mut self, path: &str) {
+        if self
+            .pending_editor_caret_line
+            .as_ref()
+            .is_none_or(|(pending_path, _)| pending_path != path)
+        {
+            return;
+        }
+        if let Some((_, line)) = self.pending_editor_caret_line.take() {
+            self.files_editor.borrow_mut().move_caret_to_line(line);
+        }
+    }
+}
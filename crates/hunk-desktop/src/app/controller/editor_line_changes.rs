@@ -0,0 +1,90 @@
+impl DiffViewer {
+    /// Recomputes the gutter change markers of the open file against HEAD once edits pause.
+    /// Calls for a buffer revision that is already scheduled leave the pending run alone, so
+    /// scrolling and cursor moves do not push it back.
+    pub(super) fn schedule_editor_line_changes_refresh(&mut self, cx: &mut Context<Self>) {
+        if self.editor_loading {
+            return;
+        }
+        let (Some(repo_root), Some(path)) = (self.repo_root.clone(), self.editor_path.clone())
+        else {
+            return;
+        };
+        let files_editor = self.files_editor.clone();
+        let Some(revision) = files_editor.borrow().stale_line_changes_revision() else {
+            return;
+        };
+        if self.editor_line_changes_scheduled == Some(revision) {
+            return;
+        }
+        self.editor_line_changes_scheduled = Some(revision);
+
+        self.editor_line_changes_task = cx.spawn(async move |this, cx| {
+            cx.background_executor()
+                .timer(EDITOR_LINE_CHANGES_DEBOUNCE)
+                .await;
+            let (revision, text) = {
+                let editor = files_editor.borrow();
+                let (Some(revision), Some(text)) =
+                    (editor.stale_line_changes_revision(), editor.current_text())
+                else {
+                    return;
+                };
+                (revision, text)
+            };
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    line_changes_against_head(repo_root.as_path(), path.as_str(), text.as_str())
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.editor_line_changes_scheduled == Some(revision) {
+                        this.editor_line_changes_scheduled = None;
+                    }
+                    let changes = match result {
+                        Ok(changes) => changes,
+                        Err(err) => {
+                            debug!("computing editor line changes failed: {err:#}");
+                            Vec::new()
+                        }
+                    };
+                    if files_editor
+                        .borrow_mut()
+                        .set_line_change_overlays(revision, line_change_overlays(&changes))
+                    {
+                        cx.notify();
+                    }
+                });
+            }
+        });
+    }
+
+    /// A new HEAD moves the baseline, so the markers are recomputed even without an edit.
+    pub(super) fn refresh_editor_line_changes_for_new_head(&mut self, cx: &mut Context<Self>) {
+        self.files_editor.borrow_mut().invalidate_line_changes();
+        self.editor_line_changes_scheduled = None;
+        self.schedule_editor_line_changes_refresh(cx);
+    }
+}
+
+fn line_change_overlays(changes: &[LineChange]) -> Vec<OverlayDescriptor> {
+    changes
+        .iter()
+        .flat_map(|change| {
+            let kind = match change.kind {
+                LineChangeKind::Added => OverlayKind::DiffAddition,
+                LineChangeKind::Modified => OverlayKind::DiffModification,
+                LineChangeKind::Deleted => OverlayKind::DiffDeletion,
+            };
+            let lines = change.start_line..change.start_line + change.line_count.max(1);
+            lines.map(move |line| OverlayDescriptor {
+                line,
+                kind,
+                message: None,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,248 @@
+impl DiffViewer {
+    fn load_editor_recovery_store() -> Option<EditorRecoveryStore> {
+        match EditorRecoveryStore::new() {
+            Ok(store) => Some(store),
+            Err(err) => {
+                error!("failed to resolve editor recovery directory: {err:#}");
+                None
+            }
+        }
+    }
+
+    /// Copies dirty buffers to the recovery store shortly after an edit. Further edits before
+    /// the copy is written ride along with it, so continuous typing still lands on disk every
+    /// few seconds.
+    fn schedule_editor_recovery_snapshot(&mut self, cx: &mut Context<Self>) {
+        if self.editor_recovery_scheduled
+            || !self.editor_dirty
+            || self.editor_recovery_store.is_none()
+        {
+            return;
+        }
+        self.editor_recovery_scheduled = true;
+
+        self.editor_recovery_task = cx.spawn(async move |this, cx| {
+            cx.background_executor()
+                .timer(EDITOR_RECOVERY_INTERVAL)
+                .await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            let snapshot = this.update(cx, |this, _| {
+                this.editor_recovery_scheduled = false;
+                this.editor_recovery_snapshot()
+            });
+            drop(this);
+            let Some((store, repo_key, buffers)) = snapshot else {
+                return;
+            };
+            cx.background_executor()
+                .spawn(async move {
+                    save_editor_recovery_buffers(&store, repo_key.as_str(), &buffers);
+                })
+                .await;
+        });
+    }
+
+    /// Writes the dirty buffers right away, before their tabs are discarded. A pending restore
+    /// prompt goes away with its tab.
+    fn flush_editor_recovery(&mut self, cx: &mut Context<Self>) {
+        self.editor_recovery_prompt = None;
+        let Some((store, repo_key, buffers)) = self.editor_recovery_snapshot() else {
+            return;
+        };
+        cx.background_executor()
+            .spawn(async move {
+                save_editor_recovery_buffers(&store, repo_key.as_str(), &buffers);
+            })
+            .detach();
+    }
+
+    /// Writes the dirty buffers on the calling thread, for when the app is going away.
+    pub(super) fn write_editor_recovery_blocking(&self) {
+        if let Some((store, repo_key, buffers)) = self.editor_recovery_snapshot() {
+            save_editor_recovery_buffers(&store, repo_key.as_str(), &buffers);
+        }
+    }
+
+    fn editor_recovery_snapshot(
+        &self,
+    ) -> Option<(EditorRecoveryStore, String, Vec<RecoveredEditorBuffer>)> {
+        let store = self.editor_recovery_store.clone()?;
+        let repo_root = self.editor_recovery_root.clone()?;
+        let saved_unix_time = now_unix_ms() / 1000;
+        let buffers = self
+            .file_editor_tabs
+            .iter()
+            .filter_map(|tab| {
+                // The active tab's live state is kept on the view, not in its tab entry.
+                let (path, dirty, files_editor) = if self.active_file_editor_tab_id == Some(tab.id)
+                {
+                    (
+                        self.editor_path.as_ref()?,
+                        self.editor_dirty,
+                        &self.files_editor,
+                    )
+                } else {
+                    (&tab.path, tab.dirty, &tab.files_editor)
+                };
+                if !dirty {
+                    return None;
+                }
+                Some(RecoveredEditorBuffer {
+                    repo_root: repo_root.clone(),
+                    path: path.clone(),
+                    text: files_editor.borrow().current_text()?,
+                    saved_unix_time,
+                })
+            })
+            .collect::<Vec<_>>();
+        if buffers.is_empty() {
+            return None;
+        }
+        Some((
+            store,
+            hunk_git::worktree::repository_storage_key(repo_root.as_path()),
+            buffers,
+        ))
+    }
+
+    /// Drops the scratch copy of a file whose edits were saved.
+    fn discard_editor_recovery(
+        &mut self,
+        repo_root: &std::path::Path,
+        path: &str,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .editor_recovery_prompt
+            .as_ref()
+            .is_some_and(|prompt| prompt.buffer.path == path)
+        {
+            self.editor_recovery_prompt = None;
+        }
+        let Some(store) = self.editor_recovery_store.clone() else {
+            return;
+        };
+        let repo_key = hunk_git::worktree::repository_storage_key(repo_root);
+        let path = path.to_string();
+        cx.background_executor()
+            .spawn(async move {
+                if let Err(err) = store.remove(repo_key.as_str(), path.as_str()) {
+                    error!("failed to clear unsaved edits of {path}: {err:#}");
+                }
+            })
+            .detach();
+    }
+
+    /// Offers edits left behind by a crash or a discarded tab once their file is open again.
+    /// A scratch copy matching the file on disk has nothing to restore and is dropped.
+    fn offer_editor_recovery(
+        &mut self,
+        tab_id: usize,
+        recovered: Option<RecoveredEditorBuffer>,
+        disk_text: &str,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(buffer) = recovered else {
+            return;
+        };
+        if buffer.text == disk_text {
+            let repo_root = buffer.repo_root.clone();
+            self.discard_editor_recovery(repo_root.as_path(), buffer.path.as_str(), cx);
+            return;
+        }
+        self.editor_recovery_prompt = Some(EditorRecoveryPrompt { tab_id, buffer });
+        cx.notify();
+    }
+
+    pub(super) fn active_editor_recovery_prompt(&self) -> Option<&RecoveredEditorBuffer> {
+        self.editor_recovery_prompt
+            .as_ref()
+            .filter(|prompt| self.active_file_editor_tab_id == Some(prompt.tab_id))
+            .map(|prompt| &prompt.buffer)
+    }
+
+    /// Replaces the open file's contents with its recovered edits as one undoable change. The
+    /// scratch copy stays until the file is saved.
+    pub(super) fn restore_editor_recovery(&mut self, cx: &mut Context<Self>) {
+        if self.active_editor_recovery_prompt().is_none()
+            || self.editor_loading
+            || self.editor_save_loading
+            || self.block_in_read_only("Restore unsaved changes", cx)
+        {
+            return;
+        }
+        let Some(prompt) = self.editor_recovery_prompt.take() else {
+            return;
+        };
+        self.files_editor
+            .borrow_mut()
+            .apply_formatted_text(prompt.buffer.text.as_str());
+        self.git_status_message = Some(tr_args(
+            "editor-recovery-restored",
+            &[("path", prompt.buffer.path.as_str())],
+        ));
+        self.sync_editor_dirty_from_input(cx);
+        cx.notify();
+    }
+
+    pub(super) fn discard_editor_recovery_prompt(&mut self, cx: &mut Context<Self>) {
+        if self.active_editor_recovery_prompt().is_none() {
+            return;
+        }
+        let Some(prompt) = self.editor_recovery_prompt.take() else {
+            return;
+        };
+        self.discard_editor_recovery(
+            prompt.buffer.repo_root.as_path(),
+            prompt.buffer.path.as_str(),
+            cx,
+        );
+        cx.notify();
+    }
+}
+
+fn save_editor_recovery_buffers(
+    store: &EditorRecoveryStore,
+    repo_key: &str,
+    buffers: &[RecoveredEditorBuffer],
+) {
+    for buffer in buffers {
+        if let Err(err) = store.save(repo_key, buffer) {
+            error!("failed to save unsaved edits of {}: {err:#}", buffer.path);
+        }
+    }
+}
+
+fn load_recovered_editor_buffer(
+    store: Option<EditorRecoveryStore>,
+    repo_root: &std::path::Path,
+    path: &str,
+) -> Option<RecoveredEditorBuffer> {
+    let repo_key = hunk_git::worktree::repository_storage_key(repo_root);
+    match store?.load(repo_key.as_str(), path) {
+        Ok(buffer) => buffer,
+        Err(err) => {
+            error!("failed to read unsaved edits of {path}: {err:#}");
+            None
+        }
+    }
+}
+
+/// Loads a file for the editor along with any unsaved edits left behind for it.
+fn load_file_editor_document_with_recovery(
+    store: Option<EditorRecoveryStore>,
+    repo_root: &std::path::Path,
+    path: &str,
+) -> (
+    anyhow::Result<FileEditorDocument>,
+    Option<RecoveredEditorBuffer>,
+) {
+    let document = load_file_editor_document(repo_root, path, FILE_EDITOR_MAX_BYTES);
+    let recovered = document
+        .is_ok()
+        .then(|| load_recovered_editor_buffer(store, repo_root, path))
+        .flatten();
+    (document, recovered)
+}
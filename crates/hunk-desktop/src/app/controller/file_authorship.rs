@@ -0,0 +1,64 @@
+impl DiffViewer {
+    /// Loads who last touched the open file, once per file and HEAD commit. The result is cached
+    /// until HEAD moves, so switching tabs back and forth does not walk history again.
+    fn request_file_authorship(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = self.editor_path.clone() else {
+            return;
+        };
+        let Some(repo_root) = self.repo_root.clone() else {
+            return;
+        };
+        let head_commit_id = self
+            .last_snapshot_fingerprint
+            .as_ref()
+            .and_then(|fingerprint| fingerprint.head_commit_id())
+            .map(ToOwned::to_owned);
+        if head_commit_id != self.file_authorship_head_commit_id {
+            self.file_authorship.clear();
+            self.file_authorship_head_commit_id = head_commit_id;
+        }
+        if self.file_authorship.contains_key(path.as_str())
+            || self.file_authorship_loading_path.as_deref() == Some(path.as_str())
+        {
+            return;
+        }
+
+        self.file_authorship_loading_path = Some(path.clone());
+        let since_unix_time = now_unix_ms() / 1000 - FILE_AUTHORSHIP_WINDOW_DAYS * 24 * 60 * 60;
+        self.file_authorship_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move { load_file_authorship(repo_root.as_path(), &path, since_unix_time) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.file_authorship_loading_path.as_deref() != Some(path.as_str()) {
+                        return;
+                    }
+                    this.file_authorship_loading_path = None;
+                    match result {
+                        Ok(authorship) => {
+                            this.file_authorship.insert(path, authorship);
+                        }
+                        Err(err) => warn!("loading history of {path} failed: {err:#}"),
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn active_file_authorship(&self) -> Option<&FileAuthorship> {
+        let path = self.editor_path.as_deref()?;
+        self.file_authorship.get(path)
+    }
+
+    pub(super) fn toggle_file_history(&mut self, cx: &mut Context<Self>) {
+        self.file_history_open = !self.file_history_open;
+        cx.notify();
+    }
+}
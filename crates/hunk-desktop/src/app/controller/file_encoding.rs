@@ -0,0 +1,120 @@
+impl DiffViewer {
+    fn review_compare_right_workspace_root(&self) -> Option<PathBuf> {
+        let right =
+            self.review_compare_source_option(self.review_loaded_right_source_id.as_deref()?)?;
+        if right.kind != crate::app::review_compare_picker::ReviewCompareSourceKind::WorkspaceTarget
+        {
+            return None;
+        }
+        right.workspace_root.clone()
+    }
+
+    pub(super) fn review_file_can_convert_to_utf8(&self, path: &str) -> bool {
+        self.review_file_encodings.contains_key(path)
+            && self.review_compare_right_workspace_root().is_some()
+    }
+
+    /// Asks before rewriting a working-copy file, naming the encoding it was detected in so a
+    /// wrong guess can be caught before the bytes are replaced.
+    pub(super) fn confirm_convert_review_file_to_utf8(
+        &mut self,
+        path: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.block_in_read_only("Convert to UTF-8", cx) {
+            return;
+        }
+        let Some(encoding) = self.review_file_encodings.get(path.as_str()).copied() else {
+            return;
+        };
+        let view = cx.entity();
+
+        gpui_component::WindowExt::open_alert_dialog(window, cx, move |alert, _, _| {
+            alert
+                .width(px(460.0))
+                .title("Convert to UTF-8")
+                .description(format!(
+                    "{path} looks like {}. Rewrite it as UTF-8 without a byte order mark? Check that the diff shows the text correctly first; the file is left unchanged if any bytes are not valid {}.",
+                    encoding.label(),
+                    encoding.name()
+                ))
+                .button_props(
+                    gpui_component::dialog::DialogButtonProps::default()
+                        .ok_text("Convert")
+                        .cancel_text("Cancel")
+                        .show_cancel(true),
+                )
+                .on_ok({
+                    let view = view.clone();
+                    let path = path.clone();
+                    move |_, _, cx| {
+                        view.update(cx, |this, cx| {
+                            this.convert_review_file_to_utf8(path.clone(), encoding, cx);
+                        });
+                        true
+                    }
+                })
+        });
+    }
+
+    /// Rewrites a working-copy file that was detected in another encoding as UTF-8.
+    fn convert_review_file_to_utf8(
+        &mut self,
+        path: String,
+        encoding: TextEncoding,
+        cx: &mut Context<Self>,
+    ) {
+        if self.block_in_read_only("Convert to UTF-8", cx) {
+            return;
+        }
+        let Some(repo_root) = self.review_compare_right_workspace_root() else {
+            Self::push_error_notification(
+                "Convert to UTF-8 needs a working copy on the right side of the comparison."
+                    .to_string(),
+                cx,
+            );
+            return;
+        };
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move {
+                        convert_file_to_utf8(repo_root.as_path(), path.as_str(), encoding)
+                    }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(()) => {
+                            let message =
+                                format!("Converted {path} from {} to UTF-8", encoding.label());
+                            this.review_file_encodings.remove(path.as_str());
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.queue_dirty_paths([path]);
+                            this.request_snapshot_refresh_internal(
+                                SnapshotRefreshRequest::background_refresh_working_copy(),
+                                cx,
+                            );
+                        }
+                        Err(err) => {
+                            error!("converting '{path}' to UTF-8 failed: {err:#}");
+                            Self::push_error_notification(
+                                format!("Convert {path} to UTF-8 failed: {err}"),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+}
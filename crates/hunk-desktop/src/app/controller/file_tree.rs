@@ -117,11 +117,12 @@ impl DiffViewer {
     }
 
     pub(super) fn toggle_sidebar_tree(&mut self, cx: &mut Context<Self>) {
-        self.sidebar_collapsed = !self.sidebar_collapsed;
-        if !self.sidebar_collapsed && self.repo_tree.nodes.is_empty() && !self.repo_tree.loading {
-            self.request_repo_tree_reload(cx);
-        }
-        cx.notify();
+        let dock = if self.sidebar_tree_visible() {
+            PanelDock::Hidden
+        } else {
+            self.tree_restore_dock
+        };
+        self.set_workspace_panel_dock(WorkspacePanel::Tree, dock, cx);
     }
 
     pub(super) fn switch_to_files_view_action(
@@ -176,9 +177,9 @@ impl DiffViewer {
     pub(super) fn set_workspace_view_mode(&mut self, mode: WorkspaceViewMode, cx: &mut Context<Self>) {
         let previous_mode = self.workspace_view_mode;
         if previous_mode == mode {
-            if !self.sidebar_collapsed
+            if self.sidebar_tree_visible()
                 && mode.supports_sidebar_tree()
-                && self.repo_tree.nodes.is_empty()
+                && (self.repo_tree.nodes.is_empty() || self.repo_tree.reload_deferred)
                 && !self.repo_tree.loading
             {
                 self.request_repo_tree_reload(cx);
@@ -215,7 +216,9 @@ impl DiffViewer {
                 if !self.restore_full_repo_tree_from_cache() && !self.repo_tree.loading {
                     self.request_repo_tree_reload(cx);
                 }
-            } else if self.repo_tree.nodes.is_empty() && !self.repo_tree.loading {
+            } else if (self.repo_tree.nodes.is_empty() || self.repo_tree.reload_deferred)
+                && !self.repo_tree.loading
+            {
                 self.request_repo_tree_reload(cx);
             }
 
@@ -242,10 +245,13 @@ impl DiffViewer {
                 self.scroll_selected_after_reload = true;
                 self.request_selected_diff_reload(cx);
             }
+        } else if mode == WorkspaceViewMode::GitWorkspace {
+            self.maybe_run_pending_recent_commits_refresh(cx);
         } else if mode == WorkspaceViewMode::Ai {
             self.refresh_ai_repo_thread_catalog(cx);
             self.ensure_ai_runtime_started(cx);
         }
+        self.sync_review_timer(cx);
 
         if self.editor_search_visible {
             self.sync_editor_search_query(cx);
@@ -281,6 +287,7 @@ impl DiffViewer {
             self.selected_status = status;
             self.request_file_editor_reload(path, cx);
         } else {
+            self.review_tree_clicked_path = Some(path.clone());
             self.set_review_selected_file(Some(path.clone()), status);
             self.scroll_to_file_start(&path);
             self.review_surface.clear_workspace_surface_snapshot();
@@ -387,6 +394,22 @@ impl DiffViewer {
         self.start_repo_tree_inline_edit(RepoTreePromptAction::RenameFile { path }, window, cx);
     }
 
+    /// Renames a changed file from the Git workspace list by opening it in Files view with the
+    /// inline rename prompt.
+    pub(super) fn rename_git_workspace_change(
+        &mut self,
+        path: String,
+        status: FileStatus,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.open_file_in_files_workspace(path.clone(), status, window, cx) {
+            return;
+        }
+        self.expand_repo_tree_ancestors(path.as_str());
+        self.open_repo_tree_rename_prompt_for_file(path, window, cx);
+    }
+
     pub(super) fn delete_repo_tree_file_at(
         &mut self,
         path: &str,
@@ -421,32 +444,51 @@ impl DiffViewer {
         cx.notify();
     }
 
-    pub(super) fn copy_repo_tree_absolute_path(
+    pub(super) fn copy_file_path(
         &mut self,
         path: &str,
+        format: FilePathCopyFormat,
         cx: &mut Context<Self>,
     ) {
-        let Some(repo_root) = self.repo_root.clone() else {
-            self.git_status_message = Some("No repository is open.".to_string());
+        let line = self.cursor_line_for_path(path);
+        let Some(text) = format_file_path_for_copy(self.repo_root.as_deref(), path, line, format)
+        else {
+            self.git_status_message = Some(match format {
+                FilePathCopyFormat::PathWithLine => format!("No cursor line in {path}"),
+                _ => "No repository is open.".to_string(),
+            });
             cx.notify();
             return;
         };
-        let absolute = repo_root.join(path);
-        cx.write_to_clipboard(ClipboardItem::new_string(
-            absolute.display().to_string(),
-        ));
-        self.git_status_message = Some(format!("Copied absolute path for {path}"));
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+        self.git_status_message = Some(file_path_copy_status_message(format, path));
         cx.notify();
     }
 
-    pub(super) fn copy_repo_tree_relative_path(
-        &mut self,
-        path: &str,
-        cx: &mut Context<Self>,
-    ) {
-        cx.write_to_clipboard(ClipboardItem::new_string(path.to_string()));
-        self.git_status_message = Some(format!("Copied relative path for {path}"));
-        cx.notify();
+    /// One-based line of the cursor in `path`: the editor caret in Files, or the selected row
+    /// in the review diff.
+    pub(super) fn cursor_line_for_path(&self, path: &str) -> Option<usize> {
+        match self.workspace_view_mode {
+            WorkspaceViewMode::Files => {
+                if self.editor_path.as_deref() != Some(path) {
+                    return None;
+                }
+                self.files_editor.borrow().cursor_line()
+            }
+            WorkspaceViewMode::Diff => {
+                let row_ix = self.review_surface.selection_head_row?;
+                let session = self.review_workspace_session.as_ref()?;
+                if session.path_at_surface_row(row_ix) != Some(path) {
+                    return None;
+                }
+                let row = session.row(row_ix)?;
+                row.right
+                    .line
+                    .or(row.left.line)
+                    .map(|line| line as usize)
+            }
+            _ => None,
+        }
     }
 
     pub(super) fn collapse_all_repo_tree_directories(&mut self, cx: &mut Context<Self>) {
@@ -458,6 +500,40 @@ impl DiffViewer {
         cx.notify();
     }
 
+    /// Excludes `path` (a file, or every file under a folder) from the overall line stats for
+    /// this session, or includes it again.
+    pub(super) fn toggle_stats_ignored_path(&mut self, path: &str, cx: &mut Context<Self>) {
+        if !self.stats_ignored_paths.remove(path) {
+            let folder_prefix = format!("{path}/");
+            self.stats_ignored_paths
+                .retain(|ignored_path| !ignored_path.starts_with(folder_prefix.as_str()));
+            self.stats_ignored_paths.insert(path.to_string());
+        }
+        cx.notify();
+    }
+
+    /// Marks every changed file under `dir` as reviewed at once, collapsing them in the review,
+    /// or clears the mark and expands them again.
+    pub(super) fn toggle_review_directory_reviewed(&mut self, dir: &str, cx: &mut Context<Self>) {
+        if let Some(collapsed) = self.review_reviewed_directories.unmark(dir) {
+            for path in &collapsed {
+                self.collapsed_files.remove(path.as_str());
+            }
+        } else {
+            let collapsed = self.review_reviewed_directories.mark(
+                dir,
+                &self.review_files,
+                &self.collapsed_files,
+            );
+            self.collapsed_files.extend(collapsed);
+        }
+
+        self.review_surface.last_diff_scroll_offset = None;
+        self.last_scroll_activity_at = Instant::now();
+        self.request_selected_diff_reload(cx);
+        cx.notify();
+    }
+
     pub(super) fn open_repo_tree_context_menu(
         &mut self,
         target_path: Option<String>,
@@ -509,209 +585,6 @@ impl DiffViewer {
         }
     }
 
-    fn selected_repo_tree_file_target(&self) -> Option<(String, RepoTreeNodeKind)> {
-        self.selected_path
-            .as_ref()
-            .map(|path| (path.clone(), RepoTreeNodeKind::File))
-    }
-
-    fn ensure_repo_tree_manageable(&mut self, cx: &mut Context<Self>) -> bool {
-        if self.workspace_view_mode != WorkspaceViewMode::Files {
-            let message = "File management is only available in Files view.".to_string();
-            self.git_status_message = Some(message.clone());
-            Self::push_warning_notification(message, None, cx);
-            cx.notify();
-            return false;
-        }
-        if self.repo_root.is_none() {
-            let message = "No repository is open.".to_string();
-            self.git_status_message = Some(message.clone());
-            Self::push_warning_notification(message, None, cx);
-            cx.notify();
-            return false;
-        }
-        true
-    }
-
-    fn start_repo_tree_inline_edit(
-        &mut self,
-        action: RepoTreePromptAction,
-        window: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        self.repo_tree_context_menu = None;
-
-        let (placeholder, initial_value) = match &action {
-            RepoTreePromptAction::CreateFile { .. } => ("e.g. notes.md", String::new()),
-            RepoTreePromptAction::CreateFolder { .. } => ("e.g. docs", String::new()),
-            RepoTreePromptAction::RenameFile { path } => {
-                ("New file name", file_name_from_repo_path(path).to_string())
-            }
-        };
-
-        let prompt_input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
-        prompt_input.update(cx, |input, cx| {
-            if !initial_value.is_empty() {
-                input.set_value(initial_value, window, cx);
-            }
-        });
-
-        let input_for_events = prompt_input.clone();
-        cx.subscribe(&prompt_input, move |this, _, event, cx| match event {
-            InputEvent::PressEnter { secondary } if !secondary => {
-                if this.repo_tree_inline_edit.as_ref().is_some_and(|edit| {
-                    edit.input_state.entity_id() == input_for_events.entity_id()
-                }) {
-                    this.submit_repo_tree_inline_edit(cx);
-                }
-            }
-            InputEvent::Blur => {
-                if this.repo_tree_inline_edit.as_ref().is_some_and(|edit| {
-                    edit.input_state.entity_id() == input_for_events.entity_id()
-                }) {
-                    this.cancel_repo_tree_inline_edit(cx);
-                }
-            }
-            _ => {}
-        })
-        .detach();
-
-        self.repo_tree_inline_edit = Some(RepoTreeInlineEditState {
-            action,
-            input_state: prompt_input,
-        });
-        if let Some(edit) = self.repo_tree_inline_edit.as_ref() {
-            edit.input_state.update(cx, |input, cx| {
-                input.focus(window, cx);
-            });
-        }
-        cx.notify();
-    }
-
-    pub(super) fn submit_repo_tree_inline_edit(&mut self, cx: &mut Context<Self>) -> bool {
-        let Some(edit_state) = self.repo_tree_inline_edit.clone() else {
-            return false;
-        };
-        let raw_value = edit_state.input_state.read(cx).value().to_string();
-        let value = raw_value.trim();
-        if value.is_empty() {
-            let message = "Path cannot be empty.".to_string();
-            self.git_status_message = Some(message.clone());
-            Self::push_warning_notification(message, None, cx);
-            cx.notify();
-            return false;
-        }
-
-        let result = match edit_state.action {
-            RepoTreePromptAction::CreateFile { base_dir } => {
-                self.create_repo_tree_file(base_dir.as_deref(), value, cx)
-            }
-            RepoTreePromptAction::CreateFolder { base_dir } => {
-                self.create_repo_tree_folder(base_dir.as_deref(), value, cx)
-            }
-            RepoTreePromptAction::RenameFile { path } => {
-                self.rename_repo_tree_file(path.as_str(), value, cx)
-            }
-        };
-
-        match result {
-            Ok(message) => {
-                self.repo_tree_inline_edit = None;
-                self.git_status_message = Some(message);
-                cx.notify();
-                true
-            }
-            Err(err) => {
-                let message = err.to_string();
-                self.git_status_message = Some(message.clone());
-                Self::push_warning_notification(message, None, cx);
-                cx.notify();
-                false
-            }
-        }
-    }
-
-    pub(super) fn cancel_repo_tree_inline_edit(&mut self, cx: &mut Context<Self>) {
-        if self.repo_tree_inline_edit.is_none() {
-            return;
-        }
-        self.repo_tree_inline_edit = None;
-        cx.notify();
-    }
-
-    fn create_repo_tree_file(
-        &mut self,
-        base_dir: Option<&str>,
-        requested_path: &str,
-        cx: &mut Context<Self>,
-    ) -> anyhow::Result<String> {
-        let Some(repo_root) = self.repo_root.clone() else {
-            anyhow::bail!("No repository is open.");
-        };
-        let relative_path = join_repo_relative(base_dir, requested_path)?;
-        fs_create_repo_tree_file(&repo_root, &relative_path)?;
-        self.expand_repo_tree_ancestors(relative_path.as_str());
-
-        if !self.prevent_unsaved_editor_discard(Some(relative_path.as_str()), cx) {
-            self.selected_path = Some(relative_path.clone());
-            self.selected_status = None;
-            if self.workspace_view_mode == WorkspaceViewMode::Files {
-                self.request_file_editor_reload(relative_path.clone(), cx);
-            }
-        }
-        self.refresh_after_repo_tree_fs_mutation(cx);
-        Ok(format!("Created {}", relative_path))
-    }
-
-    fn create_repo_tree_folder(
-        &mut self,
-        base_dir: Option<&str>,
-        requested_path: &str,
-        cx: &mut Context<Self>,
-    ) -> anyhow::Result<String> {
-        let Some(repo_root) = self.repo_root.clone() else {
-            anyhow::bail!("No repository is open.");
-        };
-        let relative_path = join_repo_relative(base_dir, requested_path)?;
-        fs_create_repo_tree_directory(&repo_root, &relative_path)?;
-        self.expand_repo_tree_ancestors(relative_path.as_str());
-        self.repo_tree.expanded_dirs.insert(relative_path.clone());
-        self.refresh_after_repo_tree_fs_mutation(cx);
-        Ok(format!("Created folder {}", relative_path))
-    }
-
-    fn rename_repo_tree_file(
-        &mut self,
-        source_path: &str,
-        requested_name: &str,
-        cx: &mut Context<Self>,
-    ) -> anyhow::Result<String> {
-        let Some(repo_root) = self.repo_root.clone() else {
-            anyhow::bail!("No repository is open.");
-        };
-        let destination_path = rename_destination_path(source_path, requested_name)?;
-        if source_path == destination_path {
-            anyhow::bail!("File name is unchanged.");
-        }
-        if self.prevent_file_editor_tab_discard_for_path(source_path, "renaming", cx) {
-            anyhow::bail!("Unsaved editor changes must be saved before renaming.");
-        }
-        fs_rename_repo_tree_file(&repo_root, source_path, destination_path.as_str())?;
-        self.expand_repo_tree_ancestors(destination_path.as_str());
-
-        if self.selected_path.as_deref() == Some(source_path) {
-            self.selected_path = Some(destination_path.clone());
-            self.selected_status = None;
-        }
-        self.sync_file_editor_tab_path(source_path, destination_path.as_str());
-        if self.editor_path.as_deref() == Some(destination_path.as_str()) {
-            self.request_file_editor_reload(destination_path.clone(), cx);
-        }
-
-        self.refresh_after_repo_tree_fs_mutation(cx);
-        Ok(format!("Renamed {} to {}", source_path, destination_path))
-    }
-
     fn expand_repo_tree_ancestors(&mut self, path: &str) {
         if let Some(parent) = repo_relative_parent_dir(path) {
             let mut current = PathBuf::new();
@@ -732,6 +605,7 @@ impl DiffViewer {
     }
 
     pub(super) fn request_repo_tree_reload(&mut self, cx: &mut Context<Self>) {
+        self.repo_tree.reload_deferred = false;
         let Some(repo_root) = self.repo_root.clone() else {
             self.repo_tree.nodes.clear();
             self.repo_tree.rows.clear();
@@ -747,6 +621,7 @@ impl DiffViewer {
             self.repo_tree.error = None;
             self.repo_tree.changed_only = false;
             self.repo_tree.last_reload = Instant::now();
+            self.repo_tree.sparse_checkout = None;
             cx.notify();
             return;
         };
@@ -777,9 +652,12 @@ impl DiffViewer {
         self.repo_tree.last_reload = std::time::Instant::now();
 
         self.repo_tree.task = cx.spawn(async move |this, cx| {
-            let result = cx
+            let (result, sparse_checkout) = cx
                 .background_executor()
-                .spawn(async move { load_repo_tree(&repo_root) })
+                .spawn(async move {
+                    let sparse_checkout = load_sparse_checkout(&repo_root);
+                    (load_repo_tree(&repo_root), sparse_checkout)
+                })
                 .await;
 
             if let Some(this) = this.upgrade() {
@@ -788,6 +666,10 @@ impl DiffViewer {
                         return;
                     }
 
+                    this.repo_tree.sparse_checkout = sparse_checkout.unwrap_or_else(|err| {
+                        warn!("failed to read sparse checkout settings: {err:#}");
+                        None
+                    });
                     self::apply_repo_tree_reload(this, result, cx);
                 });
             }
@@ -805,7 +687,11 @@ impl DiffViewer {
     }
 
     fn rebuild_repo_tree_for_changed_files(&mut self) {
-        self.repo_tree.nodes = build_changed_files_tree(self.active_diff_files());
+        self.repo_tree.nodes = build_changed_files_tree(
+            self.active_diff_files(),
+            self.active_diff_file_line_stats(),
+            self.repo_tree.changed_sort,
+        );
         self.repo_tree.file_count = count_repo_tree_kind(&self.repo_tree.nodes, RepoTreeNodeKind::File);
         self.repo_tree.folder_count =
             count_repo_tree_kind(&self.repo_tree.nodes, RepoTreeNodeKind::Directory);
@@ -813,6 +699,31 @@ impl DiffViewer {
         self.rebuild_repo_tree_rows();
     }
 
+    /// Scrolls the tree just enough to show `path`, if it has a row.
+    fn reveal_path_in_repo_tree(&self, path: &str) {
+        if let Some(row_ix) = self.repo_tree.rows.iter().position(|row| row.path == path) {
+            self.repo_tree.list_state.scroll_to_reveal_item(row_ix);
+        }
+    }
+
+    pub(super) fn toggle_review_tree_follow(&mut self, cx: &mut Context<Self>) {
+        self.review_tree_follow = !self.review_tree_follow;
+        self.review_tree_clicked_path = None;
+        if self.review_tree_follow {
+            // Catch up with wherever the diff was scrolled while following was off.
+            self.review_surface.last_diff_scroll_offset = None;
+        }
+        cx.notify();
+    }
+
+    pub(super) fn toggle_changed_files_sort(&mut self, cx: &mut Context<Self>) {
+        self.repo_tree.changed_sort = self.repo_tree.changed_sort.toggled();
+        if self.repo_tree.changed_only {
+            self.rebuild_repo_tree_for_changed_files();
+        }
+        cx.notify();
+    }
+
     fn sync_full_repo_tree_cache_from_current(&mut self) {
         self.repo_tree.full_cache = Some(RepoTreeCacheState {
             nodes: self.repo_tree.nodes.clone(),
@@ -193,14 +193,11 @@ fn fs_rename_repo_tree_file(
         );
     }
 
-    std::fs::rename(&source_absolute, &destination_absolute).with_context(|| {
-        format!(
-            "failed to rename {} to {}",
-            source_absolute.display(),
-            destination_absolute.display()
-        )
-    })?;
-    Ok(())
+    move_worktree_path(
+        repo_root,
+        repo_relative_path_from_pathbuf(&source).as_str(),
+        repo_relative_path_from_pathbuf(&destination).as_str(),
+    )
 }
 
 fn fs_delete_repo_tree_file(repo_root: &Path, path: &str) -> anyhow::Result<()> {
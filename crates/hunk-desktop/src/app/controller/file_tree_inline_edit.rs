@@ -0,0 +1,209 @@
+impl DiffViewer {
+    fn selected_repo_tree_file_target(&self) -> Option<(String, RepoTreeNodeKind)> {
+        self.selected_path
+            .as_ref()
+            .map(|path| (path.clone(), RepoTreeNodeKind::File))
+    }
+
+    fn ensure_repo_tree_manageable(&mut self, cx: &mut Context<Self>) -> bool {
+        if self.workspace_view_mode != WorkspaceViewMode::Files {
+            let message = "File management is only available in Files view.".to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return false;
+        }
+        if self.repo_root.is_none() {
+            let message = "No repository is open.".to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return false;
+        }
+        !self.block_in_read_only("File management", cx)
+    }
+
+    fn start_repo_tree_inline_edit(
+        &mut self,
+        action: RepoTreePromptAction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.repo_tree_context_menu = None;
+
+        let (placeholder, initial_value) = match &action {
+            RepoTreePromptAction::CreateFile { .. } => ("e.g. notes.md", String::new()),
+            RepoTreePromptAction::CreateFolder { .. } => ("e.g. docs", String::new()),
+            RepoTreePromptAction::RenameFile { path } => {
+                ("New file name", file_name_from_repo_path(path).to_string())
+            }
+        };
+
+        let prompt_input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+        prompt_input.update(cx, |input, cx| {
+            if !initial_value.is_empty() {
+                input.set_value(initial_value, window, cx);
+            }
+        });
+
+        let input_for_events = prompt_input.clone();
+        cx.subscribe(&prompt_input, move |this, _, event, cx| match event {
+            InputEvent::PressEnter { secondary } if !secondary => {
+                if this.repo_tree_inline_edit.as_ref().is_some_and(|edit| {
+                    edit.input_state.entity_id() == input_for_events.entity_id()
+                }) {
+                    this.submit_repo_tree_inline_edit(cx);
+                }
+            }
+            InputEvent::Blur => {
+                if this.repo_tree_inline_edit.as_ref().is_some_and(|edit| {
+                    edit.input_state.entity_id() == input_for_events.entity_id()
+                }) {
+                    this.cancel_repo_tree_inline_edit(cx);
+                }
+            }
+            _ => {}
+        })
+        .detach();
+
+        self.repo_tree_inline_edit = Some(RepoTreeInlineEditState {
+            action,
+            input_state: prompt_input,
+        });
+        if let Some(edit) = self.repo_tree_inline_edit.as_ref() {
+            edit.input_state.update(cx, |input, cx| {
+                input.focus(window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    pub(super) fn submit_repo_tree_inline_edit(&mut self, cx: &mut Context<Self>) -> bool {
+        let Some(edit_state) = self.repo_tree_inline_edit.clone() else {
+            return false;
+        };
+        let raw_value = edit_state.input_state.read(cx).value().to_string();
+        let value = raw_value.trim();
+        if value.is_empty() {
+            let message = "Path cannot be empty.".to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return false;
+        }
+
+        let result = match edit_state.action {
+            RepoTreePromptAction::CreateFile { base_dir } => {
+                self.create_repo_tree_file(base_dir.as_deref(), value, cx)
+            }
+            RepoTreePromptAction::CreateFolder { base_dir } => {
+                self.create_repo_tree_folder(base_dir.as_deref(), value, cx)
+            }
+            RepoTreePromptAction::RenameFile { path } => {
+                self.rename_repo_tree_file(path.as_str(), value, cx)
+            }
+        };
+
+        match result {
+            Ok(message) => {
+                self.repo_tree_inline_edit = None;
+                self.git_status_message = Some(message);
+                cx.notify();
+                true
+            }
+            Err(err) => {
+                let message = err.to_string();
+                self.git_status_message = Some(message.clone());
+                Self::push_warning_notification(message, None, cx);
+                cx.notify();
+                false
+            }
+        }
+    }
+
+    pub(super) fn cancel_repo_tree_inline_edit(&mut self, cx: &mut Context<Self>) {
+        if self.repo_tree_inline_edit.is_none() {
+            return;
+        }
+        self.repo_tree_inline_edit = None;
+        cx.notify();
+    }
+
+    fn create_repo_tree_file(
+        &mut self,
+        base_dir: Option<&str>,
+        requested_path: &str,
+        cx: &mut Context<Self>,
+    ) -> anyhow::Result<String> {
+        let Some(repo_root) = self.repo_root.clone() else {
+            anyhow::bail!("No repository is open.");
+        };
+        let relative_path = join_repo_relative(base_dir, requested_path)?;
+        fs_create_repo_tree_file(&repo_root, &relative_path)?;
+        self.expand_repo_tree_ancestors(relative_path.as_str());
+
+        if !self.prevent_unsaved_editor_discard(Some(relative_path.as_str()), cx) {
+            self.selected_path = Some(relative_path.clone());
+            self.selected_status = None;
+            if self.workspace_view_mode == WorkspaceViewMode::Files {
+                self.request_file_editor_reload(relative_path.clone(), cx);
+            }
+        }
+        self.refresh_after_repo_tree_fs_mutation(cx);
+        Ok(format!("Created {}", relative_path))
+    }
+
+    fn create_repo_tree_folder(
+        &mut self,
+        base_dir: Option<&str>,
+        requested_path: &str,
+        cx: &mut Context<Self>,
+    ) -> anyhow::Result<String> {
+        let Some(repo_root) = self.repo_root.clone() else {
+            anyhow::bail!("No repository is open.");
+        };
+        let relative_path = join_repo_relative(base_dir, requested_path)?;
+        fs_create_repo_tree_directory(&repo_root, &relative_path)?;
+        self.expand_repo_tree_ancestors(relative_path.as_str());
+        self.repo_tree.expanded_dirs.insert(relative_path.clone());
+        self.refresh_after_repo_tree_fs_mutation(cx);
+        Ok(format!("Created folder {}", relative_path))
+    }
+
+    fn rename_repo_tree_file(
+        &mut self,
+        source_path: &str,
+        requested_name: &str,
+        cx: &mut Context<Self>,
+    ) -> anyhow::Result<String> {
+        let Some(repo_root) = self.repo_root.clone() else {
+            anyhow::bail!("No repository is open.");
+        };
+        let destination_path = rename_destination_path(source_path, requested_name)?;
+        if source_path == destination_path {
+            anyhow::bail!("File name is unchanged.");
+        }
+        if self.prevent_file_editor_tab_discard_for_path(source_path, "renaming", cx) {
+            anyhow::bail!("Unsaved editor changes must be saved before renaming.");
+        }
+        fs_rename_repo_tree_file(&repo_root, source_path, destination_path.as_str())?;
+        self.expand_repo_tree_ancestors(destination_path.as_str());
+
+        if self.selected_path.as_deref() == Some(source_path) {
+            self.selected_path = Some(destination_path.clone());
+            self.selected_status = None;
+        }
+        self.sync_file_editor_tab_path(source_path, destination_path.as_str());
+        if self.editor_path.as_deref() == Some(destination_path.as_str()) {
+            self.request_file_editor_reload(destination_path.clone(), cx);
+        }
+
+        self.migrate_comments_for_renamed_paths(
+            vec![(source_path.to_string(), destination_path.clone())],
+            cx,
+        );
+
+        self.refresh_after_repo_tree_fs_mutation(cx);
+        Ok(format!("Renamed {} to {}", source_path, destination_path))
+    }
+}
@@ -0,0 +1,69 @@
+impl DiffViewer {
+    fn branch_name_policy(&self) -> anyhow::Result<Option<BranchNamePolicy>> {
+        BranchNamePolicy::parse(self.config.branch_naming.template.as_str())
+    }
+
+    /// Why the typed branch name breaks the configured naming convention, if it does.
+    ///
+    /// Existing branches can always be switched to, so only new names are checked.
+    pub(super) fn branch_name_policy_message(&self, raw_name: &str) -> Option<String> {
+        if raw_name.trim().is_empty() {
+            return None;
+        }
+        let branch_name = sanitize_branch_name(raw_name);
+        if self.branches.iter().any(|branch| branch.name == branch_name) {
+            return None;
+        }
+
+        match self.branch_name_policy() {
+            Ok(Some(policy)) => policy.violation_message(branch_name.as_str()),
+            Ok(None) => None,
+            Err(err) => Some(format!("Invalid branch naming template: {err:#}")),
+        }
+    }
+
+    pub(super) fn has_branch_name_policy(&self) -> bool {
+        !self.config.branch_naming.template.trim().is_empty()
+    }
+
+    /// Starts the branch input from the naming template, filling `{user}` from the configured
+    /// initials and `{ticket}` from a ticket id on the clipboard.
+    pub(super) fn fill_branch_input_from_template(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let policy = match self.branch_name_policy() {
+            Ok(Some(policy)) => policy,
+            Ok(None) => return,
+            Err(err) => {
+                self.set_git_warning_message(
+                    format!("Invalid branch naming template: {err:#}"),
+                    Some(window),
+                    cx,
+                );
+                return;
+            }
+        };
+        let ticket = cx
+            .read_from_clipboard()
+            .and_then(|item| item.text())
+            .and_then(|text| {
+                self.ticket_linker
+                    .as_ref()
+                    .and_then(|linker| linker.first_ticket_id(text.as_str()))
+                    .or_else(|| detect_ticket_id(text.as_str()))
+            });
+        let filled = policy.autofill(
+            self.config.branch_naming.user_initials.as_str(),
+            ticket.as_deref(),
+        );
+        self.branch_input_has_text = !filled.trim().is_empty();
+        self.branch_name_policy_message = self.branch_name_policy_message(filled.as_str());
+        self.branch_input_state.update(cx, |state, cx| {
+            state.set_value(filled, window, cx);
+            state.focus(window, cx);
+        });
+        cx.notify();
+    }
+}
@@ -0,0 +1,64 @@
+fn git_action_cancel_token(timeout_secs: u64) -> CancellationToken {
+    if timeout_secs == 0 {
+        CancellationToken::new()
+    } else {
+        CancellationToken::with_timeout(Duration::from_secs(timeout_secs))
+    }
+}
+
+impl DiffViewer {
+    pub(super) fn git_action_cancellable(&self) -> bool {
+        self.git_action_loading && self.git_action_cancel.is_some()
+    }
+
+    fn install_git_action_cancel(&mut self, timeout_secs: u64) -> CancellationToken {
+        let cancel = git_action_cancel_token(timeout_secs);
+        self.git_action_cancel = Some(cancel.clone());
+        cancel
+    }
+
+    fn run_cancellable_git_action<F>(
+        &mut self,
+        action_name: &'static str,
+        timeout_secs: u64,
+        cx: &mut Context<Self>,
+        action: F,
+    ) -> bool
+    where
+        F: FnOnce(std::path::PathBuf, CancellationToken) -> anyhow::Result<String> + Send + 'static,
+    {
+        let cancel = git_action_cancel_token(timeout_secs);
+        let task_cancel = cancel.clone();
+        let started = self.run_git_action(action_name, cx, move |repo_root| {
+            action(repo_root, task_cancel)
+        });
+        if started {
+            self.git_action_cancel = Some(cancel);
+        }
+        started
+    }
+
+    pub(super) fn git_action_cancelling(&self) -> bool {
+        self.git_action_loading && self.git_action_cancelling
+    }
+
+    /// Asks the running network operation to stop. The controls stay busy until the backend
+    /// task returns, since libgit2 may still hold ref or index locks while it unwinds.
+    pub(super) fn cancel_git_action(&mut self, cx: &mut Context<Self>) {
+        if self.git_action_cancelling {
+            return;
+        }
+        let Some(cancel) = self.git_action_cancel.as_ref() else {
+            return;
+        };
+        cancel.cancel();
+        self.git_action_cancelling = true;
+
+        let action_label = self
+            .git_action_label
+            .clone()
+            .unwrap_or_else(|| "Git action".to_string());
+        self.git_status_message = Some(format!("Cancelling {action_label}…"));
+        cx.notify();
+    }
+}
@@ -0,0 +1,108 @@
+const CHANGELOG_COMMIT_LIMIT: usize = 200;
+
+impl DiffViewer {
+    /// Writes a changelog fragment for the checked out branch's commits to the configured
+    /// path and opens it in the Files editor for tweaks.
+    pub(super) fn generate_changelog_fragment(&mut self, cx: &mut Context<Self>) {
+        if self.git_controls_busy() || self.block_in_read_only("Changelog fragment", cx) {
+            return;
+        }
+        let Some(branch_name) = self.checked_out_branch_name().map(str::to_string) else {
+            let message = "Activate a branch before generating a changelog fragment.".to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return;
+        };
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+        let relative_path = changelog_fragment_path(
+            self.config.changelog.fragment_path.as_str(),
+            branch_name.as_str(),
+        );
+        if relative_path.is_empty() {
+            let message = "Set changelog.fragment_path in the config file first.".to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return;
+        }
+
+        let format = self.config.changelog.format;
+        let open_in_files = self.repo_root.as_ref() == Some(&repo_root);
+        let epoch = self.begin_git_action("Generate changelog", cx);
+        self.git_action_task = cx.spawn(async move |this, cx| {
+            let write_path = relative_path.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let messages = load_branch_commit_messages(&repo_root, CHANGELOG_COMMIT_LIMIT)?;
+                    let fragment = changelog_fragment(
+                        messages.iter().rev().map(|commit| commit.message.as_str()),
+                        format,
+                    );
+                    if fragment.is_empty() {
+                        return Err(anyhow::anyhow!(
+                            "no changelog-worthy commits on {branch_name}"
+                        ));
+                    }
+                    let path = repo_root.join(write_path.as_str());
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)
+                            .with_context(|| format!("failed to create {}", parent.display()))?;
+                    }
+                    std::fs::write(&path, fragment)
+                        .with_context(|| format!("failed to write {}", path.display()))?;
+                    Ok::<_, anyhow::Error>(messages.len())
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if epoch != this.git_action_epoch {
+                        return;
+                    }
+                    this.finish_git_action();
+                    match result {
+                        Ok(commit_count) => {
+                            this.git_status_message =
+                                Some(format!("Wrote {relative_path} from {commit_count} commits"));
+                            this.refresh_after_git_action("Generate changelog", cx);
+                            if open_in_files {
+                                this.defer_open_file_in_files_workspace(relative_path, cx);
+                            }
+                        }
+                        Err(err) => {
+                            error!("changelog fragment generation failed: {err:#}");
+                            this.git_status_message =
+                                Some(format!("Changelog fragment failed: {err:#}"));
+                            Self::push_error_notification(
+                                format!("Changelog fragment failed: {err}"),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    fn defer_open_file_in_files_workspace(&self, path: String, cx: &mut Context<Self>) {
+        let window_handle = self.window_handle;
+        let view = cx.entity();
+        cx.defer(move |cx| {
+            let result = cx.update_window(window_handle, |_, window, cx| {
+                view.update(cx, |this, cx| {
+                    this.open_file_in_files_workspace(path, FileStatus::Untracked, window, cx);
+                });
+            });
+            if let Err(err) = result {
+                error!("failed to open changelog fragment: {err:#}");
+            }
+        });
+    }
+}
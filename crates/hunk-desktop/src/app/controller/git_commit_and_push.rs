@@ -0,0 +1,322 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CombinedWorkspaceCommitAndPushBlocker {
+    Busy,
+    ReadOnly,
+    MissingBranch,
+    MissingRepo,
+    NoChanges,
+    PathCollisions,
+}
+
+impl CombinedWorkspaceCommitAndPushBlocker {
+    const fn message(self) -> &'static str {
+        match self {
+            Self::Busy => "Another workspace action is in progress.",
+            Self::ReadOnly => "This repository is open in read-only mode.",
+            Self::MissingBranch => "Activate a branch before committing and pushing.",
+            Self::MissingRepo => "No Git repository available.",
+            Self::NoChanges => "No changed files to stage and commit.",
+            Self::PathCollisions => {
+                "Acknowledge the case warnings on changed files before committing."
+            }
+        }
+    }
+}
+
+struct CombinedWorkspaceCommitAndPushOutcome {
+    created_commit: hunk_git::mutation::CreatedCommit,
+    branch_name: String,
+    /// Why the push was skipped after committing, when it was.
+    push_skipped: Option<String>,
+}
+
+/// Names the step that failed and everything that completed before it, since the earlier steps
+/// are not rolled back.
+fn combined_workspace_commit_and_push_failure(step: &str, completed: &[String]) -> String {
+    if completed.is_empty() {
+        format!("{step} failed")
+    } else {
+        format!("{step} failed after {}", completed.join(" and "))
+    }
+}
+
+impl DiffViewer {
+    fn combined_workspace_commit_and_push_blocker(
+        &self,
+    ) -> Option<CombinedWorkspaceCommitAndPushBlocker> {
+        if self.git_rail_controls_busy() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::Busy);
+        }
+        if self.read_only_active() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::ReadOnly);
+        }
+        if !self.branch_syncable() || !self.active_branch_is_checked_out() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::MissingBranch);
+        }
+        if self.selected_git_workspace_root().is_none() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::MissingRepo);
+        }
+        if self.git_workspace.files.is_empty() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::NoChanges);
+        }
+        if !self.unacknowledged_commit_path_collisions(false).is_empty() {
+            return Some(CombinedWorkspaceCommitAndPushBlocker::PathCollisions);
+        }
+        None
+    }
+
+    pub(super) fn combined_workspace_commit_and_push_tooltip(&self) -> String {
+        if let Some(blocker) = self.combined_workspace_commit_and_push_blocker() {
+            return blocker.message().to_string();
+        }
+        if self.active_branch_is_protected() {
+            return format!(
+                "Stage all changed files, generate a commit message, and create a commit. '{}' is protected, so it is not pushed.",
+                self.git_workspace.branch_name
+            );
+        }
+        "Stage all changed files, generate a commit message, create a commit, and push or publish this branch. The push is skipped if the remote has commits this branch does not."
+            .to_string()
+    }
+
+    fn active_branch_is_protected(&self) -> bool {
+        is_protected_branch(
+            self.git_workspace.branch_name.as_str(),
+            &self.config.branch_naming.protected_branches,
+        )
+    }
+
+    pub(super) fn can_run_combined_workspace_commit_and_push_for_ui(&self) -> bool {
+        self.combined_workspace_commit_and_push_blocker().is_none()
+    }
+
+    pub(super) fn confirm_combined_workspace_commit_and_push(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(blocker) = self.combined_workspace_commit_and_push_blocker() {
+            self.set_git_warning_message(blocker.message().to_string(), Some(window), cx);
+            return;
+        }
+
+        let branch_name = self.git_workspace.branch_name.clone();
+        let changed_count = self.git_workspace.files.len();
+        let view = cx.entity();
+
+        gpui_component::WindowExt::open_alert_dialog(window, cx, move |alert, _, _| {
+            alert
+                .width(px(460.0))
+                .title("Commit And Push?")
+                .description(format!(
+                    "Stage all {changed_count} changed file(s), generate a commit message, create a commit, and push branch '{branch_name}'?"
+                ))
+                .button_props(
+                    gpui_component::dialog::DialogButtonProps::default()
+                        .ok_text("Yes")
+                        .cancel_text("No")
+                        .show_cancel(true),
+                )
+                .on_ok({
+                    let view = view.clone();
+                    move |_, _, cx| {
+                        view.update(cx, |this, cx| {
+                            this.run_combined_workspace_commit_and_push(cx);
+                        });
+                        true
+                    }
+                })
+        });
+    }
+
+    fn run_combined_workspace_commit_and_push(&mut self, cx: &mut Context<Self>) {
+        if let Some(blocker) = self.combined_workspace_commit_and_push_blocker() {
+            let message = blocker.message().to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return;
+        }
+
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            let message = CombinedWorkspaceCommitAndPushBlocker::MissingRepo
+                .message()
+                .to_string();
+            self.git_status_message = Some(message.clone());
+            Self::push_warning_notification(message, None, cx);
+            cx.notify();
+            return;
+        };
+
+        let changed_paths = self
+            .git_workspace
+            .files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect::<Vec<_>>();
+        let branch_name = self.git_workspace.branch_name.clone();
+        let protected_push_skip = protected_branch_push_skip_reason(
+            branch_name.as_str(),
+            &self.config.branch_naming.protected_branches,
+        );
+        let codex_executable = Self::resolve_codex_executable_path();
+        let epoch = self.begin_git_action("Commit and Push", cx);
+        let cancel = self.install_git_action_cancel(self.config.git_timeouts.push_secs);
+        self.begin_ai_git_progress(
+            epoch,
+            AiGitProgressAction::WorkspaceCommitAndPush,
+            crate::app::ai_git_progress::workspace_commit_and_push_progress_steps(),
+            AiGitProgressStep::StagingFiles,
+            Some(format!("Files: {}", changed_paths.len())),
+            cx,
+        );
+
+        self.spawn_ai_git_action_with_progress(
+            epoch,
+            cx,
+            move |progress_tx| {
+                let mut step = "Staging files";
+                let mut completed = Vec::new();
+                let result = (|| -> anyhow::Result<CombinedWorkspaceCommitAndPushOutcome> {
+                    stage_paths(repo_root.as_path(), &changed_paths)?;
+                    completed.push(format!("staging {} file(s)", changed_paths.len()));
+
+                    step = "Generating the commit message";
+                    send_ai_git_progress(
+                        &progress_tx,
+                        AiGitProgressStep::GeneratingCommitMessage,
+                        Some(ai_branch_progress_detail("Branch", branch_name.as_str())),
+                    );
+                    let commit_message = try_ai_commit_message_for_staged_index(
+                        AiCodexGenerationConfig {
+                            codex_executable: codex_executable.as_path(),
+                            repo_root: repo_root.as_path(),
+                        },
+                        repo_root.as_path(),
+                        branch_name.as_str(),
+                    )?;
+
+                    step = "Creating the commit";
+                    send_ai_git_progress(
+                        &progress_tx,
+                        AiGitProgressStep::CreatingCommit,
+                        Some(ai_commit_progress_detail(commit_message.subject.as_str())),
+                    );
+                    let created_commit = commit_index_with_details(
+                        repo_root.as_path(),
+                        commit_message.as_git_message().as_str(),
+                    )?;
+                    completed.push(format!("committing \"{}\"", created_commit.subject));
+                    let skipped = |reason: String| CombinedWorkspaceCommitAndPushOutcome {
+                        created_commit: created_commit.clone(),
+                        branch_name: branch_name.clone(),
+                        push_skipped: Some(reason),
+                    };
+                    if let Some(reason) = protected_push_skip.as_ref() {
+                        return Ok(skipped(reason.clone()));
+                    }
+
+                    step = "Pushing";
+                    send_ai_git_progress(
+                        &progress_tx,
+                        AiGitProgressStep::PushingBranch,
+                        Some(ai_branch_progress_detail("Branch", branch_name.as_str())),
+                    );
+                    if branch_behind_remote_with_cancel(
+                        repo_root.as_path(),
+                        branch_name.as_str(),
+                        &cancel,
+                    )? {
+                        return Ok(skipped(format!(
+                            "the remote '{branch_name}' has commits this branch does not; sync before pushing"
+                        )));
+                    }
+                    push_current_branch_with_publish_fallback(
+                        repo_root.as_path(),
+                        branch_name.as_str(),
+                        &cancel,
+                    )?;
+
+                    Ok(CombinedWorkspaceCommitAndPushOutcome {
+                        created_commit,
+                        branch_name: branch_name.clone(),
+                        push_skipped: None,
+                    })
+                })();
+                result.map_err(|err| {
+                    err.context(combined_workspace_commit_and_push_failure(step, &completed))
+                })
+            },
+            move |this, result, execution_elapsed, total_elapsed, cx| {
+                if epoch != this.git_action_epoch {
+                    return;
+                }
+
+                this.finish_git_action();
+                match result {
+                    Ok(CombinedWorkspaceCommitAndPushOutcome {
+                        created_commit,
+                        branch_name,
+                        push_skipped,
+                    }) => {
+                        debug!(
+                            "git action complete: epoch={} action=Commit and Push exec_elapsed_ms={} total_elapsed_ms={} branch={}",
+                            epoch,
+                            execution_elapsed.as_millis(),
+                            total_elapsed.as_millis(),
+                            branch_name
+                        );
+                        this.apply_optimistic_commit_success(created_commit.subject.as_str());
+                        this.apply_optimistic_recent_commit(&created_commit);
+                        this.request_snapshot_refresh_workflow_only(true, cx);
+                        this.request_git_workspace_refresh(false, cx);
+                        this.request_recent_commits_refresh(true, cx);
+
+                        let commit_input_state = this.commit_input_state.clone();
+                        if let Some(window_handle) = cx.windows().into_iter().next()
+                            && let Err(err) = cx.update_window(window_handle, |_, window, cx| {
+                                commit_input_state.update(cx, |state, cx| {
+                                    state.set_value("", window, cx);
+                                });
+                            })
+                        {
+                            error!(
+                                "failed to clear commit input after combined commit and push: {err:#}"
+                            );
+                        }
+
+                        if let Some(reason) = push_skipped {
+                            let message = format!(
+                                "Committed {} but did not push: {reason}",
+                                created_commit.subject
+                            );
+                            this.git_status_message = Some(message.clone());
+                            Self::push_warning_notification(message, None, cx);
+                        } else {
+                            let message = format!("Committed and pushed {}", branch_name);
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                        }
+                    }
+                    Err(err) => {
+                        error!(
+                            "git action failed: epoch={} action=Commit and Push exec_elapsed_ms={} total_elapsed_ms={} err={err:#}",
+                            epoch,
+                            execution_elapsed.as_millis(),
+                            total_elapsed.as_millis()
+                        );
+                        let summary = err.to_string();
+                        this.git_status_message = Some(format!("Git error: {err:#}"));
+                        Self::push_git_error_notification(
+                            format!("Commit and Push failed: {summary}"),
+                            &err,
+                            cx,
+                        );
+                    }
+                }
+
+                cx.notify();
+            },
+        );
+    }
+}
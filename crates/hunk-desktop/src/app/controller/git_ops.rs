@@ -1,22 +1,3 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum CombinedWorkspaceCommitAndPushBlocker {
-    Busy,
-    MissingBranch,
-    MissingRepo,
-    NoChanges,
-}
-
-impl CombinedWorkspaceCommitAndPushBlocker {
-    const fn message(self) -> &'static str {
-        match self {
-            Self::Busy => "Another workspace action is in progress.",
-            Self::MissingBranch => "Activate a branch before committing and pushing.",
-            Self::MissingRepo => "No Git repository available.",
-            Self::NoChanges => "No changed files to stage and commit.",
-        }
-    }
-}
-
 impl DiffViewer {
     pub(super) fn git_controls_busy(&self) -> bool {
         self.git_action_loading || self.workspace_target_switch_loading
@@ -26,6 +7,7 @@ impl DiffViewer {
         self.git_action_label.as_deref().is_some_and(|label| {
             label.eq_ignore_ascii_case("Stage files")
                 || label.eq_ignore_ascii_case("Unstage files")
+                || label.eq_ignore_ascii_case("Stage hunk")
         })
     }
 
@@ -135,6 +117,8 @@ impl DiffViewer {
     fn finish_git_action(&mut self) {
         self.git_action_loading = false;
         self.git_action_label = None;
+        self.git_action_cancel = None;
+        self.git_action_cancelling = false;
         self.ai_git_progress = None;
     }
 
@@ -241,7 +225,7 @@ impl DiffViewer {
     where
         F: FnOnce(std::path::PathBuf) -> anyhow::Result<String> + Send + 'static,
     {
-        if self.git_controls_busy() {
+        if self.git_controls_busy() || self.block_in_read_only(action_name, cx) {
             return false;
         }
 
@@ -302,6 +286,7 @@ impl DiffViewer {
                                 fingerprint,
                                 workflow_snapshot,
                             );
+                            this.refresh_review_hunk_stage_states(cx);
                         }
                         Ok((message, Err(err))) => {
                             warn!(
@@ -315,6 +300,7 @@ impl DiffViewer {
                             };
                             this.apply_optimistic_git_action_success(action_name);
                             this.refresh_after_git_action(action_name, cx);
+                            this.refresh_review_hunk_stage_states(cx);
                         }
                         Err(err) => {
                             error!(
@@ -326,8 +312,9 @@ impl DiffViewer {
                             );
                             let summary = err.to_string();
                             this.git_status_message = Some(format!("Git error: {err:#}"));
-                            Self::push_error_notification(
+                            Self::push_git_error_notification(
                                 format!("{action_name} failed: {summary}"),
+                                &err,
                                 cx,
                             );
                         }
@@ -350,7 +337,7 @@ impl DiffViewer {
     where
         F: FnOnce(std::path::PathBuf) -> anyhow::Result<String> + Send + 'static,
     {
-        if self.git_controls_busy() {
+        if self.git_controls_busy() || self.block_in_read_only(action_name, cx) {
             return false;
         }
 
@@ -396,6 +383,9 @@ impl DiffViewer {
                                 Some(message)
                             };
                             this.apply_optimistic_git_action_success(action_name);
+                            if matches!(action_name, "Publish branch" | "Push branch") {
+                                this.start_ci_watch_after_push(cx);
+                            }
                             this.refresh_after_git_action(action_name, cx);
                         }
                         Err(err) => {
@@ -408,8 +398,9 @@ impl DiffViewer {
                             );
                             let summary = err.to_string();
                             this.git_status_message = Some(format!("Git error: {err:#}"));
-                            Self::push_error_notification(
+                            Self::push_git_error_notification(
                                 format!("{action_name} failed: {summary}"),
+                                &err,
                                 cx,
                             );
                             if action_name == "Activate branch" {
@@ -485,6 +476,45 @@ impl DiffViewer {
         );
     }
 
+    /// Stages one hunk of a working-copy file so a staged commit can leave the rest of the file
+    /// behind. The file list still reports a partly staged file as both staged and unstaged; the
+    /// checkboxes on the review's hunk headers show which hunks are in the index.
+    pub(super) fn stage_review_hunk(
+        &mut self,
+        file_path: String,
+        hunk: HunkSelector,
+        cx: &mut Context<Self>,
+    ) {
+        if self.git_controls_busy() {
+            return;
+        }
+
+        self.run_git_index_action("Stage hunk", cx, move |repo_root| {
+            stage_hunk(&repo_root, file_path.as_str(), &hunk)?;
+            Ok(tr_args("git-staged-hunk", &[("path", file_path.as_str())]))
+        });
+    }
+
+    /// Takes one staged hunk back out of the index, leaving the worktree as it is.
+    pub(super) fn unstage_review_hunk(
+        &mut self,
+        file_path: String,
+        hunk: HunkSelector,
+        cx: &mut Context<Self>,
+    ) {
+        if self.git_controls_busy() {
+            return;
+        }
+
+        self.run_git_index_action("Unstage hunk", cx, move |repo_root| {
+            unstage_hunk(&repo_root, file_path.as_str(), &hunk)?;
+            Ok(tr_args(
+                "git-unstaged-hunk",
+                &[("path", file_path.as_str())],
+            ))
+        });
+    }
+
     pub(super) fn stage_all_files_for_commit(&mut self, cx: &mut Context<Self>) {
         if self.git_controls_busy() || !self.git_workspace.files.iter().any(|file| file.unstaged) {
             return;
@@ -579,7 +609,10 @@ impl DiffViewer {
     }
 
     pub(super) fn can_run_active_branch_actions_for_ui(&self) -> bool {
-        self.branch_syncable() && self.active_branch_is_checked_out() && !self.git_rail_controls_busy()
+        self.branch_syncable()
+            && self.active_branch_is_checked_out()
+            && !self.git_rail_controls_busy()
+            && !self.read_only_active()
     }
 
     fn tracking_area_clean(&self) -> bool {
@@ -607,216 +640,6 @@ impl DiffViewer {
             && !self.git_rail_controls_busy()
     }
 
-    fn combined_workspace_commit_and_push_blocker(
-        &self,
-    ) -> Option<CombinedWorkspaceCommitAndPushBlocker> {
-        if self.git_rail_controls_busy() {
-            return Some(CombinedWorkspaceCommitAndPushBlocker::Busy);
-        }
-        if !self.branch_syncable() || !self.active_branch_is_checked_out() {
-            return Some(CombinedWorkspaceCommitAndPushBlocker::MissingBranch);
-        }
-        if self.selected_git_workspace_root().is_none() {
-            return Some(CombinedWorkspaceCommitAndPushBlocker::MissingRepo);
-        }
-        if self.git_workspace.files.is_empty() {
-            return Some(CombinedWorkspaceCommitAndPushBlocker::NoChanges);
-        }
-        None
-    }
-
-    pub(super) fn combined_workspace_commit_and_push_tooltip(&self) -> String {
-        self.combined_workspace_commit_and_push_blocker()
-            .map(|blocker| blocker.message().to_string())
-            .unwrap_or_else(|| {
-                "Stage all changed files, generate a commit message, create a commit, and push or publish this branch."
-                    .to_string()
-            })
-    }
-
-    pub(super) fn can_run_combined_workspace_commit_and_push_for_ui(&self) -> bool {
-        self.combined_workspace_commit_and_push_blocker().is_none()
-    }
-
-    pub(super) fn confirm_combined_workspace_commit_and_push(
-        &mut self,
-        window: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        if let Some(blocker) = self.combined_workspace_commit_and_push_blocker() {
-            self.set_git_warning_message(blocker.message().to_string(), Some(window), cx);
-            return;
-        }
-
-        let branch_name = self.git_workspace.branch_name.clone();
-        let changed_count = self.git_workspace.files.len();
-        let view = cx.entity();
-
-        gpui_component::WindowExt::open_alert_dialog(window, cx, move |alert, _, _| {
-            alert
-                .width(px(460.0))
-                .title("Commit And Push?")
-                .description(format!(
-                    "Stage all {changed_count} changed file(s), generate a commit message, create a commit, and push branch '{branch_name}'?"
-                ))
-                .button_props(
-                    gpui_component::dialog::DialogButtonProps::default()
-                        .ok_text("Yes")
-                        .cancel_text("No")
-                        .show_cancel(true),
-                )
-                .on_ok({
-                    let view = view.clone();
-                    move |_, _, cx| {
-                        view.update(cx, |this, cx| {
-                            this.run_combined_workspace_commit_and_push(cx);
-                        });
-                        true
-                    }
-                })
-        });
-    }
-
-    fn run_combined_workspace_commit_and_push(&mut self, cx: &mut Context<Self>) {
-        if let Some(blocker) = self.combined_workspace_commit_and_push_blocker() {
-            let message = blocker.message().to_string();
-            self.git_status_message = Some(message.clone());
-            Self::push_warning_notification(message, None, cx);
-            cx.notify();
-            return;
-        }
-
-        let Some(repo_root) = self.selected_git_workspace_root() else {
-            let message = CombinedWorkspaceCommitAndPushBlocker::MissingRepo
-                .message()
-                .to_string();
-            self.git_status_message = Some(message.clone());
-            Self::push_warning_notification(message, None, cx);
-            cx.notify();
-            return;
-        };
-
-        let changed_paths = self
-            .git_workspace
-            .files
-            .iter()
-            .map(|file| file.path.clone())
-            .collect::<Vec<_>>();
-        let branch_name = self.git_workspace.branch_name.clone();
-        let codex_executable = Self::resolve_codex_executable_path();
-        let epoch = self.begin_git_action("Commit and Push", cx);
-        self.begin_ai_git_progress(
-            epoch,
-            AiGitProgressAction::WorkspaceCommitAndPush,
-            crate::app::ai_git_progress::workspace_commit_and_push_progress_steps(),
-            AiGitProgressStep::StagingFiles,
-            Some(format!("Files: {}", changed_paths.len())),
-            cx,
-        );
-
-        self.spawn_ai_git_action_with_progress(
-            epoch,
-            cx,
-            move |progress_tx| {
-                (|| -> anyhow::Result<(hunk_git::mutation::CreatedCommit, String)> {
-                    stage_paths(repo_root.as_path(), &changed_paths)?;
-
-                    send_ai_git_progress(
-                        &progress_tx,
-                        AiGitProgressStep::GeneratingCommitMessage,
-                        Some(ai_branch_progress_detail("Branch", branch_name.as_str())),
-                    );
-                    let commit_message = try_ai_commit_message_for_staged_index(
-                        AiCodexGenerationConfig {
-                            codex_executable: codex_executable.as_path(),
-                            repo_root: repo_root.as_path(),
-                        },
-                        repo_root.as_path(),
-                        branch_name.as_str(),
-                    )?;
-
-                    send_ai_git_progress(
-                        &progress_tx,
-                        AiGitProgressStep::CreatingCommit,
-                        Some(ai_commit_progress_detail(commit_message.subject.as_str())),
-                    );
-                    let created_commit = commit_index_with_details(
-                        repo_root.as_path(),
-                        commit_message.as_git_message().as_str(),
-                    )?;
-
-                    send_ai_git_progress(
-                        &progress_tx,
-                        AiGitProgressStep::PushingBranch,
-                        Some(ai_branch_progress_detail("Branch", branch_name.as_str())),
-                    );
-                    push_current_branch_with_publish_fallback(
-                        repo_root.as_path(),
-                        branch_name.as_str(),
-                    )?;
-
-                    Ok((created_commit, branch_name))
-                })()
-            },
-            move |this, result, execution_elapsed, total_elapsed, cx| {
-                if epoch != this.git_action_epoch {
-                    return;
-                }
-
-                this.finish_git_action();
-                match result {
-                    Ok((created_commit, branch_name)) => {
-                        debug!(
-                            "git action complete: epoch={} action=Commit and Push exec_elapsed_ms={} total_elapsed_ms={} branch={}",
-                            epoch,
-                            execution_elapsed.as_millis(),
-                            total_elapsed.as_millis(),
-                            branch_name
-                        );
-                        this.apply_optimistic_commit_success(created_commit.subject.as_str());
-                        this.apply_optimistic_recent_commit(&created_commit);
-                        this.request_snapshot_refresh_workflow_only(true, cx);
-                        this.request_git_workspace_refresh(false, cx);
-                        this.request_recent_commits_refresh(true, cx);
-
-                        let commit_input_state = this.commit_input_state.clone();
-                        if let Some(window_handle) = cx.windows().into_iter().next()
-                            && let Err(err) = cx.update_window(window_handle, |_, window, cx| {
-                                commit_input_state.update(cx, |state, cx| {
-                                    state.set_value("", window, cx);
-                                });
-                            })
-                        {
-                            error!(
-                                "failed to clear commit input after combined commit and push: {err:#}"
-                            );
-                        }
-
-                        let message = format!("Committed and pushed {}", branch_name);
-                        this.git_status_message = Some(message.clone());
-                        Self::push_success_notification(message, cx);
-                    }
-                    Err(err) => {
-                        error!(
-                            "git action failed: epoch={} action=Commit and Push exec_elapsed_ms={} total_elapsed_ms={} err={err:#}",
-                            epoch,
-                            execution_elapsed.as_millis(),
-                            total_elapsed.as_millis()
-                        );
-                        let summary = err.to_string();
-                        this.git_status_message = Some(format!("Git error: {err:#}"));
-                        Self::push_error_notification(
-                            format!("Commit and Push failed: {summary}"),
-                            cx,
-                        );
-                    }
-                }
-
-                cx.notify();
-            },
-        );
-    }
-
     pub(super) fn create_or_switch_branch_from_input(
         &mut self,
         window: &mut Window,
@@ -828,6 +651,11 @@ impl DiffViewer {
             return;
         }
 
+        if let Some(message) = self.branch_name_policy_message(raw_name.as_str()) {
+            self.set_git_warning_message(message, Some(window), cx);
+            return;
+        }
+
         let sanitized = sanitize_branch_name(&raw_name);
         let started =
             self.request_activate_or_create_branch_with_dirty_guard(sanitized, Some(window), cx);
@@ -838,9 +666,28 @@ impl DiffViewer {
         }
     }
 
+    /// Opens a URL or linked ticket id clicked in a diff line.
+    pub(super) fn open_diff_text_link(
+        &mut self,
+        target: DiffTextLinkTarget,
+        cx: &mut Context<Self>,
+    ) {
+        match target {
+            DiffTextLinkTarget::Ticket(ticket_id) => self.open_ticket_link(ticket_id.as_str(), cx),
+            DiffTextLinkTarget::Url(url) => {
+                if let Err(err) = open_url_in_browser(url.as_str()) {
+                    error!("failed to open diff URL '{url}': {err:#}");
+                    Self::push_error_notification(format!("Open URL failed: {err}"), cx);
+                }
+            }
+        }
+    }
+
     pub(super) fn publish_current_branch(&mut self, cx: &mut Context<Self>) {
         if !self.can_run_active_branch_actions() {
-            let message = "Activate a branch before publishing.".to_string();
+            let message = self
+                .detached_head_branch_hint()
+                .unwrap_or_else(|| "Activate a branch before publishing.".to_string());
             self.git_status_message = Some(message.clone());
             Self::push_warning_notification(message, None, cx);
             cx.notify();
@@ -865,15 +712,25 @@ impl DiffViewer {
         }
 
         let branch_name = self.git_workspace.branch_name.clone();
-        self.run_git_action("Publish branch", cx, move |repo_root| {
-            push_current_branch(&repo_root, &branch_name, false)?;
-            Ok(format!("Published branch {}", branch_name))
-        });
+        let timeout_secs = self.config.git_timeouts.push_secs;
+        let database_store = self.database_store.clone();
+        self.run_cancellable_git_action(
+            "Publish branch",
+            timeout_secs,
+            cx,
+            move |repo_root, cancel| {
+                push_current_branch_with_cancel(&repo_root, &branch_name, false, &cancel)?;
+                record_push_usage_metric(database_store.as_ref());
+                Ok(format!("Published branch {}", branch_name))
+            },
+        );
     }
 
     pub(super) fn push_current_branch(&mut self, cx: &mut Context<Self>) {
         if !self.can_run_active_branch_actions() {
-            let message = "Activate a branch before pushing.".to_string();
+            let message = self
+                .detached_head_branch_hint()
+                .unwrap_or_else(|| "Activate a branch before pushing.".to_string());
             self.git_status_message = Some(message.clone());
             Self::push_warning_notification(message, None, cx);
             cx.notify();
@@ -898,10 +755,18 @@ impl DiffViewer {
         }
 
         let branch_name = self.git_workspace.branch_name.clone();
-        self.run_git_action("Push branch", cx, move |repo_root| {
-            push_current_branch(&repo_root, &branch_name, true)?;
-            Ok(format!("Pushed branch {}", branch_name))
-        });
+        let timeout_secs = self.config.git_timeouts.push_secs;
+        let database_store = self.database_store.clone();
+        self.run_cancellable_git_action(
+            "Push branch",
+            timeout_secs,
+            cx,
+            move |repo_root, cancel| {
+                push_current_branch_with_cancel(&repo_root, &branch_name, true, &cancel)?;
+                record_push_usage_metric(database_store.as_ref());
+                Ok(format!("Pushed branch {}", branch_name))
+            },
+        );
     }
 
     pub(super) fn sync_current_branch_from_remote(&mut self, cx: &mut Context<Self>) {
@@ -931,11 +796,17 @@ impl DiffViewer {
         }
 
         let branch_name = self.git_workspace.branch_name.clone();
+        let timeout_secs = self.config.git_timeouts.sync_secs;
 
-        self.run_git_action("Sync branch", cx, move |repo_root| {
-            sync_current_branch(&repo_root, &branch_name)?;
-            Ok(format!("Synced branch {}", branch_name))
-        });
+        self.run_cancellable_git_action(
+            "Sync branch",
+            timeout_secs,
+            cx,
+            move |repo_root, cancel| {
+                sync_current_branch_with_cancel(&repo_root, &branch_name, &cancel)?;
+                Ok(format!("Synced branch {}", branch_name))
+            },
+        );
     }
 
     pub(super) fn open_current_branch_review_url(&mut self, cx: &mut Context<Self>) {
@@ -1113,16 +984,21 @@ impl DiffViewer {
     }
 
     pub(super) fn commit_from_input(&mut self, _: &mut Window, cx: &mut Context<Self>) {
-        if self.git_controls_busy() {
+        if self.git_controls_busy() || self.block_in_read_only("Commit", cx) {
             return;
         }
 
-        let message = self.commit_input_state.read(cx).value().to_string();
+        let mut message = self.commit_input_state.read(cx).value().to_string();
         if message.trim().is_empty() {
             self.git_status_message = Some("Commit message cannot be empty.".to_string());
             cx.notify();
             return;
         }
+        if self.config.ticket_links.insert_into_commit_messages
+            && let Some(ticket_id) = self.active_branch_ticket_id()
+        {
+            message = commit_message_with_ticket(message.as_str(), ticket_id.as_str());
+        }
 
         let Some(repo_root) = self.selected_git_workspace_root() else {
             self.git_status_message = Some("No Git repository available.".to_string());
@@ -1135,6 +1011,14 @@ impl DiffViewer {
             cx.notify();
             return;
         }
+        if let Some(collision) = self.unacknowledged_commit_path_collisions(true).first() {
+            self.git_status_message = Some(format!(
+                "{}. Acknowledge the case warning before committing.",
+                collision.summary()
+            ));
+            cx.notify();
+            return;
+        }
 
         let epoch = self.begin_git_action("Create commit", cx);
         let started_at = Instant::now();
@@ -1315,7 +1199,7 @@ impl DiffViewer {
             return;
         }
 
-        if self.git_controls_busy() {
+        if self.git_controls_busy() || self.block_in_read_only("Restore file", cx) {
             return;
         }
 
@@ -0,0 +1,42 @@
+impl DiffViewer {
+    pub(super) fn path_collision_for(&self, path: &str) -> Option<&PathCollision> {
+        self.git_workspace
+            .path_collisions
+            .iter()
+            .find(|collision| collision.affects(path))
+    }
+
+    /// Case collisions touching the files a commit would include that have not been
+    /// acknowledged. Committing them on a case-insensitive filesystem can lose one spelling.
+    pub(super) fn unacknowledged_commit_path_collisions(
+        &self,
+        staged_only: bool,
+    ) -> Vec<&PathCollision> {
+        self.git_workspace
+            .path_collisions
+            .iter()
+            .filter(|collision| !self.acknowledged_path_collisions.contains(&collision.key()))
+            .filter(|collision| {
+                self.git_workspace
+                    .files
+                    .iter()
+                    .filter(|file| !staged_only || file.staged)
+                    .any(|file| collision.affects(file.path.as_str()))
+            })
+            .collect()
+    }
+
+    pub(super) fn acknowledge_commit_path_collisions(&mut self, cx: &mut Context<Self>) {
+        let keys = self
+            .unacknowledged_commit_path_collisions(false)
+            .into_iter()
+            .map(PathCollision::key)
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return;
+        }
+        self.acknowledged_path_collisions.extend(keys);
+        self.git_status_message = Some("Case warnings acknowledged.".to_string());
+        cx.notify();
+    }
+}
@@ -0,0 +1,262 @@
+fn git_recovery_input(
+    value: &str,
+    placeholder: &'static str,
+    window: &mut Window,
+    cx: &mut Context<DiffViewer>,
+) -> Entity<InputState> {
+    let value = value.to_string();
+    cx.new(|cx| {
+        let mut state = InputState::new(window, cx).placeholder(placeholder);
+        state.set_value(value.clone(), window, cx);
+        state
+    })
+}
+
+impl DiffViewer {
+    fn push_git_error_notification(message: String, err: &anyhow::Error, cx: &mut Context<Self>) {
+        if GitError::classify(err) == Some(GitError::Cancelled) {
+            Self::push_warning_notification(message, None, cx);
+            return;
+        }
+        let Some(action) = hunk_git::recovery::recovery_action_for_error(err) else {
+            Self::push_error_notification(message, cx);
+            return;
+        };
+
+        let view = cx.entity().downgrade();
+        let window_handles = cx.windows().into_iter().collect::<Vec<_>>();
+        if window_handles.is_empty() {
+            error!("cannot show git action error notification: no windows available");
+            return;
+        }
+
+        for window_handle in window_handles {
+            let view = view.clone();
+            if let Err(err) = cx.update_window(window_handle, |_, window, cx| {
+                gpui_component::WindowExt::push_notification(
+                    window,
+                    crate::app::notifications::error_with_quick_fix(
+                        message.clone(),
+                        action.label(),
+                        move |window, cx| {
+                            if let Some(view) = view.upgrade() {
+                                view.update(cx, |this, cx| {
+                                    this.open_git_recovery(action, window, cx);
+                                });
+                            }
+                        },
+                    ),
+                    cx,
+                );
+            }) {
+                error!("failed to show git action error notification: {err:#}");
+            }
+        }
+    }
+
+    pub(super) fn open_git_recovery(
+        &mut self,
+        action: GitRecoveryAction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if action == GitRecoveryAction::ResolveConflicts {
+            self.open_first_conflicted_file(window, cx);
+            return;
+        }
+
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+
+        let (primary_input_state, secondary_input_state) = match action {
+            GitRecoveryAction::ConfigureIdentity => (
+                git_recovery_input("", "Your Name", window, cx),
+                git_recovery_input("", "you@example.com", window, cx),
+            ),
+            GitRecoveryAction::ConfigureRemote | GitRecoveryAction::ResolveConflicts => (
+                git_recovery_input("origin", "origin", window, cx),
+                git_recovery_input("", "git@github.com:owner/repo.git", window, cx),
+            ),
+        };
+        if action == GitRecoveryAction::ConfigureIdentity {
+            self.load_git_recovery_identity(
+                repo_root.clone(),
+                primary_input_state.clone(),
+                secondary_input_state.clone(),
+                cx,
+            );
+        }
+
+        self.git_recovery_prompt = Some(GitRecoveryPromptState {
+            action,
+            repo_root,
+            scope: GitConfigScope::Global,
+            primary_input_state,
+            secondary_input_state,
+            saving: false,
+            error_message: None,
+        });
+        cx.notify();
+    }
+
+    /// Reads the current identity off the UI thread and fills it into the prompt, leaving any
+    /// field the user already typed into alone.
+    fn load_git_recovery_identity(
+        &mut self,
+        repo_root: PathBuf,
+        name_input: Entity<InputState>,
+        email_input: Entity<InputState>,
+        cx: &mut Context<Self>,
+    ) {
+        let window_handle = self.window_handle;
+        cx.spawn(async move |this, cx| {
+            let identity = cx
+                .background_executor()
+                .spawn(async move {
+                    hunk_git::user_config::read_user_identity(repo_root.as_path())
+                        .unwrap_or_default()
+                })
+                .await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            let still_open = this.update(cx, |this, _| {
+                this.git_recovery_prompt
+                    .as_ref()
+                    .is_some_and(|prompt| prompt.primary_input_state == name_input)
+            });
+            if !still_open {
+                return;
+            }
+            if let Err(err) = cx.update_window(window_handle, |_, window, cx| {
+                for (input, value) in [(name_input, identity.name), (email_input, identity.email)] {
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    input.update(cx, |input, cx| {
+                        if input.value().is_empty() {
+                            input.set_value(value, window, cx);
+                        }
+                    });
+                }
+            }) {
+                error!("failed to fill the Git identity prompt: {err:#}");
+            }
+        })
+        .detach();
+    }
+
+    fn open_first_conflicted_file(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(path) = self
+            .files
+            .iter()
+            .find(|file| file.status == FileStatus::Conflicted)
+            .map(|file| file.path.clone())
+        else {
+            self.set_git_warning_message(
+                "No conflicted files found in the working copy.".to_string(),
+                Some(window),
+                cx,
+            );
+            return;
+        };
+
+        self.open_file_in_files_workspace(path, FileStatus::Conflicted, window, cx);
+    }
+
+    pub(super) fn set_git_recovery_scope(&mut self, scope: GitConfigScope, cx: &mut Context<Self>) {
+        let Some(prompt) = self.git_recovery_prompt.as_mut() else {
+            return;
+        };
+        prompt.scope = scope;
+        cx.notify();
+    }
+
+    pub(super) fn close_git_recovery(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.git_recovery_prompt.take().is_some() {
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn save_git_recovery(&mut self, cx: &mut Context<Self>) {
+        if self.block_in_read_only("Save Git config", cx) {
+            return;
+        }
+        let Some(prompt) = self.git_recovery_prompt.as_mut() else {
+            return;
+        };
+        if prompt.saving {
+            return;
+        }
+
+        let action = prompt.action;
+        let scope = prompt.scope;
+        let repo_root = prompt.repo_root.clone();
+        let primary = prompt
+            .primary_input_state
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        let secondary = prompt
+            .secondary_input_state
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        prompt.saving = true;
+        prompt.error_message = None;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    match action {
+                        GitRecoveryAction::ConfigureIdentity => {
+                            hunk_git::user_config::write_user_identity(
+                                repo_root.as_path(),
+                                scope,
+                                primary.as_str(),
+                                secondary.as_str(),
+                            )
+                            .map(|()| "Saved Git identity.".to_string())
+                        }
+                        GitRecoveryAction::ConfigureRemote => hunk_git::network::add_remote(
+                            repo_root.as_path(),
+                            primary.as_str(),
+                            secondary.as_str(),
+                        )
+                        .map(|()| format!("Added remote '{primary}'.")),
+                        GitRecoveryAction::ResolveConflicts => Ok(String::new()),
+                    }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(message) => {
+                            this.git_recovery_prompt = None;
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_git_workspace_refresh(false, cx);
+                        }
+                        Err(err) => {
+                            if let Some(prompt) = this.git_recovery_prompt.as_mut() {
+                                prompt.saving = false;
+                                prompt.error_message = Some(format!("{err:#}"));
+                            }
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,69 @@
+impl DiffViewer {
+    /// Ticket id found in the checked out branch name by the configured ticket patterns.
+    pub(super) fn active_branch_ticket_id(&self) -> Option<String> {
+        let branch_name = self.checked_out_branch_name()?;
+        self.ticket_linker.as_ref()?.branch_ticket_id(branch_name)
+    }
+
+    pub(super) fn ticket_url(&self, ticket_id: &str) -> Option<String> {
+        self.ticket_linker.as_ref()?.ticket_url(ticket_id)
+    }
+
+    /// Ticket ids in `text` that have a configured link.
+    pub(super) fn linked_ticket_ids(&self, text: &str) -> Vec<String> {
+        let Some(linker) = self.ticket_linker.as_ref() else {
+            return Vec::new();
+        };
+        linker
+            .ticket_ids(text)
+            .into_iter()
+            .filter(|ticket_id| linker.ticket_url(ticket_id).is_some())
+            .collect()
+    }
+
+    pub(super) fn open_ticket_link(&mut self, ticket_id: &str, cx: &mut Context<Self>) {
+        let Some(url) = self.ticket_url(ticket_id) else {
+            return;
+        };
+        match open_url_in_browser(url.as_str()) {
+            Ok(()) => {
+                self.git_status_message = Some(format!("Opened {ticket_id} in browser"));
+            }
+            Err(err) => {
+                error!("Open ticket URL failed: {err:#}");
+                let message = format!("Open URL failed: {err}");
+                self.git_status_message = Some(message.clone());
+                Self::push_warning_notification(message, None, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// The current branch's ticket id when the commit message does not mention it yet.
+    pub(super) fn commit_message_missing_active_ticket(
+        &self,
+        cx: &Context<Self>,
+    ) -> Option<String> {
+        let ticket_id = self.active_branch_ticket_id()?;
+        let message = self.commit_input_state.read(cx).value().to_string();
+        (commit_message_with_ticket(message.as_str(), ticket_id.as_str()) != message)
+            .then_some(ticket_id)
+    }
+
+    pub(super) fn insert_active_ticket_into_commit_message(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(ticket_id) = self.active_branch_ticket_id() else {
+            return;
+        };
+        let message = self.commit_input_state.read(cx).value().to_string();
+        let message = commit_message_with_ticket(message.as_str(), ticket_id.as_str());
+        self.commit_input_state.update(cx, |state, cx| {
+            state.set_value(message, window, cx);
+            state.focus(window, cx);
+        });
+        cx.notify();
+    }
+}
@@ -0,0 +1,192 @@
+impl DiffViewer {
+    /// Repository root and branch of the review, for finding its GitHub pull request.
+    fn github_review_scope(&mut self, cx: &mut Context<Self>) -> Option<(String, String)> {
+        let Some(repo_root) = self.comment_scope_repo_root() else {
+            Self::push_error_notification(tr("comment-import-no-repository"), cx);
+            return None;
+        };
+        let branch_name = self.comment_scope_branch_name();
+        if branch_name == "detached" {
+            Self::push_error_notification(tr("github-review-no-branch"), cx);
+            return None;
+        }
+        Some((repo_root, branch_name))
+    }
+
+    /// Posts the review's open comments on the matching lines of the branch's GitHub pull
+    /// request. Comments the pull request already has are not posted again.
+    pub(super) fn publish_review_comments_to_github(&mut self, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            Self::push_error_notification(tr("comment-import-database-unavailable"), cx);
+            return;
+        };
+        let Some((repo_root, branch_name)) = self.github_review_scope(cx) else {
+            return;
+        };
+        let Some(working_copy_root) = self.selected_git_workspace_root() else {
+            Self::push_error_notification(tr("comment-import-no-repository"), cx);
+            return;
+        };
+
+        let comments_reply = actor.list_comments(repo_root.clone(), branch_name.clone(), false);
+        let provider_mappings = self.config.review_provider_mappings.clone();
+        self.github_review_task = cx.spawn(async move |this, cx| {
+            let comments = match comments_reply.await {
+                Ok(comments) => comments,
+                Err(err) => {
+                    error!("failed to load comments to publish: {err:#}");
+                    if let Some(this) = this.upgrade() {
+                        this.update(cx, |_, cx| {
+                            Self::push_error_notification(
+                                tr_args(
+                                    "github-review-failed",
+                                    &[("error", format!("{err:#}").as_str())],
+                                ),
+                                cx,
+                            );
+                        });
+                    }
+                    return;
+                }
+            };
+            if comments.is_empty() {
+                if let Some(this) = this.upgrade() {
+                    this.update(cx, |_, cx| {
+                        Self::push_error_notification(tr("github-review-no-open-comments"), cx);
+                    });
+                }
+                return;
+            }
+
+            let total = comments.len();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let (client, pull_request) = github_pull_request_for_branch(
+                        Path::new(repo_root.as_str()),
+                        branch_name.as_str(),
+                        &provider_mappings,
+                    )?;
+                    ensure_working_copy_matches_pull_request(
+                        working_copy_root.as_path(),
+                        &pull_request,
+                        &comments,
+                    )?;
+                    let summary = client.publish_comments(&pull_request, &comments)?;
+                    Ok::<_, anyhow::Error>((pull_request, summary))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |_, cx| match result {
+                    Ok((pull_request, summary)) => {
+                        let number = pull_request.number.to_string();
+                        let mut message = tr_args(
+                            "github-review-published",
+                            &[
+                                ("published", summary.published.to_string().as_str()),
+                                ("total", total.to_string().as_str()),
+                                ("number", number.as_str()),
+                            ],
+                        );
+                        if summary.published == total {
+                            Self::push_success_notification(message, cx);
+                            return;
+                        }
+                        message.push(' ');
+                        message.push_str(
+                            tr_args(
+                                "github-review-skipped",
+                                &[
+                                    ("present", summary.already_present.to_string().as_str()),
+                                    ("rejected", summary.rejected.to_string().as_str()),
+                                ],
+                            )
+                            .as_str(),
+                        );
+                        Self::push_warning_notification(message, None, cx);
+                    }
+                    Err(err) => {
+                        error!("failed to publish comments to GitHub: {err:#}");
+                        Self::push_error_notification(
+                            tr_args(
+                                "github-review-failed",
+                                &[("error", format!("{err:#}").as_str())],
+                            ),
+                            cx,
+                        );
+                    }
+                });
+            }
+        });
+    }
+
+    /// Brings the review comments of the branch's GitHub pull request into the comments panel,
+    /// anchored like imported comments. Comments already here are skipped.
+    pub(super) fn pull_github_review_comments(&mut self, cx: &mut Context<Self>) {
+        if !self.review_comments_enabled() {
+            self.comment_status_message = Some(tr("comment-import-disabled"));
+            cx.notify();
+            return;
+        }
+        let Some((repo_root, branch_name)) = self.github_review_scope(cx) else {
+            return;
+        };
+
+        let provider_mappings = self.config.review_provider_mappings.clone();
+        self.github_review_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let (client, pull_request) = github_pull_request_for_branch(
+                        Path::new(repo_root.as_str()),
+                        branch_name.as_str(),
+                        &provider_mappings,
+                    )?;
+                    let comments = client.review_comments(&pull_request)?;
+                    Ok::<_, anyhow::Error>((pull_request, comments))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok((pull_request, entries)) => {
+                            let entries = entries
+                                .into_iter()
+                                .filter(|entry| {
+                                    !this
+                                        .comments_cache
+                                        .iter()
+                                        .any(|comment| github_comment_matches(entry, comment))
+                                })
+                                .collect::<Vec<_>>();
+                            if entries.is_empty() {
+                                Self::push_success_notification(
+                                    tr_args(
+                                        "github-review-up-to-date",
+                                        &[("number", pull_request.number.to_string().as_str())],
+                                    ),
+                                    cx,
+                                );
+                            } else {
+                                this.apply_imported_comments(entries, cx);
+                            }
+                        }
+                        Err(err) => {
+                            error!("failed to pull GitHub review comments: {err:#}");
+                            Self::push_error_notification(
+                                tr_args(
+                                    "github-review-failed",
+                                    &[("error", format!("{err:#}").as_str())],
+                                ),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,65 @@
+impl DiffViewer {
+    /// Reloads which local branches share the checked-out commit, so the toolbar can tell them
+    /// apart instead of silently acting on whichever one is checked out.
+    fn request_head_branches_reload(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.head_branch_names.clear();
+            return;
+        };
+
+        self.head_branches_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let repo_root = repo_root.clone();
+                    async move { branches_at_head(repo_root.as_path()) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.selected_git_workspace_root().as_ref() != Some(&repo_root) {
+                        return;
+                    }
+                    let names = result.unwrap_or_else(|err| {
+                        warn!("failed to list branches at HEAD: {err:#}");
+                        Vec::new()
+                    });
+                    if this.head_branch_names != names {
+                        this.head_branch_names = names;
+                        cx.notify();
+                    }
+                });
+            }
+        });
+    }
+
+    /// Branches at HEAD worth surfacing: more than one, or any while HEAD is detached.
+    pub(super) fn ambiguous_head_branch_names(&self) -> &[String] {
+        let detached = self.checked_out_branch_name().is_none();
+        if self.head_branch_names.len() > 1 || (detached && !self.head_branch_names.is_empty()) {
+            self.head_branch_names.as_slice()
+        } else {
+            &[]
+        }
+    }
+
+    /// Explains what to do when a branch action is blocked on a detached HEAD that some branch
+    /// still points at.
+    pub(super) fn detached_head_branch_hint(&self) -> Option<String> {
+        if self.checked_out_branch_name().is_some() || self.head_branch_names.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "HEAD is detached at the tip of {}. Pick one from the toolbar branch menu first.",
+            self.head_branch_names.join(", ")
+        ))
+    }
+
+    pub(super) fn switch_to_head_branch(&mut self, branch_name: String, cx: &mut Context<Self>) {
+        if self.checked_out_branch_name() == Some(branch_name.as_str()) {
+            return;
+        }
+        self.checkout_branch(branch_name, cx);
+    }
+}
@@ -0,0 +1,179 @@
+impl DiffViewer {
+    /// Context menu items run without a window, so the prompt's inputs are built on the next
+    /// frame.
+    pub(super) fn defer_open_hunk_apply_prompt(
+        &self,
+        path: String,
+        hunk: HunkSelector,
+        cx: &mut Context<Self>,
+    ) {
+        let window_handle = self.window_handle;
+        let view = cx.entity();
+        cx.defer(move |cx| {
+            let result = cx.update_window(window_handle, |_, window, cx| {
+                view.update(cx, |this, cx| {
+                    this.open_hunk_apply_prompt(path, hunk, window, cx);
+                });
+            });
+            if let Err(err) = result {
+                error!("failed to open hunk apply prompt: {err:#}");
+            }
+        });
+    }
+
+    pub(super) fn open_hunk_apply_prompt(
+        &mut self,
+        path: String,
+        hunk: HunkSelector,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .hunk_apply_prompt
+            .as_ref()
+            .is_some_and(|prompt| prompt.applying)
+        {
+            return;
+        }
+
+        let branch_input_state = git_recovery_input("", "fix/drive-by", window, cx);
+        branch_input_state.update(cx, |state, cx| state.focus(window, cx));
+        self.hunk_apply_prompt = Some(HunkApplyPromptState {
+            path,
+            hunk,
+            branch_input_state,
+            message_input_state: git_recovery_input(
+                "",
+                "Commit message (optional when amending)",
+                window,
+                cx,
+            ),
+            mode: HunkApplyMode::NewCommit,
+            applying: false,
+            error_message: None,
+        });
+        cx.notify();
+    }
+
+    pub(super) fn close_hunk_apply_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self
+            .hunk_apply_prompt
+            .as_ref()
+            .is_some_and(|prompt| !prompt.applying)
+        {
+            self.hunk_apply_prompt = None;
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn set_hunk_apply_mode(&mut self, mode: HunkApplyMode, cx: &mut Context<Self>) {
+        if let Some(prompt) = self.hunk_apply_prompt.as_mut()
+            && prompt.mode != mode
+        {
+            prompt.mode = mode;
+            prompt.error_message = None;
+            cx.notify();
+        }
+    }
+
+    pub(super) fn submit_hunk_apply_prompt(&mut self, cx: &mut Context<Self>) {
+        if self.block_in_read_only("Apply hunk", cx) {
+            return;
+        }
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        let Some(prompt) = self.hunk_apply_prompt.as_ref() else {
+            return;
+        };
+        if prompt.applying {
+            return;
+        }
+
+        let raw_branch_name = prompt.branch_input_state.read(cx).value().to_string();
+        let branch_name = sanitize_branch_name(raw_branch_name.as_str());
+        let message = prompt
+            .message_input_state
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        let validation_error = if branch_name.is_empty() {
+            Some("Enter a branch name.".to_string())
+        } else if branch_name == self.branch_name {
+            Some(format!(
+                "'{branch_name}' is the current branch. Pick another branch."
+            ))
+        } else if prompt.mode == HunkApplyMode::NewCommit && message.is_empty() {
+            Some("Enter a commit message.".to_string())
+        } else if prompt.mode == HunkApplyMode::AmendTip
+            && !self
+                .branches
+                .iter()
+                .any(|branch| branch.name == branch_name)
+        {
+            Some(format!(
+                "Branch '{branch_name}' does not exist, so there is no commit to amend."
+            ))
+        } else {
+            self.branch_name_policy_message(raw_branch_name.as_str())
+        };
+
+        let request = HunkApplyRequest {
+            path: prompt.path.clone(),
+            hunk: prompt.hunk.clone(),
+            branch_name,
+            message,
+            mode: prompt.mode,
+        };
+        let Some(prompt) = self.hunk_apply_prompt.as_mut() else {
+            return;
+        };
+        if let Some(message) = validation_error {
+            prompt.error_message = Some(message);
+            cx.notify();
+            return;
+        }
+        prompt.applying = true;
+        prompt.error_message = None;
+        cx.notify();
+
+        self.hunk_apply_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { apply_hunk_to_branch(repo_root.as_path(), &request) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(applied) => {
+                            this.hunk_apply_prompt = None;
+                            let verb = if applied.created_branch {
+                                "Created"
+                            } else {
+                                "Updated"
+                            };
+                            let message = format!(
+                                "{verb} {} with {}",
+                                applied.branch_name, applied.commit.subject
+                            );
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_git_workspace_refresh(true, cx);
+                        }
+                        Err(err) => {
+                            error!("applying hunk to branch failed: {err:#}");
+                            if let Some(prompt) = this.hunk_apply_prompt.as_mut() {
+                                prompt.applying = false;
+                                prompt.error_message = Some(format!("{err:#}"));
+                            }
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,133 @@
+impl DiffViewer {
+    /// Reads which hunks of a working-copy review are in the index, for the checkboxes on their
+    /// headers. Other comparisons have no index to stage into and keep the thin hunk dividers.
+    pub(super) fn refresh_review_hunk_stage_states(&mut self, cx: &mut Context<Self>) {
+        let repo_root = self.review_compare_working_copy_root();
+        if let Some(session) = self.review_workspace_session.as_mut() {
+            session.set_hunk_stage_controls(repo_root.is_some());
+        }
+        let (Some(repo_root), Some(session)) = (repo_root, self.review_workspace_session.as_ref())
+        else {
+            self.review_hunk_stage_states.clear();
+            self.review_hunk_stage_task = Task::ready(());
+            return;
+        };
+
+        let mut hunks_by_path = BTreeMap::<String, Vec<HunkSelector>>::new();
+        for range in session.hunk_ranges() {
+            hunks_by_path
+                .entry(range.path.clone())
+                .or_default()
+                .push(review_hunk_selector(session, range));
+        }
+
+        self.review_hunk_stage_task = cx.spawn(async move |this, cx| {
+            let loaded_root = repo_root.clone();
+            let states = cx
+                .background_executor()
+                .spawn(async move {
+                    hunks_by_path
+                        .into_iter()
+                        .filter_map(|(path, hunks)| {
+                            let states =
+                                match load_hunk_stage_states(&repo_root, path.as_str(), &hunks) {
+                                    Ok(states) => states,
+                                    Err(err) => {
+                                        debug!("no hunk stage states for {path}: {err:#}");
+                                        return None;
+                                    }
+                                };
+                            let by_header = hunks
+                                .into_iter()
+                                .zip(states)
+                                .filter_map(|(hunk, state)| Some((hunk.header, state?)))
+                                .collect::<BTreeMap<_, _>>();
+                            Some((path, by_header))
+                        })
+                        .collect::<BTreeMap<_, _>>()
+                })
+                .await;
+
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                if this.review_compare_working_copy_root().as_ref() != Some(&loaded_root) {
+                    return;
+                }
+                this.review_hunk_stage_states = states;
+                this.review_surface.clear_workspace_surface_snapshot();
+                cx.notify();
+            });
+        });
+    }
+
+    pub(super) fn review_hunk_stage_state(
+        &self,
+        path: &str,
+        header: &str,
+    ) -> Option<HunkStageState> {
+        self.review_hunk_stage_states
+            .get(path)?
+            .get(header)
+            .copied()
+    }
+
+    /// Stage states keyed by the review row of each hunk header.
+    pub(super) fn review_hunk_stage_states_by_row(&self) -> BTreeMap<usize, HunkStageState> {
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return BTreeMap::new();
+        };
+        session
+            .hunk_ranges()
+            .iter()
+            .filter_map(|range| {
+                let state =
+                    self.review_hunk_stage_state(range.path.as_str(), range.header.as_str())?;
+                Some((range.start_row, state))
+            })
+            .collect()
+    }
+
+    /// Flips the checkbox on a hunk header: a staged hunk is taken out of the index and any other
+    /// hunk is staged. Partly staged hunks have no checkbox.
+    pub(super) fn toggle_review_hunk_staged(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return;
+        };
+        let Some(range) = session
+            .hunk_ranges()
+            .iter()
+            .find(|range| range.start_row == row_ix)
+        else {
+            return;
+        };
+        let path = range.path.clone();
+        let hunk = review_hunk_selector(session, range);
+        match self.review_hunk_stage_state(path.as_str(), hunk.header.as_str()) {
+            Some(HunkStageState::Staged) => self.unstage_review_hunk(path, hunk, cx),
+            Some(HunkStageState::Unstaged) => self.stage_review_hunk(path, hunk, cx),
+            None => {}
+        }
+    }
+}
+
+/// Identifies a review hunk by the lines it removes and adds.
+fn review_hunk_selector(
+    session: &review_workspace_session::ReviewWorkspaceSession,
+    range: &review_workspace_session::ReviewWorkspaceHunkRange,
+) -> HunkSelector {
+    let mut hunk = HunkSelector {
+        header: range.header.clone(),
+        ..HunkSelector::default()
+    };
+    for row in (range.start_row..range.end_row).filter_map(|ix| session.row(ix)) {
+        if row.left.kind == DiffCellKind::Removed {
+            hunk.removed.push(row.left.text.clone());
+        }
+        if row.right.kind == DiffCellKind::Added {
+            hunk.added.push(row.right.text.clone());
+        }
+    }
+    hunk
+}
@@ -0,0 +1,251 @@
+fn settings_retention_days(
+    input: &Entity<InputState>,
+    label: &str,
+    cx: &Context<DiffViewer>,
+) -> Result<i64, String> {
+    let value = input.read(cx).value().trim().to_string();
+    match value.parse::<i64>() {
+        Ok(days) if days > 0 => Ok(days),
+        _ => Err(format!(
+            "Maintenance: {label} retention must be a whole number of days above 0."
+        )),
+    }
+}
+
+/// Resolved and stale retention, in days, as typed in the settings draft.
+fn settings_comment_retention_days(
+    state: &SettingsMaintenanceState,
+    cx: &Context<DiffViewer>,
+) -> Result<(i64, i64), String> {
+    let resolved = settings_retention_days(&state.comment_retention_days, "Resolved comment", cx)?;
+    let stale = settings_retention_days(&state.stale_comment_retention_days, "Stale comment", cx)?;
+    Ok((resolved, stale))
+}
+
+fn comment_retention_cutoff_unix_ms(retention_days: i64) -> i64 {
+    let retention_ms = retention_days.saturating_mul(24 * 60 * 60 * 1000);
+    now_unix_ms().saturating_sub(retention_ms)
+}
+
+fn pruned_comments_detail(pruned: usize) -> String {
+    match pruned {
+        1 => "Pruned 1 comment".to_string(),
+        count => format!("Pruned {count} comments"),
+    }
+}
+
+fn maintenance_run_detail(task: SettingsMaintenanceTask, amount: u64) -> String {
+    match task {
+        SettingsMaintenanceTask::PruneComments => pruned_comments_detail(amount as usize),
+        SettingsMaintenanceTask::GitGc | SettingsMaintenanceTask::CompactDatabase => {
+            reclaimed_bytes_detail(amount)
+        }
+    }
+}
+
+fn reclaimed_bytes_detail(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("Reclaimed {bytes} B")
+    } else {
+        format!("Reclaimed {value:.1} {}", UNITS[unit])
+    }
+}
+
+impl DiffViewer {
+    fn record_maintenance_run(&mut self, task: SettingsMaintenanceTask, detail: String) {
+        let run = Some(MaintenanceRun {
+            ran_unix_ms: now_unix_ms(),
+            detail,
+        });
+        let maintenance = &mut self.state.maintenance;
+        match task {
+            SettingsMaintenanceTask::PruneComments => maintenance.last_comment_prune = run,
+            SettingsMaintenanceTask::GitGc => maintenance.last_git_gc = run,
+            SettingsMaintenanceTask::CompactDatabase => maintenance.last_database_compact = run,
+        }
+        self.persist_state();
+    }
+
+    fn record_comment_prune(&mut self, pruned: usize) {
+        self.state
+            .maintenance
+            .record_comment_prune(now_unix_ms(), pruned);
+        self.record_maintenance_run(
+            SettingsMaintenanceTask::PruneComments,
+            pruned_comments_detail(pruned),
+        );
+    }
+
+    pub(super) fn recently_pruned_comments(&self) -> usize {
+        self.state
+            .maintenance
+            .recently_pruned_comments(now_unix_ms())
+    }
+
+    /// Prunes comments past the saved retention now and then once a day while the app runs.
+    fn start_comment_prune_schedule(&mut self, cx: &mut Context<Self>) {
+        let Some(actor) = self.database_actor.clone() else {
+            return;
+        };
+        self.comment_prune_task = cx.spawn(async move |this, cx| {
+            loop {
+                let Some(view) = this.upgrade() else {
+                    return;
+                };
+                let mut cutoffs = (0, 0);
+                view.update(cx, |this, _| {
+                    cutoffs = (
+                        comment_retention_cutoff_unix_ms(this.config.comment_retention_days),
+                        comment_retention_cutoff_unix_ms(this.config.stale_comment_retention_days),
+                    );
+                });
+                drop(view);
+
+                let result = actor.prune_non_open_comments(cutoffs.0, cutoffs.1).await;
+                let Some(view) = this.upgrade() else {
+                    return;
+                };
+                view.update(cx, |this, cx| match result {
+                    Ok(pruned) => {
+                        this.record_comment_prune(pruned);
+                        if pruned > 0 {
+                            this.reload_comments_cache(false, cx);
+                            cx.notify();
+                        }
+                    }
+                    Err(err) => error!("failed to prune old comments: {err:#}"),
+                });
+                drop(view);
+
+                cx.background_executor().timer(COMMENT_PRUNE_INTERVAL).await;
+            }
+        });
+    }
+
+    pub(super) fn last_maintenance_run(
+        &self,
+        task: SettingsMaintenanceTask,
+    ) -> Option<&MaintenanceRun> {
+        let maintenance = &self.state.maintenance;
+        match task {
+            SettingsMaintenanceTask::PruneComments => maintenance.last_comment_prune.as_ref(),
+            SettingsMaintenanceTask::GitGc => maintenance.last_git_gc.as_ref(),
+            SettingsMaintenanceTask::CompactDatabase => maintenance.last_database_compact.as_ref(),
+        }
+    }
+
+    /// Runs one maintenance task in the background. Pruning uses the retention typed in the
+    /// settings draft so it can be tried before saving.
+    pub(super) fn run_settings_maintenance_task(
+        &mut self,
+        task: SettingsMaintenanceTask,
+        cx: &mut Context<Self>,
+    ) {
+        let actor = self.database_actor.clone();
+        let repo_root = self.selected_git_workspace_root();
+        let read_only = self.read_only_active();
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.maintenance.running.is_some() {
+            return;
+        }
+
+        // Database work queues on the database thread behind comment writes; git gc runs on the
+        // background executor.
+        let job: futures::future::LocalBoxFuture<'static, anyhow::Result<u64>> = match task {
+            SettingsMaintenanceTask::PruneComments => {
+                let (resolved_days, stale_days) =
+                    match settings_comment_retention_days(&settings.maintenance, cx) {
+                        Ok(days) => days,
+                        Err(err) => {
+                            settings.error_message = Some(err);
+                            cx.notify();
+                            return;
+                        }
+                    };
+                let Some(actor) = actor else {
+                    settings.error_message =
+                        Some("Pruning comments needs the local database.".to_string());
+                    cx.notify();
+                    return;
+                };
+                let resolved_cutoff = comment_retention_cutoff_unix_ms(resolved_days);
+                let stale_cutoff = comment_retention_cutoff_unix_ms(stale_days);
+                let reply = actor.prune_non_open_comments(resolved_cutoff, stale_cutoff);
+                Box::pin(async move { reply.await.map(|pruned| pruned as u64) })
+            }
+            SettingsMaintenanceTask::GitGc => {
+                if read_only {
+                    settings.error_message = Some(format!(
+                        "Git garbage collection unavailable: {READ_ONLY_REASON}."
+                    ));
+                    cx.notify();
+                    return;
+                }
+                let Some(repo_root) = repo_root else {
+                    settings.error_message =
+                        Some("Open a repository to run Git garbage collection.".to_string());
+                    cx.notify();
+                    return;
+                };
+                Box::pin(cx.background_executor().spawn(async move {
+                    run_git_gc(repo_root.as_path()).map(|report| report.reclaimed_bytes())
+                }))
+            }
+            SettingsMaintenanceTask::CompactDatabase => {
+                let Some(actor) = actor else {
+                    settings.error_message =
+                        Some("Compacting needs the local database.".to_string());
+                    cx.notify();
+                    return;
+                };
+                Box::pin(actor.compact())
+            }
+        };
+        settings.maintenance.running = Some(task);
+        settings.error_message = None;
+        cx.notify();
+
+        self.maintenance_task = cx.spawn(async move |this, cx| {
+            let result = job.await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if let Some(settings) = this.settings_draft.as_mut() {
+                        settings.maintenance.running = None;
+                    }
+                    match result {
+                        Ok(amount) => {
+                            let detail = maintenance_run_detail(task, amount);
+                            let message = format!("{}: {detail}.", task.title());
+                            if task == SettingsMaintenanceTask::PruneComments {
+                                this.record_comment_prune(amount as usize);
+                                this.reload_comments_cache(false, cx);
+                            } else {
+                                this.record_maintenance_run(task, detail);
+                            }
+                            Self::push_success_notification(message, cx);
+                        }
+                        Err(err) => {
+                            error!("maintenance task '{}' failed: {err:#}", task.title());
+                            let message = format!("{} failed: {err:#}", task.title());
+                            if let Some(settings) = this.settings_draft.as_mut() {
+                                settings.error_message = Some(message.clone());
+                            }
+                            Self::push_error_notification(message, cx);
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -1,5 +1,6 @@
 use crate::app::markdown_links::{
-    MarkdownLinkTarget, MarkdownWorkspaceFileLink, resolve_markdown_link_target,
+    MarkdownLinkTarget, MarkdownWorkspaceFileLink, resolve_clipboard_path_target,
+    resolve_markdown_link_target,
 };
 
 impl DiffViewer {
@@ -0,0 +1,61 @@
+impl DiffViewer {
+    /// Looks for one substitution repeated across many files of the loaded comparison. Runs in
+    /// the background since it reads every patch.
+    fn refresh_review_mechanical_changes(&mut self, cx: &mut Context<Self>) {
+        let Some(snapshot) = self.review_loaded_compare_snapshot.clone() else {
+            self.review_mechanical_changes = ReviewMechanicalChanges::default();
+            return;
+        };
+
+        self.review_mechanical_changes_task = cx.spawn(async move |this, cx| {
+            let detected_snapshot = snapshot.clone();
+            let groups = cx
+                .background_executor()
+                .spawn(async move {
+                    hunk_domain::mechanical_changes::detect_mechanical_changes(
+                        detected_snapshot
+                            .patches_by_path
+                            .iter()
+                            .map(|(path, patch)| (path.as_str(), patch.as_str())),
+                    )
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if !this
+                        .review_loaded_compare_snapshot
+                        .as_ref()
+                        .is_some_and(|loaded| Arc::ptr_eq(loaded, &snapshot))
+                    {
+                        return;
+                    }
+                    let mechanical_changes = &mut this.review_mechanical_changes;
+                    mechanical_changes.approved.retain_groups(&groups);
+                    mechanical_changes.groups = groups;
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Approves every file of a mechanical change at once by collapsing them, or brings back
+    /// the ones the approval collapsed when the group was already approved.
+    pub(super) fn toggle_review_mechanical_change_approved(
+        &mut self,
+        group_ix: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(group) = self.review_mechanical_changes.groups.get(group_ix) else {
+            return;
+        };
+        self.review_mechanical_changes
+            .approved
+            .toggle(group, &mut self.collapsed_files);
+
+        self.review_surface.last_diff_scroll_offset = None;
+        self.last_scroll_activity_at = Instant::now();
+        self.request_selected_diff_reload(cx);
+        cx.notify();
+    }
+}
@@ -12,51 +12,153 @@ use crate::app::ai_thread_flow::{
     ai_branch_generation_seed_for_thread, ai_branch_name_for_prompt, ai_branch_name_for_thread,
     ai_commit_message_for_thread, try_ai_branch_name_for_prompt, try_ai_commit_message,
 };
+use crate::app::desktop_notifications::{
+    CiRunsState, DesktopNotificationEvent, query_github_runs, show_os_notification,
+    text_mentions_handle,
+};
+use crate::app::diff_links::DiffTextLinkTarget;
+use crate::app::file_formatter::{FORMATTER_TIMEOUT, run_formatter};
+use crate::app::github_review::{
+    ensure_working_copy_matches_pull_request, github_comment_matches,
+    github_pull_request_for_branch,
+};
+use crate::app::highlight::{SyntaxTokenKind, build_syntax_only_line_segments};
 use crate::app::markdown_links::open_url_in_browser;
 
 use super::data::{
-    DiffSegmentQuality, DiffStream, DiffStreamRowKind, RepoTreeNodeKind, build_changed_files_tree,
-    build_diff_row_segment_cache_from_cells, build_diff_stream_from_patch_map, build_repo_tree,
-    count_repo_tree_kind, flatten_repo_tree_rows, is_markdown_path, line_number_column_width,
-    load_file_editor_document, save_file_editor_document,
+    DiffSegmentQuality, DiffStream, DiffStreamRowKind, FileEditorDocument, FilePathCopyFormat,
+    RepoTreeNodeKind, build_changed_files_tree, build_diff_row_segment_cache_from_cells,
+    build_diff_stream_from_patch_map, build_repo_tree, count_repo_tree_kind,
+    diff_text_zoom_after_scroll, file_path_copy_status_message, flatten_repo_tree_rows,
+    format_file_path_for_copy, is_binary_patch, is_markdown_path, line_number_column_width,
+    line_stats_from_patch, line_stats_without_ignored_paths, load_file_editor_document,
+    path_is_stats_ignored, replace_file_rows_in_diff_stream, save_file_editor_document,
 };
 use super::*;
+use hunk_domain::comment_import::parse_comment_import;
+use hunk_domain::crash::CrashReportStore;
+use hunk_domain::diff::{
+    DiffHunk, hunk_collapse_key, hunk_collapse_key_for_header, parse_patch_document,
+};
+use hunk_domain::review_export::{
+    ReviewExportColorMode, ReviewExportDocument, ReviewExportFile, ReviewExportLine,
+    ReviewExportLineKind, ReviewExportRgb, ReviewExportSpan, render_review_pdf,
+};
+use hunk_domain::snapshot_cache::{CachedLineStats, CachedReviewSnapshot};
+use hunk_domain::state::{ReviewPinState, WorkspaceLayoutState};
+use hunk_editor::{OverlayDescriptor, OverlayKind};
+use hunk_git::abandoned::{
+    DEFAULT_ABANDONED_COMMIT_LIMIT, load_abandoned_commits, restore_abandoned_commit,
+};
 use hunk_git::branch::{
-    RenameBranchIfSafeOutcome, rename_branch_if_current_unpublished,
+    RenameBranchIfSafeOutcome, branches_at_head, rename_branch_if_current_unpublished,
     review_url_for_branch_with_provider_map, sanitize_branch_name,
 };
-use hunk_git::compare::{CompareSource, load_compare_snapshot, resolve_default_base_branch_name};
+use hunk_git::branch_naming::{
+    BranchNamePolicy, detect_ticket_id, is_protected_branch, protected_branch_push_skip_reason,
+};
+use hunk_git::changelog::{changelog_fragment, changelog_fragment_path};
+use hunk_git::clone::{CloneProgress, clone_directory_name, clone_repository_with_cancel};
+use hunk_git::codeowners::load_code_owners;
+use hunk_git::commit_message::{
+    CHANGE_ID_TRAILER, HeadCommitMessage, REVIEWED_BY_TRAILER, StructuredCommitMessage,
+    load_head_commit_message, reword_head_commit,
+};
+use hunk_git::compare::{
+    CompareOptions, CompareSource, compare_source_tree_id, load_compare_snapshot_with_options,
+    refresh_compare_snapshot_paths, resolve_default_base_branch_name,
+};
+use hunk_git::document_text::is_extracted_text_patch;
+use hunk_git::encoding::{convert_file_to_utf8, load_working_copy_encodings};
 use hunk_git::git::{
     RepoSnapshotFingerprint, WorkflowSnapshot, count_non_ignored_repo_tree_entries,
-    invalidate_repo_metadata_caches, load_repo_file_line_stats_for_paths_without_refresh,
-    load_repo_file_line_stats_without_refresh, load_repo_tree, load_snapshot_fingerprint,
-    load_workflow_snapshot, load_workflow_snapshot_if_changed,
-    load_workflow_snapshot_if_changed_without_refresh, load_workflow_snapshot_with_fingerprint,
-    load_workflow_snapshot_with_fingerprint_without_refresh,
+    invalidate_repo_metadata_caches, load_patches_for_files,
+    load_repo_file_line_stats_for_paths_without_refresh, load_repo_file_line_stats_without_refresh,
+    load_repo_tree, load_snapshot_fingerprint, load_workflow_snapshot,
+    load_workflow_snapshot_if_changed, load_workflow_snapshot_if_changed_without_refresh,
+    load_workflow_snapshot_with_fingerprint,
+    load_workflow_snapshot_with_fingerprint_without_refresh, load_worktree_renames,
 };
 use hunk_git::history::{
-    DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, load_recent_authored_commits_fingerprint,
+    DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, load_branch_commit_messages, load_commit_changed_files,
+    load_commit_details, load_file_authorship, load_recent_authored_commits_fingerprint,
     load_recent_authored_commits_if_changed, load_recent_authored_commits_with_fingerprint,
 };
+use hunk_git::hunk_apply::{
+    HunkApplyRequest, apply_hunk_to_branch, load_hunk_stage_states, stage_hunk, unstage_hunk,
+};
+use hunk_git::init::{GitignoreTemplate, init_repository};
+use hunk_git::issues::{detect_repo_issues, resolve_repo_issue};
+use hunk_git::line_changes::{LineChange, LineChangeKind, line_changes_against_head};
+use hunk_git::maintenance::run_git_gc;
 use hunk_git::mutation::{
     activate_or_create_branch as checkout_or_create_branch_with_change_transfer,
     commit_all_with_details as commit_staged_with_details, commit_index_with_details,
-    restore_working_copy_paths, stage_paths, staged_index_context_for_ai, unstage_paths,
-    working_copy_context_for_ai,
+    move_worktree_path, restore_working_copy_paths, stage_paths, staged_index_context_for_ai,
+    unstage_paths, working_copy_context_for_ai,
 };
 use hunk_git::network::{
-    push_current_branch, sync_branch_from_remote_if_tracked, sync_current_branch,
+    RemoteUpstreamHead, branch_behind_remote_with_cancel, push_current_branch_with_cancel,
+    remote_upstream_head_with_cancel, sync_branch_from_remote_if_tracked,
+    sync_current_branch_with_cancel,
+};
+use hunk_git::operations::{
+    DEFAULT_REPO_OPERATION_LIMIT, diff_repo_operations, load_repo_operations,
+    restore_repo_operation, undo_repo_operation,
+};
+use hunk_git::sparse_checkout::{
+    load_sparse_checkout, path_excluded_by_sparse_checkout, sparse_checkout_widen_pattern,
+    widen_sparse_checkout,
 };
+use hunk_git::stack::{apply_stack_plan, load_branch_stack};
+use hunk_git::tickets::commit_message_with_ticket;
 
 include!("core.rs");
 include!("core_runtime.rs");
+include!("crash_reports.rs");
 include!("markdown_links.rs");
+include!("clipboard_path.rs");
+include!("ci_log.rs");
+include!("repo_content_search.rs");
 include!("project_open.rs");
+include!("repo_init.rs");
+include!("repo_clone.rs");
+include!("repo_issues.rs");
+include!("operation_diff.rs");
+include!("operation_history.rs");
+include!("stack_editor.rs");
 include!("git_ops_review.rs");
 include!("git_ops.rs");
+include!("git_commit_and_push.rs");
+include!("git_branch_naming.rs");
+include!("git_tickets.rs");
+include!("git_path_collisions.rs");
+include!("git_changelog.rs");
+include!("git_cancel.rs");
+include!("git_recovery.rs");
 include!("recent_commits.rs");
+include!("abandoned_commits.rs");
+include!("recent_commit_files.rs");
+include!("commit_details.rs");
+include!("file_authorship.rs");
 include!("review_compare.rs");
+include!("review_compare_watch.rs");
+include!("review_compare_refresh.rs");
+include!("review_changed_lines.rs");
+include!("review_compare_revisions.rs");
+include!("review_hunk_folds.rs");
+include!("review_snapshot_cache.rs");
+include!("mechanical_changes.rs");
+include!("change_set_size.rs");
+include!("file_encoding.rs");
 include!("workspace_mode.rs");
+include!("workspace_layout.rs");
+include!("review_timer.rs");
+include!("detached_diff_window.rs");
+include!("review_export.rs");
+include!("hunk_apply.rs");
+include!("hunk_stage.rs");
+include!("commit_message_editor.rs");
 include!("terminal_runtime_store.rs");
 include!("ai.rs");
 include!("ai_composer_completion.rs");
@@ -64,16 +166,43 @@ include!("ai_git_ops.rs");
 include!("file_terminal.rs");
 include!("file_tree.rs");
 include!("file_tree_fs.rs");
+include!("file_tree_inline_edit.rs");
 include!("file_quick_open.rs");
+include!("editor_format.rs");
+include!("editor_gestures.rs");
+include!("editor_line_changes.rs");
+include!("editor_recovery.rs");
 include!("editor_reuse.rs");
 include!("editor_search.rs");
 include!("editor.rs");
 include!("comments.rs");
+include!("comments_write.rs");
 include!("comments_match.rs");
+include!("comments_watch.rs");
+include!("comments_renames.rs");
+include!("comment_import.rs");
+include!("comment_export.rs");
+include!("github_review.rs");
+include!("review_pins.rs");
+include!("co_review.rs");
+include!("desktop_notifications.rs");
 include!("selection.rs");
+include!("vim_mode.rs");
 include!("context_menu.rs");
 include!("scroll.rs");
 include!("ai_perf.rs");
 include!("fps.rs");
 include!("about.rs");
+include!("diagnostics.rs");
 include!("settings.rs");
+include!("settings_git.rs");
+include!("settings_fonts.rs");
+include!("settings_notifications.rs");
+include!("settings_gestures.rs");
+include!("settings_formatting.rs");
+include!("usage_metrics.rs");
+include!("maintenance.rs");
+include!("read_only.rs");
+include!("sparse_checkout.rs");
+include!("code_owners.rs");
+include!("head_branches.rs");
@@ -0,0 +1,137 @@
+impl DiffViewer {
+    pub(super) fn open_operation_diff_action(
+        &mut self,
+        _: &OpenOperationDiff,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_operation_diff(cx);
+    }
+
+    pub(super) fn open_operation_diff(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+
+        self.operation_diff_panel = Some(OperationDiffPanelState {
+            repo_root: repo_root.clone(),
+            loading: true,
+            operations: Vec::new(),
+            before_ix: 0,
+            after_ix: 0,
+            diff: None,
+            error_message: None,
+        });
+        cx.notify();
+
+        self.operation_diff_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    load_repo_operations(repo_root.as_path(), DEFAULT_REPO_OPERATION_LIMIT)
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.operation_diff_panel.as_mut() else {
+                        return;
+                    };
+                    match result {
+                        Ok(operations) if operations.len() >= 2 => {
+                            panel.operations = operations;
+                            panel.before_ix = 1;
+                            panel.after_ix = 0;
+                            this.load_operation_diff(cx);
+                            return;
+                        }
+                        Ok(operations) => {
+                            panel.operations = operations;
+                            panel.error_message =
+                                Some("At least two operations are needed to compare.".to_string());
+                        }
+                        Err(err) => {
+                            error!("loading repository operations failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    panel.loading = false;
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn close_operation_diff(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.operation_diff_panel.take().is_some() {
+            self.operation_diff_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn select_operation_diff_before(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_diff_panel.as_mut() else {
+            return;
+        };
+        if panel.before_ix == ix || ix >= panel.operations.len() {
+            return;
+        }
+        panel.before_ix = ix;
+        self.load_operation_diff(cx);
+    }
+
+    pub(super) fn select_operation_diff_after(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_diff_panel.as_mut() else {
+            return;
+        };
+        if panel.after_ix == ix || ix >= panel.operations.len() {
+            return;
+        }
+        panel.after_ix = ix;
+        self.load_operation_diff(cx);
+    }
+
+    fn load_operation_diff(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_diff_panel.as_mut() else {
+            return;
+        };
+        let (Some(before), Some(after)) = (
+            panel.operations.get(panel.before_ix).cloned(),
+            panel.operations.get(panel.after_ix).cloned(),
+        ) else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.loading = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.operation_diff_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { diff_repo_operations(repo_root.as_path(), &before, &after) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.operation_diff_panel.as_mut() else {
+                        return;
+                    };
+                    panel.loading = false;
+                    match result {
+                        Ok(diff) => panel.diff = Some(diff),
+                        Err(err) => {
+                            error!("comparing repository operations failed: {err:#}");
+                            panel.diff = None;
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
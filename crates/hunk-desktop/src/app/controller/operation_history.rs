@@ -0,0 +1,230 @@
+impl DiffViewer {
+    pub(super) fn open_operation_history_action(
+        &mut self,
+        _: &OpenOperationHistory,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_operation_history(cx);
+    }
+
+    pub(super) fn open_operation_history(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+
+        self.operation_history_panel = Some(OperationHistoryPanelState {
+            repo_root,
+            loading: true,
+            restoring: false,
+            operations: Vec::new(),
+            selected_ix: 0,
+            diff: None,
+            error_message: None,
+        });
+        self.load_operation_history(cx);
+    }
+
+    pub(super) fn close_operation_history(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.operation_history_panel.take().is_some() {
+            self.operation_history_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    fn load_operation_history(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_history_panel.as_mut() else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.loading = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.operation_history_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    load_repo_operations(repo_root.as_path(), DEFAULT_REPO_OPERATION_LIMIT)
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.operation_history_panel.as_mut() else {
+                        return;
+                    };
+                    match result {
+                        Ok(operations) if !operations.is_empty() => {
+                            panel.operations = operations;
+                            panel.selected_ix = 0;
+                            this.load_operation_history_diff(cx);
+                            return;
+                        }
+                        Ok(_) => {
+                            panel.operations.clear();
+                            panel.diff = None;
+                            panel.error_message = Some(tr("operation-history-empty"));
+                        }
+                        Err(err) => {
+                            error!("loading repository operations failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    panel.loading = false;
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn select_operation_history_entry(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_history_panel.as_mut() else {
+            return;
+        };
+        if panel.selected_ix == ix || ix >= panel.operations.len() {
+            return;
+        }
+        panel.selected_ix = ix;
+        self.load_operation_history_diff(cx);
+    }
+
+    /// Loads what the selected operation changed, compared with the operation before it.
+    fn load_operation_history_diff(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.operation_history_panel.as_mut() else {
+            return;
+        };
+        let Some(after) = panel.operations.get(panel.selected_ix).cloned() else {
+            return;
+        };
+        panel.diff = None;
+        panel.error_message = None;
+        let Some(before) = panel.operations.get(panel.selected_ix + 1).cloned() else {
+            panel.loading = false;
+            cx.notify();
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.loading = true;
+        cx.notify();
+
+        self.operation_history_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { diff_repo_operations(repo_root.as_path(), &before, &after) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.operation_history_panel.as_mut() else {
+                        return;
+                    };
+                    panel.loading = false;
+                    match result {
+                        Ok(diff) => panel.diff = Some(diff),
+                        Err(err) => {
+                            error!("loading operation changes failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    pub(super) fn undo_last_repo_operation(&mut self, cx: &mut Context<Self>) {
+        self.run_operation_restore("Undo operation", None, cx);
+    }
+
+    pub(super) fn restore_selected_repo_operation(&mut self, cx: &mut Context<Self>) {
+        let Some(operation) = self
+            .operation_history_panel
+            .as_ref()
+            .and_then(|panel| panel.operations.get(panel.selected_ix).cloned())
+        else {
+            return;
+        };
+        self.run_operation_restore("Restore operation", Some(operation), cx);
+    }
+
+    /// Restores `operation`, or undoes the latest operation when it is `None`, then refreshes
+    /// the workspace and reloads the history so the restore shows up as its own operation.
+    fn run_operation_restore(
+        &mut self,
+        action_name: &'static str,
+        operation: Option<RepoOperation>,
+        cx: &mut Context<Self>,
+    ) {
+        if self.git_controls_busy() || self.block_in_read_only(action_name, cx) {
+            return;
+        }
+        let Some(panel) = self.operation_history_panel.as_mut() else {
+            return;
+        };
+        if panel.restoring {
+            return;
+        }
+        let repo_root = panel.repo_root.clone();
+        let failed_key = if operation.is_some() {
+            "operation-history-restore-failed"
+        } else {
+            "operation-history-undo-failed"
+        };
+        panel.restoring = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.operation_history_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    match operation.as_ref() {
+                        Some(operation) => restore_repo_operation(repo_root.as_path(), operation),
+                        None => undo_repo_operation(repo_root.as_path()),
+                    }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.operation_history_panel.as_mut() else {
+                        return;
+                    };
+                    panel.restoring = false;
+                    match result {
+                        Ok(restored) => {
+                            let target = restored
+                                .branch
+                                .clone()
+                                .unwrap_or_else(|| tr("operation-history-detached-head"));
+                            let short_id =
+                                restored.head_commit_id.chars().take(7).collect::<String>();
+                            let message = tr_args(
+                                "operation-history-restored",
+                                &[("target", target.as_str()), ("commit", short_id.as_str())],
+                            );
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_snapshot_refresh(cx);
+                            this.request_git_workspace_refresh(true, cx);
+                            this.load_operation_history(cx);
+                        }
+                        Err(err) => {
+                            error!("{action_name} failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                            Self::push_error_notification(
+                                tr_args(failed_key, &[("error", err.to_string().as_str())]),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
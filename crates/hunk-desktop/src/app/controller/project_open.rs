@@ -93,4 +93,43 @@ impl DiffViewer {
             }
         }
     }
+
+    pub(super) fn repo_file_exists_on_disk(&self, path: &str) -> bool {
+        self.repo_root
+            .as_ref()
+            .is_some_and(|repo_root| repo_root.join(path).exists())
+    }
+
+    pub(super) fn reveal_repo_file_in_file_manager(&mut self, path: &str, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.repo_root.clone() else {
+            self.git_status_message = Some("No repository is open.".to_string());
+            cx.notify();
+            return;
+        };
+
+        let absolute_path = repo_root.join(path);
+        if let Err(err) = project_open::reveal_path_in_file_manager(absolute_path.as_path()) {
+            error!("failed to reveal '{}': {err:#}", absolute_path.display());
+            Self::push_error_notification(format!("Reveal {path} failed: {err}"), cx);
+        }
+    }
+
+    pub(super) fn open_repo_file_with_default_application(
+        &mut self,
+        path: &str,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo_root) = self.repo_root.clone() else {
+            self.git_status_message = Some("No repository is open.".to_string());
+            cx.notify();
+            return;
+        };
+
+        let absolute_path = repo_root.join(path);
+        if let Err(err) = project_open::open_path_with_default_application(absolute_path.as_path())
+        {
+            error!("failed to open '{}': {err:#}", absolute_path.display());
+            Self::push_error_notification(format!("Open {path} failed: {err}"), cx);
+        }
+    }
 }
@@ -0,0 +1,59 @@
+const READ_ONLY_REASON: &str = "this repository is open in read-only mode";
+
+impl DiffViewer {
+    fn read_only_project_key(&self) -> Option<String> {
+        self.project_path
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string())
+    }
+
+    /// True when the `--read-only` launch flag is set or the open project was toggled read-only.
+    pub(super) fn read_only_active(&self) -> bool {
+        self.launch_read_only
+            || self
+                .read_only_project_key()
+                .is_some_and(|key| self.state.read_only_project_paths.contains(key.as_str()))
+    }
+
+    /// The launch flag applies to every project, so the per-project toggle cannot turn it off.
+    pub(super) fn read_only_locked_by_launch(&self) -> bool {
+        self.launch_read_only
+    }
+
+    pub(super) fn toggle_project_read_only(&mut self, cx: &mut Context<Self>) {
+        if self.launch_read_only {
+            return;
+        }
+        let Some(key) = self.read_only_project_key() else {
+            return;
+        };
+
+        let enabled = if self.state.read_only_project_paths.remove(key.as_str()) {
+            false
+        } else {
+            self.state.read_only_project_paths.insert(key);
+            true
+        };
+        self.persist_state();
+        self.git_status_message = Some(if enabled {
+            "Read-only mode on. Actions that write to this repository are disabled.".to_string()
+        } else {
+            "Read-only mode off.".to_string()
+        });
+        cx.notify();
+    }
+
+    /// Refuses `action_label` with a warning when read-only mode is on. Returns true when the
+    /// caller must stop.
+    fn block_in_read_only(&mut self, action_label: &str, cx: &mut Context<Self>) -> bool {
+        if !self.read_only_active() {
+            return false;
+        }
+
+        let message = format!("{action_label} unavailable: {READ_ONLY_REASON}.");
+        self.git_status_message = Some(message.clone());
+        Self::push_warning_notification(message, None, cx);
+        cx.notify();
+        true
+    }
+}
@@ -0,0 +1,54 @@
+impl DiffViewer {
+    /// Expands a Recent Commits row to list the files that commit changed, or collapses it when
+    /// it is already expanded.
+    pub(super) fn toggle_recent_commit_files(&mut self, commit_id: String, cx: &mut Context<Self>) {
+        if self.recent_commit_files_commit_id.as_deref() == Some(commit_id.as_str()) {
+            self.close_recent_commit_files();
+            cx.notify();
+            return;
+        }
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            return;
+        };
+        self.recent_commit_files_commit_id = Some(commit_id.clone());
+        self.recent_commit_files.clear();
+        self.recent_commit_files_loading = true;
+        self.recent_commit_files_error = None;
+        cx.notify();
+
+        self.recent_commit_files_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let commit_id = commit_id.clone();
+                    async move { load_commit_changed_files(repo_root.as_path(), &commit_id) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.recent_commit_files_commit_id.as_deref() != Some(commit_id.as_str()) {
+                        return;
+                    }
+                    this.recent_commit_files_loading = false;
+                    match result {
+                        Ok(files) => this.recent_commit_files = files,
+                        Err(err) => {
+                            error!("loading files changed by {commit_id} failed: {err:#}");
+                            this.recent_commit_files_error = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    fn close_recent_commit_files(&mut self) {
+        self.recent_commit_files_commit_id = None;
+        self.recent_commit_files.clear();
+        self.recent_commit_files_loading = false;
+        self.recent_commit_files_error = None;
+        self.recent_commit_files_task = Task::ready(());
+    }
+}
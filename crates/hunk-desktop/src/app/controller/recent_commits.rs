@@ -195,6 +195,12 @@ impl DiffViewer {
             .take()
             .map_or(request, |pending| request.merge(pending));
 
+        // Only the Git workspace shows recent commits, so hold the request until it is visible.
+        if !self.recent_commits_panel_visible() {
+            self.enqueue_recent_commits_refresh(request);
+            return;
+        }
+
         if self.recent_commits_loading {
             if request.is_more_urgent_than(self.active_recent_commits_refresh_request()) {
                 debug!(
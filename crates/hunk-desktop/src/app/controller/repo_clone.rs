@@ -0,0 +1,137 @@
+impl DiffViewer {
+    pub(super) fn open_repo_clone(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.repo_clone_prompt.is_some() {
+            return;
+        }
+
+        let parent = self
+            .project_path
+            .as_deref()
+            .and_then(std::path::Path::parent)
+            .map(std::path::Path::to_path_buf)
+            .or_else(dirs::home_dir)
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        self.repo_clone_prompt = Some(RepoClonePromptState {
+            url_input_state: git_recovery_input("", "git@github.com:owner/repo.git", window, cx),
+            parent_input_state: git_recovery_input(
+                parent.as_str(),
+                "Folder to clone into",
+                window,
+                cx,
+            ),
+            cancel: CancellationToken::new(),
+            cloning: false,
+            progress: None,
+            error_message: None,
+        });
+        cx.notify();
+    }
+
+    /// Cancels an in-flight clone as well; libgit2 removes the partial checkout.
+    pub(super) fn close_repo_clone(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(prompt) = self.repo_clone_prompt.take() {
+            prompt.cancel.cancel();
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    /// Where the clone dialog's current inputs would put the repository.
+    pub(super) fn repo_clone_destination(&self, cx: &App) -> Option<PathBuf> {
+        let prompt = self.repo_clone_prompt.as_ref()?;
+        let name = clone_directory_name(prompt.url_input_state.read(cx).value().as_ref())?;
+        let parent = prompt
+            .parent_input_state
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        (!parent.is_empty()).then(|| PathBuf::from(parent).join(name))
+    }
+
+    pub(super) fn start_repo_clone(&mut self, cx: &mut Context<Self>) {
+        let destination = self.repo_clone_destination(cx);
+        let Some(prompt) = self.repo_clone_prompt.as_mut() else {
+            return;
+        };
+        if prompt.cloning {
+            return;
+        }
+        let url = prompt.url_input_state.read(cx).value().trim().to_string();
+        let Some(destination) = destination else {
+            prompt.error_message = Some(if url.is_empty() {
+                "Enter a repository URL.".to_string()
+            } else {
+                "Choose a folder to clone into.".to_string()
+            });
+            cx.notify();
+            return;
+        };
+
+        let cancel = CancellationToken::new();
+        prompt.cancel = cancel.clone();
+        prompt.cloning = true;
+        prompt.progress = Some("Connecting...".to_string());
+        prompt.error_message = None;
+        cx.notify();
+
+        let (progress_tx, mut progress_rx) = mpsc::unbounded::<CloneProgress>();
+        self.repo_clone_task = cx.spawn(async move |this, cx| {
+            let clone_url = url.clone();
+            let clone = cx.background_executor().spawn(async move {
+                // Transfer callbacks fire per object, so only forward phase or percent changes.
+                let last_sent = std::cell::Cell::new(None);
+                let root = clone_repository_with_cancel(
+                    clone_url.as_str(),
+                    destination.as_path(),
+                    &cancel,
+                    |progress| {
+                        let key = Some((std::mem::discriminant(&progress), progress.percent()));
+                        if last_sent.replace(key) != key {
+                            progress_tx.unbounded_send(progress).ok();
+                        }
+                    },
+                )?;
+                Self::canonical_workspace_project_root(root.as_path())
+            });
+
+            while let Some(progress) = progress_rx.next().await {
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+                this.update(cx, |this, cx| {
+                    if let Some(prompt) = this.repo_clone_prompt.as_mut() {
+                        prompt.progress = Some(progress.summary());
+                        cx.notify();
+                    }
+                });
+            }
+            let result = clone.await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(prompt) = this.repo_clone_prompt.as_mut() else {
+                        return;
+                    };
+                    match result {
+                        Ok(project_root) => {
+                            this.repo_clone_prompt = None;
+                            this.activate_workspace_project_root(project_root, cx);
+                            let message = format!("Cloned {url}");
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                        }
+                        Err(err) => {
+                            error!("repository clone failed: {err:#}");
+                            prompt.cloning = false;
+                            prompt.progress = None;
+                            prompt.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,159 @@
+impl DiffViewer {
+    pub(super) fn search_repository_action(
+        &mut self,
+        _: &SearchRepository,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.repo_content_search_root().is_none() {
+            self.git_status_message = Some("No repository is open.".to_string());
+            cx.notify();
+            return;
+        }
+
+        if self.repo_content_search_panel.is_none() {
+            let query_input_state =
+                cx.new(|cx| InputState::new(window, cx).placeholder(tr("repo-search-placeholder")));
+            let _subscriptions = vec![cx.subscribe(
+                &query_input_state,
+                |this, _, event: &InputEvent, cx| match event {
+                    InputEvent::Change => {
+                        this.schedule_repo_content_search(REPO_CONTENT_SEARCH_DEBOUNCE, cx);
+                    }
+                    InputEvent::PressEnter { .. } => {
+                        this.schedule_repo_content_search(Duration::ZERO, cx);
+                    }
+                    _ => {}
+                },
+            )];
+            self.repo_content_search_panel = Some(RepoContentSearchPanelState {
+                query_input_state,
+                query: String::new(),
+                results: RepoContentSearchResults::default(),
+                searching: false,
+                cancelled: Arc::new(AtomicBool::new(false)),
+                error_message: None,
+                search_task: Task::ready(()),
+                _subscriptions,
+            });
+        }
+        if let Some(panel) = self.repo_content_search_panel.as_ref() {
+            panel
+                .query_input_state
+                .update(cx, |state, cx| state.focus(window, cx));
+        }
+        cx.notify();
+    }
+
+    pub(super) fn close_repo_content_search_panel(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(panel) = self.repo_content_search_panel.take() {
+            panel
+                .cancelled
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    fn repo_content_search_root(&self) -> Option<PathBuf> {
+        self.selected_git_workspace_root()
+            .or_else(|| self.repo_root.clone())
+    }
+
+    /// Searches the repository for the panel's query after `delay`, replacing any search still
+    /// running. Files are listed for each search, so new files and .gitignore changes count.
+    fn schedule_repo_content_search(&mut self, delay: Duration, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.repo_content_search_root() else {
+            return;
+        };
+        let Some(panel) = self.repo_content_search_panel.as_mut() else {
+            return;
+        };
+        let query = panel.query_input_state.read(cx).value().to_string();
+        panel
+            .cancelled
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        panel.cancelled = cancelled.clone();
+        panel.error_message = None;
+        if query.trim().is_empty() {
+            panel.query = query;
+            panel.results = RepoContentSearchResults::default();
+            panel.searching = false;
+            panel.search_task = Task::ready(());
+            cx.notify();
+            return;
+        }
+        panel.searching = true;
+        cx.notify();
+
+        panel.search_task = cx.spawn(async move |this, cx| {
+            if !delay.is_zero() {
+                cx.background_executor().timer(delay).await;
+            }
+            let search_cancelled = cancelled.clone();
+            let search_query = query.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let paths = hunk_git::git::load_visible_repo_file_paths(&repo_root)?;
+                    Ok::<_, anyhow::Error>(search_repo_contents(
+                        repo_root.as_path(),
+                        &paths,
+                        search_query.as_str(),
+                        &search_cancelled,
+                    ))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.repo_content_search_panel.as_mut() else {
+                        return;
+                    };
+                    if !Arc::ptr_eq(&panel.cancelled, &cancelled) {
+                        return;
+                    }
+                    panel.searching = false;
+                    panel.query = query;
+                    match result {
+                        Ok(results) => panel.results = results,
+                        Err(err) => {
+                            error!("repository search failed: {err:#}");
+                            panel.results = RepoContentSearchResults::default();
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Jumps to a match in the review when the diff shows its line, otherwise opens the file in
+    /// Files at that line. The panel stays open to step through more matches.
+    pub(super) fn open_repo_content_search_match(
+        &mut self,
+        file_ix: usize,
+        match_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((path, line)) = self
+            .repo_content_search_panel
+            .as_ref()
+            .and_then(|panel| panel.results.files.get(file_ix))
+            .and_then(|file| {
+                let line = file.matches.get(match_ix)?.line;
+                Some((file.path.clone(), line))
+            })
+        else {
+            return;
+        };
+        self.open_clipboard_path(path, Some(line), window, cx);
+    }
+}
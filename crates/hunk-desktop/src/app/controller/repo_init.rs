@@ -0,0 +1,77 @@
+impl DiffViewer {
+    /// Offers to initialize a repository in `path` after it was opened without one.
+    fn prompt_repo_init(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.repo_init_path = Some(path);
+        self.repo_init_template = RepoInitTemplate::default();
+        self.repo_init_loading = false;
+        cx.notify();
+    }
+
+    pub(super) fn cancel_repo_init(&mut self, cx: &mut Context<Self>) {
+        if self.repo_init_loading {
+            return;
+        }
+        self.repo_init_path = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_repo_init_gitignore(
+        &mut self,
+        gitignore: GitignoreTemplate,
+        cx: &mut Context<Self>,
+    ) {
+        self.repo_init_template.gitignore = gitignore;
+        cx.notify();
+    }
+
+    pub(super) fn set_repo_init_readme(&mut self, readme: bool, cx: &mut Context<Self>) {
+        self.repo_init_template.readme = readme;
+        cx.notify();
+    }
+
+    /// Runs `git init` in the pending folder, writes the chosen template files and opens the new
+    /// repository as a project.
+    pub(super) fn initialize_repo_here(&mut self, cx: &mut Context<Self>) {
+        if self.repo_init_loading {
+            return;
+        }
+        let Some(path) = self.repo_init_path.clone() else {
+            return;
+        };
+        let template = self.repo_init_template;
+        self.repo_init_loading = true;
+        cx.notify();
+
+        self.open_project_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let root = init_repository(path.as_path(), template)?;
+                    Self::canonical_workspace_project_root(root.as_path())
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    this.repo_init_loading = false;
+                    match result {
+                        Ok(project_root) => {
+                            this.repo_init_path = None;
+                            this.activate_workspace_project_root(project_root, cx);
+                            this.git_status_message =
+                                Some("Initialized Git repository".to_string());
+                        }
+                        Err(err) => {
+                            error!("repository initialization failed: {err:#}");
+                            Self::push_error_notification(
+                                format!("Failed to initialize repository: {err}"),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,124 @@
+impl DiffViewer {
+    pub(super) fn open_repository_issues_action(
+        &mut self,
+        _: &OpenRepositoryIssues,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_repo_issues(cx);
+    }
+
+    pub(super) fn open_repo_issues(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+
+        self.repo_issues_panel = Some(RepoIssuesPanelState {
+            repo_root,
+            loading: true,
+            issues: Vec::new(),
+            resolving_ix: None,
+            error_message: None,
+        });
+        self.load_repo_issues(cx);
+    }
+
+    pub(super) fn close_repo_issues(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.repo_issues_panel.take().is_some() {
+            self.repo_issues_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn load_repo_issues(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.repo_issues_panel.as_mut() else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.loading = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.repo_issues_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { detect_repo_issues(repo_root.as_path()) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.repo_issues_panel.as_mut() else {
+                        return;
+                    };
+                    panel.loading = false;
+                    match result {
+                        Ok(issues) => panel.issues = issues,
+                        Err(err) => {
+                            error!("repository issue detection failed: {err:#}");
+                            panel.issues.clear();
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Applies the suggested resolution for the issue at `issue_ix`, then checks the repository
+    /// again since one fix can clear or reveal others.
+    pub(super) fn resolve_repo_issue_at(&mut self, issue_ix: usize, cx: &mut Context<Self>) {
+        if self.block_in_read_only("Resolve issue", cx) {
+            return;
+        }
+        let Some(panel) = self.repo_issues_panel.as_mut() else {
+            return;
+        };
+        if panel.loading || panel.resolving_ix.is_some() {
+            return;
+        }
+        let Some(resolution) = panel
+            .issues
+            .get(issue_ix)
+            .and_then(|issue| issue.resolution.clone())
+        else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.resolving_ix = Some(issue_ix);
+        panel.error_message = None;
+        cx.notify();
+
+        self.repo_issues_task = cx.spawn(async move |this, cx| {
+            let label = resolution.label();
+            let result = cx
+                .background_executor()
+                .spawn(async move { resolve_repo_issue(repo_root.as_path(), &resolution) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.repo_issues_panel.as_mut() else {
+                        return;
+                    };
+                    panel.resolving_ix = None;
+                    match result {
+                        Ok(()) => {
+                            this.git_status_message = Some(format!("{label} completed."));
+                            this.request_git_workspace_refresh(false, cx);
+                            this.load_repo_issues(cx);
+                        }
+                        Err(err) => {
+                            error!("repository issue resolution failed: {err:#}");
+                            panel.error_message = Some(format!("{label} failed: {err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,32 @@
+impl DiffViewer {
+    pub(super) fn toggle_changed_lines_only(&mut self, cx: &mut Context<Self>) {
+        self.changed_lines_only.toggle_all();
+        self.reload_after_changed_lines_only_toggle(cx);
+    }
+
+    pub(super) fn toggle_file_changed_lines_only(&mut self, path: String, cx: &mut Context<Self>) {
+        self.changed_lines_only.toggle_file(path.as_str());
+        let status = self
+            .active_diff_files()
+            .iter()
+            .find(|file| file.path == path)
+            .map(|file| file.status);
+        self.set_review_selected_file(Some(path), status);
+        self.reload_after_changed_lines_only_toggle(cx);
+    }
+
+    fn reload_after_changed_lines_only_toggle(&mut self, cx: &mut Context<Self>) {
+        self.scroll_selected_after_reload = true;
+        self.review_surface.last_diff_scroll_offset = None;
+        self.last_scroll_activity_at = Instant::now();
+        self.request_selected_diff_reload(cx);
+        cx.notify();
+    }
+
+    /// Loaded patches to refilter when only the row filters changed since the last load.
+    fn loaded_review_compare_snapshot_for_reuse(&self) -> Option<Arc<CompareSnapshot>> {
+        should_reuse_loaded_review_compare_patches(self.loaded_review_compare_reuse_state())
+            .then(|| self.review_loaded_compare_snapshot.clone())
+            .flatten()
+    }
+}
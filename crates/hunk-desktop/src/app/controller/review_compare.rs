@@ -9,12 +9,25 @@ struct LoadedReviewCompareReuseState<'a, F> {
     loaded_right_source_id: Option<&'a str>,
     current_collapsed_files: &'a BTreeSet<String>,
     loaded_collapsed_files: &'a BTreeSet<String>,
+    current_changed_lines_only: &'a ChangedLinesOnlyMode,
+    loaded_changed_lines_only: &'a ChangedLinesOnlyMode,
+    current_hunk_folds: &'a HunkFolds,
+    loaded_hunk_folds: &'a HunkFolds,
     current_snapshot_fingerprint: Option<&'a F>,
     loaded_snapshot_fingerprint: Option<&'a F>,
 }
 
 fn should_reuse_loaded_review_compare<F: PartialEq>(
     state: LoadedReviewCompareReuseState<'_, F>,
+) -> bool {
+    should_reuse_loaded_review_compare_patches(state)
+        && state.current_changed_lines_only == state.loaded_changed_lines_only
+        && state.current_hunk_folds == state.loaded_hunk_folds
+}
+
+/// Whether the loaded patches still match, so only the stream filters need rebuilding.
+fn should_reuse_loaded_review_compare_patches<F: PartialEq>(
+    state: LoadedReviewCompareReuseState<'_, F>,
 ) -> bool {
     state.has_loaded_session
         && !state.review_compare_loading
@@ -286,7 +299,27 @@ impl DiffViewer {
     }
 
     pub(crate) fn should_reuse_loaded_review_compare(&self) -> bool {
-        should_reuse_loaded_review_compare(LoadedReviewCompareReuseState {
+        should_reuse_loaded_review_compare(self.loaded_review_compare_reuse_state())
+    }
+
+    /// Whether the comparison diffs a branch against the open repository's working copy, the
+    /// only side the snapshot fingerprint tracks.
+    fn review_compare_follows_working_copy(
+        &self,
+        left_source: &CompareSource,
+        right_source: &CompareSource,
+    ) -> bool {
+        matches!(left_source, CompareSource::Branch { .. })
+            && matches!(
+                right_source,
+                CompareSource::WorkspaceTarget { root, .. } if self.repo_root.as_ref() == Some(root)
+            )
+    }
+
+    fn loaded_review_compare_reuse_state(
+        &self,
+    ) -> LoadedReviewCompareReuseState<'_, RepoSnapshotFingerprint> {
+        LoadedReviewCompareReuseState {
             has_loaded_session: self.review_workspace_session.is_some(),
             review_compare_loading: self.review_compare_loading,
             review_compare_error: self.review_compare_error.as_deref(),
@@ -296,9 +329,13 @@ impl DiffViewer {
             loaded_right_source_id: self.review_loaded_right_source_id.as_deref(),
             current_collapsed_files: &self.collapsed_files,
             loaded_collapsed_files: &self.review_loaded_collapsed_files,
+            current_changed_lines_only: &self.changed_lines_only,
+            loaded_changed_lines_only: &self.review_loaded_changed_lines_only,
+            current_hunk_folds: &self.hunk_folds,
+            loaded_hunk_folds: &self.review_loaded_hunk_folds,
             current_snapshot_fingerprint: self.last_snapshot_fingerprint.as_ref(),
             loaded_snapshot_fingerprint: self.review_loaded_snapshot_fingerprint.as_ref(),
-        })
+        }
     }
 
     fn subscribe_review_compare_picker_states(&self, cx: &mut Context<Self>) {
@@ -472,15 +509,47 @@ impl DiffViewer {
     }
 
     pub(crate) fn active_diff_file_count(&self) -> usize {
-        self.active_diff_files().len()
+        self.active_diff_files()
+            .iter()
+            .filter(|file| !path_is_stats_ignored(file.path.as_str(), &self.stats_ignored_paths))
+            .count()
     }
 
-    pub(crate) fn active_diff_overall_line_stats(&self) -> LineStats {
+    pub(super) fn active_diff_file_line_stats(&self) -> &BTreeMap<String, LineStats> {
         if self.workspace_view_mode == WorkspaceViewMode::Diff {
+            &self.review_file_line_stats
+        } else {
+            &self.file_line_stats
+        }
+    }
+
+    /// Overall line stats of the active diff, leaving out files excluded from stats.
+    pub(crate) fn active_diff_overall_line_stats(&self) -> LineStats {
+        let overall = if self.workspace_view_mode == WorkspaceViewMode::Diff {
             self.review_overall_line_stats
         } else {
             self.overall_line_stats
-        }
+        };
+        line_stats_without_ignored_paths(
+            overall,
+            self.active_diff_file_line_stats(),
+            &self.stats_ignored_paths,
+        )
+    }
+
+    pub(crate) fn git_workspace_overall_line_stats(&self) -> LineStats {
+        line_stats_without_ignored_paths(
+            self.git_workspace.overall_line_stats,
+            &self.git_workspace.file_line_stats,
+            &self.stats_ignored_paths,
+        )
+    }
+
+    pub(crate) fn stats_ignored_file_count(&self) -> usize {
+        self.active_diff_files()
+            .iter()
+            .filter(|file| path_is_stats_ignored(file.path.as_str(), &self.stats_ignored_paths))
+            .count()
     }
 
     fn active_diff_contains_path(&self, path: &str) -> bool {
@@ -726,6 +795,9 @@ impl DiffViewer {
             .clone()
             .or_else(|| persisted_selection.and_then(|selection| selection.right_source_id))
             .or(default_right_source_id.clone());
+        for source_id in [left_source_id.as_deref(), right_source_id.as_deref()] {
+            push_review_compare_revision_source(&mut sources, &mut seen_ids, source_id);
+        }
         let (left_source_id, right_source_id) =
             Self::normalize_review_compare_selection_ids(&sources, left_source_id, right_source_id);
 
@@ -750,21 +822,6 @@ impl DiffViewer {
         ))
     }
 
-    fn review_compare_option_to_git_source(
-        &self,
-        option: &ReviewCompareSourceOption,
-    ) -> Option<CompareSource> {
-        match option.kind {
-            crate::app::review_compare_picker::ReviewCompareSourceKind::WorkspaceTarget => Some(CompareSource::WorkspaceTarget {
-                target_id: option.workspace_target_id.clone()?,
-                root: option.workspace_root.clone()?,
-            }),
-            crate::app::review_compare_picker::ReviewCompareSourceKind::Branch => Some(CompareSource::Branch {
-                name: option.branch_name.clone()?,
-            }),
-        }
-    }
-
     fn active_review_compare_is_default_pair(&self) -> bool {
         let (default_left, default_right) = self.default_review_compare_selection_ids();
         self.review_left_source_id == default_left && self.review_right_source_id == default_right
@@ -793,6 +850,7 @@ impl DiffViewer {
         self.review_loaded_left_source_id = None;
         self.review_loaded_right_source_id = None;
         self.review_loaded_collapsed_files.clear();
+        self.clear_loaded_review_row_filters();
         self.review_loaded_snapshot_fingerprint = None;
         self.review_surface.clear_workspace_editors();
         self.review_surface.clear_workspace_search_matches();
@@ -801,8 +859,14 @@ impl DiffViewer {
         self.review_files.clear();
         self.review_file_status_by_path.clear();
         self.review_file_line_stats.clear();
+        self.review_file_encodings.clear();
+        self.review_extracted_text_paths.clear();
+        self.review_mechanical_changes = ReviewMechanicalChanges::default();
+        self.review_mechanical_changes_task = Task::ready(());
+        self.review_change_set_size = ReviewChangeSetSize::default();
         self.review_overall_line_stats = LineStats::default();
         self.comments_cache.clear();
+        self.comment_counts = CommentScopeCounts::default();
         self.comment_miss_streaks.clear();
         self.reset_comment_row_match_cache();
         self.clear_comment_ui_state();
@@ -823,13 +887,32 @@ impl DiffViewer {
             return;
         };
 
+        self.adopt_review_snapshot_cache_if_current();
+        match self.review_compare_changed_paths(&left_source, &right_source) {
+            Some(changed_paths) if changed_paths.is_empty() => {
+                // Only branch metadata moved, so the loaded patches are still current.
+                self.review_loaded_snapshot_fingerprint = self.last_snapshot_fingerprint.clone();
+            }
+            Some(changed_paths) => {
+                self.refresh_changed_review_files(
+                    primary_repo_root,
+                    left_source,
+                    right_source,
+                    changed_paths,
+                    cx,
+                );
+                return;
+            }
+            None => {}
+        }
+
         if self.should_reuse_loaded_review_compare() {
             self.review_compare_loading = false;
             self.review_compare_error = None;
             self.review_surface.status_message = None;
             self.review_surface.selected_path = self.current_review_path();
             if self.review_comments_enabled() {
-                self.refresh_comments_cache_from_store();
+                self.reload_comments_cache(false, cx);
             }
             if self.editor_search_visible {
                 self.sync_editor_search_query(cx);
@@ -839,11 +922,18 @@ impl DiffViewer {
             return;
         }
 
+        let loaded_snapshot = self.loaded_review_compare_snapshot_for_reuse();
         let previous_review_line_stats = self.review_file_line_stats.clone();
         let collapsed_files = self.collapsed_files.clone();
+        let changed_lines_only = self.changed_lines_only.clone();
+        let hunk_folds = self.hunk_folds.clone();
         let left_source_id = self.review_left_source_id.clone();
         let right_source_id = self.review_right_source_id.clone();
+        let compare_options = CompareOptions {
+            hex_diff_max_bytes: self.config.hex_diff_max_bytes,
+        };
         let epoch = self.next_patch_epoch();
+        let cancel = self.patch_cancel.clone();
 
         self.review_compare_loading = true;
         self.review_compare_error = None;
@@ -857,16 +947,47 @@ impl DiffViewer {
             let result = cx
                 .background_executor()
                 .spawn(async move {
-                    let snapshot =
-                        load_compare_snapshot(primary_repo_root.as_path(), &left_source, &right_source)?;
+                    // Row filter toggles only refilter rows, so reuse the loaded patches.
+                    let snapshot = match loaded_snapshot {
+                        Some(snapshot) => snapshot,
+                        None => Arc::new(load_compare_snapshot_with_options(
+                            primary_repo_root.as_path(),
+                            &left_source,
+                            &right_source,
+                            compare_options,
+                            &cancel,
+                        )?),
+                    };
+                    let changed_lines_only_files = changed_lines_only.paths_for(&snapshot.files);
                     let stream = build_diff_stream_from_patch_map(
                         &snapshot.files,
                         &collapsed_files,
+                        &changed_lines_only_files,
+                        &hunk_folds,
                         &previous_review_line_stats,
                         &snapshot.patches_by_path,
                         &BTreeSet::new(),
                     );
-                    Ok::<_, anyhow::Error>((snapshot, stream))
+                    // Only working copies can be converted, so branch sides skip detection.
+                    let file_encodings = match &right_source {
+                        CompareSource::WorkspaceTarget { root, .. } => load_working_copy_encodings(
+                            root.as_path(),
+                            snapshot.files.iter().map(|file| file.path.as_str()),
+                        )
+                        .unwrap_or_else(|err| {
+                            warn!("failed to detect review file encodings: {err:#}");
+                            BTreeMap::new()
+                        }),
+                        CompareSource::Branch { .. } | CompareSource::Revision { .. } => {
+                            BTreeMap::new()
+                        }
+                    };
+                    Ok::<_, anyhow::Error>((
+                        snapshot,
+                        stream,
+                        changed_lines_only_files,
+                        file_encodings,
+                    ))
                 })
                 .await;
 
@@ -878,7 +999,7 @@ impl DiffViewer {
 
                     this.review_compare_loading = false;
                     match result {
-                        Ok((snapshot, stream)) => {
+                        Ok((snapshot, stream, changed_lines_only_files, file_encodings)) => {
                             debug!(
                                 left = left_source_id.as_deref().unwrap_or("unknown"),
                                 right = right_source_id.as_deref().unwrap_or("unknown"),
@@ -887,7 +1008,20 @@ impl DiffViewer {
                                 elapsed_ms = started_at.elapsed().as_millis(),
                                 "review compare snapshot loaded"
                             );
-                            this.apply_loaded_review_compare_stream(snapshot, stream, cx);
+                            this.record_usage_metric(UsageMetricKind::DiffViewed, None, cx);
+                            this.review_file_encodings = file_encodings;
+                            this.review_extracted_text_paths = snapshot
+                                .patches_by_path
+                                .iter()
+                                .filter(|(_, patch)| is_extracted_text_patch(patch))
+                                .map(|(path, _)| path.clone())
+                                .collect();
+                            this.apply_loaded_review_compare_stream(
+                                snapshot,
+                                stream,
+                                &changed_lines_only_files,
+                                cx,
+                            );
                         }
                         Err(err) => {
                             error!(
@@ -911,19 +1045,30 @@ impl DiffViewer {
 
     fn apply_loaded_review_compare_stream(
         &mut self,
-        snapshot: hunk_git::compare::CompareSnapshot,
+        snapshot: Arc<CompareSnapshot>,
         stream: DiffStream,
+        changed_lines_only_files: &BTreeSet<String>,
         cx: &mut Context<Self>,
     ) {
         self.review_compare_error = None;
         self.review_surface.status_message = None;
+        // Keep the row at the top of the viewport in place unless the reload jumps to a file.
+        let scroll_anchor = if self.scroll_selected_after_reload {
+            None
+        } else {
+            self.current_review_scroll_anchor()
+        };
         self.review_workspace_session =
             match crate::app::review_workspace_session::ReviewWorkspaceSession::from_compare_snapshot(
                 &snapshot,
                 &self.collapsed_files,
+                changed_lines_only_files,
+                &self.hunk_folds,
             ) {
                 Ok(session) => {
-                    let session = session.with_render_stream(&stream);
+                    let session = session
+                        .with_unified_rows(self.diff_unified_view)
+                        .with_render_stream(&stream);
                     debug!(
                         workspace_documents = session.layout().documents().len(),
                         workspace_excerpts = session.layout().excerpts().len(),
@@ -943,6 +1088,9 @@ impl DiffViewer {
                     return;
                 }
             };
+        if let Some(anchor) = scroll_anchor.as_ref() {
+            self.restore_review_scroll_anchor(anchor);
+        }
         let preferred_selected_path = self
             .current_review_editor_path()
             .or_else(|| self.review_surface.selected_path.clone());
@@ -970,22 +1118,47 @@ impl DiffViewer {
         };
         self.review_surface.set_workspace_owner(workspace_owner);
         let seeded_display_rows = self.seed_review_surface_display_rows();
-        self.review_files = snapshot.files;
+        self.review_files = snapshot.files.clone();
         self.review_file_status_by_path = self
             .review_files
             .iter()
             .map(|file| (file.path.clone(), file.status))
             .collect();
+        let reopened_files = self
+            .review_reviewed_directories
+            .reset_for_new_files(&self.review_files);
         self.review_loaded_left_source_id = self.review_left_source_id.clone();
         self.review_loaded_right_source_id = self.review_right_source_id.clone();
         self.review_loaded_collapsed_files = self.collapsed_files.clone();
+        self.review_loaded_changed_lines_only = self.changed_lines_only.clone();
+        self.review_loaded_hunk_folds = self.hunk_folds.clone();
         self.review_loaded_snapshot_fingerprint = self.last_snapshot_fingerprint.clone();
-        self.review_file_line_stats = snapshot.file_line_stats;
+        self.review_file_line_stats = snapshot.file_line_stats.clone();
         self.review_overall_line_stats = snapshot.overall_line_stats;
+        let snapshot_changed = !self
+            .review_loaded_compare_snapshot
+            .as_ref()
+            .is_some_and(|loaded| Arc::ptr_eq(loaded, &snapshot));
+        self.review_loaded_compare_snapshot = Some(snapshot);
+        if snapshot_changed {
+            self.refresh_review_mechanical_changes(cx);
+            self.refresh_review_change_set_size();
+        }
         self.collapsed_files
             .retain(|path| self.review_files.iter().any(|file| file.path == *path));
+        // Folders that lost their reviewed mark hand back the files they collapsed; the stream
+        // was built with them collapsed, so another pass rebuilds it.
+        let mut reopened_collapsed_files = false;
+        for path in &reopened_files {
+            reopened_collapsed_files |= self.collapsed_files.remove(path.as_str());
+        }
+        self.refresh_review_hunk_stage_states(cx);
 
         self.apply_loaded_review_workspace_surface();
+        if let Some(anchor) = scroll_anchor.as_ref() {
+            // Seeding measured the rows around the viewport, so resolve against final geometry.
+            self.restore_review_scroll_anchor(anchor);
+        }
         debug!(
             seeded_display_rows,
             "review workspace surface projection initialized"
@@ -1023,19 +1196,20 @@ impl DiffViewer {
         } else {
             self.review_surface.clear_workspace_search_matches();
         }
-        self.refresh_comments_cache_from_store();
         self.rebuild_comment_row_match_cache();
-        if self.review_comments_enabled() {
-            self.reconcile_comments_with_loaded_diff();
-        }
+        self.reload_comments_for_loaded_diff(cx);
 
         if self.scroll_selected_after_reload {
             self.scroll_selected_file_to_top();
             self.scroll_selected_after_reload = false;
         }
         self.prime_diff_surface_visible_state(true, cx);
+        self.persist_review_snapshot_cache(cx);
 
         self.request_repo_tree_reload(cx);
+        if reopened_collapsed_files {
+            self.request_selected_diff_reload(cx);
+        }
         cx.notify();
     }
 
@@ -1062,6 +1236,10 @@ impl DiffViewer {
     ) {
         let left_source_id = next_left_source_id.or_else(|| self.review_left_source_id.clone());
         let right_source_id = next_right_source_id.or_else(|| self.review_right_source_id.clone());
+        self.push_review_compare_revision_sources([
+            left_source_id.as_deref(),
+            right_source_id.as_deref(),
+        ]);
         let (left_source_id, right_source_id) = Self::normalize_review_compare_selection_ids(
             &self.review_compare_sources,
             left_source_id,
@@ -1080,12 +1258,14 @@ impl DiffViewer {
         }
         self.sync_review_compare_picker_states(cx);
         self.comments_cache.clear();
+        self.comment_counts = CommentScopeCounts::default();
         self.comment_miss_streaks.clear();
         self.reset_comment_row_match_cache();
         self.clear_comment_ui_state();
         self.review_loaded_left_source_id = None;
         self.review_loaded_right_source_id = None;
         self.review_loaded_collapsed_files.clear();
+        self.clear_loaded_review_row_filters();
         self.review_loaded_snapshot_fingerprint = None;
         if self.workspace_view_mode == WorkspaceViewMode::Diff {
             self.scroll_selected_after_reload = true;
@@ -1099,8 +1279,9 @@ impl DiffViewer {
 #[cfg(test)]
 mod review_compare_tests {
     use super::{
-        LoadedReviewCompareReuseState, preferred_review_workspace_path_for_session,
-        should_reuse_loaded_review_compare,
+        ChangedLinesOnlyMode, HunkFolds, LoadedReviewCompareReuseState,
+        preferred_review_workspace_path_for_session, should_reuse_loaded_review_compare,
+        should_reuse_loaded_review_compare_patches,
     };
     use hunk_git::compare::CompareSnapshot;
     use hunk_git::git::{ChangedFile, FileStatus, LineStats};
@@ -1110,6 +1291,8 @@ mod review_compare_tests {
     fn loaded_review_compare_reuse_requires_matching_identity() {
         let current_collapsed_files = BTreeSet::new();
         let loaded_collapsed_files = BTreeSet::new();
+        let changed_lines_only = ChangedLinesOnlyMode::default();
+        let hunk_folds = HunkFolds::default();
         let matching_state = LoadedReviewCompareReuseState {
             has_loaded_session: true,
             review_compare_loading: false,
@@ -1120,6 +1303,10 @@ mod review_compare_tests {
             loaded_right_source_id: Some("right"),
             current_collapsed_files: &current_collapsed_files,
             loaded_collapsed_files: &loaded_collapsed_files,
+            current_changed_lines_only: &changed_lines_only,
+            loaded_changed_lines_only: &changed_lines_only,
+            current_hunk_folds: &hunk_folds,
+            loaded_hunk_folds: &hunk_folds,
             current_snapshot_fingerprint: Some(&1_u8),
             loaded_snapshot_fingerprint: Some(&1_u8),
         };
@@ -1148,6 +1335,49 @@ mod review_compare_tests {
         }));
     }
 
+    #[test]
+    fn changed_lines_only_toggle_reuses_loaded_patches_but_rebuilds_stream() {
+        let collapsed_files = BTreeSet::new();
+        let loaded_changed_lines_only = ChangedLinesOnlyMode::default();
+        let mut current_changed_lines_only = ChangedLinesOnlyMode::default();
+        current_changed_lines_only.toggle_all();
+        let hunk_folds = HunkFolds::default();
+        let state = LoadedReviewCompareReuseState {
+            has_loaded_session: true,
+            review_compare_loading: false,
+            review_compare_error: None,
+            current_left_source_id: Some("left"),
+            current_right_source_id: Some("right"),
+            loaded_left_source_id: Some("left"),
+            loaded_right_source_id: Some("right"),
+            current_collapsed_files: &collapsed_files,
+            loaded_collapsed_files: &collapsed_files,
+            current_changed_lines_only: &current_changed_lines_only,
+            loaded_changed_lines_only: &loaded_changed_lines_only,
+            current_hunk_folds: &hunk_folds,
+            loaded_hunk_folds: &hunk_folds,
+            current_snapshot_fingerprint: Some(&1_u8),
+            loaded_snapshot_fingerprint: Some(&1_u8),
+        };
+
+        assert!(!should_reuse_loaded_review_compare(state));
+        assert!(should_reuse_loaded_review_compare_patches(state));
+        assert!(!should_reuse_loaded_review_compare_patches(LoadedReviewCompareReuseState {
+            loaded_snapshot_fingerprint: Some(&2_u8),
+            ..state
+        }));
+
+        let mut current_hunk_folds = HunkFolds::default();
+        current_hunk_folds.toggle_collapsed("src/lib.rs", 1);
+        let hunk_toggle_state = LoadedReviewCompareReuseState {
+            current_changed_lines_only: &loaded_changed_lines_only,
+            current_hunk_folds: &current_hunk_folds,
+            ..state
+        };
+        assert!(!should_reuse_loaded_review_compare(hunk_toggle_state));
+        assert!(should_reuse_loaded_review_compare_patches(hunk_toggle_state));
+    }
+
     fn changed_file(path: &str, status: FileStatus) -> ChangedFile {
         ChangedFile {
             path: path.to_string(),
@@ -1174,6 +1404,8 @@ mod review_compare_tests {
         crate::app::review_workspace_session::ReviewWorkspaceSession::from_compare_snapshot(
             &snapshot,
             &BTreeSet::new(),
+            &BTreeSet::new(),
+            &HunkFolds::default(),
         )
         .expect("review workspace session should build")
     }
@@ -0,0 +1,183 @@
+impl DiffViewer {
+    /// Files whose blobs changed in the working copy since the comparison was loaded, when
+    /// nothing else about the comparison did. `None` means the whole comparison needs reloading.
+    fn review_compare_changed_paths(
+        &self,
+        left_source: &CompareSource,
+        right_source: &CompareSource,
+    ) -> Option<BTreeSet<String>> {
+        let follows_working_copy =
+            self.review_compare_follows_working_copy(left_source, right_source);
+        let state = self.loaded_review_compare_reuse_state();
+        let view_unchanged = should_reuse_loaded_review_compare(LoadedReviewCompareReuseState {
+            current_snapshot_fingerprint: None,
+            loaded_snapshot_fingerprint: None,
+            ..state
+        });
+        if !follows_working_copy
+            || !view_unchanged
+            || self.review_loaded_compare_snapshot.is_none()
+        {
+            return None;
+        }
+
+        state
+            .current_snapshot_fingerprint?
+            .changed_paths_since(state.loaded_snapshot_fingerprint?)
+    }
+
+    /// Reloads only `changed_paths` and splices their rows into the loaded stream, so untouched
+    /// files keep their rows, segment caches and comment anchors.
+    fn refresh_changed_review_files(
+        &mut self,
+        primary_repo_root: PathBuf,
+        left_source: CompareSource,
+        right_source: CompareSource,
+        changed_paths: BTreeSet<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(previous), Some(session)) = (
+            self.review_loaded_compare_snapshot.clone(),
+            self.review_workspace_session.as_ref(),
+        ) else {
+            return;
+        };
+        let stream = session.render_stream();
+        let previous_review_line_stats = self.review_file_line_stats.clone();
+        let collapsed_files = self.collapsed_files.clone();
+        let changed_lines_only = self.changed_lines_only.clone();
+        let hunk_folds = self.hunk_folds.clone();
+        let compare_options = CompareOptions {
+            hex_diff_max_bytes: self.config.hex_diff_max_bytes,
+        };
+        let epoch = self.next_patch_epoch();
+        let cancel = self.patch_cancel.clone();
+
+        self.patch_task = cx.spawn(async move |this, cx| {
+            let started_at = Instant::now();
+            let changed_count = changed_paths.len();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let snapshot = refresh_compare_snapshot_paths(
+                        primary_repo_root.as_path(),
+                        &left_source,
+                        &right_source,
+                        compare_options,
+                        &previous,
+                        &changed_paths,
+                        &cancel,
+                    )?;
+                    let changed_lines_only_files = changed_lines_only.paths_for(&snapshot.files);
+                    let mut stream = stream;
+                    let spliced = changed_paths.iter().all(|path| {
+                        let previous_file = previous.files.iter().find(|file| file.path == *path);
+                        let file = snapshot.files.iter().find(|file| file.path == *path);
+                        match (previous_file, file) {
+                            (None, None) => true,
+                            (Some(previous_file), Some(file))
+                                if previous_file.status == file.status
+                                    && !collapsed_files.contains(path.as_str()) =>
+                            {
+                                replace_file_rows_in_diff_stream(
+                                    &mut stream,
+                                    file,
+                                    snapshot
+                                        .patches_by_path
+                                        .get(path.as_str())
+                                        .map(String::as_str)
+                                        .unwrap_or_default(),
+                                    changed_lines_only_files.contains(path.as_str()),
+                                    &hunk_folds,
+                                )
+                            }
+                            _ => false,
+                        }
+                    });
+                    // Files entering or leaving the comparison shift every row after them.
+                    if !spliced {
+                        stream = build_diff_stream_from_patch_map(
+                            &snapshot.files,
+                            &collapsed_files,
+                            &changed_lines_only_files,
+                            &hunk_folds,
+                            &previous_review_line_stats,
+                            &snapshot.patches_by_path,
+                            &BTreeSet::new(),
+                        );
+                    }
+                    let file_encodings: BTreeMap<_, _> = match &right_source {
+                        CompareSource::WorkspaceTarget { root, .. } => load_working_copy_encodings(
+                            root.as_path(),
+                            changed_paths
+                                .iter()
+                                .filter(|path| snapshot.patches_by_path.contains_key(*path))
+                                .map(String::as_str),
+                        )
+                        .unwrap_or_else(|err| {
+                            warn!("failed to detect review file encodings: {err:#}");
+                            BTreeMap::new()
+                        }),
+                        CompareSource::Branch { .. } | CompareSource::Revision { .. } => {
+                            BTreeMap::new()
+                        }
+                    };
+                    Ok::<_, anyhow::Error>((
+                        Arc::new(snapshot),
+                        stream,
+                        changed_lines_only_files,
+                        changed_paths,
+                        file_encodings,
+                    ))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if epoch != this.patch_epoch {
+                        return;
+                    }
+
+                    match result {
+                        Ok((
+                            snapshot,
+                            stream,
+                            changed_lines_only_files,
+                            changed_paths,
+                            file_encodings,
+                        )) => {
+                            debug!(
+                                files = changed_count,
+                                elapsed_ms = started_at.elapsed().as_millis(),
+                                "review files refreshed in place"
+                            );
+                            this.review_file_encodings
+                                .retain(|path, _| !changed_paths.contains(path));
+                            this.review_file_encodings.extend(file_encodings);
+                            this.review_extracted_text_paths = snapshot
+                                .patches_by_path
+                                .iter()
+                                .filter(|(_, patch)| is_extracted_text_patch(patch))
+                                .map(|(path, _)| path.clone())
+                                .collect();
+                            this.apply_loaded_review_compare_stream(
+                                snapshot,
+                                stream,
+                                &changed_lines_only_files,
+                                cx,
+                            );
+                        }
+                        Err(err) => {
+                            debug!(
+                                files = changed_count,
+                                "in-place review refresh fell back to a full reload: {err:#}"
+                            );
+                            this.review_loaded_snapshot_fingerprint = None;
+                            this.request_review_compare_refresh(cx);
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
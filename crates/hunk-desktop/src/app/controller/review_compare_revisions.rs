@@ -0,0 +1,53 @@
+/// Adds a source for a revision typed into the compare picker, which isn't one of the listed
+/// branches or workspaces.
+fn push_review_compare_revision_source(
+    sources: &mut Vec<ReviewCompareSourceOption>,
+    seen_ids: &mut BTreeSet<String>,
+    source_id: Option<&str>,
+) {
+    let Some(spec) = source_id.and_then(hunk_git::compare::compare_revision_source_spec) else {
+        return;
+    };
+    let source = ReviewCompareSourceOption::from_revision(spec);
+    if seen_ids.insert(source.id.clone()) {
+        sources.push(source);
+    }
+}
+
+impl DiffViewer {
+    /// Lists revisions typed into the compare pickers alongside the known sources.
+    fn push_review_compare_revision_sources(&mut self, source_ids: [Option<&str>; 2]) {
+        let mut seen_ids = self
+            .review_compare_sources
+            .iter()
+            .map(|source| source.id.clone())
+            .collect::<BTreeSet<_>>();
+        for source_id in source_ids {
+            push_review_compare_revision_source(
+                &mut self.review_compare_sources,
+                &mut seen_ids,
+                source_id,
+            );
+        }
+    }
+
+    fn review_compare_option_to_git_source(
+        &self,
+        option: &ReviewCompareSourceOption,
+    ) -> Option<CompareSource> {
+        match option.kind {
+            crate::app::review_compare_picker::ReviewCompareSourceKind::WorkspaceTarget => Some(CompareSource::WorkspaceTarget {
+                target_id: option.workspace_target_id.clone()?,
+                root: option.workspace_root.clone()?,
+            }),
+            crate::app::review_compare_picker::ReviewCompareSourceKind::Branch => Some(CompareSource::Branch {
+                name: option.branch_name.clone()?,
+            }),
+            crate::app::review_compare_picker::ReviewCompareSourceKind::Revision => {
+                Some(CompareSource::Revision {
+                    spec: option.revision.clone()?,
+                })
+            }
+        }
+    }
+}
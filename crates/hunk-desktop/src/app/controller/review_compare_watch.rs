@@ -0,0 +1,140 @@
+impl DiffViewer {
+    /// Root whose `HEAD`-relative patches match the loaded comparison: a branch compared
+    /// against the checkout of that same branch in the open repository.
+    fn review_compare_working_copy_root(&self) -> Option<PathBuf> {
+        let left =
+            self.review_compare_source_option(self.review_loaded_left_source_id.as_deref()?)?;
+        let right =
+            self.review_compare_source_option(self.review_loaded_right_source_id.as_deref()?)?;
+        if left.kind != crate::app::review_compare_picker::ReviewCompareSourceKind::Branch
+            || right.kind
+                != crate::app::review_compare_picker::ReviewCompareSourceKind::WorkspaceTarget
+            || left.branch_name.is_none()
+            || left.branch_name != right.branch_name
+        {
+            return None;
+        }
+
+        right
+            .workspace_root
+            .clone()
+            .filter(|root| self.repo_root.as_ref() == Some(root))
+    }
+
+    /// Reloads only the selected file's patch when the watcher saw no other changes, keeping
+    /// scroll position and row selection. Returns `false` when a full refresh is needed.
+    pub(super) fn refresh_selected_review_file_in_place(&mut self, cx: &mut Context<Self>) -> bool {
+        if self.review_compare_loading || self.review_compare_error.is_some() {
+            return false;
+        }
+        let selected_path = self.current_review_path();
+        let Some(path) = single_file_watch_update_path(
+            &self.pending_dirty_paths,
+            &self.review_files,
+            selected_path.as_deref(),
+        )
+        .map(ToString::to_string) else {
+            return false;
+        };
+        if self.collapsed_files.contains(path.as_str()) {
+            return false;
+        }
+        let Some(repo_root) = self.review_compare_working_copy_root() else {
+            return false;
+        };
+        let (Some(snapshot), Some(session)) = (
+            self.review_loaded_compare_snapshot.clone(),
+            self.review_workspace_session.as_ref(),
+        ) else {
+            return false;
+        };
+        let Some(file) = snapshot.files.iter().find(|file| file.path == path).cloned() else {
+            return false;
+        };
+
+        let stream = session.render_stream();
+        let changed_lines_only = self.review_loaded_changed_lines_only.applies_to(path.as_str());
+        let changed_lines_only_files =
+            self.review_loaded_changed_lines_only.paths_for(&snapshot.files);
+        let hunk_folds = self.review_loaded_hunk_folds.clone();
+        self.pending_dirty_paths.clear();
+        let epoch = self.next_patch_epoch();
+
+        self.patch_task = cx.spawn(async move |this, cx| {
+            let started_at = Instant::now();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut patches =
+                        load_patches_for_files(repo_root.as_path(), std::slice::from_ref(&file))?;
+                    let patch = patches
+                        .remove(file.path.as_str())
+                        .filter(|patch| !patch.is_empty())
+                        .ok_or_else(|| anyhow::anyhow!("{} no longer has changes", file.path))?;
+                    // Working-copy patches leave binaries opaque; the compare loader renders
+                    // small ones as hex dumps.
+                    if is_binary_patch(patch.as_str()) {
+                        return Err(anyhow::anyhow!("{} is a binary file", file.path));
+                    }
+                    let mut stream = stream;
+                    if !replace_file_rows_in_diff_stream(
+                        &mut stream,
+                        &file,
+                        patch.as_str(),
+                        changed_lines_only,
+                        &hunk_folds,
+                    ) {
+                        return Err(anyhow::anyhow!("{} is not in the review stream", file.path));
+                    }
+
+                    let line_stats = line_stats_from_patch(patch.as_str());
+                    let mut snapshot = CompareSnapshot::clone(&snapshot);
+                    snapshot.file_line_stats.insert(file.path.clone(), line_stats);
+                    snapshot.overall_line_stats =
+                        Self::sum_line_stats(snapshot.file_line_stats.values().copied());
+                    snapshot.patches_by_path.insert(file.path.clone(), patch);
+                    Ok::<_, anyhow::Error>((Arc::new(snapshot), stream, line_stats))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if epoch != this.patch_epoch {
+                        return;
+                    }
+
+                    match result {
+                        Ok((snapshot, stream, line_stats)) => {
+                            debug!(
+                                path = path.as_str(),
+                                elapsed_ms = started_at.elapsed().as_millis(),
+                                "review file patched in place"
+                            );
+                            this.file_line_stats.insert(path, line_stats);
+                            this.recompute_overall_line_stats_from_file_stats();
+                            this.sync_git_workspace_with_primary_state();
+                            this.apply_loaded_review_compare_stream(
+                                snapshot,
+                                stream,
+                                &changed_lines_only_files,
+                                cx,
+                            );
+                        }
+                        Err(err) => {
+                            debug!(
+                                path = path.as_str(),
+                                "in-place review file update fell back to a full refresh: {err:#}"
+                            );
+                            this.queue_dirty_paths([path]);
+                            this.request_snapshot_refresh_internal(
+                                SnapshotRefreshRequest::background_refresh_working_copy(),
+                                cx,
+                            );
+                        }
+                    }
+                });
+            }
+        });
+        true
+    }
+}
@@ -0,0 +1,351 @@
+/// Syntax colors used on the printed page. Paper is white, so these always come from the light
+/// editor palette regardless of the app theme.
+#[derive(Debug, Clone, Copy)]
+struct ReviewExportSyntaxPalette {
+    keyword: ReviewExportRgb,
+    string: ReviewExportRgb,
+    number: ReviewExportRgb,
+    comment: ReviewExportRgb,
+    function: ReviewExportRgb,
+    type_name: ReviewExportRgb,
+    constant: ReviewExportRgb,
+    variable: ReviewExportRgb,
+    operator: ReviewExportRgb,
+}
+
+impl ReviewExportSyntaxPalette {
+    fn light(theme: &gpui_component::Theme) -> Self {
+        let colors = crate::app::theme::hunk_editor_syntax_colors(theme, false);
+        Self {
+            keyword: review_export_rgb(colors.keyword),
+            string: review_export_rgb(colors.string),
+            number: review_export_rgb(colors.number),
+            comment: review_export_rgb(colors.comment),
+            function: review_export_rgb(colors.function),
+            type_name: review_export_rgb(colors.type_name),
+            constant: review_export_rgb(colors.constant),
+            variable: review_export_rgb(colors.variable),
+            operator: review_export_rgb(colors.operator),
+        }
+    }
+
+    fn color(&self, token: SyntaxTokenKind) -> Option<ReviewExportRgb> {
+        match token {
+            SyntaxTokenKind::Plain => None,
+            SyntaxTokenKind::Keyword => Some(self.keyword),
+            SyntaxTokenKind::String => Some(self.string),
+            SyntaxTokenKind::Number => Some(self.number),
+            SyntaxTokenKind::Comment => Some(self.comment),
+            SyntaxTokenKind::Function => Some(self.function),
+            SyntaxTokenKind::TypeName => Some(self.type_name),
+            SyntaxTokenKind::Constant => Some(self.constant),
+            SyntaxTokenKind::Variable => Some(self.variable),
+            SyntaxTokenKind::Operator => Some(self.operator),
+        }
+    }
+}
+
+fn review_export_rgb(color: gpui::Hsla) -> ReviewExportRgb {
+    let rgba = gpui::Rgba::from(color);
+    [rgba.r, rgba.g, rgba.b].map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// One file of the loaded review, copied off the viewer so highlighting and PDF writing can run
+/// on the background executor.
+struct ReviewExportFileSource {
+    path: String,
+    status: FileStatus,
+    line_stats: LineStats,
+    rows: Vec<(DiffStreamRowKind, SideBySideRow)>,
+    comments_by_row: BTreeMap<usize, Vec<CommentRecord>>,
+    unanchored_comments: Vec<CommentRecord>,
+}
+
+impl DiffViewer {
+    pub(super) fn export_review_pdf_action(
+        &mut self,
+        _: &ExportReviewPdf,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.export_review_pdf(ReviewExportColorMode::Color, cx);
+    }
+
+    pub(super) fn export_review_pdf_grayscale_action(
+        &mut self,
+        _: &ExportReviewPdfGrayscale,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.export_review_pdf(ReviewExportColorMode::Grayscale, cx);
+    }
+
+    /// Asks where to save, then writes the loaded review (file list, diffs and comments) as a PDF.
+    pub(super) fn export_review_pdf(
+        &mut self,
+        mode: ReviewExportColorMode,
+        cx: &mut Context<Self>,
+    ) {
+        let sources = self.review_export_file_sources();
+        if sources.is_empty() {
+            self.git_status_message = Some("No review changes to export.".to_string());
+            cx.notify();
+            return;
+        }
+
+        let repo_root = self.selected_git_workspace_root();
+        let project_name = repo_root
+            .as_deref()
+            .and_then(|root| root.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "review".to_string());
+        let title = format!("Hunk review: {project_name}");
+        let subtitle = format!(
+            "{} -> {}",
+            self.review_compare_source_label(self.review_loaded_left_source_id.as_deref()),
+            self.review_compare_source_label(self.review_loaded_right_source_id.as_deref()),
+        );
+        let palette = ReviewExportSyntaxPalette::light(cx.theme());
+        let directory = repo_root
+            .clone()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let suggested_name = format!("{project_name}-review.pdf");
+        let prompt = cx.prompt_for_new_path(directory.as_path(), Some(suggested_name.as_str()));
+
+        self.review_export_task = cx.spawn(async move |this, cx| {
+            let target_path = match prompt.await {
+                Ok(Ok(Some(path))) => path,
+                Ok(Ok(None)) => return,
+                Ok(Err(err)) => {
+                    if let Some(this) = this.upgrade() {
+                        this.update(cx, |_, cx| {
+                            Self::push_error_notification(
+                                format!("Failed to open save dialog: {err:#}"),
+                                cx,
+                            );
+                        });
+                    }
+                    return;
+                }
+                Err(err) => {
+                    error!("review export prompt channel closed: {err}");
+                    return;
+                }
+            };
+
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let target_path = target_path.clone();
+                    async move {
+                        let document =
+                            build_review_export_document(title, subtitle, sources, palette);
+                        let pdf = render_review_pdf(&document, mode);
+                        std::fs::write(target_path.as_path(), pdf)
+                            .with_context(|| format!("failed to write {}", target_path.display()))
+                    }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |_, cx| match result {
+                    Ok(()) => Self::push_success_notification(
+                        format!("Exported review to {}", target_path.display()),
+                        cx,
+                    ),
+                    Err(err) => {
+                        error!("review export failed: {err:#}");
+                        Self::push_error_notification(
+                            format!("Failed to export review: {err:#}"),
+                            cx,
+                        );
+                    }
+                });
+            }
+        });
+    }
+
+    fn review_export_file_sources(&self) -> Vec<ReviewExportFileSource> {
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return Vec::new();
+        };
+
+        session
+            .file_ranges()
+            .iter()
+            .map(|range| {
+                let rows = (range.start_row..range.end_row)
+                    .filter_map(|row_ix| {
+                        let kind = session.row_metadata(row_ix)?.kind;
+                        Some((kind, session.row(row_ix)?.clone()))
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut comments_by_row = BTreeMap::<usize, Vec<CommentRecord>>::new();
+                let mut unanchored_comments = Vec::new();
+                for comment in self
+                    .comments_cache
+                    .iter()
+                    .filter(|comment| comment.file_path == range.path)
+                {
+                    match self.comment_row_matches.get(comment.id.as_str()) {
+                        Some(row_ix) if (range.start_row..range.end_row).contains(row_ix) => {
+                            comments_by_row
+                                .entry(*row_ix - range.start_row)
+                                .or_default()
+                                .push(comment.clone());
+                        }
+                        _ => unanchored_comments.push(comment.clone()),
+                    }
+                }
+
+                ReviewExportFileSource {
+                    path: range.path.clone(),
+                    status: range.status,
+                    line_stats: self
+                        .review_file_line_stats
+                        .get(range.path.as_str())
+                        .copied()
+                        .unwrap_or_default(),
+                    rows,
+                    comments_by_row,
+                    unanchored_comments,
+                }
+            })
+            .collect()
+    }
+}
+
+fn build_review_export_document(
+    title: String,
+    subtitle: String,
+    sources: Vec<ReviewExportFileSource>,
+    palette: ReviewExportSyntaxPalette,
+) -> ReviewExportDocument {
+    let files = sources
+        .into_iter()
+        .map(|source| {
+            let mut lines = Vec::new();
+            for (ix, (kind, row)) in source.rows.iter().enumerate() {
+                match kind {
+                    DiffStreamRowKind::CoreCode => {
+                        lines.extend(review_export_code_lines(
+                            source.path.as_str(),
+                            row,
+                            &palette,
+                        ));
+                    }
+                    DiffStreamRowKind::CoreHunkHeader => lines.push(ReviewExportLine::plain(
+                        ReviewExportLineKind::HunkHeader,
+                        row.text.as_str(),
+                    )),
+                    DiffStreamRowKind::CoreMeta
+                    | DiffStreamRowKind::FileCollapsed
+                    | DiffStreamRowKind::FileError
+                    | DiffStreamRowKind::FileWarning
+                        if !row.text.is_empty() =>
+                    {
+                        lines.push(ReviewExportLine::plain(
+                            ReviewExportLineKind::Context,
+                            row.text.as_str(),
+                        ));
+                    }
+                    _ => {}
+                }
+                if let Some(comments) = source.comments_by_row.get(&ix) {
+                    lines.extend(comments.iter().flat_map(review_export_comment_lines));
+                }
+            }
+            lines.extend(
+                source
+                    .unanchored_comments
+                    .iter()
+                    .flat_map(review_export_comment_lines),
+            );
+
+            ReviewExportFile {
+                path: source.path,
+                status: source.status.tag().to_string(),
+                added: source.line_stats.added,
+                removed: source.line_stats.removed,
+                lines,
+            }
+        })
+        .collect();
+
+    ReviewExportDocument {
+        title,
+        subtitle,
+        files,
+    }
+}
+
+/// Flattens a side-by-side code row into unified lines: the removed side first, then the added
+/// side, or a single context line when both sides match.
+fn review_export_code_lines(
+    path: &str,
+    row: &SideBySideRow,
+    palette: &ReviewExportSyntaxPalette,
+) -> Vec<ReviewExportLine> {
+    let highlighted = |kind, old_line, new_line, text: &str| ReviewExportLine {
+        kind,
+        old_line,
+        new_line,
+        spans: build_syntax_only_line_segments(Some(path), text)
+            .into_iter()
+            .map(|segment| ReviewExportSpan {
+                text: segment.text,
+                color: palette.color(segment.syntax),
+            })
+            .collect(),
+    };
+
+    if row.left.kind == DiffCellKind::Context || row.right.kind == DiffCellKind::Context {
+        return vec![highlighted(
+            ReviewExportLineKind::Context,
+            row.left.line,
+            row.right.line,
+            row.right.text.as_str(),
+        )];
+    }
+
+    let mut lines = Vec::with_capacity(2);
+    if row.left.kind == DiffCellKind::Removed {
+        lines.push(highlighted(
+            ReviewExportLineKind::Removed,
+            row.left.line,
+            None,
+            row.left.text.as_str(),
+        ));
+    }
+    if row.right.kind == DiffCellKind::Added {
+        lines.push(highlighted(
+            ReviewExportLineKind::Added,
+            None,
+            row.right.line,
+            row.right.text.as_str(),
+        ));
+    }
+    lines
+}
+
+fn review_export_comment_lines(comment: &CommentRecord) -> Vec<ReviewExportLine> {
+    let status = DiffViewer::comment_status_label(comment.status);
+    comment
+        .comment_text
+        .lines()
+        .enumerate()
+        .map(|(ix, line)| {
+            let text = if ix == 0 {
+                match comment.author_label() {
+                    Some(author) => format!("[{status}] {author}: {line}"),
+                    None => format!("[{status}] {line}"),
+                }
+            } else {
+                line.to_string()
+            };
+            ReviewExportLine::plain(ReviewExportLineKind::Comment, text)
+        })
+        .collect()
+}
@@ -0,0 +1,76 @@
+impl DiffViewer {
+    pub(super) fn toggle_review_hunk_collapsed(
+        &mut self,
+        path: String,
+        hunk_header: &str,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(key) = hunk_collapse_key_for_header(hunk_header) else {
+            return;
+        };
+        self.hunk_folds.toggle_collapsed(path.as_str(), key);
+        self.reload_after_hunk_collapse_change(cx);
+    }
+
+    pub(super) fn collapse_all_review_hunks_in_file(
+        &mut self,
+        path: String,
+        cx: &mut Context<Self>,
+    ) {
+        let keys = self.review_hunk_collapse_keys(path.as_str());
+        self.hunk_folds.collapse_all(path.as_str(), keys);
+        self.reload_after_hunk_collapse_change(cx);
+    }
+
+    pub(super) fn expand_all_review_hunks_in_file(&mut self, path: String, cx: &mut Context<Self>) {
+        let keys = self.review_hunk_collapse_keys(path.as_str());
+        self.hunk_folds.expand_all(path.as_str(), keys);
+        self.reload_after_hunk_collapse_change(cx);
+    }
+
+    pub(super) fn expand_large_review_hunk(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return;
+        };
+        let (Some(path), Some(key)) = (
+            session.path_at_surface_row(row_ix).map(str::to_string),
+            session
+                .hunk_header_at_surface_row(row_ix)
+                .and_then(hunk_collapse_key_for_header),
+        ) else {
+            return;
+        };
+        self.hunk_folds.expand_large_hunk(path.as_str(), key);
+        self.reload_after_hunk_collapse_change(cx);
+    }
+
+    fn review_hunk_collapse_keys(&self, path: &str) -> Vec<u32> {
+        self.loaded_review_hunks(path)
+            .iter()
+            .map(hunk_collapse_key)
+            .collect()
+    }
+
+    fn loaded_review_hunks(&self, path: &str) -> Vec<DiffHunk> {
+        self.review_loaded_compare_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.patches_by_path.get(path))
+            .map(|patch| parse_patch_document(patch).hunks)
+            .unwrap_or_default()
+    }
+
+    /// Unlike the row filters, folding keeps the viewport on the row the reader was looking at.
+    fn reload_after_hunk_collapse_change(&mut self, cx: &mut Context<Self>) {
+        self.last_scroll_activity_at = Instant::now();
+        self.request_selected_diff_reload(cx);
+        cx.notify();
+    }
+
+    /// Forgets the row filters the loaded stream was built with, along with the patches they
+    /// would otherwise refilter.
+    fn clear_loaded_review_row_filters(&mut self) {
+        self.review_loaded_changed_lines_only = ChangedLinesOnlyMode::default();
+        self.review_loaded_hunk_folds = HunkFolds::default();
+        self.review_loaded_compare_snapshot = None;
+    }
+}
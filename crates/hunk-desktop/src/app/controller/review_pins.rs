@@ -0,0 +1,242 @@
+/// Whether `row` is the line `pin` was placed on. Pins follow the new side when they have one,
+/// so edits above the pinned line on the old side don't lose it.
+fn review_pin_matches_row(pin: &ReviewPinState, row: &SideBySideRow) -> bool {
+    if row.kind != DiffRowKind::Code {
+        return false;
+    }
+    match pin.new_line {
+        Some(line) => row.right.line == Some(line),
+        None => pin.old_line.is_some() && row.left.line == pin.old_line,
+    }
+}
+
+/// `path:line` label for a pin in the pinned list.
+fn review_pin_location_label(pin: &ReviewPinState) -> String {
+    match (pin.new_line, pin.old_line) {
+        (Some(line), _) => format!("{}:{line}", pin.file_path),
+        (None, Some(line)) => format!("{}:{line} (old)", pin.file_path),
+        (None, None) => pin.file_path.clone(),
+    }
+}
+
+impl DiffViewer {
+    pub(super) fn review_pins(&self) -> &[ReviewPinState] {
+        self.review_compare_repo_key()
+            .and_then(|repo_key| self.state.review_pins_by_repo.get(repo_key.as_str()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn review_pin_row(&self, pin: &ReviewPinState) -> Option<usize> {
+        let session = self.review_workspace_session.as_ref()?;
+        let range = session.file_range_for_path(pin.file_path.as_str())?;
+        (range.start_row..range.end_row).find(|row_ix| {
+            session
+                .row(*row_ix)
+                .is_some_and(|row| review_pin_matches_row(pin, row))
+        })
+    }
+
+    pub(super) fn review_pinned_rows(&self) -> BTreeSet<usize> {
+        self.review_pins()
+            .iter()
+            .filter_map(|pin| self.review_pin_row(pin))
+            .collect()
+    }
+
+    fn update_review_pins(&mut self, update: impl FnOnce(&mut Vec<ReviewPinState>)) {
+        let Some(repo_key) = self.review_compare_repo_key() else {
+            return;
+        };
+        let pins = self
+            .state
+            .review_pins_by_repo
+            .entry(repo_key.clone())
+            .or_default();
+        update(pins);
+        if pins.is_empty() {
+            self.state.review_pins_by_repo.remove(repo_key.as_str());
+        }
+        self.persist_state();
+        self.invalidate_review_comment_surface_snapshot();
+    }
+
+    pub(super) fn toggle_review_pin_action(
+        &mut self,
+        _: &ToggleReviewPin,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        let Some(row_ix) = self.review_surface.selection_head_row else {
+            self.comment_status_message = Some(tr("review-pin-select-line"));
+            cx.notify();
+            return;
+        };
+        self.toggle_review_pin_at_row(row_ix, cx);
+    }
+
+    /// Pins the first changed line of `path`, or unpins it when it already is.
+    pub(super) fn toggle_review_file_pin(&mut self, path: &str, cx: &mut Context<Self>) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        let Some(session) = self.review_workspace_session.as_ref() else {
+            return;
+        };
+        let Some(range) = session.file_range_for_path(path) else {
+            return;
+        };
+        let code_rows = (range.start_row..range.end_row).filter(|row_ix| {
+            session
+                .row(*row_ix)
+                .is_some_and(|row| row.kind == DiffRowKind::Code)
+        });
+        let changed_row = code_rows.clone().find(|row_ix| {
+            session.row(*row_ix).is_some_and(|row| {
+                row.left.kind == DiffCellKind::Removed || row.right.kind == DiffCellKind::Added
+            })
+        });
+        let Some(row_ix) = changed_row.or_else(|| code_rows.min()) else {
+            return;
+        };
+        self.toggle_review_pin_at_row(row_ix, cx);
+    }
+
+    fn toggle_review_pin_at_row(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        if self
+            .active_diff_row(row_ix)
+            .is_none_or(|row| row.kind != DiffRowKind::Code)
+        {
+            self.comment_status_message = Some(tr("review-pin-code-lines-only"));
+            cx.notify();
+            return;
+        }
+
+        if let Some(pin_ix) = self
+            .review_pins()
+            .iter()
+            .position(|pin| self.review_pin_row(pin) == Some(row_ix))
+        {
+            self.update_review_pins(|pins| {
+                pins.remove(pin_ix);
+            });
+            self.comment_status_message = Some(tr("review-pin-unpinned"));
+            cx.notify();
+            return;
+        }
+
+        let Some(anchor) = self.build_row_comment_anchor(row_ix) else {
+            return;
+        };
+        let pin = ReviewPinState {
+            file_path: anchor.file_path,
+            old_line: anchor.old_line,
+            new_line: anchor.new_line,
+            line_text: anchor.line_text.trim().to_string(),
+        };
+        let label = review_pin_location_label(&pin);
+        self.update_review_pins(|pins| pins.push(pin));
+        self.comment_status_message = Some(tr_args(
+            "review-pin-pinned",
+            &[("location", label.as_str())],
+        ));
+        cx.notify();
+    }
+
+    pub(super) fn next_review_pin_action(
+        &mut self,
+        _: &NextReviewPin,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        self.select_review_pin_relative(1, cx);
+    }
+
+    pub(super) fn previous_review_pin_action(
+        &mut self,
+        _: &PreviousReviewPin,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        self.select_review_pin_relative(-1, cx);
+    }
+
+    /// Moves to the next pinned row in diff order, wrapping around at either end.
+    fn select_review_pin_relative(&mut self, direction: isize, cx: &mut Context<Self>) {
+        let pinned_rows = self.review_pinned_rows();
+        let current_row = self
+            .review_surface
+            .selection_head_row
+            .or_else(|| self.current_review_surface_top_row())
+            .unwrap_or(0);
+        let target = if direction >= 0 {
+            pinned_rows
+                .range(current_row.saturating_add(1)..)
+                .next()
+                .or_else(|| pinned_rows.first())
+        } else {
+            pinned_rows
+                .range(..current_row)
+                .next_back()
+                .or_else(|| pinned_rows.last())
+        };
+        let Some(target_row) = target.copied() else {
+            self.comment_status_message = Some(tr(if self.review_pins().is_empty() {
+                "review-pin-none"
+            } else {
+                "review-pin-none-visible"
+            }));
+            cx.notify();
+            return;
+        };
+        self.select_row_and_scroll(target_row, false, cx);
+    }
+
+    pub(super) fn jump_to_review_pin(&mut self, pin_ix: usize, cx: &mut Context<Self>) {
+        let Some(pin) = self.review_pins().get(pin_ix).cloned() else {
+            return;
+        };
+        if let Some(row_ix) = self.review_pin_row(&pin) {
+            self.select_row_and_scroll(row_ix, false, cx);
+            cx.notify();
+            return;
+        }
+
+        if let Some((status, start_row)) = self
+            .active_diff_file_range_for_path(pin.file_path.as_str())
+            .map(|range| (range.status, range.start_row))
+        {
+            self.set_review_selected_file(Some(pin.file_path), Some(status));
+            self.select_row_and_scroll(start_row, false, cx);
+            self.comment_status_message = Some(tr("review-pin-moved"));
+            cx.notify();
+            return;
+        }
+
+        self.comment_status_message = Some(tr("review-pin-file-missing"));
+        cx.notify();
+    }
+
+    pub(super) fn remove_review_pin(&mut self, pin_ix: usize, cx: &mut Context<Self>) {
+        self.update_review_pins(|pins| {
+            if pin_ix < pins.len() {
+                pins.remove(pin_ix);
+            }
+        });
+        cx.notify();
+    }
+
+    pub(super) fn clear_review_pins(&mut self, cx: &mut Context<Self>) {
+        self.update_review_pins(Vec::clear);
+        cx.notify();
+    }
+}
@@ -0,0 +1,258 @@
+fn compare_snapshot_from_cache(cached: CachedReviewSnapshot) -> CompareSnapshot {
+    let files = cached
+        .files
+        .into_iter()
+        .map(|file| ChangedFile {
+            path: file.path,
+            status: DiffViewer::file_status_from_cache_tag(file.status_tag.as_str()),
+            staged: file.staged,
+            unstaged: file.unstaged,
+            untracked: file.untracked,
+        })
+        .collect();
+    let file_line_stats = cached
+        .file_line_stats
+        .into_iter()
+        .map(|(path, stats)| {
+            (
+                path,
+                LineStats {
+                    added: stats.added,
+                    removed: stats.removed,
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let overall_line_stats = DiffViewer::sum_line_stats(file_line_stats.values().copied());
+    CompareSnapshot {
+        files,
+        file_line_stats,
+        overall_line_stats,
+        patches_by_path: cached.patches_by_path,
+    }
+}
+
+impl DiffViewer {
+    fn load_snapshot_cache_store() -> Option<SnapshotCacheStore> {
+        match SnapshotCacheStore::new() {
+            Ok(store) => Some(store),
+            Err(err) => {
+                error!("failed to resolve snapshot cache directory: {err:#}");
+                None
+            }
+        }
+    }
+
+    fn review_snapshot_cache_key(&self) -> Option<String> {
+        self.project_path
+            .as_deref()
+            .map(hunk_git::worktree::repository_storage_key)
+    }
+
+    /// Paints the comparison cached for the open project while Git computes the live one.
+    fn hydrate_review_snapshot_cache_if_available(&mut self, cx: &mut Context<Self>) {
+        self.review_snapshot_cache_state_id = None;
+        if self.workspace_view_mode != WorkspaceViewMode::Diff
+            || self.review_workspace_session.is_some()
+        {
+            return;
+        }
+        let (Some(store), Some(cache_key)) = (
+            self.snapshot_cache_store.clone(),
+            self.review_snapshot_cache_key(),
+        ) else {
+            return;
+        };
+        let (Some(left_source_id), Some(right_source_id)) = (
+            self.review_left_source_id.clone(),
+            self.review_right_source_id.clone(),
+        ) else {
+            return;
+        };
+        let (Some(repo_root), Some((left_source, _))) = (
+            self.project_path.clone(),
+            self.selected_review_compare_sources(),
+        ) else {
+            return;
+        };
+        let collapsed_files = self.collapsed_files.clone();
+        let changed_lines_only = self.changed_lines_only.clone();
+        let hunk_folds = self.hunk_folds.clone();
+        let epoch = self.next_patch_epoch();
+
+        self.patch_task = cx.spawn(async move |this, cx| {
+            let started_at = Instant::now();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let Some(cached) = store.load(cache_key.as_str())? else {
+                        return Ok(None);
+                    };
+                    if cached.left_source_id != left_source_id
+                        || cached.right_source_id != right_source_id
+                    {
+                        return Ok(None);
+                    }
+                    // The state id can't see the base branch, so check where it points now.
+                    let left_tree_id = compare_source_tree_id(repo_root.as_path(), &left_source)?;
+                    if cached.left_tree_id != left_tree_id {
+                        return Ok(None);
+                    }
+                    let state_id = cached.state_id.clone();
+                    let snapshot = compare_snapshot_from_cache(cached);
+                    let changed_lines_only_files = changed_lines_only.paths_for(&snapshot.files);
+                    let stream = build_diff_stream_from_patch_map(
+                        &snapshot.files,
+                        &collapsed_files,
+                        &changed_lines_only_files,
+                        &hunk_folds,
+                        &snapshot.file_line_stats,
+                        &snapshot.patches_by_path,
+                        &BTreeSet::new(),
+                    );
+                    Ok::<_, anyhow::Error>(Some((
+                        state_id,
+                        Arc::new(snapshot),
+                        stream,
+                        changed_lines_only_files,
+                    )))
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    // A live comparison that landed first always wins over the cache.
+                    if epoch != this.patch_epoch || this.review_workspace_session.is_some() {
+                        return;
+                    }
+                    match result {
+                        Ok(Some((state_id, snapshot, stream, changed_lines_only_files))) => {
+                            debug!(
+                                files = snapshot.files.len(),
+                                elapsed_ms = started_at.elapsed().as_millis(),
+                                "review compare snapshot restored from cache"
+                            );
+                            this.review_snapshot_cache_state_id = Some(state_id);
+                            this.review_extracted_text_paths = snapshot
+                                .patches_by_path
+                                .iter()
+                                .filter(|(_, patch)| is_extracted_text_patch(patch))
+                                .map(|(path, _)| path.clone())
+                                .collect();
+                            this.apply_loaded_review_compare_stream(
+                                snapshot,
+                                stream,
+                                &changed_lines_only_files,
+                                cx,
+                            );
+                        }
+                        Ok(None) => {}
+                        Err(err) => warn!("failed to read review snapshot cache: {err:#}"),
+                    }
+                });
+            }
+        });
+    }
+
+    /// Keeps a comparison restored from the cache once the live snapshot reports the state it
+    /// was cached from, instead of reloading every patch to confirm it. The base branch was
+    /// already checked against the cache when it was restored.
+    fn adopt_review_snapshot_cache_if_current(&mut self) {
+        if self.review_loaded_snapshot_fingerprint.is_some()
+            || self.review_workspace_session.is_none()
+        {
+            return;
+        }
+        let Some(fingerprint) = self.last_snapshot_fingerprint.as_ref() else {
+            return;
+        };
+        if self.review_snapshot_cache_state_id.as_deref() == Some(fingerprint.state_id().as_str())
+        {
+            self.review_loaded_snapshot_fingerprint = Some(fingerprint.clone());
+        }
+    }
+
+    /// Writes the loaded comparison to disk when it was computed from a live snapshot the cache
+    /// doesn't hold yet. Only branch-against-working-copy comparisons are cached, since the
+    /// snapshot state id can't tell whether any other pair is still current.
+    fn persist_review_snapshot_cache(&mut self, cx: &mut Context<Self>) {
+        let Some(state_id) = self
+            .review_loaded_snapshot_fingerprint
+            .as_ref()
+            .map(RepoSnapshotFingerprint::state_id)
+        else {
+            return;
+        };
+        if self.review_snapshot_cache_state_id.as_deref() == Some(state_id.as_str()) {
+            return;
+        }
+        let (Some(store), Some(cache_key), Some(repo_root), Some(snapshot)) = (
+            self.snapshot_cache_store.clone(),
+            self.review_snapshot_cache_key(),
+            self.project_path.clone(),
+            self.review_loaded_compare_snapshot.clone(),
+        ) else {
+            return;
+        };
+        let (Some(left_source_id), Some(right_source_id)) = (
+            self.review_loaded_left_source_id.clone(),
+            self.review_loaded_right_source_id.clone(),
+        ) else {
+            return;
+        };
+        let Some((left_source, right_source)) = self.selected_review_compare_sources() else {
+            return;
+        };
+        if !self.review_compare_follows_working_copy(&left_source, &right_source) {
+            return;
+        }
+
+        self.review_snapshot_cache_state_id = Some(state_id.clone());
+        let cached_unix_time = Self::workflow_cache_unix_time();
+        self.snapshot_cache_task = cx.background_executor().spawn(async move {
+            let left_tree_id = match compare_source_tree_id(repo_root.as_path(), &left_source) {
+                Ok(left_tree_id) => left_tree_id,
+                Err(err) => {
+                    warn!("failed to resolve review snapshot cache base: {err:#}");
+                    return;
+                }
+            };
+            let cached = CachedReviewSnapshot {
+                repo_root,
+                state_id,
+                left_source_id,
+                left_tree_id,
+                right_source_id,
+                files: snapshot
+                    .files
+                    .iter()
+                    .map(|file| CachedChangedFileState {
+                        path: file.path.clone(),
+                        status_tag: file.status.tag().to_string(),
+                        staged: file.staged,
+                        unstaged: file.unstaged,
+                        untracked: file.untracked,
+                    })
+                    .collect(),
+                file_line_stats: snapshot
+                    .file_line_stats
+                    .iter()
+                    .map(|(path, stats)| {
+                        (
+                            path.clone(),
+                            CachedLineStats {
+                                added: stats.added,
+                                removed: stats.removed,
+                            },
+                        )
+                    })
+                    .collect(),
+                patches_by_path: snapshot.patches_by_path.clone(),
+                cached_unix_time,
+            };
+            if let Err(err) = store.save(cache_key.as_str(), &cached) {
+                warn!("failed to write review snapshot cache: {err:#}");
+            }
+        });
+    }
+}
@@ -0,0 +1,76 @@
+impl DiffViewer {
+    /// Runs the review timer while Review view is shown in the focused window and pauses it
+    /// otherwise. Called whenever the view mode, window focus, or timer setting changes.
+    fn sync_review_timer(&mut self, cx: &mut Context<Self>) {
+        if !self.config.review_timer.enabled {
+            self.review_timer = ReviewTimer::default();
+            self.review_timer_task = Task::ready(());
+            return;
+        }
+
+        let running = self.window_active && self.workspace_view_mode == WorkspaceViewMode::Diff;
+        if running == self.review_timer.is_running() {
+            return;
+        }
+        self.review_timer.set_running(running, Instant::now());
+        if running {
+            self.start_review_timer_ticks(cx);
+        } else {
+            self.review_timer_task = Task::ready(());
+        }
+        cx.notify();
+    }
+
+    fn start_review_timer_ticks(&mut self, cx: &mut Context<Self>) {
+        self.review_timer_task = cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(REVIEW_TIMER_TICK_INTERVAL)
+                    .await;
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+                this.update(cx, |this, cx| {
+                    this.tick_review_timer(cx);
+                });
+            }
+        });
+    }
+
+    fn tick_review_timer(&mut self, cx: &mut Context<Self>) {
+        let minutes = self.config.review_timer.break_reminder_minutes;
+        let interval = Duration::from_secs(minutes.saturating_mul(60));
+        if self
+            .review_timer
+            .take_break_reminder(interval, Instant::now())
+        {
+            Self::push_warning_notification(
+                tr_args(
+                    "review-timer-break",
+                    &[("elapsed", review_timer_label(interval).as_str())],
+                ),
+                None,
+                cx,
+            );
+        }
+        cx.notify();
+    }
+
+    pub(super) fn reset_review_timer(&mut self, cx: &mut Context<Self>) {
+        self.review_timer.reset(Instant::now());
+        cx.notify();
+    }
+
+    pub(super) fn review_timer_footer_label(&self) -> Option<String> {
+        if !self.config.review_timer.enabled {
+            return None;
+        }
+        let elapsed = review_timer_label(self.review_timer.elapsed(Instant::now()));
+        let key = if self.review_timer.is_running() {
+            "review-timer-running"
+        } else {
+            "review-timer-paused"
+        };
+        Some(tr_args(key, &[("elapsed", elapsed.as_str())]))
+    }
+}
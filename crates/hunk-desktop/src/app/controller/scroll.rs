@@ -21,6 +21,31 @@ impl DiffViewer {
         self.scroll_to_file_start(&path);
     }
 
+    fn current_review_scroll_anchor(
+        &self,
+    ) -> Option<review_workspace_session::ReviewWorkspaceScrollAnchor> {
+        self.review_workspace_session
+            .as_ref()?
+            .scroll_anchor_at(self.current_review_surface_scroll_top_px())
+    }
+
+    fn restore_review_scroll_anchor(
+        &mut self,
+        anchor: &review_workspace_session::ReviewWorkspaceScrollAnchor,
+    ) {
+        let Some(top_offset_px) = self
+            .review_workspace_session
+            .as_ref()
+            .and_then(|session| session.scroll_top_for_anchor(anchor))
+        else {
+            return;
+        };
+        self.review_surface
+            .diff_scroll_handle
+            .set_offset(point(px(0.), -px(top_offset_px as f32)));
+        self.review_surface.last_diff_scroll_offset = None;
+    }
+
     fn scroll_to_file_start(&mut self, path: &str) {
         if let Some(session) = self.review_workspace_session.as_ref()
             && let Some(top_offset_px) = session
@@ -40,7 +65,9 @@ impl DiffViewer {
         row_ix: usize,
         cx: &mut Context<Self>,
     ) {
-        if self.workspace_view_mode == WorkspaceViewMode::Diff {
+        let follows_scroll = self.workspace_view_mode != WorkspaceViewMode::Diff
+            || self.review_selection_follows_scroll();
+        if follows_scroll && self.workspace_view_mode == WorkspaceViewMode::Diff {
             self.sync_review_workspace_editor_selection_for_row(row_ix);
         }
 
@@ -49,6 +76,9 @@ impl DiffViewer {
         {
             self.request_visible_row_range_segment_prefetch(visible_range, false, cx);
         }
+        if !follows_scroll {
+            return;
+        }
 
         let Some((next_path, next_status)) = (if self.workspace_view_mode == WorkspaceViewMode::Diff {
             self.review_workspace_session
@@ -93,6 +123,7 @@ impl DiffViewer {
         }
 
         if self.workspace_view_mode == WorkspaceViewMode::Diff {
+            self.reveal_path_in_repo_tree(next_path.as_str());
             self.set_review_selected_file(Some(next_path), Some(next_status));
         } else {
             self.selected_path = Some(next_path);
@@ -101,6 +132,31 @@ impl DiffViewer {
         cx.notify();
     }
 
+    /// Whether scrolling should move the review selection. A file picked in the tree keeps the
+    /// selection while any of its rows are visible, so short files near the end of the diff
+    /// don't hand it back to the file above them.
+    fn review_selection_follows_scroll(&mut self) -> bool {
+        if !self.review_tree_follow {
+            return false;
+        }
+        let Some(path) = self.review_tree_clicked_path.as_deref() else {
+            return true;
+        };
+        let clicked_file_visible = self
+            .review_workspace_session
+            .as_ref()
+            .and_then(|session| session.file_range_for_path(path))
+            .zip(self.current_review_visible_row_range())
+            .is_some_and(|(range, visible)| {
+                range.start_row < visible.end && visible.start < range.end_row
+            });
+        if clicked_file_visible {
+            return false;
+        }
+        self.review_tree_clicked_path = None;
+        true
+    }
+
     fn request_review_visible_row_range_segment_prefetch(
         &mut self,
         visible_range: std::ops::Range<usize>,
@@ -284,6 +340,23 @@ impl DiffViewer {
         self.last_scroll_activity_at = Instant::now();
     }
 
+    /// Scales the review surface text for ctrl-scroll and touchpad pinch gestures, which the
+    /// platform delivers as scroll events with the control modifier held.
+    pub(super) fn zoom_diff_text_by_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        cx: &mut Context<Self>,
+    ) {
+        let delta_y = f32::from(event.delta.pixel_delta(px(self.config.fonts.mono_font_size)).y);
+        let zoom = diff_text_zoom_after_scroll(self.review_surface.diff_text_zoom, delta_y);
+        if zoom == self.review_surface.diff_text_zoom {
+            return;
+        }
+        self.review_surface.diff_text_zoom = zoom;
+        self.recompute_diff_layout();
+        cx.notify();
+    }
+
     fn prime_diff_surface_visible_state(
         &mut self,
         force_reprime: bool,
@@ -325,21 +398,23 @@ impl DiffViewer {
     }
 
     fn recompute_diff_layout(&mut self) {
+        let zoom = self.review_surface.diff_text_zoom;
+        let char_width = self.review_surface.diff_mono_char_width;
         if self.uses_review_workspace_sections_surface()
             && let Some(session) = self.review_workspace_session.as_ref()
         {
             let (max_left_line_digits, max_right_line_digits) =
                 session.line_number_digit_widths();
             self.review_surface.diff_left_line_number_width =
-                line_number_column_width(max_left_line_digits);
+                line_number_column_width(max_left_line_digits, char_width) * zoom;
             self.review_surface.diff_right_line_number_width =
-                line_number_column_width(max_right_line_digits);
+                line_number_column_width(max_right_line_digits, char_width) * zoom;
             return;
         }
         self.review_surface.diff_left_line_number_width =
-            line_number_column_width(DIFF_LINE_NUMBER_MIN_DIGITS);
+            line_number_column_width(DIFF_LINE_NUMBER_MIN_DIGITS, char_width) * zoom;
         self.review_surface.diff_right_line_number_width =
-            line_number_column_width(DIFF_LINE_NUMBER_MIN_DIGITS);
+            line_number_column_width(DIFF_LINE_NUMBER_MIN_DIGITS, char_width) * zoom;
     }
 
 }
@@ -48,6 +48,30 @@ impl DiffViewer {
         cx.notify();
     }
 
+    /// Lays `path` out right to left in the diff, or back to left to right. Only painting
+    /// changes, so the diff is not reloaded.
+    pub(super) fn toggle_file_right_to_left(&mut self, path: String, cx: &mut Context<Self>) {
+        if !self.right_to_left_files.remove(path.as_str()) {
+            self.right_to_left_files.insert(path);
+        }
+        cx.notify();
+    }
+
+    /// Switches the diff between side by side and one unified column. The session keeps its
+    /// rows and only relays them out, so the row at the top of the viewport stays in place.
+    pub(super) fn toggle_diff_unified_view(&mut self, cx: &mut Context<Self>) {
+        self.diff_unified_view = !self.diff_unified_view;
+        let scroll_anchor = self.current_review_scroll_anchor();
+        if let Some(session) = self.review_workspace_session.as_mut() {
+            session.set_unified_rows(self.diff_unified_view);
+        }
+        self.review_surface.clear_workspace_surface_snapshot();
+        if let Some(anchor) = scroll_anchor.as_ref() {
+            self.restore_review_scroll_anchor(anchor);
+        }
+        cx.notify();
+    }
+
     fn clamp_selection_to_rows(&mut self) {
         let row_count = self.active_diff_row_count();
         if row_count == 0 {
@@ -81,8 +105,61 @@ impl DiffViewer {
         cx: &mut Context<Self>,
     ) {
         self.focus_handle.focus(window, cx);
+        if event.button == MouseButton::Middle
+            && self.config.gestures.file_middle_click != FileMiddleClickAction::Nothing
+            && let Some(row) = self.active_diff_row_metadata(row_ix)
+            && row.kind == DiffStreamRowKind::FileHeader
+            && let Some(path) = row.file_path.clone()
+        {
+            self.on_file_middle_click(path, cx);
+            return;
+        }
         self.drag_selecting_rows = true;
         self.select_row(row_ix, event.modifiers.shift, cx);
+        if event.button == MouseButton::Left && event.click_count == 2 {
+            self.on_diff_row_double_click(row_ix, window, cx);
+        }
+    }
+
+    fn on_diff_row_double_click(
+        &mut self,
+        row_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match self.config.gestures.diff_row_double_click {
+            DiffRowDoubleClickAction::SelectLine => {}
+            DiffRowDoubleClickAction::AddComment => {
+                self.drag_selecting_rows = false;
+                self.open_comment_editor_for_row(row_ix, window, cx);
+            }
+            DiffRowDoubleClickAction::OpenInEditor => {
+                let Some(line) = self.active_diff_row(row_ix).and_then(|row| {
+                    (row.kind == DiffRowKind::Code)
+                        .then(|| row.right.line.or(row.left.line))
+                        .flatten()
+                }) else {
+                    return;
+                };
+                let Some((path, status)) = self.selected_file_from_row_metadata(row_ix) else {
+                    return;
+                };
+                self.drag_selecting_rows = false;
+                self.open_file_in_files_workspace_at_line(path, status, line as usize, window, cx);
+            }
+        }
+    }
+
+    /// Runs the configured middle-click gesture on a file, from its header or the file tree.
+    pub(super) fn on_file_middle_click(&mut self, path: String, cx: &mut Context<Self>) {
+        if self.workspace_view_mode != WorkspaceViewMode::Diff {
+            return;
+        }
+        match self.config.gestures.file_middle_click {
+            FileMiddleClickAction::Nothing => {}
+            FileMiddleClickAction::Collapse => self.toggle_file_collapsed(path, cx),
+            FileMiddleClickAction::Pin => self.toggle_review_file_pin(path.as_str(), cx),
+        }
     }
 
     pub(super) fn on_diff_row_mouse_move(
@@ -153,17 +230,52 @@ impl DiffViewer {
         if !self.is_row_selected(row_ix) {
             self.select_row(row_ix, false, cx);
         }
+        let file_path = self
+            .review_workspace_session
+            .as_ref()
+            .and_then(|session| session.path_at_surface_row(row_ix))
+            .map(str::to_string);
+        let changed_lines_only = file_path
+            .as_deref()
+            .is_some_and(|path| self.changed_lines_only.applies_to(path));
+        let right_to_left = file_path
+            .as_deref()
+            .is_some_and(|path| self.right_to_left_files.contains(path));
+        let hunk = self.review_hunk_at_row(row_ix);
+        let hunk_stage_state = match (file_path.as_deref(), hunk.as_ref()) {
+            (Some(path), Some(hunk)) => self.review_hunk_stage_state(path, hunk.header.as_str()),
+            _ => None,
+        };
+        let can_change_index = hunk.is_some() && self.review_compare_working_copy_root().is_some();
+        let can_stage_hunk = can_change_index && hunk_stage_state != Some(HunkStageState::Staged);
+        let can_unstage_hunk = can_change_index && hunk_stage_state == Some(HunkStageState::Staged);
         self.open_workspace_text_context_menu(
             WorkspaceTextContextMenuTarget::DiffRows(DiffRowsContextMenuTarget {
                 can_copy: self.selected_row_range().is_some(),
                 can_select_all: self.active_diff_row_count() > 0,
+                file_path,
+                hunk,
+                can_stage_hunk,
+                can_unstage_hunk,
+                changed_lines_only,
+                right_to_left,
             }),
             position,
             cx,
         );
     }
 
-    fn select_row_and_scroll(
+    /// The hunk under a review row, identified by the lines it removes and adds.
+    fn review_hunk_at_row(&self, row_ix: usize) -> Option<HunkSelector> {
+        let session = self.review_workspace_session.as_ref()?;
+        let range = session
+            .hunk_ranges()
+            .iter()
+            .find(|range| range.start_row <= row_ix && row_ix < range.end_row)?;
+        Some(review_hunk_selector(session, range))
+    }
+
+    pub(super) fn select_row_and_scroll(
         &mut self,
         row_ix: usize,
         extend_selection: bool,
@@ -44,13 +44,19 @@ fn validate_keyboard_shortcuts(shortcuts: &KeyboardShortcuts) -> Result<(), Stri
     validate_shortcut_list("Next File", &shortcuts.next_file)?;
     validate_shortcut_list("Previous File", &shortcuts.previous_file)?;
     validate_shortcut_list("View Review File", &shortcuts.view_current_review_file)?;
+    validate_shortcut_list("Pin Row", &shortcuts.toggle_review_pin)?;
+    validate_shortcut_list("Next Pin", &shortcuts.next_review_pin)?;
+    validate_shortcut_list("Previous Pin", &shortcuts.previous_review_pin)?;
     validate_shortcut_list("Toggle File Tree", &shortcuts.toggle_sidebar_tree)?;
+    validate_shortcut_list(TOGGLE_FOCUS_MODE_LABEL, &shortcuts.toggle_focus_mode)?;
     validate_shortcut_list("Switch to Files View", &shortcuts.switch_to_files_view)?;
     validate_shortcut_list("Switch to Review View", &shortcuts.switch_to_review_view)?;
     validate_shortcut_list("Switch to Git View", &shortcuts.switch_to_git_view)?;
     validate_shortcut_list("Switch to AI View", &shortcuts.switch_to_ai_view)?;
     validate_shortcut_list("Toggle Terminal", &shortcuts.toggle_ai_terminal_drawer)?;
     validate_shortcut_list("Open Project", &shortcuts.open_project)?;
+    validate_shortcut_list(OPEN_PATH_FROM_CLIPBOARD_LABEL, &shortcuts.open_path_from_clipboard)?;
+    validate_shortcut_list("Search in Repository", &shortcuts.search_repository)?;
     validate_shortcut_list("Save Current File", &shortcuts.save_current_file)?;
     validate_shortcut_list("Next Editor Tab", &shortcuts.next_editor_tab)?;
     validate_shortcut_list("Previous Editor Tab", &shortcuts.previous_editor_tab)?;
@@ -161,12 +167,36 @@ impl DiffViewer {
                 window,
                 cx,
             ),
+            toggle_review_pin: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.toggle_review_pin,
+                "Comma-separated shortcuts, e.g. cmd-f2, ctrl-f2",
+                window,
+                cx,
+            ),
+            next_review_pin: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.next_review_pin,
+                "Comma-separated shortcuts, e.g. f2",
+                window,
+                cx,
+            ),
+            previous_review_pin: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.previous_review_pin,
+                "Comma-separated shortcuts, e.g. shift-f2",
+                window,
+                cx,
+            ),
             toggle_sidebar_tree: settings_shortcut_input(
                 &self.config.keyboard_shortcuts.toggle_sidebar_tree,
                 "Comma-separated shortcuts, e.g. cmd-b, ctrl-b",
                 window,
                 cx,
             ),
+            toggle_focus_mode: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.toggle_focus_mode,
+                "Comma-separated shortcuts, e.g. cmd-shift-enter, ctrl-shift-enter",
+                window,
+                cx,
+            ),
             switch_to_files_view: settings_shortcut_input(
                 &self.config.keyboard_shortcuts.switch_to_files_view,
                 "Comma-separated shortcuts, e.g. cmd-1, ctrl-1",
@@ -197,6 +227,18 @@ impl DiffViewer {
                 window,
                 cx,
             ),
+            open_path_from_clipboard: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.open_path_from_clipboard,
+                "Comma-separated shortcuts, e.g. cmd-alt-v, ctrl-alt-v",
+                window,
+                cx,
+            ),
+            search_repository: settings_shortcut_input(
+                &self.config.keyboard_shortcuts.search_repository,
+                "Comma-separated shortcuts, e.g. cmd-shift-f, ctrl-shift-f",
+                window,
+                cx,
+            ),
             save_current_file: settings_shortcut_input(
                 &self.config.keyboard_shortcuts.save_current_file,
                 "Comma-separated shortcuts, e.g. cmd-s, ctrl-s",
@@ -272,10 +314,44 @@ impl DiffViewer {
         self.settings_draft = Some(SettingsDraft {
             category: SettingsCategory::Ui,
             theme: self.config.theme,
+            language: self.config.language,
             reduce_motion: self.config.reduce_motion,
             show_fps_counter: self.config.show_fps_counter,
+            offer_clipboard_paths: self.config.offer_clipboard_paths,
+            fonts: settings_fonts_state(&self.config.fonts, window, cx),
             terminal,
+            git: settings_git_state(
+                self.config.git_timeouts,
+                self.selected_git_workspace_root(),
+                window,
+                cx,
+            ),
+            desktop_notifications: self.config.desktop_notifications,
+            review_timer: self.config.review_timer,
+            gestures: self.config.gestures,
+            formatting: settings_formatting_state(&self.config.formatting, window, cx),
             shortcuts,
+            vim_mode: self.config.vim_mode,
+            usage_metrics: None,
+            usage_metrics_loading: false,
+            maintenance: SettingsMaintenanceState {
+                comment_retention_days: settings_terminal_input(
+                    self.config.comment_retention_days.to_string().as_str(),
+                    "14",
+                    window,
+                    cx,
+                ),
+                stale_comment_retention_days: settings_terminal_input(
+                    self.config
+                        .stale_comment_retention_days
+                        .to_string()
+                        .as_str(),
+                    "30",
+                    window,
+                    cx,
+                ),
+                running: None,
+            },
             error_message: None,
         });
         cx.notify();
@@ -311,6 +387,9 @@ impl DiffViewer {
         }
         settings.category = category;
         settings.error_message = None;
+        if category == SettingsCategory::UsageMetrics {
+            self.load_settings_usage_metrics(cx);
+        }
         cx.notify();
     }
 
@@ -330,6 +409,18 @@ impl DiffViewer {
         cx.notify();
     }
 
+    pub(super) fn set_settings_language(&mut self, language: Language, cx: &mut Context<Self>) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.language == language {
+            return;
+        }
+        settings.language = language;
+        settings.error_message = None;
+        cx.notify();
+    }
+
     pub(super) fn set_settings_reduce_motion(
         &mut self,
         reduce_motion: bool,
@@ -362,6 +453,34 @@ impl DiffViewer {
         cx.notify();
     }
 
+    pub(super) fn set_settings_offer_clipboard_paths(
+        &mut self,
+        offer_clipboard_paths: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.offer_clipboard_paths == offer_clipboard_paths {
+            return;
+        }
+        settings.offer_clipboard_paths = offer_clipboard_paths;
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_vim_mode(&mut self, vim_mode: bool, cx: &mut Context<Self>) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.vim_mode == vim_mode {
+            return;
+        }
+        settings.vim_mode = vim_mode;
+        settings.error_message = None;
+        cx.notify();
+    }
+
     pub(super) fn set_settings_terminal_shell_choice(
         &mut self,
         shell_choice: SettingsTerminalShellChoice,
@@ -411,7 +530,25 @@ impl DiffViewer {
     }
 
     pub(super) fn save_settings(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        let (theme, reduce_motion, show_fps_counter, terminal, keyboard_shortcuts) = {
+        let (
+            theme,
+            language,
+            reduce_motion,
+            show_fps_counter,
+            offer_clipboard_paths,
+            fonts,
+            terminal,
+            git_settings,
+            git_timeouts,
+            snapshot_exclusions,
+            desktop_notifications,
+            review_timer,
+            gestures,
+            formatting,
+            keyboard_shortcuts,
+            vim_mode,
+            (comment_retention_days, stale_comment_retention_days),
+        ) = {
             let Some(settings) = self.settings_draft.as_mut() else {
                 return;
             };
@@ -443,10 +580,17 @@ impl DiffViewer {
                     &settings.shortcuts.view_current_review_file,
                     cx,
                 ),
+                toggle_review_pin: read_shortcut_input(&settings.shortcuts.toggle_review_pin, cx),
+                next_review_pin: read_shortcut_input(&settings.shortcuts.next_review_pin, cx),
+                previous_review_pin: read_shortcut_input(
+                    &settings.shortcuts.previous_review_pin,
+                    cx,
+                ),
                 toggle_sidebar_tree: read_shortcut_input(
                     &settings.shortcuts.toggle_sidebar_tree,
                     cx,
                 ),
+                toggle_focus_mode: read_shortcut_input(&settings.shortcuts.toggle_focus_mode, cx),
                 switch_to_files_view: read_shortcut_input(
                     &settings.shortcuts.switch_to_files_view,
                     cx,
@@ -465,6 +609,11 @@ impl DiffViewer {
                     cx,
                 ),
                 open_project: read_shortcut_input(&settings.shortcuts.open_project, cx),
+                open_path_from_clipboard: read_shortcut_input(
+                    &settings.shortcuts.open_path_from_clipboard,
+                    cx,
+                ),
+                search_repository: read_shortcut_input(&settings.shortcuts.search_repository, cx),
                 save_current_file: read_shortcut_input(
                     &settings.shortcuts.save_current_file,
                     cx,
@@ -497,6 +646,15 @@ impl DiffViewer {
                 return;
             }
 
+            let fonts = match settings_font_config(&settings.fonts, cx) {
+                Ok(fonts) => fonts,
+                Err(err) => {
+                    settings.error_message = Some(err);
+                    cx.notify();
+                    return;
+                }
+            };
+
             let terminal = match settings_terminal_config(&settings.terminal, cx) {
                 Ok(terminal) => terminal,
                 Err(err) => {
@@ -506,36 +664,119 @@ impl DiffViewer {
                 }
             };
 
+            let git_settings = settings_git_user_settings(&settings.git, cx);
+            if let Err(err) = hunk_git::user_config::validate_user_settings(&git_settings) {
+                settings.error_message = Some(format!("Git: {err}"));
+                cx.notify();
+                return;
+            }
+            let git_timeouts = match settings_git_timeouts(&settings.git, cx) {
+                Ok(git_timeouts) => git_timeouts,
+                Err(err) => {
+                    settings.error_message = Some(err);
+                    cx.notify();
+                    return;
+                }
+            };
+            let snapshot_exclusions = match settings_git_snapshot_exclusions(&settings.git, cx) {
+                Ok(snapshot_exclusions) => snapshot_exclusions,
+                Err(err) => {
+                    settings.error_message = Some(err);
+                    cx.notify();
+                    return;
+                }
+            };
+
+            let comment_retention =
+                match settings_comment_retention_days(&settings.maintenance, cx) {
+                    Ok(days) => days,
+                    Err(err) => {
+                        settings.error_message = Some(err);
+                        cx.notify();
+                        return;
+                    }
+                };
+
             settings.error_message = None;
             (
                 settings.theme,
+                settings.language,
                 settings.reduce_motion,
                 settings.show_fps_counter,
+                settings.offer_clipboard_paths,
+                fonts,
                 terminal,
+                git_settings,
+                git_timeouts,
+                snapshot_exclusions,
+                settings.desktop_notifications,
+                settings.review_timer,
+                settings.gestures,
+                settings_formatting_config(&settings.formatting, cx),
                 keyboard_shortcuts,
+                settings.vim_mode,
+                comment_retention,
             )
         };
 
+        let git_config_saving = self.save_settings_git(git_settings, cx);
+
+        let snapshot_exclusions_saved =
+            match self.save_settings_snapshot_exclusions(snapshot_exclusions) {
+                Ok(saved) => saved,
+                Err(err) => {
+                    if let Some(settings) = self.settings_draft.as_mut() {
+                        settings.error_message = Some(err.clone());
+                    }
+                    Self::push_error_notification(err, cx);
+                    cx.notify();
+                    return;
+                }
+            };
+
         let keyboard_shortcuts_changed = self.config.keyboard_shortcuts != keyboard_shortcuts;
         let terminal_changed = self.config.terminal != terminal;
         let terminal_requires_restart = self.config.terminal.hydrate_app_environment_on_launch
             != terminal.hydrate_app_environment_on_launch;
 
         self.config.theme = theme;
+        self.config.language = language;
         self.config.reduce_motion = reduce_motion;
         self.config.show_fps_counter = show_fps_counter;
+        self.config.offer_clipboard_paths = offer_clipboard_paths;
+        self.config.fonts = fonts;
         self.config.terminal = terminal;
+        self.config.git_timeouts = git_timeouts;
+        self.config.desktop_notifications = desktop_notifications;
+        self.config.review_timer = review_timer;
+        self.config.gestures = gestures;
+        self.config.formatting = formatting;
         self.config.keyboard_shortcuts = keyboard_shortcuts;
-        self.apply_theme_preference(window, cx);
+        self.config.vim_mode = vim_mode;
+        self.config.comment_retention_days = comment_retention_days;
+        self.config.stale_comment_retention_days = stale_comment_retention_days;
+        if !vim_mode {
+            self.vim_mode = VimMode::Normal;
+        }
+        crate::app::l10n::set_language(language);
+        self.apply_font_preferences(window, cx);
         self.restart_auto_refresh(cx);
+        self.sync_review_timer(cx);
         self.persist_config();
+        if snapshot_exclusions_saved {
+            self.request_git_workspace_refresh(false, cx);
+        }
 
         let saved_path = self
             .config_store
             .as_ref()
             .map(|store| store.path().display().to_string())
             .unwrap_or_else(|| "~/.hunkdiff/config.toml".to_string());
-        let save_message = format!("Saved settings to {}.", saved_path);
+        let save_message = if git_config_saving {
+            format!("Saved settings to {} and updating global Git config.", saved_path)
+        } else {
+            format!("Saved settings to {}.", saved_path)
+        };
         let follow_up =
             match (keyboard_shortcuts_changed, terminal_changed, terminal_requires_restart) {
                 (true, true, true) => {
@@ -0,0 +1,83 @@
+fn settings_fonts_state(
+    fonts: &FontConfig,
+    window: &mut Window,
+    cx: &mut Context<DiffViewer>,
+) -> SettingsFontsState {
+    SettingsFontsState {
+        ui_font_family: settings_terminal_input(
+            fonts.ui_font_family.as_str(),
+            "System default",
+            window,
+            cx,
+        ),
+        ui_font_size: settings_terminal_input(
+            fonts.ui_font_size.to_string().as_str(),
+            "14",
+            window,
+            cx,
+        ),
+        mono_font_family: settings_terminal_input(
+            fonts.mono_font_family.as_str(),
+            "System default",
+            window,
+            cx,
+        ),
+        mono_font_size: settings_terminal_input(
+            fonts.mono_font_size.to_string().as_str(),
+            "12",
+            window,
+            cx,
+        ),
+        line_height: settings_terminal_input(
+            fonts.line_height.to_string().as_str(),
+            "1.45",
+            window,
+            cx,
+        ),
+        mono_ligatures: fonts.mono_ligatures,
+    }
+}
+
+/// Font settings as typed in the draft. Sizes outside the supported range are clamped rather
+/// than rejected.
+fn settings_font_config(
+    state: &SettingsFontsState,
+    cx: &Context<DiffViewer>,
+) -> Result<FontConfig, String> {
+    let parse = |label_key: &str, input: &Entity<InputState>| {
+        let value = input.read(cx).value().trim().to_string();
+        value
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite() && *value > 0.0)
+            .ok_or_else(|| {
+                tr_args(
+                    "settings-fonts-invalid-number",
+                    &[("field", tr(label_key).as_str())],
+                )
+            })
+    };
+    let fonts = FontConfig {
+        ui_font_family: state.ui_font_family.read(cx).value().to_string(),
+        ui_font_size: parse("settings-fonts-ui-size", &state.ui_font_size)?,
+        mono_font_family: state.mono_font_family.read(cx).value().to_string(),
+        mono_font_size: parse("settings-fonts-code-size", &state.mono_font_size)?,
+        mono_ligatures: state.mono_ligatures,
+        line_height: parse("settings-fonts-line-height", &state.line_height)?,
+    };
+    Ok(fonts.normalized())
+}
+
+impl DiffViewer {
+    pub(super) fn set_settings_mono_ligatures(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.fonts.mono_ligatures == enabled {
+            return;
+        }
+        settings.fonts.mono_ligatures = enabled;
+        settings.error_message = None;
+        cx.notify();
+    }
+}
@@ -0,0 +1,61 @@
+fn settings_formatting_state(
+    formatting: &FormattingConfig,
+    window: &mut Window,
+    cx: &mut Context<DiffViewer>,
+) -> SettingsFormattingState {
+    SettingsFormattingState {
+        format_on_save: formatting.format_on_save,
+        formatters: formatting.formatters.clone(),
+        commands: formatting
+            .formatters
+            .iter()
+            .map(|formatter| {
+                settings_terminal_input(
+                    formatter.command_line().as_str(),
+                    tr("settings-formatting-not-formatted"),
+                    window,
+                    cx,
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Formatter commands as typed in the draft. Command lines split on whitespace, so a line left
+/// as it was keeps the configured arguments, including any that contain spaces.
+fn settings_formatting_config(
+    state: &SettingsFormattingState,
+    cx: &Context<DiffViewer>,
+) -> FormattingConfig {
+    let formatters = state
+        .formatters
+        .iter()
+        .zip(&state.commands)
+        .map(|(formatter, input)| {
+            let mut formatter = formatter.clone();
+            let line = input.read(cx).value().trim().to_string();
+            if line != formatter.command_line() {
+                formatter.set_command_line(line.as_str());
+            }
+            formatter
+        })
+        .collect();
+    FormattingConfig {
+        format_on_save: state.format_on_save,
+        formatters,
+    }
+}
+
+impl DiffViewer {
+    pub(super) fn set_settings_format_on_save(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.formatting.format_on_save == enabled {
+            return;
+        }
+        settings.formatting.format_on_save = enabled;
+        settings.error_message = None;
+        cx.notify();
+    }
+}
@@ -0,0 +1,34 @@
+impl DiffViewer {
+    fn update_settings_gestures(
+        &mut self,
+        cx: &mut Context<Self>,
+        update: impl FnOnce(&mut GestureConfig),
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        let previous = settings.gestures;
+        update(&mut settings.gestures);
+        if settings.gestures == previous {
+            return;
+        }
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_diff_row_double_click(
+        &mut self,
+        action: DiffRowDoubleClickAction,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_gestures(cx, |config| config.diff_row_double_click = action);
+    }
+
+    pub(super) fn set_settings_file_middle_click(
+        &mut self,
+        action: FileMiddleClickAction,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_gestures(cx, |config| config.file_middle_click = action);
+    }
+}
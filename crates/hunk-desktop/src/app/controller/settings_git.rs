@@ -0,0 +1,255 @@
+fn settings_git_input(
+    value: &str,
+    placeholder: &'static str,
+    window: &mut Window,
+    cx: &mut Context<DiffViewer>,
+) -> Entity<InputState> {
+    let value = value.to_string();
+    cx.new(|cx| {
+        let mut state = InputState::new(window, cx).placeholder(placeholder);
+        state.set_value(value.clone(), window, cx);
+        state
+    })
+}
+
+fn settings_git_state(
+    timeouts: GitTimeoutConfig,
+    repo_root: Option<PathBuf>,
+    window: &mut Window,
+    cx: &mut Context<DiffViewer>,
+) -> SettingsGitState {
+    let (original, load_error) = match hunk_git::user_config::read_global_user_settings() {
+        Ok(settings) => (settings, None),
+        Err(err) => {
+            warn!("failed to read global Git config: {err:#}");
+            (GitUserSettings::default(), Some(format!("{err:#}")))
+        }
+    };
+    let (snapshot_exclusions_repo_root, original_snapshot_exclusions) = match repo_root {
+        Some(repo_root) => {
+            match hunk_git::snapshot_exclusions::read_snapshot_exclusions(repo_root.as_path()) {
+                Ok(patterns) => (Some(repo_root), patterns),
+                Err(err) => {
+                    warn!("failed to read snapshot exclusions: {err:#}");
+                    (None, Vec::new())
+                }
+            }
+        }
+        None => (None, Vec::new()),
+    };
+
+    SettingsGitState {
+        name: settings_git_input(
+            original.identity.name.as_deref().unwrap_or_default(),
+            "Your Name",
+            window,
+            cx,
+        ),
+        email: settings_git_input(
+            original.identity.email.as_deref().unwrap_or_default(),
+            "you@example.com",
+            window,
+            cx,
+        ),
+        signing_key: settings_git_input(
+            original.signing_key.as_deref().unwrap_or_default(),
+            "Key ID or path to SSH public key",
+            window,
+            cx,
+        ),
+        push_timeout: settings_git_input(
+            timeouts.push_secs.to_string().as_str(),
+            "Seconds, 0 for no limit",
+            window,
+            cx,
+        ),
+        sync_timeout: settings_git_input(
+            timeouts.sync_secs.to_string().as_str(),
+            "Seconds, 0 for no limit",
+            window,
+            cx,
+        ),
+        snapshot_exclusions: settings_git_input(
+            original_snapshot_exclusions.join(", ").as_str(),
+            "scratch/, *.local",
+            window,
+            cx,
+        ),
+        snapshot_exclusions_repo_root,
+        original_snapshot_exclusions,
+        auto_setup_remote: original.auto_setup_remote,
+        sign_commits: original.sign_commits,
+        signing_format: original.signing_format,
+        original,
+        load_error,
+    }
+}
+
+fn settings_git_input_value(
+    input: &Entity<InputState>,
+    cx: &Context<DiffViewer>,
+) -> Option<String> {
+    let value = input.read(cx).value().trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+fn settings_git_user_settings(
+    state: &SettingsGitState,
+    cx: &Context<DiffViewer>,
+) -> GitUserSettings {
+    GitUserSettings {
+        identity: hunk_git::user_config::UserIdentity {
+            name: settings_git_input_value(&state.name, cx),
+            email: settings_git_input_value(&state.email, cx),
+        },
+        auto_setup_remote: state.auto_setup_remote,
+        sign_commits: state.sign_commits,
+        signing_format: state.signing_format,
+        signing_key: settings_git_input_value(&state.signing_key, cx),
+    }
+}
+
+fn settings_git_timeouts(
+    state: &SettingsGitState,
+    cx: &Context<DiffViewer>,
+) -> Result<GitTimeoutConfig, String> {
+    let parse = |label: &str, input: &Entity<InputState>| {
+        let value = input.read(cx).value().trim().to_string();
+        value
+            .parse::<u64>()
+            .map_err(|_| format!("Git: {label} timeout must be a whole number of seconds."))
+    };
+    Ok(GitTimeoutConfig {
+        push_secs: parse("Push", &state.push_timeout)?,
+        sync_secs: parse("Sync", &state.sync_timeout)?,
+    })
+}
+
+/// Snapshot exclusions are edited as one comma-separated line of gitignore-style patterns.
+fn settings_git_snapshot_exclusions(
+    state: &SettingsGitState,
+    cx: &Context<DiffViewer>,
+) -> Result<Vec<String>, String> {
+    let value = state.snapshot_exclusions.read(cx).value().to_string();
+    let patterns = value.split(',').map(ToOwned::to_owned).collect::<Vec<_>>();
+    hunk_git::snapshot_exclusions::normalize_snapshot_exclusions(&patterns)
+        .map_err(|err| format!("Git: Snapshot exclusions: {err:#}"))
+}
+
+impl DiffViewer {
+    pub(super) fn set_settings_git_auto_setup_remote(
+        &mut self,
+        auto_setup_remote: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.git.auto_setup_remote == auto_setup_remote {
+            return;
+        }
+        settings.git.auto_setup_remote = auto_setup_remote;
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_git_sign_commits(
+        &mut self,
+        sign_commits: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.git.sign_commits == sign_commits {
+            return;
+        }
+        settings.git.sign_commits = sign_commits;
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_git_signing_format(
+        &mut self,
+        signing_format: GitSigningFormat,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        if settings.git.signing_format == signing_format {
+            return;
+        }
+        settings.git.signing_format = signing_format;
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    /// Writes changed identity and signing settings to the global Git config off the UI thread.
+    /// Returns whether a write was started.
+    fn save_settings_git(&mut self, git_settings: GitUserSettings, cx: &mut Context<Self>) -> bool {
+        let Some(settings) = self.settings_draft.as_ref() else {
+            return false;
+        };
+        if settings.git.load_error.is_some() || settings.git.original == git_settings {
+            return false;
+        }
+
+        self.git_config_save_task = cx.spawn(async move |this, cx| {
+            let (git_settings, result) = cx
+                .background_executor()
+                .spawn(async move {
+                    let result = hunk_git::user_config::write_global_user_settings(&git_settings);
+                    (git_settings, result)
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(()) => {
+                            if let Some(settings) = this.settings_draft.as_mut() {
+                                settings.git.original = git_settings;
+                            }
+                            Self::push_success_notification(
+                                "Updated global Git config.".to_string(),
+                                cx,
+                            );
+                        }
+                        Err(err) => {
+                            error!("failed to save global Git config: {err:#}");
+                            let message = format!("Failed to save Git config: {err:#}");
+                            if let Some(settings) = this.settings_draft.as_mut() {
+                                settings.error_message = Some(message.clone());
+                            }
+                            Self::push_error_notification(message, cx);
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+        true
+    }
+
+    fn save_settings_snapshot_exclusions(&mut self, patterns: Vec<String>) -> Result<bool, String> {
+        let read_only = self.read_only_active();
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return Ok(false);
+        };
+        let Some(repo_root) = settings.git.snapshot_exclusions_repo_root.as_ref() else {
+            return Ok(false);
+        };
+        if settings.git.original_snapshot_exclusions == patterns {
+            return Ok(false);
+        }
+        if read_only {
+            return Err(format!("Snapshot exclusions unavailable: {READ_ONLY_REASON}."));
+        }
+
+        hunk_git::snapshot_exclusions::write_snapshot_exclusions(repo_root.as_path(), &patterns)
+            .map_err(|err| format!("Failed to save snapshot exclusions: {err:#}"))?;
+        settings.git.original_snapshot_exclusions = patterns;
+        Ok(true)
+    }
+}
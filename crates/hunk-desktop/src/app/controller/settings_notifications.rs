@@ -0,0 +1,89 @@
+impl DiffViewer {
+    fn update_settings_desktop_notifications(
+        &mut self,
+        cx: &mut Context<Self>,
+        update: impl FnOnce(&mut DesktopNotificationsConfig),
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        let previous = settings.desktop_notifications;
+        update(&mut settings.desktop_notifications);
+        if settings.desktop_notifications == previous {
+            return;
+        }
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_desktop_notifications_enabled(
+        &mut self,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_desktop_notifications(cx, |config| config.enabled = enabled);
+    }
+
+    pub(super) fn set_settings_desktop_notifications_ci_finished(
+        &mut self,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_desktop_notifications(cx, |config| config.ci_finished = enabled);
+    }
+
+    pub(super) fn set_settings_desktop_notifications_remote_branch_moved(
+        &mut self,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_desktop_notifications(cx, |config| {
+            config.remote_branch_moved = enabled;
+        });
+    }
+
+    pub(super) fn set_settings_desktop_notifications_comment_mentions(
+        &mut self,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_desktop_notifications(cx, |config| {
+            config.comment_mentions = enabled;
+        });
+    }
+
+    fn update_settings_review_timer(
+        &mut self,
+        cx: &mut Context<Self>,
+        update: impl FnOnce(&mut ReviewTimerConfig),
+    ) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        let previous = settings.review_timer;
+        update(&mut settings.review_timer);
+        if settings.review_timer == previous {
+            return;
+        }
+        settings.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_settings_review_timer_enabled(
+        &mut self,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_review_timer(cx, |config| config.enabled = enabled);
+    }
+
+    pub(super) fn set_settings_review_break_reminder_minutes(
+        &mut self,
+        minutes: u64,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_settings_review_timer(cx, |config| {
+            config.break_reminder_minutes = minutes;
+        });
+    }
+}
@@ -0,0 +1,127 @@
+impl DiffViewer {
+    pub(super) fn sparse_checkout_summary(&self) -> Option<String> {
+        let sparse = self.repo_tree.sparse_checkout.as_ref()?;
+        let mode = if sparse.cone {
+            "cone mode"
+        } else {
+            "pattern mode"
+        };
+        Some(match sparse.patterns.len() {
+            1 => format!("Sparse checkout active ({mode}, 1 pattern)."),
+            count => format!("Sparse checkout active ({mode}, {count} patterns)."),
+        })
+    }
+
+    /// Reads the index, so only call this for a path that is missing from the working tree.
+    fn path_hidden_by_sparse_checkout(&self, path: &str) -> bool {
+        let Some(repo_root) = self.repo_root.as_ref() else {
+            return false;
+        };
+        path_excluded_by_sparse_checkout(repo_root.as_path(), path).unwrap_or_else(|err| {
+            warn!("failed to check sparse checkout for {path}: {err:#}");
+            false
+        })
+    }
+
+    fn confirm_widen_sparse_checkout(
+        &mut self,
+        path: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo_root) = self.repo_root.as_ref() else {
+            return;
+        };
+        // The tree only reloads in Files view, so read the settings fresh rather than trusting
+        // `repo_tree.sparse_checkout`.
+        let sparse = match load_sparse_checkout(repo_root.as_path()) {
+            Ok(Some(sparse)) => sparse,
+            Ok(None) => return,
+            Err(err) => {
+                self.set_git_warning_message(format!("{err:#}"), Some(window), cx);
+                return;
+            }
+        };
+        let pattern = match sparse_checkout_widen_pattern(&sparse, path.as_str()) {
+            Ok(pattern) => pattern,
+            Err(err) => {
+                self.set_git_warning_message(format!("{err:#}"), Some(window), cx);
+                return;
+            }
+        };
+        let view = cx.entity();
+
+        gpui_component::WindowExt::open_alert_dialog(window, cx, move |alert, _, _| {
+            alert
+                .width(px(460.0))
+                .title("Outside Sparse Checkout")
+                .description(format!(
+                    "{path} is not part of this sparse checkout. Add '{pattern}' to the sparse set and check out its files?"
+                ))
+                .button_props(
+                    gpui_component::dialog::DialogButtonProps::default()
+                        .ok_text("Widen Checkout")
+                        .cancel_text("Cancel")
+                        .show_cancel(true),
+                )
+                .on_ok({
+                    let view = view.clone();
+                    let path = path.clone();
+                    let pattern = pattern.clone();
+                    move |_, _, cx| {
+                        view.update(cx, |this, cx| {
+                            this.widen_sparse_checkout_for_path(path.clone(), pattern.clone(), cx);
+                        });
+                        true
+                    }
+                })
+        });
+    }
+
+    fn widen_sparse_checkout_for_path(
+        &mut self,
+        path: String,
+        pattern: String,
+        cx: &mut Context<Self>,
+    ) {
+        if self.block_in_read_only("Widen sparse checkout", cx) {
+            return;
+        }
+        let Some(repo_root) = self.repo_root.clone() else {
+            return;
+        };
+        self.git_status_message = Some(format!("Adding '{pattern}' to the sparse checkout..."));
+        cx.notify();
+
+        self.sparse_checkout_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let pattern = pattern.clone();
+                    async move { widen_sparse_checkout(repo_root.as_path(), pattern.as_str()) }
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    match result {
+                        Ok(()) => {
+                            let message = format!("Added '{pattern}' to the sparse checkout.");
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.refresh_after_repo_tree_fs_mutation(cx);
+                            this.defer_open_file_in_files_workspace(path, cx);
+                        }
+                        Err(err) => {
+                            error!("failed to widen sparse checkout: {err:#}");
+                            let message = format!("Widening sparse checkout failed: {err:#}");
+                            this.git_status_message = Some(message.clone());
+                            Self::push_error_notification(message, cx);
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
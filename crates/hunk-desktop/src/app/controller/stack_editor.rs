@@ -0,0 +1,227 @@
+impl DiffViewer {
+    pub(super) fn open_stack_editor_action(
+        &mut self,
+        _: &OpenStackEditor,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_stack_editor(cx);
+    }
+
+    pub(super) fn open_stack_editor(&mut self, cx: &mut Context<Self>) {
+        let Some(repo_root) = self.selected_git_workspace_root() else {
+            self.git_status_message = Some("No Git repository available.".to_string());
+            cx.notify();
+            return;
+        };
+
+        self.stack_editor_panel = Some(StackEditorPanelState {
+            repo_root,
+            loading: true,
+            applying: false,
+            stack: None,
+            plan: Vec::new(),
+            error_message: None,
+        });
+        self.load_stack_editor(cx);
+    }
+
+    pub(super) fn close_stack_editor(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.stack_editor_panel.take().is_some() {
+            self.stack_editor_task = Task::ready(());
+            self.focus_handle.focus(window, cx);
+            cx.notify();
+        }
+    }
+
+    fn load_stack_editor(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.stack_editor_panel.as_mut() else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        panel.loading = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.stack_editor_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { load_branch_stack(repo_root.as_path()) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.stack_editor_panel.as_mut() else {
+                        return;
+                    };
+                    panel.loading = false;
+                    match result {
+                        Ok(stack) => {
+                            if stack.commits.is_empty() {
+                                panel.error_message = Some(tr_args(
+                                    "stack-editor-empty",
+                                    &[
+                                        ("branch", stack.branch.as_str()),
+                                        ("base", stack.base_label.as_str()),
+                                    ],
+                                ));
+                            }
+                            panel.plan = stack
+                                .commits
+                                .iter()
+                                .map(|commit| StackPlanEntry::pick(commit.commit_id.clone()))
+                                .collect();
+                            panel.stack = Some(stack);
+                        }
+                        Err(err) => {
+                            error!("loading branch stack failed: {err:#}");
+                            panel.stack = None;
+                            panel.plan.clear();
+                            panel.error_message = Some(format!("{err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+
+    /// Moves the commit at `from` so it lands at position `to` of the edited stack.
+    pub(super) fn move_stack_editor_entry(
+        &mut self,
+        from: usize,
+        to: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(panel) = self.stack_editor_panel.as_mut() else {
+            return;
+        };
+        if panel.applying || from == to || from >= panel.plan.len() || to >= panel.plan.len() {
+            return;
+        }
+        let entry = panel.plan.remove(from);
+        panel.plan.insert(to, entry);
+        panel.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn set_stack_editor_action(
+        &mut self,
+        ix: usize,
+        action: StackAction,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(panel) = self.stack_editor_panel.as_mut() else {
+            return;
+        };
+        let Some(entry) = panel.plan.get_mut(ix) else {
+            return;
+        };
+        if panel.applying || entry.action == action {
+            return;
+        }
+        entry.action = action;
+        panel.error_message = None;
+        cx.notify();
+    }
+
+    pub(super) fn reset_stack_editor_plan(&mut self, cx: &mut Context<Self>) {
+        let Some(panel) = self.stack_editor_panel.as_mut() else {
+            return;
+        };
+        let Some(stack) = panel.stack.as_ref() else {
+            return;
+        };
+        if panel.applying {
+            return;
+        }
+        panel.plan = stack
+            .commits
+            .iter()
+            .map(|commit| StackPlanEntry::pick(commit.commit_id.clone()))
+            .collect();
+        panel.error_message = None;
+        cx.notify();
+    }
+
+    /// Whether the edited stack differs from the branch as loaded.
+    pub(super) fn stack_editor_plan_changed(panel: &StackEditorPanelState) -> bool {
+        let Some(stack) = panel.stack.as_ref() else {
+            return false;
+        };
+        panel.plan.len() != stack.commits.len()
+            || panel
+                .plan
+                .iter()
+                .zip(&stack.commits)
+                .any(|(entry, commit)| {
+                    entry.action != StackAction::Pick || entry.commit_id != commit.commit_id
+                })
+    }
+
+    /// Rebuilds the branch from the edited stack, then refreshes the workspace and reloads the
+    /// stack from the rewritten branch.
+    pub(super) fn apply_stack_editor_plan(&mut self, cx: &mut Context<Self>) {
+        if self.git_controls_busy() || self.block_in_read_only("Edit stack", cx) {
+            return;
+        }
+        let Some(panel) = self.stack_editor_panel.as_mut() else {
+            return;
+        };
+        if panel.applying || panel.loading || !Self::stack_editor_plan_changed(panel) {
+            return;
+        }
+        let Some(stack) = panel.stack.clone() else {
+            return;
+        };
+        let repo_root = panel.repo_root.clone();
+        let plan = panel.plan.clone();
+        panel.applying = true;
+        panel.error_message = None;
+        cx.notify();
+
+        self.stack_editor_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { apply_stack_plan(repo_root.as_path(), &stack, &plan) })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(panel) = this.stack_editor_panel.as_mut() else {
+                        return;
+                    };
+                    panel.applying = false;
+                    match result {
+                        Ok(rewrite) => {
+                            let message = tr_args(
+                                "stack-editor-rewrote",
+                                &[
+                                    ("branch", rewrite.branch.as_str()),
+                                    ("count", rewrite.commit_count.to_string().as_str()),
+                                ],
+                            );
+                            this.git_status_message = Some(message.clone());
+                            Self::push_success_notification(message, cx);
+                            this.request_snapshot_refresh(cx);
+                            this.request_git_workspace_refresh(true, cx);
+                            this.load_stack_editor(cx);
+                        }
+                        Err(err) => {
+                            error!("Edit stack failed: {err:#}");
+                            panel.error_message = Some(format!("{err:#}"));
+                            Self::push_error_notification(
+                                tr_args(
+                                    "stack-editor-failed",
+                                    &[("error", err.to_string().as_str())],
+                                ),
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
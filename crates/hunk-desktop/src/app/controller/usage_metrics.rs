@@ -0,0 +1,70 @@
+const SETTINGS_USAGE_METRICS_DAYS: i64 = 30;
+
+impl DiffViewer {
+    /// Counts a local usage event in the background. Failures are logged and otherwise ignored.
+    fn record_usage_metric(
+        &self,
+        kind: UsageMetricKind,
+        duration_ms: Option<u64>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(store) = self.database_store.clone() else {
+            return;
+        };
+        cx.background_executor()
+            .spawn(async move {
+                if let Err(err) = store.record_usage_metric(kind, duration_ms) {
+                    error!("failed to record usage metric: {err:#}");
+                }
+            })
+            .detach();
+    }
+
+    fn load_settings_usage_metrics(&mut self, cx: &mut Context<Self>) {
+        let Some(settings) = self.settings_draft.as_mut() else {
+            return;
+        };
+        let Some(store) = self.database_store.clone() else {
+            settings.error_message = Some("Usage metrics need the local database.".to_string());
+            return;
+        };
+        settings.usage_metrics_loading = true;
+
+        self.usage_metrics_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    store.usage_metrics_summary(SETTINGS_USAGE_METRICS_DAYS, now_unix_ms())
+                })
+                .await;
+
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    let Some(settings) = this.settings_draft.as_mut() else {
+                        return;
+                    };
+                    settings.usage_metrics_loading = false;
+                    match result {
+                        Ok(summary) => settings.usage_metrics = Some(summary),
+                        Err(err) => {
+                            error!("failed to load usage metrics: {err:#}");
+                            settings.error_message =
+                                Some(format!("Failed to load usage metrics: {err:#}"));
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        });
+    }
+}
+
+/// For pushes that run on a background thread without access to the view.
+fn record_push_usage_metric(store: Option<&DatabaseStore>) {
+    let Some(store) = store else {
+        return;
+    };
+    if let Err(err) = store.record_usage_metric(UsageMetricKind::Push, None) {
+        error!("failed to record push usage metric: {err:#}");
+    }
+}
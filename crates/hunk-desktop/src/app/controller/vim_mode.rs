@@ -0,0 +1,80 @@
+impl DiffViewer {
+    /// Runs the vim command bound to a key press in the review surface. Keys are only consumed
+    /// while the surface itself has focus, so search and comment inputs still receive them.
+    pub(super) fn handle_vim_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if !self.config.vim_mode
+            || self.workspace_view_mode != WorkspaceViewMode::Diff
+            || !self.focus_handle.is_focused(window)
+        {
+            return false;
+        }
+        let modifiers = &event.keystroke.modifiers;
+        if modifiers.control || modifiers.platform || modifiers.alt || modifiers.function {
+            return false;
+        }
+        let key = event
+            .keystroke
+            .key_char
+            .as_deref()
+            .unwrap_or(event.keystroke.key.as_str());
+        let Some(command) = vim_command_for_key(key) else {
+            return false;
+        };
+
+        let visual = self.vim_mode == VimMode::Visual;
+        match command {
+            VimCommand::LineDown => self.move_selection_by(1, visual, cx),
+            VimCommand::LineUp => self.move_selection_by(-1, visual, cx),
+            VimCommand::NextHunk => {
+                self.exit_vim_visual_mode(cx);
+                self.select_hunk_relative(1, cx);
+            }
+            VimCommand::PreviousHunk => {
+                self.exit_vim_visual_mode(cx);
+                self.select_hunk_relative(-1, cx);
+            }
+            VimCommand::NextFile => {
+                self.exit_vim_visual_mode(cx);
+                self.select_file_relative(1, cx);
+            }
+            VimCommand::PreviousFile => {
+                self.exit_vim_visual_mode(cx);
+                self.select_file_relative(-1, cx);
+            }
+            VimCommand::ToggleVisual if visual => self.exit_vim_visual_mode(cx),
+            VimCommand::ToggleVisual => {
+                if self.review_surface.selection_head_row.is_none() {
+                    self.move_selection_by(0, false, cx);
+                }
+                self.vim_mode = VimMode::Visual;
+                cx.notify();
+            }
+            VimCommand::Yank => {
+                self.copy_selection_action(&CopySelection, window, cx);
+                self.exit_vim_visual_mode(cx);
+            }
+            VimCommand::Search => {
+                self.exit_vim_visual_mode(cx);
+                self.toggle_editor_search(true, window, cx);
+            }
+            VimCommand::Escape if visual => self.exit_vim_visual_mode(cx),
+            VimCommand::Escape => return false,
+        }
+        true
+    }
+
+    /// Leaves visual mode, collapsing the row selection back onto the cursor row.
+    fn exit_vim_visual_mode(&mut self, cx: &mut Context<Self>) {
+        if self.vim_mode != VimMode::Visual {
+            return;
+        }
+        self.vim_mode = VimMode::Normal;
+        self.review_surface.selection_anchor_row = self.review_surface.selection_head_row;
+        cx.notify();
+    }
+}
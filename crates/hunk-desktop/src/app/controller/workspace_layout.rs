@@ -0,0 +1,130 @@
+impl DiffViewer {
+    /// The persisted dock for `panel`, falling back to the default when the state file holds a
+    /// placement the panel cannot use.
+    pub(super) fn workspace_panel_dock(&self, panel: WorkspacePanel) -> PanelDock {
+        let dock = layout_panel_dock(&self.state.workspace_layout, panel);
+        if panel.supported_docks().contains(&dock) {
+            dock
+        } else {
+            layout_panel_dock(&WorkspaceLayoutState::default(), panel)
+        }
+    }
+
+    pub(super) fn sidebar_tree_visible(&self) -> bool {
+        self.workspace_panel_dock(WorkspacePanel::Tree) != PanelDock::Hidden
+    }
+
+    pub(super) fn recent_commits_panel_visible(&self) -> bool {
+        self.workspace_view_mode == WorkspaceViewMode::GitWorkspace
+            && (self.focus_mode
+                || self.workspace_panel_dock(WorkspacePanel::RecentCommits) != PanelDock::Hidden)
+    }
+
+    /// The catalog key of the pane maximized right now. Focus mode stays on across view
+    /// switches but does nothing in views without a pane to maximize.
+    pub(super) fn active_focus_mode_pane(&self) -> Option<&'static str> {
+        if !self.focus_mode {
+            return None;
+        }
+        self.workspace_view_mode.focus_mode_pane_key()
+    }
+
+    pub(super) fn toggle_focus_mode_action(
+        &mut self,
+        _: &ToggleFocusMode,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.workspace_view_mode.focus_mode_pane_key().is_none() {
+            return;
+        }
+        self.toggle_focus_mode(window, cx);
+    }
+
+    pub(super) fn toggle_focus_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.focus_mode = !self.focus_mode;
+        self.workspace_text_context_menu = None;
+        // Focus mode shows the commit history even when its dock is hidden.
+        self.maybe_run_pending_recent_commits_refresh(cx);
+        self.focus_handle.focus(window, cx);
+        cx.notify();
+    }
+
+    pub(super) fn set_workspace_panel_dock(
+        &mut self,
+        panel: WorkspacePanel,
+        dock: PanelDock,
+        cx: &mut Context<Self>,
+    ) {
+        if !panel.supported_docks().contains(&dock) || self.workspace_panel_dock(panel) == dock {
+            return;
+        }
+
+        let layout = &mut self.state.workspace_layout;
+        match panel {
+            WorkspacePanel::Tree => {
+                layout.tree = dock;
+                if dock.is_docked() {
+                    self.tree_restore_dock = dock;
+                }
+            }
+            WorkspacePanel::Comments => {
+                layout.comments = dock;
+                if dock == PanelDock::Hidden {
+                    self.comments_preview_open = false;
+                }
+            }
+            WorkspacePanel::RecentCommits => layout.recent_commits = dock,
+        }
+        self.persist_state();
+
+        if panel == WorkspacePanel::Tree
+            && dock.is_docked()
+            && (self.repo_tree.nodes.is_empty() || self.repo_tree.reload_deferred)
+            && !self.repo_tree.loading
+        {
+            self.request_repo_tree_reload(cx);
+        }
+        if panel == WorkspacePanel::RecentCommits {
+            self.maybe_run_pending_recent_commits_refresh(cx);
+        }
+        cx.notify();
+    }
+
+    /// Moves a panel from the layout menu, revealing the comments list when it gets a new home.
+    pub(super) fn move_workspace_panel(
+        &mut self,
+        panel: WorkspacePanel,
+        dock: PanelDock,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_workspace_panel_dock(panel, dock, cx);
+        if panel == WorkspacePanel::Comments
+            && dock != PanelDock::Hidden
+            && self.workspace_view_mode == WorkspaceViewMode::Diff
+            && !self.comments_preview_open
+        {
+            self.toggle_comments_preview(cx);
+        }
+    }
+
+    pub(super) fn reset_workspace_layout_action(
+        &mut self,
+        _: &ResetWorkspaceLayout,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let defaults = WorkspaceLayoutState::default();
+        for panel in WorkspacePanel::ALL {
+            self.set_workspace_panel_dock(panel, layout_panel_dock(&defaults, panel), cx);
+        }
+    }
+}
+
+fn layout_panel_dock(layout: &WorkspaceLayoutState, panel: WorkspacePanel) -> PanelDock {
+    match panel {
+        WorkspacePanel::Tree => layout.tree,
+        WorkspacePanel::Comments => layout.comments,
+        WorkspacePanel::RecentCommits => layout.recent_commits,
+    }
+}
@@ -1,22 +1,29 @@
-use std::collections::hash_map::DefaultHasher;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::hash::{Hash, Hasher};
 use std::io::Write as _;
 use std::path::Path;
 
 use anyhow::{Result, anyhow};
 
+pub(super) use super::data_line_stats::{
+    ChangedFileSort, line_stats_from_patch, line_stats_sparkline_widths,
+    line_stats_without_ignored_paths, path_is_stats_ignored,
+};
+pub(super) use super::data_path_copy::{
+    FilePathCopyFormat, file_path_copy_status_message, format_file_path_for_copy,
+};
 pub(super) use super::data_segments::{
-    cached_runtime_fallback_segments, compact_cached_segments_for_render, is_binary_patch,
-    is_probably_binary_extension,
+    cached_runtime_fallback_segments, compact_cached_segments_for_render,
+};
+pub(super) use super::data_stream::{
+    build_diff_stream_from_patch_map, replace_file_rows_in_diff_stream,
 };
 use super::highlight::{
     StyledSegment, SyntaxTokenKind, build_line_segments, build_syntax_only_line_segments,
 };
-pub(super) use super::workspace_view::{WorkspaceSwitchAction, WorkspaceViewMode};
+pub(super) use super::workspace_view::{WorkspacePanel, WorkspaceSwitchAction, WorkspaceViewMode};
 use super::*;
-use hunk_domain::diff::parse_patch_side_by_side;
+pub(super) use hunk_git::file_rows::is_binary_patch;
 use hunk_git::git::{RepoTreeEntry, RepoTreeEntryKind};
 
 #[derive(Default)]
@@ -106,6 +113,8 @@ pub(super) enum DiffStreamRowKind {
     FileLoading,
     FileCollapsed,
     FileError,
+    /// Banner listing invisible or confusable characters the file's added lines introduce.
+    FileWarning,
     EmptyState,
 }
 
@@ -117,17 +126,138 @@ pub(super) struct DiffStreamRowMeta {
     pub(super) kind: DiffStreamRowKind,
 }
 
+/// Which files in the review stream hide their context rows.
+///
+/// `toggled_files` flips the global choice for individual paths, so toggling one file while
+/// the global mode is on brings its context back.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct ChangedLinesOnlyMode {
+    pub(super) all_files: bool,
+    pub(super) toggled_files: BTreeSet<String>,
+}
+
+impl ChangedLinesOnlyMode {
+    pub(super) fn applies_to(&self, path: &str) -> bool {
+        self.all_files != self.toggled_files.contains(path)
+    }
+
+    pub(super) fn toggle_all(&mut self) {
+        self.all_files = !self.all_files;
+        self.toggled_files.clear();
+    }
+
+    pub(super) fn toggle_file(&mut self, path: &str) {
+        if !self.toggled_files.remove(path) {
+            self.toggled_files.insert(path.to_string());
+        }
+    }
+
+    pub(super) fn paths_for(&self, files: &[ChangedFile]) -> BTreeSet<String> {
+        files
+            .iter()
+            .filter(|file| self.applies_to(file.path.as_str()))
+            .map(|file| file.path.clone())
+            .collect()
+    }
+}
+
+/// Folders of the review marked as reviewed in one gesture, with the changed files each one
+/// covered when it was marked.
+///
+/// A folder drops back to unreviewed as soon as a changed file shows up under it that it did
+/// not cover, so later additions still get looked at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct ReviewedDirectories {
+    folders: BTreeMap<String, ReviewedDirectory>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ReviewedDirectory {
+    covered_files: BTreeSet<String>,
+    /// Covered files that were expanded until the mark collapsed them.
+    collapsed_files: BTreeSet<String>,
+}
+
+impl ReviewedDirectories {
+    pub(super) fn contains(&self, dir: &str) -> bool {
+        self.folders.contains_key(dir)
+    }
+
+    /// Whether a reviewed folder covers the changed file at `path`.
+    pub(super) fn covers_file(&self, path: &str) -> bool {
+        self.folders
+            .values()
+            .any(|folder| folder.covered_files.contains(path))
+    }
+
+    /// Marks `dir` as reviewed and returns the changed files it covers that are not in
+    /// `collapsed_files` yet, which the caller collapses. Reviewed folders nested under `dir` are
+    /// folded into it.
+    pub(super) fn mark(
+        &mut self,
+        dir: &str,
+        files: &[ChangedFile],
+        collapsed_files: &BTreeSet<String>,
+    ) -> BTreeSet<String> {
+        let folder_prefix = format!("{dir}/");
+        let mut folder = ReviewedDirectory::default();
+        self.folders.retain(|reviewed_dir, nested| {
+            if !reviewed_dir.starts_with(folder_prefix.as_str()) {
+                return true;
+            }
+            folder.collapsed_files.append(&mut nested.collapsed_files);
+            false
+        });
+        folder.covered_files = files
+            .iter()
+            .filter(|file| file.path.starts_with(folder_prefix.as_str()))
+            .map(|file| file.path.clone())
+            .collect();
+        let newly_collapsed = folder
+            .covered_files
+            .iter()
+            .filter(|path| !collapsed_files.contains(path.as_str()))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        folder
+            .collapsed_files
+            .extend(newly_collapsed.iter().cloned());
+        self.folders.insert(dir.to_string(), folder);
+        newly_collapsed
+    }
+
+    /// Clears the reviewed mark of `dir`, returning the files the mark collapsed.
+    pub(super) fn unmark(&mut self, dir: &str) -> Option<BTreeSet<String>> {
+        self.folders
+            .remove(dir)
+            .map(|folder| folder.collapsed_files)
+    }
+
+    /// Clears every folder that gained a changed file since it was marked, returning the files
+    /// those marks collapsed.
+    pub(super) fn reset_for_new_files(&mut self, files: &[ChangedFile]) -> BTreeSet<String> {
+        let mut reopened = BTreeSet::new();
+        self.folders.retain(|dir, folder| {
+            let folder_prefix = format!("{dir}/");
+            let gained_file = files.iter().any(|file| {
+                file.path.starts_with(folder_prefix.as_str())
+                    && !folder.covered_files.contains(file.path.as_str())
+            });
+            if gained_file {
+                reopened.append(&mut folder.collapsed_files);
+            }
+            !gained_file
+        });
+        reopened
+    }
+}
+
 pub(super) struct DiffStream {
     pub(super) rows: Vec<SideBySideRow>,
     pub(super) row_metadata: Vec<DiffStreamRowMeta>,
     pub(super) row_segments: Vec<Option<DiffRowSegmentCache>>,
 }
 
-struct LoadedFileDiffRows {
-    core_rows: Vec<SideBySideRow>,
-    load_error: Option<String>,
-}
-
 const MAX_RENDER_SEGMENTS_PER_CELL_DETAILED: usize = 48;
 const MAX_RENDER_SEGMENTS_PER_CELL_LARGE_FILE: usize = 24;
 
@@ -164,9 +294,26 @@ pub(super) fn build_repo_tree(entries: &[RepoTreeEntry]) -> Vec<RepoTreeNode> {
     build_repo_tree_nodes(&root, "")
 }
 
-pub(super) fn build_changed_files_tree(files: &[ChangedFile]) -> Vec<RepoTreeNode> {
+pub(super) fn build_changed_files_tree(
+    files: &[ChangedFile],
+    line_stats: &BTreeMap<String, LineStats>,
+    sort: ChangedFileSort,
+) -> Vec<RepoTreeNode> {
+    let mut files = files.iter().collect::<Vec<_>>();
+    if sort == ChangedFileSort::ChangeSize {
+        // Stable sort keeps path order among files with the same size.
+        files.sort_by_key(|file| {
+            std::cmp::Reverse(
+                line_stats
+                    .get(file.path.as_str())
+                    .map(|stats| stats.changed())
+                    .unwrap_or_default(),
+            )
+        });
+    }
+
     files
-        .iter()
+        .into_iter()
         .map(|file| RepoTreeNode {
             path: file.path.clone(),
             name: file.path.clone(),
@@ -435,156 +582,6 @@ pub(super) fn message_row(kind: DiffRowKind, text: impl Into<String>) -> SideByS
     }
 }
 
-pub(super) fn build_diff_stream_from_patch_map(
-    files: &[ChangedFile],
-    collapsed_files: &BTreeSet<String>,
-    previous_file_line_stats: &BTreeMap<String, LineStats>,
-    patches_by_path: &BTreeMap<String, String>,
-    loading_paths: &BTreeSet<String>,
-) -> DiffStream {
-    let mut rows = Vec::new();
-    let mut row_metadata = Vec::new();
-    let mut row_segments = Vec::new();
-
-    for file in files {
-        let mut file_row_ordinal = 0_usize;
-        push_stream_row(
-            &mut rows,
-            &mut row_metadata,
-            message_row(DiffRowKind::Meta, file.path.clone()),
-            DiffStreamRowKind::FileHeader,
-            Some(file.path.as_str()),
-            Some(file.status),
-            file_row_ordinal,
-        );
-        row_segments.push(None);
-        file_row_ordinal = file_row_ordinal.saturating_add(1);
-
-        if collapsed_files.contains(file.path.as_str()) {
-            let collapsed_stats = previous_file_line_stats
-                .get(file.path.as_str())
-                .copied()
-                .unwrap_or_default();
-            let collapsed_message = if collapsed_stats.changed() > 0 {
-                format!(
-                    "File collapsed ({} changed lines hidden, counts may be stale). Expand to refresh.",
-                    collapsed_stats.changed()
-                )
-            } else {
-                "File collapsed. Expand to load and refresh its diff.".to_string()
-            };
-            push_stream_row(
-                &mut rows,
-                &mut row_metadata,
-                message_row(DiffRowKind::Empty, collapsed_message),
-                DiffStreamRowKind::FileCollapsed,
-                Some(file.path.as_str()),
-                Some(file.status),
-                file_row_ordinal,
-            );
-            row_segments.push(None);
-        } else if loading_paths.contains(file.path.as_str()) {
-            push_stream_row(
-                &mut rows,
-                &mut row_metadata,
-                message_row(DiffRowKind::Meta, "Loading file diff..."),
-                DiffStreamRowKind::FileLoading,
-                Some(file.path.as_str()),
-                Some(file.status),
-                file_row_ordinal,
-            );
-            row_segments.push(None);
-        } else {
-            let patch = patches_by_path
-                .get(file.path.as_str())
-                .map(String::as_str)
-                .unwrap_or_default();
-            let loaded_file = load_file_diff_rows(file, patch);
-            if let Some(load_error) = loaded_file.load_error {
-                push_stream_row(
-                    &mut rows,
-                    &mut row_metadata,
-                    message_row(DiffRowKind::Meta, load_error),
-                    DiffStreamRowKind::FileError,
-                    Some(file.path.as_str()),
-                    Some(file.status),
-                    file_row_ordinal,
-                );
-                row_segments.push(None);
-            } else {
-                for row in loaded_file.core_rows.into_iter().filter(|row| {
-                    matches!(
-                        row.kind,
-                        DiffRowKind::Code | DiffRowKind::HunkHeader | DiffRowKind::Empty
-                    )
-                }) {
-                    let row_kind = stream_kind_for_core_row(&row);
-                    push_stream_row(
-                        &mut rows,
-                        &mut row_metadata,
-                        row,
-                        row_kind,
-                        Some(file.path.as_str()),
-                        Some(file.status),
-                        file_row_ordinal,
-                    );
-                    row_segments.push(None);
-                    file_row_ordinal = file_row_ordinal.saturating_add(1);
-                }
-            }
-        }
-    }
-
-    if rows.is_empty() {
-        push_stream_row(
-            &mut rows,
-            &mut row_metadata,
-            message_row(DiffRowKind::Empty, "No changed files."),
-            DiffStreamRowKind::EmptyState,
-            None,
-            None,
-            0,
-        );
-        row_segments.push(None);
-    }
-
-    debug_assert_eq!(row_segments.len(), rows.len());
-
-    DiffStream {
-        rows,
-        row_metadata,
-        row_segments,
-    }
-}
-
-fn load_file_diff_rows(file: &ChangedFile, patch: &str) -> LoadedFileDiffRows {
-    if is_probably_binary_extension(file.path.as_str()) {
-        return LoadedFileDiffRows {
-            core_rows: Vec::new(),
-            load_error: Some(format!(
-                "Preview unavailable for {}: binary file type.",
-                file.path
-            )),
-        };
-    }
-
-    if is_binary_patch(patch) {
-        return LoadedFileDiffRows {
-            core_rows: Vec::new(),
-            load_error: Some(format!(
-                "Preview unavailable for {}: binary diff.",
-                file.path
-            )),
-        };
-    }
-
-    let core_rows = parse_patch_side_by_side(patch);
-    LoadedFileDiffRows {
-        core_rows,
-        load_error: None,
-    }
-}
-
 pub(super) fn cached_segments_from_styled(
     segments: Vec<StyledSegment>,
 ) -> Vec<CachedStyledSegment> {
@@ -652,79 +649,165 @@ pub(super) fn build_diff_row_segment_cache_from_cells(
     }
 }
 
-fn stream_kind_for_core_row(row: &SideBySideRow) -> DiffStreamRowKind {
-    match row.kind {
-        DiffRowKind::Code => DiffStreamRowKind::CoreCode,
-        DiffRowKind::HunkHeader => DiffStreamRowKind::CoreHunkHeader,
-        DiffRowKind::Meta => DiffStreamRowKind::CoreMeta,
-        DiffRowKind::Empty => DiffStreamRowKind::CoreEmpty,
-    }
+/// Gutter width for `digits` line number digits drawn `char_width` pixels apart.
+pub(super) fn line_number_column_width(digits: u32, char_width: f32) -> f32 {
+    digits as f32 * char_width + DIFF_LINE_NUMBER_EXTRA_PADDING
 }
 
-fn push_stream_row(
-    rows: &mut Vec<SideBySideRow>,
-    row_metadata: &mut Vec<DiffStreamRowMeta>,
-    row: SideBySideRow,
-    kind: DiffStreamRowKind,
-    file_path: Option<&str>,
-    file_status: Option<FileStatus>,
-    ordinal: usize,
-) -> u64 {
-    let stable_id = compute_stable_row_id(file_path, kind, ordinal);
-    rows.push(row);
-    row_metadata.push(DiffStreamRowMeta {
-        stable_id,
-        file_path: file_path.map(ToString::to_string),
-        file_status,
-        kind,
-    });
-    stable_id
+/// Applies a ctrl-scroll or pinch delta to the diff text zoom. Scrolling up zooms in, and the
+/// result stays within the range where code still fits the fixed review row height.
+pub(super) fn diff_text_zoom_after_scroll(zoom: f32, scroll_delta_y: f32) -> f32 {
+    (zoom + scroll_delta_y * DIFF_TEXT_ZOOM_PER_SCROLL_PX)
+        .clamp(DIFF_TEXT_ZOOM_MIN, DIFF_TEXT_ZOOM_MAX)
 }
 
-fn compute_stable_row_id(file_path: Option<&str>, kind: DiffStreamRowKind, ordinal: usize) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    file_path.unwrap_or("__stream__").hash(&mut hasher);
-    stable_kind_tag(kind).hash(&mut hasher);
-    ordinal.hash(&mut hasher);
-    hasher.finish()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-fn stable_kind_tag(kind: DiffStreamRowKind) -> &'static str {
-    match kind {
-        DiffStreamRowKind::FileHeader => "file-header",
-        DiffStreamRowKind::CoreCode => "core-code",
-        DiffStreamRowKind::CoreHunkHeader => "core-hunk-header",
-        DiffStreamRowKind::CoreMeta => "core-meta",
-        DiffStreamRowKind::CoreEmpty => "core-empty",
-        DiffStreamRowKind::FileLoading => "file-loading",
-        DiffStreamRowKind::FileCollapsed => "file-collapsed",
-        DiffStreamRowKind::FileError => "file-error",
-        DiffStreamRowKind::EmptyState => "empty-state",
+    #[test]
+    fn diff_text_zoom_follows_scroll_and_stays_clamped() {
+        assert_eq!(diff_text_zoom_after_scroll(1.0, 0.0), 1.0);
+        assert!(diff_text_zoom_after_scroll(1.0, 50.0) > 1.0);
+        assert!(diff_text_zoom_after_scroll(1.0, -50.0) < 1.0);
+        assert_eq!(
+            diff_text_zoom_after_scroll(1.0, 10_000.0),
+            DIFF_TEXT_ZOOM_MAX
+        );
+        assert_eq!(
+            diff_text_zoom_after_scroll(1.0, -10_000.0),
+            DIFF_TEXT_ZOOM_MIN
+        );
     }
-}
 
-pub(super) fn line_number_column_width(digits: u32) -> f32 {
-    digits as f32 * DIFF_MONO_CHAR_WIDTH + DIFF_LINE_NUMBER_EXTRA_PADDING
-}
+    #[test]
+    fn line_number_column_width_follows_measured_glyph_width() {
+        let narrow = line_number_column_width(3, 7.0);
+        let wide = line_number_column_width(3, 9.5);
+        assert_eq!(narrow, 21.0 + DIFF_LINE_NUMBER_EXTRA_PADDING);
+        assert_eq!(wide, 28.5 + DIFF_LINE_NUMBER_EXTRA_PADDING);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn changed_files_tree_sorts_by_change_size_when_requested() {
+        let files = ["a.rs", "b.rs", "c.rs"]
+            .into_iter()
+            .map(|path| ChangedFile {
+                path: path.to_string(),
+                status: FileStatus::Modified,
+                staged: false,
+                unstaged: true,
+                untracked: false,
+            })
+            .collect::<Vec<_>>();
+        let line_stats = BTreeMap::from([
+            (
+                "a.rs".to_string(),
+                LineStats {
+                    added: 1,
+                    removed: 0,
+                },
+            ),
+            (
+                "b.rs".to_string(),
+                LineStats {
+                    added: 10,
+                    removed: 5,
+                },
+            ),
+        ]);
+
+        let paths = |sort| {
+            build_changed_files_tree(&files, &line_stats, sort)
+                .into_iter()
+                .map(|node| node.path)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(paths(ChangedFileSort::Path), ["a.rs", "b.rs", "c.rs"]);
+        assert_eq!(paths(ChangedFileSort::ChangeSize), ["b.rs", "a.rs", "c.rs"]);
+    }
 
     #[test]
-    fn stable_row_id_is_deterministic_for_same_row() {
-        let first = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreCode, 2);
-        let second = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreCode, 2);
+    fn changed_lines_only_file_toggles_flip_the_global_mode() {
+        let mut mode = ChangedLinesOnlyMode::default();
+        mode.toggle_file("src/lib.rs");
+        assert!(mode.applies_to("src/lib.rs"));
+        assert!(!mode.applies_to("src/main.rs"));
+
+        mode.toggle_all();
+        assert!(mode.applies_to("src/lib.rs"));
+        assert!(mode.applies_to("src/main.rs"));
+
+        mode.toggle_file("src/main.rs");
+        assert!(!mode.applies_to("src/main.rs"));
+        mode.toggle_file("src/main.rs");
+        assert!(mode.applies_to("src/main.rs"));
+    }
 
-        assert_eq!(first, second);
+    #[test]
+    fn reviewed_directories_reset_when_a_new_file_appears_under_them() {
+        let changed = |paths: &[&str]| {
+            paths
+                .iter()
+                .map(|path| ChangedFile {
+                    path: path.to_string(),
+                    status: FileStatus::Modified,
+                    staged: false,
+                    unstaged: true,
+                    untracked: false,
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut reviewed = ReviewedDirectories::default();
+        let mut collapsed = BTreeSet::from(["tests/ui.rs".to_string()]);
+        collapsed.extend(reviewed.mark("src/ui", &changed(&["src/ui/button.rs"]), &collapsed));
+        let newly_collapsed = reviewed.mark(
+            "src",
+            &changed(&["src/lib.rs", "src/ui/button.rs", "tests/ui.rs"]),
+            &collapsed,
+        );
+        assert_eq!(newly_collapsed, BTreeSet::from(["src/lib.rs".to_string()]));
+        assert!(!reviewed.contains("src/ui"));
+        assert!(reviewed.covers_file("src/ui/button.rs"));
+        assert!(!reviewed.covers_file("tests/ui.rs"));
+
+        assert!(
+            reviewed
+                .reset_for_new_files(&changed(&["src/lib.rs", "tests/new.rs"]))
+                .is_empty()
+        );
+        assert_eq!(
+            reviewed.reset_for_new_files(&changed(&["src/lib.rs", "src/new.rs"])),
+            BTreeSet::from(["src/lib.rs".to_string(), "src/ui/button.rs".to_string()])
+        );
+        assert!(!reviewed.contains("src"));
+        assert!(!reviewed.covers_file("src/lib.rs"));
     }
 
     #[test]
-    fn stable_row_id_changes_when_ordinal_changes() {
-        let first = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreMeta, 0);
-        let second = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreMeta, 1);
+    fn unmarking_a_reviewed_directory_only_reopens_files_it_collapsed() {
+        let files = ["src/lib.rs", "src/main.rs"]
+            .iter()
+            .map(|path| ChangedFile {
+                path: path.to_string(),
+                status: FileStatus::Modified,
+                staged: false,
+                unstaged: true,
+                untracked: false,
+            })
+            .collect::<Vec<_>>();
+        let mut reviewed = ReviewedDirectories::default();
+        let collapsed = BTreeSet::from(["src/main.rs".to_string()]);
 
-        assert_ne!(first, second);
+        assert_eq!(
+            reviewed.mark("src", &files, &collapsed),
+            BTreeSet::from(["src/lib.rs".to_string()])
+        );
+        assert_eq!(
+            reviewed.unmark("src"),
+            Some(BTreeSet::from(["src/lib.rs".to_string()]))
+        );
+        assert_eq!(reviewed.unmark("src"), None);
     }
 
     #[test]
@@ -833,7 +916,7 @@ mod tests {
             },
         ];
 
-        let nodes = build_changed_files_tree(&files);
+        let nodes = build_changed_files_tree(&files, &BTreeMap::new(), ChangedFileSort::Path);
         assert_eq!(nodes.len(), 2);
         assert!(
             nodes
@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum ChangedFileSort {
+    #[default]
+    Path,
+    ChangeSize,
+}
+
+impl ChangedFileSort {
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Path => "Sort: Path",
+            Self::ChangeSize => "Sort: Size",
+        }
+    }
+
+    pub(super) fn toggled(self) -> Self {
+        match self {
+            Self::Path => Self::ChangeSize,
+            Self::ChangeSize => Self::Path,
+        }
+    }
+}
+
+/// Splits a sparkline of `width` into added and removed segments for one file.
+///
+/// The filled length grows with the square root of the change size relative to the largest
+/// file, so small edits stay visible next to very large ones.
+pub(super) fn line_stats_sparkline_widths(
+    stats: LineStats,
+    max_changed: u64,
+    width: f32,
+) -> (f32, f32) {
+    let changed = stats.changed();
+    if changed == 0 || max_changed == 0 {
+        return (0.0, 0.0);
+    }
+
+    let filled =
+        ((changed as f64).sqrt() / (max_changed.max(changed) as f64).sqrt()) as f32 * width;
+    let filled = filled.clamp(2.0_f32.min(width), width);
+    let added = filled * (stats.added as f32 / changed as f32);
+    (added, filled - added)
+}
+
+/// Whether `path` is one of the `ignored` paths or sits inside an ignored folder.
+pub(super) fn path_is_stats_ignored(path: &str, ignored: &BTreeSet<String>) -> bool {
+    ignored.iter().any(|ignored_path| {
+        path == ignored_path
+            || path
+                .strip_prefix(ignored_path.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+/// Removes the line stats of ignored files from `overall`.
+pub(super) fn line_stats_without_ignored_paths(
+    overall: LineStats,
+    file_line_stats: &BTreeMap<String, LineStats>,
+    ignored: &BTreeSet<String>,
+) -> LineStats {
+    if ignored.is_empty() {
+        return overall;
+    }
+
+    file_line_stats
+        .iter()
+        .filter(|(path, _)| path_is_stats_ignored(path, ignored))
+        .fold(overall, |total, (_, stats)| LineStats {
+            added: total.added.saturating_sub(stats.added),
+            removed: total.removed.saturating_sub(stats.removed),
+        })
+}
+
+/// Counts added and removed lines in a single-file unified patch.
+pub(super) fn line_stats_from_patch(patch: &str) -> LineStats {
+    let mut stats = LineStats::default();
+    let mut in_hunk = false;
+    for line in patch.lines() {
+        if line.starts_with("@@") {
+            in_hunk = true;
+        } else if !in_hunk {
+            continue;
+        } else if line.starts_with('+') {
+            stats.added = stats.added.saturating_add(1);
+        } else if line.starts_with('-') {
+            stats.removed = stats.removed.saturating_add(1);
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_widths_scale_with_largest_file_and_split_by_kind() {
+        let largest = LineStats {
+            added: 75,
+            removed: 25,
+        };
+        assert_eq!(
+            line_stats_sparkline_widths(largest, 100, 40.0),
+            (30.0, 10.0)
+        );
+
+        let (added, removed) = line_stats_sparkline_widths(
+            LineStats {
+                added: 0,
+                removed: 25,
+            },
+            100,
+            40.0,
+        );
+        assert_eq!(added, 0.0);
+        assert_eq!(removed, 20.0);
+
+        assert_eq!(
+            line_stats_sparkline_widths(LineStats::default(), 100, 40.0),
+            (0.0, 0.0)
+        );
+        let (added, _) = line_stats_sparkline_widths(
+            LineStats {
+                added: 1,
+                removed: 0,
+            },
+            1_000_000,
+            40.0,
+        );
+        assert_eq!(added, 2.0);
+    }
+
+    #[test]
+    fn ignored_paths_are_removed_from_overall_line_stats() {
+        let file_line_stats = BTreeMap::from([
+            (
+                "src/main.rs".to_string(),
+                LineStats {
+                    added: 10,
+                    removed: 4,
+                },
+            ),
+            (
+                "vendor/lib/a.rs".to_string(),
+                LineStats {
+                    added: 100,
+                    removed: 70,
+                },
+            ),
+            (
+                "vendored.rs".to_string(),
+                LineStats {
+                    added: 5,
+                    removed: 1,
+                },
+            ),
+        ]);
+        let overall = LineStats {
+            added: 115,
+            removed: 75,
+        };
+
+        assert_eq!(
+            line_stats_without_ignored_paths(overall, &file_line_stats, &BTreeSet::new()),
+            overall
+        );
+
+        let ignored = BTreeSet::from(["vendor".to_string()]);
+        assert!(path_is_stats_ignored("vendor/lib/a.rs", &ignored));
+        assert!(!path_is_stats_ignored("vendored.rs", &ignored));
+        assert_eq!(
+            line_stats_without_ignored_paths(overall, &file_line_stats, &ignored),
+            LineStats {
+                added: 15,
+                removed: 5,
+            }
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FilePathCopyFormat {
+    RelativePath,
+    AbsolutePath,
+    FileName,
+    PathWithLine,
+}
+
+impl FilePathCopyFormat {
+    pub(super) const ALL: [Self; 4] = [
+        Self::RelativePath,
+        Self::AbsolutePath,
+        Self::FileName,
+        Self::PathWithLine,
+    ];
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::RelativePath => "Copy Relative Path",
+            Self::AbsolutePath => "Copy Path",
+            Self::FileName => "Copy File Name",
+            Self::PathWithLine => "Copy Path:Line",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::RelativePath => "relative path",
+            Self::AbsolutePath => "absolute path",
+            Self::FileName => "file name",
+            Self::PathWithLine => "path and line",
+        }
+    }
+}
+
+/// Formats a repository-relative `path` for the clipboard, or `None` when the format needs
+/// context that is missing (no open repository, or no cursor line for `path:line`).
+pub(super) fn format_file_path_for_copy(
+    repo_root: Option<&Path>,
+    path: &str,
+    line: Option<usize>,
+    format: FilePathCopyFormat,
+) -> Option<String> {
+    match format {
+        FilePathCopyFormat::RelativePath => Some(path.to_string()),
+        FilePathCopyFormat::AbsolutePath => {
+            repo_root.map(|repo_root| repo_root.join(path).display().to_string())
+        }
+        FilePathCopyFormat::FileName => Some(
+            path.rsplit('/')
+                .find(|segment| !segment.is_empty())
+                .unwrap_or(path)
+                .to_string(),
+        ),
+        FilePathCopyFormat::PathWithLine => line.map(|line| format!("{path}:{line}")),
+    }
+}
+
+pub(super) fn file_path_copy_status_message(format: FilePathCopyFormat, path: &str) -> String {
+    format!("Copied {} for {path}", format.description())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_path_copy_formats_cover_each_variant() {
+        let repo_root = Path::new("/work/hunk");
+        let format = |format, line| {
+            format_file_path_for_copy(Some(repo_root), "crates/app/src/main.rs", line, format)
+        };
+
+        assert_eq!(
+            format(FilePathCopyFormat::RelativePath, None).as_deref(),
+            Some("crates/app/src/main.rs")
+        );
+        let absolute = repo_root.join("crates/app/src/main.rs");
+        assert_eq!(
+            format(FilePathCopyFormat::AbsolutePath, None),
+            Some(absolute.display().to_string())
+        );
+        assert_eq!(
+            format(FilePathCopyFormat::FileName, None).as_deref(),
+            Some("main.rs")
+        );
+        assert_eq!(
+            format(FilePathCopyFormat::PathWithLine, Some(42)).as_deref(),
+            Some("crates/app/src/main.rs:42")
+        );
+        assert_eq!(format(FilePathCopyFormat::PathWithLine, None), None);
+        assert_eq!(
+            format_file_path_for_copy(None, "README.md", None, FilePathCopyFormat::AbsolutePath),
+            None
+        );
+    }
+}
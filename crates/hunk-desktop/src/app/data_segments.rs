@@ -1,5 +1,3 @@
-use std::path::Path;
-
 use gpui::SharedString;
 
 use super::data::CachedStyledSegment;
@@ -66,65 +64,3 @@ pub(super) fn cached_runtime_fallback_segments(text: &str) -> Vec<CachedStyledSe
         search_match: false,
     }]
 }
-
-pub(super) fn is_probably_binary_extension(path: &str) -> bool {
-    let Some(extension) = Path::new(path).extension().and_then(|ext| ext.to_str()) else {
-        return false;
-    };
-
-    let extension = extension.to_ascii_lowercase();
-    matches!(
-        extension.as_str(),
-        "7z" | "a"
-            | "apk"
-            | "bin"
-            | "bmp"
-            | "class"
-            | "dll"
-            | "dmg"
-            | "doc"
-            | "docx"
-            | "ear"
-            | "eot"
-            | "exe"
-            | "gif"
-            | "gz"
-            | "ico"
-            | "jar"
-            | "jpeg"
-            | "jpg"
-            | "lib"
-            | "lockb"
-            | "mov"
-            | "mp3"
-            | "mp4"
-            | "o"
-            | "obj"
-            | "otf"
-            | "pdf"
-            | "png"
-            | "pyc"
-            | "so"
-            | "tar"
-            | "tif"
-            | "tiff"
-            | "ttf"
-            | "war"
-            | "wasm"
-            | "webm"
-            | "webp"
-            | "woff"
-            | "woff2"
-            | "xls"
-            | "xlsx"
-            | "zip"
-    )
-}
-
-pub(super) fn is_binary_patch(patch: &str) -> bool {
-    patch.contains('\0')
-        || patch.contains("\nGIT binary patch\n")
-        || patch
-            .lines()
-            .any(|line| line.starts_with("Binary files ") && line.ends_with(" differ"))
-}
@@ -0,0 +1,430 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+
+use super::data::{DiffStream, DiffStreamRowKind, DiffStreamRowMeta, message_row};
+use super::*;
+use hunk_domain::diff::HunkFolds;
+use hunk_domain::unicode_hazards::unicode_hazard_banner;
+use hunk_git::file_rows::load_file_diff_rows;
+
+pub(super) fn build_diff_stream_from_patch_map(
+    files: &[ChangedFile],
+    collapsed_files: &BTreeSet<String>,
+    changed_lines_only_files: &BTreeSet<String>,
+    hunk_folds: &HunkFolds,
+    previous_file_line_stats: &BTreeMap<String, LineStats>,
+    patches_by_path: &BTreeMap<String, String>,
+    loading_paths: &BTreeSet<String>,
+) -> DiffStream {
+    let mut stream = DiffStream {
+        rows: Vec::new(),
+        row_metadata: Vec::new(),
+        row_segments: Vec::new(),
+    };
+
+    for file in files {
+        let content = if collapsed_files.contains(file.path.as_str()) {
+            FileStreamContent::Collapsed(
+                previous_file_line_stats
+                    .get(file.path.as_str())
+                    .copied()
+                    .unwrap_or_default(),
+            )
+        } else if loading_paths.contains(file.path.as_str()) {
+            FileStreamContent::Loading
+        } else {
+            FileStreamContent::Patch {
+                patch: patches_by_path
+                    .get(file.path.as_str())
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+                changed_lines_only: changed_lines_only_files.contains(file.path.as_str()),
+                hunk_folds,
+            }
+        };
+        push_file_stream_rows(&mut stream, file, content);
+    }
+
+    if stream.rows.is_empty() {
+        push_stream_row(
+            &mut stream.rows,
+            &mut stream.row_metadata,
+            message_row(DiffRowKind::Empty, "No changed files."),
+            DiffStreamRowKind::EmptyState,
+            None,
+            None,
+            0,
+        );
+        stream.row_segments.push(None);
+    }
+
+    debug_assert_eq!(stream.row_segments.len(), stream.rows.len());
+
+    stream
+}
+
+/// Replaces the rows of an already streamed, expanded `file` with rows built from `patch`.
+///
+/// Row ids only depend on the path and the row's position within its file, so the result
+/// matches a full rebuild while other files keep their rows and segment caches. Returns
+/// `false` when `file` has no rows in `stream`.
+pub(super) fn replace_file_rows_in_diff_stream(
+    stream: &mut DiffStream,
+    file: &ChangedFile,
+    patch: &str,
+    changed_lines_only: bool,
+    hunk_folds: &HunkFolds,
+) -> bool {
+    let is_file_row =
+        |meta: &DiffStreamRowMeta| meta.file_path.as_deref() == Some(file.path.as_str());
+    let Some(start) = stream.row_metadata.iter().position(is_file_row) else {
+        return false;
+    };
+    let end = start
+        + stream.row_metadata[start..]
+            .iter()
+            .take_while(|meta| is_file_row(meta))
+            .count();
+
+    let mut file_stream = DiffStream {
+        rows: Vec::new(),
+        row_metadata: Vec::new(),
+        row_segments: Vec::new(),
+    };
+    push_file_stream_rows(
+        &mut file_stream,
+        file,
+        FileStreamContent::Patch {
+            patch,
+            changed_lines_only,
+            hunk_folds,
+        },
+    );
+    stream.rows.splice(start..end, file_stream.rows);
+    stream
+        .row_metadata
+        .splice(start..end, file_stream.row_metadata);
+    stream
+        .row_segments
+        .splice(start..end, file_stream.row_segments);
+    true
+}
+
+enum FileStreamContent<'a> {
+    Collapsed(LineStats),
+    Loading,
+    Patch {
+        patch: &'a str,
+        changed_lines_only: bool,
+        hunk_folds: &'a HunkFolds,
+    },
+}
+
+fn push_file_stream_rows(stream: &mut DiffStream, file: &ChangedFile, content: FileStreamContent) {
+    let DiffStream {
+        rows,
+        row_metadata,
+        row_segments,
+    } = stream;
+    let mut file_row_ordinal = 0_usize;
+    push_stream_row(
+        rows,
+        row_metadata,
+        message_row(DiffRowKind::Meta, file.path.clone()),
+        DiffStreamRowKind::FileHeader,
+        Some(file.path.as_str()),
+        Some(file.status),
+        file_row_ordinal,
+    );
+    row_segments.push(None);
+    file_row_ordinal = file_row_ordinal.saturating_add(1);
+
+    match content {
+        FileStreamContent::Collapsed(collapsed_stats) => {
+            let collapsed_message = if collapsed_stats.changed() > 0 {
+                format!(
+                    "File collapsed ({} changed lines hidden, counts may be stale). Expand to refresh.",
+                    collapsed_stats.changed()
+                )
+            } else {
+                "File collapsed. Expand to load and refresh its diff.".to_string()
+            };
+            push_stream_row(
+                rows,
+                row_metadata,
+                message_row(DiffRowKind::Empty, collapsed_message),
+                DiffStreamRowKind::FileCollapsed,
+                Some(file.path.as_str()),
+                Some(file.status),
+                file_row_ordinal,
+            );
+            row_segments.push(None);
+        }
+        FileStreamContent::Loading => {
+            push_stream_row(
+                rows,
+                row_metadata,
+                message_row(DiffRowKind::Meta, "Loading file diff..."),
+                DiffStreamRowKind::FileLoading,
+                Some(file.path.as_str()),
+                Some(file.status),
+                file_row_ordinal,
+            );
+            row_segments.push(None);
+        }
+        FileStreamContent::Patch {
+            patch,
+            changed_lines_only,
+            hunk_folds,
+        } => {
+            let loaded_file = load_file_diff_rows(file.path.as_str(), patch);
+            if let Some(load_error) = loaded_file.load_error {
+                push_stream_row(
+                    rows,
+                    row_metadata,
+                    message_row(DiffRowKind::Meta, load_error),
+                    DiffStreamRowKind::FileError,
+                    Some(file.path.as_str()),
+                    Some(file.status),
+                    file_row_ordinal,
+                );
+                row_segments.push(None);
+                return;
+            }
+            // The banner has its own row kind, so it leaves the ids of the code rows alone.
+            if let Some(banner) = unicode_hazard_banner(&loaded_file.core_rows) {
+                push_stream_row(
+                    rows,
+                    row_metadata,
+                    message_row(DiffRowKind::Meta, banner),
+                    DiffStreamRowKind::FileWarning,
+                    Some(file.path.as_str()),
+                    Some(file.status),
+                    file_row_ordinal,
+                );
+                row_segments.push(None);
+            }
+
+            let core_rows = hunk_folds.fold_rows(
+                file.path.as_str(),
+                loaded_file.core_rows,
+                changed_lines_only,
+            );
+            for row in core_rows.into_iter().filter(|row| {
+                matches!(
+                    row.kind,
+                    DiffRowKind::Code | DiffRowKind::HunkHeader | DiffRowKind::Empty
+                )
+            }) {
+                let row_kind = stream_kind_for_core_row(&row);
+                push_stream_row(
+                    rows,
+                    row_metadata,
+                    row,
+                    row_kind,
+                    Some(file.path.as_str()),
+                    Some(file.status),
+                    file_row_ordinal,
+                );
+                row_segments.push(None);
+                file_row_ordinal = file_row_ordinal.saturating_add(1);
+            }
+        }
+    }
+}
+
+fn stream_kind_for_core_row(row: &SideBySideRow) -> DiffStreamRowKind {
+    match row.kind {
+        DiffRowKind::Code => DiffStreamRowKind::CoreCode,
+        DiffRowKind::HunkHeader => DiffStreamRowKind::CoreHunkHeader,
+        DiffRowKind::Meta => DiffStreamRowKind::CoreMeta,
+        DiffRowKind::Empty => DiffStreamRowKind::CoreEmpty,
+    }
+}
+
+fn push_stream_row(
+    rows: &mut Vec<SideBySideRow>,
+    row_metadata: &mut Vec<DiffStreamRowMeta>,
+    row: SideBySideRow,
+    kind: DiffStreamRowKind,
+    file_path: Option<&str>,
+    file_status: Option<FileStatus>,
+    ordinal: usize,
+) -> u64 {
+    let stable_id = compute_stable_row_id(file_path, kind, ordinal);
+    rows.push(row);
+    row_metadata.push(DiffStreamRowMeta {
+        stable_id,
+        file_path: file_path.map(ToString::to_string),
+        file_status,
+        kind,
+    });
+    stable_id
+}
+
+fn compute_stable_row_id(file_path: Option<&str>, kind: DiffStreamRowKind, ordinal: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    file_path.unwrap_or("__stream__").hash(&mut hasher);
+    stable_kind_tag(kind).hash(&mut hasher);
+    ordinal.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn stable_kind_tag(kind: DiffStreamRowKind) -> &'static str {
+    match kind {
+        DiffStreamRowKind::FileHeader => "file-header",
+        DiffStreamRowKind::CoreCode => "core-code",
+        DiffStreamRowKind::CoreHunkHeader => "core-hunk-header",
+        DiffStreamRowKind::CoreMeta => "core-meta",
+        DiffStreamRowKind::CoreEmpty => "core-empty",
+        DiffStreamRowKind::FileLoading => "file-loading",
+        DiffStreamRowKind::FileCollapsed => "file-collapsed",
+        DiffStreamRowKind::FileError => "file-error",
+        DiffStreamRowKind::FileWarning => "file-warning",
+        DiffStreamRowKind::EmptyState => "empty-state",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replacing_file_rows_matches_a_full_stream_rebuild() {
+        let files = ["a.rs", "b.rs", "c.rs"]
+            .into_iter()
+            .map(|path| ChangedFile {
+                path: path.to_string(),
+                status: FileStatus::Modified,
+                staged: false,
+                unstaged: true,
+                untracked: false,
+            })
+            .collect::<Vec<_>>();
+        let patch = |path: &str, body: &str| {
+            format!("diff --git a/{path} b/{path}\n--- a/{path}\n+++ b/{path}\n{body}")
+        };
+        let mut patches = files
+            .iter()
+            .map(|file| {
+                let body = "@@ -1,2 +1,2 @@\n keep\n-old\n+new\n";
+                (file.path.clone(), patch(file.path.as_str(), body))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let build = |patches: &BTreeMap<String, String>| {
+            build_diff_stream_from_patch_map(
+                &files,
+                &BTreeSet::new(),
+                &BTreeSet::new(),
+                &HunkFolds::default(),
+                &BTreeMap::new(),
+                patches,
+                &BTreeSet::new(),
+            )
+        };
+        let mut stream = build(&patches);
+
+        let updated = patch(
+            "b.rs",
+            "@@ -1,2 +1,4 @@\n keep\n-old\n+new\n+more\n+lines\n",
+        );
+        assert!(replace_file_rows_in_diff_stream(
+            &mut stream,
+            &files[1],
+            updated.as_str(),
+            false,
+            &HunkFolds::default()
+        ));
+        patches.insert("b.rs".to_string(), updated);
+        let rebuilt = build(&patches);
+
+        let ids = |stream: &DiffStream| {
+            stream
+                .row_metadata
+                .iter()
+                .map(|meta| meta.stable_id)
+                .collect::<Vec<_>>()
+        };
+        let texts = |stream: &DiffStream| {
+            stream
+                .rows
+                .iter()
+                .map(|row| (row.left.text.clone(), row.right.text.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&stream), ids(&rebuilt));
+        assert_eq!(texts(&stream), texts(&rebuilt));
+        assert_eq!(stream.row_segments.len(), rebuilt.rows.len());
+        assert_eq!(
+            line_stats_from_patch(patches["b.rs"].as_str()),
+            LineStats {
+                added: 3,
+                removed: 1,
+            }
+        );
+
+        let unknown = ChangedFile {
+            path: "missing.rs".to_string(),
+            ..files[0].clone()
+        };
+        assert!(!replace_file_rows_in_diff_stream(
+            &mut stream,
+            &unknown,
+            "",
+            false,
+            &HunkFolds::default()
+        ));
+    }
+
+    #[test]
+    fn hidden_characters_on_added_lines_get_a_file_warning_banner() {
+        let file = ChangedFile {
+            path: "src/auth.rs".to_string(),
+            status: FileStatus::Modified,
+            staged: false,
+            unstaged: true,
+            untracked: false,
+        };
+        let patch = "diff --git a/src/auth.rs b/src/auth.rs\n--- a/src/auth.rs\n+++ b/src/auth.rs\n\
+                     @@ -1,2 +1,2 @@\n keep\n-old\u{200b}\n+new \u{202e}x\n";
+        let stream = build_diff_stream_from_patch_map(
+            std::slice::from_ref(&file),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &HunkFolds::default(),
+            &BTreeMap::new(),
+            &BTreeMap::from([(file.path.clone(), patch.to_string())]),
+            &BTreeSet::new(),
+        );
+
+        let warnings = stream
+            .row_metadata
+            .iter()
+            .zip(&stream.rows)
+            .filter(|(meta, _)| meta.kind == DiffStreamRowKind::FileWarning)
+            .map(|(_, row)| row.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            vec!["1 hidden or confusable character added: line 2 U+202E right-to-left override"]
+        );
+        assert_eq!(stream.row_metadata[1].kind, DiffStreamRowKind::FileWarning);
+    }
+
+    #[test]
+    fn stable_row_id_is_deterministic_for_same_row() {
+        let first = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreCode, 2);
+        let second = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreCode, 2);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn stable_row_id_changes_when_ordinal_changes() {
+        let first = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreMeta, 0);
+        let second = compute_stable_row_id(Some("src/lib.rs"), DiffStreamRowKind::CoreMeta, 1);
+
+        assert_ne!(first, second);
+    }
+}
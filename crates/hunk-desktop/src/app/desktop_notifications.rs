@@ -0,0 +1,238 @@
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use hunk_domain::config::DesktopNotificationsConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DesktopNotificationEvent {
+    CiFinished,
+    RemoteBranchMoved,
+    CommentMention,
+}
+
+impl DesktopNotificationEvent {
+    pub(crate) fn enabled(self, config: &DesktopNotificationsConfig) -> bool {
+        config.enabled
+            && match self {
+                Self::CiFinished => config.ci_finished,
+                Self::RemoteBranchMoved => config.remote_branch_moved,
+                Self::CommentMention => config.comment_mentions,
+            }
+    }
+}
+
+/// Aggregate state of the CI runs triggered by one pushed commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CiRunsState {
+    Pending,
+    Passed {
+        url: Option<String>,
+    },
+    Failed {
+        url: Option<String>,
+        failed_runs: Vec<String>,
+    },
+}
+
+/// Returns whether `text` contains `@handle`. Matching ignores case and whitespace in the
+/// handle, so "Ada Lovelace" is mentioned by `@adalovelace`.
+pub(crate) fn text_mentions_handle(text: &str, handle: &str) -> bool {
+    let handle = mention_key(handle);
+    if handle.is_empty() {
+        return false;
+    }
+
+    text.match_indices('@').any(|(ix, _)| {
+        // Skip the `@` inside email addresses.
+        if text[..ix].chars().next_back().is_some_and(is_mention_char) {
+            return false;
+        }
+        let token = text[ix + 1..]
+            .chars()
+            .take_while(|ch| is_mention_char(*ch))
+            .collect::<String>();
+        mention_key(token.as_str()) == handle
+    })
+}
+
+fn is_mention_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '-' | '_' | '.')
+}
+
+fn mention_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Summarizes `gh run list --json name,status,conclusion,url` output.
+///
+/// An empty list is still pending because workflows can take a while to register a push.
+pub(crate) fn parse_github_runs(json: &str) -> Result<CiRunsState> {
+    let runs = serde_json::from_str::<serde_json::Value>(json)
+        .context("failed to parse GitHub Actions run list")?;
+    let runs = runs
+        .as_array()
+        .ok_or_else(|| anyhow!("GitHub Actions run list is not an array"))?;
+    if runs.is_empty() {
+        return Ok(CiRunsState::Pending);
+    }
+
+    let field = |run: &serde_json::Value, key: &str| {
+        run.get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    };
+    if runs
+        .iter()
+        .any(|run| field(run, "status").as_deref() != Some("completed"))
+    {
+        return Ok(CiRunsState::Pending);
+    }
+
+    let failed = runs
+        .iter()
+        .filter(|run| {
+            !matches!(
+                field(run, "conclusion").as_deref(),
+                Some("success" | "skipped" | "neutral")
+            )
+        })
+        .collect::<Vec<_>>();
+    if failed.is_empty() {
+        return Ok(CiRunsState::Passed {
+            url: runs.first().and_then(|run| field(run, "url")),
+        });
+    }
+
+    Ok(CiRunsState::Failed {
+        url: failed.first().and_then(|run| field(run, "url")),
+        failed_runs: failed
+            .iter()
+            .map(|run| field(run, "name").unwrap_or_else(|| "workflow".to_string()))
+            .collect(),
+    })
+}
+
+/// Queries GitHub Actions for `commit_id` on `branch_name` through the `gh` CLI.
+#[cfg_attr(test, allow(dead_code))]
+pub(crate) fn query_github_runs(
+    repo_root: &Path,
+    branch_name: &str,
+    commit_id: &str,
+) -> Result<CiRunsState> {
+    let output = std::process::Command::new("gh")
+        .current_dir(repo_root)
+        .args([
+            "run",
+            "list",
+            "--branch",
+            branch_name,
+            "--commit",
+            commit_id,
+            "--json",
+            "name,status,conclusion,url",
+        ])
+        .output()
+        .context("failed to launch the GitHub CLI (gh)")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "gh run list failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    parse_github_runs(String::from_utf8_lossy(&output.stdout).as_ref())
+}
+
+/// Shows an OS-native notification and returns `true` when the user clicked it.
+///
+/// Only Linux reports clicks, and it blocks until the notification is dismissed, so call this
+/// from a background task. Other platforms return `false` once the notification is posted.
+#[cfg_attr(test, allow(dead_code))]
+pub(crate) fn show_os_notification(title: &str, body: &str) -> Result<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        // Passing the text as script arguments avoids AppleScript string escaping.
+        let status = std::process::Command::new("osascript")
+            .args([
+                "-e",
+                "on run argv",
+                "-e",
+                "display notification (item 2 of argv) with title (item 1 of argv)",
+                "-e",
+                "end run",
+                title,
+                body,
+            ])
+            .status()
+            .context("failed to launch macOS notification helper")?;
+        if !status.success() {
+            return Err(anyhow!("osascript exited with {status}"));
+        }
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let output = std::process::Command::new("notify-send")
+            .args([
+                "--app-name=Hunk",
+                "--action=default=Open",
+                "--wait",
+                title,
+                body,
+            ])
+            .output()
+            .context("failed to launch notify-send")?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).trim() == "default");
+        }
+
+        // Older libnotify builds do not know `--action`; post without click-through instead.
+        let status = std::process::Command::new("notify-send")
+            .args(["--app-name=Hunk", title, body])
+            .status()
+            .context("failed to launch notify-send")?;
+        if !status.success() {
+            return Err(anyhow!("notify-send exited with {status}"));
+        }
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt as _;
+
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        const TOAST_SCRIPT: &str = "\
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+$template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02)
+$text = $template.GetElementsByTagName('text')
+$text.Item(0).AppendChild($template.CreateTextNode($env:HUNK_NOTIFICATION_TITLE)) | Out-Null
+$text.Item(1).AppendChild($template.CreateTextNode($env:HUNK_NOTIFICATION_BODY)) | Out-Null
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('Hunk').Show([Windows.UI.Notifications.ToastNotification]::new($template))";
+
+        let mut command = std::process::Command::new("powershell");
+        command
+            .args(["-NoProfile", "-NonInteractive", "-Command", TOAST_SCRIPT])
+            .env("HUNK_NOTIFICATION_TITLE", title)
+            .env("HUNK_NOTIFICATION_BODY", body)
+            .creation_flags(CREATE_NO_WINDOW);
+        let status = command
+            .status()
+            .context("failed to launch Windows notification helper")?;
+        if !status.success() {
+            return Err(anyhow!("powershell exited with {status}"));
+        }
+        return Ok(false);
+    }
+
+    #[allow(unreachable_code)]
+    Err(anyhow!(
+        "desktop notifications are not supported on this platform"
+    ))
+}
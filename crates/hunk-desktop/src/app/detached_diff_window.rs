@@ -0,0 +1,251 @@
+use std::ops::Range;
+
+use gpui::{
+    AnyElement, App, AppContext as _, Context, Entity, InteractiveElement as _, IntoElement,
+    ListAlignment, ListState, MouseButton, ParentElement as _, Render, Styled as _, Subscription,
+    TitlebarOptions, Window, WindowHandle, WindowOptions, div, list, prelude::FluentBuilder as _,
+    px,
+};
+use gpui_component::{ActiveTheme as _, Root, StyledExt as _, h_flex, v_flex};
+use hunk_domain::diff::{DiffCell, DiffCellKind, DiffRowKind};
+
+use crate::app::DiffViewer;
+use crate::app::theme::{hunk_blend, hunk_opacity, hunk_tone};
+
+const DETACHED_DIFF_ROW_HEIGHT: f32 = 22.0;
+const DETACHED_DIFF_LINE_NUMBER_WIDTH: f32 = 52.0;
+
+/// Opens a second OS window that shows the selected file's diff at full height, so the main
+/// window can keep the tree and graph on screen.
+pub(crate) fn open_detached_diff_window(
+    viewer: Entity<DiffViewer>,
+    cx: &mut App,
+) -> anyhow::Result<WindowHandle<Root>> {
+    let window_options = WindowOptions {
+        app_id: Some("hunk_desktop".into()),
+        titlebar: Some(TitlebarOptions {
+            title: Some("Hunk Diff".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    cx.open_window(window_options, |window, cx| {
+        let view = cx.new(|cx| DetachedDiffWindow::new(viewer, cx));
+        cx.new(|cx| Root::new(view, window, cx))
+    })
+}
+
+/// Mirrors the main window's review surface for one file. The window owns no diff state of its
+/// own: it re-reads the viewer whenever it notifies, and clicks select rows in the viewer.
+pub(crate) struct DetachedDiffWindow {
+    viewer: Entity<DiffViewer>,
+    list_state: ListState,
+    file_rows: Option<(String, Range<usize>)>,
+    revealed_row: Option<usize>,
+    _viewer_subscription: Subscription,
+}
+
+impl DetachedDiffWindow {
+    fn new(viewer: Entity<DiffViewer>, cx: &mut Context<Self>) -> Self {
+        let viewer_subscription = cx.observe(&viewer, |_, _, cx| cx.notify());
+        Self {
+            viewer,
+            list_state: ListState::new(0, ListAlignment::Top, px(DETACHED_DIFF_ROW_HEIGHT)),
+            file_rows: None,
+            revealed_row: None,
+            _viewer_subscription: viewer_subscription,
+        }
+    }
+
+    /// Picks up a new selected file or a refreshed stream, and scrolls a selection made in the
+    /// main window into view.
+    fn sync_with_viewer(&mut self, cx: &App) {
+        let viewer = self.viewer.read(cx);
+        let file_rows = viewer.selected_path.as_ref().and_then(|path| {
+            let range = viewer
+                .review_workspace_session
+                .as_ref()?
+                .file_range_for_path(path)?;
+            Some((path.clone(), range.start_row..range.end_row))
+        });
+        if file_rows != self.file_rows {
+            let row_count = file_rows.as_ref().map_or(0, |(_, rows)| rows.len());
+            self.list_state.reset(row_count);
+            self.file_rows = file_rows;
+            self.revealed_row = None;
+        }
+
+        let head_row = viewer.review_surface.selection_head_row;
+        if head_row == self.revealed_row {
+            return;
+        }
+        self.revealed_row = head_row;
+        if let (Some(head_row), Some((_, rows))) = (head_row, self.file_rows.as_ref())
+            && rows.contains(&head_row)
+        {
+            self.list_state.scroll_to_reveal_item(head_row - rows.start);
+        }
+    }
+
+    fn render_row(&self, ix: usize, cx: &App) -> AnyElement {
+        let Some((_, rows)) = self.file_rows.as_ref() else {
+            return div().into_any_element();
+        };
+        let row_ix = rows.start + ix;
+        let viewer = self.viewer.read(cx);
+        let Some(row) = viewer
+            .review_workspace_session
+            .as_ref()
+            .and_then(|session| session.row(row_ix))
+        else {
+            return div().into_any_element();
+        };
+        let is_selected = viewer.is_row_selected(row_ix);
+        let font_size = viewer.diff_code_font_size();
+        let theme = cx.theme();
+        let is_dark = theme.mode.is_dark();
+
+        let content = match row.kind {
+            DiffRowKind::Code => h_flex()
+                .size_full()
+                .child(detached_diff_cell(&row.left, is_selected, cx))
+                .child(div().w(px(1.0)).h_full().bg(theme.border))
+                .child(detached_diff_cell(&row.right, is_selected, cx))
+                .into_any_element(),
+            DiffRowKind::HunkHeader | DiffRowKind::Meta | DiffRowKind::Empty => div()
+                .size_full()
+                .px_3()
+                .flex()
+                .items_center()
+                .bg(if is_selected {
+                    hunk_opacity(theme.primary, is_dark, 0.34, 0.18)
+                } else {
+                    hunk_opacity(theme.muted, is_dark, 0.26, 0.40)
+                })
+                .text_color(theme.muted_foreground)
+                .child(row.text.clone())
+                .into_any_element(),
+        };
+
+        let viewer = self.viewer.clone();
+        h_flex()
+            .w_full()
+            .h(px(DETACHED_DIFF_ROW_HEIGHT))
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .font_family(theme.mono_font_family.clone())
+            .text_size(font_size)
+            .on_mouse_down(MouseButton::Left, move |event, _, cx| {
+                viewer.update(cx, |this, cx| {
+                    this.select_row_and_scroll(row_ix, event.modifiers.shift, cx);
+                });
+            })
+            .child(content)
+            .into_any_element()
+    }
+}
+
+impl Render for DetachedDiffWindow {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.sync_with_viewer(cx);
+        let title = self
+            .file_rows
+            .as_ref()
+            .map(|(path, _)| path.clone())
+            .unwrap_or_else(|| "No file selected".to_string());
+        let has_rows = self
+            .file_rows
+            .as_ref()
+            .is_some_and(|(_, rows)| !rows.is_empty());
+
+        let body = if has_rows {
+            list(
+                self.list_state.clone(),
+                cx.processor(|this, ix: usize, _window, cx| this.render_row(ix, cx)),
+            )
+            .size_full()
+            .into_any_element()
+        } else {
+            div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child("Select a changed file in the main window to show its diff here.")
+                .into_any_element()
+        };
+
+        let theme = cx.theme();
+
+        v_flex()
+            .size_full()
+            .bg(theme.background)
+            .text_color(theme.foreground)
+            .child(
+                h_flex()
+                    .w_full()
+                    .flex_none()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .truncate()
+                            .text_sm()
+                            .font_semibold()
+                            .child(title),
+                    )
+                    .child(
+                        div()
+                            .flex_none()
+                            .text_xs()
+                            .text_color(theme.muted_foreground)
+                            .child("Follows the main window selection"),
+                    ),
+            )
+            .child(div().flex_1().min_h_0().child(body))
+    }
+}
+
+fn detached_diff_cell(cell: &DiffCell, is_selected: bool, cx: &App) -> impl IntoElement {
+    let theme = cx.theme();
+    let is_dark = theme.mode.is_dark();
+    let mut background = match cell.kind {
+        DiffCellKind::Added => hunk_blend(theme.background, theme.success, is_dark, 0.24, 0.11),
+        DiffCellKind::Removed => hunk_blend(theme.background, theme.danger, is_dark, 0.24, 0.11),
+        DiffCellKind::Context | DiffCellKind::None => theme.background,
+    };
+    if is_selected {
+        background = hunk_blend(background, theme.primary, is_dark, 0.22, 0.13);
+    }
+
+    h_flex()
+        .flex_1()
+        .min_w_0()
+        .h_full()
+        .bg(background)
+        .when(cell.kind == DiffCellKind::None, |this| this.opacity(0.6))
+        .child(
+            div()
+                .w(px(DETACHED_DIFF_LINE_NUMBER_WIDTH))
+                .flex_none()
+                .pr_2()
+                .text_right()
+                .text_color(hunk_tone(theme.muted_foreground, is_dark, 0.18, 0.12))
+                .child(cell.line.map(|line| line.to_string()).unwrap_or_default()),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .child(cell.text.clone()),
+        )
+}
@@ -0,0 +1,164 @@
+use std::ops::Range;
+
+use hunk_git::tickets::TicketLinker;
+
+/// What a link inside a diff line opens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum DiffTextLinkTarget {
+    Url(String),
+    Ticket(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DiffTextLink {
+    /// Character columns of the link in the line.
+    pub columns: Range<usize>,
+    pub target: DiffTextLinkTarget,
+}
+
+/// URLs in `text`, plus ticket ids the linker has a URL for, in column order. Ticket ids inside a
+/// URL belong to the URL.
+pub(crate) fn diff_text_links(
+    text: &str,
+    ticket_linker: Option<&TicketLinker>,
+) -> Vec<DiffTextLink> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let mut byte_links = url_ranges(text)
+        .into_iter()
+        .map(|range| {
+            let target = DiffTextLinkTarget::Url(text[range.clone()].to_string());
+            (range, target)
+        })
+        .collect::<Vec<_>>();
+    if let Some(linker) = ticket_linker {
+        for ticket in linker.find_tickets(text) {
+            if linker.ticket_url(ticket.id.as_str()).is_none()
+                || byte_links.iter().any(|(range, _)| {
+                    range.start < ticket.range.end && ticket.range.start < range.end
+                })
+            {
+                continue;
+            }
+            byte_links.push((ticket.range, DiffTextLinkTarget::Ticket(ticket.id)));
+        }
+    }
+    if byte_links.is_empty() {
+        return Vec::new();
+    }
+    byte_links.sort_by_key(|(range, _)| range.start);
+
+    byte_links
+        .into_iter()
+        .map(|(range, target)| DiffTextLink {
+            columns: text[..range.start].chars().count()..text[..range.end].chars().count(),
+            target,
+        })
+        .collect()
+}
+
+pub(crate) fn diff_text_link_at_column(
+    links: &[DiffTextLink],
+    column: usize,
+) -> Option<&DiffTextLink> {
+    links
+        .iter()
+        .find(|link| link.columns.start <= column && column < link.columns.end)
+}
+
+fn url_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut search_from = 0;
+    while let Some(start) = next_url_start(text, search_from) {
+        let end = text[start..]
+            .find(|ch: char| ch.is_whitespace() || matches!(ch, '"' | '\'' | '`' | '<' | '>'))
+            .map_or(text.len(), |offset| start + offset);
+        let end = start + trim_url_end(&text[start..end]).len();
+        if text[start..end].contains("://") && !text[start..end].ends_with("://") {
+            ranges.push(start..end);
+        }
+        search_from = end.max(start + 1);
+    }
+    ranges
+}
+
+fn next_url_start(text: &str, from: usize) -> Option<usize> {
+    ["https://", "http://"]
+        .into_iter()
+        .filter_map(|scheme| text[from..].find(scheme).map(|offset| from + offset))
+        .min()
+}
+
+/// Drops trailing punctuation that ends the sentence or code around a URL, keeping closing
+/// brackets the URL itself opened, as in wiki links.
+fn trim_url_end(mut url: &str) -> &str {
+    loop {
+        let Some(last) = url.chars().last() else {
+            return url;
+        };
+        let opener = match last {
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            '.' | ',' | ';' | ':' | '!' | '?' => {
+                url = &url[..url.len() - 1];
+                continue;
+            }
+            _ => return url,
+        };
+        if url.matches(opener).count() >= url.matches(last).count() {
+            return url;
+        }
+        url = &url[..url.len() - 1];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffTextLinkTarget, diff_text_link_at_column, diff_text_links};
+    use hunk_git::tickets::TicketLinker;
+
+    #[test]
+    fn diff_text_links_find_urls_without_surrounding_punctuation() {
+        let links = diff_text_links(
+            "// See (https://example.com/docs?id=1). And https://example.com/w/Rust_(lang),",
+            None,
+        );
+        assert_eq!(
+            links
+                .iter()
+                .map(|link| link.target.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                DiffTextLinkTarget::Url("https://example.com/docs?id=1".to_string()),
+                DiffTextLinkTarget::Url("https://example.com/w/Rust_(lang)".to_string()),
+            ]
+        );
+        assert_eq!(links[0].columns, 8..37);
+        assert!(diff_text_links("let scheme = \"https://\";", None).is_empty());
+    }
+
+    #[test]
+    fn diff_text_links_only_link_tickets_with_a_url_and_count_columns_in_chars() {
+        let linker = TicketLinker::new(&[], "https://tracker.example/{ticket}").expect("linker");
+        let links = diff_text_links("// é ABC-12 https://tracker.example/ABC-13", Some(&linker));
+        assert_eq!(links.len(), 2);
+        assert_eq!(
+            links[0].target,
+            DiffTextLinkTarget::Ticket("ABC-12".to_string())
+        );
+        assert_eq!(links[0].columns, 5..11);
+        assert_eq!(
+            diff_text_link_at_column(&links, 12).map(|link| &link.target),
+            Some(&DiffTextLinkTarget::Url(
+                "https://tracker.example/ABC-13".to_string()
+            ))
+        );
+        assert_eq!(diff_text_link_at_column(&links, 11), None);
+
+        let unlinked = TicketLinker::new(&[], "").expect("linker");
+        assert!(diff_text_links("// ABC-12", Some(&unlinked)).is_empty());
+    }
+}
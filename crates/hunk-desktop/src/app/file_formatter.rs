@@ -0,0 +1,172 @@
+use std::io::{Read, Write as _};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use hunk_domain::config::FormatterCommand;
+
+const MAX_ERROR_LINES: usize = 6;
+/// How long a formatter may run before it is stopped, so a hung one cannot hold up saving.
+pub(crate) const FORMATTER_TIMEOUT: Duration = Duration::from_secs(30);
+const FORMATTER_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Why a formatter produced no output to apply. `line` is the zero-based source line the
+/// formatter blamed, when its message names one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FormatterError {
+    pub(crate) message: String,
+    pub(crate) line: Option<usize>,
+}
+
+/// Pipes `text`, the contents of `path`, through `formatter` and returns its output. The
+/// formatter is killed when it runs longer than `timeout`.
+pub(crate) fn run_formatter(
+    formatter: &FormatterCommand,
+    repo_root: &Path,
+    path: &Path,
+    text: &str,
+    timeout: Duration,
+) -> Result<String, FormatterError> {
+    let program = formatter.program.trim();
+    let path = path.to_string_lossy();
+    let args = formatter
+        .args
+        .iter()
+        .map(|arg| arg.replace("{path}", &path));
+    let mut child = Command::new(program)
+        .current_dir(repo_root)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| FormatterError {
+            message: format!("Failed to launch {program}: {err}"),
+            line: None,
+        })?;
+
+    // Written and read from other threads so a formatter that fills its output pipe before
+    // reading all of its input cannot deadlock, and so the wait below can give up.
+    let writer = child.stdin.take().map(|mut stdin| {
+        let input = text.to_string();
+        thread::spawn(move || stdin.write_all(input.as_bytes()))
+    });
+    let stdout = child.stdout.take().map(read_pipe);
+    let stderr = child.stderr.take().map(read_pipe);
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(FormatterError {
+                    message: format!(
+                        "{program} did not finish within {} seconds and was stopped.",
+                        timeout.as_secs()
+                    ),
+                    line: None,
+                });
+            }
+            Ok(None) => thread::sleep(FORMATTER_POLL_INTERVAL),
+            Err(err) => {
+                let _ = child.kill();
+                return Err(FormatterError {
+                    message: format!("{program} did not finish: {err}"),
+                    line: None,
+                });
+            }
+        }
+    };
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    let stdout = stdout.map(join_pipe).unwrap_or_default();
+    let stderr = stderr.map(join_pipe).unwrap_or_default();
+
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        let stdout = String::from_utf8_lossy(&stdout);
+        let details = if stderr.trim().is_empty() {
+            stdout
+        } else {
+            stderr
+        };
+        return Err(formatter_error(program, details.as_ref()));
+    }
+    let formatted = String::from_utf8(stdout).map_err(|_| FormatterError {
+        message: format!("{program} printed output that is not UTF-8."),
+        line: None,
+    })?;
+    // An empty result for a non-empty file is a formatter misconfigured to write elsewhere,
+    // not a request to clear the file.
+    if formatted.trim().is_empty() && !text.trim().is_empty() {
+        return Err(FormatterError {
+            message: format!("{program} printed no output. Configure it to write to stdout."),
+            line: None,
+        });
+    }
+    Ok(formatted)
+}
+
+fn read_pipe(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+fn join_pipe(reader: JoinHandle<Vec<u8>>) -> Vec<u8> {
+    reader.join().unwrap_or_default()
+}
+
+/// Builds the error for a failed formatter run from the start of its diagnostic output.
+pub(crate) fn formatter_error(program: &str, output: &str) -> FormatterError {
+    let details = output
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .take(MAX_ERROR_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+    FormatterError {
+        line: formatter_error_line(output),
+        message: if details.is_empty() {
+            format!("{program} failed.")
+        } else {
+            format!("{program} failed: {details}")
+        },
+    }
+}
+
+/// Finds the first `line:column` location in formatter output, as rustfmt (`<stdin>:3:5`),
+/// prettier (`(3:5)`), and black (`Cannot parse: 3:5`) print them, as a zero-based line.
+pub(crate) fn formatter_error_line(output: &str) -> Option<usize> {
+    output.lines().find_map(|line| {
+        line.char_indices().find_map(|(start, ch)| {
+            let preceded_by_digit = line[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|previous| previous.is_ascii_digit());
+            if !ch.is_ascii_digit() || preceded_by_digit {
+                return None;
+            }
+            let rest = &line[start..];
+            let line_end = rest
+                .find(|ch: char| !ch.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let column = rest[line_end..].strip_prefix(':')?;
+            if !column.starts_with(|ch: char| ch.is_ascii_digit()) {
+                return None;
+            }
+            rest[..line_end]
+                .parse::<usize>()
+                .ok()
+                .filter(|line| *line > 0)
+                .map(|line| line - 1)
+        })
+    })
+}
@@ -0,0 +1,295 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use hunk_domain::comment_import::{ImportedComment, parse_github_review_comments};
+use hunk_domain::config::{ReviewProviderKind, ReviewProviderMapping};
+use hunk_domain::db::{CommentLineSide, CommentRecord};
+use hunk_git::branch::{ReviewRemote, review_remote_for_branch};
+use hunk_git::git::load_workflow_snapshot_with_fingerprint;
+use serde_json::Value;
+
+/// The open pull request of a branch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct GithubPullRequest {
+    /// `owner/repo` the pull request was opened in, which is the parent of a fork.
+    pub repository: String,
+    pub number: u64,
+    pub head_commit: String,
+}
+
+/// What publishing local comments to a pull request did.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct GithubPublishSummary {
+    pub published: usize,
+    /// Already on the pull request with the same text.
+    pub already_present: usize,
+    /// File comments and lines GitHub rejected, usually because they are not part of the
+    /// pull request's diff.
+    pub rejected: usize,
+}
+
+/// Talks to GitHub through the GitHub CLI (`gh`), which also handles authentication.
+pub(crate) struct GithubReviewClient {
+    repo_root: PathBuf,
+    remote: ReviewRemote,
+}
+
+/// The GitHub repository and open pull request `branch_name` is reviewed in.
+pub(crate) fn github_pull_request_for_branch(
+    repo_root: &Path,
+    branch_name: &str,
+    provider_mappings: &[ReviewProviderMapping],
+) -> Result<(GithubReviewClient, GithubPullRequest)> {
+    let remote = review_remote_for_branch(repo_root, branch_name, provider_mappings)?
+        .ok_or_else(|| anyhow!("the branch's remote is not a known GitHub host"))?;
+    let client = GithubReviewClient::new(repo_root, remote)
+        .ok_or_else(|| anyhow!("only GitHub pull requests are supported"))?;
+    let pull_request = client
+        .pull_request_for_branch(branch_name)?
+        .ok_or_else(|| anyhow!("no open pull request for {branch_name}"))?;
+    Ok((client, pull_request))
+}
+
+impl GithubReviewClient {
+    /// `None` when the remote is not a GitHub host.
+    fn new(repo_root: &Path, remote: ReviewRemote) -> Option<Self> {
+        (remote.provider == ReviewProviderKind::GitHub).then(|| Self {
+            repo_root: repo_root.to_path_buf(),
+            remote,
+        })
+    }
+
+    /// The branch is pushed to `self.remote`, so its owner is the pull request's head owner.
+    /// A branch pushed to a fork has its pull request in the fork's parent repository.
+    fn pull_request_for_branch(&self, branch_name: &str) -> Result<Option<GithubPullRequest>> {
+        let owner = self.remote.repository.split('/').next().unwrap_or_default();
+        let head = format!("{owner}:{branch_name}");
+        if let Some(pull_request) =
+            self.open_pull_request(self.remote.repository.as_str(), head.as_str())?
+        {
+            return Ok(Some(pull_request));
+        }
+        let output = self.api(&[format!("repos/{}", self.remote.repository).as_str()])?;
+        match parse_github_parent_repository(output.as_str())? {
+            Some(parent) => self.open_pull_request(parent.as_str(), head.as_str()),
+            None => Ok(None),
+        }
+    }
+
+    fn open_pull_request(&self, repository: &str, head: &str) -> Result<Option<GithubPullRequest>> {
+        let output = self.api(&[
+            "--method",
+            "GET",
+            format!("repos/{repository}/pulls").as_str(),
+            "-f",
+            format!("head={head}").as_str(),
+            "-f",
+            "state=open",
+        ])?;
+        parse_github_pull_request(output.as_str(), repository)
+    }
+
+    pub(crate) fn review_comments(
+        &self,
+        pull_request: &GithubPullRequest,
+    ) -> Result<Vec<ImportedComment>> {
+        let output = self.api(&[
+            "--paginate",
+            "--jq",
+            ".[]",
+            format!(
+                "repos/{}/pulls/{}/comments",
+                pull_request.repository, pull_request.number
+            )
+            .as_str(),
+        ])?;
+        parse_github_review_comments(output.as_str())
+    }
+
+    /// Posts each comment on its line of the pull request's head commit, skipping comments the
+    /// pull request already has.
+    pub(crate) fn publish_comments(
+        &self,
+        pull_request: &GithubPullRequest,
+        comments: &[CommentRecord],
+    ) -> Result<GithubPublishSummary> {
+        let existing = self.review_comments(pull_request)?;
+        let endpoint = format!(
+            "repos/{}/pulls/{}/comments",
+            pull_request.repository, pull_request.number
+        );
+        let mut summary = GithubPublishSummary::default();
+        for comment in comments {
+            let Some((side, line)) = github_comment_position(comment) else {
+                summary.rejected += 1;
+                continue;
+            };
+            if existing
+                .iter()
+                .any(|entry| github_comment_matches(entry, comment))
+            {
+                summary.already_present += 1;
+                continue;
+            }
+
+            let result = self.api(&[
+                "--method",
+                "POST",
+                endpoint.as_str(),
+                "-f",
+                format!("body={}", comment.comment_text).as_str(),
+                "-f",
+                format!("commit_id={}", pull_request.head_commit).as_str(),
+                "-f",
+                format!("path={}", comment.file_path).as_str(),
+                "-F",
+                format!("line={line}").as_str(),
+                "-f",
+                format!("side={side}").as_str(),
+            ]);
+            match result {
+                Ok(_) => summary.published += 1,
+                // GitHub answers 422 for lines outside the pull request's diff.
+                Err(err) if format!("{err:#}").contains("HTTP 422") => summary.rejected += 1,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(summary)
+    }
+
+    fn api(&self, args: &[&str]) -> Result<String> {
+        let mut command = Command::new("gh");
+        command
+            .current_dir(self.repo_root.as_path())
+            .args(["api", "--hostname", self.remote.host.as_str()])
+            .args(args);
+        let output = command
+            .output()
+            .context("failed to launch the GitHub CLI (gh)")?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "gh api failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Local comments carry line numbers from the working copy, while GitHub places them on the
+/// pull request's head commit. Both only agree when the working copy is at that commit and
+/// the commented files have no uncommitted changes.
+pub(crate) fn ensure_working_copy_matches_pull_request(
+    working_copy_root: &Path,
+    pull_request: &GithubPullRequest,
+    comments: &[CommentRecord],
+) -> Result<()> {
+    let (fingerprint, snapshot) = load_workflow_snapshot_with_fingerprint(working_copy_root)?;
+    if fingerprint.head_commit_id() != Some(pull_request.head_commit.as_str()) {
+        bail!(
+            "the checked out commit is not the head of pull request #{}; push or pull first",
+            pull_request.number
+        );
+    }
+    if let Some(file) = snapshot.files.iter().find(|file| {
+        comments
+            .iter()
+            .any(|comment| comment.file_path == file.path)
+    }) {
+        bail!(
+            "{} has uncommitted changes, so its comments would land on the wrong lines",
+            file.path
+        );
+    }
+    Ok(())
+}
+
+/// Whether `entry`, read from GitHub, is the same note as the local `comment`.
+pub(crate) fn github_comment_matches(entry: &ImportedComment, comment: &CommentRecord) -> bool {
+    let line = match entry.line_side {
+        CommentLineSide::Left => comment.old_line,
+        _ => comment.new_line,
+    };
+    entry.file_path == comment.file_path
+        && entry.line.is_some()
+        && entry.line == line
+        && entry.text.trim() == comment.comment_text.trim()
+}
+
+fn github_comment_position(comment: &CommentRecord) -> Option<(&'static str, u32)> {
+    match comment.line_side {
+        CommentLineSide::Left => comment.old_line.map(|line| ("LEFT", line)),
+        _ => comment
+            .new_line
+            .map(|line| ("RIGHT", line))
+            .or_else(|| comment.old_line.map(|line| ("LEFT", line))),
+    }
+}
+
+/// The `owner/repo` a repository was forked from, or `None` when it is not a fork.
+fn parse_github_parent_repository(output: &str) -> Result<Option<String>> {
+    let value: Value = serde_json::from_str(output.trim())
+        .map_err(|err| anyhow!("invalid GitHub repository: {err}"))?;
+    Ok(value
+        .get("parent")
+        .and_then(|parent| parent.get("full_name"))
+        .and_then(Value::as_str)
+        .map(str::to_string))
+}
+
+fn parse_github_pull_request(output: &str, repository: &str) -> Result<Option<GithubPullRequest>> {
+    let value: Value = serde_json::from_str(output.trim())
+        .map_err(|err| anyhow!("invalid GitHub pull request list: {err}"))?;
+    let Some(pull_request) = value.as_array().and_then(|pulls| pulls.first()) else {
+        return Ok(None);
+    };
+    let field = |path: &[&str]| {
+        path.iter()
+            .try_fold(pull_request, |value, key| value.get(*key))
+            .cloned()
+    };
+
+    Ok(Some(GithubPullRequest {
+        repository: repository.to_string(),
+        number: field(&["number"])
+            .and_then(|number| number.as_u64())
+            .ok_or_else(|| anyhow!("GitHub pull request has no number"))?,
+        head_commit: field(&["head", "sha"])
+            .and_then(|sha| sha.as_str().map(str::to_string))
+            .ok_or_else(|| anyhow!("GitHub pull request has no head commit"))?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GithubPullRequest, parse_github_parent_repository, parse_github_pull_request};
+
+    #[test]
+    fn parse_github_pull_request_reads_the_first_open_pull_request() {
+        let output = r#"[{"number":42,"html_url":"https://github.com/o/r/pull/42","head":{"sha":"abc123"}}]"#;
+        assert_eq!(
+            parse_github_pull_request(output, "o/r").expect("parse"),
+            Some(GithubPullRequest {
+                repository: "o/r".to_string(),
+                number: 42,
+                head_commit: "abc123".to_string(),
+            })
+        );
+        assert_eq!(parse_github_pull_request("[]", "o/r").expect("parse"), None);
+        assert!(parse_github_pull_request("[{}]", "o/r").is_err());
+    }
+
+    #[test]
+    fn parse_github_parent_repository_reads_the_fork_parent() {
+        let fork = r#"{"full_name":"me/r","fork":true,"parent":{"full_name":"o/r"}}"#;
+        assert_eq!(
+            parse_github_parent_repository(fork)
+                .expect("parse")
+                .as_deref(),
+            Some("o/r")
+        );
+        let source = r#"{"full_name":"o/r","fork":false}"#;
+        assert_eq!(parse_github_parent_repository(source).expect("parse"), None);
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+
+use hunk_domain::l10n::{Language, Localizer};
+use tracing::error;
+
+static LOCALIZER: LazyLock<RwLock<Arc<Localizer>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(Localizer::default())));
+
+/// Switches the language every later [`tr`] lookup uses. The catalog is process-wide so render
+/// helpers without a view can translate too.
+pub(crate) fn set_language(language: Language) {
+    let localizer = Localizer::new(language).unwrap_or_else(|err| {
+        error!(
+            "failed to load the {} message catalog: {err:#}",
+            language.code()
+        );
+        Localizer::default()
+    });
+    *LOCALIZER.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(localizer);
+}
+
+fn localizer() -> Arc<Localizer> {
+    LOCALIZER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// The message for `key` in the current language.
+pub(crate) fn tr(key: &str) -> String {
+    localizer().text(key)
+}
+
+/// The message for `key` with its `{ $name }` placeholders filled from `args`.
+pub(crate) fn tr_args(key: &str, args: &[(&str, &str)]) -> String {
+    localizer().format(key, args)
+}
@@ -0,0 +1,58 @@
+/// Options parsed from the command line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LaunchOptions {
+    /// `--read-only`: disables every action that writes to a repository, for every project.
+    pub read_only: bool,
+}
+
+pub fn run(launch: LaunchOptions) -> Result<()> {
+    let app = gpui_platform::application().with_assets(HunkAssets);
+    let keyboard_shortcuts = load_keyboard_shortcuts();
+    app.on_reopen(move |cx: &mut App| {
+        if cx.windows().is_empty() {
+            open_main_window(launch, cx);
+        }
+        cx.activate(true);
+    });
+
+    app.run(move |cx| {
+        gpui_component::init(cx);
+        theme::install_hunk_themes(cx);
+        // Keep a global quit hook alive so tracked Codex hosts are cleaned up even if a
+        // particular view/runtime teardown path is bypassed during shutdown.
+        std::mem::forget(cx.on_app_quit(|_| async move {
+            hunk_codex::host::begin_host_shutdown();
+            hunk_codex::host::cleanup_tracked_hosts_for_shutdown();
+        }));
+        cx.on_action(quit_app);
+        bind_keyboard_shortcuts(cx, &keyboard_shortcuts);
+        install_application_menus(cx);
+        cx.activate(true);
+        open_main_window(launch, cx);
+    });
+
+    Ok(())
+}
+
+fn open_main_window(launch: LaunchOptions, cx: &mut App) {
+    let window_options = WindowOptions {
+        app_id: Some("hunk_desktop".into()),
+        titlebar: Some(TitlebarOptions {
+            title: Some("Hunk".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    if let Err(err) = cx.open_window(window_options, |window, cx| {
+        let view = cx.new(|cx| DiffViewer::new(launch, window, cx));
+        view.update(cx, |this, cx| this.defer_root_focus(cx));
+        cx.new(|cx| Root::new(view, window, cx))
+    }) {
+        error!("failed to open window: {err:#}");
+    }
+}
+
+fn quit_app(_: &QuitApp, cx: &mut App) {
+    cx.quit();
+}
@@ -4,6 +4,9 @@ use std::path::{Component, Path, PathBuf};
 use anyhow::Context as _;
 use hunk_domain::markdown_preview::MarkdownInlineSpan;
 
+const CLIPBOARD_PATH_MAX_LINES: usize = 40;
+const CLIPBOARD_PATH_MAX_TOKENS: usize = 400;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) struct MarkdownLinkRange {
     pub range: Range<usize>,
@@ -89,6 +92,37 @@ pub(crate) fn resolve_markdown_link_target(
         ))
 }
 
+/// Finds the first repository file mentioned in copied text, such as `src/lib.rs:12:5` on its
+/// own or inside a CI failure line, along with the line it points at.
+#[cfg_attr(test, allow(dead_code))]
+pub(crate) fn resolve_clipboard_path_target(
+    text: &str,
+    workspace_root: &Path,
+) -> Option<MarkdownWorkspaceFileLink> {
+    text.lines()
+        .take(CLIPBOARD_PATH_MAX_LINES)
+        .flat_map(str::split_whitespace)
+        .take(CLIPBOARD_PATH_MAX_TOKENS)
+        .map(|token| {
+            let token = token
+                .trim_start_matches(|ch: char| matches!(ch, '"' | '\'' | '`' | '(' | '[' | '<'))
+                .trim_end_matches(|ch: char| {
+                    matches!(
+                        ch,
+                        '"' | '\'' | '`' | ')' | ']' | '>' | ',' | ';' | ':' | '.'
+                    )
+                });
+            // pytest node ids: `tests/test_app.py::test_name`.
+            token.split_once("::").map_or(token, |(path, _)| path)
+        })
+        .find_map(
+            |token| match resolve_markdown_link_target(token, Some(workspace_root), None)? {
+                MarkdownLinkTarget::WorkspaceFile(link) => Some(link),
+                MarkdownLinkTarget::ExternalUrl(_) => None,
+            },
+        )
+}
+
 #[cfg_attr(test, allow(dead_code))]
 pub(crate) fn open_url_in_browser(url: &str) -> anyhow::Result<()> {
     #[cfg(target_os = "macos")]
@@ -289,7 +323,10 @@ fn pathbuf_to_workspace_relative(path: &Path) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{MarkdownLinkTarget, resolve_markdown_link_target, split_markdown_file_target};
+    use super::{
+        MarkdownLinkTarget, resolve_clipboard_path_target, resolve_markdown_link_target,
+        split_markdown_file_target,
+    };
 
     #[test]
     fn split_markdown_file_target_accepts_line_and_column_suffixes() {
@@ -317,4 +354,30 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn resolve_clipboard_path_target_finds_paths_inside_ci_output() {
+        let workspace = tempfile::tempdir().expect("tempdir");
+        for path in ["src/main.rs", "tests/test_app.py"] {
+            let file_path = workspace.path().join(path);
+            std::fs::create_dir_all(file_path.parent().expect("parent")).expect("create dir");
+            std::fs::write(&file_path, "\n").expect("write file");
+        }
+
+        let rust_error = "error[E0308]: mismatched types\n  --> src/main.rs:12:5\n";
+        let link = resolve_clipboard_path_target(rust_error, workspace.path()).expect("rust path");
+        assert_eq!(link.normalized_path, "src/main.rs");
+        assert_eq!(link.line, Some(12));
+
+        let pytest_failure = "FAILED tests/test_app.py::test_login - AssertionError";
+        let link =
+            resolve_clipboard_path_target(pytest_failure, workspace.path()).expect("pytest path");
+        assert_eq!(link.normalized_path, "tests/test_app.py");
+        assert_eq!(link.line, None);
+
+        assert_eq!(
+            resolve_clipboard_path_target("see src/missing.rs:3", workspace.path()),
+            None
+        );
+    }
 }
@@ -0,0 +1,120 @@
+/// Action labels shown in more than one menu and in the shortcut settings.
+const TOGGLE_FOCUS_MODE_LABEL: &str = "Toggle Focus Mode";
+const OPEN_PATH_FROM_CLIPBOARD_LABEL: &str = "Open Path from Clipboard";
+
+fn build_application_menus() -> Vec<Menu> {
+    if cfg!(target_os = "macos") {
+        vec![
+            Menu {
+                name: "Hunk".into(),
+                items: vec![
+                    MenuItem::action("About Hunk", AboutHunk),
+                    MenuItem::separator(),
+                    MenuItem::os_submenu("Services", SystemMenuType::Services),
+                    MenuItem::separator(),
+                    MenuItem::action("Settings...", OpenSettings),
+                    MenuItem::action("Diagnostics...", OpenDiagnostics),
+                    MenuItem::action("Repository Issues...", OpenRepositoryIssues),
+                    MenuItem::action("Compare Operations...", OpenOperationDiff),
+                    MenuItem::action("Operation History...", OpenOperationHistory),
+                    MenuItem::action("Edit Stack...", OpenStackEditor),
+                    MenuItem::action("Reset Workspace Layout", ResetWorkspaceLayout),
+                    MenuItem::action(TOGGLE_FOCUS_MODE_LABEL, ToggleFocusMode),
+                    MenuItem::action("Open Diff in New Window", OpenDetachedDiffWindow),
+                    MenuItem::action("Export Review to PDF...", ExportReviewPdf),
+                    MenuItem::action(
+                        "Export Review to PDF (Grayscale)...",
+                        ExportReviewPdfGrayscale,
+                    ),
+                    MenuItem::separator(),
+                    MenuItem::action("Quit Hunk", QuitApp),
+                ],
+            },
+            Menu {
+                name: "File".into(),
+                items: vec![
+                    MenuItem::action("Open Project...", OpenProject),
+                    MenuItem::action("Clone Repository...", CloneRepository),
+                    MenuItem::action("Quick Open...", QuickOpenFile),
+                    MenuItem::action(OPEN_PATH_FROM_CLIPBOARD_LABEL, OpenPathFromClipboard),
+                    MenuItem::action("Navigate CI Log...", OpenCiLogPanel),
+                    MenuItem::action("Search in Repository...", SearchRepository),
+                    MenuItem::action("Save File", SaveCurrentFile),
+                    MenuItem::separator(),
+                    MenuItem::action("About Hunk", AboutHunk),
+                    MenuItem::action("Settings...", OpenSettings),
+                    MenuItem::action("Diagnostics...", OpenDiagnostics),
+                    MenuItem::action("Repository Issues...", OpenRepositoryIssues),
+                    MenuItem::action("Compare Operations...", OpenOperationDiff),
+                    MenuItem::action("Operation History...", OpenOperationHistory),
+                    MenuItem::action("Edit Stack...", OpenStackEditor),
+                    MenuItem::action("Reset Workspace Layout", ResetWorkspaceLayout),
+                    MenuItem::action(TOGGLE_FOCUS_MODE_LABEL, ToggleFocusMode),
+                    MenuItem::action("Open Diff in New Window", OpenDetachedDiffWindow),
+                    MenuItem::action("Export Review to PDF...", ExportReviewPdf),
+                    MenuItem::action(
+                        "Export Review to PDF (Grayscale)...",
+                        ExportReviewPdfGrayscale,
+                    ),
+                ],
+            },
+            Menu {
+                name: "Edit".into(),
+                items: vec![
+                    MenuItem::os_action("Copy", CopySelection, OsAction::Copy),
+                    MenuItem::os_action("Select All", SelectAllDiffRows, OsAction::SelectAll),
+                ],
+            },
+        ]
+    } else {
+        vec![
+            Menu {
+                name: "File".into(),
+                items: vec![
+                    MenuItem::action("Open Project...", OpenProject),
+                    MenuItem::action("Clone Repository...", CloneRepository),
+                    MenuItem::action("Quick Open...", QuickOpenFile),
+                    MenuItem::action(OPEN_PATH_FROM_CLIPBOARD_LABEL, OpenPathFromClipboard),
+                    MenuItem::action("Navigate CI Log...", OpenCiLogPanel),
+                    MenuItem::action("Search in Repository...", SearchRepository),
+                    MenuItem::action("Save File", SaveCurrentFile),
+                    MenuItem::separator(),
+                    MenuItem::action("About Hunk", AboutHunk),
+                    MenuItem::action("Settings...", OpenSettings),
+                    MenuItem::action("Diagnostics...", OpenDiagnostics),
+                    MenuItem::action("Repository Issues...", OpenRepositoryIssues),
+                    MenuItem::action("Compare Operations...", OpenOperationDiff),
+                    MenuItem::action("Operation History...", OpenOperationHistory),
+                    MenuItem::action("Edit Stack...", OpenStackEditor),
+                    MenuItem::action("Reset Workspace Layout", ResetWorkspaceLayout),
+                    MenuItem::action(TOGGLE_FOCUS_MODE_LABEL, ToggleFocusMode),
+                    MenuItem::action("Open Diff in New Window", OpenDetachedDiffWindow),
+                    MenuItem::action("Export Review to PDF...", ExportReviewPdf),
+                    MenuItem::action(
+                        "Export Review to PDF (Grayscale)...",
+                        ExportReviewPdfGrayscale,
+                    ),
+                    MenuItem::separator(),
+                    MenuItem::action("Quit Hunk", QuitApp),
+                ],
+            },
+            Menu {
+                name: "Edit".into(),
+                items: vec![
+                    MenuItem::action("Copy", CopySelection),
+                    MenuItem::action("Select All", SelectAllDiffRows),
+                ],
+            },
+        ]
+    }
+}
+
+fn install_application_menus(cx: &mut App) {
+    cx.set_menus(build_application_menus());
+    GlobalState::global_mut(cx).set_app_menus(
+        build_application_menus()
+            .into_iter()
+            .map(|menu| menu.owned())
+            .collect(),
+    );
+}
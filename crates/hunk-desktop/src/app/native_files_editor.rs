@@ -91,6 +91,9 @@ pub(crate) struct FilesEditor {
     search_query: Option<String>,
     syntax_highlights: Vec<HighlightCapture>,
     manual_overlays: Vec<OverlayDescriptor>,
+    format_error: Option<OverlayDescriptor>,
+    line_change_overlays: Vec<OverlayDescriptor>,
+    line_changes_revision: Option<(BufferId, u64)>,
     visible_highlight_cache: Option<VisibleHighlightCache>,
     row_syntax_cache: Option<RowSyntaxSpanCache>,
     semantic_highlight_revision: u64,
@@ -230,6 +233,9 @@ impl FilesEditor {
             search_query: None,
             syntax_highlights: Vec::new(),
             manual_overlays: Vec::new(),
+            format_error: None,
+            line_change_overlays: Vec::new(),
+            line_changes_revision: None,
             visible_highlight_cache: None,
             row_syntax_cache: None,
             semantic_highlight_revision: 0,
@@ -253,6 +259,9 @@ impl FilesEditor {
         self.fold_candidates.clear();
         self.clear_syntax_highlights();
         self.manual_overlays.clear();
+        self.format_error = None;
+        self.line_change_overlays.clear();
+        self.line_changes_revision = None;
         self.visible_highlight_cache = None;
         self.row_syntax_cache = None;
         self.semantic_highlight_revision = 0;
@@ -290,10 +299,92 @@ impl FilesEditor {
         })
     }
 
+    pub(crate) fn cursor_line(&self) -> Option<usize> {
+        self.active_path()?;
+        Some(self.editor.status_snapshot().cursor_line)
+    }
+
+    /// Puts the caret at the start of zero-based `line`, scrolling it into view.
+    pub(crate) fn move_caret_to_line(&mut self, line: usize) -> bool {
+        if self.active_path().is_none() {
+            return false;
+        }
+        self.editor
+            .apply(EditorCommand::SetSelection(Selection::caret(
+                TextPosition::new(line, 0),
+            )))
+            .selection_changed
+    }
+
     pub(crate) fn mark_saved(&mut self) {
         self.editor.apply(EditorCommand::MarkSaved);
     }
 
+    /// The scope name of the open file's language, such as `rust`, which formatters are
+    /// configured by.
+    pub(crate) fn language_scope_name(&self) -> Option<String> {
+        let path = self.active_path()?;
+        self.registry
+            .language_for_path(path)
+            .map(|language| language.scope_name.clone())
+    }
+
+    pub(crate) fn apply_formatted_text(&mut self, formatted: &str) -> bool {
+        if self.active_path().is_none() {
+            return false;
+        }
+        self.apply_editor_command(EditorCommand::ApplyFormatting(formatted.to_string()))
+            .document_changed
+    }
+
+    pub(crate) fn format_error_message(&self) -> Option<&str> {
+        self.format_error.as_ref()?.message.as_deref()
+    }
+
+    /// Marks the line a formatter failed on, or clears the mark. The mark also goes away with
+    /// the next edit.
+    pub(crate) fn set_format_error(&mut self, error: Option<(usize, String)>) {
+        self.format_error = error.map(|(line, message)| OverlayDescriptor {
+            line,
+            kind: OverlayKind::DiagnosticError,
+            message: Some(message),
+        });
+        self.sync_overlays();
+    }
+
+    /// The buffer revision whose gutter change markers still need computing, or `None` when
+    /// the markers are current.
+    pub(crate) fn stale_line_changes_revision(&self) -> Option<(BufferId, u64)> {
+        self.active_path()?;
+        let snapshot = self.editor.buffer().snapshot();
+        let revision = (snapshot.buffer_id, snapshot.version);
+        (self.line_changes_revision != Some(revision)).then_some(revision)
+    }
+
+    pub(crate) fn invalidate_line_changes(&mut self) {
+        self.line_changes_revision = None;
+    }
+
+    /// Shows gutter change markers computed for `revision`. Markers for a buffer that has
+    /// changed since are dropped.
+    pub(crate) fn set_line_change_overlays(
+        &mut self,
+        revision: (BufferId, u64),
+        overlays: Vec<OverlayDescriptor>,
+    ) -> bool {
+        let snapshot = self.editor.buffer().snapshot();
+        if (snapshot.buffer_id, snapshot.version) != revision {
+            return false;
+        }
+        self.line_changes_revision = Some(revision);
+        if self.line_change_overlays == overlays {
+            return false;
+        }
+        self.line_change_overlays = overlays;
+        self.sync_overlays();
+        true
+    }
+
     pub(crate) fn copy_selection_text(&self) -> Option<String> {
         let mut clone = self.editor.clone();
         clone.apply(EditorCommand::CopySelection).copied_text
@@ -613,6 +704,9 @@ impl FilesEditor {
 
     fn apply_editor_command(&mut self, command: EditorCommand) -> hunk_editor::CommandOutput {
         let output = self.editor.apply(command);
+        if output.document_changed && self.format_error.take().is_some() {
+            self.sync_overlays();
+        }
         if output.document_changed
             && let Err(err) = self.refresh_syntax_state()
         {
@@ -640,6 +734,14 @@ impl FilesEditor {
             return Ok(());
         };
 
+        let editing_rules = self
+            .registry
+            .language_for_path(&path)
+            .map(|language| language.editing_rules())
+            .unwrap_or_default();
+        self.editor
+            .apply(EditorCommand::SetEditingRules(editing_rules));
+
         let source = self.editor.buffer().text();
         let syntax = self.syntax.parse_for_path(&self.registry, &path, &source)?;
         self.fold_candidates = self.syntax.fold_candidates(&self.registry, &source);
@@ -881,6 +983,8 @@ impl FilesEditor {
 
     fn sync_overlays(&mut self) {
         let mut overlays = self.manual_overlays.clone();
+        overlays.extend(self.line_change_overlays.iter().cloned());
+        overlays.extend(self.format_error.clone());
         overlays.extend(
             self.editor
                 .diagnostics()
@@ -53,7 +53,10 @@ impl FilesEditor {
                     .document_changed
             }
             "escape" => self.collapse_selection_to_head(),
-            "enter" => self.insert_newline_with_indent(),
+            "enter" => {
+                self.apply_editor_command(EditorCommand::InsertNewline)
+                    .document_changed
+            }
             "tab" if !keystroke.modifiers.control && !keystroke.modifiers.platform => {
                 self.insert_text("    ")
             }
@@ -312,6 +315,14 @@ impl FilesEditor {
         self.select_all()
     }
 
+    pub(crate) fn toggle_line_comment_action(&mut self) -> bool {
+        if self.active_path().is_none() {
+            return false;
+        }
+        self.apply_editor_command(EditorCommand::ToggleLineComment)
+            .document_changed
+    }
+
     fn insert_key_char(&mut self, keystroke: &Keystroke) -> bool {
         if keystroke.modifiers.control || keystroke.modifiers.platform {
             return false;
@@ -327,17 +338,6 @@ impl FilesEditor {
         self.insert_text(text)
     }
 
-    fn insert_newline_with_indent(&mut self) -> bool {
-        let selection = self.editor.selection();
-        let snapshot = self.editor.buffer().snapshot();
-        let line_text = current_line_text(&snapshot, selection.head.line);
-        let indent: String = line_text
-            .chars()
-            .take_while(|ch| matches!(ch, ' ' | '\t'))
-            .collect();
-        self.insert_text(format!("\n{indent}").as_str())
-    }
-
     fn insert_text(&mut self, text: &str) -> bool {
         self.apply_editor_command(EditorCommand::InsertText(text.to_string()))
             .document_changed
@@ -563,7 +563,8 @@ pub(super) fn paint_overlays(
 ) {
     for overlay in &row.overlays {
         let colors = palette.overlay_colors(overlay.kind);
-        if is_diff_overlay(overlay.kind) {
+        // Deleted lines are gone from the buffer, so only the gutter shows where they were.
+        if is_diff_overlay(overlay.kind) && overlay.kind != hunk_editor::OverlayKind::DiffDeletion {
             window.paint_quad(fill(
                 Bounds {
                     origin: point(row_origin.x, row_origin.y),
@@ -589,18 +590,25 @@ pub(super) fn paint_overlays(
             ));
         }
 
-        window.paint_quad(fill(
+        let marker_x = layout.hitbox.bounds.origin.x
+            + (layout.cell_width * layout.gutter_columns as f32)
+            - px(3.0);
+        let marker_bounds = if overlay.kind == hunk_editor::OverlayKind::DiffDeletion {
+            // A short tick on the row's bottom edge, where the deleted lines used to be.
             Bounds {
                 origin: point(
-                    layout.hitbox.bounds.origin.x
-                        + (layout.cell_width * layout.gutter_columns as f32)
-                        - px(3.0),
-                    row_origin.y + px(4.0),
+                    marker_x - px(4.0),
+                    row_origin.y + layout.line_height - px(1.5),
                 ),
+                size: size(px(6.0), px(3.0)),
+            }
+        } else {
+            Bounds {
+                origin: point(marker_x, row_origin.y + px(4.0)),
                 size: size(px(2.0), layout.line_height - px(8.0)),
-            },
-            colors.gutter_marker,
-        ));
+            }
+        };
+        window.paint_quad(fill(marker_bounds, colors.gutter_marker));
     }
 }
 
@@ -221,6 +221,9 @@ impl FilesEditor {
         self.visible_highlight_cache = None;
         self.row_syntax_cache = None;
         self.semantic_highlight_revision = self.semantic_highlight_revision.saturating_add(1);
+        // Change markers belong to the previous buffer until they are recomputed.
+        self.line_change_overlays.clear();
+        self.line_changes_revision = None;
         self.apply_path_defaults(path);
         self.refresh_syntax_state()?;
         if self.search_query.is_some() {
@@ -1,4 +1,4 @@
-use gpui::{ClipboardItem, SharedString};
+use gpui::{App, ClipboardItem, SharedString, Window};
 use gpui_component::{
     button::{Button, ButtonVariants as _},
     notification::Notification,
@@ -35,3 +35,49 @@ pub(crate) fn warning(message: impl Into<SharedString>) -> Notification {
     let message = message.into();
     with_copy_action(Notification::warning(message.clone()), message)
 }
+
+pub(crate) fn error_with_quick_fix(
+    message: impl Into<SharedString>,
+    label: impl Into<SharedString>,
+    on_quick_fix: impl Fn(&mut Window, &mut App) + 'static,
+) -> Notification {
+    let label = label.into();
+    let on_quick_fix = std::rc::Rc::new(on_quick_fix);
+    Notification::error(message.into())
+        .action(move |_, _, _| {
+            Button::new("quick-fix-notification")
+                .label(label.clone())
+                .ghost()
+                .on_click({
+                    let on_quick_fix = on_quick_fix.clone();
+                    move |_, window, cx| {
+                        cx.stop_propagation();
+                        on_quick_fix(window, cx);
+                    }
+                })
+        })
+        .autohide(false)
+}
+
+pub(crate) fn info_with_action(
+    message: impl Into<SharedString>,
+    label: impl Into<SharedString>,
+    on_action: impl Fn(&mut Window, &mut App) + 'static,
+) -> Notification {
+    let label = label.into();
+    let on_action = std::rc::Rc::new(on_action);
+    Notification::info(message.into())
+        .action(move |_, _, _| {
+            Button::new("open-notification-target")
+                .label(label.clone())
+                .ghost()
+                .on_click({
+                    let on_action = on_action.clone();
+                    move |_, window, cx| {
+                        cx.stop_propagation();
+                        on_action(window, cx);
+                    }
+                })
+        })
+        .autohide(true)
+}
@@ -0,0 +1,139 @@
+struct GitRecoveryPromptState {
+    action: GitRecoveryAction,
+    repo_root: PathBuf,
+    scope: GitConfigScope,
+    primary_input_state: Entity<InputState>,
+    secondary_input_state: Entity<InputState>,
+    saving: bool,
+    error_message: Option<String>,
+}
+
+struct RepoClonePromptState {
+    url_input_state: Entity<InputState>,
+    parent_input_state: Entity<InputState>,
+    cancel: CancellationToken,
+    cloning: bool,
+    progress: Option<String>,
+    error_message: Option<String>,
+}
+
+/// Copies one working-copy hunk onto another branch.
+struct HunkApplyPromptState {
+    path: String,
+    hunk: HunkSelector,
+    branch_input_state: Entity<InputState>,
+    message_input_state: Entity<InputState>,
+    mode: HunkApplyMode,
+    applying: bool,
+    error_message: Option<String>,
+}
+
+struct CommitMessageEditorState {
+    /// The commit being edited, or `None` until its message has loaded.
+    commit_id: Option<String>,
+    subject_input_state: Entity<InputState>,
+    body_input_state: Entity<InputState>,
+    reviewed_by_input_state: Entity<InputState>,
+    change_id_input_state: Entity<InputState>,
+    /// Trailers from the loaded message; the Reviewed-by and Change-Id fields replace theirs.
+    trailers: Vec<CommitTrailer>,
+    published_upstream: Option<String>,
+    saving: bool,
+    error_message: Option<String>,
+    _subscriptions: Vec<Subscription>,
+}
+
+struct RepoIssuesPanelState {
+    repo_root: PathBuf,
+    loading: bool,
+    issues: Vec<RepoIssue>,
+    resolving_ix: Option<usize>,
+    error_message: Option<String>,
+}
+
+/// A pasted CI log and the failing tests and locations found in it.
+struct CiLogPanelState {
+    log_input_state: Entity<InputState>,
+    entries: Vec<CiLogPanelEntry>,
+    scanned: bool,
+}
+
+struct CiLogPanelEntry {
+    reference: CiLogReference,
+    /// Points at a changed file or an added line of the current review.
+    in_change: bool,
+}
+
+/// Repository-wide text search and the results of its last finished query.
+struct RepoContentSearchPanelState {
+    query_input_state: Entity<InputState>,
+    /// Query `results` were found for.
+    query: String,
+    results: RepoContentSearchResults,
+    searching: bool,
+    /// Stops the search in flight once a newer query replaces it.
+    cancelled: Arc<AtomicBool>,
+    error_message: Option<String>,
+    /// Dropped along with the panel, so closing it abandons the pending search.
+    search_task: Task<()>,
+    _subscriptions: Vec<Subscription>,
+}
+
+/// Unsaved edits found for a file when it was opened, waiting for the user to restore or
+/// discard them.
+struct EditorRecoveryPrompt {
+    tab_id: usize,
+    buffer: RecoveredEditorBuffer,
+}
+
+struct CommitDetailsPanelState {
+    commit_id: String,
+    loading: bool,
+    details: Option<CommitDetails>,
+    error_message: Option<String>,
+}
+
+struct OperationDiffPanelState {
+    repo_root: PathBuf,
+    loading: bool,
+    operations: Vec<RepoOperation>,
+    before_ix: usize,
+    after_ix: usize,
+    diff: Option<OperationDiff>,
+    error_message: Option<String>,
+}
+
+struct OperationHistoryPanelState {
+    repo_root: PathBuf,
+    loading: bool,
+    restoring: bool,
+    operations: Vec<RepoOperation>,
+    selected_ix: usize,
+    /// What the selected operation changed, compared with the one before it.
+    diff: Option<OperationDiff>,
+    error_message: Option<String>,
+}
+
+struct StackEditorPanelState {
+    repo_root: PathBuf,
+    loading: bool,
+    applying: bool,
+    stack: Option<BranchStack>,
+    /// The edited stack, oldest commit first, in the order it will be rebuilt.
+    plan: Vec<StackPlanEntry>,
+    error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CoReviewPeer {
+    name: String,
+    presence: Presence,
+}
+
+struct CoReviewState {
+    session: CoReviewSession,
+    share_target: Option<String>,
+    peers: BTreeMap<String, CoReviewPeer>,
+    following: Option<String>,
+    last_sent_presence: Option<Presence>,
+}
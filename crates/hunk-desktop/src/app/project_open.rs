@@ -105,6 +105,119 @@ pub(crate) fn open_path_in_project_target(path: &Path, target: ProjectOpenTarget
     }
 }
 
+pub(crate) fn reveal_in_file_manager_label() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "Reveal in Finder"
+    } else if cfg!(target_os = "windows") {
+        "Reveal in Explorer"
+    } else {
+        "Reveal in Files"
+    }
+}
+
+/// Shows `path` selected in the platform file manager. Linux file managers that do not
+/// implement the freedesktop `ShowItems` call fall back to opening the parent directory.
+pub(crate) fn reveal_path_in_file_manager(path: &Path) -> Result<()> {
+    if !path.exists() {
+        return Err(anyhow!("path does not exist: {}", path.display()));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = Command::new("open");
+        command.arg("-R").arg(path);
+        spawn_background_command(command).context("failed to reveal path in Finder")
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut select_arg = std::ffi::OsString::from("/select,");
+        select_arg.push(path.as_os_str());
+        let mut command = Command::new(windows_explorer_command());
+        command.arg(select_arg);
+        configure_background_windows_command(&mut command);
+        spawn_background_command(command).context("failed to reveal path in Explorer")
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        reveal_path_in_file_manager_linux(path)
+    }
+}
+
+pub(crate) fn open_path_with_default_application(path: &Path) -> Result<()> {
+    if !path.exists() {
+        return Err(anyhow!("path does not exist: {}", path.display()));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = Command::new("open");
+        command.arg(path);
+        spawn_background_command(command).context("failed to open path on macOS")
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]).arg(path);
+        configure_background_windows_command(&mut command);
+        spawn_background_command(command).context("failed to open path on Windows")
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let command_path = resolve_first_command_path(&["xdg-open"])
+            .ok_or_else(|| anyhow!("xdg-open is not available on this system"))?;
+        let mut command = Command::new(command_path);
+        command.arg(path);
+        spawn_background_command(command).context("failed to open path with xdg-open")
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn reveal_path_in_file_manager_linux(path: &Path) -> Result<()> {
+    if let Some(dbus_send) = resolve_first_command_path(&["dbus-send"]) {
+        let mut command = Command::new(dbus_send);
+        command
+            .args([
+                "--session",
+                "--dest=org.freedesktop.FileManager1",
+                "--type=method_call",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+            ])
+            .arg(format!("array:string:{}", file_uri_for_path(path)))
+            .arg("string:")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        if command.status().is_ok_and(|status| status.success()) {
+            return Ok(());
+        }
+    }
+
+    let directory = if path.is_dir() {
+        path
+    } else {
+        path.parent().unwrap_or(path)
+    };
+    open_path_in_target_linux(directory, ProjectOpenTargetId::FileManager)
+}
+
+#[cfg_attr(any(target_os = "macos", target_os = "windows"), allow(dead_code))]
+fn file_uri_for_path(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(format!("%{byte:02X}").as_str());
+        }
+    }
+    uri
+}
+
 fn is_project_open_target_available(target: ProjectOpenTargetId) -> bool {
     #[cfg(target_os = "macos")]
     {
@@ -490,7 +603,21 @@ fn configure_background_windows_command(command: &mut Command) {
 
 #[cfg(test)]
 mod tests {
-    use super::{ProjectOpenTargetId, resolve_preferred_project_open_target};
+    use std::path::Path;
+
+    use super::{ProjectOpenTargetId, file_uri_for_path, resolve_preferred_project_open_target};
+
+    #[test]
+    fn file_uri_for_path_percent_encodes_reserved_bytes() {
+        assert_eq!(
+            file_uri_for_path(Path::new("/repo/src/main.rs")),
+            "file:///repo/src/main.rs"
+        );
+        assert_eq!(
+            file_uri_for_path(Path::new("/repo/my notes/#1 é.md")),
+            "file:///repo/my%20notes/%231%20%C3%A9.md"
+        );
+    }
 
     #[test]
     fn preferred_project_open_target_uses_stored_value_when_available() {
@@ -157,12 +157,13 @@ pub(super) const fn should_bootstrap_empty_files_workspace_editor(
     files_view_active && editor_missing && !editor_loading
 }
 
+/// Hidden trees skip the reload; the caller marks them to reload once their pane is shown.
 pub(super) const fn should_reload_repo_tree_after_snapshot(
     root_changed: bool,
-    supports_sidebar_tree: bool,
+    tree_visible: bool,
     file_list_changed: bool,
 ) -> bool {
-    root_changed || (supports_sidebar_tree && file_list_changed)
+    tree_visible && (root_changed || file_list_changed)
 }
 
 pub(super) const fn should_run_cold_start_reconcile(
@@ -269,6 +270,25 @@ pub(super) fn line_stats_paths_from_dirty_paths(
         .collect()
 }
 
+/// Returns the selected review file when it is the only path a watcher burst touched, so its
+/// patch can be reloaded in place instead of refreshing the whole snapshot.
+pub(super) fn single_file_watch_update_path<'a>(
+    pending_dirty_paths: &'a BTreeSet<String>,
+    files: &[ChangedFile],
+    selected_path: Option<&str>,
+) -> Option<&'a str> {
+    let mut dirty_paths = pending_dirty_paths.iter();
+    let path = dirty_paths.next()?;
+    if dirty_paths.next().is_some() || selected_path != Some(path.as_str()) {
+        return None;
+    }
+
+    files
+        .iter()
+        .any(|file| file.path == *path)
+        .then_some(path.as_str())
+}
+
 pub(super) fn retained_selection_path(
     files: &[ChangedFile],
     selected_path: Option<&str>,
@@ -32,7 +32,7 @@ impl DiffViewer {
         ai_view_state: Option<AiVisibleFrameState>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
-        if self.repo_discovery_failed {
+        if self.repo_discovery_failed || self.repo_init_path.is_some() {
             return self.render_open_project_empty_state(cx);
         }
 
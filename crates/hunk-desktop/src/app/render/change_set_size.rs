@@ -0,0 +1,202 @@
+fn review_time_estimate_label(minutes: u64) -> String {
+    if minutes < 60 {
+        tr_args(
+            "change-set-minutes",
+            &[("minutes", minutes.to_string().as_str())],
+        )
+    } else {
+        tr_args(
+            "change-set-hours",
+            &[
+                ("hours", (minutes / 60).to_string().as_str()),
+                ("minutes", format!("{:02}", minutes % 60).as_str()),
+            ],
+        )
+    }
+}
+
+impl DiffViewer {
+    fn render_review_change_set_size_banner(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let size = &self.review_change_set_size;
+        let warning = size.warning.as_ref().filter(|_| !size.dismissed)?;
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let budget = self.config.review_budget;
+        let title = tr_args(
+            "change-set-title",
+            &[
+                ("files", warning.files.to_string().as_str()),
+                ("lines", warning.changed_lines.to_string().as_str()),
+            ],
+        );
+        let detail = tr_args(
+            "change-set-estimate",
+            &[
+                (
+                    "estimate",
+                    review_time_estimate_label(warning.estimated_review_minutes).as_str(),
+                ),
+                ("rate", budget.lines_per_hour.to_string().as_str()),
+            ],
+        );
+        let largest = warning
+            .largest
+            .iter()
+            .map(|file| format!("{} ({})", file.path, file.changed_lines))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let split_hint = match (budget.max_files, budget.max_changed_lines) {
+            (0, lines) => tr_args(
+                "change-set-split-lines",
+                &[("lines", lines.to_string().as_str())],
+            ),
+            (files, 0) => tr_args(
+                "change-set-split-files",
+                &[("files", files.to_string().as_str())],
+            ),
+            (files, lines) => tr_args(
+                "change-set-split-files-and-lines",
+                &[
+                    ("files", files.to_string().as_str()),
+                    ("lines", lines.to_string().as_str()),
+                ],
+            ),
+        };
+
+        Some(
+            v_flex()
+                .id("review-change-set-size")
+                .w_full()
+                .gap_1()
+                .px_3()
+                .py_1p5()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .bg(hunk_opacity(cx.theme().warning, is_dark, 0.14, 0.08))
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .child(
+                            h_flex()
+                                .min_w_0()
+                                .flex_1()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .min_w_0()
+                                        .truncate()
+                                        .text_sm()
+                                        .font_semibold()
+                                        .text_color(cx.theme().foreground)
+                                        .child(title),
+                                )
+                                .child(
+                                    div()
+                                        .flex_none()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(detail),
+                                ),
+                        )
+                        .child({
+                            let view = view.clone();
+                            Button::new("review-change-set-size-dismiss")
+                                .ghost()
+                                .compact()
+                                .rounded(px(8.0))
+                                .label(tr("change-set-dismiss"))
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.dismiss_review_change_set_size_warning(cx);
+                                    });
+                                })
+                        }),
+                )
+                .child(
+                    div()
+                        .min_w_0()
+                        .truncate()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(tr_args(
+                            "change-set-largest",
+                            &[("files", largest.as_str())],
+                        )),
+                )
+                .when(!warning.parts.is_empty(), |this| {
+                    this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr_args(
+                                "change-set-split-suggestion",
+                                &[
+                                    ("count", warning.parts.len().to_string().as_str()),
+                                    ("limit", split_hint.as_str()),
+                                ],
+                            )),
+                    )
+                    .children(warning.parts.iter().enumerate().map(|(part_ix, part)| {
+                        let focused = size.focused_part == Some(part_ix);
+                        let directory = if part.directory.is_empty() {
+                            tr("change-set-repository-root")
+                        } else {
+                            format!("{}/", part.directory)
+                        };
+                        let view = view.clone();
+                        h_flex()
+                            .w_full()
+                            .min_w_0()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .min_w_0()
+                                    .flex_1()
+                                    .truncate()
+                                    .text_xs()
+                                    .text_color(cx.theme().foreground)
+                                    .child(tr_args(
+                                        "change-set-part",
+                                        &[
+                                            ("number", (part_ix + 1).to_string().as_str()),
+                                            ("directory", directory.as_str()),
+                                            ("files", part.paths.len().to_string().as_str()),
+                                            ("lines", part.changed_lines.to_string().as_str()),
+                                            (
+                                                "estimate",
+                                                review_time_estimate_label(
+                                                    part.estimated_review_minutes,
+                                                )
+                                                .as_str(),
+                                            ),
+                                        ],
+                                    )),
+                            )
+                            .child(
+                                Button::new(("review-change-set-part-focus", part_ix))
+                                    .when(focused, |button| button.primary())
+                                    .when(!focused, |button| button.outline())
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .label(if focused {
+                                        tr("change-set-show-all")
+                                    } else {
+                                        tr("change-set-review-part")
+                                    })
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.toggle_review_change_set_part_focus(part_ix, cx);
+                                        });
+                                    }),
+                            )
+                    }))
+                })
+                .into_any_element(),
+        )
+    }
+}
@@ -0,0 +1,191 @@
+impl DiffViewer {
+    fn render_ci_log_panel(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.ci_log_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let divider = hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66);
+        let in_change_count = panel.entries.iter().filter(|entry| entry.in_change).count();
+        let summary = if !panel.scanned {
+            tr("ci-log-hint")
+        } else if panel.entries.is_empty() {
+            tr("ci-log-empty")
+        } else {
+            tr_args(
+                "ci-log-summary",
+                &[
+                    ("count", panel.entries.len().to_string().as_str()),
+                    ("in_change", in_change_count.to_string().as_str()),
+                ],
+            )
+        };
+
+        let header = h_flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .border_b_1()
+            .border_color(divider)
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(tr("ci-log-title")),
+            )
+            .child({
+                let view = view.clone();
+                Button::new("ci-log-close-panel")
+                    .compact()
+                    .ghost()
+                    .rounded(px(7.0))
+                    .label(tr("ci-log-close"))
+                    .on_click(move |_, window, cx| {
+                        view.update(cx, |this, cx| {
+                            this.close_ci_log_panel(window, cx);
+                        });
+                    })
+            });
+
+        let body = v_flex()
+            .gap_2()
+            .px_3()
+            .py_3()
+            .child(
+                Input::new(&panel.log_input_state)
+                    .w_full()
+                    .rounded(px(7.0))
+                    .border_1()
+                    .border_color(input_surface.border)
+                    .bg(input_surface.background),
+            )
+            .child(
+                h_flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .child(
+                        div()
+                            .min_w_0()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(summary),
+                    )
+                    .child({
+                        let view = view.clone();
+                        Button::new("ci-log-scan")
+                            .compact()
+                            .primary()
+                            .rounded(px(7.0))
+                            .label(tr("ci-log-find-references"))
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.scan_ci_log(cx);
+                                });
+                            })
+                    }),
+            );
+
+        let list = v_flex()
+            .id("ci-log-references")
+            .max_h(px(320.0))
+            .min_h_0()
+            .overflow_y_scroll()
+            .px_1()
+            .pb_2()
+            .children(panel.entries.iter().enumerate().map(|(ix, entry)| {
+                let view = view.clone();
+                let location =
+                    entry
+                        .reference
+                        .location
+                        .as_ref()
+                        .map(|location| match location.line {
+                            Some(line) => format!("{}:{line}", location.normalized_path),
+                            None => location.normalized_path.clone(),
+                        });
+                let title = entry
+                    .reference
+                    .test_name
+                    .clone()
+                    .or_else(|| location.clone())
+                    .unwrap_or_default();
+                let detail = location.filter(|_| entry.reference.test_name.is_some());
+
+                h_flex()
+                    .id(("ci-log-reference", ix))
+                    .w_full()
+                    .min_w_0()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .rounded(px(7.0))
+                    .px_2()
+                    .py_1()
+                    .hover(|style| style.bg(hunk_opacity(cx.theme().accent, is_dark, 0.18, 0.10)))
+                    .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                        view.update(cx, |this, cx| {
+                            this.open_ci_log_reference(ix, window, cx);
+                        });
+                        cx.stop_propagation();
+                    })
+                    .child(
+                        v_flex()
+                            .min_w_0()
+                            .child(
+                                div()
+                                    .truncate()
+                                    .text_sm()
+                                    .text_color(cx.theme().foreground)
+                                    .child(title),
+                            )
+                            .when_some(detail, |this, detail| {
+                                this.child(
+                                    div()
+                                        .truncate()
+                                        .text_xs()
+                                        .font_family(cx.theme().mono_font_family.clone())
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(detail),
+                                )
+                            }),
+                    )
+                    .when(entry.in_change, |this| {
+                        this.child(
+                            div()
+                                .flex_none()
+                                .text_xs()
+                                .font_semibold()
+                                .text_color(cx.theme().success)
+                                .child(tr("ci-log-in-change")),
+                        )
+                    })
+            }));
+
+        v_flex()
+            .absolute()
+            .top(px(48.0))
+            .right(px(12.0))
+            .w(px(420.0))
+            .rounded(px(10.0))
+            .border_1()
+            .overflow_hidden()
+            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.72))
+            .bg(hunk_blend(
+                cx.theme().popover,
+                cx.theme().background,
+                is_dark,
+                0.20,
+                0.08,
+            ))
+            .child(header)
+            .child(body)
+            .child(list)
+            .into_any_element()
+    }
+}
@@ -0,0 +1,264 @@
+impl DiffViewer {
+    fn render_co_review_panel(&self, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let divider = hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66);
+
+        let header = h_flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .border_b_1()
+            .border_color(divider)
+            .child(
+                v_flex()
+                    .gap_0p5()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Co-review"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                "Share scroll position, file, and comments with another reviewer.",
+                            ),
+                    ),
+            )
+            .child({
+                let view = view.clone();
+                Button::new("co-review-close-panel")
+                    .compact()
+                    .ghost()
+                    .rounded(px(7.0))
+                    .label("Close")
+                    .on_click(move |_, _, cx| {
+                        view.update(cx, |this, cx| {
+                            this.toggle_co_review_panel(cx);
+                        });
+                    })
+            });
+
+        let body = match self.co_review.as_ref() {
+            None => v_flex()
+                .gap_3()
+                .px_3()
+                .py_3()
+                .child({
+                    let view = view.clone();
+                    Button::new("co-review-host")
+                        .compact()
+                        .primary()
+                        .rounded(px(7.0))
+                        .label("Host Session")
+                        .disabled(self.co_review_joining)
+                        .on_click(move |_, _, cx| {
+                            view.update(cx, |this, cx| {
+                                this.host_co_review_session(cx);
+                            });
+                        })
+                })
+                .child(
+                    div()
+                        .text_xs()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Join a session"),
+                )
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .items_center()
+                        .child(
+                            Input::new(&self.co_review_join_input_state)
+                                .flex_1()
+                                .h(px(30.0))
+                                .rounded(px(7.0))
+                                .border_1()
+                                .border_color(input_surface.border)
+                                .bg(input_surface.background),
+                        )
+                        .child({
+                            let view = view.clone();
+                            Button::new("co-review-join")
+                                .compact()
+                                .outline()
+                                .rounded(px(7.0))
+                                .label(if self.co_review_joining {
+                                    "Joining..."
+                                } else {
+                                    "Join"
+                                })
+                                .disabled(self.co_review_joining)
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.join_co_review_session(cx);
+                                    });
+                                })
+                        }),
+                )
+                .into_any_element(),
+            Some(state) => {
+                let role = if state.session.is_host() {
+                    "Hosting"
+                } else {
+                    "Joined"
+                };
+                v_flex()
+                    .gap_2()
+                    .px_3()
+                    .py_3()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{role} as {}", state.session.participant().name)),
+                    )
+                    .when_some(state.share_target.clone(), |this, share_target| {
+                        let copied_target = share_target.clone();
+                        this.child(
+                            h_flex()
+                                .items_center()
+                                .justify_between()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .min_w_0()
+                                        .truncate()
+                                        .text_sm()
+                                        .font_family(cx.theme().mono_font_family.clone())
+                                        .text_color(cx.theme().foreground)
+                                        .child(share_target),
+                                )
+                                .child(
+                                    Button::new("co-review-copy-target")
+                                        .compact()
+                                        .outline()
+                                        .rounded(px(7.0))
+                                        .label("Copy")
+                                        .on_click(move |_, _, cx| {
+                                            cx.write_to_clipboard(ClipboardItem::new_string(
+                                                copied_target.clone(),
+                                            ));
+                                        }),
+                                ),
+                        )
+                    })
+                    .child(
+                        div()
+                            .pt_1()
+                            .text_xs()
+                            .font_semibold()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("Participants ({})", state.peers.len())),
+                    )
+                    .when(state.peers.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Waiting for someone to join."),
+                        )
+                    })
+                    .children(state.peers.iter().enumerate().map(|(ix, (id, peer))| {
+                        let following = state.following.as_deref() == Some(id.as_str());
+                        let follow_id = id.clone();
+                        let location = peer
+                            .presence
+                            .path
+                            .clone()
+                            .unwrap_or_else(|| "Not viewing a file".to_string());
+                        h_flex()
+                            .id(("co-review-peer", ix))
+                            .items_center()
+                            .justify_between()
+                            .gap_2()
+                            .child(
+                                h_flex()
+                                    .min_w_0()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div().size(px(8.0)).rounded_full().bg(cx.theme().success),
+                                    )
+                                    .child(
+                                        v_flex()
+                                            .min_w_0()
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(cx.theme().foreground)
+                                                    .child(peer.name.clone()),
+                                            )
+                                            .child(
+                                                div()
+                                                    .truncate()
+                                                    .text_xs()
+                                                    .text_color(cx.theme().muted_foreground)
+                                                    .child(location),
+                                            ),
+                                    ),
+                            )
+                            .child({
+                                let view = view.clone();
+                                Button::new(("co-review-follow", ix))
+                                    .compact()
+                                    .outline()
+                                    .rounded(px(7.0))
+                                    .label(if following { "Unfollow" } else { "Follow" })
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.toggle_co_review_follow(follow_id.clone(), cx);
+                                        });
+                                    })
+                            })
+                    }))
+                    .child({
+                        let view = view.clone();
+                        Button::new("co-review-leave")
+                            .compact()
+                            .ghost()
+                            .rounded(px(7.0))
+                            .label(if state.session.is_host() {
+                                "End Session"
+                            } else {
+                                "Leave Session"
+                            })
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.leave_co_review_session(cx);
+                                });
+                            })
+                    })
+                    .into_any_element()
+            }
+        };
+
+        v_flex()
+            .absolute()
+            .top(px(48.0))
+            .right(px(12.0))
+            .w(px(380.0))
+            .rounded(px(10.0))
+            .border_1()
+            .overflow_hidden()
+            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.72))
+            .bg(hunk_blend(
+                cx.theme().popover,
+                cx.theme().background,
+                is_dark,
+                0.20,
+                0.08,
+            ))
+            .child(header)
+            .child(body)
+            .into_any_element()
+    }
+}
@@ -0,0 +1,98 @@
+const GIT_CODE_OWNERS_PANEL_GROUP_LIMIT: usize = 8;
+
+impl DiffViewer {
+    /// Review summary of who has to sign off: changed files grouped by CODEOWNERS owner.
+    fn render_git_code_owners_panel(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let code_owners = self.code_owners.as_ref()?;
+        if self.git_workspace.files.is_empty() {
+            return None;
+        }
+        let groups = code_owners.group_paths_by_owner(
+            self.git_workspace
+                .files
+                .iter()
+                .map(|file| file.path.as_str()),
+        );
+        let hidden_group_count = groups
+            .len()
+            .saturating_sub(GIT_CODE_OWNERS_PANEL_GROUP_LIMIT);
+        let is_dark = cx.theme().mode.is_dark();
+        let colors = hunk_git_workspace(cx.theme(), is_dark);
+
+        Some(
+            v_flex()
+                .w_full()
+                .gap_1p5()
+                .p_2p5()
+                .rounded(px(12.0))
+                .border_1()
+                .border_color(colors.rail.border)
+                .bg(colors.rail.background)
+                .child(
+                    v_flex()
+                        .gap_0p5()
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_semibold()
+                                .text_color(cx.theme().foreground)
+                                .child("Code Owners"),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("From {}", code_owners.source_path)),
+                        ),
+                )
+                .children(
+                    groups
+                        .into_iter()
+                        .take(GIT_CODE_OWNERS_PANEL_GROUP_LIMIT)
+                        .map(|group| {
+                            let file_count = match group.paths.len() {
+                                1 => "1 file".to_string(),
+                                count => format!("{count} files"),
+                            };
+                            let owner_color = if group.owner.is_some() {
+                                cx.theme().foreground
+                            } else {
+                                cx.theme().muted_foreground
+                            };
+                            h_flex()
+                                .w_full()
+                                .items_center()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .min_w_0()
+                                        .truncate()
+                                        .text_xs()
+                                        .font_semibold()
+                                        .text_color(owner_color)
+                                        .child(
+                                            group.owner.unwrap_or_else(|| "No owner".to_string()),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .flex_none()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(file_count),
+                                )
+                        }),
+                )
+                .when(hidden_group_count > 0, |this| {
+                    this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("+{hidden_group_count} more owners")),
+                    )
+                })
+                .into_any_element(),
+        )
+    }
+}
@@ -1,19 +1,26 @@
 impl DiffViewer {
-    fn render_comments_preview(&self, cx: &mut Context<Self>) -> AnyElement {
+    fn render_comments_panel(&self, floating: bool, cx: &mut Context<Self>) -> AnyElement {
         let view = cx.entity();
         let is_dark = cx.theme().mode.is_dark();
         let comments = self.comments_preview_records();
         let open_count = self.comments_open_count();
         let stale_count = self.comments_stale_count();
         let resolved_count = self.comments_resolved_count();
+        let authors = self.comment_authors();
+        let author_filter = self.comments_author_filter.clone();
+        let unplaced_imports = &self.comment_import_unplaced;
+        let pins = self.review_pins();
 
         v_flex()
-            .absolute()
-            .top(px(48.0))
-            .right(px(12.0))
-            .w(px(520.0))
-            .h(px(520.0))
-            .rounded(px(10.0))
+            .when(floating, |this| {
+                this.absolute()
+                    .top(px(48.0))
+                    .right(px(12.0))
+                    .w(px(520.0))
+                    .h(px(520.0))
+                    .rounded(px(10.0))
+            })
+            .when(!floating, |this| this.size_full())
             .border_1()
             .overflow_hidden()
             .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.72))
@@ -48,6 +55,23 @@ impl DiffViewer {
                         h_flex()
                             .items_center()
                             .gap_2()
+                            .child({
+                                let view = view.clone();
+                                Button::new("comments-import")
+                                    .compact()
+                                    .outline()
+                                    .rounded(px(7.0))
+                                    .label("Import...")
+                                    .tooltip(
+                                        "Add comments from a JSON file, copied comment bundles, \
+                                         or a path:line: comment list.",
+                                    )
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.import_review_comments(cx);
+                                        });
+                                    })
+                            })
                             .child({
                                 let view = view.clone();
                                 Button::new("comments-copy-all-open")
@@ -104,6 +128,72 @@ impl DiffViewer {
                             })
                     }),
             )
+            .when(authors.len() > 1, |this| {
+                this.child(
+                    h_flex()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .px_3()
+                        .py_2()
+                        .border_b_1()
+                        .border_color(hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66))
+                        .child(
+                            div()
+                                .flex_none()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Author"),
+                        )
+                        .child(
+                            h_flex()
+                                .min_w_0()
+                                .items_center()
+                                .justify_end()
+                                .gap_1()
+                                .flex_wrap()
+                                .child({
+                                    let view = view.clone();
+                                    let button = Button::new("comments-author-filter-all")
+                                        .compact()
+                                        .rounded(px(7.0))
+                                        .label("All")
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.set_comments_author_filter(None, cx);
+                                            });
+                                        });
+                                    if author_filter.is_none() {
+                                        button.primary()
+                                    } else {
+                                        button.outline()
+                                    }
+                                })
+                                .children(authors.into_iter().enumerate().map(|(ix, author)| {
+                                    let view = view.clone();
+                                    let selected =
+                                        author_filter.as_deref() == Some(author.as_str());
+                                    let button = Button::new(("comments-author-filter", ix))
+                                        .compact()
+                                        .rounded(px(7.0))
+                                        .label(author.clone())
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.set_comments_author_filter(
+                                                    Some(author.clone()),
+                                                    cx,
+                                                );
+                                            });
+                                        });
+                                    if selected {
+                                        button.primary()
+                                    } else {
+                                        button.outline()
+                                    }
+                                })),
+                        ),
+                )
+            })
             .child(
                 h_flex()
                     .items_center()
@@ -163,6 +253,159 @@ impl DiffViewer {
                             }),
                     ),
             )
+            .when(!unplaced_imports.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .gap_1()
+                        .px_3()
+                        .py_2()
+                        .border_b_1()
+                        .border_color(hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66))
+                        .bg(hunk_opacity(cx.theme().warning, is_dark, 0.10, 0.06))
+                        .child(
+                            h_flex()
+                                .items_center()
+                                .justify_between()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .font_semibold()
+                                        .text_color(cx.theme().warning)
+                                        .child(format!(
+                                            "Needs manual placement ({})",
+                                            unplaced_imports.len()
+                                        )),
+                                )
+                                .child(
+                                    h_flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .child({
+                                            let view = view.clone();
+                                            Button::new("comments-import-copy-unplaced")
+                                                .compact()
+                                                .outline()
+                                                .rounded(px(7.0))
+                                                .label("Copy")
+                                                .on_click(move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.copy_unplaced_comment_imports(cx);
+                                                    });
+                                                })
+                                        })
+                                        .child({
+                                            let view = view.clone();
+                                            Button::new("comments-import-dismiss-unplaced")
+                                                .compact()
+                                                .ghost()
+                                                .rounded(px(7.0))
+                                                .label("Dismiss")
+                                                .on_click(move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.dismiss_unplaced_comment_imports(cx);
+                                                    });
+                                                })
+                                        }),
+                                ),
+                        )
+                        .children(unplaced_imports.iter().map(|entry| {
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .truncate()
+                                .child(format!("{} — {}", entry.location_label(), entry.text))
+                        })),
+                )
+            })
+            .when(!pins.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .gap_1()
+                        .px_3()
+                        .py_2()
+                        .border_b_1()
+                        .border_color(hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66))
+                        .child(
+                            h_flex()
+                                .items_center()
+                                .justify_between()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .font_semibold()
+                                        .text_color(cx.theme().foreground)
+                                        .child(format!("Pinned lines ({})", pins.len())),
+                                )
+                                .child({
+                                    let view = view.clone();
+                                    Button::new("review-pins-clear")
+                                        .compact()
+                                        .ghost()
+                                        .rounded(px(7.0))
+                                        .label("Clear")
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.clear_review_pins(cx);
+                                            });
+                                        })
+                                }),
+                        )
+                        .children(pins.iter().enumerate().map(|(ix, pin)| {
+                            h_flex()
+                                .items_center()
+                                .gap_2()
+                                .child(
+                                    v_flex()
+                                        .flex_1()
+                                        .min_w_0()
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .font_semibold()
+                                                .text_color(cx.theme().foreground)
+                                                .truncate()
+                                                .child(review_pin_location_label(pin)),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .font_family(cx.theme().mono_font_family.clone())
+                                                .text_color(cx.theme().muted_foreground)
+                                                .truncate()
+                                                .child(pin.line_text.clone()),
+                                        ),
+                                )
+                                .child({
+                                    let view = view.clone();
+                                    Button::new(("review-pin-jump", ix))
+                                        .compact()
+                                        .outline()
+                                        .rounded(px(7.0))
+                                        .label("Jump")
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.jump_to_review_pin(ix, cx);
+                                            });
+                                        })
+                                })
+                                .child({
+                                    let view = view.clone();
+                                    Button::new(("review-pin-remove", ix))
+                                        .compact()
+                                        .ghost()
+                                        .rounded(px(7.0))
+                                        .label("Unpin")
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.remove_review_pin(ix, cx);
+                                            });
+                                        })
+                                })
+                        })),
+                )
+            })
             .child(
                 div()
                     .flex_1()
@@ -195,6 +438,10 @@ impl DiffViewer {
                                 .map(|line| line.to_string())
                                 .unwrap_or_else(|| "-".to_string())
                         );
+                        let line_hint = match comment.author_label() {
+                            Some(author) => format!("{author} • {line_hint}"),
+                            None => line_hint,
+                        };
                         let status_text = Self::comment_status_label(comment.status).to_string();
                         let status_color = match comment.status {
                             CommentStatus::Open => cx.theme().success,
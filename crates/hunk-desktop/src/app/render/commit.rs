@@ -17,6 +17,7 @@ impl DiffViewer {
         let view = cx.entity();
         let staged_count = self.staged_commit_file_count();
         let has_unstaged_changes = self.git_workspace.files.iter().any(|file| file.unstaged);
+        let read_only = self.read_only_active();
         let is_dark = cx.theme().mode.is_dark();
         let colors = hunk_git_workspace(cx.theme(), is_dark);
 
@@ -81,7 +82,11 @@ impl DiffViewer {
                                         .rounded(px(8.0))
                                         .label("Stage All")
                                         .tooltip("Stage every changed file.")
-                                        .disabled(self.git_action_loading || !has_unstaged_changes)
+                                        .disabled(
+                                            self.git_action_loading
+                                                || read_only
+                                                || !has_unstaged_changes,
+                                        )
                                         .on_click(move |_, _, cx| {
                                             view.update(cx, |this, cx| {
                                                 this.stage_all_files_for_commit(cx);
@@ -97,7 +102,11 @@ impl DiffViewer {
                                         .rounded(px(8.0))
                                         .label("Unstage All")
                                         .tooltip("Unstage every staged file.")
-                                        .disabled(self.git_action_loading || staged_count == 0)
+                                        .disabled(
+                                            self.git_action_loading
+                                                || read_only
+                                                || staged_count == 0,
+                                        )
                                         .on_click(move |_, _, cx| {
                                             view.update(cx, |this, cx| {
                                                 this.unstage_all_files_for_commit(cx);
@@ -183,17 +192,21 @@ impl DiffViewer {
             self.git_action_loading_named("Generate commit message");
         let push_loading = self.git_action_loading_named("Push branch");
         let git_controls_busy = self.git_rail_controls_busy();
+        let read_only = self.read_only_active();
         let push_button_colors = hunk_action_ready_button(cx.theme(), is_dark, HunkAccentTone::Accent);
         let push_available = self.can_push_current_branch_for_ui() || push_loading;
         let push_disabled = !push_available || (git_controls_busy && !push_loading);
-        let push_tooltip = if !self.can_run_active_branch_actions_for_ui() {
-            "Activate a branch before pushing."
+        let push_tooltip = if read_only {
+            "This repository is open in read-only mode.".to_string()
+        } else if !self.can_run_active_branch_actions_for_ui() {
+            self.detached_head_branch_hint()
+                .unwrap_or_else(|| "Activate a branch before pushing.".to_string())
         } else if !self.git_workspace.branch_has_upstream {
-            "Publish this branch before pushing."
+            "Publish this branch before pushing.".to_string()
         } else if self.git_workspace.branch_ahead_count == 0 {
-            "No local commits to push."
+            "No local commits to push.".to_string()
         } else {
-            "Push all local commits on this branch."
+            "Push all local commits on this branch.".to_string()
         };
         let staged_count = self.staged_commit_file_count();
         let total_count = self.git_workspace.files.len();
@@ -204,21 +217,30 @@ impl DiffViewer {
             .trim()
             .is_empty();
         let commit_message_has_text = !commit_message_has_text;
-        let commit_disabled = staged_count == 0
+        let unacknowledged_collision_count = self.unacknowledged_commit_path_collisions(false).len();
+        let staged_collision_blocked = !self.unacknowledged_commit_path_collisions(true).is_empty();
+        let commit_disabled = read_only
+            || staged_count == 0
             || !commit_message_has_text
+            || staged_collision_blocked
             || (git_controls_busy && !create_commit_loading);
         let generate_commit_message_disabled =
             staged_count == 0 || (git_controls_busy && !generate_commit_message_loading);
         let commit_and_push_tooltip = self.combined_workspace_commit_and_push_tooltip();
         let commit_and_push_disabled =
             !self.can_run_combined_workspace_commit_and_push_for_ui() && !commit_and_push_loading;
-        let commit_readiness_label = if staged_count == 0 {
+        let commit_readiness_label = if read_only {
+            "Read-only".to_string()
+        } else if staged_count == 0 {
             "Stage files".to_string()
+        } else if staged_collision_blocked {
+            "Acknowledge case warnings".to_string()
         } else if !commit_message_has_text {
             "Add commit message".to_string()
         } else {
             "Ready to commit".to_string()
         };
+        let missing_ticket_id = self.commit_message_missing_active_ticket(cx);
         let last_commit_text = self
             .last_commit_subject
             .as_deref()
@@ -257,7 +279,28 @@ impl DiffViewer {
                                     .text_color(cx.theme().muted_foreground)
                                     .child(format!("Staged {staged_count}/{total_count} files")),
                             ),
-                    ),
+                    )
+                    .child({
+                        let view = view.clone();
+                        Button::new("git-read-only-toggle")
+                            .ghost()
+                            .xsmall()
+                            .label(if read_only { "Read-Only: On" } else { "Read-Only: Off" })
+                            .tooltip(if self.read_only_locked_by_launch() {
+                                "Hunk was started with --read-only, so every repository is read-only."
+                            } else {
+                                "Disable every action that writes to this repository, for safely browsing checkouts you do not own."
+                            })
+                            .when(read_only, |this| this.text_color(cx.theme().warning))
+                            .disabled(
+                                self.read_only_locked_by_launch() || self.project_path.is_none(),
+                            )
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.toggle_project_read_only(cx);
+                                });
+                            })
+                    }),
             )
             .child(
                 h_flex()
@@ -282,7 +325,44 @@ impl DiffViewer {
                             HunkAccentTone::Neutral
                         },
                         cx,
-                    )),
+                    ))
+                    .when(unacknowledged_collision_count > 0, |this| {
+                        let view = view.clone();
+                        this.child(
+                            Button::new("git-acknowledge-path-collisions")
+                                .ghost()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .rounded(px(999.0))
+                                .label(format!("Acknowledge {unacknowledged_collision_count} Case Warning(s)"))
+                                .tooltip("These paths differ only by case. On a case-insensitive filesystem one spelling can overwrite the other. Commit them anyway.")
+                                .text_color(cx.theme().warning)
+                                .disabled(git_controls_busy)
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.acknowledge_commit_path_collisions(cx);
+                                    });
+                                }),
+                        )
+                    })
+                    .when_some(missing_ticket_id, |this, ticket_id| {
+                        let view = view.clone();
+                        this.child(
+                            Button::new("git-insert-commit-ticket")
+                                .ghost()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .rounded(px(999.0))
+                                .label(format!("Add {ticket_id}"))
+                                .tooltip("Prefix the commit message with this branch's ticket id.")
+                                .disabled(git_controls_busy)
+                                .on_click(move |_, window, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.insert_active_ticket_into_commit_message(window, cx);
+                                    });
+                                }),
+                        )
+                    }),
             )
             .child(
                 div()
@@ -400,11 +480,35 @@ impl DiffViewer {
                     .border_color(colors.muted_card.border)
                     .bg(colors.muted_card.background)
                     .child(
-                        div()
-                            .text_xs()
-                            .font_semibold()
-                            .text_color(cx.theme().muted_foreground)
-                            .child("Last Commit"),
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .font_semibold()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child("Last Commit"),
+                            )
+                            .child({
+                                let view = view.clone();
+                                Button::new("git-edit-last-commit-message")
+                                    .ghost()
+                                    .xsmall()
+                                    .label("Edit Message")
+                                    .tooltip("Reword the last commit's subject, body and trailers.")
+                                    .disabled(
+                                        self.last_commit_subject.is_none()
+                                            || git_controls_busy
+                                            || read_only,
+                                    )
+                                    .on_click(move |_, window, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.open_commit_message_editor(window, cx);
+                                        });
+                                    })
+                            }),
                     )
                     .child(
                         div()
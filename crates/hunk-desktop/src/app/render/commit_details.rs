@@ -0,0 +1,354 @@
+const COMMIT_DETAILS_PATCH_LINE_LIMIT: usize = 400;
+
+impl DiffViewer {
+    fn render_commit_details_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.commit_details_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let title = panel
+            .details
+            .as_ref()
+            .and_then(|details| details.message.lines().next())
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                tr_args(
+                    "commit-details-title",
+                    &[("commit", short_commit_id(panel.commit_id.as_str()).as_str())],
+                )
+            });
+        let summary = if panel.loading {
+            tr("commit-details-loading")
+        } else {
+            match panel.details.as_ref() {
+                Some(details) => tr_args(
+                    "commit-details-summary",
+                    &[("count", details.files.len().to_string().as_str())],
+                ),
+                None => String::new(),
+            }
+        };
+
+        div()
+            .id("commit-details-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("commit-details-popup")
+                            .w_full()
+                            .max_w(px(860.0))
+                            .h(px(680.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_1()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .whitespace_normal()
+                                            .child(title),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(summary),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .id("commit-details-body")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_y_scroll()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .when_some(panel.details.as_ref(), |this, details| {
+                                        this.child(self.render_commit_details_metadata(details, cx))
+                                            .children(details.files.iter().enumerate().map(
+                                                |(ix, file)| {
+                                                    self.render_commit_details_file(ix, file, cx)
+                                                },
+                                            ))
+                                    })
+                                    .when_some(panel.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .whitespace_normal()
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        Button::new("commit-details-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(tr("commit-details-close"))
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_commit_details(window, cx);
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_commit_details_metadata(
+        &self,
+        details: &CommitDetails,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let card_surface = hunk_card_surface(cx.theme(), cx.theme().mode.is_dark());
+        let person = |person: &hunk_git::history::CommitPerson| {
+            format!(
+                "{} <{}>, {}",
+                person.name,
+                person.email,
+                relative_time_label(Some(person.unix_time))
+            )
+        };
+        let parents = if details.parent_ids.is_empty() {
+            tr("commit-details-no-parents")
+        } else {
+            details
+                .parent_ids
+                .iter()
+                .map(|parent_id| short_commit_id(parent_id.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut fields = vec![
+            (tr("commit-details-commit"), details.commit_id.clone()),
+            (tr("commit-details-parents"), parents),
+            (tr("commit-details-author"), person(&details.author)),
+        ];
+        if details.committer != details.author {
+            fields.push((tr("commit-details-committer"), person(&details.committer)));
+        }
+        if let Some(change_id) = details.change_id.clone() {
+            fields.push((tr("commit-details-change-id"), change_id));
+        }
+        let body = details
+            .message
+            .split_once('\n')
+            .map(|(_, body)| body.trim().to_string())
+            .unwrap_or_default();
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .p_3()
+            .rounded(px(8.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .children(fields.into_iter().map(|(label, value)| {
+                h_flex()
+                    .w_full()
+                    .min_w_0()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_none()
+                            .w(px(84.0))
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(label),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .text_xs()
+                            .font_family(cx.theme().mono_font_family.clone())
+                            .text_color(cx.theme().foreground)
+                            .whitespace_normal()
+                            .child(value),
+                    )
+            }))
+            .when(!body.is_empty(), |this| {
+                this.child(
+                    div()
+                        .pt_2()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .whitespace_normal()
+                        .child(body),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn render_commit_details_file(
+        &self,
+        ix: usize,
+        file: &hunk_git::history::CommitFilePatch,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let line_colors = hunk_line_stats(cx.theme(), is_dark);
+        let (status_label, status_color) = change_status_label_color(file.file.status, cx);
+        let line_count = file.patch.lines().count();
+        let hidden_lines = line_count.saturating_sub(COMMIT_DETAILS_PATCH_LINE_LIMIT);
+        let note = if file.patch.is_empty() {
+            Some(tr("commit-details-no-text-changes"))
+        } else if hidden_lines > 0 {
+            Some(tr_args(
+                "commit-details-more-lines",
+                &[("count", hidden_lines.to_string().as_str())],
+            ))
+        } else if file.truncated {
+            Some(tr("commit-details-patch-truncated"))
+        } else {
+            None
+        };
+
+        v_flex()
+            .id(("commit-details-file", ix))
+            .w_full()
+            .rounded(px(8.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .child(
+                h_flex()
+                    .w_full()
+                    .min_w_0()
+                    .items_center()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(card_surface.border)
+                    .child(
+                        div()
+                            .flex_none()
+                            .text_xs()
+                            .font_family(cx.theme().mono_font_family.clone())
+                            .text_color(status_color)
+                            .child(status_label),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .truncate()
+                            .text_sm()
+                            .text_color(cx.theme().foreground)
+                            .child(file.file.path.clone()),
+                    )
+                    .child(self.render_workspace_change_stats(file.file.line_stats, cx)),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .py_1()
+                    .children(
+                        file.patch
+                            .lines()
+                            .take(COMMIT_DETAILS_PATCH_LINE_LIMIT)
+                            .map(|line| {
+                                let (color, background) = if line.starts_with("@@") {
+                                    (cx.theme().muted_foreground, None)
+                                } else if line.starts_with('+') {
+                                    (
+                                        line_colors.added,
+                                        Some(hunk_opacity(cx.theme().success, is_dark, 0.14, 0.10)),
+                                    )
+                                } else if line.starts_with('-') {
+                                    (
+                                        line_colors.removed,
+                                        Some(hunk_opacity(cx.theme().danger, is_dark, 0.14, 0.10)),
+                                    )
+                                } else {
+                                    (cx.theme().foreground, None)
+                                };
+                                div()
+                                    .w_full()
+                                    .px_3()
+                                    .text_xs()
+                                    .font_family(cx.theme().mono_font_family.clone())
+                                    .text_color(color)
+                                    .when_some(background, |this, background| this.bg(background))
+                                    .whitespace_nowrap()
+                                    .child(line.to_string())
+                            }),
+                    )
+                    .when_some(note, |this, note| {
+                        this.child(
+                            div()
+                                .px_3()
+                                .py_1()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(note),
+                        )
+                    }),
+            )
+            .into_any_element()
+    }
+}
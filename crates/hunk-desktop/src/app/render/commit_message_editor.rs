@@ -0,0 +1,238 @@
+impl DiffViewer {
+    fn render_commit_message_editor_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(editor) = self.commit_message_editor.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let loading = editor.commit_id.is_none() && editor.error_message.is_none();
+        let inputs_disabled = editor.commit_id.is_none() || editor.saving;
+        let description = match editor.commit_id.as_deref() {
+            Some(commit_id) => format!("Rewords commit {}", &commit_id[..commit_id.len().min(10)]),
+            None if loading => "Loading the last commit message...".to_string(),
+            None => "The last commit message could not be loaded.".to_string(),
+        };
+        let problems = if editor.commit_id.is_some() {
+            editor.structured_message(cx).validate()
+        } else {
+            Vec::new()
+        };
+        let subject_len = editor
+            .subject_input_state
+            .read(cx)
+            .value()
+            .trim()
+            .chars()
+            .count();
+        let submit_disabled = inputs_disabled
+            || editor.published_upstream.is_some()
+            || problems.iter().any(|problem| problem.is_blocking());
+
+        let field = |label: String, input_state: &Entity<InputState>, height: f32| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(label),
+                )
+                .child(
+                    Input::new(input_state)
+                        .h(px(height))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background)
+                        .disabled(inputs_disabled),
+                )
+        };
+
+        div()
+            .id("commit-message-editor-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("commit-message-editor-popup")
+                            .w_full()
+                            .max_w(px(620.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Edit Last Commit Message"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(description),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .child(field(
+                                        format!(
+                                            "Subject  {subject_len}/{COMMIT_SUBJECT_MAX_CHARS}"
+                                        ),
+                                        &editor.subject_input_state,
+                                        34.0,
+                                    ))
+                                    .child(field(
+                                        "Body".to_string(),
+                                        &editor.body_input_state,
+                                        140.0,
+                                    ))
+                                    .child(
+                                        h_flex()
+                                            .w_full()
+                                            .gap_3()
+                                            .child(field(
+                                                REVIEWED_BY_TRAILER.to_string(),
+                                                &editor.reviewed_by_input_state,
+                                                34.0,
+                                            ))
+                                            .child(field(
+                                                CHANGE_ID_TRAILER.to_string(),
+                                                &editor.change_id_input_state,
+                                                34.0,
+                                            )),
+                                    )
+                                    .when(!problems.is_empty(), |this| {
+                                        this.child(v_flex().gap_0p5().children(
+                                            problems.iter().map(|problem| {
+                                                let color = if problem.is_blocking() {
+                                                    cx.theme().danger
+                                                } else {
+                                                    cx.theme().warning
+                                                };
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(color)
+                                                    .child(problem.to_string())
+                                            }),
+                                        ))
+                                    })
+                                    .when_some(
+                                        editor.published_upstream.clone(),
+                                        |this, upstream| {
+                                            this.child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(cx.theme().warning)
+                                                    .child(format!(
+                                                        "This commit is already on '{upstream}', so its message can no longer be changed here."
+                                                    )),
+                                            )
+                                        },
+                                    )
+                                    .when_some(editor.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("commit-message-editor-cancel")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Cancel")
+                                            .disabled(editor.saving)
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_commit_message_editor(window, cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("commit-message-editor-save")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(if editor.saving {
+                                                "Saving..."
+                                            } else {
+                                                "Save Message"
+                                            })
+                                            .disabled(submit_disabled)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.submit_commit_message_editor(cx);
+                                                });
+                                            })
+                                    }),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
@@ -52,7 +52,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Cut", target.can_cut, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_cut(cx);
                             });
@@ -62,7 +62,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Copy", target.can_copy, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_copy(cx);
                             });
@@ -72,7 +72,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Paste", target.can_paste, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_paste(cx);
                             });
@@ -86,7 +86,7 @@ impl DiffViewer {
                         target.can_select_all,
                         {
                             let view = view.clone();
-                            move |cx| {
+                            move |_, cx| {
                                 view.update(cx, |this, cx| {
                                     this.workspace_text_context_menu_select_all(cx);
                                 });
@@ -100,7 +100,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Copy", target.can_copy, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_copy(cx);
                             });
@@ -113,7 +113,7 @@ impl DiffViewer {
                         target.can_select_all,
                         {
                             let view = view.clone();
-                            move |cx| {
+                            move |_, cx| {
                                 view.update(cx, |this, cx| {
                                     this.workspace_text_context_menu_select_all(cx);
                                 });
@@ -127,7 +127,7 @@ impl DiffViewer {
                     items.push(
                         self.render_workspace_text_context_menu_item("Open Link", true, {
                             let view = view.clone();
-                            move |cx| {
+                            move |_, cx| {
                                 view.update(cx, |this, cx| {
                                     this.workspace_text_context_menu_open_link(cx);
                                 });
@@ -140,7 +140,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Copy", target.can_copy, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_copy(cx);
                             });
@@ -150,7 +150,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Paste", target.can_paste, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_paste(cx);
                             });
@@ -163,7 +163,7 @@ impl DiffViewer {
                         target.can_select_all,
                         {
                             let view = view.clone();
-                            move |cx| {
+                            move |_, cx| {
                                 view.update(cx, |this, cx| {
                                     this.workspace_text_context_menu_select_all(cx);
                                 });
@@ -176,7 +176,7 @@ impl DiffViewer {
                 items.push(
                     self.render_workspace_text_context_menu_item("Clear", target.can_clear, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_clear_terminal(cx);
                             });
@@ -185,10 +185,98 @@ impl DiffViewer {
                 );
             }
             WorkspaceTextContextMenuTarget::DiffRows(target) => {
+                if let Some(path) = target.file_path.clone() {
+                    let label = if target.changed_lines_only {
+                        "Show Context Lines in File"
+                    } else {
+                        "Show Changed Lines Only in File"
+                    };
+                    items.push(self.render_workspace_text_context_menu_item(label, true, {
+                        let view = view.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.toggle_file_changed_lines_only(path.clone(), cx);
+                            });
+                        }
+                    }, cx));
+                }
+                if let Some(path) = target.file_path.clone() {
+                    let label = if target.right_to_left {
+                        tr("context-menu-left-to-right")
+                    } else {
+                        tr("context-menu-right-to-left")
+                    };
+                    items.push(self.render_workspace_text_context_menu_item(label, true, {
+                        let view = view.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.toggle_file_right_to_left(path.clone(), cx);
+                            });
+                        }
+                    }, cx));
+                }
+                if let (Some(path), Some(hunk)) = (target.file_path.clone(), target.hunk.clone()) {
+                    if target.can_stage_hunk {
+                        items.push(self.render_workspace_text_context_menu_item(
+                            tr("context-menu-stage-hunk"),
+                            !self.git_controls_busy(),
+                            {
+                                let view = view.clone();
+                                let path = path.clone();
+                                let hunk = hunk.clone();
+                                move |_, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.close_workspace_text_context_menu(cx);
+                                        this.stage_review_hunk(path.clone(), hunk.clone(), cx);
+                                    });
+                                }
+                            },
+                            cx,
+                        ));
+                    }
+                    if target.can_unstage_hunk {
+                        items.push(self.render_workspace_text_context_menu_item(
+                            tr("context-menu-unstage-hunk"),
+                            !self.git_controls_busy(),
+                            {
+                                let view = view.clone();
+                                let path = path.clone();
+                                let hunk = hunk.clone();
+                                move |_, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.close_workspace_text_context_menu(cx);
+                                        this.unstage_review_hunk(path.clone(), hunk.clone(), cx);
+                                    });
+                                }
+                            },
+                            cx,
+                        ));
+                    }
+                    items.push(self.render_workspace_text_context_menu_item(
+                        "Apply Hunk to Branch...",
+                        true,
+                        {
+                            let view = view.clone();
+                            move |_, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.close_workspace_text_context_menu(cx);
+                                    this.defer_open_hunk_apply_prompt(
+                                        path.clone(),
+                                        hunk.clone(),
+                                        cx,
+                                    );
+                                });
+                            }
+                        },
+                        cx,
+                    ));
+                }
                 items.push(
                     self.render_workspace_text_context_menu_item("Copy", target.can_copy, {
                         let view = view.clone();
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_copy(cx);
                             });
@@ -199,7 +287,7 @@ impl DiffViewer {
                     self.render_workspace_text_context_menu_item(
                         "Select All",
                         target.can_select_all,
-                        move |cx| {
+                        move |_, cx| {
                             view.update(cx, |this, cx| {
                                 this.workspace_text_context_menu_select_all(cx);
                             });
@@ -208,15 +296,116 @@ impl DiffViewer {
                     ),
                 );
             }
+            WorkspaceTextContextMenuTarget::FilePath(target) => {
+                for format in FilePathCopyFormat::ALL {
+                    let enabled =
+                        format != FilePathCopyFormat::PathWithLine || target.line.is_some();
+                    items.push(self.render_workspace_text_context_menu_item(
+                        format.label(),
+                        enabled,
+                        {
+                            let view = view.clone();
+                            let path = target.path.clone();
+                            move |_, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.close_workspace_text_context_menu(cx);
+                                    this.copy_file_path(path.as_str(), format, cx);
+                                });
+                            }
+                        },
+                        cx,
+                    ));
+                }
+                items.push(self.render_workspace_text_context_menu_item(
+                    "Collapse All Hunks in File",
+                    target.can_collapse_hunks,
+                    {
+                        let view = view.clone();
+                        let path = target.path.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.collapse_all_review_hunks_in_file(path.clone(), cx);
+                            });
+                        }
+                    },
+                    cx,
+                ));
+                items.push(self.render_workspace_text_context_menu_item(
+                    "Expand All Hunks",
+                    target.can_expand_hunks,
+                    {
+                        let view = view.clone();
+                        let path = target.path.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.expand_all_review_hunks_in_file(path.clone(), cx);
+                            });
+                        }
+                    },
+                    cx,
+                ));
+                if target.can_convert_to_utf8 {
+                    items.push(self.render_workspace_text_context_menu_item(
+                        "Convert to UTF-8",
+                        true,
+                        {
+                            let view = view.clone();
+                            let path = target.path.clone();
+                            move |window, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.close_workspace_text_context_menu(cx);
+                                    this.confirm_convert_review_file_to_utf8(
+                                        path.clone(),
+                                        window,
+                                        cx,
+                                    );
+                                });
+                            }
+                        },
+                        cx,
+                    ));
+                }
+                items.push(self.render_workspace_text_context_menu_item(
+                    project_open::reveal_in_file_manager_label(),
+                    target.exists_on_disk,
+                    {
+                        let view = view.clone();
+                        let path = target.path.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.reveal_repo_file_in_file_manager(path.as_str(), cx);
+                            });
+                        }
+                    },
+                    cx,
+                ));
+                items.push(self.render_workspace_text_context_menu_item(
+                    "Open with Default Application",
+                    target.exists_on_disk,
+                    {
+                        let path = target.path.clone();
+                        move |_, cx| {
+                            view.update(cx, |this, cx| {
+                                this.close_workspace_text_context_menu(cx);
+                                this.open_repo_file_with_default_application(path.as_str(), cx);
+                            });
+                        }
+                    },
+                    cx,
+                ));
+            }
         }
         items
     }
 
     fn render_workspace_text_context_menu_item(
         &self,
-        label: &'static str,
+        label: impl Into<SharedString>,
         enabled: bool,
-        on_click: impl Fn(&mut App) + 'static,
+        on_click: impl Fn(&mut Window, &mut App) + 'static,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         let text_color = if enabled {
@@ -233,13 +422,13 @@ impl DiffViewer {
             .text_sm()
             .text_color(text_color)
             .when(enabled, |this| {
-                this.on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                this.on_mouse_down(MouseButton::Left, move |_, window, cx| {
                     cx.stop_propagation();
-                    on_click(cx);
+                    on_click(window, cx);
                 })
                 .hover(move |style| style.bg(hover_bg).cursor_pointer())
             })
-            .child(div().min_w_0().truncate().child(label))
+            .child(div().min_w_0().truncate().child(label.into()))
             .into_any_element()
     }
 }
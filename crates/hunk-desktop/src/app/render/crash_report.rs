@@ -0,0 +1,216 @@
+impl DiffViewer {
+    fn render_crash_report_banner(&self, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let message = self
+            .previous_crash_report
+            .as_ref()
+            .map(|report| {
+                format!(
+                    "Hunk crashed during the previous session: {}",
+                    report.message
+                )
+            })
+            .unwrap_or_default();
+
+        h_flex()
+            .id("crash-report-banner")
+            .w_full()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .px_3()
+            .py_1p5()
+            .border_b_1()
+            .border_color(cx.theme().warning)
+            .bg(hunk_opacity(cx.theme().warning, is_dark, 0.14, 0.08))
+            .child(
+                div()
+                    .min_w_0()
+                    .flex_1()
+                    .text_sm()
+                    .text_color(cx.theme().foreground)
+                    .truncate()
+                    .child(message),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child({
+                        let view = view.clone();
+                        Button::new("crash-report-view")
+                            .outline()
+                            .compact()
+                            .rounded(px(8.0))
+                            .label("View Report")
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.open_crash_report_viewer(cx);
+                                });
+                            })
+                    })
+                    .child(
+                        Button::new("crash-report-dismiss")
+                            .ghost()
+                            .compact()
+                            .rounded(px(8.0))
+                            .label("Dismiss")
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.dismiss_previous_crash_report(cx);
+                                });
+                            }),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_crash_report_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(report) = self.previous_crash_report.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+
+        div()
+            .id("crash-report-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("crash-report-popup")
+                            .w_full()
+                            .max_w(px(720.0))
+                            .max_h(px(640.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Crash Report"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .whitespace_normal()
+                                            .child(
+                                                "Stored only on this machine. It contains the \
+                                                 panic, a backtrace and where recent log events \
+                                                 came from, but no repository content.",
+                                            ),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .id("crash-report-body")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_y_scroll()
+                                    .m_4()
+                                    .p_3()
+                                    .rounded(px(8.0))
+                                    .border_1()
+                                    .border_color(input_surface.border)
+                                    .bg(input_surface.background)
+                                    .text_xs()
+                                    .font_family(cx.theme().mono_font_family.clone())
+                                    .text_color(cx.theme().foreground)
+                                    .whitespace_normal()
+                                    .children(
+                                        report
+                                            .to_issue_text()
+                                            .lines()
+                                            .map(|line| div().child(line.to_string()))
+                                            .collect::<Vec<_>>(),
+                                    ),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("crash-report-copy")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Copy Report for Issue")
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.copy_previous_crash_report(cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("crash-report-dismiss-from-viewer")
+                                            .ghost()
+                                            .rounded(px(8.0))
+                                            .label("Dismiss")
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.dismiss_previous_crash_report(cx);
+                                                });
+                                            })
+                                    })
+                                    .child(
+                                        Button::new("crash-report-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label("Close")
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_crash_report_viewer(window, cx);
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
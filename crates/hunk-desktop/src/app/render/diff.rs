@@ -53,7 +53,7 @@ impl DiffViewer {
                             .font_family(cx.theme().mono_font_family.clone())
                             .bg(chrome.column_header_badge_background)
                             .text_color(cx.theme().muted_foreground)
-                            .child("OLD"),
+                            .child(tr("diff-column-old")),
                     )
                     .child(
                         div()
@@ -82,7 +82,7 @@ impl DiffViewer {
                             .font_family(cx.theme().mono_font_family.clone())
                             .bg(chrome.column_header_badge_background)
                             .text_color(cx.theme().muted_foreground)
-                            .child("NEW"),
+                            .child(tr("diff-column-new")),
                     )
                     .child(
                         div()
@@ -95,6 +95,51 @@ impl DiffViewer {
             .into_any_element()
     }
 
+    fn render_unified_diff_column_header(
+        &self,
+        old_label: String,
+        new_label: String,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let chrome = hunk_diff_chrome(cx.theme(), is_dark);
+        let badge = |label: String| {
+            div()
+                .px_1p5()
+                .py_0p5()
+                .text_xs()
+                .font_semibold()
+                .font_family(cx.theme().mono_font_family.clone())
+                .bg(chrome.column_header_badge_background)
+                .text_color(cx.theme().muted_foreground)
+                .child(label)
+        };
+        let source = |label: String| {
+            div()
+                .min_w_0()
+                .truncate()
+                .text_xs()
+                .font_family(cx.theme().mono_font_family.clone())
+                .text_color(cx.theme().muted_foreground)
+                .child(label)
+        };
+
+        h_flex()
+            .w_full()
+            .items_center()
+            .gap_2()
+            .px_3()
+            .py_1()
+            .border_b_1()
+            .border_color(chrome.row_divider)
+            .bg(chrome.column_header_background)
+            .child(badge(tr("diff-column-old")))
+            .child(source(old_label))
+            .child(badge(tr("diff-column-new")))
+            .child(source(new_label))
+            .into_any_element()
+    }
+
     fn render_diff_split_handle(
         &self,
         layout: DiffColumnLayout,
@@ -227,6 +272,9 @@ impl DiffViewer {
     }
 
     fn render_open_project_empty_state(&self, cx: &mut Context<Self>) -> AnyElement {
+        if let Some(path) = self.repo_init_path.as_ref() {
+            return self.render_repo_init_state(path.display().to_string(), cx);
+        }
         let view = cx.entity();
         let is_dark = cx.theme().mode.is_dark();
 
@@ -260,15 +308,31 @@ impl DiffViewer {
                             .child("Choose a folder that contains a Git repository."),
                     )
                     .child(
-                        Button::new("open-project-empty-state")
-                            .primary()
-                            .rounded(px(8.0))
-                            .label("Open Project Folder")
-                            .on_click(move |_, _, cx| {
-                                view.update(cx, |this, cx| {
-                                    this.open_project_picker(cx);
-                                });
-                            }),
+                        h_flex()
+                            .gap_2()
+                            .child({
+                                let view = view.clone();
+                                Button::new("open-project-empty-state")
+                                    .primary()
+                                    .rounded(px(8.0))
+                                    .label("Open Project Folder")
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.open_project_picker(cx);
+                                        });
+                                    })
+                            })
+                            .child(
+                                Button::new("clone-repository-empty-state")
+                                    .outline()
+                                    .rounded(px(8.0))
+                                    .label("Clone Repository")
+                                    .on_click(move |_, window, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.open_repo_clone(window, cx);
+                                        });
+                                    }),
+                            ),
                     )
                     .child(
                         div()
@@ -280,6 +344,165 @@ impl DiffViewer {
             .into_any_element()
     }
 
+    fn render_repo_init_state(&self, path_label: String, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let template = self.repo_init_template;
+        let loading = self.repo_init_loading;
+
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .p_6()
+            .child(
+                v_flex()
+                    .items_center()
+                    .gap_3()
+                    .max_w(px(520.0))
+                    .px_8()
+                    .py_6()
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                    .bg(hunk_blend(cx.theme().sidebar, cx.theme().muted, is_dark, 0.22, 0.34))
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("No Git repository found"),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(path_label),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child({
+                                let view = view.clone();
+                                Button::new("repo-init-gitignore-dropdown")
+                                    .outline()
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .bg(dropdown_bg)
+                                    .dropdown_caret(true)
+                                    .disabled(loading)
+                                    .label(match template.gitignore {
+                                        GitignoreTemplate::None => "No .gitignore".to_string(),
+                                        gitignore => format!("{} .gitignore", gitignore.label()),
+                                    })
+                                    .dropdown_menu(move |menu, _, _| {
+                                        GitignoreTemplate::ALL.into_iter().fold(
+                                            menu,
+                                            |menu, gitignore| {
+                                                menu.item(
+                                                    PopupMenuItem::new(gitignore.label())
+                                                        .checked(template.gitignore == gitignore)
+                                                        .on_click({
+                                                            let view = view.clone();
+                                                            move |_, _, cx| {
+                                                                view.update(cx, |this, cx| {
+                                                                    this.set_repo_init_gitignore(
+                                                                        gitignore, cx,
+                                                                    );
+                                                                });
+                                                            }
+                                                        }),
+                                                )
+                                            },
+                                        )
+                                    })
+                            })
+                            .child({
+                                let view = view.clone();
+                                Button::new("repo-init-readme-dropdown")
+                                    .outline()
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .bg(dropdown_bg)
+                                    .dropdown_caret(true)
+                                    .disabled(loading)
+                                    .label(if template.readme {
+                                        "With README.md"
+                                    } else {
+                                        "No README"
+                                    })
+                                    .dropdown_menu(move |menu, _, _| {
+                                        [("With README.md", true), ("No README", false)]
+                                            .into_iter()
+                                            .fold(menu, |menu, (label, readme)| {
+                                                menu.item(
+                                                    PopupMenuItem::new(label)
+                                                        .checked(template.readme == readme)
+                                                        .on_click({
+                                                            let view = view.clone();
+                                                            move |_, _, cx| {
+                                                                view.update(cx, |this, cx| {
+                                                                    this.set_repo_init_readme(
+                                                                        readme, cx,
+                                                                    );
+                                                                });
+                                                            }
+                                                        }),
+                                                )
+                                            })
+                                    })
+                            }),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child({
+                                let view = view.clone();
+                                Button::new("repo-init-submit")
+                                    .primary()
+                                    .rounded(px(8.0))
+                                    .loading(loading)
+                                    .disabled(loading)
+                                    .label("Initialize Repository Here")
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.initialize_repo_here(cx);
+                                        });
+                                    })
+                            })
+                            .child({
+                                let view = view.clone();
+                                Button::new("repo-init-open-other")
+                                    .outline()
+                                    .rounded(px(8.0))
+                                    .disabled(loading)
+                                    .label("Open Another Folder")
+                                    .on_click(move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.open_project_picker(cx);
+                                        });
+                                    })
+                            })
+                            .when(self.project_path.is_some(), |this| {
+                                this.child(
+                                    Button::new("repo-init-cancel")
+                                        .ghost()
+                                        .rounded(px(8.0))
+                                        .disabled(loading)
+                                        .label("Cancel")
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.cancel_repo_init(cx);
+                                            });
+                                        }),
+                                )
+                            }),
+                    ),
+            )
+            .into_any_element()
+    }
+
     fn diff_column_labels(&self) -> (String, String) {
         if self.workspace_view_mode == WorkspaceViewMode::Diff {
             return (
@@ -0,0 +1,72 @@
+impl DiffViewer {
+    fn render_editor_recovery_banner(
+        &self,
+        view: Entity<Self>,
+        is_dark: bool,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        let buffer = self.active_editor_recovery_prompt()?;
+        let message = tr_args(
+            "editor-recovery-prompt",
+            &[(
+                "time",
+                relative_time_label(Some(buffer.saved_unix_time)).as_str(),
+            )],
+        );
+        let restore_view = view.clone();
+
+        Some(
+            h_flex()
+                .w_full()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .px_3()
+                .py_1p5()
+                .border_b_1()
+                .border_color(hunk_opacity(cx.theme().border, is_dark, 0.86, 0.72))
+                .bg(hunk_opacity(cx.theme().warning, is_dark, 0.14, 0.08))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .whitespace_normal()
+                        .child(message),
+                )
+                .child(
+                    h_flex()
+                        .flex_none()
+                        .items_center()
+                        .gap_1()
+                        .child(
+                            Button::new("editor-recovery-discard")
+                                .outline()
+                                .compact()
+                                .rounded(px(7.0))
+                                .label(tr("editor-recovery-discard"))
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.discard_editor_recovery_prompt(cx);
+                                    });
+                                }),
+                        )
+                        .child(
+                            Button::new("editor-recovery-restore")
+                                .primary()
+                                .compact()
+                                .rounded(px(7.0))
+                                .label(tr("editor-recovery-restore"))
+                                .disabled(self.editor_loading || self.editor_save_loading)
+                                .on_click(move |_, _, cx| {
+                                    restore_view.update(cx, |this, cx| {
+                                        this.restore_editor_recovery(cx);
+                                    });
+                                }),
+                        ),
+                )
+                .into_any_element(),
+        )
+    }
+}
@@ -28,8 +28,11 @@ struct ReviewWorkspaceFileHeaderPaint {
     view_label: SharedString,
     view_text_color: gpui::Hsla,
     view_background: gpui::Hsla,
+    actions_label: SharedString,
+    actions_text_color: gpui::Hsla,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_review_workspace_file_header_paint(
     theme: &Theme,
     path: &str,
@@ -38,6 +41,9 @@ fn build_review_workspace_file_header_paint(
     is_selected: bool,
     is_collapsed: bool,
     can_view_file: bool,
+    encoding: Option<&str>,
+    is_extracted_text: bool,
+    code_owners: Option<&str>,
 ) -> ReviewWorkspaceFileHeaderPaint {
     let is_dark = theme.mode.is_dark();
     let chrome = hunk_diff_chrome(theme, is_dark);
@@ -54,8 +60,24 @@ fn build_review_workspace_file_header_paint(
         badge_text_color: theme.foreground,
         path: SharedString::from(path.to_string()),
         path_text_color: theme.foreground,
-        stats_label: SharedString::from("file"),
-        stats_label_color: theme.muted_foreground,
+        // Extracted document text drops layout and formatting, so call out that the diff is
+        // only an approximation of the change.
+        stats_label: SharedString::from({
+            let label = if is_extracted_text {
+                "approximate text diff".to_string()
+            } else {
+                encoding.map_or_else(|| "file".to_string(), |encoding| format!("{encoding} file"))
+            };
+            match code_owners {
+                Some(owners) => format!("{label} • {owners}"),
+                None => label,
+            }
+        }),
+        stats_label_color: if is_extracted_text {
+            theme.warning
+        } else {
+            theme.muted_foreground
+        },
         stats_added: SharedString::from(format!("+{}", stats.added)),
         stats_added_color: line_stats.added,
         stats_removed: SharedString::from(format!("-{}", stats.removed)),
@@ -77,6 +99,8 @@ fn build_review_workspace_file_header_paint(
         } else {
             hunk_blend(theme.background, theme.muted, is_dark, 0.10, 0.06)
         },
+        actions_label: SharedString::from("More"),
+        actions_text_color: theme.foreground,
     }
 }
 
@@ -84,6 +108,7 @@ fn build_review_workspace_file_header_paint(
 pub(crate) struct ReviewWorkspaceFileHeaderControlsLayout {
     pub(crate) collapse_bounds: Bounds<Pixels>,
     pub(crate) view_bounds: Bounds<Pixels>,
+    pub(crate) actions_bounds: Bounds<Pixels>,
 }
 
 pub(crate) fn review_workspace_file_header_controls_layout(
@@ -95,6 +120,9 @@ pub(crate) fn review_workspace_file_header_controls_layout(
     let collapse_height = px(22.0);
     let view_width = px(72.0);
     let view_height = px(22.0);
+    let actions_width = px(44.0);
+    let control_gap = px(6.0);
+    let view_x = bounds.origin.x + bounds.size.width - right_padding - view_width;
 
     ReviewWorkspaceFileHeaderControlsLayout {
         collapse_bounds: Bounds {
@@ -106,11 +134,18 @@ pub(crate) fn review_workspace_file_header_controls_layout(
         },
         view_bounds: Bounds {
             origin: point(
-                bounds.origin.x + bounds.size.width - right_padding - view_width,
+                view_x,
                 bounds.origin.y + ((bounds.size.height - view_height) / 2.).max(Pixels::ZERO),
             ),
             size: gpui::size(view_width, view_height),
         },
+        actions_bounds: Bounds {
+            origin: point(
+                view_x - control_gap - actions_width,
+                bounds.origin.y + ((bounds.size.height - view_height) / 2.).max(Pixels::ZERO),
+            ),
+            size: gpui::size(actions_width, view_height),
+        },
     }
 }
 
@@ -127,7 +162,7 @@ fn paint_review_workspace_file_header_row(
     let badge_gap = px(8.0);
     let right_padding = px(12.0);
     let stats_gap = px(8.0);
-    let view_button_reserve = px(88.0);
+    let view_button_reserve = px(138.0);
     let badge_height = px(18.0);
 
     window.with_content_mask(Some(ContentMask { bounds }), |window| {
@@ -250,6 +285,8 @@ fn paint_review_workspace_file_header_row(
         paint_review_workspace_outline(window, controls.collapse_bounds, paint.control_border);
         window.paint_quad(gpui::fill(controls.view_bounds, paint.view_background));
         paint_review_workspace_outline(window, controls.view_bounds, paint.control_border);
+        window.paint_quad(gpui::fill(controls.actions_bounds, paint.control_background));
+        paint_review_workspace_outline(window, controls.actions_bounds, paint.control_border);
 
         let control_text_style = gpui::TextStyle {
             color: paint.view_text_color,
@@ -288,6 +325,32 @@ fn paint_review_workspace_file_header_row(
             control_line_height,
         );
 
+        let actions_runs = vec![single_color_text_run(
+            paint.actions_label.len(),
+            paint.actions_text_color,
+            control_font.clone(),
+        )];
+        let actions_shape = shape_editor_line(
+            window,
+            paint.actions_label.clone(),
+            control_font_size,
+            &actions_runs,
+        );
+        paint_editor_line(
+            window,
+            cx,
+            &actions_shape,
+            point(
+                controls.actions_bounds.origin.x
+                    + ((controls.actions_bounds.size.width - actions_shape.width()) / 2.)
+                        .max(Pixels::ZERO),
+                controls.actions_bounds.origin.y
+                    + ((controls.actions_bounds.size.height - control_line_height) / 2.)
+                        .max(Pixels::ZERO),
+            ),
+            control_line_height,
+        );
+
         let view_runs = vec![single_color_text_run(
             paint.view_label.len(),
             paint.view_text_color,
@@ -278,15 +278,23 @@ impl DiffViewer {
         let editor_font_size = cx.theme().mono_font_size * 1.2;
         let is_markdown_file = is_markdown_path(file_path.as_str());
         let preview_active = is_markdown_file && self.editor_markdown_preview;
-        let (editor_status, search_match_count, show_whitespace, soft_wrap_enabled) = {
+        let (
+            editor_status,
+            search_match_count,
+            show_whitespace,
+            soft_wrap_enabled,
+            format_error,
+        ) = {
             let files_editor = self.files_editor.borrow();
             (
                 files_editor.status_snapshot(),
                 files_editor.search_match_count(),
                 files_editor.show_whitespace(),
                 files_editor.soft_wrap_enabled(),
+                files_editor.format_error_message().map(ToOwned::to_owned),
             )
         };
+        let can_format = self.current_editor_formatter().is_some();
         let status_color = if self.editor_save_loading {
             cx.theme().warning
         } else if self.editor_dirty {
@@ -416,7 +424,8 @@ impl DiffViewer {
                                     .font_medium()
                                     .text_color(status_color)
                                     .child(status_label),
-                            ),
+                            )
+                            .children(self.render_file_authorship_button(cx)),
                     )
                     .child(
                         h_flex()
@@ -548,6 +557,23 @@ impl DiffViewer {
                                     div().into_any_element()
                                 }
                             )
+                            .when(can_format, |this| {
+                                let view = view.clone();
+                                this.child(
+                                    Button::new("editor-format")
+                                        .outline()
+                                        .compact()
+                                        .rounded(px(7.0))
+                                        .label(tr("editor-format"))
+                                        .tooltip(tr("editor-format-tooltip"))
+                                        .disabled(self.editor_save_loading || preview_active)
+                                        .on_click(move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.format_current_editor_file(false, cx);
+                                            });
+                                        }),
+                                )
+                            })
                             .child({
                                 let view = view.clone();
                                 let mut button = Button::new("editor-save")
@@ -577,6 +603,7 @@ impl DiffViewer {
                             }),
                     ),
             )
+            .children(self.render_file_history_panel(is_dark, cx))
             .when(self.editor_search_visible, |this| {
                 this.child(self.render_workspace_search_bar(
                     view.clone(),
@@ -587,6 +614,21 @@ impl DiffViewer {
                     cx,
                 ))
             })
+            .children(format_error.map(|message| {
+                div()
+                    .w_full()
+                    .px_3()
+                    .py_1p5()
+                    .border_b_1()
+                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.86, 0.72))
+                    .bg(hunk_opacity(cx.theme().danger, is_dark, 0.14, 0.08))
+                    .text_xs()
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_color(cx.theme().danger)
+                    .whitespace_normal()
+                    .child(message)
+            }))
+            .children(self.render_editor_recovery_banner(view.clone(), is_dark, cx))
             .child(editor_content)
             .when(terminal_state.open, |this| {
                 this.child(
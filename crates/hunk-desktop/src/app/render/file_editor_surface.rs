@@ -14,11 +14,13 @@ impl DiffViewer {
         let view = cx.entity();
         let is_editor_focused = self.files_editor_focus_handle.is_focused(window);
         let editor_chrome = hunk_editor_chrome_colors(cx.theme(), is_dark);
+        let line_height_factor = self.config.fonts.line_height;
         let text_style = TextStyle {
             color: editor_chrome.foreground,
             font_family: cx.theme().mono_font_family.clone(),
+            font_features: self.code_font_features(),
             font_size: editor_font_size.into(),
-            line_height: relative(1.45),
+            line_height: relative(line_height_factor),
             ..Default::default()
         };
         let editor_element = crate::app::native_files_editor::FilesEditorElement::new(
@@ -81,6 +83,8 @@ impl DiffViewer {
             .on_action(cx.listener(Self::files_editor_copy_action))
             .on_action(cx.listener(Self::files_editor_cut_action))
             .on_action(cx.listener(Self::files_editor_paste_action))
+            .on_action(cx.listener(Self::files_editor_toggle_line_comment_action))
+            .on_action(cx.listener(Self::files_editor_format_action))
             .on_action(cx.listener(Self::files_editor_move_up_action))
             .on_action(cx.listener(Self::files_editor_move_down_action))
             .on_action(cx.listener(Self::files_editor_move_left_action))
@@ -159,7 +163,7 @@ impl DiffViewer {
                 let view = view.clone();
                 move |event, _, cx| {
                     let handled = view.update(cx, |this, cx| {
-                        let line_height = (editor_font_size * 1.45).max(px(14.0));
+                        let line_height = (editor_font_size * line_height_factor).max(px(14.0));
                         if let Some((direction, line_count)) =
                             crate::app::native_files_editor::scroll_direction_and_count(
                                 event,
@@ -0,0 +1,102 @@
+fn file_authorship_summary(authorship: &FileAuthorship) -> Option<String> {
+    let last_commit = authorship.last_commit()?;
+    let contributors = match authorship.recent_contributor_count {
+        1 => "1 contributor".to_string(),
+        count => format!("{count} contributors"),
+    };
+    Some(format!(
+        "Last touched by {}, {}, {contributors} in last {FILE_AUTHORSHIP_WINDOW_DAYS} days",
+        last_commit.author_name,
+        relative_time_label(Some(last_commit.committed_unix_time)),
+    ))
+}
+
+impl DiffViewer {
+    fn render_file_authorship_button(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let summary = file_authorship_summary(self.active_file_authorship()?)?;
+        let view = cx.entity();
+
+        Some(
+            Button::new("editor-file-authorship")
+                .ghost()
+                .compact()
+                .with_size(gpui_component::Size::Small)
+                .label(summary)
+                .tooltip(if self.file_history_open {
+                    "Hide file history"
+                } else {
+                    "Show file history"
+                })
+                .on_click(move |_, _, cx| {
+                    view.update(cx, |this, cx| {
+                        this.toggle_file_history(cx);
+                    });
+                })
+                .into_any_element(),
+        )
+    }
+
+    fn render_file_history_panel(
+        &self,
+        is_dark: bool,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        if !self.file_history_open {
+            return None;
+        }
+        let authorship = self.active_file_authorship()?;
+
+        Some(
+            v_flex()
+                .id("editor-file-history")
+                .w_full()
+                .max_h(px(180.0))
+                .overflow_y_scroll()
+                .gap_1()
+                .px_3()
+                .py_2()
+                .border_b_1()
+                .border_color(hunk_opacity(cx.theme().border, is_dark, 0.86, 0.72))
+                .bg(hunk_blend(
+                    cx.theme().background,
+                    cx.theme().muted,
+                    is_dark,
+                    0.10,
+                    0.06,
+                ))
+                .children(authorship.commits.iter().map(|commit| {
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_xs()
+                                .font_family(cx.theme().mono_font_family.clone())
+                                .text_color(cx.theme().muted_foreground)
+                                .child(short_commit_id(commit.commit_id.as_str())),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .truncate()
+                                .text_xs()
+                                .text_color(cx.theme().foreground)
+                                .child(commit.subject.clone()),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!(
+                                    "{} • {}",
+                                    commit.author_name,
+                                    relative_time_label(Some(commit.committed_unix_time))
+                                )),
+                        )
+                }))
+                .into_any_element(),
+        )
+    }
+}
@@ -17,9 +17,22 @@ impl DiffViewer {
         } else {
             format!("branch {}", branch_scope_label)
         };
-        let subtitle = format!("Latest commits on {branch_scope_description}.");
+        let (title, subtitle) = if self.abandoned_commits_visible {
+            (
+                "Recently Abandoned",
+                "Commits HEAD moved away from that no branch reaches. Restore one as a branch."
+                    .to_string(),
+            )
+        } else {
+            (
+                "Recent Commits",
+                format!("Latest commits on {branch_scope_description}."),
+            )
+        };
 
-        let list_container = if self.recent_commits_loading && self.recent_commits.is_empty() {
+        let list_container = if self.abandoned_commits_visible {
+            self.render_git_abandoned_commits_list(cx)
+        } else if self.recent_commits_loading && self.recent_commits.is_empty() {
             self.render_git_recent_commits_loading_skeleton(cx)
         } else if let Some(error) = self
             .recent_commits_error
@@ -77,22 +90,48 @@ impl DiffViewer {
             .border_color(colors.rail.border)
             .bg(colors.rail.background)
             .child(
-                v_flex()
-                    .gap_0p5()
+                h_flex()
+                    .w_full()
+                    .items_start()
+                    .justify_between()
+                    .gap_2()
                     .child(
-                        div()
-                            .text_sm()
-                            .font_semibold()
-                            .text_color(cx.theme().foreground)
-                            .child("Recent Commits"),
+                        v_flex()
+                            .min_w_0()
+                            .gap_0p5()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(title),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .whitespace_normal()
+                                    .child(subtitle),
+                            ),
                     )
-                    .child(
-                        div()
-                            .text_xs()
-                            .text_color(cx.theme().muted_foreground)
-                            .whitespace_normal()
-                            .child(subtitle),
-                    ),
+                    .child({
+                        let view = view.clone();
+                        Button::new("git-abandoned-commits-toggle")
+                            .ghost()
+                            .compact()
+                            .rounded(px(8.0))
+                            .label(if self.abandoned_commits_visible {
+                                "Recent"
+                            } else {
+                                "Abandoned"
+                            })
+                            .tooltip("Show commits you reset, amended or deleted away")
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.toggle_abandoned_commits(cx);
+                                });
+                            })
+                    }),
             )
             .child(
                 h_flex()
@@ -185,6 +224,13 @@ impl DiffViewer {
         let colors = hunk_git_workspace(cx.theme(), is_dark);
         let short_commit_id = short_commit_id(commit.commit_id.as_str());
         let stable_row_id = stable_recent_commit_row_id(commit.commit_id.as_str());
+        let ticket_ids = self.linked_ticket_ids(commit.subject.as_str());
+        let is_expanded =
+            self.recent_commit_files_commit_id.as_deref() == Some(commit.commit_id.as_str());
+        let view = cx.entity();
+        let commit_id = commit.commit_id.clone();
+        let details_view = view.clone();
+        let details_commit_id = commit.commit_id.clone();
 
         v_flex()
             .id(("git-recent-commit-row", stable_row_id))
@@ -193,8 +239,18 @@ impl DiffViewer {
             .p_2()
             .rounded(px(10.0))
             .border_1()
-            .border_color(colors.muted_card.border)
+            .border_color(if is_expanded {
+                hunk_opacity(cx.theme().accent, is_dark, 0.72, 0.56)
+            } else {
+                colors.muted_card.border
+            })
             .bg(colors.card.background)
+            .cursor_pointer()
+            .on_click(move |_, _, cx| {
+                view.update(cx, |this, cx| {
+                    this.toggle_recent_commit_files(commit_id.clone(), cx);
+                });
+            })
             .child(
                 div()
                     .text_sm()
@@ -210,22 +266,239 @@ impl DiffViewer {
                     .justify_between()
                     .gap_2()
                     .flex_wrap()
+                    .child(
+                        h_flex()
+                            .items_center()
+                            .gap_1()
+                            .flex_wrap()
+                            .child(
+                                div()
+                                    .px_1p5()
+                                    .py_0p5()
+                                    .rounded(px(999.0))
+                                    .bg(hunk_opacity(cx.theme().muted, is_dark, 0.40, 0.58))
+                                    .text_xs()
+                                    .font_family(cx.theme().mono_font_family.clone())
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(short_commit_id),
+                            )
+                            .children(ticket_ids.into_iter().map(|ticket_id| {
+                                let link_id = stable_recent_commit_row_id(
+                                    format!("{}:{ticket_id}", commit.commit_id).as_str(),
+                                );
+                                self.render_git_ticket_link(
+                                    ("git-recent-commit-ticket", link_id),
+                                    ticket_id,
+                                    cx,
+                                )
+                            })),
+                    )
                     .child(
                         div()
-                            .px_1p5()
-                            .py_0p5()
-                            .rounded(px(999.0))
-                            .bg(hunk_opacity(cx.theme().muted, is_dark, 0.40, 0.58))
                             .text_xs()
-                            .font_family(cx.theme().mono_font_family.clone())
                             .text_color(cx.theme().muted_foreground)
-                            .child(short_commit_id),
+                            .child(relative_time_label(commit.committed_unix_time)),
+                    ),
+            )
+            .when(is_expanded, |this| {
+                this.child(self.render_git_recent_commit_files(cx)).child(
+                    h_flex().w_full().justify_end().child(
+                        Button::new(("git-recent-commit-details", stable_row_id))
+                            .outline()
+                            .compact()
+                            .rounded(px(8.0))
+                            .label(tr("commit-details-open"))
+                            .on_click(move |_, _, cx| {
+                                cx.stop_propagation();
+                                details_view.update(cx, |this, cx| {
+                                    this.open_commit_details(details_commit_id.clone(), cx);
+                                });
+                            }),
+                    ),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn render_git_recent_commit_files(&self, cx: &mut Context<Self>) -> AnyElement {
+        let message = if self.recent_commit_files_loading {
+            Some((
+                "Loading changed files...".to_string(),
+                cx.theme().muted_foreground,
+            ))
+        } else if let Some(error) = self.recent_commit_files_error.as_ref() {
+            Some((error.clone(), cx.theme().danger))
+        } else if self.recent_commit_files.is_empty() {
+            Some(("No file changes.".to_string(), cx.theme().muted_foreground))
+        } else {
+            None
+        };
+        if let Some((message, color)) = message {
+            return div()
+                .pt_1()
+                .text_xs()
+                .text_color(color)
+                .whitespace_normal()
+                .child(message)
+                .into_any_element();
+        }
+
+        v_flex()
+            .w_full()
+            .gap_0p5()
+            .pt_1()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .children(self.recent_commit_files.iter().map(|file| {
+                let (status_label, status_color) = change_status_label_color(file.status, cx);
+                h_flex()
+                    .w_full()
+                    .min_w_0()
+                    .items_center()
+                    .gap_1p5()
+                    .child(
+                        div()
+                            .flex_none()
+                            .w(px(28.0))
+                            .text_xs()
+                            .font_family(cx.theme().mono_font_family.clone())
+                            .text_color(status_color)
+                            .child(status_label),
                     )
                     .child(
                         div()
+                            .flex_1()
+                            .min_w_0()
+                            .truncate()
                             .text_xs()
-                            .text_color(cx.theme().muted_foreground)
-                            .child(relative_time_label(commit.committed_unix_time)),
+                            .text_color(cx.theme().foreground)
+                            .child(file.path.clone()),
+                    )
+                    .child(self.render_workspace_change_stats(file.line_stats, cx))
+            }))
+            .into_any_element()
+    }
+
+    fn render_git_abandoned_commits_list(&self, cx: &mut Context<Self>) -> AnyElement {
+        if self.abandoned_commits_loading && self.abandoned_commits.is_empty() {
+            return self.render_git_recent_commits_loading_skeleton(cx);
+        }
+        let message = if let Some(error) = self.abandoned_commits_error.as_ref() {
+            Some((error.clone(), cx.theme().danger))
+        } else if self.abandoned_commits.is_empty() {
+            Some((
+                "Nothing abandoned recently.".to_string(),
+                cx.theme().muted_foreground,
+            ))
+        } else {
+            None
+        };
+        if let Some((message, color)) = message {
+            return v_flex()
+                .w_full()
+                .items_center()
+                .justify_center()
+                .p_3()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(color)
+                        .whitespace_normal()
+                        .child(message),
+                )
+                .into_any_element();
+        }
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .pb_2()
+            .children(
+                self.abandoned_commits
+                    .iter()
+                    .map(|commit| self.render_git_abandoned_commit_row(commit, cx)),
+            )
+            .into_any_element()
+    }
+
+    fn render_git_abandoned_commit_row(
+        &self,
+        commit: &AbandonedCommit,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let colors = hunk_git_workspace(cx.theme(), is_dark);
+        let stable_row_id = stable_recent_commit_row_id(commit.commit_id.as_str());
+        let restoring =
+            self.abandoned_commit_restoring.as_deref() == Some(commit.commit_id.as_str());
+        let commit_id = commit.commit_id.clone();
+
+        v_flex()
+            .id(("git-abandoned-commit-row", stable_row_id))
+            .w_full()
+            .gap_1()
+            .p_2()
+            .rounded(px(10.0))
+            .border_1()
+            .border_color(colors.muted_card.border)
+            .bg(colors.card.background)
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .whitespace_normal()
+                    .child(commit.subject.clone()),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .whitespace_normal()
+                    .child(commit.reason.clone()),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .child(
+                        h_flex()
+                            .items_center()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .px_1p5()
+                                    .py_0p5()
+                                    .rounded(px(999.0))
+                                    .bg(hunk_opacity(cx.theme().muted, is_dark, 0.40, 0.58))
+                                    .text_xs()
+                                    .font_family(cx.theme().mono_font_family.clone())
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(short_commit_id(commit.commit_id.as_str())),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(relative_time_label(Some(commit.abandoned_unix_time))),
+                            ),
+                    )
+                    .child(
+                        Button::new(("git-abandoned-commit-restore", stable_row_id))
+                            .outline()
+                            .compact()
+                            .rounded(px(8.0))
+                            .label("Restore")
+                            .loading(restoring)
+                            .disabled(self.abandoned_commit_restoring.is_some())
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.restore_abandoned_commit_by_id(commit_id.clone(), cx);
+                                });
+                            }),
                     ),
             )
             .into_any_element()
@@ -0,0 +1,232 @@
+impl DiffViewer {
+    fn render_git_recovery_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(prompt) = self.git_recovery_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let is_identity = prompt.action == GitRecoveryAction::ConfigureIdentity;
+        let (title, description, primary_label, secondary_label) = if is_identity {
+            (
+                "Set Git Identity",
+                "Commits need user.name and user.email.",
+                "Name",
+                "Email",
+            )
+        } else {
+            (
+                "Add Git Remote",
+                "Publishing needs a remote to push to.",
+                "Remote name",
+                "Remote URL",
+            )
+        };
+        let scope_label = match prompt.scope {
+            GitConfigScope::Global => "All repositories",
+            GitConfigScope::Repository => "This repository",
+        };
+
+        let field = |label: &'static str, input_state: &Entity<InputState>| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(label),
+                )
+                .child(
+                    Input::new(input_state)
+                        .h(px(34.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background),
+                )
+        };
+
+        div()
+            .id("git-recovery-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("git-recovery-popup")
+                            .w_full()
+                            .max_w(px(460.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(title),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(description),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .child(field(primary_label, &prompt.primary_input_state))
+                                    .child(field(secondary_label, &prompt.secondary_input_state))
+                                    .when(is_identity, |this| {
+                                        let view = view.clone();
+                                        let selected_scope = prompt.scope;
+                                        this.child(
+                                            h_flex()
+                                                .w_full()
+                                                .items_center()
+                                                .justify_between()
+                                                .gap_3()
+                                                .child(
+                                                    div()
+                                                        .text_sm()
+                                                        .text_color(cx.theme().foreground)
+                                                        .child("Apply to"),
+                                                )
+                                                .child(
+                                                    Button::new("git-recovery-scope-dropdown")
+                                                        .outline()
+                                                        .compact()
+                                                        .rounded(px(8.0))
+                                                        .bg(dropdown_bg)
+                                                        .dropdown_caret(true)
+                                                        .label(scope_label)
+                                                        .dropdown_menu(move |menu, _, _| {
+                                                            menu.item(
+                                                                PopupMenuItem::new("All repositories")
+                                                                    .checked(
+                                                                        selected_scope
+                                                                            == GitConfigScope::Global,
+                                                                    )
+                                                                    .on_click({
+                                                                        let view = view.clone();
+                                                                        move |_, _, cx| {
+                                                                            view.update(cx, |this, cx| {
+                                                                                this.set_git_recovery_scope(
+                                                                                    GitConfigScope::Global,
+                                                                                    cx,
+                                                                                );
+                                                                            });
+                                                                        }
+                                                                    }),
+                                                            )
+                                                            .item(
+                                                                PopupMenuItem::new("This repository")
+                                                                    .checked(
+                                                                        selected_scope
+                                                                            == GitConfigScope::Repository,
+                                                                    )
+                                                                    .on_click({
+                                                                        let view = view.clone();
+                                                                        move |_, _, cx| {
+                                                                            view.update(cx, |this, cx| {
+                                                                                this.set_git_recovery_scope(
+                                                                                    GitConfigScope::Repository,
+                                                                                    cx,
+                                                                                );
+                                                                            });
+                                                                        }
+                                                                    }),
+                                                            )
+                                                        }),
+                                                ),
+                                        )
+                                    })
+                                    .when_some(prompt.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("git-recovery-cancel")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Cancel")
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_git_recovery(window, cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("git-recovery-save")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(if prompt.saving { "Saving..." } else { "Save" })
+                                            .disabled(prompt.saving)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.save_git_recovery(cx);
+                                                });
+                                            })
+                                    }),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
@@ -93,6 +93,7 @@ impl DiffViewer {
         let is_dark = cx.theme().mode.is_dark();
         let colors = hunk_line_stats(cx.theme(), is_dark);
         let surface = hunk_tinted_button(cx.theme(), is_dark, HunkAccentTone::Neutral);
+        let overall_line_stats = self.git_workspace_overall_line_stats();
 
         h_flex()
             .items_center()
@@ -115,14 +116,14 @@ impl DiffViewer {
                     .text_xs()
                     .font_family(cx.theme().mono_font_family.clone())
                     .text_color(colors.added)
-                    .child(format!("+{}", self.git_workspace.overall_line_stats.added)),
+                    .child(format!("+{}", overall_line_stats.added)),
             )
             .child(
                 div()
                     .text_xs()
                     .font_family(cx.theme().mono_font_family.clone())
                     .text_color(colors.removed)
-                    .child(format!("-{}", self.git_workspace.overall_line_stats.removed)),
+                    .child(format!("-{}", overall_line_stats.removed)),
             )
             .into_any_element()
     }
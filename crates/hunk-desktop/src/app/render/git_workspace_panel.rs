@@ -2,12 +2,17 @@ const GIT_WORKSPACE_RAIL_WIDTH: f32 = 396.0;
 
 impl DiffViewer {
     fn render_git_workspace_operations_panel_v2(&self, cx: &mut Context<Self>) -> AnyElement {
+        let recent_commits_dock = self.workspace_panel_dock(WorkspacePanel::RecentCommits);
+
         h_flex()
             .size_full()
             .min_h_0()
             .min_w_0()
             .items_stretch()
             .gap_3()
+            .when(recent_commits_dock == PanelDock::Left, |this| {
+                this.child(self.render_git_recent_commits_dock(cx))
+            })
             .child(
                 div()
                     .flex_1()
@@ -25,17 +30,23 @@ impl DiffViewer {
                     .min_h_0()
                     .gap_3()
                     .child(self.render_git_branch_panel(cx))
+                    .children(self.render_git_code_owners_panel(cx))
                     .child(self.render_git_commit_panel(cx)),
             )
-            .child(
-                div()
-                    .flex_none()
-                    .w(px(GIT_RECENT_COMMITS_PANEL_WIDTH))
-                    .min_w(px(GIT_RECENT_COMMITS_PANEL_WIDTH))
-                    .h_full()
-                    .min_h_0()
-                    .child(self.render_git_recent_commits_panel(cx)),
-            )
+            .when(recent_commits_dock == PanelDock::Right, |this| {
+                this.child(self.render_git_recent_commits_dock(cx))
+            })
+            .into_any_element()
+    }
+
+    fn render_git_recent_commits_dock(&self, cx: &mut Context<Self>) -> AnyElement {
+        div()
+            .flex_none()
+            .w(px(GIT_RECENT_COMMITS_PANEL_WIDTH))
+            .min_w(px(GIT_RECENT_COMMITS_PANEL_WIDTH))
+            .h_full()
+            .min_h_0()
+            .child(self.render_git_recent_commits_panel(cx))
             .into_any_element()
     }
 
@@ -62,6 +73,30 @@ impl DiffViewer {
             .into_any_element()
     }
 
+    fn render_git_ticket_link(
+        &self,
+        id: impl Into<gpui::ElementId>,
+        ticket_id: String,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+
+        Button::new(id)
+            .ghost()
+            .compact()
+            .with_size(gpui_component::Size::Small)
+            .rounded(px(999.0))
+            .label(ticket_id.clone())
+            .tooltip("Open the ticket in the browser.")
+            .text_color(cx.theme().primary)
+            .on_click(move |_, _, cx| {
+                view.update(cx, |this, cx| {
+                    this.open_ticket_link(ticket_id.as_str(), cx);
+                });
+            })
+            .into_any_element()
+    }
+
     fn render_git_branch_panel(&self, cx: &mut Context<Self>) -> AnyElement {
         let view = cx.entity();
         let is_dark = cx.theme().mode.is_dark();
@@ -71,11 +106,18 @@ impl DiffViewer {
         let publish_loading = self.git_action_loading_named("Publish branch");
         let open_review_loading = self.git_action_loading_named("Open PR/MR");
         let copy_review_loading = self.git_action_loading_named("Copy PR/MR URL");
+        let changelog_loading = self.git_action_loading_named("Generate changelog");
         let git_controls_busy = self.git_rail_controls_busy();
         let branch_syncable = self.can_run_active_branch_actions_for_ui();
         let sync_disabled = !self.can_sync_current_branch_for_ui();
         let publish_disabled = !self.can_publish_current_branch_for_ui();
-        let create_or_activate_disabled = git_controls_busy || !self.branch_input_has_text;
+        let create_or_activate_disabled = git_controls_busy
+            || !self.branch_input_has_text
+            || self.branch_name_policy_message.is_some();
+        let show_branch_template_fill = self.has_branch_name_policy();
+        let active_ticket_id = self
+            .active_branch_ticket_id()
+            .filter(|ticket_id| self.ticket_url(ticket_id).is_some());
         let active_review_blocker = self.active_review_action_blocker_for_ui();
         let review_url_disabled = active_review_blocker.is_some();
         let active_target_label = self
@@ -218,7 +260,10 @@ impl DiffViewer {
                             HunkAccentTone::Neutral
                         },
                         cx,
-                    )),
+                    ))
+                    .when_some(active_ticket_id, |this, ticket_id| {
+                        this.child(self.render_git_ticket_link("git-branch-ticket", ticket_id, cx))
+                    }),
             )
             .child(
                 render_hunk_picker(
@@ -251,12 +296,41 @@ impl DiffViewer {
                     .border_color(colors.muted_card.border)
                     .disabled(git_controls_busy),
             )
+            .when_some(self.branch_name_policy_message.clone(), |this, message| {
+                this.child(
+                    div()
+                        .w_full()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(message),
+                )
+            })
             .child(
                 h_flex()
                     .w_full()
                     .items_center()
                     .gap_1p5()
                     .flex_wrap()
+                    .when(show_branch_template_fill, |this| {
+                        let view = view.clone();
+                        this.child(
+                            Button::new("fill-branch-template")
+                                .outline()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .rounded(px(8.0))
+                                .label("Fill Template")
+                                .tooltip(
+                                    "Start the branch name from the naming template, using your initials and a ticket id from the clipboard.",
+                                )
+                                .disabled(git_controls_busy)
+                                .on_click(move |_, window, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.fill_branch_input_from_template(window, cx);
+                                    });
+                                }),
+                        )
+                    })
                     .child({
                         let view = view.clone();
                         Button::new("create-or-switch-branch-v3")
@@ -325,6 +399,25 @@ impl DiffViewer {
                             button = button.primary();
                         }
                         button
+                    })
+                    .when(self.git_action_cancellable(), |this| {
+                        let view = view.clone();
+                        let cancelling = self.git_action_cancelling();
+                        this.child(
+                            Button::new("git-action-cancel-v3")
+                                .outline()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .rounded(px(8.0))
+                                .label(if cancelling { "Cancelling…" } else { "Cancel" })
+                                .disabled(cancelling)
+                                .tooltip("Stop the running Git network operation.")
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.cancel_git_action(cx);
+                                    });
+                                }),
+                        )
                     }),
             )
             .child(
@@ -374,6 +467,25 @@ impl DiffViewer {
                                     this.copy_current_branch_review_url(cx);
                                 });
                             })
+                    })
+                    .child({
+                        let view = view.clone();
+                        Button::new("generate-changelog-fragment")
+                            .outline()
+                            .compact()
+                            .with_size(gpui_component::Size::Small)
+                            .rounded(px(8.0))
+                            .loading(changelog_loading)
+                            .label("Changelog Fragment")
+                            .tooltip(
+                                "Write a changelog fragment from this branch's commits and open it for editing.",
+                            )
+                            .disabled(!branch_syncable || git_controls_busy)
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.generate_changelog_fragment(cx);
+                                });
+                            })
                     }),
             )
             .into_any_element()
@@ -0,0 +1,197 @@
+impl DiffViewer {
+    fn render_hunk_apply_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(prompt) = self.hunk_apply_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let description = format!("{}  {}", prompt.path, prompt.hunk.header);
+        let submit_label = match (prompt.applying, prompt.mode) {
+            (true, _) => "Applying...",
+            (false, HunkApplyMode::NewCommit) => "Commit to Branch",
+            (false, HunkApplyMode::AmendTip) => "Amend Branch Tip",
+        };
+        let mode_hint = match prompt.mode {
+            HunkApplyMode::NewCommit => {
+                "Adds a commit with just this hunk. A new branch starts where the current work forked."
+            }
+            HunkApplyMode::AmendTip => "Folds this hunk into the branch's latest commit.",
+        };
+
+        let field = |label: &'static str, input_state: &Entity<InputState>| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(label),
+                )
+                .child(
+                    Input::new(input_state)
+                        .h(px(34.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background)
+                        .disabled(prompt.applying),
+                )
+        };
+        let mode_button = |id: &'static str, label: &'static str, mode: HunkApplyMode| {
+            let view = view.clone();
+            Button::new(id)
+                .rounded(px(8.0))
+                .label(label)
+                .when(prompt.mode == mode, |this| this.primary())
+                .when(prompt.mode != mode, |this| this.outline())
+                .disabled(prompt.applying)
+                .on_click(move |_, _, cx| {
+                    view.update(cx, |this, cx| {
+                        this.set_hunk_apply_mode(mode, cx);
+                    });
+                })
+        };
+
+        div()
+            .id("hunk-apply-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("hunk-apply-popup")
+                            .w_full()
+                            .max_w(px(520.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Apply Hunk to Branch"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .font_family(cx.theme().mono_font_family.clone())
+                                            .text_color(cx.theme().muted_foreground)
+                                            .truncate()
+                                            .child(description),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .child(
+                                        h_flex()
+                                            .gap_2()
+                                            .child(mode_button(
+                                                "hunk-apply-mode-new",
+                                                "New Commit",
+                                                HunkApplyMode::NewCommit,
+                                            ))
+                                            .child(mode_button(
+                                                "hunk-apply-mode-amend",
+                                                "Amend Tip",
+                                                HunkApplyMode::AmendTip,
+                                            )),
+                                    )
+                                    .child(field("Branch", &prompt.branch_input_state))
+                                    .child(field("Commit message", &prompt.message_input_state))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(mode_hint),
+                                    )
+                                    .when_some(prompt.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("hunk-apply-cancel")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Cancel")
+                                            .disabled(prompt.applying)
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_hunk_apply_prompt(window, cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("hunk-apply-submit")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(submit_label)
+                                            .disabled(prompt.applying)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.submit_hunk_apply_prompt(cx);
+                                                });
+                                            })
+                                    }),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
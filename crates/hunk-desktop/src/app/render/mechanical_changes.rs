@@ -0,0 +1,128 @@
+impl DiffViewer {
+    fn render_review_mechanical_change_banners(&self, cx: &mut Context<Self>) -> Vec<AnyElement> {
+        self.review_mechanical_changes
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(group_ix, group)| {
+                self.render_review_mechanical_change_banner(group_ix, group, cx)
+            })
+            .collect()
+    }
+
+    fn render_review_mechanical_change_banner(
+        &self,
+        group_ix: usize,
+        group: &MechanicalChangeGroup,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let line_stats = hunk_line_stats(cx.theme(), is_dark);
+        let approved = self.review_mechanical_changes.approved.is_approved(group);
+        let title = tr_args(
+            if group.paths.len() == 1 {
+                "mechanical-change-title-one"
+            } else {
+                "mechanical-change-title"
+            },
+            &[
+                ("from", group.from.as_str()),
+                ("to", group.to.as_str()),
+                ("files", group.paths.len().to_string().as_str()),
+            ],
+        );
+        let detail = if approved {
+            tr("mechanical-change-approved")
+        } else {
+            tr_args(
+                "mechanical-change-lines",
+                &[("lines", group.changed_lines.to_string().as_str())],
+            )
+        };
+        let sample_line = |marker: &str, text: &str, color: Hsla| {
+            div()
+                .min_w_0()
+                .truncate()
+                .font_family(cx.theme().mono_font_family.clone())
+                .text_xs()
+                .text_color(color)
+                .child(format!("{marker}{text}"))
+        };
+
+        v_flex()
+            .id(("review-mechanical-change", group_ix))
+            .w_full()
+            .gap_1()
+            .px_3()
+            .py_1p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .bg(hunk_opacity(cx.theme().accent, is_dark, 0.14, 0.08))
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .child(
+                        h_flex()
+                            .min_w_0()
+                            .flex_1()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .min_w_0()
+                                    .truncate()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(title),
+                            )
+                            .child(
+                                div()
+                                    .flex_none()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(detail),
+                            ),
+                    )
+                    .child(
+                        Button::new(("review-mechanical-change-approve", group_ix))
+                            .when(approved, |button| button.ghost())
+                            .when(!approved, |button| button.outline())
+                            .compact()
+                            .rounded(px(8.0))
+                            .label(if approved {
+                                tr("mechanical-change-undo-approval")
+                            } else {
+                                tr("mechanical-change-approve-all")
+                            })
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.toggle_review_mechanical_change_approved(group_ix, cx);
+                                });
+                            }),
+                    ),
+            )
+            .when(!approved, |this| {
+                this.children(group.samples.iter().map(|sample| {
+                    v_flex()
+                        .w_full()
+                        .min_w_0()
+                        .child(sample_line(
+                            "",
+                            sample.path.as_str(),
+                            cx.theme().muted_foreground,
+                        ))
+                        .child(sample_line(
+                            "- ",
+                            sample.removed.as_str(),
+                            line_stats.removed,
+                        ))
+                        .child(sample_line("+ ", sample.added.as_str(), line_stats.added))
+                }))
+            })
+            .into_any_element()
+    }
+}
@@ -1,4 +1,7 @@
-use super::data::{DiffStreamRowKind, RepoTreeNodeKind, is_markdown_path};
+use super::data::{
+    DiffStreamRowKind, FilePathCopyFormat, RepoTreeNodeKind, is_markdown_path,
+    line_stats_sparkline_widths, path_is_stats_ignored,
+};
 use super::theme::*;
 use super::*;
 use crate::app::markdown_links::{MarkdownLinkRange, markdown_inline_text_and_link_ranges};
@@ -15,6 +18,9 @@ use hunk_codex::state::{ItemStatus, ThreadLifecycleStatus};
 use hunk_domain::markdown_preview::{
     MarkdownCodeTokenKind, MarkdownInlineSpan, MarkdownPreviewBlock,
 };
+use hunk_git::commit_message::{CHANGE_ID_TRAILER, COMMIT_SUBJECT_MAX_CHARS, REVIEWED_BY_TRAILER};
+use hunk_git::init::GitignoreTemplate;
+use hunk_git::operations::BranchChangeKind;
 
 fn change_status_label_color(
     status: FileStatus,
@@ -34,25 +40,44 @@ fn change_status_label_color(
 
 include!("toolbar.rs");
 include!("tree.rs");
+include!("tree_context_menu.rs");
 include!("commit.rs");
 include!("workspace_change_row.rs");
 include!("git_workspace_loading.rs");
 include!("git_recent_commits.rs");
+include!("commit_details.rs");
 include!("git_workspace_panel.rs");
+include!("code_owners.rs");
 include!("git_workspace.rs");
 include!("file_banner.rs");
 include!("file_status.rs");
 include!("comments.rs");
+include!("co_review.rs");
+include!("ci_log.rs");
+include!("repo_content_search.rs");
 include!("syntax_colors.rs");
 include!("diff.rs");
 include!("review_workspace_code_row.rs");
 include!("review_workspace_section.rs");
 include!("review_workspace_surface.rs");
+include!("mechanical_changes.rs");
+include!("change_set_size.rs");
 include!("context_menu.rs");
 include!("workspace_search_bar.rs");
 include!("file_editor.rs");
+include!("file_history.rs");
+include!("editor_recovery.rs");
 include!("file_editor_surface.rs");
 include!("file_quick_open.rs");
+include!("git_recovery.rs");
+include!("repo_clone.rs");
+include!("hunk_apply.rs");
+include!("commit_message_editor.rs");
+include!("repo_issues.rs");
+include!("operation_diff.rs");
+include!("operation_history.rs");
+include!("stack_editor.rs");
+include!("crash_report.rs");
 include!("ai_loading.rs");
 include!("ai.rs");
 include!("ai_composer.rs");
@@ -60,4 +85,12 @@ include!("ai_timeline_list_view.rs");
 include!("ai_workspace_sections.rs");
 include!("ai_helpers.rs");
 include!("settings.rs");
+include!("settings_git.rs");
+include!("settings_shortcuts.rs");
+include!("settings_fonts.rs");
+include!("settings_notifications.rs");
+include!("settings_gestures.rs");
+include!("settings_formatting.rs");
+include!("settings_usage_metrics.rs");
+include!("settings_maintenance.rs");
 include!("root.rs");
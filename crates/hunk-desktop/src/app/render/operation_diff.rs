@@ -0,0 +1,320 @@
+const OPERATION_DIFF_PATH_PREVIEW_LIMIT: usize = 20;
+
+impl DiffViewer {
+    fn render_operation_diff_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.operation_diff_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let summary = if panel.loading {
+            "Comparing operations...".to_string()
+        } else {
+            match panel.diff.as_ref() {
+                Some(diff) if diff.is_empty() => "Nothing changed between these operations.".into(),
+                Some(diff) => format!(
+                    "{} branch change(s), {} commit(s) added, {} commit(s) removed.",
+                    diff.branch_changes.len(),
+                    diff.commits_added.len(),
+                    diff.commits_removed.len()
+                ),
+                None => "Pick two operations from the HEAD reflog.".to_string(),
+            }
+        };
+
+        div()
+            .id("operation-diff-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("operation-diff-popup")
+                            .w_full()
+                            .max_w(px(640.0))
+                            .max_h(px(620.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Compare Operations"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(summary),
+                                    )
+                                    .child(self.render_operation_diff_picker(panel, true, cx))
+                                    .child(self.render_operation_diff_picker(panel, false, cx)),
+                            )
+                            .child(
+                                v_flex()
+                                    .id("operation-diff-body")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_y_scroll()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .when_some(panel.diff.as_ref(), |this, diff| {
+                                        this.children(self.render_operation_diff_sections(diff, cx))
+                                    })
+                                    .when_some(panel.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .whitespace_normal()
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        Button::new("operation-diff-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label("Close")
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_operation_diff(window, cx);
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_operation_diff_picker(
+        &self,
+        panel: &OperationDiffPanelState,
+        is_before: bool,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let (id, label, selected_ix) = if is_before {
+            ("operation-diff-before", "From", panel.before_ix)
+        } else {
+            ("operation-diff-after", "To", panel.after_ix)
+        };
+        let operations = panel.operations.clone();
+        let selected_label = operations
+            .get(selected_ix)
+            .map(operation_label)
+            .unwrap_or_else(|| "No operations".to_string());
+
+        h_flex()
+            .w_full()
+            .items_center()
+            .gap_2()
+            .child(
+                div()
+                    .w(px(40.0))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(label),
+            )
+            .child(
+                Button::new(id)
+                    .outline()
+                    .compact()
+                    .rounded(px(8.0))
+                    .bg(dropdown_bg)
+                    .dropdown_caret(true)
+                    .disabled(panel.operations.is_empty())
+                    .label(selected_label)
+                    .dropdown_menu(move |menu, _, _| {
+                        operations.iter().fold(menu, |menu, operation| {
+                            let ix = operation.index;
+                            menu.item(
+                                PopupMenuItem::new(operation_label(operation))
+                                    .checked(ix == selected_ix)
+                                    .on_click({
+                                        let view = view.clone();
+                                        move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                if is_before {
+                                                    this.select_operation_diff_before(ix, cx);
+                                                } else {
+                                                    this.select_operation_diff_after(ix, cx);
+                                                }
+                                            });
+                                        }
+                                    }),
+                            )
+                        })
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn render_operation_diff_sections(&self, diff: &OperationDiff, cx: &App) -> Vec<AnyElement> {
+        let mut sections = Vec::new();
+        if diff.head_before != diff.head_after {
+            sections.push(self.render_operation_diff_section(
+                "HEAD",
+                vec![format!(
+                    "{} -> {}",
+                    short_commit_id(diff.head_before.as_str()),
+                    short_commit_id(diff.head_after.as_str())
+                )],
+                cx,
+            ));
+        }
+        if !diff.branch_changes.is_empty() {
+            let lines = diff
+                .branch_changes
+                .iter()
+                .map(|change| {
+                    let before = change.before.as_deref().map(short_commit_id);
+                    let after = change.after.as_deref().map(short_commit_id);
+                    match change.kind {
+                        BranchChangeKind::Created => {
+                            format!("+ {} at {}", change.name, after.unwrap_or_default())
+                        }
+                        BranchChangeKind::Deleted => {
+                            format!("- {} (was {})", change.name, before.unwrap_or_default())
+                        }
+                        BranchChangeKind::Moved => format!(
+                            "~ {} {} -> {}",
+                            change.name,
+                            before.unwrap_or_default(),
+                            after.unwrap_or_default()
+                        ),
+                    }
+                })
+                .collect();
+            sections.push(self.render_operation_diff_section("Branches", lines, cx));
+        }
+        for (title, commits) in [
+            ("Commits Added", &diff.commits_added),
+            ("Commits Removed", &diff.commits_removed),
+        ] {
+            if commits.is_empty() {
+                continue;
+            }
+            let lines = commits
+                .iter()
+                .map(|commit| {
+                    format!(
+                        "{} {}",
+                        short_commit_id(commit.commit_id.as_str()),
+                        commit.subject
+                    )
+                })
+                .collect();
+            sections.push(self.render_operation_diff_section(title, lines, cx));
+        }
+        if !diff.changed_paths.is_empty() {
+            let mut lines = diff
+                .changed_paths
+                .iter()
+                .take(OPERATION_DIFF_PATH_PREVIEW_LIMIT)
+                .cloned()
+                .collect::<Vec<_>>();
+            let hidden = diff
+                .changed_paths
+                .len()
+                .saturating_sub(OPERATION_DIFF_PATH_PREVIEW_LIMIT);
+            if hidden > 0 {
+                lines.push(format!("...and {hidden} more"));
+            }
+            sections.push(self.render_operation_diff_section("Working Copy Files", lines, cx));
+        }
+        sections
+    }
+
+    fn render_operation_diff_section(
+        &self,
+        title: &'static str,
+        lines: Vec<String>,
+        cx: &App,
+    ) -> AnyElement {
+        let card_surface = hunk_card_surface(cx.theme(), cx.theme().mode.is_dark());
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .p_3()
+            .rounded(px(8.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(title),
+            )
+            .children(lines.into_iter().map(|line| {
+                div()
+                    .text_xs()
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_color(cx.theme().muted_foreground)
+                    .whitespace_normal()
+                    .child(line)
+            }))
+            .into_any_element()
+    }
+}
+
+fn operation_label(operation: &RepoOperation) -> String {
+    format!(
+        "{} {} ({})",
+        short_commit_id(operation.head_commit_id.as_str()),
+        operation.description,
+        relative_time_label(Some(operation.unix_time))
+    )
+}
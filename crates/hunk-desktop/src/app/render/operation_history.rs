@@ -0,0 +1,276 @@
+impl DiffViewer {
+    fn render_operation_history_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.operation_history_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let busy = panel.loading || panel.restoring;
+        let summary = if panel.restoring {
+            tr("operation-history-restoring")
+        } else if panel.operations.is_empty() {
+            tr("operation-history-loading")
+        } else {
+            tr_args(
+                "operation-history-summary",
+                &[("count", panel.operations.len().to_string().as_str())],
+            )
+        };
+        let details_summary = match panel.diff.as_ref() {
+            _ if panel.loading => tr("operation-history-loading-changes"),
+            Some(diff) if diff.is_empty() => tr("operation-history-no-changes"),
+            Some(_) => tr("operation-history-changes"),
+            None if panel.selected_ix + 1 >= panel.operations.len() => {
+                tr("operation-history-oldest")
+            }
+            None => String::new(),
+        };
+
+        div()
+            .id("operation-history-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("operation-history-popup")
+                            .w_full()
+                            .max_w(px(860.0))
+                            .h(px(620.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_1()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(tr("operation-history-title")),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .whitespace_normal()
+                                            .child(summary),
+                                    ),
+                            )
+                            .child(
+                                h_flex()
+                                    .flex_1()
+                                    .min_h_0()
+                                    .w_full()
+                                    .items_start()
+                                    .child(
+                                        v_flex()
+                                            .id("operation-history-list")
+                                            .w(px(340.0))
+                                            .h_full()
+                                            .flex_none()
+                                            .overflow_y_scroll()
+                                            .gap_1()
+                                            .p_2()
+                                            .border_r_1()
+                                            .border_color(hunk_opacity(
+                                                cx.theme().border,
+                                                is_dark,
+                                                0.92,
+                                                0.74,
+                                            ))
+                                            .children(panel.operations.iter().map(|operation| {
+                                                self.render_operation_history_row(
+                                                    operation,
+                                                    operation.index == panel.selected_ix,
+                                                    cx,
+                                                )
+                                            })),
+                                    )
+                                    .child(
+                                        v_flex()
+                                            .id("operation-history-details")
+                                            .flex_1()
+                                            .min_w_0()
+                                            .h_full()
+                                            .overflow_y_scroll()
+                                            .gap_3()
+                                            .p_4()
+                                            .when(!details_summary.is_empty(), |this| {
+                                                this.child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(cx.theme().muted_foreground)
+                                                        .child(details_summary),
+                                                )
+                                            })
+                                            .when_some(panel.diff.as_ref(), |this, diff| {
+                                                this.children(
+                                                    self.render_operation_diff_sections(diff, cx),
+                                                )
+                                            })
+                                            .when_some(
+                                                panel.error_message.clone(),
+                                                |this, message| {
+                                                    this.child(
+                                                        div()
+                                                            .text_sm()
+                                                            .text_color(cx.theme().danger)
+                                                            .whitespace_normal()
+                                                            .child(message),
+                                                    )
+                                                },
+                                            ),
+                                    ),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        Button::new("operation-history-undo")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label(tr("operation-history-undo"))
+                                            .disabled(busy || panel.operations.len() < 2)
+                                            .on_click({
+                                                let view = view.clone();
+                                                move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.undo_last_repo_operation(cx);
+                                                    });
+                                                }
+                                            }),
+                                    )
+                                    .child(
+                                        Button::new("operation-history-restore")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label(tr("operation-history-restore"))
+                                            .disabled(busy || panel.selected_ix == 0)
+                                            .on_click({
+                                                let view = view.clone();
+                                                move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.restore_selected_repo_operation(cx);
+                                                    });
+                                                }
+                                            }),
+                                    )
+                                    .child(
+                                        Button::new("operation-history-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(tr("operation-history-close"))
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_operation_history(window, cx);
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_operation_history_row(
+        &self,
+        operation: &RepoOperation,
+        selected: bool,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let ix = operation.index;
+
+        v_flex()
+            .id(("operation-history-row", ix))
+            .w_full()
+            .min_w_0()
+            .gap_0p5()
+            .rounded(px(8.0))
+            .px_2()
+            .py_1p5()
+            .hover(|style| style.bg(hunk_opacity(cx.theme().accent, is_dark, 0.18, 0.10)))
+            .when(selected, |this| {
+                this.bg(hunk_opacity(cx.theme().accent, is_dark, 0.24, 0.14))
+            })
+            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                view.update(cx, |this, cx| {
+                    this.select_operation_history_entry(ix, cx);
+                });
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .truncate()
+                    .text_sm()
+                    .text_color(cx.theme().foreground)
+                    .child(if operation.description.is_empty() {
+                        tr("operation-history-no-description")
+                    } else {
+                        operation.description.clone()
+                    }),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "{}  {}",
+                        short_commit_id(operation.head_commit_id.as_str()),
+                        relative_time_label(Some(operation.unix_time))
+                    )),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,169 @@
+impl DiffViewer {
+    fn render_repo_clone_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(prompt) = self.repo_clone_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let description = "Signs in with your SSH agent, SSH keys or Git credential helper.";
+        let submit_label = if prompt.cloning { "Cloning..." } else { "Clone" };
+        let destination_label = self
+            .repo_clone_destination(cx)
+            .map(|destination| format!("Clones into {}", destination.display()));
+
+        let field = |label: &'static str, input_state: &Entity<InputState>| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(label),
+                )
+                .child(
+                    Input::new(input_state)
+                        .h(px(34.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background)
+                        .disabled(prompt.cloning),
+                )
+        };
+
+        div()
+            .id("repo-clone-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("repo-clone-popup")
+                            .w_full()
+                            .max_w(px(520.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Clone Repository"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(description),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .w_full()
+                                    .gap_3()
+                                    .p_4()
+                                    .child(field("Repository URL", &prompt.url_input_state))
+                                    .child(field("Clone into", &prompt.parent_input_state))
+                                    .when_some(destination_label, |this, label| {
+                                        this.child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(label),
+                                        )
+                                    })
+                                    .when_some(prompt.progress.clone(), |this, progress| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().foreground)
+                                                .child(progress),
+                                        )
+                                    })
+                                    .when_some(prompt.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("repo-clone-cancel")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Cancel")
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_repo_clone(window, cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("repo-clone-submit")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(submit_label)
+                                            .disabled(prompt.cloning)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.start_repo_clone(cx);
+                                                });
+                                            })
+                                    }),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
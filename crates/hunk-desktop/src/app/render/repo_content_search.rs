@@ -0,0 +1,232 @@
+impl DiffViewer {
+    fn render_repo_content_search_panel(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.repo_content_search_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let divider = hunk_opacity(cx.theme().border, is_dark, 0.82, 0.66);
+        let match_highlight = gpui::HighlightStyle {
+            color: Some(cx.theme().foreground),
+            background_color: Some(hunk_opacity(cx.theme().warning, is_dark, 0.32, 0.24)),
+            font_weight: Some(gpui::FontWeight::SEMIBOLD),
+            ..gpui::HighlightStyle::default()
+        };
+        let match_count = panel.results.match_count();
+        let summary = if panel.searching {
+            tr("repo-search-searching")
+        } else if let Some(error_message) = panel.error_message.clone() {
+            error_message
+        } else if panel.query.trim().is_empty() {
+            tr("repo-search-hint")
+        } else if match_count == 0 {
+            tr_args(
+                "repo-search-no-matches",
+                &[
+                    ("query", panel.query.as_str()),
+                    (
+                        "searched",
+                        panel.results.searched_files.to_string().as_str(),
+                    ),
+                ],
+            )
+        } else {
+            tr_args(
+                if panel.results.truncated {
+                    "repo-search-first-matches"
+                } else {
+                    "repo-search-matches"
+                },
+                &[
+                    ("matches", match_count.to_string().as_str()),
+                    ("files", panel.results.files.len().to_string().as_str()),
+                    (
+                        "searched",
+                        panel.results.searched_files.to_string().as_str(),
+                    ),
+                ],
+            )
+        };
+
+        let header = h_flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .border_b_1()
+            .border_color(divider)
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(tr("repo-search-title")),
+            )
+            .child({
+                let view = view.clone();
+                Button::new("repo-content-search-close")
+                    .compact()
+                    .ghost()
+                    .rounded(px(7.0))
+                    .label(tr("repo-search-close"))
+                    .on_click(move |_, window, cx| {
+                        view.update(cx, |this, cx| {
+                            this.close_repo_content_search_panel(window, cx);
+                        });
+                    })
+            });
+
+        let body = v_flex()
+            .gap_2()
+            .px_3()
+            .py_3()
+            .child(
+                Input::new(&panel.query_input_state)
+                    .w_full()
+                    .rounded(px(7.0))
+                    .border_1()
+                    .border_color(input_surface.border)
+                    .bg(input_surface.background),
+            )
+            .child(
+                div()
+                    .min_w_0()
+                    .text_xs()
+                    .text_color(if panel.error_message.is_some() {
+                        cx.theme().danger
+                    } else {
+                        cx.theme().muted_foreground
+                    })
+                    .child(summary),
+            );
+
+        let list = v_flex()
+            .id("repo-content-search-results")
+            .max_h(px(420.0))
+            .min_h_0()
+            .overflow_y_scroll()
+            .px_1()
+            .pb_2()
+            .children(
+                panel
+                    .results
+                    .files
+                    .iter()
+                    .enumerate()
+                    .map(|(file_ix, file)| {
+                        v_flex()
+                            .id(("repo-content-search-file", file_ix))
+                            .w_full()
+                            .min_w_0()
+                            .child(
+                                h_flex()
+                                    .w_full()
+                                    .min_w_0()
+                                    .items_center()
+                                    .justify_between()
+                                    .gap_2()
+                                    .px_2()
+                                    .pt_1p5()
+                                    .pb_0p5()
+                                    .child(
+                                        div()
+                                            .min_w_0()
+                                            .truncate()
+                                            .text_xs()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(file.path.clone()),
+                                    )
+                                    .child(
+                                        div()
+                                            .flex_none()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(file.matches.len().to_string()),
+                                    ),
+                            )
+                            .children(file.matches.iter().enumerate().map(
+                                |(match_ix, search_match)| {
+                                    let view = view.clone();
+                                    h_flex()
+                                        .id(("repo-content-search-match", match_ix))
+                                        .w_full()
+                                        .min_w_0()
+                                        .items_center()
+                                        .gap_2()
+                                        .rounded(px(7.0))
+                                        .px_2()
+                                        .py_0p5()
+                                        .hover(|style| {
+                                            style.bg(hunk_opacity(
+                                                cx.theme().accent,
+                                                is_dark,
+                                                0.18,
+                                                0.10,
+                                            ))
+                                        })
+                                        .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.open_repo_content_search_match(
+                                                    file_ix, match_ix, window, cx,
+                                                );
+                                            });
+                                            cx.stop_propagation();
+                                        })
+                                        .child(
+                                            div()
+                                                .w(px(40.0))
+                                                .flex_none()
+                                                .text_xs()
+                                                .font_family(cx.theme().mono_font_family.clone())
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(search_match.line.to_string()),
+                                        )
+                                        .child(
+                                            div()
+                                                .min_w_0()
+                                                .flex_1()
+                                                .truncate()
+                                                .text_xs()
+                                                .font_family(cx.theme().mono_font_family.clone())
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(
+                                                    gpui::StyledText::new(
+                                                        search_match.preview.clone(),
+                                                    )
+                                                    .with_highlights(vec![(
+                                                        search_match.preview_match.clone(),
+                                                        match_highlight,
+                                                    )]),
+                                                ),
+                                        )
+                                },
+                            ))
+                    }),
+            );
+
+        v_flex()
+            .absolute()
+            .top(px(48.0))
+            .right(px(12.0))
+            .w(px(560.0))
+            .rounded(px(10.0))
+            .border_1()
+            .overflow_hidden()
+            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.72))
+            .bg(hunk_blend(
+                cx.theme().popover,
+                cx.theme().background,
+                is_dark,
+                0.20,
+                0.08,
+            ))
+            .child(header)
+            .child(body)
+            .child(list)
+            .into_any_element()
+    }
+}
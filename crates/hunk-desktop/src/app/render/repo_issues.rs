@@ -0,0 +1,216 @@
+impl DiffViewer {
+    fn render_repo_issues_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.repo_issues_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let busy = panel.loading || panel.resolving_ix.is_some();
+        let summary = if panel.loading {
+            "Checking the repository...".to_string()
+        } else if panel.issues.is_empty() {
+            "No issues found.".to_string()
+        } else {
+            format!("{} issue(s) need attention.", panel.issues.len())
+        };
+
+        div()
+            .id("repo-issues-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("repo-issues-popup")
+                            .w_full()
+                            .max_w(px(560.0))
+                            .max_h(px(560.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_0p5()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child("Repository Issues"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(summary),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .id("repo-issues-list")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_y_scroll()
+                                    .w_full()
+                                    .gap_2()
+                                    .p_4()
+                                    .children(panel.issues.iter().enumerate().map(
+                                        |(issue_ix, issue)| {
+                                            self.render_repo_issue_row(
+                                                issue_ix,
+                                                issue,
+                                                panel.resolving_ix == Some(issue_ix),
+                                                busy,
+                                                view.clone(),
+                                                cx,
+                                            )
+                                        },
+                                    ))
+                                    .when_some(panel.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("repo-issues-recheck")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label("Check Again")
+                                            .loading(panel.loading)
+                                            .disabled(busy)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.load_repo_issues(cx);
+                                                });
+                                            })
+                                    })
+                                    .child({
+                                        let view = view.clone();
+                                        Button::new("repo-issues-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label("Close")
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_repo_issues(window, cx);
+                                                });
+                                            })
+                                    }),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_repo_issue_row(
+        &self,
+        issue_ix: usize,
+        issue: &RepoIssue,
+        resolving: bool,
+        busy: bool,
+        view: Entity<Self>,
+        cx: &App,
+    ) -> AnyElement {
+        let card_surface = hunk_card_surface(cx.theme(), cx.theme().mode.is_dark());
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .p_3()
+            .rounded(px(8.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .child(
+                        div()
+                            .min_w_0()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(issue.title.clone()),
+                    )
+                    .when_some(issue.resolution.as_ref(), |this, resolution| {
+                        this.child(
+                            Button::new(("repo-issue-resolve", issue_ix))
+                                .outline()
+                                .compact()
+                                .rounded(px(8.0))
+                                .label(resolution.label())
+                                .loading(resolving)
+                                .disabled(busy)
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.resolve_repo_issue_at(issue_ix, cx);
+                                    });
+                                }),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .whitespace_normal()
+                    .child(issue.detail.clone()),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .whitespace_normal()
+                    .child(issue.suggestion.clone()),
+            )
+            .into_any_element()
+    }
+}
@@ -1,4 +1,5 @@
 use gpui::{App, SharedString, TextRun, Window, point, px};
+use hunk_domain::unicode_hazards::{UnicodeHazardKind, unicode_hazards};
 
 struct DiffCellRenderSpec {
     side: &'static str,
@@ -6,11 +7,15 @@ struct DiffCellRenderSpec {
     cell_kind: DiffCellKind,
     peer_kind: DiffCellKind,
     panel_width: Option<Pixels>,
+    direction: TextDirection,
 }
 
 #[derive(Clone)]
 struct ReviewWorkspaceCodeRowCellPaint {
     panel_width: Option<gpui::Pixels>,
+    direction: TextDirection,
+    /// Added lines call out invisible and confusable characters.
+    flag_unicode_hazards: bool,
     line_number_width: f32,
     background: gpui::Hsla,
     gutter_background: gpui::Hsla,
@@ -23,11 +28,14 @@ struct ReviewWorkspaceCodeRowCellPaint {
     display_row: hunk_editor::WorkspaceDisplayRow,
     syntax_spans: Vec<crate::app::native_files_editor::paint::RowSyntaxSpan>,
     changed_ranges: Vec<std::ops::Range<usize>>,
+    /// Columns of URLs and linked ticket ids, underlined.
+    link_ranges: Vec<std::ops::Range<usize>>,
 }
 
 #[derive(Clone)]
 struct ReviewWorkspaceMetaRowPaint {
     kind: DiffRowKind,
+    collapsed_hunk: bool,
     text: SharedString,
     background: gpui::Hsla,
     foreground: gpui::Hsla,
@@ -48,8 +56,7 @@ fn paint_review_workspace_code_row(
     bounds: Bounds<Pixels>,
     left: &ReviewWorkspaceCodeRowCellPaint,
     right: &ReviewWorkspaceCodeRowCellPaint,
-    center_divider: gpui::Hsla,
-    mono_font_family: SharedString,
+    style: &ReviewWorkspaceViewportPaintStyle,
 ) {
     let left_width = left.panel_width.unwrap_or(bounds.size.width / 2.);
     let right_width = right
@@ -64,41 +71,72 @@ fn paint_review_workspace_code_row(
         size: gpui::size(right_width, bounds.size.height),
     };
 
-    paint_review_workspace_code_cell(
-        window,
-        cx,
-        left_bounds,
-        left,
-        true,
-        center_divider,
-        mono_font_family.clone(),
-    );
-    paint_review_workspace_code_cell(
-        window,
-        cx,
-        right_bounds,
-        right,
-        false,
-        center_divider,
-        mono_font_family,
-    );
+    paint_review_workspace_code_cell(window, cx, left_bounds, left, true, style);
+    paint_review_workspace_code_cell(window, cx, right_bounds, right, false, style);
     window.paint_quad(gpui::fill(
         Bounds {
             origin: point(right_bounds.origin.x - px(1.0), bounds.origin.y),
             size: gpui::size(px(1.0), bounds.size.height),
         },
-        center_divider,
+        style.center_divider,
     ));
 }
 
+fn paint_review_workspace_unified_code_row(
+    window: &mut Window,
+    cx: &mut App,
+    bounds: Bounds<Pixels>,
+    viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+    left: &ReviewWorkspaceCodeRowCellPaint,
+    right: &ReviewWorkspaceCodeRowCellPaint,
+    style: &ReviewWorkspaceViewportPaintStyle,
+) {
+    let (left_bounds, right_bounds) = review_workspace_unified_cell_bounds(
+        bounds,
+        viewport_row.left_cell_kind,
+        viewport_row.right_cell_kind,
+    );
+    if let Some(left_bounds) = left_bounds {
+        paint_review_workspace_code_cell(window, cx, left_bounds, left, false, style);
+    }
+    if let Some(right_bounds) = right_bounds {
+        paint_review_workspace_code_cell(window, cx, right_bounds, right, false, style);
+    }
+}
+
+/// Where the unified view paints each side of a code row. A removed and added pair stacks the
+/// removed line above the added one; other rows show the side holding the line at full width,
+/// the new side for context lines.
+fn review_workspace_unified_cell_bounds(
+    bounds: Bounds<Pixels>,
+    left_kind: DiffCellKind,
+    right_kind: DiffCellKind,
+) -> (Option<Bounds<Pixels>>, Option<Bounds<Pixels>>) {
+    match (left_kind, right_kind) {
+        (DiffCellKind::Removed, DiffCellKind::Added) => {
+            let top_height = bounds.size.height / 2.;
+            let top = Bounds {
+                origin: bounds.origin,
+                size: gpui::size(bounds.size.width, top_height),
+            };
+            let bottom = Bounds {
+                origin: point(bounds.origin.x, bounds.origin.y + top_height),
+                size: gpui::size(bounds.size.width, bounds.size.height - top_height),
+            };
+            (Some(top), Some(bottom))
+        }
+        (left_kind, DiffCellKind::None) if left_kind != DiffCellKind::None => (Some(bounds), None),
+        _ => (None, Some(bounds)),
+    }
+}
+
 fn paint_review_workspace_code_cell(
     window: &mut Window,
     cx: &mut App,
     bounds: Bounds<Pixels>,
     cell: &ReviewWorkspaceCodeRowCellPaint,
     draw_right_divider: bool,
-    center_divider: gpui::Hsla,
-    mono_font_family: SharedString,
+    style: &ReviewWorkspaceViewportPaintStyle,
 ) {
     let padding_x = px(8.0);
     let gutter_padding_x = px(8.0);
@@ -128,14 +166,15 @@ fn paint_review_workspace_code_cell(
                 origin: point(divider_x, bounds.origin.y),
                 size: gpui::size(px(1.0), bounds.size.height),
             },
-            center_divider,
+            style.center_divider,
         ));
     }
 
     let text_style = gpui::TextStyle {
         color: cell.text_color,
-        font_family: mono_font_family,
-        font_size: px(12.0).into(),
+        font_family: style.mono_font_family.clone(),
+        font_size: style.code_font_size.into(),
+        font_features: style.code_font_features.clone(),
         line_height: gpui::relative(1.45),
         ..Default::default()
     };
@@ -195,11 +234,12 @@ fn paint_review_workspace_code_cell(
         0.42,
         0.26,
     );
-    let mut text_runs = build_review_workspace_text_runs(
+    let text_runs = build_review_workspace_text_runs(
         cx,
         &cell.display_row,
         &cell.syntax_spans,
         &cell.changed_ranges,
+        &cell.link_ranges,
         ReviewWorkspaceTextRunStyle {
             default_foreground: cell.text_color,
             font: font.clone(),
@@ -207,7 +247,17 @@ fn paint_review_workspace_code_cell(
             search_bg,
         },
     );
-    let mut text = cell.display_row.text.clone();
+    let (text, text_runs) = if cell.flag_unicode_hazards {
+        review_workspace_hazard_text(
+            cell.display_row.text.clone(),
+            text_runs,
+            cx.theme().warning_foreground,
+            cx.theme().warning,
+        )
+    } else {
+        (cell.display_row.text.clone(), text_runs)
+    };
+    let (mut text, mut text_runs) = review_workspace_visual_text(text, text_runs, cell.direction);
     if text_runs.is_empty() {
         text.push(' ');
         text_runs.push(TextRun {
@@ -226,7 +276,13 @@ fn paint_review_workspace_code_cell(
         font_size,
         &text_runs,
     );
-    let text_origin_x = gutter_bounds.origin.x + gutter_bounds.size.width + padding_x;
+    let text_start_x = gutter_bounds.origin.x + gutter_bounds.size.width + padding_x;
+    let text_origin_x = match cell.direction {
+        TextDirection::LeftToRight => text_start_x,
+        TextDirection::RightToLeft => {
+            (bounds.origin.x + bounds.size.width - padding_x - text_shape.width()).max(text_start_x)
+        }
+    };
     crate::app::native_files_editor::paint::paint_editor_line(
         window,
         cx,
@@ -236,14 +292,91 @@ fn paint_review_workspace_code_cell(
     );
 }
 
+/// The link under `position` in a code row, using the monospace cell width to find the column.
+/// Right-to-left cells are not hit tested.
+pub(crate) fn review_workspace_code_link_at_position(
+    window: &mut Window,
+    row_bounds: Bounds<Pixels>,
+    viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+    style: &ReviewWorkspaceViewportPaintStyle,
+    position: Point<Pixels>,
+) -> Option<crate::app::diff_links::DiffTextLinkTarget> {
+    if viewport_row.row_kind != DiffRowKind::Code
+        || viewport_row
+            .file_path
+            .as_deref()
+            .is_some_and(|path| style.right_to_left_files.contains(path))
+    {
+        return None;
+    }
+
+    let left_width = style.left_panel_width.unwrap_or(row_bounds.size.width / 2.);
+    let (cell_x, line_number_width, text) = if style.unified {
+        let (left_bounds, _) = review_workspace_unified_cell_bounds(
+            row_bounds,
+            viewport_row.left_cell_kind,
+            viewport_row.right_cell_kind,
+        );
+        let cell = if left_bounds.is_some_and(|bounds| bounds.contains(&position)) {
+            &viewport_row.left_cell
+        } else {
+            &viewport_row.right_cell
+        };
+        (
+            row_bounds.origin.x,
+            style
+                .left_line_number_width
+                .max(style.right_line_number_width),
+            cell.display_row.text.as_str(),
+        )
+    } else if position.x < row_bounds.origin.x + left_width {
+        (
+            row_bounds.origin.x,
+            style.left_line_number_width,
+            viewport_row.left_cell.display_row.text.as_str(),
+        )
+    } else {
+        (
+            row_bounds.origin.x + left_width,
+            style.right_line_number_width,
+            viewport_row.right_cell.display_row.text.as_str(),
+        )
+    };
+    // Matches the gutter and padding laid out by `paint_review_workspace_code_cell`.
+    let text_start_x =
+        cell_x + px(line_number_width) + px(DIFF_MARKER_GUTTER_WIDTH) + px(16.0) + px(8.0);
+    if position.x < text_start_x {
+        return None;
+    }
+
+    let links = crate::app::diff_links::diff_text_links(text, style.ticket_linker.as_ref());
+    if links.is_empty() {
+        return None;
+    }
+    let font = gpui::TextStyle {
+        font_family: style.mono_font_family.clone(),
+        font_features: style.code_font_features.clone(),
+        ..Default::default()
+    }
+    .font();
+    let font_id = window.text_system().resolve_font(&font);
+    let cell_width = window
+        .text_system()
+        .advance(font_id, style.code_font_size, 'm')
+        .map(|size| size.width)
+        .unwrap_or_else(|_| px(8.0));
+    let column = ((position.x - text_start_x) / cell_width).floor() as usize;
+    crate::app::diff_links::diff_text_link_at_column(&links, column).map(|link| link.target.clone())
+}
+
 fn paint_review_workspace_meta_row(
     window: &mut Window,
     cx: &mut App,
     bounds: Bounds<Pixels>,
     meta: &ReviewWorkspaceMetaRowPaint,
-    mono_font_family: SharedString,
+    style: &ReviewWorkspaceViewportPaintStyle,
 ) {
-    if meta.kind == DiffRowKind::HunkHeader {
+    if meta.kind == DiffRowKind::HunkHeader && !meta.collapsed_hunk {
         window.paint_quad(gpui::fill(bounds, meta.background));
         window.paint_quad(gpui::fill(
             Bounds {
@@ -273,8 +406,9 @@ fn paint_review_workspace_meta_row(
 
     let text_style = gpui::TextStyle {
         color: meta.foreground,
-        font_family: mono_font_family,
-        font_size: px(12.0).into(),
+        font_family: style.mono_font_family.clone(),
+        font_size: style.code_font_size.into(),
+        font_features: style.code_font_features.clone(),
         line_height: gpui::relative(1.45),
         ..Default::default()
     };
@@ -309,6 +443,7 @@ fn build_review_workspace_code_row_cell_paint(
     row_is_selected: bool,
     spec: DiffCellRenderSpec,
     viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+    ticket_linker: Option<&TicketLinker>,
 ) -> ReviewWorkspaceCodeRowCellPaint {
     let side = spec.side;
     let cell_kind = spec.cell_kind;
@@ -403,9 +538,15 @@ fn build_review_workspace_code_row_cell_paint(
     if row_is_selected {
         gutter_background = hunk_blend(gutter_background, theme.primary, is_dark, 0.14, 0.10);
     }
+    let link_ranges = crate::app::diff_links::diff_text_links(&display_row.text, ticket_linker)
+        .into_iter()
+        .map(|link| link.columns)
+        .collect();
 
     ReviewWorkspaceCodeRowCellPaint {
         panel_width: spec.panel_width,
+        direction: spec.direction,
+        flag_unicode_hazards: cell_kind == DiffCellKind::Added,
         line_number_width,
         background,
         gutter_background,
@@ -418,6 +559,7 @@ fn build_review_workspace_code_row_cell_paint(
         display_row,
         syntax_spans,
         changed_ranges,
+        link_ranges,
     }
 }
 
@@ -425,6 +567,7 @@ fn build_review_workspace_meta_row_paint(
     theme: &Theme,
     row_kind: DiffRowKind,
     row_text: &str,
+    is_warning: bool,
     is_selected: bool,
 ) -> ReviewWorkspaceMetaRowPaint {
     let is_dark = theme.mode.is_dark();
@@ -439,6 +582,11 @@ fn build_review_workspace_meta_row_paint(
             theme.primary_foreground,
             theme.primary,
         ),
+        DiffRowKind::Meta if is_warning => (
+            hunk_blend(theme.background, theme.warning, is_dark, 0.30, 0.18),
+            hunk_tone(theme.warning, is_dark, 0.35, 0.20),
+            theme.warning,
+        ),
         DiffRowKind::Meta => {
             if row_text.starts_with("new file mode") || row_text.starts_with("+++ b/") {
                 (
@@ -472,10 +620,19 @@ fn build_review_workspace_meta_row_paint(
     } else {
         background
     };
+    // Folded hunks show what they hide; expanded hunk headers stay a plain divider.
+    let collapsed_summary = (row_kind == DiffRowKind::HunkHeader)
+        .then(|| hunk_domain::diff::collapsed_hunk_summary(row_text))
+        .flatten();
+    let (text, foreground) = match collapsed_summary {
+        Some(summary) => (format!("\u{25b8} {summary}"), theme.muted_foreground),
+        None => (row_text.to_string(), foreground),
+    };
 
     ReviewWorkspaceMetaRowPaint {
         kind: row_kind,
-        text: row_text.to_string().into(),
+        collapsed_hunk: collapsed_summary.is_some(),
+        text: text.into(),
         background,
         foreground,
         accent,
@@ -488,6 +645,7 @@ fn build_review_workspace_text_runs(
     display_row: &hunk_editor::WorkspaceDisplayRow,
     syntax_spans: &[crate::app::native_files_editor::paint::RowSyntaxSpan],
     changed_ranges: &[std::ops::Range<usize>],
+    link_ranges: &[std::ops::Range<usize>],
     style: ReviewWorkspaceTextRunStyle,
 ) -> Vec<TextRun> {
     if display_row.text.is_empty() {
@@ -509,7 +667,7 @@ fn build_review_workspace_text_runs(
         boundaries.push(span.start_column.min(total_columns));
         boundaries.push(span.end_column.min(total_columns));
     }
-    for range in changed_ranges {
+    for range in changed_ranges.iter().chain(link_ranges) {
         if range.start < range.end {
             boundaries.push(range.start.min(total_columns));
             boundaries.push(range.end.min(total_columns));
@@ -557,12 +715,20 @@ fn build_review_workspace_text_runs(
         } else {
             None
         };
+        let underline = link_ranges
+            .iter()
+            .any(|range| range.start <= start && start < range.end)
+            .then_some(gpui::UnderlineStyle {
+                thickness: px(1.0),
+                color: Some(syntax),
+                wavy: false,
+            });
         runs.push(TextRun {
             len: column_byte_offsets[end].saturating_sub(column_byte_offsets[start]),
             color: syntax,
             font: style.font.clone(),
             background_color,
-            underline: None,
+            underline,
             strikethrough: None,
         });
     }
@@ -581,6 +747,111 @@ fn build_review_workspace_text_runs(
     runs
 }
 
+/// Swaps invisible and bidi control characters for a visible `<U+XXXX>` escape and paints them,
+/// along with confusable homoglyphs, in the warning colors.
+fn review_workspace_hazard_text(
+    text: String,
+    runs: Vec<TextRun>,
+    warning_foreground: gpui::Hsla,
+    warning_background: gpui::Hsla,
+) -> (String, Vec<TextRun>) {
+    let hazards = unicode_hazards(text.as_str());
+    if hazards.is_empty() || runs.is_empty() {
+        return (text, runs);
+    }
+
+    fn push_slice(
+        text: &str,
+        range: std::ops::Range<usize>,
+        run: &TextRun,
+        out: &mut (String, Vec<TextRun>),
+    ) {
+        if range.start < range.end {
+            out.0.push_str(&text[range.clone()]);
+            out.1.push(TextRun {
+                len: range.len(),
+                ..run.clone()
+            });
+        }
+    }
+
+    let mut out = (
+        String::with_capacity(text.len() + hazards.len() * 8),
+        Vec::with_capacity(runs.len() + hazards.len() * 2),
+    );
+    let mut hazards = hazards.into_iter().peekable();
+    let mut run_start = 0;
+    let mut cursor = 0;
+    for run in &runs {
+        let run_end = run_start + run.len;
+        while let Some(hazard) = hazards.next_if(|hazard| hazard.range.start < run_end) {
+            push_slice(&text, cursor.max(run_start)..hazard.range.start, run, &mut out);
+            let replacement = match hazard.kind {
+                UnicodeHazardKind::Confusable { .. } => text[hazard.range.clone()].to_string(),
+                UnicodeHazardKind::Invisible | UnicodeHazardKind::BidiControl => {
+                    format!("<U+{:04X}>", hazard.ch as u32)
+                }
+            };
+            out.0.push_str(replacement.as_str());
+            out.1.push(TextRun {
+                len: replacement.len(),
+                color: warning_foreground,
+                background_color: Some(warning_background),
+                ..run.clone()
+            });
+            cursor = hazard.range.end;
+        }
+        push_slice(&text, cursor.max(run_start)..run_end, run, &mut out);
+        run_start = run_end;
+    }
+    out
+}
+
+/// Puts a code cell's text and runs in screen order when the line holds right-to-left text or
+/// the file is laid out right to left. Line numbers and markers are shaped on their own, so the
+/// gutter always reads left to right.
+fn review_workspace_visual_text(
+    text: String,
+    runs: Vec<TextRun>,
+    direction: TextDirection,
+) -> (String, Vec<TextRun>) {
+    if runs.is_empty() {
+        return (text, runs);
+    }
+    let Some(visual_runs) = hunk_domain::bidi::visual_runs(text.as_str(), direction) else {
+        return (text, runs);
+    };
+
+    let run_ends = runs
+        .iter()
+        .scan(0, |end, run| {
+            *end += run.len;
+            Some(*end)
+        })
+        .collect::<Vec<_>>();
+    let mut visual_text = String::with_capacity(text.len());
+    let mut visual_text_runs = Vec::<TextRun>::with_capacity(runs.len());
+    let mut last_run_ix = None;
+    for visual_char in hunk_domain::bidi::visual_chars(text.as_str(), &visual_runs) {
+        let run_ix = run_ends
+            .partition_point(|end| *end <= visual_char.logical_offset)
+            .min(runs.len() - 1);
+        let len = visual_char.ch.len_utf8();
+        visual_text.push(visual_char.ch);
+        match visual_text_runs.last_mut() {
+            Some(run) if last_run_ix == Some(run_ix) => run.len += len,
+            _ => {
+                visual_text_runs.push(TextRun {
+                    len,
+                    ..runs[run_ix].clone()
+                });
+                last_run_ix = Some(run_ix);
+            }
+        }
+    }
+    (visual_text, visual_text_runs)
+}
+
 fn review_workspace_syntax_token_for_style_key(
     style_key: &str,
 ) -> crate::app::highlight::SyntaxTokenKind {
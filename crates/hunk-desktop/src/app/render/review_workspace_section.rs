@@ -9,11 +9,21 @@ pub(crate) struct ReviewWorkspaceCommentAffordanceLayout {
 pub(crate) struct ReviewWorkspaceViewportPaintStyle {
     pub(crate) left_panel_width: Option<Pixels>,
     pub(crate) right_panel_width: Option<Pixels>,
+    /// Paints code rows as one column, with changed line pairs stacked removed above added.
+    pub(crate) unified: bool,
     pub(crate) left_line_number_width: f32,
     pub(crate) right_line_number_width: f32,
     pub(crate) center_divider: gpui::Hsla,
     pub(crate) mono_font_family: SharedString,
     pub(crate) ui_font_family: SharedString,
+    /// Code and hunk header text size after the diff text zoom is applied.
+    pub(crate) code_font_size: Pixels,
+    /// Ligatures off when the code font setting disables them.
+    pub(crate) code_font_features: gpui::FontFeatures,
+    /// Files whose code cells are laid out right to left.
+    pub(crate) right_to_left_files: BTreeSet<String>,
+    /// Links ticket ids in code cells alongside URLs.
+    pub(crate) ticket_linker: Option<TicketLinker>,
 }
 
 pub(crate) fn paint_review_workspace_viewport_row(
@@ -38,6 +48,9 @@ pub(crate) fn paint_review_workspace_viewport_row(
             is_selected,
             viewport_row.file_is_collapsed,
             viewport_row.can_view_file,
+            viewport_row.file_encoding.as_deref(),
+            viewport_row.file_is_extracted_text,
+            viewport_row.file_code_owners.as_deref(),
         );
         paint_review_workspace_file_header_row(
             window,
@@ -52,9 +65,27 @@ pub(crate) fn paint_review_workspace_viewport_row(
 
     match viewport_row.row_kind {
         DiffRowKind::Code => {
+            let direction = if viewport_row
+                .file_path
+                .as_deref()
+                .is_some_and(|path| style.right_to_left_files.contains(path))
+            {
+                TextDirection::RightToLeft
+            } else {
+                TextDirection::LeftToRight
+            };
+            // Unified rows show either side in the same gutter, so it fits the wider numbers.
+            let (left_line_number_width, right_line_number_width) = if style.unified {
+                let width = style
+                    .left_line_number_width
+                    .max(style.right_line_number_width);
+                (width, width)
+            } else {
+                (style.left_line_number_width, style.right_line_number_width)
+            };
             let left = build_review_workspace_code_row_cell_paint(
                 cx.theme(),
-                style.left_line_number_width,
+                left_line_number_width,
                 viewport_row.stable_id,
                 is_selected,
                 DiffCellRenderSpec {
@@ -63,12 +94,14 @@ pub(crate) fn paint_review_workspace_viewport_row(
                     cell_kind: viewport_row.left_cell_kind,
                     peer_kind: viewport_row.right_cell_kind,
                     panel_width: style.left_panel_width,
+                    direction,
                 },
                 viewport_row,
+                style.ticket_linker.as_ref(),
             );
             let right = build_review_workspace_code_row_cell_paint(
                 cx.theme(),
-                style.right_line_number_width,
+                right_line_number_width,
                 viewport_row.stable_id,
                 is_selected,
                 DiffCellRenderSpec {
@@ -77,36 +110,45 @@ pub(crate) fn paint_review_workspace_viewport_row(
                     cell_kind: viewport_row.right_cell_kind,
                     peer_kind: viewport_row.left_cell_kind,
                     panel_width: style.right_panel_width,
+                    direction,
                 },
                 viewport_row,
+                style.ticket_linker.as_ref(),
             );
-            paint_review_workspace_code_row(
-                window,
-                cx,
-                row_bounds,
-                &left,
-                &right,
-                style.center_divider,
-                style.mono_font_family.clone(),
-            );
+            if style.unified {
+                paint_review_workspace_unified_code_row(
+                    window,
+                    cx,
+                    row_bounds,
+                    viewport_row,
+                    &left,
+                    &right,
+                    style,
+                );
+            } else {
+                paint_review_workspace_code_row(window, cx, row_bounds, &left, &right, style);
+            }
         }
         DiffRowKind::HunkHeader | DiffRowKind::Meta | DiffRowKind::Empty => {
             let meta = build_review_workspace_meta_row_paint(
                 cx.theme(),
                 viewport_row.row_kind,
                 &viewport_row.text,
+                viewport_row.stream_kind == DiffStreamRowKind::FileWarning,
                 is_selected,
             );
-            paint_review_workspace_meta_row(
-                window,
-                cx,
-                row_bounds,
-                &meta,
-                style.mono_font_family.clone(),
-            );
+            paint_review_workspace_meta_row(window, cx, row_bounds, &meta, style);
         }
     }
 
+    if review_workspace_hunk_header_has_stage_controls(row_bounds, viewport_row) {
+        paint_review_workspace_hunk_stage_header(window, cx, row_bounds, viewport_row, style);
+    }
+
+    if viewport_row.is_pinned {
+        paint_review_workspace_pin_marker(window, cx, row_bounds);
+    }
+
     if let Some(comment_layout) = review_workspace_comment_affordance_layout(
         row_bounds,
         viewport_row.show_comment_affordance,
@@ -122,12 +164,16 @@ pub(crate) fn paint_review_workspace_viewport_row(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn paint_review_workspace_sticky_header(
     window: &mut Window,
     cx: &mut App,
     header: &review_workspace_session::ReviewWorkspaceVisibleFileHeader,
     is_selected: bool,
     can_view_file: bool,
+    encoding: Option<&str>,
+    is_extracted_text: bool,
+    code_owners: Option<&str>,
     bounds: Bounds<Pixels>,
     style: &ReviewWorkspaceViewportPaintStyle,
 ) {
@@ -139,6 +185,9 @@ pub(crate) fn paint_review_workspace_sticky_header(
         is_selected,
         false,
         can_view_file,
+        encoding,
+        is_extracted_text,
+        code_owners,
     );
     paint_review_workspace_file_header_row(
         window,
@@ -320,6 +369,146 @@ fn paint_review_workspace_comment_affordance(
     }
 }
 
+/// Working-copy reviews give expanded hunk headers a full row with the header text and a
+/// staging checkbox. Folded hunks keep their summary instead.
+fn review_workspace_hunk_header_has_stage_controls(
+    row_bounds: Bounds<Pixels>,
+    viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+) -> bool {
+    viewport_row.row_kind == DiffRowKind::HunkHeader
+        && row_bounds.size.height
+            > px(review_workspace_session::REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX as f32)
+        && hunk_domain::diff::collapsed_hunk_summary(viewport_row.text.as_str()).is_none()
+}
+
+fn review_workspace_hunk_stage_checkbox_slot(row_bounds: Bounds<Pixels>) -> Bounds<Pixels> {
+    let side = px(14.0);
+    Bounds {
+        origin: point(
+            row_bounds.origin.x + px(12.0),
+            row_bounds.origin.y + ((row_bounds.size.height - side) / 2.).max(Pixels::ZERO),
+        ),
+        size: gpui::size(side, side),
+    }
+}
+
+/// Where a hunk header draws its staging checkbox. Partly staged hunks have none, since one click
+/// could not say which of their lines to stage.
+pub(crate) fn review_workspace_hunk_stage_checkbox_bounds(
+    row_bounds: Bounds<Pixels>,
+    viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+) -> Option<Bounds<Pixels>> {
+    (viewport_row.hunk_stage_state.is_some()
+        && review_workspace_hunk_header_has_stage_controls(row_bounds, viewport_row))
+    .then(|| review_workspace_hunk_stage_checkbox_slot(row_bounds))
+}
+
+fn paint_review_workspace_hunk_stage_header(
+    window: &mut Window,
+    cx: &mut App,
+    row_bounds: Bounds<Pixels>,
+    viewport_row: &review_workspace_session::ReviewWorkspaceViewportRow,
+    style: &ReviewWorkspaceViewportPaintStyle,
+) {
+    let is_dark = cx.theme().mode.is_dark();
+    let staged = viewport_row.hunk_stage_state == Some(HunkStageState::Staged);
+    let (box_background, box_border) = if staged {
+        (cx.theme().primary, cx.theme().primary)
+    } else {
+        (
+            cx.theme().background,
+            hunk_opacity(cx.theme().border, is_dark, 0.95, 0.85),
+        )
+    };
+    let check_color = cx.theme().primary_foreground;
+    let header_color = cx.theme().muted_foreground;
+    let checkbox_bounds = review_workspace_hunk_stage_checkbox_slot(row_bounds);
+
+    if viewport_row.hunk_stage_state.is_some() {
+        window.paint_quad(gpui::fill(checkbox_bounds, box_background));
+        paint_review_workspace_outline(window, checkbox_bounds, box_border);
+    }
+
+    if staged {
+        let check_style = gpui::TextStyle {
+            color: check_color,
+            font_family: style.ui_font_family.clone(),
+            font_size: px(11.0).into(),
+            line_height: gpui::relative(1.0),
+            ..Default::default()
+        };
+        let check_label = SharedString::from("\u{2713}");
+        let line_height = check_style.line_height_in_pixels(window.rem_size());
+        let check_runs = vec![crate::app::native_files_editor::paint::single_color_text_run(
+            check_label.len(),
+            check_color,
+            check_style.font(),
+        )];
+        let check_shape = crate::app::native_files_editor::paint::shape_editor_line(
+            window,
+            check_label,
+            check_style.font_size.to_pixels(window.rem_size()),
+            &check_runs,
+        );
+        crate::app::native_files_editor::paint::paint_editor_line(
+            window,
+            cx,
+            &check_shape,
+            point(
+                checkbox_bounds.origin.x
+                    + ((checkbox_bounds.size.width - check_shape.width()) / 2.).max(Pixels::ZERO),
+                checkbox_bounds.origin.y
+                    + ((checkbox_bounds.size.height - line_height) / 2.).max(Pixels::ZERO),
+            ),
+            line_height,
+        );
+    }
+
+    let header_style = gpui::TextStyle {
+        color: header_color,
+        font_family: style.mono_font_family.clone(),
+        font_size: style.code_font_size.into(),
+        font_features: style.code_font_features.clone(),
+        line_height: gpui::relative(1.45),
+        ..Default::default()
+    };
+    let header_text = SharedString::from(viewport_row.text.clone());
+    let line_height = header_style.line_height_in_pixels(window.rem_size());
+    let header_runs = vec![crate::app::native_files_editor::paint::single_color_text_run(
+        header_text.len(),
+        header_color,
+        header_style.font(),
+    )];
+    let header_shape = crate::app::native_files_editor::paint::shape_editor_line(
+        window,
+        header_text,
+        header_style.font_size.to_pixels(window.rem_size()),
+        &header_runs,
+    );
+    crate::app::native_files_editor::paint::paint_editor_line(
+        window,
+        cx,
+        &header_shape,
+        point(
+            checkbox_bounds.origin.x + checkbox_bounds.size.width + px(8.0),
+            row_bounds.origin.y + ((row_bounds.size.height - line_height) / 2.).max(Pixels::ZERO),
+        ),
+        line_height,
+    );
+}
+
+/// Accent bar along the left edge of a pinned row.
+fn paint_review_workspace_pin_marker(window: &mut Window, cx: &App, row_bounds: Bounds<Pixels>) {
+    let is_dark = cx.theme().mode.is_dark();
+    window.paint_quad(gpui::fill(
+        Bounds {
+            origin: row_bounds.origin,
+            size: gpui::size(px(3.0), row_bounds.size.height),
+        },
+        hunk_opacity(cx.theme().warning, is_dark, 0.90, 0.80),
+    ));
+}
+
 fn paint_review_workspace_outline(
     window: &mut Window,
     bounds: Bounds<Pixels>,
@@ -15,7 +15,7 @@ impl DiffViewer {
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) -> AnyElement {
-        if self.repo_discovery_failed {
+        if self.repo_discovery_failed || self.repo_init_path.is_some() {
             return self.render_open_project_empty_state(cx);
         }
 
@@ -99,6 +99,8 @@ impl DiffViewer {
                     .min_h_0()
                     .when(self.workspace_view_mode == WorkspaceViewMode::Diff, |this| {
                         this.child(self.render_review_compare_controls(cx))
+                            .children(self.render_review_change_set_size_banner(cx))
+                            .children(self.render_review_mechanical_change_banners(cx))
                     })
                     .when(self.editor_search_visible, |this| {
                         this.child(self.render_workspace_search_bar(
@@ -134,12 +136,20 @@ impl DiffViewer {
                                 v_flex()
                                     .size_full()
                                     .items_stretch()
-                                    .child(self.render_diff_column_header(
-                                        layout,
-                                        old_label.clone(),
-                                        new_label.clone(),
-                                        cx,
-                                    ))
+                                    .child(if self.diff_unified_view {
+                                        self.render_unified_diff_column_header(
+                                            old_label.clone(),
+                                            new_label.clone(),
+                                            cx,
+                                        )
+                                    } else {
+                                        self.render_diff_column_header(
+                                            layout,
+                                            old_label.clone(),
+                                            new_label.clone(),
+                                            cx,
+                                        )
+                                    })
                                     .child(
                                         div()
                                             .flex_1()
@@ -177,9 +187,12 @@ impl DiffViewer {
                                             ),
                                     ),
                             )
-                            .when_some(layout, |this, layout| {
-                                this.child(self.render_diff_split_handle(layout, cx))
-                            }),
+                            .when_some(
+                                layout.filter(|_| !self.diff_unified_view),
+                                |this, layout| {
+                                    this.child(self.render_diff_split_handle(layout, cx))
+                                },
+                            ),
                     ),
             )
             .into_any_element()
@@ -292,6 +305,20 @@ impl DiffViewer {
             .is_some_and(|header| {
                 self.can_open_file_in_files_workspace(header.path.as_str(), header.status)
             });
+        let sticky_file_encoding = surface
+            .sticky_file_header
+            .as_ref()
+            .and_then(|header| self.review_file_encodings.get(header.path.as_str()))
+            .map(TextEncoding::label);
+        let sticky_file_is_extracted_text =
+            surface.sticky_file_header.as_ref().is_some_and(|header| {
+                self.review_extracted_text_paths
+                    .contains(header.path.as_str())
+            });
+        let sticky_file_code_owners = surface
+            .sticky_file_header
+            .as_ref()
+            .and_then(|header| self.code_owners_label_for_path(header.path.as_str()));
 
         div()
             .id("review-workspace-viewport")
@@ -305,15 +332,23 @@ impl DiffViewer {
                         viewport: std::rc::Rc::new(viewport.clone()),
                         sticky_file_header: surface.sticky_file_header.clone(),
                         sticky_file_can_view,
+                        sticky_file_encoding,
+                        sticky_file_is_extracted_text,
+                        sticky_file_code_owners,
                         viewport_origin_px,
                         selected_row_range: self.selected_row_range(),
                         left_panel_width: layout.map(|layout| layout.left_panel_width),
                         right_panel_width: layout.map(|layout| layout.right_panel_width),
+                        unified: self.diff_unified_view,
                         left_line_number_width: self.review_surface.diff_left_line_number_width,
                         right_line_number_width: self.review_surface.diff_right_line_number_width,
                         center_divider: chrome.center_divider,
                         mono_font_family: cx.theme().mono_font_family.clone(),
                         ui_font_family: cx.theme().font_family.clone(),
+                        code_font_size: self.diff_code_font_size(),
+                        code_font_features: self.code_font_features(),
+                        right_to_left_files: self.right_to_left_files.clone(),
+                        ticket_linker: self.ticket_linker.clone(),
                     },
                 ))
                 .into_any_element(),
@@ -5,22 +5,57 @@ impl DiffViewer {
         surface: AnyElement,
         cx: &mut Context<Self>,
     ) -> AnyElement {
-        div()
-            .size_full()
-            .child(if self.sidebar_collapsed {
-                surface
-            } else {
-                h_resizable(resize_id)
-                    .child(
-                        resizable_panel()
-                            .size(px(300.0))
-                            .size_range(px(240.0)..px(520.0))
-                            .child(self.render_tree(cx)),
-                    )
-                    .child(resizable_panel().child(surface))
-                    .into_any_element()
-            })
-            .into_any_element()
+        if self.active_focus_mode_pane().is_some() {
+            return div().size_full().child(surface).into_any_element();
+        }
+
+        let tree_dock = self.workspace_panel_dock(WorkspacePanel::Tree);
+        let comments_dock = self.workspace_panel_dock(WorkspacePanel::Comments);
+        let show_docked_comments = comments_dock.is_docked()
+            && self.comments_preview_open
+            && self.workspace_view_mode == WorkspaceViewMode::Diff;
+
+        // Panels docked on the same side stack outward from the surface, tree outermost.
+        let mut left_panels = Vec::new();
+        let mut right_panels = Vec::new();
+        if tree_dock.is_docked() {
+            let panel = resizable_panel()
+                .size(px(300.0))
+                .size_range(px(240.0)..px(520.0))
+                .child(self.render_tree(cx));
+            match tree_dock {
+                PanelDock::Right => right_panels.push(panel),
+                _ => left_panels.push(panel),
+            }
+        }
+        if show_docked_comments {
+            let panel = resizable_panel()
+                .size(px(380.0))
+                .size_range(px(300.0)..px(640.0))
+                .child(self.render_comments_panel(false, cx));
+            match comments_dock {
+                PanelDock::Right => right_panels.insert(0, panel),
+                _ => left_panels.push(panel),
+            }
+        }
+
+        if left_panels.is_empty() && right_panels.is_empty() {
+            return div().size_full().child(surface).into_any_element();
+        }
+
+        // Each arrangement keeps its own panel sizes.
+        let group_id = SharedString::from(format!(
+            "{resize_id}-{tree_dock:?}-{comments_dock:?}-{show_docked_comments}"
+        ));
+        let mut group = h_resizable(group_id);
+        for panel in left_panels {
+            group = group.child(panel);
+        }
+        group = group.child(resizable_panel().child(surface));
+        for panel in right_panels {
+            group = group.child(panel);
+        }
+        div().size_full().child(group).into_any_element()
     }
 
     fn render_linux_client_title_bar(&self, cx: &mut Context<Self>) -> AnyElement {
@@ -97,7 +132,7 @@ impl DiffViewer {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> AnyElement {
-        if self.repo_discovery_failed {
+        if self.repo_discovery_failed || self.repo_init_path.is_some() {
             return self.render_open_project_empty_state(cx);
         }
 
@@ -121,7 +156,7 @@ impl DiffViewer {
     }
 
     fn render_git_workspace_screen(&mut self, cx: &mut Context<Self>) -> AnyElement {
-        if self.repo_discovery_failed {
+        if self.repo_discovery_failed || self.repo_init_path.is_some() {
             return self.render_open_project_empty_state(cx);
         }
 
@@ -142,13 +177,18 @@ impl DiffViewer {
 
         let is_dark = cx.theme().mode.is_dark();
         let show_loading_overlay = self.git_workspace_loading && !self.git_workflow_ready_for_panel();
+        let panel = if self.active_focus_mode_pane().is_some() {
+            self.render_git_recent_commits_panel(cx)
+        } else {
+            self.render_git_workspace_panel(cx)
+        };
 
         div()
             .size_full()
             .min_h_0()
             .relative()
             .pb(px(APP_BOTTOM_SAFE_INSET))
-            .child(self.render_git_workspace_panel(cx))
+            .child(panel)
             .when(show_loading_overlay, |this| {
                 this.child(render_git_workspace_loading_overlay(is_dark, cx))
             })
@@ -158,6 +198,58 @@ impl DiffViewer {
             .into_any_element()
     }
 
+    /// The slim strip that replaces the toolbar while focus mode maximizes `pane`.
+    fn render_focus_mode_bar(&self, pane: &'static str, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let exit_label = ai_preferred_shortcut_label(
+            self.config.keyboard_shortcuts.toggle_focus_mode.as_slice(),
+        )
+        .map_or_else(
+            || tr("focus-mode-exit"),
+            |shortcut| {
+                tr_args(
+                    "focus-mode-exit-shortcut",
+                    &[("shortcut", shortcut.as_str())],
+                )
+            },
+        );
+
+        h_flex()
+            .w_full()
+            .h_7()
+            .flex_none()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .px_2()
+            .border_b_1()
+            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.88, 0.68))
+            .bg(hunk_blend(cx.theme().sidebar, cx.theme().muted, is_dark, 0.18, 0.22))
+            .child(
+                div()
+                    .min_w_0()
+                    .truncate()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(tr_args("focus-mode-bar", &[("pane", tr(pane).as_str())])),
+            )
+            .child(
+                Button::new("focus-mode-exit")
+                    .ghost()
+                    .compact()
+                    .with_size(gpui_component::Size::Small)
+                    .rounded(px(6.0))
+                    .label(exit_label)
+                    .on_click(move |_, window, cx| {
+                        view.update(cx, |this, cx| {
+                            this.toggle_focus_mode(window, cx);
+                        });
+                    }),
+            )
+            .into_any_element()
+    }
+
     fn render_app_footer(&self, cx: &mut Context<Self>) -> AnyElement {
         let render_started_at = Instant::now();
         let view = cx.entity();
@@ -207,6 +299,10 @@ impl DiffViewer {
                 active_branch
             )
         };
+        let tree_hidden = !self.sidebar_tree_visible();
+        let tree_on_right = self.tree_restore_dock == PanelDock::Right;
+        let layout_docks =
+            WorkspacePanel::ALL.map(|panel| (panel, self.workspace_panel_dock(panel)));
         let active_terminal_kind = self.active_workspace_terminal_kind();
         let terminal_open = active_terminal_kind.is_some_and(|kind| self.workspace_terminal_open(kind));
         let terminal_shortcut = ai_preferred_shortcut_label(
@@ -250,7 +346,7 @@ impl DiffViewer {
                                 .compact()
                                 .rounded(px(7.0))
                                 .icon(
-                                    Icon::new(if self.sidebar_collapsed {
+                                    Icon::new(if tree_hidden != tree_on_right {
                                         IconName::ChevronRight
                                     } else {
                                         IconName::ChevronLeft
@@ -259,7 +355,7 @@ impl DiffViewer {
                                 )
                                 .min_w(px(30.0))
                                 .h(px(28.0))
-                                .tooltip(if self.sidebar_collapsed {
+                                .tooltip(if tree_hidden {
                                     "Show file tree (Cmd/Ctrl+B)"
                                 } else {
                                     "Hide file tree (Cmd/Ctrl+B)"
@@ -270,7 +366,7 @@ impl DiffViewer {
                                         this.focus_handle.focus(window, cx);
                                     });
                                 });
-                            if self.sidebar_collapsed {
+                            if tree_hidden {
                                 button = button.outline();
                             } else {
                                 button = button.primary();
@@ -413,6 +509,91 @@ impl DiffViewer {
                             button.into_any_element()
                         })
                     })
+                    .when(
+                        self.config.vim_mode && self.workspace_view_mode == WorkspaceViewMode::Diff,
+                        |this| {
+                            this.child(
+                                div()
+                                    .px_1p5()
+                                    .py_0p5()
+                                    .rounded(px(5.0))
+                                    .text_xs()
+                                    .font_family(cx.theme().mono_font_family.clone())
+                                    .font_semibold()
+                                    .when(self.vim_mode == VimMode::Visual, |this| {
+                                        this.bg(hunk_opacity(
+                                            cx.theme().accent,
+                                            is_dark,
+                                            0.32,
+                                            0.18,
+                                        ))
+                                    })
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(format!("-- {} --", self.vim_mode.label())),
+                            )
+                        },
+                    )
+                    .when_some(self.review_timer_footer_label(), |this, label| {
+                        let view = view.clone();
+                        this.child(
+                            Button::new("footer-review-timer")
+                                .compact()
+                                .ghost()
+                                .rounded(px(7.0))
+                                .h(px(28.0))
+                                .label(label)
+                                .tooltip(tr("review-timer-tooltip"))
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.reset_review_timer(cx);
+                                    });
+                                }),
+                        )
+                    })
+                    .child({
+                        let view = view.clone();
+                        Button::new("footer-workspace-layout")
+                            .compact()
+                            .outline()
+                            .rounded(px(7.0))
+                            .h(px(28.0))
+                            .dropdown_caret(true)
+                            .label("Layout")
+                            .tooltip("Dock, float or hide workspace panels")
+                            .dropdown_menu(move |mut menu, _, _| {
+                                for (panel, current_dock) in layout_docks {
+                                    for &dock in panel.supported_docks() {
+                                        let view = view.clone();
+                                        menu = menu.item(
+                                            PopupMenuItem::new(format!(
+                                                "{}: {}",
+                                                panel.label(),
+                                                dock.label()
+                                            ))
+                                            .checked(dock == current_dock)
+                                            .on_click(move |_, _, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.move_workspace_panel(panel, dock, cx);
+                                                });
+                                            }),
+                                        );
+                                    }
+                                    menu = menu.separator();
+                                }
+                                let view = view.clone();
+                                menu.item(PopupMenuItem::new("Reset Layout").on_click(
+                                    move |_, window, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.reset_workspace_layout_action(
+                                                &ResetWorkspaceLayout,
+                                                window,
+                                                cx,
+                                            );
+                                        });
+                                    },
+                                ))
+                            })
+                    })
                     .child(
                         div()
                             .text_xs()
@@ -452,11 +633,19 @@ impl Render for DiffViewer {
         let ai_view_state = ai_selected.then(|| self.visible_ai_frame_state());
         let show_linux_client_title_bar =
             cfg!(target_os = "linux") && matches!(window.window_decorations(), Decorations::Client { .. });
+        let focus_mode_pane = self.active_focus_mode_pane();
+        let show_in_app_menu_bar =
+            !cfg!(target_os = "macos") && !show_linux_client_title_bar && focus_mode_pane.is_none();
         let element = v_flex()
             .size_full()
             .relative()
             .key_context(self.workspace_view_mode.root_key_context())
             .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                if this.handle_vim_key_down(event, window, cx) {
+                    cx.stop_propagation();
+                }
+            }))
             .on_action(cx.listener(Self::select_next_line_action))
             .on_action(cx.listener(Self::select_previous_line_action))
             .on_action(cx.listener(Self::extend_selection_next_line_action))
@@ -468,7 +657,11 @@ impl Render for DiffViewer {
             .on_action(cx.listener(Self::next_file_action))
             .on_action(cx.listener(Self::previous_file_action))
             .on_action(cx.listener(Self::view_current_review_file_action))
+            .on_action(cx.listener(Self::toggle_review_pin_action))
+            .on_action(cx.listener(Self::next_review_pin_action))
+            .on_action(cx.listener(Self::previous_review_pin_action))
             .on_action(cx.listener(Self::toggle_sidebar_tree_action))
+            .on_action(cx.listener(Self::toggle_focus_mode_action))
             .on_action(cx.listener(Self::switch_to_files_view_action))
             .on_action(cx.listener(Self::switch_to_review_view_action))
             .on_action(cx.listener(Self::switch_to_git_view_action))
@@ -477,22 +670,43 @@ impl Render for DiffViewer {
             .on_action(cx.listener(Self::ai_new_thread_action))
             .on_action(cx.listener(Self::ai_new_worktree_thread_shortcut_action))
             .on_action(cx.listener(Self::open_project_action))
+            .on_action(cx.listener(Self::open_path_from_clipboard_action))
+            .on_action(cx.listener(Self::clone_repository_action))
             .on_action(cx.listener(Self::quick_open_file_action))
             .on_action(cx.listener(Self::save_current_file_action))
             .on_action(cx.listener(Self::next_editor_tab_action))
             .on_action(cx.listener(Self::previous_editor_tab_action))
             .on_action(cx.listener(Self::close_editor_tab_action))
             .on_action(cx.listener(Self::open_about_hunk_action))
+            .on_action(cx.listener(Self::open_diagnostics_action))
+            .on_action(cx.listener(Self::open_repository_issues_action))
+            .on_action(cx.listener(Self::open_ci_log_panel_action))
+            .on_action(cx.listener(Self::search_repository_action))
+            .on_action(cx.listener(Self::open_operation_diff_action))
+            .on_action(cx.listener(Self::open_operation_history_action))
+            .on_action(cx.listener(Self::open_stack_editor_action))
+            .on_action(cx.listener(Self::reset_workspace_layout_action))
+            .on_action(cx.listener(Self::open_detached_diff_window_action))
+            .on_action(cx.listener(Self::export_review_pdf_action))
+            .on_action(cx.listener(Self::export_review_pdf_grayscale_action))
             .on_action(cx.listener(Self::open_settings_action))
             .bg(cx.theme().background)
             .text_color(cx.theme().foreground)
             .when(show_linux_client_title_bar, |this| {
                 this.child(self.render_linux_client_title_bar(cx))
             })
-            .when(!cfg!(target_os = "macos") && !show_linux_client_title_bar, |this| {
+            .when(show_in_app_menu_bar, |this| {
                 this.child(self.render_in_app_menu_bar(cx))
             })
-            .child(self.render_toolbar(ai_view_state.as_ref(), cx))
+            .when_some(focus_mode_pane, |this, pane| {
+                this.child(self.render_focus_mode_bar(pane, cx))
+            })
+            .when(focus_mode_pane.is_none(), |this| {
+                this.child(self.render_toolbar(ai_view_state.as_ref(), cx))
+            })
+            .when(self.previous_crash_report.is_some(), |this| {
+                this.child(self.render_crash_report_banner(cx))
+            })
             .child(
                 div()
                     .flex_1()
@@ -507,11 +721,22 @@ impl Render for DiffViewer {
                         }
                     }),
             )
-            .child(self.render_app_footer(cx))
+            .when(focus_mode_pane.is_none(), |this| this.child(self.render_app_footer(cx)))
+            .when(
+                self.comments_preview_open
+                    && self.workspace_view_mode == WorkspaceViewMode::Diff
+                    && self.workspace_panel_dock(WorkspacePanel::Comments) == PanelDock::Floating,
+                |this| this.child(self.render_comments_panel(true, cx)),
+            )
             .when(
-                self.comments_preview_open && self.workspace_view_mode == WorkspaceViewMode::Diff,
-                |this| {
-                this.child(self.render_comments_preview(cx))
+                self.co_review_panel_open && self.workspace_view_mode == WorkspaceViewMode::Diff,
+                |this| this.child(self.render_co_review_panel(cx)),
+            )
+            .when(self.ci_log_panel.is_some(), |this| {
+                this.child(self.render_ci_log_panel(cx))
+            })
+            .when(self.repo_content_search_panel.is_some(), |this| {
+                this.child(self.render_repo_content_search_panel(cx))
             })
             .when(self.file_quick_open_visible, |this| {
                 this.child(self.render_file_quick_open_popup(window, cx))
@@ -519,6 +744,36 @@ impl Render for DiffViewer {
             .when(self.settings_draft.is_some(), |this| {
                 this.child(self.render_settings_popup(cx))
             })
+            .when(self.git_recovery_prompt.is_some(), |this| {
+                this.child(self.render_git_recovery_popup(cx))
+            })
+            .when(self.repo_clone_prompt.is_some(), |this| {
+                this.child(self.render_repo_clone_popup(cx))
+            })
+            .when(self.hunk_apply_prompt.is_some(), |this| {
+                this.child(self.render_hunk_apply_popup(cx))
+            })
+            .when(self.commit_message_editor.is_some(), |this| {
+                this.child(self.render_commit_message_editor_popup(cx))
+            })
+            .when(self.repo_issues_panel.is_some(), |this| {
+                this.child(self.render_repo_issues_popup(cx))
+            })
+            .when(self.commit_details_panel.is_some(), |this| {
+                this.child(self.render_commit_details_popup(cx))
+            })
+            .when(self.operation_diff_panel.is_some(), |this| {
+                this.child(self.render_operation_diff_popup(cx))
+            })
+            .when(self.operation_history_panel.is_some(), |this| {
+                this.child(self.render_operation_history_popup(cx))
+            })
+            .when(self.stack_editor_panel.is_some(), |this| {
+                this.child(self.render_stack_editor_popup(cx))
+            })
+            .when(self.crash_report_viewer_open, |this| {
+                this.child(self.render_crash_report_popup(cx))
+            })
             .when_some(self.render_workspace_text_context_menu(cx), |this, menu| {
                 this.child(menu)
             })
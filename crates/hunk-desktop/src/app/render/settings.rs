@@ -77,13 +77,13 @@ impl DiffViewer {
                                                     .text_lg()
                                                     .font_semibold()
                                                     .text_color(cx.theme().foreground)
-                                                    .child("Settings"),
+                                                    .child(tr("settings-title")),
                                             )
                                             .child(
                                                 div()
                                                     .text_xs()
                                                     .text_color(cx.theme().muted_foreground)
-                                                    .child("Changes are saved to ~/.hunkdiff/config.toml"),
+                                                    .child(tr("settings-saved-to-path")),
                                             ),
                                     )
                                     .child({
@@ -92,7 +92,7 @@ impl DiffViewer {
                                             .ghost()
                                             .compact()
                                             .rounded(px(8.0))
-                                            .label("Close")
+                                            .label(tr("settings-close"))
                                             .on_click(move |_, window, cx| {
                                                 view.update(cx, |this, cx| {
                                                     this.close_settings_and_refocus(window, cx);
@@ -120,7 +120,7 @@ impl DiffViewer {
                                                     .text_xs()
                                                     .font_semibold()
                                                     .text_color(cx.theme().muted_foreground)
-                                                    .child("Categories"),
+                                                    .child(tr("settings-categories")),
                                             )
                                             .children(SettingsCategory::ALL.into_iter().map(
                                                 |category| {
@@ -132,6 +132,22 @@ impl DiffViewer {
                                                         SettingsCategory::Terminal => {
                                                             "settings-nav-terminal"
                                                         }
+                                                        SettingsCategory::Git => "settings-nav-git",
+                                                        SettingsCategory::Notifications => {
+                                                            "settings-nav-notifications"
+                                                        }
+                                                        SettingsCategory::UsageMetrics => {
+                                                            "settings-nav-usage-metrics"
+                                                        }
+                                                        SettingsCategory::Maintenance => {
+                                                            "settings-nav-maintenance"
+                                                        }
+                                                        SettingsCategory::Gestures => {
+                                                            "settings-nav-gestures"
+                                                        }
+                                                        SettingsCategory::Formatting => {
+                                                            "settings-nav-formatting"
+                                                        }
                                                         SettingsCategory::KeyboardShortcuts => {
                                                             "settings-nav-keyboard-shortcuts"
                                                         }
@@ -179,6 +195,34 @@ impl DiffViewer {
                                                         settings, cx,
                                                     )
                                                 }
+                                                SettingsCategory::Git => {
+                                                    self.render_settings_git_category(settings, cx)
+                                                }
+                                                SettingsCategory::Notifications => {
+                                                    self.render_settings_notifications_category(
+                                                        settings, cx,
+                                                    )
+                                                }
+                                                SettingsCategory::UsageMetrics => {
+                                                    self.render_settings_usage_metrics_category(
+                                                        settings, cx,
+                                                    )
+                                                }
+                                                SettingsCategory::Maintenance => {
+                                                    self.render_settings_maintenance_category(
+                                                        settings, cx,
+                                                    )
+                                                }
+                                                SettingsCategory::Gestures => {
+                                                    self.render_settings_gestures_category(
+                                                        settings, cx,
+                                                    )
+                                                }
+                                                SettingsCategory::Formatting => {
+                                                    self.render_settings_formatting_category(
+                                                        settings, cx,
+                                                    )
+                                                }
                                                 SettingsCategory::KeyboardShortcuts => {
                                                     self.render_settings_shortcuts_category(
                                                         settings, cx,
@@ -205,10 +249,10 @@ impl DiffViewer {
                                                 cx.theme().muted_foreground
                                             })
                                             .child(
-                                                settings.error_message.clone().unwrap_or_else(|| {
-                                                    "Settings are saved to config.toml."
-                                                        .to_string()
-                                                }),
+                                                settings
+                                                    .error_message
+                                                    .clone()
+                                                    .unwrap_or_else(|| tr("settings-footer-hint")),
                                             ),
                                     )
                                     .child(
@@ -220,7 +264,7 @@ impl DiffViewer {
                                                 Button::new("settings-cancel")
                                                     .outline()
                                                     .rounded(px(8.0))
-                                                    .label("Cancel")
+                                                    .label(tr("settings-cancel"))
                                                     .on_click(move |_, window, cx| {
                                                         view.update(cx, |this, cx| {
                                                             this.close_settings_and_refocus(
@@ -234,7 +278,7 @@ impl DiffViewer {
                                                 Button::new("settings-save")
                                                     .primary()
                                                     .rounded(px(8.0))
-                                                    .label("Save")
+                                                    .label(tr("settings-save"))
                                                     .on_click(move |_, window, cx| {
                                                         view.update(cx, |this, cx| {
                                                             this.save_settings(window, cx);
@@ -257,13 +301,15 @@ impl DiffViewer {
         let is_dark = cx.theme().mode.is_dark();
         let card_surface = hunk_card_surface(cx.theme(), is_dark);
         let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
-        let theme_label = match settings.theme {
-            ThemePreference::System => "System",
-            ThemePreference::Light => "Light",
-            ThemePreference::Dark => "Dark",
-        };
-        let reduced_motion_label = if settings.reduce_motion { "On" } else { "Off" };
-        let show_fps_counter_label = if settings.show_fps_counter { "On" } else { "Off" };
+        let theme_label = tr(match settings.theme {
+            ThemePreference::System => "settings-theme-system",
+            ThemePreference::Light => "settings-theme-light",
+            ThemePreference::Dark => "settings-theme-dark",
+        });
+        let on_off_label = |enabled: bool| tr(if enabled { "settings-on" } else { "settings-off" });
+        let reduced_motion_label = on_off_label(settings.reduce_motion);
+        let show_fps_counter_label = on_off_label(settings.show_fps_counter);
+        let offer_clipboard_paths_label = on_off_label(settings.offer_clipboard_paths);
         v_flex()
             .w_full()
             .gap_3()
@@ -276,13 +322,13 @@ impl DiffViewer {
                             .text_base()
                             .font_semibold()
                             .text_color(cx.theme().foreground)
-                            .child("UI"),
+                            .child(tr("settings-category-ui")),
                     )
                     .child(
                         div()
                             .text_xs()
                             .text_color(cx.theme().muted_foreground)
-                            .child("Theme and UI preferences."),
+                            .child(tr("settings-ui-description")),
                     ),
             )
             .child(
@@ -305,7 +351,7 @@ impl DiffViewer {
                                     .text_sm()
                                     .font_semibold()
                                     .text_color(cx.theme().foreground)
-                                    .child("Theme"),
+                                    .child(tr("settings-theme")),
                             )
                             .child({
                                 let view = view.clone();
@@ -319,7 +365,7 @@ impl DiffViewer {
                                     .label(theme_label)
                                     .dropdown_menu(move |menu, _, _| {
                                         menu.item(
-                                            PopupMenuItem::new("System")
+                                            PopupMenuItem::new(tr("settings-theme-system"))
                                                 .checked(selected_theme == ThemePreference::System)
                                                 .on_click({
                                                     let view = view.clone();
@@ -334,7 +380,7 @@ impl DiffViewer {
                                                 }),
                                         )
                                         .item(
-                                            PopupMenuItem::new("Light")
+                                            PopupMenuItem::new(tr("settings-theme-light"))
                                                 .checked(selected_theme == ThemePreference::Light)
                                                 .on_click({
                                                     let view = view.clone();
@@ -349,7 +395,7 @@ impl DiffViewer {
                                                 }),
                                         )
                                         .item(
-                                            PopupMenuItem::new("Dark")
+                                            PopupMenuItem::new(tr("settings-theme-dark"))
                                                 .checked(selected_theme == ThemePreference::Dark)
                                                 .on_click({
                                                     let view = view.clone();
@@ -366,6 +412,7 @@ impl DiffViewer {
                                     })
                             }),
                     )
+                    .child(self.render_settings_language_row(settings, cx))
                     .child(
                         h_flex()
                             .w_full()
@@ -377,7 +424,7 @@ impl DiffViewer {
                                     .text_sm()
                                     .font_semibold()
                                     .text_color(cx.theme().foreground)
-                                    .child("Reduced Motion"),
+                                    .child(tr("settings-reduced-motion")),
                             )
                             .child({
                                 let view = view.clone();
@@ -391,7 +438,7 @@ impl DiffViewer {
                                     .label(reduced_motion_label)
                                     .dropdown_menu(move |menu, _, _| {
                                         menu.item(
-                                            PopupMenuItem::new("On")
+                                            PopupMenuItem::new(tr("settings-on"))
                                                 .checked(reduce_motion)
                                                 .on_click({
                                                     let view = view.clone();
@@ -403,7 +450,7 @@ impl DiffViewer {
                                                 }),
                                         )
                                         .item(
-                                            PopupMenuItem::new("Off")
+                                            PopupMenuItem::new(tr("settings-off"))
                                                 .checked(!reduce_motion)
                                                 .on_click({
                                                     let view = view.clone();
@@ -428,7 +475,7 @@ impl DiffViewer {
                                     .text_sm()
                                     .font_semibold()
                                     .text_color(cx.theme().foreground)
-                                    .child("FPS Counter"),
+                                    .child(tr("settings-fps-counter")),
                             )
                             .child({
                                 let view = view.clone();
@@ -442,7 +489,7 @@ impl DiffViewer {
                                     .label(show_fps_counter_label)
                                     .dropdown_menu(move |menu, _, _| {
                                         menu.item(
-                                            PopupMenuItem::new("On")
+                                            PopupMenuItem::new(tr("settings-on"))
                                                 .checked(show_fps_counter)
                                                 .on_click({
                                                     let view = view.clone();
@@ -456,7 +503,7 @@ impl DiffViewer {
                                                 }),
                                         )
                                         .item(
-                                            PopupMenuItem::new("Off")
+                                            PopupMenuItem::new(tr("settings-off"))
                                                 .checked(!show_fps_counter)
                                                 .on_click({
                                                     let view = view.clone();
@@ -472,6 +519,61 @@ impl DiffViewer {
                                     })
                             }),
                     )
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(tr("settings-offer-clipboard-paths")),
+                            )
+                            .child({
+                                let view = view.clone();
+                                let offer_clipboard_paths = settings.offer_clipboard_paths;
+                                Button::new("settings-offer-clipboard-paths-dropdown")
+                                    .outline()
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .bg(dropdown_bg)
+                                    .dropdown_caret(true)
+                                    .label(offer_clipboard_paths_label)
+                                    .dropdown_menu(move |menu, _, _| {
+                                        menu.item(
+                                            PopupMenuItem::new(tr("settings-on"))
+                                                .checked(offer_clipboard_paths)
+                                                .on_click({
+                                                    let view = view.clone();
+                                                    move |_, _, cx| {
+                                                        view.update(cx, |this, cx| {
+                                                            this.set_settings_offer_clipboard_paths(
+                                                                true, cx,
+                                                            );
+                                                        });
+                                                    }
+                                                }),
+                                        )
+                                        .item(
+                                            PopupMenuItem::new(tr("settings-off"))
+                                                .checked(!offer_clipboard_paths)
+                                                .on_click({
+                                                    let view = view.clone();
+                                                    move |_, _, cx| {
+                                                        view.update(cx, |this, cx| {
+                                                            this.set_settings_offer_clipboard_paths(
+                                                                false, cx,
+                                                            );
+                                                        });
+                                                    }
+                                                }),
+                                        )
+                                    })
+                            }),
+                    )
                     .child(
                         v_flex()
                             .w_full()
@@ -481,20 +583,63 @@ impl DiffViewer {
                                     .text_sm()
                                     .font_semibold()
                                     .text_color(cx.theme().foreground)
-                                    .child("Update behavior"),
+                                    .child(tr("settings-update-behavior")),
                             )
                             .child(
                                 div()
                                     .text_xs()
                                     .text_color(cx.theme().muted_foreground)
-                                    .child(
-                                        "Diffs refresh immediately on file events. The app also performs \
-                            a background periodic check as a fallback if file events are missed. \
-                            Reduced Motion disables animated transitions in the Git workspace.",
-                                    ),
+                                    .child(tr("settings-update-behavior-description")),
                             ),
                     ),
             )
+            .child(self.render_settings_fonts_card(settings, cx))
+            .into_any_element()
+    }
+
+    fn render_settings_language_row(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), cx.theme().mode.is_dark());
+        let selected_language = settings.language;
+        h_flex()
+            .w_full()
+            .items_center()
+            .justify_between()
+            .gap_3()
+            .child(settings_git_row_label(
+                tr("settings-language"),
+                tr("settings-language-hint"),
+                cx,
+            ))
+            .child(
+                Button::new("settings-language-dropdown")
+                    .outline()
+                    .compact()
+                    .rounded(px(8.0))
+                    .bg(dropdown_bg)
+                    .dropdown_caret(true)
+                    .label(selected_language.native_name())
+                    .dropdown_menu(move |menu, _, _| {
+                        Language::ALL.into_iter().fold(menu, |menu, language| {
+                            menu.item(
+                                PopupMenuItem::new(language.native_name())
+                                    .checked(selected_language == language)
+                                    .on_click({
+                                        let view = view.clone();
+                                        move |_, _, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.set_settings_language(language, cx);
+                                            });
+                                        }
+                                    }),
+                            )
+                        })
+                    }),
+            )
             .into_any_element()
     }
 
@@ -795,95 +940,4 @@ impl DiffViewer {
             )
             .into_any_element()
     }
-
-    fn render_settings_shortcuts_category(
-        &self,
-        settings: &SettingsDraft,
-        cx: &mut Context<Self>,
-    ) -> AnyElement {
-        let is_dark = cx.theme().mode.is_dark();
-
-        v_flex()
-            .w_full()
-            .gap_3()
-            .child(
-                v_flex()
-                    .w_full()
-                    .gap_1()
-                    .child(
-                        div()
-                            .text_base()
-                            .font_semibold()
-                            .text_color(cx.theme().foreground)
-                            .child("Keyboard Shortcuts"),
-                    )
-                    .child(
-                        div()
-                            .text_xs()
-                            .text_color(cx.theme().muted_foreground)
-                            .child(
-                                "Edit comma-separated shortcut strings for each action. Use spaces for key sequences.",
-                            ),
-                    ),
-            )
-            .children(
-                settings
-                    .shortcuts
-                    .rows()
-                    .into_iter()
-                    .map(|row| self.render_settings_shortcut_row(row, cx)),
-            )
-            .child(
-                div()
-                    .text_xs()
-                    .text_color(hunk_opacity(cx.theme().muted_foreground, is_dark, 0.94, 1.0))
-                    .child(
-                        "Use commas to add alternatives, spaces for key sequences, and cmd-, literally for the comma key.",
-                    ),
-            )
-            .into_any_element()
-    }
-
-    fn render_settings_shortcut_row(
-        &self,
-        row: SettingsShortcutRow,
-        cx: &mut Context<Self>,
-    ) -> AnyElement {
-        let is_dark = cx.theme().mode.is_dark();
-        let card_surface = hunk_card_surface(cx.theme(), is_dark);
-        let input_surface = hunk_input_surface(cx.theme(), is_dark);
-
-        v_flex()
-            .id(row.id)
-            .w_full()
-            .gap_1()
-            .p_3()
-            .rounded(px(10.0))
-            .border_1()
-            .border_color(card_surface.border)
-            .bg(card_surface.background)
-            .child(
-                div()
-                    .text_sm()
-                    .font_semibold()
-                    .text_color(cx.theme().foreground)
-                    .child(row.label),
-            )
-            .child(
-                div()
-                    .text_xs()
-                    .text_color(cx.theme().muted_foreground)
-                    .child(row.hint),
-            )
-            .child(
-                Input::new(&row.input_state)
-                    .h(px(36.0))
-                    .rounded(px(8.0))
-                    .border_1()
-                    .border_color(input_surface.border)
-                    .bg(input_surface.background)
-                    .disabled(false),
-            )
-            .into_any_element()
-    }
 }
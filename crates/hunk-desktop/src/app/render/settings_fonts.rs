@@ -0,0 +1,91 @@
+impl DiffViewer {
+    fn render_settings_fonts_card(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let fonts = &settings.fonts;
+
+        let font_field = |label: &str, input: &Entity<InputState>| {
+            v_flex()
+                .flex_1()
+                .min_w_0()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(tr(label)),
+                )
+                .child(
+                    Input::new(input)
+                        .h(px(36.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background),
+                )
+        };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .p_3()
+            .rounded(px(10.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .child(settings_git_row_label(
+                tr("settings-fonts"),
+                tr("settings-fonts-hint"),
+                cx,
+            ))
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_3()
+                    .child(font_field("settings-fonts-ui-font", &fonts.ui_font_family))
+                    .child(font_field("settings-fonts-ui-size", &fonts.ui_font_size)),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_3()
+                    .child(font_field(
+                        "settings-fonts-code-font",
+                        &fonts.mono_font_family,
+                    ))
+                    .child(font_field(
+                        "settings-fonts-code-size",
+                        &fonts.mono_font_size,
+                    ))
+                    .child(font_field("settings-fonts-line-height", &fonts.line_height)),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_3()
+                    .child(settings_git_row_label(
+                        tr("settings-fonts-ligatures"),
+                        tr("settings-fonts-ligatures-hint"),
+                        cx,
+                    ))
+                    .child(settings_git_toggle_dropdown(
+                        "settings-fonts-ligatures-dropdown",
+                        fonts.mono_ligatures,
+                        dropdown_bg,
+                        view,
+                        Self::set_settings_mono_ligatures,
+                    )),
+            )
+            .into_any_element()
+    }
+}
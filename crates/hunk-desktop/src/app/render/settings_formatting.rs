@@ -0,0 +1,106 @@
+impl DiffViewer {
+    fn render_settings_formatting_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let formatting = &settings.formatting;
+
+        let command_field = |languages: &[String], input: &Entity<InputState>| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(languages.join(", ")),
+                )
+                .child(
+                    Input::new(input)
+                        .h(px(36.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background),
+                )
+        };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(tr("settings-category-formatting")),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr("settings-formatting-description")),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                tr("settings-formatting-format-on-save"),
+                                tr("settings-formatting-format-on-save-hint"),
+                                cx,
+                            ))
+                            .child(settings_git_toggle_dropdown(
+                                "settings-formatting-format-on-save-dropdown",
+                                formatting.format_on_save,
+                                dropdown_bg,
+                                view,
+                                Self::set_settings_format_on_save,
+                            )),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .children(formatting.formatters.iter().zip(&formatting.commands).map(
+                        |(formatter, input)| command_field(formatter.languages.as_slice(), input),
+                    ))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr("settings-formatting-footer")),
+                    ),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,146 @@
+const DIFF_ROW_DOUBLE_CLICK_CHOICES: [(DiffRowDoubleClickAction, &str); 3] = [
+    (
+        DiffRowDoubleClickAction::SelectLine,
+        "settings-gesture-select-line",
+    ),
+    (
+        DiffRowDoubleClickAction::OpenInEditor,
+        "settings-gesture-open-in-editor",
+    ),
+    (
+        DiffRowDoubleClickAction::AddComment,
+        "settings-gesture-add-comment",
+    ),
+];
+
+const FILE_MIDDLE_CLICK_CHOICES: [(FileMiddleClickAction, &str); 3] = [
+    (FileMiddleClickAction::Nothing, "settings-gesture-nothing"),
+    (
+        FileMiddleClickAction::Collapse,
+        "settings-gesture-collapse-file",
+    ),
+    (FileMiddleClickAction::Pin, "settings-gesture-pin-file"),
+];
+
+/// Choices pair each value with the catalog key of its label.
+fn settings_gesture_dropdown<T: Copy + PartialEq + 'static>(
+    id: &'static str,
+    selected: T,
+    choices: &'static [(T, &'static str)],
+    dropdown_bg: Hsla,
+    view: Entity<DiffViewer>,
+    on_select: fn(&mut DiffViewer, T, &mut Context<DiffViewer>),
+) -> impl IntoElement {
+    let label = choices
+        .iter()
+        .find(|(value, _)| *value == selected)
+        .map_or(String::new(), |(_, key)| tr(key));
+    Button::new(id)
+        .outline()
+        .compact()
+        .rounded(px(8.0))
+        .bg(dropdown_bg)
+        .dropdown_caret(true)
+        .label(label)
+        .dropdown_menu(move |menu, _, _| {
+            choices.iter().fold(menu, |menu, &(value, key)| {
+                menu.item(
+                    PopupMenuItem::new(tr(key))
+                        .checked(selected == value)
+                        .on_click({
+                            let view = view.clone();
+                            move |_, _, cx| {
+                                view.update(cx, |this, cx| on_select(this, value, cx));
+                            }
+                        }),
+                )
+            })
+        })
+}
+
+impl DiffViewer {
+    fn render_settings_gestures_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let gestures = settings.gestures;
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(tr("settings-category-gestures")),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr("settings-gestures-description")),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                tr("settings-gestures-double-click"),
+                                tr("settings-gestures-double-click-hint"),
+                                cx,
+                            ))
+                            .child(settings_gesture_dropdown(
+                                "settings-gestures-diff-row-double-click-dropdown",
+                                gestures.diff_row_double_click,
+                                &DIFF_ROW_DOUBLE_CLICK_CHOICES,
+                                dropdown_bg,
+                                view.clone(),
+                                Self::set_settings_diff_row_double_click,
+                            )),
+                    )
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                tr("settings-gestures-middle-click"),
+                                tr("settings-gestures-middle-click-hint"),
+                                cx,
+                            ))
+                            .child(settings_gesture_dropdown(
+                                "settings-gestures-file-middle-click-dropdown",
+                                gestures.file_middle_click,
+                                &FILE_MIDDLE_CLICK_CHOICES,
+                                dropdown_bg,
+                                view,
+                                Self::set_settings_file_middle_click,
+                            )),
+                    ),
+            )
+            .into_any_element()
+    }
+}
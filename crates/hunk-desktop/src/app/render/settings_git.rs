@@ -0,0 +1,286 @@
+fn settings_git_row_label(
+    title: impl Into<SharedString>,
+    hint: impl Into<SharedString>,
+    cx: &App,
+) -> impl IntoElement {
+    v_flex()
+        .gap_0p5()
+        .child(
+            div()
+                .text_sm()
+                .font_semibold()
+                .text_color(cx.theme().foreground)
+                .child(title.into()),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(hint.into()),
+        )
+}
+
+fn settings_git_toggle_dropdown(
+    id: &'static str,
+    enabled: bool,
+    dropdown_bg: Hsla,
+    view: Entity<DiffViewer>,
+    on_select: fn(&mut DiffViewer, bool, &mut Context<DiffViewer>),
+) -> impl IntoElement {
+    Button::new(id)
+        .outline()
+        .compact()
+        .rounded(px(8.0))
+        .bg(dropdown_bg)
+        .dropdown_caret(true)
+        .label(tr(if enabled { "settings-on" } else { "settings-off" }))
+        .dropdown_menu(move |menu, _, _| {
+            [("settings-on", true), ("settings-off", false)]
+                .into_iter()
+                .fold(menu, |menu, (label, value)| {
+                    menu.item(
+                        PopupMenuItem::new(tr(label))
+                            .checked(enabled == value)
+                            .on_click({
+                                let view = view.clone();
+                                move |_, _, cx| {
+                                    view.update(cx, |this, cx| on_select(this, value, cx));
+                                }
+                            }),
+                    )
+                })
+        })
+}
+
+impl DiffViewer {
+    fn render_settings_git_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let git = &settings.git;
+        let config_path = hunk_git::user_config::global_config_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| "~/.gitconfig".to_string());
+
+        let input_row = |title: &'static str, input_state: &Entity<InputState>, disabled: bool| {
+            v_flex()
+                .w_full()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(title),
+                )
+                .child(
+                    Input::new(input_state)
+                        .h(px(36.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background)
+                        .disabled(disabled),
+                )
+        };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Git"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!(
+                                "Identity and signing settings are saved to {config_path}."
+                            )),
+                    ),
+            )
+            .when_some(git.load_error.clone(), |this, load_error| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().danger)
+                        .child(format!(
+                            "Git config could not be read and will not be modified: {load_error}"
+                        )),
+                )
+            })
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(input_row(
+                        "Name (user.name)",
+                        &git.name,
+                        git.load_error.is_some(),
+                    ))
+                    .child(input_row(
+                        "Email (user.email)",
+                        &git.email,
+                        git.load_error.is_some(),
+                    ))
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                "Auto Set Up Remote",
+                                "Creates the upstream branch automatically on first push (push.autoSetupRemote).",
+                                cx,
+                            ))
+                            .child(settings_git_toggle_dropdown(
+                                "settings-git-auto-setup-remote-dropdown",
+                                git.auto_setup_remote,
+                                dropdown_bg,
+                                view.clone(),
+                                Self::set_settings_git_auto_setup_remote,
+                            )),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                "Sign Commits",
+                                "Signs new commits with the configured key (commit.gpgSign).",
+                                cx,
+                            ))
+                            .child(settings_git_toggle_dropdown(
+                                "settings-git-sign-commits-dropdown",
+                                git.sign_commits,
+                                dropdown_bg,
+                                view.clone(),
+                                Self::set_settings_git_sign_commits,
+                            )),
+                    )
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(settings_git_row_label(
+                                "Signing Format",
+                                "Key type used for signing (gpg.format).",
+                                cx,
+                            ))
+                            .child({
+                                let view = view.clone();
+                                let selected = git.signing_format;
+                                Button::new("settings-git-signing-format-dropdown")
+                                    .outline()
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .bg(dropdown_bg)
+                                    .dropdown_caret(true)
+                                    .label(selected.label())
+                                    .dropdown_menu(move |menu, _, _| {
+                                        GitSigningFormat::ALL.into_iter().fold(
+                                            menu,
+                                            |menu, format| {
+                                                menu.item(
+                                                    PopupMenuItem::new(format.label())
+                                                        .checked(selected == format)
+                                                        .on_click({
+                                                            let view = view.clone();
+                                                            move |_, _, cx| {
+                                                                view.update(cx, |this, cx| {
+                                                                    this.set_settings_git_signing_format(
+                                                                        format, cx,
+                                                                    );
+                                                                });
+                                                            }
+                                                        }),
+                                                )
+                                            },
+                                        )
+                                    })
+                            }),
+                    )
+                    .child(input_row(
+                        "Signing Key (user.signingKey)",
+                        &git.signing_key,
+                        git.load_error.is_some(),
+                    )),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(settings_git_row_label(
+                        "Network Timeouts",
+                        "Push and sync stop after this many seconds. Use 0 to wait indefinitely.",
+                        cx,
+                    ))
+                    .child(input_row("Push Timeout (seconds)", &git.push_timeout, false))
+                    .child(input_row("Sync Timeout (seconds)", &git.sync_timeout, false)),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(settings_git_row_label(
+                        "Snapshot Exclusions",
+                        if git.snapshot_exclusions_repo_root.is_some() {
+                            "Comma-separated patterns for local paths this repository's working copy never starts tracking. Saved to .git/info/exclude."
+                        } else {
+                            "Open a repository to edit the paths its working copy never starts tracking."
+                        },
+                        cx,
+                    ))
+                    .child(input_row(
+                        "Excluded Paths",
+                        &git.snapshot_exclusions,
+                        git.snapshot_exclusions_repo_root.is_none(),
+                    )),
+            )
+            .into_any_element()
+    }
+}
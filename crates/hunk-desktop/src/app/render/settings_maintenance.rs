@@ -0,0 +1,163 @@
+impl DiffViewer {
+    fn render_settings_maintenance_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+        let running = settings.maintenance.running;
+
+        let task_row = |task: SettingsMaintenanceTask,
+                        id: &'static str,
+                        hint: &'static str,
+                        extra: Option<AnyElement>| {
+            let last_run = self
+                .last_maintenance_run(task)
+                .map(|run| {
+                    format!(
+                        "Last run {}: {}.",
+                        relative_time_label(Some(run.ran_unix_ms / 1000)),
+                        run.detail
+                    )
+                })
+                .unwrap_or_else(|| "Never run.".to_string());
+            let view = view.clone();
+
+            v_flex()
+                .w_full()
+                .gap_3()
+                .p_3()
+                .rounded(px(10.0))
+                .border_1()
+                .border_color(card_surface.border)
+                .bg(card_surface.background)
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_3()
+                        .child(settings_git_row_label(task.title(), hint, cx))
+                        .child(
+                            Button::new(id)
+                                .outline()
+                                .compact()
+                                .rounded(px(8.0))
+                                .label(if running == Some(task) {
+                                    "Running..."
+                                } else {
+                                    "Run Now"
+                                })
+                                .disabled(running.is_some())
+                                .on_click(move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.run_settings_maintenance_task(task, cx);
+                                    });
+                                }),
+                        ),
+                )
+                .children(extra)
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(last_run),
+                )
+        };
+
+        let retention_field = |label: &'static str, input: &Entity<InputState>| {
+            v_flex()
+                .flex_1()
+                .min_w_0()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(label),
+                )
+                .child(
+                    Input::new(input)
+                        .h(px(36.0))
+                        .rounded(px(8.0))
+                        .border_1()
+                        .border_color(input_surface.border)
+                        .bg(input_surface.background),
+                )
+        };
+        let recently_pruned = match self.recently_pruned_comments() {
+            1 => "Recently pruned: 1 comment in the last 7 days.".to_string(),
+            count => format!("Recently pruned: {count} comments in the last 7 days."),
+        };
+        let retention_input = v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_3()
+                    .child(retention_field(
+                        "Keep Resolved Comments (days)",
+                        &settings.maintenance.comment_retention_days,
+                    ))
+                    .child(retention_field(
+                        "Keep Stale Comments (days)",
+                        &settings.maintenance.stale_comment_retention_days,
+                    )),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(recently_pruned),
+            )
+            .into_any_element();
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Maintenance"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                "Reclaim disk space used by old comments, Git objects, and the local database.",
+                            ),
+                    ),
+            )
+            .child(task_row(
+                SettingsMaintenanceTask::PruneComments,
+                "settings-maintenance-prune-comments",
+                "Deletes resolved and stale comments older than their retention. Also runs at startup and once a day.",
+                Some(retention_input),
+            ))
+            .child(task_row(
+                SettingsMaintenanceTask::GitGc,
+                "settings-maintenance-git-gc",
+                "Runs git gc on the open repository to pack loose objects and drop unreachable ones.",
+                None,
+            ))
+            .child(task_row(
+                SettingsMaintenanceTask::CompactDatabase,
+                "settings-maintenance-compact-database",
+                "Rebuilds the comments and usage metrics database without its free space.",
+                None,
+            ))
+            .into_any_element()
+    }
+}
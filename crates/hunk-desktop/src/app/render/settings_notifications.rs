@@ -0,0 +1,187 @@
+const SETTINGS_REVIEW_BREAK_REMINDER_OPTIONS: [u64; 4] = [0, 25, 50, 90];
+
+fn settings_review_break_reminder_label(minutes: u64) -> String {
+    match minutes {
+        0 => tr("settings-off"),
+        minutes => tr_args(
+            "settings-review-break-minutes",
+            &[("minutes", minutes.to_string().as_str())],
+        ),
+    }
+}
+
+fn settings_review_break_reminder_dropdown(
+    minutes: u64,
+    dropdown_bg: Hsla,
+    view: Entity<DiffViewer>,
+) -> impl IntoElement {
+    Button::new("settings-review-break-reminder-dropdown")
+        .outline()
+        .compact()
+        .rounded(px(8.0))
+        .bg(dropdown_bg)
+        .dropdown_caret(true)
+        .label(settings_review_break_reminder_label(minutes))
+        .dropdown_menu(move |menu, _, _| {
+            SETTINGS_REVIEW_BREAK_REMINDER_OPTIONS
+                .into_iter()
+                .fold(menu, |menu, value| {
+                    menu.item(
+                        PopupMenuItem::new(settings_review_break_reminder_label(value))
+                            .checked(minutes == value)
+                            .on_click({
+                                let view = view.clone();
+                                move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.set_settings_review_break_reminder_minutes(value, cx);
+                                    });
+                                }
+                            }),
+                    )
+                })
+        })
+}
+
+impl DiffViewer {
+    fn render_settings_notifications_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let notifications = settings.desktop_notifications;
+        let review_timer = settings.review_timer;
+
+        // Titles and hints are catalog keys.
+        let toggle_row =
+            |title: &'static str,
+             hint: &'static str,
+             id: &'static str,
+             enabled: bool,
+             on_select: fn(&mut DiffViewer, bool, &mut Context<DiffViewer>)| {
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_3()
+                    .child(settings_git_row_label(tr(title), tr(hint), cx))
+                    .child(settings_git_toggle_dropdown(
+                        id,
+                        enabled,
+                        dropdown_bg,
+                        view.clone(),
+                        on_select,
+                    ))
+            };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(tr("settings-category-notifications")),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr("settings-notifications-description")),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(toggle_row(
+                        "settings-notifications-enabled",
+                        "settings-notifications-enabled-hint",
+                        "settings-notifications-enabled-dropdown",
+                        notifications.enabled,
+                        Self::set_settings_desktop_notifications_enabled,
+                    )),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .when(!notifications.enabled, |this| this.opacity(0.6))
+                    .child(toggle_row(
+                        "settings-notifications-ci-finished",
+                        "settings-notifications-ci-finished-hint",
+                        "settings-notifications-ci-finished-dropdown",
+                        notifications.ci_finished,
+                        Self::set_settings_desktop_notifications_ci_finished,
+                    ))
+                    .child(toggle_row(
+                        "settings-notifications-remote-branch-moved",
+                        "settings-notifications-remote-branch-moved-hint",
+                        "settings-notifications-remote-branch-moved-dropdown",
+                        notifications.remote_branch_moved,
+                        Self::set_settings_desktop_notifications_remote_branch_moved,
+                    ))
+                    .child(toggle_row(
+                        "settings-notifications-comment-mentions",
+                        "settings-notifications-comment-mentions-hint",
+                        "settings-notifications-comment-mentions-dropdown",
+                        notifications.comment_mentions,
+                        Self::set_settings_desktop_notifications_comment_mentions,
+                    )),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(toggle_row(
+                        "settings-review-timer",
+                        "settings-review-timer-hint",
+                        "settings-review-timer-enabled-dropdown",
+                        review_timer.enabled,
+                        Self::set_settings_review_timer_enabled,
+                    ))
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .when(!review_timer.enabled, |this| this.opacity(0.6))
+                            .child(settings_git_row_label(
+                                tr("settings-review-break-reminder"),
+                                tr("settings-review-break-reminder-hint"),
+                                cx,
+                            ))
+                            .child(settings_review_break_reminder_dropdown(
+                                review_timer.break_reminder_minutes,
+                                dropdown_bg,
+                                view.clone(),
+                            )),
+                    ),
+            )
+            .into_any_element()
+    }
+}
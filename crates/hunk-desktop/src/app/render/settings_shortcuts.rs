@@ -0,0 +1,167 @@
+impl DiffViewer {
+    fn render_settings_shortcuts_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let dropdown_bg = hunk_dropdown_fill(cx.theme(), is_dark);
+        let vim_mode = settings.vim_mode;
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(tr("settings-category-keyboard-shortcuts")),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(tr("settings-shortcuts-description")),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_2()
+                    .p_3()
+                    .rounded(px(10.0))
+                    .border_1()
+                    .border_color(card_surface.border)
+                    .bg(card_surface.background)
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(tr("settings-vim-mode")),
+                            )
+                            .child(
+                                Button::new("settings-vim-mode-dropdown")
+                                    .outline()
+                                    .compact()
+                                    .rounded(px(8.0))
+                                    .bg(dropdown_bg)
+                                    .dropdown_caret(true)
+                                    .label(if vim_mode {
+                                        tr("settings-on")
+                                    } else {
+                                        tr("settings-off")
+                                    })
+                                    .dropdown_menu(move |menu, _, _| {
+                                        menu.item(
+                                            PopupMenuItem::new(tr("settings-on"))
+                                                .checked(vim_mode)
+                                                .on_click({
+                                                    let view = view.clone();
+                                                    move |_, _, cx| {
+                                                        view.update(cx, |this, cx| {
+                                                            this.set_settings_vim_mode(true, cx);
+                                                        });
+                                                    }
+                                                }),
+                                        )
+                                        .item(
+                                            PopupMenuItem::new(tr("settings-off"))
+                                                .checked(!vim_mode)
+                                                .on_click({
+                                                    let view = view.clone();
+                                                    move |_, _, cx| {
+                                                        view.update(cx, |this, cx| {
+                                                            this.set_settings_vim_mode(false, cx);
+                                                        });
+                                                    }
+                                                }),
+                                        )
+                                    }),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .whitespace_normal()
+                            .child(tr("settings-vim-mode-hint")),
+                    ),
+            )
+            .children(
+                settings
+                    .shortcuts
+                    .rows()
+                    .into_iter()
+                    .map(|row| self.render_settings_shortcut_row(row, cx)),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(hunk_opacity(
+                        cx.theme().muted_foreground,
+                        is_dark,
+                        0.94,
+                        1.0,
+                    ))
+                    .child(tr("settings-shortcuts-footer")),
+            )
+            .into_any_element()
+    }
+
+    fn render_settings_shortcut_row(
+        &self,
+        row: SettingsShortcutRow,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+        let input_surface = hunk_input_surface(cx.theme(), is_dark);
+
+        v_flex()
+            .id(row.id)
+            .w_full()
+            .gap_1()
+            .p_3()
+            .rounded(px(10.0))
+            .border_1()
+            .border_color(card_surface.border)
+            .bg(card_surface.background)
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(tr(format!("settings-shortcut-{}", row.id).as_str())),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(tr(format!("settings-shortcut-{}-hint", row.id).as_str())),
+            )
+            .child(
+                Input::new(&row.input_state)
+                    .h(px(36.0))
+                    .rounded(px(8.0))
+                    .border_1()
+                    .border_color(input_surface.border)
+                    .bg(input_surface.background)
+                    .disabled(false),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,179 @@
+impl DiffViewer {
+    fn render_settings_usage_metrics_category(
+        &self,
+        settings: &SettingsDraft,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let card_surface = hunk_card_surface(cx.theme(), is_dark);
+
+        let content = match settings.usage_metrics.as_ref() {
+            None if settings.usage_metrics_loading => div()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child("Loading usage metrics...")
+                .into_any_element(),
+            None => div().into_any_element(),
+            Some(summary) if summary.days.is_empty() => div()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child("No activity recorded in the last 30 days.")
+                .into_any_element(),
+            Some(summary) => {
+                let average_load = summary
+                    .average_snapshot_load_ms()
+                    .map(|average| format!("{average} ms"))
+                    .unwrap_or_else(|| "-".to_string());
+                v_flex()
+                    .w_full()
+                    .gap_3()
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .gap_3()
+                            .child(usage_metric_tile(
+                                "Diffs Viewed",
+                                summary.total_diffs_viewed().to_string(),
+                                cx,
+                            ))
+                            .child(usage_metric_tile(
+                                "Comments Written",
+                                summary.total_comments_written().to_string(),
+                                cx,
+                            ))
+                            .child(usage_metric_tile(
+                                "Pushes",
+                                summary.total_pushes().to_string(),
+                                cx,
+                            ))
+                            .child(usage_metric_tile("Avg Snapshot Load", average_load, cx)),
+                    )
+                    .child(
+                        v_flex()
+                            .w_full()
+                            .gap_1()
+                            .p_3()
+                            .rounded(px(10.0))
+                            .border_1()
+                            .border_color(card_surface.border)
+                            .bg(card_surface.background)
+                            .child(usage_metrics_day_row(
+                                [
+                                    "Day".to_string(),
+                                    "Diffs".to_string(),
+                                    "Comments".to_string(),
+                                    "Pushes".to_string(),
+                                    "Avg Load".to_string(),
+                                ],
+                                true,
+                                cx,
+                            ))
+                            .children(summary.days.iter().map(|day| {
+                                usage_metrics_day_row(
+                                    [
+                                        usage_metrics_day_label(day.day_start_unix_ms),
+                                        day.diffs_viewed.to_string(),
+                                        day.comments_written.to_string(),
+                                        day.pushes.to_string(),
+                                        day.average_snapshot_load_ms
+                                            .map(|average| format!("{average} ms"))
+                                            .unwrap_or_else(|| "-".to_string()),
+                                    ],
+                                    false,
+                                    cx,
+                                )
+                            })),
+                    )
+                    .into_any_element()
+            }
+        };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_base()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Usage Metrics"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                "Your last 30 days, stored only in the local database. Nothing is sent anywhere.",
+                            ),
+                    ),
+            )
+            .child(content)
+            .into_any_element()
+    }
+}
+
+fn usage_metric_tile(label: &'static str, value: String, cx: &App) -> impl IntoElement {
+    let card_surface = hunk_card_surface(cx.theme(), cx.theme().mode.is_dark());
+
+    v_flex()
+        .flex_1()
+        .min_w_0()
+        .gap_0p5()
+        .p_3()
+        .rounded(px(10.0))
+        .border_1()
+        .border_color(card_surface.border)
+        .bg(card_surface.background)
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(label),
+        )
+        .child(
+            div()
+                .text_lg()
+                .font_semibold()
+                .text_color(cx.theme().foreground)
+                .child(value),
+        )
+}
+
+fn usage_metrics_day_row(columns: [String; 5], header: bool, cx: &App) -> impl IntoElement {
+    let text_color = if header {
+        cx.theme().muted_foreground
+    } else {
+        cx.theme().foreground
+    };
+
+    h_flex()
+        .w_full()
+        .gap_2()
+        .text_xs()
+        .text_color(text_color)
+        .when(header, |this| this.font_semibold())
+        .children(columns.into_iter().enumerate().map(|(ix, column)| {
+            div()
+                .when(ix == 0, |this| this.w(px(110.0)))
+                .when(ix > 0, |this| this.flex_1().text_right())
+                .child(column)
+        }))
+}
+
+fn usage_metrics_day_label(day_start_unix_ms: i64) -> String {
+    time::OffsetDateTime::from_unix_timestamp(day_start_unix_ms / 1000)
+        .map(|datetime| {
+            let date = datetime.date();
+            format!(
+                "{}-{:02}-{:02}",
+                date.year(),
+                date.month() as u8,
+                date.day()
+            )
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,371 @@
+#[derive(Clone)]
+struct StackEditorDrag {
+    ix: usize,
+    label: SharedString,
+}
+
+impl Render for StackEditorDrag {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_dark = cx.theme().mode.is_dark();
+        div()
+            .px_2()
+            .py_1()
+            .rounded(px(6.0))
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(hunk_opacity(cx.theme().accent, is_dark, 0.30, 0.18))
+            .text_xs()
+            .text_color(cx.theme().foreground)
+            .child(self.label.clone())
+    }
+}
+
+impl DiffViewer {
+    fn render_stack_editor_popup(&self, cx: &mut Context<Self>) -> AnyElement {
+        let Some(panel) = self.stack_editor_panel.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let backdrop_bg = hunk_modal_backdrop(cx.theme(), is_dark);
+        let modal_surface = hunk_modal_surface(cx.theme(), is_dark);
+        let busy = panel.loading || panel.applying;
+        let changed = Self::stack_editor_plan_changed(panel);
+        let summary = match panel.stack.as_ref() {
+            _ if panel.applying => tr("stack-editor-applying"),
+            None if panel.loading => tr("stack-editor-loading"),
+            None => String::new(),
+            Some(stack) => tr_args(
+                "stack-editor-summary",
+                &[
+                    ("count", stack.commits.len().to_string().as_str()),
+                    ("branch", stack.branch.as_str()),
+                    ("base", stack.base_label.as_str()),
+                ],
+            ),
+        };
+        let rows = panel.stack.as_ref().map_or_else(Vec::new, |stack| {
+            panel
+                .plan
+                .iter()
+                .enumerate()
+                .filter_map(|(ix, entry)| {
+                    let commit = stack
+                        .commits
+                        .iter()
+                        .find(|commit| commit.commit_id == entry.commit_id)?;
+                    Some(self.render_stack_editor_row(
+                        ix,
+                        panel.plan.len(),
+                        commit,
+                        entry.action,
+                        busy,
+                        cx,
+                    ))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        div()
+            .id("stack-editor-overlay")
+            .absolute()
+            .top_0()
+            .right_0()
+            .bottom_0()
+            .left_0()
+            .bg(backdrop_bg)
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Middle, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_scroll_wheel(|_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .size_full()
+                    .p_6()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        v_flex()
+                            .id("stack-editor-popup")
+                            .w_full()
+                            .max_w(px(760.0))
+                            .h(px(600.0))
+                            .rounded(px(12.0))
+                            .border_1()
+                            .border_color(modal_surface.border)
+                            .bg(modal_surface.background)
+                            .shadow_lg()
+                            .child(
+                                v_flex()
+                                    .gap_1()
+                                    .px_4()
+                                    .py_3()
+                                    .border_b_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        div()
+                                            .text_base()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(tr("stack-editor-title")),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .whitespace_normal()
+                                            .child(summary),
+                                    ),
+                            )
+                            .child(
+                                v_flex()
+                                    .id("stack-editor-list")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .w_full()
+                                    .overflow_y_scroll()
+                                    .gap_1()
+                                    .p_2()
+                                    .children(rows)
+                                    .when_some(panel.error_message.clone(), |this, message| {
+                                        this.child(
+                                            div()
+                                                .px_2()
+                                                .py_1()
+                                                .text_sm()
+                                                .text_color(cx.theme().danger)
+                                                .whitespace_normal()
+                                                .child(message),
+                                        )
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .justify_end()
+                                    .gap_2()
+                                    .px_4()
+                                    .py_3()
+                                    .border_t_1()
+                                    .border_color(hunk_opacity(
+                                        cx.theme().border,
+                                        is_dark,
+                                        0.92,
+                                        0.74,
+                                    ))
+                                    .child(
+                                        Button::new("stack-editor-reset")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label(tr("stack-editor-reset"))
+                                            .disabled(busy || !changed)
+                                            .on_click({
+                                                let view = view.clone();
+                                                move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.reset_stack_editor_plan(cx);
+                                                    });
+                                                }
+                                            }),
+                                    )
+                                    .child(
+                                        Button::new("stack-editor-apply")
+                                            .outline()
+                                            .rounded(px(8.0))
+                                            .label(tr("stack-editor-rewrite"))
+                                            .disabled(busy || !changed)
+                                            .on_click({
+                                                let view = view.clone();
+                                                move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.apply_stack_editor_plan(cx);
+                                                    });
+                                                }
+                                            }),
+                                    )
+                                    .child(
+                                        Button::new("stack-editor-close")
+                                            .primary()
+                                            .rounded(px(8.0))
+                                            .label(tr("stack-editor-close"))
+                                            .on_click(move |_, window, cx| {
+                                                view.update(cx, |this, cx| {
+                                                    this.close_stack_editor(window, cx);
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_stack_editor_row(
+        &self,
+        ix: usize,
+        len: usize,
+        commit: &StackCommit,
+        action: StackAction,
+        busy: bool,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let drop_target_bg = hunk_opacity(cx.theme().accent, is_dark, 0.24, 0.14);
+        let subject_color = match action {
+            StackAction::Drop => cx.theme().muted_foreground,
+            StackAction::Pick | StackAction::Squash => cx.theme().foreground,
+        };
+        let drag = StackEditorDrag {
+            ix,
+            label: format!(
+                "{}  {}",
+                short_commit_id(commit.commit_id.as_str()),
+                commit.subject
+            )
+            .into(),
+        };
+        let action_button = |id: &'static str, label: String, target: StackAction| {
+            let view = view.clone();
+            let button = Button::new((id, ix))
+                .compact()
+                .rounded(px(6.0))
+                .label(label)
+                .disabled(busy)
+                .on_click(move |_, _, cx| {
+                    view.update(cx, |this, cx| {
+                        this.set_stack_editor_action(ix, target, cx);
+                    });
+                });
+            if action == target {
+                button.primary()
+            } else {
+                button.ghost()
+            }
+        };
+
+        h_flex()
+            .id(("stack-editor-row", ix))
+            .w_full()
+            .min_w_0()
+            .items_center()
+            .gap_2()
+            .rounded(px(8.0))
+            .px_2()
+            .py_1p5()
+            .when(action == StackAction::Squash, |this| this.pl_6())
+            .hover(|style| style.bg(hunk_opacity(cx.theme().accent, is_dark, 0.12, 0.07)))
+            .when(!busy, |this| {
+                this.cursor_grab()
+                    .on_drag(drag, |drag, _, _, cx| {
+                        cx.stop_propagation();
+                        cx.new(|_| drag.clone())
+                    })
+                    .drag_over::<StackEditorDrag>(move |style, _, _, _| style.bg(drop_target_bg))
+                    .on_drop(cx.listener(move |this, drag: &StackEditorDrag, _, cx| {
+                        this.move_stack_editor_entry(drag.ix, ix, cx);
+                    }))
+            })
+            .child(
+                div()
+                    .w(px(22.0))
+                    .flex_none()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("{}", ix + 1)),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .gap_0p5()
+                    .child(
+                        div()
+                            .truncate()
+                            .text_sm()
+                            .text_color(subject_color)
+                            .child(commit.subject.clone()),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_family(cx.theme().mono_font_family.clone())
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!(
+                                "{}  {} • {}",
+                                short_commit_id(commit.commit_id.as_str()),
+                                commit.author_name,
+                                relative_time_label(Some(commit.committed_unix_time))
+                            )),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .flex_none()
+                    .items_center()
+                    .gap_1()
+                    .child(action_button(
+                        "stack-editor-pick",
+                        tr("stack-editor-pick"),
+                        StackAction::Pick,
+                    ))
+                    .child(action_button(
+                        "stack-editor-squash",
+                        tr("stack-editor-squash"),
+                        StackAction::Squash,
+                    ))
+                    .child(action_button(
+                        "stack-editor-drop",
+                        tr("stack-editor-drop"),
+                        StackAction::Drop,
+                    ))
+                    .child(
+                        Button::new(("stack-editor-up", ix))
+                            .ghost()
+                            .compact()
+                            .rounded(px(6.0))
+                            .icon(Icon::new(IconName::ChevronUp).size(px(12.0)))
+                            .tooltip(tr("stack-editor-move-up"))
+                            .disabled(busy || ix == 0)
+                            .on_click({
+                                let view = view.clone();
+                                move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.move_stack_editor_entry(ix, ix - 1, cx);
+                                    });
+                                }
+                            }),
+                    )
+                    .child(
+                        Button::new(("stack-editor-down", ix))
+                            .ghost()
+                            .compact()
+                            .rounded(px(6.0))
+                            .icon(Icon::new(IconName::ChevronDown).size(px(12.0)))
+                            .tooltip(tr("stack-editor-move-down"))
+                            .disabled(busy || ix + 1 >= len)
+                            .on_click(move |_, _, cx| {
+                                view.update(cx, |this, cx| {
+                                    this.move_stack_editor_entry(ix, ix + 1, cx);
+                                });
+                            }),
+                    ),
+            )
+            .into_any_element()
+    }
+}
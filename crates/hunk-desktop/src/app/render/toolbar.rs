@@ -25,16 +25,18 @@ impl DiffViewer {
             .primary_checked_out_branch_name()
             .unwrap_or(self.branch_name.as_str())
             .to_string();
+        let head_branches = self.ambiguous_head_branch_names().to_vec();
+        let checked_out_branch = self.checked_out_branch_name().map(ToOwned::to_owned);
         let chip_colors = hunk_toolbar_chip(cx.theme(), is_dark);
         let toolbar_button_bg = hunk_dropdown_fill(cx.theme(), is_dark);
         let visible_line_stats = self.active_diff_overall_line_stats();
-        let visible_file_count = if review_selected {
-            self.active_diff_file_count()
-        } else if git_selected {
+        let git_overall_line_stats = self.git_workspace_overall_line_stats();
+        let visible_file_count = if git_selected {
             self.git_workspace.files.len()
         } else {
-            self.files.len()
+            self.active_diff_file_count()
         };
+        let stats_ignored_file_count = self.stats_ignored_file_count();
         let ai_pending_approval_count = ai_view_state.map(|state| state.pending_approvals.len());
         let ai_pending_user_input_count =
             ai_view_state.map(|state| state.pending_user_inputs.len());
@@ -96,6 +98,48 @@ impl DiffViewer {
                                 .child(active_branch),
                         ),
                 )
+                .when(!head_branches.is_empty(), |this| {
+                    let view = view.clone();
+                    let label = match head_branches.len() {
+                        1 => "1 branch at HEAD".to_string(),
+                        count => format!("{count} branches at HEAD"),
+                    };
+                    this.child(
+                        Button::new("toolbar-head-branches")
+                            .outline()
+                            .compact()
+                            .rounded(px(7.0))
+                            .bg(toolbar_button_bg)
+                            .border_color(cx.theme().warning)
+                            .dropdown_caret(true)
+                            .label(label)
+                            .tooltip("Branches at the checked-out commit. Pick one to work on.")
+                            .dropdown_menu(move |mut menu, _, _| {
+                                for branch_name in &head_branches {
+                                    let branch_name = branch_name.clone();
+                                    menu = menu.item(
+                                        PopupMenuItem::new(branch_name.clone())
+                                            .checked(
+                                                checked_out_branch.as_deref()
+                                                    == Some(branch_name.as_str()),
+                                            )
+                                            .on_click({
+                                                let view = view.clone();
+                                                move |_, _, cx| {
+                                                    view.update(cx, |this, cx| {
+                                                        this.switch_to_head_branch(
+                                                            branch_name.clone(),
+                                                            cx,
+                                                        );
+                                                    });
+                                                }
+                                            }),
+                                    );
+                                }
+                                menu
+                            }),
+                    )
+                })
                 .child(
                     h_flex()
                         .flex_none()
@@ -138,6 +182,79 @@ impl DiffViewer {
             .items_center()
             .justify_end()
             .gap_2()
+            .when(review_selected, |this| {
+                let view = view.clone();
+                let changed_lines_only = self.changed_lines_only.all_files;
+                this.child(
+                    Button::new("toggle-changed-lines-only")
+                        .outline()
+                        .compact()
+                        .rounded(px(7.0))
+                        .bg(toolbar_button_bg)
+                        .when(changed_lines_only, |this| {
+                            this.border_color(cx.theme().accent)
+                        })
+                        .label(if changed_lines_only {
+                            "Changed Lines Only"
+                        } else {
+                            "Full Hunks"
+                        })
+                        .tooltip("Hide unchanged context rows in every file")
+                        .on_click(move |_, _, cx| {
+                            view.update(cx, |this, cx| {
+                                this.toggle_changed_lines_only(cx);
+                            });
+                        }),
+                )
+            })
+            .when(review_selected, |this| {
+                let view = view.clone();
+                let unified = self.diff_unified_view;
+                this.child(
+                    Button::new("toggle-diff-unified-view")
+                        .outline()
+                        .compact()
+                        .rounded(px(7.0))
+                        .bg(toolbar_button_bg)
+                        .when(unified, |this| this.border_color(cx.theme().accent))
+                        .label(if unified {
+                            tr("toolbar-diff-unified")
+                        } else {
+                            tr("toolbar-diff-side-by-side")
+                        })
+                        .tooltip(tr("toolbar-diff-unified-tooltip"))
+                        .on_click(move |_, _, cx| {
+                            view.update(cx, |this, cx| {
+                                this.toggle_diff_unified_view(cx);
+                            });
+                        }),
+                )
+            })
+            .when(review_selected, |this| {
+                let view = view.clone();
+                let participant_count = self.co_review_participant_count();
+                let label = match self.co_review_following_name() {
+                    Some(name) => format!("Following {name}"),
+                    None if participant_count > 0 => format!("Co-review ({participant_count})"),
+                    None => "Co-review".to_string(),
+                };
+                this.child(
+                    Button::new("toggle-co-review-panel")
+                        .outline()
+                        .compact()
+                        .rounded(px(7.0))
+                        .bg(toolbar_button_bg)
+                        .when(participant_count > 0, |this| {
+                            this.border_color(cx.theme().success)
+                        })
+                        .label(label)
+                        .on_click(move |_, _, cx| {
+                            view.update(cx, |this, cx| {
+                                this.toggle_co_review_panel(cx);
+                            });
+                        }),
+                )
+            })
             .when(review_selected, |this| {
                 let view = view.clone();
                 this.child(
@@ -146,7 +263,16 @@ impl DiffViewer {
                         .compact()
                         .rounded(px(7.0))
                         .bg(toolbar_button_bg)
-                        .label(format!("Comments ({})", self.comments_open_count()))
+                        .label(tr_args(
+                            "toolbar-comment-counts",
+                            &[
+                                ("open", self.comment_counts.open.to_string().as_str()),
+                                (
+                                    "resolved",
+                                    self.comment_counts.resolved.to_string().as_str(),
+                                ),
+                            ],
+                        ))
                         .on_click(move |_, _, cx| {
                             view.update(cx, |this, cx| {
                                 this.toggle_comments_preview(cx);
@@ -154,13 +280,79 @@ impl DiffViewer {
                         }),
                 )
             })
+            .when(review_selected, |this| {
+                let view = view.clone();
+                this.child(
+                    Button::new("toolbar-export-review")
+                        .outline()
+                        .compact()
+                        .rounded(px(7.0))
+                        .bg(toolbar_button_bg)
+                        .dropdown_caret(true)
+                        .label(tr("toolbar-export-review"))
+                        .tooltip(tr("toolbar-export-review-tooltip"))
+                        .dropdown_menu(move |menu, _, _| {
+                            menu.item(
+                                PopupMenuItem::new(tr("toolbar-export-copy-markdown")).on_click({
+                                    let view = view.clone();
+                                    move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.copy_review_comments_markdown(cx);
+                                        });
+                                    }
+                                }),
+                            )
+                            .item(
+                                PopupMenuItem::new(tr("toolbar-export-save-markdown")).on_click({
+                                    let view = view.clone();
+                                    move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.save_review_comments(
+                                                CommentExportFormat::Markdown,
+                                                cx,
+                                            );
+                                        });
+                                    }
+                                }),
+                            )
+                            .item(
+                                PopupMenuItem::new(tr("toolbar-export-save-json")).on_click({
+                                    let view = view.clone();
+                                    move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.save_review_comments(
+                                                CommentExportFormat::Json,
+                                                cx,
+                                            );
+                                        });
+                                    }
+                                }),
+                            )
+                            .separator()
+                            .item(PopupMenuItem::new(tr("toolbar-github-publish")).on_click({
+                                let view = view.clone();
+                                move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.publish_review_comments_to_github(cx);
+                                    });
+                                }
+                            }))
+                            .item(
+                                PopupMenuItem::new(tr("toolbar-github-pull")).on_click({
+                                    let view = view.clone();
+                                    move |_, _, cx| {
+                                        view.update(cx, |this, cx| {
+                                            this.pull_github_review_comments(cx);
+                                        });
+                                    }
+                                }),
+                            )
+                        }),
+                )
+            })
             .when(git_selected, |this| {
-                this.when(self.git_workspace.overall_line_stats.changed() > 0, |this| {
-                    this.child(self.render_line_stats(
-                        "overall",
-                        self.git_workspace.overall_line_stats,
-                        cx,
-                    ))
+                this.when(git_overall_line_stats.changed() > 0, |this| {
+                    this.child(self.render_line_stats("overall", git_overall_line_stats, cx))
                 })
                 .child(self.render_git_metric_pill(
                     if self.git_workspace.branch_has_upstream {
@@ -211,6 +403,14 @@ impl DiffViewer {
                             .text_color(cx.theme().muted_foreground)
                             .child(format!("{} files", visible_file_count)),
                     )
+                    .when(stats_ignored_file_count > 0, |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("({stats_ignored_file_count} excluded)")),
+                        )
+                    })
             })
             .when(ai_selected, |this| {
                 this.when_some(ai_pending_approval_count, |this, count| {
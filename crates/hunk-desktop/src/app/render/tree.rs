@@ -77,7 +77,51 @@ impl DiffViewer {
                             .font_medium()
                             .text_color(cx.theme().muted_foreground)
                             .child(tree_summary),
-                    ),
+                    )
+                    .when_some(self.sparse_checkout_summary(), |this, summary| {
+                        this.child(
+                            Button::new("repo-tree-sparse-checkout")
+                                .ghost()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .label("Sparse")
+                                .tooltip(format!(
+                                    "{summary} Files outside the sparse set are hidden."
+                                )),
+                        )
+                    })
+                    .when(self.repo_tree.changed_only, |this| {
+                        this.child(
+                            Button::new("repo-tree-changed-sort")
+                                .ghost()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .label(self.repo_tree.changed_sort.label())
+                                .tooltip("Toggle sorting changed files by path or change size")
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.toggle_changed_files_sort(cx);
+                                })),
+                        )
+                    })
+                    .when(self.workspace_view_mode == WorkspaceViewMode::Diff, |this| {
+                        this.child(
+                            Button::new("repo-tree-follow-diff")
+                                .ghost()
+                                .compact()
+                                .with_size(gpui_component::Size::Small)
+                                .label(if self.review_tree_follow {
+                                    "Follow On"
+                                } else {
+                                    "Follow Off"
+                                })
+                                .tooltip(
+                                    "Keep the selected file and the diff scroll position in sync",
+                                )
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.toggle_review_tree_follow(cx);
+                                })),
+                        )
+                    }),
             )
             .child(div().flex_1().min_h_0().child(self.render_repo_tree_content(cx)))
             .when_some(self.render_repo_tree_context_menu(cx), |this, menu| {
@@ -87,15 +131,17 @@ impl DiffViewer {
 
     fn render_repo_tree_content(&mut self, cx: &mut Context<Self>) -> AnyElement {
         if self.repo_tree.loading && self.repo_tree.rows.is_empty() {
+            let is_dark = cx.theme().mode.is_dark();
+            let widths = [140.0, 180.0, 120.0, 160.0, 100.0, 150.0];
             return v_flex()
                 .w_full()
                 .px_2()
                 .py_2()
-                .child(
-                    div()
-                        .text_xs()
-                        .text_color(cx.theme().muted_foreground)
-                        .child("Loading repository tree..."),
+                .gap_2()
+                .children(
+                    widths
+                        .into_iter()
+                        .map(|width| git_loading_skeleton_block(width, 10.0, is_dark, cx)),
                 )
                 .into_any_element();
         }
@@ -132,12 +178,21 @@ impl DiffViewer {
         self.sync_sidebar_repo_list_state(self.repo_tree.rows.len());
         let list_state = self.repo_tree.list_state.clone();
         let view = cx.entity();
+        let max_changed = if self.repo_tree.changed_only {
+            self.active_diff_file_line_stats()
+                .values()
+                .map(|stats| stats.changed())
+                .max()
+                .unwrap_or_default()
+        } else {
+            0
+        };
 
         let list = list(list_state.clone(), {
             cx.processor(move |this, ix: usize, _window, cx| {
                 this.repo_tree.rows
                     .get(ix)
-                    .map(|row| this.render_repo_tree_row(row, cx))
+                    .map(|row| this.render_repo_tree_row(row, max_changed, cx))
                     .unwrap_or_else(|| div().into_any_element())
             })
         })
@@ -250,251 +305,6 @@ impl DiffViewer {
         )
     }
 
-    fn render_repo_tree_context_menu(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
-        let menu_state = self.repo_tree_context_menu.as_ref()?.clone();
-        let view = cx.entity();
-        let is_dark = cx.theme().mode.is_dark();
-        let allow_manage = self.workspace_view_mode == WorkspaceViewMode::Files && self.repo_root.is_some();
-        let allow_target_path = menu_state.target_path.is_some();
-        let allow_rename =
-            allow_manage && allow_target_path && menu_state.target_kind == RepoTreeNodeKind::File;
-        let allow_delete = allow_rename;
-        let allow_copy = allow_target_path;
-        let allow_collapse = !self.repo_tree.expanded_dirs.is_empty();
-
-        Some(
-            deferred(
-                anchored()
-                    .position(menu_state.position)
-                    .anchor(Corner::TopLeft)
-                    .snap_to_window_with_margin(px(8.0))
-                    .child(
-                        v_flex()
-                            .id("repo-tree-context-menu")
-                            .w(px(234.0))
-                            .p_1()
-                            .gap_0p5()
-                            .rounded(px(8.0))
-                            .border_1()
-                            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
-                            .bg(cx.theme().popover)
-                            .shadow_none()
-                            .on_mouse_down_out({
-                                let view = view.clone();
-                                move |_, _, cx| {
-                                    view.update(cx, |this, cx| {
-                                        this.close_repo_tree_context_menu(cx);
-                                    });
-                                }
-                            })
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "New File",
-                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::NewFile),
-                                    allow_manage,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        let target_kind = menu_state.target_kind;
-                                        move |window, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                this.open_repo_tree_new_file_prompt_at(
-                                                    target_path
-                                                        .as_ref()
-                                                        .map(|path| (path.clone(), target_kind)),
-                                                    window,
-                                                    cx,
-                                                );
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "New Folder",
-                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::NewFolder),
-                                    allow_manage,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        let target_kind = menu_state.target_kind;
-                                        move |window, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                this.open_repo_tree_new_folder_prompt_at(
-                                                    target_path
-                                                        .as_ref()
-                                                        .map(|path| (path.clone(), target_kind)),
-                                                    window,
-                                                    cx,
-                                                );
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "Rename File",
-                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::RenameFile),
-                                    allow_rename,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        move |window, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                if let Some(path) = target_path.as_ref() {
-                                                    this.open_repo_tree_rename_prompt_for_file(
-                                                        path.clone(),
-                                                        window,
-                                                        cx,
-                                                    );
-                                                }
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "Delete File",
-                                    None,
-                                    allow_delete,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        move |_, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                if let Some(path) = target_path.as_ref() {
-                                                    this.delete_repo_tree_file_at(path.as_str(), cx);
-                                                }
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "Copy Path",
-                                    None,
-                                    allow_copy,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        move |_, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                if let Some(path) = target_path.as_ref() {
-                                                    this.copy_repo_tree_absolute_path(path.as_str(), cx);
-                                                }
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "Copy Relative Path",
-                                    None,
-                                    allow_copy,
-                                    {
-                                        let view = view.clone();
-                                        let target_path = menu_state.target_path.clone();
-                                        move |_, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                if let Some(path) = target_path.as_ref() {
-                                                    this.copy_repo_tree_relative_path(path.as_str(), cx);
-                                                }
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            )
-                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
-                            .child(
-                                self.render_repo_tree_context_menu_item(
-                                    "Collapse All Folders",
-                                    None,
-                                    allow_collapse,
-                                    {
-                                        let view = view.clone();
-                                        move |_, cx| {
-                                            view.update(cx, |this, cx| {
-                                                this.close_repo_tree_context_menu(cx);
-                                                this.collapse_all_repo_tree_directories(cx);
-                                            });
-                                        }
-                                    },
-                                    cx,
-                                ),
-                            ),
-                    ),
-            )
-            .with_priority(1)
-            .into_any_element(),
-        )
-    }
-
-    fn render_repo_tree_context_menu_item(
-        &self,
-        label: &'static str,
-        shortcut: Option<String>,
-        enabled: bool,
-        on_click: impl Fn(&mut Window, &mut App) + 'static,
-        cx: &mut Context<Self>,
-    ) -> AnyElement {
-        let text_color = if enabled {
-            cx.theme().popover_foreground
-        } else {
-            cx.theme().muted_foreground
-        };
-        let hover_bg = cx.theme().secondary_hover;
-        div()
-            .w_full()
-            .px_2()
-            .py_1()
-            .rounded(px(6.0))
-            .text_sm()
-            .text_color(text_color)
-            .when(enabled, |this| {
-                this.on_mouse_down(MouseButton::Left, move |_, window, cx| {
-                    cx.stop_propagation();
-                    on_click(window, cx);
-                })
-                .hover(move |style| style.bg(hover_bg).cursor_pointer())
-            })
-            .child(
-                h_flex()
-                    .w_full()
-                    .items_center()
-                    .justify_between()
-                    .gap_2()
-                    .child(div().min_w_0().truncate().child(label))
-                    .when_some(shortcut, |this, value| {
-                        this.child(
-                            div()
-                                .text_xs()
-                                .text_color(cx.theme().muted_foreground)
-                                .child(value),
-                        )
-                    }),
-            )
-            .into_any_element()
-    }
-
     fn sync_sidebar_repo_list_state(&mut self, row_count: usize) {
         if self.repo_tree.row_count == row_count
             && self.repo_tree.scroll_anchor_path.is_none()
@@ -549,6 +359,7 @@ impl DiffViewer {
     fn render_repo_tree_row(
         &self,
         row: &super::data::RepoTreeRow,
+        max_changed: u64,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         let view = cx.entity();
@@ -593,6 +404,29 @@ impl DiffViewer {
         let file_status = row.file_status;
         let rename_input = self.inline_repo_tree_rename_input_for_path(row.path.as_str());
         let rename_active = rename_input.is_some();
+        let sparkline = file_status
+            .filter(|_| max_changed > 0 && !rename_active)
+            .and_then(|_| self.active_diff_file_line_stats().get(row.path.as_str()))
+            .filter(|stats| stats.changed() > 0)
+            .map(|stats| self.render_repo_tree_sparkline(*stats, max_changed, cx));
+        let open_comment_count = if row.kind == RepoTreeNodeKind::File && !rename_active {
+            self.comment_counts.open_in_file(row.path.as_str())
+        } else {
+            0
+        };
+        let code_owners = file_status
+            .filter(|_| !rename_active)
+            .and_then(|_| self.code_owners_label_for_path(row.path.as_str()));
+        let reviewed = self.workspace_view_mode == WorkspaceViewMode::Diff
+            && !rename_active
+            && match row.kind {
+                RepoTreeNodeKind::Directory => {
+                    self.review_reviewed_directories.contains(row.path.as_str())
+                }
+                RepoTreeNodeKind::File => {
+                    self.review_reviewed_directories.covers_file(row.path.as_str())
+                }
+            };
         let row_hover_bg = if is_selected {
             cx.theme().secondary_active
         } else {
@@ -670,6 +504,38 @@ impl DiffViewer {
                             .child(row.name.clone())
                     }),
             )
+            .when(open_comment_count > 0, |this| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .px_1()
+                        .rounded(px(4.0))
+                        .text_xs()
+                        .font_semibold()
+                        .bg(hunk_opacity(cx.theme().accent, is_dark, 0.30, 0.16))
+                        .text_color(cx.theme().foreground)
+                        .child(open_comment_count.to_string()),
+                )
+            })
+            .when(reviewed, |this| {
+                this.child(
+                    Icon::new(IconName::Check)
+                        .size(px(12.0))
+                        .text_color(cx.theme().success),
+                )
+            })
+            .when_some(code_owners, |this, owners| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .max_w(px(96.0))
+                        .truncate()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(owners),
+                )
+            })
+            .when_some(sparkline, |this, sparkline| this.child(sparkline))
             .on_mouse_down(MouseButton::Right, {
                 let view = view.clone();
                 move |event, window, cx| {
@@ -685,6 +551,16 @@ impl DiffViewer {
                     });
                 }
             })
+            .when(row.kind == RepoTreeNodeKind::File, |this| {
+                let path = row.path.clone();
+                let view = view.clone();
+                this.on_mouse_down(MouseButton::Middle, move |_, _, cx| {
+                    cx.stop_propagation();
+                    view.update(cx, |this, cx| {
+                        this.on_file_middle_click(path.clone(), cx);
+                    });
+                })
+            })
             .when(!rename_active, |this| {
                 this.hover(move |style| style.bg(row_hover_bg).cursor_pointer())
                     .on_click({
@@ -705,8 +581,34 @@ impl DiffViewer {
             })
             .into_any_element()
     }
+
+    fn render_repo_tree_sparkline(
+        &self,
+        stats: LineStats,
+        max_changed: u64,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let colors = hunk_line_stats(cx.theme(), is_dark);
+        let (added_width, removed_width) =
+            line_stats_sparkline_widths(stats, max_changed, REPO_TREE_SPARKLINE_WIDTH);
+
+        h_flex()
+            .flex_none()
+            .w(px(REPO_TREE_SPARKLINE_WIDTH))
+            .h(px(4.0))
+            .mr_1()
+            .rounded(px(2.0))
+            .overflow_hidden()
+            .bg(hunk_opacity(cx.theme().muted, is_dark, 0.42, 0.56))
+            .child(div().h_full().w(px(added_width)).bg(colors.added))
+            .child(div().h_full().w(px(removed_width)).bg(colors.removed))
+            .into_any_element()
+    }
 }
 
+const REPO_TREE_SPARKLINE_WIDTH: f32 = 32.0;
+
 fn stable_row_id_for_path(path: &str) -> u64 {
     use std::hash::{Hash as _, Hasher as _};
 
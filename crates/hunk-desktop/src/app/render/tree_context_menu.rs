@@ -0,0 +1,351 @@
+impl DiffViewer {
+    fn render_repo_tree_context_menu(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let menu_state = self.repo_tree_context_menu.as_ref()?.clone();
+        let view = cx.entity();
+        let is_dark = cx.theme().mode.is_dark();
+        let allow_manage = self.workspace_view_mode == WorkspaceViewMode::Files && self.repo_root.is_some();
+        let allow_target_path = menu_state.target_path.is_some();
+        let allow_rename =
+            allow_manage && allow_target_path && menu_state.target_kind == RepoTreeNodeKind::File;
+        let allow_delete = allow_rename;
+        let allow_copy = allow_target_path;
+        let copy_line_available = menu_state
+            .target_path
+            .as_deref()
+            .is_some_and(|path| self.cursor_line_for_path(path).is_some());
+        let allow_open_on_disk = menu_state
+            .target_path
+            .as_deref()
+            .is_some_and(|path| self.repo_file_exists_on_disk(path));
+        let allow_collapse = !self.repo_tree.expanded_dirs.is_empty();
+        let stats_ignored = menu_state
+            .target_path
+            .as_deref()
+            .is_some_and(|path| self.stats_ignored_paths.contains(path));
+        let stats_ignored_by_folder = !stats_ignored
+            && menu_state
+                .target_path
+                .as_deref()
+                .is_some_and(|path| path_is_stats_ignored(path, &self.stats_ignored_paths));
+        let allow_mark_reviewed = self.workspace_view_mode == WorkspaceViewMode::Diff
+            && allow_target_path
+            && menu_state.target_kind == RepoTreeNodeKind::Directory;
+        let directory_reviewed = menu_state
+            .target_path
+            .as_deref()
+            .is_some_and(|path| self.review_reviewed_directories.contains(path));
+
+        Some(
+            deferred(
+                anchored()
+                    .position(menu_state.position)
+                    .anchor(Corner::TopLeft)
+                    .snap_to_window_with_margin(px(8.0))
+                    .child(
+                        v_flex()
+                            .id("repo-tree-context-menu")
+                            .w(px(234.0))
+                            .p_1()
+                            .gap_0p5()
+                            .rounded(px(8.0))
+                            .border_1()
+                            .border_color(hunk_opacity(cx.theme().border, is_dark, 0.92, 0.74))
+                            .bg(cx.theme().popover)
+                            .shadow_none()
+                            .on_mouse_down_out({
+                                let view = view.clone();
+                                move |_, _, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.close_repo_tree_context_menu(cx);
+                                    });
+                                }
+                            })
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "New File",
+                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::NewFile),
+                                    allow_manage,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        let target_kind = menu_state.target_kind;
+                                        move |window, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                this.open_repo_tree_new_file_prompt_at(
+                                                    target_path
+                                                        .as_ref()
+                                                        .map(|path| (path.clone(), target_kind)),
+                                                    window,
+                                                    cx,
+                                                );
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "New Folder",
+                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::NewFolder),
+                                    allow_manage,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        let target_kind = menu_state.target_kind;
+                                        move |window, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                this.open_repo_tree_new_folder_prompt_at(
+                                                    target_path
+                                                        .as_ref()
+                                                        .map(|path| (path.clone(), target_kind)),
+                                                    window,
+                                                    cx,
+                                                );
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "Rename File",
+                                    self.repo_tree_shortcut_label(RepoTreeMenuShortcut::RenameFile),
+                                    allow_rename,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |window, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.open_repo_tree_rename_prompt_for_file(
+                                                        path.clone(),
+                                                        window,
+                                                        cx,
+                                                    );
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "Delete File",
+                                    None,
+                                    allow_delete,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.delete_repo_tree_file_at(path.as_str(), cx);
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
+                            .children(FilePathCopyFormat::ALL.into_iter().map(|format| {
+                                let enabled = allow_copy
+                                    && (format != FilePathCopyFormat::PathWithLine
+                                        || copy_line_available);
+                                self.render_repo_tree_context_menu_item(
+                                    format.label(),
+                                    None,
+                                    enabled,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.copy_file_path(path.as_str(), format, cx);
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                )
+                            }))
+                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    project_open::reveal_in_file_manager_label(),
+                                    None,
+                                    allow_open_on_disk,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.reveal_repo_file_in_file_manager(path.as_str(), cx);
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "Open with Default Application",
+                                    None,
+                                    allow_open_on_disk,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.open_repo_file_with_default_application(
+                                                        path.as_str(),
+                                                        cx,
+                                                    );
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(div().h(px(1.0)).mx_1().bg(cx.theme().border))
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    if stats_ignored || stats_ignored_by_folder {
+                                        "Include in Stats"
+                                    } else {
+                                        "Exclude from Stats"
+                                    },
+                                    None,
+                                    allow_target_path && !stats_ignored_by_folder,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.toggle_stats_ignored_path(
+                                                        path.as_str(),
+                                                        cx,
+                                                    );
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    if directory_reviewed {
+                                        tr("tree-clear-folder-reviewed")
+                                    } else {
+                                        tr("tree-mark-folder-reviewed")
+                                    },
+                                    None,
+                                    allow_mark_reviewed,
+                                    {
+                                        let view = view.clone();
+                                        let target_path = menu_state.target_path.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                if let Some(path) = target_path.as_ref() {
+                                                    this.toggle_review_directory_reviewed(
+                                                        path.as_str(),
+                                                        cx,
+                                                    );
+                                                }
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            )
+                            .child(
+                                self.render_repo_tree_context_menu_item(
+                                    "Collapse All Folders",
+                                    None,
+                                    allow_collapse,
+                                    {
+                                        let view = view.clone();
+                                        move |_, cx| {
+                                            view.update(cx, |this, cx| {
+                                                this.close_repo_tree_context_menu(cx);
+                                                this.collapse_all_repo_tree_directories(cx);
+                                            });
+                                        }
+                                    },
+                                    cx,
+                                ),
+                            ),
+                    ),
+            )
+            .with_priority(1)
+            .into_any_element(),
+        )
+    }
+
+    fn render_repo_tree_context_menu_item(
+        &self,
+        label: impl Into<SharedString>,
+        shortcut: Option<String>,
+        enabled: bool,
+        on_click: impl Fn(&mut Window, &mut App) + 'static,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let text_color = if enabled {
+            cx.theme().popover_foreground
+        } else {
+            cx.theme().muted_foreground
+        };
+        let hover_bg = cx.theme().secondary_hover;
+        div()
+            .w_full()
+            .px_2()
+            .py_1()
+            .rounded(px(6.0))
+            .text_sm()
+            .text_color(text_color)
+            .when(enabled, |this| {
+                this.on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                    cx.stop_propagation();
+                    on_click(window, cx);
+                })
+                .hover(move |style| style.bg(hover_bg).cursor_pointer())
+            })
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .child(div().min_w_0().truncate().child(label.into()))
+                    .when_some(shortcut, |this, value| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(value),
+                        )
+                    }),
+            )
+            .into_any_element()
+    }
+}
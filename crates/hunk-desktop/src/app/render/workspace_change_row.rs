@@ -57,7 +57,17 @@ impl DiffViewer {
             .get(file.path.as_str())
             .copied()
             .unwrap_or_default();
+        let path_collision = self
+            .path_collision_for(file.path.as_str())
+            .map(|collision| {
+                (
+                    collision.summary(),
+                    self.acknowledged_path_collisions.contains(&collision.key()),
+                )
+            });
+        let code_owners = self.code_owners_label_for_path(file.path.as_str());
         let path = file.path.clone();
+        let file_status = file.status;
 
         h_flex()
             .id(("workspace-change-row", row_ix))
@@ -95,7 +105,7 @@ impl DiffViewer {
                     .border_color(stage_colors.border)
                     .text_color(stage_colors.text)
                     .tooltip(stage_tooltip)
-                    .disabled(self.git_controls_busy())
+                    .disabled(self.git_controls_busy() || self.read_only_active())
                     .on_click(move |_, _, cx| {
                         cx.stop_propagation();
                         view.update(cx, |this, cx| {
@@ -124,6 +134,33 @@ impl DiffViewer {
                     .text_color(cx.theme().foreground)
                     .child(status_label),
             )
+            .when_some(path_collision, |this, (summary, acknowledged)| {
+                let warning = cx.theme().warning;
+                this.child(
+                    Button::new(("workspace-change-case-warning", row_ix))
+                        .compact()
+                        .rounded(px(6.0))
+                        .h(px(22.0))
+                        .bg(hunk_opacity(warning, is_dark, 0.18, 0.10))
+                        .border_color(hunk_opacity(
+                            warning,
+                            is_dark,
+                            if acknowledged { 0.32 } else { 0.72 },
+                            if acknowledged { 0.22 } else { 0.48 },
+                        ))
+                        .text_color(warning)
+                        .icon(Icon::new(IconName::TriangleAlert).size(px(12.0)))
+                        .label("Aa")
+                        .tooltip(if acknowledged {
+                            format!("{summary} (acknowledged)")
+                        } else {
+                            summary
+                        })
+                        .on_click(|_, _, cx| {
+                            cx.stop_propagation();
+                        }),
+                )
+            })
             .child(
                 h_flex()
                     .flex_1()
@@ -140,10 +177,49 @@ impl DiffViewer {
                             .text_color(cx.theme().foreground)
                             .child(path.clone()),
                     )
+                    .when_some(code_owners, |this, owners| {
+                        this.child(
+                            div()
+                                .flex_none()
+                                .max_w(px(120.0))
+                                .truncate()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(owners),
+                        )
+                    })
                     .when(line_stats.changed() > 0, |this| {
                         this.child(self.render_workspace_change_stats(line_stats, cx))
                     }),
             )
+            .when(file_status != FileStatus::Deleted, |this| {
+                let view = view.clone();
+                let path = path.clone();
+                this.child(
+                    Button::new(("workspace-change-rename", row_ix))
+                        .ghost()
+                        .compact()
+                        .rounded(px(999.0))
+                        .with_size(gpui_component::Size::Small)
+                        .icon(Icon::new(IconName::Replace).size(px(12.0)))
+                        .tooltip("Rename this file, keeping its history and comments.")
+                        .disabled(self.git_controls_busy() || self.read_only_active())
+                        .text_color(cx.theme().muted_foreground)
+                        .min_w(px(22.0))
+                        .h(px(22.0))
+                        .on_click(move |_, window, cx| {
+                            cx.stop_propagation();
+                            view.update(cx, |this, cx| {
+                                this.rename_git_workspace_change(
+                                    path.clone(),
+                                    file_status,
+                                    window,
+                                    cx,
+                                );
+                            });
+                        }),
+                )
+            })
             .child({
                 let view = view.clone();
                 let path = path.clone();
@@ -155,7 +231,7 @@ impl DiffViewer {
                     .icon(Icon::new(IconName::Undo2).size(px(12.0)))
                     .tooltip(undo_tooltip)
                     .loading(undo_loading)
-                    .disabled(self.git_controls_busy())
+                    .disabled(self.git_controls_busy() || self.read_only_active())
                     .text_color(cx.theme().muted_foreground)
                     .min_w(px(22.0))
                     .h(px(22.0))
@@ -0,0 +1,165 @@
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Files larger than this are skipped; they are almost always generated or vendored.
+pub(super) const REPO_CONTENT_SEARCH_MAX_FILE_BYTES: u64 = 1024 * 1024;
+/// The search stops once it has found this many matching lines.
+pub(super) const REPO_CONTENT_SEARCH_MAX_MATCHES: usize = 2_000;
+/// Leading bytes checked for NUL to tell binary files apart, as Git does.
+const BINARY_SNIFF_BYTES: usize = 8_000;
+/// Longest line preview kept per match, in characters.
+const MATCH_PREVIEW_MAX_CHARS: usize = 240;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RepoContentMatch {
+    /// 1-based line number.
+    pub(super) line: usize,
+    /// Byte range of the first match within `preview`.
+    pub(super) preview_match: std::ops::Range<usize>,
+    pub(super) preview: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RepoContentFileMatches {
+    pub(super) path: String,
+    pub(super) matches: Vec<RepoContentMatch>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct RepoContentSearchResults {
+    pub(super) files: Vec<RepoContentFileMatches>,
+    pub(super) searched_files: usize,
+    pub(super) skipped_files: usize,
+    /// Stopped at [`REPO_CONTENT_SEARCH_MAX_MATCHES`], so more matches may exist.
+    pub(super) truncated: bool,
+}
+
+impl RepoContentSearchResults {
+    pub(super) fn match_count(&self) -> usize {
+        self.files.iter().map(|file| file.matches.len()).sum()
+    }
+}
+
+/// Searches `paths` under `repo_root` for lines containing `query`, in path order.
+///
+/// Matching is case-insensitive unless the query has an uppercase letter. Binary files, files
+/// over [`REPO_CONTENT_SEARCH_MAX_FILE_BYTES`], and unreadable files are skipped. Setting
+/// `cancelled` stops the search early with what was found so far.
+pub(super) fn search_repo_contents(
+    repo_root: &Path,
+    paths: &[String],
+    query: &str,
+    cancelled: &AtomicBool,
+) -> RepoContentSearchResults {
+    let mut results = RepoContentSearchResults::default();
+    if query.is_empty() {
+        return results;
+    }
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let needle = if case_sensitive {
+        query.to_string()
+    } else {
+        query.to_lowercase()
+    };
+
+    let mut remaining = REPO_CONTENT_SEARCH_MAX_MATCHES;
+    for path in paths {
+        if cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+        if remaining == 0 {
+            break;
+        }
+        let Some(text) = read_searchable_text(repo_root.join(path).as_path()) else {
+            results.skipped_files += 1;
+            continue;
+        };
+        results.searched_files += 1;
+
+        let mut matches = Vec::new();
+        for (line_ix, line) in text.lines().enumerate() {
+            let haystack = if case_sensitive {
+                None
+            } else {
+                Some(line.to_lowercase())
+            };
+            let Some(byte_ix) = haystack.as_deref().unwrap_or(line).find(needle.as_str()) else {
+                continue;
+            };
+            matches.push(match_preview(
+                line_ix + 1,
+                haystack.as_deref().unwrap_or(line),
+                line,
+                byte_ix,
+                needle.len(),
+            ));
+            remaining -= 1;
+            if remaining == 0 {
+                results.truncated = true;
+                break;
+            }
+        }
+        if !matches.is_empty() {
+            results.files.push(RepoContentFileMatches {
+                path: path.clone(),
+                matches,
+            });
+        }
+    }
+    results
+}
+
+/// The file's text, or `None` when it is missing, too large, binary, or not UTF-8.
+fn read_searchable_text(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() || metadata.len() > REPO_CONTENT_SEARCH_MAX_FILE_BYTES {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(metadata.len() as usize);
+    fs::File::open(path).ok()?.read_to_end(&mut bytes).ok()?;
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Builds the match's line preview, trimmed of leading whitespace and shortened around the
+/// match when the line is long. `haystack` is the searched form of `line`.
+fn match_preview(
+    line_number: usize,
+    haystack: &str,
+    line: &str,
+    byte_ix: usize,
+    byte_len: usize,
+) -> RepoContentMatch {
+    // Lowercasing can change byte offsets, so the match is carried over by character position.
+    let start_char = haystack[..byte_ix].chars().count();
+    let match_chars = haystack[byte_ix..byte_ix + byte_len].chars().count();
+    let chars = line.chars().collect::<Vec<_>>();
+    let indent = chars
+        .iter()
+        .take(start_char)
+        .take_while(|ch| ch.is_whitespace())
+        .count();
+    let context_start = start_char
+        .saturating_sub(MATCH_PREVIEW_MAX_CHARS / 3)
+        .max(indent)
+        .min(chars.len());
+    let context_end = (context_start + MATCH_PREVIEW_MAX_CHARS).min(chars.len());
+    let preview_chars = &chars[context_start..context_end];
+    let byte_offset = |char_ix: usize| {
+        preview_chars[..char_ix.min(preview_chars.len())]
+            .iter()
+            .map(|ch| ch.len_utf8())
+            .sum::<usize>()
+    };
+    let start = byte_offset(start_char.saturating_sub(context_start));
+    let end = byte_offset((start_char + match_chars).saturating_sub(context_start));
+    RepoContentMatch {
+        line: line_number,
+        preview_match: start..end,
+        preview: preview_chars.iter().collect(),
+    }
+}
@@ -2,16 +2,20 @@ use std::path::PathBuf;
 
 use gpui::{AnyElement, App, IntoElement as _, ParentElement as _, SharedString, Styled as _, div};
 use gpui_component::{ActiveTheme as _, v_flex};
-use hunk_git::compare::{compare_branch_source_id, compare_workspace_target_source_id};
+use hunk_git::compare::{
+    compare_branch_source_id, compare_revision_source_id, compare_workspace_target_source_id,
+};
 use hunk_git::git::LocalBranch;
 use hunk_git::worktree::{WorkspaceTargetKind, WorkspaceTargetSummary};
 
 use super::hunk_picker::{HunkPickerDelegate, HunkPickerItem};
+use super::l10n::tr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ReviewCompareSourceKind {
     WorkspaceTarget,
     Branch,
+    Revision,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,6 +27,7 @@ pub(crate) struct ReviewCompareSourceOption {
     pub workspace_target_id: Option<String>,
     pub workspace_root: Option<PathBuf>,
     pub branch_name: Option<String>,
+    pub revision: Option<String>,
 }
 
 impl ReviewCompareSourceOption {
@@ -57,6 +62,7 @@ impl ReviewCompareSourceOption {
             workspace_target_id: Some(target.id.clone()),
             workspace_root: Some(target.root.clone()),
             branch_name: Some(target.branch_name.clone()),
+            revision: None,
         }
     }
 
@@ -73,6 +79,21 @@ impl ReviewCompareSourceOption {
             workspace_target_id: None,
             workspace_root: None,
             branch_name: Some(branch.name.clone()),
+            revision: None,
+        }
+    }
+
+    /// A revision typed into the picker, such as a commit id or `main~3`.
+    pub(crate) fn from_revision(spec: &str) -> Self {
+        Self {
+            id: compare_revision_source_id(spec),
+            kind: ReviewCompareSourceKind::Revision,
+            display_name: spec.to_string(),
+            detail: tr("review-compare-revision"),
+            workspace_target_id: None,
+            workspace_root: None,
+            branch_name: None,
+            revision: Some(spec.to_string()),
         }
     }
 }
@@ -159,7 +180,8 @@ impl HunkPickerDelegate for ReviewComparePickerDelegate {
     }
 
     fn perform_search(&mut self, query: &str) {
-        let query = query.trim().to_lowercase();
+        let spec = query.trim();
+        let query = spec.to_lowercase();
         if query.is_empty() {
             self.matched_items = self.items.clone();
         } else {
@@ -169,6 +191,16 @@ impl HunkPickerDelegate for ReviewComparePickerDelegate {
                 .filter(|item| item.normalized_title.contains(query.as_str()))
                 .cloned()
                 .collect();
+            if !self
+                .matched_items
+                .iter()
+                .any(|item| item.normalized_title == query)
+            {
+                let revision = ReviewCompareSourceOption::from_revision(spec);
+                let mut item = ReviewComparePickerItem::from_option(&revision);
+                item.detail = SharedString::from(tr("review-compare-review-revision"));
+                self.matched_items.push(item);
+            }
         }
     }
 }
@@ -0,0 +1,84 @@
+use std::time::{Duration, Instant};
+
+/// A pause at least this long counts as a break, so the next reminder waits a full interval.
+pub(super) const REVIEW_BREAK_MIN_PAUSE: Duration = Duration::from_secs(5 * 60);
+
+/// Active time spent in the current review session.
+///
+/// The timer only advances while it is running; the app pauses it whenever the window loses
+/// focus or Review view is left. All methods take `now` so the clock stays with the caller.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ReviewTimer {
+    running_since: Option<Instant>,
+    paused_since: Option<Instant>,
+    banked: Duration,
+    /// Active time at the last break, or at the last reminder to take one.
+    last_break_at: Duration,
+}
+
+impl ReviewTimer {
+    pub(super) fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub(super) fn elapsed(&self, now: Instant) -> Duration {
+        self.banked
+            + self
+                .running_since
+                .map_or(Duration::ZERO, |since| now.saturating_duration_since(since))
+    }
+
+    pub(super) fn set_running(&mut self, running: bool, now: Instant) {
+        if running == self.is_running() {
+            return;
+        }
+        if running {
+            if self
+                .paused_since
+                .is_some_and(|since| now.saturating_duration_since(since) >= REVIEW_BREAK_MIN_PAUSE)
+            {
+                self.last_break_at = self.banked;
+            }
+            self.paused_since = None;
+            self.running_since = Some(now);
+        } else {
+            self.banked = self.elapsed(now);
+            self.running_since = None;
+            self.paused_since = Some(now);
+        }
+    }
+
+    /// Starts a new session at zero, keeping the timer running if it was.
+    pub(super) fn reset(&mut self, now: Instant) {
+        let running = self.is_running();
+        *self = Self::default();
+        if running {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Returns true once for every `interval` of active time without a break. A zero interval
+    /// never reminds.
+    pub(super) fn take_break_reminder(&mut self, interval: Duration, now: Instant) -> bool {
+        if interval.is_zero() {
+            return false;
+        }
+        let elapsed = self.elapsed(now);
+        if elapsed.saturating_sub(self.last_break_at) < interval {
+            return false;
+        }
+        self.last_break_at = elapsed;
+        true
+    }
+}
+
+/// Whole minutes, since the status bar only repaints for the timer a few times a minute:
+/// `12m` under an hour, `1h 05m` after.
+pub(super) fn review_timer_label(elapsed: Duration) -> String {
+    let minutes = elapsed.as_secs() / 60;
+    if minutes < 60 {
+        format!("{minutes}m")
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
@@ -4,13 +4,16 @@ use std::path::PathBuf;
 
 use hunk_domain::db::{CommentLineSide, compute_comment_anchor_hash};
 use hunk_domain::diff::SideBySideRow;
-use hunk_domain::diff::{DiffCellKind, DiffHunk, DiffLineKind, DiffRowKind, parse_patch_document};
+use hunk_domain::diff::{
+    DiffCellKind, DiffRowKind, HunkFolds, hunk_collapse_key, parse_patch_document,
+};
 use hunk_editor::{
     WorkspaceDisplayRow, WorkspaceDocument, WorkspaceDocumentId, WorkspaceExcerptId,
     WorkspaceExcerptKind, WorkspaceExcerptSpec, WorkspaceLayout, WorkspaceLayoutError,
 };
 use hunk_git::compare::CompareSnapshot;
 use hunk_git::git::{FileStatus, LineStats};
+use hunk_git::hunk_apply::HunkStageState;
 use hunk_text::{BufferId, TextBuffer};
 
 #[allow(clippy::duplicate_mod)]
@@ -20,9 +23,17 @@ mod geometry_impl;
 #[path = "review_workspace_session_search.rs"]
 mod search_impl;
 pub(crate) use geometry_impl::ReviewWorkspaceDisplayGeometry;
+#[allow(clippy::duplicate_mod)]
+#[path = "review_workspace_session_scroll_anchor.rs"]
+mod scroll_anchor_impl;
+#[allow(unused_imports)]
+pub(crate) use scroll_anchor_impl::ReviewWorkspaceScrollAnchor;
 #[allow(unused_imports)]
 pub(crate) use search_impl::ReviewWorkspaceSearchTarget;
 #[allow(clippy::duplicate_mod)]
+#[path = "review_workspace_session_row_modes.rs"]
+mod row_modes_impl;
+#[allow(clippy::duplicate_mod)]
 #[path = "workspace_display_buffers.rs"]
 mod workspace_display_buffers;
 
@@ -124,8 +135,17 @@ pub(crate) struct ReviewWorkspaceViewportRow {
     pub(crate) file_line_stats: Option<LineStats>,
     pub(crate) file_is_collapsed: bool,
     pub(crate) can_view_file: bool,
+    /// Encoding label for files that are text but not plain UTF-8.
+    pub(crate) file_encoding: Option<String>,
+    /// Whether the file diff compares text extracted from a PDF or DOCX document.
+    pub(crate) file_is_extracted_text: bool,
+    /// CODEOWNERS owner label for the file, when the repository has one.
+    pub(crate) file_code_owners: Option<String>,
     pub(crate) show_comment_affordance: bool,
     pub(crate) open_comment_count: usize,
+    pub(crate) is_pinned: bool,
+    /// Whether the hunk under this header is in the index, when the review can stage hunks.
+    pub(crate) hunk_stage_state: Option<HunkStageState>,
     pub(crate) text: String,
     pub(crate) left_cell_kind: DiffCellKind,
     pub(crate) left_line: Option<u32>,
@@ -189,9 +209,14 @@ pub(crate) struct ReviewWorkspaceSurfaceOptions {
     pub(crate) comment_affordance_rows: BTreeSet<usize>,
     pub(crate) comment_open_counts_by_row: BTreeMap<usize, usize>,
     pub(crate) active_comment_editor_row: Option<usize>,
+    pub(crate) pinned_rows: BTreeSet<usize>,
     pub(crate) collapsed_paths: BTreeSet<String>,
     pub(crate) view_file_enabled_paths: BTreeSet<String>,
+    pub(crate) file_encodings: BTreeMap<String, String>,
+    pub(crate) extracted_text_paths: BTreeSet<String>,
+    pub(crate) file_code_owners: BTreeMap<String, String>,
     pub(crate) search_highlight_columns_by_row: BTreeMap<usize, Vec<Range<usize>>>,
+    pub(crate) hunk_stage_states_by_row: BTreeMap<usize, HunkStageState>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -316,6 +341,10 @@ pub(crate) struct ReviewWorkspaceSession {
     row_segments: Vec<Option<DiffRowSegmentCache>>,
     cached_display_rows: ReviewWorkspaceDisplayRows,
     display_geometry: ReviewWorkspaceDisplayGeometry,
+    /// Lays the rows out for the unified (single-column) view.
+    unified_rows: bool,
+    /// Gives hunk headers a full row so they can hold a staging checkbox.
+    hunk_stage_controls: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -328,6 +357,8 @@ impl ReviewWorkspaceSession {
     pub(crate) fn from_compare_snapshot(
         snapshot: &CompareSnapshot,
         collapsed_files: &BTreeSet<String>,
+        changed_lines_only_files: &BTreeSet<String>,
+        hunk_folds: &HunkFolds,
     ) -> Result<Self, WorkspaceLayoutError> {
         let mut next_document_id = 1_u64;
         let mut next_excerpt_id = 1_u64;
@@ -362,8 +393,12 @@ impl ReviewWorkspaceSession {
                     1
                 } else {
                     let mut next_document_line = 0_usize;
+                    let changed_lines_only = changed_lines_only_files.contains(file.path.as_str());
                     for (hunk_ix, hunk) in document.hunks.iter().enumerate() {
-                        let code_row_count = surface_code_row_count_for_hunk(hunk);
+                        let hunk_collapsed =
+                            hunk_folds.is_collapsed(file.path.as_str(), hunk_collapse_key(hunk));
+                        let code_row_count =
+                            hunk_folds.code_row_count(file.path.as_str(), hunk, changed_lines_only);
                         let line_range =
                             next_document_line..next_document_line.saturating_add(code_row_count);
                         let excerpt_id = WorkspaceExcerptId::new(next_excerpt_id);
@@ -377,7 +412,11 @@ impl ReviewWorkspaceSession {
                             )
                             .with_chrome_rows(
                                 usize::from(hunk_ix == 0).saturating_add(HUNK_HEADER_SURFACE_ROWS),
-                                hunk.trailing_meta.len(),
+                                if hunk_collapsed {
+                                    0
+                                } else {
+                                    hunk.trailing_meta.len()
+                                },
                             ),
                         );
                         excerpt_headers.insert(excerpt_id, Some(hunk.header.clone()));
@@ -463,6 +502,8 @@ impl ReviewWorkspaceSession {
             row_segments: Vec::new(),
             cached_display_rows: ReviewWorkspaceDisplayRows::default(),
             display_geometry: ReviewWorkspaceDisplayGeometry::default(),
+            unified_rows: false,
+            hunk_stage_controls: false,
         })
     }
 
@@ -485,6 +526,15 @@ impl ReviewWorkspaceSession {
         self
     }
 
+    /// The stream this session renders, including segment caches filled since it was built.
+    pub(crate) fn render_stream(&self) -> DiffStream {
+        DiffStream {
+            rows: self.rows.clone(),
+            row_metadata: self.row_metadata.clone(),
+            row_segments: self.row_segments.clone(),
+        }
+    }
+
     pub(crate) fn file_ranges(&self) -> &[ReviewWorkspaceFileRange] {
         &self.file_ranges
     }
@@ -749,6 +799,15 @@ impl ReviewWorkspaceSession {
                     let can_view_file = file_path
                         .as_deref()
                         .is_some_and(|path| options.view_file_enabled_paths.contains(path));
+                    let file_encoding = file_path
+                        .as_deref()
+                        .and_then(|path| options.file_encodings.get(path).cloned());
+                    let file_is_extracted_text = file_path
+                        .as_deref()
+                        .is_some_and(|path| options.extracted_text_paths.contains(path));
+                    let file_code_owners = file_path
+                        .as_deref()
+                        .and_then(|path| options.file_code_owners.get(path).cloned());
                     let row_segment_cache = self.row_segment_cache(row_index);
                     let row_height_px = self.surface_row_height_px(row_index);
                     let display_row_offset = display_row_offsets_by_raw_row
@@ -796,6 +855,9 @@ impl ReviewWorkspaceSession {
                         file_status,
                         file_is_collapsed,
                         can_view_file,
+                        file_encoding,
+                        file_is_extracted_text,
+                        file_code_owners,
                         show_comment_affordance: options
                             .comment_affordance_rows
                             .contains(&row_index),
@@ -804,6 +866,8 @@ impl ReviewWorkspaceSession {
                             .get(&row_index)
                             .copied()
                             .unwrap_or_default(),
+                        is_pinned: options.pinned_rows.contains(&row_index),
+                        hunk_stage_state: options.hunk_stage_states_by_row.get(&row_index).copied(),
                         text: row.text.clone(),
                         left_cell_kind: row.left.kind,
                         left_line: row.left.line,
@@ -1319,6 +1383,8 @@ impl ReviewWorkspaceSession {
             &self.rows,
             &self.sections,
             Some(&self.cached_display_rows),
+            self.unified_rows,
+            self.hunk_stage_controls,
         );
         self.display_geometry = geometry;
     }
@@ -1402,7 +1468,9 @@ impl ReviewWorkspaceSession {
                 DiffStreamRowKind::FileError => {
                     return ReviewFileAnchorReconcileState::Unavailable;
                 }
-                DiffStreamRowKind::FileHeader | DiffStreamRowKind::EmptyState => {}
+                DiffStreamRowKind::FileHeader
+                | DiffStreamRowKind::FileWarning
+                | DiffStreamRowKind::EmptyState => {}
             }
         }
 
@@ -1582,8 +1650,13 @@ impl ReviewWorkspaceSession {
     }
 
     fn rebuild_display_geometry(&mut self, display_rows: Option<&ReviewWorkspaceDisplayRows>) {
-        self.display_geometry =
-            ReviewWorkspaceDisplayGeometry::build(&self.rows, &self.sections, display_rows);
+        self.display_geometry = ReviewWorkspaceDisplayGeometry::build(
+            &self.rows,
+            &self.sections,
+            display_rows,
+            self.unified_rows,
+            self.hunk_stage_controls,
+        );
     }
 
     fn rebuild_document_buffers(&mut self) {
@@ -1766,12 +1839,15 @@ impl ReviewWorkspaceSession {
     }
 
     fn surface_row_height_px(&self, row_ix: usize) -> usize {
-        match self.rows.get(row_ix).map(|row| row.kind) {
-            Some(DiffRowKind::HunkHeader) => REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX,
-            Some(DiffRowKind::Code | DiffRowKind::Meta | DiffRowKind::Empty) | None => {
-                REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX
-            }
-        }
+        self.rows
+            .get(row_ix)
+            .map_or(REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX, |row| {
+                geometry_impl::surface_row_height_px(
+                    row,
+                    self.unified_rows,
+                    self.hunk_stage_controls,
+                )
+            })
     }
 
     fn row_index_for_pixel(&self, pixel_offset: usize) -> usize {
@@ -1903,33 +1979,3 @@ fn prioritized_prefetch_row_indices_for_rows(
 fn review_decimal_digits(value: u32) -> u32 {
     if value == 0 { 1 } else { value.ilog10() + 1 }
 }
-
-fn surface_code_row_count_for_hunk(hunk: &DiffHunk) -> usize {
-    let mut ix = 0_usize;
-    let mut rows = 0_usize;
-
-    while ix < hunk.lines.len() {
-        match hunk.lines[ix].kind {
-            DiffLineKind::Context | DiffLineKind::Added => {
-                rows = rows.saturating_add(1);
-                ix += 1;
-            }
-            DiffLineKind::Removed => {
-                let removed_start = ix;
-                while ix < hunk.lines.len() && hunk.lines[ix].kind == DiffLineKind::Removed {
-                    ix += 1;
-                }
-                let added_start = ix;
-                while ix < hunk.lines.len() && hunk.lines[ix].kind == DiffLineKind::Added {
-                    ix += 1;
-                }
-                rows = rows.saturating_add(
-                    ix.saturating_sub(added_start)
-                        .max(added_start.saturating_sub(removed_start)),
-                );
-            }
-        }
-    }
-
-    rows
-}
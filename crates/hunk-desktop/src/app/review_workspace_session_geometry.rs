@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use hunk_domain::diff::{DiffRowKind, SideBySideRow};
+use hunk_domain::diff::{DiffCellKind, DiffRowKind, SideBySideRow};
 
 use super::{
     REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX, REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX,
@@ -22,6 +22,8 @@ impl ReviewWorkspaceDisplayGeometry {
         rows: &[SideBySideRow],
         sections: &[ReviewWorkspaceSection],
         display_rows: Option<&ReviewWorkspaceDisplayRows>,
+        unified_rows: bool,
+        hunk_stage_controls: bool,
     ) -> Self {
         let mut display_row_counts = vec![1usize; rows.len()];
         if let Some(display_rows) = display_rows {
@@ -65,9 +67,9 @@ impl ReviewWorkspaceDisplayGeometry {
         for (row_ix, row) in rows.iter().enumerate() {
             let display_row_count = display_row_counts[row_ix];
             next_display_row = next_display_row.saturating_add(display_row_count);
-            next_pixel_offset = next_pixel_offset.saturating_add(
-                display_row_count.saturating_mul(surface_row_height_for_kind(row.kind)),
-            );
+            next_pixel_offset = next_pixel_offset.saturating_add(display_row_count.saturating_mul(
+                surface_row_height_px(row, unified_rows, hunk_stage_controls),
+            ));
             row_display_boundaries.push(next_display_row);
             row_top_offsets_px.push(next_pixel_offset);
         }
@@ -154,11 +156,27 @@ impl ReviewWorkspaceDisplayGeometry {
     }
 }
 
-fn surface_row_height_for_kind(row_kind: DiffRowKind) -> usize {
-    match row_kind {
+/// Unified rows stack a changed line pair, removed above added, so those rows are twice as tall.
+/// Hunk headers are thin dividers unless they carry staging controls.
+pub(super) fn surface_row_height_px(
+    row: &SideBySideRow,
+    unified_rows: bool,
+    hunk_stage_controls: bool,
+) -> usize {
+    match row.kind {
+        DiffRowKind::HunkHeader if hunk_stage_controls => REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX,
         DiffRowKind::HunkHeader => REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX,
+        DiffRowKind::Code if unified_rows && is_unified_pair_row(row) => {
+            REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX.saturating_mul(2)
+        }
         DiffRowKind::Code | DiffRowKind::Meta | DiffRowKind::Empty => {
             REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX
         }
     }
 }
+
+/// Whether a code row holds a removed and an added line, which unified rows show one above the
+/// other.
+fn is_unified_pair_row(row: &SideBySideRow) -> bool {
+    row.left.kind == DiffCellKind::Removed && row.right.kind == DiffCellKind::Added
+}
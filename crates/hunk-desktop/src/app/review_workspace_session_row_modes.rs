@@ -0,0 +1,32 @@
+use super::*;
+
+impl ReviewWorkspaceSession {
+    pub(crate) fn with_unified_rows(mut self, unified_rows: bool) -> Self {
+        self.set_unified_rows(unified_rows);
+        self
+    }
+
+    pub(crate) fn unified_rows(&self) -> bool {
+        self.unified_rows
+    }
+
+    /// Switches between side-by-side and unified rows. Only row heights change, so row indices,
+    /// scroll anchors, and comment positions stay valid.
+    pub(crate) fn set_unified_rows(&mut self, unified_rows: bool) {
+        if self.unified_rows == unified_rows {
+            return;
+        }
+        self.unified_rows = unified_rows;
+        self.refresh_display_geometry_from_cached_display_rows();
+    }
+
+    /// Turns the hunk header dividers into full rows for working-copy reviews, where each one
+    /// carries a staging checkbox. Like unified rows, only row heights change.
+    pub(crate) fn set_hunk_stage_controls(&mut self, hunk_stage_controls: bool) {
+        if self.hunk_stage_controls == hunk_stage_controls {
+            return;
+        }
+        self.hunk_stage_controls = hunk_stage_controls;
+        self.refresh_display_geometry_from_cached_display_rows();
+    }
+}
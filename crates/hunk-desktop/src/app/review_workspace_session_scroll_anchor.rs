@@ -0,0 +1,105 @@
+use super::*;
+
+/// The row at the top of the viewport, described by content so it can be found again after a
+/// refresh rebuilds the stream and shifts row indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReviewWorkspaceScrollAnchor {
+    pub(crate) stable_id: u64,
+    pub(crate) file_path: Option<String>,
+    pub(crate) hunk_header: Option<String>,
+    pub(crate) left_line: Option<u32>,
+    pub(crate) right_line: Option<u32>,
+    pub(crate) offset_in_row_px: usize,
+}
+
+impl ReviewWorkspaceSession {
+    pub(crate) fn scroll_anchor_at(
+        &self,
+        scroll_top_px: usize,
+    ) -> Option<ReviewWorkspaceScrollAnchor> {
+        if self.row_count() == 0 {
+            return None;
+        }
+
+        let row_ix = self
+            .row_index_for_pixel(scroll_top_px)
+            .min(self.row_count().saturating_sub(1));
+        let row_top = self.row_top_offset_px(row_ix)?;
+        let metadata = self.row_metadata(row_ix)?;
+        let row = self.row(row_ix)?;
+        Some(ReviewWorkspaceScrollAnchor {
+            stable_id: metadata.stable_id,
+            file_path: metadata.file_path.clone(),
+            hunk_header: self
+                .hunk_header_at_surface_row(row_ix)
+                .map(ToString::to_string),
+            left_line: row.left.line,
+            right_line: row.right.line,
+            offset_in_row_px: scroll_top_px.saturating_sub(row_top),
+        })
+    }
+
+    /// Scroll offset that puts the anchored row back at the top of the viewport, or `None` when
+    /// its file is no longer part of the comparison.
+    pub(crate) fn scroll_top_for_anchor(
+        &self,
+        anchor: &ReviewWorkspaceScrollAnchor,
+    ) -> Option<usize> {
+        let row_ix = self.row_for_scroll_anchor(anchor)?;
+        let row_top = self.row_top_offset_px(row_ix)?;
+        let row_height = self
+            .row_boundary_offset_px(row_ix.saturating_add(1))
+            .map_or(0, |row_bottom| row_bottom.saturating_sub(row_top));
+        Some(row_top.saturating_add(anchor.offset_in_row_px.min(row_height.saturating_sub(1))))
+    }
+
+    fn row_for_scroll_anchor(&self, anchor: &ReviewWorkspaceScrollAnchor) -> Option<usize> {
+        let has_stable_id = |row_ix: &usize| {
+            self.row_metadata(*row_ix)
+                .is_some_and(|metadata| metadata.stable_id == anchor.stable_id)
+        };
+        let Some(path) = anchor.file_path.as_deref() else {
+            return (0..self.row_count()).find(has_stable_id);
+        };
+        let file_range = self.file_range_for_path(path)?;
+        let file_rows = file_range.start_row..file_range.end_row;
+
+        // Base-side line numbers survive edits to the working copy, so try them first.
+        let line_match = [
+            (anchor.left_line, ReviewWorkspaceEditorSide::Left),
+            (anchor.right_line, ReviewWorkspaceEditorSide::Right),
+        ]
+        .into_iter()
+        .filter_map(|(line, side)| Some((line?, side)))
+        .find_map(|(line, side)| {
+            file_rows
+                .clone()
+                .find(|row_ix| self.row_line(*row_ix, side) == Some(line))
+                .or_else(|| {
+                    file_rows.clone().find(|row_ix| {
+                        self.row_line(*row_ix, side)
+                            .is_some_and(|row_line| row_line > line)
+                    })
+                })
+        });
+        let row_ix = line_match
+            .or_else(|| {
+                let header = anchor.hunk_header.as_deref()?;
+                self.hunk_ranges
+                    .iter()
+                    .find(|range| range.path == path && range.header == header)
+                    .map(|range| range.start_row)
+            })
+            .or_else(|| file_rows.clone().find(has_stable_id))
+            .unwrap_or(file_range.start_row);
+        Some(row_ix)
+    }
+
+    fn row_line(&self, row_ix: usize, side: ReviewWorkspaceEditorSide) -> Option<u32> {
+        let row = self.row(row_ix)?;
+        match side {
+            ReviewWorkspaceEditorSide::Left => row.left.line,
+            ReviewWorkspaceEditorSide::Right => row.right.line,
+        }
+    }
+}
@@ -2,18 +2,40 @@
 enum SettingsCategory {
     Ui,
     Terminal,
+    Git,
+    Notifications,
+    UsageMetrics,
+    Maintenance,
+    Gestures,
+    Formatting,
     KeyboardShortcuts,
 }
 
 impl SettingsCategory {
-    const ALL: [Self; 3] = [Self::Ui, Self::Terminal, Self::KeyboardShortcuts];
+    const ALL: [Self; 9] = [
+        Self::Ui,
+        Self::Terminal,
+        Self::Git,
+        Self::Notifications,
+        Self::UsageMetrics,
+        Self::Maintenance,
+        Self::Gestures,
+        Self::Formatting,
+        Self::KeyboardShortcuts,
+    ];
 
-    fn title(self) -> &'static str {
-        match self {
-            Self::Ui => "UI",
-            Self::Terminal => "Terminal",
-            Self::KeyboardShortcuts => "Keyboard Shortcuts",
-        }
+    fn title(self) -> String {
+        tr(match self {
+            Self::Ui => "settings-category-ui",
+            Self::Terminal => "settings-category-terminal",
+            Self::Git => "settings-category-git",
+            Self::Notifications => "settings-category-notifications",
+            Self::UsageMetrics => "settings-category-usage-metrics",
+            Self::Maintenance => "settings-category-maintenance",
+            Self::Gestures => "settings-category-gestures",
+            Self::Formatting => "settings-category-formatting",
+            Self::KeyboardShortcuts => "settings-category-keyboard-shortcuts",
+        })
     }
 }
 
@@ -68,10 +90,71 @@ struct SettingsTerminalState {
 }
 
 #[derive(Clone)]
+struct SettingsGitState {
+    name: Entity<InputState>,
+    email: Entity<InputState>,
+    signing_key: Entity<InputState>,
+    push_timeout: Entity<InputState>,
+    sync_timeout: Entity<InputState>,
+    snapshot_exclusions: Entity<InputState>,
+    /// Repository whose `.git/info/exclude` the exclusions belong to; `None` when no repository
+    /// is open or its exclusions could not be read.
+    snapshot_exclusions_repo_root: Option<PathBuf>,
+    original_snapshot_exclusions: Vec<String>,
+    auto_setup_remote: bool,
+    sign_commits: bool,
+    signing_format: GitSigningFormat,
+    original: GitUserSettings,
+    load_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingsMaintenanceTask {
+    PruneComments,
+    GitGc,
+    CompactDatabase,
+}
+
+impl SettingsMaintenanceTask {
+    fn title(self) -> &'static str {
+        match self {
+            Self::PruneComments => "Prune Comments",
+            Self::GitGc => "Git Garbage Collection",
+            Self::CompactDatabase => "Compact Database",
+        }
+    }
+}
+
+#[derive(Clone)]
+struct SettingsFontsState {
+    ui_font_family: Entity<InputState>,
+    ui_font_size: Entity<InputState>,
+    mono_font_family: Entity<InputState>,
+    mono_font_size: Entity<InputState>,
+    line_height: Entity<InputState>,
+    mono_ligatures: bool,
+}
+
+/// Formatter commands as edited in settings, one input per configured formatter.
+#[derive(Clone)]
+struct SettingsFormattingState {
+    format_on_save: bool,
+    formatters: Vec<FormatterCommand>,
+    commands: Vec<Entity<InputState>>,
+}
+
+#[derive(Clone)]
+struct SettingsMaintenanceState {
+    comment_retention_days: Entity<InputState>,
+    stale_comment_retention_days: Entity<InputState>,
+    running: Option<SettingsMaintenanceTask>,
+}
+
+#[derive(Clone)]
+/// A shortcut setting. Its label and hint are the `settings-shortcut-<id>` and
+/// `settings-shortcut-<id>-hint` catalog messages.
 struct SettingsShortcutRow {
     id: &'static str,
-    label: &'static str,
-    hint: &'static str,
     input_state: Entity<InputState>,
 }
 
@@ -88,12 +171,18 @@ struct SettingsShortcutInputs {
     next_file: Entity<InputState>,
     previous_file: Entity<InputState>,
     view_current_review_file: Entity<InputState>,
+    toggle_review_pin: Entity<InputState>,
+    next_review_pin: Entity<InputState>,
+    previous_review_pin: Entity<InputState>,
     toggle_sidebar_tree: Entity<InputState>,
+    toggle_focus_mode: Entity<InputState>,
     switch_to_files_view: Entity<InputState>,
     switch_to_review_view: Entity<InputState>,
     switch_to_git_view: Entity<InputState>,
     toggle_ai_terminal_drawer: Entity<InputState>,
     open_project: Entity<InputState>,
+    open_path_from_clipboard: Entity<InputState>,
+    search_repository: Entity<InputState>,
     save_current_file: Entity<InputState>,
     next_editor_tab: Entity<InputState>,
     previous_editor_tab: Entity<InputState>,
@@ -110,158 +199,130 @@ impl SettingsShortcutInputs {
         vec![
             SettingsShortcutRow {
                 id: "select-next-line",
-                label: "Select Next Line",
-                hint: "Moves selection down one diff row.",
                 input_state: self.select_next_line.clone(),
             },
             SettingsShortcutRow {
                 id: "select-previous-line",
-                label: "Select Previous Line",
-                hint: "Moves selection up one diff row.",
                 input_state: self.select_previous_line.clone(),
             },
             SettingsShortcutRow {
                 id: "extend-selection-next-line",
-                label: "Extend Selection Down",
-                hint: "Extends the multi-row selection downward.",
                 input_state: self.extend_selection_next_line.clone(),
             },
             SettingsShortcutRow {
                 id: "extend-selection-previous-line",
-                label: "Extend Selection Up",
-                hint: "Extends the multi-row selection upward.",
                 input_state: self.extend_selection_previous_line.clone(),
             },
             SettingsShortcutRow {
                 id: "copy-selection",
-                label: "Copy Selection",
-                hint: "Copies the selected diff rows.",
                 input_state: self.copy_selection.clone(),
             },
             SettingsShortcutRow {
                 id: "select-all-diff-rows",
-                label: "Select All Diff Rows",
-                hint: "Selects all rows in the current diff.",
                 input_state: self.select_all_diff_rows.clone(),
             },
             SettingsShortcutRow {
                 id: "next-hunk",
-                label: "Next Hunk",
-                hint: "Jumps to the next diff hunk.",
                 input_state: self.next_hunk.clone(),
             },
             SettingsShortcutRow {
                 id: "previous-hunk",
-                label: "Previous Hunk",
-                hint: "Jumps to the previous diff hunk.",
                 input_state: self.previous_hunk.clone(),
             },
             SettingsShortcutRow {
                 id: "next-file",
-                label: "Next File",
-                hint: "Moves to the next changed file.",
                 input_state: self.next_file.clone(),
             },
             SettingsShortcutRow {
                 id: "previous-file",
-                label: "Previous File",
-                hint: "Moves to the previous changed file.",
                 input_state: self.previous_file.clone(),
             },
             SettingsShortcutRow {
                 id: "view-current-review-file",
-                label: "View Review File",
-                hint: "Opens the selected review file in Files view.",
                 input_state: self.view_current_review_file.clone(),
             },
+            SettingsShortcutRow {
+                id: "toggle-review-pin",
+                input_state: self.toggle_review_pin.clone(),
+            },
+            SettingsShortcutRow {
+                id: "next-review-pin",
+                input_state: self.next_review_pin.clone(),
+            },
+            SettingsShortcutRow {
+                id: "previous-review-pin",
+                input_state: self.previous_review_pin.clone(),
+            },
             SettingsShortcutRow {
                 id: "toggle-sidebar-tree",
-                label: "Toggle File Tree",
-                hint: "Collapses or expands the left file tree pane.",
                 input_state: self.toggle_sidebar_tree.clone(),
             },
+            SettingsShortcutRow {
+                id: "toggle-focus-mode",
+                input_state: self.toggle_focus_mode.clone(),
+            },
             SettingsShortcutRow {
                 id: "switch-to-files-view",
-                label: "Switch to Files View",
-                hint: "Switches the workspace to file editing view.",
                 input_state: self.switch_to_files_view.clone(),
             },
             SettingsShortcutRow {
                 id: "switch-to-review-view",
-                label: "Switch to Review View",
-                hint: "Switches the workspace to side-by-side diff review.",
                 input_state: self.switch_to_review_view.clone(),
             },
             SettingsShortcutRow {
                 id: "switch-to-git-view",
-                label: "Switch to Git View",
-                hint: "Switches the workspace to the Git workflow view.",
                 input_state: self.switch_to_git_view.clone(),
             },
             SettingsShortcutRow {
                 id: "toggle-ai-terminal-drawer",
-                label: "Toggle Terminal",
-                hint: "Opens or closes the terminal in AI and Files views.",
                 input_state: self.toggle_ai_terminal_drawer.clone(),
             },
             SettingsShortcutRow {
                 id: "open-project",
-                label: "Open Project",
-                hint: "Opens the system project picker.",
                 input_state: self.open_project.clone(),
             },
+            SettingsShortcutRow {
+                id: "open-path-from-clipboard",
+                input_state: self.open_path_from_clipboard.clone(),
+            },
+            SettingsShortcutRow {
+                id: "search-repository",
+                input_state: self.search_repository.clone(),
+            },
             SettingsShortcutRow {
                 id: "save-current-file",
-                label: "Save Current File",
-                hint: "Saves the active file editor buffer.",
                 input_state: self.save_current_file.clone(),
             },
             SettingsShortcutRow {
                 id: "next-editor-tab",
-                label: "Next Editor Tab",
-                hint: "Moves to the next open file tab in Files view.",
                 input_state: self.next_editor_tab.clone(),
             },
             SettingsShortcutRow {
                 id: "previous-editor-tab",
-                label: "Previous Editor Tab",
-                hint: "Moves to the previous open file tab in Files view.",
                 input_state: self.previous_editor_tab.clone(),
             },
             SettingsShortcutRow {
                 id: "close-editor-tab",
-                label: "Close Editor Tab",
-                hint: "Closes the active file tab in Files view.",
                 input_state: self.close_editor_tab.clone(),
             },
             SettingsShortcutRow {
                 id: "open-settings",
-                label: "Open Settings",
-                hint: "Opens this settings popup.",
                 input_state: self.open_settings.clone(),
             },
             SettingsShortcutRow {
                 id: "quit-app",
-                label: "Quit App",
-                hint: "Quits Hunk.",
                 input_state: self.quit_app.clone(),
             },
             SettingsShortcutRow {
                 id: "repo-tree-new-file",
-                label: "Tree: New File",
-                hint: "Creates a file from the focused file tree.",
                 input_state: self.repo_tree_new_file.clone(),
             },
             SettingsShortcutRow {
                 id: "repo-tree-new-folder",
-                label: "Tree: New Folder",
-                hint: "Creates a folder from the focused file tree.",
                 input_state: self.repo_tree_new_folder.clone(),
             },
             SettingsShortcutRow {
                 id: "repo-tree-rename-file",
-                label: "Tree: Rename File",
-                hint: "Renames the selected file in the focused file tree.",
                 input_state: self.repo_tree_rename_file.clone(),
             },
         ]
@@ -272,16 +333,28 @@ impl SettingsShortcutInputs {
 struct SettingsDraft {
     category: SettingsCategory,
     theme: ThemePreference,
+    language: Language,
     reduce_motion: bool,
     show_fps_counter: bool,
+    offer_clipboard_paths: bool,
+    fonts: SettingsFontsState,
     terminal: SettingsTerminalState,
+    git: SettingsGitState,
+    desktop_notifications: DesktopNotificationsConfig,
+    review_timer: ReviewTimerConfig,
+    gestures: GestureConfig,
+    formatting: SettingsFormattingState,
     shortcuts: SettingsShortcutInputs,
+    vim_mode: bool,
+    usage_metrics: Option<UsageMetricsSummary>,
+    usage_metrics_loading: bool,
+    maintenance: SettingsMaintenanceState,
     error_message: Option<String>,
 }
 
 fn settings_terminal_input(
     value: &str,
-    placeholder: &'static str,
+    placeholder: impl Into<SharedString>,
     window: &mut Window,
     cx: &mut Context<DiffViewer>,
 ) -> Entity<InputState> {
@@ -6,6 +6,7 @@ use gpui_component::{
     Colorize as _, Theme, ThemeMode,
     highlighter::{HighlightThemeStyle, SyntaxColors, ThemeStyle},
 };
+use hunk_domain::config::FontConfig;
 use hunk_git::git::FileStatus;
 
 #[derive(Debug, Clone, Copy)]
@@ -124,6 +125,31 @@ pub(crate) fn install_hunk_themes(cx: &mut App) {
     apply_soft_dark_theme(cx);
 }
 
+/// Points both themes at the configured fonts. The active theme picks them up on the next
+/// `Theme::change`.
+pub(crate) fn apply_hunk_fonts(fonts: &FontConfig, cx: &mut App) {
+    let ui_font_family = if fonts.ui_font_family.is_empty() {
+        preferred_ui_font_family().to_string()
+    } else {
+        fonts.ui_font_family.clone()
+    };
+    let mono_font_family = if fonts.mono_font_family.is_empty() {
+        preferred_mono_font_family().to_string()
+    } else {
+        fonts.mono_font_family.clone()
+    };
+
+    let theme = Theme::global_mut(cx);
+    for theme_config in [&mut theme.light_theme, &mut theme.dark_theme] {
+        let mut config = (**theme_config).clone();
+        config.font_family = Some(ui_font_family.clone().into());
+        config.font_size = Some(fonts.ui_font_size);
+        config.mono_font_family = Some(mono_font_family.clone().into());
+        config.mono_font_size = Some(fonts.mono_font_size);
+        *theme_config = Rc::new(config);
+    }
+}
+
 pub(crate) fn hunk_modal_backdrop(theme: &Theme, is_dark: bool) -> Hsla {
     hunk_opacity(theme.background, is_dark, 0.24, 0.12)
 }
@@ -127,6 +127,7 @@ enum WorkspaceTextContextMenuTarget {
     SelectableText(SelectableTextContextMenuTarget),
     Terminal(TerminalContextMenuTarget),
     DiffRows(DiffRowsContextMenuTarget),
+    FilePath(FilePathContextMenuTarget),
 }
 
 #[derive(Debug, Clone)]
@@ -156,10 +157,47 @@ struct TerminalContextMenuTarget {
     can_clear: bool,
 }
 
+#[derive(Debug, Clone)]
+struct FilePathContextMenuTarget {
+    path: String,
+    line: Option<usize>,
+    exists_on_disk: bool,
+    can_collapse_hunks: bool,
+    can_expand_hunks: bool,
+    can_convert_to_utf8: bool,
+}
+
+/// Substitutions repeated across many files of the loaded comparison.
+#[derive(Debug, Clone, Default)]
+struct ReviewMechanicalChanges {
+    groups: Vec<MechanicalChangeGroup>,
+    /// Groups approved as a whole, whose files are collapsed.
+    approved: MechanicalChangeApprovals,
+}
+
+/// Size warning for the loaded comparison, kept across refreshes of the same comparison.
+#[derive(Debug, Clone, Default)]
+struct ReviewChangeSetSize {
+    warning: Option<ChangeSetSizeWarning>,
+    /// Part of the proposed split being reviewed, with every file outside it collapsed.
+    focused_part: Option<usize>,
+    /// Files collapsed by focusing the part, expanded again when the focus ends.
+    collapsed_paths: BTreeSet<String>,
+    dismissed: bool,
+}
+
 #[derive(Debug, Clone)]
 struct DiffRowsContextMenuTarget {
     can_copy: bool,
     can_select_all: bool,
+    file_path: Option<String>,
+    hunk: Option<HunkSelector>,
+    /// Staging needs the review to show `HEAD` against this repository's working copy.
+    can_stage_hunk: bool,
+    /// The hunk is in the index, so it can be taken back out.
+    can_unstage_hunk: bool,
+    changed_lines_only: bool,
+    right_to_left: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -690,6 +728,21 @@ struct GitWorkspaceState {
     file_status_by_path: BTreeMap<String, FileStatus>,
     file_line_stats: BTreeMap<String, LineStats>,
     overall_line_stats: LineStats,
+    path_collisions: Vec<PathCollision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DesktopNotificationTarget {
+    GitWorkspace,
+    Comment(String),
+    Url(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RemoteBranchWatchHead {
+    root: PathBuf,
+    branch_name: String,
+    commit_id: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
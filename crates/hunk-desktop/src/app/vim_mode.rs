@@ -0,0 +1,48 @@
+/// Whether vim-style navigation is moving the cursor row or extending a row selection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum VimMode {
+    #[default]
+    Normal,
+    Visual,
+}
+
+impl VimMode {
+    pub(crate) const fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Visual => "VISUAL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VimCommand {
+    LineDown,
+    LineUp,
+    NextHunk,
+    PreviousHunk,
+    NextFile,
+    PreviousFile,
+    ToggleVisual,
+    Yank,
+    Search,
+    Escape,
+}
+
+/// Maps the text a keystroke produces to a vim command. Shifted punctuation arrives as the
+/// produced character, so `{` is matched directly rather than as `shift-[`.
+pub(crate) fn vim_command_for_key(key: &str) -> Option<VimCommand> {
+    match key {
+        "j" => Some(VimCommand::LineDown),
+        "k" => Some(VimCommand::LineUp),
+        "}" => Some(VimCommand::NextHunk),
+        "{" => Some(VimCommand::PreviousHunk),
+        "]" => Some(VimCommand::NextFile),
+        "[" => Some(VimCommand::PreviousFile),
+        "v" | "V" => Some(VimCommand::ToggleVisual),
+        "y" => Some(VimCommand::Yank),
+        "/" => Some(VimCommand::Search),
+        "escape" => Some(VimCommand::Escape),
+        _ => None,
+    }
+}
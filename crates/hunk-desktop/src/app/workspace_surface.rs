@@ -3,7 +3,7 @@ use std::rc::Rc;
 use gpui::{
     App, Bounds, ContentMask, DispatchPhase, Element, ElementId, GlobalElementId,
     InspectorElementId, IntoElement, LayoutId, MouseButton, MouseDownEvent, MouseMoveEvent,
-    MouseUpEvent, Pixels, Point, SharedString, Window, point, px,
+    MouseUpEvent, Pixels, Point, ScrollWheelEvent, SharedString, Window, point, px,
 };
 
 use crate::app::{DiffViewer, review_workspace_session};
@@ -31,15 +31,23 @@ pub(crate) struct ProjectedWorkspaceSurfaceElement {
     pub(crate) sticky_file_header:
         Option<review_workspace_session::ReviewWorkspaceVisibleFileHeader>,
     pub(crate) sticky_file_can_view: bool,
+    pub(crate) sticky_file_encoding: Option<String>,
+    pub(crate) sticky_file_is_extracted_text: bool,
+    pub(crate) sticky_file_code_owners: Option<String>,
     pub(crate) viewport_origin_px: usize,
     pub(crate) selected_row_range: Option<(usize, usize)>,
     pub(crate) left_panel_width: Option<Pixels>,
     pub(crate) right_panel_width: Option<Pixels>,
+    pub(crate) unified: bool,
     pub(crate) left_line_number_width: f32,
     pub(crate) right_line_number_width: f32,
     pub(crate) center_divider: gpui::Hsla,
     pub(crate) mono_font_family: SharedString,
     pub(crate) ui_font_family: SharedString,
+    pub(crate) code_font_size: Pixels,
+    pub(crate) code_font_features: gpui::FontFeatures,
+    pub(crate) right_to_left_files: std::collections::BTreeSet<String>,
+    pub(crate) ticket_linker: Option<hunk_git::tickets::TicketLinker>,
 }
 
 #[derive(Clone)]
@@ -111,12 +119,18 @@ impl Element for ProjectedWorkspaceSurfaceElement {
         let paint_style = crate::app::render::ReviewWorkspaceViewportPaintStyle {
             left_panel_width: self.left_panel_width,
             right_panel_width: self.right_panel_width,
+            unified: self.unified,
             left_line_number_width: self.left_line_number_width,
             right_line_number_width: self.right_line_number_width,
             center_divider: self.center_divider,
             mono_font_family: self.mono_font_family.clone(),
             ui_font_family: self.ui_font_family.clone(),
+            code_font_size: self.code_font_size,
+            code_font_features: self.code_font_features.clone(),
+            right_to_left_files: self.right_to_left_files.clone(),
+            ticket_linker: self.ticket_linker.clone(),
         };
+        let link_style = paint_style.clone();
         let hitbox = layout.hitbox.clone();
         let view = self.view.clone();
         window.on_mouse_event(move |event: &MouseDownEvent, phase, window, cx| {
@@ -143,6 +157,14 @@ impl Element for ProjectedWorkspaceSurfaceElement {
                             });
                             return;
                         }
+                        if controls.actions_bounds.contains(&event.position) {
+                            let path = header.path.clone();
+                            view.update(cx, |this, cx| {
+                                this.open_file_path_actions_menu(path, event.position, cx);
+                                cx.stop_propagation();
+                            });
+                            return;
+                        }
                         if controls.view_bounds.contains(&event.position) {
                             if !sticky_file_can_view {
                                 cx.stop_propagation();
@@ -157,6 +179,12 @@ impl Element for ProjectedWorkspaceSurfaceElement {
                             return;
                         }
                     }
+                    if event.button == MouseButton::Middle {
+                        let path = header.path.clone();
+                        view.update(cx, |this, cx| {
+                            this.on_file_middle_click(path, cx);
+                        });
+                    }
                     cx.stop_propagation();
                     return;
                 }
@@ -191,6 +219,14 @@ impl Element for ProjectedWorkspaceSurfaceElement {
                     });
                     return;
                 }
+                if controls.actions_bounds.contains(&event.position) {
+                    let path = path.clone();
+                    view.update(cx, |this, cx| {
+                        this.open_file_path_actions_menu(path, event.position, cx);
+                        cx.stop_propagation();
+                    });
+                    return;
+                }
                 if controls.view_bounds.contains(&event.position) && viewport_row.can_view_file {
                     let path = path.clone();
                     view.update(cx, |this, cx| {
@@ -200,6 +236,65 @@ impl Element for ProjectedWorkspaceSurfaceElement {
                     return;
                 }
             }
+            if event.button == MouseButton::Left
+                && hunk_domain::diff::large_hunk_hidden_line_count(viewport_row.text.as_str())
+                    .is_some()
+            {
+                view.update(cx, |this, cx| {
+                    this.expand_large_review_hunk(viewport_row.row_index, cx);
+                    cx.stop_propagation();
+                });
+                return;
+            }
+            if event.button == MouseButton::Left
+                && let Some(checkbox_bounds) =
+                    crate::app::render::review_workspace_hunk_stage_checkbox_bounds(
+                        row_bounds,
+                        viewport_row,
+                    )
+                && checkbox_bounds.contains(&event.position)
+            {
+                view.update(cx, |this, cx| {
+                    this.toggle_review_hunk_staged(viewport_row.row_index, cx);
+                    cx.stop_propagation();
+                });
+                return;
+            }
+            if viewport_row.stream_kind == crate::app::data::DiffStreamRowKind::CoreHunkHeader
+                && event.button == MouseButton::Left
+                && !event.modifiers.shift
+                && let Some(path) = viewport_row.file_path.as_ref()
+            {
+                let path = path.clone();
+                let hunk_header = viewport_row.text.clone();
+                view.update(cx, |this, cx| {
+                    this.toggle_review_hunk_collapsed(path, hunk_header.as_str(), cx);
+                    cx.stop_propagation();
+                });
+                return;
+            }
+            // Plain clicks keep selecting rows; links open on Cmd/Ctrl-click.
+            let uses_primary_modifier = if cfg!(target_os = "macos") {
+                event.modifiers.platform
+            } else {
+                event.modifiers.control
+            };
+            if event.button == MouseButton::Left
+                && uses_primary_modifier
+                && let Some(target) = crate::app::render::review_workspace_code_link_at_position(
+                    window,
+                    row_bounds,
+                    viewport_row,
+                    &link_style,
+                    event.position,
+                )
+            {
+                view.update(cx, |this, cx| {
+                    this.open_diff_text_link(target, cx);
+                    cx.stop_propagation();
+                });
+                return;
+            }
             if let Some(comment_layout) =
                 crate::app::render::review_workspace_comment_affordance_layout(
                     row_bounds,
@@ -254,6 +349,22 @@ impl Element for ProjectedWorkspaceSurfaceElement {
             });
         });
 
+        let hitbox = layout.hitbox.clone();
+        let view = self.view.clone();
+        window.on_mouse_event(move |event: &ScrollWheelEvent, phase, window, cx| {
+            let modifiers = &event.modifiers;
+            if phase != DispatchPhase::Capture
+                || !(modifiers.control || modifiers.platform)
+                || !hitbox.is_hovered(window)
+            {
+                return;
+            }
+            view.update(cx, |this, cx| {
+                this.zoom_diff_text_by_scroll(event, cx);
+            });
+            cx.stop_propagation();
+        });
+
         let view = self.view.clone();
         window.on_mouse_event(move |event: &MouseUpEvent, phase, window, cx| {
             if phase != DispatchPhase::Bubble {
@@ -308,6 +419,9 @@ impl Element for ProjectedWorkspaceSurfaceElement {
                     header,
                     is_selected,
                     self.sticky_file_can_view,
+                    self.sticky_file_encoding.as_deref(),
+                    self.sticky_file_is_extracted_text,
+                    self.sticky_file_code_owners.as_deref(),
                     sticky_bounds,
                     &paint_style,
                 );
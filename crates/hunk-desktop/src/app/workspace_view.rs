@@ -1,3 +1,5 @@
+use hunk_domain::state::PanelDock;
+
 pub(crate) const SHORTCUT_CONTEXT_FILES_WORKSPACE: &str = "FilesWorkspace";
 pub(crate) const SHORTCUT_CONTEXT_REVIEW_WORKSPACE: &str = "ReviewWorkspace";
 pub(crate) const SHORTCUT_CONTEXT_GIT_WORKSPACE: &str = "GitWorkspace";
@@ -21,6 +23,41 @@ pub(super) enum WorkspaceSwitchAction {
     Ai,
 }
 
+/// A workspace panel whose placement is part of the persisted layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum WorkspacePanel {
+    Tree,
+    Comments,
+    RecentCommits,
+}
+
+impl WorkspacePanel {
+    pub(super) const ALL: [Self; 3] = [Self::Tree, Self::Comments, Self::RecentCommits];
+
+    pub(super) const fn label(self) -> &'static str {
+        match self {
+            Self::Tree => "File Tree",
+            Self::Comments => "Comments",
+            Self::RecentCommits => "Recent Commits",
+        }
+    }
+
+    /// Only the comments list can float; the other panels always live in a dock.
+    pub(super) const fn supported_docks(self) -> &'static [PanelDock] {
+        match self {
+            Self::Comments => &[
+                PanelDock::Left,
+                PanelDock::Right,
+                PanelDock::Floating,
+                PanelDock::Hidden,
+            ],
+            Self::Tree | Self::RecentCommits => {
+                &[PanelDock::Left, PanelDock::Right, PanelDock::Hidden]
+            }
+        }
+    }
+}
+
 impl WorkspaceViewMode {
     pub(super) const fn supports_sidebar_tree(self) -> bool {
         matches!(self, Self::Files | Self::Diff)
@@ -30,6 +67,16 @@ impl WorkspaceViewMode {
         matches!(self, Self::Diff)
     }
 
+    /// The catalog key naming the pane focus mode maximizes in this view, if the view has one.
+    pub(super) const fn focus_mode_pane_key(self) -> Option<&'static str> {
+        match self {
+            Self::Files => Some("focus-mode-pane-editor"),
+            Self::Diff => Some("focus-mode-pane-diff"),
+            Self::GitWorkspace => Some("focus-mode-pane-commit-history"),
+            Self::Ai => None,
+        }
+    }
+
     pub(super) const fn shows_toolbar_workspace_identity(self) -> bool {
         !matches!(self, Self::Ai)
     }
@@ -0,0 +1,69 @@
+use std::backtrace::Backtrace;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::crash::{CONFIG_FORMAT_VERSION, CrashReport, CrashReportStore, RecentActivityLog};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+const RECENT_ACTIVITY_CAPACITY: usize = 200;
+
+static RECENT_ACTIVITY: RecentActivityLog = RecentActivityLog::new(RECENT_ACTIVITY_CAPACITY);
+
+/// Writes a crash report for every panic, then defers to the default hook so the panic is still
+/// printed to stderr.
+pub(crate) fn install_panic_hook() {
+    let Ok(store) = CrashReportStore::new() else {
+        return;
+    };
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string());
+        let report = CrashReport {
+            unix_time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs() as i64)
+                .unwrap_or_default(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            config_version: CONFIG_FORMAT_VERSION.to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            thread: std::thread::current()
+                .name()
+                .unwrap_or("unnamed")
+                .to_string(),
+            message,
+            location: info.location().map(ToString::to_string),
+            backtrace: Backtrace::force_capture().to_string(),
+            recent_activity: RECENT_ACTIVITY.snapshot(),
+        };
+        if let Err(err) = store.save(&report) {
+            eprintln!("failed to write crash report: {err:#}");
+        }
+        previous_hook(info);
+    }));
+}
+
+/// Keeps the last info-and-above log events around for [`install_panic_hook`]. Only the level,
+/// target and call site are kept: messages and fields can carry paths and repository text.
+pub(crate) struct RecentActivityLayer;
+
+impl<S: Subscriber> Layer<S> for RecentActivityLayer {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > Level::INFO {
+            return;
+        }
+        let line = match (metadata.file(), metadata.line()) {
+            (Some(file), Some(line)) => {
+                format!("{} {} ({file}:{line})", metadata.level(), metadata.target())
+            }
+            _ => format!("{} {}", metadata.level(), metadata.target()),
+        };
+        RECENT_ACTIVITY.push(line);
+    }
+}
@@ -4,15 +4,19 @@
 )]
 
 mod app;
+mod crash_reporter;
 mod terminal_env;
 
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Context, Result};
 use hunk_domain::config::{AppConfig, ConfigStore};
+use tracing_subscriber::layer::SubscriberExt as _;
+use tracing_subscriber::util::SubscriberInitExt as _;
 use tracing_subscriber::{EnvFilter, filter::LevelFilter};
 
 static SIGNAL_SHUTDOWN_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+const READ_ONLY_ARG: &str = "--read-only";
 
 fn main() -> Result<()> {
     if terminal_env::maybe_handle_terminal_env_helper_mode()? {
@@ -61,15 +65,23 @@ fn run_app() -> Result<()> {
         .add_directive("html5ever=warn".parse()?)
         .add_directive("markup5ever=warn".parse()?);
 
-    tracing_subscriber::fmt()
-        .with_env_filter(env_filter)
-        .without_time()
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(tracing_subscriber::fmt::layer().without_time())
+        .with(crash_reporter::RecentActivityLayer)
         .init();
+    crash_reporter::install_panic_hook();
 
     log_linux_compositor_selection();
     install_process_signal_cleanup()?;
 
-    app::run()
+    app::run(parse_launch_options())
+}
+
+fn parse_launch_options() -> app::LaunchOptions {
+    app::LaunchOptions {
+        read_only: std::env::args_os().skip(1).any(|arg| arg == READ_ONLY_ARG),
+    }
 }
 
 #[cfg(target_os = "windows")]
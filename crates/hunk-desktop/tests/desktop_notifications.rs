@@ -0,0 +1,64 @@
+#[path = "../src/app/desktop_notifications.rs"]
+mod desktop_notifications;
+
+use desktop_notifications::{
+    CiRunsState, DesktopNotificationEvent, parse_github_runs, text_mentions_handle,
+};
+use hunk_domain::config::DesktopNotificationsConfig;
+
+#[test]
+fn events_require_master_toggle_and_event_toggle() {
+    let mut config = DesktopNotificationsConfig::default();
+    assert!(!DesktopNotificationEvent::CiFinished.enabled(&config));
+
+    config.enabled = true;
+    assert!(DesktopNotificationEvent::CiFinished.enabled(&config));
+
+    config.remote_branch_moved = false;
+    assert!(!DesktopNotificationEvent::RemoteBranchMoved.enabled(&config));
+    assert!(DesktopNotificationEvent::CommentMention.enabled(&config));
+}
+
+#[test]
+fn mentions_match_handle_case_and_whitespace_insensitively() {
+    assert!(text_mentions_handle("@ada can you check this?", "Ada"));
+    assert!(text_mentions_handle("cc @AdaLovelace.", "Ada Lovelace"));
+    assert!(!text_mentions_handle("@adam can you check this?", "Ada"));
+    assert!(!text_mentions_handle("mail ada@example.com", "example.com"));
+    assert!(!text_mentions_handle("@ada", "  "));
+}
+
+#[test]
+fn github_runs_stay_pending_until_every_run_completes() {
+    assert_eq!(parse_github_runs("[]").unwrap(), CiRunsState::Pending);
+
+    let runs = r#"[
+        {"name":"CI","status":"completed","conclusion":"success","url":"https://ci/1"},
+        {"name":"Lint","status":"in_progress","conclusion":"","url":"https://ci/2"}
+    ]"#;
+    assert_eq!(parse_github_runs(runs).unwrap(), CiRunsState::Pending);
+}
+
+#[test]
+fn github_runs_report_failed_workflows() {
+    let runs = r#"[
+        {"name":"CI","status":"completed","conclusion":"success","url":"https://ci/1"},
+        {"name":"Lint","status":"completed","conclusion":"failure","url":"https://ci/2"}
+    ]"#;
+    assert_eq!(
+        parse_github_runs(runs).unwrap(),
+        CiRunsState::Failed {
+            url: Some("https://ci/2".to_string()),
+            failed_runs: vec!["Lint".to_string()],
+        }
+    );
+
+    let runs =
+        r#"[{"name":"CI","status":"completed","conclusion":"skipped","url":"https://ci/1"}]"#;
+    assert_eq!(
+        parse_github_runs(runs).unwrap(),
+        CiRunsState::Passed {
+            url: Some("https://ci/1".to_string()),
+        }
+    );
+}
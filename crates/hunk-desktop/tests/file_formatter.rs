@@ -0,0 +1,103 @@
+#[path = "../src/app/file_formatter.rs"]
+mod file_formatter;
+
+use std::time::{Duration, Instant};
+
+use file_formatter::{FORMATTER_TIMEOUT, formatter_error, formatter_error_line};
+use hunk_domain::config::FormatterCommand;
+
+#[test]
+fn error_lines_are_read_from_common_formatter_messages() {
+    let rustfmt = "error: expected `;`, found `}`\n --> <stdin>:3:14\n  |";
+    assert_eq!(formatter_error_line(rustfmt), Some(2));
+
+    let prettier = "[error] stdin: SyntaxError: Unexpected token (12:5)";
+    assert_eq!(formatter_error_line(prettier), Some(11));
+
+    let black = "error: cannot format -: Cannot parse: 7:0: def broken(";
+    assert_eq!(formatter_error_line(black), Some(6));
+
+    assert_eq!(formatter_error_line("prettier: command not found"), None);
+    assert_eq!(formatter_error_line("ratio 1:x at 0:4"), None);
+}
+
+#[test]
+fn formatter_errors_keep_the_start_of_the_output() {
+    let output = (1..=10)
+        .map(|line| format!("line {line}"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let error = formatter_error("rustfmt", output.as_str());
+    assert_eq!(
+        error.message,
+        "rustfmt failed: line 1\nline 2\nline 3\nline 4\nline 5\nline 6"
+    );
+    assert_eq!(error.line, None);
+
+    assert_eq!(formatter_error("black", "  \n").message, "black failed.");
+}
+
+#[cfg(unix)]
+#[test]
+fn run_formatter_pipes_the_buffer_and_reports_failures() {
+    let root = std::env::temp_dir();
+    let path = root.join("main.rs");
+
+    let echo = FormatterCommand {
+        languages: vec!["rust".to_string()],
+        program: "cat".to_string(),
+        args: Vec::new(),
+    };
+    assert_eq!(
+        file_formatter::run_formatter(&echo, &root, &path, "fn main() {}\n", FORMATTER_TIMEOUT),
+        Ok("fn main() {}\n".to_string())
+    );
+
+    let failing = FormatterCommand {
+        program: "sh".to_string(),
+        args: vec![
+            "-c".to_string(),
+            "echo \"$0:2:1: bad input\" >&2; exit 1".to_string(),
+            "{path}".to_string(),
+        ],
+        ..echo.clone()
+    };
+    let error =
+        file_formatter::run_formatter(&failing, &root, &path, "fn main() {}\n", FORMATTER_TIMEOUT)
+            .expect_err("formatter should fail");
+    assert_eq!(
+        error.message,
+        format!("sh failed: {}:2:1: bad input", path.display())
+    );
+    assert_eq!(error.line, Some(1));
+
+    let missing = FormatterCommand {
+        program: "hunk-formatter-that-does-not-exist".to_string(),
+        ..echo
+    };
+    assert!(file_formatter::run_formatter(&missing, &root, &path, "x", FORMATTER_TIMEOUT).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn run_formatter_stops_a_formatter_that_hangs() {
+    let root = std::env::temp_dir();
+    let hanging = FormatterCommand {
+        languages: vec!["rust".to_string()],
+        program: "sleep".to_string(),
+        args: vec!["30".to_string()],
+    };
+
+    let started = Instant::now();
+    let error = file_formatter::run_formatter(
+        &hanging,
+        &root,
+        &root.join("main.rs"),
+        "fn main() {}\n",
+        Duration::from_millis(200),
+    )
+    .expect_err("a hanging formatter should be stopped");
+
+    assert!(error.message.contains("was stopped"), "{}", error.message);
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
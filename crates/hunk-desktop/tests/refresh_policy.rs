@@ -13,7 +13,7 @@ use refresh_policy::{
     should_refresh_line_stats_after_snapshot, should_reload_diff_after_snapshot,
     should_reload_empty_files_workspace_tree, should_reload_repo_tree_after_snapshot,
     should_request_startup_git_workspace_refresh, should_run_cold_start_reconcile,
-    should_scroll_selected_after_reload,
+    should_scroll_selected_after_reload, single_file_watch_update_path,
 };
 
 #[test]
@@ -72,11 +72,17 @@ fn diff_reload_requires_real_diff_state_change_or_empty_rows() {
 
 #[test]
 fn repo_tree_reload_only_tracks_file_list_changes_or_root_switches() {
-    assert!(should_reload_repo_tree_after_snapshot(true, false, false));
+    assert!(should_reload_repo_tree_after_snapshot(true, true, false));
     assert!(should_reload_repo_tree_after_snapshot(false, true, true));
     assert!(!should_reload_repo_tree_after_snapshot(false, true, false));
 }
 
+#[test]
+fn hidden_repo_tree_waits_until_shown() {
+    assert!(!should_reload_repo_tree_after_snapshot(true, false, false));
+    assert!(!should_reload_repo_tree_after_snapshot(false, false, true));
+}
+
 #[test]
 fn empty_files_workspace_tree_reload_only_happens_in_files_view() {
     assert!(should_reload_empty_files_workspace_tree(true, true, false));
@@ -194,6 +200,56 @@ fn dirty_path_matching_supports_exact_and_directory_prefix_hits() {
     );
 }
 
+#[test]
+fn single_file_watch_updates_only_cover_the_selected_changed_file() {
+    let files = vec![
+        ChangedFile {
+            path: "src/lib.rs".to_string(),
+            status: FileStatus::Modified,
+            staged: false,
+            unstaged: true,
+            untracked: false,
+        },
+        ChangedFile {
+            path: "README.md".to_string(),
+            status: FileStatus::Modified,
+            staged: false,
+            unstaged: true,
+            untracked: false,
+        },
+    ];
+    let selected_only = BTreeSet::from([String::from("src/lib.rs")]);
+
+    assert_eq!(
+        single_file_watch_update_path(&selected_only, &files, Some("src/lib.rs")),
+        Some("src/lib.rs")
+    );
+    assert_eq!(
+        single_file_watch_update_path(&selected_only, &files, Some("README.md")),
+        None
+    );
+    assert_eq!(
+        single_file_watch_update_path(
+            &BTreeSet::from([String::from("src/lib.rs"), String::from("README.md")]),
+            &files,
+            Some("src/lib.rs"),
+        ),
+        None
+    );
+    assert_eq!(
+        single_file_watch_update_path(
+            &BTreeSet::from([String::from("src/new.rs")]),
+            &files,
+            Some("src/new.rs"),
+        ),
+        None
+    );
+    assert_eq!(
+        single_file_watch_update_path(&BTreeSet::new(), &files, Some("src/lib.rs")),
+        None
+    );
+}
+
 #[test]
 fn missing_line_stats_only_returns_changed_files_without_cached_stats() {
     let files = vec![
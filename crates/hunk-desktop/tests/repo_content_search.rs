@@ -0,0 +1,89 @@
+#[path = "../src/app/repo_content_search.rs"]
+mod repo_content_search;
+
+use std::fs;
+use std::sync::atomic::AtomicBool;
+
+use repo_content_search::{
+    REPO_CONTENT_SEARCH_MAX_FILE_BYTES, REPO_CONTENT_SEARCH_MAX_MATCHES, RepoContentMatch,
+    search_repo_contents,
+};
+
+fn paths(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn matches_are_grouped_by_file_with_smart_case() {
+    let repo = tempfile::tempdir().expect("temp dir");
+    fs::create_dir_all(repo.path().join("src")).expect("src dir");
+    fs::write(
+        repo.path().join("src/lib.rs"),
+        "pub struct Config;\n\n    let config = Config::load();\n",
+    )
+    .expect("write lib.rs");
+    fs::write(repo.path().join("README.md"), "No settings here.\n").expect("write readme");
+    let files = paths(&["README.md", "src/lib.rs"]);
+    let cancelled = AtomicBool::new(false);
+
+    let results = search_repo_contents(repo.path(), &files, "config", &cancelled);
+    assert_eq!(results.searched_files, 2);
+    assert_eq!(results.match_count(), 2);
+    assert_eq!(results.files.len(), 1);
+    assert_eq!(results.files[0].path, "src/lib.rs");
+    assert_eq!(
+        results.files[0].matches[1],
+        RepoContentMatch {
+            line: 3,
+            preview_match: 4..10,
+            preview: "let config = Config::load();".to_string(),
+        }
+    );
+
+    let results = search_repo_contents(repo.path(), &files, "Config", &cancelled);
+    assert_eq!(results.match_count(), 2);
+    assert_eq!(results.files[0].matches[1].preview_match, 13..19);
+
+    let results = search_repo_contents(repo.path(), &files, "CONFIG", &cancelled);
+    assert_eq!(results.match_count(), 0);
+}
+
+#[test]
+fn binary_large_and_missing_files_are_skipped() {
+    let repo = tempfile::tempdir().expect("temp dir");
+    fs::write(repo.path().join("image.png"), b"needle\0\x89PNG").expect("write binary");
+    fs::write(
+        repo.path().join("huge.txt"),
+        "needle\n".repeat(REPO_CONTENT_SEARCH_MAX_FILE_BYTES as usize / 7 + 1),
+    )
+    .expect("write large file");
+    fs::write(repo.path().join("notes.txt"), "needle\n").expect("write text");
+    let files = paths(&["huge.txt", "image.png", "missing.txt", "notes.txt"]);
+
+    let results = search_repo_contents(repo.path(), &files, "needle", &AtomicBool::new(false));
+    assert_eq!(results.searched_files, 1);
+    assert_eq!(results.skipped_files, 3);
+    assert_eq!(results.files.len(), 1);
+    assert_eq!(results.files[0].path, "notes.txt");
+}
+
+#[test]
+fn search_stops_at_the_match_limit_or_when_cancelled() {
+    let repo = tempfile::tempdir().expect("temp dir");
+    fs::write(
+        repo.path().join("a.txt"),
+        "hit\n".repeat(REPO_CONTENT_SEARCH_MAX_MATCHES + 5),
+    )
+    .expect("write a.txt");
+    fs::write(repo.path().join("b.txt"), "hit\n").expect("write b.txt");
+    let files = paths(&["a.txt", "b.txt"]);
+
+    let results = search_repo_contents(repo.path(), &files, "hit", &AtomicBool::new(false));
+    assert!(results.truncated);
+    assert_eq!(results.match_count(), REPO_CONTENT_SEARCH_MAX_MATCHES);
+    assert_eq!(results.files.len(), 1);
+
+    let results = search_repo_contents(repo.path(), &files, "hit", &AtomicBool::new(true));
+    assert_eq!(results.searched_files, 0);
+    assert!(results.files.is_empty());
+}
@@ -0,0 +1,61 @@
+#[path = "../src/app/review_timer.rs"]
+mod review_timer;
+
+use std::time::{Duration, Instant};
+
+use review_timer::{REVIEW_BREAK_MIN_PAUSE, ReviewTimer, review_timer_label};
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+#[test]
+fn review_timer_only_counts_time_while_running() {
+    let start = Instant::now();
+    let mut timer = ReviewTimer::default();
+    assert_eq!(timer.elapsed(start + MINUTE), Duration::ZERO);
+
+    timer.set_running(true, start);
+    assert_eq!(timer.elapsed(start + 10 * MINUTE), 10 * MINUTE);
+    timer.set_running(false, start + 10 * MINUTE);
+    assert!(!timer.is_running());
+    assert_eq!(timer.elapsed(start + 30 * MINUTE), 10 * MINUTE);
+
+    timer.set_running(true, start + 30 * MINUTE);
+    assert_eq!(timer.elapsed(start + 35 * MINUTE), 15 * MINUTE);
+
+    timer.reset(start + 35 * MINUTE);
+    assert!(timer.is_running());
+    assert_eq!(timer.elapsed(start + 36 * MINUTE), MINUTE);
+}
+
+#[test]
+fn break_reminders_fire_once_per_interval_and_long_pauses_count_as_breaks() {
+    let start = Instant::now();
+    let interval = 50 * MINUTE;
+    let mut timer = ReviewTimer::default();
+    timer.set_running(true, start);
+
+    assert!(!timer.take_break_reminder(interval, start + 49 * MINUTE));
+    assert!(timer.take_break_reminder(interval, start + 50 * MINUTE));
+    assert!(!timer.take_break_reminder(interval, start + 60 * MINUTE));
+    assert!(timer.take_break_reminder(interval, start + 100 * MINUTE));
+    assert!(!timer.take_break_reminder(Duration::ZERO, start + 200 * MINUTE));
+
+    // A short pause keeps counting toward the next reminder...
+    timer.set_running(false, start + 140 * MINUTE);
+    timer.set_running(true, start + 141 * MINUTE);
+    assert!(timer.take_break_reminder(interval, start + 151 * MINUTE));
+
+    // ...while a long one starts the interval over.
+    timer.set_running(false, start + 160 * MINUTE);
+    timer.set_running(true, start + 160 * MINUTE + REVIEW_BREAK_MIN_PAUSE);
+    assert!(!timer.take_break_reminder(interval, start + 200 * MINUTE + REVIEW_BREAK_MIN_PAUSE));
+    assert!(timer.take_break_reminder(interval, start + 210 * MINUTE + REVIEW_BREAK_MIN_PAUSE));
+}
+
+#[test]
+fn review_timer_label_adds_hours_only_when_needed() {
+    assert_eq!(review_timer_label(Duration::ZERO), "0m");
+    assert_eq!(review_timer_label(Duration::from_secs(754)), "12m");
+    assert_eq!(review_timer_label(Duration::from_secs(3600)), "1h 00m");
+    assert_eq!(review_timer_label(Duration::from_secs(3923)), "1h 05m");
+}
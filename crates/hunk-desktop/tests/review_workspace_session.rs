@@ -230,11 +230,13 @@ mod review_workspace_session;
 use std::collections::{BTreeMap, BTreeSet};
 
 use hunk_domain::diff::{
-    DiffCell, DiffCellKind, DiffRowKind, SideBySideRow, parse_patch_side_by_side,
+    DiffCell, DiffCellKind, DiffRowKind, HunkFolds, SideBySideRow, parse_patch_side_by_side,
+    retain_changed_rows,
 };
 use hunk_editor::{SearchHighlight, WorkspaceDisplayRow};
 use hunk_git::compare::CompareSnapshot;
 use hunk_git::git::{ChangedFile, FileStatus, LineStats};
+use hunk_git::hunk_apply::HunkStageState;
 use review_workspace_session::{
     REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX, REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX,
     ReviewWorkspaceDisplayRowEntry, ReviewWorkspaceDisplayRows, ReviewWorkspaceEditorSide,
@@ -371,8 +373,13 @@ fn review_workspace_session_registers_multi_file_hunk_excerpts() {
         ]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
 
     assert_eq!(session.layout().documents().len(), 2);
     assert_eq!(session.layout().excerpts().len(), 3);
@@ -413,9 +420,14 @@ fn review_workspace_session_search_matches_follow_excerpt_surface_order() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let matches = session.workspace_search_matches("needle");
 
     assert!(matches.len() >= 2);
@@ -454,9 +466,14 @@ fn review_workspace_surface_snapshot_marks_visible_search_matches() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let matches = session.workspace_search_matches("needle");
     let options = ReviewWorkspaceSurfaceOptions {
         search_highlight_columns_by_row: session.build_search_highlight_columns_by_row(&matches),
@@ -493,9 +510,14 @@ fn review_workspace_surface_snapshot_prefers_display_row_search_highlights() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let row_range = 0..session.row_count();
     let left_by_row = session
         .build_display_snapshot_for_side(row_range.clone(), ReviewWorkspaceEditorSide::Left)
@@ -577,9 +599,14 @@ fn review_workspace_display_geometry_tracks_expanded_display_rows() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let base_total_height = session.total_surface_height_px();
     let base_section_pixel_range = session
         .section_pixel_range(0)
@@ -706,9 +733,14 @@ fn review_workspace_surface_snapshot_positions_expanded_display_rows_by_display_
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
 
     let left_by_raw_row = session
         .build_display_snapshot_for_side(0..session.row_count(), ReviewWorkspaceEditorSide::Left)
@@ -841,9 +873,14 @@ fn review_workspace_session_builds_display_viewport_for_expanded_rows() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
 
     let left_by_raw_row = session
         .build_display_snapshot_for_side(0..session.row_count(), ReviewWorkspaceEditorSide::Left)
@@ -951,9 +988,14 @@ fn review_workspace_display_geometry_collapses_hidden_rows_from_multi_row_entrie
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let base_total_display_rows = session.display_geometry_total_display_rows();
 
     let first_code_row = (0..session.row_count())
@@ -1017,8 +1059,13 @@ fn review_workspace_session_surface_rows_match_current_side_by_side_shape() {
         patches_by_path: BTreeMap::from([("src/app.rs".to_string(), patch.to_string())]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let file_range = &session.file_ranges()[0];
     let expected_surface_rows = 1 + parse_patch_side_by_side(patch).len();
 
@@ -1064,14 +1111,131 @@ fn review_workspace_session_layout_matches_render_rows_for_mixed_change_blocks()
         patches_by_path: BTreeMap::from([("src/app.rs".to_string(), patch.to_string())]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let expected_surface_rows = 1 + parse_patch_side_by_side(patch).len();
 
     assert_eq!(session.layout().total_rows(), expected_surface_rows);
     assert_eq!(session.file_ranges()[0].end_row, expected_surface_rows);
 }
 
+#[test]
+fn review_workspace_session_layout_matches_changed_lines_only_rows() {
+    let patch = "\
+@@ -10,8 +10,8 @@
+ keep one
+-old a
+-old b
+-old c
++new a
+ keep two
+-old d
++new d
++new e
++new f
+ keep three
+";
+    let snapshot = CompareSnapshot {
+        files: vec![changed_file("src/app.rs", FileStatus::Modified)],
+        file_line_stats: BTreeMap::new(),
+        overall_line_stats: LineStats::default(),
+        patches_by_path: BTreeMap::from([("src/app.rs".to_string(), patch.to_string())]),
+    };
+
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::from(["src/app.rs".to_string()]),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
+    let changed_rows = retain_changed_rows(parse_patch_side_by_side(patch));
+    let expected_surface_rows = 1 + changed_rows.len();
+
+    assert_eq!(changed_rows.len(), 1 + 3 + 1 + 3);
+    assert_eq!(session.layout().total_rows(), expected_surface_rows);
+    assert_eq!(session.file_ranges()[0].end_row, expected_surface_rows);
+}
+
+#[test]
+fn review_workspace_session_layout_matches_collapsed_hunk_rows() {
+    let patch = "\
+@@ -1,3 +1,3 @@
+ keep
+-old
++new
+@@ -20,2 +20,4 @@
+ keep
++added one
++added two
+ tail
+";
+    let snapshot = CompareSnapshot {
+        files: vec![changed_file("src/app.rs", FileStatus::Modified)],
+        file_line_stats: BTreeMap::new(),
+        overall_line_stats: LineStats::default(),
+        patches_by_path: BTreeMap::from([("src/app.rs".to_string(), patch.to_string())]),
+    };
+    let mut hunk_folds = HunkFolds::default();
+    hunk_folds.collapse_all("src/app.rs", [20]);
+
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &hunk_folds,
+    )
+    .expect("workspace session should build");
+    let folded_rows = hunk_folds.fold_rows("src/app.rs", parse_patch_side_by_side(patch), false);
+    let expected_surface_rows = 1 + folded_rows.len();
+
+    assert_eq!(folded_rows.len(), 1 + 2 + 1);
+    assert_eq!(session.layout().total_rows(), expected_surface_rows);
+    assert_eq!(session.hunk_ranges().len(), 2);
+    assert_eq!(
+        session.hunk_ranges()[1].start_row + 1,
+        session.hunk_ranges()[1].end_row
+    );
+}
+
+#[test]
+fn review_workspace_session_layout_matches_large_hunk_fold_rows() {
+    let mut patch = String::from("@@ -1,2 +1,42 @@\n keep\n");
+    for line in 0..40 {
+        patch.push_str(&format!("+generated {line}\n"));
+    }
+    patch.push_str(" tail\n");
+    let snapshot = CompareSnapshot {
+        files: vec![changed_file("src/gen.rs", FileStatus::Modified)],
+        file_line_stats: BTreeMap::new(),
+        overall_line_stats: LineStats::default(),
+        patches_by_path: BTreeMap::from([("src/gen.rs".to_string(), patch.clone())]),
+    };
+    let mut hunk_folds = HunkFolds::default();
+    hunk_folds.set_large_hunk_threshold(10);
+
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &hunk_folds,
+    )
+    .expect("workspace session should build");
+    let folded_rows = hunk_folds.fold_rows(
+        "src/gen.rs",
+        parse_patch_side_by_side(patch.as_str()),
+        false,
+    );
+
+    assert!(folded_rows.len() < 42);
+    assert_eq!(session.layout().total_rows(), 1 + folded_rows.len());
+}
+
 #[test]
 fn review_workspace_session_tracks_stable_file_scope_queries() {
     let first_patch = "\
@@ -1098,8 +1262,13 @@ fn review_workspace_session_tracks_stable_file_scope_queries() {
         ]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let first_range = &session.file_ranges()[0];
     let second_range = &session.file_ranges()[1];
 
@@ -1156,8 +1325,13 @@ fn review_workspace_session_exposes_excerpt_sections_for_surface_rendering() {
         patches_by_path: BTreeMap::from([("src/main.rs".to_string(), patch.to_string())]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let first = session.section(0).expect("first section");
     let second = session.section(1).expect("second section");
 
@@ -1193,9 +1367,14 @@ fn review_workspace_session_tracks_section_pixel_geometry() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     let first = session.section(0).expect("first section");
     let second = session.section(1).expect("second section");
@@ -1233,6 +1412,104 @@ fn review_workspace_session_tracks_section_pixel_geometry() {
     );
 }
 
+#[test]
+fn review_workspace_session_unified_rows_stack_changed_line_pairs() {
+    let patch = "\
+@@ -1,3 +1,3 @@
+ before
+-old
++new
+ keep
+";
+    let snapshot = CompareSnapshot {
+        files: vec![changed_file("src/main.rs", FileStatus::Modified)],
+        file_line_stats: BTreeMap::new(),
+        overall_line_stats: LineStats::default(),
+        patches_by_path: BTreeMap::from([("src/main.rs".to_string(), patch.to_string())]),
+    };
+    let rows = parse_patch_side_by_side(patch);
+    let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
+    let side_by_side_height = session.total_surface_height_px();
+    let row_count = session.row_count();
+
+    session.set_unified_rows(true);
+
+    assert!(session.unified_rows());
+    assert_eq!(session.row_count(), row_count);
+    assert_eq!(
+        session.total_surface_height_px(),
+        side_by_side_height + REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX
+    );
+
+    session.set_unified_rows(false);
+    assert_eq!(session.total_surface_height_px(), side_by_side_height);
+}
+
+#[test]
+fn review_workspace_session_hunk_stage_controls_turn_dividers_into_rows() {
+    let patch = "\
+@@ -1,3 +1,3 @@
+ before
+-old
++new
+ keep
+";
+    let snapshot = CompareSnapshot {
+        files: vec![changed_file("src/main.rs", FileStatus::Modified)],
+        file_line_stats: BTreeMap::new(),
+        overall_line_stats: LineStats::default(),
+        patches_by_path: BTreeMap::from([("src/main.rs".to_string(), patch.to_string())]),
+    };
+    let rows = parse_patch_side_by_side(patch);
+    let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
+    let divider_height = session.total_surface_height_px();
+
+    session.set_hunk_stage_controls(true);
+    assert_eq!(
+        session.total_surface_height_px(),
+        divider_height + REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX
+            - REVIEW_SURFACE_HUNK_DIVIDER_HEIGHT_PX
+    );
+
+    let header_row = session.hunk_ranges()[0].start_row;
+    let surface = session.build_surface_snapshot(
+        0,
+        REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 8,
+        1,
+        8,
+        &ReviewWorkspaceSurfaceOptions {
+            hunk_stage_states_by_row: BTreeMap::from([(header_row, HunkStageState::Staged)]),
+            ..ReviewWorkspaceSurfaceOptions::default()
+        },
+    );
+    let header = surface
+        .viewport
+        .row_by_raw_index(header_row)
+        .expect("hunk header row should be visible");
+    assert_eq!(header.row_kind, DiffRowKind::HunkHeader);
+    assert_eq!(header.height_px, REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX);
+    assert_eq!(header.hunk_stage_state, Some(HunkStageState::Staged));
+
+    session.set_hunk_stage_controls(false);
+    assert_eq!(session.total_surface_height_px(), divider_height);
+}
+
 #[test]
 fn review_workspace_session_limits_section_rows_to_viewport_slice() {
     let patch = "\
@@ -1253,9 +1530,14 @@ fn review_workspace_session_limits_section_rows_to_viewport_slice() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     let first = session.section(0).expect("first section");
     let second = session.section(1).expect("second section");
@@ -1302,9 +1584,14 @@ fn review_workspace_session_builds_viewport_snapshot_from_shared_geometry() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let expected_first_visible_rows = session
         .section_visible_row_range(0, 0, REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 2, 1)
         .expect("first section visible rows")
@@ -1440,9 +1727,14 @@ fn review_workspace_session_builds_visible_state_from_viewport() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     let visible_state = session.build_visible_state(0, REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 2);
 
@@ -1497,9 +1789,14 @@ fn review_workspace_session_maps_viewport_pixels_back_to_rows() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let viewport = session.build_viewport_snapshot(
         0,
         REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 3,
@@ -1534,6 +1831,77 @@ fn review_workspace_session_maps_viewport_pixels_back_to_rows() {
     );
 }
 
+#[test]
+fn review_workspace_session_scroll_anchor_follows_rows_across_rebuilds() {
+    let session_for_patch = |patch: &str| {
+        let snapshot = CompareSnapshot {
+            files: vec![changed_file("src/main.rs", FileStatus::Modified)],
+            file_line_stats: BTreeMap::new(),
+            overall_line_stats: LineStats::default(),
+            patches_by_path: BTreeMap::from([("src/main.rs".to_string(), patch.to_string())]),
+        };
+        let rows = parse_patch_side_by_side(patch);
+        let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
+        ReviewWorkspaceSession::from_compare_snapshot(
+            &snapshot,
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &HunkFolds::default(),
+        )
+        .expect("workspace session should build")
+        .with_render_stream(&stream)
+    };
+    let row_for_left_line = |session: &ReviewWorkspaceSession, line: u32| {
+        (0..session.row_count())
+            .find(|row_ix| session.row(*row_ix).and_then(|row| row.left.line) == Some(line))
+            .expect("row for base line should exist")
+    };
+
+    let before = session_for_patch(
+        "\
+@@ -10,4 +10,4 @@
+ first
+-second
++SECOND
+ third
+ fourth
+",
+    );
+    let anchored_row = row_for_left_line(&before, 12);
+    let anchored_top = before
+        .row_top_offset_px(anchored_row)
+        .expect("anchored row should have geometry");
+    let anchor = before
+        .scroll_anchor_at(anchored_top + 3)
+        .expect("scroll anchor should resolve");
+    assert_eq!(anchor.left_line, Some(12));
+    assert_eq!(anchor.offset_in_row_px, 3);
+
+    let after = session_for_patch(
+        "\
+@@ -2,1 +2,2 @@
+ header
++inserted
+@@ -10,4 +11,4 @@
+ first
+-second
++SECOND
+ third
+ fourth
+",
+    );
+    let moved_row = row_for_left_line(&after, 12);
+    assert!(moved_row > anchored_row);
+    assert_eq!(
+        after.scroll_top_for_anchor(&anchor),
+        after.row_top_offset_px(moved_row).map(|top| top + 3)
+    );
+
+    let mut removed_file_anchor = anchor.clone();
+    removed_file_anchor.file_path = Some("src/removed.rs".to_string());
+    assert_eq!(after.scroll_top_for_anchor(&removed_file_anchor), None);
+}
+
 #[test]
 fn review_workspace_session_exposes_header_and_line_number_helpers() {
     let patch = "\
@@ -1557,9 +1925,14 @@ fn review_workspace_session_exposes_header_and_line_number_helpers() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     let header = session
         .visible_file_header_at_surface_row(2)
@@ -1593,9 +1966,14 @@ fn review_workspace_session_can_attach_render_rows() {
         quality: app::DiffSegmentQuality::Detailed,
         ..Default::default()
     });
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     assert_eq!(session.row_count(), session.layout().total_rows());
     assert_eq!(session.row_count(), rows.len() + 1);
@@ -1636,9 +2014,14 @@ fn review_workspace_session_prefetches_visible_code_rows_from_viewport_state() {
         quality: app::DiffSegmentQuality::Detailed,
         ..Default::default()
     });
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     let pending = session.build_segment_prefetch_rows(ReviewWorkspaceSegmentPrefetchRequest {
         scroll_top_px: 0,
@@ -1687,9 +2070,14 @@ fn review_workspace_session_surface_snapshot_reuses_viewport_and_visible_state()
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let surface = session.build_surface_snapshot(
         0,
         REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 4,
@@ -1738,9 +2126,14 @@ fn review_workspace_session_surface_snapshot_includes_sticky_file_header_after_h
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let surface = session.build_surface_snapshot(
         REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX,
         REVIEW_SURFACE_COMPACT_ROW_HEIGHT_PX * 4,
@@ -1782,9 +2175,14 @@ fn review_workspace_session_surface_snapshot_builds_sparse_overlays_from_surface
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let comment_row = 2;
     let surface = session.build_surface_snapshot(
         0,
@@ -1795,9 +2193,17 @@ fn review_workspace_session_surface_snapshot_builds_sparse_overlays_from_surface
             comment_affordance_rows: BTreeSet::from([comment_row]),
             comment_open_counts_by_row: BTreeMap::from([(comment_row, 1)]),
             active_comment_editor_row: Some(comment_row),
+            pinned_rows: BTreeSet::from([comment_row]),
             collapsed_paths: BTreeSet::from(["src/main.rs".to_string()]),
             view_file_enabled_paths: BTreeSet::from(["src/main.rs".to_string()]),
+            file_encodings: BTreeMap::from([("src/main.rs".to_string(), "Shift_JIS".to_string())]),
+            extracted_text_paths: BTreeSet::from(["src/main.rs".to_string()]),
+            file_code_owners: BTreeMap::from([(
+                "src/main.rs".to_string(),
+                "@acme/core".to_string(),
+            )]),
             search_highlight_columns_by_row: BTreeMap::new(),
+            hunk_stage_states_by_row: BTreeMap::new(),
         },
     );
 
@@ -1812,12 +2218,17 @@ fn review_workspace_session_surface_snapshot_builds_sparse_overlays_from_surface
         row.stream_kind == app::DiffStreamRowKind::FileHeader
             && row.file_is_collapsed
             && row.can_view_file
+            && row.file_encoding.as_deref() == Some("Shift_JIS")
+            && row.file_is_extracted_text
+            && row.file_code_owners.as_deref() == Some("@acme/core")
     }));
     assert!(
         surface
             .viewport
             .row_by_raw_index(comment_row)
-            .is_some_and(|row| row.show_comment_affordance && row.open_comment_count == 1)
+            .is_some_and(|row| row.show_comment_affordance
+                && row.open_comment_count == 1
+                && row.is_pinned)
     );
 }
 
@@ -1847,8 +2258,13 @@ fn review_workspace_session_can_build_editor_session_for_selected_path() {
         ]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let editor_session = session.build_editor_session(Some("src/lib.rs"));
 
     assert_eq!(
@@ -1875,13 +2291,18 @@ fn review_workspace_session_exports_editor_documents_for_each_side() {
         patches_by_path: BTreeMap::from([("src/main.rs".to_string(), patch.to_string())]),
     };
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&review_stream_for_rows(
-            &rows,
-            "src/main.rs",
-            FileStatus::Modified,
-        ));
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&review_stream_for_rows(
+        &rows,
+        "src/main.rs",
+        FileStatus::Modified,
+    ));
 
     assert_eq!(
         session.editor_documents(ReviewWorkspaceEditorSide::Left),
@@ -1912,9 +2333,14 @@ fn review_workspace_session_prefers_higher_quality_segment_upgrades() {
         quality: app::DiffSegmentQuality::Plain,
         ..Default::default()
     });
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
 
     assert!(session.set_row_segment_cache_if_better(
         0,
@@ -1952,8 +2378,13 @@ fn collapsed_review_workspace_files_keep_compact_surface_ranges() {
     };
     let collapsed = BTreeSet::from(["src/main.rs".to_string()]);
 
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &collapsed)
-        .expect("workspace session should build");
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &collapsed,
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build");
     let file_range = &session.file_ranges()[0];
 
     assert_eq!(file_range.end_row - file_range.start_row, 2);
@@ -1986,9 +2417,14 @@ fn review_workspace_session_builds_comment_anchors_from_render_rows() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("workspace session should build")
+    .with_render_stream(&stream);
     let (anchors, rows_by_path) = session.build_comment_anchor_index(2);
 
     assert_eq!(rows_by_path.get("src/main.rs").map(Vec::len), Some(4));
@@ -2066,9 +2502,14 @@ fn review_workspace_session_reuses_cached_display_rows_across_ranges() {
 
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/lib.rs", FileStatus::Modified);
-    let mut session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let mut session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
 
     let first = ReviewWorkspaceDisplayRows {
         rows: vec![ReviewWorkspaceDisplayRowEntry {
@@ -2125,9 +2566,14 @@ fn review_workspace_surface_snapshot_preserves_display_row_identity() {
     };
     let rows = parse_patch_side_by_side(patch);
     let stream = review_stream_for_rows(&rows, "src/main.rs", FileStatus::Modified);
-    let session = ReviewWorkspaceSession::from_compare_snapshot(&snapshot, &BTreeSet::new())
-        .expect("review workspace session should build")
-        .with_render_stream(&stream);
+    let session = ReviewWorkspaceSession::from_compare_snapshot(
+        &snapshot,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        &HunkFolds::default(),
+    )
+    .expect("review workspace session should build")
+    .with_render_stream(&stream);
     let row_range = 0..session.row_count();
     let left_by_row = session
         .build_display_snapshot_for_side(row_range.clone(), ReviewWorkspaceEditorSide::Left)
@@ -0,0 +1,41 @@
+#[path = "../src/app/vim_mode.rs"]
+mod vim_mode;
+
+use vim_mode::{VimCommand, VimMode, vim_command_for_key};
+
+#[test]
+fn vim_keys_map_to_row_hunk_and_file_motions() {
+    assert_eq!(vim_command_for_key("j"), Some(VimCommand::LineDown));
+    assert_eq!(vim_command_for_key("k"), Some(VimCommand::LineUp));
+    assert_eq!(vim_command_for_key("}"), Some(VimCommand::NextHunk));
+    assert_eq!(vim_command_for_key("{"), Some(VimCommand::PreviousHunk));
+    assert_eq!(vim_command_for_key("]"), Some(VimCommand::NextFile));
+    assert_eq!(vim_command_for_key("["), Some(VimCommand::PreviousFile));
+}
+
+#[test]
+fn vim_keys_map_to_selection_copy_and_search() {
+    assert_eq!(vim_command_for_key("v"), Some(VimCommand::ToggleVisual));
+    assert_eq!(vim_command_for_key("V"), Some(VimCommand::ToggleVisual));
+    assert_eq!(vim_command_for_key("y"), Some(VimCommand::Yank));
+    assert_eq!(vim_command_for_key("/"), Some(VimCommand::Search));
+    assert_eq!(vim_command_for_key("escape"), Some(VimCommand::Escape));
+}
+
+#[test]
+fn unbound_keys_are_left_to_standard_shortcuts() {
+    for key in ["down", "up", "f7", "g", "J", "x", "space"] {
+        assert_eq!(
+            vim_command_for_key(key),
+            None,
+            "{key} should not be a vim command"
+        );
+    }
+}
+
+#[test]
+fn vim_mode_defaults_to_normal() {
+    assert_eq!(VimMode::default(), VimMode::Normal);
+    assert_eq!(VimMode::Normal.label(), "NORMAL");
+    assert_eq!(VimMode::Visual.label(), "VISUAL");
+}
@@ -1,11 +1,12 @@
 #[path = "../src/app/workspace_view.rs"]
 mod workspace_view;
 
+use hunk_domain::state::{PanelDock, WorkspaceLayoutState};
 use workspace_view::{
     SHORTCUT_CONTEXT_AI_WORKSPACE, SHORTCUT_CONTEXT_FILES_WORKSPACE,
     SHORTCUT_CONTEXT_GIT_WORKSPACE, SHORTCUT_CONTEXT_REVIEW_WORKSPACE,
-    SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE, SHORTCUT_CONTEXT_TREE_WORKSPACE, WorkspaceSwitchAction,
-    WorkspaceViewMode,
+    SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE, SHORTCUT_CONTEXT_TREE_WORKSPACE, WorkspacePanel,
+    WorkspaceSwitchAction, WorkspaceViewMode,
 };
 
 #[test]
@@ -54,6 +55,23 @@ fn only_review_mode_enables_diff_stream() {
     assert!(!WorkspaceViewMode::GitWorkspace.supports_diff_stream());
 }
 
+#[test]
+fn focus_mode_maximizes_one_pane_per_workspace_except_ai() {
+    assert_eq!(
+        WorkspaceViewMode::Files.focus_mode_pane_key(),
+        Some("focus-mode-pane-editor")
+    );
+    assert_eq!(
+        WorkspaceViewMode::Diff.focus_mode_pane_key(),
+        Some("focus-mode-pane-diff")
+    );
+    assert_eq!(
+        WorkspaceViewMode::GitWorkspace.focus_mode_pane_key(),
+        Some("focus-mode-pane-commit-history")
+    );
+    assert_eq!(WorkspaceViewMode::Ai.focus_mode_pane_key(), None);
+}
+
 #[test]
 fn ai_mode_hides_primary_workspace_toolbar_treatment() {
     assert!(!WorkspaceViewMode::Ai.shows_toolbar_workspace_identity());
@@ -136,3 +154,37 @@ fn root_key_contexts_include_only_the_scopes_each_workspace_needs() {
             .contains(SHORTCUT_CONTEXT_SELECTABLE_WORKSPACE)
     );
 }
+
+#[test]
+fn only_comments_panel_can_float() {
+    for panel in WorkspacePanel::ALL {
+        assert!(!panel.label().is_empty());
+        assert!(panel.supported_docks().contains(&PanelDock::Left));
+        assert!(panel.supported_docks().contains(&PanelDock::Right));
+        assert!(panel.supported_docks().contains(&PanelDock::Hidden));
+        assert_eq!(
+            panel.supported_docks().contains(&PanelDock::Floating),
+            panel == WorkspacePanel::Comments
+        );
+    }
+}
+
+#[test]
+fn default_workspace_layout_uses_supported_docks() {
+    let layout = WorkspaceLayoutState::default();
+    assert!(
+        WorkspacePanel::Tree
+            .supported_docks()
+            .contains(&layout.tree)
+    );
+    assert!(
+        WorkspacePanel::Comments
+            .supported_docks()
+            .contains(&layout.comments)
+    );
+    assert!(
+        WorkspacePanel::RecentCommits
+            .supported_docks()
+            .contains(&layout.recent_commits)
+    );
+}
@@ -0,0 +1,392 @@
+# English messages. This is the fallback catalog: every key used by the app must be here.
+#
+# The catalog covers settings, comments, and every screen added since it landed. Older screens
+# still carry inline English and move here as they are touched; new UI text must go through tr().
+
+## Settings
+
+settings-title = Settings
+settings-saved-to-path = Changes are saved to ~/.hunkdiff/config.toml
+settings-close = Close
+settings-categories = Categories
+settings-category-ui = UI
+settings-category-terminal = Terminal
+settings-category-git = Git
+settings-category-notifications = Notifications
+settings-category-usage-metrics = Usage Metrics
+settings-category-maintenance = Maintenance
+settings-category-gestures = Gestures
+settings-category-formatting = Formatting
+settings-category-keyboard-shortcuts = Keyboard Shortcuts
+settings-footer-hint = Settings are saved to config.toml.
+settings-cancel = Cancel
+settings-save = Save
+settings-on = On
+settings-off = Off
+
+settings-ui-description = Theme, fonts, language, and UI preferences.
+settings-theme = Theme
+settings-theme-system = System
+settings-theme-light = Light
+settings-theme-dark = Dark
+settings-language = Language
+settings-language-hint = Translated parts of the app switch as soon as settings are saved.
+settings-reduced-motion = Reduced Motion
+settings-fps-counter = FPS Counter
+settings-offer-clipboard-paths = Offer Clipboard Paths
+settings-update-behavior = Update behavior
+settings-update-behavior-description =
+    Diffs refresh immediately on file events. The app also performs a background periodic check as a fallback if file events are missed.
+    Reduced Motion disables animated transitions in the Git workspace.
+
+settings-fonts = Fonts
+settings-fonts-hint = Leave a family empty to use the platform default. Changes apply on save.
+settings-fonts-ui-font = UI Font
+settings-fonts-ui-size = UI Size
+settings-fonts-code-font = Code Font
+settings-fonts-code-size = Code Size
+settings-fonts-line-height = Line Height
+settings-fonts-ligatures = Code Ligatures
+settings-fonts-ligatures-hint = Combine character sequences like -> and != in diffs and the editor.
+settings-fonts-invalid-number = Fonts: { $field } must be a number above 0.
+
+## Review pins
+
+review-pin-select-line = Select a diff line to pin.
+review-pin-code-lines-only = Only code lines can be pinned.
+review-pin-unpinned = Unpinned line.
+review-pin-pinned = Pinned { $location }.
+review-pin-none = No pinned lines in this review.
+review-pin-none-visible = No pinned lines are visible in this diff.
+review-pin-moved = Pinned line is no longer in the diff; jumped to file.
+review-pin-file-missing = Pinned file is not part of this diff.
+
+## Comment import
+
+comment-import-disabled = Comments are disabled for custom compare pairs.
+comment-import-title = Import Comments
+comment-import-picker-failed = Failed to open file picker: { $error }
+comment-import-failed = Comment import failed: { $error }
+comment-import-database-unavailable = Comments database is unavailable on this machine.
+comment-import-no-repository = No repository is open.
+comment-import-empty = No comments found in the selected file.
+comment-import-imported = Imported { $created } comments.
+comment-import-none-matched = None of { $unplaced } comments matched a diff row.
+comment-import-partial = Imported { $created } comments. { $unplaced } need manual placement.
+comment-import-copied = Copied unplaced comments.
+
+## Comment export
+
+comment-export-empty = This review has no comments to export.
+comment-export-copied = Copied review comments as Markdown.
+comment-export-saved = Exported review comments to { $path }
+comment-export-failed = Comment export failed: { $error }
+
+## GitHub review comments
+
+github-review-no-branch = Check out the pull request's branch to sync comments with GitHub.
+github-review-no-open-comments = This review has no open comments to publish.
+github-review-published = Published { $published } of { $total } comments to pull request #{ $number }.
+github-review-skipped = { $present } already on the pull request; { $rejected } not on a line of its diff.
+github-review-up-to-date = Pull request #{ $number } has no comments missing here.
+github-review-failed = GitHub sync failed: { $error }
+
+## Operation history
+
+operation-history-title = Operation History
+operation-history-loading = Loading operations...
+operation-history-restoring = Restoring operation...
+operation-history-summary = { $count } recent operation(s). Restoring moves HEAD and local branches back.
+operation-history-loading-changes = Loading changes...
+operation-history-no-changes = This operation changed nothing.
+operation-history-changes = Changes made by this operation:
+operation-history-oldest = This is the oldest operation in the history.
+operation-history-undo = Undo Last Operation
+operation-history-restore = Restore to This Operation
+operation-history-close = Close
+operation-history-no-description = (no description)
+operation-history-empty = This repository has no operations yet.
+operation-history-detached-head = detached HEAD
+operation-history-restored = Restored { $target } at { $commit }
+operation-history-undo-failed = Undo operation failed: { $error }
+operation-history-restore-failed = Restore operation failed: { $error }
+
+## Stack editor
+
+stack-editor-title = Edit Stack
+stack-editor-loading = Loading stack...
+stack-editor-applying = Rewriting the branch...
+stack-editor-summary = { $count } commit(s) on { $branch } above { $base }, oldest first. Drag commits to reorder them, then pick, squash into the commit above, or drop each one.
+stack-editor-reset = Reset
+stack-editor-rewrite = Rewrite Branch
+stack-editor-close = Close
+stack-editor-pick = Pick
+stack-editor-squash = Squash
+stack-editor-drop = Drop
+stack-editor-move-up = Move up
+stack-editor-move-down = Move down
+stack-editor-empty = '{ $branch }' has no commits on top of '{ $base }'.
+stack-editor-rewrote = Rewrote { $branch } with { $count } commit(s)
+stack-editor-failed = Edit stack failed: { $error }
+
+## Change set size
+
+change-set-title = Large change set: { $files } files, { $lines } changed lines
+change-set-estimate = { $estimate } to review at { $rate } lines an hour
+change-set-minutes = ~{ $minutes } min
+change-set-hours = ~{ $hours }h { $minutes }m
+change-set-dismiss = Dismiss
+change-set-largest = Largest: { $files }
+change-set-split-suggestion = Consider splitting the review into { $count } parts of { $limit }:
+change-set-split-lines = up to { $lines } lines each
+change-set-split-files = up to { $files } files each
+change-set-split-files-and-lines = up to { $files } files and { $lines } lines each
+change-set-repository-root = (repository root)
+change-set-part = Part { $number }: { $directory } - { $files } files, { $lines } lines, { $estimate }
+change-set-show-all = Show All
+change-set-review-part = Review Part
+
+## Repository search
+
+repo-search-title = Search in Repository
+repo-search-placeholder = Search file contents in the repository
+repo-search-searching = Searching...
+repo-search-hint = Matches in tracked and untracked files. Ignored and binary files are skipped.
+repo-search-no-matches = No matches for "{ $query }" in { $searched } files.
+repo-search-matches = { $matches } matches in { $files } of { $searched } files
+repo-search-first-matches = First { $matches } matches in { $files } of { $searched } files
+repo-search-close = Close
+
+## Review context menu
+
+context-menu-left-to-right = Show File Left to Right
+context-menu-right-to-left = Show File Right to Left
+context-menu-stage-hunk = Stage Hunk
+context-menu-unstage-hunk = Unstage Hunk
+git-staged-hunk = Staged hunk of { $path }
+git-unstaged-hunk = Unstaged hunk of { $path }
+
+## Review compare
+
+review-compare-revision = Revision
+review-compare-review-revision = Review this revision
+
+## Mechanical changes
+
+mechanical-change-title-one = Mechanical change: { $from } → { $to } in { $files } file
+mechanical-change-title = Mechanical change: { $from } → { $to } in { $files } files
+mechanical-change-approved = Approved, files collapsed
+mechanical-change-lines = { $lines } changed lines
+mechanical-change-undo-approval = Undo Approval
+mechanical-change-approve-all = Approve All
+
+## Keyboard shortcut settings
+
+settings-shortcuts-description = Edit comma-separated shortcut strings for each action. Use spaces for key sequences.
+settings-vim-mode = Vim Mode
+settings-vim-mode-hint = In review mode: j/k move rows, { and } jump hunks, [ and ] jump files, v starts a row selection, y copies it and / searches. The shortcuts below keep working either way.
+settings-shortcuts-footer = Use commas to add alternatives, spaces for key sequences, and cmd-, literally for the comma key.
+settings-shortcut-select-next-line = Select Next Line
+settings-shortcut-select-next-line-hint = Moves selection down one diff row.
+settings-shortcut-select-previous-line = Select Previous Line
+settings-shortcut-select-previous-line-hint = Moves selection up one diff row.
+settings-shortcut-extend-selection-next-line = Extend Selection Down
+settings-shortcut-extend-selection-next-line-hint = Extends the multi-row selection downward.
+settings-shortcut-extend-selection-previous-line = Extend Selection Up
+settings-shortcut-extend-selection-previous-line-hint = Extends the multi-row selection upward.
+settings-shortcut-copy-selection = Copy Selection
+settings-shortcut-copy-selection-hint = Copies the selected diff rows.
+settings-shortcut-select-all-diff-rows = Select All Diff Rows
+settings-shortcut-select-all-diff-rows-hint = Selects all rows in the current diff.
+settings-shortcut-next-hunk = Next Hunk
+settings-shortcut-next-hunk-hint = Jumps to the next diff hunk.
+settings-shortcut-previous-hunk = Previous Hunk
+settings-shortcut-previous-hunk-hint = Jumps to the previous diff hunk.
+settings-shortcut-next-file = Next File
+settings-shortcut-next-file-hint = Moves to the next changed file.
+settings-shortcut-previous-file = Previous File
+settings-shortcut-previous-file-hint = Moves to the previous changed file.
+settings-shortcut-view-current-review-file = View Review File
+settings-shortcut-view-current-review-file-hint = Opens the selected review file in Files view.
+settings-shortcut-toggle-review-pin = Pin Row
+settings-shortcut-toggle-review-pin-hint = Pins or unpins the selected diff row.
+settings-shortcut-next-review-pin = Next Pin
+settings-shortcut-next-review-pin-hint = Jumps to the next pinned diff row.
+settings-shortcut-previous-review-pin = Previous Pin
+settings-shortcut-previous-review-pin-hint = Jumps to the previous pinned diff row.
+settings-shortcut-toggle-sidebar-tree = Toggle File Tree
+settings-shortcut-toggle-sidebar-tree-hint = Collapses or expands the left file tree pane.
+settings-shortcut-toggle-focus-mode = Toggle Focus Mode
+settings-shortcut-toggle-focus-mode-hint = Maximizes the diff, editor, or commit history pane.
+settings-shortcut-switch-to-files-view = Switch to Files View
+settings-shortcut-switch-to-files-view-hint = Switches the workspace to file editing view.
+settings-shortcut-switch-to-review-view = Switch to Review View
+settings-shortcut-switch-to-review-view-hint = Switches the workspace to side-by-side diff review.
+settings-shortcut-switch-to-git-view = Switch to Git View
+settings-shortcut-switch-to-git-view-hint = Switches the workspace to the Git workflow view.
+settings-shortcut-toggle-ai-terminal-drawer = Toggle Terminal
+settings-shortcut-toggle-ai-terminal-drawer-hint = Opens or closes the terminal in AI and Files views.
+settings-shortcut-open-project = Open Project
+settings-shortcut-open-project-hint = Opens the system project picker.
+settings-shortcut-open-path-from-clipboard = Open Path from Clipboard
+settings-shortcut-open-path-from-clipboard-hint = Jumps to a repository path:line copied from logs or CI output.
+settings-shortcut-search-repository = Search in Repository
+settings-shortcut-search-repository-hint = Searches the contents of every file in the repository.
+settings-shortcut-save-current-file = Save Current File
+settings-shortcut-save-current-file-hint = Saves the active file editor buffer.
+settings-shortcut-next-editor-tab = Next Editor Tab
+settings-shortcut-next-editor-tab-hint = Moves to the next open file tab in Files view.
+settings-shortcut-previous-editor-tab = Previous Editor Tab
+settings-shortcut-previous-editor-tab-hint = Moves to the previous open file tab in Files view.
+settings-shortcut-close-editor-tab = Close Editor Tab
+settings-shortcut-close-editor-tab-hint = Closes the active file tab in Files view.
+settings-shortcut-open-settings = Open Settings
+settings-shortcut-open-settings-hint = Opens this settings popup.
+settings-shortcut-quit-app = Quit App
+settings-shortcut-quit-app-hint = Quits Hunk.
+settings-shortcut-repo-tree-new-file = Tree: New File
+settings-shortcut-repo-tree-new-file-hint = Creates a file from the focused file tree.
+settings-shortcut-repo-tree-new-folder = Tree: New Folder
+settings-shortcut-repo-tree-new-folder-hint = Creates a folder from the focused file tree.
+settings-shortcut-repo-tree-rename-file = Tree: Rename File
+settings-shortcut-repo-tree-rename-file-hint = Renames the selected file in the focused file tree.
+
+## Gesture settings
+
+settings-gestures-description = What double-clicks and middle-clicks do in the review.
+settings-gestures-double-click = Double-Click a Diff Row
+settings-gestures-double-click-hint = Opens the file in the editor at that line, or starts a comment on it.
+settings-gestures-middle-click = Middle-Click a File
+settings-gestures-middle-click-hint = Applies to files in the tree and file headers in the review.
+settings-gesture-select-line = Select Line
+settings-gesture-open-in-editor = Open in Editor
+settings-gesture-add-comment = Add Comment
+settings-gesture-nothing = Nothing
+settings-gesture-collapse-file = Collapse File
+settings-gesture-pin-file = Pin File
+
+## File tree
+
+tree-clear-folder-reviewed = Clear Folder Reviewed
+tree-mark-folder-reviewed = Mark Folder as Reviewed
+
+## Open path from clipboard
+
+clipboard-path-none = The clipboard doesn't mention a file in this repository.
+clipboard-path-found = Clipboard mentions { $path }.
+clipboard-path-open = Open
+
+## CI log
+
+ci-log-title = CI Log
+ci-log-close = Close
+ci-log-placeholder = Paste the output of a failing CI run
+ci-log-hint = Find the failing tests and file locations the log mentions.
+ci-log-empty = No tests or repository files found in this log.
+ci-log-summary = { $count } references, { $in_change } in this change
+ci-log-find-references = Find References
+ci-log-in-change = In change
+ci-log-test-not-in-diff = { $test } is not defined in the current diff.
+
+## Review toolbar
+
+toolbar-diff-unified = Unified
+toolbar-diff-side-by-side = Side by Side
+toolbar-diff-unified-tooltip = Show the diff in one interleaved column, like git diff
+toolbar-comment-counts = Comments ({ $open } open, { $resolved } resolved)
+toolbar-export-review = Export Review
+toolbar-export-review-tooltip = Export comments or sync them with the GitHub pull request
+toolbar-export-copy-markdown = Copy as Markdown
+toolbar-export-save-markdown = Save as Markdown...
+toolbar-export-save-json = Save as JSON...
+toolbar-github-publish = Publish to GitHub Pull Request
+toolbar-github-pull = Pull GitHub Review Comments
+
+## Comment storage
+
+comment-database-locked = Another Hunk instance holds the comment database. Try again in a moment.
+comment-save-failed = Failed to save comment.
+comment-delete-failed = Failed to delete comment.
+comment-reopen-failed = Failed to reopen comment.
+
+## Formatting settings
+
+settings-formatting-description = External formatters for the file editor. Each command reads the file on stdin and prints the formatted file; {path} expands to the file path.
+settings-formatting-format-on-save = Format on Save
+settings-formatting-format-on-save-hint = Runs the file's formatter before writing it. Formatter errors are shown in the editor and the file is still saved.
+settings-formatting-not-formatted = Not formatted
+settings-formatting-footer = Clear a command to stop formatting those languages. Formatters for other languages can be added to config.toml.
+
+## Editor formatting
+
+editor-format = Format
+editor-format-tooltip = Format file
+editor-format-no-file = No file is open in editor.
+editor-format-no-formatter = No formatter is configured for { $path }.
+editor-format-blocked = Format blocked for { $path }: { $error }
+editor-format-done = Formatted { $path }
+editor-format-unchanged = { $path } is already formatted
+editor-format-skipped = Skipped formatting { $path } because it changed while formatting.
+editor-format-failed = Format failed for { $path }
+
+## Commit details
+
+commit-details-open = Details
+commit-details-title = Commit { $commit }
+commit-details-loading = Loading commit...
+commit-details-summary = { $count } changed file(s) against the first parent.
+commit-details-close = Close
+commit-details-no-parents = none (root commit)
+commit-details-commit = Commit
+commit-details-parents = Parents
+commit-details-author = Author
+commit-details-committer = Committer
+commit-details-change-id = Change-Id
+commit-details-no-text-changes = No textual changes to show.
+commit-details-more-lines = ...and { $count } more line(s)
+commit-details-patch-truncated = Patch truncated.
+
+## Editor recovery
+
+editor-recovery-prompt = Recovered unsaved changes to this file, last written { $time }.
+editor-recovery-discard = Discard
+editor-recovery-restore = Restore
+editor-recovery-restored = Restored unsaved changes to { $path }
+
+## Focus mode
+
+focus-mode-bar = Focus mode: { $pane }
+focus-mode-exit = Exit Focus Mode
+focus-mode-exit-shortcut = Exit Focus Mode ({ $shortcut })
+focus-mode-pane-editor = Editor
+focus-mode-pane-diff = Diff
+focus-mode-pane-commit-history = Commit History
+
+## Notification settings
+
+settings-notifications-description = System notifications for background events while Hunk is not in front, and reminders to take a break during long reviews.
+settings-notifications-enabled = Desktop Notifications
+settings-notifications-enabled-hint = Shows OS notifications for the events enabled below.
+settings-notifications-ci-finished = CI Finished
+settings-notifications-ci-finished-hint = After a push, reports when GitHub Actions runs for the commit finish (requires gh).
+settings-notifications-remote-branch-moved = Remote Branch Moved
+settings-notifications-remote-branch-moved-hint = Reports when someone else pushes to the current branch's upstream.
+settings-notifications-comment-mentions = Comment Mentions
+settings-notifications-comment-mentions-hint = Reports new comments from agents or co-reviewers that @mention your co-review name.
+settings-review-timer = Review Timer
+settings-review-timer-hint = Shows active time spent in Review view in the status bar. Time away from the window is not counted.
+settings-review-break-reminder = Break Reminder
+settings-review-break-reminder-hint = Suggests a break after this much review time. Five minutes away counts as a break.
+settings-review-break-minutes = { $minutes } minutes
+
+## Review timer
+
+review-timer-running = Review { $elapsed }
+review-timer-paused = Review { $elapsed } (paused)
+review-timer-tooltip = Active review time. Click to start a new session.
+review-timer-break = You have been reviewing for { $elapsed } without a break. Step away for a few minutes to keep your eyes fresh.
+
+## Diff columns
+
+diff-column-old = OLD
+diff-column-new = NEW
@@ -0,0 +1,389 @@
+# Mensajes en español. Las claves que falten se muestran en inglés.
+
+## Ajustes
+
+settings-title = Ajustes
+settings-saved-to-path = Los cambios se guardan en ~/.hunkdiff/config.toml
+settings-close = Cerrar
+settings-categories = Categorías
+settings-category-ui = Interfaz
+settings-category-terminal = Terminal
+settings-category-git = Git
+settings-category-notifications = Notificaciones
+settings-category-usage-metrics = Métricas de uso
+settings-category-maintenance = Mantenimiento
+settings-category-gestures = Gestos
+settings-category-formatting = Formato
+settings-category-keyboard-shortcuts = Atajos de teclado
+settings-footer-hint = Los ajustes se guardan en config.toml.
+settings-cancel = Cancelar
+settings-save = Guardar
+settings-on = Activado
+settings-off = Desactivado
+
+settings-ui-description = Tema, fuentes, idioma y preferencias de la interfaz.
+settings-theme = Tema
+settings-theme-system = Sistema
+settings-theme-light = Claro
+settings-theme-dark = Oscuro
+settings-language = Idioma
+settings-language-hint = Las partes traducidas de la aplicación cambian en cuanto se guardan los ajustes.
+settings-reduced-motion = Movimiento reducido
+settings-fps-counter = Contador de FPS
+settings-offer-clipboard-paths = Ofrecer rutas del portapapeles
+settings-update-behavior = Comportamiento de actualización
+settings-update-behavior-description =
+    Las diferencias se actualizan en cuanto cambian los archivos. La aplicación también hace una comprobación periódica en segundo plano por si se pierde algún evento.
+    Movimiento reducido desactiva las transiciones animadas en el espacio de trabajo de Git.
+
+settings-fonts = Fuentes
+settings-fonts-hint = Deja la familia vacía para usar la predeterminada del sistema. Los cambios se aplican al guardar.
+settings-fonts-ui-font = Fuente de la interfaz
+settings-fonts-ui-size = Tamaño de la interfaz
+settings-fonts-code-font = Fuente del código
+settings-fonts-code-size = Tamaño del código
+settings-fonts-line-height = Altura de línea
+settings-fonts-ligatures = Ligaduras en el código
+settings-fonts-ligatures-hint = Combina secuencias como -> y != en las diferencias y en el editor.
+settings-fonts-invalid-number = Fuentes: { $field } debe ser un número mayor que 0.
+
+## Líneas fijadas
+
+review-pin-select-line = Selecciona una línea de la diferencia para fijarla.
+review-pin-code-lines-only = Solo se pueden fijar líneas de código.
+review-pin-unpinned = Línea desfijada.
+review-pin-pinned = Fijada { $location }.
+review-pin-none = No hay líneas fijadas en esta revisión.
+review-pin-none-visible = Ninguna línea fijada es visible en esta diferencia.
+review-pin-moved = La línea fijada ya no está en la diferencia; se saltó al archivo.
+review-pin-file-missing = El archivo fijado no forma parte de esta diferencia.
+
+## Importación de comentarios
+
+comment-import-disabled = Los comentarios están desactivados para pares de comparación personalizados.
+comment-import-title = Importar comentarios
+comment-import-picker-failed = No se pudo abrir el selector de archivos: { $error }
+comment-import-failed = Error al importar comentarios: { $error }
+comment-import-database-unavailable = La base de datos de comentarios no está disponible en este equipo.
+comment-import-no-repository = No hay ningún repositorio abierto.
+comment-import-empty = No se encontraron comentarios en el archivo seleccionado.
+comment-import-imported = Se importaron { $created } comentarios.
+comment-import-none-matched = Ninguno de los { $unplaced } comentarios coincide con una fila de la diferencia.
+comment-import-partial = Se importaron { $created } comentarios. { $unplaced } necesitan colocación manual.
+comment-import-copied = Comentarios sin colocar copiados.
+
+## Exportación de comentarios
+
+comment-export-empty = Esta revisión no tiene comentarios para exportar.
+comment-export-copied = Comentarios de la revisión copiados como Markdown.
+comment-export-saved = Comentarios de la revisión exportados a { $path }
+comment-export-failed = Error al exportar comentarios: { $error }
+
+## Comentarios de revisión en GitHub
+
+github-review-no-branch = Cambia a la rama del pull request para sincronizar comentarios con GitHub.
+github-review-no-open-comments = Esta revisión no tiene comentarios abiertos para publicar.
+github-review-published = { $published } de { $total } comentarios publicados en el pull request #{ $number }.
+github-review-skipped = { $present } ya estaban en el pull request; { $rejected } no están en una línea de su diff.
+github-review-up-to-date = Aquí no falta ningún comentario del pull request #{ $number }.
+github-review-failed = Error al sincronizar con GitHub: { $error }
+
+## Historial de operaciones
+
+operation-history-title = Historial de operaciones
+operation-history-loading = Cargando operaciones...
+operation-history-restoring = Restaurando operación...
+operation-history-summary = { $count } operación(es) reciente(s). Restaurar mueve HEAD y las ramas locales hacia atrás.
+operation-history-loading-changes = Cargando cambios...
+operation-history-no-changes = Esta operación no cambió nada.
+operation-history-changes = Cambios hechos por esta operación:
+operation-history-oldest = Esta es la operación más antigua del historial.
+operation-history-undo = Deshacer la última operación
+operation-history-restore = Restaurar a esta operación
+operation-history-close = Cerrar
+operation-history-no-description = (sin descripción)
+operation-history-empty = Este repositorio aún no tiene operaciones.
+operation-history-detached-head = HEAD separado
+operation-history-restored = Se restauró { $target } en { $commit }
+operation-history-undo-failed = No se pudo deshacer la operación: { $error }
+operation-history-restore-failed = No se pudo restaurar la operación: { $error }
+
+## Editor de pila
+
+stack-editor-title = Editar pila
+stack-editor-loading = Cargando pila...
+stack-editor-applying = Reescribiendo la rama...
+stack-editor-summary = { $count } commit(s) en { $branch } sobre { $base }, del más antiguo al más reciente. Arrastra los commits para reordenarlos y luego elige, combina con el commit de arriba o descarta cada uno.
+stack-editor-reset = Restablecer
+stack-editor-rewrite = Reescribir rama
+stack-editor-close = Cerrar
+stack-editor-pick = Elegir
+stack-editor-squash = Combinar
+stack-editor-drop = Descartar
+stack-editor-move-up = Subir
+stack-editor-move-down = Bajar
+stack-editor-empty = '{ $branch }' no tiene commits sobre '{ $base }'.
+stack-editor-rewrote = Se reescribió { $branch } con { $count } commit(s)
+stack-editor-failed = No se pudo editar la pila: { $error }
+
+## Tamaño del conjunto de cambios
+
+change-set-title = Conjunto de cambios grande: { $files } archivos, { $lines } líneas cambiadas
+change-set-estimate = { $estimate } de revisión a { $rate } líneas por hora
+change-set-minutes = ~{ $minutes } min
+change-set-hours = ~{ $hours } h { $minutes } min
+change-set-dismiss = Descartar
+change-set-largest = Más grandes: { $files }
+change-set-split-suggestion = Considera dividir la revisión en { $count } partes de { $limit }:
+change-set-split-lines = hasta { $lines } líneas cada una
+change-set-split-files = hasta { $files } archivos cada una
+change-set-split-files-and-lines = hasta { $files } archivos y { $lines } líneas cada una
+change-set-repository-root = (raíz del repositorio)
+change-set-part = Parte { $number }: { $directory } - { $files } archivos, { $lines } líneas, { $estimate }
+change-set-show-all = Mostrar todo
+change-set-review-part = Revisar parte
+
+## Búsqueda en el repositorio
+
+repo-search-title = Buscar en el repositorio
+repo-search-placeholder = Buscar en el contenido de los archivos del repositorio
+repo-search-searching = Buscando...
+repo-search-hint = Busca en archivos con y sin seguimiento. Se omiten los archivos ignorados y binarios.
+repo-search-no-matches = No hay coincidencias para "{ $query }" en { $searched } archivos.
+repo-search-matches = { $matches } coincidencias en { $files } de { $searched } archivos
+repo-search-first-matches = Primeras { $matches } coincidencias en { $files } de { $searched } archivos
+repo-search-close = Cerrar
+
+## Menú contextual de la revisión
+
+context-menu-left-to-right = Mostrar archivo de izquierda a derecha
+context-menu-right-to-left = Mostrar archivo de derecha a izquierda
+context-menu-stage-hunk = Preparar bloque
+context-menu-unstage-hunk = Quitar bloque del área de preparación
+git-staged-hunk = Bloque de { $path } preparado
+git-unstaged-hunk = Bloque de { $path } quitado del área de preparación
+
+## Comparación de revisiones
+
+review-compare-revision = Revisión
+review-compare-review-revision = Revisar esta revisión
+
+## Cambios mecánicos
+
+mechanical-change-title-one = Cambio mecánico: { $from } → { $to } en { $files } archivo
+mechanical-change-title = Cambio mecánico: { $from } → { $to } en { $files } archivos
+mechanical-change-approved = Aprobado, archivos contraídos
+mechanical-change-lines = { $lines } líneas cambiadas
+mechanical-change-undo-approval = Deshacer aprobación
+mechanical-change-approve-all = Aprobar todo
+
+## Ajustes de atajos de teclado
+
+settings-shortcuts-description = Edita las listas de atajos separadas por comas de cada acción. Usa espacios para las secuencias de teclas.
+settings-vim-mode = Modo Vim
+settings-vim-mode-hint = En la vista de revisión: j/k mueven filas, { y } saltan entre bloques, [ y ] saltan entre archivos, v inicia una selección de filas, y la copia y / busca. Los atajos de abajo siguen funcionando en ambos casos.
+settings-shortcuts-footer = Usa comas para añadir alternativas, espacios para secuencias de teclas y cmd-, tal cual para la tecla coma.
+settings-shortcut-select-next-line = Seleccionar la línea siguiente
+settings-shortcut-select-next-line-hint = Mueve la selección una fila del diff hacia abajo.
+settings-shortcut-select-previous-line = Seleccionar la línea anterior
+settings-shortcut-select-previous-line-hint = Mueve la selección una fila del diff hacia arriba.
+settings-shortcut-extend-selection-next-line = Extender la selección hacia abajo
+settings-shortcut-extend-selection-next-line-hint = Extiende la selección de varias filas hacia abajo.
+settings-shortcut-extend-selection-previous-line = Extender la selección hacia arriba
+settings-shortcut-extend-selection-previous-line-hint = Extiende la selección de varias filas hacia arriba.
+settings-shortcut-copy-selection = Copiar la selección
+settings-shortcut-copy-selection-hint = Copia las filas del diff seleccionadas.
+settings-shortcut-select-all-diff-rows = Seleccionar todas las filas del diff
+settings-shortcut-select-all-diff-rows-hint = Selecciona todas las filas del diff actual.
+settings-shortcut-next-hunk = Bloque siguiente
+settings-shortcut-next-hunk-hint = Salta al siguiente bloque del diff.
+settings-shortcut-previous-hunk = Bloque anterior
+settings-shortcut-previous-hunk-hint = Salta al bloque anterior del diff.
+settings-shortcut-next-file = Archivo siguiente
+settings-shortcut-next-file-hint = Pasa al siguiente archivo cambiado.
+settings-shortcut-previous-file = Archivo anterior
+settings-shortcut-previous-file-hint = Pasa al archivo cambiado anterior.
+settings-shortcut-view-current-review-file = Ver archivo de la revisión
+settings-shortcut-view-current-review-file-hint = Abre el archivo seleccionado de la revisión en la vista Archivos.
+settings-shortcut-toggle-review-pin = Fijar fila
+settings-shortcut-toggle-review-pin-hint = Fija o desfija la fila del diff seleccionada.
+settings-shortcut-next-review-pin = Fijación siguiente
+settings-shortcut-next-review-pin-hint = Salta a la siguiente fila fijada del diff.
+settings-shortcut-previous-review-pin = Fijación anterior
+settings-shortcut-previous-review-pin-hint = Salta a la fila fijada anterior del diff.
+settings-shortcut-toggle-sidebar-tree = Mostrar u ocultar el árbol de archivos
+settings-shortcut-toggle-sidebar-tree-hint = Contrae o expande el panel izquierdo del árbol de archivos.
+settings-shortcut-toggle-focus-mode = Alternar modo de enfoque
+settings-shortcut-toggle-focus-mode-hint = Maximiza el panel del diff, del editor o del historial de commits.
+settings-shortcut-switch-to-files-view = Cambiar a la vista Archivos
+settings-shortcut-switch-to-files-view-hint = Cambia el espacio de trabajo a la vista de edición de archivos.
+settings-shortcut-switch-to-review-view = Cambiar a la vista Revisión
+settings-shortcut-switch-to-review-view-hint = Cambia el espacio de trabajo a la revisión del diff lado a lado.
+settings-shortcut-switch-to-git-view = Cambiar a la vista Git
+settings-shortcut-switch-to-git-view-hint = Cambia el espacio de trabajo a la vista del flujo de trabajo de Git.
+settings-shortcut-toggle-ai-terminal-drawer = Mostrar u ocultar la terminal
+settings-shortcut-toggle-ai-terminal-drawer-hint = Abre o cierra la terminal en las vistas IA y Archivos.
+settings-shortcut-open-project = Abrir proyecto
+settings-shortcut-open-project-hint = Abre el selector de proyectos del sistema.
+settings-shortcut-open-path-from-clipboard = Abrir ruta del portapapeles
+settings-shortcut-open-path-from-clipboard-hint = Salta a una ruta:línea del repositorio copiada de registros o de la salida de CI.
+settings-shortcut-search-repository = Buscar en el repositorio
+settings-shortcut-search-repository-hint = Busca en el contenido de todos los archivos del repositorio.
+settings-shortcut-save-current-file = Guardar el archivo actual
+settings-shortcut-save-current-file-hint = Guarda el búfer del editor de archivos activo.
+settings-shortcut-next-editor-tab = Pestaña siguiente del editor
+settings-shortcut-next-editor-tab-hint = Pasa a la siguiente pestaña de archivo abierta en la vista Archivos.
+settings-shortcut-previous-editor-tab = Pestaña anterior del editor
+settings-shortcut-previous-editor-tab-hint = Pasa a la pestaña de archivo anterior en la vista Archivos.
+settings-shortcut-close-editor-tab = Cerrar pestaña del editor
+settings-shortcut-close-editor-tab-hint = Cierra la pestaña de archivo activa en la vista Archivos.
+settings-shortcut-open-settings = Abrir configuración
+settings-shortcut-open-settings-hint = Abre esta ventana de configuración.
+settings-shortcut-quit-app = Salir de la aplicación
+settings-shortcut-quit-app-hint = Cierra Hunk.
+settings-shortcut-repo-tree-new-file = Árbol: nuevo archivo
+settings-shortcut-repo-tree-new-file-hint = Crea un archivo desde el árbol de archivos enfocado.
+settings-shortcut-repo-tree-new-folder = Árbol: nueva carpeta
+settings-shortcut-repo-tree-new-folder-hint = Crea una carpeta desde el árbol de archivos enfocado.
+settings-shortcut-repo-tree-rename-file = Árbol: renombrar archivo
+settings-shortcut-repo-tree-rename-file-hint = Renombra el archivo seleccionado en el árbol de archivos enfocado.
+
+## Ajustes de gestos
+
+settings-gestures-description = Qué hacen el doble clic y el clic central en la revisión.
+settings-gestures-double-click = Doble clic en una fila del diff
+settings-gestures-double-click-hint = Abre el archivo en el editor en esa línea o inicia un comentario en ella.
+settings-gestures-middle-click = Clic central en un archivo
+settings-gestures-middle-click-hint = Se aplica a los archivos del árbol y a los encabezados de archivo en la revisión.
+settings-gesture-select-line = Seleccionar línea
+settings-gesture-open-in-editor = Abrir en el editor
+settings-gesture-add-comment = Añadir comentario
+settings-gesture-nothing = Nada
+settings-gesture-collapse-file = Contraer archivo
+settings-gesture-pin-file = Fijar archivo
+
+## Árbol de archivos
+
+tree-clear-folder-reviewed = Quitar carpeta revisada
+tree-mark-folder-reviewed = Marcar carpeta como revisada
+
+## Abrir ruta del portapapeles
+
+clipboard-path-none = El portapapeles no menciona ningún archivo de este repositorio.
+clipboard-path-found = El portapapeles menciona { $path }.
+clipboard-path-open = Abrir
+
+## Registro de CI
+
+ci-log-title = Registro de CI
+ci-log-close = Cerrar
+ci-log-placeholder = Pega la salida de una ejecución de CI fallida
+ci-log-hint = Busca las pruebas fallidas y las ubicaciones de archivos que menciona el registro.
+ci-log-empty = No se encontraron pruebas ni archivos del repositorio en este registro.
+ci-log-summary = { $count } referencias, { $in_change } en este cambio
+ci-log-find-references = Buscar referencias
+ci-log-in-change = En el cambio
+ci-log-test-not-in-diff = { $test } no está definido en el diff actual.
+
+## Barra de herramientas de la revisión
+
+toolbar-diff-unified = Unificada
+toolbar-diff-side-by-side = Lado a lado
+toolbar-diff-unified-tooltip = Muestra el diff en una sola columna intercalada, como git diff
+toolbar-comment-counts = Comentarios ({ $open } abiertos, { $resolved } resueltos)
+toolbar-export-review = Exportar revisión
+toolbar-export-review-tooltip = Exporta los comentarios o sincronízalos con la pull request de GitHub
+toolbar-export-copy-markdown = Copiar como Markdown
+toolbar-export-save-markdown = Guardar como Markdown...
+toolbar-export-save-json = Guardar como JSON...
+toolbar-github-publish = Publicar en la pull request de GitHub
+toolbar-github-pull = Traer comentarios de revisión de GitHub
+
+## Almacenamiento de comentarios
+
+comment-database-locked = Otra instancia de Hunk tiene bloqueada la base de datos de comentarios. Inténtalo de nuevo en un momento.
+comment-save-failed = No se pudo guardar el comentario.
+comment-delete-failed = No se pudo eliminar el comentario.
+comment-reopen-failed = No se pudo reabrir el comentario.
+
+## Ajustes de formato
+
+settings-formatting-description = Formateadores externos para el editor de archivos. Cada comando lee el archivo por stdin e imprime el archivo formateado; {path} se sustituye por la ruta del archivo.
+settings-formatting-format-on-save = Formatear al guardar
+settings-formatting-format-on-save-hint = Ejecuta el formateador del archivo antes de escribirlo. Los errores del formateador se muestran en el editor y el archivo se guarda igualmente.
+settings-formatting-not-formatted = Sin formatear
+settings-formatting-footer = Borra un comando para dejar de formatear esos lenguajes. Los formateadores de otros lenguajes se pueden añadir en config.toml.
+
+## Formato en el editor
+
+editor-format = Formatear
+editor-format-tooltip = Formatear archivo
+editor-format-no-file = No hay ningún archivo abierto en el editor.
+editor-format-no-formatter = No hay ningún formateador configurado para { $path }.
+editor-format-blocked = Formateo bloqueado para { $path }: { $error }
+editor-format-done = Se formateó { $path }
+editor-format-unchanged = { $path } ya está formateado
+editor-format-skipped = Se omitió el formateo de { $path } porque cambió mientras se formateaba.
+editor-format-failed = Falló el formateo de { $path }
+
+## Detalles del commit
+
+commit-details-open = Detalles
+commit-details-title = Commit { $commit }
+commit-details-loading = Cargando commit...
+commit-details-summary = { $count } archivo(s) cambiado(s) respecto al primer padre.
+commit-details-close = Cerrar
+commit-details-no-parents = ninguno (commit raíz)
+commit-details-commit = Commit
+commit-details-parents = Padres
+commit-details-author = Autor
+commit-details-committer = Confirmador
+commit-details-change-id = Change-Id
+commit-details-no-text-changes = No hay cambios de texto que mostrar.
+commit-details-more-lines = ...y { $count } línea(s) más
+commit-details-patch-truncated = Parche truncado.
+
+## Recuperación del editor
+
+editor-recovery-prompt = Se recuperaron cambios sin guardar de este archivo, escritos por última vez { $time }.
+editor-recovery-discard = Descartar
+editor-recovery-restore = Restaurar
+editor-recovery-restored = Se restauraron los cambios sin guardar de { $path }
+
+## Modo de enfoque
+
+focus-mode-bar = Modo de enfoque: { $pane }
+focus-mode-exit = Salir del modo de enfoque
+focus-mode-exit-shortcut = Salir del modo de enfoque ({ $shortcut })
+focus-mode-pane-editor = Editor
+focus-mode-pane-diff = Diff
+focus-mode-pane-commit-history = Historial de commits
+
+## Ajustes de notificaciones
+
+settings-notifications-description = Notificaciones del sistema para eventos en segundo plano mientras Hunk no está al frente, y recordatorios para tomar un descanso durante revisiones largas.
+settings-notifications-enabled = Notificaciones de escritorio
+settings-notifications-enabled-hint = Muestra notificaciones del sistema para los eventos activados abajo.
+settings-notifications-ci-finished = CI terminada
+settings-notifications-ci-finished-hint = Tras un push, avisa cuando terminan las ejecuciones de GitHub Actions del commit (requiere gh).
+settings-notifications-remote-branch-moved = Rama remota movida
+settings-notifications-remote-branch-moved-hint = Avisa cuando otra persona hace push al upstream de la rama actual.
+settings-notifications-comment-mentions = Menciones en comentarios
+settings-notifications-comment-mentions-hint = Avisa de comentarios nuevos de agentes o correvisores que @mencionan tu nombre de correvisión.
+settings-review-timer = Temporizador de revisión
+settings-review-timer-hint = Muestra en la barra de estado el tiempo activo en la vista de revisión. El tiempo fuera de la ventana no cuenta.
+settings-review-break-reminder = Recordatorio de descanso
+settings-review-break-reminder-hint = Sugiere un descanso tras este tiempo de revisión. Cinco minutos fuera cuentan como descanso.
+settings-review-break-minutes = { $minutes } minutos
+
+## Temporizador de revisión
+
+review-timer-running = Revisión { $elapsed }
+review-timer-paused = Revisión { $elapsed } (en pausa)
+review-timer-tooltip = Tiempo de revisión activo. Haz clic para iniciar una sesión nueva.
+review-timer-break = Llevas { $elapsed } revisando sin descanso. Aléjate unos minutos para descansar la vista.
+
+## Columnas del diff
+
+diff-column-old = ANTES
+diff-column-new = DESPUÉS
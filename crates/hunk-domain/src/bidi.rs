@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use unicode_bidi::{BidiClass, BidiInfo, Level, bidi_class};
+use unicode_bidi_mirroring::get_mirrored;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A stretch of a line laid out in one direction, as a byte range into the logical text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualRun {
+    pub range: Range<usize>,
+    pub direction: TextDirection,
+}
+
+/// One character of a reordered line and the byte offset it came from in the logical text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisualChar {
+    pub ch: char,
+    pub logical_offset: usize,
+}
+
+pub fn has_right_to_left_text(text: &str) -> bool {
+    text.chars().any(|ch| {
+        matches!(
+            bidi_class(ch),
+            BidiClass::R | BidiClass::AL | BidiClass::RLE | BidiClass::RLO | BidiClass::RLI
+        )
+    })
+}
+
+/// Runs of `text` in screen order, left to right, resolved with the Unicode bidirectional
+/// algorithm from the `base` direction. Returns `None` for lines that need no reordering so
+/// callers can draw them as they are.
+pub fn visual_runs(text: &str, base: TextDirection) -> Option<Vec<VisualRun>> {
+    if text.is_empty() || (base == TextDirection::LeftToRight && !has_right_to_left_text(text)) {
+        return None;
+    }
+    let base_level = match base {
+        TextDirection::LeftToRight => Level::ltr(),
+        TextDirection::RightToLeft => Level::rtl(),
+    };
+    let info = BidiInfo::new(text, Some(base_level));
+    let mut runs = Vec::new();
+    for paragraph in &info.paragraphs {
+        let (levels, level_runs) = info.visual_runs(paragraph, paragraph.range.clone());
+        runs.extend(level_runs.into_iter().map(|range| VisualRun {
+            direction: if levels[range.start].is_rtl() {
+                TextDirection::RightToLeft
+            } else {
+                TextDirection::LeftToRight
+            },
+            range,
+        }));
+    }
+    Some(runs)
+}
+
+/// The characters of `text` in the screen order given by `runs`. Right-to-left runs are
+/// reversed with combining marks kept after their base character, and mirrored characters such
+/// as brackets are swapped for their counterpart.
+pub fn visual_chars(text: &str, runs: &[VisualRun]) -> Vec<VisualChar> {
+    let mut chars = Vec::with_capacity(text.len());
+    for run in runs {
+        let Some(run_text) = text.get(run.range.clone()) else {
+            continue;
+        };
+        let run_chars = run_text.char_indices().map(|(offset, ch)| VisualChar {
+            ch,
+            logical_offset: run.range.start + offset,
+        });
+        if run.direction == TextDirection::LeftToRight {
+            chars.extend(run_chars);
+            continue;
+        }
+
+        let mut clusters: Vec<Vec<VisualChar>> = Vec::new();
+        for visual_char in run_chars {
+            match clusters.last_mut() {
+                Some(cluster) if bidi_class(visual_char.ch) == BidiClass::NSM => {
+                    cluster.push(visual_char);
+                }
+                _ => clusters.push(vec![visual_char]),
+            }
+        }
+        for cluster in clusters.into_iter().rev() {
+            chars.extend(cluster.into_iter().map(|visual_char| VisualChar {
+                ch: get_mirrored(visual_char.ch).unwrap_or(visual_char.ch),
+                ..visual_char
+            }));
+        }
+    }
+    chars
+}
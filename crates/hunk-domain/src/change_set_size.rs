@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+
+use crate::config::ReviewBudgetConfig;
+
+/// Files listed as the largest contributors of an oversized change set.
+pub const CHANGE_SET_LARGEST_CONTRIBUTORS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSetContributor {
+    pub path: String,
+    pub changed_lines: u64,
+}
+
+/// One review-sized slice of a proposed split, made of whole directories where they fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSetPart {
+    /// Deepest directory shared by every file of the part, empty at the repository root.
+    pub directory: String,
+    pub paths: Vec<String>,
+    pub changed_lines: u64,
+    pub estimated_review_minutes: u64,
+}
+
+/// A change set over the review budget, with what makes it large and how to split it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSetSizeWarning {
+    pub files: usize,
+    pub changed_lines: u64,
+    pub estimated_review_minutes: u64,
+    /// Files with the most changed lines, largest first.
+    pub largest: Vec<ChangeSetContributor>,
+    /// Parts that each fit the budget, in path order. Empty when no useful split exists.
+    pub parts: Vec<ChangeSetPart>,
+}
+
+/// Checks `files` (path and changed lines) against `budget`, returning a warning when either
+/// the file count or the changed line count is over. A zero limit is never exceeded.
+pub fn assess_change_set<'a>(
+    files: impl IntoIterator<Item = (&'a str, u64)>,
+    budget: &ReviewBudgetConfig,
+) -> Option<ChangeSetSizeWarning> {
+    let mut files = files
+        .into_iter()
+        .map(|(path, changed_lines)| ChangeSetContributor {
+            path: path.to_string(),
+            changed_lines,
+        })
+        .collect::<Vec<_>>();
+    let changed_lines = files.iter().map(|file| file.changed_lines).sum::<u64>();
+    if fits_budget(files.len(), changed_lines, budget) {
+        return None;
+    }
+
+    files.sort_by(|left, right| left.path.cmp(&right.path));
+    let parts = propose_split(&files, budget);
+    let mut largest = files.clone();
+    largest.sort_by(|left, right| right.changed_lines.cmp(&left.changed_lines));
+    largest.truncate(CHANGE_SET_LARGEST_CONTRIBUTORS);
+
+    Some(ChangeSetSizeWarning {
+        files: files.len(),
+        changed_lines,
+        estimated_review_minutes: estimated_review_minutes(changed_lines, budget),
+        largest,
+        parts,
+    })
+}
+
+/// Minutes to review `changed_lines` at the budget's pace, rounded up to a whole minute.
+pub fn estimated_review_minutes(changed_lines: u64, budget: &ReviewBudgetConfig) -> u64 {
+    (changed_lines * 60).div_ceil(budget.lines_per_hour.max(1))
+}
+
+fn fits_budget(files: usize, changed_lines: u64, budget: &ReviewBudgetConfig) -> bool {
+    (budget.max_files == 0 || files <= budget.max_files)
+        && (budget.max_changed_lines == 0 || changed_lines <= budget.max_changed_lines)
+}
+
+/// Packs directories into parts in path order, starting a new part when the next directory
+/// would push the current one over budget. A directory too large on its own is packed file by
+/// file instead.
+fn propose_split(
+    files: &[ChangeSetContributor],
+    budget: &ReviewBudgetConfig,
+) -> Vec<ChangeSetPart> {
+    let mut directories = BTreeMap::<&str, Vec<&ChangeSetContributor>>::new();
+    for file in files {
+        directories
+            .entry(parent_directory(file.path.as_str()))
+            .or_default()
+            .push(file);
+    }
+
+    let mut parts = Vec::<Vec<&ChangeSetContributor>>::new();
+    let mut current = Vec::new();
+    for directory_files in directories.into_values() {
+        if fits_alongside(&current, &directory_files, budget) {
+            current.extend(directory_files);
+            continue;
+        }
+        if !current.is_empty() {
+            parts.push(std::mem::take(&mut current));
+        }
+        if fits_alongside(&[], &directory_files, budget) {
+            current = directory_files;
+            continue;
+        }
+        for file in directory_files {
+            if !current.is_empty() && !fits_alongside(&current, &[file], budget) {
+                parts.push(std::mem::take(&mut current));
+            }
+            current.push(file);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    if parts.len() < 2 {
+        return Vec::new();
+    }
+
+    parts
+        .into_iter()
+        .map(|files| {
+            let changed_lines = files.iter().map(|file| file.changed_lines).sum();
+            ChangeSetPart {
+                directory: common_directory(&files),
+                paths: files.iter().map(|file| file.path.clone()).collect(),
+                changed_lines,
+                estimated_review_minutes: estimated_review_minutes(changed_lines, budget),
+            }
+        })
+        .collect()
+}
+
+fn fits_alongside(
+    current: &[&ChangeSetContributor],
+    added: &[&ChangeSetContributor],
+    budget: &ReviewBudgetConfig,
+) -> bool {
+    let changed_lines = current
+        .iter()
+        .chain(added)
+        .map(|file| file.changed_lines)
+        .sum();
+    fits_budget(current.len() + added.len(), changed_lines, budget)
+}
+
+fn parent_directory(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(directory, _)| directory)
+}
+
+fn common_directory(files: &[&ChangeSetContributor]) -> String {
+    let mut common = None::<Vec<&str>>;
+    for file in files {
+        let components = parent_directory(file.path.as_str())
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>();
+        common = Some(match common {
+            None => components,
+            Some(common) => common
+                .into_iter()
+                .zip(components)
+                .take_while(|(left, right)| left == right)
+                .map(|(component, _)| component)
+                .collect(),
+        });
+    }
+    common.unwrap_or_default().join("/")
+}
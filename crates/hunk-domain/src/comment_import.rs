@@ -0,0 +1,277 @@
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+
+use crate::db::CommentLineSide;
+
+const CLIPBOARD_BLOCK_HEADER: &str = "[Hunk Comment]";
+
+/// One review comment read from an import file, before it is anchored to a diff row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedComment {
+    pub file_path: String,
+    pub line_side: CommentLineSide,
+    /// Line number on `line_side`. `None` for notes about the whole file, which can't be
+    /// anchored automatically.
+    pub line: Option<u32>,
+    pub text: String,
+    pub author: Option<String>,
+}
+
+impl ImportedComment {
+    /// `path:line` location shown when an entry needs manual placement.
+    pub fn location_label(&self) -> String {
+        match (self.line, self.line_side) {
+            (Some(line), CommentLineSide::Left) => format!("{}:{line} (old)", self.file_path),
+            (Some(line), _) => format!("{}:{line}", self.file_path),
+            (None, _) => self.file_path.clone(),
+        }
+    }
+}
+
+/// Reads review comments from any of the supported formats:
+///
+/// - JSON, either an array of comments or an object with a `comments` array, using the field
+///   names the JSON-RPC server returns (`path`, `side`, `oldLine`, `newLine`, `text`, `author`).
+/// - The `[Hunk Comment]` bundles produced by "Copy" and "Copy All Open".
+/// - A plain `path:line: comment` list, one per line, as emitted by linters and LLM reviews.
+///   Markdown bullets and backticks around the location are ignored, and indented lines
+///   continue the previous comment.
+pub fn parse_comment_import(contents: &str) -> Result<Vec<ImportedComment>> {
+    let trimmed = contents.trim_start();
+    if trimmed.starts_with('{')
+        || (trimmed.starts_with('[') && !trimmed.starts_with(CLIPBOARD_BLOCK_HEADER))
+    {
+        return parse_json_comments(trimmed);
+    }
+    if contents.contains(CLIPBOARD_BLOCK_HEADER) {
+        return Ok(parse_clipboard_blocks(contents));
+    }
+    Ok(parse_line_list(contents))
+}
+
+/// Reads pull request review comments from the GitHub REST API, given either as an array or as
+/// one object per line. Comments on lines that are no longer in the diff come back without a
+/// line and need manual placement.
+pub fn parse_github_review_comments(contents: &str) -> Result<Vec<ImportedComment>> {
+    let trimmed = contents.trim();
+    let entries = if trimmed.starts_with('[') {
+        serde_json::from_str::<Vec<Value>>(trimmed)
+            .map_err(|err| anyhow!("invalid GitHub review comments: {err}"))?
+    } else {
+        trimmed
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<Value>(line)
+                    .map_err(|err| anyhow!("invalid GitHub review comment: {err}"))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| {
+            let file_path = entry.get("path")?.as_str()?.to_string();
+            let text = entry.get("body")?.as_str()?.trim().to_string();
+            if text.is_empty() {
+                return None;
+            }
+            let line_side = match entry.get("side").and_then(Value::as_str) {
+                Some("LEFT") => CommentLineSide::Left,
+                _ => CommentLineSide::Right,
+            };
+            Some(ImportedComment {
+                file_path,
+                line_side,
+                line: entry
+                    .get("line")
+                    .and_then(Value::as_u64)
+                    .and_then(|line| u32::try_from(line).ok()),
+                text,
+                author: entry
+                    .get("user")
+                    .and_then(|user| user.get("login"))
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned),
+            })
+        })
+        .collect())
+}
+
+fn parse_json_comments(contents: &str) -> Result<Vec<ImportedComment>> {
+    let value: Value =
+        serde_json::from_str(contents).map_err(|err| anyhow!("invalid JSON review file: {err}"))?;
+    let entries = match &value {
+        Value::Array(entries) => entries,
+        Value::Object(object) => object
+            .get("comments")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow!("JSON review file has no `comments` array"))?,
+        _ => return Err(anyhow!("JSON review file must be an array or object")),
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(ix, entry)| {
+            json_comment(entry).ok_or_else(|| {
+                anyhow!(
+                    "JSON comment {} needs a `path` and a non-empty `text`",
+                    ix + 1
+                )
+            })
+        })
+        .collect()
+}
+
+fn json_comment(entry: &Value) -> Option<ImportedComment> {
+    let field = |names: &[&str]| names.iter().find_map(|name| entry.get(*name));
+    let text_field = |names: &[&str]| {
+        field(names)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned)
+    };
+    let line_field = |names: &[&str]| {
+        field(names)
+            .and_then(Value::as_u64)
+            .and_then(|line| u32::try_from(line).ok())
+    };
+
+    let file_path = text_field(&["path", "file", "filePath"])?;
+    let text = text_field(&["text", "body", "comment"])?;
+    let old_line = line_field(&["oldLine"]);
+    let new_line = line_field(&["newLine", "line"]);
+    let (line_side, line) = match field(&["side"]).and_then(Value::as_str) {
+        Some("old" | "left") => (CommentLineSide::Left, old_line.or(new_line)),
+        _ => match new_line {
+            Some(line) => (CommentLineSide::Right, Some(line)),
+            None => (CommentLineSide::Left, old_line),
+        },
+    };
+
+    Some(ImportedComment {
+        file_path,
+        line_side,
+        line,
+        text,
+        author: text_field(&["author"]),
+    })
+}
+
+fn parse_clipboard_blocks(contents: &str) -> Vec<ImportedComment> {
+    contents
+        .split(CLIPBOARD_BLOCK_HEADER)
+        .skip(1)
+        .filter_map(|block| {
+            let mut file_path = None;
+            let mut old_line = None;
+            let mut new_line = None;
+            let mut text_lines = Vec::new();
+            let mut in_comment = false;
+            for line in block.lines() {
+                if in_comment {
+                    if line == "Snippet:" {
+                        break;
+                    }
+                    text_lines.push(line);
+                } else if let Some(path) = line.strip_prefix("File: ") {
+                    file_path = Some(path.trim().to_string());
+                } else if let Some(lines) = line.strip_prefix("Lines: ") {
+                    let (old, new) = lines.split_once('|').unwrap_or((lines, ""));
+                    old_line = clipboard_line_number(old, "old");
+                    new_line = clipboard_line_number(new, "new");
+                } else if line == "Comment:" {
+                    in_comment = true;
+                }
+            }
+
+            let text = text_lines.join("\n").trim().to_string();
+            if text.is_empty() {
+                return None;
+            }
+            let (line_side, line) = match new_line {
+                Some(line) => (CommentLineSide::Right, Some(line)),
+                None => (CommentLineSide::Left, old_line),
+            };
+            Some(ImportedComment {
+                file_path: file_path?,
+                line_side,
+                line,
+                text,
+                author: None,
+            })
+        })
+        .collect()
+}
+
+fn clipboard_line_number(value: &str, label: &str) -> Option<u32> {
+    value.trim().strip_prefix(label)?.trim().parse().ok()
+}
+
+fn parse_line_list(contents: &str) -> Vec<ImportedComment> {
+    let mut comments = Vec::<ImportedComment>::new();
+    let mut continues_previous = false;
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            continues_previous = false;
+            continue;
+        }
+        if continues_previous && line.starts_with([' ', '\t']) {
+            if let Some(previous) = comments.last_mut() {
+                previous.text.push('\n');
+                previous.text.push_str(line.trim());
+            }
+            continue;
+        }
+
+        continues_previous = false;
+        if let Some(comment) = parse_line_list_entry(line) {
+            comments.push(comment);
+            continues_previous = true;
+        }
+    }
+    comments
+}
+
+/// Parses `path:line: comment`, also accepting `path:line:column: comment`.
+fn parse_line_list_entry(line: &str) -> Option<ImportedComment> {
+    let line = line
+        .trim()
+        .trim_start_matches(['-', '*'])
+        .trim_start()
+        .trim_start_matches('`');
+    for (colon_ix, _) in line.match_indices(':') {
+        let file_path = line[..colon_ix].trim();
+        let rest = &line[colon_ix + 1..];
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if file_path.is_empty() || file_path.contains(char::is_whitespace) || digits == 0 {
+            continue;
+        }
+        let Ok(line_number) = rest[..digits].parse::<u32>() else {
+            continue;
+        };
+
+        let mut rest = &rest[digits..];
+        if let Some(column) = rest.strip_prefix(':') {
+            let column_digits = column.bytes().take_while(u8::is_ascii_digit).count();
+            if column_digits > 0 && column[column_digits..].starts_with(':') {
+                rest = &column[column_digits..];
+            }
+        }
+        let rest = rest.trim_start_matches('`');
+        let text = rest.strip_prefix(':')?.trim();
+        if text.is_empty() {
+            return None;
+        }
+        return Some(ImportedComment {
+            file_path: file_path.to_string(),
+            line_side: CommentLineSide::Right,
+            line: Some(line_number),
+            text: text.to_string(),
+            author: None,
+        });
+    }
+    None
+}
@@ -4,13 +4,53 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context as _, Result, anyhow};
 use serde::{Deserialize, Serialize};
 
+use crate::l10n::Language;
+
 const CONFIG_FILE_NAME: &str = "config.toml";
 const DEFAULT_AUTO_REFRESH_INTERVAL_MS: u64 = 60_000;
+const DEFAULT_GIT_NETWORK_TIMEOUT_SECS: u64 = 300;
+const DEFAULT_CO_REVIEW_PORT: u16 = 47321;
+const DEFAULT_REVIEW_BREAK_REMINDER_MINUTES: u64 = 50;
+const DEFAULT_REVIEW_BUDGET_MAX_FILES: usize = 50;
+const DEFAULT_REVIEW_BUDGET_MAX_CHANGED_LINES: u64 = 1_000;
+const DEFAULT_REVIEW_BUDGET_LINES_PER_HOUR: u64 = 400;
+const DEFAULT_LARGE_HUNK_FOLD_LINES: usize = 200;
+const DEFAULT_HEX_DIFF_MAX_BYTES: usize = 16 * 1024;
+const DEFAULT_COMMENT_RETENTION_DAYS: i64 = 14;
+const DEFAULT_STALE_COMMENT_RETENTION_DAYS: i64 = 30;
+const DEFAULT_CHANGELOG_FRAGMENT_PATH: &str = "changelog.d/{branch}.md";
+const DEFAULT_UI_FONT_SIZE: f32 = 14.0;
+const DEFAULT_MONO_FONT_SIZE: f32 = 12.0;
+const DEFAULT_LINE_HEIGHT: f32 = 1.45;
+const FONT_SIZE_RANGE: (f32, f32) = (9.0, 24.0);
+const LINE_HEIGHT_RANGE: (f32, f32) = (1.0, 2.0);
 
 pub const fn default_auto_refresh_interval_ms() -> u64 {
     DEFAULT_AUTO_REFRESH_INTERVAL_MS
 }
 
+/// Hunks with more lines than this render folded in review; `0` keeps every hunk expanded.
+pub const fn default_large_hunk_fold_lines() -> usize {
+    DEFAULT_LARGE_HUNK_FOLD_LINES
+}
+
+/// Binary files up to this many bytes show a hex dump diff in review; `0` keeps the plain
+/// "Binary files differ" notice.
+pub const fn default_hex_diff_max_bytes() -> usize {
+    DEFAULT_HEX_DIFF_MAX_BYTES
+}
+
+/// Resolved comments older than this many days are pruned from the database.
+pub const fn default_comment_retention_days() -> i64 {
+    DEFAULT_COMMENT_RETENTION_DAYS
+}
+
+/// Stale comments lost their anchor rather than being settled, so they are kept longer than
+/// resolved ones in case the code they point at comes back.
+pub const fn default_stale_comment_retention_days() -> i64 {
+    DEFAULT_STALE_COMMENT_RETENTION_DAYS
+}
+
 pub const fn default_terminal_hydrate_app_environment_on_launch() -> bool {
     !cfg!(target_os = "windows")
 }
@@ -69,6 +109,317 @@ impl Default for TerminalConfig {
     }
 }
 
+/// Per-operation time limits for Git network actions, in seconds. `0` disables the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitTimeoutConfig {
+    pub push_secs: u64,
+    pub sync_secs: u64,
+}
+
+impl Default for GitTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            push_secs: DEFAULT_GIT_NETWORK_TIMEOUT_SECS,
+            sync_secs: DEFAULT_GIT_NETWORK_TIMEOUT_SECS,
+        }
+    }
+}
+
+/// OS notifications for background events. Nothing is shown until `enabled` is set; the
+/// per-event flags then pick which events are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesktopNotificationsConfig {
+    pub enabled: bool,
+    pub ci_finished: bool,
+    pub remote_branch_moved: bool,
+    pub comment_mentions: bool,
+}
+
+impl Default for DesktopNotificationsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ci_finished: true,
+            remote_branch_moved: true,
+            comment_mentions: true,
+        }
+    }
+}
+
+/// Review session timer shown in the status bar. It counts active review time, pausing while
+/// the window is unfocused, and reminds you to take a break after every
+/// `break_reminder_minutes` without one. Zero minutes turns the reminder off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewTimerConfig {
+    pub enabled: bool,
+    pub break_reminder_minutes: u64,
+}
+
+impl Default for ReviewTimerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            break_reminder_minutes: DEFAULT_REVIEW_BREAK_REMINDER_MINUTES,
+        }
+    }
+}
+
+/// Size past which a change set is flagged as too large to review in one sitting. Either
+/// limit set to zero is ignored. `lines_per_hour` is the pace used for review time estimates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewBudgetConfig {
+    pub max_files: usize,
+    pub max_changed_lines: u64,
+    pub lines_per_hour: u64,
+}
+
+impl Default for ReviewBudgetConfig {
+    fn default() -> Self {
+        Self {
+            max_files: DEFAULT_REVIEW_BUDGET_MAX_FILES,
+            max_changed_lines: DEFAULT_REVIEW_BUDGET_MAX_CHANGED_LINES,
+            lines_per_hour: DEFAULT_REVIEW_BUDGET_LINES_PER_HOUR,
+        }
+    }
+}
+
+/// Team branch naming convention. A blank `template` leaves branch names unchecked;
+/// `user_initials` fills `{user}` when the branch name is auto-filled from the template.
+/// `protected_branches` are committed to but never pushed by the combined Commit & Push; a `*`
+/// in one of them matches within one path segment, as in `release/*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BranchNamingConfig {
+    pub template: String,
+    pub user_initials: String,
+    pub protected_branches: Vec<String>,
+}
+
+/// Tracker ticket links for branch names and commit messages.
+///
+/// `patterns` are regexes for ticket ids and default to Jira/Linear style `ABC-123` ids when
+/// empty. `url_template` links an id by replacing `{ticket}`. With
+/// `insert_into_commit_messages`, commits are prefixed with the current branch's ticket id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TicketLinksConfig {
+    pub patterns: Vec<String>,
+    pub url_template: String,
+    pub insert_into_commit_messages: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangelogFormat {
+    #[default]
+    KeepAChangelog,
+    Towncrier,
+}
+
+/// Changelog fragments generated from the current branch's commits. `fragment_path` is
+/// relative to the working copy; `{branch}` expands to the branch name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangelogConfig {
+    pub format: ChangelogFormat,
+    pub fragment_path: String,
+}
+
+impl Default for ChangelogConfig {
+    fn default() -> Self {
+        Self {
+            format: ChangelogFormat::default(),
+            fragment_path: DEFAULT_CHANGELOG_FRAGMENT_PATH.to_string(),
+        }
+    }
+}
+
+/// What double-clicking a code row in the review does. The first click always selects it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffRowDoubleClickAction {
+    #[default]
+    SelectLine,
+    OpenInEditor,
+    AddComment,
+}
+
+/// What middle-clicking a changed file in the tree or its header in the review does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileMiddleClickAction {
+    #[default]
+    Nothing,
+    Collapse,
+    Pin,
+}
+
+/// Mouse gestures on review rows and files. The defaults keep plain selection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GestureConfig {
+    pub diff_row_double_click: DiffRowDoubleClickAction,
+    pub file_middle_click: FileMiddleClickAction,
+}
+
+/// An external formatter for the file editor. The buffer is piped to `program` on stdin and
+/// its stdout replaces the buffer. `{path}` in `args` expands to the file's absolute path.
+/// `languages` are the editor language names the formatter handles, such as `rust`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatterCommand {
+    pub languages: Vec<String>,
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl FormatterCommand {
+    fn new(languages: &[&str], program: &str, args: &[&str]) -> Self {
+        Self {
+            languages: languages
+                .iter()
+                .map(|language| language.to_string())
+                .collect(),
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// The program and arguments as one space-separated line, for editing in settings.
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Replaces the program and arguments from a space-separated line. A blank line leaves
+    /// the languages without a formatter.
+    pub fn set_command_line(&mut self, line: &str) {
+        let mut parts = line.split_whitespace().map(ToOwned::to_owned);
+        self.program = parts.next().unwrap_or_default();
+        self.args = parts.collect();
+    }
+}
+
+/// Formatting in the file editor: the formatter per language and whether saving runs it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormattingConfig {
+    pub format_on_save: bool,
+    pub formatters: Vec<FormatterCommand>,
+}
+
+impl Default for FormattingConfig {
+    fn default() -> Self {
+        Self {
+            format_on_save: false,
+            formatters: vec![
+                FormatterCommand::new(&["rust"], "rustfmt", &["--edition", "2024"]),
+                FormatterCommand::new(
+                    &[
+                        "javascript",
+                        "typescript",
+                        "tsx",
+                        "json",
+                        "css",
+                        "html",
+                        "markdown",
+                        "yaml",
+                    ],
+                    "prettier",
+                    &["--stdin-filepath", "{path}"],
+                ),
+                FormatterCommand::new(
+                    &["python"],
+                    "black",
+                    &["--quiet", "--stdin-filename", "{path}", "-"],
+                ),
+            ],
+        }
+    }
+}
+
+impl FormattingConfig {
+    /// The first formatter with a program that handles `language`.
+    pub fn formatter_for_language(&self, language: &str) -> Option<&FormatterCommand> {
+        self.formatters.iter().find(|formatter| {
+            !formatter.program.trim().is_empty()
+                && formatter.languages.iter().any(|name| name == language)
+        })
+    }
+}
+
+/// Fonts for the app chrome and for code in review and the file editor. An empty family keeps
+/// the platform default. Sizes are in pixels and `line_height` is a multiple of the font size.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FontConfig {
+    pub ui_font_family: String,
+    pub ui_font_size: f32,
+    pub mono_font_family: String,
+    pub mono_font_size: f32,
+    pub mono_ligatures: bool,
+    pub line_height: f32,
+}
+
+impl Default for FontConfig {
+    fn default() -> Self {
+        Self {
+            ui_font_family: String::new(),
+            ui_font_size: DEFAULT_UI_FONT_SIZE,
+            mono_font_family: String::new(),
+            mono_font_size: DEFAULT_MONO_FONT_SIZE,
+            mono_ligatures: true,
+            line_height: DEFAULT_LINE_HEIGHT,
+        }
+    }
+}
+
+impl FontConfig {
+    /// Trims the family names and clamps sizes into the range the layout can render. Values
+    /// that are not numbers fall back to the defaults.
+    pub fn normalized(&self) -> Self {
+        let clamp = |value: f32, (min, max): (f32, f32), default: f32| {
+            if value.is_finite() {
+                value.clamp(min, max)
+            } else {
+                default
+            }
+        };
+        Self {
+            ui_font_family: self.ui_font_family.trim().to_string(),
+            ui_font_size: clamp(self.ui_font_size, FONT_SIZE_RANGE, DEFAULT_UI_FONT_SIZE),
+            mono_font_family: self.mono_font_family.trim().to_string(),
+            mono_font_size: clamp(self.mono_font_size, FONT_SIZE_RANGE, DEFAULT_MONO_FONT_SIZE),
+            mono_ligatures: self.mono_ligatures,
+            line_height: clamp(self.line_height, LINE_HEIGHT_RANGE, DEFAULT_LINE_HEIGHT),
+        }
+    }
+}
+
+/// Live co-review settings. An empty `display_name` falls back to the OS user name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoReviewConfig {
+    pub display_name: String,
+    pub port: u16,
+}
+
+impl Default for CoReviewConfig {
+    fn default() -> Self {
+        Self {
+            display_name: String::new(),
+            port: DEFAULT_CO_REVIEW_PORT,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct KeyboardShortcuts {
@@ -83,7 +434,11 @@ pub struct KeyboardShortcuts {
     pub next_file: Vec<String>,
     pub previous_file: Vec<String>,
     pub view_current_review_file: Vec<String>,
+    pub toggle_review_pin: Vec<String>,
+    pub next_review_pin: Vec<String>,
+    pub previous_review_pin: Vec<String>,
     pub toggle_sidebar_tree: Vec<String>,
+    pub toggle_focus_mode: Vec<String>,
     pub switch_to_files_view: Vec<String>,
     pub switch_to_review_view: Vec<String>,
     #[serde(alias = "switch_to_graph_view")]
@@ -91,6 +446,8 @@ pub struct KeyboardShortcuts {
     pub switch_to_ai_view: Vec<String>,
     pub toggle_ai_terminal_drawer: Vec<String>,
     pub open_project: Vec<String>,
+    pub open_path_from_clipboard: Vec<String>,
+    pub search_repository: Vec<String>,
     pub save_current_file: Vec<String>,
     pub next_editor_tab: Vec<String>,
     pub previous_editor_tab: Vec<String>,
@@ -116,13 +473,19 @@ impl Default for KeyboardShortcuts {
             next_file: vec!["alt-down".into()],
             previous_file: vec!["alt-up".into()],
             view_current_review_file: vec!["g space".into()],
+            toggle_review_pin: vec!["cmd-f2".into(), "ctrl-f2".into()],
+            next_review_pin: vec!["f2".into()],
+            previous_review_pin: vec!["shift-f2".into()],
             toggle_sidebar_tree: vec!["cmd-b".into(), "ctrl-b".into()],
+            toggle_focus_mode: vec!["cmd-shift-enter".into(), "ctrl-shift-enter".into()],
             switch_to_files_view: vec!["cmd-1".into(), "ctrl-1".into()],
             switch_to_review_view: vec!["cmd-2".into(), "ctrl-2".into()],
             switch_to_git_view: vec!["cmd-3".into(), "ctrl-3".into()],
             switch_to_ai_view: vec!["cmd-4".into(), "ctrl-4".into()],
             toggle_ai_terminal_drawer: vec!["cmd-j".into(), "ctrl-j".into()],
             open_project: vec!["cmd-shift-o".into(), "ctrl-shift-o".into()],
+            open_path_from_clipboard: vec!["cmd-alt-v".into(), "ctrl-alt-v".into()],
+            search_repository: vec!["cmd-shift-f".into(), "ctrl-shift-f".into()],
             save_current_file: vec!["cmd-s".into(), "ctrl-s".into()],
             next_editor_tab: if cfg!(target_os = "macos") {
                 vec!["cmd-}".into()]
@@ -165,29 +528,71 @@ impl KeyboardShortcuts {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppConfig {
     pub theme: ThemePreference,
+    pub language: Language,
+    pub fonts: FontConfig,
     pub reduce_motion: bool,
     pub show_fps_counter: bool,
     pub terminal: TerminalConfig,
     pub keyboard_shortcuts: KeyboardShortcuts,
+    /// Modal j/k navigation in the review surface. Off by default so bare keys stay untouched.
+    pub vim_mode: bool,
     pub review_provider_mappings: Vec<ReviewProviderMapping>,
     #[serde(default = "default_auto_refresh_interval_ms")]
     pub auto_refresh_interval_ms: u64,
+    #[serde(default = "default_large_hunk_fold_lines")]
+    pub large_hunk_fold_lines: usize,
+    #[serde(default = "default_hex_diff_max_bytes")]
+    pub hex_diff_max_bytes: usize,
+    #[serde(default = "default_comment_retention_days")]
+    pub comment_retention_days: i64,
+    #[serde(default = "default_stale_comment_retention_days")]
+    pub stale_comment_retention_days: i64,
+    pub git_timeouts: GitTimeoutConfig,
+    pub co_review: CoReviewConfig,
+    pub desktop_notifications: DesktopNotificationsConfig,
+    pub review_timer: ReviewTimerConfig,
+    pub review_budget: ReviewBudgetConfig,
+    pub branch_naming: BranchNamingConfig,
+    pub ticket_links: TicketLinksConfig,
+    pub changelog: ChangelogConfig,
+    pub gestures: GestureConfig,
+    pub formatting: FormattingConfig,
+    /// Offer to open a repository path found in the clipboard whenever the window is focused.
+    pub offer_clipboard_paths: bool,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         let mut config = Self {
             theme: ThemePreference::System,
+            language: Language::default(),
+            fonts: FontConfig::default(),
             reduce_motion: false,
             show_fps_counter: true,
             terminal: TerminalConfig::default(),
             keyboard_shortcuts: KeyboardShortcuts::default(),
+            vim_mode: false,
             review_provider_mappings: Vec::new(),
             auto_refresh_interval_ms: default_auto_refresh_interval_ms(),
+            large_hunk_fold_lines: default_large_hunk_fold_lines(),
+            hex_diff_max_bytes: default_hex_diff_max_bytes(),
+            comment_retention_days: default_comment_retention_days(),
+            stale_comment_retention_days: default_stale_comment_retention_days(),
+            git_timeouts: GitTimeoutConfig::default(),
+            co_review: CoReviewConfig::default(),
+            desktop_notifications: DesktopNotificationsConfig::default(),
+            review_timer: ReviewTimerConfig::default(),
+            review_budget: ReviewBudgetConfig::default(),
+            branch_naming: BranchNamingConfig::default(),
+            ticket_links: TicketLinksConfig::default(),
+            changelog: ChangelogConfig::default(),
+            gestures: GestureConfig::default(),
+            formatting: FormattingConfig::default(),
+            offer_clipboard_paths: false,
         };
         config.keyboard_shortcuts.normalize_files_tab_shortcuts();
         config
@@ -225,6 +630,7 @@ impl ConfigStore {
             )
         })?;
         config.keyboard_shortcuts.normalize_files_tab_shortcuts();
+        config.fonts = config.fonts.normalized();
         Ok(config)
     }
 
@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+const CRASH_REPORT_DIR_NAME: &str = "crashes";
+const CRASH_REPORT_PREFIX: &str = "crash-";
+const CRASH_REPORT_EXTENSION: &str = "toml";
+const SEEN_CRASH_REPORT_SUFFIX: &str = ".seen";
+const MAX_STORED_CRASH_REPORTS: usize = 10;
+
+/// The hunk-domain version, which owns the config and state file formats.
+pub const CONFIG_FORMAT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// What Hunk knows about a panic. Holds no repository content: only the panic message,
+/// the backtrace and where the recent log events came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub unix_time: i64,
+    pub app_version: String,
+    pub config_version: String,
+    pub os: String,
+    pub arch: String,
+    pub thread: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub recent_activity: Vec<String>,
+}
+
+impl CrashReport {
+    /// Markdown suitable for pasting into an issue.
+    pub fn to_issue_text(&self) -> String {
+        let mut text = format!(
+            "## Crash report\n\n\
+             - Hunk: {}\n\
+             - Config format: {}\n\
+             - OS: {} ({})\n\
+             - Thread: {}\n\
+             - Panic: {}\n",
+            self.app_version, self.config_version, self.os, self.arch, self.thread, self.message,
+        );
+        if let Some(location) = self.location.as_ref() {
+            text.push_str(format!("- Location: {location}\n").as_str());
+        }
+        text.push_str("\n### Recent activity\n\n```\n");
+        for line in &self.recent_activity {
+            text.push_str(line);
+            text.push('\n');
+        }
+        text.push_str("```\n\n### Backtrace\n\n```\n");
+        text.push_str(self.backtrace.trim_end());
+        text.push_str("\n```\n");
+        text
+    }
+}
+
+/// Crash reports in `~/.hunkdiff/crashes`, one TOML file per panic.
+///
+/// Reports stay "unseen" until the user dismisses them, which renames them to `*.seen.toml`.
+#[derive(Debug, Clone)]
+pub struct CrashReportStore {
+    dir: PathBuf,
+}
+
+impl CrashReportStore {
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_dir(
+            crate::paths::hunk_home_dir()?.join(CRASH_REPORT_DIR_NAME),
+        ))
+    }
+
+    pub fn from_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Writes `report` and drops the oldest reports beyond the last ten.
+    ///
+    /// Reports from the same second, such as a worker panic followed by one on the main thread,
+    /// get a `-N` suffix instead of overwriting each other.
+    pub fn save(&self, report: &CrashReport) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!(
+                "failed to create crash report directory {}",
+                self.dir.display()
+            )
+        })?;
+        let raw = toml::to_string(report).context("failed to serialize crash report")?;
+        // Numbering past every stored report of that second keeps the new one newest, even
+        // after older ones were pruned or marked seen.
+        let mut sequence = self
+            .report_paths()?
+            .iter()
+            .filter_map(|path| report_order_key(path))
+            .filter(|(unix_time, _)| *unix_time == report.unix_time)
+            .map(|(_, sequence)| sequence + 1)
+            .max()
+            .unwrap_or(0);
+        let (path, mut file) = loop {
+            let name = if sequence == 0 {
+                format!(
+                    "{CRASH_REPORT_PREFIX}{}.{CRASH_REPORT_EXTENSION}",
+                    report.unix_time
+                )
+            } else {
+                format!(
+                    "{CRASH_REPORT_PREFIX}{}-{sequence}.{CRASH_REPORT_EXTENSION}",
+                    report.unix_time
+                )
+            };
+            sequence += 1;
+            let path = self.dir.join(name);
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(err) => {
+                    return Err(err).with_context(|| {
+                        format!("failed to create crash report {}", path.display())
+                    });
+                }
+            }
+        };
+        file.write_all(raw.as_bytes())
+            .with_context(|| format!("failed to write crash report {}", path.display()))?;
+
+        let reports = self.report_paths()?;
+        for stale in reports
+            .iter()
+            .take(reports.len().saturating_sub(MAX_STORED_CRASH_REPORTS))
+        {
+            let _ = fs::remove_file(stale);
+        }
+        Ok(path)
+    }
+
+    /// The newest report the user has not dismissed yet.
+    pub fn latest_unseen(&self) -> Result<Option<(PathBuf, CrashReport)>> {
+        let Some(path) = self
+            .report_paths()?
+            .into_iter()
+            .rev()
+            .find(|path| !is_seen_report(path))
+        else {
+            return Ok(None);
+        };
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read crash report {}", path.display()))?;
+        let report = toml::from_str::<CrashReport>(&raw)
+            .with_context(|| format!("failed to parse crash report {}", path.display()))?;
+        Ok(Some((path, report)))
+    }
+
+    /// Marks every stored report as seen so the previous-session banner stops showing.
+    pub fn mark_all_seen(&self) -> Result<()> {
+        for path in self.report_paths()? {
+            if is_seen_report(&path) {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let seen_path = path.with_file_name(format!(
+                "{stem}{SEEN_CRASH_REPORT_SUFFIX}.{CRASH_REPORT_EXTENSION}"
+            ));
+            fs::rename(&path, &seen_path).with_context(|| {
+                format!("failed to mark crash report {} as seen", path.display())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Every stored report, oldest first.
+    fn report_paths(&self) -> Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "failed to read crash report directory {}",
+                        self.dir.display()
+                    )
+                });
+            }
+        };
+        let mut reports = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter_map(|path| report_order_key(&path).map(|key| (key, path)))
+            .collect::<Vec<_>>();
+        reports.sort();
+        Ok(reports.into_iter().map(|(_, path)| path).collect())
+    }
+}
+
+/// `(unix_time, sequence)` of a report file, where the sequence tells apart reports saved in
+/// the same second.
+fn report_order_key(path: &Path) -> Option<(i64, u32)> {
+    if path.extension().and_then(|extension| extension.to_str()) != Some(CRASH_REPORT_EXTENSION) {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let stem = stem.strip_suffix(SEEN_CRASH_REPORT_SUFFIX).unwrap_or(stem);
+    let name = stem.strip_prefix(CRASH_REPORT_PREFIX)?;
+    match name.split_once('-') {
+        Some((unix_time, sequence)) => Some((unix_time.parse().ok()?, sequence.parse().ok()?)),
+        None => Some((name.parse().ok()?, 0)),
+    }
+}
+
+fn is_seen_report(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.ends_with(SEEN_CRASH_REPORT_SUFFIX))
+}
+
+/// A bounded buffer of recent log lines that a panic hook can copy into a [`CrashReport`].
+#[derive(Debug)]
+pub struct RecentActivityLog {
+    capacity: usize,
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl RecentActivityLog {
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lines: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn push(&self, line: String) {
+        let Ok(mut lines) = self.lines.lock() else {
+            return;
+        };
+        while lines.len() >= self.capacity.max(1) {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// Oldest line first. Still works if a panic poisoned the lock.
+    pub fn snapshot(&self) -> Vec<String> {
+        let lines = match self.lines.lock() {
+            Ok(lines) => lines,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        lines.iter().cloned().collect()
+    }
+}
@@ -0,0 +1,162 @@
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use anyhow::{Context as _, Result, anyhow};
+
+use super::comments::{CommentRecord, CommentScopeCounts, CommentScopeRevision};
+use super::connection::DatabaseStore;
+
+type DatabaseCommand = Box<dyn FnOnce(&DatabaseStore) + Send>;
+
+/// Runs database calls on one background thread that owns the store, so the UI thread only
+/// waits on futures. Cloned handles share the thread, which exits once every handle is dropped.
+#[derive(Debug, Clone)]
+pub struct DatabaseActor {
+    commands: Sender<DatabaseCommand>,
+}
+
+impl DatabaseActor {
+    pub fn spawn(store: DatabaseStore) -> Result<Self> {
+        let (commands, receiver) = mpsc::channel::<DatabaseCommand>();
+        thread::Builder::new()
+            .name("hunk-database".to_string())
+            .spawn(move || {
+                for command in receiver {
+                    // A panicking call fails its own reply but leaves the thread serving the rest.
+                    let _ = panic::catch_unwind(AssertUnwindSafe(|| command(&store)));
+                }
+            })
+            .context("failed to start the database thread")?;
+        Ok(Self { commands })
+    }
+
+    /// Queues `call` behind the calls sent before it. The returned future resolves with its
+    /// result, or with an error if the database thread stopped first.
+    pub fn call<T, F>(&self, call: F) -> DatabaseReply<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&DatabaseStore) -> Result<T> + Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(ReplySlot::default()));
+        let sender = ReplySender {
+            slot: Some(slot.clone()),
+        };
+        let command: DatabaseCommand = Box::new(move |store| sender.send(call(store)));
+        // A failed send drops the command, and with it the sender, which fills the slot.
+        let _ = self.commands.send(command);
+        DatabaseReply { slot }
+    }
+
+    pub fn list_comments(
+        &self,
+        repo_root: String,
+        branch_name: String,
+        include_non_open: bool,
+    ) -> DatabaseReply<Vec<CommentRecord>> {
+        self.call(move |store| {
+            store.list_comments(repo_root.as_str(), branch_name.as_str(), include_non_open)
+        })
+    }
+
+    pub fn count_comments(
+        &self,
+        repo_root: String,
+        branch_name: String,
+    ) -> DatabaseReply<CommentScopeCounts> {
+        self.call(move |store| store.count_comments(repo_root.as_str(), branch_name.as_str()))
+    }
+
+    pub fn comment_scope_revision(
+        &self,
+        repo_root: String,
+        branch_name: String,
+    ) -> DatabaseReply<CommentScopeRevision> {
+        self.call(move |store| {
+            store.comment_scope_revision(repo_root.as_str(), branch_name.as_str())
+        })
+    }
+
+    pub fn prune_non_open_comments(
+        &self,
+        resolved_cutoff_unix_ms: i64,
+        stale_cutoff_unix_ms: i64,
+    ) -> DatabaseReply<usize> {
+        self.call(move |store| {
+            store.prune_non_open_comments(resolved_cutoff_unix_ms, stale_cutoff_unix_ms)
+        })
+    }
+
+    pub fn compact(&self) -> DatabaseReply<u64> {
+        self.call(|store| store.compact())
+    }
+}
+
+/// The pending result of a [`DatabaseActor`] call.
+#[must_use = "database calls run either way, but their result is only seen by awaiting"]
+pub struct DatabaseReply<T> {
+    slot: Arc<Mutex<ReplySlot<T>>>,
+}
+
+struct ReplySlot<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T> Default for ReplySlot<T> {
+    fn default() -> Self {
+        Self {
+            result: None,
+            waker: None,
+        }
+    }
+}
+
+impl<T> Future for DatabaseReply<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock().unwrap_or_else(|err| err.into_inner());
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Fills the reply slot exactly once. Dropping it unsent, because the call panicked or never
+/// reached the thread, resolves the reply with an error instead of leaving it pending.
+struct ReplySender<T> {
+    slot: Option<Arc<Mutex<ReplySlot<T>>>>,
+}
+
+impl<T> ReplySender<T> {
+    fn send(mut self, result: Result<T>) {
+        if let Some(slot) = self.slot.take() {
+            fill_reply_slot(&slot, result);
+        }
+    }
+}
+
+impl<T> Drop for ReplySender<T> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            fill_reply_slot(&slot, Err(anyhow!("the database call did not finish")));
+        }
+    }
+}
+
+fn fill_reply_slot<T>(slot: &Mutex<ReplySlot<T>>, result: Result<T>) {
+    let mut slot = slot.lock().unwrap_or_else(|err| err.into_inner());
+    slot.result = Some(result);
+    if let Some(waker) = slot.waker.take() {
+        waker.wake();
+    }
+}
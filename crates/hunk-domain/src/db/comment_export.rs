@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use serde_json::{Value, json};
+
+use super::comments::{CommentLineSide, CommentRecord, comment_status_label};
+use super::connection::DatabaseStore;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentExportFormat {
+    /// Grouped by file with a diff snippet per comment, for pasting into a PR description.
+    Markdown,
+    /// The fields `comments.list` returns plus the captured context. Comment import reads it
+    /// back.
+    Json,
+}
+
+impl CommentExportFormat {
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// The comments an export covers, matching the repository and branch they were written on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentExportScope {
+    pub repo_root: String,
+    pub branch_name: String,
+    /// Also export resolved and stale comments.
+    pub include_non_open: bool,
+}
+
+impl DatabaseStore {
+    pub fn export_comments(
+        &self,
+        scope: &CommentExportScope,
+        format: CommentExportFormat,
+    ) -> Result<String> {
+        let comments = self.list_comments(
+            scope.repo_root.as_str(),
+            scope.branch_name.as_str(),
+            scope.include_non_open,
+        )?;
+        format_comment_export(scope, &comments, format)
+    }
+}
+
+pub fn format_comment_export(
+    scope: &CommentExportScope,
+    comments: &[CommentRecord],
+    format: CommentExportFormat,
+) -> Result<String> {
+    match format {
+        CommentExportFormat::Markdown => Ok(format_markdown_export(scope, comments)),
+        CommentExportFormat::Json => {
+            let value = json!({
+                "repo": scope.repo_root,
+                "branch": scope.branch_name,
+                "comments": comments.iter().map(comment_export_json).collect::<Vec<_>>(),
+            });
+            serde_json::to_string_pretty(&value).context("failed to serialize comments")
+        }
+    }
+}
+
+fn comment_export_json(comment: &CommentRecord) -> Value {
+    json!({
+        "id": comment.id,
+        "path": comment.file_path,
+        "status": comment_status_label(comment.status),
+        "side": comment.line_side.as_str(),
+        "oldLine": comment.old_line,
+        "newLine": comment.new_line,
+        "hunkHeader": comment.hunk_header,
+        "lineText": comment.line_text,
+        "contextBefore": comment.context_before,
+        "contextAfter": comment.context_after,
+        "text": comment.comment_text,
+        "author": comment.author_name,
+        "authorEmail": comment.author_email,
+        "staleReason": comment.stale_reason,
+        "createdAtUnixMs": comment.created_at_unix_ms,
+        "updatedAtUnixMs": comment.updated_at_unix_ms,
+        "resolvedAtUnixMs": comment.resolved_at_unix_ms,
+    })
+}
+
+fn format_markdown_export(scope: &CommentExportScope, comments: &[CommentRecord]) -> String {
+    let mut by_path = BTreeMap::<&str, Vec<&CommentRecord>>::new();
+    for comment in comments {
+        by_path
+            .entry(comment.file_path.as_str())
+            .or_default()
+            .push(comment);
+    }
+
+    let noun = if comments.len() == 1 {
+        "comment"
+    } else {
+        "comments"
+    };
+    let mut out = format!(
+        "# Review comments\n\n{} {noun} on `{}`.\n",
+        comments.len(),
+        scope.branch_name
+    );
+    for (path, mut comments) in by_path {
+        comments.sort_by_key(|comment| comment.new_line.or(comment.old_line));
+        out.push_str(format!("\n## `{path}`\n").as_str());
+        for comment in comments {
+            out.push_str(format!("\n### {}\n\n", markdown_comment_heading(comment)).as_str());
+            let snippet = markdown_comment_snippet(comment);
+            if !snippet.is_empty() {
+                let fence = "`".repeat(longest_backtick_run(snippet.as_str()).max(2) + 1);
+                out.push_str(format!("{fence}diff\n{snippet}\n{fence}\n\n").as_str());
+            }
+            out.push_str(comment.comment_text.trim_end());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn markdown_comment_heading(comment: &CommentRecord) -> String {
+    let location = match (comment.line_side, comment.old_line, comment.new_line) {
+        (CommentLineSide::Left, Some(line), _) => format!("Old line {line}"),
+        (CommentLineSide::Right, _, Some(line)) => format!("Line {line}"),
+        _ => "File".to_string(),
+    };
+    let mut heading = format!("{location} ({})", comment_status_label(comment.status));
+    if let Some(author) = comment.author_label() {
+        heading.push_str(format!(" by {author}").as_str());
+    }
+    heading
+}
+
+/// The hunk header and the captured diff lines around the commented row.
+fn markdown_comment_snippet(comment: &CommentRecord) -> String {
+    let lines = [
+        comment.context_before.as_str(),
+        comment.line_text.as_str(),
+        comment.context_after.as_str(),
+    ]
+    .into_iter()
+    .flat_map(str::lines)
+    .collect::<Vec<_>>();
+    let mut snippet = Vec::with_capacity(lines.len() + 1);
+    if let Some(header) = comment.hunk_header.as_deref().map(str::trim)
+        && !header.is_empty()
+        && !lines.iter().any(|line| line.trim() == header)
+    {
+        snippet.push(header);
+    }
+    snippet.extend(lines);
+    snippet.join("\n")
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|ch: char| ch != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -43,7 +44,7 @@ pub enum CommentLineSide {
 }
 
 impl CommentLineSide {
-    fn as_str(self) -> &'static str {
+    pub(super) fn as_str(self) -> &'static str {
         match self {
             Self::Left => "left",
             Self::Right => "right",
@@ -77,6 +78,31 @@ pub struct NewComment {
     pub context_after: String,
     pub anchor_hash: String,
     pub comment_text: String,
+    /// Who wrote the comment, taken from `user.name` and `user.email` when it was created.
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+}
+
+/// Cheap change marker for one comment scope, used to notice writes from other processes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommentScopeRevision {
+    pub comment_count: i64,
+    pub latest_updated_at_unix_ms: i64,
+}
+
+/// How many comments of each status one scope has, plus open comments per file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentScopeCounts {
+    pub open: usize,
+    pub stale: usize,
+    pub resolved: usize,
+    pub open_by_file: BTreeMap<String, usize>,
+}
+
+impl CommentScopeCounts {
+    pub fn open_in_file(&self, file_path: &str) -> usize {
+        self.open_by_file.get(file_path).copied().unwrap_or(0)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -102,6 +128,18 @@ pub struct CommentRecord {
     pub updated_at_unix_ms: i64,
     pub last_seen_at_unix_ms: Option<i64>,
     pub resolved_at_unix_ms: Option<i64>,
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+}
+
+impl CommentRecord {
+    /// The author's name, falling back to their email for comments without one.
+    pub fn author_label(&self) -> Option<&str> {
+        self.author_name
+            .as_deref()
+            .or(self.author_email.as_deref())
+            .filter(|author| !author.trim().is_empty())
+    }
 }
 
 pub fn now_unix_ms() -> i64 {
@@ -208,35 +246,38 @@ impl DatabaseStore {
         let now = now_unix_ms();
         let row_stable_id = encode_row_stable_id_for_sql(input.row_stable_id);
 
-        let conn = self.open_connection()?;
-        conn.execute(
-            sql::comments::INSERT,
-            params![
-                id,
-                input.repo_root,
-                input.branch_name,
-                input.created_head_commit,
-                CommentStatus::Open.as_str(),
-                input.file_path,
-                input.line_side.as_str(),
-                input.old_line.map(i64::from),
-                input.new_line.map(i64::from),
-                row_stable_id,
-                input.hunk_header,
-                input.line_text,
-                input.context_before,
-                input.context_after,
-                input.anchor_hash,
-                input.comment_text,
-                now,
-                now,
-                now,
-            ],
-        )
-        .context("failed to insert comment")?;
+        self.with_write_lock(|conn| {
+            conn.execute(
+                sql::comments::INSERT,
+                params![
+                    id,
+                    input.repo_root,
+                    input.branch_name,
+                    input.created_head_commit,
+                    CommentStatus::Open.as_str(),
+                    input.file_path,
+                    input.line_side.as_str(),
+                    input.old_line.map(i64::from),
+                    input.new_line.map(i64::from),
+                    row_stable_id,
+                    input.hunk_header,
+                    input.line_text,
+                    input.context_before,
+                    input.context_after,
+                    input.anchor_hash,
+                    input.comment_text,
+                    now,
+                    now,
+                    now,
+                    input.author_name,
+                    input.author_email,
+                ],
+            )
+            .context("failed to insert comment")?;
 
-        get_comment_with_connection(&conn, &id)?
-            .ok_or_else(|| anyhow!("inserted comment id {id} was not found"))
+            get_comment_with_connection(conn, &id)?
+                .ok_or_else(|| anyhow!("inserted comment id {id} was not found"))
+        })
     }
 
     pub fn get_comment(&self, id: &str) -> Result<Option<CommentRecord>> {
@@ -270,6 +311,58 @@ impl DatabaseStore {
         Ok(comments)
     }
 
+    pub fn comment_scope_revision(
+        &self,
+        repo_root: &str,
+        branch_name: &str,
+    ) -> Result<CommentScopeRevision> {
+        let conn = self.open_connection()?;
+        conn.query_row(
+            sql::comments::SELECT_SCOPE_REVISION,
+            params![repo_root, branch_name],
+            |row| {
+                Ok(CommentScopeRevision {
+                    comment_count: row.get(0)?,
+                    latest_updated_at_unix_ms: row.get(1)?,
+                })
+            },
+        )
+        .context("failed to query comment scope revision")
+    }
+
+    /// Counts the scope's comments by status without loading them.
+    pub fn count_comments(&self, repo_root: &str, branch_name: &str) -> Result<CommentScopeCounts> {
+        let conn = self.open_connection()?;
+        let mut stmt = conn
+            .prepare(sql::comments::SELECT_COUNTS_BY_FILE)
+            .context("failed to prepare comment count query")?;
+        let rows = stmt
+            .query_map(params![repo_root, branch_name], |row| {
+                let file_path: String = row.get(0)?;
+                let status_raw: String = row.get(1)?;
+                let status = CommentStatus::from_db(status_raw.as_str())
+                    .ok_or_else(|| invalid_text_value("status", status_raw.as_str()))?;
+                let count: i64 = row.get(2)?;
+                Ok((file_path, status, count))
+            })
+            .context("failed to query comment counts")?;
+
+        let mut counts = CommentScopeCounts::default();
+        for row in rows {
+            let (file_path, status, count) = row.context("failed to read comment count row")?;
+            let count = usize::try_from(count).unwrap_or(0);
+            match status {
+                CommentStatus::Open => {
+                    counts.open += count;
+                    *counts.open_by_file.entry(file_path).or_default() += count;
+                }
+                CommentStatus::Stale => counts.stale += count,
+                CommentStatus::Resolved => counts.resolved += count,
+            }
+        }
+        Ok(counts)
+    }
+
     pub fn mark_comment_status(
         &self,
         id: &str,
@@ -282,14 +375,15 @@ impl DatabaseStore {
             _ => None,
         };
 
-        let conn = self.open_connection()?;
-        let rows_updated = conn
-            .execute(
-                sql::comments::UPDATE_STATUS,
-                params![id, status.as_str(), stale_reason_value, updated_at_unix_ms,],
-            )
-            .with_context(|| format!("failed to update status for comment {id}"))?;
-        Ok(rows_updated > 0)
+        self.with_write_lock(|conn| {
+            let rows_updated = conn
+                .execute(
+                    sql::comments::UPDATE_STATUS,
+                    params![id, status.as_str(), stale_reason_value, updated_at_unix_ms,],
+                )
+                .with_context(|| format!("failed to update status for comment {id}"))?;
+            Ok(rows_updated > 0)
+        })
     }
 
     pub fn mark_many_comment_status(
@@ -308,32 +402,34 @@ impl DatabaseStore {
             _ => None,
         };
 
-        let mut conn = self.open_connection()?;
-        execute_many_comment_ids(
-            &mut conn,
-            sql::comments::UPDATE_STATUS,
-            ids,
-            "failed to start sqlite transaction for status batch update",
-            "failed to prepare status batch update statement",
-            "failed to commit status batch update transaction",
-            |stmt, id| {
-                stmt.execute(params![
-                    id,
-                    status.as_str(),
-                    stale_reason_value,
-                    updated_at_unix_ms,
-                ])
-                .with_context(|| format!("failed to batch update status for comment {id}"))
-            },
-        )
+        self.with_write_lock(|conn| {
+            execute_many_comment_ids(
+                conn,
+                sql::comments::UPDATE_STATUS,
+                ids,
+                "failed to start sqlite transaction for status batch update",
+                "failed to prepare status batch update statement",
+                "failed to commit status batch update transaction",
+                |stmt, id| {
+                    stmt.execute(params![
+                        id,
+                        status.as_str(),
+                        stale_reason_value,
+                        updated_at_unix_ms,
+                    ])
+                    .with_context(|| format!("failed to batch update status for comment {id}"))
+                },
+            )
+        })
     }
 
     pub fn touch_comment_seen(&self, id: &str, seen_at_unix_ms: i64) -> Result<bool> {
-        let conn = self.open_connection()?;
-        let rows_updated = conn
-            .execute(sql::comments::TOUCH_SEEN, params![id, seen_at_unix_ms])
-            .with_context(|| format!("failed to update last_seen for comment {id}"))?;
-        Ok(rows_updated > 0)
+        self.with_write_lock(|conn| {
+            let rows_updated = conn
+                .execute(sql::comments::TOUCH_SEEN, params![id, seen_at_unix_ms])
+                .with_context(|| format!("failed to update last_seen for comment {id}"))?;
+            Ok(rows_updated > 0)
+        })
     }
 
     pub fn touch_many_comment_seen(&self, ids: &[String], seen_at_unix_ms: i64) -> Result<usize> {
@@ -341,27 +437,30 @@ impl DatabaseStore {
             return Ok(0);
         }
 
-        let mut conn = self.open_connection()?;
-        execute_many_comment_ids(
-            &mut conn,
-            sql::comments::TOUCH_SEEN,
-            ids,
-            "failed to start sqlite transaction for last_seen batch update",
-            "failed to prepare last_seen batch update statement",
-            "failed to commit last_seen batch update transaction",
-            |stmt, id| {
-                stmt.execute(params![id, seen_at_unix_ms])
-                    .with_context(|| format!("failed to batch update last_seen for comment {id}"))
-            },
-        )
+        self.with_write_lock(|conn| {
+            execute_many_comment_ids(
+                conn,
+                sql::comments::TOUCH_SEEN,
+                ids,
+                "failed to start sqlite transaction for last_seen batch update",
+                "failed to prepare last_seen batch update statement",
+                "failed to commit last_seen batch update transaction",
+                |stmt, id| {
+                    stmt.execute(params![id, seen_at_unix_ms]).with_context(|| {
+                        format!("failed to batch update last_seen for comment {id}")
+                    })
+                },
+            )
+        })
     }
 
     pub fn delete_comment(&self, id: &str) -> Result<bool> {
-        let conn = self.open_connection()?;
-        let rows_deleted = conn
-            .execute(sql::comments::DELETE_BY_ID, params![id])
-            .with_context(|| format!("failed to delete comment {id}"))?;
-        Ok(rows_deleted > 0)
+        self.with_write_lock(|conn| {
+            let rows_deleted = conn
+                .execute(sql::comments::DELETE_BY_ID, params![id])
+                .with_context(|| format!("failed to delete comment {id}"))?;
+            Ok(rows_deleted > 0)
+        })
     }
 
     pub fn delete_many_comments(&self, ids: &[String]) -> Result<usize> {
@@ -369,25 +468,94 @@ impl DatabaseStore {
             return Ok(0);
         }
 
-        let mut conn = self.open_connection()?;
-        execute_many_comment_ids(
-            &mut conn,
-            sql::comments::DELETE_BY_ID,
-            ids,
-            "failed to start sqlite transaction for comment batch delete",
-            "failed to prepare comment batch delete statement",
-            "failed to commit comment batch delete transaction",
-            |stmt, id| {
-                stmt.execute(params![id])
-                    .with_context(|| format!("failed to batch delete comment {id}"))
-            },
-        )
+        self.with_write_lock(|conn| {
+            execute_many_comment_ids(
+                conn,
+                sql::comments::DELETE_BY_ID,
+                ids,
+                "failed to start sqlite transaction for comment batch delete",
+                "failed to prepare comment batch delete statement",
+                "failed to commit comment batch delete transaction",
+                |stmt, id| {
+                    stmt.execute(params![id])
+                        .with_context(|| format!("failed to batch delete comment {id}"))
+                },
+            )
+        })
     }
 
-    pub fn prune_non_open_comments(&self, cutoff_unix_ms: i64) -> Result<usize> {
-        let conn = self.open_connection()?;
-        conn.execute(sql::comments::PRUNE_NON_OPEN, params![cutoff_unix_ms])
+    /// Re-points every comment on `old_path` in this scope at `new_path`, for files renamed in
+    /// the working copy. Anchor hashes are recomputed for the new path, and stale comments reopen
+    /// so the next reconcile checks them against the renamed file. Returns how many moved.
+    pub fn migrate_comment_paths(
+        &self,
+        repo_root: &str,
+        branch_name: &str,
+        old_path: &str,
+        new_path: &str,
+    ) -> Result<usize> {
+        if old_path == new_path {
+            return Ok(0);
+        }
+
+        self.with_write_lock(|conn| {
+            let tx = conn
+                .transaction()
+                .context("failed to start sqlite transaction for comment path migration")?;
+            let anchors = {
+                let mut stmt = tx
+                    .prepare(sql::comments::SELECT_ANCHORS_BY_FILE_PATH)
+                    .context("failed to prepare comment anchor query")?;
+                stmt.query_map(params![repo_root, branch_name, old_path], |row| {
+                    let id: String = row.get(0)?;
+                    let hunk_header: Option<String> = row.get(1)?;
+                    let line_text: String = row.get(2)?;
+                    let context_before: String = row.get(3)?;
+                    let context_after: String = row.get(4)?;
+                    let anchor_hash = compute_comment_anchor_hash(
+                        new_path,
+                        hunk_header.as_deref(),
+                        line_text.as_str(),
+                        context_before.as_str(),
+                        context_after.as_str(),
+                    );
+                    Ok((id, anchor_hash))
+                })
+                .with_context(|| format!("failed to query comments on {old_path}"))?
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .with_context(|| format!("failed to read comments on {old_path}"))?
+            };
+
+            let now = now_unix_ms();
+            {
+                let mut stmt = tx
+                    .prepare(sql::comments::MOVE_TO_FILE_PATH)
+                    .context("failed to prepare comment path migration statement")?;
+                for (id, anchor_hash) in &anchors {
+                    stmt.execute(params![id, new_path, anchor_hash, now])
+                        .with_context(|| format!("failed to move comment {id} to {new_path}"))?;
+                }
+            }
+            tx.commit()
+                .context("failed to commit comment path migration transaction")?;
+            Ok(anchors.len())
+        })
+    }
+
+    /// Deletes resolved comments settled before `resolved_cutoff_unix_ms` and stale comments last
+    /// touched before `stale_cutoff_unix_ms`. Open comments are never pruned.
+    pub fn prune_non_open_comments(
+        &self,
+        resolved_cutoff_unix_ms: i64,
+        stale_cutoff_unix_ms: i64,
+    ) -> Result<usize> {
+        self.with_write_lock(|conn| {
+            conn.execute(
+                sql::comments::PRUNE_NON_OPEN,
+                params![resolved_cutoff_unix_ms, stale_cutoff_unix_ms],
+            )
             .context("failed to prune stale/resolved comments")
+        })
     }
 }
 
@@ -484,6 +652,8 @@ fn map_comment_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CommentRecord> {
         updated_at_unix_ms: row.get("updated_at_unix_ms")?,
         last_seen_at_unix_ms: row.get("last_seen_at_unix_ms")?,
         resolved_at_unix_ms: row.get("resolved_at_unix_ms")?,
+        author_name: row.get("author_name")?,
+        author_email: row.get("author_email")?,
     })
 }
 
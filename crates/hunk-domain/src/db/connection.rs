@@ -1,5 +1,6 @@
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context as _, Result, anyhow};
 use rusqlite::Connection;
@@ -7,7 +8,9 @@ use rusqlite::Connection;
 use super::sql;
 
 const DB_FILE_NAME: &str = "hunk.db";
-const DB_SCHEMA_VERSION: i64 = 3;
+/// How long a comment write waits for another Hunk instance to finish writing.
+const DEFAULT_WRITE_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+const DB_SCHEMA_VERSION: i64 = 6;
 const MIGRATIONS: &[Migration] = &[
     Migration {
         version: 1,
@@ -24,6 +27,21 @@ const MIGRATIONS: &[Migration] = &[
         name: "0003_row_stable_id_cleanup.sql",
         sql: include_str!("migrations/0003_row_stable_id_cleanup.sql"),
     },
+    Migration {
+        version: 4,
+        name: "0004_usage_metrics.sql",
+        sql: include_str!("migrations/0004_usage_metrics.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "0005_comment_author.sql",
+        sql: include_str!("migrations/0005_comment_author.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "0006_comment_counts_index.sql",
+        sql: include_str!("migrations/0006_comment_counts_index.sql"),
+    },
 ];
 
 struct Migration {
@@ -35,23 +53,85 @@ struct Migration {
 #[derive(Debug, Clone)]
 pub struct DatabaseStore {
     path: PathBuf,
+    pub(super) write_lock_timeout: Duration,
 }
 
 impl DatabaseStore {
     pub fn new() -> Result<Self> {
-        Ok(Self {
-            path: crate::paths::hunk_home_dir()?.join(DB_FILE_NAME),
-        })
+        Ok(Self::from_path(
+            crate::paths::hunk_home_dir()?.join(DB_FILE_NAME),
+        ))
     }
 
     pub fn from_path(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            write_lock_timeout: DEFAULT_WRITE_LOCK_TIMEOUT,
+        }
+    }
+
+    pub fn with_write_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.write_lock_timeout = timeout;
+        self
     }
 
     pub fn path(&self) -> &Path {
         &self.path
     }
 
+    /// The advisory lock file instances hold while writing comments, next to the database.
+    pub fn write_lock_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push("-lock");
+        PathBuf::from(path)
+    }
+
+    pub fn check_integrity(&self) -> Result<Vec<String>> {
+        let conn = self.open_connection()?;
+        let mut statement = conn
+            .prepare("PRAGMA integrity_check")
+            .context("failed to prepare sqlite integrity check")?;
+        let rows = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .context("failed to run sqlite integrity check")?;
+        let mut problems = Vec::new();
+        for row in rows {
+            let row = row.context("failed to read sqlite integrity check result")?;
+            if row != "ok" {
+                problems.push(row);
+            }
+        }
+        Ok(problems)
+    }
+
+    /// Rebuilds the database without its free pages and truncates the write-ahead log, returning
+    /// the bytes reclaimed on disk. Like comment writes, this waits for the write lock.
+    pub fn compact(&self) -> Result<u64> {
+        let size_before = self.size_on_disk()?;
+        self.with_write_lock(|conn| {
+            conn.execute_batch("VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")
+                .context("failed to compact sqlite database")
+        })?;
+        Ok(size_before.saturating_sub(self.size_on_disk()?))
+    }
+
+    fn size_on_disk(&self) -> Result<u64> {
+        let mut wal_path = self.path.clone().into_os_string();
+        wal_path.push("-wal");
+        let mut total = 0;
+        for path in [self.path.clone(), PathBuf::from(wal_path)] {
+            match fs::metadata(path.as_path()) {
+                Ok(metadata) => total += metadata.len(),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(err)
+                        .with_context(|| format!("failed to inspect {}", path.display()));
+                }
+            }
+        }
+        Ok(total)
+    }
+
     pub(super) fn open_connection(&self) -> Result<Connection> {
         ensure_db_parent_dir(&self.path)?;
         let conn = Connection::open(&self.path).with_context(|| {
@@ -65,7 +145,7 @@ impl DatabaseStore {
     }
 }
 
-fn ensure_db_parent_dir(path: &Path) -> Result<()> {
+pub(super) fn ensure_db_parent_dir(path: &Path) -> Result<()> {
     let parent = path
         .parent()
         .ok_or_else(|| anyhow!("database path has no parent: {}", path.display()))?;
@@ -0,0 +1,9 @@
+CREATE TABLE usage_events (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  kind TEXT NOT NULL CHECK (kind IN ('diff_viewed', 'snapshot_load', 'comment_written', 'push')),
+  duration_ms INTEGER CHECK (duration_ms IS NULL OR duration_ms >= 0),
+  recorded_at_unix_ms INTEGER NOT NULL
+);
+
+CREATE INDEX usage_events_recorded_at_idx
+  ON usage_events (recorded_at_unix_ms);
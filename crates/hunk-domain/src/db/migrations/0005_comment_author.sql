@@ -0,0 +1,2 @@
+ALTER TABLE comments ADD COLUMN author_name TEXT;
+ALTER TABLE comments ADD COLUMN author_email TEXT;
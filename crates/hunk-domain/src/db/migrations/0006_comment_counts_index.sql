@@ -0,0 +1,2 @@
+CREATE INDEX IF NOT EXISTS comments_scope_file_status_idx
+  ON comments (repo_root, branch_name, file_path, status);
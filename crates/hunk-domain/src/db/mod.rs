@@ -1,10 +1,20 @@
+mod actor;
+mod comment_export;
 mod comments;
 mod connection;
 mod sql;
+mod usage_metrics;
+mod write_lock;
 
+pub use actor::{DatabaseActor, DatabaseReply};
+pub use comment_export::{CommentExportFormat, CommentExportScope, format_comment_export};
 pub use comments::{
-    CommentLineSide, CommentRecord, CommentStatus, NewComment, comment_status_label,
-    compute_comment_anchor_hash, format_comment_clipboard_blob, next_status_for_unmatched_anchor,
-    now_unix_ms,
+    CommentLineSide, CommentRecord, CommentScopeCounts, CommentScopeRevision, CommentStatus,
+    NewComment, comment_status_label, compute_comment_anchor_hash, format_comment_clipboard_blob,
+    next_status_for_unmatched_anchor, now_unix_ms,
 };
 pub use connection::DatabaseStore;
+pub use usage_metrics::{
+    USAGE_METRICS_RETENTION_DAYS, UsageMetricKind, UsageMetricsDay, UsageMetricsSummary,
+};
+pub use write_lock::{CommentDatabaseLocked, is_comment_database_locked};
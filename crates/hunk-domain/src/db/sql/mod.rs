@@ -21,7 +21,9 @@ INSERT INTO comments (
   created_at_unix_ms,
   updated_at_unix_ms,
   last_seen_at_unix_ms,
-  resolved_at_unix_ms
+  resolved_at_unix_ms,
+  author_name,
+  author_email
 ) VALUES (
   ?1,
   ?2,
@@ -43,7 +45,9 @@ INSERT INTO comments (
   ?17,
   ?18,
   ?19,
-  NULL
+  NULL,
+  ?20,
+  ?21
 );
 "#;
 
@@ -69,7 +73,9 @@ SELECT
   created_at_unix_ms,
   updated_at_unix_ms,
   last_seen_at_unix_ms,
-  resolved_at_unix_ms
+  resolved_at_unix_ms,
+  author_name,
+  author_email
 FROM comments
 WHERE id = ?1;
 "#;
@@ -96,13 +102,37 @@ SELECT
   created_at_unix_ms,
   updated_at_unix_ms,
   last_seen_at_unix_ms,
-  resolved_at_unix_ms
+  resolved_at_unix_ms,
+  author_name,
+  author_email
 FROM comments
 WHERE
   repo_root = ?1
   AND branch_name = ?2
   AND (?3 = 1 OR status = 'open')
 ORDER BY updated_at_unix_ms DESC, created_at_unix_ms DESC, id DESC;
+"#;
+
+    pub(crate) const SELECT_SCOPE_REVISION: &str = r#"
+SELECT
+  COUNT(*),
+  COALESCE(MAX(updated_at_unix_ms), 0)
+FROM comments
+WHERE
+  repo_root = ?1
+  AND branch_name = ?2;
+"#;
+
+    pub(crate) const SELECT_COUNTS_BY_FILE: &str = r#"
+SELECT
+  file_path,
+  status,
+  COUNT(*)
+FROM comments
+WHERE
+  repo_root = ?1
+  AND branch_name = ?2
+GROUP BY file_path, status;
 "#;
 
     pub(crate) const UPDATE_STATUS: &str = r#"
@@ -126,6 +156,37 @@ WHERE id = ?1;
 UPDATE comments
 SET last_seen_at_unix_ms = ?2
 WHERE id = ?1;
+"#;
+
+    pub(crate) const SELECT_ANCHORS_BY_FILE_PATH: &str = r#"
+SELECT
+  id,
+  hunk_header,
+  line_text,
+  context_before,
+  context_after
+FROM comments
+WHERE
+  repo_root = ?1
+  AND branch_name = ?2
+  AND file_path = ?3;
+"#;
+
+    pub(crate) const MOVE_TO_FILE_PATH: &str = r#"
+UPDATE comments
+SET
+  file_path = ?2,
+  anchor_hash = ?3,
+  status = CASE
+    WHEN status = 'stale' THEN 'open'
+    ELSE status
+  END,
+  stale_reason = CASE
+    WHEN status = 'stale' THEN NULL
+    ELSE stale_reason
+  END,
+  updated_at_unix_ms = ?4
+WHERE id = ?1;
 "#;
 
     pub(crate) const DELETE_BY_ID: &str = r#"
@@ -136,8 +197,34 @@ WHERE id = ?1;
     pub(crate) const PRUNE_NON_OPEN: &str = r#"
 DELETE FROM comments
 WHERE
-  status IN ('stale', 'resolved')
-  AND COALESCE(resolved_at_unix_ms, updated_at_unix_ms) < ?1;
+  (status = 'resolved' AND COALESCE(resolved_at_unix_ms, updated_at_unix_ms) < ?1)
+  OR (status = 'stale' AND updated_at_unix_ms < ?2);
+"#;
+}
+
+pub(crate) mod usage_metrics {
+    pub(crate) const INSERT: &str = r#"
+INSERT INTO usage_events (kind, duration_ms, recorded_at_unix_ms)
+VALUES (?1, ?2, ?3);
+"#;
+
+    pub(crate) const PRUNE_BEFORE: &str = r#"
+DELETE FROM usage_events
+WHERE recorded_at_unix_ms < ?1;
+"#;
+
+    pub(crate) const SELECT_DAILY_SINCE: &str = r#"
+SELECT
+  recorded_at_unix_ms / 86400000 AS day,
+  SUM(kind = 'diff_viewed'),
+  SUM(kind = 'snapshot_load'),
+  CAST(AVG(CASE WHEN kind = 'snapshot_load' THEN duration_ms END) AS INTEGER),
+  SUM(kind = 'comment_written'),
+  SUM(kind = 'push')
+FROM usage_events
+WHERE recorded_at_unix_ms >= ?1
+GROUP BY day
+ORDER BY day DESC;
 "#;
 }
 
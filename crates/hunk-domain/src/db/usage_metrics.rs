@@ -0,0 +1,142 @@
+use anyhow::{Context as _, Result};
+use rusqlite::params;
+
+use super::comments::now_unix_ms;
+use super::connection::DatabaseStore;
+use super::sql;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+/// Usage events older than this are dropped whenever a new one is recorded.
+pub const USAGE_METRICS_RETENTION_DAYS: i64 = 90;
+
+/// Something Hunk counts locally. Nothing here ever leaves the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageMetricKind {
+    DiffViewed,
+    SnapshotLoad,
+    CommentWritten,
+    Push,
+}
+
+impl UsageMetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::DiffViewed => "diff_viewed",
+            Self::SnapshotLoad => "snapshot_load",
+            Self::CommentWritten => "comment_written",
+            Self::Push => "push",
+        }
+    }
+}
+
+/// Totals for one UTC day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageMetricsDay {
+    pub day_start_unix_ms: i64,
+    pub diffs_viewed: u64,
+    pub snapshot_loads: u64,
+    pub average_snapshot_load_ms: Option<u64>,
+    pub comments_written: u64,
+    pub pushes: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageMetricsSummary {
+    /// Days with any activity, most recent first.
+    pub days: Vec<UsageMetricsDay>,
+}
+
+impl UsageMetricsSummary {
+    pub fn total_diffs_viewed(&self) -> u64 {
+        self.days.iter().map(|day| day.diffs_viewed).sum()
+    }
+
+    pub fn total_comments_written(&self) -> u64 {
+        self.days.iter().map(|day| day.comments_written).sum()
+    }
+
+    pub fn total_pushes(&self) -> u64 {
+        self.days.iter().map(|day| day.pushes).sum()
+    }
+
+    /// Average snapshot load time across all days, weighted by the number of loads.
+    pub fn average_snapshot_load_ms(&self) -> Option<u64> {
+        let (total_ms, loads) = self
+            .days
+            .iter()
+            .filter_map(|day| {
+                day.average_snapshot_load_ms
+                    .map(|average| (average * day.snapshot_loads, day.snapshot_loads))
+            })
+            .fold((0, 0), |(total_ms, loads), (day_ms, day_loads)| {
+                (total_ms + day_ms, loads + day_loads)
+            });
+        (loads > 0).then(|| total_ms / loads)
+    }
+}
+
+impl DatabaseStore {
+    pub fn record_usage_metric(
+        &self,
+        kind: UsageMetricKind,
+        duration_ms: Option<u64>,
+    ) -> Result<()> {
+        self.record_usage_metric_at(kind, duration_ms, now_unix_ms())
+    }
+
+    pub fn record_usage_metric_at(
+        &self,
+        kind: UsageMetricKind,
+        duration_ms: Option<u64>,
+        recorded_at_unix_ms: i64,
+    ) -> Result<()> {
+        let conn = self.open_connection()?;
+        let duration_ms = duration_ms.map(|duration_ms| duration_ms.min(i64::MAX as u64) as i64);
+        conn.execute(
+            sql::usage_metrics::INSERT,
+            params![kind.as_str(), duration_ms, recorded_at_unix_ms],
+        )
+        .context("failed to record usage metric")?;
+        conn.execute(
+            sql::usage_metrics::PRUNE_BEFORE,
+            params![recorded_at_unix_ms - USAGE_METRICS_RETENTION_DAYS * DAY_MS],
+        )
+        .context("failed to prune old usage metrics")?;
+        Ok(())
+    }
+
+    /// Daily totals for the last `days` UTC days, counting today.
+    pub fn usage_metrics_summary(
+        &self,
+        days: i64,
+        now_unix_ms: i64,
+    ) -> Result<UsageMetricsSummary> {
+        let today = now_unix_ms.div_euclid(DAY_MS);
+        let since_unix_ms = (today - days.max(1) + 1) * DAY_MS;
+
+        let conn = self.open_connection()?;
+        let mut stmt = conn
+            .prepare(sql::usage_metrics::SELECT_DAILY_SINCE)
+            .context("failed to prepare usage metrics query")?;
+        let rows = stmt
+            .query_map(params![since_unix_ms], |row| {
+                Ok(UsageMetricsDay {
+                    day_start_unix_ms: row.get::<_, i64>(0)? * DAY_MS,
+                    diffs_viewed: row.get::<_, i64>(1)?.max(0) as u64,
+                    snapshot_loads: row.get::<_, i64>(2)?.max(0) as u64,
+                    average_snapshot_load_ms: row
+                        .get::<_, Option<i64>>(3)?
+                        .map(|average| average.max(0) as u64),
+                    comments_written: row.get::<_, i64>(4)?.max(0) as u64,
+                    pushes: row.get::<_, i64>(5)?.max(0) as u64,
+                })
+            })
+            .context("failed to query usage metrics")?;
+
+        let mut summary = UsageMetricsSummary::default();
+        for row in rows {
+            summary.days.push(row?);
+        }
+        Ok(summary)
+    }
+}
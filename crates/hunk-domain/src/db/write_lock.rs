@@ -0,0 +1,110 @@
+use std::fmt;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context as _, Result};
+use rusqlite::{Connection, ErrorCode};
+
+use super::connection::{DatabaseStore, ensure_db_parent_dir};
+
+const WRITE_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(25);
+/// Back-off between attempts while sqlite still reports the database busy, e.g. because a
+/// `hunk serve` built before the advisory lock is writing.
+const BUSY_RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_millis(100),
+    Duration::from_millis(300),
+    Duration::from_millis(900),
+];
+
+/// Another Hunk instance kept the comment database locked for as long as a write waited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentDatabaseLocked {
+    pub lock_path: PathBuf,
+}
+
+impl fmt::Display for CommentDatabaseLocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "another Hunk instance holds the comment database ({})",
+            self.lock_path.display()
+        )
+    }
+}
+
+impl std::error::Error for CommentDatabaseLocked {}
+
+/// Whether `err` failed because another instance holds the comment database, as opposed to a
+/// broken database.
+pub fn is_comment_database_locked(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<CommentDatabaseLocked>().is_some()
+}
+
+impl DatabaseStore {
+    /// Runs `write` while holding the advisory lock every instance takes before writing comments,
+    /// so concurrent instances queue up instead of racing sqlite's busy timeout. `write` runs
+    /// again on a fresh connection when sqlite still reports the database busy. Waiting can take
+    /// up to `write_lock_timeout`, so UI code reaches this through the `DatabaseActor`.
+    pub(super) fn with_write_lock<T>(
+        &self,
+        mut write: impl FnMut(&mut Connection) -> Result<T>,
+    ) -> Result<T> {
+        let _lock = self.acquire_write_lock()?;
+        let mut delays = BUSY_RETRY_DELAYS.iter();
+        loop {
+            let result = self.open_connection().and_then(|mut conn| write(&mut conn));
+            match result {
+                Err(err) if is_sqlite_busy(&err) => match delays.next() {
+                    Some(delay) => thread::sleep(*delay),
+                    None => {
+                        return Err(err.context(CommentDatabaseLocked {
+                            lock_path: self.write_lock_path(),
+                        }));
+                    }
+                },
+                result => return result,
+            }
+        }
+    }
+
+    /// Blocks until the lock file is locked or `write_lock_timeout` passes. The lock is released
+    /// when the returned file is dropped, or by the OS if the process dies.
+    fn acquire_write_lock(&self) -> Result<File> {
+        let lock_path = self.write_lock_path();
+        ensure_db_parent_dir(lock_path.as_path())?;
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(lock_path.as_path())
+            .with_context(|| format!("failed to open {}", lock_path.display()))?;
+
+        let deadline = Instant::now() + self.write_lock_timeout;
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(file),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    thread::sleep(WRITE_LOCK_POLL_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(CommentDatabaseLocked { lock_path }.into());
+                }
+                Err(TryLockError::Error(err)) => {
+                    return Err(err)
+                        .with_context(|| format!("failed to lock {}", lock_path.display()));
+                }
+            }
+        }
+    }
+}
+
+fn is_sqlite_busy(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<rusqlite::Error>()
+            .and_then(rusqlite::Error::sqlite_error_code)
+            .is_some_and(|code| matches!(code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked))
+    })
+}
@@ -1,7 +1,15 @@
+mod changed_only;
+mod collapsed_hunks;
+mod hunk_folds;
 mod parser;
 mod side_by_side;
 mod types;
 
+pub use changed_only::{CHANGED_ONLY_SEPARATOR, changed_only_code_row_count, retain_changed_rows};
+pub use collapsed_hunks::{
+    collapsed_hunk_summary, fold_collapsed_hunks, hunk_collapse_key, hunk_collapse_key_for_header,
+};
+pub use hunk_folds::{HunkFolds, LARGE_HUNK_EDGE_ROWS, large_hunk_hidden_line_count};
 pub use parser::parse_patch_document;
 pub use side_by_side::parse_patch_side_by_side;
 pub use types::{
@@ -0,0 +1,59 @@
+use super::side_by_side::append_hunk_rows;
+use super::{DiffCell, DiffCellKind, DiffHunk, DiffRowKind, SideBySideRow};
+
+/// Text of the row that stands in for context lines hidden between two change blocks.
+pub const CHANGED_ONLY_SEPARATOR: &str = "…";
+
+/// Drops unchanged context rows, keeping hunk headers and added/removed rows.
+///
+/// Context between two change blocks of the same hunk collapses into one separator row so
+/// readers can still see where the hunk skips lines. Leading and trailing context is dropped.
+pub fn retain_changed_rows(rows: impl IntoIterator<Item = SideBySideRow>) -> Vec<SideBySideRow> {
+    let mut retained = Vec::new();
+    let mut seen_change = false;
+    let mut pending_separator = false;
+
+    for row in rows {
+        if row.kind != DiffRowKind::Code {
+            seen_change = false;
+            pending_separator = false;
+            retained.push(row);
+            continue;
+        }
+
+        if is_context_row(&row) {
+            pending_separator |= seen_change;
+            continue;
+        }
+
+        if pending_separator {
+            retained.push(separator_row());
+            pending_separator = false;
+        }
+        seen_change = true;
+        retained.push(row);
+    }
+
+    retained
+}
+
+/// Number of code rows [`retain_changed_rows`] keeps for one hunk, separators included.
+pub fn changed_only_code_row_count(hunk: &DiffHunk) -> usize {
+    let mut rows = Vec::new();
+    append_hunk_rows(hunk, &mut rows);
+    retain_changed_rows(rows)
+        .iter()
+        .filter(|row| row.kind == DiffRowKind::Code)
+        .count()
+}
+
+fn is_context_row(row: &SideBySideRow) -> bool {
+    row.left.kind == DiffCellKind::Context && row.right.kind == DiffCellKind::Context
+}
+
+fn separator_row() -> SideBySideRow {
+    SideBySideRow::code(
+        DiffCell::new(None, CHANGED_ONLY_SEPARATOR, DiffCellKind::Context),
+        DiffCell::new(None, CHANGED_ONLY_SEPARATOR, DiffCellKind::Context),
+    )
+}
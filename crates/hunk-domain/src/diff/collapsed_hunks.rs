@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+
+use super::parser::parse_hunk_header;
+use super::{DiffCellKind, DiffHunk, DiffRowKind, SideBySideRow};
+
+/// Key that identifies a hunk for collapsing: the first old-side line in its header.
+///
+/// The old side does not move while the working copy changes, so the key survives edits
+/// elsewhere in the file.
+pub fn hunk_collapse_key(hunk: &DiffHunk) -> u32 {
+    hunk.old_start.unwrap_or_default()
+}
+
+/// Same key as [`hunk_collapse_key`], read from a hunk header row's text.
+pub fn hunk_collapse_key_for_header(header: &str) -> Option<u32> {
+    parse_hunk_header(header).map(|(old_start, _)| old_start)
+}
+
+/// Folds every hunk whose key is in `collapsed` down to its header row.
+///
+/// The header text gains a summary of the hidden added and removed lines, and the hunk's
+/// code and trailing meta rows are dropped. Other rows pass through unchanged.
+pub fn fold_collapsed_hunks(
+    rows: impl IntoIterator<Item = SideBySideRow>,
+    collapsed: &BTreeSet<u32>,
+) -> Vec<SideBySideRow> {
+    let mut folded = Vec::new();
+    let mut folding_header_ix = None;
+    let mut added = 0_usize;
+    let mut removed = 0_usize;
+
+    for row in rows {
+        match row.kind {
+            DiffRowKind::HunkHeader => {
+                finish_folded_header(&mut folded, folding_header_ix.take(), added, removed);
+                added = 0;
+                removed = 0;
+                if hunk_collapse_key_for_header(row.text.as_str())
+                    .is_some_and(|key| collapsed.contains(&key))
+                {
+                    folding_header_ix = Some(folded.len());
+                }
+                folded.push(row);
+            }
+            DiffRowKind::Code | DiffRowKind::Meta if folding_header_ix.is_some() => {
+                added += usize::from(row.right.kind == DiffCellKind::Added);
+                removed += usize::from(row.left.kind == DiffCellKind::Removed);
+            }
+            _ => folded.push(row),
+        }
+    }
+    finish_folded_header(&mut folded, folding_header_ix, added, removed);
+
+    folded
+}
+
+/// Separates a folded hunk's header from the summary [`fold_collapsed_hunks`] appends.
+const COLLAPSED_HUNK_SUMMARY_SEPARATOR: &str = "  \u{22ef} ";
+
+/// Header row text for a hunk folded by [`fold_collapsed_hunks`].
+fn collapsed_hunk_header_text(header: &str, added: usize, removed: usize) -> String {
+    let hidden = added.saturating_add(removed);
+    let noun = if hidden == 1 { "line" } else { "lines" };
+    format!(
+        "{header}{COLLAPSED_HUNK_SUMMARY_SEPARATOR}±{hidden} {noun} hidden (+{added} -{removed})"
+    )
+}
+
+/// The hidden-lines summary of a folded hunk header row, or `None` for an expanded hunk.
+pub fn collapsed_hunk_summary(header_text: &str) -> Option<&str> {
+    header_text
+        .split_once(COLLAPSED_HUNK_SUMMARY_SEPARATOR)
+        .map(|(_, summary)| summary)
+}
+
+fn finish_folded_header(
+    rows: &mut [SideBySideRow],
+    header_ix: Option<usize>,
+    added: usize,
+    removed: usize,
+) {
+    let Some(header) = header_ix.and_then(|ix| rows.get_mut(ix)) else {
+        return;
+    };
+    header.text = collapsed_hunk_header_text(header.text.as_str(), added, removed);
+}
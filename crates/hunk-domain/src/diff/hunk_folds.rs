@@ -0,0 +1,218 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::changed_only::{changed_only_code_row_count, retain_changed_rows};
+use super::collapsed_hunks::{
+    fold_collapsed_hunks, hunk_collapse_key, hunk_collapse_key_for_header,
+};
+use super::side_by_side::append_hunk_rows;
+use super::{DiffCell, DiffCellKind, DiffHunk, DiffRowKind, SideBySideRow};
+
+/// Code rows kept visible at each end of an automatically folded large hunk.
+pub const LARGE_HUNK_EDGE_ROWS: usize = 3;
+
+const LARGE_HUNK_EXPANDER_PREFIX: &str = "\u{22ef} Show ";
+const LARGE_HUNK_EXPANDER_SUFFIX: &str = " hidden lines";
+
+/// How the hunks of each file in a review stream are folded, keyed by file path and
+/// [`hunk_collapse_key`].
+///
+/// Readers collapse hunks down to their header row by hand. Hunks with more code rows than
+/// the large-hunk threshold fold their middle into one expander row automatically until the
+/// reader expands them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HunkFolds {
+    collapsed: BTreeMap<String, BTreeSet<u32>>,
+    expanded_large: BTreeMap<String, BTreeSet<u32>>,
+    large_hunk_threshold: usize,
+}
+
+impl HunkFolds {
+    /// Folds hunks with more than `threshold` code rows; `0` turns automatic folding off.
+    pub fn set_large_hunk_threshold(&mut self, threshold: usize) {
+        self.large_hunk_threshold = threshold;
+    }
+
+    pub fn is_collapsed(&self, path: &str, key: u32) -> bool {
+        self.collapsed
+            .get(path)
+            .is_some_and(|keys| keys.contains(&key))
+    }
+
+    pub fn has_collapsed_hunks(&self, path: &str) -> bool {
+        self.collapsed.contains_key(path)
+    }
+
+    pub fn toggle_collapsed(&mut self, path: &str, key: u32) {
+        let keys = self.collapsed.entry(path.to_string()).or_default();
+        if !keys.remove(&key) {
+            keys.insert(key);
+        }
+        if keys.is_empty() {
+            self.collapsed.remove(path);
+        }
+    }
+
+    pub fn collapse_all(&mut self, path: &str, keys: impl IntoIterator<Item = u32>) {
+        let keys = keys.into_iter().collect::<BTreeSet<_>>();
+        if keys.is_empty() {
+            self.collapsed.remove(path);
+        } else {
+            self.collapsed.insert(path.to_string(), keys);
+        }
+    }
+
+    /// Expands collapsed hunks of `path` and the large hunks among `keys`.
+    pub fn expand_all(&mut self, path: &str, keys: impl IntoIterator<Item = u32>) {
+        self.collapsed.remove(path);
+        self.expanded_large
+            .entry(path.to_string())
+            .or_default()
+            .extend(keys);
+    }
+
+    pub fn expand_large_hunk(&mut self, path: &str, key: u32) {
+        self.expanded_large
+            .entry(path.to_string())
+            .or_default()
+            .insert(key);
+    }
+
+    /// Whether any hunk of `hunks` shows fewer rows than it would unfolded.
+    pub fn folds_rows(&self, path: &str, hunks: &[DiffHunk]) -> bool {
+        hunks.iter().any(|hunk| {
+            let key = hunk_collapse_key(hunk);
+            self.is_collapsed(path, key)
+                || self.folds_large_hunk(path, key, full_code_row_count(hunk))
+        })
+    }
+
+    /// Applies the folds for `path` to its side-by-side rows.
+    ///
+    /// `changed_lines_only` drops context rows before large hunks are measured, so the
+    /// threshold counts the rows the reader would actually scroll past.
+    pub fn fold_rows(
+        &self,
+        path: &str,
+        rows: Vec<SideBySideRow>,
+        changed_lines_only: bool,
+    ) -> Vec<SideBySideRow> {
+        let rows = match self.collapsed.get(path) {
+            Some(keys) => fold_collapsed_hunks(rows, keys),
+            None => rows,
+        };
+        let rows = if changed_lines_only {
+            retain_changed_rows(rows)
+        } else {
+            rows
+        };
+        if self.large_hunk_threshold == 0 {
+            return rows;
+        }
+
+        let mut folded = Vec::with_capacity(rows.len());
+        let mut hunk_rows = Vec::new();
+        let mut hunk_key = None;
+        for row in rows {
+            if row.kind == DiffRowKind::HunkHeader {
+                self.push_hunk_rows(path, hunk_key, &mut hunk_rows, &mut folded);
+                hunk_key = hunk_collapse_key_for_header(row.text.as_str());
+                folded.push(row);
+            } else if hunk_key.is_some() {
+                hunk_rows.push(row);
+            } else {
+                folded.push(row);
+            }
+        }
+        self.push_hunk_rows(path, hunk_key, &mut hunk_rows, &mut folded);
+
+        folded
+    }
+
+    /// Number of code rows [`HunkFolds::fold_rows`] keeps for `hunk` of `path`.
+    pub fn code_row_count(&self, path: &str, hunk: &DiffHunk, changed_lines_only: bool) -> usize {
+        let key = hunk_collapse_key(hunk);
+        if self.is_collapsed(path, key) {
+            return 0;
+        }
+        let code_rows = if changed_lines_only {
+            changed_only_code_row_count(hunk)
+        } else {
+            full_code_row_count(hunk)
+        };
+        if self.folds_large_hunk(path, key, code_rows) {
+            LARGE_HUNK_EDGE_ROWS * 2 + 1
+        } else {
+            code_rows
+        }
+    }
+
+    fn folds_large_hunk(&self, path: &str, key: u32, code_rows: usize) -> bool {
+        self.large_hunk_threshold > 0
+            && code_rows > self.large_hunk_threshold.max(LARGE_HUNK_EDGE_ROWS * 2 + 1)
+            && !self
+                .expanded_large
+                .get(path)
+                .is_some_and(|keys| keys.contains(&key))
+    }
+
+    fn push_hunk_rows(
+        &self,
+        path: &str,
+        hunk_key: Option<u32>,
+        hunk_rows: &mut Vec<SideBySideRow>,
+        folded: &mut Vec<SideBySideRow>,
+    ) {
+        let code_rows = hunk_rows
+            .iter()
+            .filter(|row| row.kind == DiffRowKind::Code)
+            .count();
+        let fold = hunk_key.is_some_and(|key| self.folds_large_hunk(path, key, code_rows));
+        if !fold {
+            folded.append(hunk_rows);
+            return;
+        }
+
+        let hidden_rows = code_rows - LARGE_HUNK_EDGE_ROWS * 2;
+        let mut code_ix = 0_usize;
+        for row in hunk_rows.drain(..) {
+            if row.kind != DiffRowKind::Code {
+                folded.push(row);
+                continue;
+            }
+            if code_ix == LARGE_HUNK_EDGE_ROWS {
+                folded.push(large_hunk_expander_row(hidden_rows));
+            }
+            if code_ix < LARGE_HUNK_EDGE_ROWS || code_ix >= LARGE_HUNK_EDGE_ROWS + hidden_rows {
+                folded.push(row);
+            }
+            code_ix += 1;
+        }
+    }
+}
+
+/// Number of rows hidden behind a large hunk's expander row, read from the row's text.
+pub fn large_hunk_hidden_line_count(row_text: &str) -> Option<usize> {
+    row_text
+        .strip_prefix(LARGE_HUNK_EXPANDER_PREFIX)?
+        .strip_suffix(LARGE_HUNK_EXPANDER_SUFFIX)?
+        .parse()
+        .ok()
+}
+
+fn large_hunk_expander_row(hidden_rows: usize) -> SideBySideRow {
+    let label = format!("{LARGE_HUNK_EXPANDER_PREFIX}{hidden_rows}{LARGE_HUNK_EXPANDER_SUFFIX}");
+    let mut row = SideBySideRow::code(
+        DiffCell::new(None, label.clone(), DiffCellKind::Context),
+        DiffCell::new(None, label.clone(), DiffCellKind::Context),
+    );
+    row.text = label;
+    row
+}
+
+fn full_code_row_count(hunk: &DiffHunk) -> usize {
+    let mut rows = Vec::new();
+    append_hunk_rows(hunk, &mut rows);
+    rows.iter()
+        .filter(|row| row.kind == DiffRowKind::Code)
+        .count()
+}
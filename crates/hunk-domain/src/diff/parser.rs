@@ -106,7 +106,7 @@ fn is_meta_line(line: &str) -> bool {
         || line.starts_with("\\ No newline at end of file")
 }
 
-fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
+pub(super) fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
     let left_marker = line.find('-')?;
     let right_marker = line.find('+')?;
 
@@ -26,7 +26,7 @@ pub fn parse_patch_side_by_side(patch: &str) -> Vec<SideBySideRow> {
     rows
 }
 
-fn append_hunk_rows(hunk: &DiffHunk, rows: &mut Vec<SideBySideRow>) {
+pub(super) fn append_hunk_rows(hunk: &DiffHunk, rows: &mut Vec<SideBySideRow>) {
     rows.push(SideBySideRow::meta(
         DiffRowKind::HunkHeader,
         hunk.header.clone(),
@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+const EDITOR_RECOVERY_DIR_NAME: &str = "editor-recovery";
+const EDITOR_RECOVERY_EXTENSION: &str = "json";
+
+/// Unsaved contents of one editor buffer, written so a crash or a discarded tab does not lose
+/// them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecoveredEditorBuffer {
+    pub repo_root: PathBuf,
+    /// Repository-relative path of the edited file.
+    pub path: String,
+    pub text: String,
+    pub saved_unix_time: i64,
+}
+
+/// Scratch copies of dirty editor buffers in `~/.hunkdiff/editor-recovery`, one directory per
+/// repository and one JSON file per edited path.
+#[derive(Debug, Clone)]
+pub struct EditorRecoveryStore {
+    dir: PathBuf,
+}
+
+impl EditorRecoveryStore {
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_dir(
+            crate::paths::hunk_home_dir()?.join(EDITOR_RECOVERY_DIR_NAME),
+        ))
+    }
+
+    pub fn from_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Replaces the scratch copy of `buffer.path` stored under `repo_key`.
+    pub fn save(&self, repo_key: &str, buffer: &RecoveredEditorBuffer) -> Result<PathBuf> {
+        let repo_dir = self.dir.join(repo_key);
+        fs::create_dir_all(&repo_dir).with_context(|| {
+            format!(
+                "failed to create editor recovery directory {}",
+                repo_dir.display()
+            )
+        })?;
+        let path = self.buffer_path(repo_key, buffer.path.as_str());
+        let temp_path = path.with_extension("tmp");
+        let raw = serde_json::to_vec(buffer).context("failed to serialize editor buffer")?;
+        fs::write(&temp_path, raw)
+            .with_context(|| format!("failed to write editor recovery {}", temp_path.display()))?;
+        fs::rename(&temp_path, &path)
+            .with_context(|| format!("failed to write editor recovery {}", path.display()))?;
+        Ok(path)
+    }
+
+    pub fn load(&self, repo_key: &str, path: &str) -> Result<Option<RecoveredEditorBuffer>> {
+        let buffer_path = self.buffer_path(repo_key, path);
+        let raw = match fs::read(&buffer_path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("failed to read editor recovery {}", buffer_path.display())
+                });
+            }
+        };
+        let buffer: RecoveredEditorBuffer = serde_json::from_slice(&raw).with_context(|| {
+            format!("failed to parse editor recovery {}", buffer_path.display())
+        })?;
+        // File names are hashed, so a collision must not hand one file's edits to another.
+        Ok((buffer.path == path).then_some(buffer))
+    }
+
+    /// Every scratch copy stored under `repo_key`, ordered by path. Unreadable files are skipped.
+    pub fn list(&self, repo_key: &str) -> Result<Vec<RecoveredEditorBuffer>> {
+        let repo_dir = self.dir.join(repo_key);
+        let entries = match fs::read_dir(&repo_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "failed to read editor recovery directory {}",
+                        repo_dir.display()
+                    )
+                });
+            }
+        };
+        let mut buffers = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().and_then(|extension| extension.to_str())
+                    == Some(EDITOR_RECOVERY_EXTENSION)
+            })
+            .filter_map(|path| fs::read(path).ok())
+            .filter_map(|raw| serde_json::from_slice::<RecoveredEditorBuffer>(&raw).ok())
+            .collect::<Vec<_>>();
+        buffers.sort_by(|left, right| left.path.cmp(&right.path));
+        Ok(buffers)
+    }
+
+    /// Drops the scratch copy of `path`, once it was saved or the user declined to restore it.
+    pub fn remove(&self, repo_key: &str, path: &str) -> Result<()> {
+        let buffer_path = self.buffer_path(repo_key, path);
+        match fs::remove_file(&buffer_path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).with_context(|| {
+                format!("failed to remove editor recovery {}", buffer_path.display())
+            }),
+        }
+    }
+
+    fn buffer_path(&self, repo_key: &str, path: &str) -> PathBuf {
+        let file_name = path
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_') {
+                    ch
+                } else {
+                    '-'
+                }
+            })
+            .collect::<String>();
+        self.dir.join(repo_key).join(format!(
+            "{file_name}-{:016x}.{EDITOR_RECOVERY_EXTENSION}",
+            fnv1a64(path.as_bytes())
+        ))
+    }
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
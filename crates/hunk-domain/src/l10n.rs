@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+const ENGLISH_CATALOG: &str = include_str!("../locales/en.ftl");
+const SPANISH_CATALOG: &str = include_str!("../locales/es.ftl");
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Self; 2] = [Self::English, Self::Spanish];
+
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+        }
+    }
+
+    /// Name of the language in that language, for the language picker.
+    pub const fn native_name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Español",
+        }
+    }
+
+    fn bundled_catalog(self) -> &'static str {
+        match self {
+            Self::English => ENGLISH_CATALOG,
+            Self::Spanish => SPANISH_CATALOG,
+        }
+    }
+}
+
+/// Messages from a Fluent-style catalog.
+///
+/// Each message is `key = text`. Indented lines continue the previous message on a new line,
+/// `#` starts a comment, and `{ $name }` marks a placeholder filled in by [`Catalog::format`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    messages: BTreeMap<String, String>,
+}
+
+impl Catalog {
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut messages = BTreeMap::<String, String>::new();
+        let mut current_key: Option<String> = None;
+        for (line_ix, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) {
+                let Some(text) = current_key
+                    .as_ref()
+                    .and_then(|key| messages.get_mut(key.as_str()))
+                else {
+                    bail!("line {}: continuation without a message", line_ix + 1);
+                };
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(trimmed);
+                continue;
+            }
+            let Some((key, text)) = line.split_once('=') else {
+                bail!("line {}: expected `key = text`", line_ix + 1);
+            };
+            let key = key.trim();
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+            {
+                bail!("line {}: invalid message key `{key}`", line_ix + 1);
+            }
+            if messages
+                .insert(key.to_string(), text.trim().to_string())
+                .is_some()
+            {
+                bail!("line {}: duplicate message `{key}`", line_ix + 1);
+            }
+            current_key = Some(key.to_string());
+        }
+        Ok(Self { messages })
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+
+    /// Placeholder names used by `key`, in order of appearance.
+    pub fn placeholders(&self, key: &str) -> Vec<String> {
+        let Some(text) = self.messages.get(key) else {
+            return Vec::new();
+        };
+        let mut names = Vec::new();
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find("{ $") {
+            let after = &rest[start + 3..];
+            let Some(end) = after.find(" }") else {
+                break;
+            };
+            names.push(after[..end].to_string());
+            rest = &after[end + 2..];
+        }
+        names
+    }
+
+    /// The message for `key` with its placeholders replaced by `args`. Placeholders without an
+    /// argument are left as written.
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        let mut text = self.messages.get(key)?.clone();
+        for (name, value) in args {
+            text = text.replace(format!("{{ ${name} }}").as_str(), value);
+        }
+        Some(text)
+    }
+}
+
+/// Looks messages up in the selected language, then in English, then falls back to the key so
+/// a missing translation never shows an empty label.
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    language: Language,
+    catalog: Catalog,
+    fallback: Catalog,
+}
+
+impl Localizer {
+    pub fn new(language: Language) -> Result<Self> {
+        Ok(Self {
+            language,
+            catalog: Catalog::parse(language.bundled_catalog())?,
+            fallback: Catalog::parse(Language::English.bundled_catalog())?,
+        })
+    }
+
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn text(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        self.catalog
+            .format(key, args)
+            .or_else(|| self.fallback.format(key, args))
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self::new(Language::English).unwrap_or_else(|_| Self {
+            language: Language::English,
+            catalog: Catalog::default(),
+            fallback: Catalog::default(),
+        })
+    }
+}
+
+/// The catalog shipped for `language`.
+pub fn bundled_catalog(language: Language) -> Result<Catalog> {
+    Catalog::parse(language.bundled_catalog())
+}
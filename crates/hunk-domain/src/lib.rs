@@ -1,6 +1,18 @@
+#[cfg(feature = "gui")]
+pub mod bidi;
+pub mod change_set_size;
+pub mod comment_import;
 pub mod config;
+pub mod crash;
 pub mod db;
 pub mod diff;
+pub mod editor_recovery;
+pub mod l10n;
+#[cfg(feature = "gui")]
 pub mod markdown_preview;
+pub mod mechanical_changes;
 pub mod paths;
+pub mod review_export;
+pub mod snapshot_cache;
 pub mod state;
+pub mod unicode_hazards;
@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Fewest files a substitution has to span before it is grouped as a mechanical change.
+pub const MECHANICAL_CHANGE_MIN_FILES: usize = 3;
+/// Changed lines kept per group to preview the substitution.
+pub const MECHANICAL_CHANGE_SAMPLE_LINES: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MechanicalChangeSample {
+    pub path: String,
+    pub removed: String,
+    pub added: String,
+}
+
+/// Files whose every changed line applies the same `from` to `to` token substitution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MechanicalChangeGroup {
+    pub from: String,
+    pub to: String,
+    pub paths: Vec<String>,
+    pub changed_lines: usize,
+    pub samples: Vec<MechanicalChangeSample>,
+}
+
+/// Mechanical change groups approved as a whole, keyed by `(from, to)`, with the files each
+/// approval collapsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MechanicalChangeApprovals {
+    collapsed_by_group: BTreeMap<(String, String), BTreeSet<String>>,
+}
+
+impl MechanicalChangeApprovals {
+    pub fn is_approved(&self, group: &MechanicalChangeGroup) -> bool {
+        self.collapsed_by_group
+            .contains_key(&(group.from.clone(), group.to.clone()))
+    }
+
+    /// Approves `group` by collapsing its files, or clears the approval and expands only the
+    /// files it collapsed. Files that were already collapsed stay as they were.
+    pub fn toggle(
+        &mut self,
+        group: &MechanicalChangeGroup,
+        collapsed_files: &mut BTreeSet<String>,
+    ) {
+        let key = (group.from.clone(), group.to.clone());
+        if let Some(collapsed) = self.collapsed_by_group.remove(&key) {
+            for path in &collapsed {
+                collapsed_files.remove(path.as_str());
+            }
+            return;
+        }
+
+        let collapsed = group
+            .paths
+            .iter()
+            .filter(|path| collapsed_files.insert((*path).clone()))
+            .cloned()
+            .collect();
+        self.collapsed_by_group.insert(key, collapsed);
+    }
+
+    /// Drops the approvals of groups that are no longer detected.
+    pub fn retain_groups(&mut self, groups: &[MechanicalChangeGroup]) {
+        self.collapsed_by_group.retain(|(from, to), _| {
+            groups
+                .iter()
+                .any(|group| group.from == *from && group.to == *to)
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileSubstitution {
+    from: String,
+    to: String,
+    pairs: Vec<(String, String)>,
+}
+
+/// Groups files that make the same substitution on every changed line, largest group first.
+///
+/// A file counts when each removed line is replaced by one added line that differs from it
+/// only in tokens rewritten the same way, such as a renamed identifier. Files that also add or
+/// drop lines are left out.
+pub fn detect_mechanical_changes<'a>(
+    patches: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Vec<MechanicalChangeGroup> {
+    let mut groups = BTreeMap::<(String, String), MechanicalChangeGroup>::new();
+    for (path, patch) in patches {
+        let Some(substitution) = patch_substitution(patch) else {
+            continue;
+        };
+        let group = groups
+            .entry((substitution.from.clone(), substitution.to.clone()))
+            .or_insert_with(|| MechanicalChangeGroup {
+                from: substitution.from,
+                to: substitution.to,
+                paths: Vec::new(),
+                changed_lines: 0,
+                samples: Vec::new(),
+            });
+        group.paths.push(path.to_string());
+        group.changed_lines += substitution.pairs.len();
+        if group.samples.len() < MECHANICAL_CHANGE_SAMPLE_LINES
+            && let Some((removed, added)) = substitution.pairs.into_iter().next()
+        {
+            group.samples.push(MechanicalChangeSample {
+                path: path.to_string(),
+                removed,
+                added,
+            });
+        }
+    }
+
+    let mut groups = groups
+        .into_values()
+        .filter(|group| group.paths.len() >= MECHANICAL_CHANGE_MIN_FILES)
+        .collect::<Vec<_>>();
+    groups.sort_by(|left, right| right.paths.len().cmp(&left.paths.len()));
+    groups
+}
+
+fn patch_substitution(patch: &str) -> Option<FileSubstitution> {
+    let mut pairs = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let mut in_hunk = false;
+    for line in patch.lines() {
+        if line.starts_with("@@") {
+            in_hunk = true;
+        } else if !in_hunk || line.starts_with('\\') {
+            continue;
+        } else if let Some(text) = line.strip_prefix('-') {
+            if !added.is_empty() {
+                if removed.len() != added.len() {
+                    return None;
+                }
+                pairs.extend(removed.drain(..).zip(added.drain(..)));
+            }
+            removed.push(text);
+            continue;
+        } else if let Some(text) = line.strip_prefix('+') {
+            added.push(text);
+            continue;
+        }
+        if removed.len() != added.len() {
+            return None;
+        }
+        pairs.extend(removed.drain(..).zip(added.drain(..)));
+    }
+    if removed.len() != added.len() {
+        return None;
+    }
+    pairs.extend(removed.drain(..).zip(added.drain(..)));
+
+    let mut substitution = None::<(&str, &str)>;
+    for &(old, new) in &pairs {
+        let pair = line_substitution(old, new)?;
+        if substitution.is_some_and(|substitution| substitution != pair) {
+            return None;
+        }
+        substitution = Some(pair);
+    }
+    let (from, to) = substitution?;
+    Some(FileSubstitution {
+        from: from.to_string(),
+        to: to.to_string(),
+        pairs: pairs
+            .into_iter()
+            .map(|(old, new)| (old.to_string(), new.to_string()))
+            .collect(),
+    })
+}
+
+/// The one token rewrite that turns `old` into `new`, if every differing token changes the
+/// same way.
+fn line_substitution<'a>(old: &'a str, new: &'a str) -> Option<(&'a str, &'a str)> {
+    let old_tokens = tokens(old);
+    let new_tokens = tokens(new);
+    if old_tokens.len() != new_tokens.len() {
+        return None;
+    }
+    let mut substitution = None;
+    for (old_token, new_token) in old_tokens.into_iter().zip(new_tokens) {
+        if old_token == new_token {
+            continue;
+        }
+        if substitution.is_some_and(|substitution| substitution != (old_token, new_token)) {
+            return None;
+        }
+        substitution = Some((old_token, new_token));
+    }
+    substitution
+}
+
+/// Splits `line` into identifier runs and single other characters.
+fn tokens(line: &str) -> Vec<&str> {
+    let is_identifier = |ch: char| ch.is_alphanumeric() || ch == '_';
+    let mut tokens = Vec::new();
+    let mut identifier_start = None;
+    for (offset, ch) in line.char_indices() {
+        if is_identifier(ch) {
+            identifier_start.get_or_insert(offset);
+            continue;
+        }
+        if let Some(start) = identifier_start.take() {
+            tokens.push(&line[start..offset]);
+        }
+        tokens.push(&line[offset..offset + ch.len_utf8()]);
+    }
+    if let Some(start) = identifier_start {
+        tokens.push(&line[start..]);
+    }
+    tokens
+}
@@ -0,0 +1,483 @@
+//! Paginated PDF export of a review.
+//!
+//! The writer only uses the standard Courier faces, so the output needs no embedded fonts.
+//! Those faces cover printable ASCII; other characters are written as `?`.
+
+use std::fmt::Write as _;
+
+pub type ReviewExportRgb = [u8; 3];
+
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const PAGE_MARGIN: f32 = 40.0;
+const CODE_FONT_SIZE: f32 = 8.0;
+const COURIER_CHAR_WIDTH_EM: f32 = 0.6;
+const LINE_SPACING: f32 = 1.3;
+const PAGE_HEADER_HEIGHT: f32 = 20.0;
+const TAB_WIDTH: usize = 4;
+const GUTTER_COLUMNS: usize = 14;
+
+const TEXT_COLOR: ReviewExportRgb = [0x24, 0x29, 0x2f];
+const MUTED_COLOR: ReviewExportRgb = [0x6e, 0x77, 0x81];
+const ADDED_BACKGROUND: ReviewExportRgb = [0xe6, 0xff, 0xec];
+const REMOVED_BACKGROUND: ReviewExportRgb = [0xff, 0xeb, 0xe9];
+const HUNK_HEADER_BACKGROUND: ReviewExportRgb = [0xdd, 0xf4, 0xff];
+const COMMENT_BACKGROUND: ReviewExportRgb = [0xff, 0xf8, 0xc5];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewExportColorMode {
+    Color,
+    Grayscale,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewExportDocument {
+    pub title: String,
+    pub subtitle: String,
+    pub files: Vec<ReviewExportFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewExportFile {
+    pub path: String,
+    pub status: String,
+    pub added: u64,
+    pub removed: u64,
+    pub lines: Vec<ReviewExportLine>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewExportLineKind {
+    HunkHeader,
+    Context,
+    Added,
+    Removed,
+    Comment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewExportLine {
+    pub kind: ReviewExportLineKind,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    pub spans: Vec<ReviewExportSpan>,
+}
+
+impl ReviewExportLine {
+    pub fn plain(kind: ReviewExportLineKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            old_line: None,
+            new_line: None,
+            spans: vec![ReviewExportSpan {
+                text: text.into(),
+                color: None,
+            }],
+        }
+    }
+}
+
+/// A run of text in one color. `None` uses the default text color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewExportSpan {
+    pub text: String,
+    pub color: Option<ReviewExportRgb>,
+}
+
+#[derive(Debug, Clone)]
+struct LayoutLine {
+    bold: bool,
+    font_size: f32,
+    background: Option<ReviewExportRgb>,
+    runs: Vec<(String, ReviewExportRgb)>,
+}
+
+impl LayoutLine {
+    fn text(bold: bool, font_size: f32, text: &str, color: ReviewExportRgb) -> Self {
+        Self {
+            bold,
+            font_size,
+            background: None,
+            runs: vec![(pdf_safe_text(text), color)],
+        }
+    }
+
+    fn blank() -> Self {
+        Self {
+            bold: false,
+            font_size: CODE_FONT_SIZE,
+            background: None,
+            runs: Vec::new(),
+        }
+    }
+
+    fn height(&self) -> f32 {
+        self.font_size * LINE_SPACING
+    }
+}
+
+/// Renders the review as a PDF: a summary page listing the changed files, followed by each
+/// file's diff starting on a new page with its comments inline.
+pub fn render_review_pdf(document: &ReviewExportDocument, mode: ReviewExportColorMode) -> Vec<u8> {
+    let pages = layout_pages(document);
+    let page_count = pages.len();
+    let contents = pages
+        .iter()
+        .enumerate()
+        .map(|(ix, page)| page_content(document, page, ix + 1, page_count, mode))
+        .collect::<Vec<_>>();
+    write_pdf(document.title.as_str(), contents)
+}
+
+fn layout_pages(document: &ReviewExportDocument) -> Vec<Vec<LayoutLine>> {
+    let mut pages = Vec::new();
+    let mut summary = vec![
+        LayoutLine::text(true, 16.0, document.title.as_str(), TEXT_COLOR),
+        LayoutLine::text(false, 10.0, document.subtitle.as_str(), MUTED_COLOR),
+        LayoutLine::blank(),
+        LayoutLine::text(
+            true,
+            11.0,
+            format!("Changed files ({})", document.files.len()).as_str(),
+            TEXT_COLOR,
+        ),
+    ];
+    summary.extend(document.files.iter().map(|file| {
+        let added = format!("+{}", file.added);
+        let removed = format!("-{}", file.removed);
+        LayoutLine::text(
+            false,
+            CODE_FONT_SIZE,
+            format!("{:<2} {added:>7} {removed:>7}  {}", file.status, file.path).as_str(),
+            TEXT_COLOR,
+        )
+    }));
+    paginate(summary, &mut pages);
+
+    for file in &document.files {
+        let mut lines = vec![
+            LayoutLine::text(
+                true,
+                11.0,
+                format!(
+                    "{}  ({}, +{} -{})",
+                    file.path, file.status, file.added, file.removed
+                )
+                .as_str(),
+                TEXT_COLOR,
+            ),
+            LayoutLine::blank(),
+        ];
+        for line in &file.lines {
+            lines.extend(layout_diff_line(line));
+        }
+        if file.lines.is_empty() {
+            lines.push(LayoutLine::text(
+                false,
+                CODE_FONT_SIZE,
+                "No textual changes.",
+                MUTED_COLOR,
+            ));
+        }
+        paginate(lines, &mut pages);
+    }
+    pages
+}
+
+fn paginate(lines: Vec<LayoutLine>, pages: &mut Vec<Vec<LayoutLine>>) {
+    let available = PAGE_HEIGHT - PAGE_MARGIN * 2.0 - PAGE_HEADER_HEIGHT;
+    let mut page = Vec::new();
+    let mut used = 0.0;
+    for line in lines {
+        if used + line.height() > available && !page.is_empty() {
+            pages.push(std::mem::take(&mut page));
+            used = 0.0;
+        }
+        used += line.height();
+        page.push(line);
+    }
+    pages.push(page);
+}
+
+fn layout_diff_line(line: &ReviewExportLine) -> Vec<LayoutLine> {
+    let (marker, background) = match line.kind {
+        ReviewExportLineKind::HunkHeader => (' ', Some(HUNK_HEADER_BACKGROUND)),
+        ReviewExportLineKind::Context => (' ', None),
+        ReviewExportLineKind::Added => ('+', Some(ADDED_BACKGROUND)),
+        ReviewExportLineKind::Removed => ('-', Some(REMOVED_BACKGROUND)),
+        ReviewExportLineKind::Comment => ('#', Some(COMMENT_BACKGROUND)),
+    };
+    let gutter = format!(
+        "{:>5} {:>5} {marker} ",
+        line.old_line
+            .map(|line| line.to_string())
+            .unwrap_or_default(),
+        line.new_line
+            .map(|line| line.to_string())
+            .unwrap_or_default(),
+    );
+    let continuation = " ".repeat(GUTTER_COLUMNS);
+    let default_color = match line.kind {
+        ReviewExportLineKind::HunkHeader => MUTED_COLOR,
+        _ => TEXT_COLOR,
+    };
+    let runs = line
+        .spans
+        .iter()
+        .map(|span| {
+            (
+                pdf_safe_text(span.text.as_str()),
+                span.color.unwrap_or(default_color),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    wrap_runs(runs, code_columns() - GUTTER_COLUMNS)
+        .into_iter()
+        .enumerate()
+        .map(|(ix, mut runs)| {
+            let prefix = if ix == 0 {
+                gutter.clone()
+            } else {
+                continuation.clone()
+            };
+            runs.insert(0, (prefix, MUTED_COLOR));
+            LayoutLine {
+                bold: false,
+                font_size: CODE_FONT_SIZE,
+                background,
+                runs,
+            }
+        })
+        .collect()
+}
+
+fn code_columns() -> usize {
+    ((PAGE_WIDTH - PAGE_MARGIN * 2.0) / (CODE_FONT_SIZE * COURIER_CHAR_WIDTH_EM)) as usize
+}
+
+/// Splits colored runs into rows of at most `columns` characters. Every input produces at
+/// least one row so empty diff lines still take up space.
+fn wrap_runs(
+    runs: Vec<(String, ReviewExportRgb)>,
+    columns: usize,
+) -> Vec<Vec<(String, ReviewExportRgb)>> {
+    let mut rows = vec![Vec::new()];
+    let mut row_len = 0;
+    for (text, color) in runs {
+        let mut rest = text.as_str();
+        while !rest.is_empty() {
+            if row_len == columns {
+                rows.push(Vec::new());
+                row_len = 0;
+            }
+            let take = rest.len().min(columns - row_len);
+            let (head, tail) = rest.split_at(take);
+            if let Some(row) = rows.last_mut() {
+                row.push((head.to_string(), color));
+            }
+            row_len += take;
+            rest = tail;
+        }
+    }
+    rows
+}
+
+/// Expands tabs and replaces anything the standard fonts cannot show. The result is ASCII, so
+/// byte offsets and character columns line up.
+fn pdf_safe_text(text: &str) -> String {
+    let mut safe = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\t' => {
+                let pad = TAB_WIDTH - safe.len() % TAB_WIDTH;
+                safe.extend(std::iter::repeat_n(' ', pad));
+            }
+            ' '..='~' => safe.push(ch),
+            '\r' | '\n' => {}
+            _ => safe.push('?'),
+        }
+    }
+    safe
+}
+
+fn escape_pdf_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '(' | ')' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn page_content(
+    document: &ReviewExportDocument,
+    lines: &[LayoutLine],
+    page_number: usize,
+    page_count: usize,
+    mode: ReviewExportColorMode,
+) -> String {
+    let mut content = String::new();
+    let left = PAGE_MARGIN;
+    let right = PAGE_WIDTH - PAGE_MARGIN;
+    let header_y = PAGE_HEIGHT - PAGE_MARGIN;
+
+    let footer = format!("Page {page_number} of {page_count}");
+    let footer_x = right - footer.len() as f32 * CODE_FONT_SIZE * COURIER_CHAR_WIDTH_EM;
+    push_text(
+        &mut content,
+        "F1",
+        CODE_FONT_SIZE,
+        left,
+        header_y,
+        &[(pdf_safe_text(document.title.as_str()), MUTED_COLOR)],
+        mode,
+    );
+    push_text(
+        &mut content,
+        "F1",
+        CODE_FONT_SIZE,
+        footer_x,
+        header_y,
+        &[(footer, MUTED_COLOR)],
+        mode,
+    );
+    push_fill_color(&mut content, MUTED_COLOR, mode);
+    let _ = writeln!(
+        content,
+        "{left:.2} {:.2} {:.2} 0.5 re f",
+        header_y - 6.0,
+        right - left
+    );
+
+    let mut y = header_y - PAGE_HEADER_HEIGHT;
+    for line in lines {
+        let height = line.height();
+        if let Some(background) = line.background {
+            push_fill_color(&mut content, background, mode);
+            let _ = writeln!(
+                content,
+                "{left:.2} {:.2} {:.2} {height:.2} re f",
+                y - height,
+                right - left
+            );
+        }
+        let font = if line.bold { "F2" } else { "F1" };
+        let baseline = y - line.font_size;
+        push_text(
+            &mut content,
+            font,
+            line.font_size,
+            left,
+            baseline,
+            &line.runs,
+            mode,
+        );
+        y -= height;
+    }
+    content
+}
+
+fn push_text(
+    content: &mut String,
+    font: &str,
+    font_size: f32,
+    x: f32,
+    y: f32,
+    runs: &[(String, ReviewExportRgb)],
+    mode: ReviewExportColorMode,
+) {
+    if runs.iter().all(|(text, _)| text.is_empty()) {
+        return;
+    }
+    let _ = writeln!(content, "BT /{font} {font_size:.1} Tf {x:.2} {y:.2} Td");
+    for (text, color) in runs {
+        push_fill_color(content, *color, mode);
+        let _ = writeln!(content, "({}) Tj", escape_pdf_string(text));
+    }
+    content.push_str("ET\n");
+}
+
+fn push_fill_color(content: &mut String, color: ReviewExportRgb, mode: ReviewExportColorMode) {
+    let [red, green, blue] = color.map(|component| f32::from(component) / 255.0);
+    match mode {
+        ReviewExportColorMode::Color => {
+            let _ = writeln!(content, "{red:.3} {green:.3} {blue:.3} rg");
+        }
+        ReviewExportColorMode::Grayscale => {
+            let gray = 0.299 * red + 0.587 * green + 0.114 * blue;
+            let _ = writeln!(content, "{gray:.3} g");
+        }
+    }
+}
+
+/// Serializes the page content streams with the catalog, page tree, fonts and cross-reference
+/// table that a PDF reader needs.
+fn write_pdf(title: &str, page_contents: Vec<String>) -> Vec<u8> {
+    const CATALOG_ID: usize = 1;
+    const PAGES_ID: usize = 2;
+    const REGULAR_FONT_ID: usize = 3;
+    const BOLD_FONT_ID: usize = 4;
+    const INFO_ID: usize = 5;
+    const FIRST_PAGE_ID: usize = 6;
+
+    let page_ids = (0..page_contents.len())
+        .map(|ix| FIRST_PAGE_ID + ix * 2)
+        .collect::<Vec<_>>();
+    let kids = page_ids
+        .iter()
+        .map(|id| format!("{id} 0 R"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut objects = vec![
+        format!("<< /Type /Catalog /Pages {PAGES_ID} 0 R >>"),
+        format!(
+            "<< /Type /Pages /Kids [{kids}] /Count {} >>",
+            page_contents.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Courier /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Courier-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        format!(
+            "<< /Title ({}) /Producer (Hunk) >>",
+            escape_pdf_string(pdf_safe_text(title).as_str())
+        ),
+    ];
+    for (page_id, content) in page_ids.iter().zip(page_contents) {
+        objects.push(format!(
+            "<< /Type /Page /Parent {PAGES_ID} 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+             /Resources << /Font << /F1 {REGULAR_FONT_ID} 0 R /F2 {BOLD_FONT_ID} 0 R >> >> \
+             /Contents {} 0 R >>",
+            page_id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{content}endstream",
+            content.len()
+        ));
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (ix, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", ix + 1).as_bytes());
+    }
+
+    let xref_offset = pdf.len();
+    let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(trailer, "{offset:010} 00000 n ");
+    }
+    let _ = writeln!(
+        trailer,
+        "trailer\n<< /Size {} /Root {CATALOG_ID} 0 R /Info {INFO_ID} 0 R >>\nstartxref\n\
+         {xref_offset}\n%%EOF",
+        objects.len() + 1
+    );
+    pdf.extend_from_slice(trailer.as_bytes());
+    pdf
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::state::CachedChangedFileState;
+
+const SNAPSHOT_CACHE_DIR_NAME: &str = "snapshot-cache";
+const SNAPSHOT_CACHE_EXTENSION: &str = "json";
+const MAX_CACHED_SNAPSHOTS: usize = 20;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CachedLineStats {
+    pub added: u64,
+    pub removed: u64,
+}
+
+/// A loaded review comparison, written so that reopening the project can paint the diff before
+/// Git answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CachedReviewSnapshot {
+    pub repo_root: PathBuf,
+    /// Repository state the patches were rendered from. The cache is only kept once the live
+    /// repository reports the same id.
+    pub state_id: String,
+    pub left_source_id: String,
+    /// Tree the left source pointed at when the patches were rendered. `state_id` only covers
+    /// the working copy, so a base branch that moved since makes the cache stale.
+    pub left_tree_id: Option<String>,
+    pub right_source_id: String,
+    pub files: Vec<CachedChangedFileState>,
+    pub file_line_stats: BTreeMap<String, CachedLineStats>,
+    pub patches_by_path: BTreeMap<String, String>,
+    pub cached_unix_time: i64,
+}
+
+/// Review snapshots in `~/.hunkdiff/snapshot-cache`, one JSON file per repository.
+///
+/// Only the most recently written repositories are kept.
+#[derive(Debug, Clone)]
+pub struct SnapshotCacheStore {
+    dir: PathBuf,
+}
+
+impl SnapshotCacheStore {
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_dir(
+            crate::paths::hunk_home_dir()?.join(SNAPSHOT_CACHE_DIR_NAME),
+        ))
+    }
+
+    pub fn from_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Replaces the snapshot stored under `repo_key` and drops the least recently written
+    /// repositories beyond the last twenty.
+    pub fn save(&self, repo_key: &str, snapshot: &CachedReviewSnapshot) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!(
+                "failed to create snapshot cache directory {}",
+                self.dir.display()
+            )
+        })?;
+        let path = self.snapshot_path(repo_key);
+        let temp_path = path.with_extension("tmp");
+        let raw = serde_json::to_vec(snapshot).context("failed to serialize review snapshot")?;
+        fs::write(&temp_path, raw)
+            .with_context(|| format!("failed to write snapshot cache {}", temp_path.display()))?;
+        fs::rename(&temp_path, &path)
+            .with_context(|| format!("failed to write snapshot cache {}", path.display()))?;
+
+        let snapshots = self.snapshot_paths_oldest_first()?;
+        for stale in snapshots
+            .iter()
+            .take(snapshots.len().saturating_sub(MAX_CACHED_SNAPSHOTS))
+        {
+            let _ = fs::remove_file(stale);
+        }
+        Ok(path)
+    }
+
+    pub fn load(&self, repo_key: &str) -> Result<Option<CachedReviewSnapshot>> {
+        let path = self.snapshot_path(repo_key);
+        let raw = match fs::read(&path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read snapshot cache {}", path.display()));
+            }
+        };
+        serde_json::from_slice(&raw)
+            .map(Some)
+            .with_context(|| format!("failed to parse snapshot cache {}", path.display()))
+    }
+
+    fn snapshot_path(&self, repo_key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{repo_key}.{SNAPSHOT_CACHE_EXTENSION}"))
+    }
+
+    fn snapshot_paths_oldest_first(&self) -> Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "failed to read snapshot cache directory {}",
+                        self.dir.display()
+                    )
+                });
+            }
+        };
+        let mut snapshots = entries
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .path()
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    == Some(SNAPSHOT_CACHE_EXTENSION)
+            })
+            .filter_map(|entry| {
+                let modified = entry.metadata().and_then(|metadata| metadata.modified());
+                modified.ok().map(|modified| (modified, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        snapshots.sort();
+        Ok(snapshots.into_iter().map(|(_, path)| path).collect())
+    }
+}
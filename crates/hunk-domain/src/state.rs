@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 
 const APP_DATA_DIR_NAME: &str = "hunk";
 const STATE_FILE_NAME: &str = "state.toml";
+/// How far back the maintenance panel totals pruned comments.
+pub const RECENT_COMMENT_PRUNE_WINDOW_MS: i64 = 7 * 24 * 60 * 60 * 1000;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -115,6 +117,110 @@ pub struct ReviewCompareSelectionState {
     pub right_source_id: Option<String>,
 }
 
+/// A diff row pinned during review as a lightweight "come back to this" marker.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewPinState {
+    pub file_path: String,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    /// Text of the pinned line, shown in the pinned list.
+    pub line_text: String,
+}
+
+/// Where a dockable workspace panel is shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PanelDock {
+    #[default]
+    Left,
+    Right,
+    Floating,
+    Hidden,
+}
+
+impl PanelDock {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Left => "Dock Left",
+            Self::Right => "Dock Right",
+            Self::Floating => "Float",
+            Self::Hidden => "Hide",
+        }
+    }
+
+    pub const fn is_docked(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+/// When a maintenance task last finished and what it reported, e.g. the space it reclaimed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceRun {
+    pub ran_unix_ms: i64,
+    pub detail: String,
+}
+
+/// How many comments one prune removed, kept to report recent totals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommentPruneRun {
+    pub ran_unix_ms: i64,
+    pub pruned: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceState {
+    pub last_comment_prune: Option<MaintenanceRun>,
+    pub last_git_gc: Option<MaintenanceRun>,
+    pub last_database_compact: Option<MaintenanceRun>,
+    /// Prunes from the last [`RECENT_COMMENT_PRUNE_WINDOW_MS`], oldest first.
+    pub recent_comment_prunes: Vec<CommentPruneRun>,
+}
+
+impl MaintenanceState {
+    /// Records a comment prune and forgets runs that fell out of the recent window.
+    pub fn record_comment_prune(&mut self, now_unix_ms: i64, pruned: usize) {
+        let window_start = now_unix_ms.saturating_sub(RECENT_COMMENT_PRUNE_WINDOW_MS);
+        self.recent_comment_prunes
+            .retain(|run| run.ran_unix_ms >= window_start);
+        self.recent_comment_prunes.push(CommentPruneRun {
+            ran_unix_ms: now_unix_ms,
+            pruned,
+        });
+    }
+
+    /// Comments pruned within the recent window ending at `now_unix_ms`.
+    pub fn recently_pruned_comments(&self, now_unix_ms: i64) -> usize {
+        let window_start = now_unix_ms.saturating_sub(RECENT_COMMENT_PRUNE_WINDOW_MS);
+        self.recent_comment_prunes
+            .iter()
+            .filter(|run| run.ran_unix_ms >= window_start)
+            .map(|run| run.pruned)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceLayoutState {
+    pub tree: PanelDock,
+    pub comments: PanelDock,
+    pub recent_commits: PanelDock,
+}
+
+impl Default for WorkspaceLayoutState {
+    fn default() -> Self {
+        Self {
+            tree: PanelDock::Left,
+            comments: PanelDock::Floating,
+            recent_commits: PanelDock::Right,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppState {
@@ -125,6 +231,8 @@ pub struct AppState {
     pub preferred_project_open_target_id: Option<String>,
     pub last_workspace_target_by_repo: BTreeMap<String, String>,
     pub review_compare_selection_by_repo: BTreeMap<String, ReviewCompareSelectionState>,
+    /// Diff rows pinned in each repository's review, in the order they were pinned.
+    pub review_pins_by_repo: BTreeMap<String, Vec<ReviewPinState>>,
     pub ai_bookmarked_thread_ids: BTreeSet<String>,
     pub ai_workspace_mad_max: BTreeMap<String, bool>,
     pub ai_workspace_include_hidden_models: BTreeMap<String, bool>,
@@ -132,6 +240,11 @@ pub struct AppState {
     pub ai_thread_session_overrides: BTreeMap<String, AiThreadSessionState>,
     pub git_workflow_cache_by_repo: BTreeMap<String, CachedWorkflowState>,
     pub git_recent_commits_cache_by_repo: BTreeMap<String, CachedRecentCommitsState>,
+    pub workspace_layout: WorkspaceLayoutState,
+    pub maintenance: MaintenanceState,
+    /// Project paths opened in read-only mode, where every action that writes to the repository
+    /// is disabled.
+    pub read_only_project_paths: BTreeSet<String>,
 }
 
 impl AppState {
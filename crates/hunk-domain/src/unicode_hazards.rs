@@ -0,0 +1,243 @@
+use std::ops::Range;
+
+use crate::diff::{DiffCellKind, DiffRowKind, SideBySideRow};
+
+const UNICODE_HAZARD_BANNER_LIMIT: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeHazardKind {
+    /// Draws nothing, such as a zero-width space or a tag character.
+    Invisible,
+    /// Changes the display order of the text around it.
+    BidiControl,
+    /// Looks like the ASCII character but is not, inside a word that is otherwise ASCII.
+    Confusable { looks_like: char },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnicodeHazard {
+    pub ch: char,
+    pub kind: UnicodeHazardKind,
+    /// Byte range of the character in the line.
+    pub range: Range<usize>,
+}
+
+impl UnicodeHazard {
+    /// Short description such as `U+202E right-to-left override`.
+    pub fn label(&self) -> String {
+        let code_point = format!("U+{:04X}", self.ch as u32);
+        match self.kind {
+            UnicodeHazardKind::Invisible | UnicodeHazardKind::BidiControl => {
+                format!("{code_point} {}", hazard_name(self.ch))
+            }
+            UnicodeHazardKind::Confusable { looks_like } => {
+                format!("{code_point} looks like '{looks_like}'")
+            }
+        }
+    }
+}
+
+/// Invisible characters, bidi controls, and confusable homoglyphs in `line`, in order.
+///
+/// A byte order mark at the very start of the line is allowed, since that is where files carry
+/// one. Homoglyphs are only reported inside words that also contain ASCII letters or digits, so
+/// text written in Cyrillic or Greek is left alone.
+pub fn unicode_hazards(line: &str) -> Vec<UnicodeHazard> {
+    if line.is_ascii() {
+        return Vec::new();
+    }
+
+    let mut hazards = Vec::new();
+    let mut word_has_ascii = false;
+    let mut word_confusables = Vec::new();
+    // A trailing space closes the last word.
+    for (offset, ch) in line.char_indices().chain([(line.len(), ' ')]) {
+        if (ch.is_alphanumeric() && !is_invisible(ch)) || ch == '_' {
+            word_has_ascii |= ch.is_ascii_alphanumeric();
+            if let Some(looks_like) = confusable_ascii(ch) {
+                word_confusables.push(UnicodeHazard {
+                    ch,
+                    kind: UnicodeHazardKind::Confusable { looks_like },
+                    range: offset..offset + ch.len_utf8(),
+                });
+            }
+            continue;
+        }
+
+        if word_has_ascii {
+            hazards.append(&mut word_confusables);
+        }
+        word_has_ascii = false;
+        word_confusables.clear();
+
+        let kind = if is_bidi_control(ch) {
+            UnicodeHazardKind::BidiControl
+        } else if is_invisible(ch) && !(ch == '\u{feff}' && offset == 0) {
+            UnicodeHazardKind::Invisible
+        } else {
+            continue;
+        };
+        hazards.push(UnicodeHazard {
+            ch,
+            kind,
+            range: offset..offset + ch.len_utf8(),
+        });
+    }
+    hazards
+}
+
+/// Lists the invisible, bidi control, and confusable characters on a file's added lines, which
+/// can make a change read differently from what it does. The review and `hunk diff` both show
+/// it above the file's rows.
+pub fn unicode_hazard_banner(rows: &[SideBySideRow]) -> Option<String> {
+    let occurrences = rows
+        .iter()
+        .filter(|row| row.kind == DiffRowKind::Code && row.right.kind == DiffCellKind::Added)
+        .flat_map(|row| {
+            unicode_hazards(row.right.text.as_str())
+                .into_iter()
+                .map(|hazard| match row.right.line {
+                    Some(line) => format!("line {line} {}", hazard.label()),
+                    None => hazard.label(),
+                })
+        })
+        .collect::<Vec<_>>();
+    if occurrences.is_empty() {
+        return None;
+    }
+
+    let noun = if occurrences.len() == 1 {
+        "character"
+    } else {
+        "characters"
+    };
+    let mut banner = format!(
+        "{} hidden or confusable {noun} added: {}",
+        occurrences.len(),
+        occurrences
+            .iter()
+            .take(UNICODE_HAZARD_BANNER_LIMIT)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let hidden = occurrences
+        .len()
+        .saturating_sub(UNICODE_HAZARD_BANNER_LIMIT);
+    if hidden > 0 {
+        banner.push_str(format!(", and {hidden} more").as_str());
+    }
+    Some(banner)
+}
+
+fn is_bidi_control(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_invisible(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{00ad}'
+            | '\u{034f}'
+            | '\u{115f}'
+            | '\u{1160}'
+            | '\u{17b4}'
+            | '\u{17b5}'
+            | '\u{180e}'
+            | '\u{200b}'..='\u{200d}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{3164}'
+            | '\u{feff}'
+            | '\u{ffa0}'
+            | '\u{e0000}'..='\u{e007f}'
+    )
+}
+
+fn hazard_name(ch: char) -> &'static str {
+    match ch {
+        '\u{00ad}' => "soft hyphen",
+        '\u{061c}' => "arabic letter mark",
+        '\u{180e}' => "mongolian vowel separator",
+        '\u{200b}' => "zero width space",
+        '\u{200c}' => "zero width non-joiner",
+        '\u{200d}' => "zero width joiner",
+        '\u{200e}' => "left-to-right mark",
+        '\u{200f}' => "right-to-left mark",
+        '\u{202a}' => "left-to-right embedding",
+        '\u{202b}' => "right-to-left embedding",
+        '\u{202c}' => "pop directional formatting",
+        '\u{202d}' => "left-to-right override",
+        '\u{202e}' => "right-to-left override",
+        '\u{2060}' => "word joiner",
+        '\u{2066}' => "left-to-right isolate",
+        '\u{2067}' => "right-to-left isolate",
+        '\u{2068}' => "first strong isolate",
+        '\u{2069}' => "pop directional isolate",
+        '\u{feff}' => "zero width no-break space",
+        '\u{e0000}'..='\u{e007f}' => "tag character",
+        '\u{115f}' | '\u{1160}' | '\u{3164}' | '\u{ffa0}' => "hangul filler",
+        _ => "invisible character",
+    }
+}
+
+/// The ASCII character `ch` is commonly mistaken for.
+fn confusable_ascii(ch: char) -> Option<char> {
+    let looks_like = match ch {
+        // Fullwidth forms map onto printable ASCII one to one.
+        '\u{ff01}'..='\u{ff5e}' => return char::from_u32(ch as u32 - 0xfee0),
+        // Cyrillic.
+        'а' => 'a',
+        'с' => 'c',
+        'ԁ' => 'd',
+        'е' => 'e',
+        'һ' => 'h',
+        'і' => 'i',
+        'ј' => 'j',
+        'ӏ' => 'l',
+        'о' => 'o',
+        'р' => 'p',
+        'ԛ' => 'q',
+        'ѕ' => 's',
+        'ԝ' => 'w',
+        'х' => 'x',
+        'у' => 'y',
+        'А' => 'A',
+        'В' => 'B',
+        'С' => 'C',
+        'Е' => 'E',
+        'Н' => 'H',
+        'І' => 'I',
+        'Ј' => 'J',
+        'К' => 'K',
+        'М' => 'M',
+        'О' => 'O',
+        'Р' => 'P',
+        'Ѕ' => 'S',
+        'Т' => 'T',
+        'Х' => 'X',
+        'Ү' => 'Y',
+        // Greek.
+        'ν' => 'v',
+        'ο' => 'o',
+        'ρ' => 'p',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        'Ζ' => 'Z',
+        _ => return None,
+    };
+    Some(looks_like)
+}
@@ -1,5 +1,8 @@
 use hunk_domain::config::{
-    AppConfig, KeyboardShortcuts, ReviewProviderKind, TerminalShell, ThemePreference,
+    AppConfig, BranchNamingConfig, ChangelogFormat, DesktopNotificationsConfig,
+    DiffRowDoubleClickAction, FileMiddleClickAction, FontConfig, FormattingConfig, GestureConfig,
+    GitTimeoutConfig, KeyboardShortcuts, ReviewBudgetConfig, ReviewProviderKind, ReviewTimerConfig,
+    TerminalShell, ThemePreference, TicketLinksConfig,
     default_terminal_hydrate_app_environment_on_launch,
 };
 
@@ -49,10 +52,23 @@ fn app_config_defaults_include_existing_keyboard_shortcuts() {
         config.keyboard_shortcuts.view_current_review_file,
         strings(&["g space"])
     );
+    assert_eq!(
+        config.keyboard_shortcuts.toggle_review_pin,
+        strings(&["cmd-f2", "ctrl-f2"])
+    );
+    assert_eq!(config.keyboard_shortcuts.next_review_pin, strings(&["f2"]));
+    assert_eq!(
+        config.keyboard_shortcuts.previous_review_pin,
+        strings(&["shift-f2"])
+    );
     assert_eq!(
         config.keyboard_shortcuts.toggle_sidebar_tree,
         strings(&["cmd-b", "ctrl-b"])
     );
+    assert_eq!(
+        config.keyboard_shortcuts.toggle_focus_mode,
+        strings(&["cmd-shift-enter", "ctrl-shift-enter"])
+    );
     assert_eq!(
         config.keyboard_shortcuts.switch_to_files_view,
         strings(&["cmd-1", "ctrl-1"])
@@ -73,6 +89,14 @@ fn app_config_defaults_include_existing_keyboard_shortcuts() {
         config.keyboard_shortcuts.open_project,
         strings(&["cmd-shift-o", "ctrl-shift-o"])
     );
+    assert_eq!(
+        config.keyboard_shortcuts.open_path_from_clipboard,
+        strings(&["cmd-alt-v", "ctrl-alt-v"])
+    );
+    assert_eq!(
+        config.keyboard_shortcuts.search_repository,
+        strings(&["cmd-shift-f", "ctrl-shift-f"])
+    );
     assert_eq!(
         config.keyboard_shortcuts.save_current_file,
         strings(&["cmd-s", "ctrl-s"])
@@ -124,6 +148,20 @@ theme = "dark"
         config.show_fps_counter,
         "configs missing show_fps_counter should fall back to true"
     );
+    assert!(
+        !config.vim_mode,
+        "configs missing vim_mode should fall back to false"
+    );
+}
+
+#[test]
+fn app_config_parses_vim_mode_when_present() {
+    let raw = r#"
+vim_mode = true
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("config with vim_mode should parse");
+
+    assert!(config.vim_mode);
 }
 
 #[test]
@@ -293,3 +331,261 @@ hydrate_app_environment_on_launch = false
     assert!(!config.terminal.inherit_login_environment);
     assert!(!config.terminal.hydrate_app_environment_on_launch);
 }
+
+#[test]
+fn app_config_large_hunk_fold_lines_defaults_and_parses() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.large_hunk_fold_lines, 200);
+
+    let config: AppConfig =
+        toml::from_str("large_hunk_fold_lines = 0").expect("fold threshold should parse");
+    assert_eq!(config.large_hunk_fold_lines, 0);
+}
+
+#[test]
+fn app_config_hex_diff_max_bytes_defaults_and_parses() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.hex_diff_max_bytes, 16 * 1024);
+
+    let config: AppConfig =
+        toml::from_str("hex_diff_max_bytes = 0").expect("hex diff limit should parse");
+    assert_eq!(config.hex_diff_max_bytes, 0);
+}
+
+#[test]
+fn app_config_comment_retention_days_defaults_and_parses() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.comment_retention_days, 14);
+    assert_eq!(config.stale_comment_retention_days, 30);
+
+    let config: AppConfig =
+        toml::from_str("comment_retention_days = 90\nstale_comment_retention_days = 7")
+            .expect("retention should parse");
+    assert_eq!(config.comment_retention_days, 90);
+    assert_eq!(config.stale_comment_retention_days, 7);
+}
+
+#[test]
+fn app_config_branch_naming_defaults_blank_and_parses() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.branch_naming, BranchNamingConfig::default());
+
+    let raw = r#"
+[branch_naming]
+template = "{user}/{ticket}-{slug}"
+user_initials = "nb"
+protected_branches = ["main", "release"]
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("branch naming should parse");
+    assert_eq!(config.branch_naming.template, "{user}/{ticket}-{slug}");
+    assert_eq!(config.branch_naming.user_initials, "nb");
+    assert_eq!(
+        config.branch_naming.protected_branches,
+        vec!["main".to_string(), "release".to_string()]
+    );
+}
+
+#[test]
+fn app_config_ticket_links_default_off_and_parse() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.ticket_links, TicketLinksConfig::default());
+
+    let raw = r#"
+[ticket_links]
+patterns = ["ENG-[0-9]+"]
+url_template = "https://linear.app/acme/issue/{ticket}"
+insert_into_commit_messages = true
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("ticket links should parse");
+    assert_eq!(config.ticket_links.patterns, strings(&["ENG-[0-9]+"]));
+    assert_eq!(
+        config.ticket_links.url_template,
+        "https://linear.app/acme/issue/{ticket}"
+    );
+    assert!(config.ticket_links.insert_into_commit_messages);
+}
+
+#[test]
+fn app_config_changelog_defaults_and_parses_format() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.changelog.format, ChangelogFormat::KeepAChangelog);
+    assert_eq!(config.changelog.fragment_path, "changelog.d/{branch}.md");
+
+    let raw = r#"
+[changelog]
+format = "towncrier"
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("changelog config should parse");
+    assert_eq!(config.changelog.format, ChangelogFormat::Towncrier);
+    assert_eq!(config.changelog.fragment_path, "changelog.d/{branch}.md");
+}
+
+#[test]
+fn app_config_parses_partial_git_timeouts() {
+    let raw = r#"
+[git_timeouts]
+push_secs = 30
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("git timeouts should parse");
+
+    assert_eq!(config.git_timeouts.push_secs, 30);
+    assert_eq!(
+        config.git_timeouts.sync_secs,
+        GitTimeoutConfig::default().sync_secs
+    );
+}
+
+#[test]
+fn app_config_desktop_notifications_default_off_with_events_enabled() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(
+        config.desktop_notifications,
+        DesktopNotificationsConfig::default()
+    );
+    assert!(!config.desktop_notifications.enabled);
+
+    let raw = r#"
+[desktop_notifications]
+enabled = true
+remote_branch_moved = false
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("desktop notifications should parse");
+    assert!(config.desktop_notifications.enabled);
+    assert!(config.desktop_notifications.ci_finished);
+    assert!(!config.desktop_notifications.remote_branch_moved);
+    assert!(config.desktop_notifications.comment_mentions);
+}
+
+#[test]
+fn app_config_review_timer_defaults_off_with_a_fifty_minute_break_reminder() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.review_timer, ReviewTimerConfig::default());
+    assert!(!config.review_timer.enabled);
+    assert_eq!(config.review_timer.break_reminder_minutes, 50);
+
+    let raw = r#"
+[review_timer]
+enabled = true
+break_reminder_minutes = 0
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("review timer should parse");
+    assert!(config.review_timer.enabled);
+    assert_eq!(config.review_timer.break_reminder_minutes, 0);
+}
+
+#[test]
+fn app_config_review_budget_defaults_and_parses_partial_overrides() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.review_budget, ReviewBudgetConfig::default());
+    assert_eq!(config.review_budget.max_files, 50);
+    assert_eq!(config.review_budget.max_changed_lines, 1_000);
+    assert_eq!(config.review_budget.lines_per_hour, 400);
+
+    let raw = r#"
+[review_budget]
+max_files = 0
+max_changed_lines = 400
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("review budget should parse");
+    assert_eq!(config.review_budget.max_files, 0);
+    assert_eq!(config.review_budget.max_changed_lines, 400);
+    assert_eq!(config.review_budget.lines_per_hour, 400);
+}
+
+#[test]
+fn app_config_gestures_default_to_selection_and_parse() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.gestures, GestureConfig::default());
+    assert_eq!(
+        config.gestures.diff_row_double_click,
+        DiffRowDoubleClickAction::SelectLine
+    );
+    assert_eq!(
+        config.gestures.file_middle_click,
+        FileMiddleClickAction::Nothing
+    );
+
+    let raw = r#"
+[gestures]
+diff_row_double_click = "open_in_editor"
+file_middle_click = "pin"
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("gestures should parse");
+    assert_eq!(
+        config.gestures.diff_row_double_click,
+        DiffRowDoubleClickAction::OpenInEditor
+    );
+    assert_eq!(
+        config.gestures.file_middle_click,
+        FileMiddleClickAction::Pin
+    );
+}
+
+#[test]
+fn app_config_formatting_defaults_and_parses_formatters() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.formatting, FormattingConfig::default());
+    assert!(!config.formatting.format_on_save);
+    let rustfmt = config
+        .formatting
+        .formatter_for_language("rust")
+        .expect("rust formatter");
+    assert_eq!(rustfmt.command_line(), "rustfmt --edition 2024");
+    assert_eq!(
+        config
+            .formatting
+            .formatter_for_language("tsx")
+            .map(|formatter| formatter.program.as_str()),
+        Some("prettier")
+    );
+    assert!(config.formatting.formatter_for_language("go").is_none());
+
+    let raw = r#"
+[formatting]
+format_on_save = true
+
+[[formatting.formatters]]
+languages = ["go"]
+program = "gofmt"
+
+[[formatting.formatters]]
+languages = ["rust"]
+program = ""
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("formatting should parse");
+    assert!(config.formatting.format_on_save);
+    let gofmt = config
+        .formatting
+        .formatter_for_language("go")
+        .expect("go formatter");
+    assert_eq!(gofmt.command_line(), "gofmt");
+    assert!(config.formatting.formatter_for_language("rust").is_none());
+
+    let mut formatter = gofmt.clone();
+    formatter.set_command_line("  goimports   -local example.com ");
+    assert_eq!(formatter.program, "goimports");
+    assert_eq!(formatter.args, strings(&["-local", "example.com"]));
+}
+
+#[test]
+fn app_config_fonts_parse_and_normalize() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.fonts, FontConfig::default());
+    assert!(config.fonts.mono_ligatures);
+
+    let raw = r#"
+[fonts]
+mono_font_family = "  JetBrains Mono "
+mono_font_size = 40.0
+mono_ligatures = false
+line_height = 0.5
+"#;
+    let config: AppConfig = toml::from_str(raw).expect("font settings should parse");
+    let fonts = config.fonts.normalized();
+    assert_eq!(fonts.mono_font_family, "JetBrains Mono");
+    assert_eq!(fonts.mono_font_size, 24.0);
+    assert!(!fonts.mono_ligatures);
+    assert_eq!(fonts.line_height, 1.0);
+    assert_eq!(fonts.ui_font_family, "");
+    assert_eq!(fonts.ui_font_size, FontConfig::default().ui_font_size);
+}
@@ -9,7 +9,14 @@ use hunk_domain::state::CachedLocalBranchState;
 use hunk_domain::state::CachedRecentCommitState;
 use hunk_domain::state::CachedRecentCommitsState;
 use hunk_domain::state::CachedWorkflowState;
+use hunk_domain::state::CommentPruneRun;
+use hunk_domain::state::MaintenanceRun;
+use hunk_domain::state::MaintenanceState;
+use hunk_domain::state::PanelDock;
+use hunk_domain::state::RECENT_COMMENT_PRUNE_WINDOW_MS;
 use hunk_domain::state::ReviewCompareSelectionState;
+use hunk_domain::state::ReviewPinState;
+use hunk_domain::state::WorkspaceLayoutState;
 
 #[test]
 fn app_state_defaults_workspace_state_to_empty() {
@@ -19,6 +26,7 @@ fn app_state_defaults_workspace_state_to_empty() {
     assert_eq!(state.preferred_project_open_target_id, None);
     assert!(state.last_workspace_target_by_repo.is_empty());
     assert!(state.review_compare_selection_by_repo.is_empty());
+    assert!(state.review_pins_by_repo.is_empty());
     assert!(state.ai_bookmarked_thread_ids.is_empty());
     assert!(state.ai_workspace_mad_max.is_empty());
     assert!(state.ai_workspace_include_hidden_models.is_empty());
@@ -37,6 +45,7 @@ fn app_state_parses_without_workspace_fields() {
     assert_eq!(state.preferred_project_open_target_id, None);
     assert!(state.last_workspace_target_by_repo.is_empty());
     assert!(state.review_compare_selection_by_repo.is_empty());
+    assert!(state.review_pins_by_repo.is_empty());
     assert!(state.ai_bookmarked_thread_ids.is_empty());
     assert!(state.ai_workspace_mad_max.is_empty());
     assert!(state.ai_workspace_include_hidden_models.is_empty());
@@ -46,6 +55,21 @@ fn app_state_parses_without_workspace_fields() {
     assert!(state.git_recent_commits_cache_by_repo.is_empty());
 }
 
+#[test]
+fn app_state_workspace_layout_fills_missing_panels_with_defaults() {
+    let raw = "[workspace_layout]\ntree = \"hidden\"\n";
+    let state: AppState = toml::from_str(raw).expect("partial layout should parse");
+    assert_eq!(
+        state.workspace_layout,
+        WorkspaceLayoutState {
+            tree: PanelDock::Hidden,
+            ..WorkspaceLayoutState::default()
+        }
+    );
+    assert_eq!(state.workspace_layout.comments, PanelDock::Floating);
+    assert_eq!(state.workspace_layout.recent_commits, PanelDock::Right);
+}
+
 #[test]
 fn app_state_migrates_legacy_last_project_path_into_workspace_state() {
     let raw = r#"
@@ -104,6 +128,25 @@ fn app_state_round_trips_workspace_fields() {
         )]
         .into_iter()
         .collect(),
+        review_pins_by_repo: [(
+            "/tmp/hunk-repo".to_string(),
+            vec![
+                ReviewPinState {
+                    file_path: "src/lib.rs".to_string(),
+                    old_line: Some(10),
+                    new_line: Some(12),
+                    line_text: "let value = parse(input)?;".to_string(),
+                },
+                ReviewPinState {
+                    file_path: "src/old.rs".to_string(),
+                    old_line: Some(4),
+                    new_line: None,
+                    line_text: "fn removed() {}".to_string(),
+                },
+            ],
+        )]
+        .into_iter()
+        .collect(),
         ai_bookmarked_thread_ids: ["thread-1".to_string(), "thread-2".to_string()]
             .into_iter()
             .collect(),
@@ -179,6 +222,27 @@ fn app_state_round_trips_workspace_fields() {
         )]
         .into_iter()
         .collect(),
+        workspace_layout: WorkspaceLayoutState {
+            tree: PanelDock::Right,
+            comments: PanelDock::Left,
+            recent_commits: PanelDock::Hidden,
+        },
+        maintenance: MaintenanceState {
+            last_comment_prune: Some(MaintenanceRun {
+                ran_unix_ms: 1_711_111_222_000,
+                detail: "Pruned 3 comments".to_string(),
+            }),
+            last_git_gc: None,
+            last_database_compact: Some(MaintenanceRun {
+                ran_unix_ms: 1_711_111_333_000,
+                detail: "Reclaimed 12.0 KB".to_string(),
+            }),
+            recent_comment_prunes: vec![CommentPruneRun {
+                ran_unix_ms: 1_711_111_222_000,
+                pruned: 3,
+            }],
+        },
+        read_only_project_paths: ["/tmp/hunk-prod".to_string()].into_iter().collect(),
     };
 
     let raw = toml::to_string(&state).expect("state should serialize");
@@ -187,6 +251,33 @@ fn app_state_round_trips_workspace_fields() {
     assert_eq!(loaded, state);
 }
 
+#[test]
+fn maintenance_state_totals_only_recent_comment_prunes() {
+    let mut maintenance = MaintenanceState::default();
+    maintenance.record_comment_prune(1_000, 4);
+    maintenance.record_comment_prune(2_000, 0);
+
+    let later = 1_000 + RECENT_COMMENT_PRUNE_WINDOW_MS;
+    assert_eq!(maintenance.recently_pruned_comments(later), 4);
+    assert_eq!(maintenance.recently_pruned_comments(later + 1), 0);
+
+    maintenance.record_comment_prune(later + 500, 2);
+    assert_eq!(
+        maintenance.recent_comment_prunes,
+        vec![
+            CommentPruneRun {
+                ran_unix_ms: 2_000,
+                pruned: 0,
+            },
+            CommentPruneRun {
+                ran_unix_ms: later + 500,
+                pruned: 2,
+            },
+        ]
+    );
+    assert_eq!(maintenance.recently_pruned_comments(later + 500), 2);
+}
+
 #[test]
 fn normalize_workspace_state_promotes_active_project_when_list_is_empty() {
     let mut state = AppState {
@@ -0,0 +1,73 @@
+use hunk_domain::bidi::{
+    TextDirection, VisualRun, has_right_to_left_text, visual_chars, visual_runs,
+};
+
+fn visual_string(text: &str, base: TextDirection) -> String {
+    let runs = visual_runs(text, base).expect("line should need reordering");
+    visual_chars(text, &runs)
+        .into_iter()
+        .map(|visual_char| visual_char.ch)
+        .collect()
+}
+
+#[test]
+fn left_to_right_lines_need_no_reordering() {
+    assert!(!has_right_to_left_text("let total = count + 1;"));
+    assert_eq!(
+        visual_runs("let total = count + 1;", TextDirection::LeftToRight),
+        None
+    );
+    assert_eq!(visual_runs("", TextDirection::RightToLeft), None);
+}
+
+#[test]
+fn hebrew_inside_code_is_reversed_in_place() {
+    let text = "let s = \"שלום\";";
+    assert!(has_right_to_left_text(text));
+    assert_eq!(
+        visual_string(text, TextDirection::LeftToRight),
+        "let s = \"םולש\";"
+    );
+}
+
+#[test]
+fn right_to_left_base_reverses_run_order_and_mirrors_brackets() {
+    let text = "שלום (abc)";
+    let runs = visual_runs(text, TextDirection::RightToLeft).expect("runs");
+    let latin_start = text.find('a').expect("latin run");
+    assert_eq!(
+        runs.get(1),
+        Some(&VisualRun {
+            range: latin_start..latin_start + 3,
+            direction: TextDirection::LeftToRight,
+        })
+    );
+    assert_eq!(
+        visual_string(text, TextDirection::RightToLeft),
+        "(abc) םולש"
+    );
+}
+
+#[test]
+fn combining_marks_stay_after_their_base_character() {
+    // Hebrew bet with a dagesh, followed by an alef.
+    let text = "\u{05d1}\u{05bc}\u{05d0}";
+    let chars = visual_chars(
+        text,
+        &visual_runs(text, TextDirection::LeftToRight).expect("runs"),
+    );
+    assert_eq!(
+        chars
+            .iter()
+            .map(|visual_char| visual_char.ch)
+            .collect::<String>(),
+        "\u{05d0}\u{05d1}\u{05bc}"
+    );
+    assert_eq!(
+        chars
+            .iter()
+            .map(|visual_char| visual_char.logical_offset)
+            .collect::<Vec<_>>(),
+        vec![4, 0, 2]
+    );
+}
@@ -0,0 +1,94 @@
+use hunk_domain::change_set_size::{
+    CHANGE_SET_LARGEST_CONTRIBUTORS, ChangeSetContributor, assess_change_set,
+    estimated_review_minutes,
+};
+use hunk_domain::config::ReviewBudgetConfig;
+
+fn budget(max_files: usize, max_changed_lines: u64) -> ReviewBudgetConfig {
+    ReviewBudgetConfig {
+        max_files,
+        max_changed_lines,
+        lines_per_hour: 600,
+    }
+}
+
+#[test]
+fn change_sets_within_budget_are_not_flagged() {
+    let files = [("src/lib.rs", 40), ("src/main.rs", 60)];
+    assert_eq!(assess_change_set(files, &budget(2, 100)), None);
+    assert_eq!(assess_change_set(files, &budget(0, 0)), None);
+    assert!(assess_change_set(files, &budget(1, 0)).is_some());
+    assert!(assess_change_set(files, &budget(0, 99)).is_some());
+}
+
+#[test]
+fn oversized_change_sets_list_largest_files_and_estimate_review_time() {
+    let paths = (0..8)
+        .map(|ix| format!("src/file_{ix}.rs"))
+        .collect::<Vec<_>>();
+    let warning = assess_change_set(
+        paths
+            .iter()
+            .enumerate()
+            .map(|(ix, path)| (path.as_str(), (ix as u64 + 1) * 10)),
+        &budget(0, 300),
+    )
+    .expect("change set should be over budget");
+
+    assert_eq!(warning.files, 8);
+    assert_eq!(warning.changed_lines, 360);
+    assert_eq!(warning.estimated_review_minutes, 36);
+    assert_eq!(warning.largest.len(), CHANGE_SET_LARGEST_CONTRIBUTORS);
+    assert_eq!(
+        warning.largest[0],
+        ChangeSetContributor {
+            path: "src/file_7.rs".to_string(),
+            changed_lines: 80,
+        }
+    );
+    assert_eq!(estimated_review_minutes(1, &budget(0, 0)), 1);
+}
+
+#[test]
+fn split_keeps_directories_together_and_breaks_up_oversized_ones() {
+    let files = [
+        ("docs/guide.md", 30),
+        ("src/app/mod.rs", 60),
+        ("src/app/view.rs", 50),
+        ("src/git/diff.rs", 90),
+        ("src/git/status.rs", 90),
+        ("src/git/stash.rs", 90),
+        ("README.md", 10),
+    ];
+    let warning = assess_change_set(files, &budget(0, 200)).expect("over budget");
+
+    let parts = warning
+        .parts
+        .iter()
+        .map(|part| {
+            (
+                part.directory.as_str(),
+                part.paths.len(),
+                part.changed_lines,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parts,
+        vec![("", 4, 150), ("src/git", 2, 180), ("src/git", 1, 90)]
+    );
+    assert!(
+        warning
+            .parts
+            .iter()
+            .all(|part| part.changed_lines <= 200 && part.estimated_review_minutes > 0)
+    );
+}
+
+#[test]
+fn split_is_omitted_when_a_single_file_is_the_whole_change() {
+    let warning =
+        assess_change_set([("Cargo.lock", 5_000)], &budget(0, 1_000)).expect("over budget");
+    assert!(warning.parts.is_empty());
+    assert_eq!(warning.largest.len(), 1);
+}
@@ -25,6 +25,8 @@ fn sample_comment() -> CommentRecord {
         updated_at_unix_ms: 10,
         last_seen_at_unix_ms: None,
         resolved_at_unix_ms: None,
+        author_name: Some("Ada Lovelace".to_string()),
+        author_email: Some("ada@example.com".to_string()),
     }
 }
 
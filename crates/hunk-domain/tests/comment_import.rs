@@ -0,0 +1,135 @@
+use hunk_domain::comment_import::{
+    ImportedComment, parse_comment_import, parse_github_review_comments,
+};
+use hunk_domain::db::CommentLineSide;
+
+fn comment(file_path: &str, line_side: CommentLineSide, line: u32, text: &str) -> ImportedComment {
+    ImportedComment {
+        file_path: file_path.to_string(),
+        line_side,
+        line: Some(line),
+        text: text.to_string(),
+        author: None,
+    }
+}
+
+#[test]
+fn parses_line_list_with_markdown_bullets_and_continuations() {
+    let contents = "\
+# Review notes
+
+- `src/lib.rs:12`: Prefer a borrowed slice here.
+  The caller already owns the buffer.
+* src/main.rs:40:7: unused import
+Some prose that is not a comment.
+";
+
+    let comments = parse_comment_import(contents).expect("parse line list");
+    assert_eq!(
+        comments,
+        vec![
+            comment(
+                "src/lib.rs",
+                CommentLineSide::Right,
+                12,
+                "Prefer a borrowed slice here.\nThe caller already owns the buffer."
+            ),
+            comment("src/main.rs", CommentLineSide::Right, 40, "unused import"),
+        ]
+    );
+}
+
+#[test]
+fn parses_copied_comment_bundles() {
+    let contents = "\
+[Hunk Comment]
+File: src/lib.rs
+Lines: old 10 | new -
+Comment:
+This branch was removed on purpose?
+Snippet:
+-let value = 1;
+
+[Hunk Comment]
+File: src/app.rs
+Lines: old 3 | new 4
+Comment:
+Rename this.
+Snippet:
++let renamed = 2;
+";
+
+    let comments = parse_comment_import(contents).expect("parse bundles");
+    assert_eq!(
+        comments,
+        vec![
+            comment(
+                "src/lib.rs",
+                CommentLineSide::Left,
+                10,
+                "This branch was removed on purpose?"
+            ),
+            comment("src/app.rs", CommentLineSide::Right, 4, "Rename this."),
+        ]
+    );
+}
+
+#[test]
+fn parses_server_json_comments() {
+    let contents = r#"{
+  "comments": [
+    { "path": "src/lib.rs", "side": "new", "oldLine": 9, "newLine": 11, "text": "Looks off", "author": "Ada" },
+    { "path": "src/old.rs", "side": "old", "oldLine": 5, "newLine": null, "text": "Why drop this?" },
+    { "file": "README.md", "body": "Whole-file note" }
+  ]
+}"#;
+
+    let comments = parse_comment_import(contents).expect("parse json");
+    assert_eq!(comments.len(), 3);
+    assert_eq!(
+        comments[0],
+        ImportedComment {
+            author: Some("Ada".to_string()),
+            ..comment("src/lib.rs", CommentLineSide::Right, 11, "Looks off")
+        }
+    );
+    assert_eq!(
+        comments[1],
+        comment("src/old.rs", CommentLineSide::Left, 5, "Why drop this?")
+    );
+    assert_eq!(comments[2].line, None);
+    assert_eq!(comments[2].location_label(), "README.md");
+}
+
+#[test]
+fn rejects_json_comments_without_text() {
+    let err = parse_comment_import(r#"[{ "path": "src/lib.rs", "line": 3 }]"#)
+        .expect_err("missing text should fail");
+    assert!(err.to_string().contains("JSON comment 1"));
+}
+
+#[test]
+fn parses_github_review_comments_from_json_lines() {
+    let contents = r#"{"path":"src/lib.rs","line":12,"side":"RIGHT","body":"Borrow here.","user":{"login":"octocat"}}
+{"path":"src/old.rs","line":4,"side":"LEFT","body":"Why drop this?","user":{"login":"hubot"}}
+{"path":"src/lib.rs","line":null,"side":"RIGHT","body":"Outdated note","user":{"login":"octocat"}}
+{"path":"src/lib.rs","line":3,"side":"RIGHT","body":"  ","user":{"login":"octocat"}}
+"#;
+
+    let comments = parse_github_review_comments(contents).expect("parse GitHub comments");
+    assert_eq!(comments.len(), 3);
+    assert_eq!(
+        comments[0],
+        ImportedComment {
+            author: Some("octocat".to_string()),
+            ..comment("src/lib.rs", CommentLineSide::Right, 12, "Borrow here.")
+        }
+    );
+    assert_eq!(comments[1].line_side, CommentLineSide::Left);
+    assert_eq!(comments[1].line, Some(4));
+    assert_eq!(comments[2].line, None);
+    assert_eq!(
+        parse_github_review_comments("[]").expect("parse empty array"),
+        Vec::new()
+    );
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::crash::{CrashReport, CrashReportStore, RecentActivityLog};
+
+struct TempCrashDir {
+    path: PathBuf,
+}
+
+impl TempCrashDir {
+    fn new(prefix: &str) -> Self {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("hunk-{prefix}-{}-{unique}", std::process::id()));
+        Self { path }
+    }
+}
+
+impl Drop for TempCrashDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[test]
+fn newest_crash_report_is_shown_until_marked_seen() {
+    let tempdir = TempCrashDir::new("crash-round-trip");
+    let store = CrashReportStore::from_dir(tempdir.path.join("crashes"));
+    assert_eq!(store.latest_unseen().expect("empty store"), None);
+
+    store.save(&report(100)).expect("save older report");
+    let newest_path = store.save(&report(200)).expect("save newer report");
+
+    let (path, loaded) = store
+        .latest_unseen()
+        .expect("load report")
+        .expect("unseen report");
+    assert_eq!(path, newest_path);
+    assert_eq!(loaded, report(200));
+
+    store.mark_all_seen().expect("mark seen");
+    assert_eq!(store.latest_unseen().expect("all seen"), None);
+}
+
+#[test]
+fn crash_reports_from_the_same_second_are_all_kept() {
+    let tempdir = TempCrashDir::new("crash-same-second");
+    let store = CrashReportStore::from_dir(tempdir.path.clone());
+    let first_path = store.save(&report(300)).expect("save first report");
+    store.mark_all_seen().expect("mark seen");
+    let mut worker = report(300);
+    worker.thread = "worker".to_string();
+    let worker_path = store.save(&worker).expect("save worker report");
+    let main_path = store.save(&report(300)).expect("save main report");
+
+    assert_ne!(first_path, worker_path);
+    assert_ne!(worker_path, main_path);
+    assert_eq!(fs::read_dir(store.dir()).expect("read dir").count(), 3);
+    let (path, loaded) = store
+        .latest_unseen()
+        .expect("load report")
+        .expect("unseen report");
+    assert_eq!(path, main_path);
+    assert_eq!(loaded, report(300));
+}
+
+#[test]
+fn pruning_same_second_reports_keeps_the_newest_one() {
+    let tempdir = TempCrashDir::new("crash-same-second-prune");
+    let store = CrashReportStore::from_dir(tempdir.path.clone());
+    let mut newest_path = PathBuf::new();
+    for _ in 0..12 {
+        newest_path = store.save(&report(400)).expect("save report");
+    }
+
+    assert_eq!(fs::read_dir(store.dir()).expect("read dir").count(), 10);
+    let (path, _) = store
+        .latest_unseen()
+        .expect("load report")
+        .expect("unseen report");
+    assert_eq!(path, newest_path);
+}
+
+#[test]
+fn crash_report_store_keeps_the_newest_ten_reports() {
+    let tempdir = TempCrashDir::new("crash-prune");
+    let store = CrashReportStore::from_dir(tempdir.path.clone());
+    for unix_time in 0..12 {
+        store.save(&report(unix_time)).expect("save report");
+    }
+
+    let stored = fs::read_dir(store.dir()).expect("read dir").count();
+    assert_eq!(stored, 10);
+}
+
+#[test]
+fn issue_text_includes_versions_activity_and_backtrace() {
+    let text = report(7).to_issue_text();
+    assert!(text.contains("- Hunk: 1.2.3"));
+    assert!(text.contains("- Location: src/app.rs:10:5"));
+    assert!(text.contains("opened project"));
+    assert!(text.contains("0: hunk::main"));
+}
+
+#[test]
+fn recent_activity_log_keeps_the_latest_lines() {
+    let log = RecentActivityLog::new(2);
+    log.push("one".to_string());
+    log.push("two".to_string());
+    log.push("three".to_string());
+    assert_eq!(log.snapshot(), vec!["two".to_string(), "three".to_string()]);
+}
+
+fn report(unix_time: i64) -> CrashReport {
+    CrashReport {
+        unix_time,
+        app_version: "1.2.3".to_string(),
+        config_version: "0.1.0".to_string(),
+        os: "linux".to_string(),
+        arch: "x86_64".to_string(),
+        thread: "main".to_string(),
+        message: "boom".to_string(),
+        location: Some("src/app.rs:10:5".to_string()),
+        backtrace: "0: hunk::main".to_string(),
+        recent_activity: vec!["INFO hunk: opened project".to_string()],
+    }
+}
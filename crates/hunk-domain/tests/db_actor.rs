@@ -0,0 +1,114 @@
+use std::fs;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::db::{CommentLineSide, DatabaseActor, DatabaseStore, NewComment};
+
+struct TempDb {
+    path: PathBuf,
+    store: DatabaseStore,
+}
+
+impl TempDb {
+    fn new(prefix: &str) -> Self {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("hunk-{prefix}-{}-{unique}.db", std::process::id()));
+        Self {
+            store: DatabaseStore::from_path(path.clone()),
+            path,
+        }
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+        let _ = fs::remove_file(self.path.with_extension("db-shm"));
+        let _ = fs::remove_file(self.path.with_extension("db-wal"));
+        let _ = fs::remove_file(self.path.with_extension("db-lock"));
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+fn new_comment(file_path: &str, text: &str) -> NewComment {
+    NewComment {
+        repo_root: "/repo".to_string(),
+        branch_name: "main".to_string(),
+        created_head_commit: None,
+        file_path: file_path.to_string(),
+        line_side: CommentLineSide::Right,
+        old_line: None,
+        new_line: Some(3),
+        row_stable_id: None,
+        hunk_header: None,
+        line_text: "let value = 1;".to_string(),
+        context_before: String::new(),
+        context_after: String::new(),
+        anchor_hash: "anchor".to_string(),
+        comment_text: text.to_string(),
+        author_name: None,
+        author_email: None,
+    }
+}
+
+#[test]
+fn database_actor_runs_calls_in_order_on_its_own_thread() {
+    let fixture = TempDb::new("db-actor-order");
+    let actor = DatabaseActor::spawn(fixture.store.clone()).expect("spawn actor");
+
+    let caller = thread::current().id();
+    let created = actor.call(move |store| {
+        assert_ne!(thread::current().id(), caller);
+        store.create_comment(&new_comment("src/lib.rs", "first"))
+    });
+    // Queued after the write, so it sees it even though nothing awaited the write yet.
+    let listed = actor.list_comments("/repo".to_string(), "main".to_string(), false);
+    let counts = actor.count_comments("/repo".to_string(), "main".to_string());
+
+    let created = block_on(created).expect("create comment");
+    let listed = block_on(listed).expect("list comments");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, created.id);
+    assert_eq!(block_on(counts).expect("count comments").open, 1);
+}
+
+#[test]
+fn database_actor_reports_a_panicking_call_and_keeps_serving() {
+    let fixture = TempDb::new("db-actor-panic");
+    let actor = DatabaseActor::spawn(fixture.store.clone()).expect("spawn actor");
+
+    let failed = actor.call(|_| -> anyhow::Result<()> { panic!("broken call") });
+    assert!(block_on(failed).is_err());
+
+    let revision = block_on(actor.comment_scope_revision("/repo".to_string(), "main".to_string()))
+        .expect("later calls still run");
+    assert_eq!(revision.comment_count, 0);
+}
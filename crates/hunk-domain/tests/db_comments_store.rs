@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use hunk_domain::db::{CommentLineSide, CommentStatus, DatabaseStore, NewComment};
+use hunk_domain::comment_import::parse_comment_import;
+use hunk_domain::db::{
+    CommentExportFormat, CommentExportScope, CommentLineSide, CommentScopeRevision, CommentStatus,
+    DatabaseStore, NewComment, compute_comment_anchor_hash, is_comment_database_locked,
+};
 use rusqlite::Connection;
 
 const MIGRATION_0001_INIT: &str = include_str!("../src/db/migrations/0001_init.sql");
@@ -33,6 +37,7 @@ impl Drop for TempDb {
         let _ = fs::remove_file(&self.path);
         let _ = fs::remove_file(self.path.with_extension("db-shm"));
         let _ = fs::remove_file(self.path.with_extension("db-wal"));
+        let _ = fs::remove_file(self.path.with_extension("db-lock"));
     }
 }
 
@@ -52,6 +57,8 @@ fn new_comment(repo_root: &str, branch_name: &str, file_path: &str, text: &str)
         context_after: "+let value = 1;".to_string(),
         anchor_hash: "anchor-hash-1".to_string(),
         comment_text: text.to_string(),
+        author_name: Some("Ada Lovelace".to_string()),
+        author_email: Some("ada@example.com".to_string()),
     }
 }
 
@@ -70,7 +77,99 @@ fn sqlite_store_bootstraps_schema() {
     let user_version: i64 = conn
         .pragma_query_value(None, "user_version", |row| row.get(0))
         .expect("read sqlite user_version");
-    assert_eq!(user_version, 3);
+    assert_eq!(user_version, 6);
+}
+
+#[test]
+fn sqlite_integrity_check_reports_no_problems_for_fresh_store() {
+    let fixture = TempDb::new("comments-integrity");
+
+    fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "check me"))
+        .expect("create comment");
+
+    let problems = fixture
+        .store
+        .check_integrity()
+        .expect("integrity check should run");
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn comment_writes_wait_for_another_instance_holding_the_write_lock() {
+    let fixture = TempDb::new("comments-write-lock");
+    let store = DatabaseStore::from_path(fixture.path.clone())
+        .with_write_lock_timeout(Duration::from_millis(100));
+    fixture
+        .store
+        .list_comments("/repo", "main", true)
+        .expect("initialize db");
+
+    let other_instance = fs::File::create(store.write_lock_path()).expect("create lock file");
+    other_instance.lock().expect("hold write lock");
+    let err = store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "blocked"))
+        .expect_err("write should time out while another instance holds the lock");
+    assert!(is_comment_database_locked(&err));
+    assert!(
+        store
+            .list_comments("/repo", "main", true)
+            .expect("reads do not take the write lock")
+            .is_empty()
+    );
+
+    drop(other_instance);
+    store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "saved"))
+        .expect("write after the lock is released");
+    assert_eq!(
+        fixture
+            .store
+            .list_comments("/repo", "main", true)
+            .expect("list comments")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn count_comments_totals_statuses_and_open_comments_per_file() {
+    let fixture = TempDb::new("comments-counts");
+
+    for (file_path, text) in [
+        ("src/lib.rs", "first"),
+        ("src/lib.rs", "second"),
+        ("src/main.rs", "third"),
+    ] {
+        fixture
+            .store
+            .create_comment(&new_comment("/repo", "main", file_path, text))
+            .expect("create comment");
+    }
+    let resolved = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/main.rs", "done"))
+        .expect("create resolved comment");
+    fixture
+        .store
+        .mark_comment_status(resolved.id.as_str(), CommentStatus::Resolved, None, 20)
+        .expect("resolve comment");
+    fixture
+        .store
+        .create_comment(&new_comment("/repo", "feature", "src/lib.rs", "elsewhere"))
+        .expect("create comment on another branch");
+
+    let counts = fixture
+        .store
+        .count_comments("/repo", "main")
+        .expect("count comments");
+    assert_eq!(counts.open, 3);
+    assert_eq!(counts.resolved, 1);
+    assert_eq!(counts.stale, 0);
+    assert_eq!(counts.open_in_file("src/lib.rs"), 2);
+    assert_eq!(counts.open_in_file("src/main.rs"), 1);
+    assert_eq!(counts.open_in_file("README.md"), 0);
 }
 
 #[test]
@@ -96,6 +195,96 @@ fn create_and_list_comment_round_trip() {
         .expect("list open comments");
     assert_eq!(listed.len(), 1);
     assert_eq!(listed[0].id, created.id);
+    assert_eq!(listed[0].author_name.as_deref(), Some("Ada Lovelace"));
+    assert_eq!(listed[0].author_email.as_deref(), Some("ada@example.com"));
+}
+
+#[test]
+fn comment_author_label_falls_back_to_email() {
+    let fixture = TempDb::new("comments-author");
+
+    let mut input = new_comment("/repo", "main", "src/lib.rs", "from email only");
+    input.author_name = None;
+    let email_only = fixture
+        .store
+        .create_comment(&input)
+        .expect("create comment without author name");
+    assert_eq!(email_only.author_label(), Some("ada@example.com"));
+
+    input.author_email = None;
+    let anonymous = fixture
+        .store
+        .create_comment(&input)
+        .expect("create comment without author");
+    assert_eq!(anonymous.author_label(), None);
+}
+
+#[test]
+fn exported_comments_render_as_markdown_and_reimport_from_json() {
+    let fixture = TempDb::new("comments-export");
+    let open = fixture
+        .store
+        .create_comment(&new_comment(
+            "/repo",
+            "main",
+            "src/lib.rs",
+            "Handle errors here.",
+        ))
+        .expect("create open comment");
+    let resolved = fixture
+        .store
+        .create_comment(&new_comment(
+            "/repo",
+            "main",
+            "src/main.rs",
+            "Already fixed.",
+        ))
+        .expect("create resolved comment");
+    fixture
+        .store
+        .mark_comment_status(resolved.id.as_str(), CommentStatus::Resolved, None, 20)
+        .expect("resolve comment");
+    let scope = CommentExportScope {
+        repo_root: "/repo".to_string(),
+        branch_name: "main".to_string(),
+        include_non_open: true,
+    };
+
+    let markdown = fixture
+        .store
+        .export_comments(&scope, CommentExportFormat::Markdown)
+        .expect("export markdown");
+    assert!(markdown.starts_with("# Review comments\n\n2 comments on `main`.\n"));
+    assert!(markdown.contains(
+        "## `src/lib.rs`\n\n### Line 11 (open) by Ada Lovelace\n\n```diff\n\
+         @@ -10,3 +11,4 @@\n let other = 0;\nlet value = 1;\n+let value = 1;\n```\n\n\
+         Handle errors here.\n"
+    ));
+    assert!(markdown.contains("### Line 11 (resolved) by Ada Lovelace"));
+
+    let json = fixture
+        .store
+        .export_comments(&scope, CommentExportFormat::Json)
+        .expect("export json");
+    let value: serde_json::Value = serde_json::from_str(json.as_str()).expect("valid json");
+    assert_eq!(value["comments"][0]["id"], open.id.as_str());
+    assert_eq!(value["comments"][0]["hunkHeader"], "@@ -10,3 +11,4 @@");
+    let imported = parse_comment_import(json.as_str()).expect("reimport exported json");
+    assert_eq!(imported.len(), 2);
+    assert_eq!(imported[0].file_path, "src/lib.rs");
+    assert_eq!(imported[0].line, Some(11));
+    assert_eq!(imported[0].text, "Handle errors here.");
+
+    let open_only = CommentExportScope {
+        include_non_open: false,
+        ..scope
+    };
+    let markdown = fixture
+        .store
+        .export_comments(&open_only, CommentExportFormat::Markdown)
+        .expect("export open comments");
+    assert!(markdown.contains("1 comment on `main`."));
+    assert!(!markdown.contains("src/main.rs"));
 }
 
 #[test]
@@ -169,7 +358,7 @@ fn status_updates_and_pruning_work() {
 
     let removed = fixture
         .store
-        .prune_non_open_comments(200)
+        .prune_non_open_comments(200, 200)
         .expect("prune stale comments");
     assert_eq!(removed, 1);
 
@@ -180,6 +369,209 @@ fn status_updates_and_pruning_work() {
     assert!(after_prune.is_empty());
 }
 
+#[test]
+fn pruning_uses_separate_cutoffs_for_resolved_and_stale_comments() {
+    let fixture = TempDb::new("comments-prune-cutoffs");
+    let resolved = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "resolved"))
+        .expect("create resolved comment");
+    let stale = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "stale"))
+        .expect("create stale comment");
+    let open = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "open"))
+        .expect("create open comment");
+    fixture
+        .store
+        .mark_comment_status(resolved.id.as_str(), CommentStatus::Resolved, None, 100)
+        .expect("mark resolved");
+    fixture
+        .store
+        .mark_comment_status(
+            stale.id.as_str(),
+            CommentStatus::Stale,
+            Some("anchor_not_found"),
+            100,
+        )
+        .expect("mark stale");
+
+    let removed = fixture
+        .store
+        .prune_non_open_comments(200, 50)
+        .expect("prune resolved comments");
+    assert_eq!(removed, 1);
+    assert!(
+        fixture
+            .store
+            .get_comment(resolved.id.as_str())
+            .expect("load resolved")
+            .is_none()
+    );
+    assert!(
+        fixture
+            .store
+            .get_comment(stale.id.as_str())
+            .expect("load stale")
+            .is_some()
+    );
+
+    let removed = fixture
+        .store
+        .prune_non_open_comments(200, 200)
+        .expect("prune stale comments");
+    assert_eq!(removed, 1);
+    assert!(
+        fixture
+            .store
+            .get_comment(open.id.as_str())
+            .expect("load open")
+            .is_some()
+    );
+}
+
+#[test]
+fn migrating_comment_paths_moves_only_matching_scope() {
+    let fixture = TempDb::new("comments-migrate-path");
+    for (branch_name, file_path) in [
+        ("main", "src/old.rs"),
+        ("main", "src/old.rs"),
+        ("main", "src/other.rs"),
+        ("feature", "src/old.rs"),
+    ] {
+        fixture
+            .store
+            .create_comment(&new_comment("/repo", branch_name, file_path, "note"))
+            .expect("create comment");
+    }
+
+    let moved = fixture
+        .store
+        .migrate_comment_paths("/repo", "main", "src/old.rs", "src/new.rs")
+        .expect("migrate comment paths");
+    assert_eq!(moved, 2);
+
+    let main_paths = fixture
+        .store
+        .list_comments("/repo", "main", true)
+        .expect("list main comments")
+        .into_iter()
+        .map(|comment| comment.file_path)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        main_paths
+            .iter()
+            .filter(|path| *path == "src/new.rs")
+            .count(),
+        2
+    );
+    assert!(main_paths.contains(&"src/other.rs".to_string()));
+    assert!(!main_paths.contains(&"src/old.rs".to_string()));
+
+    let feature = fixture
+        .store
+        .list_comments("/repo", "feature", true)
+        .expect("list feature comments");
+    assert_eq!(feature[0].file_path, "src/old.rs");
+}
+
+#[test]
+fn migrating_comment_paths_rehashes_anchors_and_reopens_stale_comments() {
+    let fixture = TempDb::new("comments-migrate-anchor");
+    let stale = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/old.rs", "stale note"))
+        .expect("create comment");
+    fixture
+        .store
+        .mark_comment_status(
+            stale.id.as_str(),
+            CommentStatus::Stale,
+            Some("anchor_not_found"),
+            100,
+        )
+        .expect("mark stale");
+
+    fixture
+        .store
+        .migrate_comment_paths("/repo", "main", "src/old.rs", "src/new.rs")
+        .expect("migrate comment paths");
+
+    let moved = fixture
+        .store
+        .get_comment(stale.id.as_str())
+        .expect("load moved comment")
+        .expect("moved comment exists");
+    assert_eq!(moved.file_path, "src/new.rs");
+    assert_eq!(moved.status, CommentStatus::Open);
+    assert_eq!(moved.stale_reason, None);
+    assert_eq!(
+        moved.anchor_hash,
+        compute_comment_anchor_hash(
+            "src/new.rs",
+            moved.hunk_header.as_deref(),
+            moved.line_text.as_str(),
+            moved.context_before.as_str(),
+            moved.context_after.as_str(),
+        )
+    );
+}
+
+#[test]
+fn compacting_after_deletes_reclaims_space() {
+    let fixture = TempDb::new("compact");
+    let mut ids = Vec::new();
+    for ix in 0..200 {
+        let mut input = new_comment("/repo", "main", "src/lib.rs", "long comment");
+        input.comment_text = format!("{ix} {}", "padding ".repeat(200));
+        ids.push(
+            fixture
+                .store
+                .create_comment(&input)
+                .expect("create comment")
+                .id,
+        );
+    }
+    fixture
+        .store
+        .delete_many_comments(&ids)
+        .expect("delete comments");
+
+    let reclaimed = fixture.store.compact().expect("compact database");
+
+    assert!(reclaimed > 0);
+    assert!(
+        fixture
+            .store
+            .check_integrity()
+            .expect("integrity check")
+            .is_empty()
+    );
+}
+
+#[test]
+fn compacting_waits_for_another_instance_holding_the_write_lock() {
+    let fixture = TempDb::new("compact-write-lock");
+    let store = DatabaseStore::from_path(fixture.path.clone())
+        .with_write_lock_timeout(Duration::from_millis(100));
+    fixture
+        .store
+        .list_comments("/repo", "main", true)
+        .expect("initialize db");
+
+    let other_instance = fs::File::create(store.write_lock_path()).expect("create lock file");
+    other_instance.lock().expect("hold write lock");
+    let err = store
+        .compact()
+        .expect_err("compaction should time out while another instance holds the lock");
+    assert!(is_comment_database_locked(&err));
+
+    drop(other_instance);
+    store.compact().expect("compact after the lock is released");
+}
+
 #[test]
 fn touch_and_delete_comment_work() {
     let fixture = TempDb::new("comments-touch-delete");
@@ -215,6 +607,51 @@ fn touch_and_delete_comment_work() {
     assert!(missing.is_none());
 }
 
+#[test]
+fn comment_scope_revision_tracks_writes_in_scope() {
+    let fixture = TempDb::new("comments-scope-revision");
+
+    let empty = fixture
+        .store
+        .comment_scope_revision("/repo", "main")
+        .expect("load empty revision");
+    assert_eq!(empty, CommentScopeRevision::default());
+
+    let created = fixture
+        .store
+        .create_comment(&new_comment("/repo", "main", "src/lib.rs", "watch me"))
+        .expect("create comment");
+    fixture
+        .store
+        .create_comment(&new_comment("/repo", "other", "src/lib.rs", "other branch"))
+        .expect("create comment on other branch");
+
+    let after_create = fixture
+        .store
+        .comment_scope_revision("/repo", "main")
+        .expect("load revision after create");
+    assert_eq!(after_create.comment_count, 1);
+    assert_eq!(
+        after_create.latest_updated_at_unix_ms,
+        created.updated_at_unix_ms
+    );
+
+    fixture
+        .store
+        .mark_comment_status(
+            created.id.as_str(),
+            CommentStatus::Resolved,
+            None,
+            created.updated_at_unix_ms + 1,
+        )
+        .expect("resolve comment");
+    let after_resolve = fixture
+        .store
+        .comment_scope_revision("/repo", "main")
+        .expect("load revision after resolve");
+    assert_ne!(after_resolve, after_create);
+}
+
 #[test]
 fn batch_comment_updates_apply_to_each_requested_id() {
     let fixture = TempDb::new("comments-batch-updates");
@@ -478,7 +915,7 @@ fn migration_sanitizes_legacy_negative_row_stable_ids() {
     let user_version: i64 = conn
         .pragma_query_value(None, "user_version", |row| row.get(0))
         .expect("read sanitized sqlite user_version");
-    assert_eq!(user_version, 3);
+    assert_eq!(user_version, 6);
 }
 
 #[test]
@@ -551,5 +988,5 @@ fn upgrading_a_version_1_database_runs_ordered_migrations() {
     let user_version: i64 = conn
         .pragma_query_value(None, "user_version", |row| row.get(0))
         .expect("read upgraded sqlite user_version");
-    assert_eq!(user_version, 3);
+    assert_eq!(user_version, 6);
 }
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::db::{DatabaseStore, USAGE_METRICS_RETENTION_DAYS, UsageMetricKind};
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW: i64 = 1_000 * DAY_MS + 12 * 60 * 60 * 1000;
+
+struct TempDb {
+    path: PathBuf,
+    store: DatabaseStore,
+}
+
+impl TempDb {
+    fn new(prefix: &str) -> Self {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("hunk-{prefix}-{}-{unique}.db", std::process::id()));
+        Self {
+            store: DatabaseStore::from_path(path.clone()),
+            path,
+        }
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+        let _ = fs::remove_file(self.path.with_extension("db-shm"));
+        let _ = fs::remove_file(self.path.with_extension("db-wal"));
+    }
+}
+
+#[test]
+fn usage_metrics_are_grouped_by_day() {
+    let fixture = TempDb::new("usage-metrics-daily");
+    let store = &fixture.store;
+    let yesterday = NOW - DAY_MS;
+    for (kind, duration_ms, recorded_at) in [
+        (UsageMetricKind::DiffViewed, None, yesterday),
+        (UsageMetricKind::SnapshotLoad, Some(100), yesterday),
+        (UsageMetricKind::DiffViewed, None, NOW),
+        (UsageMetricKind::DiffViewed, None, NOW),
+        (UsageMetricKind::SnapshotLoad, Some(20), NOW),
+        (UsageMetricKind::SnapshotLoad, Some(40), NOW),
+        (UsageMetricKind::CommentWritten, None, NOW),
+        (UsageMetricKind::Push, None, NOW),
+    ] {
+        store
+            .record_usage_metric_at(kind, duration_ms, recorded_at)
+            .expect("record usage metric");
+    }
+
+    let summary = store
+        .usage_metrics_summary(7, NOW)
+        .expect("load usage metrics");
+    assert_eq!(summary.days.len(), 2);
+
+    let today = summary.days[0];
+    assert_eq!(today.day_start_unix_ms, 1_000 * DAY_MS);
+    assert_eq!(today.diffs_viewed, 2);
+    assert_eq!(today.snapshot_loads, 2);
+    assert_eq!(today.average_snapshot_load_ms, Some(30));
+    assert_eq!(today.comments_written, 1);
+    assert_eq!(today.pushes, 1);
+
+    assert_eq!(summary.days[1].diffs_viewed, 1);
+    assert_eq!(summary.total_diffs_viewed(), 3);
+    assert_eq!(summary.average_snapshot_load_ms(), Some(53));
+
+    let today_only = store
+        .usage_metrics_summary(1, NOW)
+        .expect("load today's usage metrics");
+    assert_eq!(today_only.days.len(), 1);
+}
+
+#[test]
+fn recording_usage_metrics_prunes_expired_events() {
+    let fixture = TempDb::new("usage-metrics-prune");
+    let store = &fixture.store;
+    let expired = NOW - (USAGE_METRICS_RETENTION_DAYS + 1) * DAY_MS;
+    store
+        .record_usage_metric_at(UsageMetricKind::Push, None, expired)
+        .expect("record expired metric");
+    store
+        .record_usage_metric_at(UsageMetricKind::Push, None, NOW)
+        .expect("record current metric");
+
+    let summary = store
+        .usage_metrics_summary(USAGE_METRICS_RETENTION_DAYS * 2, NOW)
+        .expect("load usage metrics");
+    assert_eq!(summary.total_pushes(), 1);
+}
@@ -1,5 +1,11 @@
+use std::collections::BTreeSet;
+
 use hunk_domain::diff::{
-    DiffCellKind, DiffLineKind, DiffRowKind, parse_patch_document, parse_patch_side_by_side,
+    CHANGED_ONLY_SEPARATOR, DiffCellKind, DiffLineKind, DiffRowKind, HunkFolds,
+    LARGE_HUNK_EDGE_ROWS, changed_only_code_row_count, collapsed_hunk_summary,
+    fold_collapsed_hunks, hunk_collapse_key, hunk_collapse_key_for_header,
+    large_hunk_hidden_line_count, parse_patch_document, parse_patch_side_by_side,
+    retain_changed_rows,
 };
 
 #[test]
@@ -326,3 +332,163 @@ fn malformed_hunk_header_does_not_fabricate_zero_line_numbers() {
     assert_eq!(hunk.lines[1].old_line, None);
     assert_eq!(hunk.lines[1].new_line, None);
 }
+
+#[test]
+fn changed_only_rows_hide_context_and_mark_gaps_between_changes() {
+    let patch = "\
+@@ -1,7 +1,7 @@
+ leading
+-old one
++new one
+ middle a
+ middle b
+-old two
++new two
+ trailing";
+
+    let rows = retain_changed_rows(parse_patch_side_by_side(patch));
+    let texts = rows
+        .iter()
+        .map(|row| match row.kind {
+            DiffRowKind::HunkHeader => "@@".to_string(),
+            _ => format!("{}|{}", row.left.text, row.right.text),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["@@", "old one|new one", "…|…", "old two|new two",]);
+    assert_eq!(rows[2].left.line, None);
+    assert_eq!(rows[2].right.line, None);
+
+    let document = parse_patch_document(patch);
+    assert_eq!(changed_only_code_row_count(&document.hunks[0]), 3);
+}
+
+#[test]
+fn changed_only_rows_restart_per_hunk() {
+    let patch = "\
+@@ -1,2 +1,2 @@
+-a
++b
+ context
+@@ -10,2 +10,2 @@
+ context
+-c
++d";
+
+    let rows = retain_changed_rows(parse_patch_side_by_side(patch));
+    let kinds = rows.iter().map(|row| row.kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            DiffRowKind::HunkHeader,
+            DiffRowKind::Code,
+            DiffRowKind::HunkHeader,
+            DiffRowKind::Code,
+        ]
+    );
+    assert!(
+        rows.iter()
+            .all(|row| row.left.text != CHANGED_ONLY_SEPARATOR)
+    );
+}
+
+#[test]
+fn collapsed_hunks_fold_to_a_summarized_header_row() {
+    let patch = "\
+@@ -1,3 +1,3 @@ fn first()
+ context
+-old
++new
+@@ -10,2 +10,3 @@ fn second()
+ context
++added one
++added two";
+
+    let document = parse_patch_document(patch);
+    let keys = document
+        .hunks
+        .iter()
+        .map(hunk_collapse_key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, [1, 10]);
+    assert_eq!(
+        hunk_collapse_key_for_header(document.hunks[1].header.as_str()),
+        Some(10)
+    );
+
+    let rows = fold_collapsed_hunks(parse_patch_side_by_side(patch), &BTreeSet::from([10]));
+    let kinds = rows.iter().map(|row| row.kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            DiffRowKind::HunkHeader,
+            DiffRowKind::Code,
+            DiffRowKind::Code,
+            DiffRowKind::HunkHeader,
+        ]
+    );
+    assert_eq!(rows[0].text, "@@ -1,3 +1,3 @@ fn first()");
+    assert_eq!(
+        rows[3].text,
+        "@@ -10,2 +10,3 @@ fn second()  \u{22ef} ±2 lines hidden (+2 -0)"
+    );
+    assert_eq!(
+        collapsed_hunk_summary(rows[3].text.as_str()),
+        Some("±2 lines hidden (+2 -0)")
+    );
+    assert_eq!(collapsed_hunk_summary(rows[0].text.as_str()), None);
+    assert_eq!(
+        hunk_collapse_key_for_header(rows[3].text.as_str()),
+        Some(10)
+    );
+}
+
+#[test]
+fn large_hunks_fold_their_middle_until_expanded() {
+    let mut patch = String::from("@@ -1,0 +1,20 @@\n");
+    for line in 0..20 {
+        patch.push_str(&format!("+line {line}\n"));
+    }
+    let document = parse_patch_document(patch.as_str());
+    let mut folds = HunkFolds::default();
+    folds.set_large_hunk_threshold(10);
+
+    let rows = folds.fold_rows("gen.rs", parse_patch_side_by_side(patch.as_str()), false);
+    let edge = LARGE_HUNK_EDGE_ROWS;
+    assert_eq!(rows.len(), 1 + edge * 2 + 1);
+    assert_eq!(
+        folds.code_row_count("gen.rs", &document.hunks[0], false),
+        edge * 2 + 1
+    );
+    assert_eq!(rows[edge].right.text, format!("line {}", edge - 1));
+    assert_eq!(
+        large_hunk_hidden_line_count(rows[edge + 1].text.as_str()),
+        Some(20 - edge * 2)
+    );
+    assert_eq!(rows[edge + 2].right.text, format!("line {}", 20 - edge));
+    assert!(folds.folds_rows("gen.rs", &document.hunks));
+
+    folds.expand_large_hunk("gen.rs", hunk_collapse_key(&document.hunks[0]));
+    let rows = folds.fold_rows("gen.rs", parse_patch_side_by_side(patch.as_str()), false);
+    assert_eq!(rows.len(), 21);
+    assert_eq!(
+        folds.code_row_count("gen.rs", &document.hunks[0], false),
+        20
+    );
+    assert!(!folds.folds_rows("gen.rs", &document.hunks));
+}
+
+#[test]
+fn hunk_folds_toggle_collapsed_hunks_per_file() {
+    let mut folds = HunkFolds::default();
+    folds.toggle_collapsed("src/lib.rs", 10);
+    assert!(folds.is_collapsed("src/lib.rs", 10));
+    assert!(!folds.is_collapsed("src/main.rs", 10));
+
+    folds.toggle_collapsed("src/lib.rs", 10);
+    assert!(!folds.has_collapsed_hunks("src/lib.rs"));
+
+    folds.collapse_all("src/lib.rs", [1, 10, 40]);
+    assert!(folds.is_collapsed("src/lib.rs", 40));
+    folds.expand_all("src/lib.rs", []);
+    assert!(!folds.has_collapsed_hunks("src/lib.rs"));
+}
@@ -0,0 +1,93 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::editor_recovery::{EditorRecoveryStore, RecoveredEditorBuffer};
+
+struct TempRecoveryDir {
+    path: PathBuf,
+}
+
+impl TempRecoveryDir {
+    fn new(prefix: &str) -> Self {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("hunk-{prefix}-{}-{unique}", std::process::id()));
+        Self { path }
+    }
+}
+
+impl Drop for TempRecoveryDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+fn buffer(path: &str, text: &str) -> RecoveredEditorBuffer {
+    RecoveredEditorBuffer {
+        repo_root: PathBuf::from("/repo"),
+        path: path.to_string(),
+        text: text.to_string(),
+        saved_unix_time: 100,
+    }
+}
+
+#[test]
+fn recovered_buffers_round_trip_per_path_and_are_removed() {
+    let tempdir = TempRecoveryDir::new("editor-recovery-round-trip");
+    let store = EditorRecoveryStore::from_dir(tempdir.path.join("editor-recovery"));
+    assert_eq!(store.load("repo-0123", "src/lib.rs").expect("empty"), None);
+    assert!(store.list("repo-0123").expect("empty list").is_empty());
+
+    store
+        .save("repo-0123", &buffer("src/lib.rs", "first"))
+        .expect("save first");
+    store
+        .save("repo-0123", &buffer("src/lib.rs", "second"))
+        .expect("save second");
+    store
+        .save("repo-0123", &buffer("lib.rs", "top level"))
+        .expect("save same file name");
+
+    assert_eq!(
+        store.load("repo-0123", "src/lib.rs").expect("load buffer"),
+        Some(buffer("src/lib.rs", "second"))
+    );
+    assert_eq!(store.load("other-4567", "src/lib.rs").expect("other"), None);
+    assert_eq!(
+        store.list("repo-0123").expect("list buffers"),
+        vec![
+            buffer("lib.rs", "top level"),
+            buffer("src/lib.rs", "second")
+        ]
+    );
+
+    store.remove("repo-0123", "src/lib.rs").expect("remove");
+    store
+        .remove("repo-0123", "src/lib.rs")
+        .expect("remove twice");
+    assert_eq!(
+        store.load("repo-0123", "src/lib.rs").expect("removed"),
+        None
+    );
+    assert_eq!(store.list("repo-0123").expect("list after remove").len(), 1);
+}
+
+#[test]
+fn unreadable_recovered_buffer_reports_an_error_and_is_skipped_in_lists() {
+    let tempdir = TempRecoveryDir::new("editor-recovery-corrupt");
+    let store = EditorRecoveryStore::from_dir(tempdir.path.join("editor-recovery"));
+    let path = store
+        .save("repo-0123", &buffer("notes.md", "draft"))
+        .expect("save buffer");
+    fs::write(&path, "not json").expect("corrupt buffer");
+
+    let err = store
+        .load("repo-0123", "notes.md")
+        .expect_err("corrupt buffer should fail");
+    assert!(err.to_string().contains("failed to parse editor recovery"));
+    assert!(store.list("repo-0123").expect("list").is_empty());
+}
@@ -0,0 +1,84 @@
+use hunk_domain::config::AppConfig;
+use hunk_domain::l10n::{Catalog, Language, Localizer, bundled_catalog};
+
+#[test]
+fn catalog_parses_comments_continuations_and_placeholders() {
+    let catalog = Catalog::parse(
+        "\
+# comment
+greeting = Hello { $name }!
+notice =
+    First line.
+    Second line.
+",
+    )
+    .expect("parse catalog");
+
+    assert_eq!(
+        catalog.format("greeting", &[("name", "Ada")]).as_deref(),
+        Some("Hello Ada!")
+    );
+    assert_eq!(catalog.placeholders("greeting"), vec!["name".to_string()]);
+    assert_eq!(
+        catalog.format("notice", &[]).as_deref(),
+        Some("First line.\nSecond line.")
+    );
+    assert_eq!(catalog.format("missing", &[]), None);
+}
+
+#[test]
+fn catalog_rejects_duplicate_and_malformed_messages() {
+    let err = Catalog::parse("a = one\na = two\n").expect_err("duplicate key should fail");
+    assert!(err.to_string().contains("duplicate message `a`"));
+
+    let err = Catalog::parse("not a message\n").expect_err("missing `=` should fail");
+    assert!(err.to_string().contains("line 1"));
+}
+
+#[test]
+fn bundled_translations_cover_english_keys_with_matching_placeholders() {
+    let english = bundled_catalog(Language::English).expect("english catalog");
+    for language in Language::ALL {
+        let catalog = bundled_catalog(language).expect("bundled catalog");
+        for key in english.keys() {
+            assert!(
+                catalog.contains(key),
+                "{} is missing `{key}`",
+                language.code()
+            );
+            assert_eq!(
+                catalog.placeholders(key),
+                english.placeholders(key),
+                "{} placeholders differ for `{key}`",
+                language.code()
+            );
+        }
+        for key in catalog.keys() {
+            assert!(
+                english.contains(key),
+                "{} has unknown key `{key}`",
+                language.code()
+            );
+        }
+    }
+}
+
+#[test]
+fn localizer_falls_back_to_english_then_key() {
+    let spanish = Localizer::new(Language::Spanish).expect("spanish localizer");
+    assert_eq!(spanish.text("settings-save"), "Guardar");
+    assert_eq!(
+        spanish.format("review-pin-pinned", &[("location", "src/lib.rs:3")]),
+        "Fijada src/lib.rs:3."
+    );
+    assert_eq!(spanish.text("not-a-message"), "not-a-message");
+}
+
+#[test]
+fn app_config_language_defaults_to_english_and_parses() {
+    let config: AppConfig = toml::from_str("").expect("empty config should parse");
+    assert_eq!(config.language, Language::English);
+
+    let config: AppConfig = toml::from_str("language = \"spanish\"").expect("language parses");
+    assert_eq!(config.language, Language::Spanish);
+}
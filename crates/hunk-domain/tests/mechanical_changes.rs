@@ -0,0 +1,93 @@
+use std::collections::BTreeSet;
+
+use hunk_domain::mechanical_changes::{
+    MECHANICAL_CHANGE_SAMPLE_LINES, MechanicalChangeApprovals, MechanicalChangeGroup,
+    MechanicalChangeSample, detect_mechanical_changes,
+};
+
+fn patch(path: &str, hunks: &str) -> String {
+    format!("diff --git a/{path} b/{path}\n--- a/{path}\n+++ b/{path}\n{hunks}")
+}
+
+#[test]
+fn repeated_renames_are_grouped_across_files() {
+    let patches = (0..5)
+        .map(|ix| {
+            let path = format!("src/module_{ix}.rs");
+            let hunks = "@@ -1,4 +1,4 @@\n use crate::config;\n\
+                         -let client = OldClient::new(OldClient::DEFAULT);\n\
+                         +let client = NewClient::new(NewClient::DEFAULT);\n \n\
+                         -OldClient::shutdown();\n+NewClient::shutdown();\n";
+            (path.clone(), patch(path.as_str(), hunks))
+        })
+        .collect::<Vec<_>>();
+
+    let groups = detect_mechanical_changes(
+        patches
+            .iter()
+            .map(|(path, patch)| (path.as_str(), patch.as_str())),
+    );
+
+    assert_eq!(groups.len(), 1);
+    let group = &groups[0];
+    assert_eq!(
+        (group.from.as_str(), group.to.as_str()),
+        ("OldClient", "NewClient")
+    );
+    assert_eq!(group.paths.len(), 5);
+    assert_eq!(group.changed_lines, 10);
+    assert_eq!(group.samples.len(), MECHANICAL_CHANGE_SAMPLE_LINES);
+    assert_eq!(
+        group.samples[0],
+        MechanicalChangeSample {
+            path: "src/module_0.rs".to_string(),
+            removed: "let client = OldClient::new(OldClient::DEFAULT);".to_string(),
+            added: "let client = NewClient::new(NewClient::DEFAULT);".to_string(),
+        }
+    );
+}
+
+#[test]
+fn files_with_other_edits_or_too_few_matches_are_not_grouped() {
+    let rename = "@@ -1 +1 @@\n-foo();\n+bar();\n";
+    let patches = [
+        ("a.rs", patch("a.rs", rename)),
+        ("b.rs", patch("b.rs", rename)),
+        // Also adds a line, so the file is not purely mechanical.
+        (
+            "c.rs",
+            patch("c.rs", "@@ -1 +1,2 @@\n-foo();\n+bar();\n+extra();\n"),
+        ),
+        // Two different rewrites on one line.
+        ("d.rs", patch("d.rs", "@@ -1 +1 @@\n-foo(x);\n+bar(y);\n")),
+    ];
+
+    let groups =
+        detect_mechanical_changes(patches.iter().map(|(path, patch)| (*path, patch.as_str())));
+
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn unapproving_a_group_keeps_files_collapsed_before_the_approval() {
+    let group = MechanicalChangeGroup {
+        from: "OldClient".to_string(),
+        to: "NewClient".to_string(),
+        paths: vec!["src/a.rs".to_string(), "src/b.rs".to_string()],
+        changed_lines: 2,
+        samples: Vec::new(),
+    };
+    let mut approvals = MechanicalChangeApprovals::default();
+    let mut collapsed_files = BTreeSet::from(["src/a.rs".to_string()]);
+
+    approvals.toggle(&group, &mut collapsed_files);
+    assert!(approvals.is_approved(&group));
+    assert_eq!(
+        collapsed_files,
+        BTreeSet::from(["src/a.rs".to_string(), "src/b.rs".to_string()])
+    );
+
+    approvals.toggle(&group, &mut collapsed_files);
+    assert!(!approvals.is_approved(&group));
+    assert_eq!(collapsed_files, BTreeSet::from(["src/a.rs".to_string()]));
+}
@@ -0,0 +1,136 @@
+use hunk_domain::review_export::{
+    ReviewExportColorMode, ReviewExportDocument, ReviewExportFile, ReviewExportLine,
+    ReviewExportLineKind, ReviewExportSpan, render_review_pdf,
+};
+
+fn code_line(kind: ReviewExportLineKind, line: u32, text: &str) -> ReviewExportLine {
+    ReviewExportLine {
+        kind,
+        old_line: (kind != ReviewExportLineKind::Added).then_some(line),
+        new_line: (kind != ReviewExportLineKind::Removed).then_some(line),
+        spans: vec![
+            ReviewExportSpan {
+                text: "fn ".to_string(),
+                color: Some([0xcf, 0x22, 0x2e]),
+            },
+            ReviewExportSpan {
+                text: text.to_string(),
+                color: None,
+            },
+        ],
+    }
+}
+
+fn sample_document(line_count: u32) -> ReviewExportDocument {
+    let mut lines = vec![ReviewExportLine::plain(
+        ReviewExportLineKind::HunkHeader,
+        "@@ -1,3 +1,4 @@",
+    )];
+    lines.extend((1..=line_count).map(|line| {
+        let kind = match line % 3 {
+            0 => ReviewExportLineKind::Added,
+            1 => ReviewExportLineKind::Removed,
+            _ => ReviewExportLineKind::Context,
+        };
+        code_line(kind, line, "main() {}")
+    }));
+    lines.push(ReviewExportLine::plain(
+        ReviewExportLineKind::Comment,
+        "[open] Needs a test (and docs)",
+    ));
+
+    ReviewExportDocument {
+        title: "Hunk review: demo".to_string(),
+        subtitle: "main".to_string(),
+        files: vec![ReviewExportFile {
+            path: "src/main.rs".to_string(),
+            status: "M".to_string(),
+            added: 2,
+            removed: 1,
+            lines,
+        }],
+    }
+}
+
+fn pdf_text(bytes: &[u8]) -> String {
+    String::from_utf8(bytes.to_vec()).expect("pdf output should be ascii")
+}
+
+#[test]
+fn review_pdf_has_summary_page_and_file_pages() {
+    let pdf = pdf_text(&render_review_pdf(
+        &sample_document(3),
+        ReviewExportColorMode::Color,
+    ));
+
+    assert!(pdf.starts_with("%PDF-1.4\n"));
+    assert!(pdf.ends_with("%%EOF\n"));
+    assert!(pdf.contains("/Count 2"));
+    assert!(pdf.contains("(Changed files \\(1\\)) Tj"));
+    assert!(pdf.contains("(src/main.rs  \\(M, +2 -1\\)) Tj"));
+    assert!(pdf.contains("(Page 2 of 2) Tj"));
+    assert!(pdf.contains("([open] Needs a test \\(and docs\\)) Tj"));
+}
+
+#[test]
+fn long_diffs_are_paginated() {
+    let pdf = pdf_text(&render_review_pdf(
+        &sample_document(400),
+        ReviewExportColorMode::Color,
+    ));
+
+    assert!(pdf.contains("(Page 1 of 8) Tj"));
+    assert!(pdf.contains("/Count 8"));
+}
+
+#[test]
+fn grayscale_mode_only_uses_gray_fills() {
+    let color = pdf_text(&render_review_pdf(
+        &sample_document(3),
+        ReviewExportColorMode::Color,
+    ));
+    let grayscale = pdf_text(&render_review_pdf(
+        &sample_document(3),
+        ReviewExportColorMode::Grayscale,
+    ));
+
+    assert!(color.contains(" rg\n"));
+    assert!(!grayscale.contains(" rg\n"));
+    assert!(grayscale.contains(" g\n"));
+}
+
+#[test]
+fn cross_reference_offsets_point_at_objects() {
+    let bytes = render_review_pdf(&sample_document(3), ReviewExportColorMode::Color);
+    let pdf = pdf_text(&bytes);
+    let startxref = pdf
+        .rsplit("startxref\n")
+        .next()
+        .and_then(|tail| tail.lines().next())
+        .and_then(|offset| offset.parse::<usize>().ok())
+        .expect("startxref offset");
+    assert!(pdf[startxref..].starts_with("xref\n"));
+
+    let entries = pdf[startxref..]
+        .lines()
+        .skip(3)
+        .take_while(|line| line.ends_with(" n "))
+        .collect::<Vec<_>>();
+    assert!(!entries.is_empty());
+    for (ix, entry) in entries.iter().enumerate() {
+        let offset = entry[..10].parse::<usize>().expect("xref offset");
+        assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", ix + 1).as_str()));
+    }
+}
+
+#[test]
+fn non_ascii_text_is_replaced_and_tabs_are_expanded() {
+    let mut document = sample_document(0);
+    document.files[0].lines = vec![ReviewExportLine::plain(
+        ReviewExportLineKind::Context,
+        "\tlet caf\u{e9} = 1;",
+    )];
+    let pdf = pdf_text(&render_review_pdf(&document, ReviewExportColorMode::Color));
+
+    assert!(pdf.contains("(    let caf? = 1;) Tj"));
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hunk_domain::snapshot_cache::{CachedLineStats, CachedReviewSnapshot, SnapshotCacheStore};
+use hunk_domain::state::CachedChangedFileState;
+
+struct TempCacheDir {
+    path: PathBuf,
+}
+
+impl TempCacheDir {
+    fn new(prefix: &str) -> Self {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("hunk-{prefix}-{}-{unique}", std::process::id()));
+        Self { path }
+    }
+}
+
+impl Drop for TempCacheDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+fn snapshot(state_id: &str) -> CachedReviewSnapshot {
+    CachedReviewSnapshot {
+        repo_root: PathBuf::from("/repo"),
+        state_id: state_id.to_string(),
+        left_source_id: "branch:main".to_string(),
+        left_tree_id: Some("4b825dc642cb6eb9a060e54bf8d69288fbee4904".to_string()),
+        right_source_id: "workspace:primary".to_string(),
+        files: vec![CachedChangedFileState {
+            path: "src/lib.rs".to_string(),
+            status_tag: "M".to_string(),
+            staged: false,
+            unstaged: false,
+            untracked: false,
+        }],
+        file_line_stats: BTreeMap::from([(
+            "src/lib.rs".to_string(),
+            CachedLineStats {
+                added: 1,
+                removed: 0,
+            },
+        )]),
+        patches_by_path: BTreeMap::from([(
+            "src/lib.rs".to_string(),
+            "@@ -1 +1,2 @@\n line\n+added\n".to_string(),
+        )]),
+        cached_unix_time: 100,
+    }
+}
+
+#[test]
+fn review_snapshot_round_trips_and_replaces_previous_state() {
+    let tempdir = TempCacheDir::new("snapshot-cache-round-trip");
+    let store = SnapshotCacheStore::from_dir(tempdir.path.join("snapshot-cache"));
+    assert_eq!(store.load("repo-0123").expect("empty store"), None);
+
+    store
+        .save("repo-0123", &snapshot("head:1"))
+        .expect("save first");
+    store
+        .save("repo-0123", &snapshot("head:2"))
+        .expect("save second");
+
+    assert_eq!(
+        store.load("repo-0123").expect("load snapshot"),
+        Some(snapshot("head:2"))
+    );
+    assert_eq!(store.load("other-4567").expect("other repo"), None);
+}
+
+#[test]
+fn unreadable_snapshot_cache_reports_an_error() {
+    let tempdir = TempCacheDir::new("snapshot-cache-corrupt");
+    let store = SnapshotCacheStore::from_dir(tempdir.path.join("snapshot-cache"));
+    fs::create_dir_all(store.dir()).expect("create cache dir");
+    fs::write(store.dir().join("repo-0123.json"), "not json").expect("write corrupt cache");
+
+    let err = store
+        .load("repo-0123")
+        .expect_err("corrupt cache should fail");
+    assert!(err.to_string().contains("failed to parse snapshot cache"));
+}
@@ -0,0 +1,74 @@
+use hunk_domain::diff::parse_patch_side_by_side;
+use hunk_domain::unicode_hazards::{UnicodeHazardKind, unicode_hazard_banner, unicode_hazards};
+
+#[test]
+fn plain_ascii_and_foreign_text_have_no_hazards() {
+    assert!(unicode_hazards("let total = count + 1;").is_empty());
+    assert!(unicode_hazards("// Привет, мир").is_empty());
+    assert!(unicode_hazards("title = \"Ελληνικά\"").is_empty());
+    assert!(unicode_hazards("\u{feff}fn main() {}").is_empty());
+}
+
+#[test]
+fn bidi_controls_and_invisible_characters_are_reported_with_their_offsets() {
+    let line = "if is_admin \u{202e} \u{2066}// check\u{2069} {\u{200b}";
+    let hazards = unicode_hazards(line);
+
+    assert_eq!(
+        hazards
+            .iter()
+            .map(|hazard| (hazard.ch, hazard.kind))
+            .collect::<Vec<_>>(),
+        vec![
+            ('\u{202e}', UnicodeHazardKind::BidiControl),
+            ('\u{2066}', UnicodeHazardKind::BidiControl),
+            ('\u{2069}', UnicodeHazardKind::BidiControl),
+            ('\u{200b}', UnicodeHazardKind::Invisible),
+        ]
+    );
+    assert_eq!(&line[hazards[0].range.clone()], "\u{202e}");
+    assert_eq!(hazards[0].label(), "U+202E right-to-left override");
+    assert_eq!(hazards[3].label(), "U+200B zero width space");
+}
+
+#[test]
+fn homoglyphs_are_reported_inside_ascii_words() {
+    let hazards = unicode_hazards("if user.isАdmin { grant(); }");
+
+    assert_eq!(hazards.len(), 1);
+    assert_eq!(
+        hazards[0].kind,
+        UnicodeHazardKind::Confusable { looks_like: 'A' }
+    );
+    assert_eq!(hazards[0].label(), "U+0410 looks like 'A'");
+
+    let fullwidth = unicode_hazards("ｅval(input)");
+    assert_eq!(
+        fullwidth
+            .iter()
+            .map(|hazard| hazard.kind)
+            .collect::<Vec<_>>(),
+        vec![UnicodeHazardKind::Confusable { looks_like: 'e' }]
+    );
+}
+
+#[test]
+fn hazard_banner_lists_added_lines_only() {
+    let rows = parse_patch_side_by_side(
+        "\
+@@ -1,2 +1,2 @@
+-let a\u{200b} = 1;
++let b\u{200b} = 1;
+ let c = 2;
+",
+    );
+
+    assert_eq!(
+        unicode_hazard_banner(&rows).as_deref(),
+        Some("1 hidden or confusable character added: line 1 U+200B zero width space")
+    );
+    assert_eq!(
+        unicode_hazard_banner(&parse_patch_side_by_side("@@ -1 +1 @@\n-a\n+b\n")),
+        None
+    );
+}
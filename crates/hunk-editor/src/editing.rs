@@ -0,0 +1,254 @@
+use hunk_text::{Selection, TextPosition, Transaction};
+
+use crate::EditorState;
+use crate::display::line_text;
+
+impl EditorState {
+    /// Breaks the line at the selection and carries its indentation over. A line that opens a
+    /// block indents the new line one level deeper, and a caret between a bracket pair moves
+    /// the closing bracket to a line of its own below the caret.
+    pub(crate) fn insert_newline(&mut self) -> bool {
+        let snapshot = self.buffer.snapshot();
+        let range = self.primary_selection.range();
+        let before = line_prefix(&line_text(&snapshot, range.start.line), range.start.column);
+        let after: String = line_text(&snapshot, range.end.line)
+            .chars()
+            .skip(range.end.column)
+            .collect();
+        let indent = leading_whitespace(&before);
+        if !self.editing_rules.opens_block(&before) {
+            return self.replace_selection_text(format!("\n{indent}").as_str());
+        }
+
+        let inner_indent = format!("{indent}{}", self.indent_unit(indent));
+        let closes_block = before
+            .trim_end()
+            .chars()
+            .next_back()
+            .and_then(|open| self.editing_rules.closing_bracket(open))
+            .is_some_and(|close| after.trim_start().starts_with(close));
+        if !closes_block {
+            return self.replace_selection_text(format!("\n{inner_indent}").as_str());
+        }
+        if !self.replace_selection_text(format!("\n{inner_indent}\n{indent}").as_str()) {
+            return false;
+        }
+        self.primary_selection = Selection::caret(TextPosition::new(
+            range.start.line + 1,
+            inner_indent.chars().count(),
+        ));
+        true
+    }
+
+    /// Inserts typed text. A closing bracket typed into a line's leading whitespace first
+    /// steps the line back one indentation level.
+    pub(crate) fn insert_typed_text(&mut self, text: &str) -> bool {
+        self.insert_closing_bracket(text)
+            .unwrap_or_else(|| self.replace_selection_text(text))
+    }
+
+    fn insert_closing_bracket(&mut self, text: &str) -> Option<bool> {
+        let mut chars = text.chars();
+        let (Some(bracket), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        if !self.editing_rules.is_closing_bracket(bracket) || !self.primary_selection.is_caret() {
+            return None;
+        }
+
+        let caret = self.primary_selection.head;
+        let before = line_prefix(
+            &line_text(&self.buffer.snapshot(), caret.line),
+            caret.column,
+        );
+        if before.is_empty() || !before.chars().all(|ch| matches!(ch, ' ' | '\t')) {
+            return None;
+        }
+        let unit = self.indent_unit(&before);
+        let indent = before.strip_suffix(unit.as_str())?.to_string();
+        self.primary_selection = Selection::new(TextPosition::new(caret.line, 0), caret);
+        Some(self.replace_selection_text(format!("{indent}{bracket}").as_str()))
+    }
+
+    /// Comments out the lines of the selection, or uncomments them when every non-blank one
+    /// is already commented. Languages without line comments wrap each line in block comment
+    /// delimiters instead.
+    pub(crate) fn toggle_line_comment(&mut self) -> bool {
+        let style = match (
+            self.editing_rules.line_comment,
+            self.editing_rules.block_comment,
+        ) {
+            (Some(prefix), _) => CommentStyle::Line(prefix),
+            (None, Some((open, close))) => CommentStyle::Block(open, close),
+            (None, None) => return false,
+        };
+
+        let snapshot = self.buffer.snapshot();
+        let range = self.primary_selection.range();
+        let mut end_line = range.end.line;
+        // A selection ending at the start of a line does not take that line along.
+        if end_line > range.start.line && range.end.column == 0 {
+            end_line -= 1;
+        }
+        let lines = (range.start.line..=end_line)
+            .map(|line| (line, line_text(&snapshot, line)))
+            .filter(|(_, text)| !text.trim().is_empty())
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            return false;
+        }
+        let uncomment = lines
+            .iter()
+            .all(|(_, text)| style.is_commented(text.trim()));
+        // Comment markers line up at the shallowest indentation of the lines.
+        let comment_column = lines
+            .iter()
+            .map(|(_, text)| leading_whitespace(text).len())
+            .min()
+            .unwrap_or_default();
+
+        let mut transaction = Transaction::new();
+        let mut shifts = Vec::with_capacity(lines.len());
+        for (line, text) in &lines {
+            let Ok(line_start) = snapshot.line_to_byte(*line) else {
+                return false;
+            };
+            let edit = if uncomment {
+                style.uncomment_edit(text)
+            } else {
+                style.comment_edit(text, comment_column)
+            };
+            for (byte_range, replacement) in edit.edits {
+                transaction.push_replace(
+                    line_start + byte_range.start..line_start + byte_range.end,
+                    replacement,
+                );
+            }
+            shifts.push((*line, edit.shift));
+        }
+        if self.buffer.apply_transaction(transaction).is_err() {
+            return false;
+        }
+
+        let shift_position = |position: TextPosition| {
+            let Some((_, shift)) = shifts.iter().find(|(line, _)| *line == position.line) else {
+                return position;
+            };
+            TextPosition::new(position.line, shift.apply(position.column))
+        };
+        self.primary_selection = Selection::new(
+            shift_position(self.primary_selection.anchor),
+            shift_position(self.primary_selection.head),
+        );
+        self.preferred_display_column = None;
+        true
+    }
+
+    fn indent_unit(&self, indent: &str) -> String {
+        if indent.contains('\t') {
+            "\t".to_string()
+        } else {
+            " ".repeat(self.tab_width)
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum CommentStyle {
+    Line(&'static str),
+    Block(&'static str, &'static str),
+}
+
+/// The edits commenting or uncommenting one line, as byte ranges within the line, and how
+/// columns after its opening marker move.
+struct LineCommentEdit {
+    edits: Vec<(std::ops::Range<usize>, String)>,
+    shift: ColumnShift,
+}
+
+#[derive(Clone, Copy)]
+enum ColumnShift {
+    Inserted { column: usize, len: usize },
+    Removed { column: usize, len: usize },
+}
+
+impl ColumnShift {
+    fn apply(self, column: usize) -> usize {
+        match self {
+            Self::Inserted { column: at, len } if column >= at => column + len,
+            Self::Removed { column: at, len } if column > at => column.saturating_sub(len).max(at),
+            _ => column,
+        }
+    }
+}
+
+impl CommentStyle {
+    fn is_commented(self, trimmed: &str) -> bool {
+        match self {
+            Self::Line(prefix) => trimmed.starts_with(prefix),
+            Self::Block(open, close) => {
+                trimmed.len() >= open.len() + close.len()
+                    && trimmed.starts_with(open)
+                    && trimmed.ends_with(close)
+            }
+        }
+    }
+
+    fn comment_edit(self, text: &str, column: usize) -> LineCommentEdit {
+        let (open, close) = match self {
+            Self::Line(prefix) => (prefix, None),
+            Self::Block(open, close) => (open, Some(close)),
+        };
+        let marker = format!("{open} ");
+        let mut edits = vec![(column..column, marker.clone())];
+        if let Some(close) = close {
+            edits.push((text.len()..text.len(), format!(" {close}")));
+        }
+        LineCommentEdit {
+            edits,
+            shift: ColumnShift::Inserted {
+                column,
+                len: marker.len(),
+            },
+        }
+    }
+
+    fn uncomment_edit(self, text: &str) -> LineCommentEdit {
+        let start = leading_whitespace(text).len();
+        let (open, close) = match self {
+            Self::Line(prefix) => (prefix, None),
+            Self::Block(open, close) => (open, Some(close)),
+        };
+        let mut open_end = start + open.len();
+        if text[open_end..].starts_with(' ') {
+            open_end += 1;
+        }
+        let mut edits = vec![(start..open_end, String::new())];
+        if let Some(close) = close {
+            let end = text.trim_end().len();
+            let mut close_start = end - close.len();
+            if close_start > open_end && text[..close_start].ends_with(' ') {
+                close_start -= 1;
+            }
+            edits.push((close_start.max(open_end)..end, String::new()));
+        }
+        LineCommentEdit {
+            edits,
+            shift: ColumnShift::Removed {
+                column: start,
+                len: open_end - start,
+            },
+        }
+    }
+}
+
+fn line_prefix(line: &str, column: usize) -> String {
+    line.chars().take(column).collect()
+}
+
+fn leading_whitespace(text: &str) -> &str {
+    let end = text
+        .find(|ch: char| !matches!(ch, ' ' | '\t'))
+        .unwrap_or(text.len());
+    &text[..end]
+}
@@ -0,0 +1,62 @@
+use hunk_text::{Selection, TextPosition, Transaction};
+
+use crate::EditorState;
+
+impl EditorState {
+    /// Replaces the document with a formatter's output. Only the lines between the unchanged
+    /// head and tail of the document are rewritten, so undo restores the original in one step
+    /// and a selection outside the rewritten lines stays on the same text.
+    pub(crate) fn apply_formatted_text(&mut self, formatted: &str) -> bool {
+        let current = self.buffer.text();
+        if current == formatted {
+            return false;
+        }
+
+        let old_lines = current.split_inclusive('\n').collect::<Vec<_>>();
+        let new_lines = formatted.split_inclusive('\n').collect::<Vec<_>>();
+        let prefix = old_lines
+            .iter()
+            .zip(&new_lines)
+            .take_while(|(old, new)| old == new)
+            .count();
+        let suffix = old_lines[prefix..]
+            .iter()
+            .rev()
+            .zip(new_lines[prefix..].iter().rev())
+            .take_while(|(old, new)| old == new)
+            .count();
+        let start = old_lines[..prefix]
+            .iter()
+            .map(|line| line.len())
+            .sum::<usize>();
+        let suffix_len = old_lines[old_lines.len() - suffix..]
+            .iter()
+            .map(|line| line.len())
+            .sum::<usize>();
+        let old_end = current.len() - suffix_len;
+        let new_end = formatted.len() - suffix_len;
+
+        let transaction = Transaction::new().replace(start..old_end, &formatted[start..new_end]);
+        if self.buffer.apply_transaction(transaction).is_err() {
+            return false;
+        }
+
+        let old_tail_line = old_lines.len() - suffix;
+        let new_tail_line = new_lines.len() - suffix;
+        let map_position = |position: TextPosition| {
+            if position.line < old_tail_line {
+                return position;
+            }
+            TextPosition::new(
+                position.line - old_tail_line + new_tail_line,
+                position.column,
+            )
+        };
+        self.primary_selection = Selection::new(
+            map_position(self.primary_selection.anchor),
+            map_position(self.primary_selection.head),
+        );
+        self.preferred_display_column = None;
+        true
+    }
+}
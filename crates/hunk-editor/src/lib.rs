@@ -1,4 +1,6 @@
 mod display;
+mod editing;
+mod formatting;
 mod workspace;
 mod workspace_display;
 mod workspace_display_projection;
@@ -11,8 +13,8 @@ use display::{
     project_search_matches, search_matches_for_line,
 };
 use hunk_language::{
-    CompletionRequest, DefinitionRequest, Diagnostic, HoverRequest, LanguageId, ParseStatus,
-    SemanticToken,
+    CompletionRequest, DefinitionRequest, Diagnostic, EditingRules, HoverRequest, LanguageId,
+    ParseStatus, SemanticToken,
 };
 use hunk_text::{Selection, TextBuffer, TextPosition, Transaction};
 pub use workspace::{
@@ -156,6 +158,7 @@ pub enum EditorCommand {
     SetShowWhitespace(bool),
     SetSelection(Selection),
     SetLanguage(Option<LanguageId>),
+    SetEditingRules(EditingRules),
     SetParseStatus(ParseStatus),
     SetSearchQuery(Option<String>),
     SetOverlays(Vec<OverlayDescriptor>),
@@ -171,7 +174,10 @@ pub enum EditorCommand {
     ClearCompletionRequest,
     ReplaceAll(String),
     ReplaceSelection(String),
+    ApplyFormatting(String),
     InsertText(String),
+    InsertNewline,
+    ToggleLineComment,
     DeleteBackward,
     DeleteForward,
     MoveLeft,
@@ -206,6 +212,7 @@ pub struct EditorState {
     dirty: bool,
     saved_text: String,
     language_id: Option<LanguageId>,
+    editing_rules: EditingRules,
     parse_status: ParseStatus,
     wrap_width: Option<usize>,
     tab_width: usize,
@@ -240,6 +247,7 @@ impl EditorState {
             dirty: false,
             saved_text,
             language_id: None,
+            editing_rules: EditingRules::default(),
             parse_status: ParseStatus::Idle,
             wrap_width: None,
             tab_width: 4,
@@ -398,6 +406,9 @@ impl EditorState {
             EditorCommand::SetLanguage(language_id) => {
                 self.language_id = language_id;
             }
+            EditorCommand::SetEditingRules(rules) => {
+                self.editing_rules = rules;
+            }
             EditorCommand::SetParseStatus(parse_status) => {
                 self.parse_status = parse_status;
             }
@@ -469,11 +480,31 @@ impl EditorState {
                 output.document_changed = true;
                 output.selection_changed = true;
             }
-            EditorCommand::ReplaceSelection(text) | EditorCommand::InsertText(text) => {
+            EditorCommand::ReplaceSelection(text) => {
                 let changed = self.replace_selection_text(&text);
                 output.document_changed = changed;
                 output.selection_changed = changed;
             }
+            EditorCommand::ApplyFormatting(text) => {
+                let changed = self.apply_formatted_text(&text);
+                output.document_changed = changed;
+                output.selection_changed = changed;
+            }
+            EditorCommand::InsertText(text) => {
+                let changed = self.insert_typed_text(&text);
+                output.document_changed = changed;
+                output.selection_changed = changed;
+            }
+            EditorCommand::InsertNewline => {
+                let changed = self.insert_newline();
+                output.document_changed = changed;
+                output.selection_changed = changed;
+            }
+            EditorCommand::ToggleLineComment => {
+                let changed = self.toggle_line_comment();
+                output.document_changed = changed;
+                output.selection_changed = changed;
+            }
             EditorCommand::DeleteBackward => {
                 let changed = self.delete_backward();
                 output.document_changed = changed;
@@ -0,0 +1,155 @@
+use hunk_editor::{EditorCommand, EditorState};
+use hunk_language::EditingRules;
+use hunk_text::{BufferId, Selection, TextBuffer, TextPosition};
+
+const RUST_LIKE: EditingRules = EditingRules {
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    brackets: &[('{', '}'), ('(', ')'), ('[', ']')],
+    indent_after: &[],
+};
+
+fn editor_with_rules(text: &str, rules: EditingRules) -> EditorState {
+    let mut editor = EditorState::new(TextBuffer::new(BufferId::new(1), text));
+    editor.apply(EditorCommand::SetEditingRules(rules));
+    editor
+}
+
+fn set_caret(editor: &mut EditorState, line: usize, column: usize) {
+    editor.apply(EditorCommand::SetSelection(Selection::caret(
+        TextPosition::new(line, column),
+    )));
+}
+
+#[test]
+fn newline_after_an_opening_bracket_indents_and_splits_the_pair() {
+    let mut editor = editor_with_rules("fn main() {}", RUST_LIKE);
+    set_caret(&mut editor, 0, 11);
+
+    assert!(editor.apply(EditorCommand::InsertNewline).document_changed);
+
+    assert_eq!(editor.buffer().text(), "fn main() {\n    \n}");
+    assert_eq!(
+        editor.selection(),
+        Selection::caret(TextPosition::new(1, 4))
+    );
+
+    editor.apply(EditorCommand::InsertText("x".to_string()));
+    editor.apply(EditorCommand::InsertNewline);
+    assert_eq!(editor.buffer().text(), "fn main() {\n    x\n    \n}");
+}
+
+#[test]
+fn newline_uses_language_block_endings_and_plain_text_keeps_indentation() {
+    let python = EditingRules {
+        line_comment: Some("#"),
+        indent_after: &[":"],
+        ..EditingRules::default()
+    };
+    let mut editor = editor_with_rules("\tif ready:", python);
+    set_caret(&mut editor, 0, 10);
+    editor.apply(EditorCommand::InsertNewline);
+    assert_eq!(editor.buffer().text(), "\tif ready:\n\t\t");
+
+    let mut editor = editor_with_rules("  note {", EditingRules::default());
+    set_caret(&mut editor, 0, 8);
+    editor.apply(EditorCommand::InsertNewline);
+    assert_eq!(editor.buffer().text(), "  note {\n  ");
+}
+
+#[test]
+fn typing_a_closing_bracket_in_leading_whitespace_dedents_the_line() {
+    let mut editor = editor_with_rules("if x {\n        ", RUST_LIKE);
+    set_caret(&mut editor, 1, 8);
+
+    editor.apply(EditorCommand::InsertText("}".to_string()));
+
+    assert_eq!(editor.buffer().text(), "if x {\n    }");
+    assert_eq!(
+        editor.selection(),
+        Selection::caret(TextPosition::new(1, 5))
+    );
+
+    editor.apply(EditorCommand::Undo);
+    assert_eq!(editor.buffer().text(), "if x {\n        ");
+}
+
+#[test]
+fn toggle_line_comment_comments_at_the_shallowest_indent_and_back() {
+    let text = "fn main() {\n    let a = 1;\n\n        call();\n}";
+    let mut editor = editor_with_rules(text, RUST_LIKE);
+    editor.apply(EditorCommand::SetSelection(Selection::new(
+        TextPosition::new(1, 6),
+        TextPosition::new(4, 0),
+    )));
+
+    assert!(
+        editor
+            .apply(EditorCommand::ToggleLineComment)
+            .document_changed
+    );
+    assert_eq!(
+        editor.buffer().text(),
+        "fn main() {\n    // let a = 1;\n\n    //     call();\n}"
+    );
+    assert_eq!(editor.selection().anchor, TextPosition::new(1, 9));
+
+    assert!(
+        editor
+            .apply(EditorCommand::ToggleLineComment)
+            .document_changed
+    );
+    assert_eq!(editor.buffer().text(), text);
+    assert_eq!(editor.selection().anchor, TextPosition::new(1, 6));
+}
+
+#[test]
+fn toggle_line_comment_wraps_lines_in_block_comments_without_line_comments() {
+    let markup = EditingRules {
+        block_comment: Some(("<!--", "-->")),
+        ..EditingRules::default()
+    };
+    let mut editor = editor_with_rules("  <p>hi</p>", markup);
+    set_caret(&mut editor, 0, 2);
+
+    editor.apply(EditorCommand::ToggleLineComment);
+    assert_eq!(editor.buffer().text(), "  <!-- <p>hi</p> -->");
+
+    editor.apply(EditorCommand::ToggleLineComment);
+    assert_eq!(editor.buffer().text(), "  <p>hi</p>");
+
+    let mut editor = editor_with_rules("{}", EditingRules::default());
+    assert!(
+        !editor
+            .apply(EditorCommand::ToggleLineComment)
+            .document_changed
+    );
+}
+
+#[test]
+fn apply_formatting_rewrites_only_changed_lines_and_keeps_the_selection() {
+    let text = "fn main() {\nlet a=1;\n    let b = 2;\n}\n";
+    let mut editor = editor_with_rules(text, RUST_LIKE);
+    set_caret(&mut editor, 3, 1);
+
+    let formatted = "fn main() {\n    let a = 1;\n\n    let b = 2;\n}\n";
+    assert!(
+        editor
+            .apply(EditorCommand::ApplyFormatting(formatted.to_string()))
+            .document_changed
+    );
+    assert_eq!(editor.buffer().text(), formatted);
+    assert_eq!(
+        editor.selection(),
+        Selection::caret(TextPosition::new(4, 1))
+    );
+
+    assert!(
+        !editor
+            .apply(EditorCommand::ApplyFormatting(formatted.to_string()))
+            .document_changed
+    );
+
+    editor.apply(EditorCommand::Undo);
+    assert_eq!(editor.buffer().text(), text);
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{BranchType, Oid, Repository};
+
+use crate::git2_helpers::open_git2_repo;
+
+pub const DEFAULT_ABANDONED_COMMIT_LIMIT: usize = 20;
+const RESTORED_BRANCH_PREFIX: &str = "restored/";
+
+/// A commit HEAD used to point at that no branch, tag or remote ref reaches any more, such as the
+/// old tip after a reset, an amend or deleting a branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbandonedCommit {
+    pub commit_id: String,
+    pub subject: String,
+    /// When HEAD moved away from the commit.
+    pub abandoned_unix_time: i64,
+    /// The reflog entry that moved HEAD away, such as `reset: moving to HEAD~1`.
+    pub reason: String,
+}
+
+/// Most recently abandoned commits first, read from the HEAD reflog.
+pub fn load_abandoned_commits(repo_root: &Path, limit: usize) -> Result<Vec<AbandonedCommit>> {
+    let repo = open_git2_repo(repo_root)?;
+    let reflog = match repo.reflog("HEAD") {
+        Ok(reflog) => reflog,
+        Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(anyhow::Error::new(err).context("failed to read the HEAD reflog")),
+    };
+
+    let mut seen = BTreeSet::new();
+    let mut candidates = Vec::new();
+    for entry in reflog.iter() {
+        let commit_id = entry.id_old();
+        if commit_id.is_zero() || !seen.insert(commit_id) {
+            continue;
+        }
+        candidates.push((
+            commit_id,
+            entry.committer().when().seconds(),
+            entry.message().unwrap_or_default().to_string(),
+        ));
+    }
+
+    let unreachable = unreachable_commits(&repo, candidates.iter().map(|(id, _, _)| *id))?;
+    let mut commits = Vec::new();
+    for (commit_id, abandoned_unix_time, reason) in candidates {
+        if commits.len() >= limit {
+            break;
+        }
+        if !unreachable.contains(&commit_id) {
+            continue;
+        }
+        let Ok(commit) = repo.find_commit(commit_id) else {
+            continue;
+        };
+        commits.push(AbandonedCommit {
+            commit_id: commit_id.to_string(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+            abandoned_unix_time,
+            reason,
+        });
+    }
+    Ok(commits)
+}
+
+/// Creates a `restored/<short id>` branch at `commit_id` and returns its name.
+pub fn restore_abandoned_commit(repo_root: &Path, commit_id: &str) -> Result<String> {
+    let repo = open_git2_repo(repo_root)?;
+    let oid = Oid::from_str(commit_id).with_context(|| format!("invalid commit id {commit_id}"))?;
+    let commit = repo
+        .find_commit(oid)
+        .with_context(|| format!("commit {commit_id} is no longer in the repository"))?;
+
+    let short_id = commit_id.chars().take(7).collect::<String>();
+    let branch_name = (1..)
+        .map(|attempt| match attempt {
+            1 => format!("{RESTORED_BRANCH_PREFIX}{short_id}"),
+            attempt => format!("{RESTORED_BRANCH_PREFIX}{short_id}-{attempt}"),
+        })
+        .find(|name| repo.find_branch(name, BranchType::Local).is_err())
+        .ok_or_else(|| anyhow!("no free branch name for {commit_id}"))?;
+    repo.branch(branch_name.as_str(), &commit, false)
+        .with_context(|| format!("failed to create branch '{branch_name}'"))?;
+    Ok(branch_name)
+}
+
+/// The subset of `candidates` that no ref reaches.
+fn unreachable_commits(
+    repo: &Repository,
+    candidates: impl Iterator<Item = Oid>,
+) -> Result<BTreeSet<Oid>> {
+    let mut walk = repo.revwalk().context("failed to start revision walk")?;
+    let mut pushed = false;
+    for commit_id in candidates {
+        // Reflogs can outlive their objects after gc.
+        if repo.find_commit(commit_id).is_ok() {
+            walk.push(commit_id)?;
+            pushed = true;
+        }
+    }
+    if !pushed {
+        return Ok(BTreeSet::new());
+    }
+    walk.hide_glob("refs/*")?;
+    if repo.head().is_ok() {
+        walk.hide_head()?;
+    }
+    walk.collect::<std::result::Result<BTreeSet<_>, _>>()
+        .context("failed to walk abandoned commits")
+}
@@ -0,0 +1,91 @@
+//! Entry points for tools that reuse Hunk's snapshot and diff logic.
+//!
+//! Nothing here depends on GPUI or desktop state. The functions keep their signatures while the
+//! rest of the crate tracks whatever the desktop app needs next, so prefer them over reaching
+//! into `git`, `history`, or `hunk_domain::diff` directly. The re-exported snapshot structs are
+//! the crate's own types and can gain fields, so read them rather than building or exhaustively
+//! matching them.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Result;
+
+pub use crate::error::GitError;
+/// Rows for one file the way the review workspace and `hunk diff` show them, with binary files
+/// reported instead of parsed. The desktop's diff stream is assembled from these rows in the app,
+/// since it also carries fold and layout state.
+pub use crate::file_rows::{LoadedFileDiffRows, is_binary_patch, load_file_diff_rows};
+pub use crate::git::{ChangedFile, FileStatus, LineStats, RepoSnapshot, WorkflowSnapshot};
+pub use crate::history::{RecentCommitSummary, RecentCommitsSnapshot};
+pub use crate::path_collisions::PathCollision;
+pub use crate::user_config::UserIdentity;
+
+/// Patch parsing and side-by-side row building used by the diff viewer.
+pub mod diff {
+    pub use hunk_domain::diff::{
+        DiffCell, DiffCellKind, DiffDocument, DiffHunk, DiffLine, DiffLineKind, DiffRowKind,
+        SideBySideRow, parse_patch_document, parse_patch_side_by_side,
+    };
+}
+
+/// One changed file with its raw patch and the parsed forms the viewer renders.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FileDiff {
+    pub path: String,
+    pub status: FileStatus,
+    pub patch: String,
+    pub document: diff::DiffDocument,
+    pub rows: Vec<diff::SideBySideRow>,
+}
+
+/// Loads the working copy state, changed files, and aggregate line stats for a repository.
+///
+/// `path` may be any directory inside the working tree.
+pub fn load_snapshot(path: &Path) -> Result<RepoSnapshot> {
+    crate::git::load_snapshot(path)
+}
+
+/// Loads the working copy state without computing line stats.
+pub fn load_workflow_snapshot(path: &Path) -> Result<WorkflowSnapshot> {
+    crate::git::load_workflow_snapshot(path)
+}
+
+/// Renders unified patches against `HEAD` for `files`, keyed by repository-relative path.
+pub fn load_patches_for_files(
+    repo_root: &Path,
+    files: &[ChangedFile],
+) -> Result<BTreeMap<String, String>> {
+    crate::git::load_patches_for_files(repo_root, files)
+}
+
+/// Loads patches for `files` and parses each one into hunks and side-by-side rows.
+///
+/// Files without a patch, such as binary or unchanged entries, are skipped.
+pub fn load_file_diffs(repo_root: &Path, files: &[ChangedFile]) -> Result<Vec<FileDiff>> {
+    let mut patches = load_patches_for_files(repo_root, files)?;
+    Ok(files
+        .iter()
+        .filter_map(|file| {
+            let patch = patches.remove(file.path.as_str())?;
+            Some(FileDiff {
+                path: file.path.clone(),
+                status: file.status,
+                document: diff::parse_patch_document(patch.as_str()),
+                rows: diff::parse_patch_side_by_side(patch.as_str()),
+                patch,
+            })
+        })
+        .collect())
+}
+
+/// Loads up to `limit` commits made on the current branch since it left its base branch.
+pub fn load_recent_commits(path: &Path, limit: usize) -> Result<RecentCommitsSnapshot> {
+    crate::history::load_recent_authored_commits(path, limit)
+}
+
+/// Reads `user.name` and `user.email` as Git resolves them for the repository at `path`.
+pub fn load_user_identity(path: &Path) -> Result<UserIdentity> {
+    crate::user_config::read_user_identity(path)
+}
@@ -3,11 +3,22 @@ use std::path::Path;
 use anyhow::{Context as _, Result, anyhow};
 
 use crate::config::{ReviewProviderKind, ReviewProviderMapping};
+use crate::error::GitError;
 use crate::git::open_repo_at_root;
 use crate::git2_helpers::open_git2_repo;
 
 const RESERVED_BRANCH_NAMES: &[&str] = &["detached", "unknown"];
 
+/// The hosted repository a branch is reviewed on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewRemote {
+    pub provider: ReviewProviderKind,
+    /// Host name, with the port when it is not the default one.
+    pub host: String,
+    /// `owner/repo` path of the repository on the host.
+    pub repository: String,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RenameBranchIfSafeOutcome {
     Renamed,
@@ -184,7 +195,10 @@ pub fn rename_branch(repo_root: &Path, old_branch_name: &str, new_branch_name: &
         .find_branch(new_branch_name, git2::BranchType::Local)
         .is_ok()
     {
-        return Err(anyhow!("branch '{new_branch_name}' already exists"));
+        return Err(GitError::BranchExists {
+            branch: new_branch_name.to_string(),
+        }
+        .into());
     }
 
     let mut branch = repo
@@ -206,6 +220,39 @@ pub fn rename_branch(repo_root: &Path, old_branch_name: &str, new_branch_name: &
     Ok(())
 }
 
+/// Local branches whose tip is the commit `HEAD` points at, sorted by name. Several branches
+/// can share a commit right after one is created from another, or after a fast-forward.
+pub fn branches_at_head(repo_root: &Path) -> Result<Vec<String>> {
+    let repo = open_git2_repo(repo_root)?;
+    let head_id = match repo.head() {
+        Ok(head) => head
+            .peel_to_commit()
+            .context("failed to resolve HEAD commit")?
+            .id(),
+        Err(err) if err.code() == git2::ErrorCode::UnbornBranch => return Ok(Vec::new()),
+        Err(err) => return Err(err).context("failed to resolve HEAD"),
+    };
+
+    let mut names = Vec::new();
+    for branch in repo
+        .branches(Some(git2::BranchType::Local))
+        .context("failed to list local branches")?
+    {
+        let (branch, _) = branch.context("failed to read local branch")?;
+        let Ok(commit) = branch.get().peel_to_commit() else {
+            continue;
+        };
+        if commit.id() != head_id {
+            continue;
+        }
+        if let Some(name) = branch.name().ok().flatten() {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
 pub fn rename_branch_if_current_unpublished(
     repo_root: &Path,
     expected_current_branch_name: &str,
@@ -308,16 +355,9 @@ pub fn review_url_for_branch_with_provider_map(
         return Err(anyhow!("cannot build review URL without a branch name"));
     }
 
-    let repo = open_repo_at_root(repo_root)?;
-    let remote = resolve_review_remote(repo.repository(), branch_name)?;
-    let Some(remote_url) = remote
-        .url(gix::remote::Direction::Push)
-        .or_else(|| remote.url(gix::remote::Direction::Fetch))
-    else {
+    let Some(remote_url) = review_remote_url(repo_root, branch_name)? else {
         return Ok(None);
     };
-    let remote_url = remote_url.to_string();
-
     Ok(review_url_for_remote(
         remote_url.as_str(),
         branch_name,
@@ -325,6 +365,51 @@ pub fn review_url_for_branch_with_provider_map(
     ))
 }
 
+/// Host and repository of the remote `branch_name` is reviewed on, when the host is a known
+/// GitHub or GitLab instance.
+pub fn review_remote_for_branch(
+    repo_root: &Path,
+    branch_name: &str,
+    provider_mappings: &[ReviewProviderMapping],
+) -> Result<Option<ReviewRemote>> {
+    let branch_name = branch_name.trim();
+    if branch_name.is_empty() || branch_name == "detached" {
+        return Err(anyhow!("cannot find a review remote without a branch name"));
+    }
+
+    let Some(remote_url) = review_remote_url(repo_root, branch_name)? else {
+        return Ok(None);
+    };
+    let Some((host, base_url)) = normalized_remote_base_url(remote_url.as_str()) else {
+        return Ok(None);
+    };
+    let Some(provider) = review_provider_from_host(host.as_str(), provider_mappings) else {
+        return Ok(None);
+    };
+    let Some((authority, repository)) = base_url
+        .split_once("://")
+        .and_then(|(_, rest)| rest.split_once('/'))
+        .filter(|(_, repository)| !repository.is_empty())
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(ReviewRemote {
+        provider,
+        host: authority.to_string(),
+        repository: repository.to_string(),
+    }))
+}
+
+fn review_remote_url(repo_root: &Path, branch_name: &str) -> Result<Option<String>> {
+    let repo = open_repo_at_root(repo_root)?;
+    let remote = resolve_review_remote(repo.repository(), branch_name)?;
+    Ok(remote
+        .url(gix::remote::Direction::Push)
+        .or_else(|| remote.url(gix::remote::Direction::Fetch))
+        .map(|url| url.to_string()))
+}
+
 fn is_reserved_branch_name(name: &str) -> bool {
     RESERVED_BRANCH_NAMES
         .iter()
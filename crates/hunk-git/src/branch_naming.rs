@@ -0,0 +1,225 @@
+use anyhow::{Result, anyhow};
+
+use crate::branch::sanitize_branch_name;
+
+/// A branch naming convention written as a template such as `{user}/{ticket}-{slug}`.
+///
+/// Placeholders match one path segment each:
+/// - `{user}`: lowercase letters and digits, usually the configured initials.
+/// - `{ticket}`: a tracker id such as `abc-123`, or a bare issue number.
+/// - `{slug}`: lowercase words joined by `-`, `_` or `.`.
+///
+/// Everything else in the template must appear literally in the branch name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchNamePolicy {
+    template: String,
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    User,
+    Ticket,
+    Slug,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Literal(String),
+    Placeholder(Placeholder),
+}
+
+impl BranchNamePolicy {
+    /// Parses `template`, or returns `None` when it is blank and no convention applies.
+    pub fn parse(template: &str) -> Result<Option<Self>> {
+        let template = template.trim();
+        if template.is_empty() {
+            return Ok(None);
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            if open > 0 {
+                parts.push(TemplatePart::Literal(rest[..open].to_string()));
+            }
+            let close = rest[open..]
+                .find('}')
+                .map(|close| open + close)
+                .ok_or_else(|| anyhow!("unclosed placeholder in branch name template"))?;
+            let placeholder = match &rest[open + 1..close] {
+                "user" => Placeholder::User,
+                "ticket" => Placeholder::Ticket,
+                "slug" => Placeholder::Slug,
+                other => {
+                    return Err(anyhow!(
+                        "unknown placeholder {{{other}}} in branch name template"
+                    ));
+                }
+            };
+            parts.push(TemplatePart::Placeholder(placeholder));
+            rest = &rest[close + 1..];
+        }
+        if rest.contains('}') {
+            return Err(anyhow!("unopened placeholder in branch name template"));
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_string()));
+        }
+
+        Ok(Some(Self {
+            template: template.to_string(),
+            parts,
+        }))
+    }
+
+    pub fn template(&self) -> &str {
+        self.template.as_str()
+    }
+
+    pub fn matches(&self, branch_name: &str) -> bool {
+        parts_match(self.parts.as_slice(), branch_name)
+    }
+
+    /// Inline validation message for `branch_name`, or `None` when it follows the convention.
+    pub fn violation_message(&self, branch_name: &str) -> Option<String> {
+        if self.matches(branch_name) {
+            None
+        } else {
+            Some(format!("Branch names must follow {}.", self.template))
+        }
+    }
+
+    /// Fills the template up to its first placeholder without a value, so the reader can type
+    /// the rest.
+    ///
+    /// `{slug}` is never filled; a missing ticket stops the fill before `{ticket}`.
+    pub fn autofill(&self, user: &str, ticket: Option<&str>) -> String {
+        let user = sanitize_template_value(user);
+        let ticket = ticket.map(sanitize_template_value);
+        let mut filled = String::new();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Literal(text) => filled.push_str(text),
+                TemplatePart::Placeholder(Placeholder::User) if !user.is_empty() => {
+                    filled.push_str(user.as_str());
+                }
+                TemplatePart::Placeholder(Placeholder::Ticket) => match ticket.as_deref() {
+                    Some(ticket) if !ticket.is_empty() => filled.push_str(ticket),
+                    _ => break,
+                },
+                TemplatePart::Placeholder(_) => break,
+            }
+        }
+        filled
+    }
+}
+
+/// Finds the first tracker ticket id in `text`, such as `ABC-123` or `#456`.
+///
+/// Ids come back lowercased (`abc-123`, `456`) so they can be used in branch names.
+pub fn detect_ticket_id(text: &str) -> Option<String> {
+    text.split(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '-' || ch == '#'))
+        .find_map(|token| {
+            if let Some(number) = token.strip_prefix('#') {
+                return (!number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit()))
+                    .then(|| number.to_string());
+            }
+            let (project, number) = token.split_once('-')?;
+            let project_ok = project.len() >= 2
+                && project.starts_with(|ch: char| ch.is_ascii_alphabetic())
+                && project.chars().all(|ch| ch.is_ascii_alphanumeric())
+                && project.chars().any(|ch| ch.is_ascii_uppercase());
+            let number_ok = !number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit());
+            (project_ok && number_ok).then(|| token.to_ascii_lowercase())
+        })
+}
+
+/// Whether `branch_name` matches one of the protected branch `patterns`.
+///
+/// `*` matches any run of characters within one path segment, so `release/*` covers
+/// `release/1.2` but not `release/1.2/hotfix`. Everything else must match exactly.
+pub fn is_protected_branch(branch_name: &str, patterns: &[String]) -> bool {
+    patterns
+        .iter()
+        .map(|pattern| pattern.trim())
+        .filter(|pattern| !pattern.is_empty())
+        .any(|pattern| branch_pattern_matches(pattern.as_bytes(), branch_name.as_bytes()))
+}
+
+/// Why the combined Commit & Push commits to `branch_name` without pushing it, if it does.
+pub fn protected_branch_push_skip_reason(branch_name: &str, patterns: &[String]) -> Option<String> {
+    is_protected_branch(branch_name, patterns)
+        .then(|| format!("'{branch_name}' is a protected branch"))
+}
+
+fn sanitize_template_value(value: &str) -> String {
+    if value.trim().is_empty() {
+        return String::new();
+    }
+    sanitize_branch_name(value).replace('/', "-")
+}
+
+fn parts_match(parts: &[TemplatePart], name: &str) -> bool {
+    let Some((part, rest)) = parts.split_first() else {
+        return name.is_empty();
+    };
+    match part {
+        TemplatePart::Literal(text) => name
+            .strip_prefix(text.as_str())
+            .is_some_and(|name| parts_match(rest, name)),
+        TemplatePart::Placeholder(placeholder) => name
+            .char_indices()
+            .map(|(ix, ch)| ix + ch.len_utf8())
+            .any(|end| {
+                placeholder_matches(*placeholder, &name[..end]) && parts_match(rest, &name[end..])
+            }),
+    }
+}
+
+fn placeholder_matches(placeholder: Placeholder, value: &str) -> bool {
+    match placeholder {
+        Placeholder::User => value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit()),
+        Placeholder::Ticket => {
+            let number = match value.split_once('-') {
+                Some((project, number)) => {
+                    let project_ok = project.starts_with(|ch: char| ch.is_ascii_lowercase())
+                        && project
+                            .chars()
+                            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit());
+                    if !project_ok {
+                        return false;
+                    }
+                    number
+                }
+                None => value,
+            };
+            !number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit())
+        }
+        Placeholder::Slug => {
+            value.starts_with(|ch: char| ch.is_ascii_lowercase() || ch.is_ascii_digit())
+                && !value.ends_with(['-', '_', '.'])
+                && value.chars().all(|ch| {
+                    ch.is_ascii_lowercase() || ch.is_ascii_digit() || matches!(ch, '-' | '_' | '.')
+                })
+        }
+    }
+}
+
+fn branch_pattern_matches(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => {
+            let segment_len = name
+                .iter()
+                .position(|byte| *byte == b'/')
+                .unwrap_or(name.len());
+            (0..=segment_len).any(|skip| branch_pattern_matches(rest, &name[skip..]))
+        }
+        Some((byte, rest)) => {
+            name.first() == Some(byte) && branch_pattern_matches(rest, &name[1..])
+        }
+    }
+}
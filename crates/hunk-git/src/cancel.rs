@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context as _, Result, anyhow};
+
+use crate::error::GitError;
+
+const WATCHDOG_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Shared stop signal for long-running Git operations.
+///
+/// Clones observe the same flag, so the UI can keep one handle and cancel work that runs
+/// on a background thread. An optional deadline turns the token into a timeout as well.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            timeout: Some(timeout),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    pub fn should_stop(&self) -> bool {
+        self.is_cancelled() || self.is_timed_out()
+    }
+
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(GitError::Cancelled.into());
+        }
+        if self.is_timed_out() {
+            return Err(GitError::TimedOut {
+                seconds: self.timeout.unwrap_or_default().as_secs(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Runs `operation` on its own thread and returns as soon as it finishes or the token
+    /// stops. libgit2 only looks at the token from its callbacks, which never run while a
+    /// connect or a read is stalled; this bounds the caller by the deadline regardless. An
+    /// abandoned operation keeps running until its socket times out, so only hand over work
+    /// whose result is safe to drop.
+    pub(crate) fn run_until_stopped<T, F>(&self, operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Result<T> + Send + 'static,
+    {
+        self.check()?;
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name("hunk-git-remote".to_string())
+            .spawn(move || {
+                let _ = sender.send(operation());
+            })
+            .context("failed to start Git remote operation thread")?;
+        loop {
+            match receiver.recv_timeout(WATCHDOG_POLL_INTERVAL) {
+                Ok(result) => return result,
+                Err(RecvTimeoutError::Timeout) => self.check()?,
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(anyhow!("Git remote operation thread stopped unexpectedly"));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use hunk_domain::config::ChangelogFormat;
+
+/// Keep a Changelog sections, in the order they are written.
+const SECTIONS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+const CHANGELOG_TRAILER: &str = "changelog";
+
+/// Builds a changelog fragment from commit messages, oldest first.
+///
+/// A `Changelog: <section>` trailer picks the Keep a Changelog section for a commit and
+/// `Changelog: skip` leaves it out. Otherwise conventional commit types decide: `feat` is
+/// added, `fix` is fixed, maintenance types such as `chore` or `ci` are left out and
+/// everything else is changed. Merge and fixup commits are always left out.
+pub fn changelog_fragment<'a>(
+    messages: impl IntoIterator<Item = &'a str>,
+    format: ChangelogFormat,
+) -> String {
+    let entries = messages
+        .into_iter()
+        .filter_map(changelog_entry)
+        .collect::<Vec<_>>();
+
+    match format {
+        ChangelogFormat::KeepAChangelog => SECTIONS
+            .iter()
+            .filter_map(|section| {
+                let lines = entries
+                    .iter()
+                    .filter(|(entry_section, _)| entry_section == section)
+                    .map(|(_, text)| format!("- {text}\n"))
+                    .collect::<String>();
+                (!lines.is_empty()).then(|| format!("### {section}\n\n{lines}"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ChangelogFormat::Towncrier => entries
+            .iter()
+            .map(|(_, text)| format!("{text}\n"))
+            .collect(),
+    }
+}
+
+/// Expands `{branch}` in a configured fragment path, keeping the fragment in one directory.
+pub fn changelog_fragment_path(template: &str, branch_name: &str) -> String {
+    template
+        .trim()
+        .replace("{branch}", branch_name.replace('/', "-").as_str())
+}
+
+fn changelog_entry(message: &str) -> Option<(&'static str, String)> {
+    let subject = message.lines().find(|line| !line.trim().is_empty())?.trim();
+    if subject.starts_with("Merge ")
+        || subject.starts_with("fixup!")
+        || subject.starts_with("squash!")
+    {
+        return None;
+    }
+
+    let (commit_type, description) = split_conventional_subject(subject);
+    let section = match changelog_trailer(message) {
+        Some(value) if value.eq_ignore_ascii_case("skip") || value.eq_ignore_ascii_case("none") => {
+            return None;
+        }
+        Some(value) => SECTIONS
+            .iter()
+            .copied()
+            .find(|section| section.eq_ignore_ascii_case(value))
+            .unwrap_or("Changed"),
+        None => match commit_type {
+            Some("feat") => "Added",
+            Some("fix") => "Fixed",
+            Some("security") => "Security",
+            Some("revert") => "Removed",
+            Some("build" | "chore" | "ci" | "docs" | "style" | "test") => return None,
+            _ => "Changed",
+        },
+    };
+
+    let mut text = description.to_string();
+    if let Some(first) = text.chars().next() {
+        text.replace_range(
+            ..first.len_utf8(),
+            first.to_uppercase().to_string().as_str(),
+        );
+    }
+    Some((section, text))
+}
+
+/// Splits `type(scope)!: description` into its lowercase type and description.
+fn split_conventional_subject(subject: &str) -> (Option<&str>, &str) {
+    let Some((head, description)) = subject.split_once(':') else {
+        return (None, subject);
+    };
+    let commit_type = head
+        .split_once('(')
+        .map_or(head, |(commit_type, _)| commit_type)
+        .trim_end_matches('!');
+    let description = description.trim();
+    if commit_type.is_empty()
+        || description.is_empty()
+        || !commit_type.chars().all(|ch| ch.is_ascii_lowercase())
+    {
+        return (None, subject);
+    }
+    (Some(commit_type), description)
+}
+
+fn changelog_trailer(message: &str) -> Option<&str> {
+    let (_, trailers) = message.trim_end().rsplit_once("\n\n")?;
+    trailers.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim()
+            .eq_ignore_ascii_case(CHANGELOG_TRAILER)
+            .then(|| value.trim())
+    })
+}
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{FetchOptions, RemoteCallbacks};
+
+use crate::cancel::CancellationToken;
+use crate::network::{ensure_remote_socket_timeouts, remote_operation_error, resolve_credentials};
+
+/// Progress reported while [`clone_repository_with_cancel`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneProgress {
+    ReceivingObjects {
+        received: usize,
+        total: usize,
+        received_bytes: usize,
+    },
+    ResolvingDeltas {
+        indexed: usize,
+        total: usize,
+    },
+    CheckingOut {
+        completed: usize,
+        total: usize,
+    },
+}
+
+impl CloneProgress {
+    /// Completion of the current phase, from 0 to 100.
+    pub fn percent(self) -> usize {
+        let (done, total) = match self {
+            Self::ReceivingObjects {
+                received, total, ..
+            } => (received, total),
+            Self::ResolvingDeltas { indexed, total } => (indexed, total),
+            Self::CheckingOut { completed, total } => (completed, total),
+        };
+        if total == 0 {
+            return 0;
+        }
+        done.min(total) * 100 / total
+    }
+
+    pub fn summary(self) -> String {
+        match self {
+            Self::ReceivingObjects {
+                received,
+                total,
+                received_bytes,
+            } => format!(
+                "Receiving objects: {}% ({received}/{total}), {:.1} MiB",
+                self.percent(),
+                received_bytes as f64 / (1024.0 * 1024.0)
+            ),
+            Self::ResolvingDeltas { indexed, total } => {
+                format!("Resolving deltas: {}% ({indexed}/{total})", self.percent())
+            }
+            Self::CheckingOut { completed, total } => {
+                format!(
+                    "Checking out files: {}% ({completed}/{total})",
+                    self.percent()
+                )
+            }
+        }
+    }
+}
+
+/// The folder name `git clone` would pick for `url`, such as `hunk` for
+/// `git@github.com:niteshbalusu11/hunk.git`.
+pub fn clone_directory_name(url: &str) -> Option<String> {
+    let trimmed = url.trim().trim_end_matches('/');
+    let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+    let name = trimmed
+        .rsplit(['/', ':', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    (!name.is_empty() && name != "." && name != "..").then(|| name.to_string())
+}
+
+/// Clones `url` into `destination` and returns the new repository root.
+///
+/// `destination` must not exist yet or be an empty folder. Credentials are resolved the same way
+/// as for push and sync: the SSH agent, configured identities, then Git credential helpers.
+pub fn clone_repository_with_cancel(
+    url: &str,
+    destination: &Path,
+    cancel: &CancellationToken,
+    on_progress: impl Fn(CloneProgress),
+) -> Result<PathBuf> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(anyhow!("repository URL cannot be empty"));
+    }
+    if destination.exists()
+        && std::fs::read_dir(destination)
+            .with_context(|| format!("failed to read {}", destination.display()))?
+            .next()
+            .is_some()
+    {
+        return Err(anyhow!(
+            "destination {} already exists and is not empty",
+            destination.display()
+        ));
+    }
+    cancel.check()?;
+
+    let config = git2::Config::open_default().context("failed to load Git config")?;
+    let mut callbacks = RemoteCallbacks::new();
+    let credentials_cancel = cancel.clone();
+    callbacks.credentials(move |url, username_from_url, allowed| {
+        if credentials_cancel.should_stop() {
+            return Err(git2::Error::from_str("operation was cancelled"));
+        }
+        resolve_credentials(&config, url, username_from_url, allowed)
+    });
+    callbacks.transfer_progress(|stats| {
+        let progress =
+            if stats.total_deltas() > 0 && stats.received_objects() == stats.total_objects() {
+                CloneProgress::ResolvingDeltas {
+                    indexed: stats.indexed_deltas(),
+                    total: stats.total_deltas(),
+                }
+            } else {
+                CloneProgress::ReceivingObjects {
+                    received: stats.received_objects(),
+                    total: stats.total_objects(),
+                    received_bytes: stats.received_bytes(),
+                }
+            };
+        on_progress(progress);
+        !cancel.should_stop()
+    });
+    let sideband_cancel = cancel.clone();
+    callbacks.sideband_progress(move |_| !sideband_cancel.should_stop());
+
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(callbacks);
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.progress(|_, completed, total| {
+        on_progress(CloneProgress::CheckingOut { completed, total });
+    });
+
+    ensure_remote_socket_timeouts();
+    let clone_result = git2::build::RepoBuilder::new()
+        .fetch_options(fetch_options)
+        .with_checkout(checkout)
+        .clone(url, destination);
+    if clone_result.is_err() {
+        cancel.check()?;
+    }
+    let repo = clone_result
+        .map_err(|err| remote_operation_error(err, "origin"))
+        .with_context(|| format!("failed to clone {url}"))?;
+    repo.workdir()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow!("cloned repository at {} is bare", destination.display()))
+}
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+
+/// Where GitHub looks for the file, in the order it checks them. The first one found is used.
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Parsed CODEOWNERS rules. Later rules take precedence, as on GitHub.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeOwners {
+    /// Repository-relative location the rules were read from.
+    pub source_path: String,
+    rules: Vec<CodeOwnersRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CodeOwnersRule {
+    pattern: String,
+    owners: Vec<String>,
+}
+
+/// Changed files grouped under one owner by [`CodeOwners::group_paths_by_owner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeOwnerGroup {
+    /// `None` collects files no rule assigns an owner to.
+    pub owner: Option<String>,
+    pub paths: Vec<String>,
+}
+
+impl CodeOwners {
+    pub fn parse(source_path: impl Into<String>, contents: &str) -> Self {
+        let rules = contents
+            .lines()
+            .filter_map(|line| {
+                let line = line.split_once(" #").map_or(line, |(rule, _)| rule).trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let mut fields = line.split_whitespace();
+                let pattern = fields.next()?.to_string();
+                let owners = fields
+                    .filter(|owner| owner.contains('@'))
+                    .map(ToOwned::to_owned)
+                    .collect();
+                Some(CodeOwnersRule { pattern, owners })
+            })
+            .collect();
+
+        Self {
+            source_path: source_path.into(),
+            rules,
+        }
+    }
+
+    /// Owners of `path`. A matching rule without owners leaves the path explicitly unowned.
+    pub fn owners_for(&self, path: &str) -> &[String] {
+        let path = path.trim_matches('/');
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| codeowners_pattern_matches(rule.pattern.as_str(), path))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Groups `paths` by every owner that must approve them. A file with several owners appears
+    /// in each of their groups. Owners sort by name, with unowned files last.
+    pub fn group_paths_by_owner<'a>(
+        &self,
+        paths: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<CodeOwnerGroup> {
+        let mut owned = BTreeMap::<String, Vec<String>>::new();
+        let mut unowned = Vec::new();
+        for path in paths {
+            let owners = self.owners_for(path);
+            if owners.is_empty() {
+                unowned.push(path.to_string());
+                continue;
+            }
+            for owner in owners {
+                owned
+                    .entry(owner.clone())
+                    .or_default()
+                    .push(path.to_string());
+            }
+        }
+
+        let mut groups = owned
+            .into_iter()
+            .map(|(owner, paths)| CodeOwnerGroup {
+                owner: Some(owner),
+                paths,
+            })
+            .collect::<Vec<_>>();
+        if !unowned.is_empty() {
+            groups.push(CodeOwnerGroup {
+                owner: None,
+                paths: unowned,
+            });
+        }
+        groups
+    }
+}
+
+/// Reads the repository's CODEOWNERS file, or `None` when it has none.
+pub fn load_code_owners(repo_root: &Path) -> Result<Option<CodeOwners>> {
+    for location in CODEOWNERS_LOCATIONS {
+        let path: PathBuf = repo_root.join(location);
+        match fs::read_to_string(path.as_path()) {
+            Ok(contents) => return Ok(Some(CodeOwners::parse(location, contents.as_str()))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// gitignore-style matching with GitHub's CODEOWNERS rules: patterns without an inner slash match
+/// at any depth, a directory match covers everything beneath it, and a trailing `/*` only
+/// covers the directory's direct children.
+fn codeowners_pattern_matches(pattern: &str, path: &str) -> bool {
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+    let directory_only = pattern.ends_with('/');
+    let trimmed = trimmed.trim_start_matches('/');
+    if trimmed.is_empty() {
+        return false;
+    }
+
+    let mut pattern_segments = trimmed.split('/').collect::<Vec<_>>();
+    if !anchored {
+        pattern_segments.insert(0, "**");
+    }
+    let allow_descendants = pattern_segments.last() != Some(&"*");
+    let path_segments = path.split('/').collect::<Vec<_>>();
+    segments_match(
+        pattern_segments.as_slice(),
+        path_segments.as_slice(),
+        allow_descendants,
+        directory_only,
+    )
+}
+
+fn segments_match(
+    pattern: &[&str],
+    path: &[&str],
+    allow_descendants: bool,
+    directory_only: bool,
+) -> bool {
+    match pattern.split_first() {
+        None => {
+            // The whole pattern matched. What is left of the path lies inside a matched
+            // directory.
+            if path.is_empty() {
+                !directory_only
+            } else {
+                allow_descendants
+            }
+        }
+        Some((&"**", rest)) => (0..=path.len())
+            .any(|skip| segments_match(rest, &path[skip..], allow_descendants, directory_only)),
+        Some((segment, rest)) => match path.split_first() {
+            Some((path_segment, path_rest)) => {
+                glob_segment_matches(segment.as_bytes(), path_segment.as_bytes())
+                    && segments_match(rest, path_rest, allow_descendants, directory_only)
+            }
+            None => false,
+        },
+    }
+}
+
+fn glob_segment_matches(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=text.len()).any(|skip| glob_segment_matches(rest, &text[skip..]))
+        }
+        Some((b'?', rest)) => !text.is_empty() && glob_segment_matches(rest, &text[1..]),
+        Some((byte, rest)) => text.first() == Some(byte) && glob_segment_matches(rest, &text[1..]),
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+
+use crate::error::GitError;
+use crate::git2_helpers::open_git2_repo;
+use crate::mutation::CreatedCommit;
+use crate::signing::write_signed_commit_as;
+
+pub const COMMIT_SUBJECT_MAX_CHARS: usize = 72;
+pub const COMMIT_BODY_LINE_MAX_CHARS: usize = 72;
+pub const REVIEWED_BY_TRAILER: &str = "Reviewed-by";
+pub const CHANGE_ID_TRAILER: &str = "Change-Id";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitTrailer {
+    pub key: String,
+    pub value: String,
+}
+
+/// A commit message split into the parts the message editor shows as separate fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructuredCommitMessage {
+    pub subject: String,
+    pub body: String,
+    pub trailers: Vec<CommitTrailer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitMessageProblem {
+    EmptySubject,
+    SubjectTooLong {
+        len: usize,
+    },
+    /// `line` is 1-based within the body.
+    BodyLineTooLong {
+        line: usize,
+        len: usize,
+    },
+    InvalidTrailerKey {
+        key: String,
+    },
+    InvalidChangeId {
+        value: String,
+    },
+}
+
+impl CommitMessageProblem {
+    /// Problems that would produce a malformed message, as opposed to style warnings.
+    pub fn is_blocking(&self) -> bool {
+        matches!(
+            self,
+            Self::EmptySubject | Self::InvalidTrailerKey { .. } | Self::InvalidChangeId { .. }
+        )
+    }
+}
+
+impl fmt::Display for CommitMessageProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySubject => write!(f, "subject cannot be empty"),
+            Self::SubjectTooLong { len } => write!(
+                f,
+                "subject is {len} characters; keep it to {COMMIT_SUBJECT_MAX_CHARS}"
+            ),
+            Self::BodyLineTooLong { line, len } => write!(
+                f,
+                "body line {line} is {len} characters; wrap at {COMMIT_BODY_LINE_MAX_CHARS}"
+            ),
+            Self::InvalidTrailerKey { key } => write!(f, "'{key}' is not a valid trailer key"),
+            Self::InvalidChangeId { value } => write!(
+                f,
+                "Change-Id '{value}' should be 'I' followed by 40 hex characters"
+            ),
+        }
+    }
+}
+
+impl StructuredCommitMessage {
+    /// Splits `message` into subject, body and trailers. The last paragraph is read as
+    /// trailers only when every line in it is a `Key: value` pair or a continuation of one.
+    pub fn parse(message: &str) -> Self {
+        let message = message.trim_matches(|ch: char| ch == '\n' || ch == '\r');
+        let (subject, rest) = match message.split_once('\n') {
+            Some((subject, rest)) => (subject, rest),
+            None => (message, ""),
+        };
+        let rest = rest.trim_matches(|ch: char| ch == '\n' || ch == '\r');
+        let (body, trailer_block) = match rest.rsplit_once("\n\n") {
+            Some((body, last)) => (body, last),
+            None => ("", rest),
+        };
+        match parse_trailer_block(trailer_block) {
+            Some(trailers) => Self {
+                subject: subject.trim().to_string(),
+                body: body.trim_end().to_string(),
+                trailers,
+            },
+            None => Self {
+                subject: subject.trim().to_string(),
+                body: rest.trim_end().to_string(),
+                trailers: Vec::new(),
+            },
+        }
+    }
+
+    pub fn trailer_values(&self, key: &str) -> Vec<&str> {
+        self.trailers
+            .iter()
+            .filter(|trailer| trailer.key.eq_ignore_ascii_case(key))
+            .map(|trailer| trailer.value.as_str())
+            .collect()
+    }
+
+    /// Replaces every `key` trailer with `values`, keeping the position of the first one so
+    /// unrelated trailers stay in their original order. Blank values are dropped.
+    pub fn set_trailer_values<'a>(&mut self, key: &str, values: impl IntoIterator<Item = &'a str>) {
+        let position = self
+            .trailers
+            .iter()
+            .position(|trailer| trailer.key.eq_ignore_ascii_case(key))
+            .unwrap_or(self.trailers.len());
+        self.trailers
+            .retain(|trailer| !trailer.key.eq_ignore_ascii_case(key));
+        let position = position.min(self.trailers.len());
+        let replacements = values
+            .into_iter()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| CommitTrailer {
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+            .collect::<Vec<_>>();
+        self.trailers.splice(position..position, replacements);
+    }
+
+    /// Serializes the message the way `git interpret-trailers` lays it out: subject, a blank
+    /// line, the body, a blank line and one trailer per line.
+    pub fn to_message(&self) -> String {
+        let mut message = self.subject.trim().to_string();
+        message.push('\n');
+        let body = self.body.trim_matches(|ch: char| ch == '\n' || ch == '\r');
+        if !body.trim().is_empty() {
+            message.push('\n');
+            message.push_str(body.trim_end());
+            message.push('\n');
+        }
+        if !self.trailers.is_empty() {
+            message.push('\n');
+            for trailer in &self.trailers {
+                message.push_str(trailer.key.trim());
+                message.push_str(": ");
+                message.push_str(trailer.value.trim());
+                message.push('\n');
+            }
+        }
+        message
+    }
+
+    pub fn validate(&self) -> Vec<CommitMessageProblem> {
+        let mut problems = Vec::new();
+        let subject_len = self.subject.trim().chars().count();
+        if subject_len == 0 {
+            problems.push(CommitMessageProblem::EmptySubject);
+        } else if subject_len > COMMIT_SUBJECT_MAX_CHARS {
+            problems.push(CommitMessageProblem::SubjectTooLong { len: subject_len });
+        }
+        for (ix, line) in self.body.lines().enumerate() {
+            let len = line.trim_end().chars().count();
+            // Long URLs and other unbreakable tokens cannot be wrapped, so only flag lines that
+            // contain a space somewhere.
+            if len > COMMIT_BODY_LINE_MAX_CHARS && line.trim().contains(' ') {
+                problems.push(CommitMessageProblem::BodyLineTooLong { line: ix + 1, len });
+            }
+        }
+        for trailer in &self.trailers {
+            if !is_valid_trailer_key(trailer.key.trim()) {
+                problems.push(CommitMessageProblem::InvalidTrailerKey {
+                    key: trailer.key.clone(),
+                });
+            } else if trailer.key.eq_ignore_ascii_case(CHANGE_ID_TRAILER)
+                && !is_valid_change_id(trailer.value.trim())
+            {
+                problems.push(CommitMessageProblem::InvalidChangeId {
+                    value: trailer.value.clone(),
+                });
+            }
+        }
+        problems
+    }
+}
+
+/// The message of the checked-out commit, loaded for the message editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadCommitMessage {
+    pub commit_id: String,
+    pub message: String,
+    /// Upstream branch that already contains the commit, which makes rewording it a rewrite
+    /// of published history.
+    pub published_upstream: Option<String>,
+}
+
+pub fn load_head_commit_message(repo_root: &Path) -> Result<HeadCommitMessage> {
+    let repo = open_git2_repo(repo_root)?;
+    let head = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .context("there is no commit to edit yet")?;
+    Ok(HeadCommitMessage {
+        commit_id: head.id().to_string(),
+        message: String::from_utf8_lossy(head.message_bytes()).into_owned(),
+        published_upstream: published_upstream(&repo, head.id())?,
+    })
+}
+
+/// Replaces the message of the checked-out commit, keeping its tree, parents and author.
+///
+/// Refuses when the commit is already on the branch's upstream, since rewording it would
+/// diverge from what was pushed.
+pub fn reword_head_commit(repo_root: &Path, message: &str) -> Result<CreatedCommit> {
+    let structured = StructuredCommitMessage::parse(message);
+    if let Some(problem) = structured
+        .validate()
+        .into_iter()
+        .find(CommitMessageProblem::is_blocking)
+    {
+        return Err(anyhow!("{problem}"));
+    }
+    let message = structured.to_message();
+
+    let repo = open_git2_repo(repo_root)?;
+    let head = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .context("there is no commit to edit yet")?;
+    if let Some(upstream) = published_upstream(&repo, head.id())? {
+        return Err(anyhow!(
+            "the last commit is already on '{upstream}'; rewording it would rewrite pushed history"
+        ));
+    }
+    if head.message_bytes() == message.as_bytes() {
+        return Err(anyhow!("the commit message is unchanged"));
+    }
+
+    let tree = head.tree()?;
+    let parents = head.parents().collect::<Vec<_>>();
+    let parent_refs = parents.iter().collect::<Vec<_>>();
+    let author = head.author();
+    let committer = repo
+        .signature()
+        .map_err(|err| anyhow::Error::new(err).context(GitError::MissingIdentity))?;
+    let signing = repo.config()?.get_bool("commit.gpgSign").unwrap_or(false);
+    let commit_id = if signing {
+        write_signed_commit_as(
+            &repo,
+            &author,
+            &committer,
+            message.as_str(),
+            &tree,
+            &parent_refs,
+        )?
+    } else {
+        repo.commit(
+            None,
+            &author,
+            &committer,
+            message.as_str(),
+            &tree,
+            parent_refs.as_slice(),
+        )
+        .context("failed to write reworded commit")?
+    };
+
+    let reflog_message = format!("reword: {}", structured.subject.trim());
+    let head_ref = repo
+        .find_reference("HEAD")
+        .context("failed to resolve HEAD")?;
+    match head_ref.symbolic_target() {
+        Some(target) => {
+            repo.reference(target, commit_id, true, reflog_message.as_str())
+                .with_context(|| format!("failed to update {target} to the reworded commit"))?;
+        }
+        None => {
+            repo.set_head_detached(commit_id)
+                .context("failed to move detached HEAD to the reworded commit")?;
+        }
+    }
+
+    let commit = repo.find_commit(commit_id)?;
+    Ok(CreatedCommit {
+        commit_id: commit_id.to_string(),
+        subject: commit.summary().unwrap_or_default().to_string(),
+        committed_unix_time: Some(commit.time().seconds()),
+    })
+}
+
+fn published_upstream(repo: &git2::Repository, commit_id: git2::Oid) -> Result<Option<String>> {
+    let Ok(head) = repo.head() else {
+        return Ok(None);
+    };
+    if !head.is_branch() {
+        return Ok(None);
+    }
+    let Some(branch_name) = head.shorthand() else {
+        return Ok(None);
+    };
+    let branch = repo.find_branch(branch_name, git2::BranchType::Local)?;
+    let upstream = match branch.upstream() {
+        Ok(upstream) => upstream,
+        Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let Some(upstream_id) = upstream.get().target() else {
+        return Ok(None);
+    };
+    let contains = upstream_id == commit_id || repo.graph_descendant_of(upstream_id, commit_id)?;
+    Ok(contains.then(|| {
+        upstream
+            .name()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+            .to_string()
+    }))
+}
+
+fn parse_trailer_block(block: &str) -> Option<Vec<CommitTrailer>> {
+    let mut trailers: Vec<CommitTrailer> = Vec::new();
+    for line in block.lines() {
+        if line.starts_with([' ', '\t']) {
+            let last = trailers.last_mut()?;
+            last.value.push(' ');
+            last.value.push_str(line.trim());
+            continue;
+        }
+        let (key, value) = line.split_once(':')?;
+        if !is_valid_trailer_key(key) {
+            return None;
+        }
+        trailers.push(CommitTrailer {
+            key: key.to_string(),
+            value: value.trim().to_string(),
+        });
+    }
+    (!trailers.is_empty()).then_some(trailers)
+}
+
+fn is_valid_trailer_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+        && !key.starts_with('-')
+}
+
+fn is_valid_change_id(value: &str) -> bool {
+    value
+        .strip_prefix('I')
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|ch| ch.is_ascii_hexdigit()))
+}
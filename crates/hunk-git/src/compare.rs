@@ -6,14 +6,27 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context as _, Result, anyhow};
 use git2::{DiffOptions, ObjectType, Oid, Patch, Repository, Tree};
 
+use crate::cancel::CancellationToken;
+use crate::document_text::{DocumentKind, EXTRACTED_TEXT_PATCH_HEADER, extract_document_text};
+use crate::encoding::utf8_diff_bytes;
 use crate::git::{ChangedFile, FileStatus, LineStats, read_worktree_file_in_git_form};
 use crate::git2_helpers::open_git2_repo;
+use crate::hex_diff::{HEX_DUMP_PATCH_HEADER, hex_dump};
 use crate::worktree::repo_relative_path_is_within_managed_worktrees;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CompareSource {
-    WorkspaceTarget { target_id: String, root: PathBuf },
-    Branch { name: String },
+    WorkspaceTarget {
+        target_id: String,
+        root: PathBuf,
+    },
+    Branch {
+        name: String,
+    },
+    /// Any revision Git can parse, such as a commit id, a tag, or `main~3`.
+    Revision {
+        spec: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +37,13 @@ pub struct CompareSnapshot {
     pub patches_by_path: BTreeMap<String, String>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompareOptions {
+    /// Binary files no larger than this on either side render as a hex dump diff instead of
+    /// "Binary files differ". `0` disables hex dumps.
+    pub hex_diff_max_bytes: usize,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ComparePathKind {
     Regular,
@@ -100,28 +120,49 @@ pub fn compare_workspace_target_source_id(target_id: &str) -> String {
     format!("workspace:{target_id}")
 }
 
-pub fn resolve_default_base_branch_name(repo_root: &Path) -> Result<Option<String>> {
-    let repo = gix::discover(repo_root).with_context(|| {
-        format!(
-            "failed to discover Git repository from {}",
-            repo_root.display()
-        )
-    })?;
-    if let Some(branch_name) = remote_default_branch_name(&repo)? {
-        return Ok(Some(branch_name));
-    }
-    for candidate in ["main", "master"] {
-        if local_branch_exists(repo_root, candidate)? {
-            return Ok(Some(candidate.to_string()));
-        }
-    }
-    Ok(None)
+pub fn compare_revision_source_id(spec: &str) -> String {
+    format!("revision:{spec}")
+}
+
+/// The revision spec inside a source id built by [`compare_revision_source_id`].
+pub fn compare_revision_source_spec(source_id: &str) -> Option<&str> {
+    source_id
+        .strip_prefix("revision:")
+        .filter(|spec| !spec.trim().is_empty())
+}
+
+/// The tree `source` is compared from, so a comparison computed earlier can be checked against
+/// where a branch points now. Working copies report their `HEAD` tree, without their edits.
+pub fn compare_source_tree_id(
+    primary_repo_root: &Path,
+    source: &CompareSource,
+) -> Result<Option<String>> {
+    let repo = open_repository(primary_repo_root)?;
+    let source = resolve_compare_source(&repo, source)?;
+    Ok(source.head_tree_oid.map(|oid| oid.to_string()))
 }
 
 pub fn load_compare_snapshot(
     primary_repo_root: &Path,
     left: &CompareSource,
     right: &CompareSource,
+) -> Result<CompareSnapshot> {
+    load_compare_snapshot_with_options(
+        primary_repo_root,
+        left,
+        right,
+        CompareOptions::default(),
+        &CancellationToken::new(),
+    )
+}
+
+/// Loads the comparison, checking `cancel` between files so a huge diff can be abandoned.
+pub fn load_compare_snapshot_with_options(
+    primary_repo_root: &Path,
+    left: &CompareSource,
+    right: &CompareSource,
+    options: CompareOptions,
+    cancel: &CancellationToken,
 ) -> Result<CompareSnapshot> {
     let common_repo = open_repository(primary_repo_root)?;
     let left = resolve_compare_source(&common_repo, left)?;
@@ -160,52 +201,176 @@ pub fn load_compare_snapshot(
         }
     }
 
-    let mut files = Vec::new();
-    let mut file_line_stats = BTreeMap::new();
-    let mut patches_by_path = BTreeMap::new();
-    let mut overall_line_stats = LineStats::default();
-
+    let mut entries = Vec::new();
     for path in candidate_paths {
-        let old_state = load_compare_source_state(
+        cancel.check()?;
+        if let Some(entry) = load_compare_path_entry(
             &common_repo,
             &left,
             left_workspace_session.as_mut(),
-            path.as_str(),
-        )?;
-        let new_state = load_compare_source_state(
+            &right,
+            right_workspace_session.as_mut(),
+            path,
+            &render_context,
+            options,
+        )? {
+            entries.push(entry);
+        }
+    }
+
+    Ok(compare_snapshot_from_entries(entries))
+}
+
+/// Reloads only `paths` and keeps every other file's patch from `previous`, for refreshes where
+/// the caller knows which files changed since `previous` was loaded.
+pub fn refresh_compare_snapshot_paths(
+    primary_repo_root: &Path,
+    left: &CompareSource,
+    right: &CompareSource,
+    options: CompareOptions,
+    previous: &CompareSnapshot,
+    paths: &BTreeSet<String>,
+    cancel: &CancellationToken,
+) -> Result<CompareSnapshot> {
+    let common_repo = open_repository(primary_repo_root)?;
+    let left = resolve_compare_source(&common_repo, left)?;
+    let right = resolve_compare_source(&common_repo, right)?;
+    let left_workspace_repo = left
+        .workspace_root
+        .as_deref()
+        .map(open_filter_repository)
+        .transpose()?;
+    let right_workspace_repo = right
+        .workspace_root
+        .as_deref()
+        .map(open_filter_repository)
+        .transpose()?;
+    let mut left_workspace_session =
+        match (left.workspace_root.as_ref(), left_workspace_repo.as_ref()) {
+            (Some(root), Some(repo)) => Some(CompareWorkspaceSession::new(root.clone(), repo)?),
+            _ => None,
+        };
+    let mut right_workspace_session =
+        match (right.workspace_root.as_ref(), right_workspace_repo.as_ref()) {
+            (Some(root), Some(repo)) => Some(CompareWorkspaceSession::new(root.clone(), repo)?),
+            _ => None,
+        };
+
+    let mut entries_by_path = previous
+        .files
+        .iter()
+        .filter(|file| !paths.contains(file.path.as_str()))
+        .map(|file| {
+            let entry = ComparePathEntry {
+                file: file.clone(),
+                line_stats: previous
+                    .file_line_stats
+                    .get(file.path.as_str())
+                    .copied()
+                    .unwrap_or_default(),
+                patch: previous
+                    .patches_by_path
+                    .get(file.path.as_str())
+                    .cloned()
+                    .unwrap_or_default(),
+            };
+            (file.path.clone(), entry)
+        })
+        .collect::<BTreeMap<_, _>>();
+    // A workspace tree render cache would diff every file, which is what this avoids.
+    for path in paths {
+        cancel.check()?;
+        if let Some(entry) = load_compare_path_entry(
             &common_repo,
+            &left,
+            left_workspace_session.as_mut(),
             &right,
             right_workspace_session.as_mut(),
-            path.as_str(),
-        )?;
-        if old_state == new_state {
-            continue;
+            path.clone(),
+            &CompareRenderContext::None,
+            options,
+        )? {
+            entries_by_path.insert(path.clone(), entry);
         }
+    }
+
+    Ok(compare_snapshot_from_entries(entries_by_path.into_values()))
+}
 
-        let (patch, line_stats) =
-            render_patch_and_line_stats(path.as_str(), &old_state, &new_state, &render_context)?;
-        let status = compare_file_status(&old_state, &new_state);
-        files.push(ChangedFile {
-            path: path.clone(),
+struct ComparePathEntry {
+    file: ChangedFile,
+    line_stats: LineStats,
+    patch: String,
+}
+
+/// Renders `path` between both sources, or `None` when the sides are identical.
+#[allow(clippy::too_many_arguments)]
+fn load_compare_path_entry(
+    common_repo: &Repository,
+    left: &ResolvedCompareSource,
+    left_workspace_session: Option<&mut CompareWorkspaceSession<'_>>,
+    right: &ResolvedCompareSource,
+    right_workspace_session: Option<&mut CompareWorkspaceSession<'_>>,
+    path: String,
+    render_context: &CompareRenderContext,
+    options: CompareOptions,
+) -> Result<Option<ComparePathEntry>> {
+    let old_state =
+        load_compare_source_state(common_repo, left, left_workspace_session, path.as_str())?;
+    let new_state =
+        load_compare_source_state(common_repo, right, right_workspace_session, path.as_str())?;
+    if old_state == new_state {
+        return Ok(None);
+    }
+
+    let (patch, line_stats) = render_patch_and_line_stats(
+        path.as_str(),
+        &old_state,
+        &new_state,
+        render_context,
+        options,
+    )?;
+    let status = compare_file_status(&old_state, &new_state);
+    Ok(Some(ComparePathEntry {
+        file: ChangedFile {
+            path,
             status,
             staged: false,
             unstaged: false,
             untracked: false,
-        });
-        file_line_stats.insert(path.clone(), line_stats);
-        patches_by_path.insert(path, patch);
-        overall_line_stats.added = overall_line_stats.added.saturating_add(line_stats.added);
+        },
+        line_stats,
+        patch,
+    }))
+}
+
+fn compare_snapshot_from_entries(
+    entries: impl IntoIterator<Item = ComparePathEntry>,
+) -> CompareSnapshot {
+    let mut files = Vec::new();
+    let mut file_line_stats = BTreeMap::new();
+    let mut patches_by_path = BTreeMap::new();
+    let mut overall_line_stats = LineStats::default();
+
+    for entry in entries {
+        let path = entry.file.path.clone();
+        files.push(entry.file);
+        file_line_stats.insert(path.clone(), entry.line_stats);
+        patches_by_path.insert(path, entry.patch);
+        overall_line_stats.added = overall_line_stats
+            .added
+            .saturating_add(entry.line_stats.added);
         overall_line_stats.removed = overall_line_stats
             .removed
-            .saturating_add(line_stats.removed);
+            .saturating_add(entry.line_stats.removed);
     }
 
-    Ok(CompareSnapshot {
+    CompareSnapshot {
         files,
         file_line_stats,
         overall_line_stats,
         patches_by_path,
-    })
+    }
 }
 
 #[derive(Debug)]
@@ -306,6 +471,10 @@ fn resolve_compare_source(
             workspace_root: None,
             head_tree_oid: Some(branch_tree_oid(repo, name.as_str())?),
         }),
+        CompareSource::Revision { spec } => Ok(ResolvedCompareSource {
+            workspace_root: None,
+            head_tree_oid: Some(revision_tree_oid(repo, spec.as_str())?),
+        }),
     }
 }
 
@@ -376,6 +545,7 @@ fn render_patch_and_line_stats(
     old_state: &ComparePathState,
     new_state: &ComparePathState,
     render_context: &CompareRenderContext,
+    compare_options: CompareOptions,
 ) -> Result<(String, LineStats)> {
     if let Some(rendered_path) = render_context.rendered_path(path) {
         return Ok((rendered_path.patch.clone(), rendered_path.line_stats));
@@ -396,16 +566,73 @@ fn render_patch_and_line_stats(
         ));
     }
 
-    let mut options = diff_options();
-    let old_bytes = old_state.patch_bytes();
-    let new_bytes = new_state.patch_bytes();
+    if let Some(kind) = DocumentKind::for_path(path)
+        && let Some(rendered) =
+            render_extracted_text_patch(path, kind, old_state, new_state, mode_headers.as_str())?
+    {
+        return Ok(rendered);
+    }
+
+    let old_bytes = utf8_diff_bytes(old_state.patch_bytes());
+    let new_bytes = utf8_diff_bytes(new_state.patch_bytes());
+    let (old_bytes, new_bytes) = (old_bytes.as_ref(), new_bytes.as_ref());
     if is_binary(old_bytes) || is_binary(new_bytes) {
+        if old_bytes.len().max(new_bytes.len()) <= compare_options.hex_diff_max_bytes {
+            return render_text_patch(
+                path,
+                hex_dump(old_bytes).as_bytes(),
+                hex_dump(new_bytes).as_bytes(),
+                format!("{mode_headers}{HEX_DUMP_PATCH_HEADER}\n").as_str(),
+            );
+        }
         return Ok((
             render_binary_patch(path, old_state, new_state, mode_headers.as_str()),
             LineStats::default(),
         ));
     }
 
+    render_text_patch(path, old_bytes, new_bytes, mode_headers.as_str())
+}
+
+/// Diffs the text extracted from both sides of a PDF or DOCX file. Returns `None` when either
+/// side cannot be extracted or the text is unchanged, leaving the file to the binary notice.
+fn render_extracted_text_patch(
+    path: &str,
+    kind: DocumentKind,
+    old_state: &ComparePathState,
+    new_state: &ComparePathState,
+    mode_headers: &str,
+) -> Result<Option<(String, LineStats)>> {
+    let extract = |state: &ComparePathState| {
+        if state.is_present() {
+            extract_document_text(kind, state.patch_bytes()).ok()
+        } else {
+            Some(String::new())
+        }
+    };
+    let (Some(old_text), Some(new_text)) = (extract(old_state), extract(new_state)) else {
+        return Ok(None);
+    };
+    if old_text == new_text {
+        return Ok(None);
+    }
+
+    render_text_patch(
+        path,
+        old_text.as_bytes(),
+        new_text.as_bytes(),
+        format!("{mode_headers}{EXTRACTED_TEXT_PATCH_HEADER}\n").as_str(),
+    )
+    .map(Some)
+}
+
+fn render_text_patch(
+    path: &str,
+    old_bytes: &[u8],
+    new_bytes: &[u8],
+    mode_headers: &str,
+) -> Result<(String, LineStats)> {
+    let mut options = diff_options();
     let mut patch = Patch::from_buffers(
         old_bytes,
         Some(Path::new(path)),
@@ -424,7 +651,7 @@ fn render_patch_and_line_stats(
         .line_stats()
         .with_context(|| format!("failed to compute patch line stats for {path}"))?;
     Ok((
-        prepend_mode_headers(path, patch_text, mode_headers.as_str()),
+        prepend_mode_headers(path, patch_text, mode_headers),
         LineStats {
             added: additions as u64,
             removed: deletions as u64,
@@ -504,13 +731,18 @@ fn render_workspace_tree_diff_entry(
     else {
         return Ok(None);
     };
+    // Binary, non-UTF-8 and document entries fall back to the buffer renderer, which decodes
+    // text in other encodings, extracts document text and renders small binaries as hex dumps.
+    if patch.delta().flags().is_binary() || DocumentKind::for_path(path).is_some() {
+        return Ok(None);
+    }
 
-    let patch_text = patch
+    let patch_buf = patch
         .to_buf()
-        .with_context(|| format!("failed to render workspace compare patch buffer for '{path}'"))?
-        .as_str()
-        .ok_or_else(|| anyhow!("workspace compare patch for '{path}' is not valid UTF-8"))?
-        .to_string();
+        .with_context(|| format!("failed to render workspace compare patch buffer for '{path}'"))?;
+    let Some(patch_text) = patch_buf.as_str().map(ToOwned::to_owned) else {
+        return Ok(None);
+    };
     if patch_text.is_empty() {
         return Ok(None);
     }
@@ -601,6 +833,15 @@ fn branch_tree_oid(repo: &Repository, branch_name: &str) -> Result<Oid> {
     Ok(commit.tree_id())
 }
 
+fn revision_tree_oid(repo: &Repository, spec: &str) -> Result<Oid> {
+    let tree = repo
+        .revparse_single(spec.trim())
+        .with_context(|| format!("revision '{spec}' does not exist"))?
+        .peel_to_tree()
+        .with_context(|| format!("revision '{spec}' does not point at a tree"))?;
+    Ok(tree.id())
+}
+
 fn peel_tree(repo: &Repository, tree_oid: Option<Oid>) -> Result<Option<Tree<'_>>> {
     tree_oid
         .map(|tree_oid| {
@@ -679,47 +920,6 @@ fn diff_delta_path(delta: &git2::DiffDelta<'_>) -> Option<String> {
         .and_then(path_to_repo_string)
 }
 
-fn remote_default_branch_name(repo: &gix::Repository) -> Result<Option<String>> {
-    let remote_name = repo
-        .find_default_remote(gix::remote::Direction::Fetch)
-        .and_then(Result::ok)
-        .and_then(|remote| {
-            remote.name().and_then(|name| match name {
-                gix::remote::Name::Symbol(name) => Some(name.to_string()),
-                gix::remote::Name::Url(_) => None,
-            })
-        })
-        .or_else(|| {
-            repo.remote_names()
-                .into_iter()
-                .next()
-                .map(|name| name.as_ref().to_string())
-        });
-    let Some(remote_name) = remote_name else {
-        return Ok(None);
-    };
-
-    let default_remote_head_ref = format!("refs/remotes/{remote_name}/HEAD");
-    let Ok(reference) = repo.find_reference(default_remote_head_ref.as_str()) else {
-        return Ok(None);
-    };
-    Ok(reference
-        .target()
-        .try_name()
-        .map(|name| name.to_string())
-        .and_then(|name| {
-            name.strip_prefix(format!("refs/remotes/{remote_name}/").as_str())
-                .map(str::to_owned)
-        }))
-}
-
-fn local_branch_exists(repo_root: &Path, branch_name: &str) -> Result<bool> {
-    let repo = open_repository(repo_root)?;
-    Ok(repo
-        .find_branch(branch_name, git2::BranchType::Local)
-        .is_ok())
-}
-
 fn path_to_repo_string(path: &Path) -> Option<String> {
     Some(path.to_string_lossy().replace('\\', "/"))
 }
@@ -757,3 +957,5 @@ fn prepend_mode_headers(path: &str, patch_text: String, mode_headers: &str) -> S
 fn format_mode(mode: u32) -> String {
     format!("{mode:06o}")
 }
+
+include!("compare/base_branch.rs");
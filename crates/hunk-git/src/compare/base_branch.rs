@@ -0,0 +1,58 @@
+pub fn resolve_default_base_branch_name(repo_root: &Path) -> Result<Option<String>> {
+    let repo = gix::discover(repo_root).with_context(|| {
+        format!(
+            "failed to discover Git repository from {}",
+            repo_root.display()
+        )
+    })?;
+    if let Some(branch_name) = remote_default_branch_name(&repo)? {
+        return Ok(Some(branch_name));
+    }
+    for candidate in ["main", "master"] {
+        if local_branch_exists(repo_root, candidate)? {
+            return Ok(Some(candidate.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+fn remote_default_branch_name(repo: &gix::Repository) -> Result<Option<String>> {
+    let remote_name = repo
+        .find_default_remote(gix::remote::Direction::Fetch)
+        .and_then(Result::ok)
+        .and_then(|remote| {
+            remote.name().and_then(|name| match name {
+                gix::remote::Name::Symbol(name) => Some(name.to_string()),
+                gix::remote::Name::Url(_) => None,
+            })
+        })
+        .or_else(|| {
+            repo.remote_names()
+                .into_iter()
+                .next()
+                .map(|name| name.as_ref().to_string())
+        });
+    let Some(remote_name) = remote_name else {
+        return Ok(None);
+    };
+
+    let default_remote_head_ref = format!("refs/remotes/{remote_name}/HEAD");
+    let Ok(reference) = repo.find_reference(default_remote_head_ref.as_str()) else {
+        return Ok(None);
+    };
+    Ok(reference
+        .target()
+        .try_name()
+        .map(|name| name.to_string())
+        .and_then(|name| {
+            name.strip_prefix(format!("refs/remotes/{remote_name}/").as_str())
+                .map(str::to_owned)
+        }))
+}
+
+fn local_branch_exists(repo_root: &Path, branch_name: &str) -> Result<bool> {
+    let repo = open_repository(repo_root)?;
+    Ok(repo
+        .find_branch(branch_name, git2::BranchType::Local)
+        .is_ok())
+}
@@ -0,0 +1,212 @@
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::Context as _;
+use git2::Config;
+
+use crate::command_env::git_cli_command;
+use crate::git2_helpers::open_git2_repo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStatus {
+    Pass,
+    Warning,
+    Fail,
+}
+
+impl DiagnosticStatus {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Warning => "WARN",
+            Self::Fail => "FAIL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticCheck {
+    pub name: String,
+    pub status: DiagnosticStatus,
+    pub detail: String,
+    pub remediation: Option<String>,
+}
+
+impl DiagnosticCheck {
+    pub fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Pass,
+            detail: detail.into(),
+            remediation: None,
+        }
+    }
+
+    pub fn warning(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        remediation: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Warning,
+            detail: detail.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+
+    pub fn fail(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        remediation: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Fail,
+            detail: detail.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+}
+
+pub fn run_git_diagnostics(repo_root: Option<&Path>) -> Vec<DiagnosticCheck> {
+    let config = match repo_root {
+        Some(repo_root) => open_git2_repo(repo_root).and_then(|repo| {
+            repo.config()
+                .context("failed to load repository Git config")
+        }),
+        None => Config::open_default().context("failed to load Git config"),
+    };
+
+    let mut checks = vec![git_cli_check()];
+    match config {
+        Ok(config) => {
+            checks.push(identity_check(&config));
+            checks.push(signing_check(&config));
+        }
+        Err(err) => checks.push(DiagnosticCheck::fail(
+            "Git config",
+            format!("{err:#}"),
+            "Check that the repository and ~/.gitconfig are readable and valid.",
+        )),
+    }
+    if let Some(repo_root) = repo_root {
+        checks.push(remote_check(repo_root));
+    }
+    checks
+}
+
+fn git_cli_check() -> DiagnosticCheck {
+    match git_cli_command("git").arg("--version").output() {
+        Ok(output) if output.status.success() => DiagnosticCheck::pass(
+            "Git CLI",
+            String::from_utf8_lossy(output.stdout.as_slice())
+                .trim()
+                .to_string(),
+        ),
+        Ok(output) => DiagnosticCheck::fail(
+            "Git CLI",
+            format!("git --version exited with status {}", output.status),
+            "Reinstall Git or fix the git executable on your PATH.",
+        ),
+        Err(err) => DiagnosticCheck::warning(
+            "Git CLI",
+            format!("failed to launch git: {err}"),
+            "Install Git to clean up repositories (git gc), widen a sparse checkout, and commit \
+             with hunk.signWithGitCli. Everything else works without the git CLI.",
+        ),
+    }
+}
+
+fn identity_check(config: &Config) -> DiagnosticCheck {
+    let name = config.get_string("user.name").ok();
+    let email = config.get_string("user.email").ok();
+    match (name, email) {
+        (Some(name), Some(email)) => {
+            DiagnosticCheck::pass("Git identity", format!("{name} <{email}>"))
+        }
+        (name, email) => {
+            let missing = [
+                name.is_none().then_some("user.name"),
+                email.is_none().then_some("user.email"),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" and ");
+            DiagnosticCheck::fail(
+                "Git identity",
+                format!("{missing} is not configured"),
+                "Set your name and email in Settings > Git.",
+            )
+        }
+    }
+}
+
+fn signing_check(config: &Config) -> DiagnosticCheck {
+    if !config.get_bool("commit.gpgSign").unwrap_or(false) {
+        return DiagnosticCheck::pass("Commit signing", "Disabled");
+    }
+
+    let format = config
+        .get_string("gpg.format")
+        .unwrap_or_else(|_| "openpgp".to_string());
+    let (program_key, default_program) = match format.as_str() {
+        "ssh" => ("gpg.ssh.program", "ssh-keygen"),
+        "x509" => ("gpg.x509.program", "gpgsm"),
+        _ => ("gpg.program", "gpg"),
+    };
+    let program = config
+        .get_string(program_key)
+        .unwrap_or_else(|_| default_program.to_string());
+
+    match git_cli_command(program.as_str()).arg("--help").output() {
+        Err(err) if err.kind() == ErrorKind::NotFound => DiagnosticCheck::fail(
+            "Commit signing",
+            format!("{format} signing is enabled but {program} was not found"),
+            format!("Install {program} or set {program_key} to its full path."),
+        ),
+        Err(err) => DiagnosticCheck::fail(
+            "Commit signing",
+            format!("failed to launch {program}: {err}"),
+            format!(
+                "Check that {program} is executable or set {program_key} to a working program."
+            ),
+        ),
+        Ok(_) if config.get_string("user.signingKey").is_err() && format == "ssh" => {
+            DiagnosticCheck::fail(
+                "Commit signing",
+                "SSH signing is enabled but user.signingKey is not set",
+                "Set a signing key in Settings > Git.",
+            )
+        }
+        Ok(_) => DiagnosticCheck::pass("Commit signing", format!("{format} via {program}")),
+    }
+}
+
+fn remote_check(repo_root: &Path) -> DiagnosticCheck {
+    let remotes = open_git2_repo(repo_root).and_then(|repo| {
+        repo.remotes()
+            .map(|remotes| {
+                remotes
+                    .iter()
+                    .flatten()
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>()
+            })
+            .context("failed to list Git remotes")
+    });
+    match remotes {
+        Ok(remotes) if remotes.is_empty() => DiagnosticCheck::warning(
+            "Remotes",
+            "No Git remote is configured",
+            "Add a remote to publish and sync branches.",
+        ),
+        Ok(remotes) => DiagnosticCheck::pass("Remotes", remotes.join(", ")),
+        Err(err) => DiagnosticCheck::fail(
+            "Remotes",
+            format!("{err:#}"),
+            "Check the [remote] sections in the repository config.",
+        ),
+    }
+}
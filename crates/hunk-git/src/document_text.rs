@@ -0,0 +1,442 @@
+use std::io::Read as _;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use flate2::read::{DeflateDecoder, ZlibDecoder};
+
+/// Extended header line marking a patch whose hunks diff text extracted from a document.
+pub const EXTRACTED_TEXT_PATCH_HEADER: &str = "approximate text diff of extracted document content";
+
+const MAX_EXTRACTED_PART_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentKind {
+    Pdf,
+    Docx,
+}
+
+impl DocumentKind {
+    pub fn for_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "pdf" => Some(Self::Pdf),
+            "docx" => Some(Self::Docx),
+            _ => None,
+        }
+    }
+}
+
+/// True when `patch` diffs extracted document text rather than the file itself.
+pub fn is_extracted_text_patch(patch: &str) -> bool {
+    patch
+        .lines()
+        .take_while(|line| !line.starts_with("@@"))
+        .any(|line| line == EXTRACTED_TEXT_PATCH_HEADER)
+}
+
+/// Extracts readable text from a document, one paragraph or text line per line.
+///
+/// This is deliberately approximate: PDF text comes from the literal strings shown by content
+/// stream text operators, so fonts with custom encodings or text drawn as outlines come out
+/// garbled or missing, and DOCX text keeps paragraphs but drops tables and formatting.
+pub fn extract_document_text(kind: DocumentKind, bytes: &[u8]) -> Result<String> {
+    let text = match kind {
+        DocumentKind::Pdf => extract_pdf_text(bytes),
+        DocumentKind::Docx => extract_docx_text(bytes)?,
+    };
+    Ok(normalize_extracted_lines(text.as_str()))
+}
+
+fn normalize_extracted_lines(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            continue;
+        }
+        normalized.push_str(line.as_str());
+        normalized.push('\n');
+    }
+    normalized
+}
+
+fn extract_docx_text(bytes: &[u8]) -> Result<String> {
+    let xml = read_zip_entry(bytes, "word/document.xml")?
+        .ok_or_else(|| anyhow!("document has no word/document.xml part"))?;
+    let xml = String::from_utf8(xml).context("word/document.xml is not valid UTF-8")?;
+    Ok(docx_xml_text(xml.as_str()))
+}
+
+fn docx_xml_text(xml: &str) -> String {
+    let mut text = String::new();
+    let mut rest = xml;
+    let mut in_text = false;
+    let mut in_paragraph_properties = false;
+    while let Some(open) = rest.find('<') {
+        if in_text {
+            text.push_str(unescape_xml(&rest[..open]).as_str());
+        }
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|ch: char| ch.is_whitespace() || ch == '/')
+            .next()
+            .unwrap_or_default();
+        match name {
+            "w:t" => in_text = !closing && !self_closing,
+            "w:pPr" => in_paragraph_properties = !closing && !self_closing,
+            "w:tab" if !closing && !in_paragraph_properties => text.push('\t'),
+            "w:br" | "w:cr" if !closing => text.push('\n'),
+            "w:p" if closing || self_closing => text.push('\n'),
+            _ => {}
+        }
+        rest = &rest[open + close + 1..];
+    }
+    text
+}
+
+fn unescape_xml(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        unescaped.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(semi) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..semi];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(ch) => {
+                unescaped.push(ch);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Reads one stored or deflated entry from a ZIP archive through its central directory.
+fn read_zip_entry(archive: &[u8], entry_name: &str) -> Result<Option<Vec<u8>>> {
+    const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+    const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+    const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+    let search_start = archive.len().saturating_sub(22 + usize::from(u16::MAX));
+    let end_record = (search_start..archive.len().saturating_sub(21))
+        .rev()
+        .find(|offset| read_u32(archive, *offset) == Some(END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| anyhow!("not a ZIP archive"))?;
+    let entry_count = read_u16(archive, end_record + 10).unwrap_or_default();
+    let mut offset = read_u32(archive, end_record + 16).unwrap_or_default() as usize;
+
+    for _ in 0..entry_count {
+        if read_u32(archive, offset) != Some(CENTRAL_DIRECTORY_HEADER) {
+            return Err(anyhow!("corrupt ZIP central directory"));
+        }
+        let field = |at: usize| read_u16(archive, offset + at).map(usize::from);
+        let (Some(method), Some(name_len), Some(extra_len), Some(comment_len)) =
+            (field(10), field(28), field(30), field(32))
+        else {
+            return Err(anyhow!("truncated ZIP central directory"));
+        };
+        let compressed_size = read_u32(archive, offset + 20).unwrap_or_default() as usize;
+        let local_offset = read_u32(archive, offset + 42).unwrap_or_default() as usize;
+        let name = archive
+            .get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(|| anyhow!("truncated ZIP entry name"))?;
+        offset += 46 + name_len + extra_len + comment_len;
+        if name != entry_name.as_bytes() {
+            continue;
+        }
+
+        if read_u32(archive, local_offset) != Some(LOCAL_FILE_HEADER) {
+            return Err(anyhow!("corrupt ZIP local header for {entry_name}"));
+        }
+        let local_name_len = read_u16(archive, local_offset + 26).unwrap_or_default();
+        let local_extra_len = read_u16(archive, local_offset + 28).unwrap_or_default();
+        let data_start =
+            local_offset + 30 + usize::from(local_name_len) + usize::from(local_extra_len);
+        let data = archive
+            .get(data_start..data_start + compressed_size)
+            .ok_or_else(|| anyhow!("truncated ZIP entry {entry_name}"))?;
+        let mut contents = Vec::new();
+        match method {
+            0 => contents.extend_from_slice(data),
+            8 => {
+                DeflateDecoder::new(data)
+                    .take(MAX_EXTRACTED_PART_BYTES)
+                    .read_to_end(&mut contents)
+                    .with_context(|| format!("failed to inflate {entry_name}"))?;
+            }
+            other => return Err(anyhow!("unsupported ZIP compression method {other}")),
+        }
+        return Ok(Some(contents));
+    }
+    Ok(None)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn extract_pdf_text(bytes: &[u8]) -> String {
+    let mut text = String::new();
+    let mut cursor = 0;
+    while let Some(keyword) = find_bytes(bytes, b"stream", cursor) {
+        cursor = keyword + b"stream".len();
+        if bytes[..keyword].ends_with(b"end") {
+            continue;
+        }
+        let mut data_start = cursor;
+        if bytes.get(data_start) == Some(&b'\r') {
+            data_start += 1;
+        }
+        if bytes.get(data_start) == Some(&b'\n') {
+            data_start += 1;
+        }
+        let Some(data_end) = find_bytes(bytes, b"endstream", data_start) else {
+            break;
+        };
+        cursor = data_end + b"endstream".len();
+
+        let dictionary_start = bytes[..keyword]
+            .windows(3)
+            .rposition(|window| window == b"obj")
+            .unwrap_or(0);
+        let dictionary = &bytes[dictionary_start..keyword];
+        let data = &bytes[data_start..data_end];
+        let content = if find_bytes(dictionary, b"/Filter", 0).is_none() {
+            data.to_vec()
+        } else if find_bytes(dictionary, b"/FlateDecode", 0).is_some() {
+            let mut inflated = Vec::new();
+            if ZlibDecoder::new(data)
+                .take(MAX_EXTRACTED_PART_BYTES)
+                .read_to_end(&mut inflated)
+                .is_err()
+                && inflated.is_empty()
+            {
+                continue;
+            }
+            inflated
+        } else {
+            continue;
+        };
+        if find_bytes(&content, b"BT", 0).is_some() && find_bytes(&content, b"ET", 0).is_some() {
+            pdf_content_text(&content, &mut text);
+        }
+    }
+    text
+}
+
+enum PdfOperand {
+    Text(Vec<u8>),
+    Number(f64),
+}
+
+/// Collects the strings shown by text operators in one content stream.
+fn pdf_content_text(content: &[u8], text: &mut String) {
+    let mut operands = Vec::<PdfOperand>::new();
+    let mut ix = 0;
+    while ix < content.len() {
+        let byte = content[ix];
+        match byte {
+            b'(' => {
+                let (string, next) = pdf_literal_string(content, ix + 1);
+                operands.push(PdfOperand::Text(string));
+                ix = next;
+            }
+            b'<' if content.get(ix + 1) != Some(&b'<') => {
+                let end = content[ix..]
+                    .iter()
+                    .position(|byte| *byte == b'>')
+                    .map_or(content.len(), |end| ix + end);
+                operands.push(PdfOperand::Text(pdf_hex_string(&content[ix + 1..end])));
+                ix = end + 1;
+            }
+            b'%' => {
+                while ix < content.len() && !matches!(content[ix], b'\r' | b'\n') {
+                    ix += 1;
+                }
+            }
+            b'/' => {
+                // Names only select fonts and resources, which the text does not need.
+                ix += 1;
+                while ix < content.len() && is_pdf_regular(content[ix]) {
+                    ix += 1;
+                }
+            }
+            _ if !is_pdf_regular(byte) => ix += 1,
+            _ => {
+                let start = ix;
+                while ix < content.len() && is_pdf_regular(content[ix]) {
+                    ix += 1;
+                }
+                let token = &content[start..ix];
+                if let Some(number) = std::str::from_utf8(token)
+                    .ok()
+                    .and_then(|token| token.parse::<f64>().ok())
+                {
+                    operands.push(PdfOperand::Number(number));
+                    continue;
+                }
+                apply_pdf_text_operator(token, &operands, text);
+                operands.clear();
+            }
+        }
+    }
+}
+
+fn is_pdf_regular(byte: u8) -> bool {
+    !byte.is_ascii_whitespace() && !b"()<>[]{}/%".contains(&byte)
+}
+
+fn apply_pdf_text_operator(operator: &[u8], operands: &[PdfOperand], text: &mut String) {
+    match operator {
+        b"Tj" | b"'" | b"\"" => {
+            if operator != b"Tj" {
+                text.push('\n');
+            }
+            if let Some(PdfOperand::Text(string)) = operands.last() {
+                text.push_str(pdf_decode_string(string).as_str());
+            }
+        }
+        b"TJ" => {
+            for operand in operands {
+                match operand {
+                    PdfOperand::Text(string) => text.push_str(pdf_decode_string(string).as_str()),
+                    // Large negative kerning adjustments stand in for word spaces.
+                    PdfOperand::Number(adjustment) if *adjustment < -200.0 => text.push(' '),
+                    PdfOperand::Number(_) => {}
+                }
+            }
+        }
+        b"T*" | b"ET" | b"Tm" => text.push('\n'),
+        b"Td" | b"TD" => match operands {
+            [.., PdfOperand::Number(_), PdfOperand::Number(ty)] if *ty != 0.0 => text.push('\n'),
+            _ => text.push(' '),
+        },
+        _ => {}
+    }
+}
+
+fn pdf_literal_string(content: &[u8], start: usize) -> (Vec<u8>, usize) {
+    let mut string = Vec::new();
+    let mut depth = 1;
+    let mut ix = start;
+    while ix < content.len() {
+        let byte = content[ix];
+        ix += 1;
+        match byte {
+            b'\\' => {
+                let Some(&escaped) = content.get(ix) else {
+                    break;
+                };
+                ix += 1;
+                match escaped {
+                    b'n' => string.push(b'\n'),
+                    b'r' => string.push(b'\r'),
+                    b't' => string.push(b'\t'),
+                    b'b' => string.push(0x08),
+                    b'f' => string.push(0x0c),
+                    b'\r' | b'\n' => {
+                        if escaped == b'\r' && content.get(ix) == Some(&b'\n') {
+                            ix += 1;
+                        }
+                    }
+                    b'0'..=b'7' => {
+                        let mut value = u32::from(escaped - b'0');
+                        for _ in 0..2 {
+                            match content.get(ix) {
+                                Some(digit @ b'0'..=b'7') => {
+                                    value = value * 8 + u32::from(digit - b'0');
+                                    ix += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        string.push(value as u8);
+                    }
+                    other => string.push(other),
+                }
+            }
+            b'(' => {
+                depth += 1;
+                string.push(byte);
+            }
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+                string.push(byte);
+            }
+            _ => string.push(byte),
+        }
+    }
+    (string, ix)
+}
+
+fn pdf_hex_string(hex: &[u8]) -> Vec<u8> {
+    let digits = hex
+        .iter()
+        .filter_map(|byte| char::from(*byte).to_digit(16))
+        .map(|digit| digit as u8)
+        .collect::<Vec<_>>();
+    digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+fn pdf_decode_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xfe, 0xff]) {
+        let units = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        return String::from_utf16_lossy(&units);
+    }
+    bytes
+        .iter()
+        .map(|byte| char::from(*byte))
+        .filter(|ch| !ch.is_control() || *ch == '\n' || *ch == '\t')
+        .collect()
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| from + position)
+}
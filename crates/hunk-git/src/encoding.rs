@@ -0,0 +1,277 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path};
+
+use anyhow::{Context as _, Result, anyhow};
+use encoding_rs::{
+    BIG5, EUC_JP, EUC_KR, Encoding, GBK, SHIFT_JIS, UTF_8, UTF_16BE, UTF_16LE, WINDOWS_1252,
+};
+
+const DETECTION_WINDOW: usize = 8 * 1024;
+
+/// The text encoding detected for a file's contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEncoding {
+    pub encoding: &'static Encoding,
+    /// Whether the contents start with a byte order mark.
+    pub bom: bool,
+}
+
+impl TextEncoding {
+    pub fn name(&self) -> &'static str {
+        self.encoding.name()
+    }
+
+    /// Short label for the file banner, e.g. `Shift_JIS` or `UTF-8 with BOM`.
+    pub fn label(&self) -> String {
+        if self.bom {
+            format!("{} with BOM", self.name())
+        } else {
+            self.name().to_string()
+        }
+    }
+
+    /// True for plain UTF-8, which is what the diff and editor expect already.
+    pub fn is_utf8(&self) -> bool {
+        self.encoding == UTF_8 && !self.bom
+    }
+}
+
+/// Guesses the text encoding of `bytes`, or returns `None` for binary contents.
+///
+/// A byte order mark wins, then NUL-interleaved UTF-16, then valid UTF-8. Otherwise the common
+/// CJK double-byte encodings are tried in turn, accepting the first that decodes cleanly into
+/// its own script, and anything else is read as Windows-1252.
+pub fn detect_text_encoding(bytes: &[u8]) -> Option<TextEncoding> {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return Some(TextEncoding {
+            encoding,
+            bom: true,
+        });
+    }
+    if let Some(encoding) = detect_bomless_utf16(bytes) {
+        return Some(TextEncoding {
+            encoding,
+            bom: false,
+        });
+    }
+    if bytes.iter().take(DETECTION_WINDOW).any(|byte| *byte == 0) {
+        return None;
+    }
+    if std::str::from_utf8(bytes).is_ok() {
+        return Some(TextEncoding {
+            encoding: UTF_8,
+            bom: false,
+        });
+    }
+
+    Some(TextEncoding {
+        encoding: detect_multibyte_encoding(bytes).unwrap_or(WINDOWS_1252),
+        bom: false,
+    })
+}
+
+/// Decodes `bytes` with `encoding`, dropping the byte order mark and replacing malformed
+/// sequences.
+pub fn decode_text(bytes: &[u8], encoding: TextEncoding) -> String {
+    let bytes = if encoding.bom {
+        Encoding::for_bom(bytes)
+            .map(|(_, bom_len)| &bytes[bom_len..])
+            .unwrap_or(bytes)
+    } else {
+        bytes
+    };
+    encoding
+        .encoding
+        .decode_without_bom_handling(bytes)
+        .0
+        .into_owned()
+}
+
+/// Returns the UTF-8 form of text contents so patches show the decoded characters. Binary and
+/// UTF-8 contents are returned unchanged.
+pub(crate) fn utf8_diff_bytes(bytes: &[u8]) -> Cow<'_, [u8]> {
+    match detect_text_encoding(bytes) {
+        Some(encoding) if encoding.encoding != UTF_8 => {
+            Cow::Owned(decode_text(bytes, encoding).into_bytes())
+        }
+        _ => Cow::Borrowed(bytes),
+    }
+}
+
+/// Detects the encoding of each working-copy file in `paths`, keeping only the files that are
+/// text but not plain UTF-8. Missing files and directories are skipped.
+pub fn load_working_copy_encodings<'a>(
+    repo_root: &Path,
+    paths: impl IntoIterator<Item = &'a str>,
+) -> Result<BTreeMap<String, TextEncoding>> {
+    let mut encodings = BTreeMap::new();
+    for path in paths {
+        let absolute_path = repo_root.join(checked_relative_path(path)?);
+        let metadata = match fs::symlink_metadata(absolute_path.as_path()) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to inspect {}", absolute_path.display()));
+            }
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let bytes = fs::read(absolute_path.as_path())
+            .with_context(|| format!("failed to read {}", absolute_path.display()))?;
+        if let Some(encoding) = detect_text_encoding(&bytes)
+            && !encoding.is_utf8()
+        {
+            encodings.insert(path.to_string(), encoding);
+        }
+    }
+    Ok(encodings)
+}
+
+/// Rewrites a working-copy file as UTF-8 without a byte order mark.
+///
+/// `expected` is the encoding the user confirmed; the file is left alone if it now detects as
+/// something else or if any byte sequence is malformed in that encoding, so nothing is lost to
+/// replacement characters.
+pub fn convert_file_to_utf8(repo_root: &Path, path: &str, expected: TextEncoding) -> Result<()> {
+    let absolute_path = repo_root.join(checked_relative_path(path)?);
+    let bytes = fs::read(absolute_path.as_path())
+        .with_context(|| format!("failed to read {}", absolute_path.display()))?;
+    let encoding = detect_text_encoding(&bytes)
+        .ok_or_else(|| anyhow!("'{path}' looks like a binary file and cannot be converted"))?;
+    if encoding.is_utf8() {
+        return Err(anyhow!("'{path}' is already UTF-8"));
+    }
+    if encoding != expected {
+        return Err(anyhow!(
+            "'{path}' now looks like {} rather than {}; review it again before converting",
+            encoding.label(),
+            expected.label()
+        ));
+    }
+
+    let text = decode_text_strict(&bytes, encoding).ok_or_else(|| {
+        anyhow!(
+            "'{path}' has bytes that are not valid {}; it was left unchanged",
+            encoding.name()
+        )
+    })?;
+    fs::write(absolute_path.as_path(), text)
+        .with_context(|| format!("failed to write {}", absolute_path.display()))
+}
+
+fn decode_text_strict(bytes: &[u8], encoding: TextEncoding) -> Option<String> {
+    let bytes = if encoding.bom {
+        Encoding::for_bom(bytes)
+            .map(|(_, bom_len)| &bytes[bom_len..])
+            .unwrap_or(bytes)
+    } else {
+        bytes
+    };
+    encoding
+        .encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(Cow::into_owned)
+}
+
+fn checked_relative_path(path: &str) -> Result<&Path> {
+    let relative = Path::new(path);
+    if path.is_empty()
+        || relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Err(anyhow!("'{path}' is not a repository-relative file path"));
+    }
+    Ok(relative)
+}
+
+fn detect_bomless_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+    let window = &bytes[..bytes.len().min(DETECTION_WINDOW)];
+    if window.len() < 4 || !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let pairs = window.len() / 2;
+    let even_nuls = window.iter().step_by(2).filter(|byte| **byte == 0).count();
+    let odd_nuls = window
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|byte| **byte == 0)
+        .count();
+    let encoding = if odd_nuls * 2 >= pairs && even_nuls == 0 {
+        UTF_16LE
+    } else if even_nuls * 2 >= pairs && odd_nuls == 0 {
+        UTF_16BE
+    } else {
+        return None;
+    };
+
+    let decoded = encoding.decode_without_bom_handling_and_without_replacement(bytes)?;
+    decoded
+        .chars()
+        .all(|ch| !ch.is_control() || ch.is_whitespace())
+        .then_some(encoding)
+}
+
+fn detect_multibyte_encoding(bytes: &[u8]) -> Option<&'static Encoding> {
+    if !has_paired_high_bytes(bytes) {
+        return None;
+    }
+
+    // Kana only appear in Japanese and modern Korean is written in Hangul without Hanja, so
+    // those are tried before the Chinese encodings, which decode most of the same bytes.
+    let candidates: [(&'static Encoding, fn(&str) -> bool); 5] = [
+        (SHIFT_JIS, |text| text.chars().any(is_kana)),
+        (EUC_JP, |text| text.chars().any(is_kana)),
+        (EUC_KR, |text| {
+            text.chars().any(is_hangul) && !text.chars().any(is_han)
+        }),
+        (GBK, |text| text.chars().any(is_han)),
+        (BIG5, |text| text.chars().any(is_han)),
+    ];
+    candidates
+        .into_iter()
+        .find(|(encoding, has_signature)| {
+            encoding
+                .decode_without_bom_handling_and_without_replacement(bytes)
+                .is_some_and(|text| {
+                    text.chars().all(|ch| ch.is_ascii() || is_cjk(ch)) && has_signature(&text)
+                })
+        })
+        .map(|(encoding, _)| encoding)
+}
+
+/// Double-byte encodings put their non-ASCII bytes in pairs, while single-byte Latin text has
+/// accented letters between ASCII ones.
+fn has_paired_high_bytes(bytes: &[u8]) -> bool {
+    let high = bytes.iter().filter(|byte| !byte.is_ascii()).count();
+    let paired = bytes
+        .windows(2)
+        .filter(|pair| !pair[0].is_ascii() && !pair[1].is_ascii())
+        .count();
+    paired * 2 >= high
+}
+
+fn is_kana(ch: char) -> bool {
+    matches!(ch, '\u{3040}'..='\u{30FF}')
+}
+
+fn is_han(ch: char) -> bool {
+    matches!(ch, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+fn is_hangul(ch: char) -> bool {
+    matches!(ch, '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+fn is_cjk(ch: char) -> bool {
+    is_kana(ch)
+        || is_han(ch)
+        || is_hangul(ch)
+        || matches!(ch, '\u{3000}'..='\u{303F}' | '\u{FF00}'..='\u{FFEF}' | '\u{2010}'..='\u{2027}')
+}
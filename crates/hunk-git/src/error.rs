@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Failures callers branch on to pick recovery flows and user messaging.
+///
+/// Backend functions still return `anyhow::Result`; these values are either the root error
+/// or a context layer, so use [`GitError::classify`] instead of matching on message text.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GitError {
+    #[error("no Git repository found at {}", path.display())]
+    NotARepository { path: PathBuf },
+    #[error("the repository is locked by another Git process")]
+    WorkingCopyLocked,
+    #[error("authentication failed for remote '{remote}'")]
+    RemoteAuthFailed { remote: String },
+    #[error("no Git remote configured for publish/push")]
+    RemoteNotConfigured,
+    #[error("publish this branch before pushing")]
+    PublishRequired,
+    #[error("branch '{branch}' is already published")]
+    AlreadyPublished { branch: String },
+    #[error("remote '{remote}' rejected '{reference}': {reason}")]
+    PushRejected {
+        remote: String,
+        reference: String,
+        reason: String,
+    },
+    #[error("cannot operate on conflicted files")]
+    Conflicted,
+    #[error("no changes to commit")]
+    NothingToCommit,
+    #[error("Git author identity is not configured; set user.name and user.email")]
+    MissingIdentity,
+    #[error("branch '{branch}' already exists")]
+    BranchExists { branch: String },
+    #[error("operation was cancelled")]
+    Cancelled,
+    #[error("operation timed out after {seconds}s")]
+    TimedOut { seconds: u64 },
+}
+
+impl GitError {
+    pub fn classify(err: &anyhow::Error) -> Option<Self> {
+        if let Some(git_error) = err.downcast_ref::<Self>() {
+            return Some(git_error.clone());
+        }
+        err.chain().find_map(|cause| {
+            cause.downcast_ref::<Self>().cloned().or_else(|| {
+                cause
+                    .downcast_ref::<git2::Error>()
+                    .and_then(Self::from_git2)
+            })
+        })
+    }
+
+    fn from_git2(err: &git2::Error) -> Option<Self> {
+        match (err.class(), err.code()) {
+            (_, git2::ErrorCode::Locked) => Some(Self::WorkingCopyLocked),
+            (_, git2::ErrorCode::Conflict | git2::ErrorCode::Unmerged) => Some(Self::Conflicted),
+            (git2::ErrorClass::Repository, git2::ErrorCode::NotFound) => {
+                Some(Self::NotARepository {
+                    path: PathBuf::new(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
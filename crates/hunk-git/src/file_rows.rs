@@ -0,0 +1,105 @@
+//! Side-by-side rows for one changed file, shared by the review and `hunk diff`.
+
+use std::path::Path;
+
+use hunk_domain::diff::{SideBySideRow, parse_patch_side_by_side};
+
+use crate::document_text::is_extracted_text_patch;
+use crate::hex_diff::is_hex_dump_patch;
+
+/// A file's diff rows, or why it has none to show.
+#[derive(Debug, Clone)]
+pub struct LoadedFileDiffRows {
+    pub core_rows: Vec<SideBySideRow>,
+    pub load_error: Option<String>,
+}
+
+/// Parses `patch` for the file at `path`. Binary files get a `load_error` instead of rows,
+/// unless the loader already turned them into a hex dump or extracted text.
+pub fn load_file_diff_rows(path: &str, patch: &str) -> LoadedFileDiffRows {
+    if is_probably_binary_extension(path)
+        && !is_hex_dump_patch(patch)
+        && !is_extracted_text_patch(patch)
+    {
+        return LoadedFileDiffRows {
+            core_rows: Vec::new(),
+            load_error: Some(format!("Preview unavailable for {path}: binary file type.")),
+        };
+    }
+
+    if is_binary_patch(patch) {
+        return LoadedFileDiffRows {
+            core_rows: Vec::new(),
+            load_error: Some(format!("Preview unavailable for {path}: binary diff.")),
+        };
+    }
+
+    LoadedFileDiffRows {
+        core_rows: parse_patch_side_by_side(patch),
+        load_error: None,
+    }
+}
+
+/// True for file types whose contents are binary, so their text diff is not worth showing.
+pub fn is_probably_binary_extension(path: &str) -> bool {
+    let Some(extension) = Path::new(path).extension().and_then(|ext| ext.to_str()) else {
+        return false;
+    };
+
+    let extension = extension.to_ascii_lowercase();
+    matches!(
+        extension.as_str(),
+        "7z" | "a"
+            | "apk"
+            | "bin"
+            | "bmp"
+            | "class"
+            | "dll"
+            | "dmg"
+            | "doc"
+            | "docx"
+            | "ear"
+            | "eot"
+            | "exe"
+            | "gif"
+            | "gz"
+            | "ico"
+            | "jar"
+            | "jpeg"
+            | "jpg"
+            | "lib"
+            | "lockb"
+            | "mov"
+            | "mp3"
+            | "mp4"
+            | "o"
+            | "obj"
+            | "otf"
+            | "pdf"
+            | "png"
+            | "pyc"
+            | "so"
+            | "tar"
+            | "tif"
+            | "tiff"
+            | "ttf"
+            | "war"
+            | "wasm"
+            | "webm"
+            | "webp"
+            | "woff"
+            | "woff2"
+            | "xls"
+            | "xlsx"
+            | "zip"
+    )
+}
+
+/// True when Git reported `patch` as binary or it carries NUL bytes.
+pub fn is_binary_patch(patch: &str) -> bool {
+    patch.contains('\0')
+        || patch.contains("\nGIT binary patch\n")
+        || patch
+            .lines()
+            .any(|line| line.starts_with("Binary files ") && line.ends_with(" differ"))
+}
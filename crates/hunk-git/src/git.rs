@@ -13,8 +13,11 @@ use gix::bstr::{BStr, ByteSlice as _};
 use gix::diff::blob::intern::InternedInput;
 use gix::filter::plumbing::pipeline::convert::ToGitOutcome;
 
+use crate::encoding::utf8_diff_bytes;
+use crate::error::GitError;
 use crate::git2_helpers::open_git2_repo;
 use crate::path::normalize_windows_path_prefix;
+use crate::path_collisions::{PathCollision, detect_path_collisions};
 use crate::worktree::{
     WorkspaceTargetKind, list_workspace_targets, repo_relative_path_is_within_managed_worktrees,
 };
@@ -125,6 +128,8 @@ pub struct WorkflowSnapshot {
     pub branch_behind_count: usize,
     pub branches: Vec<LocalBranch>,
     pub files: Vec<ChangedFile>,
+    /// Paths that collide when case is ignored. Only detected when `core.ignoreCase` is set.
+    pub path_collisions: Vec<PathCollision>,
     pub last_commit_subject: Option<String>,
 }
 
@@ -138,6 +143,7 @@ pub struct RepoSnapshotFingerprint {
     branch_behind_count: usize,
     changed_file_count: usize,
     changed_file_signature: u64,
+    file_signatures: BTreeMap<String, u64>,
 }
 
 impl RepoSnapshotFingerprint {
@@ -152,6 +158,44 @@ impl RepoSnapshotFingerprint {
     pub fn head_commit_id(&self) -> Option<&str> {
         self.head_commit_id.as_deref()
     }
+
+    /// Identifies the repository state behind this fingerprint, so cached results can be
+    /// checked against a later fingerprint after a restart.
+    pub fn state_id(&self) -> String {
+        format!(
+            "{}:{}:{:016x}",
+            self.head_commit_id.as_deref().unwrap_or("unborn"),
+            self.changed_file_count,
+            self.changed_file_signature
+        )
+    }
+
+    /// Changed files whose content differs from `previous`, including files that entered or
+    /// left the changed set. `None` when the repository or `HEAD` moved, since every file's
+    /// base may have changed with it.
+    pub fn changed_paths_since(&self, previous: &Self) -> Option<BTreeSet<String>> {
+        if self.root != previous.root
+            || self.head_ref_name != previous.head_ref_name
+            || self.head_commit_id != previous.head_commit_id
+        {
+            return None;
+        }
+
+        let mut paths = self
+            .file_signatures
+            .iter()
+            .filter(|(path, signature)| previous.file_signatures.get(*path) != Some(*signature))
+            .map(|(path, _)| path.clone())
+            .collect::<BTreeSet<_>>();
+        paths.extend(
+            previous
+                .file_signatures
+                .keys()
+                .filter(|path| !self.file_signatures.contains_key(*path))
+                .cloned(),
+        );
+        Some(paths)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -341,8 +385,11 @@ impl GitRepo {
 }
 
 pub fn discover_repo_root(path: &Path) -> Result<PathBuf> {
-    let repo = gix::discover(path)
-        .with_context(|| format!("failed to discover Git repository from {}", path.display()))?;
+    let repo = gix::discover(path).map_err(|err| {
+        anyhow::Error::new(err).context(GitError::NotARepository {
+            path: path.to_path_buf(),
+        })
+    })?;
     repo_root_from_repository(&repo)
 }
 
@@ -517,6 +564,22 @@ pub fn expand_selected_paths_for_renames(
     expand_selected_paths_for_renames_from_repo(&repo, selected_paths)
 }
 
+/// Renamed files in the working copy, staged or not, keyed by new path with the path they were
+/// renamed from.
+pub fn load_worktree_renames(repo_root: &Path) -> Result<BTreeMap<String, String>> {
+    let repo = open_repo(repo_root)?;
+    let candidates = collect_candidate_files(repo.repository(), repo.root(), None)?;
+    Ok(candidates
+        .into_iter()
+        .filter_map(|(path, candidate)| {
+            candidate
+                .rename_from
+                .filter(|rename_from| *rename_from != path)
+                .map(|rename_from| (path, rename_from))
+        })
+        .collect())
+}
+
 pub fn load_repo_line_stats(path: &Path) -> Result<LineStats> {
     let stats = load_repo_file_line_stats(path)?;
     Ok(sum_line_stats(stats.into_values()))
@@ -551,6 +614,9 @@ pub fn load_repo_file_line_stats_for_paths_without_refresh(
 
 pub fn load_repo_tree(repo_root: &Path) -> Result<Vec<RepoTreeEntry>> {
     let repo = open_repo_at_root(repo_root)?;
+    if repo.repository().is_bare() {
+        return Ok(Vec::new());
+    }
     let visible_paths = load_visible_repo_paths(repo.repository(), repo.root())?;
     let mut entries = Vec::new();
     walk_repo_tree(repo.root(), repo.root(), &visible_paths, &mut entries)?;
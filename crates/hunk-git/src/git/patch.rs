@@ -341,6 +341,8 @@ fn line_stats_from_file_states(
     let Some((old_bytes, new_bytes)) = diffable_bytes(old_state, new_state) else {
         return LineStats::default();
     };
+    let (old_bytes, new_bytes) = (utf8_diff_bytes(old_bytes), utf8_diff_bytes(new_bytes));
+    let (old_bytes, new_bytes) = (old_bytes.as_ref(), new_bytes.as_ref());
     if is_binary(old_bytes) || is_binary(new_bytes) {
         return LineStats::default();
     }
@@ -441,6 +443,8 @@ fn render_patch_for_resolved_file(file: &ResolvedWorkspaceFile) -> Result<String
     if let Some((old_bytes, new_bytes)) =
         patchable_bytes(file.old_state.as_ref(), file.new_state.as_ref())
     {
+        let (old_bytes, new_bytes) = (utf8_diff_bytes(old_bytes), utf8_diff_bytes(new_bytes));
+        let (old_bytes, new_bytes) = (old_bytes.as_ref(), new_bytes.as_ref());
         if is_binary(old_bytes) || is_binary(new_bytes) {
             patch.push_str(&format!(
                 "Binary files {old_label} and {new_label} differ\n"
@@ -449,17 +453,7 @@ fn render_patch_for_resolved_file(file: &ResolvedWorkspaceFile) -> Result<String
         }
 
         patch.push_str(&format!("--- {old_label}\n+++ {new_label}\n"));
-        let input = InternedInput::new(old_bytes, new_bytes);
-        let unified = gix::diff::blob::diff(
-            gix::diff::blob::Algorithm::Histogram,
-            &input,
-            gix::diff::blob::UnifiedDiff::new(
-                &input,
-                gix::diff::blob::unified_diff::ConsumeBinaryHunk::new(String::new(), "\n"),
-                gix::diff::blob::unified_diff::ContextSize::default(),
-            ),
-        )?;
-        patch.push_str(&unified);
+        patch.push_str(&render_unified_hunks(old_bytes, new_bytes)?);
         return Ok(patch);
     }
 
@@ -469,6 +463,65 @@ fn render_patch_for_resolved_file(file: &ResolvedWorkspaceFile) -> Result<String
     Ok(patch)
 }
 
+/// The `@@` hunks between two texts, rendered the same way for every patch Hunk shows.
+pub(crate) fn render_unified_hunks(old_bytes: &[u8], new_bytes: &[u8]) -> Result<String> {
+    let input = InternedInput::new(old_bytes, new_bytes);
+    Ok(gix::diff::blob::diff(
+        gix::diff::blob::Algorithm::Histogram,
+        &input,
+        gix::diff::blob::UnifiedDiff::new(
+            &input,
+            gix::diff::blob::unified_diff::ConsumeBinaryHunk::new(String::new(), "\n"),
+            gix::diff::blob::unified_diff::ContextSize::default(),
+        ),
+    )?)
+}
+
+/// The index and worktree copies of one file in Git form, for staging part of its changes.
+/// A missing side is `None`.
+/// One file's bytes as committed in HEAD, as staged in the index and as found in the worktree.
+pub(crate) struct WorkingCopyFileBytes {
+    pub(crate) head: Option<Vec<u8>>,
+    pub(crate) staged: Option<Vec<u8>>,
+    pub(crate) worktree: Option<Vec<u8>>,
+}
+
+pub(crate) fn load_working_copy_file_bytes(
+    repo_root: &Path,
+    path: &str,
+) -> Result<WorkingCopyFileBytes> {
+    let repo = open_repo(repo_root)?;
+    let head_tree = repo
+        .repository()
+        .head_commit()
+        .ok()
+        .and_then(|commit| commit.tree().ok());
+    let (mut filter_pipeline, index_storage) = repo.repository().filter_pipeline(None)?;
+    let index = index_state(&index_storage);
+    let head = head_file_state(repo.repository(), head_tree.as_ref(), path)?;
+    let staged = index_file_state(repo.repository(), index, path)?;
+    let worktree = worktree_file_state(
+        repo.repository(),
+        repo.root(),
+        &mut filter_pipeline,
+        index,
+        path,
+    )?;
+    for state in [head.as_ref(), staged.as_ref(), worktree.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        if file_kind_class(state.kind) != FileKindClass::Regular {
+            return Err(anyhow!("{path} is not a regular file"));
+        }
+    }
+    Ok(WorkingCopyFileBytes {
+        head: head.and_then(|state| state.bytes),
+        staged: staged.and_then(|state| state.bytes),
+        worktree: worktree.and_then(|state| state.bytes),
+    })
+}
+
 fn patch_side_label(prefix: &str, path: &str, present: bool) -> String {
     if present {
         format!("{prefix}/{path}")
@@ -36,6 +36,8 @@ fn load_workflow_snapshot_internal(
     );
     let working_copy_commit_id =
         synthetic_working_copy_id(seed.head_commit_id.as_deref(), seed.entries.values());
+    let files = snapshot_files(seed.entries.values());
+    let path_collisions = snapshot_path_collisions(&repo, files.as_slice())?;
     let workflow = WorkflowSnapshot {
         root: seed.root,
         working_copy_commit_id,
@@ -44,7 +46,8 @@ fn load_workflow_snapshot_internal(
         branch_ahead_count: seed.branch_ahead_count,
         branch_behind_count: seed.branch_behind_count,
         branches: seed.branches,
-        files: snapshot_files(seed.entries.values()),
+        files,
+        path_collisions,
         last_commit_subject: seed.last_commit_subject,
     };
     Ok((fingerprint, workflow))
@@ -100,7 +103,9 @@ fn load_snapshot_seed(
         head_ref_name.as_deref(),
         &branch_workspace_occupancy,
     )?;
+    // A bare repository has no working copy, so there is nothing to compare against HEAD.
     let entries = match mode {
+        _ if repo.repository().is_bare() => BTreeMap::new(),
         SnapshotLoadMode::ReadOnlyLight => {
             collect_workspace_diff_entries_light(repo.repository(), repo.root(), None)?
         }
@@ -127,6 +132,29 @@ fn load_snapshot_seed(
     })
 }
 
+/// Case collisions only corrupt checkouts on case-insensitive filesystems, which Git records as
+/// `core.ignoreCase` when the repository is created.
+fn snapshot_path_collisions(repo: &GitRepo, files: &[ChangedFile]) -> Result<Vec<PathCollision>> {
+    let ignore_case = repo
+        .repository()
+        .config_snapshot()
+        .boolean("core.ignoreCase")
+        .unwrap_or(false);
+    if !ignore_case {
+        return Ok(Vec::new());
+    }
+
+    let index = repo.repository().index_or_empty()?;
+    let tracked_paths = index
+        .entries_with_paths_by_filter_map(|_path, _| Some(()))
+        .map(|(path, ())| normalize_bstr_path(path))
+        .collect::<Vec<_>>();
+    Ok(detect_path_collisions(
+        tracked_paths.iter().map(String::as_str),
+        files,
+    ))
+}
+
 fn snapshot_files<'a>(
     entries: impl IntoIterator<Item = &'a WorkspaceDiffEntry>,
 ) -> Vec<ChangedFile> {
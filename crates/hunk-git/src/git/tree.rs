@@ -1,7 +1,14 @@
 fn load_visible_repo_paths(repo: &gix::Repository, root: &Path) -> Result<BTreeSet<String>> {
+    if repo.is_bare() {
+        return Ok(BTreeSet::new());
+    }
     let index = repo.index_or_empty()?;
     let mut paths = BTreeSet::new();
-    for (path, ()) in index.entries_with_paths_by_filter_map(|_path, _| Some(())) {
+    // Sparse checkout leaves skip-worktree entries out of the working tree; don't list them.
+    let entries = index.entries_with_paths_by_filter_map(|_path, entry| {
+        (!entry.flags.contains(gix::index::entry::Flags::SKIP_WORKTREE)).then_some(())
+    });
+    for (path, ()) in entries {
         let path = normalize_bstr_path(path);
         if !path.is_empty()
             && !repo_relative_path_is_within_managed_worktrees(path.as_str())
@@ -188,6 +195,10 @@ fn snapshot_fingerprint(
         branch_behind_count,
         changed_file_count: entries.len(),
         changed_file_signature: hash_changed_entries(entries.values()),
+        file_signatures: entries
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.content_signature))
+            .collect(),
     }
 }
 
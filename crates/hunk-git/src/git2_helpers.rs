@@ -3,9 +3,21 @@ use std::path::Path;
 use anyhow::{Context as _, Result};
 use git2::{Repository, StatusOptions, Statuses};
 
+use crate::error::GitError;
+
 pub(crate) fn open_git2_repo(repo_root: &Path) -> Result<Repository> {
-    Repository::open(repo_root)
-        .with_context(|| format!("failed to open Git repository at {}", repo_root.display()))
+    Repository::open(repo_root).map_err(|err| {
+        if err.code() == git2::ErrorCode::NotFound {
+            anyhow::Error::new(err).context(GitError::NotARepository {
+                path: repo_root.to_path_buf(),
+            })
+        } else {
+            anyhow::Error::new(err).context(format!(
+                "failed to open Git repository at {}",
+                repo_root.display()
+            ))
+        }
+    })
 }
 
 pub(crate) fn standard_status_options() -> StatusOptions {
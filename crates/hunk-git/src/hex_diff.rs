@@ -0,0 +1,45 @@
+use std::fmt::Write as _;
+
+pub const HEX_DUMP_BYTES_PER_LINE: usize = 16;
+
+/// Extended header line marking a patch whose hunks diff hex dumps instead of file text.
+pub const HEX_DUMP_PATCH_HEADER: &str = "hex dump of binary content";
+
+/// Renders `bytes` as `hexdump -C` style lines: offset, sixteen hex bytes and their printable
+/// ASCII. Keeping one fixed-width line per sixteen bytes lets a line diff pair up the rows that
+/// changed, and the intraline highlight then marks the changed bytes.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    let mut dump = String::with_capacity(bytes.len().div_ceil(HEX_DUMP_BYTES_PER_LINE) * 79);
+    for (line_ix, chunk) in bytes.chunks(HEX_DUMP_BYTES_PER_LINE).enumerate() {
+        let _ = write!(dump, "{:08x} ", line_ix * HEX_DUMP_BYTES_PER_LINE);
+        for column in 0..HEX_DUMP_BYTES_PER_LINE {
+            if column % 8 == 0 {
+                dump.push(' ');
+            }
+            match chunk.get(column) {
+                Some(byte) => {
+                    let _ = write!(dump, "{byte:02x} ");
+                }
+                None => dump.push_str("   "),
+            }
+        }
+        dump.push_str(" |");
+        dump.extend(chunk.iter().map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                char::from(*byte)
+            } else {
+                '.'
+            }
+        }));
+        dump.push_str("|\n");
+    }
+    dump
+}
+
+/// True when `patch` was rendered from hex dumps by the compare loader.
+pub fn is_hex_dump_patch(patch: &str) -> bool {
+    patch
+        .lines()
+        .take_while(|line| !line.starts_with("@@"))
+        .any(|line| line == HEX_DUMP_PATCH_HEADER)
+}
@@ -1,11 +1,21 @@
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context as _, Result};
 use gix::traverse::commit::simple::CommitTimeOrder;
 
-use crate::git::open_repo;
+use crate::commit_message::{CHANGE_ID_TRAILER, StructuredCommitMessage};
+use crate::git::{FileStatus, LineStats, open_repo};
+use crate::git2_helpers::open_git2_repo;
 
 pub const DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT: usize = 15;
+/// Most commits [`load_file_authorship`] lists for one file.
+pub const FILE_HISTORY_COMMIT_LIMIT: usize = 20;
+/// Upper bound on commits inspected for one file, so very old files in large repositories stay
+/// cheap. Commits past the bound are not counted.
+const FILE_HISTORY_WALK_LIMIT: usize = 5_000;
+/// Patch text [`load_commit_details`] keeps per file; longer patches are cut at a line break.
+pub const COMMIT_DETAIL_PATCH_BYTE_LIMIT: usize = 256 * 1024;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RecentCommitSummary {
@@ -14,6 +24,75 @@ pub struct RecentCommitSummary {
     pub committed_unix_time: Option<i64>,
 }
 
+/// Full message of a commit on the checked out branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchCommitMessage {
+    pub commit_id: String,
+    pub message: String,
+}
+
+/// A file changed by a commit, relative to its first parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitChangedFile {
+    pub path: String,
+    pub status: FileStatus,
+    pub line_stats: LineStats,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitPerson {
+    pub name: String,
+    pub email: String,
+    pub unix_time: i64,
+}
+
+/// A file changed by a commit, with its patch against the first parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitFilePatch {
+    pub file: CommitChangedFile,
+    /// The hunks of the unified diff, without file headers. Empty for binary files.
+    pub patch: String,
+    pub truncated: bool,
+}
+
+/// Everything the commit inspector shows about one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitDetails {
+    pub commit_id: String,
+    pub message: String,
+    pub author: CommitPerson,
+    pub committer: CommitPerson,
+    /// The value of the `Change-Id` trailer, when the message has one.
+    pub change_id: Option<String>,
+    pub parent_ids: Vec<String>,
+    pub files: Vec<CommitFilePatch>,
+}
+
+/// A commit that changed one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileHistoryCommit {
+    pub commit_id: String,
+    pub subject: String,
+    pub author_name: String,
+    pub committed_unix_time: i64,
+}
+
+/// Who has changed a file: its newest commits from HEAD and how many distinct authors touched it
+/// since a cutoff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileAuthorship {
+    pub path: String,
+    /// Newest first, at most [`FILE_HISTORY_COMMIT_LIMIT`].
+    pub commits: Vec<FileHistoryCommit>,
+    pub recent_contributor_count: usize,
+}
+
+impl FileAuthorship {
+    pub fn last_commit(&self) -> Option<&FileHistoryCommit> {
+        self.commits.first()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RecentCommitsSnapshot {
     pub root: PathBuf,
@@ -97,6 +176,242 @@ pub fn load_recent_authored_commits_if_changed(
     ))
 }
 
+/// Messages of up to `limit` commits on the checked out branch that are not on its base
+/// branch, newest first.
+pub fn load_branch_commit_messages(path: &Path, limit: usize) -> Result<Vec<BranchCommitMessage>> {
+    let (repo, tip_id, base_tip_id, _) = recent_commits_context(path, limit)?;
+    if tip_id.is_null() || limit == 0 {
+        return Ok(Vec::new());
+    }
+    walk_branch_commits(
+        repo.repository(),
+        tip_id,
+        base_tip_id,
+        limit,
+        |info, commit| BranchCommitMessage {
+            commit_id: info.id.to_string(),
+            message: String::from_utf8_lossy(commit.message_raw_sloppy().as_ref()).into_owned(),
+        },
+    )
+}
+
+/// Files changed by `commit_id` against its first parent, or against an empty tree for a root
+/// commit. Renames are detected, and binary files report no line stats.
+pub fn load_commit_changed_files(path: &Path, commit_id: &str) -> Result<Vec<CommitChangedFile>> {
+    let repo = open_git2_repo(path)?;
+    let commit = find_commit(&repo, commit_id)?;
+    let diff = commit_diff(&repo, &commit)?;
+    Ok(commit_file_patches(&diff, false)?
+        .into_iter()
+        .map(|patch| patch.file)
+        .collect())
+}
+
+/// The metadata of `commit_id` and the patch of every file it changed, diffed the same way as
+/// [`load_commit_changed_files`].
+pub fn load_commit_details(path: &Path, commit_id: &str) -> Result<CommitDetails> {
+    let repo = open_git2_repo(path)?;
+    let commit = find_commit(&repo, commit_id)?;
+    let diff = commit_diff(&repo, &commit)?;
+    let message = String::from_utf8_lossy(commit.message_bytes()).into_owned();
+    let change_id = StructuredCommitMessage::parse(message.as_str())
+        .trailers
+        .into_iter()
+        .find(|trailer| trailer.key.eq_ignore_ascii_case(CHANGE_ID_TRAILER))
+        .map(|trailer| trailer.value.trim().to_string());
+    let person = |signature: git2::Signature<'_>| CommitPerson {
+        name: String::from_utf8_lossy(signature.name_bytes()).into_owned(),
+        email: String::from_utf8_lossy(signature.email_bytes()).into_owned(),
+        unix_time: signature.when().seconds(),
+    };
+
+    Ok(CommitDetails {
+        commit_id: commit.id().to_string(),
+        message,
+        author: person(commit.author()),
+        committer: person(commit.committer()),
+        change_id,
+        parent_ids: commit.parent_ids().map(|oid| oid.to_string()).collect(),
+        files: commit_file_patches(&diff, true)?,
+    })
+}
+
+fn find_commit<'repo>(
+    repo: &'repo git2::Repository,
+    commit_id: &str,
+) -> Result<git2::Commit<'repo>> {
+    let oid =
+        git2::Oid::from_str(commit_id).with_context(|| format!("invalid commit id {commit_id}"))?;
+    repo.find_commit(oid)
+        .with_context(|| format!("commit {commit_id} is not in the repository"))
+}
+
+fn commit_diff<'repo>(
+    repo: &'repo git2::Repository,
+    commit: &git2::Commit<'_>,
+) -> Result<git2::Diff<'repo>> {
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent_count() {
+        0 => None,
+        _ => Some(commit.parent(0)?.tree()?),
+    };
+    let mut diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .with_context(|| format!("failed to diff commit {}", commit.id()))?;
+    diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))
+        .context("failed to detect renames")?;
+    Ok(diff)
+}
+
+fn commit_file_patches(
+    diff: &git2::Diff<'_>,
+    with_patch_text: bool,
+) -> Result<Vec<CommitFilePatch>> {
+    let mut files = Vec::with_capacity(diff.deltas().len());
+    for (index, delta) in diff.deltas().enumerate() {
+        let Some(file_path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+            continue;
+        };
+        let status = match delta.status() {
+            git2::Delta::Added => FileStatus::Added,
+            git2::Delta::Deleted => FileStatus::Deleted,
+            git2::Delta::Modified => FileStatus::Modified,
+            git2::Delta::Renamed | git2::Delta::Copied => FileStatus::Renamed,
+            git2::Delta::Typechange => FileStatus::TypeChange,
+            _ => FileStatus::Unknown,
+        };
+        let mut line_stats = LineStats::default();
+        let mut patch_text = String::new();
+        let mut truncated = false;
+        if let Some(mut patch) = git2::Patch::from_diff(diff, index)? {
+            let (_, added, removed) = patch.line_stats()?;
+            line_stats = LineStats {
+                added: added as u64,
+                removed: removed as u64,
+            };
+            if with_patch_text {
+                let buf = patch.to_buf()?;
+                let text = String::from_utf8_lossy(&buf);
+                (patch_text, truncated) = patch_hunks(text.as_ref());
+            }
+        }
+        files.push(CommitFilePatch {
+            file: CommitChangedFile {
+                path: file_path.to_string_lossy().replace('\\', "/"),
+                status,
+                line_stats,
+            },
+            patch: patch_text,
+            truncated,
+        });
+    }
+    Ok(files)
+}
+
+/// Drops the file headers in front of the first hunk and cuts long patches at a line break.
+fn patch_hunks(patch: &str) -> (String, bool) {
+    let start = if patch.starts_with("@@") {
+        0
+    } else {
+        patch.find("\n@@").map_or(patch.len(), |ix| ix + 1)
+    };
+    let hunks = &patch[start..];
+    if hunks.len() <= COMMIT_DETAIL_PATCH_BYTE_LIMIT {
+        return (hunks.to_string(), false);
+    }
+    let mut end = COMMIT_DETAIL_PATCH_BYTE_LIMIT;
+    while !hunks.is_char_boundary(end) {
+        end -= 1;
+    }
+    let end = hunks[..end].rfind('\n').map_or(end, |ix| ix + 1);
+    (hunks[..end].to_string(), true)
+}
+
+/// History of `file_path` reachable from HEAD. Authors are told apart by email, and only commits
+/// at or after `since_unix_time` count towards `recent_contributor_count`. Renames are not
+/// followed.
+pub fn load_file_authorship(
+    path: &Path,
+    file_path: &str,
+    since_unix_time: i64,
+) -> Result<FileAuthorship> {
+    let repo = open_git2_repo(path)?;
+    let mut authorship = FileAuthorship {
+        path: file_path.to_string(),
+        commits: Vec::new(),
+        recent_contributor_count: 0,
+    };
+    let head_id = match repo.head() {
+        Ok(head) => match head.target() {
+            Some(id) => id,
+            None => return Ok(authorship),
+        },
+        Err(err) if err.code() == git2::ErrorCode::UnbornBranch => return Ok(authorship),
+        Err(err) => return Err(err).context("failed to resolve HEAD for file history"),
+    };
+
+    let mut walk = repo
+        .revwalk()
+        .context("failed to start file history traversal")?;
+    walk.set_sorting(git2::Sort::TIME)?;
+    walk.push(head_id)?;
+    let file = Path::new(file_path);
+    let mut recent_authors = BTreeSet::new();
+
+    for oid in walk.take(FILE_HISTORY_WALK_LIMIT) {
+        let commit = repo.find_commit(oid?)?;
+        let committed_unix_time = commit.time().seconds();
+        if authorship.commits.len() >= FILE_HISTORY_COMMIT_LIMIT
+            && committed_unix_time < since_unix_time
+        {
+            break;
+        }
+        if !commit_changes_path(&commit, file)? {
+            continue;
+        }
+
+        let author = commit.author();
+        if committed_unix_time >= since_unix_time {
+            let key = author
+                .email()
+                .or_else(|| author.name())
+                .unwrap_or_default()
+                .to_lowercase();
+            recent_authors.insert(key);
+        }
+        if authorship.commits.len() < FILE_HISTORY_COMMIT_LIMIT {
+            authorship.commits.push(FileHistoryCommit {
+                commit_id: commit.id().to_string(),
+                subject: commit
+                    .summary()
+                    .filter(|subject| !subject.trim().is_empty())
+                    .unwrap_or("(no subject)")
+                    .to_string(),
+                author_name: author.name().unwrap_or("unknown").to_string(),
+                committed_unix_time,
+            });
+        }
+    }
+
+    authorship.recent_contributor_count = recent_authors.len();
+    Ok(authorship)
+}
+
+/// Like `git log -- <path>`: a merge only counts when the file differs from every parent.
+fn commit_changes_path(commit: &git2::Commit<'_>, file: &Path) -> Result<bool> {
+    let entry_id = |tree: &git2::Tree<'_>| tree.get_path(file).ok().map(|entry| entry.id());
+    let current = entry_id(&commit.tree()?);
+    if commit.parent_count() == 0 {
+        return Ok(current.is_some());
+    }
+    for parent in commit.parents() {
+        if entry_id(&parent.tree()?) == current {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 fn recent_commits_context(
     path: &Path,
     limit: usize,
@@ -157,6 +472,22 @@ fn collect_recent_authored_commits(
     base_tip_id: Option<gix::ObjectId>,
     limit: usize,
 ) -> Result<Vec<RecentCommitSummary>> {
+    walk_branch_commits(repo, tip_id, base_tip_id, limit, |info, commit| {
+        RecentCommitSummary {
+            commit_id: info.id.to_string(),
+            subject: commit_subject(commit),
+            committed_unix_time: Some(info.commit_time()),
+        }
+    })
+}
+
+fn walk_branch_commits<T>(
+    repo: &gix::Repository,
+    tip_id: gix::ObjectId,
+    base_tip_id: Option<gix::ObjectId>,
+    limit: usize,
+    mut map_commit: impl FnMut(&gix::revision::walk::Info<'_>, &gix::Commit<'_>) -> T,
+) -> Result<Vec<T>> {
     let walk_builder = repo.rev_walk([tip_id]);
     let walk_builder = if let Some(base_tip_id) = base_tip_id {
         walk_builder.with_hidden([base_tip_id])
@@ -177,11 +508,7 @@ fn collect_recent_authored_commits(
             .object()
             .with_context(|| format!("failed to load commit {}", info.id))?;
 
-        commits.push(RecentCommitSummary {
-            commit_id: info.id.to_string(),
-            subject: commit_subject(&commit),
-            committed_unix_time: Some(info.commit_time()),
-        });
+        commits.push(map_commit(&info, &commit));
         if commits.len() >= limit {
             break;
         }
@@ -0,0 +1,581 @@
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{BranchType, FileMode, IndexEntry, IndexTime, Repository};
+use hunk_domain::diff::{DiffHunk, DiffLineKind, parse_patch_document};
+
+use crate::branch::is_valid_branch_name;
+use crate::compare::resolve_default_base_branch_name;
+use crate::error::GitError;
+use crate::git::{FileStatus, load_patch, load_working_copy_file_bytes, render_unified_hunks};
+use crate::git2_helpers::open_git2_repo;
+use crate::mutation::CreatedCommit;
+use crate::signing::write_signed_commit_as;
+use crate::worktree::list_workspace_targets;
+
+const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HunkApplyMode {
+    /// Add a new commit on top of the branch.
+    NewCommit,
+    /// Fold the hunk into the branch's tip commit.
+    AmendTip,
+}
+
+/// Picks out one hunk by the lines it removes and adds. The header only breaks ties between
+/// hunks that make the same change, since its line numbers shift as the file is edited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HunkSelector {
+    pub header: String,
+    pub removed: Vec<String>,
+    pub added: Vec<String>,
+}
+
+impl HunkSelector {
+    pub fn from_hunk(hunk: &DiffHunk) -> Self {
+        Self {
+            header: hunk.header.clone(),
+            removed: changed_lines(hunk, DiffLineKind::Removed)
+                .map(str::to_string)
+                .collect(),
+            added: changed_lines(hunk, DiffLineKind::Added)
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// The same hunk as it appears in a diff taken in the opposite direction.
+    fn reversed(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            removed: self.added.clone(),
+            added: self.removed.clone(),
+        }
+    }
+
+    fn matches(&self, hunk: &DiffHunk) -> bool {
+        changed_lines(hunk, DiffLineKind::Removed).eq(self.removed.iter().map(String::as_str))
+            && changed_lines(hunk, DiffLineKind::Added).eq(self.added.iter().map(String::as_str))
+    }
+}
+
+/// One working-copy hunk to copy onto another branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HunkApplyRequest {
+    pub path: String,
+    pub hunk: HunkSelector,
+    pub branch_name: String,
+    /// Required for a new commit. When amending, an empty message keeps the tip's message.
+    pub message: String,
+    pub mode: HunkApplyMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedHunk {
+    pub branch_name: String,
+    /// The branch did not exist and was started from the fork point of the current branch.
+    pub created_branch: bool,
+    pub commit: CreatedCommit,
+}
+
+/// Commits a single working-copy hunk onto another branch without touching the worktree, the
+/// index or HEAD.
+///
+/// The hunk is applied to the branch tip's copy of the file wherever its context matches, so it
+/// still lands when the branch has drifted. A missing branch is created at the merge base of HEAD
+/// and the default base branch. Amending keeps the tip's author.
+pub fn apply_hunk_to_branch(repo_root: &Path, request: &HunkApplyRequest) -> Result<AppliedHunk> {
+    let branch_name = request.branch_name.trim();
+    if branch_name.is_empty() {
+        return Err(anyhow!("branch name cannot be empty"));
+    }
+    if !is_valid_branch_name(branch_name) {
+        return Err(anyhow!("invalid branch name: {branch_name}"));
+    }
+    let message = request.message.trim();
+    if request.mode == HunkApplyMode::NewCommit && message.is_empty() {
+        return Err(anyhow!("commit message cannot be empty"));
+    }
+
+    let repo = open_git2_repo(repo_root)?;
+    if let Ok(head) = repo.head()
+        && head.is_branch()
+        && head.shorthand() == Some(branch_name)
+    {
+        return Err(anyhow!(
+            "branch '{branch_name}' is checked out; commit the hunk there directly"
+        ));
+    }
+    // Moving the ref of a branch another worktree has checked out would leave that worktree's
+    // files and index describing the old tip.
+    if let Some(target) = list_workspace_targets(repo_root)?
+        .into_iter()
+        .find(|target| !target.is_active && target.branch_name == branch_name)
+    {
+        return Err(anyhow!(
+            "branch '{branch_name}' is checked out in {}; commit the hunk there directly",
+            target.root.display()
+        ));
+    }
+
+    let hunk = working_copy_hunk(repo_root, request.path.as_str(), &request.hunk)?;
+
+    let existing_tip = match repo.find_branch(branch_name, BranchType::Local) {
+        Ok(branch) => Some(
+            branch
+                .get()
+                .peel_to_commit()
+                .with_context(|| format!("failed to resolve branch '{branch_name}'"))?,
+        ),
+        Err(err) if err.code() == git2::ErrorCode::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    if request.mode == HunkApplyMode::AmendTip && existing_tip.is_none() {
+        return Err(anyhow!(
+            "branch '{branch_name}' does not exist, so there is no commit to amend"
+        ));
+    }
+    let base = match existing_tip.as_ref() {
+        Some(tip) => tip.clone(),
+        None => new_branch_base(repo_root, &repo)?,
+    };
+
+    let base_tree = base.tree()?;
+    let tree_id = apply_hunk_to_tree(&repo, &base_tree, request.path.as_str(), &hunk)
+        .with_context(|| format!("hunk does not apply to {} on '{branch_name}'", request.path))?;
+    if tree_id == base_tree.id() {
+        return Err(anyhow!("branch '{branch_name}' already contains this hunk"));
+    }
+    let tree = repo.find_tree(tree_id)?;
+
+    let (message, parents) = match request.mode {
+        HunkApplyMode::NewCommit => (message.to_string(), vec![base.clone()]),
+        HunkApplyMode::AmendTip => {
+            let message = if message.is_empty() {
+                base.message().unwrap_or_default().to_string()
+            } else {
+                message.to_string()
+            };
+            (message, base.parents().collect::<Vec<_>>())
+        }
+    };
+    let parent_refs = parents.iter().collect::<Vec<_>>();
+    let signature = repo
+        .signature()
+        .map_err(|err| anyhow::Error::new(err).context(GitError::MissingIdentity))?;
+    let author = match request.mode {
+        HunkApplyMode::NewCommit => signature.clone(),
+        HunkApplyMode::AmendTip => base.author().to_owned(),
+    };
+    let signing = repo.config()?.get_bool("commit.gpgSign").unwrap_or(false);
+    let commit_id = if signing {
+        write_signed_commit_as(
+            &repo,
+            &author,
+            &signature,
+            message.as_str(),
+            &tree,
+            &parent_refs,
+        )?
+    } else {
+        repo.commit(
+            None,
+            &author,
+            &signature,
+            message.as_str(),
+            &tree,
+            parent_refs.as_slice(),
+        )
+        .context("failed to create hunk commit")?
+    };
+
+    let reflog_message = match request.mode {
+        HunkApplyMode::NewCommit => format!("hunk: apply to {branch_name}"),
+        HunkApplyMode::AmendTip => format!("hunk: amend {branch_name}"),
+    };
+    repo.reference(
+        format!("refs/heads/{branch_name}").as_str(),
+        commit_id,
+        true,
+        reflog_message.as_str(),
+    )
+    .with_context(|| format!("failed to update branch '{branch_name}'"))?;
+
+    let commit = repo.find_commit(commit_id)?;
+    Ok(AppliedHunk {
+        branch_name: branch_name.to_string(),
+        created_branch: existing_tip.is_none(),
+        commit: CreatedCommit {
+            commit_id: commit_id.to_string(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+            committed_unix_time: Some(commit.time().seconds()),
+        },
+    })
+}
+
+/// Whether a working-copy hunk is in the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HunkStageState {
+    Staged,
+    Unstaged,
+}
+
+/// Looks each working-copy hunk of `path` up in the diff between HEAD and the index and in the
+/// diff between the index and the worktree. A hunk that is only partly staged matches neither
+/// and has no state.
+pub fn load_hunk_stage_states(
+    repo_root: &Path,
+    path: &str,
+    hunks: &[HunkSelector],
+) -> Result<Vec<Option<HunkStageState>>> {
+    let files = load_working_copy_file_bytes(repo_root, path)?;
+    let head = file_text(files.head.as_deref(), path)?;
+    let staged = file_text(files.staged.as_deref(), path)?;
+    let worktree = file_text(files.worktree.as_deref(), path)?;
+    let staged_hunks = text_hunks(head, staged)?;
+    let unstaged_hunks = text_hunks(staged, worktree)?;
+    Ok(hunks
+        .iter()
+        .map(|hunk| {
+            if staged_hunks.iter().any(|candidate| hunk.matches(candidate)) {
+                Some(HunkStageState::Staged)
+            } else if unstaged_hunks
+                .iter()
+                .any(|candidate| hunk.matches(candidate))
+            {
+                Some(HunkStageState::Unstaged)
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+/// Stages a single working-copy hunk into the index, leaving the rest of the file's changes
+/// unstaged so the regular staged commit only picks up the selected hunks.
+///
+/// The hunk is looked up again in the diff between the index and the worktree and its worktree
+/// lines are copied over the matching index lines, so hunks staged earlier are kept and a hunk
+/// that is already staged is refused instead of being applied twice.
+pub fn stage_hunk(repo_root: &Path, path: &str, hunk: &HunkSelector) -> Result<()> {
+    let files = load_working_copy_file_bytes(repo_root, path)?;
+    let staged = file_text(files.staged.as_deref(), path)?;
+    let worktree = file_text(files.worktree.as_deref(), path)?;
+    let Some(unstaged_hunk) = find_hunk(text_hunks(staged, worktree)?, hunk)? else {
+        if working_copy_hunk(repo_root, path, hunk).is_ok() {
+            return Err(anyhow!("the hunk is already staged in {path}"));
+        }
+        return Err(no_longer_in_working_copy(path, hunk));
+    };
+
+    let updated = splice_hunk(staged, worktree, &unstaged_hunk, path)?;
+    write_index_file(repo_root, path, updated.as_str(), files.worktree.is_none())
+}
+
+/// Takes a single hunk back out of the index, the reverse of [`stage_hunk`]. The worktree is left
+/// alone, so the hunk shows up as unstaged again.
+///
+/// The index lines of the hunk are replaced with HEAD's copy of them, so other staged hunks of
+/// the file stay staged.
+pub fn unstage_hunk(repo_root: &Path, path: &str, hunk: &HunkSelector) -> Result<()> {
+    let files = load_working_copy_file_bytes(repo_root, path)?;
+    let head = file_text(files.head.as_deref(), path)?;
+    let staged = file_text(files.staged.as_deref(), path)?;
+    let Some(staged_hunk) = find_hunk(text_hunks(staged, head)?, &hunk.reversed())? else {
+        return Err(anyhow!(
+            "hunk '{}' is not staged in {path}",
+            hunk.header.trim()
+        ));
+    };
+
+    let updated = splice_hunk(staged, head, &staged_hunk, path)?;
+    write_index_file(repo_root, path, updated.as_str(), files.head.is_none())
+}
+
+fn text_hunks(old: &str, new: &str) -> Result<Vec<DiffHunk>> {
+    let patch = render_unified_hunks(old.as_bytes(), new.as_bytes())?;
+    Ok(parse_patch_document(patch.as_str()).hunks)
+}
+
+/// Copies the new side of a hunk from `new` over its old side in `old`, the staged copy of the
+/// file both when staging and when unstaging.
+fn splice_hunk(old: &str, new: &str, hunk: &DiffHunk, path: &str) -> Result<String> {
+    let old_file_lines = old.split_inclusive('\n').collect::<Vec<_>>();
+    let new_file_lines = new.split_inclusive('\n').collect::<Vec<_>>();
+    let old_lines = hunk
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::Added)
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>();
+    let new_len = hunk
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::Removed)
+        .count();
+    let old_start = hunk_start_index(hunk.old_start, old_lines.len());
+    let new_start = hunk_start_index(hunk.new_start, new_len);
+    let old_end = old_start + old_lines.len();
+    let new_end = new_start + new_len;
+    if old_end > old_file_lines.len()
+        || new_end > new_file_lines.len()
+        || !old_file_lines[old_start..old_end]
+            .iter()
+            .map(|line| line_text(line))
+            .eq(old_lines.iter().copied())
+    {
+        return Err(anyhow!(
+            "hunk does not line up with the staged copy of {path}"
+        ));
+    }
+
+    let mut updated = old_file_lines[..old_start].concat();
+    updated.push_str(new_file_lines[new_start..new_end].concat().as_str());
+    updated.push_str(old_file_lines[old_end..].concat().as_str());
+    Ok(updated)
+}
+
+/// Writes the staged copy of `path`. An empty copy of a file that `missing` says no longer
+/// exists drops the entry instead.
+fn write_index_file(repo_root: &Path, path: &str, content: &str, missing: bool) -> Result<()> {
+    let repo = open_git2_repo(repo_root)?;
+    let mut index = repo.index().context("failed to open index")?;
+    if missing && content.is_empty() {
+        index.remove_path(Path::new(path))?;
+    } else {
+        let entry = index
+            .get_path(Path::new(path), 0)
+            .unwrap_or_else(|| new_index_entry(path));
+        index
+            .add_frombuffer(&entry, content.as_bytes())
+            .with_context(|| format!("failed to update the index entry of {path}"))?;
+    }
+    index.write().context("failed to write index")
+}
+
+fn working_copy_hunk(repo_root: &Path, path: &str, hunk: &HunkSelector) -> Result<DiffHunk> {
+    let patch = load_patch(repo_root, path, FileStatus::Modified)?;
+    find_hunk(parse_patch_document(patch.as_str()).hunks, hunk)?
+        .ok_or_else(|| no_longer_in_working_copy(path, hunk))
+}
+
+fn find_hunk(hunks: Vec<DiffHunk>, selector: &HunkSelector) -> Result<Option<DiffHunk>> {
+    let mut matches = hunks
+        .into_iter()
+        .filter(|hunk| selector.matches(hunk))
+        .collect::<Vec<_>>();
+    if matches.len() <= 1 {
+        return Ok(matches.pop());
+    }
+    match matches
+        .iter()
+        .position(|hunk| hunk.header.trim() == selector.header.trim())
+    {
+        Some(ix) => Ok(Some(matches.swap_remove(ix))),
+        None => Err(anyhow!(
+            "{} hunks make the same change; apply it by hand",
+            matches.len()
+        )),
+    }
+}
+
+fn no_longer_in_working_copy(path: &str, hunk: &HunkSelector) -> anyhow::Error {
+    anyhow!(
+        "hunk '{}' is no longer in the working copy of {path}",
+        hunk.header.trim()
+    )
+}
+
+fn changed_lines(hunk: &DiffHunk, kind: DiffLineKind) -> impl Iterator<Item = &str> {
+    hunk.lines
+        .iter()
+        .filter(move |line| line.kind == kind)
+        .map(|line| line.text.as_str())
+}
+
+fn file_text<'a>(bytes: Option<&'a [u8]>, path: &str) -> Result<&'a str> {
+    std::str::from_utf8(bytes.unwrap_or_default()).map_err(|_| anyhow!("{path} is not a text file"))
+}
+
+/// Zero-based index of a hunk side's first line. An empty side's start names the line before it.
+fn hunk_start_index(start: Option<u32>, len: usize) -> usize {
+    let start = start.unwrap_or(0) as usize;
+    if len == 0 {
+        start
+    } else {
+        start.saturating_sub(1)
+    }
+}
+
+fn new_index_entry(path: &str) -> IndexEntry {
+    IndexEntry {
+        ctime: IndexTime::new(0, 0),
+        mtime: IndexTime::new(0, 0),
+        dev: 0,
+        ino: 0,
+        mode: 0o100644,
+        uid: 0,
+        gid: 0,
+        file_size: 0,
+        id: git2::Oid::zero(),
+        flags: 0,
+        flags_extended: 0,
+        path: path.as_bytes().to_vec(),
+    }
+}
+
+/// Where a new branch for a peeled-off hunk starts: the point the current work forked from the
+/// default base branch, or HEAD when there is no base to compare with.
+fn new_branch_base<'repo>(
+    repo_root: &Path,
+    repo: &'repo Repository,
+) -> Result<git2::Commit<'repo>> {
+    let head = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .context("cannot create a branch without an existing HEAD commit")?;
+    let Some(base_branch) = resolve_default_base_branch_name(repo_root)? else {
+        return Ok(head);
+    };
+    let Ok(base) = repo
+        .revparse_single(base_branch.as_str())
+        .and_then(|object| object.peel_to_commit())
+    else {
+        return Ok(head);
+    };
+    match repo.merge_base(head.id(), base.id()) {
+        Ok(fork_point) => Ok(repo.find_commit(fork_point)?),
+        Err(_) => Ok(head),
+    }
+}
+
+fn apply_hunk_to_tree(
+    repo: &Repository,
+    base_tree: &git2::Tree<'_>,
+    path: &str,
+    hunk: &DiffHunk,
+) -> Result<git2::Oid> {
+    let entry = base_tree.get_path(Path::new(path)).ok();
+    let (content, mode) = match entry.as_ref() {
+        Some(entry) => {
+            let blob = entry
+                .to_object(repo)?
+                .peel_to_blob()
+                .with_context(|| format!("{path} is not a file"))?;
+            let content = String::from_utf8(blob.content().to_vec())
+                .map_err(|_| anyhow!("{path} is not a text file"))?;
+            let mode = if entry.filemode() == i32::from(FileMode::BlobExecutable) {
+                FileMode::BlobExecutable
+            } else {
+                FileMode::Blob
+            };
+            (content, mode)
+        }
+        None if hunk.old_start == Some(0) => (String::new(), FileMode::Blob),
+        None => return Err(anyhow!("{path} does not exist")),
+    };
+
+    let updated = apply_hunk_to_text(content.as_str(), hunk)?;
+    let mut builder = git2::build::TreeUpdateBuilder::new();
+    if updated.is_empty() && hunk.new_start == Some(0) {
+        builder.remove(path);
+    } else {
+        let blob_id = repo.blob(updated.as_bytes())?;
+        builder.upsert(path, blob_id, mode);
+    }
+    builder
+        .create_updated(repo, base_tree)
+        .context("failed to write updated tree")
+}
+
+/// Replaces the hunk's old lines with its new lines. The old lines must match exactly once, or
+/// at the hunk's recorded start when the context repeats, and the text must not already hold the
+/// hunk's new side.
+fn apply_hunk_to_text(content: &str, hunk: &DiffHunk) -> Result<String> {
+    let lines = content.split_inclusive('\n').collect::<Vec<_>>();
+    let old_lines = hunk
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::Added)
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>();
+    let new_lines = hunk
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::Removed)
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>();
+
+    // An addition-only hunk still matches its own context once applied, so look for the new
+    // side first rather than inserting the same lines twice.
+    if !new_lines.is_empty() && !matching_starts(&lines, &new_lines).is_empty() {
+        return Err(anyhow!("the hunk's changes are already present"));
+    }
+
+    let expected = (hunk.old_start.unwrap_or(1).max(1) - 1) as usize;
+    let start = if old_lines.is_empty() {
+        if !lines.is_empty() && hunk.old_start == Some(0) {
+            return Err(anyhow!("the file already exists"));
+        }
+        expected.min(lines.len())
+    } else {
+        let starts = matching_starts(&lines, &old_lines);
+        match starts.as_slice() {
+            [] => return Err(anyhow!("the hunk's context was not found")),
+            [start] => *start,
+            _ if starts.contains(&expected) => expected,
+            _ => {
+                return Err(anyhow!(
+                    "the hunk's context matches {} places; apply it by hand",
+                    starts.len()
+                ));
+            }
+        }
+    };
+    let end = start + old_lines.len();
+
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let new_side_unterminated = hunk
+        .trailing_meta
+        .iter()
+        .any(|meta| meta == NO_NEWLINE_MARKER)
+        && hunk
+            .lines
+            .last()
+            .is_some_and(|line| line.kind != DiffLineKind::Removed);
+
+    let mut updated = lines[..start].concat();
+    for (ix, line) in new_lines.iter().enumerate() {
+        updated.push_str(line);
+        let is_last_line = ix + 1 == new_lines.len() && end == lines.len();
+        if !(is_last_line && new_side_unterminated) {
+            updated.push_str(newline);
+        }
+    }
+    updated.push_str(lines[end..].concat().as_str());
+    Ok(updated)
+}
+
+fn matching_starts(lines: &[&str], needle: &[&str]) -> Vec<usize> {
+    if needle.len() > lines.len() {
+        return Vec::new();
+    }
+    (0..=lines.len() - needle.len())
+        .filter(|&start| {
+            lines[start..start + needle.len()]
+                .iter()
+                .zip(needle)
+                .all(|(line, expected)| line_text(line) == *expected)
+        })
+        .collect()
+}
+
+fn line_text(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
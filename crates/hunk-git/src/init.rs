@@ -0,0 +1,93 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, anyhow, bail};
+
+use crate::git::discover_repo_root;
+
+/// Language presets for the `.gitignore` written into a newly initialized repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GitignoreTemplate {
+    #[default]
+    None,
+    Rust,
+    Node,
+    Python,
+    Go,
+}
+
+impl GitignoreTemplate {
+    pub const ALL: [Self; 5] = [Self::None, Self::Rust, Self::Node, Self::Python, Self::Go];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::None => "No .gitignore",
+            Self::Rust => "Rust",
+            Self::Node => "Node",
+            Self::Python => "Python",
+            Self::Go => "Go",
+        }
+    }
+
+    fn contents(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Rust => Some("/target\n**/*.rs.bk\n"),
+            Self::Node => Some("node_modules/\ndist/\ncoverage/\n.env\n*.log\n"),
+            Self::Python => Some("__pycache__/\n*.py[cod]\n.venv/\nbuild/\ndist/\n*.egg-info/\n"),
+            Self::Go => Some("/bin/\n*.exe\n*.test\n*.out\n"),
+        }
+    }
+}
+
+/// Files to seed a newly initialized repository with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RepoInitTemplate {
+    pub gitignore: GitignoreTemplate,
+    pub readme: bool,
+}
+
+/// Initializes a Git repository at `path` and seeds it from `template`, returning the new
+/// repository root.
+///
+/// Fails when `path` is already inside a repository. Existing `.gitignore` and `README.md` files
+/// are left untouched.
+pub fn init_repository(path: &Path, template: RepoInitTemplate) -> Result<PathBuf> {
+    if let Ok(existing_root) = discover_repo_root(path) {
+        bail!(
+            "{} is already inside the Git repository at {}",
+            path.display(),
+            existing_root.display()
+        );
+    }
+
+    let repo = git2::Repository::init(path)
+        .with_context(|| format!("failed to initialize Git repository at {}", path.display()))?;
+    let root = repo
+        .workdir()
+        .ok_or_else(|| anyhow!("initialized repository at {} is bare", path.display()))?
+        .to_path_buf();
+
+    if let Some(contents) = template.gitignore.contents() {
+        write_new_file(root.join(".gitignore").as_path(), contents)?;
+    }
+    if template.readme {
+        let project_name = root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Project".to_string());
+        write_new_file(
+            root.join("README.md").as_path(),
+            format!("# {project_name}\n").as_str(),
+        )?;
+    }
+
+    Ok(root)
+}
+
+fn write_new_file(path: &Path, contents: &str) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
@@ -0,0 +1,185 @@
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{BranchType, Repository};
+
+use crate::git2_helpers::open_git2_repo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoIssueKind {
+    DetachedHead,
+    DivergedBranch,
+    GoneUpstream,
+    StaleWorktree,
+}
+
+/// A fix Hunk can apply for a [`RepoIssue`] without touching any commits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepoIssueResolution {
+    /// Stops tracking a remote branch that no longer exists.
+    UnsetUpstream { branch_name: String },
+    /// Drops the registration of a linked worktree whose folder is gone.
+    PruneWorktree { worktree_name: String },
+}
+
+impl RepoIssueResolution {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::UnsetUpstream { .. } => "Unset Upstream",
+            Self::PruneWorktree { .. } => "Prune Worktree",
+        }
+    }
+}
+
+/// Repository state that is easy to miss and usually needs a decision from the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoIssue {
+    pub kind: RepoIssueKind,
+    pub title: String,
+    pub detail: String,
+    pub suggestion: String,
+    pub resolution: Option<RepoIssueResolution>,
+}
+
+/// Looks for a detached HEAD, branches that diverged from or lost their upstream, and linked
+/// worktrees whose folders were deleted.
+pub fn detect_repo_issues(repo_root: &Path) -> Result<Vec<RepoIssue>> {
+    let repo = open_git2_repo(repo_root)?;
+    let mut issues = Vec::new();
+
+    if repo.head_detached().unwrap_or(false) {
+        let commit_id = repo
+            .head()
+            .ok()
+            .and_then(|head| head.target())
+            .map(|oid| oid.to_string().chars().take(12).collect::<String>())
+            .unwrap_or_default();
+        issues.push(RepoIssue {
+            kind: RepoIssueKind::DetachedHead,
+            title: "Detached HEAD".to_string(),
+            detail: format!("The working copy is on commit {commit_id}, not a branch."),
+            suggestion: "Create a branch here before committing so new commits stay reachable."
+                .to_string(),
+            resolution: None,
+        });
+    }
+
+    issues.extend(branch_issues(&repo)?);
+    issues.extend(worktree_issues(&repo)?);
+    Ok(issues)
+}
+
+pub fn resolve_repo_issue(repo_root: &Path, resolution: &RepoIssueResolution) -> Result<()> {
+    let repo = open_git2_repo(repo_root)?;
+    match resolution {
+        RepoIssueResolution::UnsetUpstream { branch_name } => {
+            let mut branch = repo
+                .find_branch(branch_name, BranchType::Local)
+                .with_context(|| format!("branch '{branch_name}' does not exist"))?;
+            branch
+                .set_upstream(None)
+                .with_context(|| format!("failed to unset upstream for '{branch_name}'"))
+        }
+        RepoIssueResolution::PruneWorktree { worktree_name } => {
+            let worktree = repo
+                .find_worktree(worktree_name)
+                .with_context(|| format!("worktree '{worktree_name}' is not registered"))?;
+            if worktree.validate().is_ok() {
+                return Err(anyhow!(
+                    "worktree '{worktree_name}' still exists at {}",
+                    worktree.path().display()
+                ));
+            }
+            worktree
+                .prune(None)
+                .with_context(|| format!("failed to prune worktree '{worktree_name}'"))
+        }
+    }
+}
+
+fn branch_issues(repo: &Repository) -> Result<Vec<RepoIssue>> {
+    let mut issues = Vec::new();
+    for branch in repo
+        .branches(Some(BranchType::Local))
+        .context("failed to list local branches")?
+    {
+        let (branch, _) = branch.context("failed to read local branch")?;
+        let Some(branch_name) = branch.name().ok().flatten().map(str::to_string) else {
+            continue;
+        };
+        let Some(local_oid) = branch.get().target() else {
+            continue;
+        };
+        let Ok(upstream_name) =
+            repo.branch_upstream_name(format!("refs/heads/{branch_name}").as_str())
+        else {
+            continue;
+        };
+        let upstream_name = upstream_name.as_str().unwrap_or_default().to_string();
+        let upstream_label = upstream_name
+            .strip_prefix("refs/remotes/")
+            .unwrap_or(upstream_name.as_str())
+            .to_string();
+
+        let Some(upstream_oid) = repo
+            .find_reference(upstream_name.as_str())
+            .ok()
+            .and_then(|reference| reference.target())
+        else {
+            issues.push(RepoIssue {
+                kind: RepoIssueKind::GoneUpstream,
+                title: format!("Upstream of {branch_name} is gone"),
+                detail: format!("{branch_name} tracks {upstream_label}, which no longer exists."),
+                suggestion: "Unset the upstream to publish the branch again, or delete it if it \
+                             was merged."
+                    .to_string(),
+                resolution: Some(RepoIssueResolution::UnsetUpstream { branch_name }),
+            });
+            continue;
+        };
+
+        let (ahead, behind) = repo
+            .graph_ahead_behind(local_oid, upstream_oid)
+            .with_context(|| format!("failed to compare {branch_name} with {upstream_label}"))?;
+        if ahead > 0 && behind > 0 {
+            issues.push(RepoIssue {
+                kind: RepoIssueKind::DivergedBranch,
+                title: format!("{branch_name} diverged from {upstream_label}"),
+                detail: format!("{ahead} local and {behind} remote commits are not in the other."),
+                suggestion: "Rebase onto or merge the upstream branch before pushing.".to_string(),
+                resolution: None,
+            });
+        }
+    }
+    Ok(issues)
+}
+
+fn worktree_issues(repo: &Repository) -> Result<Vec<RepoIssue>> {
+    let mut issues = Vec::new();
+    for worktree_name in repo
+        .worktrees()
+        .context("failed to list linked worktrees")?
+        .iter()
+        .flatten()
+    {
+        let Ok(worktree) = repo.find_worktree(worktree_name) else {
+            continue;
+        };
+        if worktree.validate().is_ok() {
+            continue;
+        }
+        issues.push(RepoIssue {
+            kind: RepoIssueKind::StaleWorktree,
+            title: format!("Worktree {worktree_name} is missing"),
+            detail: format!(
+                "The folder {} was removed but Git still has it registered.",
+                worktree.path().display()
+            ),
+            suggestion: "Prune the worktree so its branch can be checked out again.".to_string(),
+            resolution: Some(RepoIssueResolution::PruneWorktree {
+                worktree_name: worktree_name.to_string(),
+            }),
+        });
+    }
+    Ok(issues)
+}
@@ -5,11 +5,39 @@ pub mod config {
 mod command_env;
 mod git2_helpers;
 mod path;
+mod signing;
 
+pub mod abandoned;
+pub mod api;
 pub mod branch;
+pub mod branch_naming;
+pub mod cancel;
+pub mod changelog;
+pub mod clone;
+pub mod codeowners;
+pub mod commit_message;
 pub mod compare;
+pub mod doctor;
+pub mod document_text;
+pub mod encoding;
+pub mod error;
+pub mod file_rows;
 pub mod git;
+pub mod hex_diff;
 pub mod history;
+pub mod hunk_apply;
+pub mod init;
+pub mod issues;
+pub mod line_changes;
+pub mod maintenance;
 pub mod mutation;
 pub mod network;
+pub mod operations;
+pub mod path_collisions;
+pub mod recovery;
+pub mod snapshot_exclusions;
+pub mod sparse_checkout;
+pub mod stack;
+pub mod tickets;
+pub mod user_config;
 pub mod worktree;
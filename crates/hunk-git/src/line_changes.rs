@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use anyhow::{Context as _, Result};
+use git2::{DiffOptions, Patch};
+
+use crate::git2_helpers::open_git2_repo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineChangeKind {
+    Added,
+    Modified,
+    /// Lines were removed right after `start_line`, or before the first line when the
+    /// deletion is at the top of the file.
+    Deleted,
+}
+
+/// A run of lines in the edited text that differs from HEAD. Lines are zero-based, and a
+/// deletion covers no lines of the edited text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineChange {
+    pub kind: LineChangeKind,
+    pub start_line: usize,
+    pub line_count: usize,
+}
+
+/// Diffs `text`, the edited contents of the repo-relative `path`, against the file at HEAD.
+/// A file missing from HEAD, or a repository without commits, counts as entirely added.
+pub fn line_changes_against_head(
+    repo_root: &Path,
+    path: &str,
+    text: &str,
+) -> Result<Vec<LineChange>> {
+    let repo = open_git2_repo(repo_root)?;
+    let head_tree = match repo.head().and_then(|head| head.peel_to_tree()) {
+        Ok(tree) => Some(tree),
+        Err(err) if err.code() == git2::ErrorCode::UnbornBranch => None,
+        Err(err) if err.code() == git2::ErrorCode::NotFound => None,
+        Err(err) => return Err(err).context("failed to resolve the HEAD tree"),
+    };
+    let head_blob = head_tree
+        .as_ref()
+        .and_then(|tree| tree.get_path(Path::new(path)).ok())
+        .and_then(|entry| entry.to_object(&repo).ok())
+        .and_then(|object| object.into_blob().ok());
+    let base = head_blob
+        .as_ref()
+        .map(|blob| blob.content())
+        .unwrap_or_default();
+    line_changes_between(base, text.as_bytes())
+}
+
+/// Line changes from `base` to `text`, as the editor gutter shows them.
+pub fn line_changes_between(base: &[u8], text: &[u8]) -> Result<Vec<LineChange>> {
+    let mut options = DiffOptions::new();
+    options.context_lines(0).force_text(true);
+    let patch = Patch::from_buffers(base, None, text, None, Some(&mut options))
+        .context("failed to diff the edited text")?;
+
+    let mut changes = Vec::with_capacity(patch.num_hunks());
+    for hunk_index in 0..patch.num_hunks() {
+        let (hunk, _) = patch.hunk(hunk_index)?;
+        let new_start = hunk.new_start() as usize;
+        let new_lines = hunk.new_lines() as usize;
+        let change = match (hunk.old_lines(), new_lines) {
+            // For a pure deletion, `new_start` is the one-based line the removed lines followed.
+            (_, 0) => LineChange {
+                kind: LineChangeKind::Deleted,
+                start_line: new_start.saturating_sub(1),
+                line_count: 0,
+            },
+            (0, _) => LineChange {
+                kind: LineChangeKind::Added,
+                start_line: new_start.saturating_sub(1),
+                line_count: new_lines,
+            },
+            _ => LineChange {
+                kind: LineChangeKind::Modified,
+                start_line: new_start.saturating_sub(1),
+                line_count: new_lines,
+            },
+        };
+        changes.push(change);
+    }
+    Ok(changes)
+}
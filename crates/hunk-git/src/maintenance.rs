@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+
+use crate::command_env::git_cli_command;
+use crate::git2_helpers::open_git2_repo;
+
+/// Outcome of [`run_git_gc`]: the size of the object store before and after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GitGcReport {
+    pub objects_bytes_before: u64,
+    pub objects_bytes_after: u64,
+}
+
+impl GitGcReport {
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.objects_bytes_before
+            .saturating_sub(self.objects_bytes_after)
+    }
+}
+
+/// Runs `git gc` on the repository, packing loose objects and pruning unreachable ones past
+/// Git's usual expiry. libgit2 has no garbage collector, so this needs the git CLI.
+pub fn run_git_gc(repo_root: &Path) -> Result<GitGcReport> {
+    let repo = open_git2_repo(repo_root)?;
+    let objects_dir = repo.commondir().join("objects");
+    let objects_bytes_before = directory_size(objects_dir.as_path())?;
+
+    let output = git_cli_command("git")
+        .current_dir(repo_root)
+        .args(["gc", "--quiet"])
+        .output()
+        .context("failed to launch git gc")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(output.stderr.as_slice())
+            .trim()
+            .to_string();
+        let details = if stderr.is_empty() {
+            format!("exited with status {}", output.status)
+        } else {
+            stderr
+        };
+        return Err(anyhow!("git gc failed: {details}"));
+    }
+
+    Ok(GitGcReport {
+        objects_bytes_before,
+        objects_bytes_after: directory_size(objects_dir.as_path())?,
+    })
+}
+
+fn directory_size(path: &Path) -> Result<u64> {
+    let mut total = 0;
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", path.display()));
+        }
+    };
+    for entry in entries {
+        let entry = entry.with_context(|| format!("failed to read {}", path.display()))?;
+        let metadata = entry
+            .metadata()
+            .with_context(|| format!("failed to inspect {}", entry.path().display()))?;
+        if metadata.is_dir() {
+            total += directory_size(entry.path().as_path())?;
+        } else {
+            total += metadata.len();
+        }
+    }
+    Ok(total)
+}
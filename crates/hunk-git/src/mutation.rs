@@ -5,8 +5,10 @@ use anyhow::{Context as _, Result, anyhow};
 
 use crate::branch::is_valid_branch_name;
 use crate::command_env::git_cli_command;
+use crate::error::GitError;
 use crate::git::expand_selected_paths_for_renames;
 use crate::git2_helpers::{load_statuses, open_git2_repo};
+use crate::signing::create_signed_commit;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum WorktreeChange {
@@ -56,7 +58,10 @@ pub fn activate_or_create_branch(
             .find_branch(branch_name, git2::BranchType::Local)
             .is_ok()
         {
-            return Err(anyhow!("branch '{branch_name}' already exists"));
+            return Err(GitError::BranchExists {
+                branch: branch_name.to_string(),
+            }
+            .into());
         }
 
         let head_commit = current_head_commit(&repo)?.ok_or_else(|| {
@@ -384,6 +389,43 @@ pub fn restore_working_copy_paths(repo_root: &Path, paths: &[String]) -> Result<
     Ok(restored_count)
 }
 
+/// Renames `from` to `to` on disk the way `git mv` does: when `from` is tracked, its index entry
+/// moves with it so Git records a rename instead of a delete and an untracked file. Untracked
+/// files are only renamed on disk.
+pub fn move_worktree_path(repo_root: &Path, from: &str, to: &str) -> Result<()> {
+    let from = normalize_repo_path(from)?;
+    let to = normalize_repo_path(to)?;
+    if from.is_empty() || to.is_empty() {
+        return Err(anyhow!("cannot move an empty path"));
+    }
+
+    let repo = open_repo(repo_root)?;
+    let from_absolute = repo_root.join(from.as_str());
+    let to_absolute = repo_root.join(to.as_str());
+    if to_absolute.exists() {
+        return Err(anyhow!("'{}' already exists", to));
+    }
+    std::fs::rename(from_absolute.as_path(), to_absolute.as_path()).with_context(|| {
+        format!(
+            "failed to rename {} to {}",
+            from_absolute.display(),
+            to_absolute.display()
+        )
+    })?;
+
+    let mut index = repo.index()?;
+    let Some(mut entry) = index.get_path(Path::new(from.as_str()), 0) else {
+        return Ok(());
+    };
+    entry.path = to.into_bytes();
+    index.add(&entry)?;
+    index.remove_path(Path::new(from.as_str()))?;
+    index
+        .write()
+        .context("failed to record rename in the Git index")?;
+    Ok(())
+}
+
 fn commit_paths_internal(
     repo_root: &Path,
     message: &str,
@@ -401,7 +443,7 @@ fn commit_paths_internal(
     )?;
     let changes = collect_worktree_changes(&repo, selected_paths)?;
     if changes.is_empty() {
-        return Err(anyhow!("no changes to commit"));
+        return Err(GitError::NothingToCommit.into());
     }
 
     stage_changes(&repo, &changes)?;
@@ -423,7 +465,7 @@ fn ensure_has_staged_index_changes(repo: &git2::Repository) -> Result<()> {
     for entry in statuses.iter() {
         let status = entry.status();
         if status.is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
         if has_index_changes(status) {
             has_staged_changes = true;
@@ -451,7 +493,7 @@ fn collect_selected_worktree_changes(
     for entry in statuses.iter() {
         let status = entry.status();
         if status.is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
 
         let Some(display_path) = status_display_path(&entry) else {
@@ -502,7 +544,7 @@ fn collect_worktree_changes(
     let statuses = repo.statuses(Some(&mut status_options))?;
     for entry in statuses.iter() {
         if entry.status().is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
     }
 
@@ -544,7 +586,7 @@ fn collect_worktree_changes(
             git2::Delta::Ignored | git2::Delta::Unreadable | git2::Delta::Unmodified => {
                 continue;
             }
-            git2::Delta::Conflicted => return Err(anyhow!("cannot operate on conflicted files")),
+            git2::Delta::Conflicted => return Err(GitError::Conflicted.into()),
         };
 
         changes.insert(path, change);
@@ -563,7 +605,7 @@ fn collect_selected_index_paths(
     for entry in statuses.iter() {
         let status = entry.status();
         if status.is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
         if !has_index_changes(status) {
             continue;
@@ -601,7 +643,7 @@ fn collect_index_changes_for_ai(repo: &git2::Repository) -> Result<BTreeMap<Stri
     for entry in statuses.iter() {
         let status = entry.status();
         if status.is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
         if !has_index_changes(status) {
             continue;
@@ -666,7 +708,7 @@ fn ensure_no_hidden_index_changes(repo: &git2::Repository, action_message: &str)
     for entry in statuses.iter() {
         let status = entry.status();
         if status.is_conflicted() {
-            return Err(anyhow!("cannot operate on conflicted files"));
+            return Err(GitError::Conflicted.into());
         }
         if has_index_changes(status) {
             return Err(anyhow!(
@@ -687,7 +729,8 @@ fn has_index_changes(status: git2::Status) -> bool {
 }
 
 fn create_commit_from_index(repo: &git2::Repository, message: &str) -> Result<git2::Oid> {
-    if commit_signing_enabled(repo)? {
+    let signing = commit_signing_enabled(repo)?;
+    if signing && git_cli_signing_enabled(repo)? {
         run_git_commit(repo, message)?;
         let refreshed_repo = reopen_existing_repo(repo)?;
         return current_head_commit(&refreshed_repo)?
@@ -695,7 +738,7 @@ fn create_commit_from_index(repo: &git2::Repository, message: &str) -> Result<gi
             .ok_or_else(|| anyhow!("git commit completed without creating a HEAD commit"));
     }
 
-    create_commit_with_git2(repo, message)
+    create_commit_with_git2(repo, message, signing)
 }
 
 fn commit_signing_enabled(repo: &git2::Repository) -> Result<bool> {
@@ -703,15 +746,29 @@ fn commit_signing_enabled(repo: &git2::Repository) -> Result<bool> {
     Ok(config.get_bool("commit.gpgSign").unwrap_or(false))
 }
 
-fn create_commit_with_git2(repo: &git2::Repository, message: &str) -> Result<git2::Oid> {
+// Escape hatch for signing setups that only the git CLI understands (custom hooks or
+// gpg.ssh.defaultKeyCommand). Enable with `git config hunk.signWithGitCli true`.
+fn git_cli_signing_enabled(repo: &git2::Repository) -> Result<bool> {
+    let config = repo.config()?;
+    Ok(config.get_bool("hunk.signWithGitCli").unwrap_or(false))
+}
+
+fn create_commit_with_git2(
+    repo: &git2::Repository,
+    message: &str,
+    signing: bool,
+) -> Result<git2::Oid> {
     let signature = repo
         .signature()
-        .context("failed to resolve Git author signature for commit")?;
+        .map_err(|err| anyhow::Error::new(err).context(GitError::MissingIdentity))?;
     let mut index = repo.index()?;
     let tree_id = index.write_tree()?;
     let tree = repo.find_tree(tree_id)?;
     let parents = current_head_commit(repo)?.into_iter().collect::<Vec<_>>();
     let parent_refs = parents.iter().collect::<Vec<_>>();
+    if signing {
+        return create_signed_commit(repo, &signature, message, &tree, parent_refs.as_slice());
+    }
     repo.commit(
         Some("HEAD"),
         &signature,
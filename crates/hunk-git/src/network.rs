@@ -1,14 +1,22 @@
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, Once};
 
 use anyhow::{Context as _, Result, anyhow};
 use git2::{
-    AutotagOption, BranchType, Cred, CredentialType, FetchOptions, PushOptions, RemoteCallbacks,
-    Repository,
+    AutotagOption, BranchType, Cred, CredentialType, Direction, FetchOptions, PushOptions,
+    RemoteCallbacks, Repository,
 };
 
 use crate::branch::is_valid_branch_name;
+use crate::cancel::CancellationToken;
+use crate::error::GitError;
 use crate::git2_helpers::{load_statuses, open_git2_repo};
 
+/// Longest libgit2 waits to open a connection to a remote.
+const REMOTE_CONNECT_TIMEOUT_MS: i32 = 30_000;
+/// Longest libgit2 waits on a remote that stopped sending or receiving data.
+const REMOTE_STALL_TIMEOUT_MS: i32 = 60_000;
+
 #[derive(Debug, Clone)]
 struct UpstreamTarget {
     remote_name: String,
@@ -16,6 +24,14 @@ struct UpstreamTarget {
     tracking_ref_name: String,
 }
 
+/// Where a branch's upstream currently points on the remote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteUpstreamHead {
+    pub remote_name: String,
+    pub remote_branch_name: String,
+    pub commit_id: String,
+}
+
 #[derive(Debug, Clone, Default)]
 struct SshConfigMatch {
     user: Option<String>,
@@ -40,6 +56,20 @@ pub fn push_current_branch(
     repo_root: &Path,
     branch_name: &str,
     require_existing_upstream: bool,
+) -> Result<()> {
+    push_current_branch_with_cancel(
+        repo_root,
+        branch_name,
+        require_existing_upstream,
+        &CancellationToken::new(),
+    )
+}
+
+pub fn push_current_branch_with_cancel(
+    repo_root: &Path,
+    branch_name: &str,
+    require_existing_upstream: bool,
+    cancel: &CancellationToken,
 ) -> Result<()> {
     let branch_name = normalized_branch_name(branch_name)?;
     let repo = open_repo(repo_root)?;
@@ -48,10 +78,13 @@ pub fn push_current_branch(
 
     let maybe_upstream = resolve_upstream_target(&repo, branch_name)?;
     if require_existing_upstream && maybe_upstream.is_none() {
-        return Err(anyhow!("publish this branch before pushing"));
+        return Err(GitError::PublishRequired.into());
     }
     if !require_existing_upstream && maybe_upstream.is_some() {
-        return Err(anyhow!("branch '{branch_name}' is already published"));
+        return Err(GitError::AlreadyPublished {
+            branch: branch_name.to_string(),
+        }
+        .into());
     }
 
     let upstream = match maybe_upstream {
@@ -71,18 +104,34 @@ pub fn push_current_branch(
         "{local_ref_name}:refs/heads/{}",
         upstream.remote_branch_name
     );
-    let mut push_options = push_options(&repo)?;
+    cancel.check()?;
+    let rejection = Arc::new(Mutex::new(None));
+    let mut push_options = push_options(&repo, cancel, rejection.clone())?;
     let mut remote = repo
         .find_remote(upstream.remote_name.as_str())
         .with_context(|| format!("remote '{}' is not configured", upstream.remote_name))?;
-    remote
-        .push(&[refspec.as_str()], Some(&mut push_options))
+    ensure_remote_socket_timeouts();
+    let push_result = remote.push(&[refspec.as_str()], Some(&mut push_options));
+    if push_result.is_err() {
+        cancel.check()?;
+    }
+    push_result
+        .map_err(|err| remote_operation_error(err, upstream.remote_name.as_str()))
         .with_context(|| {
             format!(
                 "failed to push branch '{}' to remote '{}'",
                 branch_name, upstream.remote_name
             )
         })?;
+    let rejection = rejection.lock().map(|mut rejection| rejection.take());
+    if let Ok(Some((reference, reason))) = rejection {
+        return Err(GitError::PushRejected {
+            remote: upstream.remote_name.clone(),
+            reference,
+            reason,
+        }
+        .into());
+    }
 
     if !require_existing_upstream {
         let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
@@ -102,34 +151,143 @@ pub fn push_current_branch(
     Ok(())
 }
 
+pub fn add_remote(repo_root: &Path, remote_name: &str, url: &str) -> Result<()> {
+    let remote_name = remote_name.trim();
+    let url = url.trim();
+    if !git2::Remote::is_valid_name(remote_name) {
+        return Err(anyhow!("invalid Git remote name: {remote_name}"));
+    }
+    if url.is_empty() {
+        return Err(anyhow!("remote URL cannot be empty"));
+    }
+
+    let repo = open_git2_repo(repo_root)?;
+    if repo.find_remote(remote_name).is_ok() {
+        return Err(anyhow!("remote '{remote_name}' already exists"));
+    }
+    repo.remote(remote_name, url)
+        .with_context(|| format!("failed to add remote '{remote_name}'"))?;
+    Ok(())
+}
+
 pub fn sync_current_branch(repo_root: &Path, branch_name: &str) -> Result<()> {
     sync_branch_from_remote(repo_root, branch_name)
 }
 
+pub fn sync_current_branch_with_cancel(
+    repo_root: &Path,
+    branch_name: &str,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let branch_name = normalized_branch_name(branch_name)?;
+    let repo = open_repo(repo_root)?;
+    let upstream = resolve_upstream_target(&repo, branch_name)?
+        .ok_or_else(|| anyhow!("no upstream branch to sync from"))?;
+    sync_branch_with_upstream(&repo, branch_name, &upstream, cancel)
+}
+
+/// Reads the remote head of `branch_name`'s upstream without fetching or touching local refs.
+///
+/// Returns `None` when the branch has no upstream or the remote no longer has that branch.
+pub fn remote_upstream_head_with_cancel(
+    repo_root: &Path,
+    branch_name: &str,
+    cancel: &CancellationToken,
+) -> Result<Option<RemoteUpstreamHead>> {
+    let branch_name = normalized_branch_name(branch_name)?;
+    let repo = open_repo(repo_root)?;
+    let Some(upstream) = resolve_upstream_target(&repo, branch_name)? else {
+        return Ok(None);
+    };
+
+    // Nothing is written here, so a stalled listing can be abandoned at the deadline.
+    let repo_root = repo_root.to_path_buf();
+    let operation_cancel = cancel.clone();
+    cancel.run_until_stopped(move || {
+        let repo = open_repo(repo_root.as_path())?;
+        let mut remote = repo
+            .find_remote(upstream.remote_name.as_str())
+            .with_context(|| format!("remote '{}' is not configured", upstream.remote_name))?;
+        ensure_remote_socket_timeouts();
+        let connection = remote.connect_auth(
+            Direction::Fetch,
+            Some(remote_callbacks(&repo, &operation_cancel)?),
+            None,
+        );
+        if connection.is_err() {
+            operation_cancel.check()?;
+        }
+        let connection = connection
+            .map_err(|err| remote_operation_error(err, upstream.remote_name.as_str()))
+            .with_context(|| format!("failed to connect to remote '{}'", upstream.remote_name))?;
+
+        let remote_ref_name = local_branch_ref_name(upstream.remote_branch_name.as_str());
+        let commit_id = connection
+            .list()
+            .with_context(|| format!("failed to list refs on remote '{}'", upstream.remote_name))?
+            .iter()
+            .find(|head| head.name() == remote_ref_name)
+            .map(|head| head.oid().to_string());
+
+        Ok(commit_id.map(|commit_id| RemoteUpstreamHead {
+            remote_name: upstream.remote_name,
+            remote_branch_name: upstream.remote_branch_name,
+            commit_id,
+        }))
+    })
+}
+
+/// True when the remote copy of `branch_name`'s upstream has commits the local branch lacks,
+/// so pushing would be rejected or need a sync first. Reads the live remote head without
+/// fetching; an unpublished branch or a deleted remote branch is never behind.
+pub fn branch_behind_remote_with_cancel(
+    repo_root: &Path,
+    branch_name: &str,
+    cancel: &CancellationToken,
+) -> Result<bool> {
+    let Some(remote_head) = remote_upstream_head_with_cancel(repo_root, branch_name, cancel)?
+    else {
+        return Ok(false);
+    };
+    let repo = open_repo(repo_root)?;
+    let local_oid = repo
+        .find_branch(normalized_branch_name(branch_name)?, BranchType::Local)?
+        .get()
+        .target()
+        .ok_or_else(|| anyhow!("branch '{branch_name}' does not point at a commit"))?;
+    let remote_oid = git2::Oid::from_str(remote_head.commit_id.as_str())?;
+    if remote_oid == local_oid {
+        return Ok(false);
+    }
+    // A remote head we have never fetched cannot be in the local history.
+    if repo.find_commit(remote_oid).is_err() {
+        return Ok(true);
+    }
+    Ok(!repo.graph_descendant_of(local_oid, remote_oid)?)
+}
+
 pub fn sync_branch_from_remote_if_tracked(repo_root: &Path, branch_name: &str) -> Result<bool> {
     let branch_name = normalized_branch_name(branch_name)?;
     let repo = open_repo(repo_root)?;
     let Some(upstream) = resolve_upstream_target(&repo, branch_name)? else {
         return Ok(false);
     };
-    sync_branch_with_upstream(&repo, branch_name, &upstream)?;
+    sync_branch_with_upstream(&repo, branch_name, &upstream, &CancellationToken::new())?;
     Ok(true)
 }
 
 pub fn sync_branch_from_remote(repo_root: &Path, branch_name: &str) -> Result<()> {
-    let branch_name = normalized_branch_name(branch_name)?;
-    let repo = open_repo(repo_root)?;
-    let upstream = resolve_upstream_target(&repo, branch_name)?
-        .ok_or_else(|| anyhow!("no upstream branch to sync from"))?;
-    sync_branch_with_upstream(&repo, branch_name, &upstream)
+    sync_current_branch_with_cancel(repo_root, branch_name, &CancellationToken::new())
 }
 
 fn sync_branch_with_upstream(
     repo: &Repository,
     branch_name: &str,
     upstream: &UpstreamTarget,
+    cancel: &CancellationToken,
 ) -> Result<()> {
-    fetch_upstream(repo, upstream)?;
+    fetch_upstream(repo, upstream, cancel)?;
+    cancel.check()?;
 
     let local_branch = repo
         .find_branch(branch_name, BranchType::Local)
@@ -303,27 +461,45 @@ fn resolve_publish_remote_name(repo: &Repository, branch_name: &str) -> Result<S
         ));
     }
 
-    Err(anyhow!("no Git remote configured for publish/push"))
+    Err(GitError::RemoteNotConfigured.into())
 }
 
-fn fetch_upstream(repo: &Repository, upstream: &UpstreamTarget) -> Result<()> {
-    let mut fetch_options = fetch_options(repo)?;
-    let mut remote = repo
-        .find_remote(upstream.remote_name.as_str())
-        .with_context(|| format!("remote '{}' is not configured", upstream.remote_name))?;
-    let fetch_refspec = format!(
-        "+refs/heads/{}:{}",
-        upstream.remote_branch_name, upstream.tracking_ref_name
-    );
-    remote
-        .fetch(&[fetch_refspec.as_str()], Some(&mut fetch_options), None)
-        .with_context(|| {
-            format!(
-                "failed to fetch branch '{}' from remote '{}'",
-                upstream.remote_branch_name, upstream.remote_name
-            )
-        })?;
-    Ok(())
+fn fetch_upstream(
+    repo: &Repository,
+    upstream: &UpstreamTarget,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    cancel.check()?;
+    // The fetch only moves the tracking ref, which a later sync overwrites, so a stalled fetch
+    // can be abandoned at the deadline. The worker opens its own handle on the same repository.
+    let repo_root = repo.workdir().unwrap_or(repo.path()).to_path_buf();
+    let upstream = upstream.clone();
+    let operation_cancel = cancel.clone();
+    cancel.run_until_stopped(move || {
+        let repo = open_repo(repo_root.as_path())?;
+        let mut fetch_options = fetch_options(&repo, &operation_cancel)?;
+        let mut remote = repo
+            .find_remote(upstream.remote_name.as_str())
+            .with_context(|| format!("remote '{}' is not configured", upstream.remote_name))?;
+        let fetch_refspec = format!(
+            "+refs/heads/{}:{}",
+            upstream.remote_branch_name, upstream.tracking_ref_name
+        );
+        ensure_remote_socket_timeouts();
+        let fetch_result = remote.fetch(&[fetch_refspec.as_str()], Some(&mut fetch_options), None);
+        if fetch_result.is_err() {
+            operation_cancel.check()?;
+        }
+        fetch_result
+            .map_err(|err| remote_operation_error(err, upstream.remote_name.as_str()))
+            .with_context(|| {
+                format!(
+                    "failed to fetch branch '{}' from remote '{}'",
+                    upstream.remote_branch_name, upstream.remote_name
+                )
+            })?;
+        Ok(())
+    })
 }
 
 fn fast_forward_branch(
@@ -388,31 +564,90 @@ fn update_tracking_ref_to_local_head(repo: &Repository, branch_name: &str) -> Re
     Ok(())
 }
 
-fn fetch_options(repo: &Repository) -> Result<FetchOptions<'static>> {
+fn fetch_options(repo: &Repository, cancel: &CancellationToken) -> Result<FetchOptions<'static>> {
     let mut options = FetchOptions::new();
     options.download_tags(AutotagOption::Unspecified);
-    options.remote_callbacks(remote_callbacks(repo)?);
+    options.remote_callbacks(remote_callbacks(repo, cancel)?);
     Ok(options)
 }
 
-fn push_options(repo: &Repository) -> Result<PushOptions<'static>> {
+fn push_options(
+    repo: &Repository,
+    cancel: &CancellationToken,
+    rejection: Arc<Mutex<Option<(String, String)>>>,
+) -> Result<PushOptions<'static>> {
+    let mut callbacks = remote_callbacks(repo, cancel)?;
+    // A rejected ref does not fail the push call itself; it is only reported here.
+    callbacks.push_update_reference(move |reference, status| {
+        if let Some(reason) = status
+            && let Ok(mut rejection) = rejection.lock()
+        {
+            rejection.get_or_insert_with(|| (reference.to_string(), reason.to_string()));
+        }
+        Ok(())
+    });
     let mut options = PushOptions::new();
-    options.remote_callbacks(remote_callbacks(repo)?);
+    options.remote_callbacks(callbacks);
     Ok(options)
 }
 
-fn remote_callbacks(repo: &Repository) -> Result<RemoteCallbacks<'static>> {
+pub(crate) fn remote_operation_error(err: git2::Error, remote_name: &str) -> anyhow::Error {
+    if err.code() == git2::ErrorCode::Auth {
+        return anyhow::Error::new(err).context(GitError::RemoteAuthFailed {
+            remote: remote_name.to_string(),
+        });
+    }
+    anyhow::Error::new(err)
+}
+
+/// Bounds how long libgit2 waits on a connect or a stalled socket. The setting is process-wide,
+/// so it is one fixed stall limit shared by every operation; each operation's own deadline is
+/// enforced through its token.
+pub(crate) fn ensure_remote_socket_timeouts() {
+    static SOCKET_TIMEOUTS: Once = Once::new();
+    SOCKET_TIMEOUTS.call_once(|| {
+        // SAFETY: both options only store an integer that libgit2 reads when it opens sockets.
+        unsafe {
+            let _ =
+                git2::opts::set_server_connect_timeout_in_milliseconds(REMOTE_CONNECT_TIMEOUT_MS);
+            let _ = git2::opts::set_server_timeout_in_milliseconds(REMOTE_STALL_TIMEOUT_MS);
+        }
+    });
+}
+
+fn remote_callbacks(
+    repo: &Repository,
+    cancel: &CancellationToken,
+) -> Result<RemoteCallbacks<'static>> {
     let config = repo
         .config()
         .context("failed to load Git config for authentication")?;
     let mut callbacks = RemoteCallbacks::new();
+    let credentials_cancel = cancel.clone();
     callbacks.credentials(move |url, username_from_url, allowed| {
+        if credentials_cancel.should_stop() {
+            return Err(git2::Error::from_str("operation was cancelled"));
+        }
         resolve_credentials(&config, url, username_from_url, allowed)
     });
+    // libgit2 aborts the transfer as soon as a progress callback returns false. These only run
+    // while data flows; stalls are bounded by `ensure_remote_socket_timeouts` and, for reads,
+    // by `CancellationToken::run_until_stopped`.
+    let transfer_cancel = cancel.clone();
+    callbacks.transfer_progress(move |_| !transfer_cancel.should_stop());
+    let sideband_cancel = cancel.clone();
+    callbacks.sideband_progress(move |_| !sideband_cancel.should_stop());
+    let negotiation_cancel = cancel.clone();
+    callbacks.push_negotiation(move |_| {
+        if negotiation_cancel.should_stop() {
+            return Err(git2::Error::from_str("operation was cancelled"));
+        }
+        Ok(())
+    });
     Ok(callbacks)
 }
 
-fn resolve_credentials(
+pub(crate) fn resolve_credentials(
     config: &git2::Config,
     url: &str,
     username_from_url: Option<&str>,
@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, bail};
+use git2::{BranchType, Oid, Repository};
+
+use crate::git2_helpers::{load_statuses, open_git2_repo};
+
+pub const DEFAULT_REPO_OPERATION_LIMIT: usize = 50;
+const OPERATION_DIFF_COMMIT_LIMIT: usize = 100;
+
+/// One entry of the HEAD reflog, treated as a point-in-time state of the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoOperation {
+    /// Position in the HEAD reflog, `0` being the current state.
+    pub index: usize,
+    pub head_commit_id: String,
+    pub unix_time: i64,
+    /// The reflog message, such as `commit: Fix parser` or `checkout: moving from a to b`.
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationCommit {
+    pub commit_id: String,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchChangeKind {
+    Created,
+    Moved,
+    Deleted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchChange {
+    pub name: String,
+    pub kind: BranchChangeKind,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// What changed between two [`RepoOperation`]s, in the spirit of `jj op diff`.
+///
+/// Branch positions are rebuilt from each branch's reflog, so branches that were deleted since
+/// (and took their reflog with them) do not show up. Commit lists stop after 100 entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationDiff {
+    pub head_before: String,
+    pub head_after: String,
+    pub branch_changes: Vec<BranchChange>,
+    pub commits_added: Vec<OperationCommit>,
+    pub commits_removed: Vec<OperationCommit>,
+    /// Paths whose contents differ between the two HEAD commits.
+    pub changed_paths: Vec<String>,
+}
+
+impl OperationDiff {
+    pub fn is_empty(&self) -> bool {
+        self.head_before == self.head_after
+            && self.branch_changes.is_empty()
+            && self.commits_added.is_empty()
+            && self.commits_removed.is_empty()
+    }
+}
+
+/// The repository state after [`restore_repo_operation`] moved it back to an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationRestore {
+    pub head_commit_id: String,
+    /// The branch HEAD is attached to afterwards, or `None` when it was left detached.
+    pub branch: Option<String>,
+    /// Local branches whose tips were moved, sorted by name.
+    pub moved_branches: Vec<String>,
+}
+
+/// Most recent operations first, read from the HEAD reflog.
+pub fn load_repo_operations(repo_root: &Path, limit: usize) -> Result<Vec<RepoOperation>> {
+    let repo = open_git2_repo(repo_root)?;
+    let reflog = match repo.reflog("HEAD") {
+        Ok(reflog) => reflog,
+        Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(anyhow::Error::new(err).context("failed to read the HEAD reflog")),
+    };
+
+    Ok(reflog
+        .iter()
+        .take(limit)
+        .enumerate()
+        .map(|(index, entry)| RepoOperation {
+            index,
+            head_commit_id: entry.id_new().to_string(),
+            unix_time: entry.committer().when().seconds(),
+            description: entry.message().unwrap_or_default().to_string(),
+        })
+        .collect())
+}
+
+/// Compares the repository as it was right after `before` with the state right after `after`.
+pub fn diff_repo_operations(
+    repo_root: &Path,
+    before: &RepoOperation,
+    after: &RepoOperation,
+) -> Result<OperationDiff> {
+    let repo = open_git2_repo(repo_root)?;
+    let head_before = parse_commit_id(&repo, before.head_commit_id.as_str())?;
+    let head_after = parse_commit_id(&repo, after.head_commit_id.as_str())?;
+
+    let head_log = load_head_log(&repo)?;
+    let branches_before = branch_tips_at(&repo, &head_log, before)?;
+    let branches_after = branch_tips_at(&repo, &head_log, after)?;
+    let branch_changes = branch_changes(&branches_before, &branches_after);
+
+    let tips_before = tips(head_before, &branches_before);
+    let tips_after = tips(head_after, &branches_after);
+    let commits_added = commits_between(&repo, &tips_before, &tips_after)?;
+    let commits_removed = commits_between(&repo, &tips_after, &tips_before)?;
+
+    Ok(OperationDiff {
+        head_before: head_before.to_string(),
+        head_after: head_after.to_string(),
+        branch_changes,
+        commits_added,
+        commits_removed,
+        changed_paths: changed_paths(&repo, head_before, head_after)?,
+    })
+}
+
+/// Moves HEAD and every local branch back to where they were right after `operation`, like
+/// `jj op restore`. The working tree is checked out at the restored HEAD, so this refuses to
+/// run while tracked files have uncommitted changes.
+///
+/// Branches created after the operation are kept as they are, and branches deleted since
+/// cannot be brought back because their reflog went with them.
+pub fn restore_repo_operation(
+    repo_root: &Path,
+    operation: &RepoOperation,
+) -> Result<OperationRestore> {
+    let repo = open_git2_repo(repo_root)?;
+    ensure_restore_worktree_is_clean(&repo)?;
+    let head = parse_commit_id(&repo, operation.head_commit_id.as_str())?;
+    let branch_tips = branch_tips_at(&repo, &load_head_log(&repo)?, operation)?;
+
+    // Stay on the current branch when it pointed at the restored commit; otherwise pick any
+    // branch that did, and detach HEAD when none did.
+    let current_branch = repo
+        .head()
+        .ok()
+        .filter(|head| head.is_branch())
+        .and_then(|head| head.shorthand().map(str::to_string));
+    let branch = current_branch
+        .filter(|name| branch_tips.get(name) == Some(&head))
+        .or_else(|| {
+            branch_tips
+                .iter()
+                .find(|(_, tip)| **tip == head)
+                .map(|(name, _)| name.clone())
+        });
+
+    let target = repo.find_commit(head)?;
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.safe();
+    repo.checkout_tree(target.as_object(), Some(&mut checkout))
+        .context("failed to check out the restored operation")?;
+
+    let log_message = format!("hunk: restore operation {}", operation.index);
+    let mut moved_branches = Vec::new();
+    for (name, tip) in &branch_tips {
+        let Ok(mut branch_ref) = repo.find_branch(name, BranchType::Local) else {
+            continue;
+        };
+        if branch_ref.get().target() == Some(*tip) {
+            continue;
+        }
+        branch_ref
+            .get_mut()
+            .set_target(*tip, log_message.as_str())
+            .with_context(|| format!("failed to move branch '{name}'"))?;
+        moved_branches.push(name.clone());
+    }
+
+    // Re-pointing HEAD when it already matches would add a no-op entry to the HEAD reflog,
+    // and the next undo would land on it instead of the operation before.
+    let head_ref = repo.head().ok();
+    match branch.as_deref() {
+        Some(name) => {
+            let ref_name = format!("refs/heads/{name}");
+            if head_ref.as_ref().and_then(|head| head.name()) != Some(ref_name.as_str()) {
+                repo.set_head(ref_name.as_str())
+                    .with_context(|| format!("failed to attach HEAD to branch '{name}'"))?;
+            }
+        }
+        None => {
+            let detached_at_head =
+                repo.head_detached()? && head_ref.and_then(|head| head.target()) == Some(head);
+            if !detached_at_head {
+                repo.set_head_detached(head)
+                    .context("failed to detach HEAD at the restored commit")?;
+            }
+        }
+    }
+
+    Ok(OperationRestore {
+        head_commit_id: head.to_string(),
+        branch,
+        moved_branches,
+    })
+}
+
+/// Undoes the most recent operation by restoring the one before it, like `jj undo`.
+pub fn undo_repo_operation(repo_root: &Path) -> Result<OperationRestore> {
+    let operations = load_repo_operations(repo_root, 2)?;
+    let Some(previous) = operations.get(1) else {
+        bail!("there is no earlier operation to undo to");
+    };
+    restore_repo_operation(repo_root, previous)
+}
+
+fn ensure_restore_worktree_is_clean(repo: &Repository) -> Result<()> {
+    let statuses = load_statuses(repo, || {
+        "failed to inspect worktree status before restoring".to_string()
+    })?;
+    if statuses
+        .iter()
+        .any(|entry| entry.status() != git2::Status::WT_NEW)
+    {
+        bail!("commit, stash, or discard local changes before restoring an operation");
+    }
+    Ok(())
+}
+
+fn parse_commit_id(repo: &Repository, commit_id: &str) -> Result<Oid> {
+    let oid = Oid::from_str(commit_id).with_context(|| format!("invalid commit id {commit_id}"))?;
+    if oid.is_zero() {
+        bail!("the operation has no HEAD commit");
+    }
+    repo.find_commit(oid)
+        .with_context(|| format!("commit {commit_id} is no longer in the repository"))?;
+    Ok(oid)
+}
+
+/// The parts of a reflog entry that identify one ref update. Git writes the same entry to the
+/// branch's reflog and to HEAD's when HEAD is attached to the branch that moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReflogUpdate {
+    id_old: Oid,
+    id_new: Oid,
+    unix_time: i64,
+    message: String,
+}
+
+fn read_reflog_updates(repo: &Repository, name: &str) -> Result<Vec<ReflogUpdate>> {
+    let reflog = repo
+        .reflog(name)
+        .with_context(|| format!("failed to read the {name} reflog"))?;
+    Ok(reflog
+        .iter()
+        .map(|entry| ReflogUpdate {
+            id_old: entry.id_old(),
+            id_new: entry.id_new(),
+            unix_time: entry.committer().when().seconds(),
+            message: entry.message().unwrap_or_default().to_string(),
+        })
+        .collect())
+}
+
+fn load_head_log(repo: &Repository) -> Result<Vec<ReflogUpdate>> {
+    read_reflog_updates(repo, "HEAD")
+}
+
+/// Where `operation` sits in `head_log` now. Its recorded index shifts as new operations land,
+/// so fall back to the newest entry with the same commit, message and time.
+fn operation_position(head_log: &[ReflogUpdate], operation: &RepoOperation) -> Result<usize> {
+    let matches = |entry: &ReflogUpdate| {
+        entry.id_new.to_string() == operation.head_commit_id
+            && entry.message == operation.description
+            && entry.unix_time == operation.unix_time
+    };
+    if head_log.get(operation.index).is_some_and(matches) {
+        return Ok(operation.index);
+    }
+    head_log.iter().position(matches).with_context(|| {
+        format!(
+            "operation {} is no longer in the HEAD reflog",
+            operation.index
+        )
+    })
+}
+
+/// Where each local branch pointed right after `operation`, according to its reflog.
+///
+/// Reflog times only have one-second resolution, so branch updates that Git also wrote to the
+/// HEAD reflog are ordered by their position there. Only updates that bypassed HEAD, such as
+/// moving another branch, fall back to comparing times.
+fn branch_tips_at(
+    repo: &Repository,
+    head_log: &[ReflogUpdate],
+    operation: &RepoOperation,
+) -> Result<BTreeMap<String, Oid>> {
+    let position = operation_position(head_log, operation)?;
+    let mut tips = BTreeMap::new();
+    for branch in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch?;
+        let Some(name) = branch.name()?.map(str::to_string) else {
+            continue;
+        };
+        let Some(ref_name) = branch.get().name() else {
+            continue;
+        };
+        let Ok(updates) = read_reflog_updates(repo, ref_name) else {
+            continue;
+        };
+        let tip = branch_tip_at(&updates, head_log, position, operation.unix_time)
+            .filter(|oid| !oid.is_zero());
+        if let Some(tip) = tip {
+            tips.insert(name, tip);
+        }
+    }
+    Ok(tips)
+}
+
+/// The newest of a branch's `updates` that happened no later than HEAD reflog entry `position`.
+fn branch_tip_at(
+    updates: &[ReflogUpdate],
+    head_log: &[ReflogUpdate],
+    position: usize,
+    unix_time: i64,
+) -> Option<Oid> {
+    // Both reflogs run newest first, so each update can only match a HEAD entry older than the
+    // one the previous update matched.
+    let mut next_head_entry = 0;
+    let head_positions = updates
+        .iter()
+        .map(|update| {
+            let matched = head_log[next_head_entry..]
+                .iter()
+                .position(|entry| entry == update)
+                .map(|offset| next_head_entry + offset);
+            if let Some(matched) = matched {
+                next_head_entry = matched + 1;
+            }
+            matched
+        })
+        .collect::<Vec<_>>();
+
+    updates.iter().enumerate().find_map(|(ix, update)| {
+        let happened_by_operation = match head_positions[ix] {
+            Some(head_position) => head_position >= position,
+            // An older update that came after the operation means this one did too.
+            None if head_positions[ix + 1..]
+                .iter()
+                .flatten()
+                .any(|head_position| *head_position < position) =>
+            {
+                false
+            }
+            None => update.unix_time <= unix_time,
+        };
+        happened_by_operation.then_some(update.id_new)
+    })
+}
+
+fn branch_changes(
+    before: &BTreeMap<String, Oid>,
+    after: &BTreeMap<String, Oid>,
+) -> Vec<BranchChange> {
+    let mut names = before.keys().chain(after.keys()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let before = before.get(name).copied();
+            let after = after.get(name).copied();
+            let kind = match (before, after) {
+                (None, Some(_)) => BranchChangeKind::Created,
+                (Some(_), None) => BranchChangeKind::Deleted,
+                (Some(before), Some(after)) if before != after => BranchChangeKind::Moved,
+                _ => return None,
+            };
+            Some(BranchChange {
+                name: name.clone(),
+                kind,
+                before: before.map(|oid| oid.to_string()),
+                after: after.map(|oid| oid.to_string()),
+            })
+        })
+        .collect()
+}
+
+fn tips(head: Oid, branches: &BTreeMap<String, Oid>) -> Vec<Oid> {
+    let mut tips = branches.values().copied().collect::<Vec<_>>();
+    tips.push(head);
+    tips.sort();
+    tips.dedup();
+    tips
+}
+
+/// Commits reachable from `include` but not from `exclude`, newest first.
+fn commits_between(
+    repo: &Repository,
+    exclude: &[Oid],
+    include: &[Oid],
+) -> Result<Vec<OperationCommit>> {
+    let mut walk = repo.revwalk().context("failed to start revision walk")?;
+    walk.set_sorting(git2::Sort::TIME)?;
+    for oid in include {
+        walk.push(*oid)?;
+    }
+    for oid in exclude {
+        walk.hide(*oid)?;
+    }
+
+    let mut commits = Vec::new();
+    for oid in walk.take(OPERATION_DIFF_COMMIT_LIMIT) {
+        let commit = repo.find_commit(oid?)?;
+        commits.push(OperationCommit {
+            commit_id: commit.id().to_string(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+        });
+    }
+    Ok(commits)
+}
+
+fn changed_paths(repo: &Repository, before: Oid, after: Oid) -> Result<Vec<String>> {
+    if before == after {
+        return Ok(Vec::new());
+    }
+    let before_tree = repo.find_commit(before)?.tree()?;
+    let after_tree = repo.find_commit(after)?.tree()?;
+    let diff = repo
+        .diff_tree_to_tree(Some(&before_tree), Some(&after_tree), None)
+        .context("failed to diff operation trees")?;
+    Ok(diff
+        .deltas()
+        .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+        .map(|path| path.to_string_lossy().replace('\\', "/"))
+        .collect())
+}
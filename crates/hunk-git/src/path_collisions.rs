@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::git::{ChangedFile, FileStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathCollisionKind {
+    /// A path was renamed by changing only its case, which a case-insensitive filesystem
+    /// cannot represent as two entries.
+    CaseOnlyRename,
+    /// Two or more paths differ only by case and would overwrite each other on checkout.
+    CaseCollision,
+}
+
+/// A set of spellings that name the same file or directory on a case-insensitive filesystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathCollision {
+    pub kind: PathCollisionKind,
+    /// The colliding spellings, sorted. Directory collisions list the directory prefixes.
+    pub paths: Vec<String>,
+}
+
+impl PathCollision {
+    /// Stable identity used to remember that a collision was acknowledged.
+    pub fn key(&self) -> String {
+        self.paths.join("\n")
+    }
+
+    pub fn affects(&self, path: &str) -> bool {
+        self.paths.iter().any(|spelling| {
+            path == spelling
+                || path
+                    .strip_prefix(spelling.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        let spellings = self.paths.join(" / ");
+        match self.kind {
+            PathCollisionKind::CaseOnlyRename => format!("Case-only rename: {spellings}"),
+            PathCollisionKind::CaseCollision => format!("Paths differ only by case: {spellings}"),
+        }
+    }
+}
+
+/// Finds paths that collide once case is ignored, across the index and the changed files.
+///
+/// Only the outermost collision is reported, so two spellings of one directory produce a
+/// single entry rather than one per file beneath it. A pair of spellings where one side is
+/// only deleted and the other only added is reported as a case-only rename.
+pub fn detect_path_collisions<'a>(
+    tracked_paths: impl IntoIterator<Item = &'a str>,
+    files: &[ChangedFile],
+) -> Vec<PathCollision> {
+    let mut spellings_by_folded = BTreeMap::<String, BTreeSet<String>>::new();
+    let mut record = |path: &str| {
+        let mut end = 0;
+        for component in path.split('/') {
+            end += component.len();
+            let prefix = &path[..end];
+            spellings_by_folded
+                .entry(prefix.to_lowercase())
+                .or_default()
+                .insert(prefix.to_string());
+            end += 1;
+        }
+    };
+    for path in tracked_paths {
+        if !path.is_empty() {
+            record(path);
+        }
+    }
+    for file in files {
+        if !file.path.is_empty() {
+            record(file.path.as_str());
+        }
+    }
+
+    let colliding = spellings_by_folded
+        .into_iter()
+        .filter(|(_, spellings)| spellings.len() > 1)
+        .collect::<BTreeMap<_, _>>();
+
+    colliding
+        .iter()
+        .filter(|(folded, _)| {
+            !colliding.keys().any(|ancestor| {
+                folded
+                    .strip_prefix(ancestor.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+            })
+        })
+        .map(|(_, spellings)| {
+            let paths = spellings.iter().cloned().collect::<Vec<_>>();
+            let kind = if is_case_only_rename(paths.as_slice(), files) {
+                PathCollisionKind::CaseOnlyRename
+            } else {
+                PathCollisionKind::CaseCollision
+            };
+            PathCollision { kind, paths }
+        })
+        .collect()
+}
+
+fn is_case_only_rename(paths: &[String], files: &[ChangedFile]) -> bool {
+    let [first, second] = paths else {
+        return false;
+    };
+    let side_statuses = |spelling: &str| {
+        files
+            .iter()
+            .filter(|file| {
+                file.path == spelling
+                    || file
+                        .path
+                        .strip_prefix(spelling)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+            .map(|file| file.status)
+            .collect::<Vec<_>>()
+    };
+    let only_removed = |statuses: &[FileStatus]| {
+        !statuses.is_empty() && statuses.iter().all(|status| *status == FileStatus::Deleted)
+    };
+    let only_added = |statuses: &[FileStatus]| {
+        !statuses.is_empty()
+            && statuses.iter().all(|status| {
+                matches!(
+                    status,
+                    FileStatus::Added | FileStatus::Untracked | FileStatus::Renamed
+                )
+            })
+    };
+
+    let first = side_statuses(first.as_str());
+    let second = side_statuses(second.as_str());
+    (only_removed(&first) && only_added(&second)) || (only_added(&first) && only_removed(&second))
+}
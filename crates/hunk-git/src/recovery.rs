@@ -0,0 +1,66 @@
+use crate::error::GitError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitRecoveryAction {
+    ConfigureIdentity,
+    ConfigureRemote,
+    ResolveConflicts,
+}
+
+impl GitRecoveryAction {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::ConfigureIdentity => "Set Identity",
+            Self::ConfigureRemote => "Add Remote",
+            Self::ResolveConflicts => "Open Conflicts",
+        }
+    }
+}
+
+pub fn recovery_action_for_error(err: &anyhow::Error) -> Option<GitRecoveryAction> {
+    if let Some(git_error) = GitError::classify(err) {
+        match git_error {
+            GitError::MissingIdentity => return Some(GitRecoveryAction::ConfigureIdentity),
+            GitError::RemoteNotConfigured => return Some(GitRecoveryAction::ConfigureRemote),
+            GitError::Conflicted => return Some(GitRecoveryAction::ResolveConflicts),
+            _ => {}
+        }
+    }
+
+    // The git CLI commit path only reports failures as text on stderr.
+    err.chain().find_map(|cause| {
+        cause
+            .downcast_ref::<git2::Error>()
+            .and_then(recovery_action_for_git2_error)
+            .or_else(|| recovery_action_for_message(cause.to_string().as_str()))
+    })
+}
+
+pub fn recovery_action_for_message(message: &str) -> Option<GitRecoveryAction> {
+    let message = message.to_ascii_lowercase();
+    if message.contains("user.name")
+        || message.contains("user.email")
+        || message.contains("author identity unknown")
+        || message.contains("please tell me who you are")
+        || message.contains("failed to resolve git author signature")
+    {
+        return Some(GitRecoveryAction::ConfigureIdentity);
+    }
+    if message.contains("no git remote configured") {
+        return Some(GitRecoveryAction::ConfigureRemote);
+    }
+    if message.contains("conflicted files") || message.contains("conflicted changes") {
+        return Some(GitRecoveryAction::ResolveConflicts);
+    }
+    None
+}
+
+fn recovery_action_for_git2_error(err: &git2::Error) -> Option<GitRecoveryAction> {
+    if err.class() == git2::ErrorClass::Config && err.code() == git2::ErrorCode::NotFound {
+        return recovery_action_for_message(err.message());
+    }
+    if err.code() == git2::ErrorCode::Conflict || err.code() == git2::ErrorCode::Unmerged {
+        return Some(GitRecoveryAction::ResolveConflicts);
+    }
+    None
+}
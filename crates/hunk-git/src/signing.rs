@@ -0,0 +1,233 @@
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context as _, Result, anyhow};
+
+use crate::command_env::git_cli_command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SigningFormat {
+    OpenPgp,
+    Ssh,
+    X509,
+}
+
+// Signed commits are built in-process: libgit2 produces the commit buffer, the configured
+// signing program signs it, and the signed object is written without needing the git CLI.
+pub(crate) fn create_signed_commit(
+    repo: &git2::Repository,
+    signature: &git2::Signature<'_>,
+    message: &str,
+    tree: &git2::Tree<'_>,
+    parents: &[&git2::Commit<'_>],
+) -> Result<git2::Oid> {
+    let commit_id = write_signed_commit(repo, signature, message, tree, parents)?;
+    update_head_to_commit(repo, commit_id, message)?;
+    Ok(commit_id)
+}
+
+/// Writes a signed commit object without moving HEAD, for commits that land on a branch other
+/// than the checked-out one.
+pub(crate) fn write_signed_commit(
+    repo: &git2::Repository,
+    signature: &git2::Signature<'_>,
+    message: &str,
+    tree: &git2::Tree<'_>,
+    parents: &[&git2::Commit<'_>],
+) -> Result<git2::Oid> {
+    write_signed_commit_as(repo, signature, signature, message, tree, parents)
+}
+
+/// Like [`write_signed_commit`], but keeps a separate author, as when rewording a commit that
+/// someone else wrote. The committer's identity picks the default signing key.
+pub(crate) fn write_signed_commit_as(
+    repo: &git2::Repository,
+    author: &git2::Signature<'_>,
+    committer: &git2::Signature<'_>,
+    message: &str,
+    tree: &git2::Tree<'_>,
+    parents: &[&git2::Commit<'_>],
+) -> Result<git2::Oid> {
+    let buffer = repo
+        .commit_create_buffer(author, committer, message, tree, parents)
+        .context("failed to build commit buffer for signing")?;
+    let buffer = buffer
+        .as_str()
+        .ok_or_else(|| anyhow!("commit buffer is not valid UTF-8"))?
+        .to_string();
+
+    let config = repo
+        .config()
+        .context("failed to load Git config for signing")?;
+    let commit_signature = sign_buffer(&config, committer, buffer.as_str())?;
+    repo.commit_signed(buffer.as_str(), commit_signature.as_str(), None)
+        .context("failed to write signed commit")
+}
+
+fn sign_buffer(
+    config: &git2::Config,
+    signature: &git2::Signature<'_>,
+    buffer: &str,
+) -> Result<String> {
+    let format = match config.get_string("gpg.format") {
+        Ok(value) if value.eq_ignore_ascii_case("ssh") => SigningFormat::Ssh,
+        Ok(value) if value.eq_ignore_ascii_case("x509") => SigningFormat::X509,
+        Ok(value) if value.eq_ignore_ascii_case("openpgp") => SigningFormat::OpenPgp,
+        Ok(value) => return Err(anyhow!("unsupported gpg.format '{value}'")),
+        Err(_) => SigningFormat::OpenPgp,
+    };
+    let signing_key = config
+        .get_string("user.signingKey")
+        .ok()
+        .filter(|key| !key.trim().is_empty());
+
+    match format {
+        SigningFormat::OpenPgp | SigningFormat::X509 => {
+            let (program_key, default_program) = if format == SigningFormat::X509 {
+                ("gpg.x509.program", "gpgsm")
+            } else {
+                ("gpg.program", "gpg")
+            };
+            let program = config
+                .get_string(program_key)
+                .unwrap_or_else(|_| default_program.to_string());
+            let key = signing_key.unwrap_or_else(|| committer_key_id(signature));
+            let mut command = git_cli_command(program.as_str());
+            command.args(["--status-fd=2", "-bsau", key.as_str()]);
+            run_signing_program(command, program.as_str(), buffer)
+        }
+        SigningFormat::Ssh => {
+            let program = config
+                .get_string("gpg.ssh.program")
+                .unwrap_or_else(|_| "ssh-keygen".to_string());
+            let signing_key = signing_key
+                .ok_or_else(|| anyhow!("SSH commit signing requires user.signingKey"))?;
+            let key_file = SshKeyFile::new(signing_key.as_str())?;
+            let mut command = git_cli_command(program.as_str());
+            command
+                .args(["-Y", "sign", "-n", "git", "-f"])
+                .arg(key_file.path());
+            run_signing_program(command, program.as_str(), buffer)
+        }
+    }
+}
+
+fn committer_key_id(signature: &git2::Signature<'_>) -> String {
+    match (signature.name(), signature.email()) {
+        (Some(name), Some(email)) => format!("{name} <{email}>"),
+        (None, Some(email)) => email.to_string(),
+        (Some(name), None) => name.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+fn run_signing_program(mut command: Command, program: &str, buffer: &str) -> Result<String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to launch signing program '{program}'"))?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("failed to open stdin for signing program '{program}'"))?
+        .write_all(buffer.as_bytes())
+        .with_context(|| format!("failed to send commit to signing program '{program}'"))?;
+    let output = child
+        .wait_with_output()
+        .with_context(|| format!("failed to wait for signing program '{program}'"))?;
+
+    let stdout = String::from_utf8_lossy(output.stdout.as_slice()).to_string();
+    if output.status.success() && !stdout.trim().is_empty() {
+        return Ok(stdout);
+    }
+
+    let stderr = String::from_utf8_lossy(output.stderr.as_slice())
+        .trim()
+        .to_string();
+    let details = if stderr.is_empty() {
+        format!("exited with status {}", output.status)
+    } else {
+        stderr
+    };
+    Err(anyhow!("signing with '{program}' failed: {details}"))
+}
+
+fn update_head_to_commit(
+    repo: &git2::Repository,
+    commit_id: git2::Oid,
+    message: &str,
+) -> Result<()> {
+    let reflog_message = format!("commit: {}", message.lines().next().unwrap_or_default());
+    let head = repo
+        .find_reference("HEAD")
+        .context("failed to resolve HEAD for signed commit")?;
+    match head.symbolic_target() {
+        Some(target) => {
+            repo.reference(target, commit_id, true, reflog_message.as_str())
+                .with_context(|| format!("failed to update {target} to signed commit"))?;
+        }
+        None => {
+            repo.set_head_detached(commit_id)
+                .context("failed to move detached HEAD to signed commit")?;
+        }
+    }
+    Ok(())
+}
+
+struct SshKeyFile {
+    path: PathBuf,
+    temporary: bool,
+}
+
+impl SshKeyFile {
+    fn new(signing_key: &str) -> Result<Self> {
+        let signing_key = signing_key.trim();
+        let literal_key = signing_key
+            .strip_prefix("key::")
+            .or_else(|| signing_key.starts_with("ssh-").then_some(signing_key));
+        if let Some(literal_key) = literal_key {
+            let path = std::env::temp_dir().join(format!(
+                "hunk-signing-key-{}-{}.pub",
+                std::process::id(),
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos()
+            ));
+            std::fs::write(path.as_path(), format!("{literal_key}\n"))
+                .context("failed to write temporary SSH signing key")?;
+            return Ok(Self {
+                path,
+                temporary: true,
+            });
+        }
+
+        let path = match signing_key.strip_prefix("~/") {
+            Some(relative) => std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(PathBuf::from)
+                .ok_or_else(|| anyhow!("failed to resolve home directory for user.signingKey"))?
+                .join(relative),
+            None => PathBuf::from(signing_key),
+        };
+        Ok(Self {
+            path,
+            temporary: false,
+        })
+    }
+
+    fn path(&self) -> &std::path::Path {
+        self.path.as_path()
+    }
+}
+
+impl Drop for SshKeyFile {
+    fn drop(&mut self) {
+        if self.temporary {
+            let _ = std::fs::remove_file(self.path.as_path());
+        }
+    }
+}
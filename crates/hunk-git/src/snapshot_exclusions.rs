@@ -0,0 +1,127 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, anyhow};
+
+use crate::git::invalidate_repo_metadata_caches;
+use crate::git2_helpers::open_git2_repo;
+
+const BLOCK_START: &str = "# BEGIN hunk snapshot exclusions";
+const BLOCK_END: &str = "# END hunk snapshot exclusions";
+
+/// Reads the repository's snapshot exclusions: gitignore-style patterns for local paths the
+/// working-copy snapshot never starts tracking, such as scratch directories.
+///
+/// The patterns live in a Hunk-managed block of `.git/info/exclude`, so they stay local to this
+/// clone and `git status` agrees with the snapshot. Files that are already tracked keep showing
+/// their changes.
+pub fn read_snapshot_exclusions(repo_root: &Path) -> Result<Vec<String>> {
+    let path = info_exclude_path(repo_root)?;
+    let contents = match fs::read_to_string(path.as_path()) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(anyhow::Error::new(err))
+                .with_context(|| format!("failed to read {}", path.display()));
+        }
+    };
+
+    Ok(contents
+        .lines()
+        .skip_while(|line| line.trim() != BLOCK_START)
+        .skip(1)
+        .take_while(|line| line.trim() != BLOCK_END)
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToOwned::to_owned)
+        .collect())
+}
+
+/// Replaces the Hunk-managed block of `.git/info/exclude`, leaving any hand-written patterns
+/// around it untouched. An empty list removes the block.
+pub fn write_snapshot_exclusions(repo_root: &Path, patterns: &[String]) -> Result<()> {
+    let patterns = normalize_snapshot_exclusions(patterns)?;
+    let path = info_exclude_path(repo_root)?;
+    let existing = match fs::read_to_string(path.as_path()) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => {
+            return Err(anyhow::Error::new(err))
+                .with_context(|| format!("failed to read {}", path.display()));
+        }
+    };
+
+    let mut kept = Vec::new();
+    let mut in_block = false;
+    for line in existing.lines() {
+        match line.trim() {
+            BLOCK_START => in_block = true,
+            BLOCK_END if in_block => in_block = false,
+            _ if in_block => {}
+            _ => kept.push(line),
+        }
+    }
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
+    }
+
+    let mut updated = kept.join("\n");
+    if !patterns.is_empty() {
+        if !updated.is_empty() {
+            updated.push_str("\n\n");
+        }
+        updated.push_str(BLOCK_START);
+        updated.push('\n');
+        for pattern in &patterns {
+            updated.push_str(pattern);
+            updated.push('\n');
+        }
+        updated.push_str(BLOCK_END);
+    }
+    if !updated.is_empty() {
+        updated.push('\n');
+    }
+    if updated == existing {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    fs::write(path.as_path(), updated)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    invalidate_repo_metadata_caches(repo_root);
+    Ok(())
+}
+
+/// Trims and de-duplicates patterns, rejecting the ones an exclude file cannot express.
+pub fn normalize_snapshot_exclusions(patterns: &[String]) -> Result<Vec<String>> {
+    let mut normalized = Vec::<String>::new();
+    for pattern in patterns {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            continue;
+        }
+        if pattern.starts_with('!') {
+            return Err(anyhow!(
+                "'{pattern}' re-includes paths; snapshot exclusions can only exclude"
+            ));
+        }
+        if pattern.starts_with('#') {
+            return Err(anyhow!("'{pattern}' would be read as a comment"));
+        }
+        if pattern.contains(['\n', '\r']) {
+            return Err(anyhow!("exclusion patterns must be a single line"));
+        }
+        if !normalized.iter().any(|existing| existing == pattern) {
+            normalized.push(pattern.to_string());
+        }
+    }
+    Ok(normalized)
+}
+
+fn info_exclude_path(repo_root: &Path) -> Result<PathBuf> {
+    let repo = open_git2_repo(repo_root)?;
+    Ok(repo.commondir().join("info").join("exclude"))
+}
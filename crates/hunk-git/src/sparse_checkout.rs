@@ -0,0 +1,131 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{Config, IndexEntryExtendedFlag, Repository};
+
+use crate::command_env::git_cli_command;
+use crate::git2_helpers::open_git2_repo;
+
+/// The sparse-checkout setup of a working tree, read from `core.sparseCheckout` and
+/// `$GIT_DIR/info/sparse-checkout`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseCheckout {
+    /// Cone mode lists directories; the older mode lists gitignore-style patterns.
+    pub cone: bool,
+    pub patterns: Vec<String>,
+}
+
+/// Returns the sparse-checkout setup, or `None` when the working tree is a full checkout.
+pub fn load_sparse_checkout(repo_root: &Path) -> Result<Option<SparseCheckout>> {
+    let repo = open_git2_repo(repo_root)?;
+    if !sparse_config_bool(&repo, "core.sparseCheckout")? {
+        return Ok(None);
+    }
+
+    let patterns_path = repo.path().join("info").join("sparse-checkout");
+    let patterns = match fs::read_to_string(patterns_path.as_path()) {
+        Ok(contents) => contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(ToOwned::to_owned)
+            .collect(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", patterns_path.display()));
+        }
+    };
+
+    Ok(Some(SparseCheckout {
+        cone: sparse_config_bool(&repo, "core.sparseCheckoutCone")?,
+        patterns,
+    }))
+}
+
+/// `git sparse-checkout` writes its settings to the per-worktree `config.worktree`, which older
+/// libgit2 builds don't layer into the repository config, so check that file directly too.
+fn sparse_config_bool(repo: &Repository, key: &str) -> Result<bool> {
+    let config = repo
+        .config()
+        .context("failed to read Git config for sparse checkout")?;
+    if let Ok(value) = config.get_bool(key) {
+        return Ok(value);
+    }
+
+    let worktree_config_path = repo.path().join("config.worktree");
+    if !worktree_config_path.is_file() {
+        return Ok(false);
+    }
+    let worktree_config = Config::open(worktree_config_path.as_path())
+        .with_context(|| format!("failed to read {}", worktree_config_path.display()))?;
+    Ok(worktree_config.get_bool(key).unwrap_or(false))
+}
+
+/// True when `path` is tracked but left out of the working tree by sparse checkout. Directories
+/// count as excluded when anything tracked beneath them is.
+pub fn path_excluded_by_sparse_checkout(repo_root: &Path, path: &str) -> Result<bool> {
+    let repo = open_git2_repo(repo_root)?;
+    let index = repo
+        .index()
+        .context("failed to read Git index for sparse checkout")?;
+    let path = path.trim_matches('/');
+    let directory_prefix = format!("{path}/");
+    let skip_worktree = IndexEntryExtendedFlag::SKIP_WORKTREE.bits();
+
+    Ok(index.iter().any(|entry| {
+        let entry_path = String::from_utf8_lossy(entry.path.as_slice());
+        (entry_path == path || entry_path.starts_with(directory_prefix.as_str()))
+            && entry.flags_extended & skip_worktree != 0
+    }))
+}
+
+/// The pattern that brings `path` back into the working tree. Cone mode can only add whole
+/// directories, so files widen to their parent directory.
+pub fn sparse_checkout_widen_pattern(sparse: &SparseCheckout, path: &str) -> Result<String> {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        return Err(anyhow!("cannot widen sparse checkout for an empty path"));
+    }
+    if !sparse.cone {
+        return Ok(format!("/{path}"));
+    }
+
+    match path.rsplit_once('/') {
+        Some((directory, _)) => Ok(directory.to_string()),
+        None => Err(anyhow!(
+            "'{path}' is at the repository root, which cone-mode sparse checkout always includes"
+        )),
+    }
+}
+
+/// Adds `pattern` to the sparse-checkout set and materializes the matching files. libgit2 does
+/// not rewrite sparse-checkout state, so this needs the git CLI.
+pub fn widen_sparse_checkout(repo_root: &Path, pattern: &str) -> Result<()> {
+    let output = git_cli_command("git")
+        .current_dir(repo_root)
+        .args(["sparse-checkout", "add", "--", pattern])
+        .output();
+    let output = match output {
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            return Err(anyhow!(
+                "widening a sparse checkout needs the git CLI, which was not found on PATH"
+            ));
+        }
+        output => output.context("failed to launch git sparse-checkout")?,
+    };
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(output.stderr.as_slice())
+        .trim()
+        .to_string();
+    let details = if stderr.is_empty() {
+        format!("exited with status {}", output.status)
+    } else {
+        stderr
+    };
+    Err(anyhow!("git sparse-checkout add failed: {details}"))
+}
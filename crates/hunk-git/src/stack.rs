@@ -0,0 +1,350 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use git2::{BranchType, Commit, Oid, Repository, Sort};
+
+use crate::compare::resolve_default_base_branch_name;
+use crate::error::GitError;
+use crate::git2_helpers::{load_statuses, open_git2_repo};
+use crate::signing::write_signed_commit_as;
+
+/// Stacks longer than this are refused rather than listed, since editing them commit by commit
+/// is not what the stack editor is for.
+pub const STACK_COMMIT_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackCommit {
+    pub commit_id: String,
+    pub subject: String,
+    pub author_name: String,
+    pub committed_unix_time: i64,
+}
+
+/// The commits the checked-out branch has on top of its base, as `git rebase -i` would list
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchStack {
+    pub branch: String,
+    /// The branch the stack starts from: the upstream when one is set, since commits already
+    /// pushed there must not be rewritten, and otherwise the default branch.
+    pub base_label: String,
+    pub base_commit_id: String,
+    /// Oldest first.
+    pub commits: Vec<StackCommit>,
+}
+
+impl BranchStack {
+    fn tip_commit_id(&self) -> &str {
+        self.commits
+            .last()
+            .map_or(self.base_commit_id.as_str(), |commit| {
+                commit.commit_id.as_str()
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackAction {
+    /// Keep the commit as its own commit.
+    Pick,
+    /// Fold the commit into the kept commit before it, joining their messages.
+    Squash,
+    /// Leave the commit out of the rewritten stack.
+    Drop,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackPlanEntry {
+    pub commit_id: String,
+    pub action: StackAction,
+}
+
+impl StackPlanEntry {
+    pub fn pick(commit_id: impl Into<String>) -> Self {
+        Self {
+            commit_id: commit_id.into(),
+            action: StackAction::Pick,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackRewrite {
+    pub branch: String,
+    pub head_commit_id: String,
+    /// Commits on the branch above the base afterwards.
+    pub commit_count: usize,
+}
+
+pub fn load_branch_stack(repo_root: &Path) -> Result<BranchStack> {
+    let repo = open_git2_repo(repo_root)?;
+    let head = repo.head().context("there are no commits to edit yet")?;
+    if !head.is_branch() {
+        bail!("check out a branch to edit its stack");
+    }
+    let branch_name = head.shorthand().unwrap_or_default().to_string();
+    let head_id = head
+        .peel_to_commit()
+        .context("there are no commits to edit yet")?
+        .id();
+    let branch = repo.find_branch(branch_name.as_str(), BranchType::Local)?;
+    let (base_label, base_tip) = stack_base(&repo, repo_root, &branch, branch_name.as_str())?;
+    let base = repo
+        .merge_base(head_id, base_tip)
+        .with_context(|| format!("'{branch_name}' shares no history with '{base_label}'"))?;
+
+    let mut walk = repo.revwalk()?;
+    walk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+    walk.push(head_id)?;
+    walk.hide(base)?;
+    let mut commits = Vec::new();
+    for oid in walk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() > 1 {
+            bail!(
+                "the stack contains merge commit {}, which cannot be reordered",
+                short_id(commit.id())
+            );
+        }
+        if commits.len() == STACK_COMMIT_LIMIT {
+            bail!("the stack has more than {STACK_COMMIT_LIMIT} commits on top of '{base_label}'");
+        }
+        commits.push(StackCommit {
+            commit_id: commit.id().to_string(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+            author_name: commit.author().name().unwrap_or_default().to_string(),
+            committed_unix_time: commit.time().seconds(),
+        });
+    }
+
+    Ok(BranchStack {
+        branch: branch_name,
+        base_label,
+        base_commit_id: base.to_string(),
+        commits,
+    })
+}
+
+/// Rewrites the stack in the order of `plan`, which must list every commit of `stack` once.
+///
+/// Commits are replayed in memory, so a conflict anywhere stops the edit before the branch or
+/// the working tree is touched. Commits whose position and contents do not change keep their
+/// ids. The branch moves in one step afterwards, and the working tree is checked out at the
+/// new tip, so this refuses to run while tracked files have uncommitted changes.
+pub fn apply_stack_plan(
+    repo_root: &Path,
+    stack: &BranchStack,
+    plan: &[StackPlanEntry],
+) -> Result<StackRewrite> {
+    let repo = open_git2_repo(repo_root)?;
+    ensure_stack_worktree_is_clean(&repo)?;
+    let head = repo.head().context("failed to resolve HEAD")?;
+    let head_id = head.target().map(|id| id.to_string());
+    if !head.is_branch()
+        || head.shorthand() != Some(stack.branch.as_str())
+        || head_id.as_deref() != Some(stack.tip_commit_id())
+    {
+        bail!(
+            "'{}' changed since the stack was loaded; reload it",
+            stack.branch
+        );
+    }
+    validate_stack_plan(stack, plan)?;
+
+    let committer = repo
+        .signature()
+        .map_err(|err| anyhow::Error::new(err).context(GitError::MissingIdentity))?;
+    let signing = repo.config()?.get_bool("commit.gpgSign").unwrap_or(false);
+    let mut tip = repo.find_commit(Oid::from_str(stack.base_commit_id.as_str())?)?;
+    let mut commit_count = 0;
+    for entry in plan {
+        let commit = repo.find_commit(Oid::from_str(entry.commit_id.as_str())?)?;
+        if entry.action == StackAction::Drop {
+            continue;
+        }
+        if entry.action == StackAction::Squash && commit_count == 0 {
+            bail!(
+                "{} cannot be squashed because no earlier commit is kept to squash it into",
+                short_id(commit.id())
+            );
+        }
+        // Nothing below this commit changed, so it can stay as it is.
+        if entry.action == StackAction::Pick && commit.parent_id(0).ok() == Some(tip.id()) {
+            tip = commit;
+            commit_count += 1;
+            continue;
+        }
+
+        let mut index = repo
+            .cherrypick_commit(&commit, &tip, 0, None)
+            .with_context(|| format!("failed to replay {}", short_id(commit.id())))?;
+        if index.has_conflicts() {
+            let paths = conflicted_paths(&index)?;
+            bail!(
+                "{} {} conflicts in {} after reordering; nothing was changed",
+                short_id(commit.id()),
+                commit.summary().unwrap_or_default(),
+                paths.join(", ")
+            );
+        }
+        let tree = repo.find_tree(index.write_tree_to(&repo)?)?;
+        let rewritten = if entry.action == StackAction::Squash {
+            let message = format!(
+                "{}\n\n{}\n",
+                tip.message().unwrap_or_default().trim_end(),
+                commit.message().unwrap_or_default().trim_end()
+            );
+            let parents = tip.parents().collect::<Vec<_>>();
+            let parent_refs = parents.iter().collect::<Vec<_>>();
+            write_stack_commit(
+                &repo,
+                &tip,
+                &committer,
+                message.as_str(),
+                &tree,
+                &parent_refs,
+                signing,
+            )?
+        } else {
+            commit_count += 1;
+            write_stack_commit(
+                &repo,
+                &commit,
+                &committer,
+                commit.message().unwrap_or_default(),
+                &tree,
+                &[&tip],
+                signing,
+            )?
+        };
+        tip = repo.find_commit(rewritten)?;
+    }
+
+    if tip.id().to_string() == stack.tip_commit_id() {
+        bail!("the stack is unchanged");
+    }
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.safe();
+    repo.checkout_tree(tip.as_object(), Some(&mut checkout))
+        .context("failed to check out the rewritten stack")?;
+    let mut branch = repo.find_branch(stack.branch.as_str(), BranchType::Local)?;
+    branch
+        .get_mut()
+        .set_target(tip.id(), "hunk: edit stack")
+        .with_context(|| format!("failed to move branch '{}'", stack.branch))?;
+
+    Ok(StackRewrite {
+        branch: stack.branch.clone(),
+        head_commit_id: tip.id().to_string(),
+        commit_count,
+    })
+}
+
+fn stack_base(
+    repo: &Repository,
+    repo_root: &Path,
+    branch: &git2::Branch<'_>,
+    branch_name: &str,
+) -> Result<(String, Oid)> {
+    match branch.upstream() {
+        Ok(upstream) => {
+            let label = upstream
+                .name()
+                .ok()
+                .flatten()
+                .unwrap_or_default()
+                .to_string();
+            let tip = upstream
+                .get()
+                .target()
+                .ok_or_else(|| anyhow!("upstream '{label}' does not point at a commit"))?;
+            return Ok((label, tip));
+        }
+        Err(err) if err.code() == git2::ErrorCode::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let Some(default_branch) = resolve_default_base_branch_name(repo_root)? else {
+        bail!("set an upstream for '{branch_name}' to find where its stack starts");
+    };
+    if let Ok(local) = repo.find_branch(default_branch.as_str(), BranchType::Local)
+        && let Some(tip) = local.get().target()
+    {
+        return Ok((default_branch, tip));
+    }
+    let suffix = format!("/{default_branch}");
+    for remote in repo.branches(Some(BranchType::Remote))? {
+        let (remote, _) = remote?;
+        let Some(name) = remote.name()?.map(str::to_string) else {
+            continue;
+        };
+        if name.ends_with(suffix.as_str())
+            && let Some(tip) = remote.get().target()
+        {
+            return Ok((name, tip));
+        }
+    }
+    bail!("set an upstream for '{branch_name}' to find where its stack starts")
+}
+
+fn validate_stack_plan(stack: &BranchStack, plan: &[StackPlanEntry]) -> Result<()> {
+    let expected = stack
+        .commits
+        .iter()
+        .map(|commit| commit.commit_id.as_str())
+        .collect::<BTreeSet<_>>();
+    let planned = plan
+        .iter()
+        .map(|entry| entry.commit_id.as_str())
+        .collect::<BTreeSet<_>>();
+    if plan.len() != stack.commits.len() || planned != expected {
+        bail!("the plan must list every commit of the stack exactly once");
+    }
+    Ok(())
+}
+
+fn ensure_stack_worktree_is_clean(repo: &Repository) -> Result<()> {
+    let statuses = load_statuses(repo, || {
+        "failed to inspect worktree status before editing the stack".to_string()
+    })?;
+    if statuses
+        .iter()
+        .any(|entry| entry.status() != git2::Status::WT_NEW)
+    {
+        bail!("commit, stash, or discard local changes before editing the stack");
+    }
+    Ok(())
+}
+
+fn write_stack_commit(
+    repo: &Repository,
+    original: &Commit<'_>,
+    committer: &git2::Signature<'_>,
+    message: &str,
+    tree: &git2::Tree<'_>,
+    parents: &[&Commit<'_>],
+    signing: bool,
+) -> Result<Oid> {
+    let author = original.author();
+    if signing {
+        return write_signed_commit_as(repo, &author, committer, message, tree, parents);
+    }
+    repo.commit(None, &author, committer, message, tree, parents)
+        .with_context(|| format!("failed to rewrite {}", short_id(original.id())))
+}
+
+fn conflicted_paths(index: &git2::Index) -> Result<Vec<String>> {
+    let mut paths = BTreeSet::new();
+    for conflict in index.conflicts()? {
+        let conflict = conflict?;
+        if let Some(entry) = conflict.our.or(conflict.their).or(conflict.ancestor) {
+            paths.insert(String::from_utf8_lossy(&entry.path).into_owned());
+        }
+    }
+    Ok(paths.into_iter().collect())
+}
+
+fn short_id(oid: Oid) -> String {
+    oid.to_string().chars().take(7).collect()
+}
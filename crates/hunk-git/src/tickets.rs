@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+use anyhow::{Context as _, Result};
+use regex::Regex;
+
+/// Matches Jira and Linear style ids such as `ABC-123`.
+pub const DEFAULT_TICKET_PATTERN: &str = r"\b[A-Z][A-Z0-9]+-[0-9]+\b";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketMatch {
+    pub range: Range<usize>,
+    pub id: String,
+}
+
+/// Finds tracker ticket ids in branch names and commit messages and builds links to them.
+#[derive(Debug, Clone)]
+pub struct TicketLinker {
+    patterns: Vec<Regex>,
+    url_template: String,
+}
+
+impl TicketLinker {
+    /// Compiles `patterns`, falling back to [`DEFAULT_TICKET_PATTERN`] when none are given.
+    ///
+    /// `url_template` builds ticket links by replacing `{ticket}`; leave it blank to detect ids
+    /// without linking them.
+    pub fn new(patterns: &[String], url_template: &str) -> Result<Self> {
+        let mut sources = patterns
+            .iter()
+            .map(|pattern| pattern.trim())
+            .filter(|pattern| !pattern.is_empty())
+            .collect::<Vec<_>>();
+        if sources.is_empty() {
+            sources.push(DEFAULT_TICKET_PATTERN);
+        }
+        let patterns = sources
+            .into_iter()
+            .map(|pattern| {
+                Regex::new(pattern).with_context(|| format!("invalid ticket pattern `{pattern}`"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            patterns,
+            url_template: url_template.trim().to_string(),
+        })
+    }
+
+    /// Non-overlapping ticket ids in `text`, in the order they appear.
+    pub fn find_tickets(&self, text: &str) -> Vec<TicketMatch> {
+        let mut matches = self
+            .patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(text))
+            .map(|found| TicketMatch {
+                range: found.range(),
+                id: found.as_str().to_string(),
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|found| (found.range.start, std::cmp::Reverse(found.range.end)));
+
+        let mut end = 0;
+        matches.retain(|found| {
+            if found.range.start < end || found.range.is_empty() {
+                return false;
+            }
+            end = found.range.end;
+            true
+        });
+        matches
+    }
+
+    /// Distinct ticket ids in `text`, in the order they first appear.
+    pub fn ticket_ids(&self, text: &str) -> Vec<String> {
+        let mut ids = Vec::new();
+        for found in self.find_tickets(text) {
+            if !ids.contains(&found.id) {
+                ids.push(found.id);
+            }
+        }
+        ids
+    }
+
+    pub fn first_ticket_id(&self, text: &str) -> Option<String> {
+        self.find_tickets(text)
+            .into_iter()
+            .next()
+            .map(|found| found.id)
+    }
+
+    /// The ticket a branch is for. Branch names are usually lowercase, so ids are also looked
+    /// for in the uppercased name.
+    pub fn branch_ticket_id(&self, branch_name: &str) -> Option<String> {
+        self.first_ticket_id(branch_name)
+            .or_else(|| self.first_ticket_id(branch_name.to_ascii_uppercase().as_str()))
+    }
+
+    pub fn ticket_url(&self, ticket_id: &str) -> Option<String> {
+        if self.url_template.is_empty() {
+            return None;
+        }
+        Some(self.url_template.replace("{ticket}", ticket_id))
+    }
+}
+
+/// Prefixes `message` with `ticket_id` unless the message already mentions it.
+pub fn commit_message_with_ticket(message: &str, ticket_id: &str) -> String {
+    if message
+        .to_ascii_uppercase()
+        .contains(ticket_id.to_ascii_uppercase().as_str())
+    {
+        return message.to_string();
+    }
+    format!("{ticket_id}: {}", message.trim_start())
+}
@@ -0,0 +1,279 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, anyhow};
+use git2::{Config, ConfigLevel};
+
+use crate::git2_helpers::open_git2_repo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitConfigScope {
+    Repository,
+    Global,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserIdentity {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+impl UserIdentity {
+    pub fn is_complete(&self) -> bool {
+        self.name
+            .as_deref()
+            .is_some_and(|name| !name.trim().is_empty())
+            && self
+                .email
+                .as_deref()
+                .is_some_and(|email| !email.trim().is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GitSigningFormat {
+    #[default]
+    OpenPgp,
+    Ssh,
+    X509,
+}
+
+impl GitSigningFormat {
+    pub const ALL: [Self; 3] = [Self::OpenPgp, Self::Ssh, Self::X509];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::OpenPgp => "OpenPGP",
+            Self::Ssh => "SSH",
+            Self::X509 => "X.509",
+        }
+    }
+
+    pub const fn config_value(self) -> &'static str {
+        match self {
+            Self::OpenPgp => "openpgp",
+            Self::Ssh => "ssh",
+            Self::X509 => "x509",
+        }
+    }
+
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.config_value().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitUserSettings {
+    pub identity: UserIdentity,
+    pub auto_setup_remote: bool,
+    pub sign_commits: bool,
+    pub signing_format: GitSigningFormat,
+    pub signing_key: Option<String>,
+}
+
+pub fn read_user_identity(repo_root: &Path) -> Result<UserIdentity> {
+    let repo = open_git2_repo(repo_root)?;
+    let config = repo
+        .config()
+        .context("failed to load Git config for user identity")?;
+    Ok(UserIdentity {
+        name: config.get_string("user.name").ok(),
+        email: config.get_string("user.email").ok(),
+    })
+}
+
+pub fn write_user_identity(
+    repo_root: &Path,
+    scope: GitConfigScope,
+    name: &str,
+    email: &str,
+) -> Result<()> {
+    let name = name.trim();
+    let email = email.trim();
+    validate_user_name(name)?;
+    validate_user_email(email)?;
+
+    let mut config = open_config_for_scope(repo_root, scope)?;
+    config
+        .set_str("user.name", name)
+        .context("failed to write user.name to Git config")?;
+    config
+        .set_str("user.email", email)
+        .context("failed to write user.email to Git config")?;
+    Ok(())
+}
+
+pub fn validate_user_name(name: &str) -> Result<()> {
+    if name.trim().is_empty() {
+        return Err(anyhow!("user.name cannot be empty"));
+    }
+    if name.contains(['<', '>', '\n']) {
+        return Err(anyhow!("user.name cannot contain '<', '>', or newlines"));
+    }
+    Ok(())
+}
+
+pub fn validate_user_email(email: &str) -> Result<()> {
+    let email = email.trim();
+    let Some((local, domain)) = email.split_once('@') else {
+        return Err(anyhow!("user.email must look like name@example.com"));
+    };
+    if local.is_empty() || domain.is_empty() || email.contains(['<', '>', ' ', '\n']) {
+        return Err(anyhow!("user.email must look like name@example.com"));
+    }
+    Ok(())
+}
+
+pub fn read_global_user_settings() -> Result<GitUserSettings> {
+    read_user_settings_at(global_config_path()?.as_path())
+}
+
+pub fn write_global_user_settings(settings: &GitUserSettings) -> Result<()> {
+    write_user_settings_at(global_config_path()?.as_path(), settings)
+}
+
+pub fn read_user_settings_at(path: &Path) -> Result<GitUserSettings> {
+    if !path.exists() {
+        return Ok(GitUserSettings::default());
+    }
+
+    let config = Config::open(path)
+        .with_context(|| format!("failed to open Git config at {}", path.display()))?;
+    let signing_format = match config.get_string("gpg.format") {
+        Ok(value) => GitSigningFormat::from_config_value(value.as_str()).ok_or_else(|| {
+            anyhow!(
+                "unsupported gpg.format value '{value}' in {}",
+                path.display()
+            )
+        })?,
+        Err(_) => GitSigningFormat::default(),
+    };
+    Ok(GitUserSettings {
+        identity: UserIdentity {
+            name: config.get_string("user.name").ok(),
+            email: config.get_string("user.email").ok(),
+        },
+        auto_setup_remote: config.get_bool("push.autoSetupRemote").unwrap_or(false),
+        sign_commits: config.get_bool("commit.gpgSign").unwrap_or(false),
+        signing_format,
+        signing_key: config.get_string("user.signingKey").ok(),
+    })
+}
+
+pub fn write_user_settings_at(path: &Path, settings: &GitUserSettings) -> Result<()> {
+    validate_user_settings(settings)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let mut config = Config::open(path)
+        .with_context(|| format!("failed to open Git config at {}", path.display()))?;
+
+    set_or_remove_string(&mut config, "user.name", settings.identity.name.as_deref())?;
+    set_or_remove_string(
+        &mut config,
+        "user.email",
+        settings.identity.email.as_deref(),
+    )?;
+    set_or_remove_string(
+        &mut config,
+        "user.signingKey",
+        settings.signing_key.as_deref(),
+    )?;
+    set_or_remove_bool(
+        &mut config,
+        "push.autoSetupRemote",
+        settings.auto_setup_remote,
+    )?;
+    set_or_remove_bool(&mut config, "commit.gpgSign", settings.sign_commits)?;
+    let signing_format = (settings.signing_format != GitSigningFormat::default())
+        .then_some(settings.signing_format.config_value());
+    set_or_remove_string(&mut config, "gpg.format", signing_format)?;
+    Ok(())
+}
+
+pub fn validate_user_settings(settings: &GitUserSettings) -> Result<()> {
+    if let Some(name) = non_empty(settings.identity.name.as_deref()) {
+        validate_user_name(name)?;
+    }
+    if let Some(email) = non_empty(settings.identity.email.as_deref()) {
+        validate_user_email(email)?;
+    }
+    if let Some(signing_key) = non_empty(settings.signing_key.as_deref())
+        && signing_key.contains('\n')
+    {
+        return Err(anyhow!("user.signingKey cannot contain newlines"));
+    }
+    if settings.sign_commits
+        && settings.signing_format == GitSigningFormat::Ssh
+        && non_empty(settings.signing_key.as_deref()).is_none()
+    {
+        return Err(anyhow!("SSH commit signing requires user.signingKey"));
+    }
+    Ok(())
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn set_or_remove_string(config: &mut Config, key: &str, value: Option<&str>) -> Result<()> {
+    match non_empty(value) {
+        Some(value) => config
+            .set_str(key, value)
+            .with_context(|| format!("failed to write {key} to Git config")),
+        None => remove_config_entry(config, key),
+    }
+}
+
+fn set_or_remove_bool(config: &mut Config, key: &str, enabled: bool) -> Result<()> {
+    if enabled {
+        return config
+            .set_bool(key, true)
+            .with_context(|| format!("failed to write {key} to Git config"));
+    }
+    remove_config_entry(config, key)
+}
+
+fn remove_config_entry(config: &mut Config, key: &str) -> Result<()> {
+    match config.remove(key) {
+        Ok(()) => Ok(()),
+        Err(err) if err.code() == git2::ErrorCode::NotFound => Ok(()),
+        Err(err) => Err(err).with_context(|| format!("failed to remove {key} from Git config")),
+    }
+}
+
+fn open_config_for_scope(repo_root: &Path, scope: GitConfigScope) -> Result<Config> {
+    match scope {
+        GitConfigScope::Repository => {
+            let repo = open_git2_repo(repo_root)?;
+            repo.config()
+                .and_then(|config| config.open_level(ConfigLevel::Local))
+                .with_context(|| {
+                    format!(
+                        "failed to open repository Git config for {}",
+                        repo_root.display()
+                    )
+                })
+        }
+        GitConfigScope::Global => {
+            let path = global_config_path()?;
+            Config::open(path.as_path())
+                .with_context(|| format!("failed to open global Git config at {}", path.display()))
+        }
+    }
+}
+
+pub fn global_config_path() -> Result<PathBuf> {
+    if let Ok(path) = Config::find_global() {
+        return Ok(path);
+    }
+
+    let home_dir = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| anyhow!("failed to resolve home directory for global Git config"))?;
+    Ok(PathBuf::from(home_dir).join(".gitconfig"))
+}
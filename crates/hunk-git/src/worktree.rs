@@ -6,6 +6,7 @@ use git2::{BranchType, Repository, WorktreeAddOptions};
 use hunk_domain::paths::hunk_home_dir;
 
 use crate::branch::is_valid_branch_name;
+use crate::error::GitError;
 use crate::git::discover_repo_root;
 use crate::git2_helpers::{load_statuses, open_git2_repo};
 use crate::path::normalize_windows_path_prefix;
@@ -67,17 +68,24 @@ pub fn path_is_within_managed_worktrees(
     ))
 }
 
+/// Resolves the checkout that identifies the project for `path`. Linked worktrees resolve to the
+/// main working tree, and a bare repository resolves to its Git directory. A linked worktree of a
+/// bare repository has no main working tree, so it is treated as its own primary checkout.
 pub fn primary_repo_root(path: &Path) -> Result<PathBuf> {
-    let repo = gix::discover(path)
+    let repo = Repository::discover(path)
         .with_context(|| format!("failed to discover Git repository from {}", path.display()))?;
-    let common_dir = canonicalize_existing_path(repo.common_dir())?;
-    let root = common_dir.parent().ok_or_else(|| {
-        anyhow!(
-            "failed to resolve primary repository root from common dir {}",
-            common_dir.display()
-        )
-    })?;
-    canonicalize_existing_path(root)
+    if repo.is_worktree() {
+        let common_repo = Repository::open(repo.commondir()).with_context(|| {
+            format!(
+                "failed to open main repository at {}",
+                repo.commondir().display()
+            )
+        })?;
+        if let Some(workdir) = common_repo.workdir() {
+            return canonicalize_existing_path(workdir);
+        }
+    }
+    canonicalize_existing_path(repo.workdir().unwrap_or_else(|| repo.path()))
 }
 
 pub fn list_workspace_targets(path: &Path) -> Result<Vec<WorkspaceTargetSummary>> {
@@ -99,6 +107,9 @@ pub fn list_workspace_targets(path: &Path) -> Result<Vec<WorkspaceTargetSummary>
             continue;
         }
         let root = canonicalize_existing_path(worktree.path())?;
+        if root == primary_root {
+            continue;
+        }
         targets.push(worktree_target_summary(
             worktree_name,
             root,
@@ -150,7 +161,10 @@ pub fn create_managed_worktree(
         .find_branch(branch_name, BranchType::Local)
         .is_ok()
     {
-        return Err(anyhow!("branch '{branch_name}' already exists"));
+        return Err(GitError::BranchExists {
+            branch: branch_name.to_string(),
+        }
+        .into());
     }
 
     let worktree_name = allocate_managed_worktree_name(primary_root.as_path(), &primary_repo)?;
@@ -450,7 +464,8 @@ fn managed_worktree_name_from_managed_root(root: &Path, managed_root: &Path) ->
     (!name.is_empty()).then_some(name)
 }
 
-fn repository_storage_key(primary_repo_root: &Path) -> String {
+/// File-name-safe key for per-repository storage: the folder name plus a hash of the full path.
+pub fn repository_storage_key(primary_repo_root: &Path) -> String {
     let repo_name = primary_repo_root
         .file_name()
         .and_then(|name| name.to_str())
@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{IndexAddOption, Oid, Repository, ResetType, Signature};
+use hunk_git::abandoned::{
+    DEFAULT_ABANDONED_COMMIT_LIMIT, load_abandoned_commits, restore_abandoned_commit,
+};
+use tempfile::TempDir;
+
+#[test]
+fn reset_commits_are_listed_and_restored_as_branches() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    let base = fixture.commit_all("base")?;
+    fixture.write_file("tracked.txt", "dropped\n")?;
+    let dropped = fixture.commit_all("Add dropped work")?;
+    repo.reset(&repo.find_object(base, None)?, ResetType::Hard, None)?;
+
+    let abandoned = load_abandoned_commits(fixture.root(), DEFAULT_ABANDONED_COMMIT_LIMIT)?;
+    assert_eq!(abandoned.len(), 1);
+    assert_eq!(abandoned[0].commit_id, dropped.to_string());
+    assert_eq!(abandoned[0].subject, "Add dropped work");
+    assert!(abandoned[0].reason.starts_with("reset"));
+
+    let branch_name = restore_abandoned_commit(fixture.root(), dropped.to_string().as_str())?;
+    assert_eq!(
+        branch_name,
+        format!("restored/{}", &dropped.to_string()[..7])
+    );
+    assert_eq!(
+        repo.find_branch(branch_name.as_str(), git2::BranchType::Local)?
+            .get()
+            .target(),
+        Some(dropped)
+    );
+    assert!(load_abandoned_commits(fixture.root(), DEFAULT_ABANDONED_COMMIT_LIMIT)?.is_empty());
+    assert_eq!(
+        restore_abandoned_commit(fixture.root(), dropped.to_string().as_str())?,
+        format!("restored/{}-2", &dropped.to_string()[..7])
+    );
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = fixture.root().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = test_signature()?;
+        let parents = self.head_commits(&repo)?;
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parent_refs.as_slice(),
+        )?)
+    }
+
+    fn head_commits<'repo>(&self, repo: &'repo Repository) -> Result<Vec<git2::Commit<'repo>>> {
+        let head = match repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(target) = head.target() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![repo.find_commit(target)?])
+    }
+}
+
+fn test_signature() -> Result<Signature<'static>> {
+    Ok(Signature::now("Hunk", "hunk@example.com")?)
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_git::api::diff::{DiffCellKind, DiffRowKind};
+use hunk_git::api::{FileStatus, load_file_diff_rows, load_file_diffs, load_snapshot};
+use tempfile::TempDir;
+
+#[test]
+fn api_loads_snapshot_and_parsed_file_diffs() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "one\ntwo\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("tracked.txt", "one\nthree\n")?;
+
+    let snapshot = load_snapshot(fixture.root())?;
+    assert_eq!(snapshot.files.len(), 1);
+    assert_eq!(snapshot.files[0].status, FileStatus::Modified);
+    assert_eq!(snapshot.line_stats.added, 1);
+    assert_eq!(snapshot.line_stats.removed, 1);
+
+    let diffs = load_file_diffs(fixture.root(), &snapshot.files)?;
+    assert_eq!(diffs.len(), 1);
+    let diff = &diffs[0];
+    assert_eq!(diff.path, "tracked.txt");
+    assert_eq!(diff.document.hunks.len(), 1);
+    assert!(diff.rows.iter().any(|row| {
+        row.kind == DiffRowKind::Code
+            && row.left.kind == DiffCellKind::Removed
+            && row.right.kind == DiffCellKind::Added
+            && row.right.text == "three"
+    }));
+
+    let rows = load_file_diff_rows(diff.path.as_str(), diff.patch.as_str());
+    assert_eq!(rows.load_error, None);
+    assert_eq!(rows.core_rows.len(), diff.rows.len());
+    let binary = load_file_diff_rows("logo.png", diff.patch.as_str());
+    assert!(binary.core_rows.is_empty());
+    assert!(binary.load_error.is_some());
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<()> {
+        let repo = Repository::open(self.root.as_path())?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("Hunk", "hunk@example.com")?;
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use hunk_git::branch_naming::{
+    BranchNamePolicy, detect_ticket_id, is_protected_branch, protected_branch_push_skip_reason,
+};
+
+#[test]
+fn branch_name_policy_validates_names_against_the_template() {
+    let policy = BranchNamePolicy::parse("{user}/{ticket}-{slug}")
+        .expect("template should parse")
+        .expect("template should not be blank");
+
+    assert!(policy.matches("nb/abc-123-fix-login"));
+    assert!(policy.matches("nb/456-tidy.up"));
+    assert!(!policy.matches("fix-login"));
+    assert!(!policy.matches("nb/fix-login"));
+    assert!(!policy.matches("nb/abc-123-"));
+    assert_eq!(policy.violation_message("nb/abc-123-fix-login"), None);
+    assert_eq!(
+        policy.violation_message("fix-login").as_deref(),
+        Some("Branch names must follow {user}/{ticket}-{slug}.")
+    );
+}
+
+#[test]
+fn branch_name_policy_parse_rejects_unknown_placeholders() {
+    assert_eq!(BranchNamePolicy::parse("  ").expect("blank template"), None);
+    assert!(BranchNamePolicy::parse("{team}/{slug}").is_err());
+    assert!(BranchNamePolicy::parse("{user}/{slug").is_err());
+}
+
+#[test]
+fn branch_name_policy_autofills_known_placeholders() {
+    let policy = BranchNamePolicy::parse("{user}/{ticket}-{slug}")
+        .expect("template should parse")
+        .expect("template should not be blank");
+
+    assert_eq!(policy.autofill("NB", Some("ABC-123")), "nb/abc-123-");
+    assert_eq!(policy.autofill("nb", None), "nb/");
+    assert_eq!(policy.autofill("", Some("abc-123")), "");
+}
+
+#[test]
+fn detect_ticket_id_finds_tracker_ids_in_text() {
+    assert_eq!(
+        detect_ticket_id("https://tracker.example.com/browse/ABC-123").as_deref(),
+        Some("abc-123")
+    );
+    assert_eq!(
+        detect_ticket_id("Fixes #456 in parser").as_deref(),
+        Some("456")
+    );
+    assert_eq!(detect_ticket_id("follow-up 12 items"), None);
+}
+
+#[test]
+fn protected_branches_match_exact_names_and_segment_wildcards() {
+    let patterns = vec![" main ".to_string(), "release/*".to_string(), String::new()];
+
+    assert!(is_protected_branch("main", &patterns));
+    assert!(is_protected_branch("release/1.2", &patterns));
+    assert!(!is_protected_branch("release/1.2/hotfix", &patterns));
+    assert!(!is_protected_branch("release", &patterns));
+    assert!(!is_protected_branch("mainline", &patterns));
+    assert!(!is_protected_branch("Main", &patterns));
+    assert!(!is_protected_branch("feature", &[]));
+}
+
+#[test]
+fn protected_branches_skip_the_combined_push() {
+    let patterns = vec!["release/*".to_string()];
+
+    assert_eq!(
+        protected_branch_push_skip_reason("release/1.2", &patterns).as_deref(),
+        Some("'release/1.2' is a protected branch")
+    );
+    assert_eq!(
+        protected_branch_push_skip_reason("nb/fix-login", &patterns),
+        None
+    );
+}
@@ -7,9 +7,9 @@ use git2::{
 };
 use hunk_domain::config::{ReviewProviderKind, ReviewProviderMapping};
 use hunk_git::branch::{
-    RenameBranchIfSafeOutcome, RenameBranchSkipReason, rename_branch,
-    rename_branch_if_current_unpublished, review_url_for_branch,
-    review_url_for_branch_with_provider_map, sanitize_branch_name,
+    RenameBranchIfSafeOutcome, RenameBranchSkipReason, ReviewRemote, branches_at_head,
+    rename_branch, rename_branch_if_current_unpublished, review_remote_for_branch,
+    review_url_for_branch, review_url_for_branch_with_provider_map, sanitize_branch_name,
 };
 use hunk_git::git::load_workflow_snapshot;
 use tempfile::TempDir;
@@ -385,6 +385,35 @@ fn review_url_normalizes_ssh_and_strips_credentials() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn review_remote_names_the_host_and_repository() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "line one\n")?;
+    fixture.commit_all("initial")?;
+    fixture.checkout_branch("feature/remote")?;
+    fixture.add_remote("origin", "git@github.com:example-org/hunk.git")?;
+
+    assert_eq!(
+        review_remote_for_branch(fixture.root(), "feature/remote", &[])?,
+        Some(ReviewRemote {
+            provider: ReviewProviderKind::GitHub,
+            host: "github.com".to_string(),
+            repository: "example-org/hunk".to_string(),
+        })
+    );
+
+    let unknown = TempGitRepo::new()?;
+    unknown.write_file("tracked.txt", "line one\n")?;
+    unknown.commit_all("initial")?;
+    unknown.checkout_branch("feature/remote")?;
+    unknown.add_remote("origin", "https://git.example.com/example-org/hunk.git")?;
+    assert_eq!(
+        review_remote_for_branch(unknown.root(), "feature/remote", &[])?,
+        None
+    );
+    Ok(())
+}
+
 #[test]
 fn review_url_for_path_remote_returns_none() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -398,6 +427,39 @@ fn review_url_for_path_remote_returns_none() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn branches_at_head_lists_every_branch_on_the_head_commit() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "line one\n")?;
+    fixture.commit_all("initial")?;
+    fixture.checkout_branch("feature-b")?;
+    fixture.checkout_branch("feature-a")?;
+    fixture.checkout_branch("feature-c")?;
+    fixture.write_file("tracked.txt", "line two\n")?;
+    fixture.commit_all("second")?;
+    fixture.checkout_branch("feature-d")?;
+
+    assert_eq!(
+        branches_at_head(fixture.root())?,
+        vec!["feature-c".to_string(), "feature-d".to_string()]
+    );
+
+    let repo = fixture.repository()?;
+    let first = repo
+        .find_branch("feature-a", BranchType::Local)?
+        .get()
+        .peel_to_commit()?
+        .id();
+    repo.set_head_detached(first)?;
+    let mut at_first = branches_at_head(fixture.root())?;
+    at_first.retain(|name| name.starts_with("feature-"));
+    assert_eq!(
+        at_first,
+        vec!["feature-a".to_string(), "feature-b".to_string()]
+    );
+    Ok(())
+}
+
 struct TempGitRepo {
     tempdir: TempDir,
     root: PathBuf,
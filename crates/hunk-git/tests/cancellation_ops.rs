@@ -0,0 +1,168 @@
+use std::fs;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use git2::{BranchType, IndexAddOption, Repository, Signature, build::CheckoutBuilder};
+use hunk_git::cancel::CancellationToken;
+use hunk_git::compare::{CompareOptions, CompareSource, load_compare_snapshot_with_options};
+use hunk_git::network::{push_current_branch, remote_upstream_head_with_cancel};
+use tempfile::TempDir;
+
+#[test]
+fn stalled_remote_times_out_at_the_deadline() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    fixture.create_bare_remote("origin")?;
+    fixture.checkout_branch("feature/stall")?;
+    push_current_branch(fixture.root(), "feature/stall", false)?;
+
+    // Accepts connections and never answers, so libgit2 never reaches a progress callback.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    thread::spawn(move || {
+        let mut connections = Vec::new();
+        for stream in listener.incoming().flatten() {
+            connections.push(stream);
+        }
+    });
+    fixture.set_remote_url("origin", format!("http://{address}/stalled.git").as_str())?;
+
+    let started = Instant::now();
+    let err = remote_upstream_head_with_cancel(
+        fixture.root(),
+        "feature/stall",
+        &CancellationToken::with_timeout(Duration::from_millis(500)),
+    )
+    .expect_err("a stalled remote should time out");
+
+    assert!(err.to_string().contains("timed out"));
+    assert!(started.elapsed() < Duration::from_secs(10));
+    Ok(())
+}
+
+#[test]
+fn cancelled_compare_snapshot_stops_before_rendering_files() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("tracked.txt", "base\nsecond\n")?;
+    fixture.commit_all("second")?;
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let err = load_compare_snapshot_with_options(
+        fixture.root(),
+        &CompareSource::Revision {
+            spec: "HEAD~1".to_string(),
+        },
+        &CompareSource::Revision {
+            spec: "HEAD".to_string(),
+        },
+        CompareOptions::default(),
+        &cancel,
+    )
+    .expect_err("a cancelled load should stop");
+
+    assert!(err.to_string().contains("cancelled"));
+    Ok(())
+}
+
+struct TempGitRepo {
+    tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = self.repository()?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<git2::Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = Signature::now("Hunk", "hunk@example.com")?;
+        let parent = match repo.head() {
+            Ok(head) => Some(head.peel_to_commit()?),
+            Err(_) => None,
+        };
+        let parents = parent.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parents.as_slice(),
+        )?)
+    }
+
+    fn checkout_branch(&self, name: &str) -> Result<()> {
+        let repo = self.repository()?;
+        let head_commit = repo.head()?.peel_to_commit()?;
+        if repo.find_branch(name, BranchType::Local).is_err() {
+            repo.branch(name, &head_commit, false)?;
+        }
+        repo.set_head(&format!("refs/heads/{name}"))?;
+        let mut checkout = CheckoutBuilder::new();
+        checkout.force();
+        repo.checkout_head(Some(&mut checkout))?;
+        Ok(())
+    }
+
+    fn create_bare_remote(&self, name: &str) -> Result<PathBuf> {
+        let remote_root = self.tempdir.path().join(format!("{name}.git"));
+        Repository::init_bare(remote_root.as_path())?;
+        let repo = self.repository()?;
+        if repo.find_remote(name).is_err() {
+            repo.remote(name, remote_root.to_string_lossy().as_ref())?;
+        }
+        Ok(remote_root)
+    }
+
+    fn set_remote_url(&self, name: &str, url: &str) -> Result<()> {
+        let repo = self.repository()?;
+        repo.remote_set_url(name, url)?;
+        Ok(())
+    }
+}
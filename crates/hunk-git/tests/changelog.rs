@@ -0,0 +1,37 @@
+use hunk_domain::config::ChangelogFormat;
+use hunk_git::changelog::{changelog_fragment, changelog_fragment_path};
+
+const MESSAGES: [&str; 6] = [
+    "feat(review): fold large hunks",
+    "fix: keep scroll position after refresh",
+    "chore: bump dependencies",
+    "Tidy the toolbar layout\n\nChangelog: skip\n",
+    "Rework config loading\n\nSome context.\n\nChangelog: removed\n",
+    "Merge branch 'main' into feature",
+];
+
+#[test]
+fn changelog_fragment_groups_keep_a_changelog_sections() {
+    assert_eq!(
+        changelog_fragment(MESSAGES, ChangelogFormat::KeepAChangelog),
+        "### Added\n\n- Fold large hunks\n\n\
+         ### Removed\n\n- Rework config loading\n\n\
+         ### Fixed\n\n- Keep scroll position after refresh\n"
+    );
+}
+
+#[test]
+fn changelog_fragment_writes_towncrier_entries() {
+    assert_eq!(
+        changelog_fragment(MESSAGES, ChangelogFormat::Towncrier),
+        "Fold large hunks\nKeep scroll position after refresh\nRework config loading\n"
+    );
+}
+
+#[test]
+fn changelog_fragment_path_expands_the_branch_name() {
+    assert_eq!(
+        changelog_fragment_path("changelog.d/{branch}.md", "nb/abc-123-export"),
+        "changelog.d/nb-abc-123-export.md"
+    );
+}
@@ -0,0 +1,101 @@
+use std::fs;
+
+use anyhow::Result;
+use hunk_git::codeowners::{CodeOwnerGroup, CodeOwners, load_code_owners};
+
+const RULES: &str = "\
+# Default owners
+*       @acme/core
+
+*.md    @acme/docs docs@example.com
+/apps/web/   @acme/web   # frontend
+docs/*  @acme/writers
+/build/logs/
+";
+
+#[test]
+fn later_rules_take_precedence() {
+    let owners = CodeOwners::parse(".github/CODEOWNERS", RULES);
+
+    assert_eq!(owners.owners_for("src/lib.rs"), ["@acme/core"]);
+    assert_eq!(
+        owners.owners_for("guide/intro.md"),
+        ["@acme/docs", "docs@example.com"]
+    );
+    assert_eq!(owners.owners_for("apps/web/src/index.ts"), ["@acme/web"]);
+    assert_eq!(owners.owners_for("apps/api/main.rs"), ["@acme/core"]);
+}
+
+#[test]
+fn directory_globs_only_cover_direct_children() {
+    let owners = CodeOwners::parse("CODEOWNERS", RULES);
+
+    assert_eq!(owners.owners_for("docs/setup.md"), ["@acme/writers"]);
+    assert_eq!(
+        owners.owners_for("docs/deep/setup.md"),
+        ["@acme/docs", "docs@example.com"]
+    );
+    assert_eq!(owners.owners_for("docs/deep/setup.rs"), ["@acme/core"]);
+}
+
+#[test]
+fn rules_without_owners_leave_paths_unowned() {
+    let owners = CodeOwners::parse("CODEOWNERS", RULES);
+
+    assert!(owners.owners_for("build/logs/today.txt").is_empty());
+    assert_eq!(
+        owners.owners_for("nested/build/logs/today.txt"),
+        ["@acme/core"]
+    );
+}
+
+#[test]
+fn changed_files_group_by_each_owner() {
+    let owners = CodeOwners::parse(
+        "CODEOWNERS",
+        "/src/ @acme/core\n/README.md @acme/docs @ada\n",
+    );
+
+    let groups =
+        owners.group_paths_by_owner(["src/lib.rs", "README.md", "Cargo.toml", "src/main.rs"]);
+
+    assert_eq!(
+        groups,
+        vec![
+            CodeOwnerGroup {
+                owner: Some("@acme/core".to_string()),
+                paths: vec!["src/lib.rs".to_string(), "src/main.rs".to_string()],
+            },
+            CodeOwnerGroup {
+                owner: Some("@acme/docs".to_string()),
+                paths: vec!["README.md".to_string()],
+            },
+            CodeOwnerGroup {
+                owner: Some("@ada".to_string()),
+                paths: vec!["README.md".to_string()],
+            },
+            CodeOwnerGroup {
+                owner: None,
+                paths: vec!["Cargo.toml".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn loading_prefers_the_github_directory() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    assert_eq!(load_code_owners(tempdir.path())?, None);
+
+    fs::write(tempdir.path().join("CODEOWNERS"), "* @root-owner\n")?;
+    fs::create_dir_all(tempdir.path().join(".github"))?;
+    fs::write(
+        tempdir.path().join(".github/CODEOWNERS"),
+        "* @github-owner\n",
+    )?;
+
+    let owners = load_code_owners(tempdir.path())?.expect("CODEOWNERS should load");
+    assert_eq!(owners.source_path, ".github/CODEOWNERS");
+    assert_eq!(owners.owners_for("src/lib.rs"), ["@github-owner"]);
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{IndexAddOption, Oid, Repository, Signature};
+use hunk_git::commit_message::{
+    CHANGE_ID_TRAILER, CommitMessageProblem, REVIEWED_BY_TRAILER, StructuredCommitMessage,
+    load_head_commit_message, reword_head_commit,
+};
+use tempfile::TempDir;
+
+const CHANGE_ID: &str = "I0123456789abcdef0123456789abcdef01234567";
+
+#[test]
+fn message_splits_into_subject_body_and_trailers() {
+    let message = format!(
+        "Fix overflow in hunk parser\n\nLong hunks wrapped the line counter.\n\nSecond paragraph.\n\nReviewed-by: Ada <ada@example.com>\nChange-Id: {CHANGE_ID}\nSigned-off-by: Bo\n  Example <bo@example.com>\n"
+    );
+
+    let parsed = StructuredCommitMessage::parse(message.as_str());
+
+    assert_eq!(parsed.subject, "Fix overflow in hunk parser");
+    assert_eq!(
+        parsed.body,
+        "Long hunks wrapped the line counter.\n\nSecond paragraph."
+    );
+    assert_eq!(
+        parsed.trailer_values(REVIEWED_BY_TRAILER),
+        vec!["Ada <ada@example.com>"]
+    );
+    assert_eq!(parsed.trailer_values(CHANGE_ID_TRAILER), vec![CHANGE_ID]);
+    assert_eq!(
+        parsed.trailer_values("signed-off-by"),
+        vec!["Bo Example <bo@example.com>"]
+    );
+}
+
+#[test]
+fn last_paragraph_without_trailers_stays_in_the_body() {
+    let parsed = StructuredCommitMessage::parse("Subject\n\nFirst.\n\nSee the notes below\n");
+
+    assert_eq!(parsed.body, "First.\n\nSee the notes below");
+    assert!(parsed.trailers.is_empty());
+
+    let only_trailers = StructuredCommitMessage::parse("Subject\n\nReviewed-by: Ada\n");
+    assert_eq!(only_trailers.body, "");
+    assert_eq!(only_trailers.trailers.len(), 1);
+}
+
+#[test]
+fn editing_trailers_keeps_order_and_serializes_with_blank_lines() {
+    let mut message = StructuredCommitMessage::parse(
+        "Subject\n\nBody text.\n\nReviewed-by: Ada\nSigned-off-by: Bo\nReviewed-by: Cy\n",
+    );
+
+    message.set_trailer_values(REVIEWED_BY_TRAILER, ["Dee", " ", "Eve"]);
+    message.set_trailer_values(CHANGE_ID_TRAILER, [CHANGE_ID]);
+
+    assert_eq!(
+        message.to_message(),
+        format!(
+            "Subject\n\nBody text.\n\nReviewed-by: Dee\nReviewed-by: Eve\nSigned-off-by: Bo\nChange-Id: {CHANGE_ID}\n"
+        )
+    );
+
+    message.body.clear();
+    message.trailers.clear();
+    assert_eq!(message.to_message(), "Subject\n");
+}
+
+#[test]
+fn validation_flags_long_lines_and_bad_trailers() {
+    let mut message = StructuredCommitMessage {
+        subject: "x".repeat(73),
+        body: format!("short line\n{}\n{}", "word ".repeat(16), "u".repeat(90)),
+        trailers: Vec::new(),
+    };
+    message.set_trailer_values(CHANGE_ID_TRAILER, ["I1234"]);
+
+    let problems = message.validate();
+
+    assert_eq!(
+        problems,
+        vec![
+            CommitMessageProblem::SubjectTooLong { len: 73 },
+            CommitMessageProblem::BodyLineTooLong { line: 2, len: 79 },
+            CommitMessageProblem::InvalidChangeId {
+                value: "I1234".to_string()
+            },
+        ]
+    );
+    assert!(!problems[0].is_blocking());
+    assert!(problems[2].is_blocking());
+    assert_eq!(
+        StructuredCommitMessage::default().validate(),
+        vec![CommitMessageProblem::EmptySubject]
+    );
+}
+
+#[test]
+fn rewording_head_keeps_tree_parent_and_author() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "one\n")?;
+    let base = fixture.commit_all("Base")?;
+    fixture.write_file("tracked.txt", "two\n")?;
+    let tip = fixture.commit_all("Draft subject")?;
+
+    let loaded = load_head_commit_message(fixture.root())?;
+    assert_eq!(loaded.commit_id, tip.to_string());
+    assert_eq!(loaded.message, "Draft subject");
+    assert_eq!(loaded.published_upstream, None);
+
+    let reworded = reword_head_commit(
+        fixture.root(),
+        "Final subject\n\nWhy it changed.\n\nReviewed-by: Ada\n",
+    )?;
+
+    assert_eq!(reworded.subject, "Final subject");
+    let head = repo.head()?.peel_to_commit()?;
+    assert_eq!(head.id().to_string(), reworded.commit_id);
+    assert_eq!(
+        head.message(),
+        Some("Final subject\n\nWhy it changed.\n\nReviewed-by: Ada\n")
+    );
+    assert_eq!(head.parent_id(0)?, base);
+    assert_eq!(head.tree_id(), repo.find_commit(tip)?.tree_id());
+    assert_eq!(head.author().name(), Some("Original Author"));
+    assert_eq!(head.committer().name(), Some("Hunk"));
+    assert!(reword_head_commit(fixture.root(), "  \n").is_err());
+    Ok(())
+}
+
+#[test]
+fn rewording_a_pushed_commit_is_refused() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "one\n")?;
+    let tip = fixture.commit_all("Pushed")?;
+    let branch_name = repo.head()?.shorthand().unwrap_or_default().to_string();
+    repo.reference("refs/remotes/origin/main", tip, true, "test upstream")?;
+    let mut config = repo.config()?;
+    config.set_str(format!("branch.{branch_name}.remote").as_str(), "origin")?;
+    config.set_str(
+        format!("branch.{branch_name}.merge").as_str(),
+        "refs/heads/main",
+    )?;
+    repo.remote("origin", "https://example.com/repo.git")?;
+
+    let loaded = load_head_commit_message(fixture.root())?;
+    assert_eq!(loaded.published_upstream.as_deref(), Some("origin/main"));
+    assert!(reword_head_commit(fixture.root(), "Rewritten\n").is_err());
+    assert_eq!(repo.head()?.peel_to_commit()?.id(), tip);
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = self.repository()?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = author_signature()?;
+        let parents = self.head_commits(&repo)?;
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parent_refs.as_slice(),
+        )?)
+    }
+
+    fn head_commits<'repo>(&self, repo: &'repo Repository) -> Result<Vec<git2::Commit<'repo>>> {
+        let head = match repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(target) = head.target() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![repo.find_commit(target)?])
+    }
+}
+
+/// Someone other than the configured `Hunk` identity, so rewording shows which name it keeps.
+fn author_signature() -> Result<Signature<'static>> {
+    Ok(Signature::now("Original Author", "author@example.com")?)
+}
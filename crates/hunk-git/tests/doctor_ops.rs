@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::Repository;
+use hunk_git::doctor::{DiagnosticStatus, run_git_diagnostics};
+use tempfile::TempDir;
+
+#[test]
+fn diagnostics_report_repository_identity_and_missing_remote() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+
+    let checks = run_git_diagnostics(Some(fixture.root()));
+
+    let identity = checks
+        .iter()
+        .find(|check| check.name == "Git identity")
+        .expect("identity check should run");
+    assert_eq!(identity.status, DiagnosticStatus::Pass);
+    assert_eq!(identity.detail, "Hunk <hunk@example.com>");
+
+    let remotes = checks
+        .iter()
+        .find(|check| check.name == "Remotes")
+        .expect("remote check should run");
+    assert_eq!(remotes.status, DiagnosticStatus::Warning);
+    assert!(remotes.remediation.is_some());
+    Ok(())
+}
+
+#[test]
+fn diagnostics_report_configured_remotes() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    Repository::open(fixture.root())?.remote("origin", "https://example.com/hunk.git")?;
+
+    let checks = run_git_diagnostics(Some(fixture.root()));
+
+    let remotes = checks
+        .iter()
+        .find(|check| check.name == "Remotes")
+        .expect("remote check should run");
+    assert_eq!(remotes.status, DiagnosticStatus::Pass);
+    assert_eq!(remotes.detail, "origin");
+    Ok(())
+}
+
+#[test]
+fn diagnostics_fail_when_repository_cannot_be_opened() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+
+    let checks = run_git_diagnostics(Some(tempdir.path()));
+
+    assert!(
+        checks
+            .iter()
+            .any(|check| check.name == "Git config" && check.status == DiagnosticStatus::Fail)
+    );
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = Repository::open(self.root.as_path())?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+}
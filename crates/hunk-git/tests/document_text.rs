@@ -0,0 +1,123 @@
+use std::io::Write as _;
+
+use anyhow::Result;
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
+use hunk_git::document_text::{
+    DocumentKind, EXTRACTED_TEXT_PATCH_HEADER, extract_document_text, is_extracted_text_patch,
+};
+
+/// Builds a single-entry ZIP archive with the entry stored uncompressed.
+fn stored_zip(entry_name: &str, contents: &[u8]) -> Vec<u8> {
+    let name = entry_name.as_bytes();
+    let size = contents.len() as u32;
+    let mut archive = Vec::new();
+    archive.extend_from_slice(&0x0403_4b50_u32.to_le_bytes());
+    archive.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    archive.extend_from_slice(&size.to_le_bytes());
+    archive.extend_from_slice(&size.to_le_bytes());
+    archive.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&0_u16.to_le_bytes());
+    archive.extend_from_slice(name);
+    archive.extend_from_slice(contents);
+
+    let central_directory_offset = archive.len() as u32;
+    archive.extend_from_slice(&0x0201_4b50_u32.to_le_bytes());
+    archive.extend_from_slice(&[20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    archive.extend_from_slice(&size.to_le_bytes());
+    archive.extend_from_slice(&size.to_le_bytes());
+    archive.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&[0; 12]);
+    archive.extend_from_slice(&0_u32.to_le_bytes());
+    archive.extend_from_slice(name);
+    let central_directory_len = archive.len() as u32 - central_directory_offset;
+
+    archive.extend_from_slice(&0x0605_4b50_u32.to_le_bytes());
+    archive.extend_from_slice(&[0, 0, 0, 0, 1, 0, 1, 0]);
+    archive.extend_from_slice(&central_directory_len.to_le_bytes());
+    archive.extend_from_slice(&central_directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0_u16.to_le_bytes());
+    archive
+}
+
+fn pdf_with_content(dictionary: &str, content: &[u8]) -> Vec<u8> {
+    let mut pdf = format!(
+        "%PDF-1.4\n4 0 obj\n<< /Length {}{dictionary} >>\nstream\n",
+        content.len()
+    )
+    .into_bytes();
+    pdf.extend_from_slice(content);
+    pdf.extend_from_slice(b"\nendstream\nendobj\n%%EOF\n");
+    pdf
+}
+
+#[test]
+fn docx_text_keeps_paragraphs_and_decodes_entities() -> Result<()> {
+    let document = r#"<?xml version="1.0" encoding="UTF-8"?>
+<w:document><w:body>
+<w:p><w:pPr><w:tabs><w:tab w:val="left"/></w:tabs></w:pPr><w:r><w:t>Terms &amp; </w:t></w:r><w:r><w:t xml:space="preserve">conditions</w:t></w:r></w:p>
+<w:p><w:r><w:t>Net</w:t><w:tab/><w:t>30 days</w:t><w:br/><w:t>&lt;signed&gt;</w:t></w:r></w:p>
+<w:p/>
+</w:body></w:document>"#;
+    let docx = stored_zip("word/document.xml", document.as_bytes());
+
+    let text = extract_document_text(DocumentKind::Docx, &docx)?;
+
+    assert_eq!(text, "Terms & conditions\nNet 30 days\n<signed>\n");
+    Ok(())
+}
+
+#[test]
+fn docx_without_document_part_is_an_error() {
+    let archive = stored_zip("word/styles.xml", b"<w:styles/>");
+
+    assert!(extract_document_text(DocumentKind::Docx, &archive).is_err());
+    assert!(extract_document_text(DocumentKind::Docx, b"not a zip").is_err());
+}
+
+#[test]
+fn pdf_text_comes_from_text_operators() -> Result<()> {
+    let plain = pdf_with_content(
+        "",
+        b"BT /F1 12 Tf 72 712 Td (Hello, \\(PDF\\)) Tj 0 -14 Td [(Wor) -40 (ld) -400 (again)] TJ ET",
+    );
+    assert_eq!(
+        extract_document_text(DocumentKind::Pdf, &plain)?,
+        "Hello, (PDF)\nWorld again\n"
+    );
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"BT 72 712 Td <48756e6b> Tj T* (\\101\\102) Tj ET")?;
+    let compressed = pdf_with_content(" /Filter /FlateDecode", &encoder.finish()?);
+    assert_eq!(
+        extract_document_text(DocumentKind::Pdf, &compressed)?,
+        "Hunk\nAB\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn document_kind_follows_the_file_extension() {
+    assert_eq!(
+        DocumentKind::for_path("docs/Spec.PDF"),
+        Some(DocumentKind::Pdf)
+    );
+    assert_eq!(
+        DocumentKind::for_path("contracts/offer.docx"),
+        Some(DocumentKind::Docx)
+    );
+    assert_eq!(DocumentKind::for_path("notes.doc"), None);
+    assert_eq!(DocumentKind::for_path("pdf"), None);
+}
+
+#[test]
+fn extracted_text_patches_are_recognized_by_their_header() {
+    let patch = format!(
+        "diff --git a/spec.pdf b/spec.pdf\n{EXTRACTED_TEXT_PATCH_HEADER}\n--- a/spec.pdf\n+++ b/spec.pdf\n@@ -1 +1 @@\n-old\n+new\n"
+    );
+
+    assert!(is_extracted_text_patch(patch.as_str()));
+    assert!(!is_extracted_text_patch(
+        "diff --git a/a.txt b/a.txt\n@@ -1 +1 @@\n-x\n+x\n"
+    ));
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use encoding_rs::{SHIFT_JIS, UTF_8, UTF_16LE, WINDOWS_1252};
+use git2::{Repository, Signature};
+use hunk_git::encoding::{convert_file_to_utf8, detect_text_encoding, load_working_copy_encodings};
+use hunk_git::git::{ChangedFile, FileStatus, load_patches_for_files};
+use tempfile::TempDir;
+
+fn commit_file(repo: &Repository, root: &Path, path: &str, bytes: &[u8]) -> Result<()> {
+    fs::write(root.join(path), bytes)?;
+    let mut index = repo.index()?;
+    index.add_path(Path::new(path))?;
+    index.write()?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+    let signature = Signature::now("Hunk Test", "hunk@example.com")?;
+    repo.commit(Some("HEAD"), &signature, &signature, "seed", &tree, &[])?;
+    Ok(())
+}
+
+fn modified(path: &str) -> ChangedFile {
+    ChangedFile {
+        path: path.to_string(),
+        status: FileStatus::Modified,
+        staged: false,
+        unstaged: true,
+        untracked: false,
+    }
+}
+
+#[test]
+fn detects_common_encodings() {
+    let (shift_jis, _, _) = SHIFT_JIS.encode("こんにちは、世界\n");
+    let (latin, _, _) = WINDOWS_1252.encode("café crème\n");
+    let utf16 = "hello\n"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        detect_text_encoding(&shift_jis).map(|encoding| encoding.encoding),
+        Some(SHIFT_JIS)
+    );
+    assert_eq!(
+        detect_text_encoding(&latin).map(|encoding| encoding.encoding),
+        Some(WINDOWS_1252)
+    );
+    assert_eq!(
+        detect_text_encoding(&utf16).map(|encoding| encoding.encoding),
+        Some(UTF_16LE)
+    );
+    assert!(detect_text_encoding("plain\n".as_bytes()).is_some_and(|encoding| encoding.is_utf8()));
+    assert_eq!(
+        detect_text_encoding(b"\xEF\xBB\xBFbom\n").map(|encoding| encoding.label()),
+        Some(format!("{} with BOM", UTF_8.name()))
+    );
+    assert!(detect_text_encoding(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR").is_none());
+}
+
+#[test]
+fn patch_for_non_utf8_file_shows_decoded_text() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    commit_file(
+        &repo,
+        tempdir.path(),
+        "notes.txt",
+        &SHIFT_JIS.encode("こんにちは\n").0,
+    )?;
+    fs::write(
+        tempdir.path().join("notes.txt"),
+        SHIFT_JIS.encode("さようなら\n").0,
+    )?;
+
+    let patches = load_patches_for_files(tempdir.path(), &[modified("notes.txt")])?;
+    let patch = patches.get("notes.txt").expect("patch for notes.txt");
+
+    assert!(patch.contains("-こんにちは"));
+    assert!(patch.contains("+さようなら"));
+    Ok(())
+}
+
+#[test]
+fn convert_rewrites_the_working_copy_file_as_utf8() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    commit_file(&repo, tempdir.path(), "menu.txt", b"menu\n")?;
+    fs::write(
+        tempdir.path().join("menu.txt"),
+        WINDOWS_1252.encode("café\n").0,
+    )?;
+
+    let encodings = load_working_copy_encodings(tempdir.path(), ["menu.txt", "missing.txt"])?;
+    assert_eq!(
+        encodings.get("menu.txt").map(|encoding| encoding.encoding),
+        Some(WINDOWS_1252)
+    );
+
+    let windows_1252 = encodings["menu.txt"];
+    convert_file_to_utf8(tempdir.path(), "menu.txt", windows_1252)?;
+    assert_eq!(
+        fs::read_to_string(tempdir.path().join("menu.txt"))?,
+        "café\n"
+    );
+    assert!(load_working_copy_encodings(tempdir.path(), ["menu.txt"])?.is_empty());
+    assert!(convert_file_to_utf8(tempdir.path(), "menu.txt", windows_1252).is_err());
+    assert!(convert_file_to_utf8(tempdir.path(), "../outside.txt", windows_1252).is_err());
+    Ok(())
+}
+
+#[test]
+fn convert_refuses_malformed_or_changed_encodings() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    commit_file(&repo, tempdir.path(), "notes.txt", b"notes\n")?;
+
+    // A UTF-16 byte order mark followed by an unpaired surrogate.
+    let malformed = b"\xFF\xFEa\0\0\xD8b\0\n\0".to_vec();
+    fs::write(tempdir.path().join("notes.txt"), &malformed)?;
+    let utf16 = detect_text_encoding(&malformed).expect("utf-16 text");
+    let err = convert_file_to_utf8(tempdir.path(), "notes.txt", utf16)
+        .expect_err("malformed bytes should not be replaced");
+    assert!(err.to_string().contains("left unchanged"));
+    assert_eq!(fs::read(tempdir.path().join("notes.txt"))?, malformed);
+
+    let latin = detect_text_encoding(&WINDOWS_1252.encode("café\n").0).expect("latin text");
+    fs::write(
+        tempdir.path().join("notes.txt"),
+        SHIFT_JIS.encode("こんにちは\n").0,
+    )?;
+    let err = convert_file_to_utf8(tempdir.path(), "notes.txt", latin)
+        .expect_err("a changed encoding should need a fresh confirmation");
+    assert!(err.to_string().contains("review it again"));
+    Ok(())
+}
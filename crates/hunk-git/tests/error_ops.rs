@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_git::error::GitError;
+use hunk_git::git::load_workflow_snapshot;
+use hunk_git::mutation::commit_all;
+use hunk_git::network::push_current_branch;
+use tempfile::TempDir;
+
+#[test]
+fn classify_finds_typed_error_below_context() {
+    let err = anyhow::Error::from(GitError::PublishRequired)
+        .context("Push branch failed")
+        .context("outer");
+
+    assert_eq!(GitError::classify(&err), Some(GitError::PublishRequired));
+}
+
+#[test]
+fn classify_maps_locked_git2_errors() {
+    let err = Err::<(), _>(git2::Error::new(
+        git2::ErrorCode::Locked,
+        git2::ErrorClass::Index,
+        "index.lock exists",
+    ))
+    .context("failed to write index")
+    .expect_err("error should propagate");
+
+    assert_eq!(GitError::classify(&err), Some(GitError::WorkingCopyLocked));
+}
+
+#[test]
+fn missing_repository_is_reported_as_not_a_repository() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+
+    let err = load_workflow_snapshot(tempdir.path()).expect_err("directory is not a repository");
+
+    assert!(matches!(
+        GitError::classify(&err),
+        Some(GitError::NotARepository { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn commit_without_changes_reports_nothing_to_commit() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_initial()?;
+
+    let err = commit_all(fixture.root(), "empty").expect_err("nothing should be committed");
+
+    assert_eq!(GitError::classify(&err), Some(GitError::NothingToCommit));
+    Ok(())
+}
+
+#[test]
+fn publishing_without_remote_reports_remote_not_configured() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_initial()?;
+    let branch_name = Repository::open(fixture.root())?
+        .head()?
+        .shorthand()
+        .unwrap_or("HEAD")
+        .to_string();
+
+    let err = push_current_branch(fixture.root(), branch_name.as_str(), false)
+        .expect_err("publish should need a remote");
+
+    assert_eq!(
+        GitError::classify(&err),
+        Some(GitError::RemoteNotConfigured)
+    );
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = Repository::open(self.root.as_path())?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_initial(&self) -> Result<()> {
+        let repo = Repository::open(self.root.as_path())?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("Hunk", "hunk@example.com")?;
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])?;
+        Ok(())
+    }
+}
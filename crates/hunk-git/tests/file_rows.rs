@@ -0,0 +1,29 @@
+use hunk_domain::diff::DiffRowKind;
+use hunk_git::file_rows::{is_binary_patch, load_file_diff_rows};
+
+#[test]
+fn text_patches_load_as_side_by_side_rows() {
+    let loaded = load_file_diff_rows("src/lib.rs", "@@ -1 +1 @@\n-old\n+new\n");
+
+    assert!(loaded.load_error.is_none());
+    assert_eq!(loaded.core_rows[0].kind, DiffRowKind::HunkHeader);
+    assert_eq!(loaded.core_rows[1].left.text, "old");
+    assert_eq!(loaded.core_rows[1].right.text, "new");
+}
+
+#[test]
+fn binary_files_report_why_they_have_no_rows() {
+    let by_extension = load_file_diff_rows("logo.png", "@@ -1 +1 @@\n-a\n+b\n");
+    assert!(by_extension.core_rows.is_empty());
+    assert_eq!(
+        by_extension.load_error.as_deref(),
+        Some("Preview unavailable for logo.png: binary file type.")
+    );
+
+    let patch = "diff --git a/data b/data\nBinary files a/data and b/data differ\n";
+    assert!(is_binary_patch(patch));
+    assert_eq!(
+        load_file_diff_rows("data", patch).load_error.as_deref(),
+        Some("Preview unavailable for data: binary diff.")
+    );
+}
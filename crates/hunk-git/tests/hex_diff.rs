@@ -0,0 +1,19 @@
+use hunk_git::hex_diff::hex_dump;
+
+#[test]
+fn hex_dump_lines_show_offset_bytes_and_ascii() {
+    let bytes = (0_u8..20).chain(*b"Hunk").collect::<Vec<_>>();
+
+    let dump = hex_dump(&bytes);
+
+    assert_eq!(
+        dump,
+        "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|\n\
+         00000010  10 11 12 13 48 75 6e 6b                           |....Hunk|\n"
+    );
+}
+
+#[test]
+fn empty_input_has_an_empty_dump() {
+    assert!(hex_dump(&[]).is_empty());
+}
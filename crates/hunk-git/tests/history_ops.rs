@@ -3,9 +3,11 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use git2::{BranchType, IndexAddOption, Repository, Signature, build::CheckoutBuilder};
+use hunk_git::git::{FileStatus, LineStats};
 use hunk_git::history::{
-    DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, load_recent_authored_commits_if_changed,
-    load_recent_authored_commits_with_fingerprint,
+    CommitChangedFile, DEFAULT_RECENT_AUTHORED_COMMIT_LIMIT, load_branch_commit_messages,
+    load_commit_changed_files, load_commit_details, load_file_authorship,
+    load_recent_authored_commits_if_changed, load_recent_authored_commits_with_fingerprint,
 };
 use tempfile::TempDir;
 
@@ -88,6 +90,31 @@ fn recent_authored_commits_include_all_authors_on_the_checked_out_branch() -> Re
     Ok(())
 }
 
+#[test]
+fn branch_commit_messages_keep_bodies_for_branch_only_commits() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature("Hunk", "hunk@example.com")?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all_at("initial", 1_700_000_000, "Hunk", "hunk@example.com")?;
+    fixture.checkout_branch("feature")?;
+    fixture.write_file("tracked.txt", "feature\n")?;
+    fixture.commit_all_at(
+        "feat: add export\n\nChangelog: added\n",
+        1_700_000_010,
+        "Hunk",
+        "hunk@example.com",
+    )?;
+
+    let messages = load_branch_commit_messages(fixture.root(), 50)?;
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].message,
+        "feat: add export\n\nChangelog: added\n"
+    );
+    Ok(())
+}
+
 #[test]
 fn recent_authored_commits_if_changed_refreshes_when_head_ref_changes() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -113,6 +140,115 @@ fn recent_authored_commits_if_changed_refreshes_when_head_ref_changes() -> Resul
     Ok(())
 }
 
+#[test]
+fn commit_changed_files_diff_against_the_first_parent() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "one\ntwo\n")?;
+    let initial = fixture.commit_all_at("initial", 1_700_000_000, "Hunk", "hunk@example.com")?;
+    fixture.write_file("tracked.txt", "one\n2\nthree\n")?;
+    fixture.write_file("src/new.rs", "fn main() {}\n")?;
+    let second = fixture.commit_all_at("second", 1_700_000_010, "Hunk", "hunk@example.com")?;
+
+    let files = load_commit_changed_files(fixture.root(), second.to_string().as_str())?;
+
+    assert_eq!(
+        files,
+        vec![
+            CommitChangedFile {
+                path: "src/new.rs".to_string(),
+                status: FileStatus::Added,
+                line_stats: LineStats {
+                    added: 1,
+                    removed: 0,
+                },
+            },
+            CommitChangedFile {
+                path: "tracked.txt".to_string(),
+                status: FileStatus::Modified,
+                line_stats: LineStats {
+                    added: 2,
+                    removed: 1,
+                },
+            },
+        ]
+    );
+
+    let root_files = load_commit_changed_files(fixture.root(), initial.to_string().as_str())?;
+    assert_eq!(root_files.len(), 1);
+    assert_eq!(root_files[0].status, FileStatus::Added);
+    assert!(load_commit_changed_files(fixture.root(), "not-a-commit").is_err());
+    Ok(())
+}
+
+#[test]
+fn commit_details_include_metadata_change_id_and_patches() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "one\ntwo\n")?;
+    let initial = fixture.commit_all_at("initial", 1_700_000_000, "Hunk", "hunk@example.com")?;
+    fixture.write_file("tracked.txt", "one\n2\n")?;
+    let message =
+        "Rename two\n\nUse digits.\n\nChange-Id: I0123456789abcdef0123456789abcdef01234567\n";
+    let second = fixture.commit_all_at(message, 1_700_000_010, "Other", "other@example.com")?;
+
+    let details = load_commit_details(fixture.root(), second.to_string().as_str())?;
+
+    assert_eq!(details.commit_id, second.to_string());
+    assert_eq!(details.message, message);
+    assert_eq!(details.author.name, "Other");
+    assert_eq!(details.committer.email, "other@example.com");
+    assert_eq!(details.author.unix_time, 1_700_000_010);
+    assert_eq!(
+        details.change_id.as_deref(),
+        Some("I0123456789abcdef0123456789abcdef01234567")
+    );
+    assert_eq!(details.parent_ids, vec![initial.to_string()]);
+    assert_eq!(details.files.len(), 1);
+    assert_eq!(details.files[0].file.path, "tracked.txt");
+    assert_eq!(details.files[0].patch, "@@ -1,2 +1,2 @@\n one\n-two\n+2\n");
+    assert!(!details.files[0].truncated);
+
+    let root = load_commit_details(fixture.root(), initial.to_string().as_str())?;
+    assert!(root.parent_ids.is_empty());
+    assert_eq!(root.change_id, None);
+    Ok(())
+}
+
+#[test]
+fn file_authorship_lists_commits_that_touched_the_file() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("src/lib.rs", "one\n")?;
+    fixture.commit_all_at("add lib", 1_700_000_000, "Old", "old@example.com")?;
+    fixture.write_file("src/lib.rs", "two\n")?;
+    fixture.commit_all_at("edit lib", 1_700_100_000, "Ada", "ada@example.com")?;
+    fixture.write_file("README.md", "readme\n")?;
+    fixture.commit_all_at("docs only", 1_700_200_000, "Docs", "docs@example.com")?;
+    fixture.write_file("src/lib.rs", "three\n")?;
+    fixture.commit_all_at("edit lib again", 1_700_300_000, "Ada", "ADA@example.com")?;
+    fixture.write_file("src/lib.rs", "four\n")?;
+    fixture.commit_all_at("review fixes", 1_700_400_000, "Bo", "bo@example.com")?;
+
+    let authorship = load_file_authorship(fixture.root(), "src/lib.rs", 1_700_050_000)?;
+
+    let subjects = authorship
+        .commits
+        .iter()
+        .map(|commit| commit.subject.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        subjects,
+        vec!["review fixes", "edit lib again", "edit lib", "add lib"]
+    );
+    let last = authorship.last_commit().expect("file has history");
+    assert_eq!(last.author_name, "Bo");
+    assert_eq!(last.committed_unix_time, 1_700_400_000);
+    assert_eq!(authorship.recent_contributor_count, 2);
+
+    let untouched = load_file_authorship(fixture.root(), "missing.rs", 0)?;
+    assert!(untouched.commits.is_empty());
+    assert_eq!(untouched.recent_contributor_count, 0);
+    Ok(())
+}
+
 struct TempGitRepo {
     _tempdir: TempDir,
     root: PathBuf,
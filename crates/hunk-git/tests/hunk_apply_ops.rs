@@ -0,0 +1,482 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{BranchType, IndexAddOption, Oid, Repository, Signature};
+use hunk_domain::diff::parse_patch_document;
+use hunk_git::git::{FileStatus, load_patch};
+use hunk_git::hunk_apply::{
+    HunkApplyMode, HunkApplyRequest, HunkSelector, HunkStageState, apply_hunk_to_branch,
+    load_hunk_stage_states, stage_hunk, unstage_hunk,
+};
+use tempfile::TempDir;
+
+#[test]
+fn hunk_is_committed_to_existing_branch_without_touching_worktree() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    let base = fixture.commit_all("base")?;
+    repo.branch("fix", &repo.find_commit(base)?, false)?;
+    let edited = numbered_lines(&[(2, "two fixed"), (18, "eighteen edited")]);
+    fixture.write_file("tracked.txt", edited.as_str())?;
+
+    let hunks = working_copy_hunks(fixture.root())?;
+    assert_eq!(hunks.len(), 2);
+    let applied = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "fix", "Fix line two", HunkApplyMode::NewCommit),
+    )?;
+
+    assert!(!applied.created_branch);
+    assert_eq!(applied.commit.subject, "Fix line two");
+    let tip = branch_tip(&repo, "fix")?;
+    assert_eq!(tip.parent_id(0)?, base);
+    assert_eq!(file_at(&repo, &tip)?, numbered_lines(&[(2, "two fixed")]));
+    assert_eq!(repo.head()?.peel_to_commit()?.id(), base);
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("tracked.txt"))?,
+        edited
+    );
+    Ok(())
+}
+
+#[test]
+fn amending_folds_hunk_into_branch_tip_and_missing_branch_is_created() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    let base = fixture.commit_all("base")?;
+    fixture.write_file(
+        "tracked.txt",
+        numbered_lines(&[(2, "two fixed"), (18, "eighteen edited")]).as_str(),
+    )?;
+    let hunks = working_copy_hunks(fixture.root())?;
+
+    let created = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "peeled", "Peel fix", HunkApplyMode::NewCommit),
+    )?;
+    assert!(created.created_branch);
+    assert_eq!(branch_tip(&repo, "peeled")?.parent_id(0)?, base);
+
+    apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[1], "peeled", "", HunkApplyMode::AmendTip),
+    )?;
+    let tip = branch_tip(&repo, "peeled")?;
+    assert_eq!(tip.parent_id(0)?, base);
+    assert_eq!(tip.summary(), Some("Peel fix"));
+    assert_eq!(
+        file_at(&repo, &tip)?,
+        numbered_lines(&[(2, "two fixed"), (18, "eighteen edited")])
+    );
+
+    let repeated = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[1], "peeled", "Again", HunkApplyMode::NewCommit),
+    );
+    assert!(repeated.is_err());
+    Ok(())
+}
+
+#[test]
+fn checked_out_branch_and_unknown_hunks_are_rejected() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    fixture.commit_all("base")?;
+    fixture.write_file("tracked.txt", numbered_lines(&[(2, "two fixed")]).as_str())?;
+    let hunks = working_copy_hunks(fixture.root())?;
+    let current = repo.head()?.shorthand().unwrap_or_default().to_string();
+
+    let err = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], current.as_str(), "Fix", HunkApplyMode::NewCommit),
+    )
+    .expect_err("checked-out branch should be rejected");
+    assert!(err.to_string().contains("checked out"));
+
+    let err = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&unknown_hunk(), "fix", "Fix", HunkApplyMode::NewCommit),
+    )
+    .expect_err("unknown hunk should be rejected");
+    assert!(err.to_string().contains("no longer in the working copy"));
+    Ok(())
+}
+
+#[test]
+fn addition_only_hunk_is_not_applied_twice() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    let base = fixture.commit_all("base")?;
+    repo.branch("fix", &repo.find_commit(base)?, false)?;
+    let inserted = numbered_lines(&[(10, "line 10\ninserted")]);
+    fixture.write_file("tracked.txt", inserted.as_str())?;
+    let hunks = working_copy_hunks(fixture.root())?;
+
+    apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "fix", "Insert", HunkApplyMode::NewCommit),
+    )?;
+    let err = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "fix", "Again", HunkApplyMode::NewCommit),
+    )
+    .expect_err("an applied addition should not be inserted again");
+    assert!(format!("{err:#}").contains("already present"));
+    assert_eq!(file_at(&repo, &branch_tip(&repo, "fix")?)?, inserted);
+    Ok(())
+}
+
+#[test]
+fn amending_keeps_the_tip_author() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    let base = fixture.commit_all("base")?;
+    let base_commit = repo.find_commit(base)?;
+    let author = Signature::now("Someone Else", "else@example.com")?;
+    let tip = repo.commit(
+        None,
+        &author,
+        &author,
+        "Their change",
+        &base_commit.tree()?,
+        &[&base_commit],
+    )?;
+    repo.branch("theirs", &repo.find_commit(tip)?, false)?;
+    fixture.write_file("tracked.txt", numbered_lines(&[(2, "two fixed")]).as_str())?;
+    let hunks = working_copy_hunks(fixture.root())?;
+
+    apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "theirs", "", HunkApplyMode::AmendTip),
+    )?;
+    let amended = branch_tip(&repo, "theirs")?;
+    assert_eq!(amended.author().name(), Some("Someone Else"));
+    assert_eq!(amended.committer().name(), Some("Hunk"));
+    Ok(())
+}
+
+#[test]
+fn branch_checked_out_in_another_worktree_is_rejected() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    let base = fixture.commit_all("base")?;
+    let branch = repo.branch("elsewhere", &repo.find_commit(base)?, false)?;
+    let worktree_parent = TempDir::new()?;
+    let mut options = git2::WorktreeAddOptions::new();
+    options.reference(Some(branch.get()));
+    repo.worktree(
+        "elsewhere",
+        worktree_parent.path().join("elsewhere").as_path(),
+        Some(&options),
+    )?;
+    fixture.write_file("tracked.txt", numbered_lines(&[(2, "two fixed")]).as_str())?;
+    let hunks = working_copy_hunks(fixture.root())?;
+
+    let err = apply_hunk_to_branch(
+        fixture.root(),
+        &request(&hunks[0], "elsewhere", "Fix", HunkApplyMode::NewCommit),
+    )
+    .expect_err("a branch checked out in another worktree should be rejected");
+    assert!(err.to_string().contains("checked out in"));
+    assert_eq!(branch_tip(&repo, "elsewhere")?.id(), base);
+    Ok(())
+}
+
+#[test]
+fn staging_a_hunk_leaves_the_other_hunks_unstaged() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    fixture.commit_all("base")?;
+    let edited = numbered_lines(&[(2, "two fixed"), (18, "eighteen edited")]);
+    fixture.write_file("tracked.txt", edited.as_str())?;
+
+    let hunks = working_copy_hunks(fixture.root())?;
+    stage_hunk(fixture.root(), "tracked.txt", &hunks[1])?;
+    assert_eq!(
+        fixture.staged_file("tracked.txt")?,
+        numbered_lines(&[(18, "eighteen edited")])
+    );
+
+    stage_hunk(fixture.root(), "tracked.txt", &hunks[0])?;
+    assert_eq!(fixture.staged_file("tracked.txt")?, edited);
+
+    let err = stage_hunk(fixture.root(), "tracked.txt", &hunks[0])
+        .expect_err("a staged hunk should not be staged again");
+    assert!(err.to_string().contains("already staged"));
+    assert_eq!(fixture.staged_file("tracked.txt")?, edited);
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("tracked.txt"))?,
+        edited
+    );
+
+    let err = stage_hunk(fixture.root(), "tracked.txt", &unknown_hunk())
+        .expect_err("unknown hunk should be rejected");
+    assert!(err.to_string().contains("no longer in the working copy"));
+    Ok(())
+}
+
+#[test]
+fn stale_header_does_not_stage_a_different_change() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    fixture.commit_all("base")?;
+    fixture.write_file("tracked.txt", numbered_lines(&[(2, "two fixed")]).as_str())?;
+    let picked = working_copy_hunks(fixture.root())?.remove(0);
+
+    fixture.write_file(
+        "tracked.txt",
+        numbered_lines(&[(2, "two rewritten")]).as_str(),
+    )?;
+    let err = stage_hunk(fixture.root(), "tracked.txt", &picked)
+        .expect_err("a hunk whose lines changed should be rejected");
+    assert!(err.to_string().contains("no longer in the working copy"));
+    assert_eq!(fixture.staged_file("tracked.txt")?, numbered_lines(&[]));
+    Ok(())
+}
+
+#[test]
+fn new_files_and_missing_final_newlines_are_staged_exactly() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "one\ntwo")?;
+    fixture.commit_all("base")?;
+    fixture.write_file("tracked.txt", "one\ntwo\nthree\n")?;
+    fixture.write_file("added.txt", "first\nsecond")?;
+
+    let hunks = working_copy_hunks(fixture.root())?;
+    stage_hunk(fixture.root(), "tracked.txt", &hunks[0])?;
+    assert_eq!(fixture.staged_file("tracked.txt")?, "one\ntwo\nthree\n");
+    assert!(stage_hunk(fixture.root(), "tracked.txt", &hunks[0]).is_err());
+    assert_eq!(fixture.staged_file("tracked.txt")?, "one\ntwo\nthree\n");
+
+    let patch = load_patch(fixture.root(), "added.txt", FileStatus::Added)?;
+    let added = HunkSelector::from_hunk(&parse_patch_document(patch.as_str()).hunks[0]);
+    stage_hunk(fixture.root(), "added.txt", &added)?;
+    assert_eq!(fixture.staged_file("added.txt")?, "first\nsecond");
+    let err = stage_hunk(fixture.root(), "added.txt", &added)
+        .expect_err("a staged new file should not be staged again");
+    assert!(err.to_string().contains("already staged"));
+    assert_eq!(fixture.staged_file("added.txt")?, "first\nsecond");
+    Ok(())
+}
+
+#[test]
+fn stage_states_follow_staging_and_unstaging() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", numbered_lines(&[]).as_str())?;
+    fixture.commit_all("base")?;
+    let edited = numbered_lines(&[(2, "two fixed"), (18, "eighteen edited")]);
+    fixture.write_file("tracked.txt", edited.as_str())?;
+    let hunks = working_copy_hunks(fixture.root())?;
+
+    stage_hunk(fixture.root(), "tracked.txt", &hunks[0])?;
+    stage_hunk(fixture.root(), "tracked.txt", &hunks[1])?;
+    assert_eq!(
+        load_hunk_stage_states(fixture.root(), "tracked.txt", &hunks)?,
+        vec![Some(HunkStageState::Staged), Some(HunkStageState::Staged)]
+    );
+
+    unstage_hunk(fixture.root(), "tracked.txt", &hunks[0])?;
+    assert_eq!(
+        fixture.staged_file("tracked.txt")?,
+        numbered_lines(&[(18, "eighteen edited")])
+    );
+    assert_eq!(
+        load_hunk_stage_states(fixture.root(), "tracked.txt", &hunks)?,
+        vec![Some(HunkStageState::Unstaged), Some(HunkStageState::Staged)]
+    );
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("tracked.txt"))?,
+        edited
+    );
+
+    let err = unstage_hunk(fixture.root(), "tracked.txt", &hunks[0])
+        .expect_err("an unstaged hunk should not be unstaged again");
+    assert!(err.to_string().contains("is not staged"));
+    assert_eq!(
+        load_hunk_stage_states(fixture.root(), "tracked.txt", &[unknown_hunk()])?,
+        vec![None]
+    );
+    Ok(())
+}
+
+#[test]
+fn unstaging_a_new_file_removes_it_from_the_index() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "one\n")?;
+    fixture.commit_all("base")?;
+    fixture.write_file("added.txt", "first\nsecond\n")?;
+    let patch = load_patch(fixture.root(), "added.txt", FileStatus::Added)?;
+    let added = HunkSelector::from_hunk(&parse_patch_document(patch.as_str()).hunks[0]);
+
+    stage_hunk(fixture.root(), "added.txt", &added)?;
+    unstage_hunk(fixture.root(), "added.txt", &added)?;
+
+    let repo = fixture.repository()?;
+    assert!(repo.index()?.get_path(Path::new("added.txt"), 0).is_none());
+    assert!(fixture.root().join("added.txt").exists());
+    Ok(())
+}
+
+fn request(
+    hunk: &HunkSelector,
+    branch_name: &str,
+    message: &str,
+    mode: HunkApplyMode,
+) -> HunkApplyRequest {
+    HunkApplyRequest {
+        path: "tracked.txt".to_string(),
+        hunk: hunk.clone(),
+        branch_name: branch_name.to_string(),
+        message: message.to_string(),
+        mode,
+    }
+}
+
+fn numbered_lines(replacements: &[(usize, &str)]) -> String {
+    (1..=20)
+        .map(|line| {
+            replacements
+                .iter()
+                .find(|(replaced, _)| *replaced == line)
+                .map(|(_, text)| format!("{text}\n"))
+                .unwrap_or_else(|| format!("line {line}\n"))
+        })
+        .collect()
+}
+
+fn working_copy_hunks(root: &Path) -> Result<Vec<HunkSelector>> {
+    let patch = load_patch(root, "tracked.txt", FileStatus::Modified)?;
+    Ok(parse_patch_document(patch.as_str())
+        .hunks
+        .iter()
+        .map(HunkSelector::from_hunk)
+        .collect())
+}
+
+fn unknown_hunk() -> HunkSelector {
+    HunkSelector {
+        header: "@@ -40,1 +40,1 @@".to_string(),
+        removed: vec!["line 40".to_string()],
+        added: vec!["forty".to_string()],
+    }
+}
+
+fn branch_tip<'repo>(repo: &'repo Repository, name: &str) -> Result<git2::Commit<'repo>> {
+    Ok(repo
+        .find_branch(name, BranchType::Local)?
+        .get()
+        .peel_to_commit()?)
+}
+
+fn file_at(repo: &Repository, commit: &git2::Commit<'_>) -> Result<String> {
+    let entry = commit.tree()?.get_path(Path::new("tracked.txt"))?;
+    let blob = repo.find_blob(entry.id())?;
+    Ok(String::from_utf8(blob.content().to_vec())?)
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = self.repository()?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = test_signature()?;
+        let parents = self.head_commits(&repo)?;
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parent_refs.as_slice(),
+        )?)
+    }
+
+    /// Reads the index from disk, since `stage_hunk` writes it through its own repository.
+    fn staged_file(&self, path: &str) -> Result<String> {
+        let repo = self.repository()?;
+        let index = repo.index()?;
+        let entry = index
+            .get_path(Path::new(path), 0)
+            .expect("the file should be in the index");
+        let blob = repo.find_blob(entry.id)?;
+        Ok(String::from_utf8(blob.content().to_vec())?)
+    }
+
+    fn head_commits<'repo>(&self, repo: &'repo Repository) -> Result<Vec<git2::Commit<'repo>>> {
+        let head = match repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(target) = head.target() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![repo.find_commit(target)?])
+    }
+}
+
+fn test_signature() -> Result<Signature<'static>> {
+    Ok(Signature::now("Hunk", "hunk@example.com")?)
+}
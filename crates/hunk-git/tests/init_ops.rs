@@ -0,0 +1,43 @@
+use std::fs;
+
+use anyhow::Result;
+use hunk_git::git::discover_repo_root;
+use hunk_git::init::{GitignoreTemplate, RepoInitTemplate, init_repository};
+use tempfile::TempDir;
+
+#[test]
+fn init_repository_seeds_template_files() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let project = tempdir.path().join("demo");
+    fs::create_dir_all(&project)?;
+    fs::write(project.join("README.md"), "existing\n")?;
+
+    let root = init_repository(
+        project.as_path(),
+        RepoInitTemplate {
+            gitignore: GitignoreTemplate::Rust,
+            readme: true,
+        },
+    )?;
+
+    assert!(root.join(".git").is_dir());
+    assert!(discover_repo_root(project.as_path()).is_ok());
+    assert_eq!(
+        fs::read_to_string(project.join(".gitignore"))?,
+        "/target\n**/*.rs.bk\n"
+    );
+    assert_eq!(fs::read_to_string(project.join("README.md"))?, "existing\n");
+    Ok(())
+}
+
+#[test]
+fn init_repository_refuses_existing_repositories() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    init_repository(tempdir.path(), RepoInitTemplate::default())?;
+    let nested = tempdir.path().join("nested");
+    fs::create_dir_all(&nested)?;
+
+    assert!(init_repository(nested.as_path(), RepoInitTemplate::default()).is_err());
+    assert!(!tempdir.path().join(".gitignore").exists());
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::fs;
+
+use anyhow::Result;
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_git::issues::{
+    RepoIssueKind, RepoIssueResolution, detect_repo_issues, resolve_repo_issue,
+};
+use tempfile::TempDir;
+
+#[test]
+fn detects_and_unsets_gone_upstream() -> Result<()> {
+    let (tempdir, repo) = repo_with_commit()?;
+    let branch_name = current_branch_name(&repo)?;
+    repo.remote("origin", "https://example.com/hunk.git")?;
+    let mut config = repo.config()?;
+    config.set_str(format!("branch.{branch_name}.remote").as_str(), "origin")?;
+    config.set_str(
+        format!("branch.{branch_name}.merge").as_str(),
+        "refs/heads/deleted",
+    )?;
+
+    let issues = detect_repo_issues(tempdir.path())?;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, RepoIssueKind::GoneUpstream);
+    assert_eq!(
+        issues[0].resolution,
+        Some(RepoIssueResolution::UnsetUpstream {
+            branch_name: branch_name.clone()
+        })
+    );
+
+    resolve_repo_issue(
+        tempdir.path(),
+        issues[0].resolution.as_ref().expect("resolution"),
+    )?;
+    assert!(detect_repo_issues(tempdir.path())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn detects_detached_head_and_prunes_missing_worktrees() -> Result<()> {
+    let (tempdir, repo) = repo_with_commit()?;
+    let worktrees = TempDir::new()?;
+    let worktree_path = worktrees.path().join("feature");
+    repo.worktree("feature", worktree_path.as_path(), None)?;
+    fs::remove_dir_all(&worktree_path)?;
+    let head = repo.head()?.target().expect("head commit");
+    repo.set_head_detached(head)?;
+
+    let issues = detect_repo_issues(tempdir.path())?;
+    assert_eq!(
+        issues.iter().map(|issue| issue.kind).collect::<Vec<_>>(),
+        vec![RepoIssueKind::DetachedHead, RepoIssueKind::StaleWorktree]
+    );
+    assert_eq!(issues[0].resolution, None);
+
+    resolve_repo_issue(
+        tempdir.path(),
+        issues[1].resolution.as_ref().expect("resolution"),
+    )?;
+    assert_eq!(
+        detect_repo_issues(tempdir.path())?
+            .iter()
+            .map(|issue| issue.kind)
+            .collect::<Vec<_>>(),
+        vec![RepoIssueKind::DetachedHead]
+    );
+    Ok(())
+}
+
+fn repo_with_commit() -> Result<(TempDir, Repository)> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    fs::write(tempdir.path().join("tracked.txt"), "base\n")?;
+    {
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("Hunk", "hunk@example.com")?;
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])?;
+    }
+    Ok((tempdir, repo))
+}
+
+fn current_branch_name(repo: &Repository) -> Result<String> {
+    Ok(repo.head()?.shorthand().unwrap_or_default().to_string())
+}
@@ -0,0 +1,66 @@
+use std::fs;
+
+use anyhow::Result;
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_git::line_changes::{
+    LineChange, LineChangeKind, line_changes_against_head, line_changes_between,
+};
+use tempfile::TempDir;
+
+fn change(kind: LineChangeKind, start_line: usize, line_count: usize) -> LineChange {
+    LineChange {
+        kind,
+        start_line,
+        line_count,
+    }
+}
+
+#[test]
+fn line_changes_classify_added_modified_and_deleted_runs() -> Result<()> {
+    let base = "one\ntwo\nthree\nfour\nfive\n";
+    let text = "zero\none\nTWO\nthree\nfive\n";
+
+    assert_eq!(
+        line_changes_between(base.as_bytes(), text.as_bytes())?,
+        vec![
+            change(LineChangeKind::Added, 0, 1),
+            change(LineChangeKind::Modified, 2, 1),
+            change(LineChangeKind::Deleted, 3, 0),
+        ]
+    );
+    assert_eq!(
+        line_changes_between(base.as_bytes(), "three\nfour\nfive\n".as_bytes())?,
+        vec![change(LineChangeKind::Deleted, 0, 0)]
+    );
+    assert!(line_changes_between(base.as_bytes(), base.as_bytes())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn line_changes_against_head_use_the_committed_file() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+
+    assert_eq!(
+        line_changes_against_head(tempdir.path(), "notes.txt", "a\nb\n")?,
+        vec![change(LineChangeKind::Added, 0, 2)]
+    );
+
+    fs::write(tempdir.path().join("notes.txt"), "a\nb\nc\n")?;
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let signature = Signature::now("Hunk", "hunk@example.com")?;
+    repo.commit(Some("HEAD"), &signature, &signature, "Notes", &tree, &[])?;
+
+    assert_eq!(
+        line_changes_against_head(tempdir.path(), "notes.txt", "a\nB\nc\nd\n")?,
+        vec![
+            change(LineChangeKind::Modified, 1, 1),
+            change(LineChangeKind::Added, 3, 1),
+        ]
+    );
+    assert!(line_changes_against_head(tempdir.path(), "notes.txt", "a\nb\nc\n")?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::fs;
+
+use anyhow::Result;
+use git2::{Repository, Signature};
+use hunk_git::maintenance::run_git_gc;
+use tempfile::TempDir;
+
+#[test]
+fn git_gc_packs_loose_objects() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    let signature = Signature::now("Hunk", "hunk@example.com")?;
+    let mut parent = None;
+    for ix in 0..20 {
+        fs::write(
+            tempdir.path().join("tracked.txt"),
+            format!("{}\n", "line ".repeat(ix * 50)),
+        )?;
+        let mut index = repo.index()?;
+        index.add_path(std::path::Path::new("tracked.txt"))?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let parents = parent
+            .map(|id| repo.find_commit(id))
+            .transpose()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        parent = Some(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            format!("commit {ix}").as_str(),
+            &tree,
+            parent_refs.as_slice(),
+        )?);
+    }
+
+    let report = run_git_gc(tempdir.path())?;
+
+    assert!(report.objects_bytes_before > 0);
+    assert!(report.objects_bytes_after < report.objects_bytes_before);
+    assert_eq!(
+        report.reclaimed_bytes(),
+        report.objects_bytes_before - report.objects_bytes_after
+    );
+    let packs = fs::read_dir(tempdir.path().join(".git/objects/pack"))?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "pack"))
+        .count();
+    assert_eq!(packs, 1);
+    assert_eq!(
+        repo.head()?.peel_to_commit()?.id(),
+        parent.expect("commits")
+    );
+    Ok(())
+}
@@ -6,8 +6,9 @@ use git2::{BranchType, IndexAddOption, Repository, Signature, build::CheckoutBui
 use hunk_git::git::{FileStatus, load_workflow_snapshot};
 use hunk_git::mutation::{
     activate_or_create_branch, commit_all, commit_all_with_details, commit_index_with_details,
-    commit_selected_paths, commit_selected_paths_with_details, restore_working_copy_paths,
-    stage_paths, staged_index_context_for_ai, unstage_paths, working_copy_context_for_ai,
+    commit_selected_paths, commit_selected_paths_with_details, move_worktree_path,
+    restore_working_copy_paths, stage_paths, staged_index_context_for_ai, unstage_paths,
+    working_copy_context_for_ai,
 };
 use tempfile::TempDir;
 
@@ -151,6 +152,25 @@ fn commit_all_respects_commit_gpg_sign_config() -> Result<()> {
     fixture.commit_all_git2("initial")?;
     fixture.write_file("tracked.txt", "base\nupdated\n")?;
 
+    let err = commit_all(fixture.root(), "record all")
+        .expect_err("commit signing should run the configured signing program");
+
+    assert!(format!("{err:#}").contains("does-not-exist-hunk-signer"));
+    assert_eq!(fixture.head_subject()?.as_deref(), Some("initial"));
+    Ok(())
+}
+
+#[test]
+fn commit_all_delegates_signing_to_git_cli_when_opted_in() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.set_config_str("gpg.program", "does-not-exist-hunk-signer")?;
+    fixture.set_config_bool("commit.gpgSign", true)?;
+    fixture.set_config_bool("hunk.signWithGitCli", true)?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all_git2("initial")?;
+    fixture.write_file("tracked.txt", "base\nupdated\n")?;
+
     let err = commit_all(fixture.root(), "record all")
         .expect_err("commit signing should be delegated to git");
 
@@ -159,6 +179,36 @@ fn commit_all_respects_commit_gpg_sign_config() -> Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+#[test]
+fn commit_all_signs_commits_without_git_cli() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let signer = fixture.root().join(".git").join("fake-signer.sh");
+    fs::write(
+        &signer,
+        "#!/bin/sh\ncat > /dev/null\nprintf '%s\\n' '-----BEGIN PGP SIGNATURE-----' 'fake' '-----END PGP SIGNATURE-----'\n",
+    )?;
+    fs::set_permissions(&signer, fs::Permissions::from_mode(0o755))?;
+    fixture.set_config_str("gpg.program", signer.to_string_lossy().as_ref())?;
+    fixture.set_config_bool("commit.gpgSign", true)?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all_git2("initial")?;
+    fixture.write_file("tracked.txt", "base\nupdated\n")?;
+
+    commit_all(fixture.root(), "record all")?;
+
+    assert_eq!(fixture.head_subject()?.as_deref(), Some("record all"));
+    let repo = fixture.repository()?;
+    let head_id = repo.head()?.peel_to_commit()?.id();
+    let (signature, _) = repo.extract_signature(&head_id, None)?;
+    let signature = String::from_utf8_lossy(&signature).to_string();
+    assert!(signature.contains("BEGIN PGP SIGNATURE"));
+    Ok(())
+}
+
 #[test]
 fn commit_selected_paths_leaves_excluded_changes_dirty() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -293,6 +343,45 @@ fn stage_and_unstage_paths_round_trip_rename_rows() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn move_worktree_path_stages_tracked_files_as_renames() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("src/old_name.rs", "one\ntwo\n")?;
+    fixture.commit_all_git2("initial")?;
+
+    move_worktree_path(fixture.root(), "src/old_name.rs", "src/new_name.rs")?;
+
+    assert!(!fixture.root().join("src/old_name.rs").exists());
+    let snapshot = load_workflow_snapshot(fixture.root())?;
+    assert_eq!(snapshot.files.len(), 1);
+    assert_eq!(snapshot.files[0].path, "src/new_name.rs");
+    assert_eq!(snapshot.files[0].status, FileStatus::Renamed);
+    assert!(snapshot.files[0].staged);
+    assert!(!snapshot.files[0].unstaged);
+    Ok(())
+}
+
+#[test]
+fn move_worktree_path_renames_untracked_files_on_disk_only() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all_git2("initial")?;
+    fixture.write_file("scratch.txt", "draft\n")?;
+
+    move_worktree_path(fixture.root(), "scratch.txt", "notes.txt")?;
+
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("notes.txt"))?,
+        "draft\n"
+    );
+    let snapshot = load_workflow_snapshot(fixture.root())?;
+    assert_eq!(snapshot.files.len(), 1);
+    assert_eq!(snapshot.files[0].path, "notes.txt");
+    assert_eq!(snapshot.files[0].status, FileStatus::Untracked);
+    assert!(move_worktree_path(fixture.root(), "notes.txt", "tracked.txt").is_err());
+    Ok(())
+}
+
 #[test]
 fn commit_index_with_details_records_only_staged_changes() -> Result<()> {
     let fixture = TempGitRepo::new()?;
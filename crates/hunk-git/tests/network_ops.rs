@@ -1,13 +1,17 @@
+use std::cell::RefCell;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use git2::{BranchType, IndexAddOption, Repository, Signature, build::CheckoutBuilder};
+use hunk_git::cancel::CancellationToken;
+use hunk_git::clone::{CloneProgress, clone_directory_name, clone_repository_with_cancel};
 use hunk_git::git::load_workflow_snapshot;
 use hunk_git::mutation::{commit_index_with_details, stage_paths};
 use hunk_git::network::{
-    push_current_branch, sync_branch_from_remote, sync_branch_from_remote_if_tracked,
-    sync_current_branch,
+    branch_behind_remote_with_cancel, push_current_branch, push_current_branch_with_cancel,
+    remote_upstream_head_with_cancel, sync_branch_from_remote, sync_branch_from_remote_if_tracked,
+    sync_current_branch, sync_current_branch_with_cancel,
 };
 use tempfile::TempDir;
 
@@ -55,6 +59,44 @@ fn push_branch_updates_tracking_ref_after_new_commit() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn cancelled_push_leaves_branch_unpublished() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    fixture.create_bare_remote("origin")?;
+    fixture.checkout_branch("feature/cancel")?;
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let err = push_current_branch_with_cancel(fixture.root(), "feature/cancel", false, &cancel)
+        .expect_err("cancelled push should fail");
+
+    assert!(err.to_string().contains("cancelled"));
+    let snapshot = load_workflow_snapshot(fixture.root())?;
+    assert!(!snapshot.branch_has_upstream);
+    Ok(())
+}
+
+#[test]
+fn sync_reports_timeout_when_deadline_has_passed() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    fixture.create_bare_remote("origin")?;
+    fixture.checkout_branch("feature/timeout")?;
+    push_current_branch(fixture.root(), "feature/timeout", false)?;
+    let cancel = CancellationToken::with_timeout(std::time::Duration::ZERO);
+
+    let err = sync_current_branch_with_cancel(fixture.root(), "feature/timeout", &cancel)
+        .expect_err("expired sync should fail");
+
+    assert!(err.to_string().contains("timed out"));
+    Ok(())
+}
+
 #[test]
 fn push_branch_allows_dirty_worktree_after_partial_commit() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -120,6 +162,134 @@ fn sync_branch_fast_forwards_checked_out_branch() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn remote_upstream_head_reports_peer_push_without_fetching() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let remote_root = fixture.create_bare_remote("origin")?;
+    fixture.checkout_branch("feature/watch")?;
+    assert_eq!(
+        remote_upstream_head_with_cancel(
+            fixture.root(),
+            "feature/watch",
+            &CancellationToken::new()
+        )?,
+        None
+    );
+    push_current_branch(fixture.root(), "feature/watch", false)?;
+
+    let peer = TempGitRepo::clone_from(fixture.root(), "peer")?;
+    peer.configure_signature()?;
+    peer.set_remote_url("origin", remote_root.to_string_lossy().as_ref())?;
+    peer.checkout_branch("feature/watch")?;
+    peer.write_file("tracked.txt", "base\nremote\n")?;
+    let peer_commit = peer.commit_all("remote update")?;
+    peer.push_to_remote("origin", "feature/watch", "feature/watch")?;
+
+    let head = remote_upstream_head_with_cancel(
+        fixture.root(),
+        "feature/watch",
+        &CancellationToken::new(),
+    )?
+    .expect("tracked branch should have a remote head");
+    assert_eq!(head.remote_name, "origin");
+    assert_eq!(head.remote_branch_name, "feature/watch");
+    assert_eq!(head.commit_id, peer_commit.to_string());
+
+    let snapshot = load_workflow_snapshot(fixture.root())?;
+    assert_eq!(snapshot.branch_behind_count, 0);
+    Ok(())
+}
+
+#[test]
+fn branch_is_behind_remote_only_when_remote_has_unseen_commits() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let remote_root = fixture.create_bare_remote("origin")?;
+    fixture.checkout_branch("feature/behind")?;
+    let cancel = CancellationToken::new();
+    assert!(!branch_behind_remote_with_cancel(
+        fixture.root(),
+        "feature/behind",
+        &cancel
+    )?);
+    push_current_branch(fixture.root(), "feature/behind", false)?;
+
+    fixture.write_file("tracked.txt", "base\nlocal\n")?;
+    fixture.commit_all("local update")?;
+    assert!(!branch_behind_remote_with_cancel(
+        fixture.root(),
+        "feature/behind",
+        &cancel
+    )?);
+
+    let peer = TempGitRepo::clone_from(fixture.root(), "peer")?;
+    peer.configure_signature()?;
+    peer.set_remote_url("origin", remote_root.to_string_lossy().as_ref())?;
+    peer.checkout_branch("feature/behind")?;
+    peer.write_file("other.txt", "remote\n")?;
+    peer.commit_all("remote update")?;
+    peer.push_to_remote("origin", "feature/behind", "feature/behind")?;
+
+    assert!(branch_behind_remote_with_cancel(
+        fixture.root(),
+        "feature/behind",
+        &cancel
+    )?);
+    Ok(())
+}
+
+#[test]
+fn clone_repository_checks_out_files_and_reports_progress() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let destination = fixture.tempdir.path().join("clones/copy");
+    let progress = RefCell::new(Vec::new());
+
+    let root = clone_repository_with_cancel(
+        fixture.root().to_string_lossy().as_ref(),
+        destination.as_path(),
+        &CancellationToken::new(),
+        |update| progress.borrow_mut().push(update),
+    )?;
+
+    assert_eq!(fs::read_to_string(root.join("tracked.txt"))?, "base\n");
+    assert!(
+        progress
+            .borrow()
+            .iter()
+            .any(|update| matches!(update, CloneProgress::CheckingOut { .. }))
+    );
+    let error = clone_repository_with_cancel(
+        fixture.root().to_string_lossy().as_ref(),
+        destination.as_path(),
+        &CancellationToken::new(),
+        |_| {},
+    )
+    .expect_err("non-empty destination should be rejected");
+    assert!(error.to_string().contains("not empty"));
+    Ok(())
+}
+
+#[test]
+fn clone_directory_name_matches_git_clone() {
+    assert_eq!(
+        clone_directory_name("git@github.com:niteshbalusu11/hunk.git").as_deref(),
+        Some("hunk")
+    );
+    assert_eq!(
+        clone_directory_name("https://example.com/team/app/").as_deref(),
+        Some("app")
+    );
+    assert_eq!(clone_directory_name("  "), None);
+}
+
 #[test]
 fn sync_branch_rejects_diverged_history() -> Result<()> {
     let fixture = TempGitRepo::new()?;
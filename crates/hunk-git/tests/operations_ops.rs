@@ -0,0 +1,251 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{IndexAddOption, Oid, Repository, ResetType, Signature, Time};
+use hunk_git::operations::{
+    BranchChangeKind, DEFAULT_REPO_OPERATION_LIMIT, diff_repo_operations, load_repo_operations,
+    restore_repo_operation, undo_repo_operation,
+};
+use tempfile::TempDir;
+
+#[test]
+fn operation_diff_reports_head_branches_commits_and_paths() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    let base = fixture.commit_all("Base")?;
+    fixture.write_file("tracked.txt", "dropped\n")?;
+    let dropped = fixture.commit_all("Dropped work")?;
+    let default_branch = repo.head()?.shorthand().unwrap_or_default().to_string();
+    repo.branch("feature", &repo.find_commit(base)?, false)?;
+    repo.reset(&repo.find_object(base, None)?, ResetType::Hard, None)?;
+
+    let operations = [
+        (base, 100, "commit (initial): Base"),
+        (dropped, 200, "commit: Dropped work"),
+        (base, 400, "reset: moving to HEAD~1"),
+    ];
+    rewrite_reflog(&repo, "HEAD", &operations)?;
+    rewrite_reflog(
+        &repo,
+        format!("refs/heads/{default_branch}").as_str(),
+        &operations,
+    )?;
+    rewrite_reflog(
+        &repo,
+        "refs/heads/feature",
+        &[(base, 300, "branch: Created from HEAD")],
+    )?;
+
+    let operations = load_repo_operations(fixture.root(), DEFAULT_REPO_OPERATION_LIMIT)?;
+    assert_eq!(operations.len(), 3);
+    assert_eq!(operations[0].description, "reset: moving to HEAD~1");
+    assert_eq!(operations[1].head_commit_id, dropped.to_string());
+
+    let diff = diff_repo_operations(fixture.root(), &operations[1], &operations[0])?;
+    assert_eq!(diff.head_before, dropped.to_string());
+    assert_eq!(diff.head_after, base.to_string());
+    let mut branch_changes = diff
+        .branch_changes
+        .iter()
+        .map(|change| (change.name.as_str(), change.kind))
+        .collect::<Vec<_>>();
+    branch_changes.sort_by_key(|(name, _)| *name);
+    let mut expected = vec![
+        ("feature", BranchChangeKind::Created),
+        (default_branch.as_str(), BranchChangeKind::Moved),
+    ];
+    expected.sort_by_key(|(name, _)| *name);
+    assert_eq!(branch_changes, expected);
+    assert!(diff.commits_added.is_empty());
+    assert_eq!(diff.commits_removed.len(), 1);
+    assert_eq!(diff.commits_removed[0].subject, "Dropped work");
+    assert_eq!(diff.changed_paths, vec!["tracked.txt".to_string()]);
+
+    assert!(diff_repo_operations(fixture.root(), &operations[0], &operations[0])?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn undo_restores_head_branches_and_files_and_undoing_again_redoes() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    let base = fixture.commit_all("Base")?;
+    fixture.write_file("tracked.txt", "next\n")?;
+    let next = fixture.commit_all("Next")?;
+    let default_branch = repo.head()?.shorthand().unwrap_or_default().to_string();
+    let operations = [
+        (base, 100, "commit (initial): Base"),
+        (next, 200, "commit: Next"),
+    ];
+    rewrite_reflog(&repo, "HEAD", &operations)?;
+    rewrite_reflog(
+        &repo,
+        format!("refs/heads/{default_branch}").as_str(),
+        &operations,
+    )?;
+
+    let restored = undo_repo_operation(fixture.root())?;
+    assert_eq!(restored.head_commit_id, base.to_string());
+    assert_eq!(restored.branch.as_deref(), Some(default_branch.as_str()));
+    assert_eq!(restored.moved_branches, vec![default_branch.clone()]);
+    assert_eq!(repo.head()?.target(), Some(base));
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("tracked.txt"))?,
+        "base\n"
+    );
+    assert!(repo.statuses(None)?.is_empty());
+
+    let redone = undo_repo_operation(fixture.root())?;
+    assert_eq!(redone.head_commit_id, next.to_string());
+    assert_eq!(repo.head()?.shorthand(), Some(default_branch.as_str()));
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("tracked.txt"))?,
+        "next\n"
+    );
+
+    fixture.write_file("tracked.txt", "edited\n")?;
+    let operations = load_repo_operations(fixture.root(), DEFAULT_REPO_OPERATION_LIMIT)?;
+    let error = restore_repo_operation(fixture.root(), operations.last().expect("operation"))
+        .expect_err("restoring over local changes should fail");
+    assert!(error.to_string().contains("local changes"));
+    Ok(())
+}
+
+#[test]
+fn undo_within_one_second_moves_the_branch_back() -> Result<()> {
+    // No reflog rewriting: both commits land within the same second.
+    let fixture = TempGitRepo::new()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    let base = fixture.commit_all("Base")?;
+    fixture.write_file("tracked.txt", "next\n")?;
+    let next = fixture.commit_all("Next")?;
+    let default_branch = repo.head()?.shorthand().unwrap_or_default().to_string();
+
+    let restored = undo_repo_operation(fixture.root())?;
+    assert_eq!(restored.head_commit_id, base.to_string());
+    assert_eq!(restored.branch.as_deref(), Some(default_branch.as_str()));
+    assert!(!repo.head_detached()?);
+    assert_eq!(repo.head()?.target(), Some(base));
+
+    let redone = undo_repo_operation(fixture.root())?;
+    assert_eq!(redone.head_commit_id, next.to_string());
+    assert_eq!(redone.branch.as_deref(), Some(default_branch.as_str()));
+    assert_eq!(repo.head()?.target(), Some(next));
+    Ok(())
+}
+
+#[test]
+fn restore_orders_a_commit_right_after_a_checkout() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    let base = fixture.commit_all("Base")?;
+    repo.branch("feature", &repo.find_commit(base)?, false)?;
+    repo.set_head("refs/heads/feature")?;
+    fixture.write_file("tracked.txt", "feature\n")?;
+    let feature = fixture.commit_all("Feature work")?;
+
+    let operations = load_repo_operations(fixture.root(), DEFAULT_REPO_OPERATION_LIMIT)?;
+    assert_eq!(operations[0].head_commit_id, feature.to_string());
+    let checkout = &operations[1];
+    assert_eq!(checkout.head_commit_id, base.to_string());
+
+    let restored = restore_repo_operation(fixture.root(), checkout)?;
+    assert_eq!(restored.branch.as_deref(), Some("feature"));
+    assert_eq!(restored.moved_branches, vec!["feature".to_string()]);
+    assert_eq!(repo.head()?.shorthand(), Some("feature"));
+    assert_eq!(repo.head()?.target(), Some(base));
+
+    let diff = diff_repo_operations(fixture.root(), checkout, &operations[0])?;
+    assert_eq!(diff.branch_changes.len(), 1);
+    assert_eq!(diff.branch_changes[0].name, "feature");
+    assert_eq!(diff.branch_changes[0].kind, BranchChangeKind::Moved);
+    assert_eq!(diff.commits_added.len(), 1);
+    assert_eq!(diff.commits_added[0].subject, "Feature work");
+    Ok(())
+}
+
+fn rewrite_reflog(repo: &Repository, name: &str, entries: &[(Oid, i64, &str)]) -> Result<()> {
+    repo.reflog_delete(name)?;
+    let mut reflog = repo.reflog(name)?;
+    for (oid, unix_time, message) in entries {
+        let signature = Signature::new("Hunk", "hunk@example.com", &Time::new(*unix_time, 0))?;
+        reflog.append(*oid, &signature, Some(message))?;
+    }
+    reflog.write()?;
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = test_signature()?;
+        let parents = self.head_commits(&repo)?;
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parent_refs.as_slice(),
+        )?)
+    }
+
+    fn head_commits<'repo>(&self, repo: &'repo Repository) -> Result<Vec<git2::Commit<'repo>>> {
+        let head = match repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(target) = head.target() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![repo.find_commit(target)?])
+    }
+}
+
+fn test_signature() -> Result<Signature<'static>> {
+    Ok(Signature::now("Hunk", "hunk@example.com")?)
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+use anyhow::Result;
+use git2::Repository;
+use hunk_git::git::{ChangedFile, FileStatus, load_workflow_snapshot};
+use hunk_git::path_collisions::{PathCollisionKind, detect_path_collisions};
+use tempfile::TempDir;
+
+fn changed(path: &str, status: FileStatus) -> ChangedFile {
+    ChangedFile {
+        path: path.to_string(),
+        status,
+        staged: false,
+        unstaged: true,
+        untracked: status == FileStatus::Untracked,
+    }
+}
+
+#[test]
+fn new_file_differing_only_by_case_from_a_tracked_file_collides() {
+    let files = vec![changed("README.md", FileStatus::Untracked)];
+
+    let collisions = detect_path_collisions(["Readme.md", "src/lib.rs"], &files);
+
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].kind, PathCollisionKind::CaseCollision);
+    assert_eq!(
+        collisions[0].paths,
+        vec!["README.md".to_string(), "Readme.md".to_string()]
+    );
+    assert!(collisions[0].affects("README.md"));
+    assert!(!collisions[0].affects("src/lib.rs"));
+}
+
+#[test]
+fn directory_case_rename_is_reported_once_as_a_case_only_rename() {
+    let files = vec![
+        changed("Src/a.rs", FileStatus::Deleted),
+        changed("Src/b.rs", FileStatus::Deleted),
+        changed("src/a.rs", FileStatus::Untracked),
+        changed("src/b.rs", FileStatus::Untracked),
+    ];
+
+    let collisions = detect_path_collisions(["Src/a.rs", "Src/b.rs", "docs/guide.md"], &files);
+
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].kind, PathCollisionKind::CaseOnlyRename);
+    assert_eq!(
+        collisions[0].paths,
+        vec!["Src".to_string(), "src".to_string()]
+    );
+    assert!(collisions[0].affects("src/a.rs"));
+    assert!(collisions[0].affects("Src/b.rs"));
+    assert!(!collisions[0].affects("srcs/c.rs"));
+}
+
+#[test]
+fn distinct_paths_do_not_collide() {
+    let files = vec![changed("src/main.rs", FileStatus::Modified)];
+
+    assert!(detect_path_collisions(["src/main.rs", "src/mod.rs", "Cargo.toml"], &files).is_empty());
+}
+
+#[test]
+fn snapshot_skips_collision_detection_on_case_sensitive_repositories() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let repo = Repository::init(tempdir.path())?;
+    repo.config()?.set_bool("core.ignorecase", false)?;
+    fs::write(tempdir.path().join("Readme.md"), "one\n")?;
+    fs::write(tempdir.path().join("README.md"), "two\n")?;
+
+    let snapshot = load_workflow_snapshot(tempdir.path())?;
+
+    assert!(snapshot.path_collisions.is_empty());
+    Ok(())
+}
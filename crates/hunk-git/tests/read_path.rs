@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::thread;
@@ -15,7 +15,7 @@ use hunk_git::git::{
     load_repo_line_stats, load_repo_line_stats_without_refresh, load_repo_tree, load_snapshot,
     load_snapshot_without_refresh, load_visible_repo_file_paths, load_workflow_snapshot,
     load_workflow_snapshot_if_changed_without_refresh, load_workflow_snapshot_with_fingerprint,
-    load_workflow_snapshot_without_refresh, open_patch_session,
+    load_workflow_snapshot_without_refresh, load_worktree_renames, open_patch_session,
 };
 use tempfile::TempDir;
 
@@ -565,6 +565,32 @@ fn workflow_snapshot_reports_unstaged_rename_as_single_entry() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn worktree_renames_map_new_paths_to_their_sources() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("src/old_name.rs", "one\ntwo\n")?;
+    fixture.write_file("src/staged_old.rs", "three\nfour\n")?;
+    fixture.write_file("src/edited.rs", "five\n")?;
+    fixture.commit_all("initial")?;
+    fixture.rename_path("src/old_name.rs", "src/new_name.rs")?;
+    fixture.rename_path("src/staged_old.rs", "src/staged_new.rs")?;
+    fixture.stage_rename("src/staged_old.rs", "src/staged_new.rs")?;
+    fixture.write_file("src/edited.rs", "six\n")?;
+
+    let renames = load_worktree_renames(fixture.root())?;
+    assert_eq!(
+        renames,
+        BTreeMap::from([
+            ("src/new_name.rs".to_string(), "src/old_name.rs".to_string()),
+            (
+                "src/staged_new.rs".to_string(),
+                "src/staged_old.rs".to_string()
+            ),
+        ])
+    );
+    Ok(())
+}
+
 #[test]
 fn patch_session_uses_source_path_for_staged_rename() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -615,6 +641,51 @@ fn load_patch_marks_binary_diffs_without_preview_hunks() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn loading_snapshots_and_patches_leaves_the_repository_untouched() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("src/lib.rs", "one\ntwo\n")?;
+    fixture.write_file("README.md", "readme\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("src/lib.rs", "one\nthree\n")?;
+    fixture.write_file("staged.txt", "staged\n")?;
+    fixture.stage_path("staged.txt")?;
+    fixture.write_file("untracked.txt", "untracked\n")?;
+    let before = git_dir_contents(fixture.root().join(".git").as_path())?;
+
+    let workflow = load_workflow_snapshot(fixture.root())?;
+    load_workflow_snapshot_without_refresh(fixture.root())?;
+    load_snapshot(fixture.root())?;
+    load_repo_file_line_stats_without_refresh(fixture.root())?;
+    load_repo_tree(fixture.root())?;
+    let session = open_patch_session(fixture.root())?;
+    load_patches_for_files_from_session(&session, &workflow.files)?;
+    drop(session);
+
+    assert_eq!(workflow.files.len(), 3);
+    assert_eq!(
+        git_dir_contents(fixture.root().join(".git").as_path())?,
+        before
+    );
+    Ok(())
+}
+
+fn git_dir_contents(root: &Path) -> Result<BTreeMap<PathBuf, Vec<u8>>> {
+    let mut contents = BTreeMap::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                contents.insert(path.strip_prefix(root)?.to_path_buf(), fs::read(&path)?);
+            }
+        }
+    }
+    Ok(contents)
+}
+
 struct TempGitRepo {
     tempdir: TempDir,
     root: PathBuf,
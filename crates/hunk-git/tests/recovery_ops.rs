@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, anyhow};
+use git2::Repository;
+use hunk_git::error::GitError;
+use hunk_git::network::add_remote;
+use hunk_git::recovery::{GitRecoveryAction, recovery_action_for_error};
+use hunk_git::user_config::{GitConfigScope, read_user_identity, write_user_identity};
+use tempfile::TempDir;
+
+#[test]
+fn recovery_action_detects_missing_identity_through_context() {
+    let err = anyhow!("config value 'user.name' was not found")
+        .context("failed to resolve Git author signature for commit");
+    assert_eq!(
+        recovery_action_for_error(&err),
+        Some(GitRecoveryAction::ConfigureIdentity)
+    );
+}
+
+#[test]
+fn recovery_action_detects_missing_remote_and_conflicts() {
+    let remote_err = anyhow!("no Git remote configured for publish/push");
+    assert_eq!(
+        recovery_action_for_error(&remote_err),
+        Some(GitRecoveryAction::ConfigureRemote)
+    );
+
+    let conflict_err = anyhow!("cannot operate on conflicted files");
+    assert_eq!(
+        recovery_action_for_error(&conflict_err),
+        Some(GitRecoveryAction::ResolveConflicts)
+    );
+
+    let other_err = anyhow!("failed to open repository");
+    assert_eq!(recovery_action_for_error(&other_err), None);
+}
+
+#[test]
+fn recovery_action_prefers_typed_errors() {
+    let err = anyhow::Error::from(GitError::MissingIdentity).context("Create commit failed");
+    assert_eq!(
+        recovery_action_for_error(&err),
+        Some(GitRecoveryAction::ConfigureIdentity)
+    );
+
+    let err = anyhow::Error::from(GitError::NothingToCommit);
+    assert_eq!(recovery_action_for_error(&err), None);
+}
+
+#[test]
+fn write_user_identity_updates_repository_config() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+
+    write_user_identity(
+        fixture.root(),
+        GitConfigScope::Repository,
+        " Hunk Reviewer ",
+        "reviewer@example.com",
+    )?;
+
+    let identity = read_user_identity(fixture.root())?;
+    assert_eq!(identity.name.as_deref(), Some("Hunk Reviewer"));
+    assert_eq!(identity.email.as_deref(), Some("reviewer@example.com"));
+    assert!(identity.is_complete());
+    Ok(())
+}
+
+#[test]
+fn write_user_identity_rejects_invalid_email() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+
+    let result = write_user_identity(
+        fixture.root(),
+        GitConfigScope::Repository,
+        "Hunk",
+        "not-an-email",
+    );
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn add_remote_registers_new_remote_and_rejects_duplicates() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+
+    add_remote(fixture.root(), "origin", "https://example.com/hunk.git")?;
+
+    let repo = Repository::open(fixture.root())?;
+    let remote = repo.find_remote("origin")?;
+    assert_eq!(remote.url(), Some("https://example.com/hunk.git"));
+    assert!(add_remote(fixture.root(), "origin", "https://example.com/other.git").is_err());
+    assert!(add_remote(fixture.root(), "bad name", "https://example.com/hunk.git").is_err());
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+}
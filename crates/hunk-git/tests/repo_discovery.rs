@@ -51,6 +51,35 @@ fn fingerprint_reports_unborn_head_without_commit_id() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn fingerprint_reports_paths_changed_since_previous_fingerprint() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fs::write(fixture.root().join("edited.txt"), "before\n")?;
+    fs::write(fixture.root().join("untouched.txt"), "same\n")?;
+    fs::write(fixture.root().join("removed.txt"), "gone soon\n")?;
+    let previous = load_snapshot_fingerprint(fixture.root())?;
+
+    fs::write(fixture.root().join("edited.txt"), "after the edit\n")?;
+    fs::remove_file(fixture.root().join("removed.txt"))?;
+    fs::write(fixture.root().join("added.txt"), "new\n")?;
+    let current = load_snapshot_fingerprint(fixture.root())?;
+
+    let changed = current
+        .changed_paths_since(&previous)
+        .expect("same HEAD should report a file delta");
+    assert_eq!(
+        changed.iter().map(String::as_str).collect::<Vec<_>>(),
+        vec!["added.txt", "edited.txt", "removed.txt"]
+    );
+    assert_eq!(
+        current
+            .changed_paths_since(&current)
+            .map(|paths| paths.len()),
+        Some(0)
+    );
+    Ok(())
+}
+
 #[cfg(windows)]
 #[test]
 fn discovered_repo_root_uses_normal_windows_path_prefix() -> Result<()> {
@@ -0,0 +1,62 @@
+use std::fs;
+
+use anyhow::Result;
+use git2::Repository;
+use hunk_git::git::load_workflow_snapshot;
+use hunk_git::snapshot_exclusions::{
+    normalize_snapshot_exclusions, read_snapshot_exclusions, write_snapshot_exclusions,
+};
+use tempfile::TempDir;
+
+#[test]
+fn excluded_scratch_paths_are_left_out_of_the_snapshot() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    Repository::init(tempdir.path())?;
+    fs::create_dir_all(tempdir.path().join("scratch"))?;
+    fs::write(tempdir.path().join("scratch/notes.md"), "todo\n")?;
+    fs::write(tempdir.path().join("local.env"), "KEY=1\n")?;
+    fs::write(tempdir.path().join("main.rs"), "fn main() {}\n")?;
+
+    write_snapshot_exclusions(
+        tempdir.path(),
+        &["scratch/".to_string(), "*.env".to_string()],
+    )?;
+
+    let paths = load_workflow_snapshot(tempdir.path())?
+        .files
+        .into_iter()
+        .map(|file| file.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["main.rs".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn exclusions_round_trip_and_keep_hand_written_patterns() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    Repository::init(tempdir.path())?;
+    let exclude_path = tempdir.path().join(".git/info/exclude");
+    fs::create_dir_all(exclude_path.parent().expect("info dir"))?;
+    fs::write(&exclude_path, "# personal\n*.swp\n")?;
+
+    write_snapshot_exclusions(
+        tempdir.path(),
+        &[" tmp/ ".to_string(), String::new(), "tmp/".to_string()],
+    )?;
+    assert_eq!(
+        read_snapshot_exclusions(tempdir.path())?,
+        vec!["tmp/".to_string()]
+    );
+    assert!(fs::read_to_string(&exclude_path)?.starts_with("# personal\n*.swp\n"));
+
+    write_snapshot_exclusions(tempdir.path(), &[])?;
+    assert!(read_snapshot_exclusions(tempdir.path())?.is_empty());
+    assert_eq!(fs::read_to_string(&exclude_path)?, "# personal\n*.swp\n");
+    Ok(())
+}
+
+#[test]
+fn negated_and_comment_patterns_are_rejected() {
+    assert!(normalize_snapshot_exclusions(&["!keep".to_string()]).is_err());
+    assert!(normalize_snapshot_exclusions(&["# note".to_string()]).is_err());
+}
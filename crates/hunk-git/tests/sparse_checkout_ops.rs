@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Result, anyhow};
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_git::git::{load_visible_repo_file_paths, load_workflow_snapshot};
+use hunk_git::sparse_checkout::{
+    SparseCheckout, load_sparse_checkout, path_excluded_by_sparse_checkout,
+    sparse_checkout_widen_pattern, widen_sparse_checkout,
+};
+use tempfile::TempDir;
+
+#[test]
+fn full_checkout_reports_no_sparse_checkout() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    init_monorepo(tempdir.path())?;
+
+    assert_eq!(load_sparse_checkout(tempdir.path())?, None);
+    assert!(!path_excluded_by_sparse_checkout(
+        tempdir.path(),
+        "libs/core/lib.rs"
+    )?);
+    Ok(())
+}
+
+#[test]
+fn sparse_checkout_hides_excluded_paths_until_widened() -> Result<()> {
+    let tempdir = TempDir::new()?;
+    let root = tempdir.path();
+    init_monorepo(root)?;
+    run_git(root, &["sparse-checkout", "set", "--cone", "apps"])?;
+
+    let sparse = load_sparse_checkout(root)?.expect("sparse checkout should be active");
+    assert!(sparse.cone);
+    assert!(sparse.patterns.iter().any(|pattern| pattern == "/apps/"));
+    assert!(!root.join("libs/core/lib.rs").exists());
+    assert!(path_excluded_by_sparse_checkout(root, "libs/core/lib.rs")?);
+    assert!(path_excluded_by_sparse_checkout(root, "libs")?);
+    assert!(!path_excluded_by_sparse_checkout(
+        root,
+        "apps/web/index.ts"
+    )?);
+
+    let visible = load_visible_repo_file_paths(root)?;
+    assert!(visible.contains(&"apps/web/index.ts".to_string()));
+    assert!(visible.contains(&"README.md".to_string()));
+    assert!(!visible.contains(&"libs/core/lib.rs".to_string()));
+    assert!(load_workflow_snapshot(root)?.files.is_empty());
+
+    let pattern = sparse_checkout_widen_pattern(&sparse, "libs/core/lib.rs")?;
+    assert_eq!(pattern, "libs/core");
+    widen_sparse_checkout(root, pattern.as_str())?;
+
+    assert!(root.join("libs/core/lib.rs").exists());
+    assert!(!path_excluded_by_sparse_checkout(root, "libs/core/lib.rs")?);
+    assert!(load_visible_repo_file_paths(root)?.contains(&"libs/core/lib.rs".to_string()));
+    assert!(load_workflow_snapshot(root)?.files.is_empty());
+    Ok(())
+}
+
+#[test]
+fn widen_pattern_depends_on_sparse_checkout_mode() -> Result<()> {
+    let cone = SparseCheckout {
+        cone: true,
+        patterns: Vec::new(),
+    };
+    let non_cone = SparseCheckout {
+        cone: false,
+        patterns: Vec::new(),
+    };
+
+    assert_eq!(
+        sparse_checkout_widen_pattern(&cone, "libs/core/lib.rs")?,
+        "libs/core"
+    );
+    assert!(sparse_checkout_widen_pattern(&cone, "README.md").is_err());
+    assert_eq!(
+        sparse_checkout_widen_pattern(&non_cone, "libs/core/lib.rs")?,
+        "/libs/core/lib.rs"
+    );
+    Ok(())
+}
+
+fn init_monorepo(root: &Path) -> Result<()> {
+    let repo = Repository::init(root)?;
+    for (path, contents) in [
+        ("README.md", "# monorepo\n"),
+        ("apps/web/index.ts", "export {};\n"),
+        ("libs/core/lib.rs", "pub fn core() {}\n"),
+    ] {
+        let full_path = root.join(path);
+        fs::create_dir_all(full_path.parent().expect("fixture paths have parents"))?;
+        fs::write(full_path, contents)?;
+    }
+
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let signature = Signature::now("Hunk Test", "hunk@example.com")?;
+    repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])?;
+    Ok(())
+}
+
+fn run_git(root: &Path, args: &[&str]) -> Result<()> {
+    let output = Command::new("git").current_dir(root).args(args).output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(output.stderr.as_slice())
+        ));
+    }
+    Ok(())
+}
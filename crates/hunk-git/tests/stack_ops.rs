@@ -0,0 +1,206 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git2::{IndexAddOption, Oid, Repository, Signature};
+use hunk_git::stack::{StackAction, StackPlanEntry, apply_stack_plan, load_branch_stack};
+use tempfile::TempDir;
+
+#[test]
+fn stack_edit_reorders_squashes_and_drops_commits_in_one_step() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("base.txt", "base\n")?;
+    let base = fixture.commit_all("Base")?;
+    let default_branch = repo.head()?.shorthand().unwrap_or_default().to_string();
+    fixture.start_feature_branch(base)?;
+    fixture.write_file("a.txt", "a\n")?;
+    let first = fixture.commit_all("Add a")?;
+    fixture.write_file("b.txt", "b\n")?;
+    let second = fixture.commit_all("Add b")?;
+    fixture.write_file("c.txt", "c\n")?;
+    let third = fixture.commit_all("Add c")?;
+    fixture.write_file("d.txt", "d\n")?;
+    let fourth = fixture.commit_all("Add d")?;
+
+    let stack = load_branch_stack(fixture.root())?;
+    assert_eq!(stack.branch, "feature");
+    assert_eq!(stack.base_label, default_branch);
+    assert_eq!(stack.base_commit_id, base.to_string());
+    let subjects = stack
+        .commits
+        .iter()
+        .map(|commit| commit.subject.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(subjects, vec!["Add a", "Add b", "Add c", "Add d"]);
+
+    let plan = vec![
+        StackPlanEntry::pick(first.to_string()),
+        StackPlanEntry::pick(third.to_string()),
+        StackPlanEntry {
+            commit_id: second.to_string(),
+            action: StackAction::Squash,
+        },
+        StackPlanEntry {
+            commit_id: fourth.to_string(),
+            action: StackAction::Drop,
+        },
+    ];
+    let rewrite = apply_stack_plan(fixture.root(), &stack, &plan)?;
+    assert_eq!(rewrite.branch, "feature");
+    assert_eq!(rewrite.commit_count, 2);
+
+    let head = repo.head()?.peel_to_commit()?;
+    assert_eq!(head.id().to_string(), rewrite.head_commit_id);
+    assert_eq!(head.message(), Some("Add c\n\nAdd b\n"));
+    // The untouched bottom commit keeps its id.
+    assert_eq!(head.parent_id(0)?, first);
+    assert_eq!(repo.head()?.shorthand(), Some("feature"));
+    assert!(fixture.root().join("b.txt").exists());
+    assert!(!fixture.root().join("d.txt").exists());
+    assert!(repo.statuses(None)?.is_empty());
+
+    let stale = apply_stack_plan(fixture.root(), &stack, &plan)
+        .expect_err("a stale stack should be refused");
+    assert!(
+        stale
+            .to_string()
+            .contains("changed since the stack was loaded")
+    );
+    Ok(())
+}
+
+#[test]
+fn conflicting_reorders_and_leading_squashes_leave_the_branch_alone() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.configure_signature()?;
+    let repo = fixture.repository()?;
+    fixture.write_file("notes.txt", "zero\n")?;
+    let base = fixture.commit_all("Base")?;
+    fixture.start_feature_branch(base)?;
+    fixture.write_file("notes.txt", "one\n")?;
+    let first = fixture.commit_all("One")?;
+    fixture.write_file("notes.txt", "two\n")?;
+    let second = fixture.commit_all("Two")?;
+    let stack = load_branch_stack(fixture.root())?;
+
+    let reordered = vec![
+        StackPlanEntry::pick(second.to_string()),
+        StackPlanEntry::pick(first.to_string()),
+    ];
+    let error = apply_stack_plan(fixture.root(), &stack, &reordered)
+        .expect_err("reordering dependent edits should conflict");
+    assert!(error.to_string().contains("conflicts in notes.txt"));
+    assert_eq!(repo.head()?.target(), Some(second));
+    assert_eq!(
+        fs::read_to_string(fixture.root().join("notes.txt"))?,
+        "two\n"
+    );
+
+    let squash_first = vec![
+        StackPlanEntry {
+            commit_id: first.to_string(),
+            action: StackAction::Squash,
+        },
+        StackPlanEntry::pick(second.to_string()),
+    ];
+    let error = apply_stack_plan(fixture.root(), &stack, &squash_first)
+        .expect_err("the first commit has nothing to squash into");
+    assert!(error.to_string().contains("cannot be squashed"));
+
+    let unchanged = vec![
+        StackPlanEntry::pick(first.to_string()),
+        StackPlanEntry::pick(second.to_string()),
+    ];
+    let error = apply_stack_plan(fixture.root(), &stack, &unchanged)
+        .expect_err("an unchanged plan should be refused");
+    assert!(error.to_string().contains("unchanged"));
+    Ok(())
+}
+
+struct TempGitRepo {
+    _tempdir: TempDir,
+    root: PathBuf,
+}
+
+impl TempGitRepo {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        let repo = Repository::init(root.as_path())?;
+        drop(repo);
+        Ok(Self {
+            _tempdir: tempdir,
+            root: fs::canonicalize(root)?,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn repository(&self) -> Result<Repository> {
+        Ok(Repository::open(self.root.as_path())?)
+    }
+
+    fn configure_signature(&self) -> Result<()> {
+        let repo = self.repository()?;
+        let mut config = repo.config()?;
+        config.set_str("user.name", "Hunk")?;
+        config.set_str("user.email", "hunk@example.com")?;
+        Ok(())
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Points `HEAD` at a new `feature` branch on `base`, leaving the files as they are.
+    fn start_feature_branch(&self, base: Oid) -> Result<()> {
+        let repo = self.repository()?;
+        repo.branch("feature", &repo.find_commit(base)?, false)?;
+        repo.set_head("refs/heads/feature")?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<Oid> {
+        let repo = self.repository()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = test_signature()?;
+        let parents = self.head_commits(&repo)?;
+        let parent_refs = parents.iter().collect::<Vec<_>>();
+        Ok(repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            parent_refs.as_slice(),
+        )?)
+    }
+
+    fn head_commits<'repo>(&self, repo: &'repo Repository) -> Result<Vec<git2::Commit<'repo>>> {
+        let head = match repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(target) = head.target() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![repo.find_commit(target)?])
+    }
+}
+
+fn test_signature() -> Result<Signature<'static>> {
+    Ok(Signature::now("Hunk", "hunk@example.com")?)
+}
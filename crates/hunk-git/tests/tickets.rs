@@ -0,0 +1,50 @@
+use hunk_git::tickets::{TicketLinker, commit_message_with_ticket};
+
+#[test]
+fn ticket_linker_finds_default_ids_and_builds_links() {
+    let linker = TicketLinker::new(&[], "https://tracker.example.com/browse/{ticket}")
+        .expect("default linker should build");
+
+    let tickets = linker.find_tickets("ABC-123: fix login (see ENG-9)");
+    assert_eq!(
+        tickets
+            .iter()
+            .map(|found| (found.range.clone(), found.id.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(0..7, "ABC-123"), (24..29, "ENG-9")]
+    );
+    assert_eq!(
+        linker.ticket_url("ABC-123").as_deref(),
+        Some("https://tracker.example.com/browse/ABC-123")
+    );
+    assert_eq!(
+        linker.branch_ticket_id("nb/abc-123-fix-login").as_deref(),
+        Some("ABC-123")
+    );
+    assert_eq!(linker.first_ticket_id("fix login"), None);
+}
+
+#[test]
+fn ticket_linker_uses_configured_patterns() {
+    let linker = TicketLinker::new(&["#[0-9]+".to_string()], "").expect("pattern should compile");
+
+    assert_eq!(
+        linker.ticket_ids("Fixes #12 and #12, refs #7"),
+        vec!["#12".to_string(), "#7".to_string()]
+    );
+    assert_eq!(linker.first_ticket_id("ABC-123"), None);
+    assert_eq!(linker.ticket_url("#12"), None);
+    assert!(TicketLinker::new(&["(".to_string()], "").is_err());
+}
+
+#[test]
+fn commit_message_with_ticket_prefixes_missing_ids_only() {
+    assert_eq!(
+        commit_message_with_ticket("Fix login", "ABC-123"),
+        "ABC-123: Fix login"
+    );
+    assert_eq!(
+        commit_message_with_ticket("abc-123 fix login", "ABC-123"),
+        "abc-123 fix login"
+    );
+}
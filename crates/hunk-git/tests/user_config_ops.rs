@@ -0,0 +1,81 @@
+use std::fs;
+
+use anyhow::Result;
+use hunk_git::user_config::{
+    GitSigningFormat, GitUserSettings, UserIdentity, read_user_settings_at, write_user_settings_at,
+};
+
+#[test]
+fn read_user_settings_defaults_when_config_is_missing() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+
+    let settings = read_user_settings_at(tempdir.path().join(".gitconfig").as_path())?;
+
+    assert_eq!(settings, GitUserSettings::default());
+    Ok(())
+}
+
+#[test]
+fn write_user_settings_round_trips_and_preserves_other_entries() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let path = tempdir.path().join(".gitconfig");
+    fs::write(&path, "[core]\n\teditor = vim\n")?;
+
+    let settings = GitUserSettings {
+        identity: UserIdentity {
+            name: Some("Hunk".to_string()),
+            email: Some("hunk@example.com".to_string()),
+        },
+        auto_setup_remote: true,
+        sign_commits: true,
+        signing_format: GitSigningFormat::Ssh,
+        signing_key: Some("~/.ssh/id_ed25519.pub".to_string()),
+    };
+    write_user_settings_at(path.as_path(), &settings)?;
+
+    assert_eq!(read_user_settings_at(path.as_path())?, settings);
+    let contents = fs::read_to_string(&path)?;
+    assert!(contents.contains("editor = vim"));
+    Ok(())
+}
+
+#[test]
+fn write_user_settings_removes_cleared_entries() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let path = tempdir.path().join(".gitconfig");
+    fs::write(
+        &path,
+        "[user]\n\tname = Hunk\n\tsigningKey = ABC123\n[commit]\n\tgpgSign = true\n",
+    )?;
+
+    let settings = GitUserSettings {
+        identity: UserIdentity {
+            name: Some("Hunk".to_string()),
+            email: None,
+        },
+        ..GitUserSettings::default()
+    };
+    write_user_settings_at(path.as_path(), &settings)?;
+
+    let reloaded = read_user_settings_at(path.as_path())?;
+    assert_eq!(reloaded, settings);
+    assert!(!reloaded.sign_commits);
+    assert_eq!(reloaded.signing_key, None);
+    Ok(())
+}
+
+#[test]
+fn write_user_settings_rejects_ssh_signing_without_key() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let path = tempdir.path().join(".gitconfig");
+
+    let settings = GitUserSettings {
+        sign_commits: true,
+        signing_format: GitSigningFormat::Ssh,
+        ..GitUserSettings::default()
+    };
+
+    assert!(write_user_settings_at(path.as_path(), &settings).is_err());
+    assert!(!path.exists());
+    Ok(())
+}
@@ -5,15 +5,21 @@ use std::sync::OnceLock;
 use anyhow::{Context as _, Result};
 use git2::{
     BranchType, IndexAddOption, Repository, RepositoryInitOptions, Signature,
-    build::CheckoutBuilder,
+    build::{CheckoutBuilder, RepoBuilder},
 };
 use hunk_domain::paths::{HUNK_HOME_DIR_ENV_VAR, hunk_home_dir};
-use hunk_git::compare::{CompareSource, load_compare_snapshot};
-use hunk_git::git::load_workflow_snapshot;
+use hunk_git::cancel::CancellationToken;
+use hunk_git::compare::{
+    CompareOptions, CompareSource, load_compare_snapshot, load_compare_snapshot_with_options,
+    refresh_compare_snapshot_paths,
+};
+use hunk_git::document_text::is_extracted_text_patch;
+use hunk_git::git::{load_repo_tree, load_workflow_snapshot};
+use hunk_git::hex_diff::is_hex_dump_patch;
 use hunk_git::worktree::{
     CreateWorktreeRequest, PRIMARY_WORKSPACE_TARGET_ID, WorkspaceTargetKind,
     create_managed_worktree, list_workspace_targets, managed_worktree_path, managed_worktrees_root,
-    path_is_within_managed_worktrees, remove_managed_worktree,
+    path_is_within_managed_worktrees, primary_repo_root, remove_managed_worktree,
     repo_relative_path_is_within_managed_worktrees, validate_managed_worktree_removal,
 };
 use tempfile::TempDir;
@@ -335,6 +341,112 @@ fn removing_managed_worktree_rejects_external_linked_worktrees() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn linked_worktrees_of_a_bare_repository_open_as_their_own_projects() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let parent = tempfile::tempdir()?;
+    let bare_root = parent.path().join("repo.git");
+    let bare = RepoBuilder::new().bare(true).clone(
+        fixture.root().to_string_lossy().as_ref(),
+        bare_root.as_path(),
+    )?;
+    let review_root = parent.path().join("review");
+    let other_root = parent.path().join("other");
+    bare.worktree("review", review_root.as_path(), None)?;
+    bare.worktree("other", other_root.as_path(), None)?;
+    let bare_root = fs::canonicalize(bare_root)?;
+    let review_root = fs::canonicalize(review_root)?;
+    let other_root = fs::canonicalize(other_root)?;
+
+    assert_eq!(primary_repo_root(bare_root.as_path())?, bare_root);
+    assert_eq!(primary_repo_root(review_root.as_path())?, review_root);
+
+    let targets = list_workspace_targets(review_root.as_path())?;
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0].kind, WorkspaceTargetKind::PrimaryCheckout);
+    assert_eq!(targets[0].root, review_root);
+    assert_eq!(targets[0].branch_name, "review");
+    assert!(targets[0].is_active);
+    assert_eq!(targets[1].kind, WorkspaceTargetKind::LinkedWorktree);
+    assert_eq!(targets[1].root, other_root);
+    assert!(!targets[1].is_active);
+
+    fs::write(review_root.join("tracked.txt"), "changed\n")?;
+    let snapshot = load_workflow_snapshot(review_root.as_path())?;
+    assert_eq!(snapshot.root, review_root);
+    assert_eq!(snapshot.branch_name, "review");
+    assert_eq!(
+        snapshot
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["tracked.txt"]
+    );
+    Ok(())
+}
+
+#[test]
+fn bare_repository_opens_without_a_working_copy() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let parent = tempfile::tempdir()?;
+    let bare_root = parent.path().join("repo.git");
+    RepoBuilder::new().bare(true).clone(
+        fixture.root().to_string_lossy().as_ref(),
+        bare_root.as_path(),
+    )?;
+    let bare_root = fs::canonicalize(bare_root)?;
+
+    let targets = list_workspace_targets(bare_root.as_path())?;
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].root, bare_root);
+    assert_eq!(targets[0].branch_name, "main");
+
+    let snapshot = load_workflow_snapshot(bare_root.as_path())?;
+    assert_eq!(snapshot.branch_name, "main");
+    assert!(snapshot.files.is_empty());
+    assert_eq!(snapshot.last_commit_subject.as_deref(), Some("initial"));
+    assert!(load_repo_tree(bare_root.as_path())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn separate_git_dir_resolves_to_its_working_tree() -> Result<()> {
+    let parent = tempfile::tempdir()?;
+    let git_dir = parent.path().join("storage/repo.git");
+    let workdir = parent.path().join("checkout");
+    fs::create_dir_all(workdir.as_path())?;
+    let mut options = RepositoryInitOptions::new();
+    options.initial_head("main").workdir_path(workdir.as_path());
+    Repository::init_opts(git_dir.as_path(), &options)?;
+    let workdir = fs::canonicalize(workdir)?;
+    fs::write(workdir.join("tracked.txt"), "base\n")?;
+
+    assert!(workdir.join(".git").is_file());
+    assert_eq!(primary_repo_root(workdir.as_path())?, workdir);
+
+    let targets = list_workspace_targets(workdir.as_path())?;
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].root, workdir);
+    assert!(targets[0].is_active);
+
+    let snapshot = load_workflow_snapshot(workdir.as_path())?;
+    assert_eq!(snapshot.root, workdir);
+    assert_eq!(
+        snapshot
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["tracked.txt"]
+    );
+    Ok(())
+}
+
 #[test]
 fn compare_snapshot_supports_branch_to_worktree_diffs() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -373,6 +485,53 @@ fn compare_snapshot_supports_branch_to_worktree_diffs() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn compare_snapshot_supports_revision_ranges() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("tracked.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("tracked.txt", "base\nsecond\n")?;
+    fixture.commit_all("second")?;
+    fixture.write_file("other.txt", "third\n")?;
+    fixture.commit_all("third")?;
+
+    let snapshot = load_compare_snapshot(
+        fixture.root(),
+        &CompareSource::Revision {
+            spec: "HEAD~2".to_string(),
+        },
+        &CompareSource::Revision {
+            spec: "HEAD~1".to_string(),
+        },
+    )?;
+    assert_eq!(
+        snapshot
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["tracked.txt"]
+    );
+    assert!(
+        snapshot
+            .patches_by_path
+            .get("tracked.txt")
+            .is_some_and(|patch| patch.contains("+second"))
+    );
+
+    let missing = load_compare_snapshot(
+        fixture.root(),
+        &CompareSource::Revision {
+            spec: "no-such-revision".to_string(),
+        },
+        &CompareSource::Branch {
+            name: "main".to_string(),
+        },
+    );
+    assert!(missing.is_err());
+    Ok(())
+}
+
 #[test]
 fn compare_snapshot_supports_worktree_to_branch_diffs() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -447,6 +606,75 @@ fn compare_snapshot_supports_branch_to_worktree_new_files() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn compare_snapshot_refreshes_only_requested_paths() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_file("edited.txt", "base\n")?;
+    fixture.write_file("reverted.txt", "base\n")?;
+    fixture.write_file("untouched.txt", "base\n")?;
+    fixture.commit_all("initial")?;
+    let worktree = create_managed_worktree(
+        fixture.root(),
+        &CreateWorktreeRequest {
+            branch_name: "feature/partial-refresh".to_string(),
+            base_branch_name: None,
+        },
+    )?;
+    fs::write(worktree.root.join("edited.txt"), "base\nfirst\n")?;
+    fs::write(worktree.root.join("reverted.txt"), "base\nreverted\n")?;
+    fs::write(worktree.root.join("untouched.txt"), "base\nuntouched\n")?;
+    let left = CompareSource::Branch {
+        name: "main".to_string(),
+    };
+    let right = CompareSource::WorkspaceTarget {
+        target_id: worktree.id.clone(),
+        root: worktree.root.clone(),
+    };
+    let previous = load_compare_snapshot(fixture.root(), &left, &right)?;
+    assert_eq!(previous.files.len(), 3);
+
+    fs::write(worktree.root.join("edited.txt"), "base\nsecond\nthird\n")?;
+    fs::write(worktree.root.join("reverted.txt"), "base\n")?;
+    fs::write(worktree.root.join("added.txt"), "added\n")?;
+    // Not requested, so the refresh keeps the previous patch for it.
+    fs::write(worktree.root.join("untouched.txt"), "base\nignored\n")?;
+    let paths = ["added.txt", "edited.txt", "reverted.txt"]
+        .into_iter()
+        .map(ToString::to_string)
+        .collect();
+
+    let snapshot = refresh_compare_snapshot_paths(
+        fixture.root(),
+        &left,
+        &right,
+        CompareOptions::default(),
+        &previous,
+        &paths,
+        &CancellationToken::new(),
+    )?;
+
+    assert_eq!(
+        snapshot
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["added.txt", "edited.txt", "untouched.txt"]
+    );
+    assert!(
+        snapshot
+            .patches_by_path
+            .get("edited.txt")
+            .is_some_and(|patch| patch.contains("+third"))
+    );
+    assert_eq!(
+        snapshot.patches_by_path.get("untouched.txt"),
+        previous.patches_by_path.get("untouched.txt")
+    );
+    assert_eq!(snapshot.overall_line_stats.added, 4);
+    Ok(())
+}
+
 #[test]
 fn compare_snapshot_marks_binary_branch_to_worktree_diffs() -> Result<()> {
     let fixture = TempGitRepo::new()?;
@@ -485,6 +713,105 @@ fn compare_snapshot_marks_binary_branch_to_worktree_diffs() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn compare_snapshot_renders_small_binaries_as_hex_dump_diffs() -> Result<()> {
+    let fixture = TempGitRepo::new()?;
+    fixture.write_binary_file("asset.bin", b"\0base")?;
+    fixture.commit_all("initial")?;
+    let worktree = create_managed_worktree(
+        fixture.root(),
+        &CreateWorktreeRequest {
+            branch_name: "feature/hex".to_string(),
+            base_branch_name: None,
+        },
+    )?;
+    fs::write(worktree.root.join("asset.bin"), b"\0bass")?;
+    let left = CompareSource::Branch {
+        name: "main".to_string(),
+    };
+    let right = CompareSource::WorkspaceTarget {
+        target_id: worktree.id.clone(),
+        root: worktree.root.clone(),
+    };
+
+    let snapshot = load_compare_snapshot_with_options(
+        fixture.root(),
+        &left,
+        &right,
+        CompareOptions {
+            hex_diff_max_bytes: 1024,
+        },
+        &CancellationToken::new(),
+    )?;
+    let patch = snapshot
+        .patches_by_path
+        .get("asset.bin")
+        .context("hex dump patch should be rendered")?;
+    assert!(is_hex_dump_patch(patch), "{patch}");
+    assert!(patch.contains("-00000000  00 62 61 73 65"), "{patch}");
+    assert!(patch.contains("+00000000  00 62 61 73 73"), "{patch}");
+    assert_eq!(snapshot.overall_line_stats.added, 1);
+    assert_eq!(snapshot.overall_line_stats.removed, 1);
+
+    let snapshot = load_compare_snapshot_with_options(
+        fixture.root(),
+        &left,
+        &right,
+        CompareOptions {
+            hex_diff_max_bytes: 4,
+        },
+        &CancellationToken::new(),
+    )?;
+    assert!(
+        snapshot
+            .patches_by_path
+            .get("asset.bin")
+            .is_some_and(|patch| patch.contains("Binary files") && !is_hex_dump_patch(patch))
+    );
+    Ok(())
+}
+
+#[test]
+fn compare_snapshot_diffs_text_extracted_from_pdfs() -> Result<()> {
+    let pdf = |text: &str| {
+        let content = format!("BT /F1 12 Tf 72 712 Td ({text}) Tj ET");
+        format!(
+            "%PDF-1.4\n\0\n4 0 obj\n<< /Length {} >>\nstream\n{content}\nendstream\nendobj\n",
+            content.len()
+        )
+    };
+    let fixture = TempGitRepo::new()?;
+    fixture.write_binary_file("spec.pdf", pdf("Ship on Monday").as_bytes())?;
+    fixture.commit_all("initial")?;
+    let worktree = create_managed_worktree(
+        fixture.root(),
+        &CreateWorktreeRequest {
+            branch_name: "feature/spec".to_string(),
+            base_branch_name: None,
+        },
+    )?;
+    fs::write(worktree.root.join("spec.pdf"), pdf("Ship on Friday"))?;
+
+    let snapshot = load_compare_snapshot(
+        fixture.root(),
+        &CompareSource::Branch {
+            name: "main".to_string(),
+        },
+        &CompareSource::WorkspaceTarget {
+            target_id: worktree.id.clone(),
+            root: worktree.root.clone(),
+        },
+    )?;
+    let patch = snapshot
+        .patches_by_path
+        .get("spec.pdf")
+        .context("extracted text patch should be rendered")?;
+    assert!(is_extracted_text_patch(patch), "{patch}");
+    assert!(patch.contains("-Ship on Monday"), "{patch}");
+    assert!(patch.contains("+Ship on Friday"), "{patch}");
+    Ok(())
+}
+
 #[cfg(unix)]
 #[test]
 fn compare_snapshot_keeps_mode_only_worktree_diffs() -> Result<()> {
@@ -0,0 +1,119 @@
+/// How the editor indents and comments out lines of a language.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EditingRules {
+    /// Prefix of a line comment, such as `//` or `#`.
+    pub line_comment: Option<&'static str>,
+    /// Delimiters wrapped around each line when the language has no line comments.
+    pub block_comment: Option<(&'static str, &'static str)>,
+    /// Bracket pairs whose opening bracket indents the next line and whose closing bracket
+    /// steps back out.
+    pub brackets: &'static [(char, char)],
+    /// Other line endings that open an indented block, such as `:` in Python. Words only
+    /// match as whole words.
+    pub indent_after: &'static [&'static str],
+}
+
+const C_LIKE_BRACKETS: &[(char, char)] = &[('{', '}'), ('(', ')'), ('[', ']')];
+
+const C_LIKE: EditingRules = EditingRules {
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    brackets: C_LIKE_BRACKETS,
+    indent_after: &[],
+};
+
+const HASH_COMMENTS: EditingRules = EditingRules {
+    line_comment: Some("#"),
+    block_comment: None,
+    brackets: C_LIKE_BRACKETS,
+    indent_after: &[],
+};
+
+const MARKUP: EditingRules = EditingRules {
+    line_comment: None,
+    block_comment: Some(("<!--", "-->")),
+    brackets: &[],
+    indent_after: &[],
+};
+
+impl EditingRules {
+    /// Whether a line ending in `text` opens a block, so the next line is indented one level
+    /// deeper.
+    pub fn opens_block(&self, text: &str) -> bool {
+        let text = text.trim_end();
+        if text
+            .chars()
+            .next_back()
+            .is_some_and(|last| self.brackets.iter().any(|(open, _)| *open == last))
+        {
+            return true;
+        }
+        self.indent_after.iter().any(|ending| {
+            let Some(before) = text.strip_suffix(ending) else {
+                return false;
+            };
+            let is_word = ending.chars().all(char::is_alphanumeric);
+            !is_word
+                || before
+                    .chars()
+                    .next_back()
+                    .is_none_or(|ch| !ch.is_alphanumeric() && ch != '_')
+        })
+    }
+
+    /// The closing bracket paired with `open`.
+    pub fn closing_bracket(&self, open: char) -> Option<char> {
+        self.brackets
+            .iter()
+            .find(|(candidate, _)| *candidate == open)
+            .map(|(_, close)| *close)
+    }
+
+    pub fn is_closing_bracket(&self, ch: char) -> bool {
+        self.brackets.iter().any(|(_, close)| *close == ch)
+    }
+}
+
+pub(crate) fn editing_rules_for_scope(scope_name: &str) -> EditingRules {
+    match scope_name {
+        "rust" | "javascript" | "typescript" | "tsx" | "java" | "c" | "cpp" | "csharp" | "go"
+        | "swift" | "kotlin" => C_LIKE,
+        "json" => EditingRules {
+            line_comment: None,
+            block_comment: None,
+            ..C_LIKE
+        },
+        "css" => EditingRules {
+            line_comment: None,
+            ..C_LIKE
+        },
+        "python" => EditingRules {
+            indent_after: &[":"],
+            ..HASH_COMMENTS
+        },
+        "yaml" => EditingRules {
+            indent_after: &[":"],
+            ..HASH_COMMENTS
+        },
+        "bash" => EditingRules {
+            indent_after: &["then", "do", "else"],
+            ..HASH_COMMENTS
+        },
+        "powershell" => EditingRules {
+            block_comment: Some(("<#", "#>")),
+            ..HASH_COMMENTS
+        },
+        "terraform" | "nix" => EditingRules {
+            block_comment: Some(("/*", "*/")),
+            ..HASH_COMMENTS
+        },
+        "toml" | "dockerfile" => HASH_COMMENTS,
+        "sql" => EditingRules {
+            line_comment: Some("--"),
+            brackets: &[('(', ')')],
+            ..C_LIKE
+        },
+        "html" | "markdown" | "markdown-inline" => MARKUP,
+        _ => EditingRules::default(),
+    }
+}
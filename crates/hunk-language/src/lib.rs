@@ -1,4 +1,5 @@
 mod assets;
+mod editing;
 mod features;
 mod preview;
 mod preview_tokens;
@@ -13,6 +14,7 @@ use tree_sitter::{Language, Parser, Point, Tree};
 use tree_sitter_highlight::{Highlight, HighlightConfiguration, HighlightEvent, Highlighter};
 
 pub use assets::CANONICAL_HIGHLIGHT_NAMES;
+pub use editing::EditingRules;
 pub use features::{
     CompletionContext, CompletionItem, CompletionRequest, CompletionTriggerKind, DefinitionLink,
     DefinitionRequest, Diagnostic, DiagnosticSeverity, DocumentContext, HoverRequest, HoverResult,
@@ -140,6 +142,10 @@ impl LanguageDefinition {
         Arc::clone(&self.highlight_config)
     }
 
+    pub fn editing_rules(&self) -> EditingRules {
+        editing::editing_rules_for_scope(&self.scope_name)
+    }
+
     fn is_fold_node_kind(&self, kind: &str) -> bool {
         self.fold_node_kinds
             .iter()
@@ -97,6 +97,34 @@ fn registry_resolves_builtin_languages_by_name_and_path() {
     );
 }
 
+#[test]
+fn builtin_languages_carry_comment_and_indent_rules() {
+    let registry = LanguageRegistry::builtin();
+    let rules_for = |path: &str| {
+        registry
+            .language_for_path(Path::new(path))
+            .expect("language for path")
+            .editing_rules()
+    };
+
+    let rust = rules_for("/tmp/main.rs");
+    assert_eq!(rust.line_comment, Some("//"));
+    assert!(rust.opens_block("fn main() {"));
+    assert!(rust.is_closing_bracket('}'));
+
+    let python = rules_for("/tmp/app.py");
+    assert_eq!(python.line_comment, Some("#"));
+    assert!(python.opens_block("def run():"));
+
+    let bash = rules_for("/tmp/build.sh");
+    assert!(bash.opens_block("if true; then"));
+    assert!(!bash.opens_block("echo undo"));
+
+    let json = rules_for("/tmp/package.json");
+    assert_eq!(json.line_comment, None);
+    assert_eq!(json.block_comment, None);
+}
+
 #[test]
 fn style_map_prefers_most_specific_capture_name() {
     let map = HighlightStyleMap::default();
@@ -0,0 +1,120 @@
+use hunk_domain::db::{CommentLineSide, compute_comment_anchor_hash};
+use hunk_domain::diff::{DiffCellKind, DiffRowKind, SideBySideRow};
+
+// Matches the desktop review surface so comments created here re-anchor the same way.
+const COMMENT_CONTEXT_RADIUS_ROWS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CommentAnchor {
+    pub(crate) line_side: CommentLineSide,
+    pub(crate) old_line: Option<u32>,
+    pub(crate) new_line: Option<u32>,
+    pub(crate) hunk_header: Option<String>,
+    pub(crate) line_text: String,
+    pub(crate) context_before: String,
+    pub(crate) context_after: String,
+    pub(crate) anchor_hash: String,
+}
+
+pub(crate) fn anchor_for_line(
+    file_path: &str,
+    rows: &[SideBySideRow],
+    side: CommentLineSide,
+    line: u32,
+) -> Option<CommentAnchor> {
+    let mut hunk_header = None;
+    let mut target = None;
+    for (row_ix, row) in rows.iter().enumerate() {
+        if row.kind == DiffRowKind::HunkHeader {
+            hunk_header = Some(row.text.clone());
+            continue;
+        }
+        if row.kind != DiffRowKind::Code {
+            continue;
+        }
+        let cell = match side {
+            CommentLineSide::Left => &row.left,
+            CommentLineSide::Right => &row.right,
+            CommentLineSide::Meta => return None,
+        };
+        if cell.kind != DiffCellKind::None && cell.line == Some(line) {
+            target = Some((row_ix, hunk_header.clone()));
+            break;
+        }
+    }
+    let (row_ix, hunk_header) = target?;
+    let row = &rows[row_ix];
+
+    let line_side = if row.right.kind != DiffCellKind::None {
+        CommentLineSide::Right
+    } else {
+        CommentLineSide::Left
+    };
+    let line_text = row_diff_lines(row).join("\n");
+    let context_before = collect_context(
+        rows,
+        row_ix.saturating_sub(COMMENT_CONTEXT_RADIUS_ROWS)..row_ix,
+    );
+    let after_start = row_ix.saturating_add(1);
+    let context_after = collect_context(
+        rows,
+        after_start
+            ..after_start
+                .saturating_add(COMMENT_CONTEXT_RADIUS_ROWS)
+                .min(rows.len()),
+    );
+    let anchor_hash = compute_comment_anchor_hash(
+        file_path,
+        hunk_header.as_deref(),
+        line_text.as_str(),
+        context_before.as_str(),
+        context_after.as_str(),
+    );
+
+    Some(CommentAnchor {
+        line_side,
+        old_line: row.left.line,
+        new_line: row.right.line,
+        hunk_header,
+        line_text,
+        context_before,
+        context_after,
+        anchor_hash,
+    })
+}
+
+fn collect_context(rows: &[SideBySideRow], range: std::ops::Range<usize>) -> String {
+    rows[range]
+        .iter()
+        .flat_map(row_diff_lines)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn row_diff_lines(row: &SideBySideRow) -> Vec<String> {
+    let mut lines = Vec::new();
+    match row.kind {
+        DiffRowKind::Code => {
+            if row.left.kind == DiffCellKind::Removed {
+                lines.push(format!("-{}", row.left.text));
+            }
+            if row.right.kind == DiffCellKind::Added {
+                lines.push(format!("+{}", row.right.text));
+            }
+            if row.left.kind == DiffCellKind::Context {
+                lines.push(format!(" {}", row.left.text));
+            }
+            if row.left.kind == DiffCellKind::None
+                && row.right.kind == DiffCellKind::None
+                && !row.text.is_empty()
+            {
+                lines.push(row.text.clone());
+            }
+        }
+        DiffRowKind::HunkHeader => {}
+        DiffRowKind::Meta | DiffRowKind::Empty => {
+            lines.push(row.text.clone());
+        }
+    }
+    lines
+}
@@ -0,0 +1,678 @@
+use std::collections::BTreeMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher as _, Hasher as _};
+use std::io::{BufRead, BufReader, ErrorKind, Read as _, Write};
+use std::net::{
+    IpAddr, Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context as _, Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a blocking read waits before the reader re-checks its deadlines.
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// A peer that cannot take a message within this long is treated as gone.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Connections must send their hello within this long or they are closed.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
+/// Messages queued for one guest before it counts as too slow and is dropped.
+const OUTBOX_CAPACITY: usize = 1024;
+const MAX_MESSAGE_BYTES: usize = 256 * 1024;
+/// Wrong join codes accepted before the session stops admitting guests.
+const MAX_FAILED_JOINS: usize = 10;
+const JOIN_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const JOIN_CODE_LEN: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Participant {
+    pub id: String,
+    pub name: String,
+}
+
+impl Participant {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: format!("{:016x}", random_u64()),
+            name: name.into(),
+        }
+    }
+}
+
+/// Where a participant is looking: the file and the row offset from that file's first row.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Presence {
+    pub path: Option<String>,
+    pub row_offset: Option<usize>,
+}
+
+/// A review comment as it travels between instances. Each side stores its own copy under its
+/// local repository scope, so only the anchor and text are shared.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedComment {
+    pub origin_id: String,
+    pub file_path: String,
+    pub line_side: String,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    pub hunk_header: Option<String>,
+    pub line_text: String,
+    pub context_before: String,
+    pub context_after: String,
+    pub anchor_hash: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoReviewMessage {
+    Hello {
+        participant: Participant,
+        join_code: String,
+    },
+    Presence {
+        participant_id: String,
+        presence: Presence,
+    },
+    CommentAdded {
+        participant_id: String,
+        comment: SharedComment,
+    },
+    Leave {
+        participant_id: String,
+    },
+    Rejected {
+        reason: String,
+    },
+}
+
+impl CoReviewMessage {
+    fn participant_id(&self) -> Option<&str> {
+        match self {
+            Self::Hello { participant, .. } => Some(participant.id.as_str()),
+            Self::Presence { participant_id, .. }
+            | Self::CommentAdded { participant_id, .. }
+            | Self::Leave { participant_id } => Some(participant_id.as_str()),
+            Self::Rejected { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum CoReviewEvent {
+    Message(CoReviewMessage),
+    Disconnected(String),
+}
+
+/// One side of a live co-review session.
+///
+/// The host runs a small relay over TCP that forwards every message to the other
+/// participants and replays current presence to late joiners. Guests hold a single
+/// connection to the host. Dropping the session announces the departure and closes it.
+pub struct CoReviewSession {
+    participant: Participant,
+    join_code: String,
+    local_addr: Option<SocketAddr>,
+    link: Link,
+    events: mpsc::Receiver<CoReviewEvent>,
+}
+
+enum Link {
+    Host {
+        hub: mpsc::Sender<HubInput>,
+        shutdown: Arc<AtomicBool>,
+    },
+    Guest {
+        stream: Mutex<TcpStream>,
+    },
+}
+
+impl CoReviewSession {
+    pub fn host(bind_addr: SocketAddr, participant: Participant) -> Result<Self> {
+        let listener = TcpListener::bind(bind_addr)
+            .with_context(|| format!("failed to listen for co-review on {bind_addr}"))?;
+        let local_addr = listener
+            .local_addr()
+            .context("failed to resolve co-review listener address")?;
+        listener
+            .set_nonblocking(true)
+            .context("failed to configure co-review listener")?;
+
+        let join_code = new_join_code();
+        let (event_tx, event_rx) = mpsc::channel();
+        let (hub_tx, hub_rx) = mpsc::channel();
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let hub = Hub::new(join_code.clone(), event_tx);
+        std::thread::Builder::new()
+            .name("hunk-co-review-hub".to_string())
+            .spawn(move || hub.run(hub_rx))
+            .context("failed to start co-review relay")?;
+        let accept_hub = hub_tx.clone();
+        let accept_shutdown = shutdown.clone();
+        std::thread::Builder::new()
+            .name("hunk-co-review-accept".to_string())
+            .spawn(move || accept_connections(listener, accept_hub, accept_shutdown))
+            .context("failed to start co-review listener")?;
+
+        let session = Self {
+            participant: participant.clone(),
+            join_code: join_code.clone(),
+            local_addr: Some(local_addr),
+            link: Link::Host {
+                hub: hub_tx,
+                shutdown,
+            },
+            events: event_rx,
+        };
+        session.send(CoReviewMessage::Hello {
+            participant,
+            join_code,
+        })?;
+        Ok(session)
+    }
+
+    pub fn join(address: &str, join_code: &str, participant: Participant) -> Result<Self> {
+        let socket_addr = address
+            .to_socket_addrs()
+            .with_context(|| format!("invalid co-review address '{address}'"))?
+            .next()
+            .ok_or_else(|| anyhow!("co-review address '{address}' did not resolve"))?;
+        let stream = TcpStream::connect_timeout(&socket_addr, CONNECT_TIMEOUT)
+            .with_context(|| format!("failed to connect to co-review host {socket_addr}"))?;
+        stream
+            .set_write_timeout(Some(WRITE_TIMEOUT))
+            .context("failed to configure co-review connection")?;
+        let reader = stream
+            .try_clone()
+            .and_then(MessageReader::new)
+            .context("failed to open co-review connection reader")?;
+
+        let (event_tx, event_rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("hunk-co-review-guest".to_string())
+            .spawn(move || read_guest_messages(reader, event_tx))
+            .context("failed to start co-review connection reader")?;
+
+        let session = Self {
+            participant: participant.clone(),
+            join_code: join_code.trim().to_ascii_uppercase(),
+            local_addr: None,
+            link: Link::Guest {
+                stream: Mutex::new(stream),
+            },
+            events: event_rx,
+        };
+        session.send(CoReviewMessage::Hello {
+            participant,
+            join_code: session.join_code.clone(),
+        })?;
+        Ok(session)
+    }
+
+    pub fn participant(&self) -> &Participant {
+        &self.participant
+    }
+
+    pub fn is_host(&self) -> bool {
+        matches!(self.link, Link::Host { .. })
+    }
+
+    pub fn join_code(&self) -> &str {
+        self.join_code.as_str()
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    pub fn send(&self, message: CoReviewMessage) -> Result<()> {
+        match &self.link {
+            Link::Host { hub, .. } => hub
+                .send(HubInput::Local(message))
+                .map_err(|_| anyhow!("co-review relay has stopped")),
+            Link::Guest { stream } => {
+                let mut stream = stream
+                    .lock()
+                    .map_err(|_| anyhow!("co-review connection is poisoned"))?;
+                write_message(&mut stream, &message)
+            }
+        }
+    }
+
+    pub fn send_presence(&self, presence: Presence) -> Result<()> {
+        self.send(CoReviewMessage::Presence {
+            participant_id: self.participant.id.clone(),
+            presence,
+        })
+    }
+
+    pub fn share_comment(&self, comment: SharedComment) -> Result<()> {
+        self.send(CoReviewMessage::CommentAdded {
+            participant_id: self.participant.id.clone(),
+            comment,
+        })
+    }
+
+    pub fn try_recv(&self) -> Option<CoReviewEvent> {
+        self.events.try_recv().ok()
+    }
+}
+
+impl Drop for CoReviewSession {
+    fn drop(&mut self) {
+        let _ = self.send(CoReviewMessage::Leave {
+            participant_id: self.participant.id.clone(),
+        });
+        match &self.link {
+            Link::Host { hub, shutdown } => {
+                shutdown.store(true, Ordering::SeqCst);
+                let _ = hub.send(HubInput::Shutdown);
+            }
+            Link::Guest { stream } => {
+                if let Ok(stream) = stream.lock() {
+                    let _ = stream.shutdown(Shutdown::Both);
+                }
+            }
+        }
+    }
+}
+
+/// Formats the address and join code a guest pastes to join, e.g. `192.168.1.20:47321#K7M2QP`.
+pub fn format_join_target(addr: SocketAddr, join_code: &str) -> String {
+    format!("{addr}#{join_code}")
+}
+
+/// Splits `host:port#CODE` (or `host:port CODE`) into the address and join code.
+pub fn parse_join_target(input: &str) -> Result<(String, String)> {
+    let input = input.trim();
+    let (address, join_code) = input
+        .split_once('#')
+        .or_else(|| input.split_once(char::is_whitespace))
+        .ok_or_else(|| {
+            anyhow!("enter the host address and join code, like 192.168.1.20:47321#CODE")
+        })?;
+    let (address, join_code) = (address.trim(), join_code.trim());
+    if address.is_empty() || join_code.is_empty() {
+        return Err(anyhow!(
+            "enter the host address and join code, like 192.168.1.20:47321#CODE"
+        ));
+    }
+    Ok((address.to_string(), join_code.to_ascii_uppercase()))
+}
+
+/// Best-effort LAN address for sharing with guests; falls back to loopback when offline.
+pub fn lan_address(port: u16) -> SocketAddr {
+    // Connecting a UDP socket only selects a route; no packets are sent.
+    let ip = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .and_then(|socket| {
+            socket.connect((Ipv4Addr::new(192, 0, 2, 1), 9))?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+    SocketAddr::new(ip, port)
+}
+
+enum HubInput {
+    Connected {
+        conn_id: usize,
+        outbox: mpsc::SyncSender<CoReviewMessage>,
+    },
+    Received {
+        conn_id: usize,
+        message: CoReviewMessage,
+    },
+    Local(CoReviewMessage),
+    Disconnected {
+        conn_id: usize,
+    },
+    Shutdown,
+}
+
+// Each connection has its own writer thread, so a guest that stops reading only fills its
+// own queue instead of stalling the hub. Dropping the outbox flushes it and closes the socket.
+struct HubConnection {
+    outbox: mpsc::SyncSender<CoReviewMessage>,
+    participant_id: Option<String>,
+}
+
+struct Hub {
+    join_code: String,
+    events: mpsc::Sender<CoReviewEvent>,
+    connections: BTreeMap<usize, HubConnection>,
+    hellos: BTreeMap<String, CoReviewMessage>,
+    presences: BTreeMap<String, CoReviewMessage>,
+    failed_joins: usize,
+}
+
+impl Hub {
+    fn new(join_code: String, events: mpsc::Sender<CoReviewEvent>) -> Self {
+        Self {
+            join_code,
+            events,
+            connections: BTreeMap::new(),
+            hellos: BTreeMap::new(),
+            presences: BTreeMap::new(),
+            failed_joins: 0,
+        }
+    }
+
+    fn run(mut self, inputs: mpsc::Receiver<HubInput>) {
+        while let Ok(input) = inputs.recv() {
+            match input {
+                HubInput::Connected { conn_id, outbox } => {
+                    self.connections.insert(
+                        conn_id,
+                        HubConnection {
+                            outbox,
+                            participant_id: None,
+                        },
+                    );
+                }
+                HubInput::Received { conn_id, message } => self.receive(conn_id, message),
+                HubInput::Local(message) => {
+                    let message = self.record(message);
+                    self.broadcast(&message, None);
+                }
+                HubInput::Disconnected { conn_id } => self.disconnect(conn_id),
+                HubInput::Shutdown => break,
+            }
+        }
+    }
+
+    fn receive(&mut self, conn_id: usize, message: CoReviewMessage) {
+        let Some(connection) = self.connections.get_mut(&conn_id) else {
+            return;
+        };
+        match connection.participant_id.as_deref() {
+            None => {
+                let CoReviewMessage::Hello { join_code, .. } = &message else {
+                    self.disconnect(conn_id);
+                    return;
+                };
+                if self.failed_joins >= MAX_FAILED_JOINS {
+                    self.reject(
+                        conn_id,
+                        "This session stopped accepting guests after too many wrong join codes.",
+                    );
+                    return;
+                }
+                if !join_code.eq_ignore_ascii_case(self.join_code.as_str()) {
+                    self.failed_joins += 1;
+                    self.reject(conn_id, "The join code does not match this session.");
+                    return;
+                }
+                connection.participant_id = message.participant_id().map(ToString::to_string);
+                self.replay_to(conn_id);
+            }
+            Some(participant_id) if message.participant_id() != Some(participant_id) => return,
+            Some(_) => {}
+        }
+
+        let message = self.record(message);
+        let _ = self.events.send(CoReviewEvent::Message(message.clone()));
+        self.broadcast(&message, Some(conn_id));
+    }
+
+    fn reject(&mut self, conn_id: usize, reason: &str) {
+        if let Some(connection) = self.connections.remove(&conn_id) {
+            let _ = connection.outbox.try_send(CoReviewMessage::Rejected {
+                reason: reason.to_string(),
+            });
+        }
+    }
+
+    fn disconnect(&mut self, conn_id: usize) {
+        let Some(connection) = self.connections.remove(&conn_id) else {
+            return;
+        };
+        let Some(participant_id) = connection.participant_id else {
+            return;
+        };
+        if !self.hellos.contains_key(participant_id.as_str()) {
+            return;
+        }
+        let message = self.record(CoReviewMessage::Leave { participant_id });
+        let _ = self.events.send(CoReviewEvent::Message(message.clone()));
+        self.broadcast(&message, None);
+    }
+
+    // Keeps the latest hello and presence per participant so late joiners see the room.
+    fn record(&mut self, message: CoReviewMessage) -> CoReviewMessage {
+        let message = match message {
+            CoReviewMessage::Hello { participant, .. } => CoReviewMessage::Hello {
+                participant,
+                join_code: String::new(),
+            },
+            other => other,
+        };
+        match &message {
+            CoReviewMessage::Hello { participant, .. } => {
+                self.hellos.insert(participant.id.clone(), message.clone());
+            }
+            CoReviewMessage::Presence { participant_id, .. } => {
+                self.presences
+                    .insert(participant_id.clone(), message.clone());
+            }
+            CoReviewMessage::Leave { participant_id } => {
+                self.hellos.remove(participant_id.as_str());
+                self.presences.remove(participant_id.as_str());
+            }
+            CoReviewMessage::CommentAdded { .. } | CoReviewMessage::Rejected { .. } => {}
+        }
+        message
+    }
+
+    fn replay_to(&mut self, conn_id: usize) {
+        let messages = self
+            .hellos
+            .values()
+            .chain(self.presences.values())
+            .cloned()
+            .collect::<Vec<_>>();
+        let Some(connection) = self.connections.get(&conn_id) else {
+            return;
+        };
+        let delivered = messages
+            .into_iter()
+            .all(|message| connection.outbox.try_send(message).is_ok());
+        if !delivered {
+            self.disconnect(conn_id);
+        }
+    }
+
+    fn broadcast(&mut self, message: &CoReviewMessage, except: Option<usize>) {
+        let mut failed = Vec::new();
+        for (conn_id, connection) in &self.connections {
+            if Some(*conn_id) == except || connection.participant_id.is_none() {
+                continue;
+            }
+            if connection.outbox.try_send(message.clone()).is_err() {
+                failed.push(*conn_id);
+            }
+        }
+        for conn_id in failed {
+            self.disconnect(conn_id);
+        }
+    }
+}
+
+fn accept_connections(
+    listener: TcpListener,
+    hub: mpsc::Sender<HubInput>,
+    shutdown: Arc<AtomicBool>,
+) {
+    let mut next_conn_id = 0_usize;
+    while !shutdown.load(Ordering::SeqCst) {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(err) if err.kind() == ErrorKind::WouldBlock => {
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                continue;
+            }
+            Err(_) => {
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                continue;
+            }
+        };
+        let reader = match stream
+            .set_nonblocking(false)
+            .and_then(|()| stream.set_write_timeout(Some(WRITE_TIMEOUT)))
+            .and_then(|()| stream.try_clone())
+            .and_then(MessageReader::new)
+        {
+            Ok(reader) => reader,
+            Err(_) => continue,
+        };
+
+        next_conn_id = next_conn_id.saturating_add(1);
+        let conn_id = next_conn_id;
+        let (outbox, queued) = mpsc::sync_channel(OUTBOX_CAPACITY);
+        if hub.send(HubInput::Connected { conn_id, outbox }).is_err() {
+            return;
+        }
+        let _ = std::thread::Builder::new()
+            .name("hunk-co-review-peer-writer".to_string())
+            .spawn(move || write_queued_messages(stream, queued));
+        let reader_hub = hub.clone();
+        let _ = std::thread::Builder::new()
+            .name("hunk-co-review-peer".to_string())
+            .spawn(move || read_peer_messages(reader, conn_id, reader_hub));
+    }
+}
+
+fn read_peer_messages(mut reader: MessageReader, conn_id: usize, hub: mpsc::Sender<HubInput>) {
+    let hello_deadline = Instant::now() + HELLO_TIMEOUT;
+    let mut greeted = false;
+    loop {
+        match reader.next_message() {
+            ReadOutcome::Message(message) => {
+                greeted = true;
+                let message = *message;
+                if hub.send(HubInput::Received { conn_id, message }).is_err() {
+                    return;
+                }
+            }
+            ReadOutcome::Idle if greeted || Instant::now() < hello_deadline => {}
+            ReadOutcome::Idle | ReadOutcome::Closed => break,
+        }
+    }
+    let _ = hub.send(HubInput::Disconnected { conn_id });
+}
+
+fn write_queued_messages(mut stream: TcpStream, queued: mpsc::Receiver<CoReviewMessage>) {
+    for message in queued {
+        if write_message(&mut stream, &message).is_err() {
+            break;
+        }
+    }
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+fn read_guest_messages(mut reader: MessageReader, events: mpsc::Sender<CoReviewEvent>) {
+    let mut reason = "The host ended the session.".to_string();
+    loop {
+        let message = match reader.next_message() {
+            ReadOutcome::Message(message) => *message,
+            ReadOutcome::Idle => continue,
+            ReadOutcome::Closed => break,
+        };
+        if let CoReviewMessage::Rejected { reason: rejected } = &message {
+            reason = rejected.clone();
+            continue;
+        }
+        if events.send(CoReviewEvent::Message(message)).is_err() {
+            return;
+        }
+    }
+    let _ = events.send(CoReviewEvent::Disconnected(reason));
+}
+
+enum ReadOutcome {
+    Message(Box<CoReviewMessage>),
+    /// No complete message arrived within [`READ_POLL_INTERVAL`].
+    Idle,
+    Closed,
+}
+
+/// Reads newline-delimited messages with a read timeout, so callers can enforce deadlines
+/// between reads. Partial lines survive a timeout; oversized lines close the connection.
+struct MessageReader {
+    reader: BufReader<TcpStream>,
+    line: Vec<u8>,
+}
+
+impl MessageReader {
+    fn new(stream: TcpStream) -> std::io::Result<Self> {
+        stream.set_read_timeout(Some(READ_POLL_INTERVAL))?;
+        Ok(Self {
+            reader: BufReader::new(stream),
+            line: Vec::new(),
+        })
+    }
+
+    fn next_message(&mut self) -> ReadOutcome {
+        loop {
+            let limit = (MAX_MESSAGE_BYTES + 1 - self.line.len()) as u64;
+            match (&mut self.reader)
+                .take(limit)
+                .read_until(b'\n', &mut self.line)
+            {
+                Ok(0) => return ReadOutcome::Closed,
+                Ok(_) if self.line.last() != Some(&b'\n') => {
+                    // Either the peer closed mid-line or the line is over the size limit.
+                    return ReadOutcome::Closed;
+                }
+                Ok(_) => {
+                    let line = std::mem::take(&mut self.line);
+                    if let Ok(message) = serde_json::from_slice::<CoReviewMessage>(&line) {
+                        return ReadOutcome::Message(Box::new(message));
+                    }
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    return ReadOutcome::Idle;
+                }
+                Err(_) => return ReadOutcome::Closed,
+            }
+        }
+    }
+}
+
+fn write_message(stream: &mut TcpStream, message: &CoReviewMessage) -> Result<()> {
+    let mut line = serde_json::to_string(message).context("failed to encode co-review message")?;
+    line.push('\n');
+    stream
+        .write_all(line.as_bytes())
+        .context("failed to send co-review message")
+}
+
+fn new_join_code() -> String {
+    let mut value = random_u64();
+    (0..JOIN_CODE_LEN)
+        .map(|_| {
+            let ix = (value % JOIN_CODE_ALPHABET.len() as u64) as usize;
+            value /= JOIN_CODE_ALPHABET.len() as u64;
+            char::from(JOIN_CODE_ALPHABET[ix])
+        })
+        .collect()
+}
+
+// std seeds `RandomState` randomly per instance, which is enough for join codes and ids.
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    hasher.write_u32(std::process::id());
+    hasher.finish()
+}
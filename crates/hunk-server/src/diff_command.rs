@@ -0,0 +1,390 @@
+//! `hunk diff [path]` prints the review diff to the terminal without opening a window.
+//!
+//! Rows come from the same file loader and hazard banner the review workspace uses, so the
+//! terminal output pairs lines, skips binary files and flags hidden characters like the app does.
+
+use std::collections::BTreeMap;
+use std::io::{IsTerminal as _, Write as _};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, bail};
+use hunk_domain::diff::{DiffCell, DiffCellKind, DiffRowKind, SideBySideRow};
+use hunk_domain::unicode_hazards::unicode_hazard_banner;
+use hunk_git::api::{ChangedFile, load_file_diff_rows, load_patches_for_files, load_snapshot};
+
+pub const USAGE: &str = "usage: hunk diff [--unified | --side-by-side] [--color=auto|always|never] \
+                         [--width <columns>] [path]";
+pub const DEFAULT_WIDTH: usize = 160;
+pub const MIN_WIDTH: usize = 40;
+const TAB_WIDTH: usize = 4;
+const COLUMN_SEPARATOR: &str = " \u{2502} ";
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_CYAN: &str = "\x1b[36m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    SideBySide,
+    Unified,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffArgs {
+    pub path: PathBuf,
+    pub layout: DiffLayout,
+    /// `None` colors the output only when stdout is a terminal.
+    pub color: Option<bool>,
+    pub width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffStyle {
+    pub layout: DiffLayout,
+    pub color: bool,
+    pub width: usize,
+}
+
+/// One file's part of the output: its rows, or the warning shown instead of them.
+struct FileOutput<'a> {
+    file: &'a ChangedFile,
+    warnings: Vec<String>,
+    rows: Vec<SideBySideRow>,
+}
+
+pub fn run_diff(args: &[String]) -> Result<()> {
+    let Some(args) = parse_diff_args(args)? else {
+        println!("{USAGE}");
+        return Ok(());
+    };
+    let stdout = std::io::stdout();
+    let style = DiffStyle {
+        layout: args.layout,
+        color: args
+            .color
+            .unwrap_or_else(|| stdout.is_terminal() && std::env::var_os("NO_COLOR").is_none()),
+        width: args
+            .width
+            .or_else(terminal_width)
+            .unwrap_or(DEFAULT_WIDTH)
+            .max(MIN_WIDTH),
+    };
+    let output = render_path_diff(args.path.as_path(), style)?;
+
+    let mut stdout = stdout.lock();
+    match stdout
+        .write_all(output.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        // Piping into `head` closes stdout early, which is not an error for a diff.
+        Err(error) if error.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+        result => result.context("write diff to stdout"),
+    }
+}
+
+/// Parses the arguments after `diff`. Returns `None` when help was requested.
+pub fn parse_diff_args(args: &[String]) -> Result<Option<DiffArgs>> {
+    let mut parsed = DiffArgs {
+        path: PathBuf::from("."),
+        layout: DiffLayout::SideBySide,
+        color: None,
+        width: None,
+    };
+    let mut path_given = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') {
+            if path_given {
+                bail!("hunk diff takes at most one path\n{USAGE}");
+            }
+            parsed.path = PathBuf::from(arg);
+            path_given = true;
+            continue;
+        }
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-u" | "--unified" => parsed.layout = DiffLayout::Unified,
+            "--side-by-side" => parsed.layout = DiffLayout::SideBySide,
+            "--color" | "--color=always" => parsed.color = Some(true),
+            "--color=never" | "--no-color" => parsed.color = Some(false),
+            "--color=auto" => parsed.color = None,
+            "--width" => {
+                let value = args
+                    .next()
+                    .with_context(|| format!("--width needs a column count\n{USAGE}"))?;
+                parsed.width = Some(parse_width(value)?);
+            }
+            flag => match flag.strip_prefix("--width=") {
+                Some(value) => parsed.width = Some(parse_width(value)?),
+                None => bail!("unknown option {flag}\n{USAGE}"),
+            },
+        }
+    }
+    Ok(Some(parsed))
+}
+
+fn parse_width(value: &str) -> Result<usize> {
+    value
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|width| *width > 0)
+        .with_context(|| format!("invalid --width {value:?}, expected a column count"))
+}
+
+fn terminal_width() -> Option<usize> {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse::<usize>().ok())
+        .filter(|width| *width > 0)
+}
+
+/// Diffs the working copy of the repository containing `path` against `HEAD`, limited to the
+/// changed files under `path`.
+fn render_path_diff(path: &Path, style: DiffStyle) -> Result<String> {
+    let path = path
+        .canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .with_context(|| format!("resolve path {}", path.display()))?;
+    let search_dir = if path.is_dir() {
+        path.as_path()
+    } else {
+        path.parent().unwrap_or(path.as_path())
+    };
+    let snapshot = load_snapshot(search_dir)
+        .with_context(|| format!("load repository at {}", search_dir.display()))?;
+    let root = snapshot
+        .root
+        .canonicalize()
+        .unwrap_or_else(|_| snapshot.root.clone());
+    let scope = path.strip_prefix(root.as_path()).unwrap_or(Path::new(""));
+
+    let mut files = snapshot.files;
+    files.retain(|file| Path::new(file.path.as_str()).starts_with(scope));
+    let patches = load_patches_for_files(snapshot.root.as_path(), &files)?;
+    Ok(format_file_diffs(&files, &patches, style))
+}
+
+/// Formats `files` with their patches from `patches_by_path`, in order.
+pub fn format_file_diffs(
+    files: &[ChangedFile],
+    patches_by_path: &BTreeMap<String, String>,
+    style: DiffStyle,
+) -> String {
+    let outputs = files
+        .iter()
+        .map(|file| load_file_output(file, patches_by_path))
+        .collect::<Vec<_>>();
+    if outputs.is_empty() {
+        let mut out = String::new();
+        push_styled(&mut out, style.color, ANSI_DIM, "No changed files.");
+        out.push('\n');
+        return out;
+    }
+
+    let number_width = outputs
+        .iter()
+        .flat_map(|output| &output.rows)
+        .flat_map(|row| [row.left.line, row.right.line])
+        .flatten()
+        .max()
+        .unwrap_or(0)
+        .to_string()
+        .len();
+    let mut out = String::new();
+    for output in &outputs {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        push_file_output(&mut out, output, style, number_width);
+    }
+    out
+}
+
+fn load_file_output<'a>(
+    file: &'a ChangedFile,
+    patches_by_path: &BTreeMap<String, String>,
+) -> FileOutput<'a> {
+    let patch = patches_by_path
+        .get(file.path.as_str())
+        .map(String::as_str)
+        .unwrap_or_default();
+    let loaded = load_file_diff_rows(file.path.as_str(), patch);
+    if let Some(load_error) = loaded.load_error {
+        return FileOutput {
+            file,
+            warnings: vec![load_error],
+            rows: Vec::new(),
+        };
+    }
+    FileOutput {
+        file,
+        warnings: unicode_hazard_banner(&loaded.core_rows)
+            .into_iter()
+            .collect(),
+        rows: loaded
+            .core_rows
+            .into_iter()
+            .filter(|row| {
+                matches!(
+                    row.kind,
+                    DiffRowKind::Code | DiffRowKind::HunkHeader | DiffRowKind::Empty
+                )
+            })
+            .collect(),
+    }
+}
+
+fn push_file_output(out: &mut String, output: &FileOutput, style: DiffStyle, number_width: usize) {
+    push_styled(
+        out,
+        style.color,
+        ANSI_BOLD,
+        &format!("{} {}", output.file.status.tag(), output.file.path),
+    );
+    out.push('\n');
+    for warning in &output.warnings {
+        push_styled(out, style.color, ANSI_YELLOW, warning.as_str());
+        out.push('\n');
+    }
+
+    // Unified output lists a change block's removed lines before its added ones, while the
+    // rows pair them up.
+    let mut pending_added = Vec::new();
+    for row in &output.rows {
+        let is_change_row = row.kind == DiffRowKind::Code
+            && (row.left.kind != DiffCellKind::Context || row.right.kind != DiffCellKind::Context);
+        if !is_change_row {
+            out.extend(pending_added.drain(..));
+        }
+
+        match row.kind {
+            DiffRowKind::HunkHeader => {
+                push_styled(out, style.color, ANSI_CYAN, row.text.as_str());
+                out.push('\n');
+            }
+            DiffRowKind::Code => match style.layout {
+                DiffLayout::SideBySide => {
+                    let column = style.width.saturating_sub(COLUMN_SEPARATOR.chars().count()) / 2;
+                    push_side_by_side_cell(out, style, &row.left, number_width, column, true);
+                    out.push_str(COLUMN_SEPARATOR);
+                    push_side_by_side_cell(out, style, &row.right, number_width, column, false);
+                    out.push('\n');
+                }
+                DiffLayout::Unified if !is_change_row => {
+                    let line = format!(
+                        "{:>number_width$} {:>number_width$}  {}\n",
+                        line_number(row.left.line),
+                        line_number(row.right.line),
+                        expand_tabs(row.left.text.as_str())
+                    );
+                    out.push_str(line.as_str());
+                }
+                DiffLayout::Unified => {
+                    if row.left.kind == DiffCellKind::Removed {
+                        let line = format!(
+                            "{:>number_width$} {:number_width$} -{}",
+                            line_number(row.left.line),
+                            "",
+                            expand_tabs(row.left.text.as_str())
+                        );
+                        push_styled(out, style.color, ANSI_RED, line.as_str());
+                        out.push('\n');
+                    }
+                    if row.right.kind == DiffCellKind::Added {
+                        let line = format!(
+                            "{:number_width$} {:>number_width$} +{}",
+                            "",
+                            line_number(row.right.line),
+                            expand_tabs(row.right.text.as_str())
+                        );
+                        let mut styled = String::new();
+                        push_styled(&mut styled, style.color, ANSI_GREEN, line.as_str());
+                        styled.push('\n');
+                        pending_added.push(styled);
+                    }
+                }
+            },
+            DiffRowKind::Meta | DiffRowKind::Empty => {
+                if !row.text.is_empty() {
+                    push_styled(out, style.color, ANSI_DIM, row.text.as_str());
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out.extend(pending_added);
+}
+
+/// Writes one side of a side-by-side row, truncated or padded to exactly `column` characters.
+fn push_side_by_side_cell(
+    out: &mut String,
+    style: DiffStyle,
+    cell: &DiffCell,
+    number_width: usize,
+    column: usize,
+    pad: bool,
+) {
+    let (marker, color) = match cell.kind {
+        DiffCellKind::None => {
+            if pad {
+                out.extend(std::iter::repeat_n(' ', column));
+            }
+            return;
+        }
+        DiffCellKind::Context => (' ', None),
+        DiffCellKind::Added => ('+', Some(ANSI_GREEN)),
+        DiffCellKind::Removed => ('-', Some(ANSI_RED)),
+    };
+    let text = format!(
+        "{:>number_width$} {marker}{}",
+        line_number(cell.line),
+        expand_tabs(cell.text.as_str())
+    );
+    let mut text = fit_to_width(text.as_str(), column);
+    if !pad {
+        text.truncate(text.trim_end().len());
+    }
+    match color {
+        Some(color) => push_styled(out, style.color, color, text.as_str()),
+        None => out.push_str(text.as_str()),
+    }
+}
+
+fn fit_to_width(text: &str, width: usize) -> String {
+    let length = text.chars().count();
+    if length <= width {
+        let mut fitted = text.to_string();
+        fitted.extend(std::iter::repeat_n(' ', width - length));
+        return fitted;
+    }
+    let mut fitted = text
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    fitted.push('\u{2026}');
+    fitted
+}
+
+fn push_styled(out: &mut String, color: bool, code: &str, text: &str) {
+    if color {
+        out.push_str(code);
+        out.push_str(text);
+        out.push_str(ANSI_RESET);
+    } else {
+        out.push_str(text);
+    }
+}
+
+fn line_number(line: Option<u32>) -> String {
+    line.map(|line| line.to_string()).unwrap_or_default()
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', " ".repeat(TAB_WIDTH).as_str())
+}
@@ -0,0 +1,14 @@
+//! Headless JSON-RPC access to Hunk's snapshot, diff, and review comment operations.
+//!
+//! Editors and agents talk to [`server::Server`] over newline-delimited JSON-RPC 2.0, either on
+//! stdio or on a local socket. Comments are written to the same SQLite database the desktop app
+//! uses, so they show up in an open review without a restart. [`co_review`] carries the live
+//! session sharing the desktop app uses for pair review. [`diff_command`] backs `hunk diff`,
+//! which prints the same diff to a terminal.
+
+mod anchor;
+pub mod co_review;
+pub mod diff_command;
+pub mod protocol;
+pub mod server;
+pub mod transport;
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use anyhow::{Context as _, Result, bail};
+use hunk_domain::db::DatabaseStore;
+use hunk_domain::paths::hunk_home_dir;
+use hunk_server::diff_command::{USAGE as DIFF_USAGE, run_diff};
+use hunk_server::server::{METHODS, Server};
+use hunk_server::transport::{serve_stdio, serve_unix_socket};
+
+const SOCKET_FILE_NAME: &str = "hunk.sock";
+
+enum Transport {
+    Stdio,
+    Socket(PathBuf),
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("serve") => run_serve(&args[1..]),
+        Some("diff") => run_diff(&args[1..]),
+        Some("-h" | "--help") | None => {
+            print_usage();
+            Ok(())
+        }
+        Some(other) => {
+            print_usage();
+            bail!("unknown command '{other}'")
+        }
+    }
+}
+
+fn run_serve(args: &[String]) -> Result<()> {
+    let transport = parse_serve_args(args)?;
+    let store = match DatabaseStore::new() {
+        Ok(store) => Some(store),
+        Err(err) => {
+            eprintln!("comments database is unavailable: {err:#}");
+            None
+        }
+    };
+    let server = Server::new(store);
+
+    match transport {
+        Transport::Stdio => serve_stdio(&server),
+        Transport::Socket(path) => {
+            eprintln!("hunk serve listening on {}", path.display());
+            serve_unix_socket(&server, path.as_path())
+        }
+    }
+}
+
+fn parse_serve_args(args: &[String]) -> Result<Transport> {
+    let mut transport = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stdio" => transport = Some(Transport::Stdio),
+            "--socket" => {
+                let path = args.next().context("--socket requires a path")?;
+                transport = Some(Transport::Socket(PathBuf::from(path)));
+            }
+            other => bail!("unknown serve option '{other}'"),
+        }
+    }
+    match transport {
+        Some(transport) => Ok(transport),
+        None => Ok(Transport::Socket(hunk_home_dir()?.join(SOCKET_FILE_NAME))),
+    }
+}
+
+fn print_usage() {
+    eprintln!("usage: hunk serve [--stdio | --socket <path>]");
+    eprintln!("       {}", DIFF_USAGE.trim_start_matches("usage: "));
+    eprintln!();
+    eprintln!(
+        "serve speaks newline-delimited JSON-RPC 2.0. Methods: {}",
+        METHODS.join(", ")
+    );
+    eprintln!("diff prints the working copy's changes against HEAD, side by side or unified.");
+}
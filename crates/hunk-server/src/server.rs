@@ -0,0 +1,401 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, anyhow};
+use hunk_domain::db::{
+    CommentLineSide, CommentRecord, CommentStatus, DatabaseStore, NewComment, comment_status_label,
+    now_unix_ms,
+};
+use hunk_git::api::{self, ChangedFile, FileStatus, WorkflowSnapshot};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::anchor::anchor_for_line;
+use crate::protocol::{
+    INVALID_REQUEST, JSONRPC_VERSION, METHOD_NOT_FOUND, PARSE_ERROR, Request, Response, RpcError,
+};
+
+pub const METHODS: &[&str] = &[
+    "snapshot.get",
+    "diff.get",
+    "comments.list",
+    "comments.add",
+    "comments.resolve",
+    "comments.reopen",
+    "comments.delete",
+];
+
+/// Dispatches JSON-RPC requests against Git repositories and the shared comments database.
+///
+/// The server keeps no per-client state, so one instance can be shared across connections.
+#[derive(Debug, Clone)]
+pub struct Server {
+    store: Option<DatabaseStore>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepoParams {
+    repo: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiffParams {
+    repo: PathBuf,
+    #[serde(default)]
+    paths: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListCommentsParams {
+    repo: PathBuf,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    include_non_open: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum LineSideParam {
+    Old,
+    #[default]
+    New,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddCommentParams {
+    repo: PathBuf,
+    #[serde(default)]
+    branch: Option<String>,
+    path: String,
+    line: u32,
+    #[serde(default)]
+    side: LineSideParam,
+    text: String,
+    /// Defaults to the repository's `user.name` and `user.email`.
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    author_email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentIdParams {
+    id: String,
+}
+
+impl Server {
+    pub fn new(store: Option<DatabaseStore>) -> Self {
+        Self { store }
+    }
+
+    /// Handles one line of input and returns the serialized response, or `None` for
+    /// notifications and blank lines.
+    pub fn handle_line(&self, line: &str) -> Option<String> {
+        if line.trim().is_empty() {
+            return None;
+        }
+        let response = match serde_json::from_str::<Value>(line) {
+            Ok(value) => self.handle_value(value)?,
+            Err(err) => Response::failure(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("invalid JSON: {err}")),
+            ),
+        };
+        serde_json::to_string(&response).ok()
+    }
+
+    fn handle_value(&self, value: Value) -> Option<Response> {
+        let request = match serde_json::from_value::<Request>(value) {
+            Ok(request) if request.jsonrpc == JSONRPC_VERSION => request,
+            Ok(request) => {
+                return Some(Response::failure(
+                    request.id.unwrap_or(Value::Null),
+                    RpcError::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""),
+                ));
+            }
+            Err(err) => {
+                return Some(Response::failure(
+                    Value::Null,
+                    RpcError::new(INVALID_REQUEST, format!("invalid request: {err}")),
+                ));
+            }
+        };
+
+        let result = self.dispatch(request.method.as_str(), request.params);
+        let id = request.id?;
+        Some(match result {
+            Ok(result) => Response::success(id, result),
+            Err(error) => Response::failure(id, error),
+        })
+    }
+
+    pub fn dispatch(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "snapshot.get" => self.snapshot(parse_params(params)?),
+            "diff.get" => self.diff(parse_params(params)?),
+            "comments.list" => self.list_comments(parse_params(params)?),
+            "comments.add" => self.add_comment(parse_params(params)?),
+            "comments.resolve" => {
+                self.set_comment_status(parse_params(params)?, CommentStatus::Resolved)
+            }
+            "comments.reopen" => {
+                self.set_comment_status(parse_params(params)?, CommentStatus::Open)
+            }
+            "comments.delete" => self.delete_comment(parse_params(params)?),
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method '{method}'"),
+            )),
+        }
+    }
+
+    fn snapshot(&self, params: RepoParams) -> Result<Value, RpcError> {
+        let snapshot = api::load_snapshot(params.repo.as_path())
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        Ok(json!({
+            "root": snapshot.root,
+            "branch": snapshot.branch_name,
+            "hasUpstream": snapshot.branch_has_upstream,
+            "ahead": snapshot.branch_ahead_count,
+            "behind": snapshot.branch_behind_count,
+            "lastCommitSubject": snapshot.last_commit_subject,
+            "lineStats": {
+                "added": snapshot.line_stats.added,
+                "removed": snapshot.line_stats.removed,
+            },
+            "files": snapshot.files.iter().map(changed_file_json).collect::<Vec<_>>(),
+        }))
+    }
+
+    fn diff(&self, params: DiffParams) -> Result<Value, RpcError> {
+        let snapshot = load_workflow(params.repo.as_path())?;
+        let files = snapshot
+            .files
+            .into_iter()
+            .filter(|file| params.paths.is_empty() || params.paths.contains(&file.path))
+            .collect::<Vec<_>>();
+        let diffs = api::load_file_diffs(snapshot.root.as_path(), &files)
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        Ok(json!({
+            "root": snapshot.root,
+            "files": diffs
+                .iter()
+                .map(|diff| json!({
+                    "path": diff.path,
+                    "status": file_status_label(diff.status),
+                    "patch": diff.patch,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    fn list_comments(&self, params: ListCommentsParams) -> Result<Value, RpcError> {
+        let store = self.store()?;
+        let (repo_root, branch_name) = comment_scope(params.repo.as_path(), params.branch)?;
+        let comments = store
+            .list_comments(
+                repo_root.as_str(),
+                branch_name.as_str(),
+                params.include_non_open,
+            )
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        Ok(json!({
+            "repo": repo_root,
+            "branch": branch_name,
+            "comments": comments.iter().map(comment_json).collect::<Vec<_>>(),
+        }))
+    }
+
+    fn add_comment(&self, params: AddCommentParams) -> Result<Value, RpcError> {
+        let store = self.store()?;
+        let comment_text = params.text.trim().to_string();
+        if comment_text.is_empty() {
+            return Err(RpcError::invalid_params("comment text cannot be empty"));
+        }
+
+        let snapshot = load_workflow(params.repo.as_path())?;
+        let Some(file) = snapshot
+            .files
+            .iter()
+            .find(|file| file.path == params.path)
+            .cloned()
+        else {
+            return Err(RpcError::invalid_params(format!(
+                "'{}' has no changes to comment on",
+                params.path
+            )));
+        };
+        let side = match params.side {
+            LineSideParam::Old => CommentLineSide::Left,
+            LineSideParam::New => CommentLineSide::Right,
+        };
+        let diff = api::load_file_diffs(snapshot.root.as_path(), std::slice::from_ref(&file))
+            .map_err(|err| RpcError::operation_failed(&err))?
+            .into_iter()
+            .next();
+        let Some(anchor) = diff
+            .and_then(|diff| anchor_for_line(file.path.as_str(), &diff.rows, side, params.line))
+        else {
+            return Err(RpcError::invalid_params(format!(
+                "line {} is not part of the diff for '{}'",
+                params.line, file.path
+            )));
+        };
+
+        let branch_name = params
+            .branch
+            .unwrap_or_else(|| scope_branch_name(snapshot.branch_name.as_str()));
+        let identity = if params.author.is_some() || params.author_email.is_some() {
+            api::UserIdentity {
+                name: params.author,
+                email: params.author_email,
+            }
+        } else {
+            api::load_user_identity(snapshot.root.as_path()).unwrap_or_default()
+        };
+        let input = NewComment {
+            repo_root: snapshot.root.to_string_lossy().to_string(),
+            branch_name,
+            created_head_commit: None,
+            file_path: file.path,
+            line_side: anchor.line_side,
+            old_line: anchor.old_line,
+            new_line: anchor.new_line,
+            row_stable_id: None,
+            hunk_header: anchor.hunk_header,
+            line_text: anchor.line_text,
+            context_before: anchor.context_before,
+            context_after: anchor.context_after,
+            anchor_hash: anchor.anchor_hash,
+            comment_text,
+            author_name: identity.name,
+            author_email: identity.email,
+        };
+        let record = store
+            .create_comment(&input)
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        Ok(comment_json(&record))
+    }
+
+    fn set_comment_status(
+        &self,
+        params: CommentIdParams,
+        status: CommentStatus,
+    ) -> Result<Value, RpcError> {
+        let store = self.store()?;
+        let updated = store
+            .mark_comment_status(params.id.as_str(), status, None, now_unix_ms())
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        if !updated {
+            return Err(unknown_comment(params.id.as_str()));
+        }
+        let record = store
+            .get_comment(params.id.as_str())
+            .map_err(|err| RpcError::operation_failed(&err))?
+            .ok_or_else(|| unknown_comment(params.id.as_str()))?;
+        Ok(comment_json(&record))
+    }
+
+    fn delete_comment(&self, params: CommentIdParams) -> Result<Value, RpcError> {
+        let deleted = self
+            .store()?
+            .delete_comment(params.id.as_str())
+            .map_err(|err| RpcError::operation_failed(&err))?;
+        if !deleted {
+            return Err(unknown_comment(params.id.as_str()));
+        }
+        Ok(json!({ "deleted": params.id }))
+    }
+
+    fn store(&self) -> Result<&DatabaseStore, RpcError> {
+        self.store
+            .as_ref()
+            .ok_or_else(|| RpcError::operation_failed(&anyhow!("comments database is unavailable")))
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| RpcError::invalid_params(err.to_string()))
+}
+
+fn load_workflow(repo: &Path) -> Result<WorkflowSnapshot, RpcError> {
+    api::load_workflow_snapshot(repo).map_err(|err| RpcError::operation_failed(&err))
+}
+
+fn comment_scope(repo: &Path, branch: Option<String>) -> Result<(String, String), RpcError> {
+    let snapshot = load_workflow(repo)?;
+    let branch_name = branch.unwrap_or_else(|| scope_branch_name(snapshot.branch_name.as_str()));
+    Ok((snapshot.root.to_string_lossy().to_string(), branch_name))
+}
+
+// Mirrors how the desktop scopes comments so both sides read and write the same rows.
+fn scope_branch_name(branch_name: &str) -> String {
+    let name = branch_name.trim();
+    if name.is_empty() || name == "unknown" {
+        "detached".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+fn unknown_comment(id: &str) -> RpcError {
+    RpcError::invalid_params(format!("no comment with id '{id}'"))
+}
+
+fn changed_file_json(file: &ChangedFile) -> Value {
+    json!({
+        "path": file.path,
+        "status": file_status_label(file.status),
+        "staged": file.staged,
+        "unstaged": file.unstaged,
+        "untracked": file.untracked,
+    })
+}
+
+fn comment_json(comment: &CommentRecord) -> Value {
+    json!({
+        "id": comment.id,
+        "repo": comment.repo_root,
+        "branch": comment.branch_name,
+        "path": comment.file_path,
+        "status": comment_status_label(comment.status),
+        "side": line_side_label(comment.line_side),
+        "oldLine": comment.old_line,
+        "newLine": comment.new_line,
+        "hunkHeader": comment.hunk_header,
+        "lineText": comment.line_text,
+        "text": comment.comment_text,
+        "author": comment.author_name,
+        "authorEmail": comment.author_email,
+        "staleReason": comment.stale_reason,
+        "createdAtUnixMs": comment.created_at_unix_ms,
+        "updatedAtUnixMs": comment.updated_at_unix_ms,
+    })
+}
+
+fn file_status_label(status: FileStatus) -> &'static str {
+    match status {
+        FileStatus::Added => "added",
+        FileStatus::Modified => "modified",
+        FileStatus::Deleted => "deleted",
+        FileStatus::Renamed => "renamed",
+        FileStatus::Untracked => "untracked",
+        FileStatus::TypeChange => "typechange",
+        FileStatus::Conflicted => "conflicted",
+        FileStatus::Unknown => "unknown",
+    }
+}
+
+fn line_side_label(side: CommentLineSide) -> &'static str {
+    match side {
+        CommentLineSide::Left => "old",
+        CommentLineSide::Right => "new",
+        CommentLineSide::Meta => "meta",
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use anyhow::{Context as _, Result};
+
+use crate::server::Server;
+
+/// Serves newline-delimited JSON-RPC until the reader reaches end of input.
+pub fn serve_lines(server: &Server, reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+    for line in reader.lines() {
+        let line = line.context("failed to read JSON-RPC request")?;
+        let Some(response) = server.handle_line(line.as_str()) else {
+            continue;
+        };
+        writeln!(writer, "{response}").context("failed to write JSON-RPC response")?;
+        writer
+            .flush()
+            .context("failed to flush JSON-RPC response")?;
+    }
+    Ok(())
+}
+
+pub fn serve_stdio(server: &Server) -> Result<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    serve_lines(server, stdin.lock(), stdout.lock())
+}
+
+/// Accepts local socket connections and serves each one on its own thread.
+///
+/// A stale socket left by a previous run is replaced. Anything else at the
+/// path, including a socket another server is still listening on, is left
+/// alone and reported as an error.
+#[cfg(unix)]
+pub fn serve_unix_socket(server: &Server, socket_path: &Path) -> Result<()> {
+    use std::io::BufReader;
+    use std::os::unix::net::UnixListener;
+
+    if let Some(parent) = socket_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    remove_stale_socket(socket_path)?;
+    let listener = UnixListener::bind(socket_path)
+        .with_context(|| format!("failed to bind {}", socket_path.display()))?;
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("failed to accept connection: {err}");
+                continue;
+            }
+        };
+        let server = server.clone();
+        std::thread::Builder::new()
+            .name("hunk-serve-connection".to_string())
+            .spawn(move || {
+                let reader = match stream.try_clone() {
+                    Ok(reader) => BufReader::new(reader),
+                    Err(err) => {
+                        eprintln!("failed to clone connection: {err}");
+                        return;
+                    }
+                };
+                if let Err(err) = serve_lines(&server, reader, stream) {
+                    eprintln!("connection closed with error: {err:#}");
+                }
+            })
+            .context("failed to spawn connection thread")?;
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn remove_stale_socket(socket_path: &Path) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt as _;
+    use std::os::unix::net::UnixStream;
+
+    let metadata = match std::fs::symlink_metadata(socket_path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("failed to inspect {}", socket_path.display()));
+        }
+    };
+    if !metadata.file_type().is_socket() {
+        anyhow::bail!(
+            "{} already exists and is not a socket; choose another --socket path",
+            socket_path.display()
+        );
+    }
+    if UnixStream::connect(socket_path).is_ok() {
+        anyhow::bail!(
+            "another server is already listening on {}",
+            socket_path.display()
+        );
+    }
+    std::fs::remove_file(socket_path)
+        .with_context(|| format!("failed to remove stale socket {}", socket_path.display()))
+}
+
+#[cfg(not(unix))]
+pub fn serve_unix_socket(_server: &Server, _socket_path: &Path) -> Result<()> {
+    anyhow::bail!("local sockets are only supported on Unix; use --stdio instead")
+}
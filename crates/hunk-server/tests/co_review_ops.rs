@@ -0,0 +1,146 @@
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use hunk_server::co_review::{
+    CoReviewEvent, CoReviewMessage, CoReviewSession, Participant, Presence, format_join_target,
+    parse_join_target,
+};
+
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn guests_see_the_room_and_presence_flows_both_ways() -> Result<()> {
+    let host = host_session("Host")?;
+    let address = host.local_addr().ok_or_else(|| anyhow!("host address"))?;
+    let guest = CoReviewSession::join(
+        address.to_string().as_str(),
+        host.join_code(),
+        Participant::new("Guest"),
+    )?;
+
+    wait_for(
+        &host,
+        |event| matches!(event, CoReviewEvent::Message(CoReviewMessage::Hello { participant, .. }) if participant.name == "Guest"),
+    )?;
+    wait_for(
+        &guest,
+        |event| matches!(event, CoReviewEvent::Message(CoReviewMessage::Hello { participant, .. }) if participant.name == "Host"),
+    )?;
+
+    guest.send_presence(Presence {
+        path: Some("src/lib.rs".to_string()),
+        row_offset: Some(12),
+    })?;
+    wait_for(&host, |event| {
+        matches!(
+            event,
+            CoReviewEvent::Message(CoReviewMessage::Presence { presence, .. })
+                if presence.path.as_deref() == Some("src/lib.rs") && presence.row_offset == Some(12)
+        )
+    })?;
+
+    host.send_presence(Presence {
+        path: Some("README.md".to_string()),
+        row_offset: None,
+    })?;
+    wait_for(&guest, |event| {
+        matches!(
+            event,
+            CoReviewEvent::Message(CoReviewMessage::Presence { presence, .. })
+                if presence.path.as_deref() == Some("README.md")
+        )
+    })?;
+
+    let guest_id = guest.participant().id.clone();
+    drop(guest);
+    wait_for(
+        &host,
+        |event| matches!(event, CoReviewEvent::Message(CoReviewMessage::Leave { participant_id }) if *participant_id == guest_id),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn wrong_join_code_is_rejected() -> Result<()> {
+    let host = host_session("Host")?;
+    let address = host.local_addr().ok_or_else(|| anyhow!("host address"))?;
+    let guest = CoReviewSession::join(
+        address.to_string().as_str(),
+        "NOPE00",
+        Participant::new("Guest"),
+    )?;
+
+    wait_for(
+        &guest,
+        |event| matches!(event, CoReviewEvent::Disconnected(reason) if reason.contains("join code")),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn repeated_wrong_join_codes_lock_the_session() -> Result<()> {
+    let host = host_session("Host")?;
+    let address = host.local_addr().ok_or_else(|| anyhow!("host address"))?;
+    for _ in 0..10 {
+        let guest = CoReviewSession::join(
+            address.to_string().as_str(),
+            "NOPE00",
+            Participant::new("Guest"),
+        )?;
+        wait_for(
+            &guest,
+            |event| matches!(event, CoReviewEvent::Disconnected(reason) if reason.contains("join code")),
+        )?;
+    }
+
+    let guest = CoReviewSession::join(
+        address.to_string().as_str(),
+        host.join_code(),
+        Participant::new("Guest"),
+    )?;
+    wait_for(
+        &guest,
+        |event| matches!(event, CoReviewEvent::Disconnected(reason) if reason.contains("too many")),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn join_targets_round_trip() -> Result<()> {
+    let address = SocketAddr::from((Ipv4Addr::new(192, 168, 1, 20), 47321));
+    let target = format_join_target(address, "K7M2QP");
+    assert_eq!(target, "192.168.1.20:47321#K7M2QP");
+    assert_eq!(
+        parse_join_target(target.as_str())?,
+        ("192.168.1.20:47321".to_string(), "K7M2QP".to_string())
+    );
+    assert_eq!(
+        parse_join_target(" host.local:9000 k7m2qp ")?,
+        ("host.local:9000".to_string(), "K7M2QP".to_string())
+    );
+    assert!(parse_join_target("host.local:9000").is_err());
+    Ok(())
+}
+
+fn host_session(name: &str) -> Result<CoReviewSession> {
+    CoReviewSession::host(
+        SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+        Participant::new(name),
+    )
+}
+
+fn wait_for(
+    session: &CoReviewSession,
+    mut predicate: impl FnMut(&CoReviewEvent) -> bool,
+) -> Result<()> {
+    let deadline = Instant::now() + EVENT_TIMEOUT;
+    while Instant::now() < deadline {
+        match session.try_recv() {
+            Some(event) if predicate(&event) => return Ok(()),
+            Some(_) => {}
+            None => std::thread::sleep(Duration::from_millis(10)),
+        }
+    }
+    Err(anyhow!("timed out waiting for co-review event"))
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use hunk_git::api::{ChangedFile, FileStatus};
+use hunk_server::diff_command::{
+    DEFAULT_WIDTH, DiffArgs, DiffLayout, DiffStyle, MIN_WIDTH, format_file_diffs, parse_diff_args,
+};
+
+const PATCH: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ fn keep() {}
+-fn old_one() {}
+-fn old_two() {}
++fn new_one() {}
++fn new_two() {}
+";
+
+fn changed_file(path: &str) -> ChangedFile {
+    ChangedFile {
+        path: path.to_string(),
+        status: FileStatus::Modified,
+        staged: false,
+        unstaged: true,
+        untracked: false,
+    }
+}
+
+fn format(files: &[(&str, &str)], layout: DiffLayout, width: usize) -> String {
+    let changed = files
+        .iter()
+        .map(|(path, _)| changed_file(path))
+        .collect::<Vec<_>>();
+    let patches = files
+        .iter()
+        .map(|(path, patch)| (path.to_string(), patch.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    format_file_diffs(
+        &changed,
+        &patches,
+        DiffStyle {
+            layout,
+            color: false,
+            width,
+        },
+    )
+}
+
+fn parse(args: &[&str]) -> Result<Option<DiffArgs>> {
+    parse_diff_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+}
+
+#[test]
+fn diff_args_parse_layout_width_and_path() {
+    let args = parse(&["-u", "--width", "100", "src"])
+        .expect("args should parse")
+        .expect("not a help request");
+    assert_eq!(
+        args,
+        DiffArgs {
+            path: PathBuf::from("src"),
+            layout: DiffLayout::Unified,
+            color: None,
+            width: Some(100),
+        }
+    );
+    assert_eq!(parse(&["--help"]).expect("help should parse"), None);
+    assert!(parse(&["--bogus"]).is_err());
+    assert!(parse(&["--width=0"]).is_err());
+    assert!(parse(&["one", "two"]).is_err());
+}
+
+#[test]
+fn unified_output_lists_removed_lines_before_added_ones() {
+    let output = format(&[("src/lib.rs", PATCH)], DiffLayout::Unified, DEFAULT_WIDTH);
+    let lines = output.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "M src/lib.rs");
+    assert!(lines[1].starts_with("@@ -1,3 +1,3 @@"));
+    assert_eq!(
+        &lines[2..],
+        [
+            "1 1  fn keep() {}",
+            "2   -fn old_one() {}",
+            "3   -fn old_two() {}",
+            "  2 +fn new_one() {}",
+            "  3 +fn new_two() {}",
+        ]
+    );
+}
+
+#[test]
+fn side_by_side_output_fits_the_requested_width() {
+    let output = format(&[("src/lib.rs", PATCH)], DiffLayout::SideBySide, MIN_WIDTH);
+    let paired = output
+        .lines()
+        .find(|line| line.contains("old_one"))
+        .expect("paired row");
+
+    assert!(paired.contains("new_one"));
+    assert!(output.lines().all(|line| line.chars().count() <= MIN_WIDTH));
+}
+
+#[test]
+fn binary_files_and_hidden_characters_get_warnings() {
+    let output = format(
+        &[
+            ("logo.png", "@@ -1 +1 @@\n-a\n+b\n"),
+            ("src/auth.rs", "@@ -1 +1 @@\n-ok\n+ok\u{202e}\n"),
+        ],
+        DiffLayout::Unified,
+        DEFAULT_WIDTH,
+    );
+    let lines = output.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "M logo.png");
+    assert_eq!(
+        lines[1],
+        "Preview unavailable for logo.png: binary file type."
+    );
+    assert_eq!(lines[3], "M src/auth.rs");
+    assert_eq!(
+        lines[4],
+        "1 hidden or confusable character added: line 1 U+202E right-to-left override"
+    );
+}
+
+#[test]
+fn no_changes_print_a_note() {
+    assert_eq!(
+        format(&[], DiffLayout::SideBySide, DEFAULT_WIDTH),
+        "No changed files.\n"
+    );
+}
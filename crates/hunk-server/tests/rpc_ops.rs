@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use git2::{IndexAddOption, Repository, Signature};
+use hunk_domain::db::DatabaseStore;
+use hunk_server::protocol::{INVALID_PARAMS, METHOD_NOT_FOUND};
+use hunk_server::server::Server;
+use hunk_server::transport::{serve_lines, serve_unix_socket};
+use serde_json::{Value, json};
+use tempfile::TempDir;
+
+#[test]
+fn snapshot_and_diff_report_working_tree_changes() -> Result<()> {
+    let fixture = Fixture::new()?;
+    fixture.write_file("src/lib.rs", "one\ntwo\nthree\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("src/lib.rs", "one\nTWO\nthree\n")?;
+
+    let snapshot = fixture.call("snapshot.get", json!({ "repo": fixture.root() }))?;
+    assert_eq!(snapshot["files"][0]["path"], "src/lib.rs");
+    assert_eq!(snapshot["files"][0]["status"], "modified");
+    assert_eq!(snapshot["lineStats"]["added"], 1);
+
+    let diff = fixture.call(
+        "diff.get",
+        json!({ "repo": fixture.root(), "paths": ["src/lib.rs"] }),
+    )?;
+    let patch = diff["files"][0]["patch"].as_str().context("patch")?;
+    assert!(patch.contains("+TWO"));
+    Ok(())
+}
+
+#[test]
+fn comments_round_trip_through_the_shared_store() -> Result<()> {
+    let fixture = Fixture::new()?;
+    fixture.write_file("src/lib.rs", "one\ntwo\nthree\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("src/lib.rs", "one\nTWO\nthree\n")?;
+
+    let created = fixture.call(
+        "comments.add",
+        json!({
+            "repo": fixture.root(),
+            "path": "src/lib.rs",
+            "line": 2,
+            "text": "Why uppercase?",
+            "author": "Review Bot",
+        }),
+    )?;
+    assert_eq!(created["status"], "open");
+    assert_eq!(created["author"], "Review Bot");
+    assert_eq!(created["side"], "new");
+    assert_eq!(created["newLine"], 2);
+    assert_eq!(created["lineText"], "-two\n+TWO");
+    let id = created["id"].as_str().context("comment id")?.to_string();
+
+    let listed = fixture.call("comments.list", json!({ "repo": fixture.root() }))?;
+    assert_eq!(listed["comments"].as_array().map(Vec::len), Some(1));
+    assert_eq!(listed["comments"][0]["text"], "Why uppercase?");
+
+    let resolved = fixture.call("comments.resolve", json!({ "id": id }))?;
+    assert_eq!(resolved["status"], "resolved");
+    let open_only = fixture.call("comments.list", json!({ "repo": fixture.root() }))?;
+    assert_eq!(open_only["comments"].as_array().map(Vec::len), Some(0));
+
+    fixture.call("comments.delete", json!({ "id": id }))?;
+    let all = fixture.call(
+        "comments.list",
+        json!({ "repo": fixture.root(), "includeNonOpen": true }),
+    )?;
+    assert_eq!(all["comments"].as_array().map(Vec::len), Some(0));
+    Ok(())
+}
+
+#[test]
+fn comments_add_rejects_lines_outside_the_diff() -> Result<()> {
+    let fixture = Fixture::new()?;
+    fixture.write_file("src/lib.rs", "one\ntwo\nthree\n")?;
+    fixture.commit_all("initial")?;
+    fixture.write_file("src/lib.rs", "one\nTWO\nthree\n")?;
+
+    let error = fixture
+        .server
+        .dispatch(
+            "comments.add",
+            json!({
+                "repo": fixture.root(),
+                "path": "src/lib.rs",
+                "line": 40,
+                "text": "nowhere",
+            }),
+        )
+        .expect_err("line 40 is not in the diff");
+    assert_eq!(error.code, INVALID_PARAMS);
+    Ok(())
+}
+
+#[test]
+fn line_transport_answers_requests_and_skips_notifications() -> Result<()> {
+    let fixture = Fixture::new()?;
+    let input = [
+        r#"{"jsonrpc":"2.0","id":1,"method":"nope"}"#,
+        r#"{"jsonrpc":"2.0","method":"nope"}"#,
+        "",
+        "not json",
+    ]
+    .join("\n");
+    let mut output = Vec::new();
+    serve_lines(&fixture.server, input.as_bytes(), &mut output)?;
+
+    let responses = String::from_utf8(output)?
+        .lines()
+        .map(serde_json::from_str::<Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(responses[0]["error"]["code"], METHOD_NOT_FOUND);
+    assert_eq!(responses[1]["id"], Value::Null);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_socket_refuses_to_replace_a_regular_file() -> Result<()> {
+    let fixture = Fixture::new()?;
+    let path = fixture.root().join("not-a-socket");
+    fs::write(path.as_path(), "keep me")?;
+
+    let err = serve_unix_socket(&fixture.server, path.as_path())
+        .expect_err("a regular file should not be replaced");
+    assert!(err.to_string().contains("is not a socket"), "{err:#}");
+    assert_eq!(fs::read_to_string(path)?, "keep me");
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_socket_refuses_to_replace_a_live_socket() -> Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    let fixture = Fixture::new()?;
+    let path = fixture.root().join("live.sock");
+    let _listener = UnixListener::bind(path.as_path())?;
+
+    let err = serve_unix_socket(&fixture.server, path.as_path())
+        .expect_err("a socket that still accepts connections should not be replaced");
+    assert!(err.to_string().contains("already listening"), "{err:#}");
+    assert!(path.exists());
+    Ok(())
+}
+
+struct Fixture {
+    _tempdir: TempDir,
+    root: PathBuf,
+    server: Server,
+}
+
+impl Fixture {
+    fn new() -> Result<Self> {
+        let tempdir = tempfile::tempdir()?;
+        let root = tempdir.path().join("repo");
+        Repository::init(root.as_path())?;
+        let store = DatabaseStore::from_path(tempdir.path().join("hunk.db"));
+        Ok(Self {
+            root: fs::canonicalize(root)?,
+            server: Server::new(Some(store)),
+            _tempdir: tempdir,
+        })
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        self.server
+            .dispatch(method, params)
+            .map_err(|err| anyhow::anyhow!("{method} failed: {}", err.message))
+    }
+
+    fn write_file(&self, relative: &str, contents: &str) -> Result<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<()> {
+        let repo = Repository::open(self.root.as_path())?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("Hunk", "hunk@example.com")?;
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[])?;
+        Ok(())
+    }
+}